@@ -74,6 +74,12 @@ pub enum InjectionType {
     /// correct a misunderstanding.
     Correction(String),
 
+    /// Message the user sent while the agent was busy
+    ///
+    /// Held until the current response completes, then delivered as the
+    /// next turn (e.g. an `@worker1` note to a running worker).
+    UserMessage(String),
+
     /// Worker agent result
     ///
     /// Injects results from a completed worker agent back
@@ -105,6 +111,7 @@ impl InjectionType {
             InjectionType::ToolFollowUp { .. } => "ToolFollowUp",
             InjectionType::MemorySummary(_) => "MemorySummary",
             InjectionType::Correction(_) => "Correction",
+            InjectionType::UserMessage(_) => "UserMessage",
             InjectionType::WorkerResult { .. } => "WorkerResult",
             InjectionType::ErrorRecovery { .. } => "ErrorRecovery",
         }
@@ -125,6 +132,9 @@ impl InjectionType {
             InjectionType::Correction(correction) => {
                 format!("[Correction]\n{}", correction)
             }
+            InjectionType::UserMessage(message) => {
+                format!("[Message from user]\n{}", message)
+            }
             InjectionType::WorkerResult { worker_id, result } => {
                 format!("[Worker {} Result]\n{}", worker_id, result)
             }
@@ -449,6 +459,10 @@ mod tests {
             InjectionType::Correction("test".into()).label(),
             "Correction"
         );
+        assert_eq!(
+            InjectionType::UserMessage("test".into()).label(),
+            "UserMessage"
+        );
     }
 
     #[test]
@@ -463,6 +477,12 @@ mod tests {
         };
         assert!(follow.format_message().contains("execute_javascript"));
         assert!(follow.format_message().contains("Analyze the results"));
+
+        let user = InjectionType::UserMessage("Skip read replicas".into());
+        assert_eq!(
+            user.format_message(),
+            "[Message from user]\nSkip read replicas"
+        );
     }
 
     #[test]
@@ -0,0 +1,386 @@
+//! Conversation Memory
+//!
+//! Key facts that carry over between agent sessions of one project, so a new
+//! conversation does not start from zero ("orders run in 210987654321",
+//! "prod VPCs are tagged env=production").
+//!
+//! A project is the AWS Identity Center organization the agent runs under,
+//! keyed by the host of its start URL. At the end of a task manager session
+//! (the agent is closed or its conversation cleared) the agent is asked for
+//! the facts worth remembering, which are merged into the project's
+//! [`ProjectMemory`]. New task manager conversations get the memory appended
+//! to their system prompt. Entries can be reviewed, edited, and deleted in
+//! the memory inspector.
+//!
+//! ```text
+//! ~/.local/share/awsdash/memory/
+//!   d-1234567890.awsapps.com.json
+//! ```
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Entries kept per project; the oldest are dropped first
+pub const MAX_MEMORY_ENTRIES: usize = 50;
+
+/// Facts taken from one session
+pub const MAX_FACTS_PER_SESSION: usize = 10;
+
+/// Longest fact kept, in characters
+pub const MAX_FACT_LEN: usize = 300;
+
+/// Project used when no Identity Center is configured
+pub const DEFAULT_PROJECT: &str = "default";
+
+/// Prompt sent to the agent at the end of a session to extract facts
+pub const MEMORY_EXTRACTION_PROMPT: &str = "[Session Memory]\n\
+This session is ending. List the facts from it that would help in a future \
+session with this user and these AWS accounts: which account, region, or \
+resource IDs hold which workloads, naming and tagging conventions, the \
+user's stated preferences, and conclusions that took several steps to reach. \
+Skip one-off numbers that will be stale tomorrow, anything already in \
+<project_memory>, and secrets. Write at most 10 facts, one per line, each \
+starting with \"- \" and readable on its own. If nothing is worth \
+remembering, reply with NONE.";
+
+/// One remembered fact
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryEntry {
+    pub id: String,
+    pub fact: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Agent session the fact came from (None when added by hand)
+    pub source_session: Option<String>,
+}
+
+impl MemoryEntry {
+    pub fn new(fact: impl Into<String>, source_session: Option<String>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            fact: fact.into(),
+            created_at: now,
+            updated_at: now,
+            source_session,
+        }
+    }
+}
+
+/// Remembered facts of one project, oldest first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectMemory {
+    pub project: String,
+    pub entries: Vec<MemoryEntry>,
+}
+
+impl ProjectMemory {
+    pub fn new(project: impl Into<String>) -> Self {
+        Self {
+            project: project.into(),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Add facts that are not already remembered; returns how many were added
+    ///
+    /// Facts are compared ignoring case, punctuation, and spacing. When the
+    /// project is over [`MAX_MEMORY_ENTRIES`], the oldest entries are dropped.
+    pub fn add_facts(&mut self, facts: &[String], source_session: Option<&str>) -> usize {
+        let mut added = 0;
+        for fact in facts {
+            let fact = truncate_fact(fact.trim());
+            let key = fact_key(&fact);
+            if key.is_empty() || self.entries.iter().any(|e| fact_key(&e.fact) == key) {
+                continue;
+            }
+            self.entries
+                .push(MemoryEntry::new(fact, source_session.map(str::to_string)));
+            added += 1;
+        }
+        if self.entries.len() > MAX_MEMORY_ENTRIES {
+            let excess = self.entries.len() - MAX_MEMORY_ENTRIES;
+            self.entries.drain(..excess);
+        }
+        added
+    }
+
+    /// Replace the text of an entry; returns false if it does not exist
+    pub fn update(&mut self, id: &str, fact: &str) -> bool {
+        match self.entries.iter_mut().find(|e| e.id == id) {
+            Some(entry) => {
+                entry.fact = truncate_fact(fact.trim());
+                entry.updated_at = Utc::now();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Delete an entry; returns false if it does not exist
+    pub fn remove(&mut self, id: &str) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.id != id);
+        self.entries.len() != before
+    }
+
+    /// Block appended to the system prompt of new conversations
+    ///
+    /// None when nothing is remembered.
+    pub fn system_context(&self) -> Option<String> {
+        if self.entries.is_empty() {
+            return None;
+        }
+        let facts: Vec<String> = self
+            .entries
+            .iter()
+            .map(|e| format!("- {}", e.fact))
+            .collect();
+        Some(format!(
+            "<project_memory>\n\
+             Facts remembered from earlier sessions in this AWS organization. \
+             The user can edit them; verify anything that may have changed \
+             before acting on it.\n{}\n</project_memory>",
+            facts.join("\n")
+        ))
+    }
+}
+
+/// Project key of an Identity Center start URL: its host
+///
+/// `https://d-1234567890.awsapps.com/start` -> `d-1234567890.awsapps.com`
+pub fn project_key(start_url: &str) -> String {
+    let host = start_url
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let key: String = host
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '.' || *c == '-')
+        .collect();
+    if key.trim_matches('.').is_empty() {
+        DEFAULT_PROJECT.to_string()
+    } else {
+        key
+    }
+}
+
+/// Facts in the agent's reply to [`MEMORY_EXTRACTION_PROMPT`]
+pub fn parse_memory_facts(response: &str) -> Vec<String> {
+    response
+        .lines()
+        .filter_map(|line| {
+            line.trim()
+                .strip_prefix("- ")
+                .or_else(|| line.trim().strip_prefix("* "))
+        })
+        .map(|fact| truncate_fact(fact.trim()))
+        .filter(|fact| !fact.is_empty())
+        .take(MAX_FACTS_PER_SESSION)
+        .collect()
+}
+
+fn truncate_fact(fact: &str) -> String {
+    if fact.chars().count() <= MAX_FACT_LEN {
+        fact.to_string()
+    } else {
+        let truncated: String = fact.chars().take(MAX_FACT_LEN - 3).collect();
+        format!("{}...", truncated)
+    }
+}
+
+/// Comparison key of a fact: lowercase letters and digits
+fn fact_key(fact: &str) -> String {
+    fact.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Directory of project memory files
+#[derive(Debug, Clone)]
+pub struct MemoryStore {
+    directory: PathBuf,
+}
+
+impl MemoryStore {
+    /// Create a store rooted at `directory`
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Store in the application data directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(dirs::data_local_dir()?.join("awsdash/memory")))
+    }
+
+    /// Directory holding memory files
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn path(&self, project: &str) -> PathBuf {
+        self.directory.join(format!("{}.json", project))
+    }
+
+    /// Memory of `project`, empty if nothing was saved yet
+    pub fn load(&self, project: &str) -> Result<ProjectMemory> {
+        let path = self.path(project);
+        if !path.exists() {
+            return Ok(ProjectMemory::new(project));
+        }
+        let json = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Write (or overwrite) the file for `memory.project`
+    pub fn save(&self, memory: &ProjectMemory) -> Result<()> {
+        fs::create_dir_all(&self.directory).with_context(|| {
+            format!(
+                "Failed to create memory directory {}",
+                self.directory.display()
+            )
+        })?;
+        let path = self.path(&memory.project);
+        let json = serde_json::to_string_pretty(memory)?;
+        fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Projects with saved memory, sorted by name
+    pub fn projects(&self) -> Vec<String> {
+        let Ok(entries) = fs::read_dir(&self.directory) else {
+            return Vec::new(); // Nothing remembered yet
+        };
+        let mut projects: Vec<String> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("json"))
+            .filter_map(|path| Some(path.file_stem()?.to_str()?.to_string()))
+            .collect();
+        projects.sort();
+        projects
+    }
+
+    /// Merge facts from a finished session into the project's memory
+    pub fn remember(&self, project: &str, facts: &[String], session_id: &str) -> Result<usize> {
+        let mut memory = self.load(project)?;
+        let added = memory.add_facts(facts, Some(session_id));
+        if added > 0 {
+            self.save(&memory)?;
+        }
+        Ok(added)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_project_key() {
+        assert_eq!(
+            project_key("https://d-1234567890.awsapps.com/start"),
+            "d-1234567890.awsapps.com"
+        );
+        assert_eq!(
+            project_key("My-Org.awsapps.com/start#/"),
+            "my-org.awsapps.com"
+        );
+        assert_eq!(project_key(""), DEFAULT_PROJECT);
+        assert_eq!(project_key("https://../"), DEFAULT_PROJECT);
+    }
+
+    #[test]
+    fn test_parse_memory_facts() {
+        let response = "Here is what to remember:\n\
+            - Orders run in account 210987654321 (us-east-2)\n\
+            * Prod VPCs are tagged env=production\n\
+            -  \n\
+            Not a fact";
+        assert_eq!(
+            parse_memory_facts(response),
+            vec![
+                "Orders run in account 210987654321 (us-east-2)",
+                "Prod VPCs are tagged env=production"
+            ]
+        );
+        assert!(parse_memory_facts("NONE").is_empty());
+
+        let many: String = (0..20).map(|i| format!("- fact {}\n", i)).collect();
+        assert_eq!(parse_memory_facts(&many).len(), MAX_FACTS_PER_SESSION);
+
+        let long = format!("- {}", "x".repeat(MAX_FACT_LEN + 50));
+        assert_eq!(parse_memory_facts(&long)[0].chars().count(), MAX_FACT_LEN);
+    }
+
+    #[test]
+    fn test_add_facts_dedupes_and_caps() {
+        let mut memory = ProjectMemory::new("org");
+        let facts = vec![
+            "Payments DB is payments-db in us-east-1".to_string(),
+            "payments db is Payments-DB in us-east-1.".to_string(),
+        ];
+        assert_eq!(memory.add_facts(&facts, Some("session-1")), 1);
+        assert_eq!(memory.add_facts(&facts, Some("session-2")), 0);
+        assert_eq!(
+            memory.entries[0].source_session.as_deref(),
+            Some("session-1")
+        );
+
+        let many: Vec<String> = (0..MAX_MEMORY_ENTRIES)
+            .map(|i| format!("fact {}", i))
+            .collect();
+        memory.add_facts(&many, None);
+        assert_eq!(memory.entries.len(), MAX_MEMORY_ENTRIES);
+        assert_eq!(memory.entries[0].fact, "fact 0");
+    }
+
+    #[test]
+    fn test_update_remove_and_context() {
+        let mut memory = ProjectMemory::new("org");
+        assert!(memory.system_context().is_none());
+
+        memory.add_facts(&["Use profile admin".to_string()], None);
+        let id = memory.entries[0].id.clone();
+        assert!(memory.update(&id, "  Use the ReadOnly role  "));
+        assert_eq!(memory.entries[0].fact, "Use the ReadOnly role");
+
+        let context = memory.system_context().unwrap();
+        assert!(context.starts_with("<project_memory>"));
+        assert!(context.contains("- Use the ReadOnly role\n</project_memory>"));
+
+        assert!(memory.remove(&id));
+        assert!(!memory.remove(&id));
+        assert!(!memory.update(&id, "gone"));
+    }
+
+    #[test]
+    fn test_store_round_trip() {
+        let dir = TempDir::new().unwrap();
+        let store = MemoryStore::new(dir.path().join("memory"));
+        assert!(store.projects().is_empty());
+        assert!(store.load("org").unwrap().entries.is_empty());
+
+        let facts = vec!["Staging is account 111122223333".to_string()];
+        assert_eq!(store.remember("org", &facts, "session-1").unwrap(), 1);
+        assert_eq!(store.remember("org", &facts, "session-2").unwrap(), 0);
+
+        let memory = store.load("org").unwrap();
+        assert_eq!(memory.project, "org");
+        assert_eq!(memory.entries.len(), 1);
+        assert_eq!(store.projects(), vec!["org"]);
+    }
+}
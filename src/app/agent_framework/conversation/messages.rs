@@ -17,7 +17,7 @@ pub enum ConversationRole {
 }
 
 /// A single message in the conversation
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ConversationMessage {
     /// Role of the message sender
     pub role: ConversationRole,
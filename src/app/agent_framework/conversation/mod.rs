@@ -1,10 +1,18 @@
 //! Conversation and message handling
 //!
-//! This module manages agent conversations, messages, and message injection.
+//! This module manages agent conversations, messages, message injection,
+//! transcript export, memory carried across sessions, and `@`-mention
+//! routing between the agents of one chat.
 
 pub mod injection;
+pub mod memory;
 pub mod messages;
+pub mod routing;
+pub mod transcript;
 
 // Re-export commonly used items
 pub use injection::*;
+pub use memory::*;
 pub use messages::*;
+pub use routing::*;
+pub use transcript::*;
@@ -0,0 +1,191 @@
+//! Chat Routing
+//!
+//! A task manager's chat shows the manager and its live workers as separate
+//! threads. A message that starts with `@handle` is sent to the agent with
+//! that handle; any other message goes to the thread being viewed.
+//!
+//! Handles are short and stable for the conversation: `manager`, then
+//! `worker1`, `worker2`, ... and `page1`, `page2`, ... in the order workers
+//! were started.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use crate::app::agent_framework::core::types::AgentId;
+
+/// Handle of the task manager in its own chat
+pub const MANAGER_HANDLE: &str = "manager";
+
+/// An agent that can be addressed in a chat
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatParticipant {
+    pub agent_id: AgentId,
+    /// Mention handle, without the `@`
+    pub handle: String,
+    /// What the agent is doing, for tabs and tooltips
+    pub label: String,
+    /// Whether the agent is still working on a response
+    pub is_processing: bool,
+}
+
+impl ChatParticipant {
+    pub fn new(agent_id: AgentId, handle: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            agent_id,
+            handle: handle.into(),
+            label: label.into(),
+            is_processing: false,
+        }
+    }
+
+    pub fn with_processing(mut self, is_processing: bool) -> Self {
+        self.is_processing = is_processing;
+        self
+    }
+}
+
+/// Handle of the `index`th worker (0-based) of a kind started by a manager
+pub fn worker_handle(is_page_builder: bool, index: usize) -> String {
+    let kind = if is_page_builder { "page" } else { "worker" };
+    format!("{}{}", kind, index + 1)
+}
+
+/// Where a message typed in the chat should go
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChatRoute {
+    /// No mention - send to the thread being viewed
+    Viewed(String),
+    /// Mentioned agent and the message without the mention
+    Agent { agent_id: AgentId, message: String },
+    /// Mention that matches no participant
+    UnknownHandle(String),
+}
+
+/// Leading `@handle` of a message and the text after it
+///
+/// Only a mention at the start counts, so addresses and decorators inside
+/// a message are left alone.
+pub fn parse_mention(text: &str) -> Option<(&str, &str)> {
+    let rest = text.trim_start().strip_prefix('@')?;
+    let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+    let handle = &rest[..end];
+    let valid = !handle.is_empty()
+        && handle
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+    valid.then(|| (handle, rest[end..].trim()))
+}
+
+/// Decide which participant a message is for
+pub fn route_message(text: &str, participants: &[ChatParticipant]) -> ChatRoute {
+    let Some((handle, message)) = parse_mention(text) else {
+        return ChatRoute::Viewed(text.to_string());
+    };
+    match participants
+        .iter()
+        .find(|p| p.handle.eq_ignore_ascii_case(handle))
+    {
+        Some(participant) => ChatRoute::Agent {
+            agent_id: participant.agent_id,
+            message: message.to_string(),
+        },
+        None => ChatRoute::UnknownHandle(handle.to_string()),
+    }
+}
+
+/// Participants whose handle completes a partly typed leading mention
+///
+/// Returns nothing once the mention is followed by a space.
+pub fn mention_completions<'a>(
+    input: &str,
+    participants: &'a [ChatParticipant],
+) -> Vec<&'a ChatParticipant> {
+    let Some(prefix) = input.strip_prefix('@') else {
+        return Vec::new();
+    };
+    if prefix.contains(char::is_whitespace) {
+        return Vec::new();
+    }
+    let prefix = prefix.to_lowercase();
+    participants
+        .iter()
+        .filter(|p| p.handle.to_lowercase().starts_with(&prefix))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn participants() -> Vec<ChatParticipant> {
+        vec![
+            ChatParticipant::new(AgentId::new(), MANAGER_HANDLE, "Manager"),
+            ChatParticipant::new(
+                AgentId::new(),
+                worker_handle(false, 0),
+                "Finding S3 buckets",
+            ),
+            ChatParticipant::new(
+                AgentId::new(),
+                worker_handle(false, 1),
+                "Listing RDS instances",
+            )
+            .with_processing(true),
+            ChatParticipant::new(AgentId::new(), worker_handle(true, 0), "Cost dashboard"),
+        ]
+    }
+
+    #[test]
+    fn test_parse_mention() {
+        assert_eq!(
+            parse_mention("@worker1 also check us-west-2"),
+            Some(("worker1", "also check us-west-2"))
+        );
+        assert_eq!(parse_mention("  @manager"), Some(("manager", "")));
+        assert_eq!(parse_mention("mail ops@example.com"), None);
+        assert_eq!(parse_mention("@ worker1 hi"), None);
+        assert_eq!(parse_mention("@worker1: hi"), None);
+    }
+
+    #[test]
+    fn test_route_message() {
+        let participants = participants();
+        assert_eq!(
+            route_message("list my buckets", &participants),
+            ChatRoute::Viewed("list my buckets".to_string())
+        );
+        assert_eq!(
+            route_message("@Worker2 skip the replicas", &participants),
+            ChatRoute::Agent {
+                agent_id: participants[2].agent_id,
+                message: "skip the replicas".to_string(),
+            }
+        );
+        assert_eq!(
+            route_message("@page1", &participants),
+            ChatRoute::Agent {
+                agent_id: participants[3].agent_id,
+                message: String::new(),
+            }
+        );
+        assert_eq!(
+            route_message("@worker9 hello", &participants),
+            ChatRoute::UnknownHandle("worker9".to_string())
+        );
+    }
+
+    #[test]
+    fn test_mention_completions() {
+        let participants = participants();
+        let handles = |input: &str| -> Vec<String> {
+            mention_completions(input, &participants)
+                .into_iter()
+                .map(|p| p.handle.clone())
+                .collect()
+        };
+        assert_eq!(handles("@wo"), vec!["worker1", "worker2"]);
+        assert_eq!(handles("@").len(), 4);
+        assert_eq!(handles("@P"), vec!["page1"]);
+        assert!(handles("@worker1 ").is_empty());
+        assert!(handles("worker").is_empty());
+    }
+}
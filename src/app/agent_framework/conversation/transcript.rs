@@ -0,0 +1,509 @@
+//! Conversation Transcripts
+//!
+//! A [`ConversationTranscript`] is a JSON record of one agent conversation:
+//! the messages, every tool call with its input and result, and the token
+//! usage reported by the model. Transcripts are exported from the Agent
+//! Manager and can be reopened there in a read-only replay view; replaying a
+//! transcript never executes tools.
+//!
+//! Tool calls and token usage do not appear in [`ConversationMessage`]s, so
+//! each agent owns a [`TranscriptRecorder`] that a
+//! [`TranscriptCallbackHandler`] fills in from stood callback events.
+//!
+//! ```text
+//! ~/.local/share/awsdash/transcripts/
+//!   20260114-093012-Agent-1.json
+//! ```
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use stood::agent::callbacks::{CallbackError, CallbackEvent, CallbackHandler};
+
+use super::messages::ConversationMessage;
+
+/// Transcript format version, bumped on incompatible changes
+pub const TRANSCRIPT_VERSION: u32 = 1;
+
+/// File extension for transcript files
+const TRANSCRIPT_FILE_EXTENSION: &str = "json";
+
+/// One tool execution recorded during a conversation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptToolCall {
+    /// Index of the message that was being answered when the tool ran
+    pub message_index: usize,
+    pub tool_name: String,
+    pub input: Value,
+    /// Tool output when it succeeded
+    pub output: Option<Value>,
+    /// Error message when it failed
+    pub error: Option<String>,
+    pub started_at: DateTime<Utc>,
+    /// None when the tool never completed (e.g. the agent was stopped)
+    pub duration_ms: Option<u64>,
+}
+
+impl TranscriptToolCall {
+    /// Whether the tool finished, successfully or not
+    pub fn is_complete(&self) -> bool {
+        self.duration_ms.is_some()
+    }
+
+    /// Whether the tool finished without an error
+    pub fn succeeded(&self) -> bool {
+        self.is_complete() && self.error.is_none()
+    }
+}
+
+/// Token usage summed over all model calls in a conversation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptTokenUsage {
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub total_tokens: u64,
+    /// Number of model responses that reported usage
+    pub model_calls: u64,
+    /// Input tokens of the latest model call, i.e. the current context size
+    #[serde(default)]
+    pub last_input_tokens: u64,
+}
+
+impl TranscriptTokenUsage {
+    /// Add the usage reported by one model response
+    pub fn add(&mut self, input_tokens: u64, output_tokens: u64, total_tokens: u64) {
+        self.input_tokens += input_tokens;
+        self.output_tokens += output_tokens;
+        self.total_tokens += total_tokens;
+        self.model_calls += 1;
+        self.last_input_tokens = input_tokens;
+    }
+}
+
+/// A full agent conversation, as exported to JSON
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationTranscript {
+    pub version: u32,
+    pub agent_id: String,
+    pub agent_name: String,
+    /// Agent type as displayed (e.g. "Task Manager")
+    pub agent_type: String,
+    /// Model display name
+    pub model: String,
+    pub exported_at: DateTime<Utc>,
+    pub messages: Vec<ConversationMessage>,
+    pub tool_calls: Vec<TranscriptToolCall>,
+    pub token_usage: TranscriptTokenUsage,
+}
+
+impl ConversationTranscript {
+    /// Serialize as pretty-printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).context("Failed to serialize transcript")
+    }
+
+    /// Parse a transcript, rejecting versions newer than this build understands
+    pub fn from_json(json: &str) -> Result<Self> {
+        let transcript: Self = serde_json::from_str(json).context("Invalid transcript file")?;
+        if transcript.version > TRANSCRIPT_VERSION {
+            bail!(
+                "Transcript version {} is newer than supported version {}",
+                transcript.version,
+                TRANSCRIPT_VERSION
+            );
+        }
+        Ok(transcript)
+    }
+
+    /// Tool calls made while answering the message at `message_index`
+    pub fn tool_calls_for(
+        &self,
+        message_index: usize,
+    ) -> impl Iterator<Item = &TranscriptToolCall> {
+        self.tool_calls
+            .iter()
+            .filter(move |call| call.message_index == message_index)
+    }
+}
+
+/// Tool calls and token usage collected while an agent runs
+///
+/// Clones share the same record, so the agent keeps one and hands another to
+/// its [`TranscriptCallbackHandler`].
+#[derive(Debug, Clone, Default)]
+pub struct TranscriptRecorder {
+    inner: Arc<Mutex<RecordedActivity>>,
+}
+
+#[derive(Debug, Default)]
+struct RecordedActivity {
+    message_index: usize,
+    tool_calls: Vec<TranscriptToolCall>,
+    token_usage: TranscriptTokenUsage,
+}
+
+impl TranscriptRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attribute subsequent tool calls to the message at `index`
+    pub fn set_message_index(&self, index: usize) {
+        self.inner.lock().unwrap().message_index = index;
+    }
+
+    /// Record a tool starting
+    pub fn record_tool_start(&self, tool_name: &str, input: Value) {
+        let mut activity = self.inner.lock().unwrap();
+        let message_index = activity.message_index;
+        activity.tool_calls.push(TranscriptToolCall {
+            message_index,
+            tool_name: tool_name.to_string(),
+            input,
+            output: None,
+            error: None,
+            started_at: Utc::now(),
+            duration_ms: None,
+        });
+    }
+
+    /// Record the result of the oldest unfinished call to `tool_name`
+    pub fn record_tool_complete(
+        &self,
+        tool_name: &str,
+        output: Option<Value>,
+        error: Option<String>,
+        duration: std::time::Duration,
+    ) {
+        let mut activity = self.inner.lock().unwrap();
+        let Some(call) = activity
+            .tool_calls
+            .iter_mut()
+            .find(|call| call.tool_name == tool_name && !call.is_complete())
+        else {
+            tracing::debug!("Tool completion without a recorded start: {}", tool_name);
+            return;
+        };
+        call.output = output;
+        call.error = error;
+        call.duration_ms = Some(duration.as_millis() as u64);
+    }
+
+    /// Record the token usage of one model response
+    pub fn record_tokens(&self, input_tokens: u64, output_tokens: u64, total_tokens: u64) {
+        self.inner
+            .lock()
+            .unwrap()
+            .token_usage
+            .add(input_tokens, output_tokens, total_tokens);
+    }
+
+    pub fn tool_calls(&self) -> Vec<TranscriptToolCall> {
+        self.inner.lock().unwrap().tool_calls.clone()
+    }
+
+    pub fn token_usage(&self) -> TranscriptTokenUsage {
+        self.inner.lock().unwrap().token_usage
+    }
+
+    /// Forget everything recorded so far
+    pub fn clear(&self) {
+        *self.inner.lock().unwrap() = RecordedActivity::default();
+    }
+}
+
+/// Stood callback handler that feeds a [`TranscriptRecorder`]
+///
+/// An agent takes a single callback handler, so this one can wrap another
+/// (such as the worker progress handler) and pass every event on to it.
+pub struct TranscriptCallbackHandler {
+    recorder: TranscriptRecorder,
+    inner: Option<Box<dyn CallbackHandler + Send + Sync>>,
+}
+
+impl TranscriptCallbackHandler {
+    /// Record into `recorder` only
+    pub fn new(recorder: TranscriptRecorder) -> Self {
+        Self {
+            recorder,
+            inner: None,
+        }
+    }
+
+    /// Record into `recorder`, then forward each event to `inner`
+    pub fn wrapping(
+        recorder: TranscriptRecorder,
+        inner: impl CallbackHandler + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            recorder,
+            inner: Some(Box::new(inner)),
+        }
+    }
+}
+
+#[async_trait]
+impl CallbackHandler for TranscriptCallbackHandler {
+    async fn handle_event(&self, event: CallbackEvent) -> Result<(), CallbackError> {
+        match &event {
+            CallbackEvent::ToolStart {
+                tool_name, input, ..
+            } => self.recorder.record_tool_start(tool_name, input.clone()),
+            CallbackEvent::ToolComplete {
+                tool_name,
+                output,
+                error,
+                duration,
+                ..
+            } => self.recorder.record_tool_complete(
+                tool_name,
+                output.clone(),
+                error.clone(),
+                *duration,
+            ),
+            CallbackEvent::ModelComplete {
+                tokens: Some(usage),
+                ..
+            } => self.recorder.record_tokens(
+                usage.input_tokens as u64,
+                usage.output_tokens as u64,
+                usage.total_tokens as u64,
+            ),
+            _ => {}
+        }
+
+        match &self.inner {
+            Some(inner) => inner.handle_event(event).await,
+            None => Ok(()),
+        }
+    }
+}
+
+/// A saved transcript, as listed by [`TranscriptStore::list`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptInfo {
+    pub path: PathBuf,
+    pub agent_name: String,
+    pub exported_at: DateTime<Utc>,
+    pub message_count: usize,
+    pub tool_call_count: usize,
+}
+
+/// On-disk store of exported transcripts
+#[derive(Debug, Clone)]
+pub struct TranscriptStore {
+    directory: PathBuf,
+}
+
+impl TranscriptStore {
+    /// Create a store rooted at `directory`
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Store in the application data directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(
+            dirs::data_local_dir()?.join("awsdash/transcripts"),
+        ))
+    }
+
+    /// Directory holding transcript files
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Write `transcript` to a new file and return its path
+    pub fn save(&self, transcript: &ConversationTranscript) -> Result<PathBuf> {
+        fs::create_dir_all(&self.directory).with_context(|| {
+            format!(
+                "Failed to create transcript directory {}",
+                self.directory.display()
+            )
+        })?;
+
+        let name: String = transcript
+            .agent_name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        let path = self.directory.join(format!(
+            "{}-{}.{}",
+            transcript.exported_at.format("%Y%m%d-%H%M%S"),
+            name,
+            TRANSCRIPT_FILE_EXTENSION
+        ));
+        fs::write(&path, transcript.to_json()?)
+            .with_context(|| format!("Failed to write transcript {}", path.display()))?;
+        Ok(path)
+    }
+
+    /// Read a transcript file
+    pub fn load(&self, path: &Path) -> Result<ConversationTranscript> {
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read transcript {}", path.display()))?;
+        ConversationTranscript::from_json(&json)
+    }
+
+    /// All transcripts, newest first; unreadable files are skipped
+    pub fn list(&self) -> Vec<TranscriptInfo> {
+        let Ok(entries) = fs::read_dir(&self.directory) else {
+            return Vec::new(); // Nothing exported yet
+        };
+
+        let mut transcripts: Vec<TranscriptInfo> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension().and_then(|e| e.to_str()) == Some(TRANSCRIPT_FILE_EXTENSION)
+            })
+            .filter_map(|path| match self.load(&path) {
+                Ok(transcript) => Some(TranscriptInfo {
+                    agent_name: transcript.agent_name,
+                    exported_at: transcript.exported_at,
+                    message_count: transcript.messages.len(),
+                    tool_call_count: transcript.tool_calls.len(),
+                    path,
+                }),
+                Err(e) => {
+                    tracing::warn!("Skipping unreadable transcript {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .collect();
+        transcripts.sort_by(|a, b| b.exported_at.cmp(&a.exported_at));
+        transcripts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn test_transcript(recorder: &TranscriptRecorder) -> ConversationTranscript {
+        ConversationTranscript {
+            version: TRANSCRIPT_VERSION,
+            agent_id: "a6e248e3".to_string(),
+            agent_name: "Agent 1".to_string(),
+            agent_type: "Task Manager".to_string(),
+            model: "Claude Sonnet 4.5".to_string(),
+            exported_at: Utc::now(),
+            messages: vec![
+                ConversationMessage::user("List my buckets"),
+                ConversationMessage::assistant("You have 2 buckets."),
+            ],
+            tool_calls: recorder.tool_calls(),
+            token_usage: recorder.token_usage(),
+        }
+    }
+
+    #[test]
+    fn test_recorder_pairs_tool_results() {
+        let recorder = TranscriptRecorder::new();
+        recorder.record_tool_start("execute_javascript", serde_json::json!({ "code": "1" }));
+        recorder.record_tool_start("execute_javascript", serde_json::json!({ "code": "2" }));
+        recorder.set_message_index(2);
+        recorder.record_tool_start("read_file", serde_json::json!({ "path": "a.txt" }));
+
+        recorder.record_tool_complete(
+            "execute_javascript",
+            Some(serde_json::json!(1)),
+            None,
+            Duration::from_millis(15),
+        );
+        recorder.record_tool_complete(
+            "read_file",
+            None,
+            Some("File not found".to_string()),
+            Duration::from_millis(2),
+        );
+        recorder.record_tool_complete("unknown_tool", None, None, Duration::ZERO);
+        recorder.record_tokens(100, 20, 120);
+        recorder.record_tokens(50, 10, 60);
+
+        let calls = recorder.tool_calls();
+        assert_eq!(calls.len(), 3);
+        assert!(calls[0].succeeded());
+        assert_eq!(calls[0].output, Some(serde_json::json!(1)));
+        assert_eq!(calls[0].duration_ms, Some(15));
+        assert!(!calls[1].is_complete());
+        assert_eq!(calls[2].message_index, 2);
+        assert!(calls[2].is_complete() && !calls[2].succeeded());
+        assert_eq!(
+            recorder.token_usage(),
+            TranscriptTokenUsage {
+                input_tokens: 150,
+                output_tokens: 30,
+                total_tokens: 180,
+                model_calls: 2,
+                last_input_tokens: 50,
+            }
+        );
+
+        recorder.clear();
+        assert!(recorder.tool_calls().is_empty());
+        assert_eq!(recorder.token_usage(), TranscriptTokenUsage::default());
+    }
+
+    #[test]
+    fn test_transcript_json_round_trip() {
+        let recorder = TranscriptRecorder::new();
+        recorder.record_tool_start("list_buckets", serde_json::json!({}));
+        recorder.record_tool_complete(
+            "list_buckets",
+            Some(serde_json::json!(["logs", "backups"])),
+            None,
+            Duration::from_millis(40),
+        );
+        let transcript = test_transcript(&recorder);
+
+        let json = transcript.to_json().unwrap();
+        assert!(json.contains("\"toolCalls\""));
+        assert!(json.contains("\"tokenUsage\""));
+        assert_eq!(
+            ConversationTranscript::from_json(&json).unwrap(),
+            transcript
+        );
+        assert_eq!(transcript.tool_calls_for(0).count(), 1);
+        assert_eq!(transcript.tool_calls_for(1).count(), 0);
+
+        let mut newer = transcript.clone();
+        newer.version = TRANSCRIPT_VERSION + 1;
+        assert!(ConversationTranscript::from_json(&newer.to_json().unwrap()).is_err());
+        assert!(ConversationTranscript::from_json("{}").is_err());
+    }
+
+    #[test]
+    fn test_store_save_list_load() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = TranscriptStore::new(temp_dir.path().join("transcripts"));
+        assert!(store.list().is_empty());
+
+        let transcript = test_transcript(&TranscriptRecorder::new());
+        let path = store.save(&transcript).unwrap();
+        assert!(path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .ends_with("-Agent-1.json"));
+        fs::write(store.directory().join("broken.json"), "not json").unwrap();
+
+        let listed = store.list();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].path, path);
+        assert_eq!(listed[0].message_count, 2);
+        assert_eq!(store.load(&path).unwrap(), transcript);
+    }
+}
@@ -2,12 +2,234 @@
 //!
 //! Provides cancellation token infrastructure for stopping running agents created via create_task tool.
 //! Integrates with the Agent Manager UI Stop button to provide real agent cancellation, not just UI state reset.
+//!
+//! Each agent turn also gets a [`TurnCancellation`], published to the thread running
+//! the turn. Synchronous tool code uses it to abort in-flight AWS SDK futures
+//! ([`block_on_cancellable`]) and to terminate running V8 scripts ([`watch_cancellation`]),
+//! so a Stop does not wait for background work to finish. How long each abort took
+//! after the Stop is written to the agent log.
 
+use std::cell::RefCell;
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
-use tokio_util::sync::CancellationToken;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio_util::sync::{CancellationToken, DropGuard};
 use tracing::{debug, info, warn};
 
+/// Error message of work aborted by cancellation
+pub const CANCELLED_BY_USER: &str = "Cancelled by user";
+
+/// How often a cancelled script is interrupted until it stops
+const INTERRUPT_RETRY: Duration = Duration::from_millis(10);
+
+thread_local! {
+    /// Cancellation of the agent turn running on this thread
+    static CURRENT_TURN_CANCELLATION: RefCell<Option<TurnCancellation>> = const { RefCell::new(None) };
+}
+
+/// Set the cancellation of the agent turn running on this thread
+pub fn set_current_turn_cancellation(cancellation: Option<TurnCancellation>) {
+    CURRENT_TURN_CANCELLATION.with(|cell| *cell.borrow_mut() = cancellation);
+}
+
+/// Cancellation of the agent turn running on this thread, if any
+pub fn current_turn_cancellation() -> Option<TurnCancellation> {
+    CURRENT_TURN_CANCELLATION.with(|cell| cell.borrow().clone())
+}
+
+/// Cancellation of one agent turn
+///
+/// Cancelled when any of the tokens it was linked to is cancelled (the agent's
+/// own token and, for workers, the parent's), and remembers when that happened
+/// so aborted work can report its latency.
+#[derive(Debug, Clone)]
+pub struct TurnCancellation {
+    token: CancellationToken,
+    requested_at: Arc<OnceLock<Instant>>,
+}
+
+impl TurnCancellation {
+    /// Create a turn cancellation that is not linked to any token
+    pub fn new() -> Self {
+        Self {
+            token: CancellationToken::new(),
+            requested_at: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// Create a turn cancellation that follows `sources`
+    ///
+    /// Must be called inside a tokio runtime. Dropping the returned guard ends
+    /// the turn: the token is cancelled so the forwarding tasks exit.
+    pub fn link(sources: impl IntoIterator<Item = CancellationToken>) -> (Self, DropGuard) {
+        let turn = Self::new();
+        for source in sources {
+            if source.is_cancelled() {
+                turn.cancel();
+                continue;
+            }
+            let turn = turn.clone();
+            tokio::spawn(async move {
+                tokio::select! {
+                    _ = source.cancelled() => turn.cancel(),
+                    _ = turn.token.cancelled() => {}
+                }
+            });
+        }
+        let guard = turn.token.clone().drop_guard();
+        (turn, guard)
+    }
+
+    /// Cancel the turn, recording when cancellation was requested
+    pub fn cancel(&self) {
+        self.requested_at.get_or_init(Instant::now);
+        self.token.cancel();
+    }
+
+    /// Whether the turn was cancelled or has ended
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Time since cancellation was requested
+    pub fn latency(&self) -> Option<Duration> {
+        self.requested_at.get().map(|at| at.elapsed())
+    }
+
+    /// Wait until cancellation is requested
+    pub async fn cancelled(&self) {
+        self.token.cancelled().await;
+    }
+}
+
+impl Default for TurnCancellation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Run `future` until it completes or `cancellation` is requested
+///
+/// On cancellation the future is dropped, which aborts any AWS SDK request it
+/// was waiting on, and a "Cancelled by user" error is returned.
+pub async fn run_cancellable<T>(
+    cancellation: Option<TurnCancellation>,
+    future: impl Future<Output = anyhow::Result<T>>,
+) -> anyhow::Result<T> {
+    let Some(cancellation) = cancellation else {
+        return future.await;
+    };
+    if cancellation.is_cancelled() {
+        return Err(anyhow::anyhow!(CANCELLED_BY_USER));
+    }
+    tokio::select! {
+        biased;
+        _ = cancellation.cancelled() => {}
+        result = future => return result,
+    }
+    let latency = cancellation.latency().unwrap_or_default();
+    log_to_agent(&format!(
+        "Aborted in-flight request {} ms after cancellation",
+        latency.as_millis()
+    ));
+    Err(anyhow::anyhow!(CANCELLED_BY_USER))
+}
+
+/// Run an async call from synchronous tool code, aborting it when the
+/// current agent turn is cancelled
+pub fn block_on_cancellable<T>(
+    future: impl Future<Output = anyhow::Result<T>>,
+) -> anyhow::Result<T> {
+    let cancellation = current_turn_cancellation();
+    // Use block_in_place to avoid nested runtime error
+    tokio::task::block_in_place(|| {
+        tokio::runtime::Handle::current().block_on(run_cancellable(cancellation, future))
+    })
+}
+
+/// Watches the current agent turn while a blocking execution runs
+///
+/// Created by [`watch_cancellation`]; dropping it stops the watch.
+pub struct CancellationWatch {
+    cancellation: TurnCancellation,
+    done: CancellationToken,
+    interrupted: Arc<AtomicBool>,
+}
+
+impl CancellationWatch {
+    /// Whether the execution was interrupted because the turn was cancelled
+    pub fn interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::SeqCst)
+    }
+
+    /// Time from the cancellation request until now, if interrupted
+    pub fn latency(&self) -> Option<Duration> {
+        if self.interrupted() {
+            self.cancellation.latency()
+        } else {
+            None
+        }
+    }
+}
+
+impl Drop for CancellationWatch {
+    fn drop(&mut self) {
+        self.done.cancel();
+    }
+}
+
+/// Call `interrupt` when the current agent turn is cancelled
+///
+/// `interrupt` returns false when there is nothing to interrupt yet (the
+/// execution has not started), in which case it is retried until it succeeds
+/// or the watch is dropped. Returns None outside an agent turn or a tokio
+/// runtime.
+pub fn watch_cancellation(
+    interrupt: impl Fn() -> bool + Send + 'static,
+) -> Option<CancellationWatch> {
+    let cancellation = current_turn_cancellation()?;
+    let handle = tokio::runtime::Handle::try_current().ok()?;
+    let done = CancellationToken::new();
+    let interrupted = Arc::new(AtomicBool::new(false));
+
+    let watch = CancellationWatch {
+        cancellation: cancellation.clone(),
+        done: done.clone(),
+        interrupted: Arc::clone(&interrupted),
+    };
+    handle.spawn(async move {
+        tokio::select! {
+            _ = cancellation.cancelled() => {}
+            _ = done.cancelled() => return,
+        }
+        // A turn that ends without a Stop also cancels its token
+        if cancellation.latency().is_none() {
+            return;
+        }
+        while !done.is_cancelled() {
+            if interrupt() {
+                interrupted.store(true, Ordering::SeqCst);
+                return;
+            }
+            tokio::time::sleep(INTERRUPT_RETRY).await;
+        }
+    });
+    Some(watch)
+}
+
+/// Write a message to the log of the agent running on this thread
+fn log_to_agent(message: &str) {
+    info!("🛑 {}", message);
+    if let (Some(logger), Some(agent_type)) = (
+        crate::app::agent_framework::agent_logger::get_current_agent_logger(),
+        crate::app::agent_framework::get_current_agent_type(),
+    ) {
+        logger.log_system_message(&agent_type, message);
+    }
+}
+
 /// Manages cancellation tokens for active agents
 #[derive(Debug, Clone)]
 pub struct AgentCancellationManager {
@@ -226,4 +448,103 @@ mod tests {
         assert!(token.is_cancelled());
         assert_eq!(manager.active_count(), 0);
     }
+
+    #[tokio::test]
+    async fn test_run_cancellable_aborts_pending_future() {
+        let turn = TurnCancellation::new();
+        let cancel = turn.clone();
+        tokio::spawn(async move {
+            sleep(Duration::from_millis(20)).await;
+            cancel.cancel();
+        });
+
+        let result: anyhow::Result<()> = run_cancellable(Some(turn.clone()), async {
+            sleep(Duration::from_secs(30)).await;
+            Ok(())
+        })
+        .await;
+
+        assert_eq!(result.unwrap_err().to_string(), CANCELLED_BY_USER);
+        assert!(turn.latency().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_run_cancellable_completes_without_cancel() {
+        let result = run_cancellable(Some(TurnCancellation::new()), async { Ok(42) }).await;
+        assert_eq!(result.unwrap(), 42);
+
+        let result = run_cancellable(None, async { Ok("no turn") }).await;
+        assert_eq!(result.unwrap(), "no turn");
+    }
+
+    #[tokio::test]
+    async fn test_run_cancellable_skips_work_after_cancel() {
+        let turn = TurnCancellation::new();
+        turn.cancel();
+
+        let started = Arc::new(AtomicBool::new(false));
+        let flag = Arc::clone(&started);
+        let result: anyhow::Result<()> = run_cancellable(Some(turn), async move {
+            flag.store(true, Ordering::SeqCst);
+            Ok(())
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert!(!started.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_linked_turn_follows_source_token() {
+        let parent = CancellationToken::new();
+        let (turn, guard) = TurnCancellation::link([parent.clone()]);
+        assert!(!turn.is_cancelled());
+
+        parent.cancel();
+        tokio::time::timeout(Duration::from_secs(1), turn.cancelled())
+            .await
+            .expect("turn should follow its source");
+        assert!(turn.latency().is_some());
+
+        // Ending the turn without a Stop cancels the token but records no request
+        let (ended, guard2) = TurnCancellation::link([CancellationToken::new()]);
+        drop(guard2);
+        assert!(ended.is_cancelled());
+        assert!(ended.latency().is_none());
+        drop(guard);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_watch_cancellation_interrupts_until_started() {
+        let turn = TurnCancellation::new();
+        set_current_turn_cancellation(Some(turn.clone()));
+
+        // Nothing to interrupt on the first attempts, as when a script has not started
+        let attempts = Arc::new(Mutex::new(0));
+        let counter = Arc::clone(&attempts);
+        let watch = watch_cancellation(move || {
+            let mut attempts = counter.lock().unwrap();
+            *attempts += 1;
+            *attempts >= 3
+        })
+        .expect("watch inside an agent turn");
+        set_current_turn_cancellation(None);
+
+        turn.cancel();
+        for _ in 0..100 {
+            if watch.interrupted() {
+                break;
+            }
+            sleep(Duration::from_millis(10)).await;
+        }
+        assert!(watch.interrupted());
+        assert_eq!(*attempts.lock().unwrap(), 3);
+        assert!(watch.latency().is_some());
+    }
+
+    #[test]
+    fn test_watch_cancellation_requires_turn() {
+        set_current_turn_cancellation(None);
+        assert!(watch_cancellation(|| true).is_none());
+    }
 }
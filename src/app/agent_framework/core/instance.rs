@@ -15,16 +15,27 @@ use crate::perf_guard;
 use crate::perf_timed;
 
 use crate::app::agent_framework::agent_logger::AgentLogger;
-use crate::app::agent_framework::vfs::{register_vfs, deregister_vfs, VirtualFileSystem};
+use crate::app::agent_framework::vfs::{
+    register_vfs, deregister_vfs, with_vfs, with_vfs_mut, VfsSnapshotInfo, VfsSnapshotStore,
+    VirtualFileSystem,
+};
 use super::types::{
     AgentId, AgentMetadata, AgentStatus, AgentType, StoodLogLevel,
 };
-use crate::app::agent_framework::conversation::{ConversationMessage, ConversationResponse};
+use crate::app::agent_framework::conversation::{
+    ConversationMessage, ConversationResponse, ConversationRole, ConversationTranscript,
+    TranscriptCallbackHandler, TranscriptRecorder, TRANSCRIPT_VERSION,
+};
+use crate::app::agent_framework::conversation::memory::{
+    parse_memory_facts, project_key, MemoryStore, ProjectMemory, MEMORY_EXTRACTION_PROMPT,
+};
 use crate::app::agent_framework::conversation::injection::{
     InjectionContext, InjectionTrigger, InjectionType, MessageInjector,
 };
+use crate::app::agent_framework::cost_tracking::{ConversationCost, CostTracker};
+use crate::app::agent_framework::middleware::layers::ToolTimeline;
 use crate::app::agent_framework::middleware::{
-    ConversationLayer, LayerContext, LayerError, LayerStack,
+    ConversationLayer, LayerContext, LayerError, LayerHookCallbackHandler, LayerStack,
 };
 use crate::app::agent_framework::status_display::ProcessingPhase;
 use crate::app::agent_framework::tools::TodoItem;
@@ -102,8 +113,35 @@ pub struct AgentInstance {
     /// Parent's cancellation token (for workers - cancelled when parent is cancelled)
     parent_cancel_token: Option<CancellationToken>,
 
+    /// When Stop was pressed, to log how long the agent took to stop
+    cancel_requested_at: Option<std::time::Instant>,
+
     /// VFS ID for this agent session (TaskManager owns VFS, workers inherit ID)
     vfs_id: Option<String>,
+
+    /// Tool calls and token usage for transcript export
+    transcript: TranscriptRecorder,
+
+    /// Tool invocations for the timeline panel (fed by ToolTimelineLayer)
+    tool_timeline: ToolTimeline,
+
+    /// Token usage per model for cost estimates
+    cost_tracker: CostTracker,
+
+    /// Response text streamed so far for the turn in progress (TaskManager only)
+    streaming_text: String,
+
+    /// Project whose memory is injected and updated (TaskManager only, set on initialize)
+    memory_project: Option<String>,
+
+    /// Project whose guardrail policy applies (all agent types, set on initialize)
+    project: Option<String>,
+
+    /// Whether tool inputs are checked for secrets (set by `with_guardrail_layer`)
+    tool_guardrail: bool,
+
+    /// Whether mutating tool calls wait for approval (set by `with_approval_gate_layer`)
+    tool_approval: bool,
 }
 
 impl AgentInstance {
@@ -149,6 +187,13 @@ impl AgentInstance {
             None
         };
 
+        let cost_tracker = CostTracker::new(ConversationCost::new(
+            id.to_string(),
+            metadata.name.clone(),
+            agent_type.to_string(),
+            None,
+        ));
+
         perf_checkpoint!("AgentInstance::new.building_struct");
 
         Self {
@@ -173,7 +218,16 @@ impl AgentInstance {
             layer_stack: LayerStack::new(),
             cancel_token: None,
             parent_cancel_token: None,
+            cancel_requested_at: None,
             vfs_id,
+            transcript: TranscriptRecorder::new(),
+            tool_timeline: ToolTimeline::new(),
+            cost_tracker,
+            streaming_text: String::new(),
+            memory_project: None,
+            project: None,
+            tool_guardrail: false,
+            tool_approval: false,
         }
     }
 
@@ -227,6 +281,14 @@ impl AgentInstance {
         let runtime =
             Arc::new(tokio::runtime::Runtime::new().expect("Failed to create tokio runtime"));
 
+        // Workers record their parent so their cost rolls up to the manager
+        let cost_tracker = CostTracker::new(ConversationCost::new(
+            id.to_string(),
+            metadata.name.clone(),
+            agent_type.to_string(),
+            agent_type.parent_id().map(|id| id.to_string()),
+        ));
+
         Self {
             id,
             metadata,
@@ -249,7 +311,16 @@ impl AgentInstance {
             layer_stack: LayerStack::new(),
             cancel_token: None,
             parent_cancel_token: None,
+            cancel_requested_at: None,
             vfs_id: None, // Workers inherit VFS ID from parent at execution time
+            transcript: TranscriptRecorder::new(),
+            tool_timeline: ToolTimeline::new(),
+            cost_tracker,
+            streaming_text: String::new(),
+            memory_project: None,
+            project: None,
+            tool_guardrail: false,
+            tool_approval: false,
         }
     }
 
@@ -295,6 +366,30 @@ impl AgentInstance {
         self.status = status;
         self.processing = false; // Stop processing on status change
         self.processing_phase = ProcessingPhase::Idle; // Stop animation
+        self.publish_status();
+    }
+
+    /// Push the current status to Dash Pages subscribed to `agent.status`
+    fn publish_status(&self) {
+        let (state, error) = match &self.status {
+            AgentStatus::Running if self.processing => ("processing", None),
+            AgentStatus::Running => ("idle", None),
+            AgentStatus::Paused => ("paused", None),
+            AgentStatus::Completed => ("completed", None),
+            AgentStatus::Failed(error) => ("failed", Some(error.as_str())),
+            AgentStatus::Cancelled => ("cancelled", None),
+        };
+
+        crate::app::webview::push::publish(
+            crate::app::webview::push::AGENT_STATUS_TOPIC,
+            serde_json::json!({
+                "agentId": self.id.to_string(),
+                "name": self.metadata.name,
+                "state": state,
+                "message": self.status_message,
+                "error": error,
+            }),
+        );
     }
 
     /// Add a system message to the conversation
@@ -314,11 +409,58 @@ impl AgentInstance {
         &self.messages
     }
 
+    /// Build a transcript of the conversation so far, including tool calls
+    /// and token usage
+    pub fn export_transcript(&self) -> ConversationTranscript {
+        ConversationTranscript {
+            version: TRANSCRIPT_VERSION,
+            agent_id: self.id.to_string(),
+            agent_name: self.metadata.name.clone(),
+            agent_type: self.agent_type.to_string(),
+            model: self.metadata.model.display_name().to_string(),
+            exported_at: chrono::Utc::now(),
+            messages: self.messages.iter().cloned().collect(),
+            tool_calls: self.transcript.tool_calls(),
+            token_usage: self.transcript.token_usage(),
+        }
+    }
+
+    /// Token usage per model for the current conversation
+    pub fn cost(&self) -> &ConversationCost {
+        self.cost_tracker.conversation()
+    }
+
+    /// Count token usage reported since the last update against the
+    /// current model
+    fn update_cost(&mut self) {
+        let usage = self.transcript.token_usage();
+        self.cost_tracker.update(
+            &self.metadata.name,
+            self.metadata.model,
+            usage.input_tokens,
+            usage.output_tokens,
+        );
+    }
+
     /// Check if the agent is currently processing a message
     pub fn is_processing(&self) -> bool {
         self.processing
     }
 
+    /// Response text generated so far, while a streamed response is in progress
+    pub fn streaming_text(&self) -> Option<&str> {
+        Some(self.streaming_text.as_str()).filter(|text| self.processing && !text.is_empty())
+    }
+
+    /// Append a streamed chunk of the response in progress
+    ///
+    /// Chunks that arrive after the turn finished or was cancelled are dropped.
+    pub fn append_streaming_text(&mut self, delta: &str) {
+        if self.processing {
+            self.streaming_text.push_str(delta);
+        }
+    }
+
     /// Get the current status message (for future callback support)
     pub fn status_message(&self) -> Option<&str> {
         self.status_message.as_deref()
@@ -353,6 +495,53 @@ impl AgentInstance {
         self.vfs_id = vfs_id;
     }
 
+    /// Save this agent's VFS to disk, keyed by the agent ID as session ID
+    ///
+    /// Once saved, the snapshot is refreshed again when the agent terminates.
+    pub fn save_vfs_snapshot(&self) -> anyhow::Result<VfsSnapshotInfo> {
+        let vfs_id = self
+            .vfs_id
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("Agent has no VFS"))?;
+        let store = VfsSnapshotStore::default_location()
+            .ok_or_else(|| anyhow::anyhow!("Local data directory is unavailable"))?;
+        let session_id = self.id.to_string();
+
+        let info = with_vfs(vfs_id, |vfs| {
+            store.save(&session_id, &self.metadata.name, vfs)
+        })
+        .ok_or_else(|| anyhow::anyhow!("VFS not found: {}", vfs_id))??;
+        self.logger.log_system_message(
+            &self.agent_type,
+            &format!("Saved VFS snapshot ({} files)", info.file_count),
+        );
+        Ok(info)
+    }
+
+    /// Replace this agent's VFS contents with a snapshot from an earlier session
+    ///
+    /// Only TaskManager agents own their VFS; workers share their parent's.
+    pub fn restore_vfs_snapshot(&mut self, session_id: &str) -> anyhow::Result<()> {
+        if !matches!(self.agent_type, AgentType::TaskManager) {
+            anyhow::bail!("Only TaskManager agents can restore a VFS snapshot");
+        }
+        let vfs_id = self
+            .vfs_id
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("Agent has no VFS"))?;
+        let store = VfsSnapshotStore::default_location()
+            .ok_or_else(|| anyhow::anyhow!("Local data directory is unavailable"))?;
+        let restored = store.load(session_id)?;
+
+        with_vfs_mut(&vfs_id, |vfs| *vfs = restored)
+            .ok_or_else(|| anyhow::anyhow!("VFS not found: {}", vfs_id))?;
+        self.logger.log_system_message(
+            &self.agent_type,
+            &format!("Restored VFS snapshot from session {}", session_id),
+        );
+        Ok(())
+    }
+
     // ========== Middleware API ==========
 
     /// Get reference to the layer stack
@@ -374,15 +563,32 @@ impl AgentInstance {
         self.layer_stack.add(layer);
     }
 
+    /// Tool invocations recorded by the timeline layer
+    ///
+    /// Empty unless the agent was configured with `with_tool_timeline_layer`.
+    pub fn tool_timeline(&self) -> &ToolTimeline {
+        &self.tool_timeline
+    }
+
     /// Create a LayerContext from the current agent state
     fn create_layer_context(&self) -> LayerContext {
-        LayerContext::builder()
+        let usage = self.transcript.token_usage();
+        let builder = LayerContext::builder()
             .agent_id(self.id.to_string())
             .agent_type(self.agent_type.clone())
             .message_count(self.messages.len())
             .turn_count(self.messages.len() / 2)
             .token_count(self.estimate_token_count())
-            .build()
+            .token_usage(
+                usage.input_tokens,
+                usage.output_tokens,
+                usage.last_input_tokens,
+            )
+            .context_window(self.metadata.model.context_window());
+        match &self.project {
+            Some(project) => builder.project(project.clone()).build(),
+            None => builder.build(),
+        }
     }
 
     /// Estimate total token count from conversation messages
@@ -403,17 +609,69 @@ impl AgentInstance {
         self
     }
 
+    /// Configure the agent with a token budget middleware layer
+    ///
+    /// This adds the TokenBudgetLayer, which warns as the model's context
+    /// window fills up and requests a summary before it runs out.
+    /// Returns self for method chaining.
+    pub fn with_token_budget_layer(mut self) -> Self {
+        use crate::app::agent_framework::middleware::layers::TokenBudgetLayer;
+        self.layer_stack.add(TokenBudgetLayer::with_defaults());
+        self
+    }
+
+    /// Configure the agent with an approval gate middleware layer
+    ///
+    /// This adds the ApprovalGateLayer, and holds mutating and destructive
+    /// tool calls for the user's approval once the agent is built. Workers
+    /// use their manager's threshold.
+    /// Returns self for method chaining.
+    pub fn with_approval_gate_layer(mut self) -> Self {
+        use crate::app::agent_framework::middleware::layers::ApprovalGateLayer;
+        self.layer_stack.add(ApprovalGateLayer::with_defaults());
+        self.tool_approval = true;
+        self
+    }
+
+    /// Configure the agent with a guardrail middleware layer
+    ///
+    /// This adds the GuardrailLayer, which masks or blocks secrets in outgoing
+    /// messages, and checks tool inputs for secrets once the agent is built.
+    /// Returns self for method chaining.
+    pub fn with_guardrail_layer(mut self) -> Self {
+        use crate::app::agent_framework::middleware::layers::GuardrailLayer;
+        self.layer_stack.add(GuardrailLayer::with_defaults());
+        self.tool_guardrail = true;
+        self
+    }
+
+    /// Configure the agent with a tool timeline middleware layer
+    ///
+    /// This adds the ToolTimelineLayer, which records each tool invocation
+    /// (duration, outcome, output size, VFS paths written) for the timeline panel.
+    /// Returns self for method chaining.
+    pub fn with_tool_timeline_layer(mut self) -> Self {
+        use crate::app::agent_framework::middleware::layers::ToolTimelineLayer;
+        self.layer_stack
+            .add(ToolTimelineLayer::new(self.tool_timeline.clone()));
+        self
+    }
+
     /// Configure the agent with recommended middleware layers
     ///
     /// This adds:
     /// - LoggingLayer for debugging
     /// - TokenTrackingLayer for monitoring token usage (default 100k token threshold)
+    /// - TokenBudgetLayer for context window warnings and summarization
     ///
     /// Returns self for method chaining.
     pub fn with_recommended_layers(mut self) -> Self {
-        use crate::app::agent_framework::middleware::layers::{LoggingLayer, TokenTrackingLayer};
+        use crate::app::agent_framework::middleware::layers::{
+            LoggingLayer, TokenBudgetLayer, TokenTrackingLayer,
+        };
         self.layer_stack.add(LoggingLayer::with_defaults());
         self.layer_stack.add(TokenTrackingLayer::with_defaults());
+        self.layer_stack.add(TokenBudgetLayer::with_defaults());
         self
     }
 
@@ -425,12 +683,28 @@ impl AgentInstance {
     ///
     /// This signals the stood agent's event loop to stop at the next
     /// cancellation check point (between cycles). The execution will
-    /// return with a "cancelled" status.
+    /// return with a "cancelled" status. A TaskManager's streamed response
+    /// is aborted at once, keeping the text generated so far.
+    ///
+    /// For a TaskManager, tasks still queued in its worker pool fail at
+    /// once; running workers stop through their child tokens.
     ///
     /// Returns true if cancellation was requested, false if no token available.
     pub fn cancel(&mut self) -> bool {
+        if self.agent_type.is_task_manager() {
+            crate::app::agent_framework::cancel_worker_pool(self.id);
+        }
+        // Keep what was streamed before the stop
+        if self.processing && !self.streaming_text.is_empty() {
+            let partial = std::mem::take(&mut self.streaming_text);
+            self.messages
+                .push_back(ConversationMessage::assistant(partial.trim_end()));
+        }
         if let Some(token) = &self.cancel_token {
             token.cancel();
+            if self.processing {
+                self.cancel_requested_at = Some(std::time::Instant::now());
+            }
             self.logger.log_system_message(
                 &self.agent_type,
                 "Cancellation requested - stopping agent execution",
@@ -445,6 +719,20 @@ impl AgentInstance {
         }
     }
 
+    /// Log how long the agent took to stop after cancel(), once its
+    /// background execution has reported back
+    fn log_cancellation_latency(&mut self) {
+        if let Some(requested_at) = self.cancel_requested_at.take() {
+            self.logger.log_system_message(
+                &self.agent_type,
+                &format!(
+                    "Cancellation completed in {} ms",
+                    requested_at.elapsed().as_millis()
+                ),
+            );
+        }
+    }
+
     /// Check if cancellation is available for this agent
     ///
     /// Returns true if the agent was initialized with cancellation support
@@ -553,8 +841,10 @@ impl AgentInstance {
     }
 
     /// Check if there are pending injections
+    ///
+    /// Includes an injection that already fired and waits for the next poll.
     pub fn has_pending_injections(&self) -> bool {
-        self.message_injector.has_pending()
+        self.has_pending_injection || self.message_injector.has_pending()
     }
 
     /// Process pending injections with the given context
@@ -602,6 +892,27 @@ impl AgentInstance {
         self.reset_stood_agent();
     }
 
+    /// Switch this agent to a different model
+    ///
+    /// Like a log level change, this resets the stood agent; call
+    /// `initialize()` afterwards. The new model does not see the earlier
+    /// conversation.
+    pub fn set_model(&mut self, model: crate::app::agent_framework::AgentModel) {
+        let old_model = self.metadata.model;
+        if old_model == model {
+            return; // No change needed
+        }
+
+        self.metadata.model = model;
+        self.metadata.updated_at = chrono::Utc::now();
+        self.logger.log_system_message(
+            &self.agent_type,
+            &format!("Model changed from {} to {}", old_model, model),
+        );
+
+        self.reset_stood_agent();
+    }
+
     /// Reset the stood agent for reinitialization
     ///
     /// This clears the stood agent instance and cancellation token.
@@ -643,6 +954,8 @@ impl AgentInstance {
     /// - TaskManager: think, start-task, start-page-builder, edit-page, open-page tools
     /// - TaskWorker: execute_javascript tool
     /// - PageBuilderWorker: file operations, execute_javascript, open-tool (pre-configured)
+    ///
+    /// With the `read-only` feature, page-building and file-writing tools are compiled out.
     fn get_tools_for_type(&self) -> Vec<Box<dyn stood::tools::Tool>> {
         match self.agent_type {
             AgentType::TaskManager => {
@@ -679,27 +992,38 @@ impl AgentInstance {
                 let start_task_tool =
                     Box::new(crate::app::agent_framework::tools::StartTaskTool::new());
 
-                // Start-page-builder tool for spawning page builder workers
-                let start_page_builder_tool =
-                    Box::new(crate::app::agent_framework::tools::StartPageBuilderTool::new());
-
-                // Edit-page tool for modifying existing pages
-                let edit_page_tool =
-                    Box::new(crate::app::agent_framework::tools::EditPageTool::new());
+                // Start-tasks tool for spawning workers in parallel
+                let start_tasks_tool =
+                    Box::new(crate::app::agent_framework::tools::StartTasksTool::new());
 
                 // Open-tool tool for opening completed tools by name
                 let open_tool_tool =
                     Box::new(crate::app::agent_framework::tools::OpenPageTool::new_dynamic());
 
-                vec![
+                #[allow(unused_mut)]
+                let mut tools = vec![
                     think_tool as Box<dyn stood::tools::Tool>,
                     // todo_write_tool as Box<dyn stood::tools::Tool>,
                     // todo_read_tool as Box<dyn stood::tools::Tool>,
                     start_task_tool as Box<dyn stood::tools::Tool>,
-                    start_page_builder_tool as Box<dyn stood::tools::Tool>,
-                    edit_page_tool as Box<dyn stood::tools::Tool>,
+                    start_tasks_tool as Box<dyn stood::tools::Tool>,
                     open_tool_tool as Box<dyn stood::tools::Tool>,
-                ]
+                ];
+
+                // Page building writes files - not available in the read-only build
+                #[cfg(not(feature = "read-only"))]
+                {
+                    // Start-page-builder tool for spawning page builder workers
+                    tools.push(Box::new(
+                        crate::app::agent_framework::tools::StartPageBuilderTool::new(),
+                    ) as Box<dyn stood::tools::Tool>);
+
+                    // Edit-page tool for modifying existing pages
+                    tools.push(Box::new(crate::app::agent_framework::tools::EditPageTool::new())
+                        as Box<dyn stood::tools::Tool>);
+                }
+
+                tools
             }
             AgentType::TaskWorker { .. } => {
                 vec![Box::new(
@@ -712,21 +1036,13 @@ impl AgentInstance {
                     crate::app::agent_framework::tools::ReadFileTool::new(workspace_name.as_str())
                         .expect("Failed to create ReadFileTool"),
                 );
-                let write_tool = Box::new(
-                    crate::app::agent_framework::tools::WriteFileTool::new(workspace_name.as_str())
-                        .expect("Failed to create WriteFileTool"),
-                );
-                let edit_tool = Box::new(
-                    crate::app::agent_framework::tools::EditFileTool::new(workspace_name.as_str())
-                        .expect("Failed to create EditFileTool"),
-                );
                 let list_tool = Box::new(
                     crate::app::agent_framework::tools::ListFilesTool::new(workspace_name.as_str())
                         .expect("Failed to create ListFilesTool"),
                 );
-                let delete_tool = Box::new(
-                    crate::app::agent_framework::tools::DeleteFileTool::new(workspace_name.as_str())
-                        .expect("Failed to create DeleteFileTool"),
+                let search_tool = Box::new(
+                    crate::app::agent_framework::tools::SearchFilesTool::new(workspace_name.as_str())
+                        .expect("Failed to create SearchFilesTool"),
                 );
                 let api_docs_tool = Box::new(
                     crate::app::agent_framework::tools::GetApiDocsTool::new(),
@@ -739,23 +1055,56 @@ impl AgentInstance {
                     crate::app::agent_framework::tools::OpenPageTool::new(workspace_name.as_str())
                         .expect("Failed to create OpenPageTool"),
                 );
-                // Copy file tool for efficient VFS-to-page copying without context pollution
-                let copy_file_tool = Box::new(
-                    crate::app::agent_framework::tools::CopyFileTool::new(workspace_name.as_str())
-                        .expect("Failed to create CopyFileTool"),
-                );
-
-                vec![
+                #[allow(unused_mut)]
+                let mut tools = vec![
                     read_tool as Box<dyn stood::tools::Tool>,
-                    write_tool as Box<dyn stood::tools::Tool>,
-                    edit_tool as Box<dyn stood::tools::Tool>,
                     list_tool as Box<dyn stood::tools::Tool>,
-                    delete_tool as Box<dyn stood::tools::Tool>,
-                    copy_file_tool as Box<dyn stood::tools::Tool>,
+                    search_tool as Box<dyn stood::tools::Tool>,
                     api_docs_tool as Box<dyn stood::tools::Tool>,
                     execute_js_tool as Box<dyn stood::tools::Tool>,
                     open_page_tool as Box<dyn stood::tools::Tool>,
-                ]
+                ];
+
+                // Write tools are compiled out of the read-only build
+                #[cfg(not(feature = "read-only"))]
+                {
+                    let write_tool = Box::new(
+                        crate::app::agent_framework::tools::WriteFileTool::new(workspace_name.as_str())
+                            .expect("Failed to create WriteFileTool"),
+                    );
+                    let edit_tool = Box::new(
+                        crate::app::agent_framework::tools::EditFileTool::new(workspace_name.as_str())
+                            .expect("Failed to create EditFileTool"),
+                    );
+                    let delete_tool = Box::new(
+                        crate::app::agent_framework::tools::DeleteFileTool::new(workspace_name.as_str())
+                            .expect("Failed to create DeleteFileTool"),
+                    );
+                    // Copy file tool for efficient VFS-to-page copying without context pollution
+                    let copy_file_tool = Box::new(
+                        crate::app::agent_framework::tools::CopyFileTool::new(workspace_name.as_str())
+                            .expect("Failed to create CopyFileTool"),
+                    );
+                    let move_tool = Box::new(
+                        crate::app::agent_framework::tools::MoveFileTool::new(workspace_name.as_str())
+                            .expect("Failed to create MoveFileTool"),
+                    );
+                    let copy_dir_tool = Box::new(
+                        crate::app::agent_framework::tools::CopyDirectoryTool::new(workspace_name.as_str())
+                            .expect("Failed to create CopyDirectoryTool"),
+                    );
+
+                    tools.extend([
+                        write_tool as Box<dyn stood::tools::Tool>,
+                        edit_tool as Box<dyn stood::tools::Tool>,
+                        delete_tool as Box<dyn stood::tools::Tool>,
+                        copy_file_tool as Box<dyn stood::tools::Tool>,
+                        move_tool as Box<dyn stood::tools::Tool>,
+                        copy_dir_tool as Box<dyn stood::tools::Tool>,
+                    ]);
+                }
+
+                tools
             }
         }
     }
@@ -764,11 +1113,12 @@ impl AgentInstance {
     fn get_system_prompt_for_type(&self) -> String {
         use chrono::Utc;
 
+        use crate::app::agent_framework::prompts::{load_prompt, PromptKind};
+
+        // Task manager/worker prompts may be overridden from the config directory
         let prompt = match &self.agent_type {
-            AgentType::TaskManager => crate::app::agent_framework::TASK_MANAGER_PROMPT.to_string(),
-            AgentType::TaskWorker { .. } => {
-                crate::app::agent_framework::TASK_WORKER_PROMPT.to_string()
-            }
+            AgentType::TaskManager => load_prompt(PromptKind::TaskManager),
+            AgentType::TaskWorker { .. } => load_prompt(PromptKind::TaskWorker),
             AgentType::PageBuilderWorker { is_persistent, .. } => {
                 // Select prompt based on is_persistent flag:
                 // - false: Results display page (focus on VFS data)
@@ -815,7 +1165,8 @@ impl AgentInstance {
     /// - Retrieves AWS credentials from Identity Center
     /// - Configures Bedrock provider with Claude Haiku 3
     /// - Registers execute_javascript tool
-    /// - Disables streaming to avoid hang issues
+    /// - Disables streaming to avoid hang issues, unless Settings turns it on
+    ///   for TaskManager responses (workers show their progress inline instead)
     fn create_stood_agent(
         &self,
         aws_identity: &mut crate::app::aws_identity::AwsIdentityCenter,
@@ -823,10 +1174,11 @@ impl AgentInstance {
     ) -> Result<stood::agent::Agent, String> {
         let _timing = perf_guard!("create_stood_agent", &self.metadata.name);
 
+        use crate::app::agent_framework::{
+            provider_for, ModelProviderPreferences, ProviderBuildContext,
+        };
         use stood::agent::{Agent, EventLoopConfig};
-        use stood::llm::Bedrock;
         use stood::telemetry::{AwsCredentialSource, TelemetryConfig};
-        use stood::CacheStrategy;
 
         // Get AWS credentials from the Home Dash Account for Bedrock
         perf_checkpoint!("create_stood_agent.get_bedrock_credentials_start");
@@ -926,6 +1278,18 @@ impl AgentInstance {
         // Configure agent with selected model
         let system_prompt = self.get_system_prompt_for_type();
 
+        // New conversations start with what earlier sessions of the project remembered
+        let system_prompt = match self.load_project_memory().and_then(|m| m.system_context()) {
+            Some(memory) => {
+                self.logger.log_system_message(
+                    &self.agent_type,
+                    "Project memory added to system prompt",
+                );
+                format!("{}\n\n{}", system_prompt, memory)
+            }
+            None => system_prompt,
+        };
+
         // DEBUG: Log first 200 chars of system prompt to verify it's loaded
         self.logger.log_system_message(
             &self.agent_type,
@@ -989,111 +1353,43 @@ impl AgentInstance {
             ),
         );
 
-        // Build agent with selected model (match on enum to get concrete type)
+        // Resolve the provider configured for this agent's role; models
+        // without tool use are refused here
+        let provider_config = ModelProviderPreferences::load()
+            .for_agent_type(&self.agent_type)
+            .clone();
+        let provider = provider_for(&provider_config)?;
+        self.logger.log_system_message(
+            &self.agent_type,
+            &format!("Model provider: {}", provider_config.label(self.metadata.model)),
+        );
+
+        let streaming = self.agent_type.is_task_manager()
+            && provider.capabilities().streaming
+            && crate::app::app_config::app_config().agents.stream_responses;
+
+        // Build agent with the provider's model
         // Enable cancellation support for all models
         // Include agent naming and telemetry for CloudWatch Gen AI Observability
-        use crate::app::agent_framework::AgentModel;
-        let agent_builder = match self.metadata.model {
-            AgentModel::ClaudeSonnet45 => Agent::builder()
-                .name(agent_name)
-                .with_id(&agent_id)
-                .with_telemetry(telemetry_config.clone())
-                .model(Bedrock::ClaudeSonnet45)
-                .system_prompt(&system_prompt)
-                .with_prompt_caching(CacheStrategy::SystemAndTools)
-                .with_streaming(false)
-                .with_event_loop_config(event_loop_config.clone())
-                // Cancellation token is set in event_loop_config above (child token for workers)
-                .with_credentials(
-                    access_key.clone(),
-                    secret_key.clone(),
-                    session_token.clone(),
-                    region.clone(),
-                )
-                .tools(self.get_tools_for_type()),
-            AgentModel::ClaudeHaiku45 => Agent::builder()
-                .name(agent_name)
-                .with_id(&agent_id)
-                .with_telemetry(telemetry_config.clone())
-                .model(Bedrock::ClaudeHaiku45)
-                .system_prompt(&system_prompt)
-                .with_prompt_caching(CacheStrategy::SystemAndTools)
-                .with_streaming(false)
-                .with_event_loop_config(event_loop_config.clone())
-                // Cancellation token is set in event_loop_config above (child token for workers)
-                .with_credentials(
-                    access_key.clone(),
-                    secret_key.clone(),
-                    session_token.clone(),
-                    region.clone(),
-                )
-                .tools(self.get_tools_for_type()),
-            AgentModel::ClaudeOpus45 => Agent::builder()
-                .name(agent_name)
-                .with_id(&agent_id)
-                .with_telemetry(telemetry_config.clone())
-                .model(Bedrock::ClaudeOpus45)
-                .system_prompt(&system_prompt)
-                .with_prompt_caching(CacheStrategy::SystemAndTools)
-                .with_streaming(false)
-                .with_event_loop_config(event_loop_config.clone())
-                // Cancellation token is set in event_loop_config above (child token for workers)
-                .with_credentials(
-                    access_key.clone(),
-                    secret_key.clone(),
-                    session_token.clone(),
-                    region.clone(),
-                )
-                .tools(self.get_tools_for_type()),
-            AgentModel::NovaPro => Agent::builder()
-                .name(agent_name)
-                .with_id(&agent_id)
-                .with_telemetry(telemetry_config.clone())
-                .model(Bedrock::NovaPro)
-                .system_prompt(&system_prompt)
-                .with_streaming(false)
-                .with_event_loop_config(event_loop_config.clone())
-                // Cancellation token is set in event_loop_config above (child token for workers)
-                .with_credentials(
-                    access_key.clone(),
-                    secret_key.clone(),
-                    session_token.clone(),
-                    region.clone(),
-                )
-                .tools(self.get_tools_for_type()),
-            AgentModel::NovaLite => Agent::builder()
-                .name(agent_name)
-                .with_id(&agent_id)
-                .with_telemetry(telemetry_config.clone())
-                .model(Bedrock::NovaLite)
-                .system_prompt(&system_prompt)
-                .with_streaming(false)
-                .with_event_loop_config(event_loop_config.clone())
-                // Cancellation token is set in event_loop_config above (child token for workers)
-                .with_credentials(
-                    access_key.clone(),
-                    secret_key.clone(),
-                    session_token.clone(),
-                    region.clone(),
-                )
-                .tools(self.get_tools_for_type()),
-            AgentModel::NovaMicro => Agent::builder()
-                .name(agent_name)
-                .with_id(&agent_id)
-                .with_telemetry(telemetry_config.clone())
-                .model(Bedrock::NovaMicro)
-                .system_prompt(&system_prompt)
-                .with_streaming(false)
-                .with_event_loop_config(event_loop_config.clone())
-                // Cancellation token is set in event_loop_config above (child token for workers)
-                .with_credentials(
-                    access_key.clone(),
-                    secret_key.clone(),
-                    session_token.clone(),
-                    region.clone(),
-                )
-                .tools(self.get_tools_for_type()),
-        };
+        let agent_builder = Agent::builder()
+            .name(agent_name)
+            .with_id(&agent_id)
+            .with_telemetry(telemetry_config.clone())
+            .system_prompt(&system_prompt)
+            .with_streaming(streaming)
+            // Cancellation token is set in event_loop_config above (child token for workers)
+            .with_event_loop_config(event_loop_config.clone())
+            .tools(self.get_tools_for_type());
+        let agent_builder = provider.configure(
+            agent_builder,
+            &ProviderBuildContext {
+                access_key: access_key.clone(),
+                secret_key: secret_key.clone(),
+                session_token: session_token.clone(),
+                region: region.clone(),
+                bedrock_model: self.metadata.model,
+            },
+        )?;
 
         // DEBUG: Verify model was set in builder
         self.logger.log_system_message(
@@ -1107,8 +1403,12 @@ impl AgentInstance {
             &format!("DEBUG [2]: After middleware, metadata.model={:?}", self.metadata.model),
         );
 
-        // For worker agents (TaskWorker and PageBuilderWorker), add callback handler
-        // to capture tool events and forward them to the UI for inline progress display
+        // All agents record tool calls and token usage for transcript export,
+        // and report tool events to the middleware layers' tool hooks.
+        // For worker agents (TaskWorker and PageBuilderWorker), the handlers also
+        // forward tool events to the UI for inline progress display
+        let layers = self.layer_stack.clone();
+        let layer_ctx = self.create_layer_context();
         let agent_builder = match &self.agent_type {
             AgentType::TaskWorker { parent_id } | AgentType::PageBuilderWorker { parent_id, .. } => {
                 use crate::app::agent_framework::WorkerProgressCallbackHandler;
@@ -1116,10 +1416,27 @@ impl AgentInstance {
                     &self.agent_type,
                     "Adding worker progress callback handler for inline UI tracking",
                 );
-                agent_builder
-                    .with_callback_handler(WorkerProgressCallbackHandler::new(self.id, *parent_id))
+                agent_builder.with_callback_handler(TranscriptCallbackHandler::wrapping(
+                    self.transcript.clone(),
+                    LayerHookCallbackHandler::wrapping(
+                        layers,
+                        layer_ctx,
+                        WorkerProgressCallbackHandler::new(self.id, *parent_id),
+                    ),
+                ))
+            }
+            // TaskManager: the transcript handler also streams response text to the chat
+            AgentType::TaskManager => {
+                use crate::app::agent_framework::StreamingCallbackHandler;
+                agent_builder.with_callback_handler(TranscriptCallbackHandler::wrapping(
+                    self.transcript.clone(),
+                    LayerHookCallbackHandler::wrapping(
+                        layers,
+                        layer_ctx,
+                        StreamingCallbackHandler::new(self.id),
+                    ),
+                ))
             }
-            _ => agent_builder,
         };
 
         // DEBUG: Verify model preserved after callback handler
@@ -1151,6 +1468,27 @@ impl AgentInstance {
             _ => agent_builder,
         };
 
+        // Guardrail: refuse tool calls that would pass credentials to a tool
+        let agent_builder = if self.tool_guardrail {
+            use crate::app::agent_framework::middleware::ToolGuardrailMiddleware;
+            agent_builder.with_middleware(Arc::new(ToolGuardrailMiddleware::new(
+                self.project.clone(),
+            )))
+        } else {
+            agent_builder
+        };
+
+        // Approval gate: hold mutating tool calls until the user decides
+        let agent_builder = if self.tool_approval {
+            use crate::app::agent_framework::middleware::ToolApprovalMiddleware;
+            agent_builder.with_middleware(Arc::new(ToolApprovalMiddleware::new(
+                self.id,
+                self.agent_type.parent_id(),
+            )))
+        } else {
+            agent_builder
+        };
+
         // DEBUG: About to call build()
         self.logger.log_system_message(
             &self.agent_type,
@@ -1193,10 +1531,8 @@ impl AgentInstance {
         let processed_message = match self.layer_stack.process_pre_send(&user_message, &ctx) {
             Ok(msg) => msg,
             Err(LayerError::Abort(reason)) => {
-                self.logger.log_system_message(
-                    &self.agent_type,
-                    &format!("Message send aborted by middleware: {}", reason),
-                );
+                // Show the reason in the conversation so the message isn't silently dropped
+                self.add_system_message(&format!("Message not sent: {}", reason));
                 return; // Don't send if middleware aborts
             }
             Err(e) => {
@@ -1223,9 +1559,12 @@ impl AgentInstance {
         // Add user message to conversation (use original for display, processed for sending)
         self.messages
             .push_back(ConversationMessage::user(user_message.clone()));
+        self.transcript.set_message_index(self.messages.len() - 1);
         self.processing = true;
         self.processing_phase = ProcessingPhase::Thinking;
         self.status_message = Some("Processing...".to_string());
+        self.streaming_text.clear();
+        self.publish_status();
 
         // Log message
         self.logger
@@ -1242,6 +1581,20 @@ impl AgentInstance {
         let vfs_id = self.vfs_id.clone();
         let message_for_agent = processed_message; // Use processed message for agent
         let parent_cancel_token = self.parent_cancel_token.clone(); // For worker cancellation propagation
+        // Aborts AWS calls and scripts of tools when this agent or its parent is stopped
+        let turn_cancel_sources: Vec<CancellationToken> = self
+            .cancel_token
+            .iter()
+            .chain(self.parent_cancel_token.iter())
+            .cloned()
+            .collect();
+        self.cancel_requested_at = None;
+
+        // Stop aborts a streamed response mid-stream rather than at the end of the cycle
+        let abort_token = self
+            .cancel_token
+            .clone()
+            .filter(|_| self.agent_type.is_task_manager());
 
         // Spawn background thread
         perf_checkpoint!("send_message.spawning_background_thread");
@@ -1308,6 +1661,11 @@ impl AgentInstance {
                     }
                 }
 
+                // Publish the turn's cancellation to tools running on this thread
+                let (turn_cancellation, _turn_guard) =
+                    crate::app::agent_framework::TurnCancellation::link(turn_cancel_sources);
+                crate::app::agent_framework::set_current_turn_cancellation(Some(turn_cancellation));
+
                 // Lazy initialization of stood agent
                 let mut agent_guard = stood_agent.lock().unwrap();
                 if agent_guard.is_none() {
@@ -1357,7 +1715,38 @@ Query result presentation:
                     None
                 );
                 let execute_start = std::time::Instant::now();
-                let execute_result = agent.execute(&full_message).await;
+                let execute_result = match &abort_token {
+                    Some(token) => tokio::select! {
+                        result = agent.execute(&full_message) => Some(result),
+                        _ = token.cancelled() => None,
+                    },
+                    None => Some(agent.execute(&full_message).await),
+                };
+                let Some(execute_result) = execute_result else {
+                    logger.log_system_message(&agent_type, "Execution aborted by cancellation");
+                    // Tool calls the model made get results, or the next request is rejected
+                    let cancelled = agent
+                        .conversation()
+                        .messages()
+                        .last()
+                        .and_then(cancelled_tool_results);
+                    if let Some(results) = cancelled {
+                        agent.conversation_mut().add_message(results);
+                    }
+                    // The turn ended without a model reply; add one so roles keep alternating
+                    let ends_with_user = agent
+                        .conversation()
+                        .messages()
+                        .last()
+                        .is_some_and(|m| m.role == stood::types::MessageRole::User);
+                    if ends_with_user {
+                        agent
+                            .conversation_mut()
+                            .add_assistant_message("[Response stopped by user]");
+                    }
+                    let _ = sender.send(ConversationResponse::Error("Cancelled by user".to_string()));
+                    return;
+                };
                 let execute_duration = execute_start.elapsed();
                 crate::app::agent_framework::perf_timing::log_timing(
                     "MODEL_INVOCATION.agent_execute",
@@ -1478,6 +1867,7 @@ Query result presentation:
         if self.status == AgentStatus::Cancelled {
             // Drain the channel to prevent message buildup
             while self.response_channel.1.try_recv().is_ok() {
+                self.log_cancellation_latency();
                 self.logger.log_system_message(
                     &self.agent_type,
                     "Discarding message - agent is cancelled",
@@ -1511,9 +1901,13 @@ Query result presentation:
                 self.processing = false;
                 self.processing_phase = ProcessingPhase::Idle;
                 self.status_message = None;
+                self.update_cost();
 
                 match response {
                     ConversationResponse::Success(text) => {
+                        // The complete response replaces the streamed text
+                        self.streaming_text.clear();
+
                         // === Post-response middleware processing ===
                         perf_checkpoint!("poll_response.middleware_start");
                         let ctx = self.create_layer_context();
@@ -1612,6 +2006,8 @@ Query result presentation:
                         }
                     }
                     ConversationResponse::Error(error) => {
+                        self.streaming_text.clear();
+                        self.log_cancellation_latency();
                         self.messages
                             .push_back(ConversationMessage::assistant(format!("Error: {}", error)));
                         self.logger
@@ -1625,9 +2021,11 @@ Query result presentation:
                                                 // Status updates typically indicate tool execution in progress
                         self.processing_phase =
                             ProcessingPhase::ExecutingTool("Running tool".to_string());
+                        self.publish_status();
                         return false; // Don't mark as complete, continue polling
                     }
                 }
+                self.publish_status();
                 true
             }
             Err(std::sync::mpsc::TryRecvError::Empty) => false,
@@ -1653,6 +2051,11 @@ Query result presentation:
     ) -> Result<(), String> {
         let _timing = perf_guard!("AgentInstance::initialize", &self.metadata.name);
 
+        self.project = Some(project_key(&aws_identity.start_url));
+        if self.agent_type.is_task_manager() {
+            self.memory_project = self.project.clone();
+        }
+
         let agent = perf_timed!("AgentInstance::initialize.create_stood_agent", {
             self.create_stood_agent(aws_identity, agent_logging_enabled)?
         });
@@ -1676,6 +2079,85 @@ Query result presentation:
         Ok(())
     }
 
+    /// Project whose memory this agent uses (None for workers)
+    pub fn memory_project(&self) -> Option<&str> {
+        self.memory_project.as_deref()
+    }
+
+    /// Saved memory of this agent's project, if it has one
+    fn load_project_memory(&self) -> Option<ProjectMemory> {
+        let project = self.memory_project.as_deref()?;
+        match MemoryStore::default_location()?.load(project) {
+            Ok(memory) => Some(memory),
+            Err(e) => {
+                tracing::warn!("Failed to load memory of project {}: {:#}", project, e);
+                None
+            }
+        }
+    }
+
+    /// Ask the agent for the session's key facts and save them to project memory
+    ///
+    /// Runs in the background on the stood agent, which is taken from this
+    /// instance (it is about to be dropped or reset). Only idle task managers
+    /// that exchanged at least one message are summarized. Returns whether
+    /// the summarization pass was started.
+    fn extract_session_memory(&mut self) -> bool {
+        let Some(project) = self.memory_project.clone() else {
+            return false;
+        };
+        let has_exchange = self.messages.iter().any(|m| m.role == ConversationRole::User)
+            && self.messages.iter().any(|m| m.role == ConversationRole::Assistant);
+        if self.processing || !has_exchange {
+            return false;
+        }
+        let Some(store) = MemoryStore::default_location() else {
+            return false;
+        };
+        let Some(mut agent) = self.stood_agent.lock().unwrap().take() else {
+            return false;
+        };
+
+        self.logger
+            .log_system_message(&self.agent_type, "Extracting session memory");
+        let logger = Arc::clone(&self.logger);
+        let runtime = Arc::clone(&self.runtime);
+        let agent_type = self.agent_type.clone();
+        let session_id = self.id.to_string();
+
+        std::thread::spawn(move || {
+            let response = runtime.block_on(async {
+                agent.execute(MEMORY_EXTRACTION_PROMPT).await.ok()?;
+                let last = agent.conversation().messages().last()?;
+                if last.role != stood::types::MessageRole::Assistant {
+                    return None;
+                }
+                last.text().map(|text| text.to_string())
+            });
+            let Some(response) = response else {
+                logger.log_error(&agent_type, "Session memory extraction returned no response");
+                return;
+            };
+
+            let facts = parse_memory_facts(&response);
+            match store.remember(&project, &facts, &session_id) {
+                Ok(added) => logger.log_system_message(
+                    &agent_type,
+                    &format!(
+                        "Session memory: {} new of {} facts saved to project {}",
+                        added,
+                        facts.len(),
+                        project
+                    ),
+                ),
+                Err(e) => {
+                    logger.log_error(&agent_type, &format!("Failed to save session memory: {:#}", e))
+                }
+            }
+        });
+        true
+    }
+
     /// Get mutable reference to metadata (for external updates)
     pub fn metadata_mut(&mut self) -> &mut AgentMetadata {
         &mut self.metadata
@@ -1686,8 +2168,15 @@ Query result presentation:
     /// This clears the message history and resets the stood agent,
     /// effectively starting a fresh conversation while keeping the agent instance.
     pub fn clear_conversation(&mut self) {
+        // Remember key facts before the conversation is gone
+        self.extract_session_memory();
+
         // Clear message history
         self.messages.clear();
+        self.transcript.clear();
+        self.tool_timeline.clear();
+        self.streaming_text.clear();
+        self.cost_tracker.start_new_conversation();
 
         // Log the clear operation
         self.logger
@@ -1709,8 +2198,11 @@ Query result presentation:
     ///
     /// This cancels any ongoing execution and marks the agent as terminated.
     pub fn terminate(&mut self) {
+        // Remember key facts; an idle agent handed to the memory pass must not be cancelled
+        let extracting_memory = self.extract_session_memory();
+
         // Cancel any ongoing execution
-        if let Some(token) = &self.cancel_token {
+        if let Some(token) = self.cancel_token.as_ref().filter(|_| !extracting_memory) {
             token.cancel();
             self.logger
                 .log_system_message(&self.agent_type, "Cancelled ongoing execution");
@@ -1720,10 +2212,21 @@ Query result presentation:
         self.processing = false;
         self.processing_phase = ProcessingPhase::Idle;
         self.cancel_token = None;
+        self.publish_status();
 
         // Clear workspace tracking for this agent
         crate::app::agent_framework::clear_workspace_for_agent(self.id);
 
+        // Refresh a saved workspace with the final VFS state before it is dropped
+        let session_id = self.id.to_string();
+        if matches!(self.agent_type, AgentType::TaskManager)
+            && VfsSnapshotStore::default_location().is_some_and(|store| store.exists(&session_id))
+        {
+            if let Err(e) = self.save_vfs_snapshot() {
+                tracing::warn!("Failed to refresh VFS snapshot for {}: {:#}", session_id, e);
+            }
+        }
+
         // Deregister VFS if this agent owned it (TaskManager only)
         if let Some(ref vfs_id) = self.vfs_id {
             deregister_vfs(vfs_id);
@@ -1736,6 +2239,27 @@ Query result presentation:
     }
 }
 
+/// Error results for the tool calls in `message` that an aborted turn left
+/// without results, `None` if it made none
+fn cancelled_tool_results(message: &stood::types::Message) -> Option<stood::types::Message> {
+    use stood::types::{ContentBlock, Message, MessageRole};
+
+    if message.role != MessageRole::Assistant {
+        return None;
+    }
+    let results: Vec<ContentBlock> = message
+        .content
+        .iter()
+        .filter_map(|block| match block {
+            ContentBlock::ToolUse { id, .. } => {
+                Some(ContentBlock::tool_result_error(id.clone(), "Cancelled by user"))
+            }
+            _ => None,
+        })
+        .collect();
+    (!results.is_empty()).then(|| Message::new(MessageRole::User, results))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1867,6 +2391,7 @@ mod tests {
         let tool_names: Vec<&str> = tools.iter().map(|t| t.name()).collect();
         assert!(tool_names.contains(&"think"));
         assert!(tool_names.contains(&"start_task"));
+        assert!(tool_names.contains(&"start_tasks"));
     }
 
     #[test]
@@ -1922,13 +2447,43 @@ mod tests {
         let metadata = create_test_metadata();
         let agent = AgentInstance::new(metadata, AgentType::TaskManager).with_recommended_layers();
 
-        assert_eq!(agent.layer_stack().len(), 2);
+        assert_eq!(agent.layer_stack().len(), 3);
         assert_eq!(
             agent.layer_stack().layer_names(),
-            vec!["Logging", "TokenTracking"]
+            vec!["Logging", "TokenTracking", "TokenBudget"]
         );
     }
 
+    #[test]
+    fn test_with_approval_gate_layer() {
+        let metadata = create_test_metadata();
+        let parent_id = AgentId::new();
+        let agent = AgentInstance::new(metadata, AgentType::TaskWorker { parent_id })
+            .with_approval_gate_layer();
+
+        assert_eq!(agent.layer_stack().layer_names(), vec!["ApprovalGate"]);
+        assert!(agent.tool_approval);
+    }
+
+    #[test]
+    fn test_with_guardrail_layer() {
+        let metadata = create_test_metadata();
+        let agent = AgentInstance::new(metadata, AgentType::TaskManager).with_guardrail_layer();
+
+        assert_eq!(agent.layer_stack().layer_names(), vec!["Guardrail"]);
+        assert!(agent.tool_guardrail);
+        assert!(agent.create_layer_context().project.is_none());
+    }
+
+    #[test]
+    fn test_with_tool_timeline_layer() {
+        let metadata = create_test_metadata();
+        let agent = AgentInstance::new(metadata, AgentType::TaskManager).with_tool_timeline_layer();
+
+        assert_eq!(agent.layer_stack().layer_names(), vec!["ToolTimeline"]);
+        assert!(agent.tool_timeline().is_empty());
+    }
+
     #[test]
     fn test_create_layer_context() {
         let metadata = create_test_metadata();
@@ -2042,4 +2597,49 @@ mod tests {
         assert!(token.is_cancelled());
         assert!(!agent.can_cancel()); // Token should be cleared after terminate
     }
+
+    #[test]
+    fn test_cancelled_tool_results_answer_each_tool_call() {
+        use stood::types::{ContentBlock, Message, MessageRole};
+
+        let tool_use = |id: &str| ContentBlock::ToolUse {
+            id: id.to_string(),
+            name: "execute_javascript".to_string(),
+            input: serde_json::json!({}),
+        };
+        let message = Message::new(
+            MessageRole::Assistant,
+            vec![
+                ContentBlock::Text {
+                    text: "Checking both regions".to_string(),
+                },
+                tool_use("tool-1"),
+                tool_use("tool-2"),
+            ],
+        );
+
+        let results = cancelled_tool_results(&message).expect("tool calls need results");
+        assert_eq!(results.role, MessageRole::User);
+        let ids: Vec<&str> = results
+            .content
+            .iter()
+            .filter_map(|block| match block {
+                ContentBlock::ToolResult {
+                    tool_use_id,
+                    is_error,
+                    ..
+                } if *is_error => Some(tool_use_id.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(ids, vec!["tool-1", "tool-2"]);
+
+        let reply = Message::new(
+            MessageRole::Assistant,
+            vec![ContentBlock::Text {
+                text: "Done".to_string(),
+            }],
+        );
+        assert!(cancelled_tool_results(&reply).is_none());
+    }
 }
@@ -5,6 +5,7 @@
 pub mod cancellation;
 pub mod creation;
 pub mod instance;
+pub mod model_provider;
 pub mod model_selection;
 pub mod types;
 
@@ -12,5 +13,6 @@ pub mod types;
 pub use cancellation::*;
 pub use creation::*;
 pub use instance::AgentInstance;
+pub use model_provider::*;
 pub use model_selection::*;
 pub use types::*;
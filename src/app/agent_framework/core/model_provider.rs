@@ -0,0 +1,546 @@
+//! Model Providers for Agent Framework
+//!
+//! Agents talk to their model through a [`ModelProvider`], which sets the
+//! model and its connection on the stood agent builder. Bedrock is the
+//! default and uses the signed-in account; the Anthropic API, any
+//! OpenAI-compatible endpoint, and a local Ollama server can be configured
+//! instead, per agent role, in [`ModelProviderPreferences`].
+//!
+//! Agents cannot work without tool use, so a provider whose model does not
+//! support it is refused when the agent is created.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use stood::agent::AgentBuilder;
+
+use super::model_selection::{AgentModel, AgentModelRole};
+use super::types::AgentType;
+
+/// Where agent models are served from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ModelProviderKind {
+    /// Amazon Bedrock with the Home Dash Account credentials
+    #[default]
+    Bedrock,
+    /// Anthropic Messages API
+    Anthropic,
+    /// Any server implementing the OpenAI chat completions API
+    OpenAiCompatible,
+    /// Local Ollama server
+    Ollama,
+}
+
+impl ModelProviderKind {
+    /// Get the display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ModelProviderKind::Bedrock => "Amazon Bedrock",
+            ModelProviderKind::Anthropic => "Anthropic API",
+            ModelProviderKind::OpenAiCompatible => "OpenAI-compatible",
+            ModelProviderKind::Ollama => "Ollama",
+        }
+    }
+
+    /// Endpoint used when none is configured
+    pub fn default_endpoint(&self) -> Option<&'static str> {
+        match self {
+            ModelProviderKind::Bedrock => None,
+            ModelProviderKind::Anthropic => Some("https://api.anthropic.com"),
+            ModelProviderKind::OpenAiCompatible => Some("https://api.openai.com/v1"),
+            ModelProviderKind::Ollama => Some("http://localhost:11434"),
+        }
+    }
+
+    /// Environment variable holding the API key when none is configured
+    pub fn default_api_key_env(&self) -> Option<&'static str> {
+        match self {
+            ModelProviderKind::Anthropic => Some("ANTHROPIC_API_KEY"),
+            ModelProviderKind::OpenAiCompatible => Some("OPENAI_API_KEY"),
+            ModelProviderKind::Bedrock | ModelProviderKind::Ollama => None,
+        }
+    }
+
+    /// Whether every model of this provider supports tool use
+    ///
+    /// OpenAI-compatible servers and Ollama host arbitrary models, so tool
+    /// use has to be confirmed per model in the configuration.
+    pub fn always_supports_tool_use(&self) -> bool {
+        matches!(
+            self,
+            ModelProviderKind::Bedrock | ModelProviderKind::Anthropic
+        )
+    }
+
+    /// All providers, in display order
+    pub fn all() -> &'static [ModelProviderKind] {
+        &[
+            ModelProviderKind::Bedrock,
+            ModelProviderKind::Anthropic,
+            ModelProviderKind::OpenAiCompatible,
+            ModelProviderKind::Ollama,
+        ]
+    }
+}
+
+impl std::fmt::Display for ModelProviderKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display_name())
+    }
+}
+
+/// What a provider's model can do
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderCapabilities {
+    /// Calls tools; required for every agent
+    pub tool_use: bool,
+    /// Streams response text while it is generated
+    pub streaming: bool,
+    /// Caches the system prompt and tool definitions between requests
+    pub prompt_caching: bool,
+}
+
+/// Provider configuration for one agent role
+///
+/// Empty `endpoint` and `api_key_env` fall back to the provider defaults.
+/// The model of a Bedrock role is the [`AgentModel`] chosen for the agent;
+/// `model` names the model for the other providers.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ModelProviderConfig {
+    pub kind: ModelProviderKind,
+    pub model: String,
+    pub endpoint: String,
+    pub api_key_env: String,
+    /// User confirmation that the model supports tool use, for providers
+    /// that host arbitrary models
+    pub tool_use: bool,
+}
+
+impl ModelProviderConfig {
+    /// Endpoint to connect to
+    pub fn endpoint(&self) -> Option<&str> {
+        let endpoint = self.endpoint.trim();
+        if endpoint.is_empty() {
+            self.kind.default_endpoint()
+        } else {
+            Some(endpoint)
+        }
+    }
+
+    /// Environment variable holding the API key
+    pub fn api_key_env(&self) -> Option<&str> {
+        let name = self.api_key_env.trim();
+        if name.is_empty() {
+            self.kind.default_api_key_env()
+        } else {
+            Some(name)
+        }
+    }
+
+    /// Capabilities of the configured model
+    pub fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            tool_use: self.kind.always_supports_tool_use() || self.tool_use,
+            streaming: true,
+            prompt_caching: self.kind == ModelProviderKind::Anthropic,
+        }
+    }
+
+    /// Check the configuration can run an agent
+    ///
+    /// `env` looks up environment variables, so tests need not set them.
+    pub fn validate_with(&self, env: impl Fn(&str) -> Option<String>) -> Result<(), String> {
+        if self.kind == ModelProviderKind::Bedrock {
+            return Ok(());
+        }
+        if self.model.trim().is_empty() {
+            return Err(format!("No model configured for {}", self.kind));
+        }
+        if !self.capabilities().tool_use {
+            return Err(format!(
+                "{} on {} is not marked as supporting tool use, which agents require",
+                self.model.trim(),
+                self.kind
+            ));
+        }
+        let endpoint = self.endpoint().unwrap_or_default();
+        if !(endpoint.starts_with("http://") || endpoint.starts_with("https://")) {
+            return Err(format!("Invalid {} endpoint: {}", self.kind, endpoint));
+        }
+        // An OpenAI-compatible server without a key variable may not need one
+        let key_required =
+            self.kind == ModelProviderKind::Anthropic || !self.api_key_env.trim().is_empty();
+        if key_required {
+            if let Some(name) = self.api_key_env() {
+                if env(name).filter(|key| !key.trim().is_empty()).is_none() {
+                    return Err(format!("{} API key not set: ${} is empty", self.kind, name));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check the configuration against the process environment
+    pub fn validate(&self) -> Result<(), String> {
+        self.validate_with(|name| std::env::var(name).ok())
+    }
+
+    /// Label for logs and the UI, e.g. "Ollama: qwen2.5:14b"
+    pub fn label(&self, bedrock_model: AgentModel) -> String {
+        match self.kind {
+            ModelProviderKind::Bedrock => format!("{}: {}", self.kind, bedrock_model),
+            _ => format!("{}: {}", self.kind, self.model.trim()),
+        }
+    }
+}
+
+/// Provider chosen for each agent role, persisted between sessions
+///
+/// Stored as JSON in `{config_dir}/awsdash/agent_providers.json`. Missing
+/// fields fall back to Bedrock.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ModelProviderPreferences {
+    pub manager: ModelProviderConfig,
+    pub worker: ModelProviderConfig,
+    pub page_builder: ModelProviderConfig,
+}
+
+impl ModelProviderPreferences {
+    /// Default preferences file location
+    pub fn default_location() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("awsdash/agent_providers.json"))
+    }
+
+    /// Load preferences from the default location, falling back to defaults
+    pub fn load() -> Self {
+        let Some(path) = Self::default_location() else {
+            return Self::default();
+        };
+        if !path.exists() {
+            return Self::default();
+        }
+        Self::load_from(&path).unwrap_or_else(|e| {
+            log::warn!("Failed to load agent provider preferences: {:#}", e);
+            Self::default()
+        })
+    }
+
+    /// Save preferences to the default location
+    pub fn save(&self) -> Result<()> {
+        let path = Self::default_location().context("Config directory not available")?;
+        self.save_to(&path)
+    }
+
+    /// Load preferences from `path`
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Save preferences to `path`
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Provider for a role
+    pub fn get(&self, role: AgentModelRole) -> &ModelProviderConfig {
+        match role {
+            AgentModelRole::Manager => &self.manager,
+            AgentModelRole::Worker => &self.worker,
+            AgentModelRole::PageBuilder => &self.page_builder,
+        }
+    }
+
+    /// Mutable provider for a role (for the settings window)
+    pub fn get_mut(&mut self, role: AgentModelRole) -> &mut ModelProviderConfig {
+        match role {
+            AgentModelRole::Manager => &mut self.manager,
+            AgentModelRole::Worker => &mut self.worker,
+            AgentModelRole::PageBuilder => &mut self.page_builder,
+        }
+    }
+
+    /// Provider for an agent type
+    pub fn for_agent_type(&self, agent_type: &AgentType) -> &ModelProviderConfig {
+        self.get(AgentModelRole::for_agent_type(agent_type))
+    }
+}
+
+/// Connection details available to every provider
+///
+/// Bedrock credentials come from the Home Dash Account and are also used by
+/// the tools, so they are resolved for every agent.
+#[derive(Debug, Clone)]
+pub struct ProviderBuildContext {
+    pub access_key: String,
+    pub secret_key: String,
+    pub session_token: Option<String>,
+    pub region: String,
+    /// Model chosen for the agent, used by Bedrock
+    pub bedrock_model: AgentModel,
+}
+
+/// Sets the model and its connection on a stood agent builder
+pub trait ModelProvider: Send + Sync {
+    fn kind(&self) -> ModelProviderKind;
+
+    fn capabilities(&self) -> ProviderCapabilities;
+
+    /// Configure `builder` to use this provider's model
+    fn configure(
+        &self,
+        builder: AgentBuilder,
+        ctx: &ProviderBuildContext,
+    ) -> Result<AgentBuilder, String>;
+}
+
+/// Provider for `config`, refusing configurations that cannot run an agent
+pub fn provider_for(config: &ModelProviderConfig) -> Result<Box<dyn ModelProvider>, String> {
+    config.validate()?;
+    Ok(match config.kind {
+        ModelProviderKind::Bedrock => Box::new(BedrockProvider),
+        _ => Box::new(ExternalProvider {
+            config: config.clone(),
+        }),
+    })
+}
+
+/// Amazon Bedrock with explicit Home Dash Account credentials
+pub struct BedrockProvider;
+
+impl ModelProvider for BedrockProvider {
+    fn kind(&self) -> ModelProviderKind {
+        ModelProviderKind::Bedrock
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            tool_use: true,
+            streaming: true,
+            prompt_caching: true,
+        }
+    }
+
+    fn configure(
+        &self,
+        builder: AgentBuilder,
+        ctx: &ProviderBuildContext,
+    ) -> Result<AgentBuilder, String> {
+        use stood::llm::Bedrock;
+        use stood::CacheStrategy;
+
+        // Match on the enum to get the concrete stood model type
+        let builder = match ctx.bedrock_model {
+            AgentModel::ClaudeSonnet45 => builder
+                .model(Bedrock::ClaudeSonnet45)
+                .with_prompt_caching(CacheStrategy::SystemAndTools),
+            AgentModel::ClaudeHaiku45 => builder
+                .model(Bedrock::ClaudeHaiku45)
+                .with_prompt_caching(CacheStrategy::SystemAndTools),
+            AgentModel::ClaudeOpus45 => builder
+                .model(Bedrock::ClaudeOpus45)
+                .with_prompt_caching(CacheStrategy::SystemAndTools),
+            AgentModel::NovaPro => builder.model(Bedrock::NovaPro),
+            AgentModel::NovaLite => builder.model(Bedrock::NovaLite),
+            AgentModel::NovaMicro => builder.model(Bedrock::NovaMicro),
+        };
+        Ok(builder.with_credentials(
+            ctx.access_key.clone(),
+            ctx.secret_key.clone(),
+            ctx.session_token.clone(),
+            ctx.region.clone(),
+        ))
+    }
+}
+
+/// Anthropic API, OpenAI-compatible endpoint, or Ollama server
+pub struct ExternalProvider {
+    config: ModelProviderConfig,
+}
+
+impl ModelProvider for ExternalProvider {
+    fn kind(&self) -> ModelProviderKind {
+        self.config.kind
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.config.capabilities()
+    }
+
+    fn configure(
+        &self,
+        builder: AgentBuilder,
+        _ctx: &ProviderBuildContext,
+    ) -> Result<AgentBuilder, String> {
+        use stood::llm::{ProviderConfig, ProviderType};
+
+        let endpoint = self.config.endpoint().unwrap_or_default().to_string();
+        let api_key = self
+            .config
+            .api_key_env()
+            .and_then(|name| std::env::var(name).ok());
+        let model = self.config.model.trim().to_string();
+
+        let (provider_type, provider_config) = match self.config.kind {
+            ModelProviderKind::Anthropic => (
+                ProviderType::Anthropic,
+                ProviderConfig::Anthropic {
+                    api_key: api_key.unwrap_or_default(),
+                    base_url: Some(endpoint),
+                },
+            ),
+            ModelProviderKind::OpenAiCompatible => (
+                ProviderType::OpenAI,
+                ProviderConfig::OpenAI {
+                    api_key: api_key.unwrap_or_default(),
+                    base_url: Some(endpoint),
+                    organization: None,
+                },
+            ),
+            ModelProviderKind::Ollama => (
+                ProviderType::Ollama,
+                ProviderConfig::Ollama { base_url: endpoint },
+            ),
+            ModelProviderKind::Bedrock => {
+                return Err("Bedrock is not an external provider".to_string());
+            }
+        };
+
+        let builder = builder
+            .model_by_id(provider_type, &model)
+            .map_err(|e| {
+                format!(
+                    "{} does not accept model {}: {}",
+                    self.config.kind, model, e
+                )
+            })?
+            .with_provider_config(provider_config);
+        Ok(if self.capabilities().prompt_caching {
+            builder.with_prompt_caching(stood::CacheStrategy::SystemAndTools)
+        } else {
+            builder
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(kind: ModelProviderKind, model: &str) -> ModelProviderConfig {
+        ModelProviderConfig {
+            kind,
+            model: model.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_defaults_resolve() {
+        let ollama = config(ModelProviderKind::Ollama, "qwen2.5:14b");
+        assert_eq!(ollama.endpoint(), Some("http://localhost:11434"));
+        assert_eq!(ollama.api_key_env(), None);
+
+        let mut openai = config(ModelProviderKind::OpenAiCompatible, "gpt-4.1");
+        openai.endpoint = " http://localhost:8000/v1 ".to_string();
+        assert_eq!(openai.endpoint(), Some("http://localhost:8000/v1"));
+        assert_eq!(openai.api_key_env(), Some("OPENAI_API_KEY"));
+        assert_eq!(
+            openai.label(AgentModel::NovaPro),
+            "OpenAI-compatible: gpt-4.1"
+        );
+        assert_eq!(
+            ModelProviderConfig::default().label(AgentModel::NovaPro),
+            "Amazon Bedrock: Amazon Nova Pro"
+        );
+    }
+
+    #[test]
+    fn test_tool_use_gating() {
+        let no_env = |_: &str| None;
+
+        let mut ollama = config(ModelProviderKind::Ollama, "gemma2:9b");
+        let error = ollama.validate_with(no_env).unwrap_err();
+        assert!(error.contains("tool use"));
+
+        ollama.tool_use = true;
+        assert!(ollama.validate_with(no_env).is_ok());
+
+        // Anthropic models always support tools, but need a key
+        let anthropic = config(ModelProviderKind::Anthropic, "claude-sonnet-4-5");
+        assert!(anthropic.capabilities().tool_use);
+        assert!(anthropic
+            .validate_with(no_env)
+            .unwrap_err()
+            .contains("ANTHROPIC_API_KEY"));
+        assert!(anthropic
+            .validate_with(|_| Some("sk-test".to_string()))
+            .is_ok());
+
+        assert!(ModelProviderConfig::default().validate_with(no_env).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_incomplete_config() {
+        let no_env = |_: &str| None;
+
+        let mut openai = config(ModelProviderKind::OpenAiCompatible, " ");
+        openai.tool_use = true;
+        assert!(openai
+            .validate_with(no_env)
+            .unwrap_err()
+            .contains("No model"));
+
+        // Local servers without a key variable need no key
+        openai.model = "llama-3.1-70b".to_string();
+        assert!(openai.validate_with(no_env).is_ok());
+        openai.api_key_env = "VLLM_KEY".to_string();
+        assert!(openai
+            .validate_with(no_env)
+            .unwrap_err()
+            .contains("$VLLM_KEY"));
+
+        openai.api_key_env.clear();
+        openai.endpoint = "localhost:8000".to_string();
+        assert!(openai
+            .validate_with(no_env)
+            .unwrap_err()
+            .contains("endpoint"));
+    }
+
+    #[test]
+    fn test_preferences_round_trip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("awsdash/agent_providers.json");
+
+        let mut preferences = ModelProviderPreferences::default();
+        *preferences.get_mut(AgentModelRole::Worker) = ModelProviderConfig {
+            tool_use: true,
+            ..config(ModelProviderKind::Ollama, "qwen2.5:14b")
+        };
+        preferences.save_to(&path).unwrap();
+
+        let loaded = ModelProviderPreferences::load_from(&path).unwrap();
+        assert_eq!(loaded, preferences);
+        assert_eq!(
+            loaded.for_agent_type(&AgentType::TaskManager).kind,
+            ModelProviderKind::Bedrock
+        );
+
+        // Missing fields fall back to Bedrock
+        let partial: ModelProviderPreferences =
+            serde_json::from_str(r#"{"worker": {"kind": "Anthropic"}}"#).unwrap();
+        assert_eq!(partial.worker.kind, ModelProviderKind::Anthropic);
+        assert_eq!(partial.manager, ModelProviderConfig::default());
+    }
+}
@@ -2,14 +2,25 @@
 //!
 //! Provides a selection of Claude and Nova models that support tools and agents.
 //! The actual model configuration is handled by stood - we just tell it which model to use.
+//!
+//! Each agent role (manager, task worker, page builder) has its own model,
+//! stored in [`AgentModelPreferences`], and [`ModelAvailability`] records
+//! which models the Bedrock account and region can actually use.
 
 #![warn(clippy::all, rust_2018_idioms)]
 
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use super::types::AgentType;
+
 /// Supported models for agent creation
 ///
 /// These are the Claude and Nova models available through Bedrock
 /// that support tool use and agent capabilities.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum AgentModel {
     /// Claude Sonnet 4.5 - Balanced performance and cost
     #[default]
@@ -39,6 +50,32 @@ impl AgentModel {
         }
     }
 
+    /// Bedrock foundation model ID
+    ///
+    /// Cross-region inference profiles prefix this with a geography, e.g.
+    /// `us.anthropic.claude-sonnet-4-5-20250929-v1:0`.
+    pub fn model_id(&self) -> &'static str {
+        match self {
+            AgentModel::ClaudeSonnet45 => "anthropic.claude-sonnet-4-5-20250929-v1:0",
+            AgentModel::ClaudeHaiku45 => "anthropic.claude-haiku-4-5-20251001-v1:0",
+            AgentModel::ClaudeOpus45 => "anthropic.claude-opus-4-5-20251101-v1:0",
+            AgentModel::NovaPro => "amazon.nova-pro-v1:0",
+            AgentModel::NovaLite => "amazon.nova-lite-v1:0",
+            AgentModel::NovaMicro => "amazon.nova-micro-v1:0",
+        }
+    }
+
+    /// Context window size in tokens
+    pub fn context_window(&self) -> u64 {
+        match self {
+            AgentModel::ClaudeSonnet45 | AgentModel::ClaudeHaiku45 | AgentModel::ClaudeOpus45 => {
+                200_000
+            }
+            AgentModel::NovaPro | AgentModel::NovaLite => 300_000,
+            AgentModel::NovaMicro => 128_000,
+        }
+    }
+
     /// Get all available models for dropdown
     pub fn all_models() -> &'static [AgentModel] {
         &[
@@ -58,6 +95,221 @@ impl std::fmt::Display for AgentModel {
     }
 }
 
+/// Agent roles that can each be assigned a model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AgentModelRole {
+    /// TaskManager agents created from the New Agent dialog
+    Manager,
+    /// TaskWorker agents spawned by a manager
+    Worker,
+    /// PageBuilderWorker agents spawned by a manager
+    PageBuilder,
+}
+
+impl AgentModelRole {
+    /// Role for an agent type
+    pub fn for_agent_type(agent_type: &AgentType) -> Self {
+        match agent_type {
+            AgentType::TaskManager => AgentModelRole::Manager,
+            AgentType::TaskWorker { .. } => AgentModelRole::Worker,
+            AgentType::PageBuilderWorker { .. } => AgentModelRole::PageBuilder,
+        }
+    }
+
+    /// Get the display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AgentModelRole::Manager => "Manager",
+            AgentModelRole::Worker => "Task Worker",
+            AgentModelRole::PageBuilder => "Page Builder",
+        }
+    }
+
+    /// All roles, in display order
+    pub fn all() -> &'static [AgentModelRole] {
+        &[
+            AgentModelRole::Manager,
+            AgentModelRole::Worker,
+            AgentModelRole::PageBuilder,
+        ]
+    }
+}
+
+/// Model chosen for each agent role, persisted between sessions
+///
+/// Stored as JSON in `{config_dir}/awsdash/agent_models.json`. Missing
+/// fields fall back to the default model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct AgentModelPreferences {
+    pub manager: AgentModel,
+    pub worker: AgentModel,
+    pub page_builder: AgentModel,
+}
+
+impl AgentModelPreferences {
+    /// Default preferences file location
+    pub fn default_location() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("awsdash/agent_models.json"))
+    }
+
+    /// Load preferences from the default location, falling back to defaults
+    pub fn load() -> Self {
+        let Some(path) = Self::default_location() else {
+            return Self::default();
+        };
+        if !path.exists() {
+            return Self::default();
+        }
+        Self::load_from(&path).unwrap_or_else(|e| {
+            log::warn!("Failed to load agent model preferences: {:#}", e);
+            Self::default()
+        })
+    }
+
+    /// Save preferences to the default location
+    pub fn save(&self) -> Result<()> {
+        let path = Self::default_location().context("Config directory not available")?;
+        self.save_to(&path)
+    }
+
+    /// Load preferences from `path`
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Save preferences to `path`
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Model for a role
+    pub fn get(&self, role: AgentModelRole) -> AgentModel {
+        match role {
+            AgentModelRole::Manager => self.manager,
+            AgentModelRole::Worker => self.worker,
+            AgentModelRole::PageBuilder => self.page_builder,
+        }
+    }
+
+    /// Mutable model for a role (for dropdowns)
+    pub fn get_mut(&mut self, role: AgentModelRole) -> &mut AgentModel {
+        match role {
+            AgentModelRole::Manager => &mut self.manager,
+            AgentModelRole::Worker => &mut self.worker,
+            AgentModelRole::PageBuilder => &mut self.page_builder,
+        }
+    }
+
+    /// Model for an agent type
+    pub fn for_agent_type(&self, agent_type: &AgentType) -> AgentModel {
+        self.get(AgentModelRole::for_agent_type(agent_type))
+    }
+}
+
+/// Models available to the authenticated Bedrock account and region
+///
+/// Built from the foundation model and inference profile IDs listed by
+/// Bedrock. A model counts as available when its ID is listed directly or
+/// through a cross-region inference profile.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModelAvailability {
+    /// Region the models were listed in
+    pub region: String,
+    model_ids: HashSet<String>,
+}
+
+impl ModelAvailability {
+    /// Create from listed foundation model and inference profile IDs
+    pub fn from_model_ids(
+        region: impl Into<String>,
+        model_ids: impl IntoIterator<Item = String>,
+    ) -> Self {
+        Self {
+            region: region.into(),
+            model_ids: model_ids.into_iter().collect(),
+        }
+    }
+
+    /// Whether `model` can be used in this account and region
+    pub fn is_available(&self, model: AgentModel) -> bool {
+        let model_id = model.model_id();
+        self.model_ids.iter().any(|id| {
+            id == model_id
+                || id
+                    .strip_suffix(model_id)
+                    .is_some_and(|prefix| prefix.ends_with('.'))
+        })
+    }
+
+    /// Supported models that can be used in this account and region
+    pub fn available_models(&self) -> Vec<AgentModel> {
+        AgentModel::all_models()
+            .iter()
+            .copied()
+            .filter(|model| self.is_available(*model))
+            .collect()
+    }
+
+    /// List foundation models and inference profiles with explicit credentials
+    pub async fn fetch(
+        access_key: &str,
+        secret_key: &str,
+        session_token: Option<&str>,
+        region: &str,
+    ) -> Result<Self> {
+        use aws_credential_types::Credentials;
+
+        let credentials = Credentials::new(
+            access_key,
+            secret_key,
+            session_token.map(|s| s.to_string()),
+            None, // expiry
+            "awsdash-model-availability",
+        );
+        let sdk_config = aws_config::defaults(aws_config::BehaviorVersion::latest())
+            .region(aws_types::region::Region::new(region.to_string()))
+            .credentials_provider(credentials)
+            .load()
+            .await;
+        let client = aws_sdk_bedrock::Client::new(&sdk_config);
+
+        let mut model_ids = Vec::new();
+        let models = client
+            .list_foundation_models()
+            .send()
+            .await
+            .context("Failed to list Bedrock foundation models")?;
+        model_ids.extend(
+            models
+                .model_summaries
+                .unwrap_or_default()
+                .into_iter()
+                .map(|summary| summary.model_id),
+        );
+
+        let mut paginator = client.list_inference_profiles().into_paginator().send();
+        while let Some(page) = paginator.next().await {
+            let page = page.context("Failed to list Bedrock inference profiles")?;
+            model_ids.extend(
+                page.inference_profile_summaries
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|profile| profile.inference_profile_id),
+            );
+        }
+
+        Ok(Self::from_model_ids(region, model_ids))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,6 +339,63 @@ mod tests {
         assert_eq!(models.len(), 6);
     }
 
+    #[test]
+    fn test_context_window() {
+        assert_eq!(AgentModel::ClaudeSonnet45.context_window(), 200_000);
+        assert_eq!(AgentModel::NovaPro.context_window(), 300_000);
+        assert_eq!(AgentModel::NovaMicro.context_window(), 128_000);
+    }
+
+    #[test]
+    fn test_preferences_round_trip() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("awsdash/agent_models.json");
+
+        let mut preferences = AgentModelPreferences::default();
+        *preferences.get_mut(AgentModelRole::Worker) = AgentModel::ClaudeHaiku45;
+        preferences.page_builder = AgentModel::ClaudeOpus45;
+        preferences.save_to(&path).unwrap();
+
+        let loaded = AgentModelPreferences::load_from(&path).unwrap();
+        assert_eq!(loaded, preferences);
+        assert_eq!(
+            loaded.for_agent_type(&AgentType::TaskManager),
+            AgentModel::ClaudeSonnet45
+        );
+        assert_eq!(
+            loaded.for_agent_type(&AgentType::TaskWorker {
+                parent_id: crate::app::agent_framework::AgentId::new(),
+            }),
+            AgentModel::ClaudeHaiku45
+        );
+
+        // Missing fields fall back to the default model
+        let partial: AgentModelPreferences =
+            serde_json::from_str(r#"{"worker": "NovaLite"}"#).unwrap();
+        assert_eq!(partial.worker, AgentModel::NovaLite);
+        assert_eq!(partial.manager, AgentModel::ClaudeSonnet45);
+    }
+
+    #[test]
+    fn test_model_availability() {
+        let availability = ModelAvailability::from_model_ids(
+            "us-east-1",
+            vec![
+                "amazon.nova-pro-v1:0".to_string(),
+                "us.anthropic.claude-sonnet-4-5-20250929-v1:0".to_string(),
+                "xanthropic.claude-haiku-4-5-20251001-v1:0".to_string(),
+            ],
+        );
+
+        assert!(availability.is_available(AgentModel::NovaPro));
+        assert!(availability.is_available(AgentModel::ClaudeSonnet45));
+        assert!(!availability.is_available(AgentModel::ClaudeHaiku45));
+        assert_eq!(
+            availability.available_models(),
+            vec![AgentModel::ClaudeSonnet45, AgentModel::NovaPro]
+        );
+    }
+
     #[test]
     fn test_display_trait() {
         assert_eq!(
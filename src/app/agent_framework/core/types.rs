@@ -180,7 +180,7 @@ impl fmt::Display for AgentType {
 ///
 /// Controls the verbosity of stood library debug output captured in agent logs.
 /// Higher levels include all lower level messages.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub enum StoodLogLevel {
     /// No stood traces captured
     Off,
@@ -0,0 +1,404 @@
+//! Cost Ledger
+//!
+//! Token usage per model for each conversation, saved to disk as it grows so
+//! the cost report covers earlier sessions. Usage is stored as tokens, not
+//! dollars, so cost estimates always use the current [`PriceTable`].
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::pricing::PriceTable;
+use crate::app::agent_framework::AgentModel;
+
+/// Tokens used on one model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelUsage {
+    pub model: AgentModel,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+}
+
+impl ModelUsage {
+    pub fn new(model: AgentModel) -> Self {
+        Self {
+            model,
+            input_tokens: 0,
+            output_tokens: 0,
+        }
+    }
+
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens + self.output_tokens
+    }
+
+    /// Estimated cost in US dollars
+    pub fn cost(&self, prices: &PriceTable) -> f64 {
+        prices.cost(self.model, self.input_tokens, self.output_tokens)
+    }
+}
+
+/// Token usage of one agent conversation, split by model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConversationCost {
+    pub conversation_id: String,
+    pub agent_id: String,
+    pub agent_name: String,
+    pub agent_type: String,
+    /// Manager that spawned this agent, for workers
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    pub started_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub usage: Vec<ModelUsage>,
+}
+
+impl ConversationCost {
+    /// Start an empty conversation record
+    pub fn new(
+        agent_id: impl Into<String>,
+        agent_name: impl Into<String>,
+        agent_type: impl Into<String>,
+        parent_id: Option<String>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            conversation_id: uuid::Uuid::new_v4().to_string(),
+            agent_id: agent_id.into(),
+            agent_name: agent_name.into(),
+            agent_type: agent_type.into(),
+            parent_id,
+            started_at: now,
+            updated_at: now,
+            usage: Vec::new(),
+        }
+    }
+
+    /// Add tokens used on `model`
+    pub fn add_usage(&mut self, model: AgentModel, input_tokens: u64, output_tokens: u64) {
+        let index = match self.usage.iter().position(|u| u.model == model) {
+            Some(index) => index,
+            None => {
+                self.usage.push(ModelUsage::new(model));
+                self.usage.len() - 1
+            }
+        };
+        self.usage[index].input_tokens += input_tokens;
+        self.usage[index].output_tokens += output_tokens;
+        self.updated_at = Utc::now();
+    }
+
+    pub fn input_tokens(&self) -> u64 {
+        self.usage.iter().map(|u| u.input_tokens).sum()
+    }
+
+    pub fn output_tokens(&self) -> u64 {
+        self.usage.iter().map(|u| u.output_tokens).sum()
+    }
+
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens() + self.output_tokens()
+    }
+
+    /// Whether no tokens were used yet
+    pub fn is_empty(&self) -> bool {
+        self.total_tokens() == 0
+    }
+
+    /// Estimated cost in US dollars
+    pub fn cost(&self, prices: &PriceTable) -> f64 {
+        self.usage.iter().map(|u| u.cost(prices)).sum()
+    }
+}
+
+/// Turns the cumulative token usage reported for a conversation into
+/// per-model usage and saves it
+///
+/// The model is given with each update, so usage after a model switch is
+/// counted against the new model.
+#[derive(Debug, Clone)]
+pub struct CostTracker {
+    conversation: ConversationCost,
+    /// Cumulative input tokens already counted
+    counted_input: u64,
+    /// Cumulative output tokens already counted
+    counted_output: u64,
+    store: Option<CostStore>,
+}
+
+impl CostTracker {
+    /// Track `conversation`, saving to the default [`CostStore`]
+    pub fn new(conversation: ConversationCost) -> Self {
+        Self {
+            conversation,
+            counted_input: 0,
+            counted_output: 0,
+            store: CostStore::default_location(),
+        }
+    }
+
+    /// Save to `store` instead (None = keep in memory only)
+    pub fn with_store(mut self, store: Option<CostStore>) -> Self {
+        self.store = store;
+        self
+    }
+
+    /// The conversation being tracked
+    pub fn conversation(&self) -> &ConversationCost {
+        &self.conversation
+    }
+
+    /// Count the cumulative usage reported so far against `model`
+    ///
+    /// Returns true when new usage was recorded. A drop in reported usage
+    /// means the usage counter restarted, so counting starts over.
+    pub fn update(
+        &mut self,
+        agent_name: &str,
+        model: AgentModel,
+        input_tokens: u64,
+        output_tokens: u64,
+    ) -> bool {
+        if input_tokens < self.counted_input || output_tokens < self.counted_output {
+            self.counted_input = 0;
+            self.counted_output = 0;
+        }
+        let new_input = input_tokens - self.counted_input;
+        let new_output = output_tokens - self.counted_output;
+        if new_input == 0 && new_output == 0 {
+            return false;
+        }
+
+        self.counted_input = input_tokens;
+        self.counted_output = output_tokens;
+        self.conversation.agent_name = agent_name.to_string();
+        self.conversation.add_usage(model, new_input, new_output);
+
+        if let Some(store) = &self.store {
+            if let Err(e) = store.save(&self.conversation) {
+                log::warn!("Failed to save conversation cost: {:#}", e);
+            }
+        }
+        true
+    }
+
+    /// Start a new conversation record (after the conversation is cleared)
+    pub fn start_new_conversation(&mut self) {
+        let previous = &self.conversation;
+        self.conversation = ConversationCost::new(
+            previous.agent_id.clone(),
+            previous.agent_name.clone(),
+            previous.agent_type.clone(),
+            previous.parent_id.clone(),
+        );
+        self.counted_input = 0;
+        self.counted_output = 0;
+    }
+}
+
+/// On-disk store of conversation costs, one JSON file per conversation
+#[derive(Debug, Clone)]
+pub struct CostStore {
+    directory: PathBuf,
+}
+
+impl CostStore {
+    /// Create a store rooted at `directory`
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Store in the application data directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(dirs::data_local_dir()?.join("awsdash/costs")))
+    }
+
+    /// Directory holding cost files
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Write (or overwrite) the file for `conversation`
+    pub fn save(&self, conversation: &ConversationCost) -> Result<()> {
+        fs::create_dir_all(&self.directory).with_context(|| {
+            format!(
+                "Failed to create cost directory {}",
+                self.directory.display()
+            )
+        })?;
+        let path = self
+            .directory
+            .join(format!("{}.json", conversation.conversation_id));
+        let json = serde_json::to_string(conversation)?;
+        fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// All conversations, newest first; unreadable files are skipped
+    pub fn list(&self) -> Vec<ConversationCost> {
+        let Ok(entries) = fs::read_dir(&self.directory) else {
+            return Vec::new(); // Nothing recorded yet
+        };
+
+        let mut conversations: Vec<ConversationCost> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("json"))
+            .filter_map(|path| {
+                let parsed = fs::read_to_string(&path)
+                    .map_err(anyhow::Error::from)
+                    .and_then(|json| Ok(serde_json::from_str(&json)?));
+                match parsed {
+                    Ok(conversation) => Some(conversation),
+                    Err(e) => {
+                        tracing::warn!("Skipping unreadable cost file {}: {}", path.display(), e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        conversations.sort_by(|a, b| b.started_at.cmp(&a.started_at));
+        conversations
+    }
+}
+
+/// Totals over a set of conversations, for the cost report
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CostSummary {
+    pub conversation_count: usize,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub cost: f64,
+    /// Usage and cost per model, most expensive first
+    pub by_model: Vec<(ModelUsage, f64)>,
+    /// Cost per local calendar day the conversation started, newest first
+    pub by_day: Vec<(NaiveDate, f64)>,
+}
+
+impl CostSummary {
+    pub fn from_conversations<'a>(
+        conversations: impl IntoIterator<Item = &'a ConversationCost>,
+        prices: &PriceTable,
+    ) -> Self {
+        let mut summary = Self::default();
+        let mut by_model: Vec<ModelUsage> = Vec::new();
+        let mut by_day: BTreeMap<NaiveDate, f64> = BTreeMap::new();
+
+        for conversation in conversations {
+            summary.conversation_count += 1;
+            for usage in &conversation.usage {
+                match by_model.iter_mut().find(|u| u.model == usage.model) {
+                    Some(total) => {
+                        total.input_tokens += usage.input_tokens;
+                        total.output_tokens += usage.output_tokens;
+                    }
+                    None => by_model.push(*usage),
+                }
+            }
+
+            let cost = conversation.cost(prices);
+            summary.input_tokens += conversation.input_tokens();
+            summary.output_tokens += conversation.output_tokens();
+            summary.cost += cost;
+            let day = conversation
+                .started_at
+                .with_timezone(&chrono::Local)
+                .date_naive();
+            *by_day.entry(day).or_default() += cost;
+        }
+
+        summary.by_model = by_model
+            .into_iter()
+            .map(|usage| (usage, usage.cost(prices)))
+            .collect();
+        summary.by_model.sort_by(|a, b| b.1.total_cmp(&a.1));
+        summary.by_day = by_day.into_iter().rev().collect();
+        summary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_tracker_splits_usage_by_model() {
+        let conversation = ConversationCost::new("agent-1", "Agent 1", "Task Manager", None);
+        let mut tracker = CostTracker::new(conversation).with_store(None);
+
+        assert!(tracker.update("Agent 1", AgentModel::ClaudeSonnet45, 10_000, 500));
+        assert!(!tracker.update("Agent 1", AgentModel::ClaudeSonnet45, 10_000, 500));
+        // Switched model: only the new usage counts against Haiku
+        assert!(tracker.update("Renamed", AgentModel::ClaudeHaiku45, 15_000, 800));
+
+        let conversation = tracker.conversation();
+        assert_eq!(conversation.agent_name, "Renamed");
+        assert_eq!(
+            conversation.usage,
+            vec![
+                ModelUsage {
+                    model: AgentModel::ClaudeSonnet45,
+                    input_tokens: 10_000,
+                    output_tokens: 500,
+                },
+                ModelUsage {
+                    model: AgentModel::ClaudeHaiku45,
+                    input_tokens: 5_000,
+                    output_tokens: 300,
+                },
+            ]
+        );
+
+        // Sonnet: 10K * $3 + 500 * $15; Haiku: 5K * $1 + 300 * $5 (per million)
+        let cost = conversation.cost(&PriceTable::default());
+        assert!((cost - 0.044).abs() < 1e-9);
+
+        // Usage counter restarted after the conversation was cleared
+        tracker.start_new_conversation();
+        assert!(tracker.conversation().is_empty());
+        assert!(tracker.update("Renamed", AgentModel::ClaudeHaiku45, 1_000, 100));
+        assert_eq!(tracker.conversation().total_tokens(), 1_100);
+    }
+
+    #[test]
+    fn test_store_and_summary() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = CostStore::new(temp_dir.path().to_path_buf());
+
+        let mut manager = ConversationCost::new("agent-1", "Agent 1", "Task Manager", None);
+        manager.add_usage(AgentModel::ClaudeSonnet45, 100_000, 10_000);
+        let mut worker = ConversationCost::new(
+            "agent-2",
+            "Task Worker 1",
+            "Task Worker",
+            Some("agent-1".to_string()),
+        );
+        worker.started_at = manager.started_at + chrono::Duration::minutes(1);
+        worker.add_usage(AgentModel::NovaLite, 1_000_000, 100_000);
+        worker.add_usage(AgentModel::ClaudeSonnet45, 100_000, 0);
+        store.save(&manager).unwrap();
+        store.save(&worker).unwrap();
+        fs::write(temp_dir.path().join("broken.json"), "{").unwrap();
+
+        let conversations = store.list();
+        assert_eq!(conversations.len(), 2);
+        assert_eq!(conversations[0], worker);
+
+        let summary = CostSummary::from_conversations(&conversations, &PriceTable::default());
+        assert_eq!(summary.conversation_count, 2);
+        assert_eq!(summary.input_tokens, 1_200_000);
+        assert_eq!(summary.by_model.len(), 2);
+        // Sonnet: 200K * $3 + 10K * $15 = $0.75; Nova Lite: $0.06 + $0.024
+        assert_eq!(summary.by_model[0].0.model, AgentModel::ClaudeSonnet45);
+        assert!((summary.by_model[0].1 - 0.75).abs() < 1e-9);
+        assert!((summary.cost - 0.834).abs() < 1e-9);
+        assert!(!summary.by_day.is_empty());
+    }
+}
@@ -0,0 +1,15 @@
+//! Cost Tracking
+//!
+//! Records token usage per model for each agent conversation and estimates
+//! its cost in US dollars.
+//!
+//! - [`PriceTable`]: per-model prices, built-in or overridden by the user
+//! - [`CostTracker`]: per-agent tracker fed with the usage reported by the model
+//! - [`CostStore`]: saved conversation costs for the historical cost report
+//! - [`CostSummary`]: totals per model and per day
+
+pub mod ledger;
+pub mod pricing;
+
+pub use ledger::*;
+pub use pricing::*;
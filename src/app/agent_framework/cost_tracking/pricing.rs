@@ -0,0 +1,199 @@
+//! Model Pricing
+//!
+//! Bedrock on-demand prices per model, used to estimate what a conversation
+//! cost. The built-in prices are us-east-1 list prices; users can override
+//! them in `{config_dir}/awsdash/model_prices.json` (for other regions,
+//! discounts, or price changes). Prompt caching discounts are not modelled,
+//! so estimates err on the high side.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::app::agent_framework::AgentModel;
+
+/// Price of one model in US dollars per million tokens
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModelPrice {
+    pub input_per_million: f64,
+    pub output_per_million: f64,
+}
+
+impl ModelPrice {
+    pub fn new(input_per_million: f64, output_per_million: f64) -> Self {
+        Self {
+            input_per_million,
+            output_per_million,
+        }
+    }
+
+    /// Built-in on-demand price for `model`
+    pub fn builtin(model: AgentModel) -> Self {
+        match model {
+            AgentModel::ClaudeSonnet45 => Self::new(3.0, 15.0),
+            AgentModel::ClaudeHaiku45 => Self::new(1.0, 5.0),
+            AgentModel::ClaudeOpus45 => Self::new(5.0, 25.0),
+            AgentModel::NovaPro => Self::new(0.8, 3.2),
+            AgentModel::NovaLite => Self::new(0.06, 0.24),
+            AgentModel::NovaMicro => Self::new(0.035, 0.14),
+        }
+    }
+
+    /// Cost in US dollars of the given token counts
+    pub fn cost(&self, input_tokens: u64, output_tokens: u64) -> f64 {
+        (input_tokens as f64 * self.input_per_million
+            + output_tokens as f64 * self.output_per_million)
+            / 1_000_000.0
+    }
+}
+
+/// Prices used for cost estimates: built-in prices plus user overrides
+///
+/// Only overrides are stored, so built-in price updates still reach users
+/// who never changed a price.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PriceTable {
+    #[serde(default)]
+    overrides: HashMap<AgentModel, ModelPrice>,
+}
+
+impl PriceTable {
+    /// Default price table file location
+    pub fn default_location() -> Option<PathBuf> {
+        Some(dirs::config_dir()?.join("awsdash/model_prices.json"))
+    }
+
+    /// Load the price table from the default location, falling back to
+    /// built-in prices
+    pub fn load() -> Self {
+        let Some(path) = Self::default_location() else {
+            return Self::default();
+        };
+        if !path.exists() {
+            return Self::default();
+        }
+        Self::load_from(&path).unwrap_or_else(|e| {
+            log::warn!("Failed to load model prices: {:#}", e);
+            Self::default()
+        })
+    }
+
+    /// Save the price table to the default location
+    pub fn save(&self) -> Result<()> {
+        let path = Self::default_location().context("Config directory not available")?;
+        self.save_to(&path)
+    }
+
+    /// Load the price table from `path`
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Save the price table to `path`
+    pub fn save_to(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Price for `model`
+    pub fn price(&self, model: AgentModel) -> ModelPrice {
+        self.overrides
+            .get(&model)
+            .copied()
+            .unwrap_or_else(|| ModelPrice::builtin(model))
+    }
+
+    /// Override the price for `model`; setting the built-in price removes
+    /// the override
+    pub fn set_price(&mut self, model: AgentModel, price: ModelPrice) {
+        if price == ModelPrice::builtin(model) {
+            self.overrides.remove(&model);
+        } else {
+            self.overrides.insert(model, price);
+        }
+    }
+
+    /// Whether `model` uses a user-set price
+    pub fn is_overridden(&self, model: AgentModel) -> bool {
+        self.overrides.contains_key(&model)
+    }
+
+    /// Go back to built-in prices for every model
+    pub fn reset(&mut self) {
+        self.overrides.clear();
+    }
+
+    /// Cost in US dollars of the given token counts on `model`
+    pub fn cost(&self, model: AgentModel, input_tokens: u64, output_tokens: u64) -> f64 {
+        self.price(model).cost(input_tokens, output_tokens)
+    }
+}
+
+/// Format a dollar amount, with more precision for small amounts
+pub fn format_cost(cost: f64) -> String {
+    if cost < 0.01 {
+        format!("${:.4}", cost)
+    } else if cost < 100.0 {
+        format!("${:.2}", cost)
+    } else {
+        format!("${:.0}", cost)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_builtin_cost() {
+        let prices = PriceTable::default();
+        // 1M input at $3 + 100K output at $15
+        let cost = prices.cost(AgentModel::ClaudeSonnet45, 1_000_000, 100_000);
+        assert!((cost - 4.5).abs() < 1e-9);
+        assert_eq!(prices.cost(AgentModel::NovaMicro, 0, 0), 0.0);
+    }
+
+    #[test]
+    fn test_overrides_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("awsdash/model_prices.json");
+
+        let mut prices = PriceTable::default();
+        prices.set_price(AgentModel::NovaPro, ModelPrice::new(1.0, 4.0));
+        assert!(prices.is_overridden(AgentModel::NovaPro));
+        assert!(!prices.is_overridden(AgentModel::NovaLite));
+        prices.save_to(&path).unwrap();
+
+        let loaded = PriceTable::load_from(&path).unwrap();
+        assert_eq!(loaded.price(AgentModel::NovaPro), ModelPrice::new(1.0, 4.0));
+        assert_eq!(
+            loaded.price(AgentModel::ClaudeHaiku45),
+            ModelPrice::builtin(AgentModel::ClaudeHaiku45)
+        );
+
+        // Setting the built-in price again drops the override
+        prices.set_price(
+            AgentModel::NovaPro,
+            ModelPrice::builtin(AgentModel::NovaPro),
+        );
+        assert!(!prices.is_overridden(AgentModel::NovaPro));
+    }
+
+    #[test]
+    fn test_format_cost() {
+        assert_eq!(format_cost(0.00123), "$0.0012");
+        assert_eq!(format_cost(4.5), "$4.50");
+        assert_eq!(format_cost(1234.4), "$1234");
+    }
+}
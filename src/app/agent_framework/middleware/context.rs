@@ -21,8 +21,18 @@ pub struct LayerContext {
     pub agent_id: String,
     /// Type of agent (TaskManager, TaskWorker, etc.)
     pub agent_type: AgentType,
+    /// Project (Identity Center organization) the agent works in
+    pub project: Option<String>,
     /// Estimated token count for the conversation
     pub token_count: usize,
+    /// Input tokens reported by the model, summed over the conversation
+    pub input_tokens: u64,
+    /// Output tokens reported by the model, summed over the conversation
+    pub output_tokens: u64,
+    /// Input tokens of the latest model call (the current context size)
+    pub context_tokens: u64,
+    /// Context window of the agent's model (0 when unknown)
+    pub context_window: u64,
     /// Number of conversation turns (user + assistant pairs)
     pub turn_count: usize,
     /// Number of messages in the conversation
@@ -31,6 +41,10 @@ pub struct LayerContext {
     pub last_tool: Option<String>,
     /// Whether the last tool succeeded
     pub last_tool_success: bool,
+    /// Input of the tool being reported to `on_tool_start`/`on_tool_complete`
+    pub tool_input: Option<serde_json::Value>,
+    /// Size of the serialized output (or error) passed to `on_tool_complete`
+    pub tool_output_bytes: Option<usize>,
     /// Time when processing started
     pub processing_start: Option<Instant>,
     /// Custom metadata that layers can share
@@ -42,11 +56,18 @@ impl Default for LayerContext {
         Self {
             agent_id: String::new(),
             agent_type: AgentType::TaskManager,
+            project: None,
             token_count: 0,
+            input_tokens: 0,
+            output_tokens: 0,
+            context_tokens: 0,
+            context_window: 0,
             turn_count: 0,
             message_count: 0,
             last_tool: None,
             last_tool_success: true,
+            tool_input: None,
+            tool_output_bytes: None,
             processing_start: None,
             metadata: Arc::new(Mutex::new(HashMap::new())),
         }
@@ -74,6 +95,20 @@ impl LayerContext {
         self
     }
 
+    /// Set the token usage reported by the model
+    pub fn with_token_usage(mut self, input: u64, output: u64, context: u64) -> Self {
+        self.input_tokens = input;
+        self.output_tokens = output;
+        self.context_tokens = context;
+        self
+    }
+
+    /// Set the model's context window
+    pub fn with_context_window(mut self, tokens: u64) -> Self {
+        self.context_window = tokens;
+        self
+    }
+
     /// Set the turn count
     pub fn with_turn_count(mut self, count: usize) -> Self {
         self.turn_count = count;
@@ -93,6 +128,18 @@ impl LayerContext {
         self
     }
 
+    /// Set the input of the tool being reported
+    pub fn with_tool_input(mut self, input: serde_json::Value) -> Self {
+        self.tool_input = Some(input);
+        self
+    }
+
+    /// Set the output size of the tool being reported
+    pub fn with_tool_output_bytes(mut self, bytes: usize) -> Self {
+        self.tool_output_bytes = Some(bytes);
+        self
+    }
+
     /// Mark processing as started
     pub fn with_processing_start(mut self) -> Self {
         self.processing_start = Some(Instant::now());
@@ -127,6 +174,23 @@ impl LayerContext {
         self.turn_count > threshold
     }
 
+    /// Fraction of the context window in use
+    ///
+    /// Uses the reported context size, falling back to the estimated token
+    /// count before the model has reported usage. None when the context
+    /// window is unknown.
+    pub fn context_usage(&self) -> Option<f64> {
+        if self.context_window == 0 {
+            return None;
+        }
+        let tokens = if self.context_tokens > 0 {
+            self.context_tokens
+        } else {
+            self.token_count as u64
+        };
+        Some(tokens as f64 / self.context_window as f64)
+    }
+
     /// Estimate tokens from a message (rough approximation)
     pub fn estimate_tokens(text: &str) -> usize {
         // Rough approximation: ~4 characters per token for English
@@ -139,7 +203,12 @@ impl LayerContext {
 pub struct LayerContextBuilder {
     agent_id: Option<String>,
     agent_type: Option<AgentType>,
+    project: Option<String>,
     token_count: usize,
+    input_tokens: u64,
+    output_tokens: u64,
+    context_tokens: u64,
+    context_window: u64,
     turn_count: usize,
     message_count: usize,
     last_tool: Option<String>,
@@ -164,12 +233,32 @@ impl LayerContextBuilder {
         self
     }
 
+    /// Set the project
+    pub fn project(mut self, project: impl Into<String>) -> Self {
+        self.project = Some(project.into());
+        self
+    }
+
     /// Set the token count
     pub fn token_count(mut self, count: usize) -> Self {
         self.token_count = count;
         self
     }
 
+    /// Set the token usage reported by the model
+    pub fn token_usage(mut self, input: u64, output: u64, context: u64) -> Self {
+        self.input_tokens = input;
+        self.output_tokens = output;
+        self.context_tokens = context;
+        self
+    }
+
+    /// Set the model's context window
+    pub fn context_window(mut self, tokens: u64) -> Self {
+        self.context_window = tokens;
+        self
+    }
+
     /// Set the turn count
     pub fn turn_count(mut self, count: usize) -> Self {
         self.turn_count = count;
@@ -194,11 +283,18 @@ impl LayerContextBuilder {
         LayerContext {
             agent_id: self.agent_id.unwrap_or_default(),
             agent_type: self.agent_type.unwrap_or(AgentType::TaskManager),
+            project: self.project,
             token_count: self.token_count,
+            input_tokens: self.input_tokens,
+            output_tokens: self.output_tokens,
+            context_tokens: self.context_tokens,
+            context_window: self.context_window,
             turn_count: self.turn_count,
             message_count: self.message_count,
             last_tool: self.last_tool,
             last_tool_success: self.last_tool_success,
+            tool_input: None,
+            tool_output_bytes: None,
             processing_start: None,
             metadata: Arc::new(Mutex::new(HashMap::new())),
         }
@@ -259,6 +355,17 @@ mod tests {
         assert!(!ctx.last_tool_success);
     }
 
+    #[test]
+    fn test_layer_context_tool_call() {
+        let ctx = LayerContext::new("agent", AgentType::TaskManager)
+            .with_tool_input(serde_json::json!({ "path": "a.txt" }))
+            .with_tool_output_bytes(42);
+
+        assert_eq!(ctx.tool_input, Some(serde_json::json!({ "path": "a.txt" })));
+        assert_eq!(ctx.tool_output_bytes, Some(42));
+        assert!(LayerContext::default().tool_input.is_none());
+    }
+
     #[test]
     fn test_layer_context_metadata() {
         let ctx = LayerContext::default();
@@ -284,6 +391,18 @@ mod tests {
         assert!(!ctx.many_turns(20));
     }
 
+    #[test]
+    fn test_context_usage() {
+        let ctx = LayerContext::default().with_token_count(50_000);
+        assert!(ctx.context_usage().is_none());
+
+        let ctx = ctx.with_context_window(200_000);
+        assert_eq!(ctx.context_usage(), Some(0.25));
+
+        let ctx = ctx.with_token_usage(300_000, 4_000, 150_000);
+        assert_eq!(ctx.context_usage(), Some(0.75));
+    }
+
     #[test]
     fn test_estimate_tokens() {
         // Rough approximation: ~4 chars per token
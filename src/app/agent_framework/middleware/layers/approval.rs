@@ -0,0 +1,328 @@
+//! Approval Gate Layer
+//!
+//! Human-in-the-loop approval for tool calls that would change AWS state.
+//! Tools and V8 binding operations are tagged with a [`ToolRiskLevel`] in the
+//! tool registry. Tool calls are held by
+//! [`ToolApprovalMiddleware`](crate::app::agent_framework::middleware::ToolApprovalMiddleware)
+//! before they run; a V8 binding calls [`request_tool_approval`] itself, since
+//! one script can make many calls. Either way the call waits until the user
+//! approves or denies its exact parameters in the approval dialog.
+//!
+//! [`ApprovalGateLayer`] sets, per agent, the lowest risk level that needs
+//! approval. Workers use their manager's setting. Agents without the layer
+//! still need approval for mutating calls.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Duration;
+
+use crate::app::agent_framework::middleware::{ConversationLayer, LayerContext, LayerResult};
+use crate::app::agent_framework::{
+    get_current_agent_id, get_current_agent_type, get_tool_risk, AgentId, ToolRiskLevel,
+};
+
+/// How long a call waits for a decision before it is denied
+pub const APPROVAL_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Risk level that needs approval when an agent has no [`ApprovalGateLayer`]
+const DEFAULT_THRESHOLD: ToolRiskLevel = ToolRiskLevel::Mutating;
+
+/// A tool call waiting for the user's decision
+#[derive(Debug, Clone)]
+pub struct ApprovalRequest {
+    pub id: u64,
+    /// Agent making the call (None outside agent threads)
+    pub agent_id: Option<AgentId>,
+    /// Tool or V8 binding operation, e.g. `s3.deleteObject`
+    pub operation: String,
+    pub risk: ToolRiskLevel,
+    /// Exact parameters of the API call
+    pub parameters: serde_json::Value,
+    pub requested_at: DateTime<Utc>,
+}
+
+/// The user's answer to an [`ApprovalRequest`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApprovalDecision {
+    Approved,
+    /// Denied, with the reason passed back to the agent
+    Denied(String),
+}
+
+type ApprovalChannel = (
+    Sender<ApprovalRequest>,
+    Arc<Mutex<Receiver<ApprovalRequest>>>,
+);
+
+static APPROVAL_CHANNEL: OnceLock<ApprovalChannel> = OnceLock::new();
+static APPROVAL_RESPONSES: OnceLock<Mutex<HashMap<u64, Sender<ApprovalDecision>>>> =
+    OnceLock::new();
+static APPROVAL_THRESHOLDS: OnceLock<Mutex<HashMap<String, ToolRiskLevel>>> = OnceLock::new();
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Held by tests that answer approval requests, which share one channel
+#[cfg(test)]
+pub(crate) static APPROVAL_TEST_LOCK: Mutex<()> = Mutex::new(());
+
+fn approval_channel() -> &'static ApprovalChannel {
+    APPROVAL_CHANNEL.get_or_init(|| {
+        let (sender, receiver) = channel();
+        (sender, Arc::new(Mutex::new(receiver)))
+    })
+}
+
+fn approval_responses() -> &'static Mutex<HashMap<u64, Sender<ApprovalDecision>>> {
+    APPROVAL_RESPONSES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn approval_thresholds() -> &'static Mutex<HashMap<String, ToolRiskLevel>> {
+    APPROVAL_THRESHOLDS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Lowest risk level that needs approval for an agent; a worker passes its
+/// manager as `parent_id`, whose setting comes first
+fn threshold_for(agent_id: Option<AgentId>, parent_id: Option<AgentId>) -> ToolRiskLevel {
+    let thresholds = approval_thresholds().lock().unwrap();
+    parent_id
+        .and_then(|id| thresholds.get(&id.to_string()).copied())
+        .or_else(|| agent_id.and_then(|id| thresholds.get(&id.to_string()).copied()))
+        .unwrap_or(DEFAULT_THRESHOLD)
+}
+
+/// Lowest risk level that needs approval for the agent on this thread
+fn current_threshold() -> ToolRiskLevel {
+    threshold_for(
+        get_current_agent_id(),
+        get_current_agent_type().and_then(|agent_type| agent_type.parent_id()),
+    )
+}
+
+/// Risk of a call to `operation` by an agent, `None` if it runs without approval
+pub fn approval_risk(
+    operation: &str,
+    agent_id: Option<AgentId>,
+    parent_id: Option<AgentId>,
+) -> Option<ToolRiskLevel> {
+    let risk = get_tool_risk(operation);
+    (risk != ToolRiskLevel::ReadOnly && risk >= threshold_for(agent_id, parent_id)).then_some(risk)
+}
+
+/// Ask the user to approve a call to `operation` with `parameters`
+///
+/// Read-only operations (and those below the agent's threshold) return
+/// immediately. Otherwise the calling thread blocks until the user decides
+/// or [`APPROVAL_TIMEOUT`] passes. Returns `Err` with a message for the
+/// agent when the call must not run.
+pub fn request_tool_approval(operation: &str, parameters: serde_json::Value) -> Result<(), String> {
+    request_agent_approval(
+        get_current_agent_id(),
+        get_current_agent_type().and_then(|agent_type| agent_type.parent_id()),
+        operation,
+        parameters,
+    )
+}
+
+/// [`request_tool_approval`] for a call made by `agent_id` from any thread
+pub fn request_agent_approval(
+    agent_id: Option<AgentId>,
+    parent_id: Option<AgentId>,
+    operation: &str,
+    parameters: serde_json::Value,
+) -> Result<(), String> {
+    match approval_risk(operation, agent_id, parent_id) {
+        Some(risk) => request_approval(agent_id, operation, risk, parameters, APPROVAL_TIMEOUT),
+        None => Ok(()),
+    }
+}
+
+fn request_approval(
+    agent_id: Option<AgentId>,
+    operation: &str,
+    risk: ToolRiskLevel,
+    parameters: serde_json::Value,
+    timeout: Duration,
+) -> Result<(), String> {
+    let id = NEXT_REQUEST_ID.fetch_add(1, Ordering::SeqCst);
+    let (response_sender, response_receiver) = channel();
+    approval_responses()
+        .lock()
+        .unwrap()
+        .insert(id, response_sender);
+
+    let request = ApprovalRequest {
+        id,
+        agent_id,
+        operation: operation.to_string(),
+        risk,
+        parameters,
+        requested_at: Utc::now(),
+    };
+    tracing::info!(
+        target: "agent::approval",
+        request_id = id,
+        operation,
+        risk = %risk,
+        "Waiting for approval"
+    );
+    if approval_channel().0.send(request).is_err() {
+        approval_responses().lock().unwrap().remove(&id);
+        return Err("Approval dialog is not available".to_string());
+    }
+
+    let decision = response_receiver.recv_timeout(timeout);
+    approval_responses().lock().unwrap().remove(&id);
+    match decision {
+        Ok(ApprovalDecision::Approved) => {
+            tracing::info!(target: "agent::approval", request_id = id, "Approved");
+            Ok(())
+        }
+        Ok(ApprovalDecision::Denied(reason)) => {
+            tracing::info!(target: "agent::approval", request_id = id, %reason, "Denied");
+            Err(format!("The user denied {}: {}", operation, reason))
+        }
+        Err(_) => Err(format!(
+            "No approval for {} within {} minutes; the call was not made",
+            operation,
+            timeout.as_secs() / 60
+        )),
+    }
+}
+
+/// New approval requests since the last call (for the approval dialog)
+pub fn take_approval_requests() -> Vec<ApprovalRequest> {
+    match approval_channel().1.lock() {
+        Ok(receiver) => receiver.try_iter().collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Answer an approval request; returns false if the call is no longer waiting
+pub fn respond_to_approval(request_id: u64, decision: ApprovalDecision) -> bool {
+    let sender = approval_responses().lock().unwrap().remove(&request_id);
+    match sender {
+        Some(sender) => sender.send(decision).is_ok(),
+        None => false,
+    }
+}
+
+/// Middleware layer that sets which tool calls need the user's approval
+///
+/// The threshold applies to the agent the layer belongs to and to the
+/// workers it starts.
+#[derive(Debug, Clone)]
+pub struct ApprovalGateLayer {
+    threshold: ToolRiskLevel,
+}
+
+impl ApprovalGateLayer {
+    /// Require approval for calls at or above `threshold`
+    pub fn new(threshold: ToolRiskLevel) -> Self {
+        Self { threshold }
+    }
+
+    /// Require approval for all mutating and destructive calls
+    pub fn with_defaults() -> Self {
+        Self::new(DEFAULT_THRESHOLD)
+    }
+
+    pub fn threshold(&self) -> ToolRiskLevel {
+        self.threshold
+    }
+}
+
+impl Default for ApprovalGateLayer {
+    fn default() -> Self {
+        Self::with_defaults()
+    }
+}
+
+impl ConversationLayer for ApprovalGateLayer {
+    fn name(&self) -> &str {
+        "ApprovalGate"
+    }
+
+    fn on_pre_send(&self, message: &str, ctx: &LayerContext) -> LayerResult<String> {
+        // Tools run on the agent's thread and look the threshold up by agent ID
+        approval_thresholds()
+            .lock()
+            .unwrap()
+            .insert(ctx.agent_id.clone(), self.threshold);
+        Ok(message.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::agent_framework::{
+        clear_current_agent_id, register_tool_risk, set_current_agent_id, AgentType,
+    };
+
+    #[test]
+    fn test_read_only_calls_pass() {
+        assert!(request_tool_approval("test.approval.read", serde_json::json!({})).is_ok());
+    }
+
+    #[test]
+    fn test_threshold_from_layer() {
+        register_tool_risk("test.approval.put", ToolRiskLevel::Mutating);
+        let agent_id = AgentId::new();
+        set_current_agent_id(agent_id);
+
+        // Only destructive calls need approval for this agent
+        let layer = ApprovalGateLayer::new(ToolRiskLevel::Destructive);
+        let ctx = LayerContext::new(agent_id.to_string(), AgentType::TaskManager);
+        layer.on_pre_send("hello", &ctx).unwrap();
+        assert_eq!(current_threshold(), ToolRiskLevel::Destructive);
+        assert!(request_tool_approval("test.approval.put", serde_json::json!({})).is_ok());
+
+        clear_current_agent_id();
+        assert_eq!(current_threshold(), DEFAULT_THRESHOLD);
+    }
+
+    #[test]
+    fn test_approve_and_deny() {
+        let _serial = APPROVAL_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let responder = std::thread::spawn(|| {
+            let mut answered = 0;
+            while answered < 2 {
+                for request in take_approval_requests() {
+                    let decision = if request.operation == "test.approval.approve" {
+                        ApprovalDecision::Approved
+                    } else {
+                        ApprovalDecision::Denied("not today".to_string())
+                    };
+                    assert!(respond_to_approval(request.id, decision));
+                    answered += 1;
+                }
+                std::thread::sleep(Duration::from_millis(10));
+            }
+        });
+
+        let parameters = serde_json::json!({ "bucket": "logs" });
+        assert!(request_approval(
+            None,
+            "test.approval.approve",
+            ToolRiskLevel::Mutating,
+            parameters.clone(),
+            Duration::from_secs(5)
+        )
+        .is_ok());
+        let denied = request_approval(
+            None,
+            "test.approval.deny",
+            ToolRiskLevel::Destructive,
+            parameters,
+            Duration::from_secs(5),
+        );
+        assert!(denied.unwrap_err().contains("not today"));
+        responder.join().unwrap();
+    }
+}
@@ -5,15 +5,35 @@
 //! ## Available Layers
 //!
 //! - [`TokenTrackingLayer`] - Tracks token usage and can inject summaries
+//! - [`TokenBudgetLayer`] - Warns and requests summaries as the context window fills
 //! - [`AutoAnalysisLayer`] - Automatically triggers follow-up analysis
 //! - [`LoggingLayer`] - Logs all message flow for debugging
+//! - [`ApprovalGateLayer`] - Pauses mutating tool calls for user approval
+//! - [`GuardrailLayer`] - Masks or blocks secrets in outgoing messages
+//! - [`ToolTimelineLayer`] - Records tool invocations for the timeline panel
 
 #![warn(clippy::all, rust_2018_idioms)]
 
+mod approval;
 mod auto_analysis;
+mod guardrail;
 mod logging;
+mod token_budget;
 mod token_tracking;
+mod tool_timeline;
 
+#[cfg(test)]
+pub(crate) use approval::APPROVAL_TEST_LOCK;
+pub use approval::{
+    approval_risk, request_agent_approval, request_tool_approval, respond_to_approval,
+    take_approval_requests, ApprovalDecision, ApprovalGateLayer, ApprovalRequest, APPROVAL_TIMEOUT,
+};
 pub use auto_analysis::AutoAnalysisLayer;
+pub use guardrail::{
+    load_policy, mask_secrets, record_audit, scan_secrets, GuardrailAction, GuardrailAuditEntry,
+    GuardrailLayer, GuardrailMode, GuardrailPolicy, GuardrailStore, SecretKind, SecretMatch,
+};
 pub use logging::LoggingLayer;
+pub use token_budget::{TokenBudgetConfig, TokenBudgetLayer};
 pub use token_tracking::TokenTrackingLayer;
+pub use tool_timeline::{ToolTimeline, ToolTimelineEntry, ToolTimelineLayer};
@@ -0,0 +1,336 @@
+//! Token Budget Layer
+//!
+//! Middleware layer that enforces a token budget per conversation using the
+//! token usage reported by the model (see [`LayerContext::context_tokens`]).
+//! It warns as the context fills up, asks the agent to summarize the
+//! conversation before the model limit is reached, and can block new
+//! messages once a total token budget is spent.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+use crate::app::agent_framework::middleware::{
+    ConversationLayer, LayerContext, LayerError, LayerResult, PostResponseAction,
+};
+
+/// Configuration for token budget enforcement
+#[derive(Debug, Clone)]
+pub struct TokenBudgetConfig {
+    /// Context usage fractions (0.0-1.0) at which a warning is shown, ascending
+    pub warn_thresholds: Vec<f64>,
+    /// Context usage fraction at which summarization is requested (None = never)
+    pub summarize_threshold: Option<f64>,
+    /// Prompt sent to the agent to summarize the conversation
+    pub summary_prompt: String,
+    /// Total input + output tokens allowed per conversation (None = unlimited)
+    pub max_total_tokens: Option<u64>,
+}
+
+impl Default for TokenBudgetConfig {
+    fn default() -> Self {
+        Self {
+            warn_thresholds: vec![0.5, 0.75, 0.9],
+            summarize_threshold: Some(0.85),
+            summary_prompt: "[Context Summary]\nThis conversation is close to the model's context limit. Summarize it so far: the user's goals, the key findings with resource IDs and numbers, and any open questions. Then repeat your previous answer in full so nothing is lost. Later turns will rely on this summary rather than earlier messages.".to_string(),
+            max_total_tokens: None,
+        }
+    }
+}
+
+impl TokenBudgetConfig {
+    /// Set the warning thresholds (fractions of the context window)
+    pub fn with_warn_thresholds(mut self, thresholds: Vec<f64>) -> Self {
+        self.warn_thresholds = thresholds;
+        self.warn_thresholds.sort_by(|a, b| a.total_cmp(b));
+        self
+    }
+
+    /// Set the summarization threshold (None disables summarization)
+    pub fn with_summarize_threshold(mut self, threshold: Option<f64>) -> Self {
+        self.summarize_threshold = threshold;
+        self
+    }
+
+    /// Set the summarization prompt
+    pub fn with_summary_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.summary_prompt = prompt.into();
+        self
+    }
+
+    /// Set the total token budget per conversation
+    pub fn with_max_total_tokens(mut self, max: Option<u64>) -> Self {
+        self.max_total_tokens = max;
+        self
+    }
+}
+
+/// Token budget middleware layer
+///
+/// Tracks cumulative input/output tokens per conversation and:
+/// - Appends a warning to the response when context usage crosses a
+///   threshold (each threshold warns once)
+/// - Suppresses the response and injects the summary prompt when usage
+///   crosses the summarization threshold; the summary prompt asks the agent
+///   to repeat its answer, so nothing is hidden from the user
+/// - Aborts new messages once `max_total_tokens` is spent
+///
+/// State resets when the reported usage drops, i.e. the conversation was
+/// cleared.
+///
+/// ## Example
+///
+/// ```ignore
+/// let layer = TokenBudgetLayer::new(
+///     TokenBudgetConfig::default().with_max_total_tokens(Some(2_000_000)),
+/// );
+/// stack.add(layer);
+/// ```
+pub struct TokenBudgetLayer {
+    config: TokenBudgetConfig,
+    /// Cumulative input tokens at the last response
+    input_tokens: AtomicU64,
+    /// Cumulative output tokens at the last response
+    output_tokens: AtomicU64,
+    /// Number of warning thresholds already reported
+    warnings_shown: AtomicUsize,
+    /// Whether summarization was requested for this conversation
+    summary_requested: AtomicBool,
+}
+
+impl TokenBudgetLayer {
+    /// Create a new token budget layer
+    pub fn new(config: TokenBudgetConfig) -> Self {
+        Self {
+            config,
+            input_tokens: AtomicU64::new(0),
+            output_tokens: AtomicU64::new(0),
+            warnings_shown: AtomicUsize::new(0),
+            summary_requested: AtomicBool::new(false),
+        }
+    }
+
+    /// Create with default configuration
+    pub fn with_defaults() -> Self {
+        Self::new(TokenBudgetConfig::default())
+    }
+
+    /// Cumulative input tokens for the conversation
+    pub fn input_tokens(&self) -> u64 {
+        self.input_tokens.load(Ordering::Relaxed)
+    }
+
+    /// Cumulative output tokens for the conversation
+    pub fn output_tokens(&self) -> u64 {
+        self.output_tokens.load(Ordering::Relaxed)
+    }
+
+    /// Cumulative input + output tokens for the conversation
+    pub fn total_tokens(&self) -> u64 {
+        self.input_tokens() + self.output_tokens()
+    }
+
+    /// Reset all tracking state
+    pub fn reset(&self) {
+        self.input_tokens.store(0, Ordering::Relaxed);
+        self.output_tokens.store(0, Ordering::Relaxed);
+        self.warnings_shown.store(0, Ordering::Relaxed);
+        self.summary_requested.store(false, Ordering::Relaxed);
+    }
+
+    /// Record the usage in `ctx`, resetting first if the conversation restarted
+    fn update_usage(&self, ctx: &LayerContext) {
+        if ctx.input_tokens < self.input_tokens() || ctx.output_tokens < self.output_tokens() {
+            log::debug!("TokenBudget: Reported usage dropped, conversation was reset");
+            self.reset();
+        }
+        self.input_tokens.store(ctx.input_tokens, Ordering::Relaxed);
+        self.output_tokens
+            .store(ctx.output_tokens, Ordering::Relaxed);
+    }
+
+    /// Highest warning threshold newly crossed at `usage`, if any
+    fn take_warning(&self, usage: f64) -> Option<f64> {
+        let crossed = self
+            .config
+            .warn_thresholds
+            .iter()
+            .take_while(|threshold| usage >= **threshold)
+            .count();
+        let shown = self.warnings_shown.fetch_max(crossed, Ordering::Relaxed);
+        (crossed > shown).then(|| self.config.warn_thresholds[crossed - 1])
+    }
+}
+
+impl ConversationLayer for TokenBudgetLayer {
+    fn name(&self) -> &str {
+        "TokenBudget"
+    }
+
+    fn on_pre_send(&self, message: &str, ctx: &LayerContext) -> LayerResult<String> {
+        if let Some(max) = self.config.max_total_tokens {
+            let total = ctx.input_tokens + ctx.output_tokens;
+            if total >= max {
+                log::warn!(
+                    "TokenBudget: Budget of {} tokens spent ({} used), blocking message",
+                    max,
+                    total
+                );
+                return Err(LayerError::Abort(format!(
+                    "Token budget of {} tokens for this conversation is spent ({} used). Clear the conversation to continue.",
+                    max, total
+                )));
+            }
+        }
+
+        Ok(message.to_string())
+    }
+
+    fn on_post_response(
+        &self,
+        response: &str,
+        ctx: &LayerContext,
+    ) -> LayerResult<PostResponseAction> {
+        self.update_usage(ctx);
+
+        let Some(usage) = ctx.context_usage() else {
+            return Ok(PostResponseAction::PassThrough);
+        };
+
+        if let Some(threshold) = self.config.summarize_threshold {
+            if usage >= threshold && !self.summary_requested.swap(true, Ordering::Relaxed) {
+                log::info!(
+                    "TokenBudget: Context at {:.0}% of {} tokens, requesting summary",
+                    usage * 100.0,
+                    ctx.context_window
+                );
+                // Warnings below this level are moot once a summary is requested
+                self.take_warning(usage);
+                return Ok(PostResponseAction::SuppressAndInject(
+                    self.config.summary_prompt.clone(),
+                ));
+            }
+        }
+
+        if let Some(threshold) = self.take_warning(usage) {
+            log::warn!(
+                "TokenBudget: Context at {:.0}% of {} tokens (threshold {:.0}%)",
+                usage * 100.0,
+                ctx.context_window,
+                threshold * 100.0
+            );
+            return Ok(PostResponseAction::Modify(format!(
+                "{}\n\n_Context usage: {:.0}% of the model's {}K token limit_",
+                response,
+                usage * 100.0,
+                ctx.context_window / 1000
+            )));
+        }
+
+        Ok(PostResponseAction::PassThrough)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::agent_framework::AgentType;
+
+    fn ctx_at(context_tokens: u64) -> LayerContext {
+        LayerContext::new("test", AgentType::TaskManager)
+            .with_context_window(200_000)
+            .with_token_usage(context_tokens * 2, 1_000, context_tokens)
+    }
+
+    #[test]
+    fn test_warns_once_per_threshold() {
+        let layer =
+            TokenBudgetLayer::new(TokenBudgetConfig::default().with_summarize_threshold(None));
+
+        let action = layer.on_post_response("answer", &ctx_at(50_000)).unwrap();
+        assert!(matches!(action, PostResponseAction::PassThrough));
+
+        let action = layer.on_post_response("answer", &ctx_at(110_000)).unwrap();
+        match action {
+            PostResponseAction::Modify(text) => {
+                assert!(text.starts_with("answer"));
+                assert!(text.contains("55%"));
+            }
+            other => panic!("Expected warning, got {:?}", other),
+        }
+
+        // Same threshold does not warn again
+        let action = layer.on_post_response("answer", &ctx_at(120_000)).unwrap();
+        assert!(matches!(action, PostResponseAction::PassThrough));
+
+        // Jumping past two thresholds warns once
+        let action = layer.on_post_response("answer", &ctx_at(190_000)).unwrap();
+        assert!(matches!(action, PostResponseAction::Modify(_)));
+        let action = layer.on_post_response("answer", &ctx_at(195_000)).unwrap();
+        assert!(matches!(action, PostResponseAction::PassThrough));
+    }
+
+    #[test]
+    fn test_requests_summary_near_limit() {
+        let layer = TokenBudgetLayer::with_defaults();
+
+        let action = layer.on_post_response("answer", &ctx_at(175_000)).unwrap();
+        match action {
+            PostResponseAction::SuppressAndInject(prompt) => {
+                assert!(prompt.starts_with("[Context Summary]"));
+            }
+            other => panic!("Expected summary request, got {:?}", other),
+        }
+
+        // Only once per conversation, and lower warnings are skipped
+        let action = layer.on_post_response("summary", &ctx_at(178_000)).unwrap();
+        assert!(matches!(action, PostResponseAction::PassThrough));
+    }
+
+    #[test]
+    fn test_resets_when_conversation_cleared() {
+        let layer = TokenBudgetLayer::with_defaults();
+        layer.on_post_response("answer", &ctx_at(175_000)).unwrap();
+        assert_eq!(layer.input_tokens(), 350_000);
+
+        let action = layer.on_post_response("answer", &ctx_at(2_000)).unwrap();
+        assert!(matches!(action, PostResponseAction::PassThrough));
+        assert_eq!(layer.total_tokens(), 5_000);
+
+        let action = layer.on_post_response("answer", &ctx_at(175_000)).unwrap();
+        assert!(matches!(action, PostResponseAction::SuppressAndInject(_)));
+    }
+
+    #[test]
+    fn test_unknown_context_window_passes_through() {
+        let layer = TokenBudgetLayer::with_defaults();
+        let ctx = LayerContext::new("test", AgentType::TaskManager).with_token_count(1_000_000);
+
+        let action = layer.on_post_response("answer", &ctx).unwrap();
+        assert!(matches!(action, PostResponseAction::PassThrough));
+    }
+
+    #[test]
+    fn test_blocks_when_budget_spent() {
+        let layer = TokenBudgetLayer::new(
+            TokenBudgetConfig::default().with_max_total_tokens(Some(100_000)),
+        );
+
+        assert!(layer.on_pre_send("hello", &ctx_at(10_000)).is_ok());
+        let result = layer.on_pre_send("hello", &ctx_at(50_000));
+        assert!(matches!(result, Err(LayerError::Abort(_))));
+    }
+
+    #[test]
+    fn test_config_builder() {
+        let config = TokenBudgetConfig::default()
+            .with_warn_thresholds(vec![0.9, 0.6])
+            .with_summarize_threshold(Some(0.8))
+            .with_summary_prompt("Summarize");
+
+        assert_eq!(config.warn_thresholds, vec![0.6, 0.9]);
+        assert_eq!(config.summarize_threshold, Some(0.8));
+        assert_eq!(config.summary_prompt, "Summarize");
+        assert!(config.max_total_tokens.is_none());
+    }
+}
@@ -0,0 +1,258 @@
+//! Tool Timeline Layer
+//!
+//! Middleware layer that records every tool invocation of a conversation for
+//! the timeline panel: when it started, how long it ran, whether it succeeded,
+//! how much output it returned, and which VFS paths it wrote.
+//!
+//! Written paths come from the tool input: the `path` or `destination` of the
+//! file tools, and string literals passed to `vfs.writeFile` in JavaScript.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use crate::app::agent_framework::middleware::{ConversationLayer, LayerContext};
+
+/// Maximum entries kept per conversation; the oldest are dropped first
+const MAX_TIMELINE_ENTRIES: usize = 1000;
+
+/// One tool invocation on the timeline
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolTimelineEntry {
+    pub tool_name: String,
+    pub started_at: Instant,
+    /// None while the tool is still running
+    pub duration: Option<Duration>,
+    /// None while the tool is still running
+    pub success: Option<bool>,
+    /// Size of the output (or error) returned to the model
+    pub output_bytes: usize,
+    /// VFS paths the tool wrote
+    pub vfs_paths: Vec<String>,
+}
+
+impl ToolTimelineEntry {
+    pub fn is_running(&self) -> bool {
+        self.duration.is_none()
+    }
+
+    /// Elapsed time, counting up to `now` for a running tool
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        self.duration
+            .unwrap_or_else(|| now.saturating_duration_since(self.started_at))
+    }
+}
+
+/// Tool invocations recorded for one conversation
+///
+/// Clones share the same record, so the agent keeps one and hands another to
+/// its [`ToolTimelineLayer`].
+#[derive(Debug, Clone, Default)]
+pub struct ToolTimeline {
+    entries: Arc<Mutex<Vec<ToolTimelineEntry>>>,
+}
+
+impl ToolTimeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a tool starting
+    pub fn record_start(&self, tool_name: &str, input: Option<&Value>) {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= MAX_TIMELINE_ENTRIES {
+            entries.remove(0);
+        }
+        entries.push(ToolTimelineEntry {
+            tool_name: tool_name.to_string(),
+            started_at: Instant::now(),
+            duration: None,
+            success: None,
+            output_bytes: 0,
+            vfs_paths: input
+                .map(|input| vfs_paths_written(tool_name, input))
+                .unwrap_or_default(),
+        });
+    }
+
+    /// Record the result of the oldest running call to `tool_name`
+    pub fn record_complete(&self, tool_name: &str, success: bool, output_bytes: usize) {
+        let mut entries = self.entries.lock().unwrap();
+        let Some(entry) = entries
+            .iter_mut()
+            .find(|entry| entry.tool_name == tool_name && entry.is_running())
+        else {
+            log::debug!("ToolTimeline: completion without a recorded start: {}", tool_name);
+            return;
+        };
+        entry.duration = Some(entry.started_at.elapsed());
+        entry.success = Some(success);
+        entry.output_bytes = output_bytes;
+        if !success {
+            // A failed write leaves nothing behind
+            entry.vfs_paths.clear();
+        }
+    }
+
+    pub fn entries(&self) -> Vec<ToolTimelineEntry> {
+        self.entries.lock().unwrap().clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.lock().unwrap().is_empty()
+    }
+
+    /// Forget everything recorded so far
+    pub fn clear(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+}
+
+/// VFS paths written by a call to `tool_name` with `input`
+fn vfs_paths_written(tool_name: &str, input: &Value) -> Vec<String> {
+    let field = match tool_name {
+        "write_file" | "edit_file" => "path",
+        "copy_file" | "move_file" | "copy_directory" => "destination",
+        "execute_javascript" => {
+            return input
+                .get("code")
+                .and_then(Value::as_str)
+                .map(javascript_vfs_writes)
+                .unwrap_or_default();
+        }
+        _ => return Vec::new(),
+    };
+    input
+        .get(field)
+        .and_then(Value::as_str)
+        .map(|path| vec![path.to_string()])
+        .unwrap_or_default()
+}
+
+/// Literal paths passed to `vfs.writeFile` in JavaScript source
+fn javascript_vfs_writes(code: &str) -> Vec<String> {
+    const CALL: &str = "vfs.writeFile(";
+
+    let mut paths: Vec<String> = Vec::new();
+    for (start, _) in code.match_indices(CALL) {
+        let rest = code[start + CALL.len()..].trim_start();
+        let Some(quote) = rest.chars().next().filter(|c| matches!(c, '"' | '\'' | '`')) else {
+            continue; // Computed path
+        };
+        let literal = &rest[1..];
+        if let Some(end) = literal.find(quote) {
+            let path = &literal[..end];
+            if !(quote == '`' && path.contains("${")) && !paths.iter().any(|p| p == path) {
+                paths.push(path.to_string());
+            }
+        }
+    }
+    paths
+}
+
+/// Tool timeline middleware layer
+///
+/// Feeds a [`ToolTimeline`] from the `on_tool_start` and `on_tool_complete`
+/// hooks.
+///
+/// ## Example
+///
+/// ```ignore
+/// let timeline = ToolTimeline::new();
+/// stack.add(ToolTimelineLayer::new(timeline.clone()));
+/// ```
+pub struct ToolTimelineLayer {
+    timeline: ToolTimeline,
+}
+
+impl ToolTimelineLayer {
+    /// Record into `timeline`
+    pub fn new(timeline: ToolTimeline) -> Self {
+        Self { timeline }
+    }
+
+    pub fn timeline(&self) -> &ToolTimeline {
+        &self.timeline
+    }
+}
+
+impl ConversationLayer for ToolTimelineLayer {
+    fn name(&self) -> &str {
+        "ToolTimeline"
+    }
+
+    fn on_tool_start(&self, tool_name: &str, ctx: &LayerContext) {
+        self.timeline.record_start(tool_name, ctx.tool_input.as_ref());
+    }
+
+    fn on_tool_complete(&self, tool_name: &str, success: bool, ctx: &LayerContext) {
+        self.timeline
+            .record_complete(tool_name, success, ctx.tool_output_bytes.unwrap_or(0));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::agent_framework::AgentType;
+
+    #[test]
+    fn test_layer_records_tool_calls() {
+        let layer = ToolTimelineLayer::new(ToolTimeline::new());
+        let ctx = LayerContext::new("test", AgentType::TaskManager);
+        assert_eq!(layer.name(), "ToolTimeline");
+
+        layer.on_tool_start(
+            "write_file",
+            &ctx.clone()
+                .with_tool_input(serde_json::json!({ "path": "/results/a.json" })),
+        );
+        layer.on_tool_start("execute_javascript", &ctx);
+        layer.on_tool_complete("write_file", true, &ctx.clone().with_tool_output_bytes(64));
+        layer.on_tool_complete("unknown_tool", true, &ctx);
+
+        let entries = layer.timeline().entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].success, Some(true));
+        assert_eq!(entries[0].output_bytes, 64);
+        assert_eq!(entries[0].vfs_paths, vec!["/results/a.json"]);
+        assert!(entries[1].is_running());
+
+        layer.on_tool_complete("execute_javascript", false, &ctx);
+        assert_eq!(layer.timeline().entries()[1].success, Some(false));
+
+        layer.timeline().clear();
+        assert!(layer.timeline().is_empty());
+    }
+
+    #[test]
+    fn test_failed_write_has_no_paths() {
+        let timeline = ToolTimeline::new();
+        timeline.record_start("copy_file", Some(&serde_json::json!({ "destination": "b.txt" })));
+        timeline.record_complete("copy_file", false, 20);
+        assert!(timeline.entries()[0].vfs_paths.is_empty());
+    }
+
+    #[test]
+    fn test_javascript_vfs_writes() {
+        let code = r#"
+            vfs.writeFile("/results/ec2.json", JSON.stringify(instances));
+            vfs.writeFile( '/results/s3.json', data);
+            vfs.writeFile(`/results/${name}.json`, data);
+            vfs.writeFile(path, data);
+            vfs.writeFile("/results/ec2.json", more);
+        "#;
+        assert_eq!(
+            javascript_vfs_writes(code),
+            vec!["/results/ec2.json", "/results/s3.json"]
+        );
+        assert_eq!(
+            vfs_paths_written("execute_javascript", &serde_json::json!({ "code": code })).len(),
+            2
+        );
+        assert!(vfs_paths_written("read_file", &serde_json::json!({ "path": "a" })).is_empty());
+    }
+}
@@ -44,11 +44,17 @@
 mod context;
 pub mod layers;
 mod stack;
+mod tool_approval;
+mod tool_guardrail;
+mod tool_hooks;
 pub mod workspace_locking;
 pub mod page_validation;
 
 pub use context::LayerContext;
 pub use stack::LayerStack;
+pub use tool_approval::ToolApprovalMiddleware;
+pub use tool_guardrail::ToolGuardrailMiddleware;
+pub use tool_hooks::LayerHookCallbackHandler;
 pub use workspace_locking::WorkspaceLockingMiddleware;
 pub use page_validation::PageValidationMiddleware;
 
@@ -14,7 +14,9 @@ use super::{ConversationLayer, LayerContext, LayerError, LayerResult, PostRespon
 /// Layers are processed in order for pre-send operations and in
 /// reverse order for post-response operations. This allows outer
 /// layers to wrap inner layers' behavior.
-#[derive(Default)]
+///
+/// Clones share the same layer instances.
+#[derive(Clone, Default)]
 pub struct LayerStack {
     /// Ordered list of layers
     layers: Vec<Arc<dyn ConversationLayer>>,
@@ -0,0 +1,129 @@
+//! Tool Approval Middleware
+//!
+//! Stood tool middleware that holds tool calls tagged as mutating or
+//! destructive until the user approves them in the approval dialog, using
+//! the threshold set by the approval gate layer. A denied call does not run;
+//! the agent receives the reason as the tool's error.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use async_trait::async_trait;
+use serde_json::Value;
+use stood::tools::middleware::{ToolContext, ToolMiddleware, ToolMiddlewareAction};
+use stood::tools::ToolResult;
+
+use super::layers::{approval_risk, request_agent_approval};
+use crate::app::agent_framework::AgentId;
+
+/// Holds mutating tool calls until the user approves or denies them
+#[derive(Debug)]
+pub struct ToolApprovalMiddleware {
+    agent_id: AgentId,
+    parent_id: Option<AgentId>,
+}
+
+impl ToolApprovalMiddleware {
+    /// Gate the tool calls of `agent_id`; a worker passes its manager as `parent_id`
+    pub fn new(agent_id: AgentId, parent_id: Option<AgentId>) -> Self {
+        Self {
+            agent_id,
+            parent_id,
+        }
+    }
+}
+
+#[async_trait]
+impl ToolMiddleware for ToolApprovalMiddleware {
+    async fn before_tool(
+        &self,
+        tool_name: &str,
+        params: &Value,
+        _ctx: &ToolContext,
+    ) -> ToolMiddlewareAction {
+        if approval_risk(tool_name, Some(self.agent_id), self.parent_id).is_none() {
+            return ToolMiddlewareAction::Continue;
+        }
+
+        // Waiting for the user blocks, so it runs off the async workers
+        let (agent_id, parent_id) = (self.agent_id, self.parent_id);
+        let operation = tool_name.to_string();
+        let parameters = params.clone();
+        let decision = tokio::task::spawn_blocking(move || {
+            request_agent_approval(Some(agent_id), parent_id, &operation, parameters)
+        })
+        .await
+        .unwrap_or_else(|e| Err(format!("Approval of {} failed: {}", tool_name, e)));
+
+        match decision {
+            Ok(()) => ToolMiddlewareAction::Continue,
+            Err(error_msg) => ToolMiddlewareAction::Abort {
+                reason: format!("{} was not approved", tool_name),
+                synthetic_result: Some(ToolResult::error(error_msg)),
+            },
+        }
+    }
+
+    fn name(&self) -> &str {
+        "ToolApproval"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::agent_framework::middleware::layers::{
+        respond_to_approval, take_approval_requests, ApprovalDecision, APPROVAL_TEST_LOCK,
+    };
+    use crate::app::agent_framework::{register_tool_risk, ToolRiskLevel};
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn test_mutating_call_waits_for_decision() {
+        let _serial = APPROVAL_TEST_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        register_tool_risk("test_tool_approval_put", ToolRiskLevel::Mutating);
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let middleware = ToolApprovalMiddleware::new(AgentId::new(), None);
+        let ctx = ToolContext::new("test-agent".to_string());
+        let params = json!({ "bucket": "logs" });
+
+        let read =
+            runtime.block_on(middleware.before_tool("test_tool_approval_read", &params, &ctx));
+        assert!(matches!(read, ToolMiddlewareAction::Continue));
+
+        // Run the call, answering its request once it is waiting
+        let call_with = |decision: ApprovalDecision| {
+            runtime.block_on(async {
+                let call = middleware.before_tool("test_tool_approval_put", &params, &ctx);
+                tokio::pin!(call);
+                let request = loop {
+                    tokio::select! {
+                        _ = &mut call => panic!("the call ran before the user decided"),
+                        _ = tokio::time::sleep(Duration::from_millis(10)) => {}
+                    }
+                    if let Some(request) = take_approval_requests().pop() {
+                        break request;
+                    }
+                };
+                assert_eq!(request.operation, "test_tool_approval_put");
+                assert_eq!(request.parameters, params);
+                assert!(respond_to_approval(request.id, decision));
+                call.await
+            })
+        };
+
+        assert!(matches!(
+            call_with(ApprovalDecision::Approved),
+            ToolMiddlewareAction::Continue
+        ));
+        match call_with(ApprovalDecision::Denied("not today".to_string())) {
+            ToolMiddlewareAction::Abort {
+                synthetic_result: Some(result),
+                ..
+            } => assert!(result.error.unwrap().contains("not today")),
+            _ => panic!("a denied call must not run"),
+        }
+    }
+}
@@ -0,0 +1,131 @@
+//! Tool Guardrail Middleware
+//!
+//! Stood tool middleware that refuses tool calls whose input contains a
+//! secret, using the scanner and project policy of the guardrail layer.
+//! Tool inputs are always blocked rather than masked: a tool run with a
+//! placeholder in place of a key would do something other than what the
+//! agent asked for. The agent receives an error telling it why.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use async_trait::async_trait;
+use serde_json::Value;
+use stood::tools::middleware::{ToolContext, ToolMiddleware, ToolMiddlewareAction};
+use stood::tools::ToolResult;
+
+use super::layers::{
+    load_policy, record_audit, GuardrailAction, GuardrailAuditEntry, GuardrailStore,
+};
+use crate::app::agent_framework::conversation::memory::DEFAULT_PROJECT;
+
+/// Blocks tool calls that would pass credentials to a tool
+#[derive(Debug)]
+pub struct ToolGuardrailMiddleware {
+    project: String,
+    store: Option<GuardrailStore>,
+}
+
+impl ToolGuardrailMiddleware {
+    /// Check calls against the policy of `project` in the default store
+    pub fn new(project: Option<String>) -> Self {
+        Self::with_store(project, GuardrailStore::default_location())
+    }
+
+    pub fn with_store(project: Option<String>, store: Option<GuardrailStore>) -> Self {
+        Self {
+            project: project.unwrap_or_else(|| DEFAULT_PROJECT.to_string()),
+            store,
+        }
+    }
+
+    /// Check `params`; returns the error for the agent when the call is blocked
+    fn check(&self, tool_name: &str, params: &Value, agent_id: &str) -> Option<String> {
+        let policy = load_policy(self.store.as_ref(), &self.project);
+        let mut strings = Vec::new();
+        collect_strings(params, &mut strings);
+
+        for text in strings {
+            let matches = policy.scan(text);
+            if matches.is_empty() {
+                continue;
+            }
+            let entry = GuardrailAuditEntry::new(
+                agent_id,
+                &self.project,
+                format!("tool:{}", tool_name),
+                GuardrailAction::Blocked,
+                text,
+                &matches,
+            );
+            record_audit(self.store.as_ref(), &entry);
+            return Some(format!(
+                "Blocked by guardrail: the input to {} contains a {}. \
+                 Do not pass credentials to tools; the tools already run with the \
+                 user's AWS credentials.",
+                tool_name,
+                entry.kinds_label()
+            ));
+        }
+        None
+    }
+}
+
+/// All string values in `value`, depth first
+fn collect_strings<'a>(value: &'a Value, out: &mut Vec<&'a str>) {
+    match value {
+        Value::String(text) => out.push(text),
+        Value::Array(items) => items.iter().for_each(|item| collect_strings(item, out)),
+        Value::Object(fields) => fields.values().for_each(|field| collect_strings(field, out)),
+        _ => {}
+    }
+}
+
+#[async_trait]
+impl ToolMiddleware for ToolGuardrailMiddleware {
+    async fn before_tool(
+        &self,
+        tool_name: &str,
+        params: &Value,
+        ctx: &ToolContext,
+    ) -> ToolMiddlewareAction {
+        match self.check(tool_name, params, &ctx.agent_id) {
+            None => ToolMiddlewareAction::Continue,
+            Some(error_msg) => ToolMiddlewareAction::Abort {
+                reason: format!("Secret in input of {}", tool_name),
+                synthetic_result: Some(ToolResult::error(error_msg)),
+            },
+        }
+    }
+
+    fn name(&self) -> &str {
+        "ToolGuardrail"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_blocks_secret_in_nested_input() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = GuardrailStore::new(temp_dir.path().to_path_buf());
+        let middleware = ToolGuardrailMiddleware::with_store(None, Some(store.clone()));
+
+        let clean = serde_json::json!({ "code": "const b = listBuckets();" });
+        assert!(middleware.check("execute_javascript", &clean, "agent-1").is_none());
+
+        let leaking = serde_json::json!({
+            "path": "creds.txt",
+            "edits": [{ "search": "x", "replace": "AKIAZ7Q3R5T2W4Y6U8I0" }]
+        });
+        let error = middleware.check("edit_file", &leaking, "agent-1").unwrap();
+        assert!(error.contains("access key ID"));
+
+        let audit = store.audit_entries();
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].source, "tool:edit_file");
+        assert_eq!(audit[0].project, DEFAULT_PROJECT);
+    }
+}
@@ -0,0 +1,124 @@
+//! Tool Hook Dispatch
+//!
+//! Stood reports tool executions through callback events on the agent's
+//! thread, while middleware layers live on the [`LayerStack`] owned by the
+//! agent instance. [`LayerHookCallbackHandler`] holds a clone of the stack and
+//! turns `ToolStart`/`ToolComplete` events into `on_tool_start` and
+//! `on_tool_complete` calls, with the tool input and output size on the
+//! [`LayerContext`].
+//!
+//! The stack is cloned when the stood agent is built, so layers added later
+//! only see tool events after the agent is re-created.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use async_trait::async_trait;
+use serde_json::Value;
+use stood::agent::callbacks::{CallbackError, CallbackEvent, CallbackHandler};
+
+use super::{LayerContext, LayerStack};
+
+/// Stood callback handler that forwards tool events to a [`LayerStack`]
+///
+/// Like the transcript handler, it can wrap another handler and passes every
+/// event on to it.
+pub struct LayerHookCallbackHandler {
+    layers: LayerStack,
+    /// Agent ID and type for the contexts passed to the layers
+    base_context: LayerContext,
+    inner: Option<Box<dyn CallbackHandler + Send + Sync>>,
+}
+
+impl LayerHookCallbackHandler {
+    /// Notify `layers` only
+    pub fn new(layers: LayerStack, base_context: LayerContext) -> Self {
+        Self {
+            layers,
+            base_context,
+            inner: None,
+        }
+    }
+
+    /// Notify `layers`, then forward each event to `inner`
+    pub fn wrapping(
+        layers: LayerStack,
+        base_context: LayerContext,
+        inner: impl CallbackHandler + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            layers,
+            base_context,
+            inner: Some(Box::new(inner)),
+        }
+    }
+
+    fn dispatch(&self, event: &CallbackEvent) {
+        match event {
+            CallbackEvent::ToolStart {
+                tool_name, input, ..
+            } => {
+                let ctx = self
+                    .base_context
+                    .clone()
+                    .with_last_tool(tool_name.clone(), true)
+                    .with_tool_input(input.clone());
+                self.layers.notify_tool_start(tool_name, &ctx);
+            }
+            CallbackEvent::ToolComplete {
+                tool_name,
+                output,
+                error,
+                ..
+            } => {
+                let success = error.is_none();
+                let ctx = self
+                    .base_context
+                    .clone()
+                    .with_last_tool(tool_name.clone(), success)
+                    .with_tool_output_bytes(output_bytes(output.as_ref(), error.as_deref()));
+                self.layers.notify_tool_complete(tool_name, success, &ctx);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Size of a tool result as the model receives it
+fn output_bytes(output: Option<&Value>, error: Option<&str>) -> usize {
+    match (output, error) {
+        (_, Some(error)) => error.len(),
+        (Some(Value::String(text)), None) => text.len(),
+        (Some(value), None) => value.to_string().len(),
+        (None, None) => 0,
+    }
+}
+
+#[async_trait]
+impl CallbackHandler for LayerHookCallbackHandler {
+    async fn handle_event(&self, event: CallbackEvent) -> Result<(), CallbackError> {
+        if !self.layers.is_empty() {
+            self.dispatch(&event);
+        }
+
+        match &self.inner {
+            Some(inner) => inner.handle_event(event).await,
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_bytes() {
+        assert_eq!(output_bytes(None, None), 0);
+        assert_eq!(output_bytes(Some(&Value::String("abc".into())), None), 3);
+        assert_eq!(output_bytes(Some(&serde_json::json!([1, 2])), None), 5);
+        assert_eq!(
+            output_bytes(Some(&serde_json::json!({})), Some("File not found")),
+            14
+        );
+    }
+}
@@ -11,8 +11,11 @@ pub mod vfs;
 pub mod workers;
 
 // Feature modules
+pub mod cost_tracking;
 pub mod middleware;
 pub mod prompts;
+pub mod retrieval;
+pub mod scheduler;
 pub mod skills;
 pub mod tools;
 pub mod ui;
@@ -31,6 +34,9 @@ pub use logging::*;
 // Re-export worker items
 pub use workers::*;
 
+// Re-export cost tracking items
+pub use cost_tracking::*;
+
 // Re-export middleware items
 pub use middleware::{
     ConversationLayer, LayerContext, LayerError, LayerResult, LayerStack, PostResponseAction,
@@ -42,6 +48,12 @@ pub use prompts::{
     PAGE_BUILDER_COMMON, PAGE_BUILDER_RESULTS_PROMPT, PAGE_BUILDER_TOOL_PROMPT,
 };
 
+// Re-export retrieval items
+pub use retrieval::{index_for_cache, ResourceIndex, SearchFilter, SearchHit};
+
+// Re-export scheduler items
+pub use scheduler::*;
+
 // Re-export skills
 pub use skills::*;
 
@@ -59,7 +71,7 @@ pub use v8_bindings::{
     get_api_documentation, register_bindings, set_global_aws_identity,
     register_console, ConsoleBuffers,
     initialize_v8_platform, is_v8_initialized,
-    ExecutionResult, RuntimeConfig, V8Runtime,
+    ExecutionResult, InterruptHandle, KillReason, RuntimeConfig, V8Runtime,
     from_v8_value, to_v8_value,
 };
 
@@ -23,6 +23,7 @@ pub mod page_builder_worker;
 pub mod page_builder_common;
 pub mod page_builder_results;
 pub mod page_builder_tool;
+pub mod overrides;
 
 // Re-export prompts as constants
 pub use task_manager::TASK_MANAGER_PROMPT;
@@ -32,6 +33,7 @@ pub use page_builder_worker::PAGE_BUILDER_WORKER_PROMPT;
 pub use page_builder_common::PAGE_BUILDER_COMMON;
 pub use page_builder_results::PAGE_BUILDER_RESULTS_PROMPT;
 pub use page_builder_tool::PAGE_BUILDER_TOOL_PROMPT;
+pub use overrides::{load_prompt, PromptKind, PromptOverrides};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,249 @@
+//! Prompt Overrides
+//!
+//! Lets users replace the built-in task manager and task worker prompts with
+//! files in `{config_dir}/awsdash/prompts/` (`task_manager.md`,
+//! `task_worker.md`), so teams can tune agent behavior without recompiling.
+//! Overrides are read when an agent is created; deleting the file restores
+//! the built-in prompt.
+//!
+//! Prompts may use `{{VARIABLE}}` placeholders, filled in when the agent is
+//! created (see [`PromptKind::variables`]).
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::{TASK_MANAGER_PROMPT, TASK_WORKER_PROMPT};
+
+/// Prompts that can be overridden
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PromptKind {
+    TaskManager,
+    TaskWorker,
+}
+
+impl PromptKind {
+    /// Get the display name for UI
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PromptKind::TaskManager => "Task Manager",
+            PromptKind::TaskWorker => "Task Worker",
+        }
+    }
+
+    /// All overridable prompts
+    pub fn all() -> &'static [PromptKind] {
+        &[PromptKind::TaskManager, PromptKind::TaskWorker]
+    }
+
+    /// Override file name within the prompts directory
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            PromptKind::TaskManager => "task_manager.md",
+            PromptKind::TaskWorker => "task_worker.md",
+        }
+    }
+
+    /// Prompt compiled into the application
+    pub fn builtin(&self) -> &'static str {
+        match self {
+            PromptKind::TaskManager => TASK_MANAGER_PROMPT,
+            PromptKind::TaskWorker => TASK_WORKER_PROMPT,
+        }
+    }
+
+    /// Placeholders filled in for this prompt, with their current values
+    pub fn variables(&self) -> Vec<(&'static str, String)> {
+        vec![(
+            "CURRENT_DATETIME",
+            chrono::Utc::now()
+                .format("%Y-%m-%d %H:%M:%S UTC")
+                .to_string(),
+        )]
+    }
+}
+
+/// Replace each `{{NAME}}` in `template` with its value from `variables`
+pub fn interpolate(template: &str, variables: &[(&str, String)]) -> String {
+    variables
+        .iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{{{}}}}}", name), value)
+        })
+}
+
+/// `{{NAME}}` placeholders in `template` that are not in `variables`, in
+/// order of first appearance (these are sent to the model unchanged)
+pub fn unknown_variables(template: &str, variables: &[(&str, String)]) -> Vec<String> {
+    let mut unknown: Vec<String> = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        rest = &rest[start + 2..];
+        let Some(end) = rest.find("}}") else {
+            break;
+        };
+        let name = &rest[..end];
+        let is_placeholder = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_');
+        if is_placeholder
+            && !variables.iter().any(|(known, _)| *known == name)
+            && !unknown.iter().any(|u| u == name)
+        {
+            unknown.push(name.to_string());
+        }
+        rest = &rest[end + 2..];
+    }
+    unknown
+}
+
+/// Prompt override files on disk
+#[derive(Debug, Clone)]
+pub struct PromptOverrides {
+    directory: PathBuf,
+}
+
+impl PromptOverrides {
+    /// Create a store rooted at `directory`
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Store in the application config directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(dirs::config_dir()?.join("awsdash/prompts")))
+    }
+
+    /// Directory holding override files
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Override file for `kind`
+    pub fn path(&self, kind: PromptKind) -> PathBuf {
+        self.directory.join(kind.file_name())
+    }
+
+    /// The override for `kind`, or None when there is none (or it is empty)
+    pub fn load(&self, kind: PromptKind) -> Result<Option<String>> {
+        let path = self.path(kind);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let text = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read prompt {}", path.display()))?;
+        Ok((!text.trim().is_empty()).then_some(text))
+    }
+
+    /// Write the override for `kind`
+    pub fn save(&self, kind: PromptKind, text: &str) -> Result<()> {
+        fs::create_dir_all(&self.directory).with_context(|| {
+            format!(
+                "Failed to create prompt directory {}",
+                self.directory.display()
+            )
+        })?;
+        let path = self.path(kind);
+        fs::write(&path, text).with_context(|| format!("Failed to write prompt {}", path.display()))
+    }
+
+    /// Delete the override for `kind`, going back to the built-in prompt
+    pub fn reset(&self, kind: PromptKind) -> Result<()> {
+        let path = self.path(kind);
+        if path.exists() {
+            fs::remove_file(&path)
+                .with_context(|| format!("Failed to delete prompt {}", path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// The override for `kind` if there is a readable one, else the built-in
+    /// prompt
+    pub fn prompt(&self, kind: PromptKind) -> String {
+        match self.load(kind) {
+            Ok(Some(text)) => text,
+            Ok(None) => kind.builtin().to_string(),
+            Err(e) => {
+                log::warn!("Using built-in {} prompt: {:#}", kind.display_name(), e);
+                kind.builtin().to_string()
+            }
+        }
+    }
+}
+
+/// Prompt template for `kind` from the default override location, falling
+/// back to the built-in prompt
+pub fn load_prompt(kind: PromptKind) -> String {
+    match PromptOverrides::default_location() {
+        Some(overrides) => overrides.prompt(kind),
+        None => kind.builtin().to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_override_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let overrides = PromptOverrides::new(temp_dir.path().join("prompts"));
+
+        assert_eq!(overrides.load(PromptKind::TaskWorker).unwrap(), None);
+        assert_eq!(overrides.prompt(PromptKind::TaskWorker), TASK_WORKER_PROMPT);
+
+        overrides
+            .save(
+                PromptKind::TaskWorker,
+                "You are a worker. Now: {{CURRENT_DATETIME}}",
+            )
+            .unwrap();
+        assert!(overrides
+            .prompt(PromptKind::TaskWorker)
+            .starts_with("You are a worker."));
+        assert_eq!(
+            overrides.prompt(PromptKind::TaskManager),
+            TASK_MANAGER_PROMPT
+        );
+
+        // Blank files do not replace the built-in prompt
+        overrides.save(PromptKind::TaskManager, "  \n").unwrap();
+        assert_eq!(
+            overrides.prompt(PromptKind::TaskManager),
+            TASK_MANAGER_PROMPT
+        );
+
+        overrides.reset(PromptKind::TaskWorker).unwrap();
+        assert!(!overrides.path(PromptKind::TaskWorker).exists());
+        assert_eq!(overrides.prompt(PromptKind::TaskWorker), TASK_WORKER_PROMPT);
+        // Resetting again is a no-op
+        overrides.reset(PromptKind::TaskWorker).unwrap();
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let variables = vec![("CURRENT_DATETIME", "2026-01-02 03:04:05 UTC".to_string())];
+        assert_eq!(
+            interpolate("Now: {{CURRENT_DATETIME}}, {{TEAM}}", &variables),
+            "Now: 2026-01-02 03:04:05 UTC, {{TEAM}}"
+        );
+        assert_eq!(
+            unknown_variables(
+                "{{CURRENT_DATETIME}} {{TEAM}} {{TEAM}} {{ACCOUNT_1}} {{not a var}} {{",
+                &variables
+            ),
+            vec!["TEAM".to_string(), "ACCOUNT_1".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_builtin_prompts_use_known_variables() {
+        for kind in PromptKind::all() {
+            assert!(unknown_variables(kind.builtin(), &kind.variables()).is_empty());
+        }
+    }
+}
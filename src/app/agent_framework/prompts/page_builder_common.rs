@@ -105,6 +105,8 @@ vfs.exists(path)                                // Check if path exists
 vfs.stat(path)                                  // Get file/dir info
 vfs.mkdir(path)                                 // Create directory
 vfs.delete(path)                                // Delete file/dir
+vfs.stats()                                     // Memory usage and quotas
+vfsSearch({ glob, pattern, path })              // Find files by glob / lines by regex
 ```
 
 ## dashApp API (Webview Runtime)
@@ -143,7 +145,11 @@ execute_javascript which returns optimized summaries for context efficiency.
 - `dashApp.queryCachedResources(options)` - Query cached resources
 - `dashApp.getResourceSchema(resourceType)` - Get example resource structure
 - `dashApp.queryCloudWatchLogEvents(params)` - Query CloudWatch logs
+- `dashApp.getCloudWatchMetrics(params)` - Query CloudWatch metric time series
 - `dashApp.getCloudTrailEvents(params)` - Query CloudTrail events
+- `dashApp.getConfigHistory(params)` - Query AWS Config configuration history
+- `dashApp.runAthenaQuery(params)` - Run Athena SQL against data in S3
+- `dashApp.getCostData(params)` - Month-to-date costs and forecast from Cost Explorer
 - `dashApp.openPage(pageName)` - Open page in webview
 
 ## File Operation Tools
@@ -154,7 +160,10 @@ You have these tools at your disposal:
 - `write_file(path, content)` - Create or overwrite file (for code you write)
 - `read_file(path)` - Read small files only (<10KB, blocks larger files)
 - `list_files(path?)` - List files in directory
-- `delete_file(path)` - Delete a file
+- `search_files(glob?, pattern?, path?)` - Find VFS files by glob (`/results/**/*.json`) or lines by regex
+- `delete_file(path, recursive?)` - Delete a file, or a directory with `recursive: true`
+- `move_file(source, destination)` - Move or rename a file or directory
+- `copy_directory(source, destination)` - Copy a directory within the workspace
 - `open_page()` - Preview the page in a webview
 
 **IMPORTANT: All files must be in the ROOT of your workspace - NO subfolders!**
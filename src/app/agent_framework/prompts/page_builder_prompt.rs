@@ -415,10 +415,25 @@ You have these tools at your disposal:
   - path: Optional directory path (defaults to root)
   - Returns: `{files: Array<{name, path, is_directory, size_bytes}>, total_count: number}`
 
-- `delete_file(path)` - Delete a file
+- `search_files(glob?, pattern?, path?, max_results?)` - Find files without listing every directory
+  - glob: Path glob, e.g. `*.json` or `/results/**/*.json`
+  - pattern: Regex to search file contents for
+  - path: Directory to search under; in VFS workspaces the whole VFS is searched
+  - Returns: `{matches: Array<{path, isDirectory, size}> | Array<{path, lineNumber, line}>, total_count: number, truncated: boolean}`
+
+- `delete_file(path, recursive?)` - Delete a file
   - path: Relative path within workspace
+  - recursive: Set to delete a directory and everything in it
   - Returns: `{path: string, deleted: boolean}`
 
+- `move_file(source, destination)` - Move or rename a file or directory
+  - destination: New relative path; must not exist
+  - Returns: `{source: string, destination: string, moved: boolean}`
+
+- `copy_directory(source, destination)` - Copy a directory and its contents
+  - destination: Relative path for the copy; must not exist
+  - Returns: `{source: string, destination: string, copied: boolean}`
+
 - `get_api_docs()` - Get complete dashApp API documentation
   - Returns full API reference with all methods and parameters
 
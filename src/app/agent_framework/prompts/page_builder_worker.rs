@@ -252,6 +252,19 @@ const logs = await dashApp.queryCloudWatchLogEvents({
 });
 ```
 
+**`dashApp.getCloudWatchMetrics(params)`**
+- Fetches CloudWatch metric time series (default metrics for EC2, Lambda, RDS, DynamoDB, or explicit metrics)
+- Params: `{accountId, region, resourceType?, resourceId?, metrics?, startTime?, endTime?, period?}`
+- Returns: `{series: [{label, datapoints: [{timestamp, value}]}], summary, totalDatapoints}`
+```javascript
+const metrics = await dashApp.getCloudWatchMetrics({
+    accountId: '123456789012',
+    region: 'us-east-1',
+    resourceType: 'AWS::Lambda::Function',
+    resourceId: 'my-function'
+});
+```
+
 **`dashApp.getCloudTrailEvents(params)`**
 - Queries CloudTrail events
 - Params: `{accountId, region, startTime?, endTime?, lookupAttributes?, maxResults?}`
@@ -289,10 +302,25 @@ You have these tools at your disposal:
   - path: Optional directory path (defaults to root)
   - Returns: `{files: Array<{name, path, is_directory, size_bytes}>, total_count: number}`
 
-- `delete_file(path)` - Delete a file
+- `search_files(glob?, pattern?, path?, max_results?)` - Find files without listing every directory
+  - glob: Path glob, e.g. `*.json` or `/results/**/*.json`
+  - pattern: Regex to search file contents for
+  - path: Directory to search under; in VFS workspaces the whole VFS is searched
+  - Returns: `{matches: Array<{path, isDirectory, size}> | Array<{path, lineNumber, line}>, total_count: number, truncated: boolean}`
+
+- `delete_file(path, recursive?)` - Delete a file
   - path: Relative path within workspace
+  - recursive: Set to delete a directory and everything in it
   - Returns: `{path: string, deleted: boolean}`
 
+- `move_file(source, destination)` - Move or rename a file or directory
+  - destination: New relative path; must not exist
+  - Returns: `{source: string, destination: string, moved: boolean}`
+
+- `copy_directory(source, destination)` - Copy a directory and its contents
+  - destination: Relative path for the copy; must not exist
+  - Returns: `{source: string, destination: string, copied: boolean}`
+
 - `open_page(message?)` - Open the page in a webview for preview/testing
   - message: Optional message to display (default: "Opening page preview...")
   - Returns: `{status: string, message: string, page_name: string, page_path: string}`
@@ -164,6 +164,7 @@ If something fails, wrap in XML:
 - `loadCache(options)` - Load resources, returns counts (no inline data)
 - `queryCachedResources(options)` - Query cached resources, returns `detailsPath` (read with `vfs.readFile()`)
 - `getResourceSchema(type)` - Get example resource structure (can be null if cache empty)
+- `searchResources(query)` - Cached resources ranked by meaning (\"the payments database\"), returns `results` with `resourceId`, `resourceType`, `accountId`, `region`, `score`
 
 **Accounts & Regions:**
 - `listAccounts()` - Returns `[{id, name, alias, email}]`
@@ -171,13 +172,40 @@ If something fails, wrap in XML:
 
 **Logs & Events (results auto-saved to VFS):**
 - `queryCloudWatchLogEvents(params)` - Query logs, returns `detailsPath` (read with `vfs.readFile()`)
+- `getCloudWatchMetrics(params)` - Metric time series, returns `summary` per metric plus `detailsPath` for full datapoints
+- `getMetricStatistics(params)` - Several statistics (Average, Maximum, p99, ...) of one metric, returns `summary` per statistic
+- `listAlarmsForResource(params)` - CloudWatch alarms watching a resource, firing alarms first
 - `getCloudTrailEvents(params)` - Query events, returns `detailsPath` (read with `vfs.readFile()`)
+- `getConfigHistory(params)` - Configuration timeline, returns `timeline` of changed paths plus `detailsPath` for full items and diffs
+- `runAthenaQuery(params)` - Athena SQL, returns `columns` and `rows` (first 10 plus `detailsPath` for all rows when larger)
+- `getCostData(params)` - Cost Explorer spend, returns `total`, `forecastTotal`, and `groups` sorted by amount
+
+**S3 Bucket Contents:**
+- `s3Objects.list(params)` - List objects by `prefix` (first 100 inline, `detailsPath` for all when larger)
+- `s3Objects.head(params)` - Object size, type, and metadata
+- `s3Objects.get(params)` - Read the start of an object (`maxBytes`, default 256 KB, max 5 MB)
+- `s3Objects.presignUrl(params)` - Presigned GET URL
+
+**DynamoDB Table Contents:**
+- `queryDynamoTable(params)` - Items by key condition (prefer over scan); first 20 inline, `detailsPath` for all when larger
+- `scanDynamoTable(params)` - Read a whole table or index until `maxItems`/`maxBytes`; check `truncatedBy` and `lastEvaluatedKey`
+
+**IAM Permissions:**
+- `getCallerIdentity(params)` - ARN and account of the credentials used for an account
+- `simulatePrincipalPolicy(params)` - Whether a role/user may call actions on resources, with the deciding statements (use instead of reading policy documents)
+
+**Step Functions:**
+- `getStateMachineExecutions(params)` - Executions of a state machine by status; `includeHistory: true` adds `states` with per-state input/output/error and `failedState`
+
+**Public Data:**
+- `fetch(url, options?)` - HTTPS GET/POST to allow-listed hosts (AWS IP ranges, price lists); returns a Promise, so `await` it inside `(async () => { ... })()`
 
 **VFS (only for filtered results):**
 - `vfs.writeFile(path, content)` - Save processed data
 - `vfs.readFile(path)` - Read existing data
 - `vfs.exists(path)` - Check if file exists
 - `vfs.listDir(path)` - List directory
+- `vfsSearch({glob, pattern, path})` - Find earlier results by path glob or content regex
 
 ## Property Access
 
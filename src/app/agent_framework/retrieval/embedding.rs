@@ -0,0 +1,289 @@
+//! Local Text Embedding
+//!
+//! Turns short texts (resource names, tags, property values, questions) into
+//! sparse hashed vectors without a model or network call. A vector holds
+//! three kinds of features:
+//!
+//! - **Terms**: lowercase words, with camelCase, kebab-case, and snake_case
+//!   names split apart and plurals folded ("PaymentsDB" -> "payment", "db")
+//! - **Concepts**: a shared feature for words that mean the same thing to an
+//!   AWS user ("database", "db", "rds", and "aurora" all add `~database`)
+//! - **Trigrams**: character trigrams of longer terms, so typos and partial
+//!   names ("paymnt", "checkout-svc") still score
+//!
+//! Features are hashed into a fixed space and compared with cosine
+//! similarity; term weights come from the index (see `ResourceIndex`).
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use std::collections::HashMap;
+
+/// Size of the hashed feature space
+pub const EMBEDDING_DIMENSIONS: u32 = 1 << 20;
+
+/// Weight of a trigram relative to a whole term
+const TRIGRAM_WEIGHT: f32 = 0.25;
+
+/// Weight of a concept relative to a whole term
+const CONCEPT_WEIGHT: f32 = 0.75;
+
+/// Terms shorter than this get no trigrams
+const MIN_TRIGRAM_TERM_LEN: usize = 4;
+
+/// Words that carry no meaning in a resource question
+const STOPWORDS: &[&str] = &[
+    "a", "all", "an", "and", "any", "are", "by", "find", "for", "from", "get", "in", "is", "list",
+    "me", "my", "of", "on", "or", "our", "show", "that", "the", "to", "we", "what", "where",
+    "which", "with",
+];
+
+/// Words grouped by the concept they refer to; the first word names it
+const CONCEPTS: &[&[&str]] = &[
+    &[
+        "database",
+        "db",
+        "rds",
+        "aurora",
+        "dynamodb",
+        "dbinstance",
+        "dbcluster",
+        "postgres",
+        "postgresql",
+        "mysql",
+        "mariadb",
+        "docdb",
+        "documentdb",
+        "neptune",
+    ],
+    &["bucket", "s3", "storage"],
+    &["function", "lambda", "serverless", "fn"],
+    &["queue", "sqs"],
+    &["topic", "sns", "notification"],
+    &["server", "ec2", "vm", "host"],
+    &["cluster", "ecs", "eks", "kubernetes", "k8s"],
+    &["network", "vpc", "subnet", "networking"],
+    &[
+        "loadbalancer",
+        "elb",
+        "alb",
+        "nlb",
+        "balancer",
+        "elasticloadbalancing",
+    ],
+    &["cache", "elasticache", "redis", "memcached", "valkey"],
+    &["role", "iam"],
+    &["key", "kms", "encryption"],
+    &["secret", "secretsmanager", "password", "credential"],
+    &["stream", "kinesis"],
+    &["api", "apigateway", "restapi", "endpoint"],
+    &["certificate", "acm", "cert", "tls", "ssl"],
+    &["dns", "route53", "hostedzone", "domain"],
+    &["cdn", "cloudfront", "distribution"],
+    &["workflow", "statemachine", "stepfunctions"],
+    &["production", "prod", "prd"],
+    &["development", "dev"],
+    &["staging", "stage", "stg"],
+];
+
+/// Split text into normalized terms, dropping stopwords
+///
+/// Names are split on punctuation and case changes, so "PaymentsDB-primary"
+/// becomes "payment", "db", "primary".
+pub fn tokenize(text: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    for word in text.split(|c: char| !c.is_alphanumeric()) {
+        for part in split_camel_case(word) {
+            let term = normalize_term(&part.to_lowercase());
+            if !term.is_empty() && !STOPWORDS.contains(&term.as_str()) {
+                terms.push(term);
+            }
+        }
+    }
+    terms
+}
+
+/// Split "PaymentsDBInstance" into "Payments", "DB", "Instance"
+fn split_camel_case(word: &str) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    let mut parts = Vec::new();
+    let mut current = String::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        let starts_part = i > 0
+            && ((c.is_uppercase() && chars[i - 1].is_lowercase())
+                || (c.is_uppercase()
+                    && chars[i - 1].is_uppercase()
+                    && chars.get(i + 1).is_some_and(|next| next.is_lowercase()))
+                || (c.is_ascii_digit() != chars[i - 1].is_ascii_digit()));
+        if starts_part && !current.is_empty() {
+            parts.push(std::mem::take(&mut current));
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
+/// Fold simple plurals so "payments" matches "payment"
+fn normalize_term(term: &str) -> String {
+    let folds_plural = term.len() > 3
+        && term.ends_with('s')
+        && !term.ends_with("ss")
+        && !term.ends_with("us")
+        && !term.ends_with("is")
+        && !term.chars().any(|c| c.is_ascii_digit());
+    if folds_plural {
+        term[..term.len() - 1].to_string()
+    } else {
+        term.to_string()
+    }
+}
+
+/// Concept a term belongs to, if any
+pub fn concept_of(term: &str) -> Option<&'static str> {
+    CONCEPTS
+        .iter()
+        .find(|words| words.contains(&term))
+        .map(|words| words[0])
+}
+
+/// Stable FNV-1a hash of a feature into the embedding space
+fn feature_id(feature: &str) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in feature.bytes() {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    hash % EMBEDDING_DIMENSIONS
+}
+
+/// Raw feature counts of a text, before term weighting
+///
+/// `weight` scales every feature, so a resource's name can count more than
+/// its property values.
+pub fn add_features(features: &mut HashMap<u32, f32>, terms: &[String], weight: f32) {
+    for term in terms {
+        *features.entry(feature_id(term)).or_default() += weight;
+
+        if let Some(concept) = concept_of(term) {
+            *features
+                .entry(feature_id(&format!("~{}", concept)))
+                .or_default() += weight * CONCEPT_WEIGHT;
+        }
+
+        let chars: Vec<char> = term.chars().collect();
+        if chars.len() >= MIN_TRIGRAM_TERM_LEN {
+            for trigram in chars.windows(3) {
+                let trigram: String = trigram.iter().collect();
+                *features
+                    .entry(feature_id(&format!("#{}", trigram)))
+                    .or_default() += weight * TRIGRAM_WEIGHT;
+            }
+        }
+    }
+}
+
+/// A sparse unit vector, sorted by feature ID
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SparseVector {
+    entries: Vec<(u32, f32)>,
+}
+
+impl SparseVector {
+    /// Build a unit vector from weighted features
+    ///
+    /// Counts are damped with `1 + ln(count)` so a word repeated across many
+    /// properties does not drown out the name.
+    pub fn from_features(features: HashMap<u32, f32>, idf: impl Fn(u32) -> f32) -> SparseVector {
+        let mut entries: Vec<(u32, f32)> = features
+            .into_iter()
+            .filter(|(_, count)| *count > 0.0)
+            .map(|(id, count)| {
+                let tf = if count > 1.0 { 1.0 + count.ln() } else { count };
+                (id, tf * idf(id))
+            })
+            .collect();
+        entries.sort_unstable_by_key(|(id, _)| *id);
+
+        let norm = entries.iter().map(|(_, w)| w * w).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for (_, weight) in &mut entries {
+                *weight /= norm;
+            }
+        }
+        SparseVector { entries }
+    }
+
+    pub fn entries(&self) -> &[(u32, f32)] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Cosine similarity with another unit vector
+    pub fn cosine(&self, other: &SparseVector) -> f32 {
+        let (mut i, mut j, mut dot) = (0, 0, 0.0);
+        while i < self.entries.len() && j < other.entries.len() {
+            let (a, b) = (self.entries[i], other.entries[j]);
+            match a.0.cmp(&b.0) {
+                std::cmp::Ordering::Less => i += 1,
+                std::cmp::Ordering::Greater => j += 1,
+                std::cmp::Ordering::Equal => {
+                    dot += a.1 * b.1;
+                    i += 1;
+                    j += 1;
+                }
+            }
+        }
+        dot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_splits_names() {
+        assert_eq!(
+            tokenize("PaymentsDBInstance-primary_2"),
+            vec!["payment", "db", "instance", "primary", "2"]
+        );
+        assert_eq!(
+            tokenize("AWS::RDS::DBCluster"),
+            vec!["aws", "rds", "db", "cluster"]
+        );
+        assert_eq!(
+            tokenize("Show me the payments database"),
+            vec!["payment", "database"]
+        );
+        assert_eq!(tokenize("redis status"), vec!["redis", "status"]);
+    }
+
+    #[test]
+    fn test_concepts() {
+        assert_eq!(concept_of("rds"), Some("database"));
+        assert_eq!(concept_of("database"), Some("database"));
+        assert_eq!(concept_of("prod"), Some("production"));
+        assert_eq!(concept_of("payment"), None);
+    }
+
+    #[test]
+    fn test_cosine_similarity() {
+        let vector = |text: &str| {
+            let mut features = HashMap::new();
+            add_features(&mut features, &tokenize(text), 1.0);
+            SparseVector::from_features(features, |_| 1.0)
+        };
+
+        let db = vector("payments rds instance");
+        assert!((db.cosine(&db) - 1.0).abs() < 1e-4);
+        assert!(db.cosine(&vector("payments database")) > db.cosine(&vector("orders queue")));
+        assert!(vector("paymnt").cosine(&vector("payment")) > 0.0);
+        assert_eq!(db.cosine(&SparseVector::default()), 0.0);
+    }
+}
@@ -0,0 +1,17 @@
+//! Resource Retrieval
+//!
+//! Local semantic search over the Explorer cache, so agents can resolve fuzzy
+//! references ("the payments database", "prod redis") to concrete resources
+//! before querying them. Nothing leaves the machine: embeddings are hashed
+//! term, concept, and trigram vectors built in-process.
+//!
+//! - [`embedding`]: tokenizer, AWS synonym concepts, and sparse vectors
+//! - [`ResourceIndex`]: embedded cached resources, ranked by cosine similarity
+//! - [`index_for_cache`]: the shared index, rebuilt when the cache changes
+
+pub mod embedding;
+pub mod resource_index;
+
+pub use resource_index::{
+    index_for_cache, IndexedResource, ResourceIndex, SearchFilter, SearchHit, MIN_SEARCH_SCORE,
+};
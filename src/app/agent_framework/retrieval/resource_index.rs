@@ -0,0 +1,494 @@
+//! Resource Index
+//!
+//! Retrieval over the Explorer cache: every cached resource is embedded from
+//! its name, ID, type, tags, and string property values, and questions like
+//! "the payments database" are ranked against those vectors.
+//!
+//! The index is rebuilt only when the cache changes (a query key is added,
+//! refreshed, or evicted), so repeated searches in one agent session reuse it.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use super::embedding::{add_features, concept_of, tokenize, SparseVector};
+use crate::app::resource_explorer::cache::SharedResourceCache;
+use crate::app::resource_explorer::state::{ResourceEntry, ResourceTag};
+
+/// Weight of the display name and resource ID
+const NAME_WEIGHT: f32 = 3.0;
+
+/// Weight of tag values and the resource type
+const TAG_WEIGHT: f32 = 2.0;
+
+/// Weight of tag keys and property values
+const PROPERTY_WEIGHT: f32 = 1.0;
+
+/// Property text indexed per resource
+const MAX_PROPERTY_TEXT: usize = 2048;
+
+/// Longer property strings (policy documents, certificates, scripts) are skipped
+const MAX_PROPERTY_VALUE_LEN: usize = 256;
+
+/// Results scoring below this are noise (a shared trigram or two)
+pub const MIN_SEARCH_SCORE: f32 = 0.05;
+
+/// A cached resource as stored in the index
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexedResource {
+    pub resource_id: String,
+    pub display_name: String,
+    pub account_id: String,
+    pub region: String,
+    pub resource_type: String,
+    pub status: Option<String>,
+    pub tags: Vec<ResourceTag>,
+}
+
+/// Which resources a search may return; empty lists match everything
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchFilter {
+    pub resource_types: Vec<String>,
+    pub accounts: Vec<String>,
+    pub regions: Vec<String>,
+}
+
+impl SearchFilter {
+    pub fn matches(&self, resource: &IndexedResource) -> bool {
+        (self.resource_types.is_empty() || self.resource_types.contains(&resource.resource_type))
+            && (self.accounts.is_empty() || self.accounts.contains(&resource.account_id))
+            && (self.regions.is_empty() || self.regions.contains(&resource.region))
+    }
+}
+
+/// A resource relevant to a question
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit<'a> {
+    pub resource: &'a IndexedResource,
+    /// Cosine similarity, 0 to 1
+    pub score: f32,
+    /// Question terms found in the resource, directly or through a concept
+    pub matched_terms: Vec<String>,
+}
+
+/// One indexed resource with its vector and terms
+#[derive(Debug, Clone)]
+struct IndexedDocument {
+    resource: IndexedResource,
+    vector: SparseVector,
+    /// Sorted terms and `~concept`s, for explaining matches
+    terms: Vec<String>,
+}
+
+/// Embedded resources of the Explorer cache
+#[derive(Debug, Default)]
+pub struct ResourceIndex {
+    documents: Vec<IndexedDocument>,
+    /// Feature -> (document, weight), for scoring only documents that share a feature
+    postings: HashMap<u32, Vec<(u32, f32)>>,
+    /// Inverse document frequency of each feature
+    idf: HashMap<u32, f32>,
+    /// Identifies the cache contents the index was built from
+    fingerprint: u64,
+}
+
+impl ResourceIndex {
+    /// Index resources, skipping duplicates of the same resource
+    pub fn build<'a>(entries: impl IntoIterator<Item = &'a ResourceEntry>) -> Self {
+        let mut seen = HashSet::new();
+        let mut raw = Vec::new();
+        for entry in entries {
+            if seen.insert(entry.selection_key()) {
+                raw.push(document_features(entry));
+            }
+        }
+
+        let mut document_frequency: HashMap<u32, usize> = HashMap::new();
+        for (_, features, _) in &raw {
+            for id in features.keys() {
+                *document_frequency.entry(*id).or_default() += 1;
+            }
+        }
+        let total = raw.len() as f32;
+        let idf: HashMap<u32, f32> = document_frequency
+            .into_iter()
+            .map(|(id, df)| (id, ((total + 1.0) / (df as f32 + 1.0)).ln() + 1.0))
+            .collect();
+
+        let mut postings: HashMap<u32, Vec<(u32, f32)>> = HashMap::new();
+        let documents: Vec<IndexedDocument> = raw
+            .into_iter()
+            .enumerate()
+            .map(|(index, (resource, features, terms))| {
+                let vector = SparseVector::from_features(features, |id| idf[&id]);
+                for (id, weight) in vector.entries() {
+                    postings
+                        .entry(*id)
+                        .or_default()
+                        .push((index as u32, *weight));
+                }
+                IndexedDocument {
+                    resource,
+                    vector,
+                    terms,
+                }
+            })
+            .collect();
+
+        Self {
+            documents,
+            postings,
+            idf,
+            fingerprint: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// Resources most relevant to `query`, best first
+    pub fn search(&self, query: &str, filter: &SearchFilter, limit: usize) -> Vec<SearchHit<'_>> {
+        let query_terms = tokenize(query);
+        let query_vector = self.query_vector(&query_terms);
+        if query_vector.is_empty() {
+            return Vec::new();
+        }
+
+        let mut scores: HashMap<u32, f32> = HashMap::new();
+        for (id, query_weight) in query_vector.entries() {
+            for (document, weight) in self.postings.get(id).into_iter().flatten() {
+                *scores.entry(*document).or_default() += query_weight * weight;
+            }
+        }
+
+        let mut ranked: Vec<(u32, f32)> = scores
+            .into_iter()
+            .filter(|(document, score)| {
+                *score >= MIN_SEARCH_SCORE
+                    && filter.matches(&self.documents[*document as usize].resource)
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        ranked.truncate(limit);
+
+        ranked
+            .into_iter()
+            .map(|(document, score)| {
+                let document = &self.documents[document as usize];
+                SearchHit {
+                    resource: &document.resource,
+                    score,
+                    matched_terms: matched_terms(&query_terms, &document.terms),
+                }
+            })
+            .collect()
+    }
+
+    /// Similarity of a question to one indexed resource, for tests and diagnostics
+    pub fn score(&self, query: &str, resource_id: &str) -> Option<f32> {
+        let document = self
+            .documents
+            .iter()
+            .find(|d| d.resource.resource_id == resource_id)?;
+        Some(self.query_vector(&tokenize(query)).cosine(&document.vector))
+    }
+
+    /// Embed question terms with the index's term weights
+    fn query_vector(&self, terms: &[String]) -> SparseVector {
+        let mut features = HashMap::new();
+        add_features(&mut features, terms, 1.0);
+
+        // Features no resource has still count against the question's norm
+        let unseen_idf = (self.documents.len() as f32 + 1.0).ln() + 1.0;
+        SparseVector::from_features(features, |id| {
+            self.idf.get(&id).copied().unwrap_or(unseen_idf)
+        })
+    }
+}
+
+/// The resource, its weighted features, and its sorted terms
+fn document_features(entry: &ResourceEntry) -> (IndexedResource, HashMap<u32, f32>, Vec<String>) {
+    let mut features = HashMap::new();
+    let mut terms = HashSet::new();
+    let mut add = |text: &str, weight: f32| {
+        let tokens = tokenize(text);
+        add_features(&mut features, &tokens, weight);
+        terms.extend(tokens);
+    };
+
+    add(&entry.display_name, NAME_WEIGHT);
+    if entry.resource_id != entry.display_name {
+        add(&entry.resource_id, NAME_WEIGHT);
+    }
+    // "AWS::RDS::DBInstance" also adds "dbinstance", which names a concept
+    add(&entry.resource_type, TAG_WEIGHT);
+    if let Some(kind) = entry.resource_type.rsplit("::").next() {
+        add(&kind.to_lowercase(), TAG_WEIGHT);
+    }
+    for tag in &entry.tags {
+        add(&tag.key, PROPERTY_WEIGHT);
+        add(&tag.value, TAG_WEIGHT);
+    }
+    if let Some(status) = &entry.status {
+        add(status, PROPERTY_WEIGHT);
+    }
+
+    let mut property_text = String::new();
+    collect_property_text(&entry.properties, &mut property_text);
+    add(&property_text, PROPERTY_WEIGHT);
+
+    let mut terms: Vec<String> = terms
+        .into_iter()
+        .flat_map(|term| {
+            let concept = concept_of(&term).map(|c| format!("~{}", c));
+            std::iter::once(term).chain(concept)
+        })
+        .collect();
+    terms.sort();
+    terms.dedup();
+
+    let resource = IndexedResource {
+        resource_id: entry.resource_id.clone(),
+        display_name: entry.display_name.clone(),
+        account_id: entry.account_id.clone(),
+        region: entry.region.clone(),
+        resource_type: entry.resource_type.clone(),
+        status: entry.status.clone(),
+        tags: entry.tags.clone(),
+    };
+    (resource, features, terms)
+}
+
+/// Append short string values of a property tree, up to `MAX_PROPERTY_TEXT`
+fn collect_property_text(value: &serde_json::Value, text: &mut String) {
+    if text.len() >= MAX_PROPERTY_TEXT {
+        return;
+    }
+    match value {
+        serde_json::Value::String(s) if s.len() <= MAX_PROPERTY_VALUE_LEN => {
+            text.push_str(s);
+            text.push(' ');
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_property_text(item, text);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for item in map.values() {
+                collect_property_text(item, text);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Question terms the resource has, directly or through a shared concept
+fn matched_terms(query_terms: &[String], document_terms: &[String]) -> Vec<String> {
+    let has = |term: &str| {
+        document_terms
+            .binary_search_by(|t| t.as_str().cmp(term))
+            .is_ok()
+    };
+    let mut matched: Vec<String> = query_terms
+        .iter()
+        .filter(|term| {
+            has(term) || concept_of(term).is_some_and(|concept| has(&format!("~{}", concept)))
+        })
+        .cloned()
+        .collect();
+    matched.dedup();
+    matched
+}
+
+/// Identifies the cache contents: its query keys and when each was stored
+fn cache_fingerprint(cache: &SharedResourceCache) -> u64 {
+    let mut keys: Vec<(String, Option<DateTime<Utc>>)> = cache
+        .resource_keys()
+        .into_iter()
+        .map(|key| {
+            let cached_at = cache.cached_at(&key);
+            (key, cached_at)
+        })
+        .collect();
+    keys.sort();
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    keys.hash(&mut hasher);
+    hasher.finish()
+}
+
+static SHARED_INDEX: OnceLock<Mutex<Option<Arc<ResourceIndex>>>> = OnceLock::new();
+
+/// Index of the cache's current contents, rebuilt when the cache changed
+pub fn index_for_cache(cache: &SharedResourceCache) -> Arc<ResourceIndex> {
+    let fingerprint = cache_fingerprint(cache);
+    let shared = SHARED_INDEX.get_or_init(|| Mutex::new(None));
+    let mut guard = shared.lock().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(index) = guard.as_ref().filter(|i| i.fingerprint == fingerprint) {
+        return index.clone();
+    }
+
+    let start = std::time::Instant::now();
+    let entries: Vec<Arc<ResourceEntry>> = cache
+        .resource_keys()
+        .iter()
+        .filter_map(|key| cache.get_resources(key))
+        .flatten()
+        .collect();
+    let mut index = ResourceIndex::build(entries.iter().map(|entry| entry.as_ref()));
+    index.fingerprint = fingerprint;
+    tracing::info!(
+        "Indexed {} cached resources for search in {}ms",
+        index.len(),
+        start.elapsed().as_millis()
+    );
+
+    let index = Arc::new(index);
+    *guard = Some(index.clone());
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn entry(
+        resource_type: &str,
+        id: &str,
+        name: &str,
+        properties: serde_json::Value,
+    ) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: id.to_string(),
+            display_name: name.to_string(),
+            status: None,
+            properties,
+            detailed_timestamp: None,
+            tags: vec![],
+            relationships: vec![],
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: Utc::now(),
+        }
+    }
+
+    fn sample_index() -> ResourceIndex {
+        let mut tagged_table = entry(
+            "AWS::DynamoDB::Table",
+            "ledger-entries",
+            "ledger-entries",
+            json!({"TableStatus": "ACTIVE"}),
+        );
+        tagged_table.tags = vec![ResourceTag {
+            key: "Team".to_string(),
+            value: "payments".to_string(),
+        }];
+
+        let entries = [
+            entry(
+                "AWS::RDS::DBInstance",
+                "payments-db",
+                "payments-db",
+                json!({"Engine": "postgres", "DBInstanceClass": "db.r6g.large"}),
+            ),
+            entry(
+                "AWS::Lambda::Function",
+                "payments-api-handler",
+                "payments-api-handler",
+                json!({"Runtime": "python3.12"}),
+            ),
+            entry(
+                "AWS::RDS::DBInstance",
+                "orders-db",
+                "orders-db",
+                json!({"Engine": "mysql"}),
+            ),
+            entry(
+                "AWS::S3::Bucket",
+                "payments-receipts",
+                "payments-receipts",
+                json!({}),
+            ),
+            tagged_table,
+        ];
+        ResourceIndex::build(entries.iter())
+    }
+
+    #[test]
+    fn test_search_ranks_by_meaning() {
+        let index = sample_index();
+        assert_eq!(index.len(), 5);
+
+        let hits = index.search("the payments database", &SearchFilter::default(), 10);
+        assert_eq!(hits[0].resource.resource_id, "payments-db");
+        assert_eq!(hits[0].matched_terms, vec!["payment", "database"]);
+        assert!(hits.iter().any(|h| h.resource.resource_id == "orders-db"));
+
+        let hits = index.search("payments lambda", &SearchFilter::default(), 10);
+        assert_eq!(hits[0].resource.resource_id, "payments-api-handler");
+
+        let hits = index.search("receipts bucket", &SearchFilter::default(), 1);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].resource.resource_id, "payments-receipts");
+    }
+
+    #[test]
+    fn test_search_uses_tags_and_typos() {
+        let index = sample_index();
+        let score = |query: &str| index.score(query, "ledger-entries").unwrap();
+        assert!(score("payments team table") > score("orders team table"));
+
+        let hits = index.search("paymnts db", &SearchFilter::default(), 10);
+        assert_eq!(hits[0].resource.resource_id, "payments-db");
+    }
+
+    #[test]
+    fn test_search_filter_and_empty_query() {
+        let index = sample_index();
+        let filter = SearchFilter {
+            resource_types: vec!["AWS::S3::Bucket".to_string()],
+            ..Default::default()
+        };
+        let hits = index.search("payments", &filter, 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].resource.resource_type, "AWS::S3::Bucket");
+
+        assert!(index
+            .search("the of and", &SearchFilter::default(), 10)
+            .is_empty());
+        assert!(ResourceIndex::default()
+            .search("payments", &SearchFilter::default(), 10)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_resources_indexed_once() {
+        let db = entry(
+            "AWS::RDS::DBInstance",
+            "payments-db",
+            "payments-db",
+            json!({}),
+        );
+        let index = ResourceIndex::build([&db, &db]);
+        assert_eq!(index.len(), 1);
+    }
+}
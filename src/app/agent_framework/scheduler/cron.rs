@@ -0,0 +1,261 @@
+//! Cron Schedules
+//!
+//! Parses standard five-field cron expressions (`minute hour day-of-month
+//! month day-of-week`) and finds the next matching minute in local time.
+//! Each field accepts `*`, numbers, ranges (`1-5`), lists (`1,15`) and steps
+//! (`*/15`, `0-30/10`). Day-of-week uses 0-6 with Sunday as 0 (7 is also
+//! Sunday). As in cron, when both day fields are restricted a day matches if
+//! either does.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use chrono::{DateTime, Datelike, Duration, Local, TimeZone, Timelike};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Longest search for the next run (cron expressions like `0 0 30 2 *` never
+/// match)
+const MAX_SEARCH_DAYS: i64 = 366 * 4;
+
+/// A parsed cron expression
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    /// Day-of-month field was `*`
+    any_day_of_month: bool,
+    /// Day-of-week field was `*`
+    any_day_of_week: bool,
+}
+
+impl CronSchedule {
+    /// Parse a five-field cron expression
+    pub fn parse(expression: &str) -> Result<Self, String> {
+        let fields: Vec<&str> = expression.split_whitespace().collect();
+        if fields.len() != 5 {
+            return Err(format!(
+                "Expected 5 fields (minute hour day month weekday), found {}",
+                fields.len()
+            ));
+        }
+
+        let days_of_week = parse_field(fields[4], 0, 7, "weekday")?;
+        // 7 is an alias for Sunday
+        let days_of_week = if days_of_week & (1 << 7) != 0 {
+            (days_of_week | 1) & !(1 << 7)
+        } else {
+            days_of_week
+        };
+
+        Ok(Self {
+            expression: fields.join(" "),
+            minutes: parse_field(fields[0], 0, 59, "minute")?,
+            hours: parse_field(fields[1], 0, 23, "hour")?,
+            days_of_month: parse_field(fields[2], 1, 31, "day")?,
+            months: parse_field(fields[3], 1, 12, "month")?,
+            days_of_week,
+            any_day_of_month: fields[2] == "*",
+            any_day_of_week: fields[4] == "*",
+        })
+    }
+
+    /// The expression as entered (whitespace normalized)
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// First matching minute strictly after `after`
+    pub fn next_after(&self, after: DateTime<Local>) -> Option<DateTime<Local>> {
+        let start = after.with_second(0)?.with_nanosecond(0)? + Duration::minutes(1);
+        let mut date = start.date_naive();
+        let last_date = date + Duration::days(MAX_SEARCH_DAYS);
+
+        while date <= last_date {
+            if self.matches_day(date) {
+                for hour in 0..24u32 {
+                    if !bit(self.hours, hour) {
+                        continue;
+                    }
+                    for minute in 0..60u32 {
+                        if !bit(self.minutes, minute) {
+                            continue;
+                        }
+                        let Some(naive) = date.and_hms_opt(hour, minute, 0) else {
+                            continue;
+                        };
+                        // Skip times that do not exist (DST gaps); take the
+                        // earlier of ambiguous times
+                        let Some(candidate) = Local.from_local_datetime(&naive).earliest() else {
+                            continue;
+                        };
+                        if candidate >= start {
+                            return Some(candidate);
+                        }
+                    }
+                }
+            }
+            date = date.succ_opt()?;
+        }
+        None
+    }
+
+    fn matches_day(&self, date: chrono::NaiveDate) -> bool {
+        if !bit(self.months, date.month()) {
+            return false;
+        }
+        let day_of_month = bit(self.days_of_month, date.day());
+        let day_of_week = bit(self.days_of_week, date.weekday().num_days_from_sunday());
+        match (self.any_day_of_month, self.any_day_of_week) {
+            (true, true) => true,
+            (false, true) => day_of_month,
+            (true, false) => day_of_week,
+            (false, false) => day_of_month || day_of_week,
+        }
+    }
+}
+
+impl fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+impl TryFrom<String> for CronSchedule {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<CronSchedule> for String {
+    fn from(value: CronSchedule) -> Self {
+        value.expression
+    }
+}
+
+fn bit(mask: u64, value: u32) -> bool {
+    mask & (1 << value) != 0
+}
+
+/// Parse one cron field into a bit mask of allowed values
+fn parse_field(field: &str, min: u32, max: u32, name: &str) -> Result<u64, String> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => {
+                let step: u32 = step
+                    .parse()
+                    .map_err(|_| format!("Invalid {} step '{}'", name, step))?;
+                if step == 0 {
+                    return Err(format!("Invalid {} step '0'", name));
+                }
+                (range, step)
+            }
+            None => (part, 1),
+        };
+
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (
+                parse_value(start, min, max, name)?,
+                parse_value(end, min, max, name)?,
+            )
+        } else {
+            let value = parse_value(range, min, max, name)?;
+            // `5/10` means from 5 to the end in steps of 10
+            (value, if part.contains('/') { max } else { value })
+        };
+        if start > end {
+            return Err(format!("Invalid {} range '{}'", name, range));
+        }
+
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+fn parse_value(value: &str, min: u32, max: u32, name: &str) -> Result<u32, String> {
+    let parsed: u32 = value
+        .parse()
+        .map_err(|_| format!("Invalid {} '{}'", name, value))?;
+    if parsed < min || parsed > max {
+        return Err(format!(
+            "{} {} is out of range ({}-{})",
+            name, parsed, min, max
+        ));
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn local(y: i32, mo: u32, d: u32, h: u32, mi: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(y, mo, d, h, mi, 0)
+            .earliest()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(CronSchedule::parse("* * * *").is_err());
+        assert!(CronSchedule::parse("60 * * * *").is_err());
+        assert!(CronSchedule::parse("*/0 * * * *").is_err());
+        assert!(CronSchedule::parse("5-1 * * * *").is_err());
+        assert!(CronSchedule::parse("a * * * *").is_err());
+        assert!(CronSchedule::parse("*/15  9-17 * * 1-5").is_ok());
+    }
+
+    #[test]
+    fn test_next_after() {
+        // Every 15 minutes
+        let schedule = CronSchedule::parse("*/15 * * * *").unwrap();
+        assert_eq!(
+            schedule.next_after(local(2025, 1, 6, 10, 7)),
+            Some(local(2025, 1, 6, 10, 15))
+        );
+        assert_eq!(
+            schedule.next_after(local(2025, 1, 6, 10, 15)),
+            Some(local(2025, 1, 6, 10, 30))
+        );
+
+        // 09:00 on weekdays; 2025-01-04 is a Saturday
+        let schedule = CronSchedule::parse("0 9 * * 1-5").unwrap();
+        assert_eq!(
+            schedule.next_after(local(2025, 1, 4, 12, 0)),
+            Some(local(2025, 1, 6, 9, 0))
+        );
+
+        // Sunday as 7
+        let schedule = CronSchedule::parse("30 6 * * 7").unwrap();
+        assert_eq!(
+            schedule.next_after(local(2025, 1, 1, 0, 0)),
+            Some(local(2025, 1, 5, 6, 30))
+        );
+
+        // Never matches
+        let schedule = CronSchedule::parse("0 0 30 2 *").unwrap();
+        assert_eq!(schedule.next_after(local(2025, 1, 1, 0, 0)), None);
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let schedule = CronSchedule::parse("0  8 1 * *").unwrap();
+        let json = serde_json::to_string(&schedule).unwrap();
+        assert_eq!(json, "\"0 8 1 * *\"");
+        let parsed: CronSchedule = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, schedule);
+        assert!(serde_json::from_str::<CronSchedule>("\"bad\"").is_err());
+    }
+}
@@ -0,0 +1,21 @@
+//! Scheduled Agent Tasks
+//!
+//! Runs saved agent tasks (prompt + skill + scope) on a cron-like schedule
+//! in the background, and queued tasks as soon as a slot is free.
+//!
+//! - [`CronSchedule`]: five-field cron expressions and next-run calculation
+//! - [`ScheduledTask`]: a saved task and the prompt sent to its agent
+//! - [`ScheduleStore`]: tasks, run history and run results on disk
+//! - [`TaskScheduler`]: decides which tasks are due, driven by the UI frame loop
+//! - [`TaskQueue`]: prioritized tasks run one at a time or up to N in parallel
+//!
+//! The agent manager window starts an agent for each due or dequeued task
+//! and records the result when the agent finishes.
+
+pub mod cron;
+pub mod queue;
+pub mod tasks;
+
+pub use cron::*;
+pub use queue::*;
+pub use tasks::*;
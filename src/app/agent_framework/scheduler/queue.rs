@@ -0,0 +1,481 @@
+//! Task Queue
+//!
+//! Agent tasks queued by the user to run one after another, or up to a
+//! configured number at a time. Waiting tasks start highest priority first,
+//! then in queue order, which the user can rearrange. The queue and its
+//! finished tasks are saved after every change; tasks that were running
+//! when the application closed are queued again on the next start.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Most tasks that may run at the same time
+pub const MAX_PARALLEL_TASKS: usize = 8;
+
+/// Finished tasks kept in the queue; the oldest are dropped first
+const MAX_FINISHED_TASKS: usize = 100;
+
+/// Priority of a queued task
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+pub enum TaskPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl TaskPriority {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            TaskPriority::Low => "Low",
+            TaskPriority::Normal => "Normal",
+            TaskPriority::High => "High",
+        }
+    }
+
+    /// All priorities, highest first
+    pub fn all() -> &'static [TaskPriority] {
+        &[TaskPriority::High, TaskPriority::Normal, TaskPriority::Low]
+    }
+}
+
+/// Where a task is in its life cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum QueuedTaskState {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl QueuedTaskState {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            QueuedTaskState::Queued => "Queued",
+            QueuedTaskState::Running => "Running",
+            QueuedTaskState::Completed => "Completed",
+            QueuedTaskState::Failed => "Failed",
+            QueuedTaskState::Cancelled => "Cancelled",
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self,
+            QueuedTaskState::Completed | QueuedTaskState::Failed | QueuedTaskState::Cancelled
+        )
+    }
+}
+
+/// An agent task in the queue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedTask {
+    pub id: String,
+    pub name: String,
+    /// Instructions sent to the agent
+    pub prompt: String,
+    pub priority: TaskPriority,
+    pub state: QueuedTaskState,
+    pub enqueued_at: DateTime<Utc>,
+    #[serde(default)]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub finished_at: Option<DateTime<Utc>>,
+    /// Error message, or the first line of the agent's response
+    #[serde(default)]
+    pub summary: Option<String>,
+}
+
+impl QueuedTask {
+    pub fn new(name: impl Into<String>, prompt: impl Into<String>, priority: TaskPriority) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.into(),
+            prompt: prompt.into(),
+            priority,
+            state: QueuedTaskState::Queued,
+            enqueued_at: Utc::now(),
+            started_at: None,
+            finished_at: None,
+            summary: None,
+        }
+    }
+}
+
+/// Saved contents of the queue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+struct QueueFile {
+    /// Tasks allowed to run at once (1 = one after another)
+    max_parallel: usize,
+    tasks: Vec<QueuedTask>,
+}
+
+impl Default for QueueFile {
+    fn default() -> Self {
+        Self {
+            max_parallel: 1,
+            tasks: Vec::new(),
+        }
+    }
+}
+
+/// Queue of agent tasks, saved to disk after every change
+///
+/// Driven from the UI frame loop: [`TaskQueue::start_next`] hands out the
+/// tasks to start, and [`TaskQueue::finish`] records each result.
+#[derive(Debug, Default)]
+pub struct TaskQueue {
+    path: Option<PathBuf>,
+    file: QueueFile,
+}
+
+impl TaskQueue {
+    /// Queue saved at `path` (None = keep tasks in memory only)
+    pub fn new(path: Option<PathBuf>) -> Self {
+        let mut file = match &path {
+            Some(path) if path.exists() => Self::load_from(path).unwrap_or_else(|e| {
+                tracing::warn!("Failed to load task queue: {:#}", e);
+                QueueFile::default()
+            }),
+            _ => QueueFile::default(),
+        };
+        // Runs interrupted by the last shutdown start over
+        for task in &mut file.tasks {
+            if task.state == QueuedTaskState::Running {
+                task.state = QueuedTaskState::Queued;
+                task.started_at = None;
+            }
+        }
+        file.max_parallel = file.max_parallel.clamp(1, MAX_PARALLEL_TASKS);
+        Self { path, file }
+    }
+
+    /// Queue in the application data directory
+    pub fn default_location() -> Self {
+        Self::new(dirs::data_local_dir().map(|dir| dir.join("awsdash/task_queue.json")))
+    }
+
+    fn load_from(path: &Path) -> Result<QueueFile> {
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+        let result = (|| -> Result<()> {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+            let json = serde_json::to_string_pretty(&self.file)?;
+            fs::write(path, json).with_context(|| format!("Failed to write {}", path.display()))
+        })();
+        if let Err(e) = result {
+            tracing::warn!("Failed to save task queue: {:#}", e);
+        }
+    }
+
+    /// All tasks in queue order
+    pub fn tasks(&self) -> &[QueuedTask] {
+        &self.file.tasks
+    }
+
+    pub fn task(&self, task_id: &str) -> Option<&QueuedTask> {
+        self.file.tasks.iter().find(|t| t.id == task_id)
+    }
+
+    pub fn count(&self, state: QueuedTaskState) -> usize {
+        self.file.tasks.iter().filter(|t| t.state == state).count()
+    }
+
+    /// Tasks allowed to run at once (1 = serial)
+    pub fn max_parallel(&self) -> usize {
+        self.file.max_parallel
+    }
+
+    pub fn set_max_parallel(&mut self, max_parallel: usize) {
+        let max_parallel = max_parallel.clamp(1, MAX_PARALLEL_TASKS);
+        if self.file.max_parallel != max_parallel {
+            self.file.max_parallel = max_parallel;
+            self.save();
+        }
+    }
+
+    /// Add a task to the end of the queue
+    pub fn enqueue(&mut self, task: QueuedTask) {
+        self.file.tasks.push(task);
+        self.save();
+    }
+
+    pub fn set_priority(&mut self, task_id: &str, priority: TaskPriority) {
+        if let Some(task) = self.file.tasks.iter_mut().find(|t| t.id == task_id) {
+            task.priority = priority;
+            self.save();
+        }
+    }
+
+    /// Swap a waiting task with the waiting task before (`up`) or after it
+    pub fn move_task(&mut self, task_id: &str, up: bool) {
+        let waiting: Vec<usize> = self
+            .file
+            .tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| t.state == QueuedTaskState::Queued)
+            .map(|(index, _)| index)
+            .collect();
+        let Some(position) = waiting
+            .iter()
+            .position(|index| self.file.tasks[*index].id == task_id)
+        else {
+            return;
+        };
+        let other = if up {
+            position.checked_sub(1)
+        } else {
+            Some(position + 1).filter(|p| *p < waiting.len())
+        };
+        if let Some(other) = other {
+            self.file.tasks.swap(waiting[position], waiting[other]);
+            self.save();
+        }
+    }
+
+    /// Cancel a waiting or running task; the caller stops a running agent
+    pub fn cancel(&mut self, task_id: &str) {
+        if let Some(task) = self
+            .file
+            .tasks
+            .iter_mut()
+            .find(|t| t.id == task_id && !t.state.is_finished())
+        {
+            task.state = QueuedTaskState::Cancelled;
+            task.finished_at = Some(Utc::now());
+            self.trim_and_save();
+        }
+    }
+
+    /// Put a finished task back in the queue
+    pub fn requeue(&mut self, task_id: &str) {
+        if let Some(task) = self
+            .file
+            .tasks
+            .iter_mut()
+            .find(|t| t.id == task_id && t.state.is_finished())
+        {
+            task.state = QueuedTaskState::Queued;
+            task.started_at = None;
+            task.finished_at = None;
+            task.summary = None;
+            self.save();
+        }
+    }
+
+    /// Delete a task that is not running
+    pub fn remove(&mut self, task_id: &str) {
+        let before = self.file.tasks.len();
+        self.file
+            .tasks
+            .retain(|t| t.id != task_id || t.state == QueuedTaskState::Running);
+        if self.file.tasks.len() != before {
+            self.save();
+        }
+    }
+
+    /// Delete all finished tasks
+    pub fn clear_finished(&mut self) {
+        self.file.tasks.retain(|t| !t.state.is_finished());
+        self.save();
+    }
+
+    /// Tasks to start now, marked as running
+    ///
+    /// Fills the free slots with waiting tasks, highest priority first and
+    /// in queue order within a priority.
+    pub fn start_next(&mut self) -> Vec<QueuedTask> {
+        let running = self.count(QueuedTaskState::Running);
+        let free = self.file.max_parallel.saturating_sub(running);
+        if free == 0 {
+            return Vec::new();
+        }
+
+        let mut waiting: Vec<usize> = self
+            .file
+            .tasks
+            .iter()
+            .enumerate()
+            .filter(|(_, t)| t.state == QueuedTaskState::Queued)
+            .map(|(index, _)| index)
+            .collect();
+        // Stable sort keeps queue order within a priority
+        waiting.sort_by_key(|index| std::cmp::Reverse(self.file.tasks[*index].priority));
+
+        let now = Utc::now();
+        let started: Vec<QueuedTask> = waiting
+            .into_iter()
+            .take(free)
+            .map(|index| {
+                let task = &mut self.file.tasks[index];
+                task.state = QueuedTaskState::Running;
+                task.started_at = Some(now);
+                task.clone()
+            })
+            .collect();
+        if !started.is_empty() {
+            self.save();
+        }
+        started
+    }
+
+    /// Record the result of a running task
+    pub fn finish(&mut self, task_id: &str, result: &Result<String, String>) {
+        let Some(task) = self
+            .file
+            .tasks
+            .iter_mut()
+            .find(|t| t.id == task_id && t.state == QueuedTaskState::Running)
+        else {
+            return; // Cancelled meanwhile
+        };
+        let (state, text) = match result {
+            Ok(response) => (QueuedTaskState::Completed, response),
+            Err(error) => (QueuedTaskState::Failed, error),
+        };
+        task.state = state;
+        task.finished_at = Some(Utc::now());
+        task.summary = text
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .map(|line| line.chars().take(200).collect());
+        self.trim_and_save();
+    }
+
+    fn trim_and_save(&mut self) {
+        let finished = self
+            .file
+            .tasks
+            .iter()
+            .filter(|t| t.state.is_finished())
+            .count();
+        let mut excess = finished.saturating_sub(MAX_FINISHED_TASKS);
+        if excess > 0 {
+            // Tasks finish roughly in queue order, so drop from the front
+            self.file.tasks.retain(|t| {
+                if excess > 0 && t.state.is_finished() {
+                    excess -= 1;
+                    false
+                } else {
+                    true
+                }
+            });
+        }
+        self.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn task(name: &str, priority: TaskPriority) -> QueuedTask {
+        QueuedTask::new(name, format!("Run {}", name), priority)
+    }
+
+    fn names(tasks: &[QueuedTask]) -> Vec<&str> {
+        tasks.iter().map(|t| t.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_serial_queue_starts_by_priority() {
+        let mut queue = TaskQueue::new(None);
+        queue.enqueue(task("a", TaskPriority::Normal));
+        queue.enqueue(task("b", TaskPriority::Low));
+        queue.enqueue(task("c", TaskPriority::High));
+        queue.enqueue(task("d", TaskPriority::Normal));
+
+        let started = queue.start_next();
+        assert_eq!(names(&started), vec!["c"]);
+        assert!(queue.start_next().is_empty());
+
+        queue.finish(&started[0].id, &Ok("\nAll done\nDetails".to_string()));
+        let finished = queue.task(&started[0].id).unwrap();
+        assert_eq!(finished.state, QueuedTaskState::Completed);
+        assert_eq!(finished.summary.as_deref(), Some("All done"));
+
+        assert_eq!(names(&queue.start_next()), vec!["a"]);
+    }
+
+    #[test]
+    fn test_parallel_slots_and_reordering() {
+        let mut queue = TaskQueue::new(None);
+        for name in ["a", "b", "c", "d"] {
+            queue.enqueue(task(name, TaskPriority::Normal));
+        }
+        let d = queue.tasks()[3].id.clone();
+        queue.move_task(&d, true);
+        queue.move_task(&d, true);
+        assert_eq!(names(queue.tasks()), vec!["a", "d", "b", "c"]);
+
+        queue.set_max_parallel(2);
+        let started = queue.start_next();
+        assert_eq!(names(&started), vec!["a", "d"]);
+
+        // Running tasks are not moved or removed
+        queue.move_task(&started[0].id, false);
+        queue.remove(&started[0].id);
+        assert_eq!(names(queue.tasks()), vec!["a", "d", "b", "c"]);
+
+        queue.cancel(&started[1].id);
+        queue.finish(&started[1].id, &Ok("late".to_string()));
+        assert_eq!(
+            queue.task(&started[1].id).unwrap().state,
+            QueuedTaskState::Cancelled
+        );
+        assert_eq!(names(&queue.start_next()), vec!["b"]);
+
+        queue.set_max_parallel(100);
+        assert_eq!(queue.max_parallel(), MAX_PARALLEL_TASKS);
+    }
+
+    #[test]
+    fn test_persisted_across_restarts() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("awsdash/task_queue.json");
+
+        let mut queue = TaskQueue::new(Some(path.clone()));
+        queue.set_max_parallel(3);
+        queue.enqueue(task("a", TaskPriority::Normal));
+        queue.enqueue(task("b", TaskPriority::High));
+        let running = queue.start_next();
+        assert_eq!(running.len(), 2);
+        queue.finish(&running[0].id, &Err("Access denied".to_string()));
+
+        let reloaded = TaskQueue::new(Some(path));
+        assert_eq!(reloaded.max_parallel(), 3);
+        assert_eq!(reloaded.count(QueuedTaskState::Failed), 1);
+        // The interrupted run is queued again
+        let interrupted = reloaded.task(&running[1].id).unwrap();
+        assert_eq!(interrupted.state, QueuedTaskState::Queued);
+        assert!(interrupted.started_at.is_none());
+
+        let mut reloaded = reloaded;
+        reloaded.requeue(&running[0].id);
+        reloaded.clear_finished();
+        assert_eq!(reloaded.count(QueuedTaskState::Queued), 2);
+    }
+}
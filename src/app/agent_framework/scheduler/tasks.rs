@@ -0,0 +1,444 @@
+//! Scheduled Tasks
+//!
+//! Saved agent tasks (prompt, optional skill and scope) with a cron schedule,
+//! the on-disk store for them, and a record of every run. Each run's final
+//! agent response is written to a markdown file next to the run history so
+//! results can be reviewed after the agent is gone.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Local, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::cron::CronSchedule;
+
+/// Runs kept in each task's history
+const MAX_RUN_HISTORY: usize = 50;
+
+/// An agent task run on a schedule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledTask {
+    pub id: String,
+    pub name: String,
+    /// Instructions sent to the agent
+    pub prompt: String,
+    /// Skill the agent should use, by name
+    #[serde(default)]
+    pub skill: Option<String>,
+    /// Accounts, regions or resources the task is limited to (free text)
+    #[serde(default)]
+    pub scope: Option<String>,
+    pub schedule: CronSchedule,
+    pub enabled: bool,
+}
+
+impl ScheduledTask {
+    pub fn new(name: impl Into<String>, prompt: impl Into<String>, schedule: CronSchedule) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: name.into(),
+            prompt: prompt.into(),
+            skill: None,
+            scope: None,
+            schedule,
+            enabled: true,
+        }
+    }
+
+    /// First message sent to the agent for a run
+    pub fn agent_prompt(&self) -> String {
+        let mut prompt = format!(
+            "This is a scheduled task ('{}') running unattended. No one will answer \
+             questions, so complete the task with the information available and end \
+             with a concise summary of the results.\n\n",
+            self.name
+        );
+        if let Some(skill) = self.skill.as_deref().filter(|s| !s.trim().is_empty()) {
+            prompt.push_str(&format!("Use the '{}' skill.\n", skill.trim()));
+        }
+        if let Some(scope) = self.scope.as_deref().filter(|s| !s.trim().is_empty()) {
+            prompt.push_str(&format!("Limit the task to: {}\n", scope.trim()));
+        }
+        prompt.push('\n');
+        prompt.push_str(&self.prompt);
+        prompt
+    }
+}
+
+/// Result of one scheduled run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledRun {
+    pub task_id: String,
+    pub task_name: String,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+    pub success: bool,
+    /// Error message, or the first line of the agent's response
+    pub summary: String,
+    /// Markdown file with the full response
+    pub result_path: Option<PathBuf>,
+    /// VFS snapshot saved at the end of the run
+    #[serde(default)]
+    pub vfs_session_id: Option<String>,
+}
+
+/// On-disk store of scheduled tasks and their run history
+///
+/// Layout: `tasks.json` holds all tasks; `runs/<task id>/history.json` holds
+/// the run records and `runs/<task id>/<timestamp>.md` each run's result.
+#[derive(Debug, Clone)]
+pub struct ScheduleStore {
+    directory: PathBuf,
+}
+
+impl ScheduleStore {
+    /// Create a store rooted at `directory`
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Store in the application data directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(dirs::data_local_dir()?.join("awsdash/schedules")))
+    }
+
+    /// Directory holding tasks and run results
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn tasks_path(&self) -> PathBuf {
+        self.directory.join("tasks.json")
+    }
+
+    fn runs_directory(&self, task_id: &str) -> PathBuf {
+        self.directory.join("runs").join(task_id)
+    }
+
+    /// All saved tasks (empty when none were saved yet)
+    pub fn load_tasks(&self) -> Result<Vec<ScheduledTask>> {
+        let path = self.tasks_path();
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let json = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Replace the saved tasks
+    pub fn save_tasks(&self, tasks: &[ScheduledTask]) -> Result<()> {
+        fs::create_dir_all(&self.directory).with_context(|| {
+            format!(
+                "Failed to create schedule directory {}",
+                self.directory.display()
+            )
+        })?;
+        let path = self.tasks_path();
+        let json = serde_json::to_string_pretty(tasks)?;
+        fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Save the result of a run and add it to the task's history
+    pub fn record_run(
+        &self,
+        task: &ScheduledTask,
+        started_at: DateTime<Utc>,
+        result: &Result<String, String>,
+        vfs_session_id: Option<String>,
+    ) -> Result<ScheduledRun> {
+        let directory = self.runs_directory(&task.id);
+        fs::create_dir_all(&directory)
+            .with_context(|| format!("Failed to create {}", directory.display()))?;
+
+        let finished_at = Utc::now();
+        let result_path = directory.join(format!(
+            "{}.md",
+            started_at.with_timezone(&Local).format("%Y%m%d-%H%M%S")
+        ));
+        let (status, body) = match result {
+            Ok(response) => ("Succeeded", response.as_str()),
+            Err(error) => ("Failed", error.as_str()),
+        };
+        let markdown = format!(
+            "# {}\n\n- Status: {}\n- Started: {}\n- Finished: {}\n- Schedule: {}\n\n{}\n",
+            task.name,
+            status,
+            started_at.with_timezone(&Local).format("%Y-%m-%d %H:%M:%S"),
+            finished_at
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M:%S"),
+            task.schedule,
+            body
+        );
+        fs::write(&result_path, markdown)
+            .with_context(|| format!("Failed to write {}", result_path.display()))?;
+
+        let summary = body
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .unwrap_or_default()
+            .chars()
+            .take(200)
+            .collect();
+        let run = ScheduledRun {
+            task_id: task.id.clone(),
+            task_name: task.name.clone(),
+            started_at,
+            finished_at,
+            success: result.is_ok(),
+            summary,
+            result_path: Some(result_path),
+            vfs_session_id,
+        };
+
+        let mut history = self.runs(&task.id);
+        history.insert(0, run.clone());
+        history.truncate(MAX_RUN_HISTORY);
+        let history_path = directory.join("history.json");
+        fs::write(&history_path, serde_json::to_string_pretty(&history)?)
+            .with_context(|| format!("Failed to write {}", history_path.display()))?;
+
+        Ok(run)
+    }
+
+    /// Run history for a task, newest first
+    pub fn runs(&self, task_id: &str) -> Vec<ScheduledRun> {
+        let path = self.runs_directory(task_id).join("history.json");
+        let Ok(json) = fs::read_to_string(&path) else {
+            return Vec::new(); // Never ran
+        };
+        serde_json::from_str(&json).unwrap_or_else(|e| {
+            tracing::warn!("Skipping unreadable run history {}: {}", path.display(), e);
+            Vec::new()
+        })
+    }
+
+    /// Delete a task's run history and results
+    pub fn remove_runs(&self, task_id: &str) -> Result<()> {
+        let directory = self.runs_directory(task_id);
+        if directory.exists() {
+            fs::remove_dir_all(&directory)
+                .with_context(|| format!("Failed to remove {}", directory.display()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Keeps the saved tasks and decides when each is due
+///
+/// Driven from the UI frame loop: call [`TaskScheduler::due_tasks`] each
+/// frame. Runs missed while the application was closed are not caught up;
+/// a task's first run is its first scheduled time after it is loaded.
+#[derive(Debug, Default)]
+pub struct TaskScheduler {
+    store: Option<ScheduleStore>,
+    tasks: Vec<ScheduledTask>,
+    next_runs: HashMap<String, DateTime<Local>>,
+}
+
+impl TaskScheduler {
+    /// Scheduler backed by `store` (None = keep tasks in memory only)
+    pub fn new(store: Option<ScheduleStore>) -> Self {
+        let tasks = match &store {
+            Some(store) => store.load_tasks().unwrap_or_else(|e| {
+                tracing::warn!("Failed to load scheduled tasks: {:#}", e);
+                Vec::new()
+            }),
+            None => Vec::new(),
+        };
+        let mut scheduler = Self {
+            store,
+            tasks,
+            next_runs: HashMap::new(),
+        };
+        scheduler.reschedule_all(Local::now());
+        scheduler
+    }
+
+    /// Scheduler using the default store location
+    pub fn default_location() -> Self {
+        Self::new(ScheduleStore::default_location())
+    }
+
+    pub fn store(&self) -> Option<&ScheduleStore> {
+        self.store.as_ref()
+    }
+
+    pub fn tasks(&self) -> &[ScheduledTask] {
+        &self.tasks
+    }
+
+    /// When the task runs next (None when disabled or never)
+    pub fn next_run(&self, task_id: &str) -> Option<DateTime<Local>> {
+        self.next_runs.get(task_id).copied()
+    }
+
+    /// Add a task, or replace the task with the same id
+    pub fn upsert(&mut self, task: ScheduledTask) -> Result<()> {
+        match self.tasks.iter_mut().find(|t| t.id == task.id) {
+            Some(existing) => *existing = task.clone(),
+            None => self.tasks.push(task.clone()),
+        }
+        self.schedule(&task, Local::now());
+        self.save()
+    }
+
+    /// Delete a task and its run history
+    pub fn remove(&mut self, task_id: &str) -> Result<()> {
+        self.tasks.retain(|t| t.id != task_id);
+        self.next_runs.remove(task_id);
+        if let Some(store) = &self.store {
+            store.remove_runs(task_id)?;
+        }
+        self.save()
+    }
+
+    /// Tasks whose scheduled time has come; each is scheduled again after `now`
+    pub fn due_tasks(&mut self, now: DateTime<Local>) -> Vec<ScheduledTask> {
+        let due: Vec<ScheduledTask> = self
+            .tasks
+            .iter()
+            .filter(|t| self.next_runs.get(&t.id).is_some_and(|next| *next <= now))
+            .cloned()
+            .collect();
+        for task in &due {
+            self.schedule(task, now);
+        }
+        due
+    }
+
+    /// Time until the next run of any task
+    pub fn time_until_next_run(&self, now: DateTime<Local>) -> Option<std::time::Duration> {
+        self.next_runs
+            .values()
+            .min()
+            .map(|next| (*next - now).to_std().unwrap_or_default())
+    }
+
+    fn schedule(&mut self, task: &ScheduledTask, after: DateTime<Local>) {
+        match task
+            .enabled
+            .then(|| task.schedule.next_after(after))
+            .flatten()
+        {
+            Some(next) => {
+                self.next_runs.insert(task.id.clone(), next);
+            }
+            None => {
+                self.next_runs.remove(&task.id);
+            }
+        }
+    }
+
+    fn reschedule_all(&mut self, after: DateTime<Local>) {
+        for task in self.tasks.clone() {
+            self.schedule(&task, after);
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        match &self.store {
+            Some(store) => store.save_tasks(&self.tasks),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn task(schedule: &str) -> ScheduledTask {
+        ScheduledTask::new(
+            "Unused EBS volumes",
+            "List unattached EBS volumes",
+            CronSchedule::parse(schedule).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_store_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let store = ScheduleStore::new(temp_dir.path().to_path_buf());
+        assert!(store.load_tasks().unwrap().is_empty());
+
+        let mut task = task("0 9 * * 1-5");
+        task.skill = Some("ebs-audit".to_string());
+        store.save_tasks(std::slice::from_ref(&task)).unwrap();
+        assert_eq!(store.load_tasks().unwrap(), vec![task.clone()]);
+
+        let started_at = Utc::now();
+        let run = store
+            .record_run(
+                &task,
+                started_at,
+                &Ok("\n3 unattached volumes\nDetails".to_string()),
+                None,
+            )
+            .unwrap();
+        assert!(run.success);
+        assert_eq!(run.summary, "3 unattached volumes");
+        let content = fs::read_to_string(run.result_path.as_ref().unwrap()).unwrap();
+        assert!(content.contains("Status: Succeeded"));
+
+        store
+            .record_run(&task, started_at, &Err("Access denied".to_string()), None)
+            .unwrap();
+        let runs = store.runs(&task.id);
+        assert_eq!(runs.len(), 2);
+        assert!(!runs[0].success);
+        assert_eq!(runs[0].summary, "Access denied");
+
+        store.remove_runs(&task.id).unwrap();
+        assert!(store.runs(&task.id).is_empty());
+    }
+
+    #[test]
+    fn test_due_tasks() {
+        let mut scheduler = TaskScheduler::new(None);
+        let hourly = task("0 * * * *");
+        let mut disabled = task("* * * * *");
+        disabled.enabled = false;
+        scheduler.upsert(hourly.clone()).unwrap();
+        scheduler.upsert(disabled.clone()).unwrap();
+        assert!(scheduler.next_run(&disabled.id).is_none());
+
+        let next = scheduler.next_run(&hourly.id).unwrap();
+        assert!(scheduler
+            .due_tasks(next - chrono::Duration::seconds(1))
+            .is_empty());
+
+        let due = scheduler.due_tasks(next);
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, hourly.id);
+        assert_eq!(
+            scheduler.next_run(&hourly.id),
+            hourly.schedule.next_after(next)
+        );
+        assert!(scheduler.due_tasks(next).is_empty());
+
+        scheduler.remove(&hourly.id).unwrap();
+        assert!(scheduler.next_run(&hourly.id).is_none());
+        assert_eq!(scheduler.tasks().len(), 1);
+    }
+
+    #[test]
+    fn test_agent_prompt() {
+        let mut task = task("0 9 * * *");
+        task.skill = Some("ebs-audit".to_string());
+        task.scope = Some("account 123456789012, us-east-1".to_string());
+        let prompt = task.agent_prompt();
+        assert!(prompt.contains("Use the 'ebs-audit' skill."));
+        assert!(prompt.contains("Limit the task to: account 123456789012, us-east-1"));
+        assert!(prompt.ends_with("List unattached EBS volumes"));
+    }
+}
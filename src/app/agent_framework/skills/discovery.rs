@@ -12,6 +12,8 @@ use std::sync::RwLock;
 use tracing::{debug, error, info, warn};
 use walkdir::WalkDir;
 
+use super::linter::{lint_skill, SkillLintReport};
+
 /// Skill metadata extracted from YAML frontmatter
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillMetadata {
@@ -65,6 +67,9 @@ pub struct SkillDiscoveryService {
     skill_directories: Vec<PathBuf>,
     /// Cached discovered skills
     discovered_skills: RwLock<Vec<SkillMetadata>>,
+    /// Lint results with issues from the last discovery (including skills
+    /// left out because of errors)
+    lint_reports: RwLock<Vec<SkillLintReport>>,
 }
 
 impl SkillDiscoveryService {
@@ -90,6 +95,7 @@ impl SkillDiscoveryService {
         Self {
             skill_directories,
             discovered_skills: RwLock::new(Vec::new()),
+            lint_reports: RwLock::new(Vec::new()),
         }
     }
 
@@ -98,14 +104,21 @@ impl SkillDiscoveryService {
         Self {
             skill_directories: directories,
             discovered_skills: RwLock::new(Vec::new()),
+            lint_reports: RwLock::new(Vec::new()),
         }
     }
 
+    /// Directories scanned for skills
+    pub fn skill_directories(&self) -> &[PathBuf] {
+        &self.skill_directories
+    }
+
     /// Scan all configured directories and discover skills
     ///
     /// Returns the number of skills discovered
     pub fn discover_skills(&self) -> Result<usize, SkillError> {
         let mut skills = Vec::new();
+        let mut lint_reports = Vec::new();
 
         for skill_dir in &self.skill_directories {
             if !skill_dir.exists() {
@@ -127,6 +140,25 @@ impl SkillDiscoveryService {
                 if path.is_file() && path.file_name() == Some(std::ffi::OsStr::new("SKILL.md")) {
                     debug!("Found SKILL.md: {:?}", path);
 
+                    // Skills that fail linting are not offered to agents
+                    let report = lint_skill(path);
+                    if report.has_errors() {
+                        warn!(
+                            "Skipping skill {:?}: {:?}",
+                            path,
+                            report
+                                .issues
+                                .iter()
+                                .map(|i| i.message.as_str())
+                                .collect::<Vec<_>>()
+                        );
+                        lint_reports.push(report);
+                        continue;
+                    }
+                    if !report.issues.is_empty() {
+                        lint_reports.push(report);
+                    }
+
                     match self.extract_metadata(path) {
                         Ok(metadata) => {
                             info!(
@@ -145,6 +177,10 @@ impl SkillDiscoveryService {
 
         let count = skills.len();
 
+        if let Ok(mut guard) = self.lint_reports.write() {
+            *guard = lint_reports;
+        }
+
         // Cache discovered skills
         match self.discovered_skills.write() {
             Ok(mut guard) => {
@@ -242,6 +278,17 @@ impl SkillDiscoveryService {
         }
     }
 
+    /// Lint results with issues from the last discovery
+    pub fn get_lint_reports(&self) -> Vec<SkillLintReport> {
+        match self.lint_reports.read() {
+            Ok(guard) => guard.clone(),
+            Err(e) => {
+                error!("Failed to read skill lint reports: {}", e);
+                Vec::new()
+            }
+        }
+    }
+
     /// Get a specific skill by name
     pub fn get_skill_by_name(&self, name: &str) -> Option<SkillMetadata> {
         match self.discovered_skills.read() {
@@ -270,7 +317,7 @@ impl Default for SkillDiscoveryService {
 /// ---
 /// # Skill Content
 /// ```
-fn extract_yaml_frontmatter(
+pub(super) fn extract_yaml_frontmatter(
     content: &str,
 ) -> Result<HashMap<serde_yaml::Value, serde_yaml::Value>, SkillError> {
     // Check for frontmatter start
@@ -0,0 +1,639 @@
+//! Skill Installer
+//!
+//! Installs skills from a Git repository or a zip archive (local file or
+//! HTTPS URL) into the AWS Dash skills directory (`~/.awsdash/skills`).
+//!
+//! Every install is staged in a temporary directory next to the skills
+//! directory and only moved into place once its SKILL.md frontmatter is
+//! valid and its checksum matches. Installed skills are recorded in
+//! `.installed.json` with their source, pinned Git ref, resolved commit and
+//! a SHA-256 of the installed files, so they can be updated, removed and
+//! checked for local modifications later.
+//!
+//! Git installs shell out to the `git` command line.
+
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tracing::{info, warn};
+use walkdir::WalkDir;
+
+use super::discovery::extract_yaml_frontmatter;
+
+/// Largest archive accepted for installation
+const MAX_ARCHIVE_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Maximum skill name length (Agent Skills specification)
+const MAX_NAME_LEN: usize = 64;
+
+/// Maximum skill description length (Agent Skills specification)
+const MAX_DESCRIPTION_LEN: usize = 1024;
+
+/// File recording installed skills, inside the skills directory
+const REGISTRY_FILE: &str = ".installed.json";
+
+/// Where a skill is installed from
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum SkillSource {
+    /// Git repository; `reference` pins a tag, branch or commit
+    Git {
+        url: String,
+        #[serde(default)]
+        reference: Option<String>,
+    },
+    /// Zip archive at a local path or HTTPS URL
+    Archive { location: String },
+}
+
+impl SkillSource {
+    /// Short description for the UI
+    pub fn describe(&self) -> String {
+        match self {
+            SkillSource::Git {
+                url,
+                reference: Some(reference),
+            } => format!("{} @ {}", url, reference),
+            SkillSource::Git {
+                url,
+                reference: None,
+            } => url.clone(),
+            SkillSource::Archive { location } => location.clone(),
+        }
+    }
+}
+
+/// What to install
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstallRequest {
+    pub source: SkillSource,
+    /// Expected SHA-256 (hex) of the archive, or of the installed files for
+    /// Git sources; installation fails on mismatch
+    pub expected_sha256: Option<String>,
+}
+
+/// A skill installed by the installer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstalledSkill {
+    pub name: String,
+    pub description: String,
+    /// `version` from the SKILL.md frontmatter, if present
+    #[serde(default)]
+    pub version: Option<String>,
+    pub source: SkillSource,
+    /// Checksum required at install time; updates must match it too
+    #[serde(default)]
+    pub expected_sha256: Option<String>,
+    /// Commit checked out, for Git sources
+    #[serde(default)]
+    pub commit: Option<String>,
+    /// SHA-256 of the installed files (see [`directory_sha256`])
+    pub content_sha256: String,
+    pub installed_at: DateTime<Utc>,
+}
+
+/// Installs, updates and removes skills in a skills directory
+#[derive(Debug, Clone)]
+pub struct SkillInstaller {
+    skills_dir: PathBuf,
+}
+
+impl SkillInstaller {
+    /// Create an installer for `skills_dir`
+    pub fn new(skills_dir: PathBuf) -> Self {
+        Self { skills_dir }
+    }
+
+    /// Installer for `~/.awsdash/skills`, or None without a home directory
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(dirs::home_dir()?.join(".awsdash/skills")))
+    }
+
+    /// Directory skills are installed into
+    pub fn skills_dir(&self) -> &Path {
+        &self.skills_dir
+    }
+
+    /// Skills installed by the installer, sorted by name
+    pub fn installed(&self) -> Vec<InstalledSkill> {
+        let path = self.skills_dir.join(REGISTRY_FILE);
+        let Ok(json) = fs::read_to_string(&path) else {
+            return Vec::new(); // Nothing installed yet
+        };
+        match serde_json::from_str::<Vec<InstalledSkill>>(&json) {
+            Ok(mut skills) => {
+                skills.sort_by(|a, b| a.name.cmp(&b.name));
+                skills
+            }
+            Err(e) => {
+                warn!("Failed to parse {}: {}", path.display(), e);
+                Vec::new()
+            }
+        }
+    }
+
+    /// Installed skill named `name`
+    pub fn get(&self, name: &str) -> Option<InstalledSkill> {
+        self.installed().into_iter().find(|s| s.name == name)
+    }
+
+    fn save_registry(&self, skills: &[InstalledSkill]) -> Result<()> {
+        let path = self.skills_dir.join(REGISTRY_FILE);
+        let json = serde_json::to_string_pretty(skills)?;
+        fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Install a new skill
+    ///
+    /// Fails if a skill with the same name is already in the skills
+    /// directory (use [`Self::update`] for installed skills).
+    pub fn install(&self, request: &InstallRequest) -> Result<InstalledSkill> {
+        self.install_inner(&request.source, request.expected_sha256.as_deref(), None)
+    }
+
+    /// Reinstall `name` from its recorded source and pin
+    pub fn update(&self, name: &str) -> Result<InstalledSkill> {
+        let existing = self
+            .get(name)
+            .ok_or_else(|| anyhow!("Skill '{}' was not installed from a source", name))?;
+        self.install_inner(
+            &existing.source,
+            existing.expected_sha256.as_deref(),
+            Some(name),
+        )
+    }
+
+    /// Delete an installed skill
+    pub fn remove(&self, name: &str) -> Result<()> {
+        let mut skills = self.installed();
+        let before = skills.len();
+        skills.retain(|s| s.name != name);
+        if skills.len() == before {
+            bail!("Skill '{}' was not installed from a source", name);
+        }
+
+        let directory = self.skills_dir.join(name);
+        if directory.exists() {
+            fs::remove_dir_all(&directory)
+                .with_context(|| format!("Failed to delete {}", directory.display()))?;
+        }
+        self.save_registry(&skills)?;
+        info!("🗑️ Removed skill '{}'", name);
+        Ok(())
+    }
+
+    /// Whether the files of installed skill `name` still match the checksum
+    /// recorded at install time
+    pub fn verify(&self, name: &str) -> Result<bool> {
+        let skill = self
+            .get(name)
+            .ok_or_else(|| anyhow!("Skill '{}' was not installed from a source", name))?;
+        let actual = directory_sha256(&self.skills_dir.join(name))?;
+        Ok(actual == skill.content_sha256)
+    }
+
+    fn install_inner(
+        &self,
+        source: &SkillSource,
+        expected_sha256: Option<&str>,
+        replacing: Option<&str>,
+    ) -> Result<InstalledSkill> {
+        let expected_sha256 = expected_sha256.map(normalize_sha256).transpose()?;
+
+        fs::create_dir_all(&self.skills_dir).with_context(|| {
+            format!(
+                "Failed to create skills directory {}",
+                self.skills_dir.display()
+            )
+        })?;
+        let staging = tempfile::Builder::new()
+            .prefix(".install-")
+            .tempdir_in(&self.skills_dir)
+            .context("Failed to create staging directory")?;
+        let fetched = staging.path().join("fetched");
+
+        info!("📦 Installing skill from {}", source.describe());
+        let commit = match source {
+            SkillSource::Git { url, reference } => {
+                git_clone(url, reference.as_deref(), &fetched)?;
+                let commit = git_output(&fetched, &["rev-parse", "HEAD"])?;
+                fs::remove_dir_all(fetched.join(".git"))
+                    .context("Failed to remove .git directory")?;
+                Some(commit)
+            }
+            SkillSource::Archive { location } => {
+                let bytes = read_archive(location)?;
+                if let Some(expected) = &expected_sha256 {
+                    let actual = hex::encode(Sha256::digest(&bytes));
+                    if &actual != expected {
+                        bail!(
+                            "Checksum mismatch for {}: expected {}, got {}",
+                            location,
+                            expected,
+                            actual
+                        );
+                    }
+                }
+                extract_zip(&bytes, &fetched)?;
+                None
+            }
+        };
+
+        let skill_root = find_skill_root(&fetched)?;
+        let metadata = validate_skill(&skill_root)?;
+        let content_sha256 = directory_sha256(&skill_root)?;
+        if matches!(source, SkillSource::Git { .. }) {
+            if let Some(expected) = &expected_sha256 {
+                if &content_sha256 != expected {
+                    bail!(
+                        "Checksum mismatch for {}: expected {}, got {}",
+                        source.describe(),
+                        expected,
+                        content_sha256
+                    );
+                }
+            }
+        }
+
+        let mut skills = self.installed();
+        if let Some(replacing) = replacing {
+            if metadata.name != replacing {
+                bail!(
+                    "Update renames skill '{}' to '{}'; remove and install it instead",
+                    replacing,
+                    metadata.name
+                );
+            }
+        }
+        let destination = self.skills_dir.join(&metadata.name);
+        if destination.exists() {
+            if replacing.is_none() {
+                bail!(
+                    "A skill named '{}' already exists in {}",
+                    metadata.name,
+                    self.skills_dir.display()
+                );
+            }
+            fs::remove_dir_all(&destination)
+                .with_context(|| format!("Failed to replace {}", destination.display()))?;
+        }
+        fs::rename(&skill_root, &destination)
+            .with_context(|| format!("Failed to move skill to {}", destination.display()))?;
+
+        let installed = InstalledSkill {
+            name: metadata.name,
+            description: metadata.description,
+            version: metadata.version,
+            source: source.clone(),
+            expected_sha256,
+            commit,
+            content_sha256,
+            installed_at: Utc::now(),
+        };
+        skills.retain(|s| s.name != installed.name);
+        skills.push(installed.clone());
+        self.save_registry(&skills)?;
+
+        info!(
+            "✅ Installed skill '{}' ({})",
+            installed.name,
+            installed.version.as_deref().unwrap_or("unversioned")
+        );
+        Ok(installed)
+    }
+}
+
+/// Frontmatter fields checked before installing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillManifest {
+    pub name: String,
+    pub description: String,
+    pub version: Option<String>,
+}
+
+/// Validate the SKILL.md frontmatter in `skill_root`
+///
+/// Names must be lowercase letters, digits and hyphens (they become the
+/// directory name); descriptions must be non-empty.
+pub fn validate_skill(skill_root: &Path) -> Result<SkillManifest> {
+    let skill_md = skill_root.join("SKILL.md");
+    let content = fs::read_to_string(&skill_md)
+        .with_context(|| format!("Failed to read {}", skill_md.display()))?;
+    let frontmatter = extract_yaml_frontmatter(&content).map_err(|e| anyhow!("{}", e))?;
+    let field = |key: &str| {
+        frontmatter
+            .get(&serde_yaml::Value::String(key.to_string()))
+            .and_then(|value| match value {
+                serde_yaml::Value::String(s) => Some(s.trim().to_string()),
+                serde_yaml::Value::Number(n) => Some(n.to_string()),
+                _ => None,
+            })
+    };
+
+    let name = field("name").ok_or_else(|| anyhow!("SKILL.md has no 'name'"))?;
+    if name.is_empty()
+        || name.len() > MAX_NAME_LEN
+        || name.starts_with('-')
+        || !name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        bail!(
+            "Invalid skill name '{}': use up to {} lowercase letters, digits and hyphens",
+            name,
+            MAX_NAME_LEN
+        );
+    }
+
+    let description = field("description").unwrap_or_default();
+    if description.is_empty() {
+        bail!("SKILL.md has no 'description'");
+    }
+    if description.len() > MAX_DESCRIPTION_LEN {
+        bail!(
+            "Skill description is longer than {} characters",
+            MAX_DESCRIPTION_LEN
+        );
+    }
+
+    Ok(SkillManifest {
+        name,
+        description,
+        version: field("version"),
+    })
+}
+
+/// SHA-256 over the relative paths and contents of every file under
+/// `directory`, in path order
+pub fn directory_sha256(directory: &Path) -> Result<String> {
+    let mut files: Vec<PathBuf> = WalkDir::new(directory)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .collect();
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for path in files {
+        let relative = path.strip_prefix(directory).unwrap_or(&path);
+        hasher.update(relative.to_string_lossy().replace('\\', "/").as_bytes());
+        hasher.update([0]);
+        let bytes =
+            fs::read(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+        hasher.update(&bytes);
+        hasher.update([0]);
+    }
+    Ok(hex::encode(hasher.finalize()))
+}
+
+fn normalize_sha256(value: &str) -> Result<String> {
+    let value = value.trim().to_ascii_lowercase();
+    if value.len() != 64 || !value.chars().all(|c| c.is_ascii_hexdigit()) {
+        bail!("Checksum must be 64 hexadecimal characters (SHA-256)");
+    }
+    Ok(value)
+}
+
+/// Directory containing SKILL.md: the top level, or a single subdirectory
+/// (archives often wrap their contents in one folder)
+fn find_skill_root(directory: &Path) -> Result<PathBuf> {
+    if directory.join("SKILL.md").is_file() {
+        return Ok(directory.to_path_buf());
+    }
+    let candidates: Vec<PathBuf> = fs::read_dir(directory)
+        .with_context(|| format!("Failed to read {}", directory.display()))?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.join("SKILL.md").is_file())
+        .collect();
+    match candidates.as_slice() {
+        [root] => Ok(root.clone()),
+        [] => bail!("No SKILL.md found in the source"),
+        _ => bail!("Source contains several skills; install them separately"),
+    }
+}
+
+fn git_clone(url: &str, reference: Option<&str>, destination: &Path) -> Result<()> {
+    if url.trim().is_empty() || url.starts_with('-') {
+        bail!("Invalid Git URL '{}'", url);
+    }
+    let destination_str = destination.to_string_lossy().to_string();
+    run_git(None, &["clone", "--quiet", "--", url, &destination_str])?;
+    if let Some(reference) = reference.filter(|r| !r.trim().is_empty()) {
+        if reference.starts_with('-') {
+            bail!("Invalid Git ref '{}'", reference);
+        }
+        run_git(
+            Some(destination),
+            &["checkout", "--quiet", "--detach", reference],
+        )?;
+    }
+    Ok(())
+}
+
+fn git_output(directory: &Path, args: &[&str]) -> Result<String> {
+    run_git(Some(directory), args).map(|out| out.trim().to_string())
+}
+
+fn run_git(directory: Option<&Path>, args: &[&str]) -> Result<String> {
+    let mut command = Command::new("git");
+    if let Some(directory) = directory {
+        command.current_dir(directory);
+    }
+    // Never prompt for credentials from a background install
+    command.env("GIT_TERMINAL_PROMPT", "0");
+    let output = command
+        .args(args)
+        .output()
+        .context("Failed to run git (is it installed?)")?;
+    if !output.status.success() {
+        bail!(
+            "git {} failed: {}",
+            args.first().copied().unwrap_or_default(),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Read an archive from a local path or HTTPS URL
+fn read_archive(location: &str) -> Result<Vec<u8>> {
+    if location.starts_with("http://") {
+        bail!("Archives must be downloaded over HTTPS");
+    }
+    if location.starts_with("https://") {
+        let response = reqwest::blocking::get(location)
+            .and_then(|r| r.error_for_status())
+            .with_context(|| format!("Failed to download {}", location))?;
+        let mut bytes = Vec::new();
+        response
+            .take(MAX_ARCHIVE_BYTES + 1)
+            .read_to_end(&mut bytes)
+            .with_context(|| format!("Failed to download {}", location))?;
+        if bytes.len() as u64 > MAX_ARCHIVE_BYTES {
+            bail!(
+                "Archive is larger than {} MB",
+                MAX_ARCHIVE_BYTES / 1024 / 1024
+            );
+        }
+        return Ok(bytes);
+    }
+
+    let path = Path::new(location);
+    let size = fs::metadata(path)
+        .with_context(|| format!("Failed to read {}", location))?
+        .len();
+    if size > MAX_ARCHIVE_BYTES {
+        bail!(
+            "Archive is larger than {} MB",
+            MAX_ARCHIVE_BYTES / 1024 / 1024
+        );
+    }
+    fs::read(path).with_context(|| format!("Failed to read {}", location))
+}
+
+/// Extract a zip archive, rejecting entries that escape `destination`
+fn extract_zip(bytes: &[u8], destination: &Path) -> Result<()> {
+    let mut archive =
+        zip::ZipArchive::new(std::io::Cursor::new(bytes)).context("Not a valid zip archive")?;
+    let mut total: u64 = 0;
+    for i in 0..archive.len() {
+        let mut entry = archive.by_index(i)?;
+        let relative = entry
+            .enclosed_name()
+            .map(|p| p.to_path_buf())
+            .ok_or_else(|| anyhow!("Archive entry '{}' has an unsafe path", entry.name()))?;
+        let path = destination.join(relative);
+        if entry.is_dir() {
+            fs::create_dir_all(&path)?;
+            continue;
+        }
+
+        total += entry.size();
+        if total > MAX_ARCHIVE_BYTES {
+            bail!(
+                "Archive expands to more than {} MB",
+                MAX_ARCHIVE_BYTES / 1024 / 1024
+            );
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut file = fs::File::create(&path)
+            .with_context(|| format!("Failed to create {}", path.display()))?;
+        std::io::copy(&mut entry, &mut file)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use tempfile::TempDir;
+
+    const SKILL_MD: &str = "---\nname: ec2-audit\ndescription: Audit EC2 instances\nversion: 1.2.0\n---\n# EC2 Audit\n";
+
+    fn write_zip(path: &Path, files: &[(&str, &str)]) {
+        let mut zip = zip::ZipWriter::new(fs::File::create(path).unwrap());
+        for (name, content) in files {
+            zip.start_file(*name, zip::write::FileOptions::default())
+                .unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    #[test]
+    fn test_install_archive_with_checksum() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive = temp_dir.path().join("ec2-audit.zip");
+        write_zip(
+            &archive,
+            &[
+                ("ec2-audit-main/SKILL.md", SKILL_MD),
+                ("ec2-audit-main/reference.md", "# Reference"),
+            ],
+        );
+        let checksum = hex::encode(Sha256::digest(fs::read(&archive).unwrap()));
+        let installer = SkillInstaller::new(temp_dir.path().join("skills"));
+        let source = SkillSource::Archive {
+            location: archive.to_string_lossy().to_string(),
+        };
+
+        let wrong = InstallRequest {
+            source: source.clone(),
+            expected_sha256: Some("0".repeat(64)),
+        };
+        assert!(installer.install(&wrong).is_err());
+        assert!(installer.installed().is_empty());
+
+        let request = InstallRequest {
+            source,
+            expected_sha256: Some(checksum.to_uppercase()),
+        };
+        let installed = installer.install(&request).unwrap();
+        assert_eq!(installed.name, "ec2-audit");
+        assert_eq!(installed.version.as_deref(), Some("1.2.0"));
+        assert!(installer
+            .skills_dir()
+            .join("ec2-audit/reference.md")
+            .exists());
+        assert!(installer.verify("ec2-audit").unwrap());
+
+        // Installing again conflicts; updating replaces it
+        assert!(installer.install(&request).is_err());
+        installer.update("ec2-audit").unwrap();
+        assert_eq!(installer.installed().len(), 1);
+
+        fs::write(
+            installer.skills_dir().join("ec2-audit/reference.md"),
+            "changed",
+        )
+        .unwrap();
+        assert!(!installer.verify("ec2-audit").unwrap());
+
+        installer.remove("ec2-audit").unwrap();
+        assert!(!installer.skills_dir().join("ec2-audit").exists());
+        assert!(installer.installed().is_empty());
+    }
+
+    #[test]
+    fn test_validate_skill() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_md = temp_dir.path().join("SKILL.md");
+
+        fs::write(&skill_md, SKILL_MD).unwrap();
+        assert_eq!(validate_skill(temp_dir.path()).unwrap().name, "ec2-audit");
+
+        fs::write(&skill_md, "---\nname: ../escape\ndescription: x\n---\n").unwrap();
+        assert!(validate_skill(temp_dir.path()).is_err());
+
+        fs::write(&skill_md, "---\nname: no-description\n---\n").unwrap();
+        assert!(validate_skill(temp_dir.path()).is_err());
+    }
+
+    #[test]
+    fn test_archive_rejects_unsafe_paths() {
+        let temp_dir = TempDir::new().unwrap();
+        let archive = temp_dir.path().join("evil.zip");
+        write_zip(&archive, &[("SKILL.md", SKILL_MD), ("../outside.md", "x")]);
+
+        let installer = SkillInstaller::new(temp_dir.path().join("skills"));
+        let request = InstallRequest {
+            source: SkillSource::Archive {
+                location: archive.to_string_lossy().to_string(),
+            },
+            expected_sha256: None,
+        };
+        assert!(installer.install(&request).is_err());
+        assert!(!temp_dir.path().join("outside.md").exists());
+    }
+}
@@ -0,0 +1,295 @@
+//! Skill Linter
+//!
+//! Checks a skill before it is offered to agents: SKILL.md frontmatter,
+//! files referenced from SKILL.md, and prompt size. Skills with errors are
+//! left out of discovery; warnings are reported but the skill stays usable.
+
+use regex::Regex;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::OnceLock;
+
+use super::discovery::extract_yaml_frontmatter;
+
+/// Maximum skill name length (Agent Skills specification)
+const MAX_NAME_LEN: usize = 64;
+
+/// Maximum skill description length (Agent Skills specification)
+const MAX_DESCRIPTION_LEN: usize = 1024;
+
+/// SKILL.md size above which a warning is reported (~5K tokens)
+pub const SKILL_SIZE_WARNING_BYTES: usize = 20_000;
+
+/// SKILL.md size above which the skill is rejected (~25K tokens)
+pub const SKILL_SIZE_LIMIT_BYTES: usize = 100_000;
+
+/// SKILL.md line count above which a warning is reported
+const SKILL_LINES_WARNING: usize = 500;
+
+/// How serious a lint issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintSeverity {
+    /// Skill is not offered to agents
+    Error,
+    /// Skill is offered, but should be fixed
+    Warning,
+}
+
+/// One problem found in a skill
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    pub severity: LintSeverity,
+    pub message: String,
+}
+
+impl LintIssue {
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: LintSeverity::Error,
+            message: message.into(),
+        }
+    }
+
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: LintSeverity::Warning,
+            message: message.into(),
+        }
+    }
+}
+
+/// Lint result for one SKILL.md
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillLintReport {
+    pub skill_md_path: PathBuf,
+    /// Skill name from the frontmatter, when it could be read
+    pub name: Option<String>,
+    pub issues: Vec<LintIssue>,
+}
+
+impl SkillLintReport {
+    pub fn has_errors(&self) -> bool {
+        self.issues
+            .iter()
+            .any(|i| i.severity == LintSeverity::Error)
+    }
+
+    /// Name for display: the frontmatter name, or the skill directory name
+    pub fn display_name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| {
+            self.skill_md_path
+                .parent()
+                .and_then(|p| p.file_name())
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| self.skill_md_path.display().to_string())
+        })
+    }
+}
+
+/// Lint the skill whose SKILL.md is at `skill_md_path`
+pub fn lint_skill(skill_md_path: &Path) -> SkillLintReport {
+    let mut report = SkillLintReport {
+        skill_md_path: skill_md_path.to_path_buf(),
+        name: None,
+        issues: Vec::new(),
+    };
+
+    let content = match fs::read_to_string(skill_md_path) {
+        Ok(content) => content,
+        Err(e) => {
+            report
+                .issues
+                .push(LintIssue::error(format!("Cannot read SKILL.md: {}", e)));
+            return report;
+        }
+    };
+
+    match extract_yaml_frontmatter(&content) {
+        Ok(frontmatter) => {
+            let field = |key: &str| frontmatter.get(&serde_yaml::Value::String(key.to_string()));
+            match field("name").map(|v| v.as_str()) {
+                None => report.issues.push(LintIssue::error("Missing 'name' field")),
+                Some(None) => report
+                    .issues
+                    .push(LintIssue::error("'name' must be a string")),
+                Some(Some(name)) => {
+                    lint_name(name, &mut report.issues);
+                    report.name = Some(name.to_string());
+                }
+            }
+            match field("description").map(|v| v.as_str()) {
+                None => report
+                    .issues
+                    .push(LintIssue::error("Missing 'description' field")),
+                Some(None) => report
+                    .issues
+                    .push(LintIssue::error("'description' must be a string")),
+                Some(Some(description)) if description.trim().is_empty() => report
+                    .issues
+                    .push(LintIssue::error("'description' is empty")),
+                Some(Some(description)) if description.len() > MAX_DESCRIPTION_LEN => {
+                    report.issues.push(LintIssue::warning(format!(
+                        "'description' is longer than {} characters",
+                        MAX_DESCRIPTION_LEN
+                    )))
+                }
+                Some(Some(_)) => {}
+            }
+        }
+        Err(e) => report.issues.push(LintIssue::error(e.to_string())),
+    }
+
+    // Prompt size
+    if content.len() > SKILL_SIZE_LIMIT_BYTES {
+        report.issues.push(LintIssue::error(format!(
+            "SKILL.md is {} KB, over the {} KB limit; move details into referenced files",
+            content.len() / 1024,
+            SKILL_SIZE_LIMIT_BYTES / 1024
+        )));
+    } else if content.len() > SKILL_SIZE_WARNING_BYTES {
+        report.issues.push(LintIssue::warning(format!(
+            "SKILL.md is {} KB; consider moving details into referenced files",
+            content.len() / 1024
+        )));
+    }
+    let lines = content.lines().count();
+    if lines > SKILL_LINES_WARNING {
+        report.issues.push(LintIssue::warning(format!(
+            "SKILL.md has {} lines (recommended: under {})",
+            lines, SKILL_LINES_WARNING
+        )));
+    }
+
+    // Referenced files
+    if let Some(skill_dir) = skill_md_path.parent() {
+        for reference in referenced_files(&content) {
+            let escapes = Path::new(&reference)
+                .components()
+                .any(|c| matches!(c, Component::ParentDir | Component::RootDir));
+            if escapes {
+                report.issues.push(LintIssue::error(format!(
+                    "Referenced file '{}' is outside the skill directory",
+                    reference
+                )));
+            } else if !skill_dir.join(&reference).is_file() {
+                report.issues.push(LintIssue::error(format!(
+                    "Referenced file '{}' does not exist",
+                    reference
+                )));
+            }
+        }
+    }
+
+    report
+}
+
+fn lint_name(name: &str, issues: &mut Vec<LintIssue>) {
+    if name.trim().is_empty() {
+        issues.push(LintIssue::error("'name' is empty"));
+        return;
+    }
+    if name.len() > MAX_NAME_LEN {
+        issues.push(LintIssue::warning(format!(
+            "'name' is longer than {} characters",
+            MAX_NAME_LEN
+        )));
+    }
+    if !name
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        issues.push(LintIssue::warning(
+            "'name' should use only lowercase letters, digits and hyphens",
+        ));
+    }
+}
+
+/// Relative file paths linked from markdown (`[text](path)`), without
+/// duplicates, URLs or anchors
+fn referenced_files(content: &str) -> Vec<String> {
+    static LINK: OnceLock<Regex> = OnceLock::new();
+    let link = LINK.get_or_init(|| Regex::new(r"\]\(([^)\s]+)\)").expect("valid regex"));
+
+    let mut files: Vec<String> = Vec::new();
+    for capture in link.captures_iter(content) {
+        let target = capture[1].split('#').next().unwrap_or_default();
+        if target.is_empty() || target.contains("://") || target.starts_with("mailto:") {
+            continue;
+        }
+        let target = target.trim_start_matches("./").to_string();
+        if !files.contains(&target) {
+            files.push(target);
+        }
+    }
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_skill(dir: &Path, content: &str) -> PathBuf {
+        fs::create_dir_all(dir).unwrap();
+        let path = dir.join("SKILL.md");
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_clean_skill() {
+        let temp_dir = TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("ec2-audit");
+        fs::write(
+            write_skill(
+                &skill_dir,
+                "---\nname: ec2-audit\ndescription: Audit EC2\n---\nSee [forms](forms.md#top) and [docs](https://aws.amazon.com).\n",
+            )
+            .with_file_name("forms.md"),
+            "# Forms",
+        )
+        .unwrap();
+
+        let report = lint_skill(&skill_dir.join("SKILL.md"));
+        assert_eq!(report.name.as_deref(), Some("ec2-audit"));
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_errors_and_warnings() {
+        let temp_dir = TempDir::new().unwrap();
+        let path = write_skill(
+            &temp_dir.path().join("skill"),
+            "---\nname: EC2 Audit\n---\nSee [missing](missing.md) and [escape](../secret.md).\n",
+        );
+
+        let report = lint_skill(&path);
+        assert!(report.has_errors());
+        let messages: Vec<(LintSeverity, &str)> = report
+            .issues
+            .iter()
+            .map(|i| (i.severity, i.message.as_str()))
+            .collect();
+        assert!(messages.contains(&(LintSeverity::Error, "Missing 'description' field")));
+        assert!(messages
+            .iter()
+            .any(|(s, m)| *s == LintSeverity::Warning && m.contains("lowercase")));
+        assert!(messages
+            .iter()
+            .any(|(s, m)| *s == LintSeverity::Error && m.contains("'missing.md' does not exist")));
+        assert!(messages
+            .iter()
+            .any(|(s, m)| *s == LintSeverity::Error && m.contains("outside the skill directory")));
+    }
+
+    #[test]
+    fn test_prompt_size() {
+        let temp_dir = TempDir::new().unwrap();
+        let body = "x".repeat(SKILL_SIZE_LIMIT_BYTES);
+        let path = write_skill(
+            &temp_dir.path().join("big"),
+            &format!("---\nname: big\ndescription: Big\n---\n{}", body),
+        );
+        assert!(lint_skill(&path).has_errors());
+    }
+}
@@ -5,9 +5,11 @@
 
 use super::discovery::SkillDiscoveryService;
 use super::loader::SkillLoader;
-use super::{LoadedSkill, SkillError, SkillMetadata};
+use super::watcher::{directory_fingerprint, POLL_INTERVAL};
+use super::{LoadedSkill, SkillError, SkillLintReport, SkillMetadata};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 /// Global skill manager singleton
 static GLOBAL_SKILL_MANAGER: RwLock<Option<Arc<SkillManager>>> = RwLock::new(None);
@@ -18,6 +20,12 @@ pub struct SkillManager {
     pub discovery: Arc<SkillDiscoveryService>,
     /// Skill loading service
     pub loader: Arc<SkillLoader>,
+    /// Whether development mode (hot reload) is on
+    development_mode: AtomicBool,
+    /// Identifies the current watcher thread; older watchers exit when it changes
+    watcher_id: AtomicU64,
+    /// Incremented every time skills are rediscovered
+    generation: AtomicU64,
 }
 
 impl SkillManager {
@@ -28,14 +36,24 @@ impl SkillManager {
 
         info!("🎯 Skill manager created");
 
-        Self { discovery, loader }
+        Self::with_services(discovery, loader)
     }
 
     /// Create with custom discovery service (for testing)
     pub fn with_discovery(discovery: Arc<SkillDiscoveryService>) -> Self {
         let loader = Arc::new(SkillLoader::new(discovery.clone()));
 
-        Self { discovery, loader }
+        Self::with_services(discovery, loader)
+    }
+
+    fn with_services(discovery: Arc<SkillDiscoveryService>, loader: Arc<SkillLoader>) -> Self {
+        Self {
+            discovery,
+            loader,
+            development_mode: AtomicBool::new(false),
+            watcher_id: AtomicU64::new(0),
+            generation: AtomicU64::new(0),
+        }
     }
 
     /// Discover all skills
@@ -43,7 +61,9 @@ impl SkillManager {
     /// Scans configured directories and extracts metadata.
     /// Should be called at initialization.
     pub fn discover_skills(&self) -> Result<usize, SkillError> {
-        self.discovery.discover_skills()
+        let count = self.discovery.discover_skills()?;
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        Ok(count)
     }
 
     /// Get all discovered skill metadata (cheap operation)
@@ -74,7 +94,7 @@ impl SkillManager {
         self.loader.clear_cache();
 
         // Rediscover skills
-        let count = self.discovery.discover_skills()?;
+        let count = self.discover_skills()?;
 
         info!("✅ Skill refresh complete: {} skills discovered", count);
         Ok(count)
@@ -84,6 +104,64 @@ impl SkillManager {
     pub fn get_cache_stats(&self) -> (usize, usize) {
         self.loader.get_cache_stats()
     }
+
+    /// Lint results with issues from the last discovery
+    pub fn get_lint_reports(&self) -> Vec<SkillLintReport> {
+        self.discovery.get_lint_reports()
+    }
+
+    /// Counter incremented on every rediscovery (lets UIs notice hot reloads)
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::SeqCst)
+    }
+
+    /// Whether development mode (hot reload) is on
+    pub fn is_development_mode(&self) -> bool {
+        self.development_mode.load(Ordering::SeqCst)
+    }
+
+    /// Turn skill development mode on or off
+    ///
+    /// In development mode the skill directories are polled and skills are
+    /// refreshed (metadata rediscovered, loaded content dropped) whenever a
+    /// file changes, so edits to SKILL.md are picked up without a restart.
+    pub fn set_development_mode(self: &Arc<Self>, enabled: bool) {
+        if self.development_mode.swap(enabled, Ordering::SeqCst) == enabled {
+            return;
+        }
+        let id = self.watcher_id.fetch_add(1, Ordering::SeqCst) + 1;
+        if !enabled {
+            info!("Skill development mode off");
+            return;
+        }
+
+        info!("Skill development mode on: watching skill directories");
+        let manager = Arc::downgrade(self);
+        let directories = self.discovery.skill_directories().to_vec();
+        std::thread::spawn(move || {
+            let mut fingerprint = directory_fingerprint(&directories);
+            loop {
+                std::thread::sleep(POLL_INTERVAL);
+                let Some(manager) = manager.upgrade() else {
+                    break;
+                };
+                if manager.watcher_id.load(Ordering::SeqCst) != id {
+                    break;
+                }
+                let current = directory_fingerprint(&directories);
+                if current == fingerprint {
+                    continue;
+                }
+                fingerprint = current;
+                debug!("Skill files changed, reloading");
+                match manager.refresh() {
+                    Ok(count) => info!("🔄 Skills hot-reloaded: {} skills", count),
+                    Err(e) => warn!("Skill hot reload failed: {}", e),
+                }
+            }
+            debug!("Skill watcher stopped");
+        });
+    }
 }
 
 impl Default for SkillManager {
@@ -272,6 +350,45 @@ description: A test skill
         fs::remove_dir_all(&temp_dir).ok();
     }
 
+    #[test]
+    fn test_skill_manager_development_mode() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp_dir.path().join("test-skill");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: test-skill\ndescription: A test skill\n---\n",
+        )
+        .unwrap();
+
+        let discovery = Arc::new(SkillDiscoveryService::with_directories(vec![temp_dir
+            .path()
+            .to_path_buf()]));
+        let manager = Arc::new(SkillManager::with_discovery(discovery));
+        manager.discover_skills().unwrap();
+        manager.set_development_mode(true);
+        assert!(manager.is_development_mode());
+
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: test-skill\ndescription: Edited description\n---\n",
+        )
+        .unwrap();
+
+        let deadline = std::time::Instant::now() + POLL_INTERVAL * 10;
+        while manager
+            .get_skill_metadata("test-skill")
+            .is_some_and(|m| m.description != "Edited description")
+        {
+            assert!(std::time::Instant::now() < deadline, "skill not reloaded");
+            std::thread::sleep(std::time::Duration::from_millis(100));
+        }
+        assert!(manager.generation() > 1);
+
+        manager.set_development_mode(false);
+        assert!(!manager.is_development_mode());
+    }
+
     #[test]
     fn test_global_skill_manager() {
         // Clear any existing global manager
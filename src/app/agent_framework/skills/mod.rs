@@ -4,11 +4,19 @@
 //! - Discovery: Scan directories and extract metadata
 //! - Loading: Load skill content on-demand
 //! - Management: Global singleton for skill access
+//! - Installation: Install skills from Git repositories or zip archives
+//! - Linting: Validate skills before they are offered to agents
+//! - Development mode: Hot-reload skills when their files change
 
 pub mod discovery;
+pub mod installer;
+pub mod linter;
 pub mod loader;
 pub mod manager;
+pub mod watcher;
 
 pub use discovery::{SkillDiscoveryService, SkillError, SkillMetadata};
+pub use installer::{InstallRequest, InstalledSkill, SkillInstaller, SkillSource};
+pub use linter::{lint_skill, LintIssue, LintSeverity, SkillLintReport};
 pub use loader::{LoadedSkill, SkillLoader};
 pub use manager::{get_global_skill_manager, initialize_skill_system, SkillManager};
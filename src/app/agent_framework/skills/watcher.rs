@@ -0,0 +1,76 @@
+//! Skill Directory Watching
+//!
+//! Change detection for skill development mode. The skill directories are
+//! polled and summarised as a fingerprint of every file's path, size and
+//! modification time; a changed fingerprint means skills need reloading.
+//! Polling avoids platform file-watching APIs and copes with directories
+//! that are created after startup.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+use std::time::Duration;
+use walkdir::WalkDir;
+
+/// How often skill directories are checked in development mode
+pub const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Fingerprint of all files in `directories`
+///
+/// Hidden files and directories (editor swap files, install staging, the
+/// install registry) are ignored. Missing directories contribute nothing.
+pub fn directory_fingerprint(directories: &[PathBuf]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for directory in directories {
+        let mut entries: Vec<(PathBuf, u64, Option<std::time::SystemTime>)> =
+            WalkDir::new(directory)
+                .max_depth(4)
+                .into_iter()
+                .filter_entry(|e| {
+                    e.depth() == 0 || !e.file_name().to_string_lossy().starts_with('.')
+                })
+                .filter_map(|e| e.ok())
+                .filter(|e| e.file_type().is_file())
+                .filter_map(|e| {
+                    let metadata = e.metadata().ok()?;
+                    Some((e.into_path(), metadata.len(), metadata.modified().ok()))
+                })
+                .collect();
+        entries.sort();
+        entries.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_fingerprint_changes() {
+        let temp_dir = TempDir::new().unwrap();
+        let directories = vec![
+            temp_dir.path().to_path_buf(),
+            temp_dir.path().join("missing"),
+        ];
+        let skill_dir = temp_dir.path().join("ec2-audit");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(skill_dir.join("SKILL.md"), "v1").unwrap();
+
+        let first = directory_fingerprint(&directories);
+        assert_eq!(first, directory_fingerprint(&directories));
+
+        // Hidden files are ignored
+        fs::write(skill_dir.join(".SKILL.md.swp"), "swap").unwrap();
+        assert_eq!(first, directory_fingerprint(&directories));
+
+        fs::write(skill_dir.join("forms.md"), "# Forms").unwrap();
+        let second = directory_fingerprint(&directories);
+        assert_ne!(first, second);
+
+        fs::write(skill_dir.join("SKILL.md"), "version 2").unwrap();
+        assert_ne!(second, directory_fingerprint(&directories));
+    }
+}
@@ -0,0 +1,120 @@
+//! Copy Directory Tool - Copy a directory within Page Builder workspace
+//!
+//! Copies a directory and everything in it, for example to keep a version of
+//! a page's assets before reworking them. Both paths are validated so nothing
+//! is copied out of the workspace. Supports both disk-based and VFS-based
+//! workspaces; in VFS workspaces the copy counts against the `/pages` quota.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use stood::tools::{Tool, ToolError, ToolResult};
+
+use super::workspace::WorkspaceType;
+
+/// Tool for copying directories within the Page Builder workspace
+#[derive(Debug, Clone)]
+pub struct CopyDirectoryTool {
+    workspace: WorkspaceType,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct CopyDirectoryParams {
+    /// Relative path of the directory to copy
+    source: String,
+    /// Relative destination path; must not exist yet
+    destination: String,
+}
+
+#[derive(Debug, Serialize)]
+struct CopyDirectoryResult {
+    source: String,
+    destination: String,
+    copied: bool,
+}
+
+impl CopyDirectoryTool {
+    /// Create a new CopyDirectoryTool for the specified page workspace
+    ///
+    /// # Arguments
+    /// * `page_name` - Name of the page, or VFS pattern `vfs:{vfs_id}:{page_id}`
+    pub fn new(page_name: &str) -> Result<Self> {
+        Ok(Self {
+            workspace: WorkspaceType::from_workspace_name(page_name)?,
+        })
+    }
+}
+
+#[async_trait]
+impl Tool for CopyDirectoryTool {
+    fn name(&self) -> &str {
+        "copy_directory"
+    }
+
+    fn description(&self) -> &str {
+        "Copy a directory and everything in it within the page workspace. The destination must not exist. To bring VFS data such as /results/ files into the page, use copy_file instead."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "source": {
+                    "type": "string",
+                    "description": "Relative path of the directory to copy (e.g., 'assets')"
+                },
+                "destination": {
+                    "type": "string",
+                    "description": "Relative path for the copy (e.g., 'assets-v1')"
+                }
+            },
+            "required": ["source", "destination"]
+        })
+    }
+
+    async fn execute(
+        &self,
+        parameters: Option<Value>,
+        _agent_context: Option<&stood::agent::AgentContext>,
+    ) -> Result<ToolResult, ToolError> {
+        let params_value = parameters.ok_or_else(|| ToolError::InvalidParameters {
+            message: "Missing parameters for copy_directory".to_string(),
+        })?;
+
+        let params: CopyDirectoryParams =
+            serde_json::from_value(params_value).map_err(|e| ToolError::InvalidParameters {
+                message: format!("Invalid parameters: {}", e),
+            })?;
+
+        // Validate both paths
+        for path in [&params.source, &params.destination] {
+            if let Err(e) = self.workspace.validate_path(path) {
+                return Ok(ToolResult::error(format!("Invalid path {}: {}", path, e)));
+            }
+        }
+
+        if let Err(e) = self.workspace.copy_dir(&params.source, &params.destination) {
+            return Ok(ToolResult::error(format!(
+                "Failed to copy {} to {}: {:#}",
+                params.source, params.destination, e
+            )));
+        }
+
+        let result = CopyDirectoryResult {
+            source: params.source,
+            destination: params.destination,
+            copied: true,
+        };
+
+        match serde_json::to_value(result) {
+            Ok(json) => Ok(ToolResult::success(json)),
+            Err(e) => Ok(ToolResult::error(format!(
+                "Failed to serialize result: {}",
+                e
+            ))),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Delete File Tool - Delete files from Page Builder workspace
 //!
-//! This tool allows Page Builder agents to delete files from their workspace.
+//! This tool allows Page Builder agents to delete files from their workspace,
+//! or whole directories with `recursive`.
 //! All file paths are validated to prevent directory traversal attacks.
 //! Supports both disk-based and VFS-based workspaces.
 
@@ -28,6 +29,9 @@ pub struct DeleteFileTool {
 struct DeleteFileParams {
     /// Relative path within page workspace
     path: String,
+    /// Delete a directory and everything in it
+    #[serde(default)]
+    recursive: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -66,7 +70,7 @@ impl Tool for DeleteFileTool {
     }
 
     fn description(&self) -> &str {
-        "Delete a file from the tool workspace"
+        "Delete a file from the tool workspace. Set recursive to delete a directory and everything in it."
     }
 
     fn parameters_schema(&self) -> serde_json::Value {
@@ -76,6 +80,10 @@ impl Tool for DeleteFileTool {
                 "path": {
                     "type": "string",
                     "description": "Relative path within tool workspace (e.g., 'old-file.txt', 'temp/data.json')"
+                },
+                "recursive": {
+                    "type": "boolean",
+                    "description": "Delete a directory and all of its contents (default false)"
                 }
             },
             "required": ["path"]
@@ -118,11 +126,31 @@ impl Tool for DeleteFileTool {
             Ok(true) => {}
         }
 
+        // Directories need recursive, which removes everything under the path
+        if params.recursive {
+            return match self.workspace.delete_recursive(&params.path) {
+                Ok(()) => match serde_json::to_value(DeleteFileResult {
+                    path: params.path,
+                    deleted: true,
+                }) {
+                    Ok(json) => Ok(ToolResult::success(json)),
+                    Err(e) => Ok(ToolResult::error(format!(
+                        "Failed to serialize result: {}",
+                        e
+                    ))),
+                },
+                Err(e) => Ok(ToolResult::error(format!(
+                    "Failed to delete {}: {:#}",
+                    params.path, e
+                ))),
+            };
+        }
+
         // Check if it's a file (not a directory)
         match self.workspace.is_file(&params.path) {
             Ok(false) => {
                 return Ok(ToolResult::error(format!(
-                    "Path is not a file: {}",
+                    "Path is not a file: {} (set recursive to delete a directory)",
                     params.path
                 )));
             }
@@ -168,7 +168,7 @@ Open the Resource Explorer window with specific configuration.
   accounts: string[],
   regions: string[],
   resourceTypes: string[],
-  grouping: { type: "ByAccount"|"ByRegion"|"ByResourceType"|"ByTag", key?: string },
+  grouping: { type: "ByAccount"|"ByRegion"|"ByResourceType"|"ByOrganizationalUnit"|"ByTag", key?: string },
   tagFilters: { operator: "And"|"Or", filters: [...] },
   searchFilter: string,
   title: string
@@ -234,6 +234,58 @@ Query CloudWatch Logs for analysis and monitoring.
 }
 ```
 
+### getCloudWatchMetrics(params)
+Fetch CloudWatch metric time series for utilization and health analysis.
+
+**Parameters:**
+```javascript
+{
+  accountId: string,
+  region: string,
+  // Either default metrics for a resource...
+  resourceType?: string,   // AWS::EC2::Instance, AWS::Lambda::Function,
+                           // AWS::RDS::DBInstance, AWS::DynamoDB::Table
+  resourceId?: string,     // instance ID, function name, DB identifier, table name
+  // ...or explicit metrics (max 50)
+  metrics?: Array<{
+    namespace: string,
+    metricName: string,
+    dimensions?: Array<{ name: string, value: string }>,
+    statistic?: string     // Average (default), Sum, Minimum, Maximum, p99
+  }>,
+  startTime?: number,      // Unix milliseconds (default: 3 hours ago)
+  endTime?: number,        // Unix milliseconds (default: now)
+  period?: number          // Seconds, multiple of 60 (default: 300)
+}
+```
+
+**Returns:**
+```javascript
+{
+  series: Array<{
+    label: string,
+    namespace: string,
+    metricName: string,
+    statistic: string,
+    dimensions: Array<{ name: string, value: string }>,
+    datapoints: Array<{ timestamp: number, value: number }>,
+    status: string
+  }>,
+  summary: Array<{
+    label: string,
+    datapointCount: number,
+    latest: number|null,
+    min: number|null,
+    max: number|null,
+    average: number|null
+  }>,
+  startTime: number,
+  endTime: number,
+  period: number,
+  totalDatapoints: number
+}
+```
+
 ### getCloudTrailEvents(params)
 Query CloudTrail events for governance and compliance.
 
@@ -272,6 +324,112 @@ Query CloudTrail events for governance and compliance.
 }
 ```
 
+### getConfigHistory(params)
+Get a resource's AWS Config configuration timeline with field-level diffs.
+
+**Parameters:**
+```javascript
+{
+  accountId: string,
+  region: string,
+  resourceType: string,    // e.g. 'AWS::EC2::SecurityGroup'
+  resourceId: string,      // IAM resources may use the name
+  earlierTime?: number,    // Unix milliseconds
+  laterTime?: number,      // Unix milliseconds
+  limit?: number,          // default 25, max 100
+  nextToken?: string
+}
+```
+
+**Returns:**
+```javascript
+{
+  timeline: Array<{          // oldest first
+    captureTime: number,
+    status: string,
+    changeCount: number,
+    changedPaths: string[]
+  }>,
+  items: Array<{             // newest first
+    captureTime: number,
+    status: string,
+    resourceType: string,
+    resourceId: string,
+    configuration: object,
+    tags: object,
+    relationships: Array<{ relationshipName: string, resourceType: string, resourceId: string }>
+  }>,
+  diffs: Array<{
+    fromCaptureTime: number,
+    toCaptureTime: number,
+    changes: Array<{ path: string, kind: 'added'|'removed'|'modified', before: any, after: any }>
+  }>,
+  nextToken: string|null,
+  totalItems: number
+}
+```
+
+### runAthenaQuery(params)
+Run a SQL query with Amazon Athena and wait for the results.
+
+**Parameters:**
+```javascript
+{
+  accountId: string,
+  region: string,
+  query: string,            // Trino/Presto SQL
+  database?: string,
+  catalog?: string,         // default 'AwsDataCatalog'
+  workgroup?: string,       // default 'primary'
+  outputLocation?: string,  // 's3://bucket/prefix/' if the workgroup has none
+  maxRows?: number,         // default 1000, max 10000
+  timeoutSeconds?: number   // default 300, max 900
+}
+```
+
+**Returns:**
+```javascript
+{
+  queryExecutionId: string,
+  state: string,
+  columns: Array<{ name: string, dataType: string }>,
+  rows: Array<object>,      // keyed by column name, values are strings or null
+  rowCount: number,
+  truncated: boolean,
+  dataScannedBytes: number,
+  executionTimeMs: number,
+  outputLocation: string    // S3 URI of the full CSV result
+}
+```
+
+### getCostData(params)
+Get month-to-date costs and the month-end forecast from Cost Explorer.
+
+**Parameters:**
+```javascript
+{
+  accountId: string,          // credentials used; a payer account sees linked accounts
+  groupBy?: string[],         // one or two of 'service', 'account', 'region' (default ['service'])
+  metric?: string,            // 'unblended' (default), 'amortized', 'blended'
+  includeForecast?: boolean,  // default true
+  linkedAccount?: string      // only costs of this linked account
+}
+```
+
+**Returns:**
+```javascript
+{
+  accountId: string,
+  currency: string,           // e.g. 'USD'
+  periodStart: string,        // YYYY-MM-DD
+  periodEnd: string,          // YYYY-MM-DD, exclusive
+  total: number,
+  forecastTotal: number | null,
+  groups: Array<{ keys: string[], amount: number, forecast: number | null }>,
+  groupCount: number
+}
+```
+
 ## Persistence
 
 ### saveCurrentApp(params)
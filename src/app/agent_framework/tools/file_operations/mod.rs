@@ -4,7 +4,9 @@
 //! - Read files from their workspace
 //! - Write/create files in their workspace
 //! - List files and directories
-//! - Delete files
+//! - Delete files, or directories recursively
+//! - Move/rename files and directories, and copy directories
+//! - Find files by glob and search their contents
 //! - Get API documentation
 //!
 //! All tools enforce workspace isolation and prevent directory traversal attacks.
@@ -17,22 +19,28 @@
 
 #![warn(clippy::all, rust_2018_idioms)]
 
+mod copy_directory;
 mod copy_file;
 mod delete_file;
 mod edit_file;
 mod get_api_docs;
 mod list_files;
+mod move_file;
 mod open_page;
 mod read_file;
+mod search_files;
 mod workspace;
 mod write_file;
 
+pub use copy_directory::CopyDirectoryTool;
 pub use copy_file::CopyFileTool;
 pub use delete_file::DeleteFileTool;
 pub use edit_file::EditFileTool;
 pub use get_api_docs::GetApiDocsTool;
 pub use list_files::ListFilesTool;
+pub use move_file::MoveFileTool;
 pub use open_page::OpenPageTool;
 pub use read_file::ReadFileTool;
+pub use search_files::SearchFilesTool;
 pub use workspace::{WorkspaceFileEntry, WorkspaceType};
 pub use write_file::WriteFileTool;
@@ -0,0 +1,156 @@
+//! Move File Tool - Move or rename files and directories in Page Builder workspace
+//!
+//! Both paths are validated so nothing moves into or out of the workspace.
+//! Supports both disk-based and VFS-based workspaces.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use stood::tools::{Tool, ToolError, ToolResult};
+
+use super::workspace::WorkspaceType;
+
+/// Tool for moving or renaming files and directories in the Page Builder workspace
+#[derive(Debug, Clone)]
+pub struct MoveFileTool {
+    workspace: WorkspaceType,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct MoveFileParams {
+    /// Relative path of the file or directory to move
+    source: String,
+    /// Relative destination path; must not exist yet
+    destination: String,
+}
+
+#[derive(Debug, Serialize)]
+struct MoveFileResult {
+    source: String,
+    destination: String,
+    moved: bool,
+}
+
+impl MoveFileTool {
+    /// Create a new MoveFileTool for the specified page workspace
+    ///
+    /// # Arguments
+    /// * `page_name` - Name of the page, or VFS pattern `vfs:{vfs_id}:{page_id}`
+    pub fn new(page_name: &str) -> Result<Self> {
+        Ok(Self {
+            workspace: WorkspaceType::from_workspace_name(page_name)?,
+        })
+    }
+}
+
+#[async_trait]
+impl Tool for MoveFileTool {
+    fn name(&self) -> &str {
+        "move_file"
+    }
+
+    fn description(&self) -> &str {
+        "Move or rename a file or directory within the page workspace. Directories move with everything in them; the destination must not exist."
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "source": {
+                    "type": "string",
+                    "description": "Relative path of the file or directory to move (e.g., 'draft.html', 'old-assets')"
+                },
+                "destination": {
+                    "type": "string",
+                    "description": "New relative path (e.g., 'index.html', 'assets')"
+                }
+            },
+            "required": ["source", "destination"]
+        })
+    }
+
+    async fn execute(
+        &self,
+        parameters: Option<Value>,
+        _agent_context: Option<&stood::agent::AgentContext>,
+    ) -> Result<ToolResult, ToolError> {
+        let params_value = parameters.ok_or_else(|| ToolError::InvalidParameters {
+            message: "Missing parameters for move_file".to_string(),
+        })?;
+
+        let params: MoveFileParams =
+            serde_json::from_value(params_value).map_err(|e| ToolError::InvalidParameters {
+                message: format!("Invalid parameters: {}", e),
+            })?;
+
+        // Validate both paths
+        for path in [&params.source, &params.destination] {
+            if let Err(e) = self.workspace.validate_path(path) {
+                return Ok(ToolResult::error(format!("Invalid path {}: {}", path, e)));
+            }
+        }
+
+        if let Err(e) = self
+            .workspace
+            .move_path(&params.source, &params.destination)
+        {
+            return Ok(ToolResult::error(format!(
+                "Failed to move {} to {}: {:#}",
+                params.source, params.destination, e
+            )));
+        }
+
+        let result = MoveFileResult {
+            source: params.source,
+            destination: params.destination,
+            moved: true,
+        };
+
+        match serde_json::to_value(result) {
+            Ok(json) => Ok(ToolResult::success(json)),
+            Err(e) => Ok(ToolResult::error(format!(
+                "Failed to serialize result: {}",
+                e
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_move_file() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::write(temp_dir.path().join("draft.html"), "<html>").unwrap();
+        let tool = MoveFileTool {
+            workspace: WorkspaceType::Disk {
+                path: temp_dir.path().to_path_buf(),
+            },
+        };
+
+        let params = Some(serde_json::json!({
+            "source": "draft.html",
+            "destination": "index.html"
+        }));
+        let result = tool.execute(params, None).await.unwrap();
+        assert!(result.success);
+        assert!(temp_dir.path().join("index.html").exists());
+        assert!(!temp_dir.path().join("draft.html").exists());
+
+        let params = Some(serde_json::json!({
+            "source": "index.html",
+            "destination": "../index.html"
+        }));
+        let result = tool.execute(params, None).await.unwrap();
+        assert!(!result.success);
+        assert!(temp_dir.path().join("index.html").exists());
+    }
+}
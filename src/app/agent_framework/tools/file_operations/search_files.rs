@@ -0,0 +1,339 @@
+//! Search Files Tool - Find files by glob and search their contents
+//!
+//! For VFS workspaces the whole VFS is searched (read-only), so agents can
+//! locate earlier query results under `/results/` or `/workspace/` and then
+//! bring them in with copy_file. Disk workspaces are searched only within the
+//! page folder.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use stood::tools::{Tool, ToolError, ToolResult};
+
+use super::workspace::WorkspaceType;
+use crate::app::agent_framework::vfs::search::{compile_glob, grep_text};
+use crate::app::agent_framework::vfs::{with_vfs, VfsFindMatch, VfsSearchMatches};
+
+/// Default number of matches returned
+const DEFAULT_MAX_RESULTS: usize = 100;
+
+/// Upper bound on `max_results` to keep results out of the context budget
+const MAX_RESULTS_LIMIT: usize = 500;
+
+/// Tool for finding files and searching file contents
+#[derive(Debug, Clone)]
+pub struct SearchFilesTool {
+    workspace: WorkspaceType,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+struct SearchFilesParams {
+    /// Glob for file paths (e.g., "*.json", "/results/**/*.json")
+    #[serde(default)]
+    glob: Option<String>,
+    /// Regex to search file contents for
+    #[serde(default)]
+    pattern: Option<String>,
+    /// Directory to search under
+    #[serde(default)]
+    path: Option<String>,
+    #[serde(default)]
+    max_results: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+struct SearchFilesResult {
+    matches: VfsSearchMatches,
+    total_count: usize,
+    truncated: bool,
+}
+
+impl SearchFilesTool {
+    /// Create a new SearchFilesTool for the specified page workspace
+    ///
+    /// # Arguments
+    /// * `page_name` - Name of the page, or VFS pattern `vfs:{vfs_id}:{page_id}`
+    pub fn new(page_name: &str) -> Result<Self> {
+        Ok(Self {
+            workspace: WorkspaceType::from_workspace_name(page_name)?,
+        })
+    }
+
+    fn search(&self, params: &SearchFilesParams) -> Result<VfsSearchMatches> {
+        let prefix = params.path.as_deref().unwrap_or("/");
+
+        match &self.workspace {
+            WorkspaceType::Vfs { vfs_id, .. } => with_vfs(vfs_id, |vfs| {
+                vfs.search(params.glob.as_deref(), params.pattern.as_deref(), prefix)
+            })
+            .with_context(|| format!("VFS not found: {}", vfs_id))?,
+            WorkspaceType::Disk { path: root } => {
+                let glob = params.glob.as_deref().map(compile_glob).transpose()?;
+                let pattern = params
+                    .pattern
+                    .as_deref()
+                    .map(|p| {
+                        Regex::new(p).with_context(|| format!("Invalid search pattern: {}", p))
+                    })
+                    .transpose()?;
+
+                let mut files = Vec::new();
+                collect_disk_files(root, &root.join(prefix.trim_start_matches('/')), &mut files)?;
+                files.sort();
+
+                let mut file_matches = Vec::new();
+                let mut line_matches = Vec::new();
+                for (relative, full_path) in files {
+                    if !glob
+                        .as_ref()
+                        .map_or(true, |g| g.is_match(&format!("/{}", relative)))
+                    {
+                        continue;
+                    }
+                    match &pattern {
+                        Some(regex) => {
+                            // Skip files that are not UTF-8 text
+                            if let Ok(text) = std::fs::read_to_string(&full_path) {
+                                line_matches.extend(grep_text(&relative, &text, regex));
+                            }
+                        }
+                        None => file_matches.push(VfsFindMatch {
+                            size: std::fs::metadata(&full_path)
+                                .map(|m| m.len() as usize)
+                                .unwrap_or(0),
+                            path: relative,
+                            is_directory: false,
+                        }),
+                    }
+                }
+
+                Ok(match pattern {
+                    Some(_) => VfsSearchMatches::Lines(line_matches),
+                    None => VfsSearchMatches::Files(file_matches),
+                })
+            }
+        }
+    }
+}
+
+/// Files under `directory`, as (path relative to `root`, full path)
+fn collect_disk_files(
+    root: &Path,
+    directory: &Path,
+    files: &mut Vec<(String, PathBuf)>,
+) -> Result<()> {
+    if !directory.is_dir() {
+        anyhow::bail!("Directory not found");
+    }
+    for entry in std::fs::read_dir(directory)?.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_disk_files(root, &path, files)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            let relative = relative.to_string_lossy().to_string();
+            files.push((relative, path));
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl Tool for SearchFilesTool {
+    fn name(&self) -> &str {
+        "search_files"
+    }
+
+    fn description(&self) -> &str {
+        "Find files by name and search file contents WITHOUT listing every directory.
+
+- glob only: returns matching file paths and sizes
+- pattern (regex): returns matching lines as {path, lineNumber, line}; combine with glob to limit which files are searched
+
+In VFS workspaces this searches the whole VFS (e.g. /results/, /workspace/), so you can find earlier query results and then copy_file them into the page.
+
+Example - find result files:
+{ \"glob\": \"/results/**/*.json\" }
+
+Example - find which result mentions a bucket:
+{ \"pattern\": \"my-logs-bucket\", \"path\": \"/results\" }"
+    }
+
+    fn parameters_schema(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "object",
+            "properties": {
+                "glob": {
+                    "type": "string",
+                    "description": "Glob for file paths: '*' within a directory, '**' across directories, '{a,b}' alternatives (e.g., '*.json', '/results/**/*.json')"
+                },
+                "pattern": {
+                    "type": "string",
+                    "description": "Regular expression to search file contents for"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "Directory to search under (optional, defaults to everything)"
+                },
+                "max_results": {
+                    "type": "integer",
+                    "description": "Maximum matches to return (default 100, max 500)"
+                }
+            }
+        })
+    }
+
+    async fn execute(
+        &self,
+        parameters: Option<Value>,
+        _agent_context: Option<&stood::agent::AgentContext>,
+    ) -> Result<ToolResult, ToolError> {
+        let params_value = parameters.ok_or_else(|| ToolError::InvalidParameters {
+            message: "Missing parameters for search_files".to_string(),
+        })?;
+
+        let params: SearchFilesParams =
+            serde_json::from_value(params_value).map_err(|e| ToolError::InvalidParameters {
+                message: format!("Invalid parameters: {}", e),
+            })?;
+
+        if params.glob.is_none() && params.pattern.is_none() {
+            return Ok(ToolResult::error(
+                "Provide a glob, a pattern, or both".to_string(),
+            ));
+        }
+
+        // Disk workspaces may only be searched inside the page folder
+        if let (WorkspaceType::Disk { .. }, Some(path)) = (&self.workspace, &params.path) {
+            if let Err(e) = self.workspace.validate_path(path.trim_start_matches('/')) {
+                return Ok(ToolResult::error(format!("Invalid path: {}", e)));
+            }
+        }
+        if params.path.as_deref().is_some_and(|p| p.contains("..")) {
+            return Ok(ToolResult::error(
+                "Invalid path: directory traversal not allowed".to_string(),
+            ));
+        }
+
+        let mut matches = match self.search(&params) {
+            Ok(matches) => matches,
+            Err(e) => return Ok(ToolResult::error(format!("Search failed: {:#}", e))),
+        };
+
+        let max_results = params
+            .max_results
+            .unwrap_or(DEFAULT_MAX_RESULTS)
+            .clamp(1, MAX_RESULTS_LIMIT);
+        let total_count = matches.len();
+        matches.truncate(max_results);
+
+        let result = SearchFilesResult {
+            truncated: total_count > max_results,
+            matches,
+            total_count,
+        };
+
+        match serde_json::to_value(result) {
+            Ok(json) => Ok(ToolResult::success(json)),
+            Err(e) => Ok(ToolResult::error(format!(
+                "Failed to serialize result: {}",
+                e
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::agent_framework::vfs::{deregister_vfs, register_vfs, VirtualFileSystem};
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_search_vfs_workspace() {
+        let mut vfs = VirtualFileSystem::new(1024 * 1024);
+        vfs.write_file("/results/ec2.json", b"[{\"id\": \"i-123\"}]")
+            .unwrap();
+        vfs.write_file("/results/s3.json", b"[\"my-logs-bucket\"]")
+            .unwrap();
+        vfs.write_file("/pages/report/index.html", b"<html></html>")
+            .unwrap();
+        let vfs_id = register_vfs(vfs);
+        let tool = SearchFilesTool::new(&format!("vfs:{}:report", vfs_id)).unwrap();
+
+        let result = tool
+            .execute(Some(serde_json::json!({ "glob": "*.json" })), None)
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.content["total_count"], 2);
+        assert_eq!(result.content["matches"][0]["path"], "/results/ec2.json");
+
+        let result = tool
+            .execute(
+                Some(serde_json::json!({ "pattern": "logs-bucket", "path": "/results" })),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.content["matches"][0]["path"], "/results/s3.json");
+        assert_eq!(result.content["matches"][0]["lineNumber"], 1);
+
+        let result = tool
+            .execute(
+                Some(serde_json::json!({ "glob": "**", "max_results": 1 })),
+                None,
+            )
+            .await
+            .unwrap();
+        assert_eq!(result.content["truncated"], true);
+
+        deregister_vfs(&vfs_id);
+    }
+
+    #[tokio::test]
+    async fn test_search_disk_workspace() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("js")).unwrap();
+        fs::write(
+            temp_dir.path().join("index.html"),
+            "<script src=\"js/app.js\">",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.path().join("js/app.js"),
+            "// TODO: chart\nrender();",
+        )
+        .unwrap();
+        let tool = SearchFilesTool {
+            workspace: WorkspaceType::Disk {
+                path: temp_dir.path().to_path_buf(),
+            },
+        };
+
+        let result = tool
+            .execute(
+                Some(serde_json::json!({ "pattern": "TODO", "glob": "*.js" })),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(result.success);
+        assert_eq!(result.content["total_count"], 1);
+        assert_eq!(result.content["matches"][0]["path"], "js/app.js");
+
+        let result = tool
+            .execute(
+                Some(serde_json::json!({ "glob": "*", "path": "../" })),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+    }
+}
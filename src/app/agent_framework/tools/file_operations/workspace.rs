@@ -241,6 +241,111 @@ impl WorkspaceType {
         }
     }
 
+    /// Delete a file, or a directory and everything in it
+    pub fn delete_recursive(&self, relative_path: &str) -> Result<()> {
+        self.validate_path(relative_path)?;
+        Self::reject_workspace_root(relative_path)?;
+
+        match self {
+            WorkspaceType::Disk { path } => {
+                let full_path = path.join(relative_path);
+                if full_path.is_dir() {
+                    std::fs::remove_dir_all(&full_path)
+                } else {
+                    std::fs::remove_file(&full_path)
+                }
+                .with_context(|| format!("Failed to delete: {}", relative_path))
+            }
+            WorkspaceType::Vfs { vfs_id, .. } => {
+                let vfs_path = self.vfs_path(relative_path);
+                match with_vfs_mut(vfs_id, |vfs| vfs.delete_recursive(&vfs_path)) {
+                    Some(result) => result
+                        .map(|_| ())
+                        .with_context(|| format!("Failed to delete VFS path: {}", relative_path)),
+                    None => Err(anyhow!("VFS not found: {}", vfs_id)),
+                }
+            }
+        }
+    }
+
+    /// Copy a directory and everything in it; the destination must not exist
+    pub fn copy_dir(&self, from: &str, to: &str) -> Result<()> {
+        self.validate_path(from)?;
+        self.validate_path(to)?;
+        Self::reject_workspace_root(from)?;
+        Self::reject_workspace_root(to)?;
+
+        match self {
+            WorkspaceType::Disk { path } => {
+                let source = path.join(from);
+                let destination = path.join(to);
+                if !source.is_dir() {
+                    anyhow::bail!("Directory not found: {}", from);
+                }
+                if destination.exists() {
+                    anyhow::bail!("Destination already exists: {}", to);
+                }
+                if destination.starts_with(&source) {
+                    anyhow::bail!("Cannot copy a directory into itself: {}", to);
+                }
+                copy_dir_all(&source, &destination)
+                    .with_context(|| format!("Failed to copy {} to {}", from, to))
+            }
+            WorkspaceType::Vfs { vfs_id, .. } => {
+                let (source, destination) = (self.vfs_path(from), self.vfs_path(to));
+                match with_vfs_mut(vfs_id, |vfs| vfs.copy_dir(&source, &destination)) {
+                    Some(result) => result.map(|_| ()),
+                    None => Err(anyhow!("VFS not found: {}", vfs_id)),
+                }
+            }
+        }
+    }
+
+    /// Move or rename a file or directory; the destination must not exist
+    pub fn move_path(&self, from: &str, to: &str) -> Result<()> {
+        self.validate_path(from)?;
+        self.validate_path(to)?;
+        Self::reject_workspace_root(from)?;
+        Self::reject_workspace_root(to)?;
+
+        match self {
+            WorkspaceType::Disk { path } => {
+                let source = path.join(from);
+                let destination = path.join(to);
+                if !source.exists() {
+                    anyhow::bail!("File not found: {}", from);
+                }
+                if destination.exists() {
+                    anyhow::bail!("Destination already exists: {}", to);
+                }
+                if destination.starts_with(&source) {
+                    anyhow::bail!("Cannot move a directory into itself: {}", to);
+                }
+                if let Some(parent) = destination.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::rename(&source, &destination)
+                    .with_context(|| format!("Failed to move {} to {}", from, to))
+            }
+            WorkspaceType::Vfs { vfs_id, .. } => {
+                let (source, destination) = (self.vfs_path(from), self.vfs_path(to));
+                match with_vfs_mut(vfs_id, |vfs| vfs.move_path(&source, &destination)) {
+                    Some(result) => result,
+                    None => Err(anyhow!("VFS not found: {}", vfs_id)),
+                }
+            }
+        }
+    }
+
+    /// Recursive operations must name something inside the workspace
+    fn reject_workspace_root(relative_path: &str) -> Result<()> {
+        let trimmed = relative_path.trim().trim_matches('/');
+        if trimmed.is_empty() || trimmed == "." {
+            anyhow::bail!("Invalid path: the workspace root cannot be deleted, copied, or moved");
+        }
+        Ok(())
+    }
+
     /// List files in a directory
     pub fn list_dir(&self, relative_path: Option<&str>) -> Result<Vec<WorkspaceFileEntry>> {
         if let Some(path) = relative_path {
@@ -370,6 +475,21 @@ impl WorkspaceType {
     }
 }
 
+/// Copy `source` to `destination` on disk, recursively
+fn copy_dir_all(source: &std::path::Path, destination: &std::path::Path) -> Result<()> {
+    std::fs::create_dir_all(destination)?;
+    for entry in std::fs::read_dir(source)? {
+        let entry = entry?;
+        let target = destination.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -490,4 +610,47 @@ mod tests {
         assert_eq!(ws.vfs_path("index.html"), "/pages/my-page/index.html");
         assert_eq!(ws.vfs_path("subdir/file.js"), "/pages/my-page/subdir/file.js");
     }
+
+    #[test]
+    fn test_recursive_operations() {
+        let temp_dir = TempDir::new().unwrap();
+        let vfs_id = crate::app::agent_framework::vfs::register_vfs(
+            crate::app::agent_framework::vfs::VirtualFileSystem::new(1024 * 1024),
+        );
+        let workspaces = [
+            WorkspaceType::Disk {
+                path: temp_dir.path().to_path_buf(),
+            },
+            WorkspaceType::Vfs {
+                vfs_id: vfs_id.clone(),
+                page_id: "my-page".to_string(),
+            },
+        ];
+
+        for ws in &workspaces {
+            ws.write_file("assets/app.js", b"run()").unwrap();
+            ws.write_file("assets/img/logo.svg", b"<svg/>").unwrap();
+
+            ws.copy_dir("assets", "assets-old").unwrap();
+            assert_eq!(ws.read_file("assets-old/img/logo.svg").unwrap(), b"<svg/>");
+            assert!(ws.copy_dir("assets", "assets/nested").is_err());
+
+            ws.move_path("assets-old", "archive/assets").unwrap();
+            assert!(!ws.exists("assets-old").unwrap());
+            assert!(ws.is_file("archive/assets/app.js").unwrap());
+            assert!(ws.move_path("assets", "archive/assets").is_err());
+
+            ws.delete_recursive("archive").unwrap();
+            assert!(!ws.exists("archive/assets/app.js").unwrap());
+            assert!(ws.exists("assets/app.js").unwrap());
+
+            // Isolation: nothing outside the workspace, nor the workspace itself
+            assert!(ws.delete_recursive("").is_err());
+            assert!(ws.delete_recursive("../other-page").is_err());
+            assert!(ws.move_path("assets", "../escaped").is_err());
+            assert!(ws.copy_dir("assets", "/results/assets").is_err());
+        }
+
+        crate::app::agent_framework::vfs::deregister_vfs(&vfs_id);
+    }
 }
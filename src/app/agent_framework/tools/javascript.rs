@@ -11,7 +11,8 @@
 //! - **Function Bindings**: Rust functions exposed as JavaScript globals
 //! - **Memory Limits**: 256MB heap size limit (configurable)
 //! - **Execution Timeout**: 30 second timeout (configurable)
-//! - **Error Handling**: Syntax, runtime, and timeout errors reported
+//! - **Error Handling**: Syntax, runtime, and limit errors reported
+//!   (`script killed: exceeded 30s`, `script killed: exceeded 256MB heap limit`)
 //!
 //! # Usage
 //!
@@ -121,7 +122,10 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use stood::tools::{Tool, ToolError, ToolResult};
 use tracing::{debug, info};
 
-use crate::app::agent_framework::v8_bindings::{ExecutionResult, RuntimeConfig, V8Runtime};
+use crate::app::agent_framework::v8_bindings::{
+    transpile_typescript, ErrorLocation, ExecutionResult, RuntimeConfig, TranspiledScript,
+    V8Runtime,
+};
 use crate::app::agent_framework::vfs::with_vfs_mut;
 
 /// Global sequence counter for script execution tracking
@@ -162,6 +166,26 @@ impl Default for ExecuteJavaScriptTool {
     }
 }
 
+/// Source language of a script
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScriptLanguage {
+    #[default]
+    JavaScript,
+    /// Transpiled to JavaScript (types stripped, not checked) before execution
+    TypeScript,
+}
+
+impl ScriptLanguage {
+    /// File extension used when saving scripts to VFS
+    fn extension(self) -> &'static str {
+        match self {
+            ScriptLanguage::JavaScript => "js",
+            ScriptLanguage::TypeScript => "ts",
+        }
+    }
+}
+
 /// Tool input format
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct ExecuteJavaScriptInput {
@@ -171,6 +195,9 @@ struct ExecuteJavaScriptInput {
     /// This is logged for debugging but doesn't affect execution
     #[serde(skip_serializing_if = "Option::is_none")]
     intent: Option<String>,
+    /// Source language (default: javascript)
+    #[serde(default)]
+    language: ScriptLanguage,
 }
 
 #[async_trait]
@@ -187,9 +214,13 @@ for infrastructure operations. Each execution runs in a fresh isolated environme
 
 Features:
 - Isolated V8 sandbox (256MB memory limit, 30s timeout)
+- Scripts exceeding a limit are killed with "script killed: exceeded ..." - split
+  long-running work across several executions
 - Console output capture (console.log/error/warn/debug)
 - Rust-bound functions available as JavaScript globals
 - No Node.js APIs or filesystem access (secure sandbox)
+- Set language: "typescript" to write TypeScript; types are stripped (not checked)
+  and error line numbers refer to your TypeScript
 
 Available JavaScript APIs:
 - listAccounts(): List all configured AWS accounts
@@ -385,6 +416,11 @@ Return Values:
                         "const result = queryCachedResources({ resourceTypes: ['AWS::S3::Bucket'] }); const buckets = JSON.parse(vfs.readFile(result.detailsPath)); const byEncryption = buckets.reduce((acc, b) => { const enc = b.properties.BucketEncryption?.Rules?.[0]?.ApplyServerSideEncryptionByDefault?.SSEAlgorithm || 'NONE'; acc[enc] = (acc[enc] || 0) + 1; return acc; }, {}); byEncryption;"
                     ]
                 },
+                "language": {
+                    "type": "string",
+                    "enum": ["javascript", "typescript"],
+                    "description": "Optional: Source language of `code` (default javascript). TypeScript is transpiled before execution; types are removed, not checked."
+                },
                 "intent": {
                     "type": "string",
                     "description": "Optional: Describe what you're trying to accomplish with this code. This helps with debugging and understanding the agent's reasoning.",
@@ -446,8 +482,9 @@ Return Values:
         }
 
         // Execute JavaScript with error handling
+        let intent = input.intent.as_deref();
         let tool_result =
-            match execute_with_error_handling(&input.code, &self.config, input.intent.as_deref()) {
+            match execute_with_error_handling(&input.code, &self.config, intent, input.language) {
                 Ok(result) => {
                     // Log successful execution with result details
                     if result.success {
@@ -544,8 +581,13 @@ struct ExecutionLogEntry {
 }
 
 /// Save script to VFS and return the path
-fn save_script_to_vfs(vfs_id: &str, code: &str, sequence: u64) -> Option<String> {
-    let script_path = format!("/scripts/script_{}.js", sequence);
+fn save_script_to_vfs(
+    vfs_id: &str,
+    code: &str,
+    sequence: u64,
+    language: ScriptLanguage,
+) -> Option<String> {
+    let script_path = format!("/scripts/script_{}.{}", sequence, language.extension());
 
     with_vfs_mut(vfs_id, |vfs| {
         // Ensure /scripts directory exists
@@ -587,6 +629,7 @@ fn execute_with_error_handling(
     code: &str,
     config: &RuntimeConfig,
     intent: Option<&str>,
+    language: ScriptLanguage,
 ) -> anyhow::Result<ToolResult> {
     // Copy VFS ID from tools context to VFS registry for V8 bindings
     // The tools context VFS ID is set by send_message() in instance.rs
@@ -599,20 +642,80 @@ fn execute_with_error_handling(
 
     // Save script to VFS if VFS is available
     let script_path = if let Some(ref vfs_id) = vfs_id_opt {
-        save_script_to_vfs(vfs_id, code, sequence)
+        save_script_to_vfs(vfs_id, code, sequence, language)
     } else {
         None
     };
 
+    // Transpile TypeScript; syntax errors are reported like any failed execution
+    let transpiled = match language {
+        ScriptLanguage::JavaScript => None,
+        ScriptLanguage::TypeScript => match transpile_typescript(code) {
+            Ok(script) => Some(script),
+            Err(e) => {
+                crate::app::agent_framework::vfs::set_current_vfs_id(None);
+                return Ok(format_execution_result(ExecutionResult {
+                    success: false,
+                    result: None,
+                    stdout: String::new(),
+                    stderr: e.to_string(),
+                    execution_time_ms: start_time.elapsed().as_millis() as u64,
+                    error_location: None,
+                }));
+            }
+        },
+    };
+
+    // A stopped agent does not start new scripts
+    if crate::app::agent_framework::current_turn_cancellation()
+        .is_some_and(|cancellation| cancellation.is_cancelled())
+    {
+        crate::app::agent_framework::vfs::set_current_vfs_id(None);
+        return Ok(ToolResult::error(
+            crate::app::agent_framework::CANCELLED_BY_USER.to_string(),
+        ));
+    }
+
     // Create V8 runtime with configuration
     // Note: V8Runtime automatically registers console and function bindings
     let runtime = V8Runtime::with_config(config.clone());
 
+    // Terminate the isolate when the agent is stopped mid-script
+    let interrupt = runtime.interrupt_handle();
+    let cancellation_watch =
+        crate::app::agent_framework::watch_cancellation(move || interrupt.interrupt());
+
     // Execute JavaScript code
-    let execution_result = runtime
-        .execute(code)
+    let mut execution_result = runtime
+        .execute(
+            transpiled
+                .as_ref()
+                .map_or(code, |script| script.code.as_str()),
+        )
         .map_err(|e| anyhow::anyhow!("Failed to execute JavaScript: {}", e))?;
 
+    if let Some(latency) = cancellation_watch.and_then(|watch| watch.latency()) {
+        let message = format!(
+            "V8 execution terminated {} ms after cancellation",
+            latency.as_millis()
+        );
+        info!("🛑 {}", message);
+        if let (Some(logger), Some(agent_type)) = (
+            crate::app::agent_framework::agent_logger::get_current_agent_logger(),
+            crate::app::agent_framework::get_current_agent_type(),
+        ) {
+            logger.log_system_message(&agent_type, &message);
+        }
+    }
+
+    // Point errors at the line the agent wrote
+    if let Some(location) = execution_result.error_location {
+        let location = source_location(location, transpiled.as_ref());
+        execution_result
+            .stderr
+            .push_str(&describe_error_location(code, location));
+    }
+
     let duration_ms = start_time.elapsed().as_millis() as u64;
 
     // Clear VFS registry thread-local after execution
@@ -658,6 +761,31 @@ fn execute_with_error_handling(
     Ok(format_execution_result(execution_result))
 }
 
+/// Map a V8 error location back to the submitted source
+///
+/// JavaScript locations are used as-is; TypeScript locations go through the
+/// source map, falling back to the generated position when unmapped.
+fn source_location(
+    location: ErrorLocation,
+    transpiled: Option<&TranspiledScript>,
+) -> ErrorLocation {
+    transpiled
+        .and_then(|script| script.original_location(location))
+        .unwrap_or(location)
+}
+
+/// Describe an error location with the offending source line
+fn describe_error_location(code: &str, location: ErrorLocation) -> String {
+    let mut description = format!(
+        "\n    at line {}, column {}",
+        location.line, location.column
+    );
+    if let Some(line) = code.lines().nth(location.line.saturating_sub(1)) {
+        description.push_str(&format!(": {}", line.trim()));
+    }
+    description
+}
+
 /// Public API for executing JavaScript from webview
 ///
 /// This function is called by the webview HTTP API to execute JavaScript code
@@ -682,7 +810,12 @@ pub async fn execute_javascript_internal(
     // Execute in blocking context (V8 is sync)
     let result = task::spawn_blocking(move || {
         let config = RuntimeConfig::default();
-        execute_with_error_handling(&code, &config, intent.as_deref())
+        execute_with_error_handling(
+            &code,
+            &config,
+            intent.as_deref(),
+            ScriptLanguage::JavaScript,
+        )
     })
     .await
     .map_err(|e| anyhow::anyhow!("Task join error: {}", e))??;
@@ -1003,6 +1136,60 @@ mod tests {
         assert_tool(&tool);
     }
 
+    #[test]
+    fn test_describe_error_location() {
+        let code = "const a = 1;\n  const b = a.missing.value;\nb";
+        assert_eq!(
+            describe_error_location(
+                code,
+                ErrorLocation {
+                    line: 2,
+                    column: 21
+                }
+            ),
+            "\n    at line 2, column 21: const b = a.missing.value;"
+        );
+        assert_eq!(
+            describe_error_location(code, ErrorLocation { line: 9, column: 1 }),
+            "\n    at line 9, column 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_typescript_execution() {
+        use crate::app::agent_framework::v8_bindings::initialize_v8_platform;
+        let _ = initialize_v8_platform();
+        let tool = ExecuteJavaScriptTool::new();
+
+        let result = tool
+            .execute(
+                Some(serde_json::json!({
+                    "code": "interface Point { x: number; y: number }\nconst p: Point = { x: 3, y: 4 };\nMath.hypot(p.x, p.y) as number",
+                    "language": "typescript"
+                })),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(result.success, "error: {:?}", result.error);
+        assert_eq!(result.content["result"], 5);
+
+        let result = tool
+            .execute(
+                Some(serde_json::json!({
+                    "code": "type Name = string;\n\nconst names: Name[] | undefined = undefined;\nnames!.length",
+                    "language": "typescript"
+                })),
+                None,
+            )
+            .await
+            .unwrap();
+        assert!(!result.success);
+        let error = result.error.unwrap();
+        assert!(error.contains("at line 4"), "got: {}", error);
+        assert!(error.contains("names!.length"), "got: {}", error);
+    }
+
     #[test]
     fn test_custom_config() {
         use std::time::Duration;
@@ -14,10 +14,12 @@ pub mod workspace_validation;
 //Re-export all tools for easy access
 pub use context::*;
 pub use file_operations::{
-    CopyFileTool, DeleteFileTool, EditFileTool, GetApiDocsTool, ListFilesTool, OpenPageTool, ReadFileTool, WriteFileTool,
+    CopyDirectoryTool, CopyFileTool, DeleteFileTool, EditFileTool, GetApiDocsTool, ListFilesTool, MoveFileTool, OpenPageTool, ReadFileTool, SearchFilesTool, WriteFileTool,
+};
+pub use javascript::{ExecuteJavaScriptTool, ScriptLanguage};
+pub use orchestration::{
+    EditPageTool, StartPageBuilderTool, StartTaskTool, StartTasksTool, ThinkTool,
 };
-pub use javascript::ExecuteJavaScriptTool;
-pub use orchestration::{EditPageTool, StartTaskTool, StartPageBuilderTool, ThinkTool};
 pub use security::*;
 pub use todo::{TodoItem, TodoReadTool, TodoStatus, TodoWriteTool};
 pub use workspace_validation::WorkspaceValidationMiddleware;
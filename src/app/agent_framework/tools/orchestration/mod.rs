@@ -4,11 +4,13 @@
 
 pub mod edit_page;
 pub mod start_task;
+pub mod start_tasks;
 pub mod start_page_builder;
 pub mod think;
 
 // Re-export commonly used items
 pub use edit_page::EditPageTool;
 pub use start_task::StartTaskTool;
+pub use start_tasks::StartTasksTool;
 pub use start_page_builder::StartPageBuilderTool;
 pub use think::ThinkTool;
@@ -7,10 +7,13 @@
 //! ## Implementation
 //!
 //! Uses the agent creation request/response channel to spawn TaskWorker agents.
-//! The parent agent ID is retrieved from thread-local storage.
+//! The parent agent ID is retrieved from thread-local storage. Each worker
+//! takes a slot in the parent's worker pool first, so only a limited number
+//! of workers run at once (see [`crate::app::agent_framework::workers::pool`]).
 
 use crate::app::agent_framework::{
     get_current_agent_id, get_current_vfs_id, request_agent_creation, wait_for_worker_completion,
+    worker_pool,
 };
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -23,16 +26,16 @@ pub struct StartTaskTool;
 
 /// Input schema for start-task tool
 #[derive(Debug, Deserialize, Serialize)]
-struct StartTaskInput {
+pub(super) struct StartTaskInput {
     /// Short description for UI display (3-5 words, e.g., "Listing EC2 instances")
-    short_description: String,
+    pub(super) short_description: String,
 
     /// High-level description of WHAT to accomplish
-    task_description: String,
+    pub(super) task_description: String,
 
     /// Optional description of expected output format
     #[serde(skip_serializing_if = "Option::is_none")]
-    expected_output_format: Option<String>,
+    pub(super) expected_output_format: Option<String>,
 }
 
 impl StartTaskTool {
@@ -56,8 +59,12 @@ impl StartTaskTool {
          - Objective and how this contributes to the overall goal\n\
          - Expected output format\n\n\
          Workers have access to: listAccounts(), listRegions(), loadCache(), \
-         getResourceSchema(), queryCachedResources(), showInExplorer(), \
-         queryCloudWatchLogEvents(), getCloudTrailEvents()\n\n\
+         getResourceSchema(), queryCachedResources(), searchResources(), showInExplorer(), \
+         queryCloudWatchLogEvents(), getCloudWatchMetrics(), getMetricStatistics(), \
+         listAlarmsForResource(), getCloudTrailEvents(), getConfigHistory(), \
+         runAthenaQuery(), getCostData(), s3Objects.list/head/get/presignUrl(), \
+         queryDynamoTable(), scanDynamoTable(), getCallerIdentity(), simulatePrincipalPolicy(), \
+         getStateMachineExecutions(), fetch()\n\n\
          **Good task example**:\n\
          'User asked: \"Find all production EC2 instances with high CPU usage\"\n\
          Task: List all EC2 instances in accounts with \"prod\" in the name.\n\
@@ -175,6 +182,12 @@ impl Tool for StartTaskTool {
         // Get VFS ID from parent (if TaskManager)
         let vfs_id = get_current_vfs_id();
 
+        // Wait for a free slot in the parent's worker pool
+        let permit = match worker_pool(parent_id).acquire(&input.short_description) {
+            Ok(permit) => permit,
+            Err(error) => return Ok(ToolResult::error(&error)),
+        };
+
         // Request agent creation via channel
         stood::perf_checkpoint!(
             "awsdash.start_task.request_creation.start",
@@ -196,6 +209,7 @@ impl Tool for StartTaskTool {
             "awsdash.start_task.request_creation.end",
             &format!("worker_id={}", agent_id)
         );
+        permit.set_worker(agent_id);
 
         tracing::info!(
             target: "agent::start_task",
@@ -224,9 +238,11 @@ impl Tool for StartTaskTool {
             "awsdash.start_task.wait_completion.start",
             &format!("worker_id={}", agent_id)
         );
-        match stood::perf_timed!("awsdash.start_task.wait_for_worker_completion", {
+        let completion = stood::perf_timed!("awsdash.start_task.wait_for_worker_completion", {
             wait_for_worker_completion(agent_id, Duration::from_secs(300))
-        }) {
+        });
+        permit.finish(completion.is_ok());
+        match completion {
             Ok(result) => {
                 let execution_time_ms = start_time.elapsed().as_millis();
                 stood::perf_checkpoint!(
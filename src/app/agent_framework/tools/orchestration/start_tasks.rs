@@ -0,0 +1,295 @@
+#![warn(clippy::all, rust_2018_idioms)]
+
+//! Start-Tasks Tool - Parallel Worker Spawning for Task-Manager Agents
+//!
+//! Batch version of the start_task tool: spawns one worker per task and
+//! returns all results together, in the order the tasks were given.
+//!
+//! ## Implementation
+//!
+//! Each task runs on its own thread, which takes a slot in the parent's
+//! worker pool before requesting its worker. The pool limits how many
+//! workers run at once; the rest wait in the queue in order. If the manager
+//! conversation is cancelled, queued tasks fail immediately and running
+//! workers are stopped by their cancellation tokens.
+
+use super::start_task::StartTaskInput;
+use crate::app::agent_framework::{
+    get_current_agent_id, get_current_vfs_id, request_agent_creation, wait_for_worker_completion,
+    worker_pool, AgentId,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+use stood::tools::{Tool, ToolError, ToolResult};
+
+/// Most tasks accepted in one call
+const MAX_TASKS_PER_CALL: usize = 10;
+
+/// Start-tasks tool for spawning several worker agents at once
+#[derive(Clone, Debug)]
+pub struct StartTasksTool;
+
+/// Input schema for start-tasks tool
+#[derive(Debug, Deserialize, Serialize)]
+struct StartTasksInput {
+    tasks: Vec<StartTaskInput>,
+}
+
+impl StartTasksTool {
+    /// Create a new start-tasks tool instance
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Get the tool name
+    pub fn name(&self) -> &str {
+        "start_tasks"
+    }
+
+    /// Get the tool description
+    pub fn description(&self) -> &str {
+        "Spawn several worker agents that run IN PARALLEL and wait for all of them.\n\n\
+         Use this instead of repeated start_task calls when tasks are independent \
+         (e.g., the same check in different accounts or regions). Each task takes the \
+         same fields as start_task. Results are returned in the same order as the tasks; \
+         a failed task does not stop the others.\n\n\
+         At most 10 tasks per call. Only a limited number of workers run at once; \
+         the rest wait for a free slot."
+    }
+
+    /// Get the parameters schema
+    pub fn parameters_schema(&self) -> Value {
+        json!({
+            "type": "object",
+            "required": ["tasks"],
+            "properties": {
+                "tasks": {
+                    "type": "array",
+                    "minItems": 1,
+                    "maxItems": MAX_TASKS_PER_CALL,
+                    "description": "Independent tasks to run in parallel",
+                    "items": {
+                        "type": "object",
+                        "required": ["short_description", "task_description"],
+                        "properties": {
+                            "short_description": {
+                                "type": "string",
+                                "description": "Very short description for UI display (3-5 words)"
+                            },
+                            "task_description": {
+                                "type": "string",
+                                "description": "High-level description of WHAT to accomplish, with full context"
+                            },
+                            "expected_output_format": {
+                                "type": "string",
+                                "description": "Optional description of the expected output format"
+                            }
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+impl Default for StartTasksTool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Run one task through the parent's worker pool and wait for its result
+fn run_pooled_task(
+    parent_id: AgentId,
+    vfs_id: Option<String>,
+    task: &StartTaskInput,
+) -> Result<String, String> {
+    let permit = worker_pool(parent_id).acquire(&task.short_description)?;
+    let worker_id = request_agent_creation(
+        task.short_description.clone(),
+        task.task_description.clone(),
+        task.expected_output_format.clone(),
+        parent_id,
+        vfs_id,
+    )
+    .map_err(|e| format!("Failed to create task-agent: {}", e))?;
+    permit.set_worker(worker_id);
+
+    let result = wait_for_worker_completion(worker_id, Duration::from_secs(300));
+    permit.finish(result.is_ok());
+    result
+}
+
+#[async_trait::async_trait]
+impl Tool for StartTasksTool {
+    fn name(&self) -> &str {
+        StartTasksTool::name(self)
+    }
+
+    fn description(&self) -> &str {
+        StartTasksTool::description(self)
+    }
+
+    fn parameters_schema(&self) -> Value {
+        StartTasksTool::parameters_schema(self)
+    }
+
+    async fn execute(
+        &self,
+        parameters: Option<Value>,
+        _context: Option<&stood::agent::AgentContext>,
+    ) -> Result<ToolResult, ToolError> {
+        let _tool_guard = stood::perf_guard!("awsdash.start_tasks.execute");
+
+        let params = parameters.ok_or_else(|| ToolError::InvalidParameters {
+            message: "start_tasks tool requires 'tasks' parameter".to_string(),
+        })?;
+        let input: StartTasksInput =
+            serde_json::from_value(params).map_err(|e| ToolError::InvalidParameters {
+                message: format!("Failed to parse start_tasks input: {}", e),
+            })?;
+
+        if input.tasks.is_empty() {
+            return Err(ToolError::InvalidParameters {
+                message: "tasks cannot be empty".to_string(),
+            });
+        }
+        if input.tasks.len() > MAX_TASKS_PER_CALL {
+            return Err(ToolError::InvalidParameters {
+                message: format!(
+                    "At most {} tasks per call ({} given)",
+                    MAX_TASKS_PER_CALL,
+                    input.tasks.len()
+                ),
+            });
+        }
+        if let Some(index) = input.tasks.iter().position(|t| {
+            t.short_description.trim().is_empty() || t.task_description.trim().is_empty()
+        }) {
+            return Err(ToolError::InvalidParameters {
+                message: format!(
+                    "Task {} needs a short_description and a task_description",
+                    index + 1
+                ),
+            });
+        }
+
+        let parent_id = get_current_agent_id().ok_or_else(|| ToolError::InvalidParameters {
+            message: "Cannot determine parent agent ID - agent context not set".to_string(),
+        })?;
+        let vfs_id = get_current_vfs_id();
+
+        tracing::info!(
+            target: "agent::start_tasks",
+            parent_id = %parent_id,
+            tasks = input.tasks.len(),
+            "start_tasks TOOL CALL: {:?}",
+            input
+                .tasks
+                .iter()
+                .map(|t| t.short_description.as_str())
+                .collect::<Vec<_>>()
+        );
+
+        // One thread per task; the worker pool decides how many run at once
+        let start_time = Instant::now();
+        let results: Vec<(Result<String, String>, u128)> = std::thread::scope(|scope| {
+            let handles: Vec<_> = input
+                .tasks
+                .iter()
+                .map(|task| {
+                    let vfs_id = vfs_id.clone();
+                    scope.spawn(move || {
+                        let task_start = Instant::now();
+                        let result = run_pooled_task(parent_id, vfs_id, task);
+                        (result, task_start.elapsed().as_millis())
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|_| (Err("Task thread panicked".to_string()), 0))
+                })
+                .collect()
+        });
+
+        let succeeded = results.iter().filter(|(r, _)| r.is_ok()).count();
+        tracing::info!(
+            target: "agent::start_tasks",
+            parent_id = %parent_id,
+            succeeded,
+            failed = results.len() - succeeded,
+            execution_time_ms = start_time.elapsed().as_millis(),
+            "start_tasks finished"
+        );
+
+        let tasks: Vec<Value> = input
+            .tasks
+            .iter()
+            .zip(results)
+            .map(|(task, (result, execution_time_ms))| match result {
+                Ok(result) => json!({
+                    "short_description": task.short_description,
+                    "success": true,
+                    "result": result,
+                    "execution_time_ms": execution_time_ms,
+                }),
+                Err(error) => json!({
+                    "short_description": task.short_description,
+                    "success": false,
+                    "error": error,
+                    "execution_time_ms": execution_time_ms,
+                }),
+            })
+            .collect();
+
+        Ok(ToolResult::success(json!({
+            "tasks": tasks,
+            "succeeded": succeeded,
+            "execution_time_ms": start_time.elapsed().as_millis(),
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_start_tasks_tool_schema() {
+        let tool = StartTasksTool::new();
+        assert_eq!(tool.name(), "start_tasks");
+
+        let schema = tool.parameters_schema();
+        assert_eq!(schema["properties"]["tasks"]["type"], "array");
+        assert_eq!(
+            schema["properties"]["tasks"]["maxItems"],
+            json!(MAX_TASKS_PER_CALL)
+        );
+        assert!(
+            schema["properties"]["tasks"]["items"]["properties"]["task_description"].is_object()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_start_tasks_rejects_bad_input() {
+        let tool = StartTasksTool::new();
+
+        let empty = tool.execute(Some(json!({ "tasks": [] })), None).await;
+        assert!(matches!(empty, Err(ToolError::InvalidParameters { .. })));
+
+        let too_many: Vec<Value> = (0..=MAX_TASKS_PER_CALL)
+            .map(|i| json!({ "short_description": "Task", "task_description": format!("Task {}", i) }))
+            .collect();
+        let result = tool.execute(Some(json!({ "tasks": too_many })), None).await;
+        assert!(matches!(result, Err(ToolError::InvalidParameters { .. })));
+
+        let blank = json!({ "tasks": [{ "short_description": "Task", "task_description": " " }] });
+        let result = tool.execute(Some(blank), None).await;
+        assert!(matches!(result, Err(ToolError::InvalidParameters { .. })));
+    }
+}
@@ -149,6 +149,13 @@ pub enum AgentUIEvent {
         total_tokens: u32,
     },
 
+    // ========== Response Streaming Events ==========
+    /// A chunk of an agent's response as the model generates it
+    ///
+    /// Sent by the streaming callback handler; the complete response still
+    /// arrives through the agent's response channel.
+    ResponseDelta { agent_id: AgentId, delta: String },
+
     // ========== Page Management Events ==========
     /// Open an existing page for editing
     ///
@@ -254,6 +261,11 @@ impl AgentUIEvent {
         }
     }
 
+    /// Create a new ResponseDelta event
+    pub fn response_delta(agent_id: AgentId, delta: String) -> Self {
+        Self::ResponseDelta { agent_id, delta }
+    }
+
     /// Create a new OpenPageForEdit event
     pub fn open_page_for_edit(page_name: String) -> Self {
         Self::OpenPageForEdit { page_name }
@@ -291,6 +303,15 @@ mod tests {
 
         let completed = AgentUIEvent::agent_completed(agent_id);
         assert_eq!(completed, AgentUIEvent::AgentCompleted(agent_id));
+
+        let delta = AgentUIEvent::response_delta(agent_id, "Found 3".to_string());
+        assert_eq!(
+            delta,
+            AgentUIEvent::ResponseDelta {
+                agent_id,
+                delta: "Found 3".to_string()
+            }
+        );
     }
 
     #[test]
@@ -0,0 +1,69 @@
+//! Running Cost Widget
+//!
+//! Compact estimated cost of an agent conversation, including the workers it
+//! spawned, shown next to the agent's model. Hovering shows the breakdown
+//! per model.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{RichText, Ui};
+
+use crate::app::agent_framework::cost_tracking::{
+    format_cost, ConversationCost, CostSummary, PriceTable,
+};
+
+/// Render the running cost of `conversation` plus its `workers`
+///
+/// Returns true when clicked (to open the cost report).
+pub fn render_cost_widget(
+    ui: &mut Ui,
+    conversation: &ConversationCost,
+    workers: &[&ConversationCost],
+    prices: &PriceTable,
+) -> bool {
+    let summary = CostSummary::from_conversations(
+        std::iter::once(conversation).chain(workers.iter().copied()),
+        prices,
+    );
+
+    let response = ui.add(
+        egui::Label::new(
+            RichText::new(format!(
+                "Est. cost: {} ({}K tokens)",
+                format_cost(summary.cost),
+                (summary.input_tokens + summary.output_tokens) / 1000
+            ))
+            .small()
+            .weak(),
+        )
+        .sense(egui::Sense::click()),
+    );
+
+    response
+        .on_hover_ui(|ui| {
+            ui.label(RichText::new("Estimated cost of this conversation").strong());
+            if summary.by_model.is_empty() {
+                ui.label("No tokens used yet");
+            }
+            for (usage, cost) in &summary.by_model {
+                ui.label(format!(
+                    "{}: {} ({} in / {} out)",
+                    usage.model,
+                    format_cost(*cost),
+                    usage.input_tokens,
+                    usage.output_tokens
+                ));
+            }
+            if !workers.is_empty() {
+                let worker_cost: f64 = workers.iter().map(|w| w.cost(prices)).sum();
+                ui.label(format!(
+                    "Includes {} worker(s): {}",
+                    workers.len(),
+                    format_cost(worker_cost)
+                ));
+            }
+            ui.label(RichText::new("Click for the cost report").small().weak());
+        })
+        .clicked()
+}
@@ -16,6 +16,20 @@
 //!
 //! - User messages: Plain text with ">" prefix and theme-adaptive strong color
 //! - Assistant messages: Markdown-rendered if detected, otherwise plain text
+//! - Streamed response: Shown below the last message as it is generated,
+//!   then replaced by the complete response; Stop keeps the text so far
+//!
+//! ## Mentions
+//!
+//! Typing `@` at the start of the input lists the chat's agents (manager and
+//! live workers); clicking one completes its handle. Routing the message is
+//! up to the caller (see `conversation::routing`).
+//!
+//! ## Transcript Replay
+//!
+//! [`render_transcript_replay`] shows an exported transcript read-only, with
+//! each tool call's input and result under the message it answered. Nothing
+//! is sent to the model and no tools run.
 
 use egui::{RichText, ScrollArea, Ui};
 use egui_commonmark::{CommonMarkCache, CommonMarkViewer};
@@ -24,7 +38,10 @@ use std::path::PathBuf;
 use std::time::Instant;
 
 use crate::app::agent_framework::core::instance::AgentInstance;
-use crate::app::agent_framework::conversation::{ConversationMessage, ConversationRole};
+use crate::app::agent_framework::conversation::{
+    mention_completions, ChatParticipant, ConversationMessage, ConversationRole,
+    ConversationTranscript, TranscriptToolCall,
+};
 use crate::app::agent_framework::status_display::ProcessingStatusWidget;
 
 /// Status of a tool call within a worker
@@ -124,10 +141,15 @@ fn looks_like_markdown(content: &str) -> bool {
 ///
 /// Parameters:
 /// - `inline_workers`: Optional map of message_index -> workers to display inline after each message
+/// - `participants`: Agents that can be `@`-mentioned, for input completions
+///
+/// Sending stays enabled while the agent is processing; the caller queues
+/// such messages until the current response completes.
 ///
-/// Returns: `(should_send, log_clicked, clear_clicked, terminate_clicked, stop_clicked, worker_log_clicked, vfs_clicked)`
-/// where `worker_log_clicked` is the log path if a worker's log button was clicked
-/// and `vfs_clicked` is true if the VFS button was clicked
+/// Returns: `(should_send, log_clicked, clear_clicked, terminate_clicked, stop_clicked, worker_log_clicked, vfs_clicked, export_clicked)`
+/// where `worker_log_clicked` is the log path if a worker's log button was clicked,
+/// `vfs_clicked` is true if the VFS button was clicked and `export_clicked` is true
+/// if the Export Transcript button was clicked
 pub fn render_agent_chat(
     ui: &mut Ui,
     agent: &mut AgentInstance,
@@ -135,12 +157,14 @@ pub fn render_agent_chat(
     markdown_cache: &mut CommonMarkCache,
     status_widget: &mut ProcessingStatusWidget,
     inline_workers: Option<&HashMap<usize, Vec<InlineWorkerDisplay>>>,
-) -> (bool, bool, bool, bool, bool, Option<PathBuf>, bool) {
+    participants: &[ChatParticipant],
+) -> (bool, bool, bool, bool, bool, Option<PathBuf>, bool, bool) {
     // Collect data before rendering to avoid holding locks during UI rendering
     let is_processing = agent.is_processing();
     let can_cancel = agent.can_cancel();
     let status_message = agent.status_message().map(|s| s.to_string());
     let messages: Vec<ConversationMessage> = agent.messages().iter().cloned().collect();
+    let streaming_text = agent.streaming_text().map(|s| s.to_string());
     let agent_id = agent.id();
 
     // Update widget from agent's processing phase
@@ -221,7 +245,22 @@ pub fn render_agent_chat(
 
                 ui.add_space(1.0);
             }
+
+            // Response in progress, with a cursor while tokens arrive
+            if let Some(text) = &streaming_text {
+                ui.push_id(("streaming_response", agent_id), |ui| {
+                    let text = format!("{}_", text);
+                    if looks_like_markdown(&text) {
+                        CommonMarkViewer::new().show(ui, markdown_cache, &text);
+                    } else {
+                        ui.label(text);
+                    }
+                });
+            }
         });
+    if streaming_text.is_some() {
+        ui.ctx().request_repaint();
+    }
 
     // Status line with animated widget
     // Widget handles its own space reservation and animation
@@ -232,6 +271,23 @@ pub fn render_agent_chat(
     let mut keep_focus = false;
 
     ui.vertical(|ui| {
+        // Mention completions while a leading @handle is being typed
+        let completions = mention_completions(input_text, participants);
+        if !completions.is_empty() {
+            ui.horizontal_wrapped(|ui| {
+                ui.label(RichText::new("Send to:").small().weak());
+                for participant in completions {
+                    if ui
+                        .small_button(format!("@{}", participant.handle))
+                        .on_hover_text(&participant.label)
+                        .clicked()
+                    {
+                        *input_text = format!("@{} ", participant.handle);
+                    }
+                }
+            });
+        }
+
         // Multi-line input with 3 rows minimum
         let input_response = ui.add(
             egui::TextEdit::multiline(input_text)
@@ -246,8 +302,7 @@ pub fn render_agent_chat(
         // Shift+Enter adds a newline (default TextEdit behavior)
         if had_focus
             && ui.input(|i| i.key_pressed(egui::Key::Enter) && !i.modifiers.shift)
-            && !input_text.is_empty()
-            && !is_processing
+            && !input_text.trim().is_empty()
         {
             should_send = true;
             keep_focus = true; // Keep focus after sending
@@ -255,11 +310,14 @@ pub fn render_agent_chat(
 
         // Send button
         ui.horizontal(|ui| {
-            let send_enabled = !input_text.is_empty() && !is_processing;
-            if ui
-                .add_enabled(send_enabled, egui::Button::new("Send"))
-                .clicked()
-            {
+            let send_enabled = !input_text.trim().is_empty();
+            let send_button = ui.add_enabled(send_enabled, egui::Button::new("Send"));
+            let send_button = if is_processing {
+                send_button.on_hover_text("Delivered when the current response completes")
+            } else {
+                send_button
+            };
+            if send_button.clicked() {
                 should_send = true;
                 keep_focus = had_focus; // Only maintain focus if it was already focused
             }
@@ -278,12 +336,17 @@ pub fn render_agent_chat(
     let has_vfs = agent.vfs_id().is_some();
 
     // Action buttons
-    let (log_clicked, clear_clicked, terminate_clicked, stop_clicked, vfs_clicked) = ui
-        .horizontal(|ui| {
+    let (log_clicked, clear_clicked, terminate_clicked, stop_clicked, vfs_clicked, export_clicked) =
+        ui.horizontal(|ui| {
             // Stop button - only enabled when processing and cancellation is available
             let stop_enabled = is_processing && can_cancel;
             let stop_clicked = ui
                 .add_enabled(stop_enabled, egui::Button::new("Stop"))
+                .on_hover_text(if streaming_text.is_some() {
+                    "Stop the response now, keeping the text generated so far"
+                } else {
+                    "Stop the agent and its workers"
+                })
                 .clicked();
 
             ui.separator();
@@ -305,6 +368,17 @@ pub fn render_agent_chat(
 
             ui.separator();
 
+            // Export button - disabled while a response is pending
+            let export_clicked = ui
+                .add_enabled(
+                    !is_processing && !messages.is_empty(),
+                    egui::Button::new("Export Transcript"),
+                )
+                .on_hover_text("Save messages, tool calls and token usage as a JSON transcript")
+                .clicked();
+
+            ui.separator();
+
             // Clear button
             let clear_clicked = ui.button("Clear Conversation").clicked();
 
@@ -313,7 +387,14 @@ pub fn render_agent_chat(
             // Terminate button
             let terminate_clicked = ui.button("Terminate Agent").clicked();
 
-            (log_clicked, clear_clicked, terminate_clicked, stop_clicked, vfs_clicked)
+            (
+                log_clicked,
+                clear_clicked,
+                terminate_clicked,
+                stop_clicked,
+                vfs_clicked,
+                export_clicked,
+            )
         })
         .inner;
 
@@ -325,9 +406,111 @@ pub fn render_agent_chat(
         stop_clicked,
         worker_log_clicked,
         vfs_clicked,
+        export_clicked,
     )
 }
 
+/// Render an exported transcript read-only
+///
+/// Tool calls are listed after the message they answered, with their input
+/// and result in collapsed sections. Returns true if Close Replay was clicked.
+pub fn render_transcript_replay(
+    ui: &mut Ui,
+    transcript: &ConversationTranscript,
+    markdown_cache: &mut CommonMarkCache,
+) -> bool {
+    let mut close_clicked = false;
+
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(format!("Replay: {}", transcript.agent_name)).strong());
+        ui.label(
+            RichText::new(format!(
+                "{} - {} - exported {}",
+                transcript.agent_type,
+                transcript.model,
+                transcript
+                    .exported_at
+                    .with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M")
+            ))
+            .weak(),
+        );
+        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+            close_clicked = ui.button("Close Replay").clicked();
+        });
+    });
+    let usage = &transcript.token_usage;
+    ui.label(
+        RichText::new(format!(
+            "{} tool calls - tokens: input={}, output={}, total={} over {} model calls",
+            transcript.tool_calls.len(),
+            usage.input_tokens,
+            usage.output_tokens,
+            usage.total_tokens,
+            usage.model_calls
+        ))
+        .small()
+        .weak(),
+    );
+    ui.separator();
+
+    ScrollArea::both()
+        .id_salt(("transcript_replay_scroll", &transcript.agent_id))
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            for (index, message) in transcript.messages.iter().enumerate() {
+                render_message(ui, message, markdown_cache);
+                for (call_index, call) in transcript.tool_calls_for(index).enumerate() {
+                    render_replay_tool_call(ui, call, (index, call_index));
+                }
+                ui.add_space(1.0);
+            }
+        });
+
+    close_clicked
+}
+
+/// Render one recorded tool call with collapsible input and result
+fn render_replay_tool_call(ui: &mut Ui, call: &TranscriptToolCall, id: (usize, usize)) {
+    let (status, color) = if !call.is_complete() {
+        ("[stopped]", egui::Color32::GRAY)
+    } else if call.succeeded() {
+        ("[done]", egui::Color32::from_rgb(100, 160, 100))
+    } else {
+        ("[FAIL]", egui::Color32::from_rgb(180, 100, 100))
+    };
+    let duration = call
+        .duration_ms
+        .map(|ms| format!(" ({:.2}s)", ms as f64 / 1000.0))
+        .unwrap_or_default();
+
+    ui.push_id(("replay_tool_call", id), |ui| {
+        ui.indent("replay_tool_call_indent", |ui| {
+            egui::CollapsingHeader::new(
+                RichText::new(format!("{} {}{}", status, call.tool_name, duration))
+                    .color(color)
+                    .small(),
+            )
+            .default_open(false)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Input").small().strong());
+                ui.label(RichText::new(pretty_json(&call.input)).monospace().small());
+                if let Some(error) = &call.error {
+                    ui.label(RichText::new("Error").small().strong());
+                    ui.label(RichText::new(error).monospace().small().color(color));
+                } else if let Some(output) = &call.output {
+                    ui.label(RichText::new("Output").small().strong());
+                    ui.label(RichText::new(pretty_json(output)).monospace().small());
+                }
+            });
+        });
+    });
+}
+
+fn pretty_json(value: &serde_json::Value) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|_| value.to_string())
+}
+
 /// Render inline workers for a specific message
 ///
 /// Returns a worker action request if a button was clicked (Log or Open Tool).
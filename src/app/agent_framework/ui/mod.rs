@@ -1,13 +1,19 @@
 //! UI components and events
 //!
-//! This module provides UI integration for agents, including status display
-//! and event handling.
+//! This module provides UI integration for agents, including status display,
+//! event handling, and streaming responses to the chat.
 
 pub mod agent_events;
+pub mod cost_widget;
 pub mod events;
 pub mod status_display;
+pub mod streaming;
+pub mod worker_pool_widget;
 
 // Re-export commonly used items
 pub use agent_events::*;
+pub use cost_widget::*;
 pub use events::*;
 pub use status_display::*;
+pub use streaming::*;
+pub use worker_pool_widget::*;
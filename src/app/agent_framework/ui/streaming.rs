@@ -0,0 +1,74 @@
+#![warn(clippy::all, rust_2018_idioms)]
+
+//! Response Streaming Callback Handler
+//!
+//! Forwards text chunks of a streamed model response to the UI event channel,
+//! so the chat shows the response as it is generated instead of when the
+//! whole turn completes.
+//!
+//! ## How it works
+//!
+//! 1. The agent is built with streaming enabled and this handler attached
+//! 2. stood emits a `ContentDelta` event for each chunk of model output
+//! 3. The handler sends it as `AgentUIEvent::ResponseDelta`
+//! 4. AgentManagerWindow appends it to the agent's in-progress response
+//!
+//! Text before and after a tool call is kept apart with a blank line. Chunks
+//! that arrive after the turn was cancelled are dropped by the agent.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use async_trait::async_trait;
+use stood::agent::callbacks::{CallbackError, CallbackEvent, CallbackHandler};
+
+use crate::app::agent_framework::{send_ui_event, AgentId, AgentUIEvent};
+
+/// Separator between text streamed before and after a tool call
+const SEGMENT_SEPARATOR: &str = "\n\n";
+
+/// Callback handler that streams response text to the UI
+pub struct StreamingCallbackHandler {
+    /// ID of the agent whose response is streamed
+    agent_id: AgentId,
+    /// Whether text was streamed since the last tool call
+    has_segment_text: AtomicBool,
+}
+
+impl StreamingCallbackHandler {
+    pub fn new(agent_id: AgentId) -> Self {
+        Self {
+            agent_id,
+            has_segment_text: AtomicBool::new(false),
+        }
+    }
+
+    fn send_delta(&self, delta: String) {
+        if let Err(e) = send_ui_event(AgentUIEvent::response_delta(self.agent_id, delta)) {
+            tracing::debug!(
+                target: "agent::streaming",
+                agent_id = %self.agent_id,
+                "Dropped response chunk: {}",
+                e
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl CallbackHandler for StreamingCallbackHandler {
+    async fn handle_event(&self, event: CallbackEvent) -> Result<(), CallbackError> {
+        match event {
+            CallbackEvent::ContentDelta { delta, .. } if !delta.is_empty() => {
+                self.has_segment_text.store(true, Ordering::Relaxed);
+                self.send_delta(delta);
+            }
+            CallbackEvent::ToolStart { .. } => {
+                if self.has_segment_text.swap(false, Ordering::Relaxed) {
+                    self.send_delta(SEGMENT_SEPARATOR.to_string());
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+//! Worker Pool Widget
+//!
+//! Compact progress of a manager's worker pool (running, queued, and
+//! finished workers of the current batch) with the max concurrency
+//! setting, shown next to the running cost. Hovering lists each task.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{RichText, Ui};
+
+use crate::app::agent_framework::workers::{
+    max_concurrent_workers, set_max_concurrent_workers, PoolTaskState, WorkerPoolStatus,
+    MAX_CONCURRENT_WORKERS_LIMIT,
+};
+
+/// Render worker pool progress (if the manager started workers) and the
+/// max concurrency setting
+pub fn render_worker_pool_widget(ui: &mut Ui, status: Option<&WorkerPoolStatus>) {
+    if let Some(status) = status.filter(|s| s.total() > 0) {
+        let finished = status.completed + status.failed + status.cancelled;
+        let mut summary = format!("Workers: {}/{} done", finished, status.total());
+        if status.running > 0 {
+            summary.push_str(&format!(", {} running", status.running));
+        }
+        if status.queued > 0 {
+            summary.push_str(&format!(", {} queued", status.queued));
+        }
+        if status.failed + status.cancelled > 0 {
+            summary.push_str(&format!(", {} failed", status.failed + status.cancelled));
+        }
+
+        ui.label(RichText::new(summary).small().weak())
+            .on_hover_ui(|ui| {
+                ui.label(RichText::new("Workers in this batch").strong());
+                for task in &status.tasks {
+                    let color = match task.state {
+                        PoolTaskState::Failed | PoolTaskState::Cancelled => {
+                            ui.visuals().error_fg_color
+                        }
+                        PoolTaskState::Running => ui.visuals().warn_fg_color,
+                        _ => ui.visuals().text_color(),
+                    };
+                    ui.label(
+                        RichText::new(format!(
+                            "[{}] {} ({:.0}s)",
+                            task.state.label(),
+                            task.description,
+                            task.elapsed.as_secs_f64()
+                        ))
+                        .color(color),
+                    );
+                }
+            });
+        ui.separator();
+    }
+
+    let mut max = max_concurrent_workers();
+    ui.label(RichText::new("Max workers:").small());
+    let response = ui
+        .add(egui::DragValue::new(&mut max).range(1..=MAX_CONCURRENT_WORKERS_LIMIT))
+        .on_hover_text("How many workers an agent runs at once; the rest wait in a queue");
+    if response.changed() {
+        set_max_concurrent_workers(max);
+    }
+}
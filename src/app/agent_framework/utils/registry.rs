@@ -5,6 +5,8 @@
 
 use crate::app::resource_explorer::aws_client::AWSResourceClient;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 use std::sync::{Arc, RwLock};
 use tracing::{error, info, warn};
 
@@ -19,6 +21,9 @@ static GLOBAL_AWS_CLIENT: RwLock<Option<Arc<AWSResourceClient>>> = RwLock::new(N
 /// Global AWS credentials for standalone agents
 static GLOBAL_AWS_CREDENTIALS: RwLock<Option<AwsCredentialsTuple>> = RwLock::new(None);
 
+/// Risk levels of tools and V8 binding operations that are not read-only
+static TOOL_RISK_LEVELS: RwLock<Option<HashMap<String, ToolRiskLevel>>> = RwLock::new(None);
+
 /// Global agent cancellation manager for stopping running agents
 static GLOBAL_CANCELLATION_MANAGER: RwLock<Option<Arc<AgentCancellationManager>>> =
     RwLock::new(None);
@@ -180,3 +185,86 @@ pub fn set_global_current_project(_project: Option<()>) {
 pub fn get_global_current_project() -> Option<()> {
     None
 }
+
+/// What a tool (or V8 binding operation) can do to AWS state
+///
+/// Ordered from least to most risky, so levels can be compared against an
+/// approval threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ToolRiskLevel {
+    /// Only reads AWS state (the default for unregistered tools)
+    ReadOnly,
+    /// Creates or changes AWS resources
+    Mutating,
+    /// Deletes AWS resources or data, or is otherwise hard to undo
+    Destructive,
+}
+
+impl ToolRiskLevel {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ToolRiskLevel::ReadOnly => "Read-only",
+            ToolRiskLevel::Mutating => "Mutating",
+            ToolRiskLevel::Destructive => "Destructive",
+        }
+    }
+}
+
+impl fmt::Display for ToolRiskLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Tag a tool or V8 binding operation (e.g. `"s3.deleteObject"`) with its risk level
+///
+/// Mutating and destructive calls go through the approval gate (see
+/// [`ApprovalGateLayer`](crate::app::agent_framework::middleware::layers::ApprovalGateLayer)).
+pub fn register_tool_risk(name: &str, level: ToolRiskLevel) {
+    match TOOL_RISK_LEVELS.write() {
+        Ok(mut guard) => {
+            guard
+                .get_or_insert_with(HashMap::new)
+                .insert(name.to_string(), level);
+        }
+        Err(e) => {
+            error!("❌ Failed to register tool risk level: {}", e);
+        }
+    }
+}
+
+/// Risk level of a tool or V8 binding operation; unregistered names are read-only
+pub fn get_tool_risk(name: &str) -> ToolRiskLevel {
+    match TOOL_RISK_LEVELS.read() {
+        Ok(guard) => guard
+            .as_ref()
+            .and_then(|levels| levels.get(name).copied())
+            .unwrap_or(ToolRiskLevel::ReadOnly),
+        Err(e) => {
+            // Fail safe: treat the call as mutating so it needs approval
+            error!("❌ Failed to read tool risk levels: {}", e);
+            ToolRiskLevel::Mutating
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tool_risk_registry() {
+        assert_eq!(get_tool_risk("test.unregistered"), ToolRiskLevel::ReadOnly);
+
+        register_tool_risk("test.putThing", ToolRiskLevel::Mutating);
+        register_tool_risk("test.deleteThing", ToolRiskLevel::Destructive);
+        assert_eq!(get_tool_risk("test.putThing"), ToolRiskLevel::Mutating);
+        assert_eq!(
+            get_tool_risk("test.deleteThing"),
+            ToolRiskLevel::Destructive
+        );
+
+        assert!(ToolRiskLevel::ReadOnly < ToolRiskLevel::Mutating);
+        assert!(ToolRiskLevel::Mutating < ToolRiskLevel::Destructive);
+    }
+}
@@ -0,0 +1,440 @@
+//! Athena function bindings
+//!
+//! Provides JavaScript access to Athena SQL queries against data lakes.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::app::agent_framework::middleware::layers::request_agent_approval;
+use crate::app::agent_framework::vfs::{get_current_vfs_id, with_vfs_mut};
+use crate::app::agent_framework::{
+    get_current_agent_id, get_current_agent_type, register_tool_risk, ToolRiskLevel,
+};
+use crate::app::data_plane::athena::{
+    is_read_only_statement, AthenaColumn, AthenaPollOptions, AthenaQueryClient, AthenaQueryRequest,
+};
+
+/// Approval gate name of runAthenaQuery() with a write statement
+pub const RUN_QUERY_OPERATION: &str = "athena.runAthenaQuery";
+
+/// Maximum rows per runAthenaQuery() call
+const MAX_ROWS: usize = 10_000;
+
+/// Default rows per runAthenaQuery() call
+const DEFAULT_ROWS: usize = 1000;
+
+/// Maximum query timeout in seconds
+const MAX_TIMEOUT_SECONDS: u64 = 900;
+
+/// Number of rows returned inline when the full result is saved to VFS
+const SAMPLE_ROWS: usize = 10;
+
+/// JavaScript function call arguments for runAthenaQuery()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunAthenaQueryArgs {
+    /// Account ID (required)
+    pub account_id: String,
+
+    /// AWS region (required)
+    pub region: String,
+
+    /// SQL query text (required)
+    pub query: String,
+
+    /// Database for unqualified table names (optional)
+    pub database: Option<String>,
+
+    /// Data catalog (optional, default "AwsDataCatalog")
+    pub catalog: Option<String>,
+
+    /// Workgroup (optional, default "primary")
+    pub workgroup: Option<String>,
+
+    /// S3 location for results (optional, workgroup setting used when omitted)
+    pub output_location: Option<String>,
+
+    /// Maximum rows to fetch (optional, default 1000, max 10000)
+    pub max_rows: Option<usize>,
+
+    /// Seconds to wait before cancelling (optional, default 300, max 900)
+    pub timeout_seconds: Option<u64>,
+}
+
+/// Athena query result exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AthenaQueryInfo {
+    /// Query execution ID
+    pub query_execution_id: String,
+
+    /// Final state (SUCCEEDED)
+    pub state: String,
+
+    /// Result columns
+    pub columns: Vec<AthenaColumn>,
+
+    /// Rows as objects keyed by column name (sample only when saved to VFS)
+    pub rows: Vec<serde_json::Map<String, serde_json::Value>>,
+
+    /// Number of rows fetched
+    pub row_count: usize,
+
+    /// True when more rows exist than were fetched
+    pub truncated: bool,
+
+    /// Bytes scanned by the query
+    pub data_scanned_bytes: Option<i64>,
+
+    /// Engine execution time in milliseconds
+    pub execution_time_ms: Option<i64>,
+
+    /// S3 location of the complete CSV result
+    pub output_location: Option<String>,
+
+    /// Path to all fetched rows in VFS (when VFS is used)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_path: Option<String>,
+
+    /// Message explaining how to access full data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Register Athena functions into V8 context
+pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
+    let global = scope.get_current_context().global(scope);
+
+    // Register runAthenaQuery() function
+    let query_fn = v8::Function::new(scope, run_athena_query_callback)
+        .expect("Failed to create runAthenaQuery function");
+
+    let fn_name =
+        v8::String::new(scope, "runAthenaQuery").expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), query_fn.into());
+
+    Ok(())
+}
+
+/// Callback for runAthenaQuery() JavaScript function
+///
+/// When VFS is available and the result is larger than the inline sample,
+/// saves all rows to VFS and returns the first rows.
+fn run_athena_query_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    // Parse JavaScript arguments
+    let args_obj = match args.get(0).to_object(scope) {
+        Some(obj) => obj,
+        None => {
+            let msg =
+                v8::String::new(scope, "runAthenaQuery() requires an object argument").unwrap();
+            let error = v8::Exception::type_error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Convert V8 object to JSON string for parsing
+    let json_str = match v8::json::stringify(scope, args_obj.into()) {
+        Some(s) => s.to_rust_string_lossy(scope),
+        None => {
+            let msg = v8::String::new(scope, "Failed to stringify arguments").unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Parse JSON into RunAthenaQueryArgs
+    let query_args: RunAthenaQueryArgs = match serde_json::from_str(&json_str) {
+        Ok(args) => args,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("Failed to parse arguments: {}", e)).unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Execute async query
+    let mut result = match execute_query(query_args) {
+        Ok(result) => result,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("Athena query failed: {}", e)).unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // If VFS is available and the result is large, save rows to VFS and return a sample
+    if let Some(vfs_id) = get_current_vfs_id() {
+        if result.rows.len() > SAMPLE_ROWS {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0);
+            let vfs_path = format!("/results/athena_{}.json", timestamp);
+
+            let rows_json = match serde_json::to_string_pretty(&result.rows) {
+                Ok(json) => json,
+                Err(e) => {
+                    warn!("Failed to serialize Athena rows for VFS: {}", e);
+                    String::new()
+                }
+            };
+
+            if !rows_json.is_empty() {
+                let write_result = with_vfs_mut(&vfs_id, |vfs| {
+                    vfs.write_file(&vfs_path, rows_json.as_bytes())
+                });
+
+                match write_result {
+                    Some(Ok(())) => {
+                        debug!(
+                            "Saved {} Athena rows to VFS path: {}",
+                            result.row_count, vfs_path
+                        );
+
+                        result.rows.truncate(SAMPLE_ROWS);
+                        result.details_path = Some(vfs_path.clone());
+                        result.message = Some(format!(
+                            "Query returned {} rows; first {} shown. All rows saved to VFS. Use vfs.readJson('{}') to access.",
+                            result.row_count,
+                            SAMPLE_ROWS,
+                            vfs_path
+                        ));
+                    }
+                    Some(Err(e)) => {
+                        warn!("Failed to write Athena rows to VFS: {}", e);
+                        // Fall back to inline return
+                    }
+                    None => {
+                        warn!("VFS not found for id: {}", vfs_id);
+                        // Fall back to inline return
+                    }
+                }
+            }
+        }
+    }
+
+    // Serialize result to JSON
+    let result_json = match serde_json::to_string(&result) {
+        Ok(json) => json,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("Failed to serialize query result: {}", e))
+                .unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Parse JSON string to V8 value
+    let result_value = match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(val) => val,
+        None => {
+            let msg = v8::String::new(scope, "Failed to parse result JSON").unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    rv.set(result_value);
+}
+
+/// Execute Athena query using tokio runtime
+pub fn execute_query(args: RunAthenaQueryArgs) -> Result<AthenaQueryInfo> {
+    crate::app::agent_framework::block_on_cancellable(run_athena_query_internal(args))
+}
+
+/// Build the data plane request from JavaScript arguments
+fn build_request(args: &RunAthenaQueryArgs) -> AthenaQueryRequest {
+    AthenaQueryRequest {
+        query: args.query.clone(),
+        database: args.database.clone(),
+        catalog: args.catalog.clone(),
+        workgroup: args.workgroup.clone(),
+        output_location: args.output_location.clone(),
+    }
+}
+
+/// Build poll options from JavaScript arguments, applying limits
+fn build_poll_options(args: &RunAthenaQueryArgs) -> AthenaPollOptions {
+    let mut options = AthenaPollOptions::new()
+        .with_max_rows(args.max_rows.unwrap_or(DEFAULT_ROWS).clamp(1, MAX_ROWS));
+
+    if let Some(timeout) = args.timeout_seconds {
+        options = options.with_timeout(Duration::from_secs(timeout.clamp(1, MAX_TIMEOUT_SECONDS)));
+    }
+
+    options
+}
+
+/// Internal async implementation of Athena query
+pub async fn run_athena_query_internal(args: RunAthenaQueryArgs) -> Result<AthenaQueryInfo> {
+    if args.query.trim().is_empty() {
+        return Err(anyhow!("query must not be empty"));
+    }
+
+    // Write statements (CTAS, INSERT, DROP, ...) wait for the user's approval,
+    // from scripts and from pages alike
+    if !is_read_only_statement(&args.query) {
+        register_tool_risk(RUN_QUERY_OPERATION, ToolRiskLevel::Mutating);
+        let agent_id = get_current_agent_id();
+        let parent_id = get_current_agent_type().and_then(|agent_type| agent_type.parent_id());
+        let parameters = serde_json::to_value(&args)?;
+        tokio::task::spawn_blocking(move || {
+            request_agent_approval(agent_id, parent_id, RUN_QUERY_OPERATION, parameters)
+        })
+        .await?
+        .map_err(|e| anyhow!(e))?;
+    }
+
+    info!(
+        "Running Athena query: account={}, region={}, workgroup={:?}, database={:?}",
+        args.account_id, args.region, args.workgroup, args.database
+    );
+
+    // Get global AWS client for credential coordinator
+    let aws_client = crate::app::agent_framework::utils::registry::get_global_aws_client()
+        .ok_or_else(|| anyhow!("AWS client not initialized"))?;
+
+    let credential_coordinator = aws_client.get_credential_coordinator();
+
+    let athena_client = AthenaQueryClient::new(credential_coordinator);
+
+    let request = build_request(&args);
+    let poll_options = build_poll_options(&args);
+
+    let result = athena_client
+        .run_query(&args.account_id, &args.region, &request, &poll_options)
+        .await?;
+
+    let rows = result.to_records();
+
+    Ok(AthenaQueryInfo {
+        query_execution_id: result.status.query_execution_id.clone(),
+        state: result.status.state.as_str().to_string(),
+        row_count: rows.len(),
+        rows,
+        columns: result.columns,
+        truncated: result.truncated,
+        data_scanned_bytes: result.status.data_scanned_bytes,
+        execution_time_ms: result.status.execution_time_ms,
+        output_location: result.status.output_location,
+        details_path: None,
+        message: None,
+    })
+}
+
+/// Get LLM documentation for Athena functions
+pub fn get_documentation() -> String {
+    r#"### runAthenaQuery(params)
+
+Run a SQL query with Amazon Athena and wait for the results. Use for data lakes in S3
+(CloudTrail/VPC Flow/ALB logs tables, Security Lake, cost and usage reports, etc.).
+
+**Parameters** (object):
+- `accountId` (string, required): AWS account ID
+- `region` (string, required): AWS region code (e.g., "us-east-1")
+- `query` (string, required): SQL statement (Trino/Presto syntax)
+- `database` (string, optional): Database for unqualified table names
+- `catalog` (string, optional): Data catalog (default: "AwsDataCatalog")
+- `workgroup` (string, optional): Workgroup (default: "primary")
+- `outputLocation` (string, optional): S3 prefix for results, e.g. "s3://bucket/athena/"
+  (required only if the workgroup has no result location)
+- `maxRows` (number, optional): Max rows to fetch (default: 1000, max: 10000)
+- `timeoutSeconds` (number, optional): Cancel the query after this long (default: 300, max: 900)
+
+**Returns** (object):
+- `queryExecutionId` (string), `state` (string)
+- `columns` (array): `{name, dataType}`
+- `rows` (array): Objects keyed by column name; all values are strings or null
+- `rowCount` (number), `truncated` (boolean): more rows exist than were fetched
+- `dataScannedBytes` (number), `executionTimeMs` (number)
+- `outputLocation` (string): S3 URI of the complete CSV result
+- `detailsPath` (string): VFS path holding all rows when more than 10 rows are returned
+
+**Example - Top API calls from CloudTrail logs:**
+```javascript
+const result = runAthenaQuery({
+  accountId: "123456789012",
+  region: "us-east-1",
+  database: "default",
+  query: `SELECT eventname, count(*) AS total
+          FROM cloudtrail_logs
+          WHERE eventtime > to_iso8601(current_timestamp - interval '1' day)
+          GROUP BY eventname ORDER BY total DESC LIMIT 20`
+});
+
+const rows = result.detailsPath ? vfs.readJson(result.detailsPath) : result.rows;
+rows.forEach(r => console.log(r.eventname + ": " + Number(r.total)));
+```
+
+**Important Notes:**
+- Values are returned as strings - convert with Number() where needed
+- Athena bills by data scanned: filter on partition columns and select only needed columns
+- Use `SHOW TABLES IN db` / `DESCRIBE db.table` to explore schemas
+- Write statements (CREATE TABLE AS, INSERT, DROP) wait for the user's approval and are refused in the read-only build; if denied, do not retry
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn args(value: serde_json::Value) -> RunAthenaQueryArgs {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_args_parsing() {
+        let args = args(json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "query": "SELECT 1",
+            "database": "logs",
+            "maxRows": 50
+        }));
+
+        assert_eq!(args.database.as_deref(), Some("logs"));
+        assert_eq!(args.max_rows, Some(50));
+        assert!(args.workgroup.is_none());
+
+        let request = build_request(&args);
+        assert_eq!(request.query, "SELECT 1");
+        assert_eq!(request.effective_workgroup(), "primary");
+    }
+
+    #[test]
+    fn test_build_poll_options_clamps_limits() {
+        let options = build_poll_options(&args(json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "query": "SELECT 1",
+            "maxRows": 1_000_000,
+            "timeoutSeconds": 99_999
+        })));
+
+        assert_eq!(options.max_rows, MAX_ROWS);
+        assert_eq!(options.timeout, Duration::from_secs(MAX_TIMEOUT_SECONDS));
+
+        let defaults = build_poll_options(&args(json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "query": "SELECT 1"
+        })));
+        assert_eq!(defaults.max_rows, DEFAULT_ROWS);
+    }
+}
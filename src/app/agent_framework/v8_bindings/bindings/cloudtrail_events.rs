@@ -279,11 +279,7 @@ fn get_cloudtrail_events_callback(
 
 /// Execute lookup using tokio runtime
 pub fn execute_lookup(args: GetCloudTrailEventsArgs) -> Result<CloudTrailEventsResult> {
-    // CRITICAL: Use block_in_place to avoid "Cannot start a runtime from within a runtime" error
-    tokio::task::block_in_place(|| {
-        tokio::runtime::Handle::current()
-            .block_on(async { get_cloudtrail_events_internal(args).await })
-    })
+    crate::app::agent_framework::block_on_cancellable(get_cloudtrail_events_internal(args))
 }
 
 /// Internal async implementation of CloudTrail events lookup
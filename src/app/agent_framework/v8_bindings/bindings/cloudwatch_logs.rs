@@ -275,11 +275,7 @@ fn query_cloudwatch_log_events_callback(
 
 /// Execute CloudWatch Logs query using tokio runtime
 pub fn execute_query(args: QueryCloudWatchLogEventsArgs) -> Result<CloudWatchLogsQueryResult> {
-    // Use block_in_place to avoid nested runtime error
-    tokio::task::block_in_place(|| {
-        tokio::runtime::Handle::current()
-            .block_on(async { query_cloudwatch_logs_internal(args).await })
-    })
+    crate::app::agent_framework::block_on_cancellable(query_cloudwatch_logs_internal(args))
 }
 
 /// Internal async implementation of CloudWatch Logs query
@@ -0,0 +1,1205 @@
+//! CloudWatch Metrics function bindings
+//!
+//! Provides JavaScript access to AWS CloudWatch Metrics time series,
+//! multi-statistic queries, and the alarms watching a resource.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::app::agent_framework::block_on_cancellable;
+use crate::app::agent_framework::vfs::{get_current_vfs_id, with_vfs_mut};
+use crate::app::data_plane::cloudwatch_metrics::{
+    get_default_metrics, get_metric_dimension, get_metric_namespace, AlarmResourceFilter,
+    CloudWatchMetricsClient, MetricAlarmInfo, MetricDataPoint, MetricDimension, MetricQuery,
+    MetricQueryOptions, MetricSeries, MetricStatisticsDatapoint, MetricStatisticsResult,
+};
+
+/// Maximum number of metrics per getCloudWatchMetrics() call
+const MAX_METRICS_PER_CALL: usize = 50;
+
+/// Datapoints returned inline by getMetricStatistics() before saving to VFS
+const INLINE_STATISTICS_DATAPOINTS: usize = 100;
+
+/// Alarm states accepted by listAlarmsForResource()
+const ALARM_STATES: [&str; 3] = ["OK", "ALARM", "INSUFFICIENT_DATA"];
+
+/// Metric selector passed from JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricSelectorArgs {
+    /// Metric namespace (e.g., "AWS/EC2")
+    pub namespace: String,
+
+    /// Metric name (e.g., "CPUUtilization")
+    pub metric_name: String,
+
+    /// Dimensions identifying the resource (optional)
+    #[serde(default)]
+    pub dimensions: Vec<MetricDimension>,
+
+    /// Statistic (optional, default "Average")
+    pub statistic: Option<String>,
+}
+
+/// JavaScript function call arguments for getCloudWatchMetrics()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCloudWatchMetricsArgs {
+    /// Account ID (required)
+    pub account_id: String,
+
+    /// AWS region (required)
+    pub region: String,
+
+    /// Explicit metrics to fetch (optional if resourceType/resourceId given)
+    pub metrics: Option<Vec<MetricSelectorArgs>>,
+
+    /// CloudFormation resource type for default metrics (e.g., "AWS::Lambda::Function")
+    pub resource_type: Option<String>,
+
+    /// Resource identifier for default metrics (instance ID, function name, ...)
+    pub resource_id: Option<String>,
+
+    /// Start time (Unix milliseconds timestamp, optional, default 3 hours ago)
+    pub start_time: Option<i64>,
+
+    /// End time (Unix milliseconds timestamp, optional, default now)
+    pub end_time: Option<i64>,
+
+    /// Aggregation period in seconds (optional, default 300)
+    pub period: Option<i32>,
+}
+
+impl GetCloudWatchMetricsArgs {
+    /// Resolve the metric queries to run
+    ///
+    /// Explicit `metrics` take precedence; otherwise the default metric set for
+    /// `resourceType`/`resourceId` is used.
+    pub fn to_queries(&self) -> Result<Vec<MetricQuery>> {
+        let queries: Vec<MetricQuery> = if let Some(metrics) = &self.metrics {
+            metrics
+                .iter()
+                .map(|m| MetricQuery {
+                    namespace: m.namespace.clone(),
+                    metric_name: m.metric_name.clone(),
+                    dimensions: m.dimensions.clone(),
+                    statistic: m.statistic.clone().unwrap_or_else(|| "Average".to_string()),
+                })
+                .collect()
+        } else if let (Some(resource_type), Some(resource_id)) =
+            (&self.resource_type, &self.resource_id)
+        {
+            let defaults = get_default_metrics(resource_type, resource_id);
+            if defaults.is_empty() {
+                return Err(anyhow!(
+                    "No default metrics for resource type {}. Pass 'metrics' explicitly.",
+                    resource_type
+                ));
+            }
+            defaults
+        } else {
+            return Err(anyhow!(
+                "Either 'metrics' or both 'resourceType' and 'resourceId' are required"
+            ));
+        };
+
+        if queries.is_empty() {
+            return Err(anyhow!("'metrics' must contain at least one metric"));
+        }
+        if queries.len() > MAX_METRICS_PER_CALL {
+            return Err(anyhow!(
+                "Too many metrics requested: {} (maximum {})",
+                queries.len(),
+                MAX_METRICS_PER_CALL
+            ));
+        }
+
+        Ok(queries)
+    }
+}
+
+/// Metric time series exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricSeriesInfo {
+    /// Label (metric name and statistic)
+    pub label: String,
+
+    /// Metric namespace
+    pub namespace: String,
+
+    /// Metric name
+    pub metric_name: String,
+
+    /// Statistic used for aggregation
+    pub statistic: String,
+
+    /// Dimensions identifying the resource
+    pub dimensions: Vec<MetricDimension>,
+
+    /// Datapoints sorted by timestamp ascending
+    pub datapoints: Vec<MetricDataPoint>,
+
+    /// CloudWatch status code (Complete, PartialData, ...)
+    pub status: String,
+}
+
+/// Per-series summary statistics exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricSummaryInfo {
+    /// Label (metric name and statistic)
+    pub label: String,
+
+    /// Number of datapoints in the series
+    pub datapoint_count: usize,
+
+    /// Most recent value
+    pub latest: Option<f64>,
+
+    /// Minimum value
+    pub min: Option<f64>,
+
+    /// Maximum value
+    pub max: Option<f64>,
+
+    /// Mean of all values
+    pub average: Option<f64>,
+}
+
+impl From<&MetricSeries> for MetricSummaryInfo {
+    fn from(series: &MetricSeries) -> Self {
+        Self {
+            label: series.label.clone(),
+            datapoint_count: series.datapoints.len(),
+            latest: series.latest(),
+            min: series.min(),
+            max: series.max(),
+            average: series.average(),
+        }
+    }
+}
+
+/// CloudWatch Metrics query result exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudWatchMetricsQueryResult {
+    /// Time series returned by the query (None when saved to VFS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series: Option<Vec<MetricSeriesInfo>>,
+
+    /// Summary statistics per series (always present)
+    pub summary: Vec<MetricSummaryInfo>,
+
+    /// Query start time (Unix milliseconds)
+    pub start_time: i64,
+
+    /// Query end time (Unix milliseconds)
+    pub end_time: i64,
+
+    /// Aggregation period in seconds
+    pub period: i32,
+
+    /// Total datapoints across all series
+    pub total_datapoints: usize,
+
+    /// Path to full series in VFS (when VFS is used)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_path: Option<String>,
+
+    /// Message explaining how to access full data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// JavaScript function call arguments for getMetricStatistics()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetMetricStatisticsArgs {
+    /// Account ID (required)
+    pub account_id: String,
+
+    /// AWS region (required)
+    pub region: String,
+
+    /// Metric name (required, e.g., "CPUUtilization")
+    pub metric_name: String,
+
+    /// Metric namespace (optional if resourceType is given)
+    pub namespace: Option<String>,
+
+    /// Dimensions identifying the resource (optional)
+    #[serde(default)]
+    pub dimensions: Vec<MetricDimension>,
+
+    /// CloudFormation resource type used to fill namespace and dimension
+    pub resource_type: Option<String>,
+
+    /// Resource identifier used with resourceType
+    pub resource_id: Option<String>,
+
+    /// Statistics (optional, default ["Average", "Maximum"])
+    pub statistics: Option<Vec<String>>,
+
+    /// Start time (Unix milliseconds timestamp, optional, default 3 hours ago)
+    pub start_time: Option<i64>,
+
+    /// End time (Unix milliseconds timestamp, optional, default now)
+    pub end_time: Option<i64>,
+
+    /// Aggregation period in seconds (optional, default 300)
+    pub period: Option<i32>,
+}
+
+impl GetMetricStatisticsArgs {
+    /// Resolve the metric to query
+    ///
+    /// An explicit `namespace` wins; `resourceType`/`resourceId` fill in the
+    /// namespace and add the resource's identifying dimension.
+    pub fn to_query(&self) -> Result<MetricQuery> {
+        if self.metric_name.trim().is_empty() {
+            return Err(anyhow!("'metricName' must not be empty"));
+        }
+
+        let mut dimensions = self.dimensions.clone();
+        let mut namespace = self.namespace.clone();
+
+        if let Some(resource_type) = &self.resource_type {
+            if namespace.is_none() {
+                namespace = get_metric_namespace(resource_type).map(String::from);
+            }
+            if let Some(resource_id) = &self.resource_id {
+                match get_metric_dimension(resource_type, resource_id) {
+                    Some(dimension) if !dimensions.iter().any(|d| d.name == dimension.name) => {
+                        dimensions.push(dimension)
+                    }
+                    Some(_) => {}
+                    None => {
+                        return Err(anyhow!(
+                            "No metric dimension known for resource type {}. Pass 'namespace' and 'dimensions' explicitly.",
+                            resource_type
+                        ))
+                    }
+                }
+            }
+        }
+
+        let namespace = namespace.ok_or_else(|| {
+            anyhow!("Either 'namespace' or a supported 'resourceType' is required")
+        })?;
+
+        Ok(MetricQuery {
+            namespace,
+            metric_name: self.metric_name.clone(),
+            dimensions,
+            statistic: "Average".to_string(),
+        })
+    }
+
+    /// Statistics to request, defaulting to Average and Maximum
+    pub fn statistics(&self) -> Vec<String> {
+        match &self.statistics {
+            Some(statistics) if !statistics.is_empty() => statistics.clone(),
+            _ => vec!["Average".to_string(), "Maximum".to_string()],
+        }
+    }
+}
+
+/// Per-statistic summary exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatisticSummaryInfo {
+    /// Statistic name (e.g., "Average", "p99")
+    pub statistic: String,
+
+    /// Value in the most recent datapoint
+    pub latest: Option<f64>,
+
+    /// Lowest value across datapoints
+    pub min: Option<f64>,
+
+    /// Highest value across datapoints
+    pub max: Option<f64>,
+
+    /// Timestamp of the highest value (Unix milliseconds)
+    pub max_timestamp: Option<i64>,
+}
+
+impl StatisticSummaryInfo {
+    /// Summarize one statistic over sorted datapoints
+    pub fn from_datapoints(statistic: &str, datapoints: &[MetricStatisticsDatapoint]) -> Self {
+        let values: Vec<(i64, f64)> = datapoints
+            .iter()
+            .filter_map(|p| p.value(statistic).map(|v| (p.timestamp, v)))
+            .collect();
+        let peak = values
+            .iter()
+            .copied()
+            .reduce(|a, b| if b.1 > a.1 { b } else { a });
+
+        Self {
+            statistic: statistic.to_string(),
+            latest: values.last().map(|(_, v)| *v),
+            min: values.iter().map(|(_, v)| *v).reduce(f64::min),
+            max: peak.map(|(_, v)| v),
+            max_timestamp: peak.map(|(t, _)| t),
+        }
+    }
+}
+
+/// getMetricStatistics() result exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricStatisticsQueryResult {
+    #[serde(flatten)]
+    pub result: MetricStatisticsResult,
+
+    /// Summary per requested statistic (always present)
+    pub summary: Vec<StatisticSummaryInfo>,
+
+    /// Number of datapoints (datapoints holds a sample when saved to VFS)
+    pub datapoint_count: usize,
+
+    /// Path to all datapoints in VFS (when VFS is used)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_path: Option<String>,
+
+    /// Message explaining how to access full data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// JavaScript function call arguments for listAlarmsForResource()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListAlarmsForResourceArgs {
+    /// Account ID (required)
+    pub account_id: String,
+
+    /// AWS region (required)
+    pub region: String,
+
+    /// Resource identifier (instance ID, function name, queue name, ...)
+    pub resource_id: Option<String>,
+
+    /// CloudFormation resource type, narrows the match to its dimension name
+    pub resource_type: Option<String>,
+
+    /// Explicit dimensions the alarm metric must carry (optional)
+    #[serde(default)]
+    pub dimensions: Vec<MetricDimension>,
+
+    /// Only alarms in this state: OK, ALARM, INSUFFICIENT_DATA (optional)
+    pub state_value: Option<String>,
+}
+
+impl ListAlarmsForResourceArgs {
+    /// Resolve which alarms belong to the resource
+    ///
+    /// Explicit `dimensions` win. A supported `resourceType` matches its exact
+    /// dimension; otherwise any dimension with the value `resourceId` matches.
+    pub fn to_filter(&self) -> Result<AlarmResourceFilter> {
+        if !self.dimensions.is_empty() {
+            return Ok(AlarmResourceFilter::Dimensions(self.dimensions.clone()));
+        }
+
+        let resource_id = self
+            .resource_id
+            .as_ref()
+            .filter(|id| !id.trim().is_empty())
+            .ok_or_else(|| anyhow!("Either 'resourceId' or 'dimensions' is required"))?;
+
+        let dimension = self
+            .resource_type
+            .as_deref()
+            .and_then(|resource_type| get_metric_dimension(resource_type, resource_id));
+
+        Ok(match dimension {
+            Some(dimension) => AlarmResourceFilter::Dimensions(vec![dimension]),
+            None => AlarmResourceFilter::DimensionValue(resource_id.clone()),
+        })
+    }
+
+    /// Validate and normalize the optional state filter
+    pub fn state(&self) -> Result<Option<String>> {
+        match &self.state_value {
+            None => Ok(None),
+            Some(state) => {
+                let state = state.to_uppercase();
+                if ALARM_STATES.contains(&state.as_str()) {
+                    Ok(Some(state))
+                } else {
+                    Err(anyhow!(
+                        "Invalid stateValue '{}' (expected OK, ALARM, or INSUFFICIENT_DATA)",
+                        state
+                    ))
+                }
+            }
+        }
+    }
+}
+
+/// listAlarmsForResource() result exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlarmsForResourceResult {
+    /// Matching alarms, firing alarms first
+    pub alarms: Vec<MetricAlarmInfo>,
+
+    /// Number of matching alarms
+    pub total_alarms: usize,
+
+    /// Number of matching alarms currently in ALARM state
+    pub in_alarm: usize,
+}
+
+/// Register CloudWatch Metrics functions into V8 context
+pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
+    let global = scope.get_current_context().global(scope);
+
+    // Register getCloudWatchMetrics() function
+    let get_fn = v8::Function::new(scope, get_cloudwatch_metrics_callback)
+        .expect("Failed to create getCloudWatchMetrics function");
+
+    let fn_name = v8::String::new(scope, "getCloudWatchMetrics")
+        .expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), get_fn.into());
+
+    // Register getMetricStatistics() function
+    let stats_fn = v8::Function::new(scope, get_metric_statistics_callback)
+        .expect("Failed to create getMetricStatistics function");
+
+    let fn_name = v8::String::new(scope, "getMetricStatistics")
+        .expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), stats_fn.into());
+
+    // Register listAlarmsForResource() function
+    let alarms_fn = v8::Function::new(scope, list_alarms_for_resource_callback)
+        .expect("Failed to create listAlarmsForResource function");
+
+    let fn_name = v8::String::new(scope, "listAlarmsForResource")
+        .expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), alarms_fn.into());
+
+    Ok(())
+}
+
+// Helper to throw a CloudWatch error
+fn throw_metrics_error(scope: &mut v8::PinScope<'_, '_>, msg: &str) {
+    let v8_msg = v8::String::new(scope, msg).unwrap();
+    let error = v8::Exception::error(scope, v8_msg);
+    scope.throw_exception(error);
+}
+
+// Helper to parse the object argument of a CloudWatch function
+fn parse_args<T: DeserializeOwned>(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: &v8::FunctionCallbackArguments<'_>,
+    function: &str,
+) -> Option<T> {
+    let Some(args_obj) = args.get(0).to_object(scope) else {
+        let msg = v8::String::new(
+            scope,
+            &format!("{}() requires an object argument", function),
+        )
+        .unwrap();
+        let error = v8::Exception::type_error(scope, msg);
+        scope.throw_exception(error);
+        return None;
+    };
+
+    let Some(json_str) = v8::json::stringify(scope, args_obj.into()) else {
+        throw_metrics_error(scope, "Failed to stringify arguments");
+        return None;
+    };
+    let json_str = json_str.to_rust_string_lossy(scope);
+
+    match serde_json::from_str(&json_str) {
+        Ok(args) => Some(args),
+        Err(e) => {
+            throw_metrics_error(scope, &format!("Failed to parse arguments: {}", e));
+            None
+        }
+    }
+}
+
+// Helper to return a serializable result to JavaScript
+fn set_result<T: Serialize>(
+    scope: &mut v8::PinScope<'_, '_>,
+    rv: &mut v8::ReturnValue<'_>,
+    result: &T,
+) {
+    let result_json = match serde_json::to_string(result) {
+        Ok(json) => json,
+        Err(e) => {
+            throw_metrics_error(scope, &format!("Failed to serialize result: {}", e));
+            return;
+        }
+    };
+
+    match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(value) => rv.set(value),
+        None => throw_metrics_error(scope, "Failed to parse result JSON"),
+    }
+}
+
+/// Create a CloudWatch Metrics client from the global AWS client
+fn create_client() -> Result<CloudWatchMetricsClient> {
+    let aws_client = crate::app::agent_framework::utils::registry::get_global_aws_client()
+        .ok_or_else(|| anyhow!("AWS client not initialized"))?;
+    Ok(CloudWatchMetricsClient::new(
+        aws_client.get_credential_coordinator(),
+    ))
+}
+
+/// Callback for getMetricStatistics() JavaScript function
+///
+/// When VFS is available and there are more datapoints than the inline
+/// sample, saves all datapoints to VFS and returns the most recent ones.
+fn get_metric_statistics_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let Some(stats_args) =
+        parse_args::<GetMetricStatisticsArgs>(scope, &args, "getMetricStatistics")
+    else {
+        return;
+    };
+
+    let mut result = match block_on_cancellable(get_metric_statistics_internal(stats_args)) {
+        Ok(result) => result,
+        Err(e) => {
+            throw_metrics_error(scope, &format!("getMetricStatistics failed: {}", e));
+            return;
+        }
+    };
+
+    // If VFS is available and there are many datapoints, save them and return the latest
+    if let Some(vfs_id) = get_current_vfs_id() {
+        if result.datapoint_count > INLINE_STATISTICS_DATAPOINTS {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0);
+            let vfs_path = format!("/results/metric_statistics_{}.json", timestamp);
+
+            match serde_json::to_string_pretty(&result.result.datapoints) {
+                Ok(datapoints_json) => {
+                    let write_result = with_vfs_mut(&vfs_id, |vfs| {
+                        vfs.write_file(&vfs_path, datapoints_json.as_bytes())
+                    });
+                    match write_result {
+                        Some(Ok(())) => {
+                            debug!(
+                                "Saved {} metric statistics datapoints to VFS path: {}",
+                                result.datapoint_count, vfs_path
+                            );
+                            let datapoints = &mut result.result.datapoints;
+                            datapoints.drain(..datapoints.len() - INLINE_STATISTICS_DATAPOINTS);
+                            result.details_path = Some(vfs_path.clone());
+                            result.message = Some(format!(
+                                "Fetched {} datapoints; latest {} shown. All datapoints saved to VFS. Use JSON.parse(vfs.readFile('{}')) to access.",
+                                result.datapoint_count, INLINE_STATISTICS_DATAPOINTS, vfs_path
+                            ));
+                        }
+                        Some(Err(e)) => warn!("Failed to write metric statistics to VFS: {}", e),
+                        None => warn!("VFS not found for id: {}", vfs_id),
+                    }
+                }
+                Err(e) => warn!("Failed to serialize metric statistics for VFS: {}", e),
+            }
+        }
+    }
+
+    set_result(scope, &mut rv, &result);
+}
+
+/// Callback for listAlarmsForResource() JavaScript function
+fn list_alarms_for_resource_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let Some(alarm_args) =
+        parse_args::<ListAlarmsForResourceArgs>(scope, &args, "listAlarmsForResource")
+    else {
+        return;
+    };
+
+    match block_on_cancellable(list_alarms_for_resource_internal(alarm_args)) {
+        Ok(result) => set_result(scope, &mut rv, &result),
+        Err(e) => throw_metrics_error(scope, &format!("listAlarmsForResource failed: {}", e)),
+    }
+}
+
+/// Callback for getCloudWatchMetrics() JavaScript function
+///
+/// When VFS is available, saves full series to VFS and returns summary statistics.
+fn get_cloudwatch_metrics_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    // Parse JavaScript arguments
+    let args_obj = match args.get(0).to_object(scope) {
+        Some(obj) => obj,
+        None => {
+            let msg =
+                v8::String::new(scope, "getCloudWatchMetrics() requires an object argument")
+                    .unwrap();
+            let error = v8::Exception::type_error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Convert V8 object to JSON string for parsing
+    let json_str = match v8::json::stringify(scope, args_obj.into()) {
+        Some(s) => s.to_rust_string_lossy(scope),
+        None => {
+            let msg = v8::String::new(scope, "Failed to stringify arguments").unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Parse JSON into GetCloudWatchMetricsArgs
+    let query_args: GetCloudWatchMetricsArgs = match serde_json::from_str(&json_str) {
+        Ok(args) => args,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("Failed to parse arguments: {}", e)).unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Execute async query
+    let mut result = match execute_query(query_args) {
+        Ok(result) => result,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("CloudWatch Metrics query failed: {}", e))
+                .unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // If VFS is available and we have datapoints, save to VFS and return summary
+    if let Some(vfs_id) = get_current_vfs_id() {
+        if let Some(ref series) = result.series {
+            if result.total_datapoints > 0 {
+                let timestamp = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_millis())
+                    .unwrap_or(0);
+                let vfs_path = format!("/results/cloudwatch_metrics_{}.json", timestamp);
+
+                let series_json = match serde_json::to_string_pretty(series) {
+                    Ok(json) => json,
+                    Err(e) => {
+                        warn!("Failed to serialize CloudWatch metrics for VFS: {}", e);
+                        String::new()
+                    }
+                };
+
+                if !series_json.is_empty() {
+                    let write_result = with_vfs_mut(&vfs_id, |vfs| {
+                        vfs.write_file(&vfs_path, series_json.as_bytes())
+                    });
+
+                    match write_result {
+                        Some(Ok(())) => {
+                            debug!(
+                                "Saved {} CloudWatch metric datapoints to VFS path: {}",
+                                result.total_datapoints, vfs_path
+                            );
+
+                            result.series = None;
+                            result.details_path = Some(vfs_path.clone());
+                            result.message = Some(format!(
+                                "Fetched {} datapoints across {} metrics. Full series saved to VFS. Use vfs.readJson('{}') to access.",
+                                result.total_datapoints,
+                                result.summary.len(),
+                                vfs_path
+                            ));
+                        }
+                        Some(Err(e)) => {
+                            warn!("Failed to write CloudWatch metrics to VFS: {}", e);
+                            // Fall back to inline return
+                        }
+                        None => {
+                            warn!("VFS not found for id: {}", vfs_id);
+                            // Fall back to inline return
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Serialize result to JSON
+    let result_json = match serde_json::to_string(&result) {
+        Ok(json) => json,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("Failed to serialize query result: {}", e))
+                .unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Parse JSON string to V8 value
+    let result_value = match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(val) => val,
+        None => {
+            let msg = v8::String::new(scope, "Failed to parse result JSON").unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    rv.set(result_value);
+}
+
+/// Execute CloudWatch Metrics query using tokio runtime
+pub fn execute_query(args: GetCloudWatchMetricsArgs) -> Result<CloudWatchMetricsQueryResult> {
+    block_on_cancellable(get_cloudwatch_metrics_internal(args))
+}
+
+/// Internal async implementation of CloudWatch Metrics query
+pub async fn get_cloudwatch_metrics_internal(
+    args: GetCloudWatchMetricsArgs,
+) -> Result<CloudWatchMetricsQueryResult> {
+    let queries = args.to_queries()?;
+
+    info!(
+        "Querying CloudWatch Metrics: account={}, region={}, metrics={}",
+        args.account_id,
+        args.region,
+        queries.len()
+    );
+
+    // Get global AWS client for credential coordinator
+    let aws_client = crate::app::agent_framework::utils::registry::get_global_aws_client()
+        .ok_or_else(|| anyhow!("AWS client not initialized"))?;
+
+    let credential_coordinator = aws_client.get_credential_coordinator();
+
+    // Create CloudWatch Metrics client
+    let metrics_client = CloudWatchMetricsClient::new(credential_coordinator);
+
+    // Build query options
+    let mut options = MetricQueryOptions::new();
+
+    if let Some(start_time) = args.start_time {
+        options = options.with_start_time(start_time);
+    }
+
+    if let Some(end_time) = args.end_time {
+        options = options.with_end_time(end_time);
+    }
+
+    if let Some(period) = args.period {
+        options = options.with_period(period);
+    }
+
+    // Execute query
+    let result = metrics_client
+        .get_metric_data(&args.account_id, &args.region, &queries, options)
+        .await
+        .map_err(|e| anyhow!("Failed to query CloudWatch Metrics: {}", e))?;
+
+    let summary: Vec<MetricSummaryInfo> = result.series.iter().map(Into::into).collect();
+
+    // Convert to V8-friendly format
+    let series: Vec<MetricSeriesInfo> = result
+        .series
+        .into_iter()
+        .map(|s| MetricSeriesInfo {
+            label: s.label,
+            namespace: s.namespace,
+            metric_name: s.metric_name,
+            statistic: s.statistic,
+            dimensions: s.dimensions,
+            datapoints: s.datapoints,
+            status: s.status,
+        })
+        .collect();
+
+    Ok(CloudWatchMetricsQueryResult {
+        series: Some(series),
+        summary,
+        start_time: result.start_time,
+        end_time: result.end_time,
+        period: result.period,
+        total_datapoints: result.total_datapoints,
+        details_path: None,
+        message: None,
+    })
+}
+
+/// Internal async implementation of getMetricStatistics()
+pub async fn get_metric_statistics_internal(
+    args: GetMetricStatisticsArgs,
+) -> Result<MetricStatisticsQueryResult> {
+    let query = args.to_query()?;
+    let statistics = args.statistics();
+
+    info!(
+        "Querying CloudWatch metric statistics: account={}, region={}, metric={}/{}, statistics={:?}",
+        args.account_id, args.region, query.namespace, query.metric_name, statistics
+    );
+
+    let mut options = MetricQueryOptions::new();
+
+    if let Some(start_time) = args.start_time {
+        options = options.with_start_time(start_time);
+    }
+
+    if let Some(end_time) = args.end_time {
+        options = options.with_end_time(end_time);
+    }
+
+    if let Some(period) = args.period {
+        options = options.with_period(period);
+    }
+
+    let result = create_client()?
+        .get_metric_statistics(&args.account_id, &args.region, &query, &statistics, options)
+        .await
+        .map_err(|e| anyhow!("Failed to query CloudWatch metric statistics: {}", e))?;
+
+    let summary = statistics
+        .iter()
+        .map(|statistic| StatisticSummaryInfo::from_datapoints(statistic, &result.datapoints))
+        .collect();
+
+    Ok(MetricStatisticsQueryResult {
+        datapoint_count: result.datapoints.len(),
+        result,
+        summary,
+        details_path: None,
+        message: None,
+    })
+}
+
+/// Internal async implementation of listAlarmsForResource()
+pub async fn list_alarms_for_resource_internal(
+    args: ListAlarmsForResourceArgs,
+) -> Result<AlarmsForResourceResult> {
+    let filter = args.to_filter()?;
+    let state = args.state()?;
+
+    info!(
+        "Listing CloudWatch alarms for resource: account={}, region={}, filter={:?}, state={:?}",
+        args.account_id, args.region, filter, state
+    );
+
+    let mut alarms = create_client()?
+        .list_alarms_for_resource(&args.account_id, &args.region, &filter, state.as_deref())
+        .await
+        .map_err(|e| anyhow!("Failed to list CloudWatch alarms: {}", e))?;
+
+    // Firing alarms first, then by name
+    alarms.sort_by(|a, b| {
+        (b.state_value == "ALARM")
+            .cmp(&(a.state_value == "ALARM"))
+            .then_with(|| a.alarm_name.cmp(&b.alarm_name))
+    });
+
+    let in_alarm = alarms.iter().filter(|a| a.state_value == "ALARM").count();
+
+    Ok(AlarmsForResourceResult {
+        total_alarms: alarms.len(),
+        in_alarm,
+        alarms,
+    })
+}
+
+/// Get LLM documentation for CloudWatch Metrics functions
+pub fn get_documentation() -> String {
+    r#"### getCloudWatchMetrics(params)
+
+Fetch CloudWatch metric time series for performance and utilization analysis.
+
+**Parameters** (object):
+- `accountId` (string, required): AWS account ID
+- `region` (string, required): AWS region code (e.g., "us-east-1")
+- `resourceType` + `resourceId` (strings, optional): Fetch the default metric set for a resource.
+  Supported: `AWS::EC2::Instance` (instance ID), `AWS::Lambda::Function` (function name),
+  `AWS::RDS::DBInstance` (DB identifier), `AWS::DynamoDB::Table` (table name)
+- `metrics` (array, optional): Explicit metrics, used instead of the defaults (max 50)
+  - `namespace` (string): e.g., "AWS/EC2"
+  - `metricName` (string): e.g., "CPUUtilization"
+  - `dimensions` (array of `{name, value}`, optional)
+  - `statistic` (string, optional): Average (default), Sum, Minimum, Maximum, SampleCount, p99
+- `startTime` (number, optional): Start time in Unix milliseconds (default: 3 hours ago)
+- `endTime` (number, optional): End time in Unix milliseconds (default: now)
+- `period` (number, optional): Aggregation period in seconds, multiple of 60 (default: 300)
+
+**Returns** (object):
+- `series` (array): One entry per metric (omitted when saved to VFS)
+  - `label`, `namespace`, `metricName`, `statistic`, `dimensions`, `status`
+  - `datapoints` (array of `{timestamp, value}`): Sorted oldest first
+- `summary` (array): Per-metric `{label, datapointCount, latest, min, max, average}`
+- `startTime`, `endTime`, `period`: Effective query window
+- `totalDatapoints` (number): Datapoints across all series
+- `detailsPath` (string): VFS path of the full series when results are saved to VFS
+
+**Example - Lambda health over the last day:**
+```javascript
+const metrics = getCloudWatchMetrics({
+  accountId: "123456789012",
+  region: "us-east-1",
+  resourceType: "AWS::Lambda::Function",
+  resourceId: "my-function",
+  startTime: Date.now() - (24 * 60 * 60 * 1000),
+  period: 3600
+});
+
+metrics.summary.forEach(s => console.log(s.label + ": max=" + s.max + ", latest=" + s.latest));
+```
+
+**Example - Specific metric across instances:**
+```javascript
+const cpu = getCloudWatchMetrics({
+  accountId: "123456789012",
+  region: "us-east-1",
+  metrics: ["i-0abc", "i-0def"].map(id => ({
+    namespace: "AWS/EC2",
+    metricName: "CPUUtilization",
+    dimensions: [{ name: "InstanceId", value: id }],
+    statistic: "Maximum"
+  }))
+});
+const hot = cpu.summary.filter(s => s.max > 80);
+```
+
+**Important Notes:**
+- Use the `summary` field for quick answers; read `detailsPath` only when you need every datapoint
+- Long time ranges with short periods produce many datapoints - increase `period` for multi-day windows
+- Metrics with no data in the window return an empty `datapoints` array
+
+### getMetricStatistics(params)
+
+Fetch several statistics (including percentiles) for ONE metric in a single call. Use it
+to correlate a resource's state with its load, e.g. average vs. peak CPU, or p99 duration.
+
+**Parameters** (object):
+- `accountId` (string, required): AWS account ID
+- `region` (string, required): AWS region code
+- `metricName` (string, required): e.g., "CPUUtilization", "Invocations", "Errors"
+- `namespace` (string, optional): e.g., "AWS/EC2" - filled in from `resourceType` when omitted
+- `resourceType` + `resourceId` (strings, optional): Adds the resource's dimension
+  (same resource types as getCloudWatchMetrics)
+- `dimensions` (array of `{name, value}`, optional): Explicit dimensions
+- `statistics` (array, optional): Any of Average, Sum, Minimum, Maximum, SampleCount,
+  plus percentiles like "p99" (default: ["Average", "Maximum"])
+- `startTime`, `endTime` (numbers, optional): Unix milliseconds (default: last 3 hours)
+- `period` (number, optional): Seconds, multiple of 60 (default: 300). At most 1440
+  datapoints per call - use a larger period for long ranges
+
+**Returns** (object):
+- `namespace`, `metricName`, `dimensions`, `statistics`, `startTime`, `endTime`, `period`
+- `datapoints` (array): `{timestamp, average?, sum?, minimum?, maximum?, sampleCount?,
+  extended?: {p99: ...}, unit}` oldest first (latest 100 when saved to VFS)
+- `summary` (array): Per statistic `{statistic, latest, min, max, maxTimestamp}`
+- `datapointCount` (number), `detailsPath` (string, when saved to VFS)
+
+### listAlarmsForResource(params)
+
+List the CloudWatch metric alarms that watch a resource, firing alarms first.
+
+**Parameters** (object):
+- `accountId` (string, required): AWS account ID
+- `region` (string, required): AWS region code
+- `resourceId` (string): Resource identifier (instance ID, function name, queue name, ...).
+  Matches any alarm dimension with this value
+- `resourceType` (string, optional): With `resourceId`, match only that type's dimension
+  (e.g., InstanceId for AWS::EC2::Instance)
+- `dimensions` (array of `{name, value}`, optional): Alarm metric must carry all of them
+- `stateValue` (string, optional): "OK", "ALARM", or "INSUFFICIENT_DATA"
+
+**Returns** (object):
+- `alarms` (array): `{alarmName, alarmArn, description, stateValue, stateReason,
+  stateUpdatedAt, namespace, metricName, dimensions, statistic, comparisonOperator,
+  threshold, evaluationPeriods, period, actionsEnabled, alarmActions}`
+- `totalAlarms`, `inAlarm` (numbers)
+
+**Example - Diagnose a Lambda function:**
+```javascript
+const scope = { accountId: "123456789012", region: "us-east-1" };
+const fn = { resourceType: "AWS::Lambda::Function", resourceId: "checkout" };
+
+const alarms = listAlarmsForResource({ ...scope, ...fn });
+alarms.alarms
+  .filter(a => a.stateValue === "ALARM")
+  .forEach(a => console.log(a.alarmName + ": " + a.stateReason));
+
+const errors = getMetricStatistics({
+  ...scope, ...fn,
+  metricName: "Errors",
+  statistics: ["Sum"],
+  startTime: Date.now() - (6 * 60 * 60 * 1000)
+});
+const duration = getMetricStatistics({ ...scope, ...fn, metricName: "Duration", statistics: ["Average", "p99"] });
+console.log("Errors peak: " + errors.summary[0].max + " at " + new Date(errors.summary[0].maxTimestamp));
+console.log("p99 duration: " + duration.summary[1].latest + " ms");
+```
+
+**Important Notes:**
+- Prefer getCloudWatchMetrics() for several metrics at once; use getMetricStatistics() for
+  several statistics of one metric
+- Alarms are matched on their metric dimensions; composite alarms are not included
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn base_args() -> GetCloudWatchMetricsArgs {
+        serde_json::from_value(serde_json::json!({
+            "accountId": "123456789012",
+            "region": "us-east-1"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_to_queries_from_resource() {
+        let mut args = base_args();
+        args.resource_type = Some("AWS::EC2::Instance".to_string());
+        args.resource_id = Some("i-0abc".to_string());
+
+        let queries = args.to_queries().unwrap();
+        assert_eq!(queries[0].namespace, "AWS/EC2");
+        assert_eq!(queries[0].dimensions[0].value, "i-0abc");
+    }
+
+    #[test]
+    fn test_to_queries_explicit_metrics() {
+        let args: GetCloudWatchMetricsArgs = serde_json::from_value(serde_json::json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "metrics": [{
+                "namespace": "AWS/SQS",
+                "metricName": "ApproximateNumberOfMessagesVisible",
+                "dimensions": [{ "name": "QueueName", "value": "jobs" }]
+            }]
+        }))
+        .unwrap();
+
+        let queries = args.to_queries().unwrap();
+        assert_eq!(queries.len(), 1);
+        assert_eq!(queries[0].statistic, "Average");
+        assert_eq!(queries[0].dimensions[0].name, "QueueName");
+    }
+
+    #[test]
+    fn test_to_queries_requires_selection() {
+        assert!(base_args().to_queries().is_err());
+
+        let mut args = base_args();
+        args.resource_type = Some("AWS::S3::Bucket".to_string());
+        args.resource_id = Some("my-bucket".to_string());
+        assert!(args.to_queries().is_err());
+    }
+
+    #[test]
+    fn test_metric_statistics_query_from_resource() {
+        let args: GetMetricStatisticsArgs = serde_json::from_value(serde_json::json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "metricName": "Invocations",
+            "resourceType": "AWS::Lambda::Function",
+            "resourceId": "checkout"
+        }))
+        .unwrap();
+
+        let query = args.to_query().unwrap();
+        assert_eq!(query.namespace, "AWS/Lambda");
+        assert_eq!(
+            query.dimensions,
+            vec![MetricDimension::new("FunctionName", "checkout")]
+        );
+        assert_eq!(args.statistics(), vec!["Average", "Maximum"]);
+
+        let missing: GetMetricStatisticsArgs = serde_json::from_value(serde_json::json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "metricName": "CPUUtilization"
+        }))
+        .unwrap();
+        assert!(missing.to_query().is_err());
+    }
+
+    #[test]
+    fn test_statistic_summary() {
+        let points: Vec<MetricStatisticsDatapoint> = [(1, 10.0), (2, 30.0), (3, 20.0)]
+            .iter()
+            .map(|(timestamp, maximum)| MetricStatisticsDatapoint {
+                timestamp: *timestamp,
+                maximum: Some(*maximum),
+                ..Default::default()
+            })
+            .collect();
+
+        let summary = StatisticSummaryInfo::from_datapoints("Maximum", &points);
+        assert_eq!(summary.latest, Some(20.0));
+        assert_eq!(summary.min, Some(10.0));
+        assert_eq!(summary.max, Some(30.0));
+        assert_eq!(summary.max_timestamp, Some(2));
+
+        let empty = StatisticSummaryInfo::from_datapoints("Sum", &points);
+        assert_eq!(empty.latest, None);
+        assert_eq!(empty.max_timestamp, None);
+    }
+
+    #[test]
+    fn test_alarm_args_filter_and_state() {
+        let typed: ListAlarmsForResourceArgs = serde_json::from_value(serde_json::json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "resourceType": "AWS::EC2::Instance",
+            "resourceId": "i-0abc",
+            "stateValue": "alarm"
+        }))
+        .unwrap();
+        assert_eq!(
+            typed.to_filter().unwrap(),
+            AlarmResourceFilter::Dimensions(vec![MetricDimension::new("InstanceId", "i-0abc")])
+        );
+        assert_eq!(typed.state().unwrap().as_deref(), Some("ALARM"));
+
+        let untyped: ListAlarmsForResourceArgs = serde_json::from_value(serde_json::json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "resourceId": "orders-queue",
+            "stateValue": "FIRING"
+        }))
+        .unwrap();
+        assert_eq!(
+            untyped.to_filter().unwrap(),
+            AlarmResourceFilter::DimensionValue("orders-queue".to_string())
+        );
+        assert!(untyped.state().is_err());
+
+        let empty: ListAlarmsForResourceArgs = serde_json::from_value(serde_json::json!({
+            "accountId": "123456789012",
+            "region": "us-east-1"
+        }))
+        .unwrap();
+        assert!(empty.to_filter().is_err());
+    }
+}
@@ -0,0 +1,450 @@
+//! AWS Config history function bindings
+//!
+//! Provides JavaScript access to resource configuration timelines and snapshot diffs.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::app::agent_framework::vfs::{get_current_vfs_id, with_vfs_mut};
+use crate::app::data_plane::config_history::{
+    diff_timeline, AwsConfigClient, ConfigHistoryOptions, ConfigurationItem, SnapshotDiff,
+};
+
+/// Maximum number of configuration items per getConfigHistory() call
+const MAX_ITEMS: i32 = 100;
+
+/// Number of changed paths listed per snapshot in the timeline summary
+const TIMELINE_PATH_SAMPLE: usize = 10;
+
+/// JavaScript function call arguments for getConfigHistory()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConfigHistoryArgs {
+    /// Account ID (required)
+    pub account_id: String,
+
+    /// AWS region (required)
+    pub region: String,
+
+    /// Resource type, e.g. "AWS::EC2::SecurityGroup" (required)
+    pub resource_type: String,
+
+    /// Resource ID, or name for IAM resources (required)
+    pub resource_id: String,
+
+    /// Earliest capture time (Unix milliseconds, optional)
+    pub earlier_time: Option<i64>,
+
+    /// Latest capture time (Unix milliseconds, optional)
+    pub later_time: Option<i64>,
+
+    /// Maximum configuration items to return (optional, default 25, max 100)
+    pub limit: Option<i32>,
+
+    /// Pagination token from a previous call (optional)
+    pub next_token: Option<String>,
+}
+
+/// Per-snapshot timeline entry exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEntryInfo {
+    /// Capture time (Unix milliseconds)
+    pub capture_time: i64,
+
+    /// Item status (OK, ResourceDeleted, ...)
+    pub status: String,
+
+    /// Number of fields changed since the previous snapshot
+    pub change_count: usize,
+
+    /// Sample of changed field paths
+    pub changed_paths: Vec<String>,
+}
+
+/// AWS Config history result exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigHistoryQueryResult {
+    /// Configuration items, newest first (None when saved to VFS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<Vec<ConfigurationItem>>,
+
+    /// Field-level diffs between consecutive snapshots, oldest first (None when saved to VFS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diffs: Option<Vec<SnapshotDiff>>,
+
+    /// Compact timeline, oldest first (always present)
+    pub timeline: Vec<TimelineEntryInfo>,
+
+    /// Token for pagination (if more results available)
+    pub next_token: Option<String>,
+
+    /// Number of configuration items in this result
+    pub total_items: usize,
+
+    /// Path to full items and diffs in VFS (when VFS is used)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_path: Option<String>,
+
+    /// Message explaining how to access full data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Build the compact timeline from items and their diffs
+fn build_timeline(items: &[ConfigurationItem], diffs: &[SnapshotDiff]) -> Vec<TimelineEntryInfo> {
+    let mut sorted: Vec<&ConfigurationItem> = items.iter().collect();
+    sorted.sort_by_key(|item| item.capture_time);
+
+    sorted
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            // diffs[i] describes the change from sorted[i] to sorted[i + 1]
+            let diff = index.checked_sub(1).and_then(|i| diffs.get(i));
+            TimelineEntryInfo {
+                capture_time: item.capture_time,
+                status: item.status.clone(),
+                change_count: diff.map(|d| d.changes.len()).unwrap_or(0),
+                changed_paths: diff
+                    .map(|d| {
+                        d.changes
+                            .iter()
+                            .take(TIMELINE_PATH_SAMPLE)
+                            .map(|c| c.path.clone())
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+            }
+        })
+        .collect()
+}
+
+/// Register AWS Config functions into V8 context
+pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
+    let global = scope.get_current_context().global(scope);
+
+    // Register getConfigHistory() function
+    let history_fn = v8::Function::new(scope, get_config_history_callback)
+        .expect("Failed to create getConfigHistory function");
+
+    let fn_name =
+        v8::String::new(scope, "getConfigHistory").expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), history_fn.into());
+
+    Ok(())
+}
+
+/// Callback for getConfigHistory() JavaScript function
+///
+/// When VFS is available, saves items and diffs to VFS and returns the timeline.
+fn get_config_history_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    // Parse JavaScript arguments
+    let args_obj = match args.get(0).to_object(scope) {
+        Some(obj) => obj,
+        None => {
+            let msg =
+                v8::String::new(scope, "getConfigHistory() requires an object argument").unwrap();
+            let error = v8::Exception::type_error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Convert V8 object to JSON string for parsing
+    let json_str = match v8::json::stringify(scope, args_obj.into()) {
+        Some(s) => s.to_rust_string_lossy(scope),
+        None => {
+            let msg = v8::String::new(scope, "Failed to stringify arguments").unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Parse JSON into GetConfigHistoryArgs
+    let history_args: GetConfigHistoryArgs = match serde_json::from_str(&json_str) {
+        Ok(args) => args,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("Failed to parse arguments: {}", e)).unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Execute async query
+    let mut result = match execute_query(history_args) {
+        Ok(result) => result,
+        Err(e) => {
+            let msg =
+                v8::String::new(scope, &format!("AWS Config history query failed: {}", e)).unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // If VFS is available and we have items, save to VFS and return the timeline
+    if let Some(vfs_id) = get_current_vfs_id() {
+        if let (Some(items), Some(diffs)) = (&result.items, &result.diffs) {
+            if !items.is_empty() {
+                let timestamp = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .map(|d| d.as_millis())
+                    .unwrap_or(0);
+                let vfs_path = format!("/results/config_history_{}.json", timestamp);
+
+                let details = serde_json::json!({ "items": items, "diffs": diffs });
+                let details_json = match serde_json::to_string_pretty(&details) {
+                    Ok(json) => json,
+                    Err(e) => {
+                        warn!("Failed to serialize Config history for VFS: {}", e);
+                        String::new()
+                    }
+                };
+
+                if !details_json.is_empty() {
+                    let write_result = with_vfs_mut(&vfs_id, |vfs| {
+                        vfs.write_file(&vfs_path, details_json.as_bytes())
+                    });
+
+                    match write_result {
+                        Some(Ok(())) => {
+                            debug!(
+                                "Saved {} configuration items to VFS path: {}",
+                                items.len(),
+                                vfs_path
+                            );
+
+                            result.items = None;
+                            result.diffs = None;
+                            result.details_path = Some(vfs_path.clone());
+                            result.message = Some(format!(
+                                "Found {} configuration items. Full items and diffs saved to VFS. Use vfs.readJson('{}') to access.",
+                                result.total_items,
+                                vfs_path
+                            ));
+                        }
+                        Some(Err(e)) => {
+                            warn!("Failed to write Config history to VFS: {}", e);
+                            // Fall back to inline return
+                        }
+                        None => {
+                            warn!("VFS not found for id: {}", vfs_id);
+                            // Fall back to inline return
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Serialize result to JSON
+    let result_json = match serde_json::to_string(&result) {
+        Ok(json) => json,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("Failed to serialize query result: {}", e))
+                .unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Parse JSON string to V8 value
+    let result_value = match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(val) => val,
+        None => {
+            let msg = v8::String::new(scope, "Failed to parse result JSON").unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    rv.set(result_value);
+}
+
+/// Execute AWS Config history query using tokio runtime
+pub fn execute_query(args: GetConfigHistoryArgs) -> Result<ConfigHistoryQueryResult> {
+    crate::app::agent_framework::block_on_cancellable(get_config_history_internal(args))
+}
+
+/// Internal async implementation of AWS Config history query
+pub async fn get_config_history_internal(
+    args: GetConfigHistoryArgs,
+) -> Result<ConfigHistoryQueryResult> {
+    info!(
+        "Querying AWS Config history: account={}, region={}, type={}, id={}",
+        args.account_id, args.region, args.resource_type, args.resource_id
+    );
+
+    // Get global AWS client for credential coordinator
+    let aws_client = crate::app::agent_framework::utils::registry::get_global_aws_client()
+        .ok_or_else(|| anyhow!("AWS client not initialized"))?;
+
+    let credential_coordinator = aws_client.get_credential_coordinator();
+
+    let config_client = AwsConfigClient::new(credential_coordinator);
+
+    // Build query options
+    let mut options = ConfigHistoryOptions::new();
+
+    if let Some(earlier_time) = args.earlier_time {
+        options = options.with_earlier_time(earlier_time);
+    }
+
+    if let Some(later_time) = args.later_time {
+        options = options.with_later_time(later_time);
+    }
+
+    if let Some(limit) = args.limit {
+        options = options.with_limit(limit.clamp(1, MAX_ITEMS));
+    }
+
+    if let Some(token) = args.next_token {
+        options = options.with_next_token(token);
+    }
+
+    let result = config_client
+        .get_history_for_resource(
+            &args.account_id,
+            &args.region,
+            &args.resource_type,
+            &args.resource_id,
+            options,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to query AWS Config history: {}", e))?;
+
+    let diffs = diff_timeline(&result.items);
+    let timeline = build_timeline(&result.items, &diffs);
+
+    Ok(ConfigHistoryQueryResult {
+        total_items: result.total_items,
+        next_token: result.next_token,
+        items: Some(result.items),
+        diffs: Some(diffs),
+        timeline,
+        details_path: None,
+        message: None,
+    })
+}
+
+/// Get LLM documentation for AWS Config history functions
+pub fn get_documentation() -> String {
+    r#"### getConfigHistory(params)
+
+Get a resource's configuration timeline from AWS Config and what changed between snapshots.
+Requires AWS Config recording in the target account/region.
+
+**Parameters** (object):
+- `accountId` (string, required): AWS account ID
+- `region` (string, required): AWS region code (e.g., "us-east-1")
+- `resourceType` (string, required): e.g., "AWS::EC2::SecurityGroup", "AWS::S3::Bucket"
+- `resourceId` (string, required): Resource ID (IAM roles/users/groups/policies may use the name)
+- `earlierTime` (number, optional): Earliest capture time in Unix milliseconds
+- `laterTime` (number, optional): Latest capture time in Unix milliseconds
+- `limit` (number, optional): Max configuration items (default: 25, max: 100)
+- `nextToken` (string, optional): Pagination token from a previous call
+
+**Returns** (object):
+- `timeline` (array, oldest first): `{captureTime, status, changeCount, changedPaths}`
+- `items` (array, newest first): Full configuration items (omitted when saved to VFS)
+  - `captureTime`, `status`, `resourceType`, `resourceId`, `resourceName`, `arn`
+  - `configuration` (object), `supplementaryConfiguration` (object), `tags` (object), `relationships` (array)
+- `diffs` (array, oldest first): `{fromCaptureTime, toCaptureTime, toStatus, changes}`
+  - `changes`: `{path, kind: "added"|"removed"|"modified", before, after}`
+- `nextToken` (string|null), `totalItems` (number)
+- `detailsPath` (string): VFS path holding `{items, diffs}` when results are saved to VFS
+
+**Example - Who opened a security group port?**
+```javascript
+const history = getConfigHistory({
+  accountId: "123456789012",
+  region: "us-east-1",
+  resourceType: "AWS::EC2::SecurityGroup",
+  resourceId: "sg-0123456789abcdef0",
+  earlierTime: Date.now() - (7 * 24 * 60 * 60 * 1000)
+});
+
+history.timeline
+  .filter(t => t.changedPaths.some(p => p.includes("ipPermissions")))
+  .forEach(t => console.log(new Date(t.captureTime).toISOString() + ": " + t.changedPaths.join(", ")));
+```
+
+**Important Notes:**
+- Pair with getCloudTrailEvents() around a `captureTime` to find who made the change
+- Status "ResourceDeleted" marks the final snapshot of a deleted resource
+- Empty results usually mean AWS Config is not recording that resource type
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    fn item(capture_time: i64, configuration: serde_json::Value) -> ConfigurationItem {
+        ConfigurationItem {
+            capture_time,
+            status: "OK".to_string(),
+            configuration_state_id: None,
+            resource_type: "AWS::S3::Bucket".to_string(),
+            resource_id: "my-bucket".to_string(),
+            resource_name: Some("my-bucket".to_string()),
+            arn: None,
+            aws_region: None,
+            availability_zone: None,
+            configuration,
+            supplementary_configuration: BTreeMap::new(),
+            tags: BTreeMap::new(),
+            relationships: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_build_timeline() {
+        // Newest first, as AWS Config returns by default
+        let items = vec![
+            item(3, json!({"versioning": "Enabled", "logging": true})),
+            item(2, json!({"versioning": "Enabled"})),
+            item(1, json!({"versioning": "Suspended"})),
+        ];
+        let diffs = diff_timeline(&items);
+        let timeline = build_timeline(&items, &diffs);
+
+        assert_eq!(timeline.len(), 3);
+        assert_eq!(timeline[0].capture_time, 1);
+        assert_eq!(timeline[0].change_count, 0);
+        assert_eq!(timeline[1].changed_paths, vec!["configuration.versioning"]);
+        assert_eq!(timeline[2].changed_paths, vec!["configuration.logging"]);
+    }
+
+    #[test]
+    fn test_args_parsing() {
+        let args: GetConfigHistoryArgs = serde_json::from_value(json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "resourceType": "AWS::IAM::Role",
+            "resourceId": "AdminRole",
+            "limit": 10
+        }))
+        .unwrap();
+
+        assert_eq!(args.resource_type, "AWS::IAM::Role");
+        assert_eq!(args.limit, Some(10));
+        assert!(args.earlier_time.is_none());
+    }
+}
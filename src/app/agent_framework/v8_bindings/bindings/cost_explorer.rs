@@ -0,0 +1,345 @@
+//! Cost Explorer function bindings
+//!
+//! Provides JavaScript access to month-to-date and forecast spend.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::app::agent_framework::vfs::{get_current_vfs_id, with_vfs_mut};
+use crate::app::data_plane::cost_explorer::{
+    CostDataResult, CostExplorerClient, CostGroupBy, CostMetric, CostQueryOptions,
+};
+
+/// Number of groups returned inline when the full result is saved to VFS
+const SAMPLE_GROUPS: usize = 25;
+
+/// JavaScript function call arguments for getCostData()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCostDataArgs {
+    /// Account whose credentials are used (required)
+    pub account_id: String,
+
+    /// Dimensions to group by: "service", "account", "region" (optional, default ["service"], max 2)
+    pub group_by: Option<Vec<CostGroupBy>>,
+
+    /// Cost metric: "unblended", "amortized", "blended" (optional, default "unblended")
+    pub metric: Option<CostMetric>,
+
+    /// Include the month-end forecast (optional, default true)
+    pub include_forecast: Option<bool>,
+
+    /// Only include costs of this linked account (optional)
+    pub linked_account: Option<String>,
+}
+
+/// Cost data exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CostDataInfo {
+    #[serde(flatten)]
+    pub result: CostDataResult,
+
+    /// Total number of groups (groups holds a sample when saved to VFS)
+    pub group_count: usize,
+
+    /// Path to all groups in VFS (when VFS is used)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_path: Option<String>,
+
+    /// Message explaining how to access full data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Register Cost Explorer functions into V8 context
+pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
+    let global = scope.get_current_context().global(scope);
+
+    // Register getCostData() function
+    let cost_fn = v8::Function::new(scope, get_cost_data_callback)
+        .expect("Failed to create getCostData function");
+
+    let fn_name =
+        v8::String::new(scope, "getCostData").expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), cost_fn.into());
+
+    Ok(())
+}
+
+/// Callback for getCostData() JavaScript function
+///
+/// When VFS is available and there are many groups, saves all groups to VFS
+/// and returns the most expensive ones.
+fn get_cost_data_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    // Parse JavaScript arguments
+    let args_obj = match args.get(0).to_object(scope) {
+        Some(obj) => obj,
+        None => {
+            let msg = v8::String::new(scope, "getCostData() requires an object argument").unwrap();
+            let error = v8::Exception::type_error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Convert V8 object to JSON string for parsing
+    let json_str = match v8::json::stringify(scope, args_obj.into()) {
+        Some(s) => s.to_rust_string_lossy(scope),
+        None => {
+            let msg = v8::String::new(scope, "Failed to stringify arguments").unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Parse JSON into GetCostDataArgs
+    let cost_args: GetCostDataArgs = match serde_json::from_str(&json_str) {
+        Ok(args) => args,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("Failed to parse arguments: {}", e)).unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Execute async query
+    let mut info = match execute_query(cost_args) {
+        Ok(info) => info,
+        Err(e) => {
+            let msg =
+                v8::String::new(scope, &format!("Cost Explorer query failed: {}", e)).unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // If VFS is available and there are many groups, save them to VFS and return a sample
+    if let Some(vfs_id) = get_current_vfs_id() {
+        if info.result.groups.len() > SAMPLE_GROUPS {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0);
+            let vfs_path = format!("/results/costs_{}.json", timestamp);
+
+            let groups_json = match serde_json::to_string_pretty(&info.result.groups) {
+                Ok(json) => json,
+                Err(e) => {
+                    warn!("Failed to serialize cost groups for VFS: {}", e);
+                    String::new()
+                }
+            };
+
+            if !groups_json.is_empty() {
+                let write_result = with_vfs_mut(&vfs_id, |vfs| {
+                    vfs.write_file(&vfs_path, groups_json.as_bytes())
+                });
+
+                match write_result {
+                    Some(Ok(())) => {
+                        debug!(
+                            "Saved {} cost groups to VFS path: {}",
+                            info.group_count, vfs_path
+                        );
+
+                        info.result.groups.truncate(SAMPLE_GROUPS);
+                        info.details_path = Some(vfs_path.clone());
+                        info.message = Some(format!(
+                            "{} cost groups; the {} largest are shown. All groups saved to VFS. Use vfs.readJson('{}') to access.",
+                            info.group_count,
+                            SAMPLE_GROUPS,
+                            vfs_path
+                        ));
+                    }
+                    Some(Err(e)) => {
+                        warn!("Failed to write cost groups to VFS: {}", e);
+                        // Fall back to inline return
+                    }
+                    None => {
+                        warn!("VFS not found for id: {}", vfs_id);
+                        // Fall back to inline return
+                    }
+                }
+            }
+        }
+    }
+
+    // Serialize result to JSON
+    let result_json = match serde_json::to_string(&info) {
+        Ok(json) => json,
+        Err(e) => {
+            let msg =
+                v8::String::new(scope, &format!("Failed to serialize cost data: {}", e)).unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Parse JSON string to V8 value
+    let result_value = match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(val) => val,
+        None => {
+            let msg = v8::String::new(scope, "Failed to parse result JSON").unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    rv.set(result_value);
+}
+
+/// Execute Cost Explorer query using tokio runtime
+pub fn execute_query(args: GetCostDataArgs) -> Result<CostDataInfo> {
+    crate::app::agent_framework::block_on_cancellable(get_cost_data_internal(args))
+}
+
+/// Build query options from JavaScript arguments
+fn build_options(args: &GetCostDataArgs) -> Result<CostQueryOptions> {
+    let group_by = args
+        .group_by
+        .clone()
+        .unwrap_or_else(|| vec![CostGroupBy::Service]);
+    if group_by.is_empty() || group_by.len() > 2 {
+        return Err(anyhow!(
+            "groupBy must list one or two of: service, account, region"
+        ));
+    }
+
+    let mut options = CostQueryOptions::new()
+        .with_group_by(group_by)
+        .with_metric(args.metric.unwrap_or_default())
+        .with_forecast(args.include_forecast.unwrap_or(true));
+    if let Some(linked_account) = &args.linked_account {
+        options = options.with_linked_account(linked_account.clone());
+    }
+    Ok(options)
+}
+
+/// Internal async implementation of Cost Explorer query
+pub async fn get_cost_data_internal(args: GetCostDataArgs) -> Result<CostDataInfo> {
+    let options = build_options(&args)?;
+
+    info!(
+        "Getting cost data: account={}, group_by={:?}, linked_account={:?}",
+        args.account_id, options.group_by, options.linked_account
+    );
+
+    // Get global AWS client for credential coordinator
+    let aws_client = crate::app::agent_framework::utils::registry::get_global_aws_client()
+        .ok_or_else(|| anyhow!("AWS client not initialized"))?;
+
+    let credential_coordinator = aws_client.get_credential_coordinator();
+
+    let cost_client = CostExplorerClient::new(credential_coordinator);
+
+    let result = cost_client
+        .get_cost_data(&args.account_id, &options)
+        .await?;
+
+    Ok(CostDataInfo {
+        group_count: result.groups.len(),
+        result,
+        details_path: None,
+        message: None,
+    })
+}
+
+/// Get LLM documentation for Cost Explorer functions
+pub fn get_documentation() -> String {
+    r#"### getCostData(params)
+
+Get month-to-date spend from AWS Cost Explorer, broken down by service, account, and/or
+region, with a month-end forecast.
+
+**Parameters** (object):
+- `accountId` (string, required): Account whose credentials are used. A management (payer)
+  account sees all linked accounts; a member account sees only itself
+- `groupBy` (array, optional): One or two of "service", "account", "region" (default: ["service"])
+- `metric` (string, optional): "unblended" (default), "amortized", or "blended"
+- `includeForecast` (boolean, optional): Include the month-end forecast (default: true)
+- `linkedAccount` (string, optional): Only include costs of this linked account
+
+**Returns** (object):
+- `accountId` (string), `groupBy` (array), `currency` (string, e.g. "USD")
+- `periodStart`, `periodEnd` (string): Month-to-date period (end exclusive, UTC days)
+- `total` (number): Month-to-date total
+- `forecastTotal` (number|null): Forecast month-end total
+- `groups` (array): `{keys, amount, forecast}` sorted by amount, largest first; `keys` has
+  one value per `groupBy` dimension (e.g. ["us-east-1", "AWS Lambda"])
+- `groupCount` (number): Total groups
+- `detailsPath` (string): VFS path holding all groups when more than 25 are returned
+
+**Example - Top services this month with forecast:**
+```javascript
+const costs = getCostData({ accountId: "123456789012", groupBy: ["service"] });
+
+console.log(`MTD ${costs.total.toFixed(2)} ${costs.currency}, forecast ${costs.forecastTotal}`);
+costs.groups.slice(0, 5).forEach(g =>
+  console.log(`${g.keys[0]}: ${g.amount.toFixed(2)} (forecast ${g.forecast?.toFixed(2)})`));
+```
+
+**Important Notes:**
+- Group forecasts are estimates: the total forecast split by each group's month-to-date share
+- Cost data lags by up to 24 hours; on the first day of the month `groups` is empty
+- Cost Explorer bills $0.01 per API request (one or two requests per call)
+- Global services are reported under region "global" or "NoRegion"
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn args(value: serde_json::Value) -> GetCostDataArgs {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_build_options_defaults() {
+        let options = build_options(&args(json!({ "accountId": "123456789012" }))).unwrap();
+        assert_eq!(options.group_by, vec![CostGroupBy::Service]);
+        assert_eq!(options.metric, CostMetric::Unblended);
+        assert!(options.include_forecast);
+        assert!(options.linked_account.is_none());
+    }
+
+    #[test]
+    fn test_build_options_parsing_and_limits() {
+        let options = build_options(&args(json!({
+            "accountId": "123456789012",
+            "groupBy": ["account", "region"],
+            "metric": "amortized",
+            "includeForecast": false,
+            "linkedAccount": "210987654321"
+        })))
+        .unwrap();
+        assert_eq!(
+            options.group_by,
+            vec![CostGroupBy::Account, CostGroupBy::Region]
+        );
+        assert_eq!(options.metric, CostMetric::Amortized);
+        assert!(!options.include_forecast);
+        assert_eq!(options.linked_account.as_deref(), Some("210987654321"));
+
+        assert!(build_options(&args(json!({
+            "accountId": "123456789012",
+            "groupBy": ["service", "account", "region"]
+        })))
+        .is_err());
+    }
+}
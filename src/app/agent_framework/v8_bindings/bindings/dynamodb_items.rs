@@ -0,0 +1,484 @@
+//! DynamoDB item function bindings
+//!
+//! Provides JavaScript access to the items inside DynamoDB tables through
+//! `queryDynamoTable()` and `scanDynamoTable()`. Reads are capped by item
+//! count and JSON size, and large results are saved to VFS.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use tracing::{debug, info, warn};
+
+use crate::app::agent_framework::block_on_cancellable;
+use crate::app::agent_framework::vfs::{get_current_vfs_id, with_vfs_mut};
+use crate::app::data_plane::dynamodb_items::{
+    DynamoDbItemsClient, ItemReadOptions, ItemReadResult,
+};
+
+/// Number of items returned inline when the full result is saved to VFS
+const SAMPLE_ITEMS: usize = 20;
+
+/// Results larger than this (JSON bytes) are saved to VFS
+const INLINE_BYTES: usize = 64 * 1024;
+
+/// JavaScript arguments for queryDynamoTable() and scanDynamoTable()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamoTableReadArgs {
+    /// Account ID (required)
+    pub account_id: String,
+
+    /// AWS region (required)
+    pub region: String,
+
+    /// Table name (required)
+    pub table_name: String,
+
+    /// Key condition, e.g. "pk = :pk AND begins_with(sk, :prefix)" (required for query)
+    pub key_condition_expression: Option<String>,
+
+    /// Secondary index to read (optional)
+    pub index_name: Option<String>,
+
+    /// Filter applied after reading (optional)
+    pub filter_expression: Option<String>,
+
+    /// Attributes to return (optional)
+    pub projection_expression: Option<String>,
+
+    /// Attribute name placeholders, e.g. {"#s": "status"} (optional)
+    #[serde(default)]
+    pub expression_attribute_names: HashMap<String, String>,
+
+    /// Value placeholders as plain JSON, e.g. {":pk": "user#1"} (optional)
+    #[serde(default)]
+    pub expression_attribute_values: HashMap<String, Value>,
+
+    /// lastEvaluatedKey from a previous call (optional)
+    pub start_key: Option<Map<String, Value>>,
+
+    /// Strongly consistent read (optional, default false)
+    #[serde(default)]
+    pub consistent_read: bool,
+
+    /// Sort descending by sort key, query only (optional, default false)
+    #[serde(default)]
+    pub descending: bool,
+
+    /// Maximum items to return (optional, default 100, max 5000)
+    pub max_items: Option<usize>,
+
+    /// Maximum JSON size of items in bytes (optional, default 1 MB, max 16 MB)
+    pub max_bytes: Option<usize>,
+}
+
+impl DynamoTableReadArgs {
+    /// Build read options from JavaScript arguments, applying limits
+    pub fn to_options(&self) -> Result<ItemReadOptions> {
+        if self.table_name.trim().is_empty() {
+            return Err(anyhow!("tableName must not be empty"));
+        }
+
+        let mut options = ItemReadOptions::new()
+            .with_consistent_read(self.consistent_read)
+            .with_scan_index_forward(!self.descending);
+
+        if let Some(index_name) = self.index_name.as_ref().filter(|i| !i.is_empty()) {
+            options = options.with_index_name(index_name);
+        }
+        if let Some(filter) = self.filter_expression.as_ref().filter(|f| !f.is_empty()) {
+            options = options.with_filter_expression(filter);
+        }
+        if let Some(projection) = self
+            .projection_expression
+            .as_ref()
+            .filter(|p| !p.is_empty())
+        {
+            options = options.with_projection_expression(projection);
+        }
+        if let Some(key) = &self.start_key {
+            options = options.with_exclusive_start_key(key.clone());
+        }
+        if let Some(max_items) = self.max_items {
+            options = options.with_max_items(max_items);
+        }
+        if let Some(max_bytes) = self.max_bytes {
+            options = options.with_max_bytes(max_bytes);
+        }
+
+        options.expression_attribute_names = self.expression_attribute_names.clone();
+        options.expression_attribute_values = self.expression_attribute_values.clone();
+
+        Ok(options)
+    }
+}
+
+/// Read result exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DynamoTableReadInfo {
+    #[serde(flatten)]
+    pub result: ItemReadResult,
+
+    /// Number of items returned (items holds a sample when saved to VFS)
+    pub count: usize,
+
+    /// Path to all items in VFS (when VFS is used)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_path: Option<String>,
+
+    /// Message explaining how to access full data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Register DynamoDB item functions into V8 context
+pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
+    let global = scope.get_current_context().global(scope);
+
+    // Register queryDynamoTable() function
+    let query_fn = v8::Function::new(scope, query_dynamo_table_callback)
+        .expect("Failed to create queryDynamoTable function");
+
+    let fn_name =
+        v8::String::new(scope, "queryDynamoTable").expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), query_fn.into());
+
+    // Register scanDynamoTable() function
+    let scan_fn = v8::Function::new(scope, scan_dynamo_table_callback)
+        .expect("Failed to create scanDynamoTable function");
+
+    let fn_name =
+        v8::String::new(scope, "scanDynamoTable").expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), scan_fn.into());
+
+    Ok(())
+}
+
+// Helper to throw a DynamoDB error
+fn throw_dynamo_error(scope: &mut v8::PinScope<'_, '_>, msg: &str) {
+    let v8_msg = v8::String::new(scope, msg).unwrap();
+    let error = v8::Exception::error(scope, v8_msg);
+    scope.throw_exception(error);
+}
+
+// Helper to parse the object argument of a DynamoDB function
+fn parse_args<T: DeserializeOwned>(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: &v8::FunctionCallbackArguments<'_>,
+    function: &str,
+) -> Option<T> {
+    let Some(args_obj) = args.get(0).to_object(scope) else {
+        let msg = v8::String::new(
+            scope,
+            &format!("{}() requires an object argument", function),
+        )
+        .unwrap();
+        let error = v8::Exception::type_error(scope, msg);
+        scope.throw_exception(error);
+        return None;
+    };
+
+    let Some(json_str) = v8::json::stringify(scope, args_obj.into()) else {
+        throw_dynamo_error(scope, "Failed to stringify arguments");
+        return None;
+    };
+    let json_str = json_str.to_rust_string_lossy(scope);
+
+    match serde_json::from_str(&json_str) {
+        Ok(args) => Some(args),
+        Err(e) => {
+            throw_dynamo_error(scope, &format!("Failed to parse arguments: {}", e));
+            None
+        }
+    }
+}
+
+/// Callback for queryDynamoTable() JavaScript function
+fn query_dynamo_table_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    rv: v8::ReturnValue<'_>,
+) {
+    let Some(read_args) = parse_args::<DynamoTableReadArgs>(scope, &args, "queryDynamoTable")
+    else {
+        return;
+    };
+
+    match block_on_cancellable(query_dynamo_table_internal(read_args)) {
+        Ok(result) => return_result(scope, rv, result),
+        Err(e) => throw_dynamo_error(scope, &format!("DynamoDB query failed: {}", e)),
+    }
+}
+
+/// Callback for scanDynamoTable() JavaScript function
+fn scan_dynamo_table_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    rv: v8::ReturnValue<'_>,
+) {
+    let Some(read_args) = parse_args::<DynamoTableReadArgs>(scope, &args, "scanDynamoTable") else {
+        return;
+    };
+
+    match block_on_cancellable(scan_dynamo_table_internal(read_args)) {
+        Ok(result) => return_result(scope, rv, result),
+        Err(e) => throw_dynamo_error(scope, &format!("DynamoDB scan failed: {}", e)),
+    }
+}
+
+/// Return a read result to JavaScript
+///
+/// When VFS is available and the result is large, saves all items to VFS and
+/// returns the first ones.
+fn return_result(
+    scope: &mut v8::PinScope<'_, '_>,
+    mut rv: v8::ReturnValue<'_>,
+    result: ItemReadResult,
+) {
+    let mut info = DynamoTableReadInfo {
+        count: result.items.len(),
+        result,
+        details_path: None,
+        message: None,
+    };
+
+    if let Some(vfs_id) = get_current_vfs_id() {
+        if info.count > SAMPLE_ITEMS || info.result.total_bytes > INLINE_BYTES {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0);
+            let vfs_path = format!(
+                "/results/dynamodb_{}_{}.json",
+                sanitize_table_name(&info.result.table_name),
+                timestamp
+            );
+
+            match serde_json::to_string_pretty(&info.result.items) {
+                Ok(items_json) => {
+                    let write_result = with_vfs_mut(&vfs_id, |vfs| {
+                        vfs.write_file(&vfs_path, items_json.as_bytes())
+                    });
+                    match write_result {
+                        Some(Ok(())) => {
+                            debug!(
+                                "Saved {} DynamoDB items to VFS path: {}",
+                                info.count, vfs_path
+                            );
+                            info.result.items.truncate(SAMPLE_ITEMS);
+                            info.details_path = Some(vfs_path.clone());
+                            info.message = Some(format!(
+                                "Read {} items; first {} shown. All items saved to VFS. Use JSON.parse(vfs.readFile('{}')) to access.",
+                                info.count,
+                                info.result.items.len(),
+                                vfs_path
+                            ));
+                        }
+                        Some(Err(e)) => warn!("Failed to write DynamoDB items to VFS: {}", e),
+                        None => warn!("VFS not found for id: {}", vfs_id),
+                    }
+                }
+                Err(e) => warn!("Failed to serialize DynamoDB items for VFS: {}", e),
+            }
+        }
+    }
+
+    let result_json = match serde_json::to_string(&info) {
+        Ok(json) => json,
+        Err(e) => {
+            throw_dynamo_error(scope, &format!("Failed to serialize result: {}", e));
+            return;
+        }
+    };
+
+    match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(value) => rv.set(value),
+        None => throw_dynamo_error(scope, "Failed to parse result JSON"),
+    }
+}
+
+/// Keep table names safe for use in a VFS file name
+fn sanitize_table_name(table_name: &str) -> String {
+    table_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Create a DynamoDB items client from the global AWS client
+fn create_client() -> Result<DynamoDbItemsClient> {
+    let aws_client = crate::app::agent_framework::utils::registry::get_global_aws_client()
+        .ok_or_else(|| anyhow!("AWS client not initialized"))?;
+    Ok(DynamoDbItemsClient::new(
+        aws_client.get_credential_coordinator(),
+    ))
+}
+
+/// Internal async implementation of queryDynamoTable()
+pub async fn query_dynamo_table_internal(args: DynamoTableReadArgs) -> Result<ItemReadResult> {
+    let options = args.to_options()?;
+    let key_condition = args
+        .key_condition_expression
+        .as_deref()
+        .filter(|k| !k.trim().is_empty())
+        .ok_or_else(|| {
+            anyhow!(
+                "keyConditionExpression is required (use scanDynamoTable() to read without a key)"
+            )
+        })?;
+
+    info!(
+        "Querying DynamoDB table: account={}, region={}, table={}, index={:?}",
+        args.account_id, args.region, args.table_name, args.index_name
+    );
+
+    create_client()?
+        .query_items(
+            &args.account_id,
+            &args.region,
+            &args.table_name,
+            key_condition,
+            &options,
+        )
+        .await
+}
+
+/// Internal async implementation of scanDynamoTable()
+pub async fn scan_dynamo_table_internal(args: DynamoTableReadArgs) -> Result<ItemReadResult> {
+    let options = args.to_options()?;
+
+    info!(
+        "Scanning DynamoDB table: account={}, region={}, table={}, index={:?}, filter={:?}",
+        args.account_id, args.region, args.table_name, args.index_name, args.filter_expression
+    );
+
+    create_client()?
+        .scan_items(&args.account_id, &args.region, &args.table_name, &options)
+        .await
+}
+
+/// Get LLM documentation for DynamoDB item functions
+pub fn get_documentation() -> String {
+    r##"### queryDynamoTable(params) / scanDynamoTable(params)
+
+Read the ITEMS inside a DynamoDB table or index (not its configuration). Prefer
+`queryDynamoTable()` - a scan reads the whole table until a cap is reached.
+
+**Parameters** (object):
+- `accountId` (string, required): AWS account ID
+- `region` (string, required): AWS region code
+- `tableName` (string, required): Table name
+- `keyConditionExpression` (string, required for query): e.g. "pk = :pk AND begins_with(sk, :p)"
+- `indexName` (string, optional): Global or local secondary index
+- `filterExpression` (string, optional): e.g. "#s = :failed" (applied after reading)
+- `projectionExpression` (string, optional): Attributes to return, e.g. "pk, sk, #s"
+- `expressionAttributeNames` (object, optional): e.g. `{"#s": "status"}`
+- `expressionAttributeValues` (object, optional): Plain JSON values, e.g. `{":pk": "user#1", ":n": 5}`
+- `startKey` (object, optional): `lastEvaluatedKey` from a previous call to continue
+- `consistentRead` (boolean, optional): Strongly consistent read (not on global indexes)
+- `descending` (boolean, optional, query only): Newest/highest sort key first
+- `maxItems` (number, optional): default 100, max 5000
+- `maxBytes` (number, optional): JSON size of returned items, default 1048576 (1 MB), max 16 MB
+
+**Returns** (object):
+- `tableName`, `indexName`
+- `items` (array): Items as plain JSON (numbers as numbers, binary as base64, sets as arrays)
+- `count` (number): Items returned; `scannedCount` (number): items read before the filter
+- `totalBytes` (number), `consumedCapacity` (number): Read capacity units used
+- `lastEvaluatedKey` (object|null): Pass as `startKey` to read more; null at the end
+- `truncatedBy` ("maxItems"|"maxBytes"|"maxPages"|null): Which cap stopped the read
+- `detailsPath` (string): VFS path of all items when more than 20 items or 64 KB were read
+  (`items` then holds the first 20)
+
+**Example - Recent failed jobs for a customer:**
+```javascript
+const result = queryDynamoTable({
+  accountId: "123456789012",
+  region: "us-east-1",
+  tableName: "jobs",
+  keyConditionExpression: "pk = :pk",
+  filterExpression: "#s = :failed",
+  expressionAttributeNames: { "#s": "status" },
+  expressionAttributeValues: { ":pk": "customer#42", ":failed": "FAILED" },
+  descending: true,
+  maxItems: 50
+});
+const items = result.detailsPath ? JSON.parse(vfs.readFile(result.detailsPath)) : result.items;
+console.log(result.count + " failed jobs (" + result.scannedCount + " read)");
+```
+
+**Important Notes:**
+- Use getResourceSchema('AWS::DynamoDB::Table') / queryCachedResources() to learn key names first
+- `filterExpression` does not reduce capacity used - a selective key condition does
+- A scan stops after 50 pages (about 50 MB read); check `truncatedBy` before concluding
+  an item does not exist
+- Placeholders in `expressionAttributeValues` must start with ':'
+"##
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::dynamodb_items::MAX_ITEMS;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_options() {
+        let args: DynamoTableReadArgs = serde_json::from_value(json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "tableName": "jobs",
+            "indexName": "",
+            "filterExpression": "#s = :failed",
+            "expressionAttributeNames": { "#s": "status" },
+            "expressionAttributeValues": { ":failed": "FAILED" },
+            "startKey": { "pk": "customer#42", "sk": 7 },
+            "descending": true,
+            "maxItems": 1_000_000
+        }))
+        .unwrap();
+
+        let options = args.to_options().unwrap();
+        assert!(options.index_name.is_none());
+        assert_eq!(options.filter_expression.as_deref(), Some("#s = :failed"));
+        assert_eq!(options.expression_attribute_names["#s"], "status");
+        assert_eq!(options.exclusive_start_key.unwrap()["sk"], 7);
+        assert!(!options.scan_index_forward);
+        assert_eq!(options.max_items, MAX_ITEMS);
+    }
+
+    #[test]
+    fn test_query_requires_key_condition() {
+        let args: DynamoTableReadArgs = serde_json::from_value(json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "tableName": "jobs"
+        }))
+        .unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let error = runtime
+            .block_on(query_dynamo_table_internal(args))
+            .unwrap_err();
+        assert!(error.to_string().contains("keyConditionExpression"));
+    }
+
+    #[test]
+    fn test_sanitize_table_name() {
+        assert_eq!(sanitize_table_name("orders-prod_v2"), "orders-prod_v2");
+        assert_eq!(sanitize_table_name("a.b/c"), "a_b_c");
+    }
+}
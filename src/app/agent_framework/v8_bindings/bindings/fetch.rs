@@ -0,0 +1,537 @@
+//! HTTP fetch() binding
+//!
+//! Provides a controlled subset of the browser `fetch()` API so agent scripts
+//! can retrieve public data (pricing files, IP ranges) over HTTPS. Requests are
+//! limited to allow-listed hosts, redirects are checked against the same list,
+//! and responses are capped in size and time. No credentials or cookies are
+//! ever attached.
+//!
+//! The request itself runs synchronously in Rust; a small JavaScript shim wraps
+//! the result in a Promise and a Response-like object, so `await fetch(...)`
+//! resolves during the runtime's microtask checkpoint.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tracing::info;
+use url::Url;
+
+use crate::app::agent_framework::block_on_cancellable;
+
+/// Default response size limit (5 MB)
+pub const DEFAULT_MAX_RESPONSE_BYTES: usize = 5 * 1024 * 1024;
+
+/// Default request timeout
+pub const DEFAULT_FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Most redirects followed per request
+const MAX_REDIRECTS: usize = 5;
+
+/// Request headers scripts may not set
+const FORBIDDEN_HEADERS: &[&str] = &[
+    "connection",
+    "content-length",
+    "cookie",
+    "host",
+    "proxy-authorization",
+    "transfer-encoding",
+];
+
+/// Hosts allowed by default: public AWS pricing and IP range data
+const DEFAULT_ALLOWED_HOSTS: &[&str] = &[
+    "pricing.us-east-1.amazonaws.com",
+    "b0.p.awsstatic.com",
+    "ip-ranges.amazonaws.com",
+];
+
+/// Limits applied to every fetch() call
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchPolicy {
+    /// Hosts fetch() may contact; "*.example.com" matches any subdomain
+    pub allowed_hosts: Vec<String>,
+
+    /// Largest response body accepted, in bytes
+    pub max_response_bytes: usize,
+
+    /// Longest a request may take, including redirects and the body
+    pub timeout: Duration,
+}
+
+impl Default for FetchPolicy {
+    fn default() -> Self {
+        Self {
+            allowed_hosts: DEFAULT_ALLOWED_HOSTS
+                .iter()
+                .map(|h| h.to_string())
+                .collect(),
+            max_response_bytes: DEFAULT_MAX_RESPONSE_BYTES,
+            timeout: DEFAULT_FETCH_TIMEOUT,
+        }
+    }
+}
+
+impl FetchPolicy {
+    /// Whether a host is on the allow list
+    pub fn is_host_allowed(&self, host: &str) -> bool {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        self.allowed_hosts.iter().any(|pattern| {
+            let pattern = pattern.to_ascii_lowercase();
+            match pattern.strip_prefix("*.") {
+                Some(domain) => host.ends_with(&format!(".{}", domain)),
+                None => host == pattern,
+            }
+        })
+    }
+
+    /// Whether a URL uses HTTPS and an allow-listed host
+    pub fn is_url_allowed(&self, url: &Url) -> bool {
+        url.scheme() == "https" && url.host_str().is_some_and(|h| self.is_host_allowed(h))
+    }
+}
+
+/// Second argument of fetch() (subset of RequestInit)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchInit {
+    /// HTTP method: GET, HEAD, or POST (default GET)
+    pub method: Option<String>,
+
+    /// Request headers
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+
+    /// Request body (POST only)
+    pub body: Option<String>,
+
+    /// Request timeout in milliseconds (optional, capped by the policy)
+    pub timeout_ms: Option<u64>,
+}
+
+/// A validated request ready to send
+#[derive(Debug, Clone, PartialEq)]
+pub struct FetchRequest {
+    pub url: Url,
+    pub method: reqwest::Method,
+    pub headers: BTreeMap<String, String>,
+    pub body: Option<String>,
+    pub timeout: Duration,
+}
+
+impl FetchRequest {
+    /// Validate a fetch() call against the policy
+    pub fn new(url: &str, init: FetchInit, policy: &FetchPolicy) -> Result<Self> {
+        let url = Url::parse(url).map_err(|e| anyhow!("Invalid URL '{}': {}", url, e))?;
+        if url.scheme() != "https" {
+            return Err(anyhow!("Only https:// URLs can be fetched"));
+        }
+        let host = url.host_str().unwrap_or_default();
+        if !policy.is_host_allowed(host) {
+            return Err(anyhow!(
+                "Host '{}' is not allowed (allowed: {})",
+                host,
+                policy.allowed_hosts.join(", ")
+            ));
+        }
+
+        let method = init.method.as_deref().unwrap_or("GET").to_ascii_uppercase();
+        let method = match method.as_str() {
+            "GET" => reqwest::Method::GET,
+            "HEAD" => reqwest::Method::HEAD,
+            "POST" => reqwest::Method::POST,
+            other => {
+                return Err(anyhow!(
+                    "Method {} is not supported (use GET, HEAD, or POST)",
+                    other
+                ))
+            }
+        };
+        if init.body.is_some() && method != reqwest::Method::POST {
+            return Err(anyhow!("A request body is only allowed with POST"));
+        }
+
+        if let Some(name) = init
+            .headers
+            .keys()
+            .find(|name| FORBIDDEN_HEADERS.contains(&name.to_ascii_lowercase().as_str()))
+        {
+            return Err(anyhow!("Header '{}' cannot be set", name));
+        }
+
+        let timeout = init
+            .timeout_ms
+            .map(Duration::from_millis)
+            .map_or(policy.timeout, |t| t.min(policy.timeout));
+
+        Ok(Self {
+            url,
+            method,
+            headers: init.headers,
+            body: init.body,
+            timeout,
+        })
+    }
+}
+
+/// Result handed to the JavaScript shim
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FetchResponse {
+    pub ok: bool,
+    pub status: u16,
+    pub status_text: String,
+    /// Final URL after redirects
+    pub url: String,
+    pub redirected: bool,
+    /// Response headers, lowercase names
+    pub headers: BTreeMap<String, String>,
+    /// Body as text (invalid UTF-8 replaced)
+    pub body: String,
+}
+
+/// JavaScript wrapper that turns the native call into a Promise-based fetch()
+const FETCH_SHIM: &str = r#"
+(function (global) {
+  const fetchSync = global.__fetchSync;
+  delete global.__fetchSync;
+
+  function makeHeaders(raw) {
+    const map = {};
+    Object.keys(raw || {}).forEach(k => { map[k.toLowerCase()] = raw[k]; });
+    return {
+      get: name => { const v = map[String(name).toLowerCase()]; return v === undefined ? null : v; },
+      has: name => String(name).toLowerCase() in map,
+      entries: () => Object.entries(map),
+      forEach: cb => Object.keys(map).forEach(k => cb(map[k], k)),
+    };
+  }
+
+  global.fetch = function fetch(input, init) {
+    try {
+      const url = typeof input === 'string' ? input : String(input && input.url || input);
+      const r = fetchSync(url, init || {});
+      const body = r.body;
+      return Promise.resolve({
+        ok: r.ok,
+        status: r.status,
+        statusText: r.statusText,
+        url: r.url,
+        redirected: r.redirected,
+        headers: makeHeaders(r.headers),
+        text: () => Promise.resolve(body),
+        json: () => {
+          try { return Promise.resolve(JSON.parse(body)); } catch (e) { return Promise.reject(e); }
+        },
+      });
+    } catch (e) {
+      return Promise.reject(e);
+    }
+  };
+})(globalThis);
+"#;
+
+/// Register fetch() into V8 context
+pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
+    let global = scope.get_current_context().global(scope);
+
+    // Register the native request function used by the shim
+    let fetch_fn =
+        v8::Function::new(scope, fetch_sync_callback).expect("Failed to create fetch function");
+
+    let fn_name =
+        v8::String::new(scope, "__fetchSync").expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), fetch_fn.into());
+
+    // Define fetch() on top of it
+    let source = v8::String::new(scope, FETCH_SHIM)
+        .ok_or_else(|| anyhow!("Failed to create fetch shim source"))?;
+    let script = v8::Script::compile(scope, source, None)
+        .ok_or_else(|| anyhow!("Failed to compile fetch shim"))?;
+    script
+        .run(scope)
+        .ok_or_else(|| anyhow!("Failed to run fetch shim"))?;
+
+    Ok(())
+}
+
+// Helper to throw a fetch error
+fn throw_fetch_error(scope: &mut v8::PinScope<'_, '_>, msg: &str) {
+    let v8_msg = v8::String::new(scope, msg).unwrap();
+    let error = v8::Exception::type_error(scope, v8_msg);
+    scope.throw_exception(error);
+}
+
+/// Callback for the native fetch function: fetch(url, init) -> response
+fn fetch_sync_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let url = args.get(0).to_rust_string_lossy(scope);
+
+    let init_value = args.get(1);
+    let init = if init_value.is_null_or_undefined() {
+        FetchInit::default()
+    } else {
+        let Some(json_str) = v8::json::stringify(scope, init_value) else {
+            throw_fetch_error(scope, "fetch failed: options are not serializable");
+            return;
+        };
+        let json_str = json_str.to_rust_string_lossy(scope);
+        match serde_json::from_str(&json_str) {
+            Ok(init) => init,
+            Err(e) => {
+                throw_fetch_error(scope, &format!("fetch failed: invalid options: {}", e));
+                return;
+            }
+        }
+    };
+
+    let policy = FetchPolicy::default();
+    let request = match FetchRequest::new(&url, init, &policy) {
+        Ok(request) => request,
+        Err(e) => {
+            throw_fetch_error(scope, &format!("fetch failed: {}", e));
+            return;
+        }
+    };
+
+    let response = match block_on_cancellable(send_request(request, policy)) {
+        Ok(response) => response,
+        Err(e) => {
+            throw_fetch_error(scope, &format!("fetch failed: {}", e));
+            return;
+        }
+    };
+
+    let result_json = match serde_json::to_string(&response) {
+        Ok(json) => json,
+        Err(e) => {
+            throw_fetch_error(scope, &format!("Failed to serialize response: {}", e));
+            return;
+        }
+    };
+
+    match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(value) => rv.set(value),
+        None => throw_fetch_error(scope, "Failed to parse response JSON"),
+    }
+}
+
+/// Send a validated request, enforcing redirect and size limits
+pub async fn send_request(request: FetchRequest, policy: FetchPolicy) -> Result<FetchResponse> {
+    info!("fetch {} {}", request.method, request.url);
+
+    let max_bytes = policy.max_response_bytes;
+    let redirect_policy = reqwest::redirect::Policy::custom(move |attempt| {
+        if attempt.previous().len() >= MAX_REDIRECTS {
+            attempt.error(format!("more than {} redirects", MAX_REDIRECTS))
+        } else if policy.is_url_allowed(attempt.url()) {
+            attempt.follow()
+        } else {
+            let target = attempt.url().to_string();
+            attempt.error(format!("redirect to {} is not allowed", target))
+        }
+    });
+
+    let client = reqwest::Client::builder()
+        .timeout(request.timeout)
+        .redirect(redirect_policy)
+        .build()?;
+
+    let mut builder = client.request(request.method, request.url.clone());
+    for (name, value) in &request.headers {
+        builder = builder.header(name, value);
+    }
+    if let Some(body) = request.body {
+        builder = builder.body(body);
+    }
+
+    let mut response = builder.send().await?;
+
+    if response
+        .content_length()
+        .is_some_and(|len| len as usize > max_bytes)
+    {
+        return Err(anyhow!(
+            "response is larger than the {} byte limit",
+            max_bytes
+        ));
+    }
+
+    let status = response.status();
+    let final_url = response.url().clone();
+    let headers = response
+        .headers()
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .to_str()
+                .ok()
+                .map(|v| (name.as_str().to_string(), v.to_string()))
+        })
+        .collect();
+
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        if body.len() + chunk.len() > max_bytes {
+            return Err(anyhow!(
+                "response is larger than the {} byte limit",
+                max_bytes
+            ));
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    Ok(FetchResponse {
+        ok: status.is_success(),
+        status: status.as_u16(),
+        status_text: status.canonical_reason().unwrap_or_default().to_string(),
+        redirected: final_url != request.url,
+        url: final_url.to_string(),
+        headers,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+/// Get LLM documentation for fetch()
+pub fn get_documentation() -> String {
+    let policy = FetchPolicy::default();
+    format!(
+        r#"### fetch(url, options?)
+
+Retrieve public data over HTTPS. Returns a Promise, so call it inside an async
+IIFE and `await` it; the runtime resolves the promise before returning.
+
+**Limits**: Only these hosts are allowed: {hosts}. Responses over {max_mb}MB
+and requests over {timeout}s fail. No AWS credentials or cookies are sent.
+
+**Parameters**:
+- `url` (string, required): https:// URL on an allowed host
+- `options` (object, optional): `method` ("GET", "HEAD", or "POST"), `headers` (object),
+  `body` (string, POST only), `timeoutMs` (number)
+
+**Returns**: Promise of `{{ok, status, statusText, url, redirected, headers, text(), json()}}`;
+`headers.get(name)` reads a header, `text()` and `json()` return Promises
+
+**Example - Public IP ranges for a service and region**
+```javascript
+(async () => {{
+  const res = await fetch("https://ip-ranges.amazonaws.com/ip-ranges.json");
+  if (!res.ok) throw new Error("HTTP " + res.status);
+  const data = await res.json();
+  return data.prefixes
+    .filter(p => p.service === "S3" && p.region === "us-east-1")
+    .map(p => p.ip_prefix);
+}})()
+```
+
+**Important Notes:**
+- A disallowed host, a redirect to one, or an oversized response rejects the Promise
+- HTTP error statuses (404, 500) resolve normally - check `ok` or `status`
+- Large price list files can exceed the size limit; prefer region-specific files
+"#,
+        hosts = policy.allowed_hosts.join(", "),
+        max_mb = policy.max_response_bytes / (1024 * 1024),
+        timeout = policy.timeout.as_secs(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::agent_framework::v8_bindings::{initialize_v8_platform, V8Runtime};
+
+    #[test]
+    fn test_host_allow_list() {
+        let policy = FetchPolicy {
+            allowed_hosts: vec![
+                "api.example.com".to_string(),
+                "*.static.example.com".to_string(),
+            ],
+            ..Default::default()
+        };
+
+        assert!(policy.is_host_allowed("api.example.com"));
+        assert!(policy.is_host_allowed("API.example.com."));
+        assert!(policy.is_host_allowed("cdn.static.example.com"));
+        assert!(!policy.is_host_allowed("static.example.com"));
+        assert!(!policy.is_host_allowed("evil-api.example.com"));
+        assert!(!policy.is_host_allowed("api.example.com.evil.net"));
+    }
+
+    #[test]
+    fn test_request_validation() {
+        let policy = FetchPolicy::default();
+        let url = "https://ip-ranges.amazonaws.com/ip-ranges.json";
+
+        let request = FetchRequest::new(url, FetchInit::default(), &policy).unwrap();
+        assert_eq!(request.method, reqwest::Method::GET);
+        assert_eq!(request.timeout, DEFAULT_FETCH_TIMEOUT);
+
+        assert!(FetchRequest::new(
+            "http://ip-ranges.amazonaws.com/",
+            FetchInit::default(),
+            &policy
+        )
+        .is_err());
+        assert!(FetchRequest::new("https://example.com/", FetchInit::default(), &policy).is_err());
+
+        let delete = FetchInit {
+            method: Some("delete".to_string()),
+            ..Default::default()
+        };
+        assert!(FetchRequest::new(url, delete, &policy).is_err());
+
+        let get_with_body = FetchInit {
+            body: Some("{}".to_string()),
+            ..Default::default()
+        };
+        assert!(FetchRequest::new(url, get_with_body, &policy).is_err());
+
+        let cookie = FetchInit {
+            headers: BTreeMap::from([("Cookie".to_string(), "a=b".to_string())]),
+            ..Default::default()
+        };
+        assert!(FetchRequest::new(url, cookie, &policy).is_err());
+
+        let long_timeout = FetchInit {
+            timeout_ms: Some(60_000),
+            ..Default::default()
+        };
+        let request = FetchRequest::new(url, long_timeout, &policy).unwrap();
+        assert_eq!(request.timeout, DEFAULT_FETCH_TIMEOUT);
+    }
+
+    #[test]
+    fn test_disallowed_host_rejects_promise() {
+        let _ = initialize_v8_platform();
+
+        let runtime = V8Runtime::new();
+        let code = r#"
+            (async () => {
+                try {
+                    await fetch("https://example.com/data.json");
+                    return "fetched";
+                } catch (e) {
+                    return e.message;
+                }
+            })()
+        "#;
+        let result = runtime.execute(code).unwrap();
+
+        assert!(result.success, "stderr: {}", result.stderr);
+        let message = result.result.unwrap();
+        assert!(
+            message.contains("Host 'example.com' is not allowed"),
+            "got: {}",
+            message
+        );
+
+        // The native helper is hidden behind fetch()
+        let result = runtime.execute("typeof __fetchSync").unwrap();
+        assert_eq!(result.result.unwrap(), "\"undefined\"");
+    }
+}
@@ -0,0 +1,429 @@
+//! IAM simulation function bindings
+//!
+//! Provides JavaScript access to STS caller identity and the IAM policy
+//! simulator, so agents can check whether a principal may call an action
+//! instead of interpreting policy documents themselves.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use tracing::info;
+
+use crate::app::agent_framework::block_on_cancellable;
+use crate::app::data_plane::iam_simulation::{
+    partition_for_region, role_arn, user_arn, CallerIdentity, IamSimulationClient,
+    SimulationContextEntry, SimulationOptions, SimulationResult, MAX_SIMULATION_ACTIONS,
+    MAX_SIMULATION_RESOURCES,
+};
+
+/// Region used when none is given (IAM and STS are global services)
+const DEFAULT_REGION: &str = "us-east-1";
+
+/// JavaScript arguments for getCallerIdentity()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetCallerIdentityArgs {
+    /// Account ID (required)
+    pub account_id: String,
+
+    /// AWS region (optional, default us-east-1)
+    pub region: Option<String>,
+}
+
+/// JavaScript arguments for simulatePrincipalPolicy()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatePrincipalPolicyArgs {
+    /// Account ID that owns the principal (required)
+    pub account_id: String,
+
+    /// AWS region (optional, default us-east-1; selects the partition)
+    pub region: Option<String>,
+
+    /// ARN of the user, group, or role to evaluate
+    pub policy_source_arn: Option<String>,
+
+    /// Role name (alternative to policySourceArn, may include a path)
+    pub role_name: Option<String>,
+
+    /// User name (alternative to policySourceArn, may include a path)
+    pub user_name: Option<String>,
+
+    /// Actions to evaluate, e.g. ["s3:GetObject"] (required)
+    pub action_names: Vec<String>,
+
+    /// Resource ARNs (optional, default "*")
+    #[serde(default)]
+    pub resource_arns: Vec<String>,
+
+    /// Context keys for policy conditions (optional)
+    #[serde(default)]
+    pub context_entries: Vec<SimulationContextEntry>,
+
+    /// Caller ARN (optional)
+    pub caller_arn: Option<String>,
+
+    /// Resource-based policy as a JSON string or object (optional)
+    pub resource_policy: Option<Value>,
+
+    /// Account that owns the resources (optional)
+    pub resource_owner: Option<String>,
+}
+
+impl SimulatePrincipalPolicyArgs {
+    /// Effective region
+    pub fn region(&self) -> &str {
+        self.region
+            .as_deref()
+            .filter(|r| !r.is_empty())
+            .unwrap_or(DEFAULT_REGION)
+    }
+
+    /// Resolve the principal ARN from policySourceArn, roleName, or userName
+    pub fn policy_source_arn(&self) -> Result<String> {
+        let partition = partition_for_region(self.region());
+        match (&self.policy_source_arn, &self.role_name, &self.user_name) {
+            (Some(arn), None, None) if !arn.is_empty() => Ok(arn.clone()),
+            (None, Some(role), None) if !role.is_empty() => {
+                Ok(role_arn(partition, &self.account_id, role))
+            }
+            (None, None, Some(user)) if !user.is_empty() => {
+                Ok(user_arn(partition, &self.account_id, user))
+            }
+            _ => Err(anyhow!(
+                "Exactly one of 'policySourceArn', 'roleName', or 'userName' is required"
+            )),
+        }
+    }
+
+    /// Validate limits and build simulation options
+    pub fn to_options(&self) -> Result<SimulationOptions> {
+        if self.action_names.is_empty() {
+            return Err(anyhow!("'actionNames' must contain at least one action"));
+        }
+        if self.action_names.len() > MAX_SIMULATION_ACTIONS {
+            return Err(anyhow!(
+                "Too many actions: {} (maximum {})",
+                self.action_names.len(),
+                MAX_SIMULATION_ACTIONS
+            ));
+        }
+        if self.resource_arns.len() > MAX_SIMULATION_RESOURCES {
+            return Err(anyhow!(
+                "Too many resource ARNs: {} (maximum {})",
+                self.resource_arns.len(),
+                MAX_SIMULATION_RESOURCES
+            ));
+        }
+        if let Some(action) = self.action_names.iter().find(|a| !a.contains(':')) {
+            return Err(anyhow!(
+                "Action '{}' must be in service:Action form, e.g. s3:GetObject",
+                action
+            ));
+        }
+
+        let resource_policy = match &self.resource_policy {
+            None | Some(Value::Null) => None,
+            Some(Value::String(policy)) => Some(policy.clone()),
+            Some(policy) => Some(serde_json::to_string(policy)?),
+        };
+
+        Ok(SimulationOptions {
+            resource_arns: self.resource_arns.clone(),
+            context_entries: self.context_entries.clone(),
+            caller_arn: self.caller_arn.clone(),
+            resource_policy,
+            resource_owner: self.resource_owner.clone(),
+            permissions_boundary_policies: Vec::new(),
+        })
+    }
+}
+
+/// Register IAM simulation functions into V8 context
+pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
+    let global = scope.get_current_context().global(scope);
+
+    // Register getCallerIdentity() function
+    let identity_fn = v8::Function::new(scope, get_caller_identity_callback)
+        .expect("Failed to create getCallerIdentity function");
+
+    let fn_name =
+        v8::String::new(scope, "getCallerIdentity").expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), identity_fn.into());
+
+    // Register simulatePrincipalPolicy() function
+    let simulate_fn = v8::Function::new(scope, simulate_principal_policy_callback)
+        .expect("Failed to create simulatePrincipalPolicy function");
+
+    let fn_name = v8::String::new(scope, "simulatePrincipalPolicy")
+        .expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), simulate_fn.into());
+
+    Ok(())
+}
+
+// Helper to throw an IAM error
+fn throw_iam_error(scope: &mut v8::PinScope<'_, '_>, msg: &str) {
+    let v8_msg = v8::String::new(scope, msg).unwrap();
+    let error = v8::Exception::error(scope, v8_msg);
+    scope.throw_exception(error);
+}
+
+// Helper to parse the object argument of an IAM function
+fn parse_args<T: DeserializeOwned>(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: &v8::FunctionCallbackArguments<'_>,
+    function: &str,
+) -> Option<T> {
+    let Some(args_obj) = args.get(0).to_object(scope) else {
+        let msg = v8::String::new(
+            scope,
+            &format!("{}() requires an object argument", function),
+        )
+        .unwrap();
+        let error = v8::Exception::type_error(scope, msg);
+        scope.throw_exception(error);
+        return None;
+    };
+
+    let Some(json_str) = v8::json::stringify(scope, args_obj.into()) else {
+        throw_iam_error(scope, "Failed to stringify arguments");
+        return None;
+    };
+    let json_str = json_str.to_rust_string_lossy(scope);
+
+    match serde_json::from_str(&json_str) {
+        Ok(args) => Some(args),
+        Err(e) => {
+            throw_iam_error(scope, &format!("Failed to parse arguments: {}", e));
+            None
+        }
+    }
+}
+
+// Helper to return a serializable result to JavaScript
+fn set_result<T: Serialize>(
+    scope: &mut v8::PinScope<'_, '_>,
+    rv: &mut v8::ReturnValue<'_>,
+    result: &T,
+) {
+    let result_json = match serde_json::to_string(result) {
+        Ok(json) => json,
+        Err(e) => {
+            throw_iam_error(scope, &format!("Failed to serialize result: {}", e));
+            return;
+        }
+    };
+
+    match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(value) => rv.set(value),
+        None => throw_iam_error(scope, "Failed to parse result JSON"),
+    }
+}
+
+/// Callback for getCallerIdentity() JavaScript function
+fn get_caller_identity_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let Some(identity_args) =
+        parse_args::<GetCallerIdentityArgs>(scope, &args, "getCallerIdentity")
+    else {
+        return;
+    };
+
+    match block_on_cancellable(get_caller_identity_internal(identity_args)) {
+        Ok(identity) => set_result(scope, &mut rv, &identity),
+        Err(e) => throw_iam_error(scope, &format!("getCallerIdentity failed: {}", e)),
+    }
+}
+
+/// Callback for simulatePrincipalPolicy() JavaScript function
+fn simulate_principal_policy_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let Some(simulate_args) =
+        parse_args::<SimulatePrincipalPolicyArgs>(scope, &args, "simulatePrincipalPolicy")
+    else {
+        return;
+    };
+
+    match block_on_cancellable(simulate_principal_policy_internal(simulate_args)) {
+        Ok(result) => set_result(scope, &mut rv, &result),
+        Err(e) => throw_iam_error(scope, &format!("simulatePrincipalPolicy failed: {}", e)),
+    }
+}
+
+/// Create an IAM simulation client from the global AWS client
+fn create_client() -> Result<IamSimulationClient> {
+    let aws_client = crate::app::agent_framework::utils::registry::get_global_aws_client()
+        .ok_or_else(|| anyhow!("AWS client not initialized"))?;
+    Ok(IamSimulationClient::new(
+        aws_client.get_credential_coordinator(),
+    ))
+}
+
+/// Internal async implementation of getCallerIdentity()
+pub async fn get_caller_identity_internal(args: GetCallerIdentityArgs) -> Result<CallerIdentity> {
+    let region = args
+        .region
+        .as_deref()
+        .filter(|r| !r.is_empty())
+        .unwrap_or(DEFAULT_REGION);
+
+    create_client()?
+        .get_caller_identity(&args.account_id, region)
+        .await
+}
+
+/// Internal async implementation of simulatePrincipalPolicy()
+pub async fn simulate_principal_policy_internal(
+    args: SimulatePrincipalPolicyArgs,
+) -> Result<SimulationResult> {
+    let policy_source_arn = args.policy_source_arn()?;
+    let options = args.to_options()?;
+
+    info!(
+        "Simulating principal policy: account={}, principal={}, actions={}, resources={}",
+        args.account_id,
+        policy_source_arn,
+        args.action_names.len(),
+        options.resource_arns.len()
+    );
+
+    create_client()?
+        .simulate_principal_policy(
+            &args.account_id,
+            args.region(),
+            &policy_source_arn,
+            &args.action_names,
+            &options,
+        )
+        .await
+}
+
+/// Get LLM documentation for IAM simulation functions
+pub fn get_documentation() -> String {
+    r#"### getCallerIdentity(params)
+
+Get the identity of the credentials used for an account.
+
+**Parameters**: `accountId` (string, required), `region` (string, optional)
+
+**Returns**: `{account, arn, userId, principalType, roleArn}` - `roleArn` is the IAM role
+behind an assumed-role session (null for users), usable as `policySourceArn`
+
+### simulatePrincipalPolicy(params)
+
+Answer "can principal X call action Y on resource Z" with the IAM policy simulator.
+Evaluates the principal's identity policies, SCPs, and permissions boundary (plus an
+optional resource policy). Use it instead of reading policy documents.
+
+**Parameters** (object):
+- `accountId` (string, required): Account that owns the principal
+- One of: `policySourceArn` (user, group, or role ARN), `roleName`, or `userName`
+- `actionNames` (array, required): e.g. `["s3:GetObject", "kms:Decrypt"]` (max 100)
+- `resourceArns` (array, optional): Resources to test (default "*", max 100)
+- `contextEntries` (array, optional): `[{key: "aws:SourceIp", values: ["10.0.0.1"], type: "ip"}]`
+- `resourcePolicy` (string or object, optional): e.g. a bucket or key policy to include
+- `resourceOwner` (string, optional): Account owning the resources (cross-account checks)
+- `callerArn` (string, optional): Needed when the resource policy names a principal
+- `region` (string, optional): Only selects the partition (default us-east-1)
+
+**Returns** (object):
+- `policySourceArn` (string), `allowedCount`, `deniedCount` (numbers)
+- `evaluations` (array): One per action and resource:
+  `{action, resource, decision: "allowed"|"explicitDeny"|"implicitDeny", allowed,
+  matchedStatements: [{sourcePolicyId, sourcePolicyType}], missingContextValues,
+  allowedByOrganizations, allowedByPermissionsBoundary}`
+
+**Example - Why can't the Lambda role read the bucket?**
+```javascript
+const sim = simulatePrincipalPolicy({
+  accountId: "123456789012",
+  roleName: "orders-lambda-role",
+  actionNames: ["s3:GetObject", "kms:Decrypt"],
+  resourceArns: ["arn:aws:s3:::orders-data/*", "arn:aws:kms:us-east-1:123456789012:key/abcd-1234"]
+});
+sim.evaluations.filter(e => !e.allowed).forEach(e => {
+  const reason = e.allowedByOrganizations === false ? "denied by SCP"
+    : e.allowedByPermissionsBoundary === false ? "denied by permissions boundary"
+    : e.decision === "explicitDeny" ? "explicit deny in " + e.matchedStatements.map(s => s.sourcePolicyId).join(", ")
+    : "no statement allows it";
+  console.log(e.action + " on " + e.resource + ": " + reason);
+});
+```
+
+**Important Notes:**
+- `implicitDeny` means no policy allows the action; `explicitDeny` means a Deny statement matched
+- When `missingContextValues` is not empty, conditions could not be evaluated - supply
+  `contextEntries` or treat the decision as uncertain
+- Resource policies are only evaluated when passed in `resourcePolicy`
+- Roles found from session ARNs assume no path; pass `policySourceArn` for roles with a path
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn simulate_args(extra: Value) -> SimulatePrincipalPolicyArgs {
+        let mut args = json!({
+            "accountId": "123456789012",
+            "actionNames": ["s3:GetObject"]
+        });
+        args.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(args).unwrap()
+    }
+
+    #[test]
+    fn test_policy_source_arn_resolution() {
+        let by_role = simulate_args(json!({ "roleName": "app-role", "region": "cn-north-1" }));
+        assert_eq!(
+            by_role.policy_source_arn().unwrap(),
+            "arn:aws-cn:iam::123456789012:role/app-role"
+        );
+
+        let by_user = simulate_args(json!({ "userName": "ci" }));
+        assert_eq!(
+            by_user.policy_source_arn().unwrap(),
+            "arn:aws:iam::123456789012:user/ci"
+        );
+
+        assert!(simulate_args(json!({})).policy_source_arn().is_err());
+        assert!(simulate_args(json!({ "roleName": "a", "userName": "b" }))
+            .policy_source_arn()
+            .is_err());
+    }
+
+    #[test]
+    fn test_to_options() {
+        let args = simulate_args(json!({
+            "roleName": "app-role",
+            "resourcePolicy": { "Version": "2012-10-17", "Statement": [] }
+        }));
+        let options = args.to_options().unwrap();
+        assert!(options
+            .resource_policy
+            .unwrap()
+            .contains("\"Version\":\"2012-10-17\""));
+
+        let bad_action = simulate_args(json!({ "actionNames": ["GetObject"] }));
+        assert!(bad_action.to_options().is_err());
+
+        let too_many: Vec<String> = (0..=MAX_SIMULATION_ACTIONS)
+            .map(|i| format!("s3:Action{}", i))
+            .collect();
+        let args = simulate_args(json!({ "actionNames": too_many }));
+        assert!(args.to_options().is_err());
+    }
+}
@@ -7,10 +7,20 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 pub mod accounts;
+pub mod athena;
 pub mod cloudtrail_events;
 pub mod cloudwatch_logs;
+pub mod cloudwatch_metrics;
+pub mod config_history;
+pub mod cost_explorer;
+pub mod dynamodb_items;
+pub mod fetch;
+pub mod iam_simulation;
 pub mod regions;
+pub mod resource_search;
 pub mod resources;
+pub mod s3_objects;
+pub mod step_functions;
 pub mod vfs;
 
 use anyhow::Result;
@@ -42,12 +52,42 @@ pub fn register_bindings(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_
     // Register resource query functions
     resources::register(scope)?;
 
+    // Register resource search functions
+    resource_search::register(scope)?;
+
     // Register CloudWatch Logs functions
     cloudwatch_logs::register(scope)?;
 
+    // Register CloudWatch Metrics functions
+    cloudwatch_metrics::register(scope)?;
+
     // Register CloudTrail Events functions
     cloudtrail_events::register(scope)?;
 
+    // Register AWS Config history functions
+    config_history::register(scope)?;
+
+    // Register Athena functions
+    athena::register(scope)?;
+
+    // Register Cost Explorer functions
+    cost_explorer::register(scope)?;
+
+    // Register S3 object functions
+    s3_objects::register(scope)?;
+
+    // Register DynamoDB item functions
+    dynamodb_items::register(scope)?;
+
+    // Register IAM simulation functions
+    iam_simulation::register(scope)?;
+
+    // Register Step Functions functions
+    step_functions::register(scope)?;
+
+    // Register fetch()
+    fetch::register(scope)?;
+
     // Register VFS functions
     vfs::register(scope)?;
 
@@ -83,12 +123,42 @@ pub fn get_api_documentation() -> String {
     docs.push_str("\n## Region Management\n\n");
     docs.push_str(&regions::get_documentation());
 
+    docs.push_str("\n## Resource Search\n\n");
+    docs.push_str(&resource_search::get_documentation());
+
     docs.push_str("\n## CloudWatch Logs\n\n");
     docs.push_str(&cloudwatch_logs::get_documentation());
 
+    docs.push_str("\n## CloudWatch Metrics\n\n");
+    docs.push_str(&cloudwatch_metrics::get_documentation());
+
     docs.push_str("\n## CloudTrail Events\n\n");
     docs.push_str(&cloudtrail_events::get_documentation());
 
+    docs.push_str("\n## AWS Config History\n\n");
+    docs.push_str(&config_history::get_documentation());
+
+    docs.push_str("\n## Athena\n\n");
+    docs.push_str(&athena::get_documentation());
+
+    docs.push_str("\n## Cost Explorer\n\n");
+    docs.push_str(&cost_explorer::get_documentation());
+
+    docs.push_str("\n## S3 Objects\n\n");
+    docs.push_str(&s3_objects::get_documentation());
+
+    docs.push_str("\n## DynamoDB Items\n\n");
+    docs.push_str(&dynamodb_items::get_documentation());
+
+    docs.push_str("\n## IAM Identity and Policy Simulation\n\n");
+    docs.push_str(&iam_simulation::get_documentation());
+
+    docs.push_str("\n## Step Functions\n\n");
+    docs.push_str(&step_functions::get_documentation());
+
+    docs.push_str("\n## HTTP Fetch\n\n");
+    docs.push_str(&fetch::get_documentation());
+
     docs.push_str("\n## Virtual File System\n\n");
     docs.push_str(&vfs::get_documentation());
 
@@ -17,9 +17,10 @@ pub struct RegionInfo {
     pub name: String,
 }
 
-/// Get all AWS regions
+/// Get all AWS regions of the signed-in partition
 pub fn get_regions() -> Vec<RegionInfo> {
-    crate::app::aws_regions::AWS_REGIONS
+    crate::app::aws_regions::active_partition()
+        .regions()
         .iter()
         .map(|code| RegionInfo {
             code: code.to_string(),
@@ -60,6 +61,8 @@ fn region_code_to_name(code: &str) -> String {
         "sa-east-1" => "South America (São Paulo)",
         "us-gov-east-1" => "AWS GovCloud (US-East)",
         "us-gov-west-1" => "AWS GovCloud (US-West)",
+        "cn-north-1" => "China (Beijing)",
+        "cn-northwest-1" => "China (Ningxia)",
         _ => code, // Fallback to code if unknown
     }
     .to_string()
@@ -0,0 +1,323 @@
+//! Resource search function bindings
+//!
+//! Provides JavaScript access to semantic search over the Explorer cache, so
+//! agents can turn fuzzy references like "the payments database" into
+//! resource IDs before calling the query functions.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+use crate::app::agent_framework::retrieval::{index_for_cache, SearchFilter, SearchHit};
+use crate::app::resource_explorer::state::ResourceTag;
+
+/// Results returned when no limit is given
+const DEFAULT_LIMIT: usize = 10;
+
+/// Largest limit accepted
+const MAX_LIMIT: usize = 100;
+
+/// JavaScript arguments for searchResources()
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResourcesArgs {
+    /// Natural-language description of the resources (required)
+    pub query: String,
+
+    /// Maximum results (optional, default 10, max 100)
+    pub limit: Option<usize>,
+
+    /// Resource types to search (optional, default all cached types)
+    #[serde(default)]
+    pub resource_types: Vec<String>,
+
+    /// Account IDs to search (optional, default all cached accounts)
+    #[serde(default)]
+    pub accounts: Vec<String>,
+
+    /// Region codes to search (optional, default all cached regions)
+    #[serde(default)]
+    pub regions: Vec<String>,
+}
+
+impl SearchResourcesArgs {
+    /// Effective result limit
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+    }
+
+    pub fn filter(&self) -> SearchFilter {
+        SearchFilter {
+            resource_types: self.resource_types.clone(),
+            accounts: self.accounts.clone(),
+            regions: self.regions.clone(),
+        }
+    }
+}
+
+/// A resource matching the query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceMatch {
+    pub resource_id: String,
+    pub display_name: String,
+    pub account_id: String,
+    pub region: String,
+    pub resource_type: String,
+    pub status: Option<String>,
+    pub tags: Vec<ResourceTag>,
+    /// Relevance from 0 to 1
+    pub score: f32,
+    /// Query words found in the resource, directly or as a synonym
+    pub matched_terms: Vec<String>,
+}
+
+impl From<SearchHit<'_>> for ResourceMatch {
+    fn from(hit: SearchHit<'_>) -> Self {
+        Self {
+            resource_id: hit.resource.resource_id.clone(),
+            display_name: hit.resource.display_name.clone(),
+            account_id: hit.resource.account_id.clone(),
+            region: hit.resource.region.clone(),
+            resource_type: hit.resource.resource_type.clone(),
+            status: hit.resource.status.clone(),
+            tags: hit.resource.tags.clone(),
+            score: (hit.score * 1000.0).round() / 1000.0,
+            matched_terms: hit.matched_terms,
+        }
+    }
+}
+
+/// Result from searchResources()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResourcesResult {
+    pub query: String,
+    pub count: usize,
+    /// Resources in the cache that were searched
+    pub indexed_resources: usize,
+    pub results: Vec<ResourceMatch>,
+    /// Hint when nothing matched
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Register resource search functions into V8 context
+pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
+    let global = scope.get_current_context().global(scope);
+
+    // Register searchResources() function
+    let search_fn = v8::Function::new(scope, search_resources_callback)
+        .expect("Failed to create searchResources function");
+
+    let fn_name =
+        v8::String::new(scope, "searchResources").expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), search_fn.into());
+
+    Ok(())
+}
+
+// Helper to throw a search error
+fn throw_search_error(scope: &mut v8::PinScope<'_, '_>, msg: &str) {
+    let v8_msg = v8::String::new(scope, msg).unwrap();
+    let error = v8::Exception::error(scope, v8_msg);
+    scope.throw_exception(error);
+}
+
+// Helper to parse the argument: a query string or an options object
+fn parse_args(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: &v8::FunctionCallbackArguments<'_>,
+) -> Option<SearchResourcesArgs> {
+    let arg = args.get(0);
+    if arg.is_string() {
+        return Some(SearchResourcesArgs {
+            query: arg
+                .to_string(scope)
+                .map(|s| s.to_rust_string_lossy(scope))
+                .unwrap_or_default(),
+            ..Default::default()
+        });
+    }
+
+    let Some(args_obj) = arg.to_object(scope) else {
+        let msg = v8::String::new(
+            scope,
+            "searchResources() requires a query string or an object argument with { query }",
+        )
+        .unwrap();
+        let error = v8::Exception::type_error(scope, msg);
+        scope.throw_exception(error);
+        return None;
+    };
+
+    let Some(json_str) = v8::json::stringify(scope, args_obj.into()) else {
+        throw_search_error(scope, "Failed to stringify arguments");
+        return None;
+    };
+    let json_str = json_str.to_rust_string_lossy(scope);
+
+    match serde_json::from_str(&json_str) {
+        Ok(args) => Some(args),
+        Err(e) => {
+            throw_search_error(scope, &format!("Failed to parse arguments: {}", e));
+            None
+        }
+    }
+}
+
+/// Callback for searchResources() JavaScript function
+fn search_resources_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let Some(search_args) = parse_args(scope, &args) else {
+        return;
+    };
+
+    let result = match search_resources_internal(search_args) {
+        Ok(result) => result,
+        Err(e) => {
+            throw_search_error(scope, &format!("searchResources failed: {}", e));
+            return;
+        }
+    };
+
+    let result_json = match serde_json::to_string(&result) {
+        Ok(json) => json,
+        Err(e) => {
+            throw_search_error(scope, &format!("Failed to serialize result: {}", e));
+            return;
+        }
+    };
+
+    match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(value) => rv.set(value),
+        None => throw_search_error(scope, "Failed to parse result JSON"),
+    }
+}
+
+/// Internal implementation of searchResources()
+pub fn search_resources_internal(args: SearchResourcesArgs) -> Result<SearchResourcesResult> {
+    let query = args.query.trim();
+    if query.is_empty() {
+        return Err(anyhow!("'query' must not be empty"));
+    }
+
+    let cache = crate::app::resource_explorer::cache::shared_cache();
+    let index = index_for_cache(&cache);
+    let results: Vec<ResourceMatch> = index
+        .search(query, &args.filter(), args.limit())
+        .into_iter()
+        .map(ResourceMatch::from)
+        .collect();
+
+    info!(
+        "searchResources('{}'): {} of {} cached resources",
+        query,
+        results.len(),
+        index.len()
+    );
+
+    let message = if index.is_empty() {
+        Some(
+            "No resources are cached yet - call loadCache() for the likely resource types first"
+                .to_string(),
+        )
+    } else if results.is_empty() {
+        Some("No cached resource matches - try other words, or loadCache() more types, accounts, or regions".to_string())
+    } else {
+        None
+    };
+
+    Ok(SearchResourcesResult {
+        query: query.to_string(),
+        count: results.len(),
+        indexed_resources: index.len(),
+        results,
+        message,
+    })
+}
+
+/// Get LLM documentation for resource search functions
+pub fn get_documentation() -> String {
+    r#"### searchResources(query)
+
+Find cached resources by meaning instead of exact type and ID. Use it when the user names
+a resource loosely ("the payments database", "prod redis", "checkout queue") to get its ID,
+type, account, and region, then query it with the other functions.
+
+Matches names, IDs, types, tags, and property values, and understands common AWS
+synonyms (database ~ RDS/DynamoDB/Aurora, bucket ~ S3, function ~ Lambda, prod ~ production).
+Only resources already loaded with `loadCache()` or the Explorer are searched.
+
+**Parameters**: a query string, or an object:
+- `query` (string, required): Natural-language description
+- `limit` (number, optional): Maximum results (default 10, max 100)
+- `resourceTypes` (array, optional): Only these types, e.g. `["AWS::RDS::DBInstance"]`
+- `accounts` (array, optional): Only these account IDs
+- `regions` (array, optional): Only these regions
+
+**Returns** (object):
+- `query` (string), `count` (number), `indexedResources` (number of cached resources searched)
+- `results` (array, best first): `{resourceId, displayName, accountId, region, resourceType,
+  status, tags: [{key, value}], score, matchedTerms}` - `score` is 0 to 1
+- `message` (string, optional): Hint when nothing is cached or nothing matched
+
+**Example - Which database is "the payments database"?**
+```javascript
+const found = searchResources("payments database");
+if (found.count === 0) {
+  console.log(found.message);
+} else {
+  const best = found.results[0];
+  console.log(best.resourceType + " " + best.displayName + " in " + best.region +
+    " (score " + best.score + ", matched " + best.matchedTerms.join(", ") + ")");
+}
+```
+
+**Important Notes:**
+- Scores are relative; compare the top results and ask the user when several are close
+- A result without every query word in `matchedTerms` may be a weaker match
+- When `indexedResources` is 0, call `loadCache()` for the likely types first
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_args_parsing_and_limits() {
+        let args: SearchResourcesArgs = serde_json::from_value(json!({
+            "query": "payments database",
+            "limit": 500,
+            "resourceTypes": ["AWS::RDS::DBInstance"]
+        }))
+        .unwrap();
+        assert_eq!(args.limit(), MAX_LIMIT);
+        assert_eq!(args.filter().resource_types, vec!["AWS::RDS::DBInstance"]);
+        assert!(args.filter().accounts.is_empty());
+
+        let args: SearchResourcesArgs =
+            serde_json::from_value(json!({ "query": "redis", "limit": 0 })).unwrap();
+        assert_eq!(args.limit(), 1);
+
+        let args: SearchResourcesArgs = serde_json::from_value(json!({ "query": "x" })).unwrap();
+        assert_eq!(args.limit(), DEFAULT_LIMIT);
+    }
+
+    #[test]
+    fn test_empty_query_rejected() {
+        let args = SearchResourcesArgs {
+            query: "  ".to_string(),
+            ..Default::default()
+        };
+        assert!(search_resources_internal(args).is_err());
+    }
+}
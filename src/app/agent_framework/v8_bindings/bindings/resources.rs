@@ -158,6 +158,7 @@ pub enum GroupingMode {
     ByAccount,
     ByRegion,
     ByResourceType,
+    ByOrganizationalUnit,
     ByTag { key: String },
     ByTagHierarchy { keys: Vec<String> },
     ByProperty { path: String },
@@ -451,9 +452,7 @@ fn load_cache_callback(
 
 /// Execute load cache query (synchronous wrapper for async code)
 pub fn execute_load_cache(args: LoadCacheArgs) -> Result<LoadCacheResult> {
-    tokio::task::block_in_place(|| {
-        tokio::runtime::Handle::current().block_on(async { load_cache_internal(args).await })
-    })
+    crate::app::agent_framework::block_on_cancellable(load_cache_internal(args))
 }
 
 /// Execute complete resource query with automatic enrichment (BLACK BOX API)
@@ -561,7 +560,7 @@ pub fn execute_load_cache(args: LoadCacheArgs) -> Result<LoadCacheResult> {
 /// // Now resources contains ALL security groups with FULL rules (Phase 2 enriched)
 /// // Agent can analyze: resources[0].detailed_properties["IpPermissions"]
 /// ```
-async fn execute_complete_query(
+pub async fn execute_complete_query(
     account_ids: Vec<String>,
     region_codes: Vec<String>,
     resource_types: Vec<String>,
@@ -873,10 +872,7 @@ fn get_resource_schema_callback(
 
 /// Execute get resource schema (synchronous wrapper for async code)
 pub fn execute_get_resource_schema(resource_type: &str) -> Result<GetResourceSchemaResult> {
-    tokio::task::block_in_place(|| {
-        tokio::runtime::Handle::current()
-            .block_on(async { get_resource_schema_internal(resource_type).await })
-    })
+    crate::app::agent_framework::block_on_cancellable(get_resource_schema_internal(resource_type))
 }
 
 /// Internal async implementation of get resource schema
@@ -1267,18 +1263,15 @@ fn query_cached_resources_callback(
 
 /// Execute queryCachedResources (synchronous wrapper for async code)
 pub fn execute_query_cached_resources(args: QueryCachedResourcesArgs) -> Result<QueryCachedResourcesResult> {
-    tokio::task::block_in_place(|| {
-        tokio::runtime::Handle::current()
-            .block_on(async { query_cached_resources_internal(args).await })
-    })
+    crate::app::agent_framework::block_on_cancellable(query_cached_resources_internal(args))
 }
 
 /// Internal async implementation of queryCachedResources
 ///
 /// Thin wrapper around Explorer's query infrastructure (same as loadCache).
 /// The query engine handles caching transparently - we just return full resources
-/// instead of counts.
-async fn query_cached_resources_internal(
+/// instead of counts. Public so the headless CLI can print the same JSON.
+pub async fn query_cached_resources_internal(
     args: QueryCachedResourcesArgs,
 ) -> Result<QueryCachedResourcesResult> {
     use std::collections::HashSet;
@@ -1637,10 +1630,7 @@ fn execute_bookmark_query(
     bookmark_id: &str,
     options: QueryBookmarksArgs,
 ) -> Result<UnifiedQueryResult<serde_json::Value>> {
-    tokio::task::block_in_place(|| {
-        tokio::runtime::Handle::current()
-            .block_on(async { query_bookmark_internal(bookmark_id, options).await })
-    })
+    crate::app::agent_framework::block_on_cancellable(query_bookmark_internal(bookmark_id, options))
 }
 
 /// Internal async implementation of bookmark query
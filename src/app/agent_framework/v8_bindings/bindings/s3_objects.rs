@@ -0,0 +1,513 @@
+//! S3 object function bindings
+//!
+//! Provides JavaScript access to the objects inside S3 buckets through the
+//! `s3Objects` namespace: list by prefix, head, read small bodies, and
+//! presigned URLs. Bodies are read with a Range request, so downloads never
+//! exceed the size limit.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::time::Duration;
+use tracing::{debug, info, warn};
+
+use crate::app::agent_framework::block_on_cancellable;
+use crate::app::agent_framework::vfs::{get_current_vfs_id, with_vfs_mut};
+use crate::app::data_plane::s3_objects::{
+    clamp_body_bytes, clamp_presign_expiry, ListObjectsOptions, ListObjectsResult, PresignedUrl,
+    S3ObjectBody, S3ObjectMetadata, S3ObjectsClient,
+};
+
+/// Number of objects returned inline when the full listing is saved to VFS
+const SAMPLE_OBJECTS: usize = 100;
+
+/// JavaScript arguments for s3Objects.list()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListS3ObjectsArgs {
+    /// Account ID (required)
+    pub account_id: String,
+
+    /// Bucket region (required)
+    pub region: String,
+
+    /// Bucket name (required)
+    pub bucket: String,
+
+    /// Key prefix (optional)
+    pub prefix: Option<String>,
+
+    /// Delimiter for "folders", usually "/" (optional)
+    pub delimiter: Option<String>,
+
+    /// Maximum keys to return (optional, default 1000, max 10000)
+    pub max_keys: Option<usize>,
+
+    /// Token from a previous truncated listing (optional)
+    pub continuation_token: Option<String>,
+}
+
+/// JavaScript arguments for s3Objects.head(), get(), and presignUrl()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3ObjectArgs {
+    /// Account ID (required)
+    pub account_id: String,
+
+    /// Bucket region (required)
+    pub region: String,
+
+    /// Bucket name (required)
+    pub bucket: String,
+
+    /// Object key (required)
+    pub key: String,
+
+    /// Bytes to read for get() (optional, default 256 KB, max 5 MB)
+    pub max_bytes: Option<usize>,
+
+    /// URL lifetime for presignUrl() (optional, default 900, max 604800)
+    pub expires_in_seconds: Option<u64>,
+}
+
+/// Listing exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3ObjectListInfo {
+    #[serde(flatten)]
+    pub listing: ListObjectsResult,
+
+    /// Number of objects listed (objects holds a sample when saved to VFS)
+    pub object_count: usize,
+
+    /// Path to the full listing in VFS (when VFS is used)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_path: Option<String>,
+
+    /// Message explaining how to access full data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Register the s3Objects namespace into V8 context
+pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
+    let global = scope.get_current_context().global(scope);
+
+    // Create s3Objects namespace object
+    let s3_obj = v8::Object::new(scope);
+
+    // Register s3Objects.list(params)
+    let list_fn =
+        v8::Function::new(scope, list_callback).expect("Failed to create s3Objects.list function");
+    let fn_name = v8::String::new(scope, "list").expect("Failed to create function name");
+    s3_obj.set(scope, fn_name.into(), list_fn.into());
+
+    // Register s3Objects.head(params)
+    let head_fn =
+        v8::Function::new(scope, head_callback).expect("Failed to create s3Objects.head function");
+    let fn_name = v8::String::new(scope, "head").expect("Failed to create function name");
+    s3_obj.set(scope, fn_name.into(), head_fn.into());
+
+    // Register s3Objects.get(params)
+    let get_fn =
+        v8::Function::new(scope, get_callback).expect("Failed to create s3Objects.get function");
+    let fn_name = v8::String::new(scope, "get").expect("Failed to create function name");
+    s3_obj.set(scope, fn_name.into(), get_fn.into());
+
+    // Register s3Objects.presignUrl(params)
+    let presign_fn = v8::Function::new(scope, presign_url_callback)
+        .expect("Failed to create s3Objects.presignUrl function");
+    let fn_name = v8::String::new(scope, "presignUrl").expect("Failed to create function name");
+    s3_obj.set(scope, fn_name.into(), presign_fn.into());
+
+    // Add s3Objects object to global scope
+    let s3_name = v8::String::new(scope, "s3Objects").expect("Failed to create s3Objects name");
+    global.set(scope, s3_name.into(), s3_obj.into());
+
+    Ok(())
+}
+
+// Helper to throw an S3 error
+fn throw_s3_error(scope: &mut v8::PinScope<'_, '_>, msg: &str) {
+    let v8_msg = v8::String::new(scope, msg).unwrap();
+    let error = v8::Exception::error(scope, v8_msg);
+    scope.throw_exception(error);
+}
+
+// Helper to parse the object argument of an s3Objects function
+fn parse_args<T: DeserializeOwned>(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: &v8::FunctionCallbackArguments<'_>,
+    function: &str,
+) -> Option<T> {
+    let Some(args_obj) = args.get(0).to_object(scope) else {
+        let msg = v8::String::new(
+            scope,
+            &format!("{}() requires an object argument", function),
+        )
+        .unwrap();
+        let error = v8::Exception::type_error(scope, msg);
+        scope.throw_exception(error);
+        return None;
+    };
+
+    let Some(json_str) = v8::json::stringify(scope, args_obj.into()) else {
+        throw_s3_error(scope, "Failed to stringify arguments");
+        return None;
+    };
+    let json_str = json_str.to_rust_string_lossy(scope);
+
+    match serde_json::from_str(&json_str) {
+        Ok(args) => Some(args),
+        Err(e) => {
+            throw_s3_error(scope, &format!("Failed to parse arguments: {}", e));
+            None
+        }
+    }
+}
+
+// Helper to return a serializable result to JavaScript
+fn set_result<T: Serialize>(
+    scope: &mut v8::PinScope<'_, '_>,
+    rv: &mut v8::ReturnValue<'_>,
+    result: &T,
+) {
+    let result_json = match serde_json::to_string(result) {
+        Ok(json) => json,
+        Err(e) => {
+            throw_s3_error(scope, &format!("Failed to serialize result: {}", e));
+            return;
+        }
+    };
+
+    match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(value) => rv.set(value),
+        None => throw_s3_error(scope, "Failed to parse result JSON"),
+    }
+}
+
+/// Callback for s3Objects.list() JavaScript function
+///
+/// When VFS is available and the listing is larger than the inline sample,
+/// saves all objects to VFS and returns the first ones.
+fn list_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let Some(list_args) = parse_args::<ListS3ObjectsArgs>(scope, &args, "s3Objects.list") else {
+        return;
+    };
+
+    let listing = match block_on_cancellable(list_objects_internal(list_args)) {
+        Ok(listing) => listing,
+        Err(e) => {
+            throw_s3_error(scope, &format!("S3 list failed: {}", e));
+            return;
+        }
+    };
+
+    let mut result = S3ObjectListInfo {
+        object_count: listing.objects.len(),
+        listing,
+        details_path: None,
+        message: None,
+    };
+
+    // If VFS is available and the listing is large, save it to VFS and return a sample
+    if let Some(vfs_id) = get_current_vfs_id() {
+        if result.listing.objects.len() > SAMPLE_OBJECTS {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0);
+            let vfs_path = format!("/results/s3_objects_{}.json", timestamp);
+
+            match serde_json::to_string_pretty(&result.listing.objects) {
+                Ok(objects_json) => {
+                    let write_result = with_vfs_mut(&vfs_id, |vfs| {
+                        vfs.write_file(&vfs_path, objects_json.as_bytes())
+                    });
+                    match write_result {
+                        Some(Ok(())) => {
+                            debug!(
+                                "Saved {} S3 objects to VFS path: {}",
+                                result.object_count, vfs_path
+                            );
+                            result.listing.objects.truncate(SAMPLE_OBJECTS);
+                            result.details_path = Some(vfs_path.clone());
+                            result.message = Some(format!(
+                                "Listed {} objects; first {} shown. All objects saved to VFS. Use JSON.parse(vfs.readFile('{}')) to access.",
+                                result.object_count, SAMPLE_OBJECTS, vfs_path
+                            ));
+                        }
+                        Some(Err(e)) => warn!("Failed to write S3 listing to VFS: {}", e),
+                        None => warn!("VFS not found for id: {}", vfs_id),
+                    }
+                }
+                Err(e) => warn!("Failed to serialize S3 listing for VFS: {}", e),
+            }
+        }
+    }
+
+    set_result(scope, &mut rv, &result);
+}
+
+/// Callback for s3Objects.head() JavaScript function
+fn head_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let Some(object_args) = parse_args::<S3ObjectArgs>(scope, &args, "s3Objects.head") else {
+        return;
+    };
+
+    match block_on_cancellable(head_object_internal(object_args)) {
+        Ok(metadata) => set_result(scope, &mut rv, &metadata),
+        Err(e) => throw_s3_error(scope, &format!("S3 head failed: {}", e)),
+    }
+}
+
+/// Callback for s3Objects.get() JavaScript function
+fn get_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let Some(object_args) = parse_args::<S3ObjectArgs>(scope, &args, "s3Objects.get") else {
+        return;
+    };
+
+    match block_on_cancellable(get_object_internal(object_args)) {
+        Ok(body) => set_result(scope, &mut rv, &body),
+        Err(e) => throw_s3_error(scope, &format!("S3 get failed: {}", e)),
+    }
+}
+
+/// Callback for s3Objects.presignUrl() JavaScript function
+fn presign_url_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let Some(object_args) = parse_args::<S3ObjectArgs>(scope, &args, "s3Objects.presignUrl") else {
+        return;
+    };
+
+    match block_on_cancellable(presign_url_internal(object_args)) {
+        Ok(url) => set_result(scope, &mut rv, &url),
+        Err(e) => throw_s3_error(scope, &format!("S3 presign failed: {}", e)),
+    }
+}
+
+/// Create an S3 objects client from the global AWS client
+fn create_client() -> Result<S3ObjectsClient> {
+    let aws_client = crate::app::agent_framework::utils::registry::get_global_aws_client()
+        .ok_or_else(|| anyhow!("AWS client not initialized"))?;
+    Ok(S3ObjectsClient::new(
+        aws_client.get_credential_coordinator(),
+    ))
+}
+
+/// Build list options from JavaScript arguments, applying limits
+fn build_list_options(args: &ListS3ObjectsArgs) -> ListObjectsOptions {
+    let mut options = ListObjectsOptions::new();
+    if let Some(prefix) = args.prefix.as_ref().filter(|p| !p.is_empty()) {
+        options = options.with_prefix(prefix);
+    }
+    if let Some(delimiter) = args.delimiter.as_ref().filter(|d| !d.is_empty()) {
+        options = options.with_delimiter(delimiter);
+    }
+    if let Some(max_keys) = args.max_keys {
+        options = options.with_max_keys(max_keys);
+    }
+    if let Some(token) = &args.continuation_token {
+        options = options.with_continuation_token(token);
+    }
+    options
+}
+
+fn require_bucket(bucket: &str) -> Result<()> {
+    if bucket.trim().is_empty() {
+        return Err(anyhow!("bucket must not be empty"));
+    }
+    Ok(())
+}
+
+fn require_key(args: &S3ObjectArgs) -> Result<()> {
+    require_bucket(&args.bucket)?;
+    if args.key.is_empty() {
+        return Err(anyhow!("key must not be empty"));
+    }
+    Ok(())
+}
+
+/// Internal async implementation of s3Objects.list()
+pub async fn list_objects_internal(args: ListS3ObjectsArgs) -> Result<ListObjectsResult> {
+    require_bucket(&args.bucket)?;
+    info!(
+        "Listing S3 objects: account={}, region={}, bucket={}, prefix={:?}",
+        args.account_id, args.region, args.bucket, args.prefix
+    );
+
+    create_client()?
+        .list_objects(
+            &args.account_id,
+            &args.region,
+            &args.bucket,
+            &build_list_options(&args),
+        )
+        .await
+}
+
+/// Internal async implementation of s3Objects.head()
+pub async fn head_object_internal(args: S3ObjectArgs) -> Result<S3ObjectMetadata> {
+    require_key(&args)?;
+    create_client()?
+        .head_object(&args.account_id, &args.region, &args.bucket, &args.key)
+        .await
+}
+
+/// Internal async implementation of s3Objects.get()
+pub async fn get_object_internal(args: S3ObjectArgs) -> Result<S3ObjectBody> {
+    require_key(&args)?;
+    let max_bytes = clamp_body_bytes(args.max_bytes);
+    info!(
+        "Reading S3 object: account={}, region={}, s3://{}/{} (max {} bytes)",
+        args.account_id, args.region, args.bucket, args.key, max_bytes
+    );
+
+    create_client()?
+        .get_object(
+            &args.account_id,
+            &args.region,
+            &args.bucket,
+            &args.key,
+            max_bytes,
+        )
+        .await
+}
+
+/// Internal async implementation of s3Objects.presignUrl()
+pub async fn presign_url_internal(args: S3ObjectArgs) -> Result<PresignedUrl> {
+    require_key(&args)?;
+    let expires_in = Duration::from_secs(clamp_presign_expiry(args.expires_in_seconds));
+    create_client()?
+        .presign_get_object(
+            &args.account_id,
+            &args.region,
+            &args.bucket,
+            &args.key,
+            expires_in,
+        )
+        .await
+}
+
+/// Get LLM documentation for S3 object functions
+pub fn get_documentation() -> String {
+    r#"### s3Objects - S3 Bucket Contents
+
+The `s3Objects` object reads the objects inside S3 buckets. All functions take an
+object with `accountId`, `region` (the bucket's region) and `bucket`.
+
+#### s3Objects.list(params)
+
+**Parameters**: `prefix` (string, optional), `delimiter` (string, optional, use "/" for
+folders), `maxKeys` (number, optional, default 1000, max 10000),
+`continuationToken` (string, optional)
+
+**Returns**: `{bucket, prefix, objects: [{key, size, lastModified, storageClass, etag}],
+commonPrefixes: string[], isTruncated, nextContinuationToken, totalSize, objectCount,
+detailsPath?}` - when more than 100 objects are listed, `objects` holds the first 100
+and `detailsPath` the full list in VFS
+
+#### s3Objects.head(params)
+
+**Parameters**: `key` (string, required)
+
+**Returns**: `{bucket, key, size, contentType, contentEncoding, lastModified, etag,
+storageClass, versionId, serverSideEncryption, kmsKeyId, metadata}`
+
+#### s3Objects.get(params)
+
+Reads the START of an object. Only `maxBytes` are downloaded, however large the object.
+
+**Parameters**: `key` (string, required), `maxBytes` (number, optional, default 262144
+(256 KB), max 5242880 (5 MB))
+
+**Returns**: `{bucket, key, size, contentType, encoding: "utf8"|"base64", body,
+bytesRead, truncated}` - `truncated` is true when the object is larger than `bytesRead`
+
+#### s3Objects.presignUrl(params)
+
+**Parameters**: `key` (string, required), `expiresInSeconds` (number, optional,
+default 900, max 604800)
+
+**Returns**: `{bucket, key, url, expiresAt}`
+
+**Example - Inspect the newest log file under a prefix:**
+```javascript
+const scope = { accountId: "123456789012", region: "us-east-1", bucket: "my-app-logs" };
+const listing = s3Objects.list({ ...scope, prefix: "app/2024/", maxKeys: 500 });
+const objects = listing.detailsPath
+  ? JSON.parse(vfs.readFile(listing.detailsPath))
+  : listing.objects;
+const newest = objects.sort((a, b) => b.lastModified - a.lastModified)[0];
+
+const head = s3Objects.head({ ...scope, key: newest.key });
+console.log(newest.key + ": " + head.size + " bytes, " + head.contentType);
+
+const file = s3Objects.get({ ...scope, key: newest.key, maxBytes: 65536 });
+if (file.encoding === "utf8") {
+  console.log(file.body.split("\n").slice(0, 20).join("\n"));
+}
+```
+
+**Important Notes:**
+- Use `head()` to check the size before `get()`; never try to read whole large objects
+- Use `delimiter: "/"` to browse a bucket like folders (`commonPrefixes`)
+- Compressed objects (e.g. `.gz`) come back as base64 and cannot be decompressed here
+- Presigned URLs give anyone with the link read access until they expire
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::s3_objects::MAX_LIST_KEYS;
+    use serde_json::json;
+
+    #[test]
+    fn test_build_list_options() {
+        let args: ListS3ObjectsArgs = serde_json::from_value(json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "bucket": "logs",
+            "prefix": "app/",
+            "delimiter": "",
+            "maxKeys": 1_000_000
+        }))
+        .unwrap();
+
+        let options = build_list_options(&args);
+        assert_eq!(options.prefix.as_deref(), Some("app/"));
+        assert!(options.delimiter.is_none());
+        assert_eq!(options.max_keys, MAX_LIST_KEYS);
+    }
+
+    #[test]
+    fn test_object_args_validation() {
+        let args: S3ObjectArgs = serde_json::from_value(json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "bucket": "logs",
+            "key": ""
+        }))
+        .unwrap();
+        assert!(require_key(&args).is_err());
+        assert!(args.max_bytes.is_none());
+    }
+}
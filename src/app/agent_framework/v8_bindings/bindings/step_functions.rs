@@ -0,0 +1,525 @@
+//! Step Functions function bindings
+//!
+//! Provides JavaScript access to state machine executions and their per-state history.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use tracing::{debug, info, warn};
+
+use crate::app::agent_framework::vfs::{get_current_vfs_id, with_vfs_mut};
+use crate::app::data_plane::step_functions::{
+    ExecutionHistory, ExecutionListOptions, ExecutionSummary, StateStatus, StateTransition,
+    StepFunctionsClient,
+};
+
+/// Maximum number of executions per getStateMachineExecutions() call
+const MAX_EXECUTIONS: i32 = 100;
+
+/// Default number of executions per call
+const DEFAULT_EXECUTIONS: i32 = 20;
+
+/// Maximum executions whose history is read in one call (one API call each)
+const MAX_HISTORY_EXECUTIONS: usize = 10;
+
+/// JavaScript function call arguments for getStateMachineExecutions()
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetStateMachineExecutionsArgs {
+    /// Account ID (required)
+    pub account_id: String,
+
+    /// AWS region (required)
+    pub region: String,
+
+    /// State machine ARN (required)
+    pub state_machine_arn: String,
+
+    /// Only executions with this status (optional)
+    pub status_filter: Option<String>,
+
+    /// Maximum executions to return (optional, default 20, max 100)
+    pub limit: Option<i32>,
+
+    /// Pagination token from a previous call (optional)
+    pub next_token: Option<String>,
+
+    /// Read the state history of the returned executions (optional, default false)
+    #[serde(default)]
+    pub include_history: bool,
+}
+
+/// One state visit exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateInfo {
+    pub name: String,
+    pub state_type: String,
+    pub status: StateStatus,
+    /// Time the state was entered (Unix milliseconds)
+    pub entered_at: i64,
+    pub duration_ms: Option<i64>,
+    pub input: Option<String>,
+    pub output: Option<String>,
+    pub error: Option<String>,
+    pub cause: Option<String>,
+    pub resource: Option<String>,
+    pub retries: usize,
+}
+
+impl From<&StateTransition> for StateInfo {
+    fn from(transition: &StateTransition) -> Self {
+        Self {
+            name: transition.name.clone(),
+            state_type: transition.state_type.clone(),
+            status: transition.status,
+            entered_at: transition.entered_at,
+            duration_ms: transition.duration_ms(),
+            input: transition.input.clone(),
+            output: transition.output.clone(),
+            error: transition.error.clone(),
+            cause: transition.cause.clone(),
+            resource: transition.resource.clone(),
+            retries: transition.retries,
+        }
+    }
+}
+
+/// One execution exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionInfo {
+    pub execution_arn: String,
+    pub name: String,
+    pub status: String,
+    /// Start time (Unix milliseconds)
+    pub start_date: i64,
+    /// Stop time (Unix milliseconds), null while running
+    pub stop_date: Option<i64>,
+    pub duration_ms: Option<i64>,
+
+    /// Execution input (history only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input: Option<String>,
+
+    /// Execution output (history only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+
+    /// Error that stopped the execution (history only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cause: Option<String>,
+
+    /// Name of the state that failed (history only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failed_state: Option<String>,
+
+    /// State visits in order (history only; None when saved to VFS)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub states: Option<Vec<StateInfo>>,
+}
+
+impl ExecutionInfo {
+    fn new(summary: &ExecutionSummary, history: Option<&ExecutionHistory>) -> Self {
+        Self {
+            execution_arn: summary.execution_arn.clone(),
+            name: summary.name.clone(),
+            status: summary.status.clone(),
+            start_date: summary.start_date,
+            stop_date: summary.stop_date,
+            duration_ms: summary.duration_ms(),
+            input: history.and_then(|h| h.input.clone()),
+            output: history.and_then(|h| h.output.clone()),
+            error: history.and_then(|h| h.error.clone()),
+            cause: history.and_then(|h| h.cause.clone()),
+            failed_state: history.and_then(failed_state),
+            states: history.map(|h| h.transitions.iter().map(StateInfo::from).collect()),
+        }
+    }
+}
+
+/// Innermost state that failed, e.g. the Task inside a failed Map
+fn failed_state(history: &ExecutionHistory) -> Option<String> {
+    history
+        .transitions
+        .iter()
+        .rev()
+        .find(|transition| transition.status == StateStatus::Failed)
+        .map(|transition| transition.name.clone())
+}
+
+/// Step Functions executions result exposed to JavaScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateMachineExecutionsResult {
+    /// Executions, newest first
+    pub executions: Vec<ExecutionInfo>,
+
+    /// Token for pagination (if more results available)
+    pub next_token: Option<String>,
+
+    /// Number of executions in this result
+    pub total_executions: usize,
+
+    /// Number of executions whose history was read
+    pub history_count: usize,
+
+    /// Path to full executions with states in VFS (when VFS is used)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_path: Option<String>,
+
+    /// Message explaining how to access full data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// Register Step Functions functions into V8 context
+pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
+    let global = scope.get_current_context().global(scope);
+
+    // Register getStateMachineExecutions() function
+    let executions_fn = v8::Function::new(scope, get_state_machine_executions_callback)
+        .expect("Failed to create getStateMachineExecutions function");
+
+    let fn_name = v8::String::new(scope, "getStateMachineExecutions")
+        .expect("Failed to create function name string");
+    global.set(scope, fn_name.into(), executions_fn.into());
+
+    Ok(())
+}
+
+/// Callback for getStateMachineExecutions() JavaScript function
+///
+/// When VFS is available and histories were read, saves the executions with
+/// their states to VFS and returns them without the states.
+fn get_state_machine_executions_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    // Parse JavaScript arguments
+    let args_obj = match args.get(0).to_object(scope) {
+        Some(obj) => obj,
+        None => {
+            let msg = v8::String::new(
+                scope,
+                "getStateMachineExecutions() requires an object argument",
+            )
+            .unwrap();
+            let error = v8::Exception::type_error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Convert V8 object to JSON string for parsing
+    let json_str = match v8::json::stringify(scope, args_obj.into()) {
+        Some(s) => s.to_rust_string_lossy(scope),
+        None => {
+            let msg = v8::String::new(scope, "Failed to stringify arguments").unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Parse JSON into GetStateMachineExecutionsArgs
+    let executions_args: GetStateMachineExecutionsArgs = match serde_json::from_str(&json_str) {
+        Ok(args) => args,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("Failed to parse arguments: {}", e)).unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Execute async query
+    let mut result = match execute_query(executions_args) {
+        Ok(result) => result,
+        Err(e) => {
+            let msg =
+                v8::String::new(scope, &format!("Step Functions query failed: {}", e)).unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // If VFS is available and we read histories, save to VFS and return executions without states
+    if let Some(vfs_id) = get_current_vfs_id() {
+        if result.history_count > 0 {
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_millis())
+                .unwrap_or(0);
+            let vfs_path = format!("/results/sfn_executions_{}.json", timestamp);
+
+            let details_json = match serde_json::to_string_pretty(&result.executions) {
+                Ok(json) => json,
+                Err(e) => {
+                    warn!("Failed to serialize executions for VFS: {}", e);
+                    String::new()
+                }
+            };
+
+            if !details_json.is_empty() {
+                let write_result = with_vfs_mut(&vfs_id, |vfs| {
+                    vfs.write_file(&vfs_path, details_json.as_bytes())
+                });
+
+                match write_result {
+                    Some(Ok(())) => {
+                        debug!(
+                            "Saved {} executions with history to VFS path: {}",
+                            result.history_count, vfs_path
+                        );
+
+                        for execution in &mut result.executions {
+                            execution.states = None;
+                        }
+                        result.details_path = Some(vfs_path.clone());
+                        result.message = Some(format!(
+                            "Found {} executions. Executions with their states saved to VFS. Use vfs.readJson('{}') to access.",
+                            result.total_executions,
+                            vfs_path
+                        ));
+                    }
+                    Some(Err(e)) => {
+                        warn!("Failed to write executions to VFS: {}", e);
+                        // Fall back to inline return
+                    }
+                    None => {
+                        warn!("VFS not found for id: {}", vfs_id);
+                        // Fall back to inline return
+                    }
+                }
+            }
+        }
+    }
+
+    // Serialize result to JSON
+    let result_json = match serde_json::to_string(&result) {
+        Ok(json) => json,
+        Err(e) => {
+            let msg = v8::String::new(scope, &format!("Failed to serialize query result: {}", e))
+                .unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    // Parse JSON string to V8 value
+    let result_value = match v8::json::parse(scope, v8::String::new(scope, &result_json).unwrap()) {
+        Some(val) => val,
+        None => {
+            let msg = v8::String::new(scope, "Failed to parse result JSON").unwrap();
+            let error = v8::Exception::error(scope, msg);
+            scope.throw_exception(error);
+            return;
+        }
+    };
+
+    rv.set(result_value);
+}
+
+/// Execute Step Functions query using tokio runtime
+pub fn execute_query(args: GetStateMachineExecutionsArgs) -> Result<StateMachineExecutionsResult> {
+    crate::app::agent_framework::block_on_cancellable(get_state_machine_executions_internal(args))
+}
+
+/// Internal async implementation of Step Functions query
+pub async fn get_state_machine_executions_internal(
+    args: GetStateMachineExecutionsArgs,
+) -> Result<StateMachineExecutionsResult> {
+    info!(
+        "Querying Step Functions executions: account={}, region={}, stateMachine={}",
+        args.account_id, args.region, args.state_machine_arn
+    );
+
+    // Get global AWS client for credential coordinator
+    let aws_client = crate::app::agent_framework::utils::registry::get_global_aws_client()
+        .ok_or_else(|| anyhow!("AWS client not initialized"))?;
+
+    let credential_coordinator = aws_client.get_credential_coordinator();
+
+    let sfn_client = StepFunctionsClient::new(credential_coordinator);
+
+    // Build list options
+    let mut options = ExecutionListOptions::new().with_max_results(
+        args.limit
+            .unwrap_or(DEFAULT_EXECUTIONS)
+            .clamp(1, MAX_EXECUTIONS),
+    );
+
+    if let Some(status) = args.status_filter {
+        options = options.with_status_filter(status.to_uppercase());
+    }
+
+    if let Some(token) = args.next_token {
+        options = options.with_next_token(token);
+    }
+
+    let list = sfn_client
+        .list_executions(
+            &args.account_id,
+            &args.region,
+            &args.state_machine_arn,
+            &options,
+        )
+        .await
+        .map_err(|e| anyhow!("Failed to list Step Functions executions: {}", e))?;
+
+    let mut executions = Vec::with_capacity(list.executions.len());
+    let mut history_count = 0;
+    for (index, summary) in list.executions.iter().enumerate() {
+        let history = if args.include_history && index < MAX_HISTORY_EXECUTIONS {
+            match sfn_client
+                .get_execution_history(&args.account_id, &args.region, &summary.execution_arn)
+                .await
+            {
+                Ok(history) => Some(history),
+                Err(e) => {
+                    warn!("Failed to read history of {}: {}", summary.execution_arn, e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+        history_count += usize::from(history.is_some());
+        executions.push(ExecutionInfo::new(summary, history.as_ref()));
+    }
+
+    Ok(StateMachineExecutionsResult {
+        total_executions: executions.len(),
+        executions,
+        next_token: list.next_token,
+        history_count,
+        details_path: None,
+        message: None,
+    })
+}
+
+/// Get LLM documentation for Step Functions functions
+pub fn get_documentation() -> String {
+    r#"### getStateMachineExecutions(params)
+
+List the executions of a Step Functions state machine, optionally with what each state
+received, returned, and failed with. Standard workflows only; Express workflows keep no history.
+
+**Parameters** (object):
+- `accountId` (string, required): AWS account ID
+- `region` (string, required): AWS region code (e.g., "us-east-1")
+- `stateMachineArn` (string, required): State machine ARN (the resource ID in queryResources())
+- `statusFilter` (string, optional): "RUNNING", "SUCCEEDED", "FAILED", "TIMED_OUT", "ABORTED"
+- `limit` (number, optional): Max executions (default: 20, max: 100)
+- `nextToken` (string, optional): Pagination token from a previous call
+- `includeHistory` (boolean, optional): Read the state history of the first 10 executions (default: false)
+
+**Returns** (object):
+- `executions` (array, newest first):
+  - `executionArn`, `name`, `status`, `startDate`, `stopDate` (Unix ms), `durationMs`
+  - With history: `input`, `output`, `error`, `cause`, `failedState`
+  - With history: `states` (array, in order, omitted when saved to VFS):
+    `{name, stateType, status: "succeeded"|"failed"|"running", enteredAt, durationMs, input, output, error, cause, resource, retries}`
+- `nextToken` (string|null), `totalExecutions` (number), `historyCount` (number)
+- `detailsPath` (string): VFS path holding the executions with `states` when saved to VFS
+
+**Example - Why did recent runs fail?**
+```javascript
+const result = getStateMachineExecutions({
+  accountId: "123456789012",
+  region: "us-east-1",
+  stateMachineArn: "arn:aws:states:us-east-1:123456789012:stateMachine:orders",
+  statusFilter: "FAILED",
+  limit: 5,
+  includeHistory: true
+});
+
+result.executions.forEach(e =>
+  console.log(e.name + ": " + e.failedState + " - " + e.error + " " + (e.cause || "").slice(0, 200)));
+```
+
+**Important Notes:**
+- Payloads (`input`, `output`) are JSON strings; use JSON.parse() before reading fields
+- `retries` counts failed attempts a Retry policy absorbed
+- For failures in Lambda tasks, follow up with queryCloudWatchLogs() on the function's log group
+"#
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::step_functions::HistoryEvent;
+    use serde_json::json;
+
+    fn event(id: i64, event_type: &str, state_name: Option<&str>) -> HistoryEvent {
+        HistoryEvent {
+            id,
+            timestamp: id * 1000,
+            event_type: event_type.to_string(),
+            state_name: state_name.map(String::from),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_execution_info_with_history() {
+        let summary = ExecutionSummary {
+            execution_arn: "arn:aws:states:us-east-1:123456789012:execution:orders:run-1"
+                .to_string(),
+            name: "run-1".to_string(),
+            status: "FAILED".to_string(),
+            start_date: 1000,
+            stop_date: Some(5000),
+        };
+        let history = ExecutionHistory::from_events(
+            summary.execution_arn.clone(),
+            vec![
+                event(1, "ExecutionStarted", None),
+                event(2, "TaskStateEntered", Some("Charge")),
+                HistoryEvent {
+                    error: Some("Payment.Declined".to_string()),
+                    ..event(3, "TaskFailed", None)
+                },
+                HistoryEvent {
+                    error: Some("Payment.Declined".to_string()),
+                    ..event(4, "ExecutionFailed", None)
+                },
+            ],
+            false,
+        );
+
+        let info = ExecutionInfo::new(&summary, Some(&history));
+        assert_eq!(info.duration_ms, Some(4000));
+        assert_eq!(info.failed_state.as_deref(), Some("Charge"));
+        assert_eq!(info.error.as_deref(), Some("Payment.Declined"));
+        assert_eq!(info.states.as_ref().map(Vec::len), Some(1));
+
+        let value = serde_json::to_value(ExecutionInfo::new(&summary, None)).unwrap();
+        assert!(value.get("states").is_none());
+        assert_eq!(value["status"], "FAILED");
+    }
+
+    #[test]
+    fn test_args_parsing() {
+        let args: GetStateMachineExecutionsArgs = serde_json::from_value(json!({
+            "accountId": "123456789012",
+            "region": "us-east-1",
+            "stateMachineArn": "arn:aws:states:us-east-1:123456789012:stateMachine:orders",
+            "statusFilter": "FAILED"
+        }))
+        .unwrap();
+
+        assert_eq!(args.status_filter.as_deref(), Some("FAILED"));
+        assert!(!args.include_history);
+        assert!(args.limit.is_none());
+    }
+}
@@ -5,7 +5,9 @@
 
 #![warn(clippy::all, rust_2018_idioms)]
 
-use crate::app::agent_framework::vfs::{get_current_vfs_id, with_vfs, with_vfs_mut};
+use crate::app::agent_framework::vfs::{
+    get_current_vfs_id, with_vfs, with_vfs_mut, VfsSearchMatches,
+};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 
@@ -26,6 +28,28 @@ pub struct JsFileStat {
     pub is_file: bool,
 }
 
+/// Default number of matches returned by vfsSearch()
+const DEFAULT_SEARCH_RESULTS: usize = 1000;
+
+/// Options for vfsSearch()
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VfsSearchOptions {
+    glob: Option<String>,
+    pattern: Option<String>,
+    path: Option<String>,
+    max_results: Option<usize>,
+}
+
+/// vfsSearch() result returned to JavaScript
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct VfsSearchResult {
+    matches: VfsSearchMatches,
+    total_count: usize,
+    truncated: bool,
+}
+
 /// Register VFS functions into V8 context
 pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Result<()> {
     let global = scope.get_current_context().global(scope);
@@ -75,6 +99,18 @@ pub fn register(scope: &mut v8::ContextScope<'_, '_, v8::HandleScope<'_>>) -> Re
     let fn_name = v8::String::new(scope, "delete").expect("Failed to create function name");
     vfs_obj.set(scope, fn_name.into(), delete_fn.into());
 
+    // Register vfs.stats() -> object
+    let stats_fn =
+        v8::Function::new(scope, stats_callback).expect("Failed to create vfs.stats function");
+    let fn_name = v8::String::new(scope, "stats").expect("Failed to create function name");
+    vfs_obj.set(scope, fn_name.into(), stats_fn.into());
+
+    // Register vfsSearch(options) -> object as a global function
+    let search_fn =
+        v8::Function::new(scope, search_callback).expect("Failed to create vfsSearch function");
+    let fn_name = v8::String::new(scope, "vfsSearch").expect("Failed to create function name");
+    global.set(scope, fn_name.into(), search_fn.into());
+
     // Add vfs object to global scope
     let vfs_name = v8::String::new(scope, "vfs").expect("Failed to create vfs name");
     global.set(scope, vfs_name.into(), vfs_obj.into());
@@ -142,6 +178,32 @@ Delete a file or empty directory.
 ```javascript
 vfs.delete("/workspace/temp.txt");
 ```
+
+#### vfs.stats(): {totalSize: number, maxSize: number, fileCount: number, directories: Array, largestFiles: Array, evictedFiles: number, evictedBytes: number}
+Get memory usage. Each directory entry has `directory`, `size`, `fileCount`, and `quota`
+(`{maxSize, eviction}` or null). `/results` is capped separately and evicts its least
+recently read files when full, so re-check `vfs.exists()` before reading old results.
+`/pages` has its own cap and rejects writes beyond it.
+```javascript
+const stats = vfs.stats();
+stats.largestFiles.forEach(f => console.log(`${f.path}: ${f.size} bytes`));
+```
+
+#### vfsSearch(options: {glob?: string, pattern?: string, path?: string, maxResults?: number}): {matches: Array, totalCount: number, truncated: boolean}
+Find files without listing every directory. With only `glob`, `matches` holds
+`{path, isDirectory, size}` for matching paths. With `pattern` (a regex), it holds
+`{path, lineNumber, line}` for matching lines, limited to files matching `glob` if given.
+`path` limits the search to one directory. A string argument is treated as a glob.
+Globs: `*` within a directory, `**` across directories, `?`, `[abc]`, `{a,b}`. A glob
+without `/` matches file names anywhere. Long lines are cut to the text around the match.
+```javascript
+// Earlier EC2 results
+const files = vfsSearch({ glob: "/results/**/*ec2*.json" }).matches;
+
+// Which results mention a bucket
+const hits = vfsSearch({ pattern: "my-logs-bucket", path: "/results" });
+hits.matches.forEach(m => console.log(`${m.path}:${m.lineNumber}`));
+```
 "#
     .to_string()
 }
@@ -490,3 +552,129 @@ fn delete_callback(
         None => throw_vfs_error(scope, &format!("VFS not found: {}", vfs_id)),
     }
 }
+
+/// Callback for vfs.stats()
+fn stats_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    _args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let vfs_id = match get_vfs_id_or_throw(scope) {
+        Some(id) => id,
+        None => return,
+    };
+
+    let Some(stats) = with_vfs(&vfs_id, |vfs| vfs.vfs_stats()) else {
+        throw_vfs_error(scope, &format!("VFS not found: {}", vfs_id));
+        return;
+    };
+
+    let json_str = match serde_json::to_string(&stats) {
+        Ok(s) => s,
+        Err(e) => {
+            throw_vfs_error(scope, &format!("Failed to serialize stats: {}", e));
+            return;
+        }
+    };
+
+    if let Some(v8_str) = v8::String::new(scope, &json_str) {
+        match v8::json::parse(scope, v8_str) {
+            Some(parsed) => rv.set(parsed),
+            None => throw_vfs_error(scope, "Failed to parse JSON"),
+        }
+    } else {
+        throw_vfs_error(scope, "Failed to create V8 string");
+    }
+}
+
+/// Callback for vfsSearch(options)
+fn search_callback(
+    scope: &mut v8::PinScope<'_, '_>,
+    args: v8::FunctionCallbackArguments<'_>,
+    mut rv: v8::ReturnValue<'_>,
+) {
+    let vfs_id = match get_vfs_id_or_throw(scope) {
+        Some(id) => id,
+        None => return,
+    };
+
+    let arg = args.get(0);
+    let options = if arg.is_string() {
+        VfsSearchOptions {
+            glob: arg.to_string(scope).map(|s| s.to_rust_string_lossy(scope)),
+            ..Default::default()
+        }
+    } else if arg.is_object() {
+        let json_str = match v8::json::stringify(scope, arg) {
+            Some(s) => s.to_rust_string_lossy(scope),
+            None => {
+                throw_vfs_error(scope, "Failed to stringify search options");
+                return;
+            }
+        };
+        match serde_json::from_str(&json_str) {
+            Ok(options) => options,
+            Err(e) => {
+                throw_vfs_error(scope, &format!("Invalid search options: {}", e));
+                return;
+            }
+        }
+    } else {
+        throw_vfs_error(
+            scope,
+            "vfsSearch() requires an options object: {glob?, pattern?, path?, maxResults?}",
+        );
+        return;
+    };
+
+    if options.glob.is_none() && options.pattern.is_none() {
+        throw_vfs_error(scope, "vfsSearch() requires a glob, a pattern, or both");
+        return;
+    }
+
+    let result = with_vfs(&vfs_id, |vfs| {
+        vfs.search(
+            options.glob.as_deref(),
+            options.pattern.as_deref(),
+            options.path.as_deref().unwrap_or("/"),
+        )
+    });
+
+    let mut matches = match result {
+        Some(Ok(matches)) => matches,
+        Some(Err(e)) => {
+            throw_vfs_error(scope, &format!("{:#}", e));
+            return;
+        }
+        None => {
+            throw_vfs_error(scope, &format!("VFS not found: {}", vfs_id));
+            return;
+        }
+    };
+
+    let max_results = options.max_results.unwrap_or(DEFAULT_SEARCH_RESULTS);
+    let total_count = matches.len();
+    matches.truncate(max_results);
+    let search_result = VfsSearchResult {
+        matches,
+        total_count,
+        truncated: total_count > max_results,
+    };
+
+    let json_str = match serde_json::to_string(&search_result) {
+        Ok(s) => s,
+        Err(e) => {
+            throw_vfs_error(scope, &format!("Failed to serialize search results: {}", e));
+            return;
+        }
+    };
+
+    if let Some(v8_str) = v8::String::new(scope, &json_str) {
+        match v8::json::parse(scope, v8_str) {
+            Some(parsed) => rv.set(parsed),
+            None => throw_vfs_error(scope, "Failed to parse JSON"),
+        }
+    } else {
+        throw_vfs_error(scope, "Failed to create V8 string");
+    }
+}
@@ -7,6 +7,7 @@
 //! - **Platform**: Global V8 platform initialized once at app startup
 //! - **Runtime**: Per-execution isolate with configurable limits
 //! - **Bindings**: Rust functions bound to JavaScript for infrastructure operations
+//! - **TypeScript**: Optional transpilation of TypeScript scripts before execution
 //!
 //! # Usage
 //!
@@ -24,12 +25,16 @@ pub mod console;
 pub mod platform;
 pub mod runtime;
 pub mod types;
+pub mod typescript;
 
 pub use bindings::{get_api_documentation, register_bindings, set_global_aws_identity};
 pub use console::{register_console, ConsoleBuffers};
 pub use platform::{initialize_v8_platform, is_v8_initialized};
-pub use runtime::{ExecutionResult, RuntimeConfig, V8Runtime};
+pub use runtime::{
+    ErrorLocation, ExecutionResult, InterruptHandle, KillReason, RuntimeConfig, V8Runtime,
+};
 pub use types::{from_v8_value, to_v8_value};
+pub use typescript::{transpile_typescript, TranspiledScript};
 
 #[cfg(test)]
 mod integration_tests {
@@ -17,7 +17,11 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 use anyhow::{anyhow, Result};
+use std::ffi::c_void;
+use std::fmt;
 use std::pin::pin;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
 use std::thread;
 use std::time::{Duration, Instant};
 
@@ -28,9 +32,15 @@ use super::console::{register_console, ConsoleBuffers};
 #[derive(Debug, Clone)]
 pub struct RuntimeConfig {
     /// Maximum heap size in bytes (default: 256MB)
+    ///
+    /// Scripts that grow the heap to this size are killed.
     pub max_heap_size_bytes: usize,
 
-    /// Execution timeout (default: 30 seconds)
+    /// Wall-clock execution timeout (default: 30 seconds)
+    ///
+    /// Scripts still running after this long are killed. Time spent inside
+    /// blocking bindings counts, but termination takes effect once the
+    /// binding returns to JavaScript.
     pub timeout: Duration,
 
     /// Enable console output capture (default: true)
@@ -47,6 +57,16 @@ impl Default for RuntimeConfig {
     }
 }
 
+/// Position in the executed script where an error was raised
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorLocation {
+    /// Line number (1-based)
+    pub line: usize,
+
+    /// Column number (1-based)
+    pub column: usize,
+}
+
 /// Result of JavaScript execution
 #[derive(Debug, Clone)]
 pub struct ExecutionResult {
@@ -64,24 +84,183 @@ pub struct ExecutionResult {
 
     /// Execution time in milliseconds
     pub execution_time_ms: u64,
+
+    /// Where a compile, runtime, or rejection error was raised (None on success)
+    pub error_location: Option<ErrorLocation>,
+}
+
+/// Why an execution was killed before it finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillReason {
+    /// The wall-clock timeout elapsed
+    Timeout(Duration),
+    /// The heap reached the configured limit (bytes)
+    HeapLimit(usize),
+    /// Stopped through an [`InterruptHandle`]
+    Interrupted,
+}
+
+impl fmt::Display for KillReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KillReason::Timeout(timeout) => {
+                write!(f, "script killed: exceeded {}", format_duration(*timeout))
+            }
+            KillReason::HeapLimit(bytes) => write!(
+                f,
+                "script killed: exceeded {}MB heap limit",
+                bytes / (1024 * 1024)
+            ),
+            KillReason::Interrupted => write!(f, "script killed: interrupted"),
+        }
+    }
+}
+
+/// Format a timeout as whole seconds when possible ("30s"), else milliseconds
+fn format_duration(duration: Duration) -> String {
+    if duration.subsec_nanos() == 0 {
+        format!("{}s", duration.as_secs())
+    } else {
+        format!("{}ms", duration.as_millis())
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Kill switch for one execution, shared by the watchdog thread, the heap
+/// limit callback, and the runtime's interrupt handle
+struct ExecutionGuard {
+    isolate: OnceLock<v8::IsolateHandle>,
+    heap_limit: usize,
+    kill_reason: Mutex<Option<KillReason>>,
+}
+
+impl ExecutionGuard {
+    fn new(heap_limit: usize) -> Self {
+        Self {
+            isolate: OnceLock::new(),
+            heap_limit,
+            kill_reason: Mutex::new(None),
+        }
+    }
+
+    /// Record why the execution is being killed and terminate the isolate
+    ///
+    /// The first reason wins; later kills only re-request termination.
+    fn kill(&self, reason: KillReason) {
+        lock(&self.kill_reason).get_or_insert(reason);
+        if let Some(isolate) = self.isolate.get() {
+            isolate.terminate_execution();
+        }
+    }
+
+    fn kill_reason(&self) -> Option<KillReason> {
+        *lock(&self.kill_reason)
+    }
+}
+
+/// Called by V8 when the heap approaches its limit
+///
+/// Reaching the limit aborts the whole process, so the execution is
+/// terminated and the limit raised to leave room for the isolate to unwind.
+unsafe extern "C" fn near_heap_limit_callback(
+    data: *mut c_void,
+    current_heap_limit: usize,
+    _initial_heap_limit: usize,
+) -> usize {
+    // SAFETY: `data` is the ExecutionGuard registered in `execute`, which
+    // outlives the isolate
+    let guard = unsafe { &*(data as *const ExecutionGuard) };
+    guard.kill(KillReason::HeapLimit(guard.heap_limit));
+    current_heap_limit.saturating_mul(2)
+}
+
+/// Handle for killing a runtime's current execution from another thread
+///
+/// Obtained from [`V8Runtime::interrupt_handle`]. Interrupting only affects
+/// the execution running at that moment; later executions start normally.
+#[derive(Clone, Default)]
+pub struct InterruptHandle {
+    current: Arc<Mutex<Option<Arc<ExecutionGuard>>>>,
+}
+
+impl InterruptHandle {
+    /// Kill the running execution
+    ///
+    /// Returns false when the runtime is not executing anything.
+    pub fn interrupt(&self) -> bool {
+        match lock(&self.current).as_ref() {
+            Some(guard) => {
+                guard.kill(KillReason::Interrupted);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Whether the runtime is currently executing a script
+    pub fn is_running(&self) -> bool {
+        lock(&self.current).is_some()
+    }
+}
+
+/// Registers an execution with an interrupt handle until dropped
+struct ActiveExecution<'a> {
+    handle: &'a InterruptHandle,
+}
+
+impl<'a> ActiveExecution<'a> {
+    fn start(handle: &'a InterruptHandle, guard: Arc<ExecutionGuard>) -> Self {
+        *lock(&handle.current) = Some(guard);
+        Self { handle }
+    }
+}
+
+impl Drop for ActiveExecution<'_> {
+    fn drop(&mut self) {
+        *lock(&self.handle.current) = None;
+    }
+}
+
+/// Script position of an exception
+fn exception_location<'s>(
+    scope: &mut v8::PinScope<'s, '_>,
+    exception: v8::Local<'s, v8::Value>,
+) -> Option<ErrorLocation> {
+    let message = v8::Exception::create_message(scope, exception);
+    Some(ErrorLocation {
+        line: message.get_line_number(scope)?,
+        column: message.get_start_column() + 1,
+    })
 }
 
 /// V8 JavaScript runtime
 pub struct V8Runtime {
     config: RuntimeConfig,
+    interrupt: InterruptHandle,
 }
 
 impl V8Runtime {
     /// Create a new V8 runtime with default configuration
     pub fn new() -> Self {
-        Self {
-            config: RuntimeConfig::default(),
-        }
+        Self::with_config(RuntimeConfig::default())
     }
 
     /// Create a new V8 runtime with custom configuration
     pub fn with_config(config: RuntimeConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            interrupt: InterruptHandle::default(),
+        }
+    }
+
+    /// Handle for killing the running execution from another thread
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.interrupt.clone()
     }
 
     /// Execute JavaScript code and return result
@@ -90,7 +269,9 @@ impl V8Runtime {
     /// * `code` - JavaScript source code to execute
     ///
     /// # Returns
-    /// ExecutionResult with success status, return value, output, and timing
+    /// ExecutionResult with success status, return value, output, and timing.
+    /// Scripts that exceed the timeout or heap limit, or are interrupted, are
+    /// killed and reported as failures with a "script killed: ..." error.
     ///
     /// # Example
     ///
@@ -116,6 +297,10 @@ impl V8Runtime {
         );
         let _v8_guard = stood::perf_guard!("awsdash.v8.execute");
 
+        // Declared before the isolate so it is dropped after it: the heap
+        // limit callback holds a raw pointer to the guard
+        let guard = Arc::new(ExecutionGuard::new(self.config.max_heap_size_bytes));
+
         // Create isolate with memory limits
         stood::perf_checkpoint!("awsdash.v8.isolate.create.start");
         let mut params = v8::CreateParams::default();
@@ -126,16 +311,25 @@ impl V8Runtime {
         let mut isolate = isolate_result;
         stood::perf_checkpoint!("awsdash.v8.isolate.create.end");
 
-        // Get thread-safe handle for timeout enforcement
-        let isolate_handle = isolate.thread_safe_handle();
+        // Get thread-safe handle for termination
+        let _ = guard.isolate.set(isolate.thread_safe_handle());
+        isolate.add_near_heap_limit_callback(
+            near_heap_limit_callback,
+            Arc::as_ptr(&guard) as *mut c_void,
+        );
+        let _active = ActiveExecution::start(&self.interrupt, guard.clone());
         let timeout = self.config.timeout;
 
-        // Spawn timeout watchdog thread
+        // Spawn timeout watchdog thread; it exits as soon as the sender is
+        // dropped at the end of this function
+        let (watchdog_stop, watchdog_rx) = mpsc::channel::<()>();
+        let watchdog_guard = guard.clone();
         let _timeout_thread = thread::spawn(move || {
-            thread::sleep(timeout);
-            // Terminate execution after timeout
-            isolate_handle.terminate_execution();
+            if let Err(RecvTimeoutError::Timeout) = watchdog_rx.recv_timeout(timeout) {
+                watchdog_guard.kill(KillReason::Timeout(timeout));
+            }
         });
+        let _watchdog_stop = watchdog_stop;
 
         // Execute JavaScript in proper scope hierarchy
         stood::perf_checkpoint!("awsdash.v8.scope_setup.start");
@@ -175,6 +369,7 @@ impl V8Runtime {
                     stdout,
                     stderr,
                     execution_time_ms: start_time.elapsed().as_millis() as u64,
+                    error_location: None,
                 });
             }
             stood::perf_checkpoint!("awsdash.v8.bindings.end");
@@ -199,15 +394,16 @@ impl V8Runtime {
                         (String::new(), String::new())
                     };
 
-                    // Check if terminated by timeout
-                    if scope.has_terminated() {
-                        stderr.push_str(&format!("Execution terminated (timeout: {:?})", timeout));
+                    // Check if killed (timeout, heap limit, or interrupt)
+                    if let Some(reason) = guard.kill_reason() {
+                        stderr.push_str(&reason.to_string());
                         return Ok(ExecutionResult {
                             success: false,
                             result: None,
                             stdout,
                             stderr,
                             execution_time_ms: start_time.elapsed().as_millis() as u64,
+                            error_location: None,
                         });
                     }
 
@@ -216,6 +412,7 @@ impl V8Runtime {
                     let exception_str = exception.to_string(scope).unwrap();
                     let exception_msg = exception_str.to_rust_string_lossy(scope);
                     stderr.push_str(&exception_msg);
+                    let error_location = exception_location(scope, exception);
 
                     return Ok(ExecutionResult {
                         success: false,
@@ -223,6 +420,7 @@ impl V8Runtime {
                         stdout,
                         stderr,
                         execution_time_ms: start_time.elapsed().as_millis() as u64,
+                        error_location,
                     });
                 }
             };
@@ -240,15 +438,16 @@ impl V8Runtime {
                         (String::new(), String::new())
                     };
 
-                    // Check if terminated by timeout
-                    if scope.has_terminated() {
-                        stderr.push_str(&format!("Execution terminated (timeout: {:?})", timeout));
+                    // Check if killed (timeout, heap limit, or interrupt)
+                    if let Some(reason) = guard.kill_reason() {
+                        stderr.push_str(&reason.to_string());
                         return Ok(ExecutionResult {
                             success: false,
                             result: None,
                             stdout,
                             stderr,
                             execution_time_ms: start_time.elapsed().as_millis() as u64,
+                            error_location: None,
                         });
                     }
 
@@ -257,6 +456,7 @@ impl V8Runtime {
                     let exception_str = exception.to_string(scope).unwrap();
                     let exception_msg = exception_str.to_rust_string_lossy(scope);
                     stderr.push_str(&exception_msg);
+                    let error_location = exception_location(scope, exception);
 
                     return Ok(ExecutionResult {
                         success: false,
@@ -264,6 +464,7 @@ impl V8Runtime {
                         stdout,
                         stderr,
                         execution_time_ms: start_time.elapsed().as_millis() as u64,
+                        error_location,
                     });
                 }
             };
@@ -280,6 +481,25 @@ impl V8Runtime {
                 // This is necessary because async functions schedule their resolution as microtasks
                 scope.perform_microtask_checkpoint();
 
+                // A kill during the microtasks leaves the promise pending
+                if let Some(reason) = guard.kill_reason() {
+                    let (stdout, mut stderr) = if let Some(ref buffers) = console_buffers {
+                        (buffers.get_stdout(), buffers.get_stderr())
+                    } else {
+                        (String::new(), String::new())
+                    };
+                    stderr.push_str(&reason.to_string());
+
+                    return Ok(ExecutionResult {
+                        success: false,
+                        result: None,
+                        stdout,
+                        stderr,
+                        execution_time_ms: start_time.elapsed().as_millis() as u64,
+                        error_location: None,
+                    });
+                }
+
                 match promise.state() {
                     v8::PromiseState::Fulfilled => {
                         // Promise resolved successfully - get the resolved value
@@ -297,6 +517,12 @@ impl V8Runtime {
                         let rejection_str = rejection.to_string(scope).unwrap();
                         let rejection_msg = rejection_str.to_rust_string_lossy(scope);
                         stderr.push_str(&format!("Promise rejected: {}", rejection_msg));
+                        // Only Error objects carry the position they were created at
+                        let error_location = if rejection.is_native_error() {
+                            exception_location(scope, rejection)
+                        } else {
+                            None
+                        };
 
                         return Ok(ExecutionResult {
                             success: false,
@@ -304,6 +530,7 @@ impl V8Runtime {
                             stdout,
                             stderr,
                             execution_time_ms: start_time.elapsed().as_millis() as u64,
+                            error_location,
                         });
                     }
                     v8::PromiseState::Pending => {
@@ -326,6 +553,7 @@ impl V8Runtime {
                             stdout,
                             stderr,
                             execution_time_ms: start_time.elapsed().as_millis() as u64,
+                            error_location: None,
                         });
                     }
                 }
@@ -345,12 +573,33 @@ impl V8Runtime {
                 } else {
                     // If JSON serialization fails (e.g., circular references, BigInt),
                     // fall back to toString()
-                    let result_str = result.to_string(scope).unwrap();
-                    result_str.to_rust_string_lossy(scope)
+                    result
+                        .to_string(scope)
+                        .map(|result_str| result_str.to_rust_string_lossy(scope))
+                        .unwrap_or_default()
                 }
             });
             stood::perf_checkpoint!("awsdash.v8.stringify.end");
 
+            // Serialization runs JavaScript (toJSON, getters) and can be killed too
+            if let Some(reason) = guard.kill_reason() {
+                let (stdout, mut stderr) = if let Some(ref buffers) = console_buffers {
+                    (buffers.get_stdout(), buffers.get_stderr())
+                } else {
+                    (String::new(), String::new())
+                };
+                stderr.push_str(&reason.to_string());
+
+                return Ok(ExecutionResult {
+                    success: false,
+                    result: None,
+                    stdout,
+                    stderr,
+                    execution_time_ms: start_time.elapsed().as_millis() as u64,
+                    error_location: None,
+                });
+            }
+
             Ok::<(String, Option<ConsoleBuffers>), anyhow::Error>((result_json, console_buffers))
         }?;
 
@@ -374,6 +623,7 @@ impl V8Runtime {
             stdout,
             stderr,
             execution_time_ms,
+            error_location: None,
         })
     }
 }
@@ -472,6 +722,28 @@ mod tests {
         assert!(result.stderr.contains("test error"));
     }
 
+    #[test]
+    fn test_error_location() {
+        let _ = initialize_v8_platform();
+
+        let runtime = V8Runtime::new();
+        let result = runtime
+            .execute("const a = 1;\nconst b = a.missing.value;")
+            .unwrap();
+        assert!(!result.success);
+        let location = result.error_location.expect("runtime error has a location");
+        assert_eq!(location.line, 2);
+        assert!(location.column > 1);
+
+        let result = runtime
+            .execute("(async () => {\n  throw new Error('async failure');\n})()")
+            .unwrap();
+        assert!(!result.success);
+        assert_eq!(result.error_location.map(|l| l.line), Some(2));
+
+        assert!(runtime.execute("1 + 1").unwrap().error_location.is_none());
+    }
+
     #[test]
     fn test_undefined_variable() {
         let _ = initialize_v8_platform();
@@ -516,8 +788,8 @@ mod tests {
         assert!(!result.success, "Should timeout");
         assert!(result.result.is_none());
         assert!(
-            result.stderr.contains("timeout") || result.stderr.contains("terminated"),
-            "Error message should mention timeout or terminated, got: {}",
+            result.stderr.contains("script killed: exceeded 100ms"),
+            "Error message should report the timeout, got: {}",
             result.stderr
         );
         assert!(result.execution_time_ms >= 100); // Should hit timeout
@@ -637,6 +909,81 @@ mod tests {
         // as it depends on V8 GC implementation details
     }
 
+    #[test]
+    fn test_heap_limit_kills_script() {
+        let _ = initialize_v8_platform();
+
+        let config = RuntimeConfig {
+            max_heap_size_bytes: 32 * 1024 * 1024, // 32MB
+            timeout: Duration::from_secs(20),
+            ..Default::default()
+        };
+
+        let runtime = V8Runtime::with_config(config);
+        let code = r#"
+            const chunks = [];
+            while (true) {
+                chunks.push(new Array(100000).fill({ data: 'x' }));
+            }
+        "#;
+        let result = runtime.execute(code).unwrap();
+
+        assert!(!result.success);
+        assert!(
+            result
+                .stderr
+                .contains("script killed: exceeded 32MB heap limit"),
+            "Error message should report the heap limit, got: {}",
+            result.stderr
+        );
+
+        // The runtime keeps working after a killed execution
+        assert!(runtime.execute("1 + 1").unwrap().success);
+    }
+
+    #[test]
+    fn test_interrupt_kills_running_script() {
+        let _ = initialize_v8_platform();
+
+        let runtime = V8Runtime::new();
+        let handle = runtime.interrupt_handle();
+        assert!(!handle.interrupt(), "Nothing is running yet");
+
+        let interrupter = thread::spawn(move || {
+            while !handle.interrupt() {
+                thread::sleep(Duration::from_millis(10));
+            }
+        });
+
+        let result = runtime.execute("while(true) {}").unwrap();
+        interrupter.join().unwrap();
+
+        assert!(!result.success);
+        assert!(
+            result.stderr.contains("script killed: interrupted"),
+            "Error message should report the interrupt, got: {}",
+            result.stderr
+        );
+        assert!(result.execution_time_ms < 5000);
+        assert!(!runtime.interrupt_handle().is_running());
+    }
+
+    #[test]
+    fn test_kill_reason_messages() {
+        assert_eq!(
+            KillReason::Timeout(Duration::from_secs(30)).to_string(),
+            "script killed: exceeded 30s"
+        );
+        assert_eq!(
+            KillReason::Timeout(Duration::from_millis(1500)).to_string(),
+            "script killed: exceeded 1500ms"
+        );
+        assert_eq!(
+            KillReason::HeapLimit(256 * 1024 * 1024).to_string(),
+            "script killed: exceeded 256MB heap limit"
+        );
+    }
+
     // Integration tests
 
     #[test]
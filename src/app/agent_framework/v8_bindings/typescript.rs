@@ -0,0 +1,140 @@
+//! TypeScript Transpilation
+//!
+//! Converts agent-written TypeScript to JavaScript before it runs in V8.
+//! Types are stripped with swc (through `deno_ast`); no type checking is done.
+//! A source map is kept so error positions reported by V8 can be translated
+//! back to the TypeScript the agent wrote.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use awsdash::app::agent_framework::v8_bindings::{transpile_typescript, V8Runtime};
+//!
+//! let script = transpile_typescript("const n: number = 2; n * 21").unwrap();
+//! let result = V8Runtime::new().execute(&script.code).unwrap();
+//! assert_eq!(result.result.unwrap(), "42");
+//! ```
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use deno_ast::{
+    EmitOptions, MediaType, ModuleSpecifier, ParseParams, SourceMapOption, TranspileModuleOptions,
+    TranspileOptions,
+};
+use sourcemap::SourceMap;
+
+use super::runtime::ErrorLocation;
+
+/// Name the script is parsed under (shows up in syntax errors)
+const SCRIPT_SPECIFIER: &str = "file:///script.ts";
+
+/// JavaScript produced from a TypeScript script
+pub struct TranspiledScript {
+    /// JavaScript to execute
+    pub code: String,
+
+    /// Maps positions in `code` back to the TypeScript source
+    source_map: Option<SourceMap>,
+}
+
+impl TranspiledScript {
+    /// Translate a position in the generated JavaScript to the TypeScript source
+    pub fn original_location(&self, location: ErrorLocation) -> Option<ErrorLocation> {
+        let source_map = self.source_map.as_ref()?;
+        let token = source_map.lookup_token(
+            location.line.checked_sub(1)? as u32,
+            location.column.saturating_sub(1) as u32,
+        )?;
+
+        Some(ErrorLocation {
+            line: token.get_src_line() as usize + 1,
+            column: token.get_src_col() as usize + 1,
+        })
+    }
+}
+
+/// Strip TypeScript syntax, returning JavaScript and a source map
+///
+/// Syntax errors are returned with their TypeScript line and column.
+pub fn transpile_typescript(source: &str) -> Result<TranspiledScript> {
+    let parsed = deno_ast::parse_module(ParseParams {
+        specifier: ModuleSpecifier::parse(SCRIPT_SPECIFIER)?,
+        text: source.into(),
+        media_type: MediaType::TypeScript,
+        capture_tokens: false,
+        scope_analysis: false,
+        maybe_syntax: None,
+    })
+    .map_err(|e| anyhow!("TypeScript syntax error: {}", e))?;
+
+    let emitted = parsed
+        .transpile(
+            &TranspileOptions::default(),
+            &TranspileModuleOptions::default(),
+            &EmitOptions {
+                source_map: SourceMapOption::Separate,
+                inline_sources: false,
+                ..Default::default()
+            },
+        )
+        .map_err(|e| anyhow!("TypeScript transpilation failed: {}", e))?
+        .into_source();
+
+    let source_map = emitted
+        .source_map
+        .and_then(|map| SourceMap::from_slice(map.as_bytes()).ok());
+
+    Ok(TranspiledScript {
+        code: emitted.text,
+        source_map,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::agent_framework::v8_bindings::{initialize_v8_platform, V8Runtime};
+
+    #[test]
+    fn test_types_are_stripped() {
+        let _ = initialize_v8_platform();
+
+        let script = transpile_typescript(
+            r#"
+interface Bucket { name: string; sizeBytes: number }
+const buckets: Bucket[] = [{ name: "a", sizeBytes: 10 }, { name: "b", sizeBytes: 32 }];
+const total = buckets.reduce((sum: number, b) => sum + b.sizeBytes, 0) as number;
+total
+"#,
+        )
+        .unwrap();
+
+        assert!(!script.code.contains("interface"));
+        let result = V8Runtime::new().execute(&script.code).unwrap();
+        assert!(result.success, "stderr: {}", result.stderr);
+        assert_eq!(result.result.unwrap(), "42");
+    }
+
+    #[test]
+    fn test_syntax_error_is_reported() {
+        let error = transpile_typescript("const x: number = ;").err().unwrap();
+        assert!(error.to_string().contains("TypeScript syntax error"));
+    }
+
+    #[test]
+    fn test_error_location_maps_to_typescript() {
+        let _ = initialize_v8_platform();
+
+        let source = "type Id = string;\n\ninterface Item {\n  id: Id;\n}\n\nconst item: Item | undefined = undefined;\nconst id: Id = item!.id;\nid";
+        let script = transpile_typescript(source).unwrap();
+
+        let result = V8Runtime::new().execute(&script.code).unwrap();
+        assert!(!result.success);
+
+        let location = script
+            .original_location(result.error_location.unwrap())
+            .unwrap();
+        assert_eq!(location.line, 8);
+    }
+}
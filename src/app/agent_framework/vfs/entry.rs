@@ -4,10 +4,11 @@
 
 #![warn(clippy::all, rust_2018_idioms)]
 
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
 
 /// Metadata for a VFS entry
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 pub struct VfsMetadata {
     /// Size of the content in bytes
     pub size: usize,
@@ -17,6 +18,21 @@ pub struct VfsMetadata {
     pub modified_at: Instant,
     /// Whether this is a directory
     pub is_directory: bool,
+    /// Access order for LRU eviction; higher is more recent. Atomic so reads
+    /// through a shared reference can record the access.
+    last_access: AtomicU64,
+}
+
+impl Clone for VfsMetadata {
+    fn clone(&self) -> Self {
+        Self {
+            size: self.size,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+            is_directory: self.is_directory,
+            last_access: AtomicU64::new(self.last_access()),
+        }
+    }
 }
 
 impl VfsMetadata {
@@ -28,6 +44,7 @@ impl VfsMetadata {
             created_at: now,
             modified_at: now,
             is_directory: false,
+            last_access: AtomicU64::new(0),
         }
     }
 
@@ -39,8 +56,19 @@ impl VfsMetadata {
             created_at: now,
             modified_at: now,
             is_directory: true,
+            last_access: AtomicU64::new(0),
         }
     }
+
+    /// Access order of the last read or write
+    pub fn last_access(&self) -> u64 {
+        self.last_access.load(Ordering::Relaxed)
+    }
+
+    /// Record a read or write at `tick` of the owning VFS's access clock
+    pub(super) fn touch(&self, tick: u64) {
+        self.last_access.store(tick, Ordering::Relaxed);
+    }
 }
 
 /// A file or directory entry in the VFS
@@ -10,6 +10,12 @@
 //! - Workers receive the VFS ID as a string and access VFS via the registry
 //! - V8 bindings use thread-local VFS ID to access the correct VFS
 //! - File operation tools detect `vfs:` prefix and redirect to VFS
+//! - Directories can carry their own quota; `/results` evicts least recently
+//!   used files when full, `/pages` rejects the write (see [`quota`])
+//! - `find` (glob) and `grep` (regex) locate earlier results without listing
+//!   every directory (see [`search`])
+//! - Snapshots save a VFS to disk by agent session ID so a later session, or
+//!   the Pages Manager, can reopen the workspace (see [`snapshot`])
 //!
 //! ## VFS Structure
 //!
@@ -25,17 +31,24 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 mod entry;
+pub mod quota;
 pub mod registry;
+pub mod search;
+pub mod snapshot;
 
 pub use entry::{VfsDirEntry, VfsEntry, VfsMetadata};
+pub use quota::{DirectoryQuota, DirectoryUsage, EvictionPolicy, FileUsage, VfsStats};
 pub use registry::{
-    deregister_vfs, get_current_vfs_id, register_vfs, set_current_vfs_id, vfs_exists, with_vfs,
-    with_vfs_mut,
+    deregister_vfs, get_current_vfs_id, list_vfs_ids, register_vfs, set_current_vfs_id,
+    vfs_exists, with_vfs, with_vfs_mut,
 };
+pub use search::{VfsFindMatch, VfsGrepMatch, VfsSearchMatches};
+pub use snapshot::{reopen_snapshot, VfsSnapshotInfo, VfsSnapshotStore};
 
 use anyhow::{anyhow, bail, Result};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use std::sync::atomic::AtomicU64;
 use std::time::Instant;
 
 /// Default maximum VFS size: 100MB
@@ -55,6 +68,13 @@ pub struct VirtualFileSystem {
     max_size: usize,
     /// When this VFS was created
     created_at: Instant,
+    /// Per-directory size caps
+    quotas: Vec<DirectoryQuota>,
+    /// Counter stamped on entries when read or written, for LRU eviction
+    access_clock: AtomicU64,
+    /// Files and bytes removed by LRU eviction
+    evicted_files: usize,
+    evicted_bytes: usize,
 }
 
 impl VirtualFileSystem {
@@ -65,6 +85,10 @@ impl VirtualFileSystem {
             total_size: 0,
             max_size,
             created_at: Instant::now(),
+            quotas: Vec::new(),
+            access_clock: AtomicU64::new(0),
+            evicted_files: 0,
+            evicted_bytes: 0,
         };
 
         // Create root directories
@@ -78,9 +102,11 @@ impl VirtualFileSystem {
         vfs
     }
 
-    /// Create a new VFS with default 100MB limit
+    /// Create a new VFS with default 100MB limit and default directory quotas
     pub fn with_default_size() -> Self {
-        Self::new(DEFAULT_MAX_SIZE)
+        let mut vfs = Self::new(DEFAULT_MAX_SIZE);
+        vfs.quotas = quota::default_quotas();
+        vfs
     }
 
     /// Normalize a path to ensure consistent formatting
@@ -114,8 +140,9 @@ impl VirtualFileSystem {
 
     /// Write a file to the VFS
     ///
-    /// Creates parent directories if they don't exist.
-    /// Returns an error if the size limit would be exceeded.
+    /// Creates parent directories if they don't exist. Least recently used
+    /// files may be evicted to make room; returns an error if the directory
+    /// quota or size limit would still be exceeded.
     pub fn write_file(&mut self, path: &str, content: &[u8]) -> Result<()> {
         let path = Self::normalize_path(path);
         let content_size = content.len();
@@ -128,18 +155,7 @@ impl VirtualFileSystem {
             .map(|e| e.size())
             .unwrap_or(0);
 
-        // Calculate new total size
-        let size_delta = content_size as isize - old_size as isize;
-        let new_total = (self.total_size as isize + size_delta) as usize;
-
-        if new_total > self.max_size {
-            bail!(
-                "VFS size limit exceeded: current {} + requested {} > limit {}",
-                self.total_size,
-                content_size,
-                self.max_size
-            );
-        }
+        self.make_room(&path, old_size, content_size)?;
 
         // Create parent directories if needed
         if let Some(parent) = Self::parent_path(&path) {
@@ -149,9 +165,10 @@ impl VirtualFileSystem {
         }
 
         // Write the file
-        self.files
-            .insert(path.clone(), VfsEntry::new_file(content.to_vec()));
-        self.total_size = new_total;
+        let entry = VfsEntry::new_file(content.to_vec());
+        entry.metadata.touch(self.next_access());
+        self.files.insert(path.clone(), entry);
+        self.total_size = self.total_size - old_size + content_size;
 
         tracing::trace!(
             path = %path.display(),
@@ -170,7 +187,10 @@ impl VirtualFileSystem {
         let path = Self::normalize_path(path);
 
         match self.files.get(&path) {
-            Some(entry) if entry.is_file() => Ok(&entry.content),
+            Some(entry) if entry.is_file() => {
+                entry.metadata.touch(self.next_access());
+                Ok(&entry.content)
+            }
             Some(_) => Err(anyhow!("Path is a directory: {}", path.display())),
             None => Err(anyhow!("File not found: {}", path.display())),
         }
@@ -307,6 +327,150 @@ impl VirtualFileSystem {
         Ok(())
     }
 
+    /// Delete a file, or a directory and everything under it
+    ///
+    /// Returns the number of files removed.
+    pub fn delete_recursive(&mut self, path: &str) -> Result<usize> {
+        let path = Self::normalize_path(path);
+        if path == Path::new("/") {
+            bail!("Cannot delete the VFS root");
+        }
+        if !self.files.contains_key(&path) {
+            bail!("File not found: {}", path.display());
+        }
+
+        let removed: Vec<PathBuf> = self
+            .files
+            .keys()
+            .filter(|p| p.starts_with(&path))
+            .cloned()
+            .collect();
+        let mut file_count = 0;
+        for p in removed {
+            if let Some(entry) = self.files.remove(&p) {
+                if entry.is_file() {
+                    self.total_size -= entry.size();
+                    file_count += 1;
+                }
+            }
+        }
+
+        tracing::trace!(path = %path.display(), files = file_count, "VFS: deleted recursively");
+        Ok(file_count)
+    }
+
+    /// Copy a directory and everything under it to `to`, which must not exist
+    ///
+    /// Copies go through [`write_file`](Self::write_file), so quotas apply. If
+    /// any file does not fit, the partial copy is removed. Returns the number
+    /// of files copied.
+    pub fn copy_dir(&mut self, from: &str, to: &str) -> Result<usize> {
+        let from = Self::normalize_path(from);
+        let to = Self::normalize_path(to);
+
+        match self.files.get(&from) {
+            Some(entry) if entry.is_directory() => {}
+            Some(_) => bail!("Path is not a directory: {}", from.display()),
+            None => bail!("Directory not found: {}", from.display()),
+        }
+        if self.files.contains_key(&to) {
+            bail!("Destination already exists: {}", to.display());
+        }
+        if to.starts_with(&from) {
+            bail!("Cannot copy a directory into itself: {}", to.display());
+        }
+
+        // Snapshot the source first; eviction while writing must not affect it
+        let mut entries: Vec<(PathBuf, Option<Vec<u8>>)> = self
+            .files
+            .iter()
+            .filter(|(p, _)| p.starts_with(&from))
+            .map(|(p, entry)| {
+                let relative = p.strip_prefix(&from).unwrap_or(p);
+                let content = entry.is_file().then(|| entry.content.clone());
+                (to.join(relative), content)
+            })
+            .collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut file_count = 0;
+        for (path, content) in entries {
+            let result = match content {
+                Some(content) => {
+                    file_count += 1;
+                    self.write_file(&path.display().to_string(), &content)
+                }
+                None => self.mkdir_recursive(&path),
+            };
+            if let Err(e) = result {
+                let _ = self.delete_recursive(&to.display().to_string());
+                return Err(e.context(format!(
+                    "Failed to copy {} to {}",
+                    from.display(),
+                    to.display()
+                )));
+            }
+        }
+
+        Ok(file_count)
+    }
+
+    /// Move or rename a file or directory to `to`, which must not exist
+    ///
+    /// Parent directories of `to` are created. Moving into a directory with a
+    /// quota fails if the data does not fit; nothing is evicted.
+    pub fn move_path(&mut self, from: &str, to: &str) -> Result<()> {
+        let from = Self::normalize_path(from);
+        let to = Self::normalize_path(to);
+
+        if from == Path::new("/") {
+            bail!("Cannot move the VFS root");
+        }
+        if !self.files.contains_key(&from) {
+            bail!("File not found: {}", from.display());
+        }
+        if self.files.contains_key(&to) {
+            bail!("Destination already exists: {}", to.display());
+        }
+        if to.starts_with(&from) {
+            bail!("Cannot move a directory into itself: {}", to.display());
+        }
+
+        let moved: Vec<PathBuf> = self
+            .files
+            .keys()
+            .filter(|p| p.starts_with(&from))
+            .cloned()
+            .collect();
+        let moved_size: usize = moved
+            .iter()
+            .filter_map(|p| self.files.get(p))
+            .filter(|entry| entry.is_file())
+            .map(|entry| entry.size())
+            .sum();
+        self.check_move_quota(&from, &to, moved_size)?;
+
+        if let Some(parent) = Self::parent_path(&to) {
+            if parent != PathBuf::from("/") {
+                self.mkdir_recursive(&parent)?;
+            }
+        }
+        for p in moved {
+            if let Some(entry) = self.files.remove(&p) {
+                let relative = p.strip_prefix(&from).unwrap_or(&p);
+                let destination = if relative.as_os_str().is_empty() {
+                    to.clone()
+                } else {
+                    to.join(relative)
+                };
+                self.files.insert(destination, entry);
+            }
+        }
+
+        tracing::trace!(from = %from.display(), to = %to.display(), "VFS: moved");
+        Ok(())
+    }
+
     /// Get file/directory metadata
     pub fn stat(&self, path: &str) -> Result<&VfsMetadata> {
         let path = Self::normalize_path(path);
@@ -436,6 +600,56 @@ mod tests {
         assert_eq!(vfs.total_size(), 0);
     }
 
+    #[test]
+    fn test_recursive_operations() {
+        let mut vfs = VirtualFileSystem::new(1024 * 1024);
+        vfs.write_file("/pages/report/index.html", b"<html>")
+            .unwrap();
+        vfs.write_file("/pages/report/assets/app.js", b"run()")
+            .unwrap();
+
+        assert_eq!(
+            vfs.copy_dir("/pages/report", "/pages/report-v2").unwrap(),
+            2
+        );
+        assert_eq!(
+            vfs.read_file("/pages/report-v2/assets/app.js").unwrap(),
+            b"run()"
+        );
+        assert_eq!(vfs.total_size(), 22);
+        assert!(vfs
+            .copy_dir("/pages/report", "/pages/report/nested")
+            .is_err());
+        assert!(vfs.copy_dir("/pages/report", "/pages/report-v2").is_err());
+
+        vfs.move_path("/pages/report-v2", "/final/archive/report")
+            .unwrap();
+        assert!(!vfs.exists("/pages/report-v2"));
+        assert!(vfs.is_file("/final/archive/report/index.html"));
+        assert!(vfs.is_directory("/final/archive/report/assets"));
+        vfs.move_path("/final/archive/report/index.html", "/final/index.html")
+            .unwrap();
+        assert_eq!(vfs.total_size(), 22);
+
+        assert!(vfs.delete("/pages/report").is_err());
+        assert_eq!(vfs.delete_recursive("/pages/report").unwrap(), 2);
+        assert!(!vfs.exists("/pages/report/assets"));
+        assert!(vfs.is_directory("/pages"));
+        assert_eq!(vfs.total_size(), 11);
+        assert!(vfs.delete_recursive("/").is_err());
+    }
+
+    #[test]
+    fn test_copy_dir_rolls_back_when_over_limit() {
+        let mut vfs = VirtualFileSystem::new(12);
+        vfs.write_file("/workspace/a/one.txt", b"12345").unwrap();
+        vfs.write_file("/workspace/a/two.txt", b"12345").unwrap();
+
+        assert!(vfs.copy_dir("/workspace/a", "/workspace/b").is_err());
+        assert!(!vfs.exists("/workspace/b"));
+        assert_eq!(vfs.total_size(), 10);
+    }
+
     #[test]
     fn test_path_normalization() {
         let mut vfs = VirtualFileSystem::new(1024 * 1024);
@@ -0,0 +1,386 @@
+//! VFS Quotas and Usage
+//!
+//! Directories can be given their own size cap on top of the VFS-wide limit,
+//! so bulky query results cannot crowd out generated pages. A quota either
+//! rejects writes that would exceed it or evicts the least recently used
+//! files under its directory to make room. Eviction also frees space when a
+//! write would exceed the VFS-wide limit.
+//!
+//! [`VirtualFileSystem::vfs_stats`] reports what is consuming memory, per
+//! top-level directory and by largest file.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::Ordering;
+
+use super::VirtualFileSystem;
+
+/// Default cap for `/results`: 60MB
+pub const DEFAULT_RESULTS_QUOTA: usize = 60 * 1024 * 1024;
+
+/// Default cap for `/pages`: 25MB
+pub const DEFAULT_PAGES_QUOTA: usize = 25 * 1024 * 1024;
+
+/// Number of files listed in [`VfsStats::largest_files`]
+const LARGEST_FILES_LIMIT: usize = 10;
+
+/// What happens when a write would exceed a directory quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EvictionPolicy {
+    /// Fail the write
+    Reject,
+    /// Delete least recently used files in the directory until it fits
+    Lru,
+}
+
+/// Size cap for one directory and its subdirectories
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryQuota {
+    pub directory: String,
+    pub max_size: usize,
+    pub eviction: EvictionPolicy,
+}
+
+/// Quotas applied to VFS instances created with the default size
+pub fn default_quotas() -> Vec<DirectoryQuota> {
+    vec![
+        DirectoryQuota {
+            directory: "/results".to_string(),
+            max_size: DEFAULT_RESULTS_QUOTA,
+            eviction: EvictionPolicy::Lru,
+        },
+        DirectoryQuota {
+            directory: "/pages".to_string(),
+            max_size: DEFAULT_PAGES_QUOTA,
+            eviction: EvictionPolicy::Reject,
+        },
+    ]
+}
+
+/// Memory used under one top-level directory
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DirectoryUsage {
+    pub directory: String,
+    pub size: usize,
+    pub file_count: usize,
+    pub quota: Option<DirectoryQuota>,
+}
+
+/// Size of a single file
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileUsage {
+    pub path: String,
+    pub size: usize,
+}
+
+/// Snapshot of what is consuming memory in a VFS
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VfsStats {
+    pub total_size: usize,
+    pub max_size: usize,
+    pub file_count: usize,
+    /// Top-level directories, largest first
+    pub directories: Vec<DirectoryUsage>,
+    /// Largest files, largest first
+    pub largest_files: Vec<FileUsage>,
+    /// Files removed by LRU eviction since the VFS was created
+    pub evicted_files: usize,
+    pub evicted_bytes: usize,
+}
+
+impl VirtualFileSystem {
+    /// Cap `directory` at `max_size` bytes, replacing any existing quota on it
+    pub fn set_quota(&mut self, directory: &str, max_size: usize, eviction: EvictionPolicy) {
+        let directory = Self::normalize_path(directory).display().to_string();
+        self.quotas.retain(|q| q.directory != directory);
+        self.quotas.push(DirectoryQuota {
+            directory,
+            max_size,
+            eviction,
+        });
+    }
+
+    /// Remove the quota on `directory`, if any
+    pub fn clear_quota(&mut self, directory: &str) {
+        let directory = Self::normalize_path(directory).display().to_string();
+        self.quotas.retain(|q| q.directory != directory);
+    }
+
+    /// Configured directory quotas
+    pub fn quotas(&self) -> &[DirectoryQuota] {
+        &self.quotas
+    }
+
+    /// Usage summary for display in the agent UI and `vfs.stats()`
+    pub fn vfs_stats(&self) -> VfsStats {
+        let mut directories: Vec<DirectoryUsage> = self
+            .files
+            .iter()
+            .filter(|(path, entry)| entry.is_directory() && path.parent() == Some(Path::new("/")))
+            .map(|(path, _)| {
+                let directory = path.display().to_string();
+                let (size, file_count) = self.usage_under(path);
+                DirectoryUsage {
+                    quota: self
+                        .quotas
+                        .iter()
+                        .find(|q| q.directory == directory)
+                        .cloned(),
+                    directory,
+                    size,
+                    file_count,
+                }
+            })
+            .collect();
+        directories.sort_by(|a, b| {
+            b.size
+                .cmp(&a.size)
+                .then_with(|| a.directory.cmp(&b.directory))
+        });
+
+        let mut files: Vec<FileUsage> = self
+            .files
+            .iter()
+            .filter(|(_, entry)| entry.is_file())
+            .map(|(path, entry)| FileUsage {
+                path: path.display().to_string(),
+                size: entry.size(),
+            })
+            .collect();
+        let file_count = files.len();
+        files.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.path.cmp(&b.path)));
+        files.truncate(LARGEST_FILES_LIMIT);
+
+        VfsStats {
+            total_size: self.total_size,
+            max_size: self.max_size,
+            file_count,
+            directories,
+            largest_files: files,
+            evicted_files: self.evicted_files,
+            evicted_bytes: self.evicted_bytes,
+        }
+    }
+
+    /// Next value of the access clock used for LRU ordering
+    pub(super) fn next_access(&self) -> u64 {
+        self.access_clock.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Make room for replacing `old_size` bytes at `path` with `new_size`
+    ///
+    /// Evicts from LRU directories as needed, never `path` itself. Fails if
+    /// the directory quota or the VFS-wide limit still cannot be met.
+    pub(super) fn make_room(
+        &mut self,
+        path: &Path,
+        old_size: usize,
+        new_size: usize,
+    ) -> Result<()> {
+        if let Some(quota) = self.quota_for(path).cloned() {
+            let directory = PathBuf::from(&quota.directory);
+            let used = self.usage_under(&directory).0 - old_size;
+            if used + new_size > quota.max_size {
+                if quota.eviction == EvictionPolicy::Lru && new_size <= quota.max_size {
+                    let needed = used + new_size - quota.max_size;
+                    self.evict_lru(&[directory], path, needed);
+                }
+                let used = self.usage_under(Path::new(&quota.directory)).0 - old_size;
+                if used + new_size > quota.max_size {
+                    bail!(
+                        "VFS quota exceeded for {}: used {} + requested {} > quota {}",
+                        quota.directory,
+                        used,
+                        new_size,
+                        quota.max_size
+                    );
+                }
+            }
+        }
+
+        let used = self.total_size - old_size;
+        if used + new_size > self.max_size {
+            let evictable: Vec<PathBuf> = self
+                .quotas
+                .iter()
+                .filter(|q| q.eviction == EvictionPolicy::Lru)
+                .map(|q| PathBuf::from(&q.directory))
+                .collect();
+            self.evict_lru(&evictable, path, used + new_size - self.max_size);
+
+            if self.total_size - old_size + new_size > self.max_size {
+                bail!(
+                    "VFS size limit exceeded: current {} + requested {} > limit {}",
+                    self.total_size,
+                    new_size,
+                    self.max_size
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that `size` bytes moved from `from` fit the quota covering `to`
+    pub(super) fn check_move_quota(&self, from: &Path, to: &Path, size: usize) -> Result<()> {
+        let Some(quota) = self.quota_for(to) else {
+            return Ok(());
+        };
+        if from.starts_with(&quota.directory) {
+            return Ok(()); // Already counted against this quota
+        }
+        let used = self.usage_under(Path::new(&quota.directory)).0;
+        if used + size > quota.max_size {
+            bail!(
+                "VFS quota exceeded for {}: used {} + moved {} > quota {}",
+                quota.directory,
+                used,
+                size,
+                quota.max_size
+            );
+        }
+        Ok(())
+    }
+
+    /// The most specific quota covering `path`
+    fn quota_for(&self, path: &Path) -> Option<&DirectoryQuota> {
+        self.quotas
+            .iter()
+            .filter(|q| path.starts_with(&q.directory))
+            .max_by_key(|q| q.directory.len())
+    }
+
+    /// Total file size and file count under `directory`
+    fn usage_under(&self, directory: &Path) -> (usize, usize) {
+        self.files
+            .iter()
+            .filter(|(path, entry)| entry.is_file() && path.starts_with(directory))
+            .fold((0, 0), |(size, count), (_, entry)| {
+                (size + entry.size(), count + 1)
+            })
+    }
+
+    /// Delete least recently used files under `directories` until `needed`
+    /// bytes are freed; returns the bytes freed
+    fn evict_lru(&mut self, directories: &[PathBuf], keep: &Path, needed: usize) -> usize {
+        let mut candidates: Vec<(u64, PathBuf, usize)> = self
+            .files
+            .iter()
+            .filter(|(path, entry)| {
+                entry.is_file()
+                    && path.as_path() != keep
+                    && directories.iter().any(|d| path.starts_with(d))
+            })
+            .map(|(path, entry)| (entry.metadata.last_access(), path.clone(), entry.size()))
+            .collect();
+        candidates.sort();
+
+        let mut freed = 0;
+        for (_, path, size) in candidates {
+            if freed >= needed {
+                break;
+            }
+            self.files.remove(&path);
+            self.total_size -= size;
+            self.evicted_files += 1;
+            self.evicted_bytes += size;
+            freed += size;
+            tracing::debug!(path = %path.display(), size, "VFS: evicted least recently used file");
+        }
+        freed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reject_quota() {
+        let mut vfs = VirtualFileSystem::new(1024);
+        vfs.set_quota("/pages", 10, EvictionPolicy::Reject);
+
+        vfs.write_file("/pages/a/index.html", b"12345678").unwrap();
+        let err = vfs.write_file("/pages/b/index.html", b"123").unwrap_err();
+        assert!(err.to_string().contains("quota exceeded for /pages"));
+
+        // Replacing a file only counts the difference
+        vfs.write_file("/pages/a/index.html", b"1234567890")
+            .unwrap();
+        // Other directories are unaffected
+        vfs.write_file("/results/big.json", &[0; 100]).unwrap();
+        assert_eq!(vfs.total_size(), 110);
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let mut vfs = VirtualFileSystem::new(1024);
+        vfs.set_quota("/results", 10, EvictionPolicy::Lru);
+
+        vfs.write_file("/results/a.json", b"aaaa").unwrap();
+        vfs.write_file("/results/b.json", b"bbbb").unwrap();
+        vfs.read_file("/results/a.json").unwrap();
+
+        // b was used least recently, so it goes first
+        vfs.write_file("/results/c.json", b"cccc").unwrap();
+        assert!(vfs.exists("/results/a.json"));
+        assert!(!vfs.exists("/results/b.json"));
+        assert_eq!(vfs.total_size(), 8);
+
+        // Larger than the quota itself: nothing is evicted
+        assert!(vfs.write_file("/results/d.json", &[0; 11]).is_err());
+        assert!(vfs.exists("/results/a.json"));
+
+        let stats = vfs.vfs_stats();
+        assert_eq!(stats.evicted_files, 1);
+        assert_eq!(stats.evicted_bytes, 4);
+    }
+
+    #[test]
+    fn test_lru_eviction_for_global_limit() {
+        let mut vfs = VirtualFileSystem::new(20);
+        vfs.set_quota("/results", 20, EvictionPolicy::Lru);
+
+        vfs.write_file("/results/a.json", &[0; 12]).unwrap();
+        vfs.write_file("/final/report.md", &[0; 8]).unwrap();
+        assert_eq!(vfs.total_size(), 20);
+
+        // Only /results is evictable
+        vfs.write_file("/workspace/notes.txt", &[0; 5]).unwrap();
+        assert!(!vfs.exists("/results/a.json"));
+        assert!(vfs.exists("/final/report.md"));
+        assert!(vfs.write_file("/workspace/more.txt", &[0; 10]).is_err());
+    }
+
+    #[test]
+    fn test_vfs_stats() {
+        let mut vfs = VirtualFileSystem::with_default_size();
+        vfs.write_file("/results/ec2.json", &[0; 300]).unwrap();
+        vfs.write_file("/results/s3/buckets.json", &[0; 200])
+            .unwrap();
+        vfs.write_file("/pages/report/index.html", &[0; 100])
+            .unwrap();
+
+        let stats = vfs.vfs_stats();
+        assert_eq!(stats.total_size, 600);
+        assert_eq!(stats.file_count, 3);
+        assert_eq!(stats.directories.len(), 6);
+        assert_eq!(stats.directories[0].directory, "/results");
+        assert_eq!(stats.directories[0].size, 500);
+        assert_eq!(stats.directories[0].file_count, 2);
+        assert_eq!(
+            stats.directories[0].quota.as_ref().map(|q| q.eviction),
+            Some(EvictionPolicy::Lru)
+        );
+        assert_eq!(stats.directories[1].directory, "/pages");
+        assert!(stats.directories[2].quota.is_none());
+        assert_eq!(stats.largest_files[0].path, "/results/ec2.json");
+    }
+}
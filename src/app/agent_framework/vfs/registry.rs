@@ -89,6 +89,14 @@ pub fn vfs_exists(vfs_id: &str) -> bool {
     registry.contains_key(vfs_id)
 }
 
+/// List the IDs of all registered VFS instances
+pub fn list_vfs_ids() -> Vec<String> {
+    let registry = VFS_REGISTRY.read().expect("VFS registry poisoned");
+    let mut ids: Vec<String> = registry.keys().cloned().collect();
+    ids.sort();
+    ids
+}
+
 /// Get the number of registered VFS instances (for debugging)
 #[allow(dead_code)]
 pub fn registry_size() -> usize {
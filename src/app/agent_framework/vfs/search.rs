@@ -0,0 +1,372 @@
+//! VFS Search
+//!
+//! [`VirtualFileSystem::find`] matches paths against a glob and
+//! [`VirtualFileSystem::grep`] searches file contents with a regex, so agents
+//! can locate earlier results without listing every directory.
+//!
+//! Globs support `*` (within one path segment), `**` (across segments), `?`,
+//! `[abc]` / `[!abc]` and `{a,b}`. A glob without `/` matches file names in
+//! any directory; one with `/` matches the whole path from the root.
+//! [`VirtualFileSystem::search`] combines the two for the `search_files` tool
+//! and the `vfsSearch()` V8 binding.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use serde::Serialize;
+use std::path::Path;
+
+use super::VirtualFileSystem;
+
+/// Longest line returned by [`VirtualFileSystem::grep`]; longer lines (such as
+/// minified JSON) are cut down to the text around the first match
+pub const MAX_LINE_LENGTH: usize = 200;
+
+/// A path matched by [`VirtualFileSystem::find`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VfsFindMatch {
+    pub path: String,
+    pub is_directory: bool,
+    pub size: usize,
+}
+
+/// A line matched by [`VirtualFileSystem::grep`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VfsGrepMatch {
+    pub path: String,
+    /// 1-based
+    pub line_number: usize,
+    pub line: String,
+}
+
+/// Result of [`VirtualFileSystem::search`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum VfsSearchMatches {
+    Files(Vec<VfsFindMatch>),
+    Lines(Vec<VfsGrepMatch>),
+}
+
+impl VfsSearchMatches {
+    pub fn len(&self) -> usize {
+        match self {
+            VfsSearchMatches::Files(files) => files.len(),
+            VfsSearchMatches::Lines(lines) => lines.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Keep only the first `max` matches
+    pub fn truncate(&mut self, max: usize) {
+        match self {
+            VfsSearchMatches::Files(files) => files.truncate(max),
+            VfsSearchMatches::Lines(lines) => lines.truncate(max),
+        }
+    }
+}
+
+impl VirtualFileSystem {
+    /// Grep when `pattern` is given, limited to files matching `glob`;
+    /// otherwise find by `glob` (everything when None). Either way only paths
+    /// under `path_prefix` are returned.
+    pub fn search(
+        &self,
+        glob: Option<&str>,
+        pattern: Option<&str>,
+        path_prefix: &str,
+    ) -> Result<VfsSearchMatches> {
+        match pattern {
+            Some(pattern) => {
+                let glob = glob.map(compile_glob).transpose()?;
+                let mut lines = self.grep(pattern, path_prefix)?;
+                if let Some(glob) = glob {
+                    lines.retain(|m| glob.is_match(&m.path));
+                }
+                Ok(VfsSearchMatches::Lines(lines))
+            }
+            None => {
+                let prefix = Self::normalize_path(path_prefix);
+                let mut files = self.find(glob.unwrap_or("**"))?;
+                files.retain(|m| {
+                    let path = Path::new(&m.path);
+                    path != prefix && path.starts_with(&prefix)
+                });
+                Ok(VfsSearchMatches::Files(files))
+            }
+        }
+    }
+
+    /// Files and directories whose path matches `glob`, sorted by path
+    pub fn find(&self, glob: &str) -> Result<Vec<VfsFindMatch>> {
+        let regex = compile_glob(glob)?;
+
+        let mut matches: Vec<VfsFindMatch> = self
+            .files
+            .iter()
+            .map(|(path, entry)| (path.display().to_string(), entry))
+            .filter(|(path, _)| regex.is_match(path))
+            .map(|(path, entry)| VfsFindMatch {
+                path,
+                is_directory: entry.is_directory(),
+                size: entry.size(),
+            })
+            .collect();
+        matches.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(matches)
+    }
+
+    /// Lines matching the regex `pattern` in text files under `path_prefix`
+    ///
+    /// Files are searched in path order; files that are not UTF-8 are
+    /// skipped. Use `"/"` or `""` to search everything.
+    pub fn grep(&self, pattern: &str, path_prefix: &str) -> Result<Vec<VfsGrepMatch>> {
+        let regex =
+            Regex::new(pattern).with_context(|| format!("Invalid search pattern: {}", pattern))?;
+        let prefix = Self::normalize_path(path_prefix);
+
+        let mut files: Vec<_> = self
+            .files
+            .iter()
+            .filter(|(path, entry)| entry.is_file() && path.starts_with(&prefix))
+            .collect();
+        files.sort_by(|a, b| a.0.cmp(b.0));
+
+        let mut matches = Vec::new();
+        for (path, entry) in files {
+            let Ok(text) = std::str::from_utf8(&entry.content) else {
+                continue; // Binary content
+            };
+            matches.extend(grep_text(&path.display().to_string(), text, &regex));
+        }
+        Ok(matches)
+    }
+}
+
+/// Lines of `text` matching `regex`, reported against `path`
+pub fn grep_text(path: &str, text: &str, regex: &Regex) -> Vec<VfsGrepMatch> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| regex.is_match(line))
+        .map(|(index, line)| VfsGrepMatch {
+            path: path.to_string(),
+            line_number: index + 1,
+            line: excerpt(line, regex),
+        })
+        .collect()
+}
+
+/// Translate a glob into an anchored regex over absolute paths
+pub fn compile_glob(glob: &str) -> Result<Regex> {
+    let glob = glob.trim();
+    if glob.is_empty() {
+        bail!("Empty glob pattern");
+    }
+
+    let mut source = String::from("^");
+    if !glob.contains('/') {
+        source.push_str("(?:.*/)?");
+    } else if !glob.starts_with('/') {
+        source.push('/');
+    }
+
+    let mut chars = glob.chars().peekable();
+    let mut in_braces = false;
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    source.push_str("(?:.*/)?");
+                } else {
+                    source.push_str(".*");
+                }
+            }
+            '*' => source.push_str("[^/]*"),
+            '?' => source.push_str("[^/]"),
+            '[' => {
+                source.push('[');
+                if chars.peek() == Some(&'!') {
+                    chars.next();
+                    source.push('^');
+                }
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        closed = true;
+                        break;
+                    }
+                    if matches!(c, '\\' | '[' | '^' | '&' | '~') {
+                        source.push('\\');
+                    }
+                    source.push(c);
+                }
+                if !closed {
+                    bail!("Unclosed '[' in glob: {}", glob);
+                }
+                source.push(']');
+            }
+            '{' if !in_braces => {
+                in_braces = true;
+                source.push_str("(?:");
+            }
+            '}' if in_braces => {
+                in_braces = false;
+                source.push(')');
+            }
+            ',' if in_braces => source.push('|'),
+            c => source.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    if in_braces {
+        bail!("Unclosed '{{' in glob: {}", glob);
+    }
+    source.push('$');
+
+    Regex::new(&source).with_context(|| format!("Invalid glob pattern: {}", glob))
+}
+
+/// The line, or the part of it around the first match when it is too long
+fn excerpt(line: &str, regex: &Regex) -> String {
+    if line.len() <= MAX_LINE_LENGTH {
+        return line.to_string();
+    }
+
+    let match_start = regex.find(line).map_or(0, |m| m.start());
+    let mut start = match_start.saturating_sub(MAX_LINE_LENGTH / 4);
+    while !line.is_char_boundary(start) {
+        start -= 1;
+    }
+    let mut end = (start + MAX_LINE_LENGTH).min(line.len());
+    while !line.is_char_boundary(end) {
+        end -= 1;
+    }
+
+    format!(
+        "{}{}{}",
+        if start > 0 { "..." } else { "" },
+        &line[start..end],
+        if end < line.len() { "..." } else { "" }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_vfs() -> VirtualFileSystem {
+        let mut vfs = VirtualFileSystem::new(1024 * 1024);
+        vfs.write_file("/results/ec2_instances.json", b"[{\"state\": \"running\"}]")
+            .unwrap();
+        vfs.write_file("/results/s3/buckets.json", b"[\n\"logs\",\n\"backups\"\n]")
+            .unwrap();
+        vfs.write_file(
+            "/workspace/task-1/findings.md",
+            b"# Findings\nbackups are public",
+        )
+        .unwrap();
+        vfs.write_file("/pages/report/app.js", b"const DATA = [];")
+            .unwrap();
+        vfs
+    }
+
+    fn paths(matches: Vec<VfsFindMatch>) -> Vec<String> {
+        matches.into_iter().map(|m| m.path).collect()
+    }
+
+    #[test]
+    fn test_find() {
+        let vfs = test_vfs();
+
+        assert_eq!(
+            paths(vfs.find("*.json").unwrap()),
+            vec!["/results/ec2_instances.json", "/results/s3/buckets.json"]
+        );
+        assert_eq!(
+            paths(vfs.find("/results/*.json").unwrap()),
+            vec!["/results/ec2_instances.json"]
+        );
+        assert_eq!(
+            paths(vfs.find("results/**/*.json").unwrap()),
+            vec!["/results/ec2_instances.json", "/results/s3/buckets.json"]
+        );
+        assert_eq!(
+            paths(vfs.find("*.{md,js}").unwrap()),
+            vec!["/pages/report/app.js", "/workspace/task-1/findings.md"]
+        );
+        assert_eq!(
+            paths(vfs.find("/workspace/task-?").unwrap()),
+            vec!["/workspace/task-1"]
+        );
+        assert!(vfs.find("/workspace/task-1").unwrap()[0].is_directory);
+        assert!(vfs.find("[!a-z]*.json").unwrap().is_empty());
+        assert!(vfs.find("").is_err());
+        assert!(vfs.find("*.{md").is_err());
+    }
+
+    #[test]
+    fn test_grep() {
+        let vfs = test_vfs();
+
+        let matches = vfs.grep("backups", "/").unwrap();
+        assert_eq!(
+            matches,
+            vec![
+                VfsGrepMatch {
+                    path: "/results/s3/buckets.json".to_string(),
+                    line_number: 3,
+                    line: "\"backups\"".to_string(),
+                },
+                VfsGrepMatch {
+                    path: "/workspace/task-1/findings.md".to_string(),
+                    line_number: 2,
+                    line: "backups are public".to_string(),
+                },
+            ]
+        );
+        assert_eq!(vfs.grep("backups", "/results").unwrap().len(), 1);
+        assert_eq!(vfs.grep("(?i)RUNNING", "").unwrap().len(), 1);
+        assert!(vfs.grep("(", "/").is_err());
+    }
+
+    #[test]
+    fn test_search() {
+        let vfs = test_vfs();
+
+        let matches = vfs.search(Some("*.md"), Some("backups"), "/").unwrap();
+        assert_eq!(matches.len(), 1);
+        assert!(matches!(&matches, VfsSearchMatches::Lines(lines) if lines[0].line_number == 2));
+
+        let mut matches = vfs.search(None, None, "/results").unwrap();
+        assert_eq!(matches.len(), 3); // Two files and the s3 directory
+        matches.truncate(1);
+        assert_eq!(
+            matches,
+            VfsSearchMatches::Files(vec![VfsFindMatch {
+                path: "/results/ec2_instances.json".to_string(),
+                is_directory: false,
+                size: 22,
+            }])
+        );
+    }
+
+    #[test]
+    fn test_grep_long_line_excerpt() {
+        let mut vfs = VirtualFileSystem::new(1024 * 1024);
+        let line = format!("{}needle{}", "a".repeat(500), "é".repeat(500));
+        vfs.write_file("/results/minified.json", line.as_bytes())
+            .unwrap();
+
+        let matches = vfs.grep("needle", "/results").unwrap();
+        let excerpt = &matches[0].line;
+        assert!(excerpt.starts_with("...") && excerpt.ends_with("..."));
+        assert!(excerpt.contains("needle"));
+        assert!(excerpt.len() <= MAX_LINE_LENGTH + 6);
+    }
+}
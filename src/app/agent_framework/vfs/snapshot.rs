@@ -0,0 +1,322 @@
+//! VFS Snapshots
+//!
+//! Saves a [`VirtualFileSystem`] to disk so a workspace outlives the agent
+//! session that built it. Snapshots are keyed by agent session ID:
+//!
+//! ```text
+//! ~/.local/share/awsdash/vfs_snapshots/
+//!   3f1c2b9e-....vfs
+//! ```
+//!
+//! A file holds a small JSON header ([`VfsSnapshotInfo`]) followed by the
+//! zstd-compressed entries, so listing snapshots never decompresses them.
+//! Timestamps inside the VFS are not kept; restored entries start fresh.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::quota::default_quotas;
+use super::{register_vfs, vfs_exists, VfsEntry, VirtualFileSystem, DEFAULT_MAX_SIZE};
+
+/// File extension for snapshot files
+const SNAPSHOT_FILE_EXTENSION: &str = "vfs";
+
+/// Magic bytes identifying a snapshot file (and its format version)
+const MAGIC: &[u8; 4] = b"AVS1";
+
+/// zstd compression level
+const COMPRESSION_LEVEL: i32 = 9;
+
+/// VFS IDs of reopened snapshots, by session ID
+static REOPENED: Lazy<Mutex<HashMap<String, String>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Metadata stored at the start of each snapshot file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VfsSnapshotInfo {
+    /// Agent session the VFS belonged to
+    pub session_id: String,
+    /// Agent name when the snapshot was saved
+    pub label: String,
+    pub saved_at: DateTime<Utc>,
+    pub file_count: usize,
+    /// Size of all file contents before compression
+    pub total_size: usize,
+    /// Page IDs under `/pages/`
+    pub pages: Vec<String>,
+}
+
+/// One file or directory as stored in a snapshot
+#[derive(Debug, Serialize, Deserialize)]
+struct SnapshotEntry {
+    path: String,
+    is_directory: bool,
+    content: Vec<u8>,
+}
+
+/// On-disk store of VFS snapshots
+#[derive(Debug, Clone)]
+pub struct VfsSnapshotStore {
+    directory: PathBuf,
+}
+
+impl VfsSnapshotStore {
+    /// Create a store rooted at `directory`
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Store in the application data directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(
+            dirs::data_local_dir()?.join("awsdash/vfs_snapshots"),
+        ))
+    }
+
+    /// Directory holding snapshot files
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Whether a snapshot exists for the session
+    pub fn exists(&self, session_id: &str) -> bool {
+        self.path_for(session_id).is_ok_and(|path| path.exists())
+    }
+
+    /// Save `vfs` for the session, replacing any earlier snapshot
+    ///
+    /// Writes to a temporary file first so a crash never leaves a torn file.
+    pub fn save(
+        &self,
+        session_id: &str,
+        label: &str,
+        vfs: &VirtualFileSystem,
+    ) -> Result<VfsSnapshotInfo> {
+        let path = self.path_for(session_id)?;
+        fs::create_dir_all(&self.directory).with_context(|| {
+            format!(
+                "Failed to create snapshot directory {}",
+                self.directory.display()
+            )
+        })?;
+
+        let mut entries: Vec<SnapshotEntry> = vfs
+            .files
+            .iter()
+            .map(|(path, entry)| SnapshotEntry {
+                path: path.display().to_string(),
+                is_directory: entry.is_directory(),
+                content: entry.content.clone(),
+            })
+            .collect();
+        entries.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let info = VfsSnapshotInfo {
+            session_id: session_id.to_string(),
+            label: label.to_string(),
+            saved_at: Utc::now(),
+            file_count: entries.iter().filter(|e| !e.is_directory).count(),
+            total_size: vfs.total_size(),
+            pages: page_ids(&entries),
+        };
+
+        let encoded = bincode::serialize(&entries).context("Failed to encode VFS snapshot")?;
+        let compressed = zstd::encode_all(&encoded[..], COMPRESSION_LEVEL)
+            .context("Failed to compress VFS snapshot")?;
+        let header = serde_json::to_vec(&info)?;
+
+        let mut tmp_file = tempfile::NamedTempFile::new_in(&self.directory)
+            .context("Failed to create temporary snapshot file")?;
+        tmp_file.write_all(MAGIC)?;
+        tmp_file.write_all(&(header.len() as u32).to_le_bytes())?;
+        tmp_file.write_all(&header)?;
+        tmp_file.write_all(&compressed)?;
+        tmp_file
+            .persist(&path)
+            .with_context(|| format!("Failed to move snapshot into {}", path.display()))?;
+
+        tracing::info!(
+            session_id = %session_id,
+            files = info.file_count,
+            size = info.total_size,
+            "VFS: saved snapshot"
+        );
+        Ok(info)
+    }
+
+    /// Restore the VFS saved for the session
+    ///
+    /// The size limit is the default, or the snapshot's size if larger, with
+    /// the default directory quotas.
+    pub fn load(&self, session_id: &str) -> Result<VirtualFileSystem> {
+        let path = self.path_for(session_id)?;
+        let bytes = fs::read(&path)
+            .with_context(|| format!("No VFS snapshot for session {}", session_id))?;
+        let (info, body) = split_header(&bytes)?;
+
+        let encoded = zstd::decode_all(body).context("Failed to decompress VFS snapshot")?;
+        let entries: Vec<SnapshotEntry> =
+            bincode::deserialize(&encoded).context("Failed to decode VFS snapshot")?;
+
+        let mut vfs = VirtualFileSystem::new(DEFAULT_MAX_SIZE.max(info.total_size));
+        vfs.quotas = default_quotas();
+        for entry in entries {
+            let path = VirtualFileSystem::normalize_path(&entry.path);
+            if entry.is_directory {
+                vfs.files.insert(path, VfsEntry::new_directory());
+            } else {
+                vfs.total_size += entry.content.len();
+                vfs.files.insert(path, VfsEntry::new_file(entry.content));
+            }
+        }
+        Ok(vfs)
+    }
+
+    /// All snapshots, newest first; unreadable files are skipped
+    pub fn list(&self) -> Vec<VfsSnapshotInfo> {
+        let Ok(entries) = fs::read_dir(&self.directory) else {
+            return Vec::new(); // Nothing saved yet
+        };
+
+        let mut snapshots: Vec<VfsSnapshotInfo> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension().and_then(|e| e.to_str()) == Some(SNAPSHOT_FILE_EXTENSION)
+            })
+            .filter_map(|path| match read_info(&path) {
+                Ok(info) => Some(info),
+                Err(e) => {
+                    tracing::warn!("Skipping unreadable VFS snapshot {}: {}", path.display(), e);
+                    None
+                }
+            })
+            .collect();
+        snapshots.sort_by(|a, b| b.saved_at.cmp(&a.saved_at));
+        snapshots
+    }
+
+    /// Delete the snapshot for the session
+    pub fn remove(&self, session_id: &str) -> Result<()> {
+        let path = self.path_for(session_id)?;
+        fs::remove_file(&path)
+            .with_context(|| format!("Failed to delete VFS snapshot {}", path.display()))
+    }
+
+    fn path_for(&self, session_id: &str) -> Result<PathBuf> {
+        // Session IDs are UUIDs; anything else could escape the directory
+        if session_id.is_empty()
+            || !session_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            bail!("Invalid session ID: {}", session_id);
+        }
+        Ok(self
+            .directory
+            .join(format!("{}.{}", session_id, SNAPSHOT_FILE_EXTENSION)))
+    }
+}
+
+/// Restore a saved workspace and register it as a VFS
+///
+/// Returns the VFS ID. Reopening the same session again returns the VFS
+/// already registered for it while that is still alive. The snapshot stays
+/// on disk.
+pub fn reopen_snapshot(session_id: &str) -> Result<String> {
+    let mut reopened = REOPENED.lock().expect("VFS snapshot registry poisoned");
+    if let Some(vfs_id) = reopened.get(session_id).filter(|id| vfs_exists(id)) {
+        return Ok(vfs_id.clone());
+    }
+
+    let store =
+        VfsSnapshotStore::default_location().context("Local data directory is unavailable")?;
+    let vfs_id = register_vfs(store.load(session_id)?);
+    reopened.insert(session_id.to_string(), vfs_id.clone());
+    Ok(vfs_id)
+}
+
+fn page_ids(entries: &[SnapshotEntry]) -> Vec<String> {
+    entries
+        .iter()
+        .filter(|e| e.is_directory)
+        .filter_map(|e| e.path.strip_prefix("/pages/"))
+        .filter(|rest| !rest.is_empty() && !rest.contains('/'))
+        .map(String::from)
+        .collect()
+}
+
+fn split_header(bytes: &[u8]) -> Result<(VfsSnapshotInfo, &[u8])> {
+    if bytes.len() < 8 || &bytes[..4] != MAGIC {
+        bail!("Not a VFS snapshot file");
+    }
+    let header_len = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+    let body_start = 8 + header_len;
+    if bytes.len() < body_start {
+        bail!("Truncated VFS snapshot header");
+    }
+    let info =
+        serde_json::from_slice(&bytes[8..body_start]).context("Invalid VFS snapshot header")?;
+    Ok((info, &bytes[body_start..]))
+}
+
+/// Read only the header of a snapshot file
+fn read_info(path: &Path) -> Result<VfsSnapshotInfo> {
+    let mut file = fs::File::open(path)?;
+    let mut prefix = [0u8; 8];
+    file.read_exact(&mut prefix)?;
+    let header_len = u32::from_le_bytes([prefix[4], prefix[5], prefix[6], prefix[7]]) as usize;
+    let mut bytes = prefix.to_vec();
+    bytes.resize(8 + header_len, 0);
+    file.read_exact(&mut bytes[8..])?;
+    split_header(&bytes).map(|(info, _)| info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = VfsSnapshotStore::new(dir.path().to_path_buf());
+
+        let mut vfs = VirtualFileSystem::new(1024 * 1024);
+        vfs.write_file("/results/ec2.json", b"[{\"id\": \"i-1\"}]")
+            .unwrap();
+        vfs.write_file("/pages/cost-report/index.html", b"<html>")
+            .unwrap();
+        vfs.mkdir("/workspace/task-1").unwrap();
+
+        let info = store.save("session-1", "Cost agent", &vfs).unwrap();
+        assert_eq!(info.file_count, 2);
+        assert_eq!(info.total_size, vfs.total_size());
+        assert_eq!(info.pages, vec!["cost-report".to_string()]);
+        assert!(store.exists("session-1"));
+        assert_eq!(store.list(), vec![info]);
+
+        let restored = store.load("session-1").unwrap();
+        assert_eq!(
+            restored.read_file("/results/ec2.json").unwrap(),
+            b"[{\"id\": \"i-1\"}]"
+        );
+        assert!(restored.is_directory("/workspace/task-1"));
+        assert!(restored.is_directory("/scripts"));
+        assert_eq!(restored.total_size(), vfs.total_size());
+        assert_eq!(restored.max_size(), DEFAULT_MAX_SIZE);
+
+        store.remove("session-1").unwrap();
+        assert!(store.list().is_empty());
+        assert!(store.load("session-1").is_err());
+        assert!(store.save("../escape", "x", &vfs).is_err());
+    }
+}
@@ -1,10 +1,13 @@
 //! Worker agent management
 //!
-//! This module handles worker completion tracking and progress reporting.
+//! This module handles worker completion tracking, progress reporting, and
+//! the worker pool that limits how many workers run at once.
 
 pub mod completion;
+pub mod pool;
 pub mod progress;
 
 // Re-export commonly used items
 pub use completion::*;
+pub use pool::*;
 pub use progress::*;
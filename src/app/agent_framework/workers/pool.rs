@@ -0,0 +1,427 @@
+//! Worker Pool - Concurrency limits for worker agents
+//!
+//! Each TaskManager has a worker pool that limits how many of its workers
+//! run at once. The start_task and start_tasks tools take a slot from the
+//! pool before requesting a worker and give it back when the worker
+//! finishes.
+//!
+//! ## Fairness
+//!
+//! - Slots are handed out first come, first served (tickets in a FIFO queue)
+//! - Cancelling the manager conversation fails every queued task at once;
+//!   running workers stop through their cancellation tokens
+//! - The pool reports per-task state so the UI can show progress for the
+//!   whole batch (see [`WorkerPoolStatus`])
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use crate::app::agent_framework::AgentId;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// Default number of workers a manager can run at once
+pub const DEFAULT_MAX_CONCURRENT_WORKERS: usize = 4;
+
+/// Upper bound for the max concurrency setting
+pub const MAX_CONCURRENT_WORKERS_LIMIT: usize = 16;
+
+/// How often queued tasks re-check the limit (it can change while they wait)
+const QUEUE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+static MAX_CONCURRENT_WORKERS: AtomicUsize = AtomicUsize::new(DEFAULT_MAX_CONCURRENT_WORKERS);
+
+/// Worker pools keyed by manager agent ID
+static WORKER_POOLS: once_cell::sync::Lazy<Mutex<HashMap<AgentId, Arc<WorkerPool>>>> =
+    once_cell::sync::Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Set how many workers each manager can run at once (1-16)
+pub fn set_max_concurrent_workers(max: usize) {
+    let max = max.clamp(1, MAX_CONCURRENT_WORKERS_LIMIT);
+    MAX_CONCURRENT_WORKERS.store(max, Ordering::SeqCst);
+}
+
+/// How many workers each manager can run at once
+pub fn max_concurrent_workers() -> usize {
+    MAX_CONCURRENT_WORKERS.load(Ordering::SeqCst)
+}
+
+/// Get (or create) the worker pool of a manager agent
+pub fn worker_pool(manager_id: AgentId) -> Arc<WorkerPool> {
+    let mut pools = WORKER_POOLS.lock().unwrap();
+    Arc::clone(
+        pools
+            .entry(manager_id)
+            .or_insert_with(|| Arc::new(WorkerPool::new())),
+    )
+}
+
+/// Status of a manager's worker pool (None if it never started a worker)
+pub fn worker_pool_status(manager_id: AgentId) -> Option<WorkerPoolStatus> {
+    let pool = WORKER_POOLS.lock().unwrap().get(&manager_id).cloned();
+    pool.map(|pool| pool.status())
+}
+
+/// Fail all queued tasks of a manager (called when its conversation is cancelled)
+pub fn cancel_worker_pool(manager_id: AgentId) {
+    let pool = WORKER_POOLS.lock().unwrap().get(&manager_id).cloned();
+    if let Some(pool) = pool {
+        pool.cancel();
+    }
+}
+
+/// Drop a manager's worker pool (called when the manager is terminated)
+pub fn remove_worker_pool(manager_id: AgentId) {
+    if let Some(pool) = WORKER_POOLS.lock().unwrap().remove(&manager_id) {
+        pool.cancel();
+    }
+}
+
+/// State of a task in the pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolTaskState {
+    /// Waiting for a free slot
+    Queued,
+    /// Worker is running
+    Running,
+    Completed,
+    Failed,
+    /// Cancelled with the manager conversation
+    Cancelled,
+}
+
+impl PoolTaskState {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PoolTaskState::Queued => "Queued",
+            PoolTaskState::Running => "Running",
+            PoolTaskState::Completed => "Done",
+            PoolTaskState::Failed => "Failed",
+            PoolTaskState::Cancelled => "Cancelled",
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        !matches!(self, PoolTaskState::Queued | PoolTaskState::Running)
+    }
+}
+
+/// A task in the current batch, for progress display
+#[derive(Debug, Clone)]
+pub struct PoolTaskInfo {
+    /// Short description given to start_task
+    pub description: String,
+    pub state: PoolTaskState,
+    /// Worker running the task (None until the worker is created)
+    pub worker_id: Option<AgentId>,
+    /// Time queued, running, or taken, depending on the state
+    pub elapsed: Duration,
+}
+
+/// Snapshot of a worker pool for the UI
+#[derive(Debug, Clone, Default)]
+pub struct WorkerPoolStatus {
+    pub max_concurrent: usize,
+    pub queued: usize,
+    pub running: usize,
+    pub completed: usize,
+    pub failed: usize,
+    pub cancelled: usize,
+    /// Tasks of the current batch, in the order they were queued
+    pub tasks: Vec<PoolTaskInfo>,
+}
+
+impl WorkerPoolStatus {
+    /// True while any task is queued or running
+    pub fn is_active(&self) -> bool {
+        self.queued + self.running > 0
+    }
+
+    /// Number of tasks in the current batch
+    pub fn total(&self) -> usize {
+        self.tasks.len()
+    }
+}
+
+#[derive(Debug)]
+struct PoolTask {
+    ticket: u64,
+    description: String,
+    state: PoolTaskState,
+    worker_id: Option<AgentId>,
+    queued_at: Instant,
+    started_at: Option<Instant>,
+    finished_at: Option<Instant>,
+}
+
+impl PoolTask {
+    fn info(&self, now: Instant) -> PoolTaskInfo {
+        let elapsed = match (self.started_at, self.finished_at) {
+            (Some(start), Some(end)) => end.duration_since(start),
+            (Some(start), None) => now.duration_since(start),
+            (None, Some(end)) => end.duration_since(self.queued_at),
+            (None, None) => now.duration_since(self.queued_at),
+        };
+        PoolTaskInfo {
+            description: self.description.clone(),
+            state: self.state,
+            worker_id: self.worker_id,
+            elapsed,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct PoolState {
+    next_ticket: u64,
+    /// Tickets waiting for a slot, oldest first
+    queue: VecDeque<u64>,
+    running: usize,
+    /// Bumped on cancel; tickets queued before it are failed
+    generation: u64,
+    /// Tasks of the current batch (cleared when a task is queued on an idle pool)
+    tasks: Vec<PoolTask>,
+}
+
+impl PoolState {
+    fn task_mut(&mut self, ticket: u64) -> Option<&mut PoolTask> {
+        self.tasks.iter_mut().find(|t| t.ticket == ticket)
+    }
+}
+
+/// Limits how many workers of one manager run at once
+#[derive(Debug, Default)]
+pub struct WorkerPool {
+    state: Mutex<PoolState>,
+    changed: Condvar,
+}
+
+impl WorkerPool {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait for a free slot, in the order tasks were queued
+    ///
+    /// Blocks the calling (tool) thread. Returns `Err` if the manager
+    /// conversation is cancelled while the task is queued.
+    pub fn acquire(self: &Arc<Self>, description: &str) -> Result<WorkerPermit, String> {
+        let mut state = self.state.lock().unwrap();
+        if state.running == 0 && state.queue.is_empty() {
+            // Idle pool: this is the start of a new batch
+            state.tasks.clear();
+        }
+        let ticket = state.next_ticket;
+        state.next_ticket += 1;
+        let generation = state.generation;
+        state.queue.push_back(ticket);
+        state.tasks.push(PoolTask {
+            ticket,
+            description: description.to_string(),
+            state: PoolTaskState::Queued,
+            worker_id: None,
+            queued_at: Instant::now(),
+            started_at: None,
+            finished_at: None,
+        });
+
+        loop {
+            if state.generation != generation {
+                // cancel() already removed the ticket and marked the task
+                return Err("Task cancelled before it started".to_string());
+            }
+            if state.queue.front() == Some(&ticket) && state.running < max_concurrent_workers() {
+                break;
+            }
+            state = self
+                .changed
+                .wait_timeout(state, QUEUE_POLL_INTERVAL)
+                .unwrap()
+                .0;
+        }
+
+        state.queue.pop_front();
+        state.running += 1;
+        if let Some(task) = state.task_mut(ticket) {
+            task.state = PoolTaskState::Running;
+            task.started_at = Some(Instant::now());
+        }
+        // The next ticket may fit as well
+        self.changed.notify_all();
+
+        tracing::debug!(
+            target: "agent::worker_pool",
+            ticket,
+            running = state.running,
+            queued = state.queue.len(),
+            "Worker slot acquired"
+        );
+
+        Ok(WorkerPermit {
+            pool: Arc::clone(self),
+            ticket,
+            finished: false,
+        })
+    }
+
+    /// Fail all queued tasks; running workers are stopped by their tokens
+    pub fn cancel(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.generation += 1;
+        let queued: Vec<u64> = state.queue.drain(..).collect();
+        let now = Instant::now();
+        for ticket in &queued {
+            if let Some(task) = state.task_mut(*ticket) {
+                task.state = PoolTaskState::Cancelled;
+                task.finished_at = Some(now);
+            }
+        }
+        tracing::info!(
+            target: "agent::worker_pool",
+            cancelled = queued.len(),
+            running = state.running,
+            "Worker pool cancelled"
+        );
+        self.changed.notify_all();
+    }
+
+    /// Current counts and per-task state
+    pub fn status(&self) -> WorkerPoolStatus {
+        let state = self.state.lock().unwrap();
+        let now = Instant::now();
+        let mut status = WorkerPoolStatus {
+            max_concurrent: max_concurrent_workers(),
+            ..Default::default()
+        };
+        for task in &state.tasks {
+            match task.state {
+                PoolTaskState::Queued => status.queued += 1,
+                PoolTaskState::Running => status.running += 1,
+                PoolTaskState::Completed => status.completed += 1,
+                PoolTaskState::Failed => status.failed += 1,
+                PoolTaskState::Cancelled => status.cancelled += 1,
+            }
+            status.tasks.push(task.info(now));
+        }
+        status
+    }
+
+    fn release(&self, ticket: u64, outcome: PoolTaskState) {
+        let mut state = self.state.lock().unwrap();
+        state.running = state.running.saturating_sub(1);
+        if let Some(task) = state.task_mut(ticket) {
+            task.state = outcome;
+            task.finished_at = Some(Instant::now());
+        }
+        self.changed.notify_all();
+    }
+}
+
+/// A running slot in a [`WorkerPool`]; the slot is freed when dropped
+///
+/// Dropping the permit without calling [`WorkerPermit::finish`] counts the
+/// task as failed.
+#[derive(Debug)]
+pub struct WorkerPermit {
+    pool: Arc<WorkerPool>,
+    ticket: u64,
+    finished: bool,
+}
+
+impl WorkerPermit {
+    /// Record which worker runs this task
+    pub fn set_worker(&self, worker_id: AgentId) {
+        let mut state = self.pool.state.lock().unwrap();
+        if let Some(task) = state.task_mut(self.ticket) {
+            task.worker_id = Some(worker_id);
+        }
+    }
+
+    /// Free the slot and record the outcome
+    pub fn finish(mut self, success: bool) {
+        self.finished = true;
+        let outcome = if success {
+            PoolTaskState::Completed
+        } else {
+            PoolTaskState::Failed
+        };
+        self.pool.release(self.ticket, outcome);
+    }
+}
+
+impl Drop for WorkerPermit {
+    fn drop(&mut self) {
+        if !self.finished {
+            self.pool.release(self.ticket, PoolTaskState::Failed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_pool_limits_and_orders_tasks() {
+        let pool = Arc::new(WorkerPool::new());
+        let first = pool.acquire("first").unwrap();
+        let second = pool.acquire("second").unwrap();
+        assert_eq!(pool.status().running, 2);
+
+        first.finish(true);
+        second.finish(false);
+        let status = pool.status();
+        assert_eq!(status.completed, 1);
+        assert_eq!(status.failed, 1);
+        assert!(!status.is_active());
+
+        // A new batch on an idle pool starts with a clean task list
+        let third = pool.acquire("third").unwrap();
+        assert_eq!(pool.status().total(), 1);
+        drop(third);
+        assert_eq!(pool.status().failed, 1);
+    }
+
+    #[test]
+    fn test_pool_waits_for_free_slot() {
+        let pool = Arc::new(WorkerPool::new());
+        let permits: Vec<_> = (0..max_concurrent_workers())
+            .map(|i| pool.acquire(&format!("task {}", i)).unwrap())
+            .collect();
+
+        let waiting = {
+            let pool = Arc::clone(&pool);
+            thread::spawn(move || pool.acquire("queued").map(|p| p.finish(true)))
+        };
+        thread::sleep(Duration::from_millis(100));
+        assert_eq!(pool.status().queued, 1);
+
+        drop(permits);
+        assert!(waiting.join().unwrap().is_ok());
+    }
+
+    #[test]
+    fn test_cancel_fails_queued_tasks() {
+        let pool = Arc::new(WorkerPool::new());
+        let permits: Vec<_> = (0..max_concurrent_workers())
+            .map(|i| pool.acquire(&format!("task {}", i)).unwrap())
+            .collect();
+
+        let waiting: Vec<_> = (0..3)
+            .map(|_| {
+                let pool = Arc::clone(&pool);
+                thread::spawn(move || pool.acquire("queued").map(|_| ()))
+            })
+            .collect();
+        thread::sleep(Duration::from_millis(100));
+
+        pool.cancel();
+        for handle in waiting {
+            assert!(handle.join().unwrap().is_err());
+        }
+        assert_eq!(pool.status().cancelled, 3);
+        drop(permits);
+    }
+}
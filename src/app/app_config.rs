@@ -0,0 +1,434 @@
+//! Application settings
+//!
+//! [`AppConfig`] gathers the settings that used to be spread across
+//! hard-coded constants and per-window state: theme, log levels, cache
+//! lifetimes, concurrency limits, default regions, notification webhook
+//! defaults, and agent defaults. It is saved as TOML in
+//! `{config_dir}/awsdash/config.toml` and edited in the Settings window.
+//!
+//! The settings in effect are kept in a global (see [`app_config`]) that
+//! subsystems read when they start work. [`set_app_config`] also pushes the
+//! settings that can change while running, like log levels and concurrency
+//! limits, into their subsystems; the others apply to caches, clients, and
+//! windows created afterwards.
+
+use crate::app::agent_framework::{
+    set_max_concurrent_workers, StoodLogLevel, DEFAULT_MAX_CONCURRENT_WORKERS,
+};
+use crate::app::aws_regions::partition_for_region;
+use crate::app::dashui::app::ThemeChoice;
+use crate::app::notifications::sinks::Severity;
+use crate::app::resource_explorer::adaptive_concurrency::{
+    set_max_service_concurrency, DEFAULT_SERVICE_CONCURRENCY,
+};
+use crate::app::resource_explorer::disk_cache::DEFAULT_DISK_TTL_SECS;
+use crate::app::resource_explorer::tag_cache::TagCache;
+use anyhow::{Context, Result};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+/// Level of a group of log targets
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    pub const ALL: [LogLevel; 6] = [
+        LogLevel::Off,
+        LogLevel::Error,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Debug,
+        LogLevel::Trace,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            LogLevel::Off => "Off",
+            LogLevel::Error => "Error",
+            LogLevel::Warn => "Warn",
+            LogLevel::Info => "Info",
+            LogLevel::Debug => "Debug",
+            LogLevel::Trace => "Trace",
+        }
+    }
+
+    /// Level as written at the start of a log line, e.g. `INFO`
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_uppercase().as_str() {
+            "ERROR" | "ERRO" => Some(LogLevel::Error),
+            "WARN" | "WARNING" => Some(LogLevel::Warn),
+            "INFO" => Some(LogLevel::Info),
+            "DEBUG" | "DEBG" => Some(LogLevel::Debug),
+            "TRACE" | "TRCE" => Some(LogLevel::Trace),
+            _ => None,
+        }
+    }
+
+    /// Level as written in a tracing filter directive
+    pub fn directive(&self) -> &'static str {
+        match self {
+            LogLevel::Off => "off",
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+            LogLevel::Trace => "trace",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppearanceSettings {
+    pub theme: ThemeChoice,
+}
+
+/// Levels of the global log file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggingSettings {
+    /// AWS Dash itself
+    pub app: LogLevel,
+    /// AWS SDK service clients (CloudFormation, Bedrock)
+    pub aws_sdk: LogLevel,
+    /// AWS config, signing, and HTTP plumbing
+    pub aws_runtime: LogLevel,
+    /// egui, the renderer, and windowing
+    pub gui: LogLevel,
+}
+
+impl Default for LoggingSettings {
+    fn default() -> Self {
+        Self {
+            app: LogLevel::Info,
+            aws_sdk: LogLevel::Info,
+            aws_runtime: LogLevel::Warn,
+            gui: LogLevel::Warn,
+        }
+    }
+}
+
+impl LoggingSettings {
+    /// Tracing filter for the global log file, with `stood` events at `stood`
+    pub fn filter_directive(&self, stood: StoodLogLevel) -> String {
+        let aws_sdk = self.aws_sdk.directive();
+        let aws_runtime = self.aws_runtime.directive();
+        let gui = self.gui.directive();
+        format!(
+            "awsdash={},stood={},eframe=info,egui={gui},glow={gui},glutin={gui},winit={gui},\
+             aws_sdk_cloudformation={aws_sdk},aws_sdk_bedrockruntime={aws_sdk},\
+             aws_config={aws_runtime},aws_sigv4={aws_runtime},aws_smithy_runtime={aws_runtime},\
+             aws_smithy_runtime_api={aws_runtime},hyper={aws_runtime},aws_smithy_http={aws_runtime},\
+             aws_endpoint={aws_runtime}",
+            self.app.directive(),
+            stood.to_filter_str()
+        )
+    }
+}
+
+/// Lifetimes of cached AWS data
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CacheSettings {
+    /// Resources unused this long are dropped from memory
+    pub memory_idle_minutes: u64,
+    /// Query results older than this are not restored from disk
+    pub disk_ttl_hours: u64,
+    /// Tags fetched for resources are reused this long
+    pub tag_ttl_minutes: i64,
+}
+
+impl Default for CacheSettings {
+    fn default() -> Self {
+        Self {
+            memory_idle_minutes: 30,
+            disk_ttl_hours: DEFAULT_DISK_TTL_SECS / (60 * 60),
+            tag_ttl_minutes: TagCache::DEFAULT_TTL_MINUTES,
+        }
+    }
+}
+
+/// Limits on parallel AWS calls
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConcurrencySettings {
+    /// Explorer list calls running at once across all services
+    pub explorer_queries: usize,
+    /// List calls running at once per service, before throttling lowers it
+    pub per_service: usize,
+}
+
+impl Default for ConcurrencySettings {
+    fn default() -> Self {
+        Self {
+            explorer_queries: 20,
+            per_service: DEFAULT_SERVICE_CONCURRENCY,
+        }
+    }
+}
+
+/// Regions offered when choosing what to query
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RegionSettings {
+    /// Commercial regions listed by default; empty uses the built-in list
+    pub default_regions: Vec<String>,
+}
+
+/// Defaults for notification webhooks
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NotificationSettings {
+    /// Threshold of newly added webhooks
+    pub new_webhook_severity: Severity,
+    /// How long a webhook may take to answer
+    pub delivery_timeout_secs: u64,
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            new_webhook_severity: Severity::Error,
+            delivery_timeout_secs: 10,
+        }
+    }
+}
+
+/// Defaults for new agents
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentSettings {
+    /// Stood events captured in the logs of new agents
+    pub log_level: StoodLogLevel,
+    /// Workers each task manager runs at once
+    pub max_workers: usize,
+    /// Stream task manager responses into the chat as they are generated
+    pub stream_responses: bool,
+}
+
+impl Default for AgentSettings {
+    fn default() -> Self {
+        Self {
+            log_level: StoodLogLevel::default(),
+            max_workers: DEFAULT_MAX_CONCURRENT_WORKERS,
+            stream_responses: false,
+        }
+    }
+}
+
+/// Every setting of the app
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+    pub appearance: AppearanceSettings,
+    pub logging: LoggingSettings,
+    pub cache: CacheSettings,
+    pub concurrency: ConcurrencySettings,
+    pub regions: RegionSettings,
+    pub notifications: NotificationSettings,
+    pub agents: AgentSettings,
+}
+
+impl AppConfig {
+    /// Problems that keep the settings from being saved
+    pub fn validation_errors(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        for region in &self.regions.default_regions {
+            if !partition_for_region(region)
+                .regions()
+                .contains(&region.as_str())
+            {
+                errors.push(format!("Unknown region '{}'", region));
+            }
+        }
+        if self.cache.memory_idle_minutes == 0 {
+            errors.push("Memory cache idle time must be at least a minute".to_string());
+        }
+        if self.cache.tag_ttl_minutes <= 0 {
+            errors.push("Tag cache lifetime must be at least a minute".to_string());
+        }
+        if self.concurrency.explorer_queries == 0 || self.concurrency.per_service == 0 {
+            errors.push("Concurrency limits must be at least 1".to_string());
+        }
+        if self.notifications.delivery_timeout_secs == 0 {
+            errors.push("Webhook timeout must be at least a second".to_string());
+        }
+        errors
+    }
+}
+
+/// Settings file in the config directory
+#[derive(Debug, Clone)]
+pub struct AppConfigStore {
+    path: PathBuf,
+}
+
+impl AppConfigStore {
+    /// Store backed by the TOML file at `path`
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Store in the application config directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(dirs::config_dir()?.join("awsdash/config.toml")))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The saved settings, or None when nothing was saved yet
+    pub fn load(&self) -> Result<Option<AppConfig>> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read settings {}", self.path.display()))?;
+        let config = toml::from_str(&content)
+            .with_context(|| format!("Invalid settings in {}", self.path.display()))?;
+        Ok(Some(config))
+    }
+
+    pub fn save(&self, config: &AppConfig) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create config directory {}", parent.display())
+            })?;
+        }
+        let content = toml::to_string_pretty(config).context("Failed to serialize settings")?;
+        fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write settings {}", self.path.display()))
+    }
+}
+
+/// Settings from the default store; None when nothing was saved yet
+pub fn load_app_config() -> Result<Option<AppConfig>> {
+    match AppConfigStore::default_location() {
+        Some(store) => store.load(),
+        None => Ok(None),
+    }
+}
+
+/// Save `config` to the default store and put it into effect
+pub fn save_app_config(config: AppConfig) -> Result<()> {
+    if let Some(store) = AppConfigStore::default_location() {
+        store.save(&config)?;
+    }
+    set_app_config(config);
+    Ok(())
+}
+
+static APP_CONFIG: Lazy<RwLock<AppConfig>> = Lazy::new(|| RwLock::new(AppConfig::default()));
+
+/// Level of `stood` events in the global filter
+///
+/// Stays at trace unless changed, so per-agent logs get every agent event;
+/// their own level is chosen per agent.
+static STOOD_FILTER_LEVEL: RwLock<StoodLogLevel> = RwLock::new(StoodLogLevel::Trace);
+
+/// Settings in effect
+pub fn app_config() -> AppConfig {
+    APP_CONFIG
+        .read()
+        .map(|config| config.clone())
+        .unwrap_or_default()
+}
+
+/// Put `config` into effect
+pub fn set_app_config(config: AppConfig) {
+    set_max_service_concurrency(config.concurrency.per_service);
+    set_max_concurrent_workers(config.agents.max_workers);
+    if let Ok(mut current) = APP_CONFIG.write() {
+        *current = config;
+    }
+    crate::set_log_filter(&log_filter_directive());
+}
+
+/// Tracing filter for the global log file: the Settings log levels and the
+/// `stood` level
+pub fn log_filter_directive() -> String {
+    let stood = STOOD_FILTER_LEVEL
+        .read()
+        .map(|level| *level)
+        .unwrap_or(StoodLogLevel::Trace);
+    app_config().logging.filter_directive(stood)
+}
+
+/// Change the level of `stood` events in the global filter, keeping the
+/// Settings log levels
+pub fn set_stood_filter_level(level: StoodLogLevel) {
+    if let Ok(mut current) = STOOD_FILTER_LEVEL.write() {
+        *current = level;
+    }
+    crate::set_log_filter(&log_filter_directive());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_log_filter_matches_previous_levels() {
+        assert_eq!(
+            LoggingSettings::default().filter_directive(StoodLogLevel::Trace),
+            "awsdash=info,stood=trace,eframe=info,egui=warn,glow=warn,glutin=warn,winit=warn,\
+             aws_sdk_cloudformation=info,aws_sdk_bedrockruntime=info,aws_config=warn,\
+             aws_sigv4=warn,aws_smithy_runtime=warn,aws_smithy_runtime_api=warn,hyper=warn,\
+             aws_smithy_http=warn,aws_endpoint=warn"
+        );
+        let mut logging = LoggingSettings::default();
+        logging.app = LogLevel::Debug;
+        logging.gui = LogLevel::Error;
+        let directive = logging.filter_directive(StoodLogLevel::Info);
+        assert!(directive.starts_with("awsdash=debug,stood=info,"));
+        assert!(directive.contains("winit=error"));
+    }
+
+    #[test]
+    fn test_partial_file_keeps_defaults_for_missing_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = AppConfigStore::new(dir.path().join("config.toml"));
+        assert_eq!(store.load().unwrap(), None);
+
+        std::fs::write(
+            store.path(),
+            "[appearance]\ntheme = \"Mocha\"\n\n[regions]\ndefault_regions = [\"eu-west-1\"]\n",
+        )
+        .unwrap();
+        let config = store.load().unwrap().unwrap();
+        assert_eq!(config.appearance.theme, ThemeChoice::Mocha);
+        assert_eq!(config.regions.default_regions, vec!["eu-west-1"]);
+        assert_eq!(config.cache, CacheSettings::default());
+        assert_eq!(config.agents, AgentSettings::default());
+
+        let mut changed = config.clone();
+        changed.logging.aws_sdk = LogLevel::Trace;
+        changed.notifications.new_webhook_severity = Severity::Warning;
+        store.save(&changed).unwrap();
+        assert_eq!(store.load().unwrap(), Some(changed));
+    }
+
+    #[test]
+    fn test_validation_rejects_unknown_regions_and_zero_limits() {
+        assert!(AppConfig::default().validation_errors().is_empty());
+
+        let mut config = AppConfig::default();
+        config.regions.default_regions = vec!["us-east-1".to_string(), "us-middle-9".to_string()];
+        config.concurrency.per_service = 0;
+        let errors = config.validation_errors();
+        assert_eq!(errors.len(), 2);
+        assert!(errors[0].contains("us-middle-9"));
+    }
+}
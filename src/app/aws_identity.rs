@@ -94,12 +94,23 @@
 //! The module provides comprehensive error handling for common scenarios:
 //! - Network connectivity issues during authentication
 //! - Token expiration and automatic renewal requirements
+//!
+//! ## Session Refresh
+//!
+//! The OIDC client is registered for the `refresh_token` grant, so the access
+//! token can be renewed without a new browser login. [`spawn_session_refresher`]
+//! renews it in the background shortly before it expires, for up to
+//! `keep_alive_hours` after login. [`AwsIdentityCenter::session_health`] tells the
+//! top bar how long the session has left and when to ask for a new login.
 //! - Invalid or insufficient IAM permissions
 //! - AWS service-specific error codes and user-friendly messages
 //!
 //! All operations return `Result<T, String>` with descriptive error messages suitable
 //! for display to end users while maintaining security by not exposing sensitive details.
 
+use crate::app::aws_regions::Partition;
+use crate::app::credential_sources::{role_name_from_arn, CredentialSource, ResolvedCredentials};
+use crate::app::cross_account_roles::{CrossAccountRoles, CrossAccountRoute, MEMBER_ACCOUNT_NAME};
 use aws_config::BehaviorVersion;
 use aws_sdk_iam::error::ProvideErrorMetadata;
 use aws_sdk_iam::Client as IamClient;
@@ -114,7 +125,7 @@ use percent_encoding::{percent_decode, utf8_percent_encode, NON_ALPHANUMERIC};
 use regex::Regex;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::sync::mpsc;
+use std::sync::{mpsc, Arc, Mutex};
 use std::thread;
 use tokio::runtime::Runtime;
 use tracing::{error, info, warn};
@@ -413,6 +424,59 @@ pub enum LoginState {
     Error(String),
 }
 
+/// Minutes before expiry at which the access token is refreshed, by default
+const DEFAULT_REFRESH_LEAD_MINUTES: i64 = 10;
+
+/// Hours a session is kept alive by background refreshes, by default
+pub const DEFAULT_KEEP_ALIVE_HOURS: i64 = 12;
+
+/// Remaining token time below which the top bar asks for a new login
+const REAUTH_WARNING_MINUTES: i64 = 15;
+
+/// How often the session refresher checks the access token
+const SESSION_CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_secs(30);
+
+fn default_refresh_lead_minutes() -> i64 {
+    DEFAULT_REFRESH_LEAD_MINUTES
+}
+
+fn default_keep_alive_hours() -> i64 {
+    DEFAULT_KEEP_ALIVE_HOURS
+}
+
+/// How long the Identity Center session has left, for the top bar indicator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionHealth {
+    /// The access token is valid and will be refreshed before it expires.
+    Active { remaining: Duration },
+
+    /// The access token expires soon and cannot be refreshed; log in again.
+    ExpiringSoon { remaining: Duration },
+
+    /// The access token has expired; AWS calls fail until the next login.
+    Expired,
+}
+
+/// What the session refresher needs to renew the access token.
+///
+/// Copied out of [`AwsIdentityCenter`] so the network call runs without
+/// holding its lock.
+#[derive(Clone)]
+pub struct TokenRefreshRequest {
+    region: String,
+    client_id: String,
+    client_secret: String,
+    refresh_token: String,
+}
+
+/// A renewed access token.
+pub struct RefreshedToken {
+    access_token: String,
+    /// Replacement refresh token, when AWS rotates it
+    refresh_token: Option<String>,
+    expiration: DateTime<Utc>,
+}
+
 /// Central coordinator for AWS Identity Center authentication and multi-account access.
 ///
 /// This is the primary interface for all AWS authentication operations. It manages
@@ -580,6 +644,39 @@ pub struct AwsIdentityCenter {
     /// and credentials before they expire to ensure uninterrupted service.
     pub auto_refresh: bool,
 
+    /// Minutes before expiry at which the access token is refreshed.
+    #[serde(default = "default_refresh_lead_minutes")]
+    pub refresh_lead_minutes: i64,
+
+    /// Longest time background refreshes keep a session alive, in hours.
+    ///
+    /// Once the session is this old, refreshes stop and the top bar asks for a
+    /// new login. `0` keeps the session alive until Identity Center ends it.
+    #[serde(default = "default_keep_alive_hours")]
+    pub keep_alive_hours: i64,
+
+    /// OIDC refresh token for renewing the access token without a new login.
+    ///
+    /// **Security Critical**: Grants new access tokens for the rest of the
+    /// Identity Center session. Excluded from serialization and cleared on logout.
+    #[serde(skip_serializing)]
+    pub refresh_token: Option<String>,
+
+    /// When the user last completed the browser login.
+    ///
+    /// Start of the keep-alive window. Excluded from serialization as it's
+    /// session-specific.
+    #[serde(skip)]
+    pub session_started: Option<DateTime<Utc>>,
+
+    /// Error of the last failed background refresh, cleared by the next success.
+    #[serde(skip)]
+    pub last_refresh_error: Option<String>,
+
+    /// Whether a session refresher thread is running for this instance.
+    #[serde(skip)]
+    session_refresher_running: bool,
+
     /// Unique client name for OIDC registration.
     ///
     /// Generated UUID-based name used for temporary client registration with
@@ -639,6 +736,21 @@ pub struct AwsIdentityCenter {
     /// This role is separate from both the user-provided default role and the AWSReservedSSO role.
     #[serde(skip)]
     pub cloudformation_deployment_role_name: Option<String>,
+
+    /// Profile or access keys the session signs in with instead of Identity Center.
+    ///
+    /// When set, credentials for the session's single account come from this
+    /// source and the device authorization flow is not used. Excluded from
+    /// serialization as it may hold access keys.
+    #[serde(skip)]
+    pub credential_source: Option<CredentialSource>,
+
+    /// Roles assumed from the hub account to reach member accounts.
+    ///
+    /// Set with [`Self::set_cross_account_roles`]; the `CredentialCoordinator`
+    /// consults it through [`Self::cross_account_route`].
+    #[serde(default)]
+    pub cross_account_roles: CrossAccountRoles,
 }
 
 impl AwsIdentityCenter {
@@ -699,6 +811,12 @@ impl AwsIdentityCenter {
             available_roles: HashMap::new(),
             list_all_accounts: true,
             auto_refresh: true,
+            refresh_lead_minutes: DEFAULT_REFRESH_LEAD_MINUTES,
+            keep_alive_hours: DEFAULT_KEEP_ALIVE_HOURS,
+            refresh_token: None,
+            session_started: None,
+            last_refresh_error: None,
+            session_refresher_running: false,
             client_name: format!("awsdash-{}", uuid::Uuid::new_v4()),
             token_expiration: None,
             default_role_credentials: None,
@@ -707,9 +825,153 @@ impl AwsIdentityCenter {
             infrastructure_info: None,
             home_dash_account: None,
             cloudformation_deployment_role_name: None,
+            credential_source: None,
+            cross_account_roles: CrossAccountRoles::default(),
+        }
+    }
+
+    /// Creates a session that signs in with a profile or access keys.
+    ///
+    /// The source's region is used for STS and Bedrock, and the role it acts as
+    /// becomes the default role name. Call [`Self::sign_in_with_credential_source`]
+    /// to resolve the credentials.
+    pub fn with_credential_source(source: CredentialSource) -> Self {
+        let mut identity_center =
+            Self::new(String::new(), source.role_name(), source.region.clone());
+        identity_center.auto_refresh = false;
+        identity_center.credential_source = Some(source);
+        identity_center
+    }
+
+    /// Whether this session signs in with a profile or access keys.
+    pub fn uses_credential_source(&self) -> bool {
+        self.credential_source.is_some()
+    }
+
+    /// AWS partition of the session, derived from its region.
+    pub fn partition(&self) -> Partition {
+        Partition::from_region(&self.identity_center_region)
+    }
+
+    /// Resolve the credential source and mark the session as logged in.
+    ///
+    /// Blocks on an isolated Tokio runtime; the account the credentials belong
+    /// to becomes the only account of the session.
+    pub fn sign_in_with_credential_source(&mut self) -> Result<(), String> {
+        let source = self
+            .credential_source
+            .clone()
+            .ok_or_else(|| "No credential source configured".to_string())?;
+        info!("Signing in with {}", source.label());
+
+        let runtime =
+            Runtime::new().map_err(|e| format!("Failed to create Tokio runtime: {}", e))?;
+        let resolved = runtime
+            .block_on(source.resolve())
+            .map_err(|e| format!("{:#}", e))?;
+
+        info!(
+            "Signed in to account {} as {}",
+            resolved.account_id, resolved.arn
+        );
+        self.apply_resolved_credentials(&source, resolved);
+        Ok(())
+    }
+
+    /// Reach member accounts through the hub account with `roles`.
+    ///
+    /// Listed member accounts that Identity Center does not list are added to
+    /// `accounts`, so the Explorer and agents can select them; members dropped
+    /// from the list are removed again.
+    pub fn set_cross_account_roles(&mut self, roles: CrossAccountRoles) {
+        let previous = std::mem::replace(&mut self.cross_account_roles, roles);
+        let dropped: Vec<String> = previous
+            .member_accounts
+            .into_keys()
+            .filter(|account_id| {
+                !self
+                    .cross_account_roles
+                    .member_accounts
+                    .contains_key(account_id)
+            })
+            .collect();
+        self.accounts.retain(|account| {
+            account.account_name != MEMBER_ACCOUNT_NAME || !dropped.contains(&account.account_id)
+        });
+        self.available_roles.retain(|account_id, _| {
+            !dropped.contains(account_id)
+                || self.accounts.iter().any(|a| &a.account_id == account_id)
+        });
+        self.add_member_accounts();
+    }
+
+    /// Add listed member accounts that are not in `accounts` yet.
+    fn add_member_accounts(&mut self) {
+        for account_id in self.cross_account_roles.member_accounts.keys() {
+            if self.accounts.iter().any(|a| &a.account_id == account_id) {
+                continue;
+            }
+            let role_arn = self.cross_account_roles.role_arn(account_id);
+            let role_name = role_name_from_arn(&role_arn)
+                .unwrap_or(&role_arn)
+                .to_string();
+            self.accounts.push(AwsAccount {
+                account_id: account_id.clone(),
+                account_name: MEMBER_ACCOUNT_NAME.to_string(),
+                account_email: None,
+                role_name: role_name.clone(),
+                credentials: None,
+            });
+            self.available_roles
+                .insert(account_id.clone(), vec![role_name]);
         }
     }
 
+    /// How to reach `account_id` through the hub account, if not directly.
+    ///
+    /// The hub defaults to the account of the default role credentials.
+    pub fn cross_account_route(&self, account_id: &str) -> Option<CrossAccountRoute> {
+        let directly_accessible = self
+            .available_roles
+            .get(account_id)
+            .is_some_and(|roles| roles.contains(&self.default_role_name));
+        self.cross_account_roles.route(
+            account_id,
+            self.default_role_account_id.as_deref(),
+            directly_accessible,
+        )
+    }
+
+    /// Store resolved source credentials as the session's account and default role.
+    fn apply_resolved_credentials(
+        &mut self,
+        source: &CredentialSource,
+        resolved: ResolvedCredentials,
+    ) {
+        let role_name = source.role_name();
+        let account_id = resolved.account_id;
+
+        self.update_account(AwsAccount {
+            account_id: account_id.clone(),
+            account_name: source.label(),
+            account_email: None,
+            role_name: role_name.clone(),
+            credentials: Some(resolved.credentials.clone()),
+        });
+        self.accounts
+            .retain(|account| account.account_id == account_id);
+        self.available_roles = HashMap::from([(account_id.clone(), vec![role_name.clone()])]);
+        self.add_member_accounts();
+        self.default_role_name = role_name;
+        self.default_role_credentials = Some(resolved.credentials);
+        self.default_role_account_id = Some(account_id);
+        self.last_refresh = Some(Utc::now());
+        if self.session_started.is_none() {
+            self.session_started = self.last_refresh;
+        }
+        self.login_state = LoginState::LoggedIn;
+    }
+
     /// Identify the SSO management account using AWS Organizations API
     fn identify_sso_management_account(&mut self) -> Result<(), String> {
         info!("Attempting to identify SSO management account");
@@ -825,9 +1087,13 @@ impl AwsIdentityCenter {
         self.available_roles.clear();
         self.last_refresh = None;
         self.token_expiration = None;
+        self.refresh_token = None;
+        self.session_started = None;
+        self.last_refresh_error = None;
         self.default_role_credentials = None;
         self.default_role_account_id = None;
         self.sso_management_account_id = None;
+        self.credential_source = None;
     }
 
     /// Initialize the AWS Identity Center for authentication operations.
@@ -868,11 +1134,127 @@ impl AwsIdentityCenter {
         self.access_token = None;
         self.client_id = None;
         self.client_secret = None;
+        self.refresh_token = None;
         self.default_role_credentials = None;
         self.default_role_account_id = None;
         Ok(())
     }
 
+    /// Whether background refreshes may still renew the access token.
+    ///
+    /// Requires auto-refresh, a refresh token from the login, and a session
+    /// younger than `keep_alive_hours`.
+    fn can_refresh_token(&self, now: DateTime<Utc>) -> bool {
+        let within_keep_alive = match self.session_started {
+            Some(_) if self.keep_alive_hours <= 0 => true,
+            Some(started) => now < started + Duration::hours(self.keep_alive_hours),
+            None => false,
+        };
+        self.auto_refresh
+            && within_keep_alive
+            && self.refresh_token.is_some()
+            && self.client_id.is_some()
+            && self.client_secret.is_some()
+    }
+
+    /// Check whether the access token is due for a background refresh.
+    pub fn needs_token_refresh(&self, now: DateTime<Utc>) -> bool {
+        let Some(expiration) = self.token_expiration else {
+            return false;
+        };
+        self.login_state == LoginState::LoggedIn
+            && self.can_refresh_token(now)
+            && expiration - now < Duration::minutes(self.refresh_lead_minutes)
+    }
+
+    /// How long the session has left, or `None` when not logged in.
+    ///
+    /// A token that will be refreshed counts as active however close its expiry
+    /// is; one that cannot be refreshed turns into a prompt to log in again
+    /// [`REAUTH_WARNING_MINUTES`] before it expires. A failed refresh counts as
+    /// not refreshable until a later attempt succeeds.
+    pub fn session_health(&self, now: DateTime<Utc>) -> Option<SessionHealth> {
+        if self.login_state != LoginState::LoggedIn {
+            return None;
+        }
+        let remaining = self.token_expiration? - now;
+
+        if remaining <= Duration::zero() {
+            Some(SessionHealth::Expired)
+        } else if remaining < Duration::minutes(REAUTH_WARNING_MINUTES)
+            && (self.last_refresh_error.is_some() || !self.can_refresh_token(now))
+        {
+            Some(SessionHealth::ExpiringSoon { remaining })
+        } else {
+            Some(SessionHealth::Active { remaining })
+        }
+    }
+
+    /// Copy out what a token refresh needs, if the session can be refreshed.
+    pub fn token_refresh_request(&self) -> Option<TokenRefreshRequest> {
+        Some(TokenRefreshRequest {
+            region: self.identity_center_region.clone(),
+            client_id: self.client_id.clone()?,
+            client_secret: self.client_secret.clone()?,
+            refresh_token: self.refresh_token.clone()?,
+        })
+    }
+
+    /// Exchange the refresh token for a new access token.
+    ///
+    /// Blocks on an isolated Tokio runtime; call it without holding the
+    /// identity center lock.
+    pub fn refresh_access_token(request: &TokenRefreshRequest) -> Result<RefreshedToken, String> {
+        let runtime =
+            Runtime::new().map_err(|e| format!("Failed to create Tokio runtime: {}", e))?;
+
+        runtime.block_on(async {
+            let config = aws_config::defaults(BehaviorVersion::latest())
+                .region(Region::new(request.region.clone()))
+                .load()
+                .await;
+            let sso_oidc_client = SsoOidcClient::new(&config);
+
+            let token_resp = sso_oidc_client
+                .create_token()
+                .client_id(&request.client_id)
+                .client_secret(&request.client_secret)
+                .refresh_token(&request.refresh_token)
+                .grant_type("refresh_token")
+                .send()
+                .await
+                .map_err(|e| format!("Failed to refresh access token: {}", e))?;
+
+            let access_token = token_resp
+                .access_token
+                .ok_or_else(|| "No access token in refresh response".to_string())?;
+
+            Ok(RefreshedToken {
+                access_token,
+                refresh_token: token_resp.refresh_token,
+                expiration: Utc::now() + Duration::seconds(token_resp.expires_in as i64),
+            })
+        })
+    }
+
+    /// Store a renewed access token.
+    ///
+    /// Account credentials obtained with the old token stay valid until their
+    /// own expiry and are renewed with the new token after that.
+    pub fn apply_refreshed_token(&mut self, token: RefreshedToken) {
+        info!(
+            "Identity Center access token refreshed, expires at {}",
+            token.expiration.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        self.access_token = Some(token.access_token);
+        if let Some(refresh_token) = token.refresh_token {
+            self.refresh_token = Some(refresh_token);
+        }
+        self.token_expiration = Some(token.expiration);
+        self.last_refresh = Some(Utc::now());
+        self.last_refresh_error = None;
+    }
+
     /// Obtain credentials for the default role with management account preference.
     ///
     /// Retrieves temporary credentials for the configured default role, preferentially
@@ -956,6 +1338,14 @@ impl AwsIdentityCenter {
 
         info!("Refreshing default role credentials");
 
+        if self.credential_source.is_some() {
+            self.sign_in_with_credential_source()?;
+            return self
+                .default_role_credentials
+                .clone()
+                .ok_or_else(|| "No credentials from credential source".to_string());
+        }
+
         // We need to find an account that has the default role
         // Look for accounts with the default role, prioritizing management/SSO accounts
         let accounts_with_role: Vec<_> = self
@@ -1072,6 +1462,22 @@ impl AwsIdentityCenter {
         self.default_role_account_id.clone()
     }
 
+    /// Account and region that agents call Bedrock in, without fetching
+    /// credentials.
+    ///
+    /// Follows the same Home Dash Account and fallback rules as
+    /// [`get_bedrock_credentials`](Self::get_bedrock_credentials). Returns
+    /// `None` before login.
+    pub fn bedrock_target(&self) -> Option<(String, String)> {
+        match &self.home_dash_account {
+            Some(home_dash) => Some((home_dash.account_id.clone(), home_dash.region.clone())),
+            None => self
+                .default_role_account_id
+                .clone()
+                .map(|account_id| (account_id, self.identity_center_region.clone())),
+        }
+    }
+
     /// Get credentials for Bedrock API calls from the Home Dash Account.
     ///
     /// Returns credentials from the account that owns the awsdash infrastructure
@@ -1241,10 +1647,15 @@ impl AwsIdentityCenter {
                 let sso_oidc_client = SsoOidcClient::new(&config);
 
                 // Register client with AWS SSO
+                // Registering for the refresh_token grant lets the session refresher
+                // renew the access token without another browser login
                 let register_resp = sso_oidc_client
                     .register_client()
                     .client_name(&self.client_name)
                     .client_type("public")
+                    .scopes("sso:account:access")
+                    .grant_types("urn:ietf:params:oauth:grant-type:device_code")
+                    .grant_types("refresh_token")
                     .send()
                     .await
                     .map_err(|e| format!("Failed to register client: {}", e))?;
@@ -1409,6 +1820,7 @@ impl AwsIdentityCenter {
         let device_code = device_auth_data.device_code.clone();
         let region = self.identity_center_region.clone();
         let default_role_name = self.default_role_name.clone();
+        let registered_client = (client_id.clone(), client_secret.clone());
 
         thread::spawn(move || {
             // Create a new Tokio runtime for this thread
@@ -1462,6 +1874,7 @@ impl AwsIdentityCenter {
                         Ok(token_resp) => {
                             // Got a token, now get account list with SSO client
                             let access_token = token_resp.access_token.unwrap_or_default();
+                            let refresh_token = token_resp.refresh_token;
                             // token_resp.expires_in returns i32
                             let expires_in = token_resp.expires_in;
                             let token_expiration =
@@ -1541,6 +1954,7 @@ impl AwsIdentityCenter {
 
                                     return Ok((
                                         access_token,
+                                        refresh_token,
                                         token_expiration,
                                         accounts,
                                         available_roles,
@@ -1572,13 +1986,26 @@ impl AwsIdentityCenter {
 
         // Wait for the authorization to complete (blocking)
         match rx.recv() {
-            Ok(Ok((access_token, token_expiration, accounts, available_roles))) => {
+            Ok(Ok((access_token, refresh_token, token_expiration, accounts, available_roles))) => {
                 // Update our state with the results
                 self.access_token = Some(access_token);
                 self.token_expiration = token_expiration;
                 self.last_refresh = Some(Utc::now());
+
+                // Keep the client registration for background token refreshes
+                if refresh_token.is_none() {
+                    warn!(
+                        "No refresh token issued; the session ends when the access token expires"
+                    );
+                }
+                self.refresh_token = refresh_token;
+                self.client_id = Some(registered_client.0);
+                self.client_secret = Some(registered_client.1);
+                self.session_started = Some(Utc::now());
+                self.last_refresh_error = None;
                 self.accounts = accounts;
                 self.available_roles = available_roles;
+                self.add_member_accounts();
 
                 // Don't set LoggedIn state here - let the caller set it after credentials are fetched
                 // This prevents race condition where state says "logged in" but credentials aren't ready
@@ -1687,6 +2114,14 @@ impl AwsIdentityCenter {
         account_id: &str,
         role_name: &str,
     ) -> Result<AwsCredentials, String> {
+        // Profile and access-key sessions reach only the account they signed in to
+        if self.credential_source.is_some() {
+            if self.default_role_account_id.as_deref() != Some(account_id) {
+                return Err(format!("Account {} not found", account_id));
+            }
+            return self.get_default_role_credentials();
+        }
+
         // Check if we have a valid access token
         let access_token = match &self.access_token {
             Some(token) => token.clone(),
@@ -1821,6 +2256,10 @@ impl AwsIdentityCenter {
     ) -> Result<crate::app::resource_explorer::credentials::AccountCredentials, anyhow::Error> {
         use crate::app::resource_explorer::credentials::AccountCredentials;
 
+        if let Some(source) = &self.credential_source {
+            return source.account_credentials(account_id).await;
+        }
+
         // Check if we have a valid access token
         let access_token = match &self.access_token {
             Some(token) => token.clone(),
@@ -2196,9 +2635,10 @@ impl AwsIdentityCenter {
         let session_data = session_json.to_string();
         let encoded_session_data = utf8_percent_encode(&session_data, NON_ALPHANUMERIC).to_string();
 
+        let signin_host = self.partition().signin_host();
         let console_url = format!(
-            "https://signin.aws.amazon.com/federation?Action=getSigninToken&Session={}",
-            encoded_session_data
+            "https://{}/federation?Action=getSigninToken&Session={}",
+            signin_host, encoded_session_data
         );
 
         let rt_start = std::time::Instant::now();
@@ -2237,7 +2677,8 @@ impl AwsIdentityCenter {
 
         match signin_result {
             Ok(signin_token) => Ok(format!(
-                "https://signin.aws.amazon.com/federation?Action=login&Issuer=&Destination={}&SigninToken={}",
+                "https://{}/federation?Action=login&Issuer=&Destination={}&SigninToken={}",
+                signin_host,
                 utf8_percent_encode(destination, NON_ALPHANUMERIC),
                 utf8_percent_encode(&signin_token, NON_ALPHANUMERIC)
             )),
@@ -2303,11 +2744,9 @@ impl AwsIdentityCenter {
     /// }
     /// ```
     pub fn open_aws_console(&mut self, account_id: &str, role_name: &str) -> Result<(), String> {
-        let console_signin_url = self.generate_console_signin_url(
-            account_id,
-            role_name,
-            "https://console.aws.amazon.com/",
-        )?;
+        let destination = format!("https://{}/", self.partition().console_host());
+        let console_signin_url =
+            self.generate_console_signin_url(account_id, role_name, &destination)?;
 
         if let Err(e) = open::that(&console_signin_url) {
             return Err(format!("Failed to open browser: {}", e));
@@ -2337,9 +2776,10 @@ impl AwsIdentityCenter {
         let session_data = session_json.to_string();
         let encoded_session_data = utf8_percent_encode(&session_data, NON_ALPHANUMERIC).to_string();
 
+        let signin_host = self.partition().signin_host();
         let console_url = format!(
-            "https://signin.aws.amazon.com/federation?Action=getSigninToken&Session={}",
-            encoded_session_data
+            "https://{}/federation?Action=getSigninToken&Session={}",
+            signin_host, encoded_session_data
         );
 
         let rt_start = std::time::Instant::now();
@@ -2379,8 +2819,8 @@ impl AwsIdentityCenter {
         let encoded_destination = utf8_percent_encode(destination, NON_ALPHANUMERIC).to_string();
 
         Ok(format!(
-            "https://signin.aws.amazon.com/federation?Action=login&Destination={}&SigninToken={}",
-            encoded_destination, signin_result
+            "https://{}/federation?Action=login&Destination={}&SigninToken={}",
+            signin_host, encoded_destination, signin_result
         ))
     }
 
@@ -2560,7 +3000,7 @@ impl AwsIdentityCenter {
 
             // Look for DynamoDB table ARN: arn:aws:dynamodb:REGION:ACCOUNT:table/TABLE_NAME
             let dynamodb_pattern = format!(
-                r"arn:aws:dynamodb:([a-z0-9-]+):(\d{{12}}):table/{}",
+                r"arn:aws(?:-us-gov|-cn)?:dynamodb:([a-z0-9-]+):(\d{{12}}):table/{}",
                 regex::escape(&table_pattern)
             );
             let dynamodb_re = Regex::new(&dynamodb_pattern)
@@ -3011,7 +3451,7 @@ impl AwsIdentityCenter {
             info!("Analyzing policy document for DynamoDB table and CloudFormation role ARNs");
 
             // Extract DynamoDB table ARN
-            let dynamodb_pattern = format!(r"arn:aws:dynamodb:.*:.*:table/{}", dynamodb_table_pattern);
+            let dynamodb_pattern = format!(r"arn:aws(?:-us-gov|-cn)?:dynamodb:.*:.*:table/{}", dynamodb_table_pattern);
             let dynamodb_re = Regex::new(&dynamodb_pattern)
                 .map_err(|e| format!("Invalid DynamoDB regex pattern: {}", e))?;
 
@@ -3036,7 +3476,7 @@ impl AwsIdentityCenter {
                 .to_string();
 
             // Extract CloudFormation role ARNs
-            let cf_role_pattern = r"arn:aws:iam::*:role/[a-zA-Z0-9\-_]+";
+            let cf_role_pattern = r"arn:aws(?:-us-gov|-cn)?:iam::*:role/[a-zA-Z0-9\-_]+";
             let cf_role_re = Regex::new(cf_role_pattern)
                 .map_err(|e| format!("Invalid CloudFormation role regex pattern: {}", e))?;
 
@@ -3078,6 +3518,66 @@ impl AwsIdentityCenter {
     }
 }
 
+/// Keep the session of `identity` alive in the background.
+///
+/// Checks the access token every [`SESSION_CHECK_INTERVAL`] and refreshes it
+/// when [`AwsIdentityCenter::needs_token_refresh`] says so. The lock is only held
+/// to read and store state, never during the network call. Stops on logout or
+/// when the identity center is dropped; a re-login keeps the running thread.
+pub fn spawn_session_refresher(identity: &Arc<Mutex<AwsIdentityCenter>>) {
+    {
+        let Ok(mut identity_center) = identity.lock() else {
+            return;
+        };
+        if identity_center.session_refresher_running {
+            return;
+        }
+        identity_center.session_refresher_running = true;
+    }
+
+    let identity = Arc::downgrade(identity);
+    thread::spawn(move || {
+        info!("Identity Center session refresher started");
+        loop {
+            thread::sleep(SESSION_CHECK_INTERVAL);
+
+            let Some(identity) = identity.upgrade() else {
+                break;
+            };
+
+            let request = {
+                let Ok(mut identity_center) = identity.lock() else {
+                    break;
+                };
+                if identity_center.login_state == LoginState::NotLoggedIn {
+                    identity_center.session_refresher_running = false;
+                    break;
+                }
+                if !identity_center.needs_token_refresh(Utc::now()) {
+                    continue;
+                }
+                identity_center.token_refresh_request()
+            };
+            let Some(request) = request else {
+                continue;
+            };
+
+            let result = AwsIdentityCenter::refresh_access_token(&request);
+
+            if let Ok(mut identity_center) = identity.lock() {
+                match result {
+                    Ok(token) => identity_center.apply_refreshed_token(token),
+                    Err(e) => {
+                        warn!("Identity Center token refresh failed: {}", e);
+                        identity_center.last_refresh_error = Some(e);
+                    }
+                }
+            }
+        }
+        info!("Identity Center session refresher stopped");
+    });
+}
+
 #[cfg(test)]
 mod tests {
     use std::fs;
@@ -3121,6 +3621,159 @@ mod tests {
         );
     }
 
+    fn logged_in_identity(now: chrono::DateTime<chrono::Utc>) -> super::AwsIdentityCenter {
+        let mut identity = super::AwsIdentityCenter::new(
+            "https://example.awsapps.com/start".to_string(),
+            "awsdash".to_string(),
+            "us-east-1".to_string(),
+        );
+        identity.login_state = super::LoginState::LoggedIn;
+        identity.access_token = Some("token".to_string());
+        identity.refresh_token = Some("refresh".to_string());
+        identity.client_id = Some("client".to_string());
+        identity.client_secret = Some("secret".to_string());
+        identity.session_started = Some(now);
+        identity.token_expiration = Some(now + chrono::Duration::hours(1));
+        identity
+    }
+
+    #[test]
+    fn test_token_refresh_due_before_expiry() {
+        use chrono::Duration;
+
+        let now = chrono::Utc::now();
+        let mut identity = logged_in_identity(now);
+        assert!(!identity.needs_token_refresh(now));
+        assert!(identity.needs_token_refresh(now + Duration::minutes(51)));
+
+        identity.auto_refresh = false;
+        assert!(!identity.needs_token_refresh(now + Duration::minutes(51)));
+
+        // Past the keep-alive window the session is left to expire
+        identity.auto_refresh = true;
+        identity.keep_alive_hours = 1;
+        identity.token_expiration = Some(now + Duration::hours(2));
+        assert!(!identity.needs_token_refresh(now + Duration::minutes(115)));
+        identity.keep_alive_hours = 0;
+        assert!(identity.needs_token_refresh(now + Duration::minutes(115)));
+
+        identity.refresh_token = None;
+        assert!(!identity.needs_token_refresh(now + Duration::minutes(115)));
+    }
+
+    #[test]
+    fn test_session_health_prompts_only_when_refresh_cannot_help() {
+        use super::SessionHealth;
+        use chrono::Duration;
+
+        let now = chrono::Utc::now();
+        let mut identity = logged_in_identity(now);
+        let soon = now + Duration::minutes(55);
+
+        assert!(matches!(
+            identity.session_health(soon),
+            Some(SessionHealth::Active { .. })
+        ));
+
+        identity.last_refresh_error = Some("network".to_string());
+        assert_eq!(
+            identity.session_health(soon),
+            Some(SessionHealth::ExpiringSoon {
+                remaining: Duration::minutes(5)
+            })
+        );
+        assert_eq!(
+            identity.session_health(now + Duration::hours(2)),
+            Some(SessionHealth::Expired)
+        );
+
+        identity.login_state = super::LoginState::NotLoggedIn;
+        assert_eq!(identity.session_health(soon), None);
+    }
+
+    #[test]
+    fn test_credential_source_session_has_single_account() {
+        use crate::app::credential_sources::{
+            BaseCredentials, CredentialSource, ResolvedCredentials,
+        };
+
+        let source = CredentialSource {
+            base: BaseCredentials::Profile("prod".to_string()),
+            assume_role_chain: Vec::new(),
+            region: "eu-west-1".to_string(),
+        };
+        let mut identity = super::AwsIdentityCenter::with_credential_source(source.clone());
+        assert!(identity.uses_credential_source());
+        assert_eq!(identity.identity_center_region, "eu-west-1");
+
+        identity.apply_resolved_credentials(
+            &source,
+            ResolvedCredentials {
+                credentials: super::AwsCredentials {
+                    access_key_id: "AKIA".to_string(),
+                    secret_access_key: "secret".to_string(),
+                    session_token: None,
+                    expiration: Some(chrono::Utc::now() + chrono::Duration::hours(1)),
+                },
+                account_id: "111111111111".to_string(),
+                arn: "arn:aws:iam::111111111111:user/dev".to_string(),
+            },
+        );
+
+        assert_eq!(identity.login_state, super::LoginState::LoggedIn);
+        assert_eq!(identity.accounts.len(), 1);
+        assert_eq!(identity.get_account_roles("111111111111"), vec!["prod"]);
+        assert!(identity.get_default_role_credentials().is_ok());
+        assert!(identity
+            .get_account_credentials("222222222222", "prod")
+            .is_err());
+
+        identity.logout();
+        assert!(!identity.uses_credential_source());
+    }
+
+    #[test]
+    fn test_cross_account_member_accounts() {
+        use crate::app::cross_account_roles::{parse_member_accounts, CrossAccountRoles};
+
+        let mut identity = logged_in_identity(chrono::Utc::now());
+        identity.accounts.push(super::AwsAccount {
+            account_id: "111111111111".to_string(),
+            account_name: "Management".to_string(),
+            account_email: None,
+            role_name: "awsdash".to_string(),
+            credentials: None,
+        });
+        identity
+            .available_roles
+            .insert("111111111111".to_string(), vec!["awsdash".to_string()]);
+        identity.default_role_account_id = Some("111111111111".to_string());
+
+        let roles = CrossAccountRoles {
+            role_pattern: "OrganizationAccountAccessRole".to_string(),
+            member_accounts: parse_member_accounts("222222222222\n333333333333 Audit").unwrap(),
+            ..Default::default()
+        };
+        identity.set_cross_account_roles(roles.clone());
+        assert_eq!(identity.accounts.len(), 3);
+        assert_eq!(identity.get_account_roles("333333333333"), vec!["Audit"]);
+        assert_eq!(identity.cross_account_route("111111111111"), None);
+        assert_eq!(
+            identity
+                .cross_account_route("222222222222")
+                .map(|route| route.hub_account_id),
+            Some("111111111111".to_string())
+        );
+
+        // Dropped members disappear, Identity Center accounts stay
+        identity.set_cross_account_roles(CrossAccountRoles {
+            member_accounts: parse_member_accounts("222222222222").unwrap(),
+            ..roles
+        });
+        assert_eq!(identity.accounts.len(), 2);
+        assert!(identity.get_account_roles("333333333333").is_empty());
+    }
+
     #[test]
     fn test_console_menu_role_fetch_restricted() {
         assert_symbol_usage_restricted(
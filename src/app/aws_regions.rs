@@ -1,9 +1,13 @@
 //! AWS Regions Data
 //!
-//! Provides AWS region codes for resource explorer and agent framework tools.
+//! Provides AWS region codes for resource explorer and agent framework tools,
+//! grouped by partition (commercial, GovCloud and China).
 
-/// All AWS region codes
-pub const AWS_REGIONS: &[&str] = &[
+use serde::{Deserialize, Serialize};
+use std::sync::RwLock;
+
+/// Region codes of the commercial `aws` partition
+pub const COMMERCIAL_REGIONS: &[&str] = &[
     "us-east-1",
     "us-east-2",
     "us-west-1",
@@ -31,6 +35,227 @@ pub const AWS_REGIONS: &[&str] = &[
     "me-central-1",
     "me-south-1",
     "sa-east-1",
-    "us-gov-east-1",
-    "us-gov-west-1",
 ];
+
+/// Region codes of the `aws-us-gov` partition
+pub const GOVCLOUD_REGIONS: &[&str] = &["us-gov-east-1", "us-gov-west-1"];
+
+/// Region codes of the `aws-cn` partition
+pub const CHINA_REGIONS: &[&str] = &["cn-north-1", "cn-northwest-1"];
+
+/// An AWS partition: a group of regions with its own ARNs, endpoints and console
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Partition {
+    #[default]
+    Aws,
+    AwsUsGov,
+    AwsCn,
+}
+
+impl Partition {
+    pub const ALL: [Partition; 3] = [Partition::Aws, Partition::AwsUsGov, Partition::AwsCn];
+
+    /// Partition identifier as it appears in ARNs
+    pub fn id(self) -> &'static str {
+        match self {
+            Partition::Aws => "aws",
+            Partition::AwsUsGov => "aws-us-gov",
+            Partition::AwsCn => "aws-cn",
+        }
+    }
+
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Partition::Aws => "AWS",
+            Partition::AwsUsGov => "AWS GovCloud (US)",
+            Partition::AwsCn => "AWS China",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|partition| partition.id() == id)
+    }
+
+    /// Partition a region code belongs to; unknown regions are commercial
+    pub fn from_region(region: &str) -> Self {
+        if region.starts_with("us-gov-") {
+            Partition::AwsUsGov
+        } else if region.starts_with("cn-") {
+            Partition::AwsCn
+        } else {
+            Partition::Aws
+        }
+    }
+
+    /// Partition of an ARN such as `arn:aws-cn:s3:::bucket`
+    pub fn from_arn(arn: &str) -> Option<Self> {
+        let mut parts = arn.splitn(3, ':');
+        if parts.next()? != "arn" {
+            return None;
+        }
+        Self::from_id(parts.next()?)
+    }
+
+    pub fn regions(self) -> &'static [&'static str] {
+        match self {
+            Partition::Aws => COMMERCIAL_REGIONS,
+            Partition::AwsUsGov => GOVCLOUD_REGIONS,
+            Partition::AwsCn => CHINA_REGIONS,
+        }
+    }
+
+    /// Region that global services (IAM, Organizations, Route 53, ...) are queried in
+    pub fn global_region(self) -> &'static str {
+        match self {
+            Partition::Aws => "us-east-1",
+            Partition::AwsUsGov => "us-gov-west-1",
+            Partition::AwsCn => "cn-north-1",
+        }
+    }
+
+    /// DNS suffix of service endpoints
+    pub fn dns_suffix(self) -> &'static str {
+        match self {
+            Partition::Aws | Partition::AwsUsGov => "amazonaws.com",
+            Partition::AwsCn => "amazonaws.com.cn",
+        }
+    }
+
+    /// Host of the AWS Management Console
+    pub fn console_host(self) -> &'static str {
+        match self {
+            Partition::Aws => "console.aws.amazon.com",
+            Partition::AwsUsGov => "console.amazonaws-us-gov.com",
+            Partition::AwsCn => "console.amazonaws.cn",
+        }
+    }
+
+    /// Host of the console federation endpoint
+    pub fn signin_host(self) -> &'static str {
+        match self {
+            Partition::Aws => "signin.aws.amazon.com",
+            Partition::AwsUsGov => "signin.amazonaws-us-gov.com",
+            Partition::AwsCn => "signin.amazonaws.cn",
+        }
+    }
+
+    /// Rewrite a commercial console URL to this partition's console
+    pub fn console_url(self, url: &str) -> String {
+        if self == Partition::Aws {
+            return url.to_string();
+        }
+        url.replacen(
+            "s3.console.aws.amazon.com/",
+            &format!("{}/", self.console_host()),
+            1,
+        )
+        .replacen("console.aws.amazon.com", self.console_host(), 1)
+    }
+
+    /// Build an ARN in this partition
+    pub fn arn(self, service: &str, region: &str, account_id: &str, resource: &str) -> String {
+        format!(
+            "arn:{}:{}:{}:{}:{}",
+            self.id(),
+            service,
+            region,
+            account_id,
+            resource
+        )
+    }
+}
+
+static ACTIVE_PARTITION: RwLock<Partition> = RwLock::new(Partition::Aws);
+
+/// Partition of the signed-in session
+pub fn active_partition() -> Partition {
+    ACTIVE_PARTITION
+        .read()
+        .map(|partition| *partition)
+        .unwrap_or_default()
+}
+
+/// Record the partition of the signed-in session
+pub fn set_active_partition(partition: Partition) {
+    if let Ok(mut active) = ACTIVE_PARTITION.write() {
+        *active = partition;
+    }
+}
+
+/// Partition of a resource in `region`
+///
+/// Global resources (empty or `global` region) use the active partition.
+pub fn partition_for_region(region: &str) -> Partition {
+    if region.is_empty() || region.eq_ignore_ascii_case("global") || region == "aws-global" {
+        active_partition()
+    } else {
+        Partition::from_region(region)
+    }
+}
+
+/// ARN partition identifier for a resource in `region`
+pub fn arn_partition(region: &str) -> &'static str {
+    partition_for_region(region).id()
+}
+
+/// Human-readable name of a GovCloud or China region
+pub fn partition_region_name(region: &str) -> Option<&'static str> {
+    match region {
+        "us-gov-east-1" => Some("AWS GovCloud (US-East)"),
+        "us-gov-west-1" => Some("AWS GovCloud (US-West)"),
+        "cn-north-1" => Some("China (Beijing)"),
+        "cn-northwest-1" => Some("China (Ningxia)"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition_from_region_and_arn() {
+        assert_eq!(Partition::from_region("eu-west-1"), Partition::Aws);
+        assert_eq!(Partition::from_region("us-gov-west-1"), Partition::AwsUsGov);
+        assert_eq!(Partition::from_region("cn-northwest-1"), Partition::AwsCn);
+
+        assert_eq!(
+            Partition::from_arn("arn:aws-us-gov:iam::123456789012:role/Admin"),
+            Some(Partition::AwsUsGov)
+        );
+        assert_eq!(
+            Partition::from_arn("arn:aws-cn:s3:::bucket"),
+            Some(Partition::AwsCn)
+        );
+        assert_eq!(Partition::from_arn("arn:aws-iso:s3:::bucket"), None);
+        assert_eq!(Partition::from_arn("not-an-arn"), None);
+
+        for partition in Partition::ALL {
+            assert!(partition.regions().contains(&partition.global_region()));
+            assert!(partition
+                .regions()
+                .iter()
+                .all(|region| Partition::from_region(region) == partition));
+        }
+    }
+
+    #[test]
+    fn test_partition_arns_and_console_urls() {
+        assert_eq!(
+            Partition::AwsCn.arn("lambda", "cn-north-1", "123456789012", "function:f"),
+            "arn:aws-cn:lambda:cn-north-1:123456789012:function:f"
+        );
+        assert_eq!(arn_partition("us-gov-east-1"), "aws-us-gov");
+
+        let url = "https://console.aws.amazon.com/ec2/home?region=us-gov-west-1";
+        assert_eq!(
+            Partition::AwsUsGov.console_url(url),
+            "https://console.amazonaws-us-gov.com/ec2/home?region=us-gov-west-1"
+        );
+        assert_eq!(
+            Partition::AwsCn.console_url("https://s3.console.aws.amazon.com/s3/buckets/b"),
+            "https://console.amazonaws.cn/s3/buckets/b"
+        );
+        assert_eq!(Partition::Aws.console_url(url), url);
+    }
+}
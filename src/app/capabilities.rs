@@ -0,0 +1,78 @@
+//! Build-time capability gates
+//!
+//! The `read-only` Cargo feature produces a viewer-only binary: everything
+//! that changes AWS resources, saved pages, or other persisted user content is
+//! compiled out or refused. Security teams can distribute this build broadly
+//! knowing it can only read.
+//!
+//! # Build a viewer binary
+//!
+//! ```bash
+//! cargo build --release --features read-only
+//! ```
+//!
+//! # Usage
+//!
+//! Mutating entry points call [`ensure_writable`] before doing any work so the
+//! refusal message is consistent everywhere:
+//!
+//! ```ignore
+//! use crate::app::capabilities::ensure_writable;
+//!
+//! ensure_writable("Delete page")?;
+//! std::fs::remove_dir_all(&page_path)?;
+//! ```
+//!
+//! Code that should not exist at all in the viewer build (agent write tools,
+//! mutating API commands) is gated with `#[cfg(not(feature = "read-only"))]`
+//! instead, and UI that only offers mutations checks [`READ_ONLY`] to hide it.
+
+/// True when compiled with `--features read-only`
+pub const READ_ONLY: bool = cfg!(feature = "read-only");
+
+/// Whether this is the read-only viewer build
+pub fn is_read_only() -> bool {
+    READ_ONLY
+}
+
+/// Return an error if mutations are disabled in this build
+///
+/// # Arguments
+///
+/// * `operation` - Human-readable name of the refused operation (e.g., "Rename page")
+pub fn ensure_writable(operation: &str) -> anyhow::Result<()> {
+    if READ_ONLY {
+        tracing::warn!("Refused '{}' in read-only build", operation);
+        anyhow::bail!("{} is disabled in this read-only build", operation);
+    }
+    Ok(())
+}
+
+/// Suffix for window titles identifying the viewer build
+pub fn build_label() -> &'static str {
+    if READ_ONLY {
+        " (Read-only)"
+    } else {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ensure_writable_matches_build() {
+        assert_eq!(ensure_writable("Test operation").is_ok(), !READ_ONLY);
+        assert_eq!(is_read_only(), cfg!(feature = "read-only"));
+    }
+
+    #[test]
+    fn test_build_label() {
+        if READ_ONLY {
+            assert_eq!(build_label(), " (Read-only)");
+        } else {
+            assert!(build_label().is_empty());
+        }
+    }
+}
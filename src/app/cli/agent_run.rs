@@ -0,0 +1,251 @@
+//! Headless agent run for `awsdash --cli agent run`
+//!
+//! Runs a task manager agent without the agent manager window. The parts of
+//! the window that keep an agent going are done here instead: workers
+//! requested through `start_task` are created and polled, their results are
+//! handed back to the manager, and tool calls that need approval are denied,
+//! since there is no one to ask.
+
+use crate::app::agent_framework::middleware::layers::{
+    respond_to_approval, take_approval_requests, ApprovalDecision,
+};
+use crate::app::agent_framework::{
+    get_agent_creation_receiver, send_worker_completion, take_response_channel,
+    AgentCreationRequest, AgentCreationResponse, AgentId, AgentInstance, AgentMetadata,
+    AgentModelPreferences, AgentStatus, AgentType, ConversationRole, WorkerCompletion,
+};
+use crate::app::aws_identity::AwsIdentityCenter;
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How often agents are polled
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Result of a headless agent run
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentRunOutcome {
+    /// "completed" or "failed"
+    pub status: String,
+    /// Final answer of the manager
+    pub response: Option<String>,
+    pub error: Option<String>,
+    /// Number of workers the manager started
+    pub workers: usize,
+    pub duration_ms: u64,
+    /// Per-agent log of the manager and its workers
+    pub log_path: String,
+}
+
+/// A task manager agent with the workers it starts
+pub struct HeadlessAgentRun {
+    identity: Arc<Mutex<AwsIdentityCenter>>,
+    model_preferences: AgentModelPreferences,
+    agents: HashMap<AgentId, AgentInstance>,
+    workers_started: usize,
+}
+
+impl HeadlessAgentRun {
+    pub fn new(identity: Arc<Mutex<AwsIdentityCenter>>) -> Self {
+        Self {
+            identity,
+            model_preferences: AgentModelPreferences::load(),
+            agents: HashMap::new(),
+            workers_started: 0,
+        }
+    }
+
+    /// Send `prompt` to a new task manager and wait for its answer
+    pub fn run(mut self, prompt: String) -> Result<AgentRunOutcome> {
+        crate::app::agent_framework::initialize_v8_platform()
+            .map_err(|e| anyhow!("Failed to initialize V8 platform: {}", e))?;
+
+        let started = Instant::now();
+        let metadata = AgentMetadata {
+            name: "CLI Task".to_string(),
+            description: "Task run from the command line".to_string(),
+            model: self.model_preferences.manager,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let mut manager = AgentInstance::new(metadata, AgentType::TaskManager)
+            .with_token_budget_layer()
+            .with_approval_gate_layer()
+            .with_guardrail_layer()
+            .with_tool_timeline_layer();
+        let manager_id = manager.id();
+        manager
+            .initialize(&mut self.identity.lock().unwrap(), true)
+            .map_err(|e| anyhow!("Failed to initialize agent: {}", e))?;
+        let log_path = manager.logger().log_path().display().to_string();
+
+        tracing::info!("CLI agent run started (agent {})", manager_id);
+        manager.send_message(prompt);
+        self.agents.insert(manager_id, manager);
+
+        loop {
+            self.process_creation_requests();
+            deny_approval_requests();
+            if self.poll_agents(manager_id) {
+                break;
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+
+        let manager = &self.agents[&manager_id];
+        let response = manager
+            .messages()
+            .back()
+            .filter(|m| m.role == ConversationRole::Assistant)
+            .map(|m| m.content.clone());
+        let error = match manager.status() {
+            AgentStatus::Failed(error) => Some(error.clone()),
+            _ => None,
+        };
+        let status = match error {
+            Some(_) => "failed",
+            None => "completed",
+        };
+        Ok(AgentRunOutcome {
+            status: status.to_string(),
+            response,
+            error,
+            workers: self.workers_started,
+            duration_ms: started.elapsed().as_millis() as u64,
+            log_path,
+        })
+    }
+
+    /// Poll every agent; returns true once the manager has finished
+    fn poll_agents(&mut self, manager_id: AgentId) -> bool {
+        let mut finished = false;
+        let mut completed_workers = Vec::new();
+
+        for (agent_id, agent) in self.agents.iter_mut() {
+            if !agent.poll_response() || agent.is_processing() || agent.has_pending_injections() {
+                continue;
+            }
+            if *agent_id == manager_id {
+                finished = true;
+                continue;
+            }
+            // Same completion rule as the agent manager window
+            let Some(last) = agent.messages().back() else {
+                continue;
+            };
+            if last.role != ConversationRole::Assistant {
+                continue;
+            }
+            let result = match last.content.strip_prefix("Error: ") {
+                Some(error) => Err(error.to_string()),
+                None => Ok(last.content.clone()),
+            };
+            let execution_time = (Utc::now() - agent.metadata().created_at)
+                .to_std()
+                .unwrap_or_default();
+            completed_workers.push(WorkerCompletion {
+                worker_id: *agent_id,
+                result,
+                execution_time,
+            });
+        }
+
+        for completion in completed_workers {
+            tracing::info!(
+                "CLI worker {} completed (success: {})",
+                completion.worker_id,
+                completion.result.is_ok()
+            );
+            self.agents.remove(&completion.worker_id);
+            send_worker_completion(completion);
+        }
+        finished
+    }
+
+    /// Create the workers the manager asked for
+    fn process_creation_requests(&mut self) {
+        let requests: Vec<AgentCreationRequest> = match get_agent_creation_receiver().lock() {
+            Ok(receiver) => receiver.try_iter().collect(),
+            Err(_) => Vec::new(),
+        };
+
+        for request in requests {
+            let response = match self.create_worker(&request) {
+                Ok(agent_id) => AgentCreationResponse::success(agent_id),
+                Err(error) => {
+                    tracing::warn!("CLI worker creation failed: {}", error);
+                    AgentCreationResponse::error(AgentId::new(), error)
+                }
+            };
+            if let Some(sender) = take_response_channel(request.request_id()) {
+                let _ = sender.send(response);
+            }
+        }
+    }
+
+    fn create_worker(&mut self, request: &AgentCreationRequest) -> Result<AgentId, String> {
+        let AgentCreationRequest::TaskWorker {
+            short_description,
+            task_description,
+            parent_id,
+            vfs_id,
+            ..
+        } = request
+        else {
+            return Err("Page builder workers are not available in CLI mode".to_string());
+        };
+        let parent = self
+            .agents
+            .get(parent_id)
+            .ok_or_else(|| format!("Parent agent {} not found", parent_id))?;
+        let parent_logger = parent.logger().clone();
+        let parent_cancel_token = parent.get_cancel_token();
+
+        let agent_type = AgentType::TaskWorker {
+            parent_id: *parent_id,
+        };
+        let metadata = AgentMetadata {
+            name: format!("Task Worker {}", self.workers_started + 1),
+            description: format!("Task: {}", task_description),
+            model: self.model_preferences.for_agent_type(&agent_type),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let mut worker = AgentInstance::new_with_parent_logger(metadata, agent_type, parent_logger)
+            .with_approval_gate_layer()
+            .with_guardrail_layer()
+            .with_tool_timeline_layer();
+        worker.set_vfs_id(vfs_id.clone());
+        if let Some(token) = parent_cancel_token {
+            worker.set_parent_cancel_token(token);
+        }
+        worker
+            .initialize(&mut self.identity.lock().unwrap(), true)
+            .map_err(|e| format!("Failed to initialize agent: {}", e))?;
+
+        let worker_id = worker.id();
+        tracing::info!("CLI worker {} started: {}", worker_id, short_description);
+        worker.send_message(task_description.clone());
+        self.agents.insert(worker_id, worker);
+        self.workers_started += 1;
+        Ok(worker_id)
+    }
+}
+
+/// Deny tool calls that need approval; a headless run cannot ask
+fn deny_approval_requests() {
+    for request in take_approval_requests() {
+        eprintln!(
+            "Denied {} ({:?} risk): approval is not available in CLI mode",
+            request.operation, request.risk
+        );
+        respond_to_approval(
+            request.id,
+            ApprovalDecision::Denied("Approval is not available in CLI mode".to_string()),
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! Headless command line interface
+//!
+//! `awsdash --cli <command>` runs without egui and prints JSON to stdout, so
+//! the Explorer and agents can be scripted:
+//!
+//! ```text
+//! awsdash --cli query --type AWS::S3::Bucket --accounts 123456789012 --regions us-east-1
+//! awsdash --cli export --type AWS::EC2::Instance --format csv --output instances.csv
+//! awsdash --cli agent run --prompt-file prompt.md
+//! ```
+//!
+//! Commands sign in with a named profile (`--profile`, else `AWS_PROFILE`,
+//! else `default`) through the same [`CredentialSource`] the login window
+//! uses. `--member-role` reaches other accounts listed in `--accounts` by
+//! assuming that role from the signed-in account. Queries go through the
+//! shared Explorer query path used by agents, so results and caching match
+//! the GUI.
+//!
+//! Errors are written to stderr and exit with status 1; invalid arguments
+//! exit with status 2.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+mod agent_run;
+
+use crate::app::agent_framework::utils::registry::set_global_aws_client;
+use crate::app::agent_framework::v8_bindings::bindings::resources::{
+    execute_complete_query, query_cached_resources_internal, QueryCachedResourcesArgs,
+};
+use crate::app::agent_framework::v8_bindings::set_global_aws_identity;
+use crate::app::aws_identity::AwsIdentityCenter;
+use crate::app::aws_regions::{active_partition, set_active_partition};
+use crate::app::credential_sources::{BaseCredentials, CredentialSource};
+use crate::app::cross_account_roles::CrossAccountRoles;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use crate::app::resource_explorer::export::{
+    available_columns, default_export_path, export_resources, ExportFormat,
+};
+use crate::app::resource_explorer::AWSResourceClient;
+use anyhow::{anyhow, Context, Result};
+use serde_json::{json, Value};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+pub use agent_run::{AgentRunOutcome, HeadlessAgentRun};
+
+const USAGE: &str = "\
+Usage: awsdash --cli <command> [options]
+
+Commands:
+  query        Query resources and print them as JSON
+  export       Query resources and write them to a CSV, JSON, or Excel file
+  agent run    Run a task manager agent on a prompt and print its answer
+
+Resource options (query, export):
+  --type <TYPE>          CloudFormation resource type; repeat or separate with commas
+  --accounts <IDS>       Comma-separated account IDs (default: signed-in account)
+  --regions <CODES>      Comma-separated regions (default: --region)
+
+Export options:
+  --format <FORMAT>      csv, json, or xlsx (default: csv)
+  --output <PATH>        Output file (default: Downloads folder)
+
+Agent options:
+  --prompt-file <PATH>   File with the prompt; '-' reads stdin
+
+Sign-in options:
+  --profile <NAME>       AWS profile (default: AWS_PROFILE or 'default')
+  --region <CODE>        Region for STS and Bedrock; also selects the partition
+                         (default: AWS_REGION or us-east-1)
+  --member-role <ROLE>   Role to assume in other accounts listed in --accounts
+";
+
+/// Accounts, regions, and resource types to query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceScope {
+    pub resource_types: Vec<String>,
+    pub accounts: Option<Vec<String>>,
+    pub regions: Option<Vec<String>>,
+}
+
+/// A CLI subcommand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    Query(ResourceScope),
+    Export {
+        scope: ResourceScope,
+        format: ExportFormat,
+        output: Option<PathBuf>,
+    },
+    AgentRun {
+        /// Prompt file, or `-` for stdin
+        prompt_file: PathBuf,
+    },
+    Help,
+}
+
+/// A parsed command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CliInvocation {
+    pub command: CliCommand,
+    pub profile: String,
+    pub region: String,
+    pub member_role: Option<String>,
+}
+
+/// Run the CLI when `--cli` is among `args`
+///
+/// Returns the process exit status, or None to start the GUI.
+pub fn run_cli(args: &[String]) -> Option<i32> {
+    let invocation = parse_cli_args(args)?;
+    let invocation = match invocation {
+        Ok(invocation) => invocation,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return Some(2);
+        }
+    };
+    if invocation.command == CliCommand::Help {
+        print!("{}", USAGE);
+        return Some(0);
+    }
+
+    tracing::info!("Running CLI command {:?}", invocation.command);
+    match execute(&invocation) {
+        Ok(output) => match serde_json::to_string_pretty(&output) {
+            Ok(text) => {
+                println!("{}", text);
+                Some(0)
+            }
+            Err(e) => {
+                eprintln!("error: failed to serialize output: {}", e);
+                Some(1)
+            }
+        },
+        Err(e) => {
+            tracing::error!("CLI command failed: {:#}", e);
+            eprintln!("error: {:#}", e);
+            Some(1)
+        }
+    }
+}
+
+/// Parse the command line; None when `--cli` is absent
+pub fn parse_cli_args(args: &[String]) -> Option<Result<CliInvocation, String>> {
+    if !args.iter().any(|arg| arg == "--cli") {
+        return None;
+    }
+    Some(parse_invocation(args))
+}
+
+fn parse_invocation(args: &[String]) -> Result<CliInvocation, String> {
+    let mut positional = Vec::new();
+    let mut resource_types = Vec::new();
+    let mut accounts = None;
+    let mut regions = None;
+    let mut format = ExportFormat::Csv;
+    let mut output = None;
+    let mut prompt_file = None;
+    let mut profile = std::env::var("AWS_PROFILE").unwrap_or_else(|_| "default".to_string());
+    // Without `--region` or `AWS_REGION`, STS is called in the global region
+    let mut region = std::env::var("AWS_REGION")
+        .unwrap_or_else(|_| active_partition().global_region().to_string());
+    let mut member_role = None;
+
+    // Skip the program name
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        let mut value = || {
+            iter.next()
+                .cloned()
+                .ok_or_else(|| format!("{} requires a value", arg))
+        };
+        match arg.as_str() {
+            "--cli" => {}
+            "--help" | "-h" => positional.insert(0, "help".to_string()),
+            "--type" => resource_types.extend(split_list(&value()?)),
+            "--accounts" => accounts = Some(split_list(&value()?)),
+            "--regions" => regions = Some(split_list(&value()?)),
+            "--format" => {
+                let name = value()?;
+                format = ExportFormat::all()
+                    .into_iter()
+                    .find(|f| f.extension() == name.to_lowercase())
+                    .ok_or_else(|| format!("Unknown export format '{}'", name))?;
+            }
+            "--output" => output = Some(PathBuf::from(value()?)),
+            "--prompt-file" => prompt_file = Some(PathBuf::from(value()?)),
+            "--profile" => profile = value()?,
+            "--region" => region = value()?,
+            "--member-role" => member_role = Some(value()?),
+            flag if flag.starts_with("--") => return Err(format!("Unknown option '{}'", flag)),
+            _ => positional.push(arg.clone()),
+        }
+    }
+
+    let scope = || -> Result<ResourceScope, String> {
+        if resource_types.is_empty() {
+            return Err("--type is required".to_string());
+        }
+        Ok(ResourceScope {
+            resource_types: resource_types.clone(),
+            accounts: accounts.clone(),
+            regions: regions.clone(),
+        })
+    };
+
+    let words: Vec<&str> = positional.iter().map(String::as_str).collect();
+    let command = match words.as_slice() {
+        [] | ["help", ..] => CliCommand::Help,
+        ["query"] => CliCommand::Query(scope()?),
+        ["export"] => CliCommand::Export {
+            scope: scope()?,
+            format,
+            output,
+        },
+        ["agent", "run"] => CliCommand::AgentRun {
+            prompt_file: prompt_file.ok_or("--prompt-file is required")?,
+        },
+        _ => return Err(format!("Unknown command '{}'", words.join(" "))),
+    };
+
+    Ok(CliInvocation {
+        command,
+        profile,
+        region,
+        member_role,
+    })
+}
+
+/// Split a comma-separated list, dropping empty entries
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+fn execute(invocation: &CliInvocation) -> Result<Value> {
+    // Sign in before entering the runtime: the credential source blocks on its own
+    let identity = sign_in(invocation)?;
+    let runtime = tokio::runtime::Runtime::new().context("Failed to create tokio runtime")?;
+
+    match &invocation.command {
+        CliCommand::Query(scope) => {
+            let result =
+                runtime.block_on(query_cached_resources_internal(QueryCachedResourcesArgs {
+                    accounts: scope.accounts.clone(),
+                    regions: Some(scope_regions(scope, invocation)),
+                    resource_types: scope.resource_types.clone(),
+                }))?;
+            Ok(serde_json::to_value(result)?)
+        }
+        CliCommand::Export {
+            scope,
+            format,
+            output,
+        } => {
+            let accounts = match &scope.accounts {
+                Some(accounts) if !accounts.is_empty() => accounts.clone(),
+                _ => signed_in_accounts(&identity),
+            };
+            let resources = runtime.block_on(execute_complete_query(
+                accounts,
+                scope_regions(scope, invocation),
+                scope.resource_types.clone(),
+            ))?;
+            let path = output
+                .clone()
+                .unwrap_or_else(|| default_export_path(*format));
+            let columns = available_columns(&resources);
+            export_resources(&resources, &columns, *format, &path, |_| {})?;
+            Ok(json!({
+                "status": "success",
+                "path": path,
+                "format": format.extension(),
+                "count": resources.len(),
+            }))
+        }
+        CliCommand::AgentRun { prompt_file } => {
+            let prompt = read_prompt(prompt_file)?;
+            // The agent runs on its own runtime; only the query paths use this one
+            drop(runtime);
+            let outcome = HeadlessAgentRun::new(identity).run(prompt)?;
+            Ok(serde_json::to_value(outcome)?)
+        }
+        CliCommand::Help => Ok(Value::Null),
+    }
+}
+
+/// Sign in with the profile and publish the session to the Explorer and agent tools
+fn sign_in(invocation: &CliInvocation) -> Result<Arc<Mutex<AwsIdentityCenter>>> {
+    let source = CredentialSource {
+        base: BaseCredentials::Profile(invocation.profile.clone()),
+        assume_role_chain: Vec::new(),
+        region: invocation.region.clone(),
+    };
+    let mut identity = AwsIdentityCenter::with_credential_source(source);
+    identity.sign_in_with_credential_source().map_err(|e| {
+        anyhow!(
+            "Sign-in with profile '{}' failed: {}",
+            invocation.profile,
+            e
+        )
+    })?;
+
+    if let Some(role) = &invocation.member_role {
+        let signed_in: Vec<String> = identity
+            .accounts
+            .iter()
+            .map(|account| account.account_id.clone())
+            .collect();
+        let members = match &invocation.command {
+            CliCommand::Query(scope) | CliCommand::Export { scope, .. } => {
+                scope.accounts.clone().unwrap_or_default()
+            }
+            _ => Vec::new(),
+        };
+        identity.set_cross_account_roles(CrossAccountRoles {
+            hub_account_id: None,
+            role_pattern: role.clone(),
+            external_id: None,
+            member_accounts: members
+                .into_iter()
+                .filter(|account| !signed_in.contains(account))
+                .map(|account| (account, None))
+                .collect(),
+        });
+    }
+
+    // Global services and ARNs follow the session's partition
+    set_active_partition(identity.partition());
+
+    let default_role = identity.default_role_name.clone();
+    let identity = Arc::new(Mutex::new(identity));
+    let coordinator = Arc::new(CredentialCoordinator::new(identity.clone(), default_role));
+    set_global_aws_identity(Some(identity.clone()));
+    set_global_aws_client(Some(Arc::new(AWSResourceClient::new(coordinator))));
+    Ok(identity)
+}
+
+/// Regions of the scope, or the sign-in region
+fn scope_regions(scope: &ResourceScope, invocation: &CliInvocation) -> Vec<String> {
+    match &scope.regions {
+        Some(regions) if !regions.is_empty() => regions.clone(),
+        _ => vec![invocation.region.clone()],
+    }
+}
+
+fn signed_in_accounts(identity: &Arc<Mutex<AwsIdentityCenter>>) -> Vec<String> {
+    identity
+        .lock()
+        .map(|identity| {
+            identity
+                .accounts
+                .iter()
+                .map(|account| account.account_id.clone())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn read_prompt(path: &PathBuf) -> Result<String> {
+    let prompt = if path.as_os_str() == "-" {
+        std::io::read_to_string(std::io::stdin()).context("Failed to read prompt from stdin")?
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read prompt file {}", path.display()))?
+    };
+    if prompt.trim().is_empty() {
+        return Err(anyhow!("Prompt is empty"));
+    }
+    Ok(prompt.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        std::iter::once("awsdash")
+            .chain(line.split_whitespace())
+            .map(String::from)
+            .collect()
+    }
+
+    fn parse(line: &str) -> Result<CliInvocation, String> {
+        parse_cli_args(&args(line)).expect("--cli present")
+    }
+
+    #[test]
+    fn test_gui_without_cli_flag() {
+        assert!(parse_cli_args(&args("--demo")).is_none());
+    }
+
+    #[test]
+    fn test_parse_query() {
+        let invocation = parse(
+            "--cli query --type AWS::S3::Bucket,AWS::EC2::Instance --type AWS::IAM::Role \
+             --accounts 111111111111,222222222222 --profile audit --member-role Audit",
+        )
+        .unwrap();
+        assert_eq!(
+            invocation.command,
+            CliCommand::Query(ResourceScope {
+                resource_types: vec![
+                    "AWS::S3::Bucket".to_string(),
+                    "AWS::EC2::Instance".to_string(),
+                    "AWS::IAM::Role".to_string(),
+                ],
+                accounts: Some(vec!["111111111111".to_string(), "222222222222".to_string()]),
+                regions: None,
+            })
+        );
+        assert_eq!(invocation.profile, "audit");
+        assert_eq!(invocation.member_role.as_deref(), Some("Audit"));
+    }
+
+    #[test]
+    fn test_parse_export_and_agent_run() {
+        let invocation =
+            parse("--cli export --type AWS::S3::Bucket --format XLSX --output out.xlsx").unwrap();
+        assert!(matches!(
+            invocation.command,
+            CliCommand::Export {
+                format: ExportFormat::Xlsx,
+                output: Some(_),
+                ..
+            }
+        ));
+
+        let invocation = parse("--cli agent run --prompt-file -").unwrap();
+        assert_eq!(
+            invocation.command,
+            CliCommand::AgentRun {
+                prompt_file: PathBuf::from("-")
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse("--cli query").unwrap_err().contains("--type"));
+        assert!(parse("--cli agent run")
+            .unwrap_err()
+            .contains("--prompt-file"));
+        assert!(parse("--cli export --type X --format pdf")
+            .unwrap_err()
+            .contains("pdf"));
+        assert!(parse("--cli deploy").unwrap_err().contains("deploy"));
+        assert!(parse("--cli query --type").unwrap_err().contains("value"));
+        assert_eq!(parse("--cli").unwrap().command, CliCommand::Help);
+    }
+}
@@ -0,0 +1,744 @@
+//! Change Set Workflow
+//!
+//! Stack updates go through a change set first: `CreateChangeSet`, wait for
+//! CloudFormation to compute the plan, show it for review, and only call
+//! `ExecuteChangeSet` after the user approves. Discarded plans are deleted.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_cloudformation as cfn;
+use std::time::Duration;
+
+use super::manager::DeploymentManager;
+use crate::app::capabilities::ensure_writable;
+use crate::app::resource_explorer::resource_actions::{ActionAuditEntry, ActionAuditLog};
+use crate::app::resource_explorer::status::{report_status, report_status_done};
+
+/// Audit log action ID for executed change sets
+pub const EXECUTE_ACTION_ID: &str = "cloudformation.execute_change_set";
+
+/// Delay between change set status checks
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Give up waiting for CloudFormation to compute the plan after this long
+const CREATE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Largest template CloudFormation accepts inline; larger ones need an S3 URL
+pub const MAX_TEMPLATE_BODY_BYTES: usize = 51_200;
+
+/// Capabilities acknowledged when the user allows IAM and macro changes
+pub const IAM_CAPABILITIES: [&str; 3] = [
+    "CAPABILITY_IAM",
+    "CAPABILITY_NAMED_IAM",
+    "CAPABILITY_AUTO_EXPAND",
+];
+
+/// What CloudFormation will do to a resource
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ChangeAction {
+    Remove,
+    Modify,
+    Add,
+    Import,
+    Dynamic,
+    Unknown(String),
+}
+
+impl ChangeAction {
+    pub(super) fn from_sdk(action: &str) -> Self {
+        match action {
+            "Add" => ChangeAction::Add,
+            "Modify" => ChangeAction::Modify,
+            "Remove" => ChangeAction::Remove,
+            "Import" => ChangeAction::Import,
+            "Dynamic" => ChangeAction::Dynamic,
+            other => ChangeAction::Unknown(other.to_string()),
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            ChangeAction::Add => "Add",
+            ChangeAction::Modify => "Modify",
+            ChangeAction::Remove => "Remove",
+            ChangeAction::Import => "Import",
+            ChangeAction::Dynamic => "Dynamic",
+            ChangeAction::Unknown(action) => action,
+        }
+    }
+
+    /// Short marker for text display
+    pub fn marker(&self) -> &'static str {
+        match self {
+            ChangeAction::Add | ChangeAction::Import => "+",
+            ChangeAction::Remove => "-",
+            _ => "~",
+        }
+    }
+}
+
+/// Whether a modification replaces the physical resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Replacement {
+    /// Updated in place
+    Never,
+    /// Depends on values only known during the update
+    Conditional,
+    /// A new physical resource is created and the old one deleted
+    Always,
+}
+
+impl Replacement {
+    fn from_sdk(replacement: &str) -> Self {
+        match replacement {
+            "True" => Replacement::Always,
+            "Conditional" => Replacement::Conditional,
+            _ => Replacement::Never,
+        }
+    }
+}
+
+/// One property or attribute change that causes a resource modification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeDetail {
+    /// "Properties", "Tags", "Metadata", ...
+    pub attribute: String,
+    /// Property name for property changes
+    pub name: Option<String>,
+    /// "Never", "Conditionally", or "Always"
+    pub requires_recreation: Option<String>,
+    /// What caused the change (e.g., "DirectModification", "ParameterReference")
+    pub change_source: Option<String>,
+    /// Parameter or resource behind the change, when CloudFormation knows it
+    pub causing_entity: Option<String>,
+}
+
+impl ChangeDetail {
+    /// Human-readable target, e.g. "Properties.BucketName"
+    pub fn target(&self) -> String {
+        match &self.name {
+            Some(name) => format!("{}.{}", self.attribute, name),
+            None => self.attribute.clone(),
+        }
+    }
+}
+
+/// A planned change to one stack resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceChange {
+    pub action: ChangeAction,
+    pub logical_id: String,
+    pub physical_id: Option<String>,
+    pub resource_type: String,
+    /// Only set for modifications
+    pub replacement: Option<Replacement>,
+    pub details: Vec<ChangeDetail>,
+}
+
+impl ResourceChange {
+    /// Warning text when the change deletes or may delete the physical resource
+    pub fn replacement_warning(&self) -> Option<&'static str> {
+        match (&self.action, self.replacement) {
+            (ChangeAction::Remove, _) => Some("Resource will be deleted"),
+            (_, Some(Replacement::Always)) => Some("Resource will be replaced"),
+            (_, Some(Replacement::Conditional)) => Some("Resource may be replaced"),
+            _ => None,
+        }
+    }
+}
+
+/// Status of a change set while CloudFormation computes it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeSetState {
+    Pending,
+    Ready,
+    Failed,
+}
+
+impl ChangeSetState {
+    fn from_sdk(status: &str) -> Self {
+        match status {
+            "CREATE_COMPLETE" => ChangeSetState::Ready,
+            "FAILED" | "DELETE_COMPLETE" | "DELETE_FAILED" => ChangeSetState::Failed,
+            _ => ChangeSetState::Pending,
+        }
+    }
+}
+
+/// A computed change set ready for review
+#[derive(Debug, Clone)]
+pub struct ChangeSetPlan {
+    pub stack_name: String,
+    /// Change set ARN, used for execute and delete
+    pub change_set_id: String,
+    pub change_set_name: String,
+    /// Sorted removes first, then modifies, then adds
+    pub changes: Vec<ResourceChange>,
+    /// CloudFormation's reason when the change set failed to compute
+    pub status_reason: Option<String>,
+    /// Whether the change set can be executed ("AVAILABLE")
+    pub executable: bool,
+}
+
+impl ChangeSetPlan {
+    /// Counts of (adds, modifies, removes)
+    pub fn counts(&self) -> (usize, usize, usize) {
+        self.changes
+            .iter()
+            .fold((0, 0, 0), |(add, modify, remove), change| {
+                match change.action {
+                    ChangeAction::Add | ChangeAction::Import => (add + 1, modify, remove),
+                    ChangeAction::Remove => (add, modify, remove + 1),
+                    _ => (add, modify + 1, remove),
+                }
+            })
+    }
+
+    /// Changes that delete or may replace a physical resource
+    pub fn destructive_changes(&self) -> impl Iterator<Item = &ResourceChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.replacement_warning().is_some())
+    }
+}
+
+/// Template to plan the update with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TemplateSource {
+    /// Keep the stack's current template (parameter or capability changes only)
+    Current,
+    /// Inline template body (JSON or YAML)
+    Body(String),
+}
+
+/// A stack parameter for the update
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackParameter {
+    pub key: String,
+    /// Keep the value currently deployed instead of `value`
+    pub use_previous: bool,
+    pub value: String,
+}
+
+/// Everything needed to create an update change set
+#[derive(Debug, Clone)]
+pub struct ChangeSetRequest {
+    pub stack_name: String,
+    pub template: TemplateSource,
+    pub parameters: Vec<StackParameter>,
+    pub capabilities: Vec<String>,
+}
+
+impl ChangeSetRequest {
+    /// Check the request before calling CloudFormation
+    pub fn validate(&self) -> Result<()> {
+        if let TemplateSource::Body(body) = &self.template {
+            if body.trim().is_empty() {
+                anyhow::bail!("Template is empty");
+            }
+            if body.len() > MAX_TEMPLATE_BODY_BYTES {
+                anyhow::bail!(
+                    "Template is {} bytes; templates over {} bytes must be uploaded to S3",
+                    body.len(),
+                    MAX_TEMPLATE_BODY_BYTES
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Current template, parameters, and capabilities of a stack
+#[derive(Debug, Clone, Default)]
+pub struct StackUpdateDefaults {
+    pub template_body: String,
+    pub parameters: Vec<StackParameter>,
+    pub capabilities: Vec<String>,
+}
+
+impl DeploymentManager {
+    /// Load a stack's template and parameters to start an update from
+    pub async fn stack_update_defaults(
+        &self,
+        account_id: &str,
+        region: &str,
+        stack_name: &str,
+    ) -> Result<StackUpdateDefaults> {
+        let client = self.create_client(account_id, region).await?;
+
+        let template = client
+            .get_template()
+            .stack_name(stack_name)
+            .template_stage(cfn::types::TemplateStage::Original)
+            .send()
+            .await
+            .with_context(|| format!("Failed to get template for {}", stack_name))?;
+
+        let stacks = client
+            .describe_stacks()
+            .stack_name(stack_name)
+            .send()
+            .await
+            .with_context(|| format!("Failed to describe stack {}", stack_name))?;
+        let stack = stacks
+            .stacks
+            .unwrap_or_default()
+            .into_iter()
+            .next()
+            .with_context(|| format!("Stack {} not found", stack_name))?;
+
+        Ok(StackUpdateDefaults {
+            template_body: template.template_body.unwrap_or_default(),
+            parameters: stack
+                .parameters
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|parameter| {
+                    Some(StackParameter {
+                        key: parameter.parameter_key?,
+                        use_previous: true,
+                        value: parameter.parameter_value.unwrap_or_default(),
+                    })
+                })
+                .collect(),
+            capabilities: stack
+                .capabilities
+                .unwrap_or_default()
+                .iter()
+                .map(|capability| capability.as_str().to_string())
+                .collect(),
+        })
+    }
+
+    /// Create an update change set and return its ID
+    pub async fn create_change_set(
+        &self,
+        account_id: &str,
+        region: &str,
+        request: &ChangeSetRequest,
+    ) -> Result<String> {
+        request.validate()?;
+        let client = self.create_client(account_id, region).await?;
+
+        let parameters = request
+            .parameters
+            .iter()
+            .map(|parameter| {
+                let builder = cfn::types::Parameter::builder().parameter_key(&parameter.key);
+                if parameter.use_previous {
+                    builder.use_previous_value(true).build()
+                } else {
+                    builder.parameter_value(&parameter.value).build()
+                }
+            })
+            .collect();
+        let capabilities = request
+            .capabilities
+            .iter()
+            .map(|capability| cfn::types::Capability::from(capability.as_str()))
+            .collect();
+
+        let mut create = client
+            .create_change_set()
+            .stack_name(&request.stack_name)
+            .change_set_name(change_set_name(chrono::Utc::now()))
+            .change_set_type(cfn::types::ChangeSetType::Update)
+            .description("Created by AWS Dash")
+            .set_parameters(Some(parameters))
+            .set_capabilities(Some(capabilities));
+        create = match &request.template {
+            TemplateSource::Current => create.use_previous_template(true),
+            TemplateSource::Body(body) => create.template_body(body),
+        };
+
+        let response = create
+            .send()
+            .await
+            .with_context(|| format!("Failed to create change set for {}", request.stack_name))?;
+
+        response
+            .id
+            .context("CreateChangeSet returned no change set ID")
+    }
+
+    /// Describe a change set; the plan is only complete once the state is Ready
+    pub async fn describe_change_set(
+        &self,
+        account_id: &str,
+        region: &str,
+        change_set_id: &str,
+    ) -> Result<(ChangeSetState, ChangeSetPlan)> {
+        let client = self.create_client(account_id, region).await?;
+        let mut changes = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let response = client
+                .describe_change_set()
+                .change_set_name(change_set_id)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .with_context(|| format!("Failed to describe change set {}", change_set_id))?;
+
+            changes.extend(
+                response
+                    .changes
+                    .iter()
+                    .flatten()
+                    .filter_map(|change| change.resource_change.as_ref())
+                    .map(convert_resource_change),
+            );
+
+            next_token = response.next_token.clone();
+            if next_token.is_none() {
+                let state = response
+                    .status
+                    .as_ref()
+                    .map(|status| ChangeSetState::from_sdk(status.as_str()))
+                    .unwrap_or(ChangeSetState::Pending);
+                sort_changes(&mut changes);
+                let plan = ChangeSetPlan {
+                    stack_name: response.stack_name.unwrap_or_default(),
+                    change_set_id: response
+                        .change_set_id
+                        .unwrap_or_else(|| change_set_id.to_string()),
+                    change_set_name: response.change_set_name.unwrap_or_default(),
+                    changes,
+                    status_reason: response.status_reason,
+                    executable: response
+                        .execution_status
+                        .is_some_and(|status| status.as_str() == "AVAILABLE"),
+                };
+                return Ok((state, plan));
+            }
+        }
+    }
+
+    /// Create a change set and wait until CloudFormation has computed the plan
+    ///
+    /// A change set without changes comes back as a plan with no changes and
+    /// is deleted right away, since it can never be executed.
+    pub async fn plan_stack_update(
+        &self,
+        account_id: &str,
+        region: &str,
+        request: &ChangeSetRequest,
+    ) -> Result<ChangeSetPlan> {
+        report_status(
+            "CloudFormation",
+            "create_change_set",
+            Some(&request.stack_name),
+        );
+
+        let change_set_id = self.create_change_set(account_id, region, request).await?;
+        let plan = self
+            .wait_for_change_set(account_id, region, &change_set_id, &request.stack_name)
+            .await?;
+
+        report_status_done(
+            "CloudFormation",
+            "create_change_set",
+            Some(&request.stack_name),
+        );
+        Ok(plan)
+    }
+
+    /// Poll a change set until CloudFormation has computed it
+    pub(super) async fn wait_for_change_set(
+        &self,
+        account_id: &str,
+        region: &str,
+        change_set_id: &str,
+        stack_name: &str,
+    ) -> Result<ChangeSetPlan> {
+        let started = std::time::Instant::now();
+
+        loop {
+            let (state, plan) = self
+                .describe_change_set(account_id, region, change_set_id)
+                .await?;
+            match state {
+                ChangeSetState::Ready => return Ok(plan),
+                ChangeSetState::Failed if is_no_changes_reason(plan.status_reason.as_deref()) => {
+                    if let Err(e) = self
+                        .delete_change_set(account_id, region, change_set_id)
+                        .await
+                    {
+                        tracing::warn!("Failed to delete empty change set: {:#}", e);
+                    }
+                    return Ok(plan);
+                }
+                ChangeSetState::Failed => {
+                    anyhow::bail!(
+                        "Change set for {} failed: {}",
+                        stack_name,
+                        plan.status_reason.as_deref().unwrap_or("no reason given")
+                    );
+                }
+                ChangeSetState::Pending => {}
+            }
+            if started.elapsed() > CREATE_TIMEOUT {
+                anyhow::bail!(
+                    "Change set for {} was not ready within {} seconds",
+                    stack_name,
+                    CREATE_TIMEOUT.as_secs()
+                );
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Execute an approved change set; the stack operation continues in CloudFormation
+    ///
+    /// Every attempt is recorded in the resource actions audit log.
+    pub async fn execute_change_set(
+        &self,
+        account_id: &str,
+        region: &str,
+        plan: &ChangeSetPlan,
+    ) -> Result<()> {
+        ensure_writable("Execute change set")?;
+
+        let result = async {
+            let client = self.create_client(account_id, region).await?;
+            client
+                .execute_change_set()
+                .change_set_name(&plan.change_set_id)
+                .send()
+                .await
+                .with_context(|| format!("Failed to execute change set {}", plan.change_set_name))?;
+            Ok::<(), anyhow::Error>(())
+        }
+        .await;
+
+        let (adds, modifies, removes) = plan.counts();
+        let entry = ActionAuditEntry {
+            timestamp: chrono::Utc::now(),
+            action_id: EXECUTE_ACTION_ID.to_string(),
+            account_id: account_id.to_string(),
+            region: region.to_string(),
+            resource_type: "AWS::CloudFormation::Stack".to_string(),
+            resource_id: plan.stack_name.clone(),
+            success: result.is_ok(),
+            message: match &result {
+                Ok(()) => format!(
+                    "Executed {}: {} add, {} modify, {} remove",
+                    plan.change_set_name, adds, modifies, removes
+                ),
+                Err(e) => format!("{:#}", e),
+            },
+        };
+        match ActionAuditLog::new() {
+            Ok(log) => {
+                if let Err(e) = log.record(&entry) {
+                    tracing::warn!("Failed to write change set audit entry: {:#}", e);
+                }
+            }
+            Err(e) => tracing::warn!("Change set audit log unavailable: {:#}", e),
+        }
+        result
+    }
+
+    /// Delete a change set that will not be executed
+    pub async fn delete_change_set(
+        &self,
+        account_id: &str,
+        region: &str,
+        change_set_id: &str,
+    ) -> Result<()> {
+        let client = self.create_client(account_id, region).await?;
+
+        client
+            .delete_change_set()
+            .change_set_name(change_set_id)
+            .send()
+            .await
+            .with_context(|| format!("Failed to delete change set {}", change_set_id))?;
+        Ok(())
+    }
+}
+
+/// Convert an SDK resource change into our type
+fn convert_resource_change(change: &cfn::types::ResourceChange) -> ResourceChange {
+    ResourceChange {
+        action: change
+            .action
+            .as_ref()
+            .map(|action| ChangeAction::from_sdk(action.as_str()))
+            .unwrap_or_else(|| ChangeAction::Unknown(String::new())),
+        logical_id: change.logical_resource_id.clone().unwrap_or_default(),
+        physical_id: change.physical_resource_id.clone(),
+        resource_type: change.resource_type.clone().unwrap_or_default(),
+        replacement: change
+            .replacement
+            .as_ref()
+            .map(|replacement| Replacement::from_sdk(replacement.as_str())),
+        details: change
+            .details
+            .iter()
+            .flatten()
+            .map(|detail| ChangeDetail {
+                attribute: detail
+                    .target
+                    .as_ref()
+                    .and_then(|target| target.attribute.as_ref())
+                    .map(|attribute| attribute.as_str().to_string())
+                    .unwrap_or_default(),
+                name: detail
+                    .target
+                    .as_ref()
+                    .and_then(|target| target.name.clone()),
+                requires_recreation: detail
+                    .target
+                    .as_ref()
+                    .and_then(|target| target.requires_recreation.as_ref())
+                    .map(|recreation| recreation.as_str().to_string()),
+                change_source: detail
+                    .change_source
+                    .as_ref()
+                    .map(|source| source.as_str().to_string()),
+                causing_entity: detail.causing_entity.clone(),
+            })
+            .collect(),
+    }
+}
+
+/// Removes first, then modifies, then adds; by logical ID within each
+fn sort_changes(changes: &mut [ResourceChange]) {
+    changes.sort_by(|a, b| (&a.action, &a.logical_id).cmp(&(&b.action, &b.logical_id)));
+}
+
+/// CloudFormation fails change sets that would not change anything
+fn is_no_changes_reason(reason: Option<&str>) -> bool {
+    reason.is_some_and(|reason| {
+        reason.contains("didn't contain changes")
+            || reason.contains("No updates are to be performed")
+    })
+}
+
+/// Unique change set name; names must start with a letter
+pub(super) fn change_set_name(now: chrono::DateTime<chrono::Utc>) -> String {
+    format!("awsdash-{}", now.format("%Y%m%d-%H%M%S"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(
+        action: ChangeAction,
+        logical_id: &str,
+        replacement: Option<Replacement>,
+    ) -> ResourceChange {
+        ResourceChange {
+            action,
+            logical_id: logical_id.to_string(),
+            physical_id: None,
+            resource_type: "AWS::S3::Bucket".to_string(),
+            replacement,
+            details: Vec::new(),
+        }
+    }
+
+    fn plan(changes: Vec<ResourceChange>) -> ChangeSetPlan {
+        ChangeSetPlan {
+            stack_name: "app".to_string(),
+            change_set_id: "arn:aws:cloudformation:us-east-1:123456789012:changeSet/awsdash/1"
+                .to_string(),
+            change_set_name: "awsdash".to_string(),
+            changes,
+            status_reason: None,
+            executable: true,
+        }
+    }
+
+    #[test]
+    fn test_plan_counts_sorting_and_warnings() {
+        let mut changes = vec![
+            change(ChangeAction::Add, "Queue", None),
+            change(ChangeAction::Modify, "Table", Some(Replacement::Never)),
+            change(ChangeAction::Remove, "OldBucket", None),
+            change(ChangeAction::Modify, "Bucket", Some(Replacement::Always)),
+            change(
+                ChangeAction::Modify,
+                "Function",
+                Some(Replacement::Conditional),
+            ),
+        ];
+        sort_changes(&mut changes);
+        let plan = plan(changes);
+
+        let order: Vec<&str> = plan.changes.iter().map(|c| c.logical_id.as_str()).collect();
+        assert_eq!(
+            order,
+            vec!["OldBucket", "Bucket", "Function", "Table", "Queue"]
+        );
+        assert_eq!(plan.counts(), (1, 3, 1));
+
+        let warnings: Vec<_> = plan
+            .destructive_changes()
+            .filter_map(|c| c.replacement_warning())
+            .collect();
+        assert_eq!(
+            warnings,
+            vec![
+                "Resource will be deleted",
+                "Resource will be replaced",
+                "Resource may be replaced"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sdk_value_mapping() {
+        assert_eq!(ChangeAction::from_sdk("Modify"), ChangeAction::Modify);
+        assert_eq!(ChangeAction::from_sdk("Other").label(), "Other");
+        assert_eq!(Replacement::from_sdk("True"), Replacement::Always);
+        assert_eq!(Replacement::from_sdk("False"), Replacement::Never);
+        assert_eq!(
+            ChangeSetState::from_sdk("CREATE_IN_PROGRESS"),
+            ChangeSetState::Pending
+        );
+        assert_eq!(
+            ChangeSetState::from_sdk("CREATE_COMPLETE"),
+            ChangeSetState::Ready
+        );
+        assert!(is_no_changes_reason(Some(
+            "The submitted information didn't contain changes. Submit different information to create a change set."
+        )));
+        assert!(!is_no_changes_reason(Some("Template format error")));
+        assert!(!is_no_changes_reason(None));
+
+        let detail = ChangeDetail {
+            attribute: "Properties".to_string(),
+            name: Some("BucketName".to_string()),
+            requires_recreation: Some("Always".to_string()),
+            change_source: None,
+            causing_entity: None,
+        };
+        assert_eq!(detail.target(), "Properties.BucketName");
+    }
+
+    #[test]
+    fn test_request_validation_and_name() {
+        let mut request = ChangeSetRequest {
+            stack_name: "app".to_string(),
+            template: TemplateSource::Current,
+            parameters: Vec::new(),
+            capabilities: Vec::new(),
+        };
+        assert!(request.validate().is_ok());
+
+        request.template = TemplateSource::Body("  ".to_string());
+        assert!(request.validate().is_err());
+
+        request.template = TemplateSource::Body("x".repeat(MAX_TEMPLATE_BODY_BYTES + 1));
+        let error = request.validate().unwrap_err().to_string();
+        assert!(error.contains("S3"));
+
+        let now = chrono::DateTime::from_timestamp(0, 0).unwrap();
+        assert_eq!(change_set_name(now), "awsdash-19700101-000000");
+    }
+}
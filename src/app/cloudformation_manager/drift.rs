@@ -0,0 +1,491 @@
+//! Stack Drift Detection
+//!
+//! Starts `DetectStackDrift`, polls `DescribeStackDriftDetectionStatus` until
+//! the detection finishes, and loads per-resource drift details with a
+//! structured diff of expected vs. actual properties.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_cloudformation as cfn;
+use serde_json::Value;
+use std::time::Duration;
+
+use super::manager::DeploymentManager;
+use crate::app::data_plane::config_history::diff::{diff_values, ChangeKind, ConfigChange};
+use crate::app::resource_explorer::status::{report_status, report_status_done};
+
+/// Delay between detection status checks
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Give up waiting for a detection after this long
+const DETECTION_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Root path for property diffs, matching the template section
+const PROPERTIES_PATH: &str = "Properties";
+
+/// State of a drift detection operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DetectionStatus {
+    InProgress,
+    Complete,
+    Failed,
+}
+
+impl DetectionStatus {
+    fn from_sdk(status: &str) -> Self {
+        match status {
+            "DETECTION_COMPLETE" => DetectionStatus::Complete,
+            "DETECTION_FAILED" => DetectionStatus::Failed,
+            _ => DetectionStatus::InProgress,
+        }
+    }
+}
+
+/// Result of one `DescribeStackDriftDetectionStatus` call
+#[derive(Debug, Clone)]
+pub struct DriftDetectionStatus {
+    pub detection_status: DetectionStatus,
+    /// Stack-level drift status (e.g., "DRIFTED", "IN_SYNC")
+    pub stack_drift_status: Option<String>,
+    /// Why the detection failed or only partially completed
+    pub reason: Option<String>,
+    pub drifted_resource_count: Option<i32>,
+}
+
+/// Drift status of a single stack resource, most significant first
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ResourceDriftStatus {
+    Deleted,
+    Modified,
+    InSync,
+    NotChecked,
+    Unknown(String),
+}
+
+impl ResourceDriftStatus {
+    fn from_sdk(status: &str) -> Self {
+        match status {
+            "DELETED" => ResourceDriftStatus::Deleted,
+            "MODIFIED" => ResourceDriftStatus::Modified,
+            "IN_SYNC" => ResourceDriftStatus::InSync,
+            "NOT_CHECKED" => ResourceDriftStatus::NotChecked,
+            other => ResourceDriftStatus::Unknown(other.to_string()),
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            ResourceDriftStatus::Deleted => "Deleted",
+            ResourceDriftStatus::Modified => "Modified",
+            ResourceDriftStatus::InSync => "In sync",
+            ResourceDriftStatus::NotChecked => "Not checked",
+            ResourceDriftStatus::Unknown(status) => status,
+        }
+    }
+
+    /// Whether the live resource no longer matches the template
+    pub fn is_drifted(&self) -> bool {
+        matches!(
+            self,
+            ResourceDriftStatus::Deleted | ResourceDriftStatus::Modified
+        )
+    }
+}
+
+/// A property difference as reported by CloudFormation
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyDifference {
+    /// JSON pointer style path (e.g., "/Tags/0/Value")
+    pub property_path: String,
+    pub expected_value: String,
+    pub actual_value: String,
+    /// "ADD", "REMOVE", or "NOT_EQUAL"
+    pub difference_type: String,
+}
+
+/// Drift details for one stack resource
+#[derive(Debug, Clone)]
+pub struct ResourceDrift {
+    pub logical_id: String,
+    pub physical_id: Option<String>,
+    pub resource_type: String,
+    pub status: ResourceDriftStatus,
+    /// Properties as defined by the template
+    pub expected_properties: Option<Value>,
+    /// Properties of the live resource
+    pub actual_properties: Option<Value>,
+    pub property_differences: Vec<PropertyDifference>,
+    pub checked_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl ResourceDrift {
+    /// Structured diff of expected -> actual properties
+    ///
+    /// Diffs the full property documents when CloudFormation returned both,
+    /// otherwise falls back to the reported property differences.
+    pub fn property_changes(&self) -> Vec<ConfigChange> {
+        if let (Some(expected), Some(actual)) = (&self.expected_properties, &self.actual_properties)
+        {
+            let mut changes = Vec::new();
+            diff_values(PROPERTIES_PATH, expected, actual, &mut changes);
+            return changes;
+        }
+
+        self.property_differences
+            .iter()
+            .map(|difference| {
+                let kind = match difference.difference_type.as_str() {
+                    "ADD" => ChangeKind::Added,
+                    "REMOVE" => ChangeKind::Removed,
+                    _ => ChangeKind::Modified,
+                };
+                ConfigChange {
+                    path: pointer_to_path(&difference.property_path),
+                    kind,
+                    before: (kind != ChangeKind::Added)
+                        .then(|| parse_property_value(&difference.expected_value)),
+                    after: (kind != ChangeKind::Removed)
+                        .then(|| parse_property_value(&difference.actual_value)),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Outcome of a completed drift detection
+#[derive(Debug, Clone)]
+pub struct StackDriftReport {
+    pub stack_name: String,
+    pub detection_id: String,
+    /// Stack-level drift status (e.g., "DRIFTED", "IN_SYNC")
+    pub stack_drift_status: String,
+    /// Set when the detection only partially completed
+    pub reason: Option<String>,
+    /// Sorted with drifted resources first, then by logical ID
+    pub resources: Vec<ResourceDrift>,
+    pub detected_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl StackDriftReport {
+    /// Resources that are modified or deleted
+    pub fn drifted_resources(&self) -> impl Iterator<Item = &ResourceDrift> {
+        self.resources
+            .iter()
+            .filter(|resource| resource.status.is_drifted())
+    }
+}
+
+impl DeploymentManager {
+    /// Start drift detection on a stack and return the detection ID
+    pub async fn detect_stack_drift(
+        &self,
+        account_id: &str,
+        region: &str,
+        stack_name: &str,
+    ) -> Result<String> {
+        let client = self.create_client(account_id, region).await?;
+
+        let response = client
+            .detect_stack_drift()
+            .stack_name(stack_name)
+            .send()
+            .await
+            .with_context(|| format!("Failed to start drift detection for {}", stack_name))?;
+
+        response
+            .stack_drift_detection_id
+            .context("DetectStackDrift returned no detection ID")
+    }
+
+    /// Check the status of a drift detection
+    pub async fn drift_detection_status(
+        &self,
+        account_id: &str,
+        region: &str,
+        detection_id: &str,
+    ) -> Result<DriftDetectionStatus> {
+        let client = self.create_client(account_id, region).await?;
+
+        let response = client
+            .describe_stack_drift_detection_status()
+            .stack_drift_detection_id(detection_id)
+            .send()
+            .await
+            .with_context(|| {
+                format!("Failed to get drift detection status for {}", detection_id)
+            })?;
+
+        Ok(DriftDetectionStatus {
+            detection_status: response
+                .detection_status
+                .as_ref()
+                .map(|status| DetectionStatus::from_sdk(status.as_str()))
+                .unwrap_or(DetectionStatus::InProgress),
+            stack_drift_status: response
+                .stack_drift_status
+                .map(|status| status.as_str().to_string()),
+            reason: response.detection_status_reason,
+            drifted_resource_count: response.drifted_stack_resource_count,
+        })
+    }
+
+    /// Load drift details for every resource checked in the last detection
+    pub async fn stack_resource_drifts(
+        &self,
+        account_id: &str,
+        region: &str,
+        stack_name: &str,
+    ) -> Result<Vec<ResourceDrift>> {
+        let client = self.create_client(account_id, region).await?;
+        let mut resources = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let response = client
+                .describe_stack_resource_drifts()
+                .stack_name(stack_name)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .with_context(|| {
+                    format!("Failed to describe resource drifts for {}", stack_name)
+                })?;
+
+            resources.extend(
+                response
+                    .stack_resource_drifts
+                    .unwrap_or_default()
+                    .iter()
+                    .map(convert_resource_drift),
+            );
+
+            next_token = response.next_token;
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        sort_resources(&mut resources);
+        Ok(resources)
+    }
+
+    /// Detect drift on a stack, wait for completion, and load resource details
+    pub async fn run_drift_detection(
+        &self,
+        account_id: &str,
+        region: &str,
+        stack_name: &str,
+    ) -> Result<StackDriftReport> {
+        report_status("CloudFormation", "detect_stack_drift", Some(stack_name));
+
+        let detection_id = self
+            .detect_stack_drift(account_id, region, stack_name)
+            .await?;
+        let started = std::time::Instant::now();
+
+        let status = loop {
+            let status = self
+                .drift_detection_status(account_id, region, &detection_id)
+                .await?;
+            match status.detection_status {
+                DetectionStatus::Complete => break status,
+                DetectionStatus::Failed if status.stack_drift_status.is_none() => {
+                    anyhow::bail!(
+                        "Drift detection failed for {}: {}",
+                        stack_name,
+                        status.reason.as_deref().unwrap_or("no reason given")
+                    );
+                }
+                // Partial failures still report a stack status; show what was checked
+                DetectionStatus::Failed => break status,
+                DetectionStatus::InProgress => {}
+            }
+            if started.elapsed() > DETECTION_TIMEOUT {
+                anyhow::bail!(
+                    "Drift detection for {} did not finish within {} seconds",
+                    stack_name,
+                    DETECTION_TIMEOUT.as_secs()
+                );
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        };
+
+        let resources = self
+            .stack_resource_drifts(account_id, region, stack_name)
+            .await?;
+
+        report_status_done("CloudFormation", "detect_stack_drift", Some(stack_name));
+        Ok(StackDriftReport {
+            stack_name: stack_name.to_string(),
+            detection_id,
+            stack_drift_status: status
+                .stack_drift_status
+                .unwrap_or_else(|| "UNKNOWN".to_string()),
+            reason: status.reason,
+            resources,
+            detected_at: chrono::Utc::now(),
+        })
+    }
+}
+
+/// Convert an SDK resource drift into our type
+fn convert_resource_drift(drift: &cfn::types::StackResourceDrift) -> ResourceDrift {
+    ResourceDrift {
+        logical_id: drift.logical_resource_id.clone().unwrap_or_default(),
+        physical_id: drift.physical_resource_id.clone(),
+        resource_type: drift.resource_type.clone().unwrap_or_default(),
+        status: drift
+            .stack_resource_drift_status
+            .as_ref()
+            .map(|status| ResourceDriftStatus::from_sdk(status.as_str()))
+            .unwrap_or(ResourceDriftStatus::NotChecked),
+        expected_properties: drift
+            .expected_properties
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok()),
+        actual_properties: drift
+            .actual_properties
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok()),
+        property_differences: drift
+            .property_differences
+            .iter()
+            .flatten()
+            .map(|difference| PropertyDifference {
+                property_path: difference.property_path.clone().unwrap_or_default(),
+                expected_value: difference.expected_value.clone().unwrap_or_default(),
+                actual_value: difference.actual_value.clone().unwrap_or_default(),
+                difference_type: difference
+                    .difference_type
+                    .as_ref()
+                    .map(|kind| kind.as_str().to_string())
+                    .unwrap_or_default(),
+            })
+            .collect(),
+        checked_at: drift
+            .timestamp
+            .and_then(|t| chrono::DateTime::from_timestamp(t.secs(), t.subsec_nanos())),
+    }
+}
+
+/// Drifted resources first, then by logical ID
+fn sort_resources(resources: &mut [ResourceDrift]) {
+    resources.sort_by(|a, b| (&a.status, &a.logical_id).cmp(&(&b.status, &b.logical_id)));
+}
+
+/// Convert a property path like "/Tags/0/Value" to "Properties.Tags[0].Value"
+fn pointer_to_path(pointer: &str) -> String {
+    let mut path = PROPERTIES_PATH.to_string();
+    for segment in pointer.split('/').filter(|s| !s.is_empty()) {
+        if segment.chars().all(|c| c.is_ascii_digit()) {
+            path.push_str(&format!("[{}]", segment));
+        } else {
+            path.push('.');
+            path.push_str(segment);
+        }
+    }
+    path
+}
+
+/// Property difference values are JSON when structured, plain text otherwise
+fn parse_property_value(value: &str) -> Value {
+    serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn drift(logical_id: &str, status: ResourceDriftStatus) -> ResourceDrift {
+        ResourceDrift {
+            logical_id: logical_id.to_string(),
+            physical_id: None,
+            resource_type: "AWS::S3::Bucket".to_string(),
+            status,
+            expected_properties: None,
+            actual_properties: None,
+            property_differences: Vec::new(),
+            checked_at: None,
+        }
+    }
+
+    #[test]
+    fn test_property_changes_from_documents() {
+        let mut resource = drift("Bucket", ResourceDriftStatus::Modified);
+        resource.expected_properties = Some(json!({
+            "BucketName": "data",
+            "Tags": [{ "Key": "env", "Value": "prod" }]
+        }));
+        resource.actual_properties = Some(json!({
+            "BucketName": "data",
+            "Tags": [{ "Key": "env", "Value": "dev" }],
+            "VersioningConfiguration": { "Status": "Enabled" }
+        }));
+
+        let changes = resource.property_changes();
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].path, "Properties.Tags[0].Value");
+        assert_eq!(changes[0].kind, ChangeKind::Modified);
+        assert_eq!(changes[0].before, Some(json!("prod")));
+        assert_eq!(changes[0].after, Some(json!("dev")));
+        assert_eq!(changes[1].path, "Properties.VersioningConfiguration");
+        assert_eq!(changes[1].kind, ChangeKind::Added);
+    }
+
+    #[test]
+    fn test_property_changes_from_differences() {
+        let mut resource = drift("Queue", ResourceDriftStatus::Modified);
+        resource.property_differences = vec![
+            PropertyDifference {
+                property_path: "/VisibilityTimeout".to_string(),
+                expected_value: "30".to_string(),
+                actual_value: "60".to_string(),
+                difference_type: "NOT_EQUAL".to_string(),
+            },
+            PropertyDifference {
+                property_path: "/Tags/1".to_string(),
+                expected_value: r#"{"Key":"team","Value":"data"}"#.to_string(),
+                actual_value: String::new(),
+                difference_type: "REMOVE".to_string(),
+            },
+        ];
+
+        let changes = resource.property_changes();
+        assert_eq!(changes[0].path, "Properties.VisibilityTimeout");
+        assert_eq!(changes[0].before, Some(json!(30)));
+        assert_eq!(changes[0].after, Some(json!(60)));
+        assert_eq!(changes[1].path, "Properties.Tags[1]");
+        assert_eq!(changes[1].kind, ChangeKind::Removed);
+        assert_eq!(
+            changes[1].before,
+            Some(json!({ "Key": "team", "Value": "data" }))
+        );
+        assert_eq!(changes[1].after, None);
+    }
+
+    #[test]
+    fn test_sort_puts_drifted_resources_first() {
+        let mut resources = vec![
+            drift("Alpha", ResourceDriftStatus::InSync),
+            drift("Zeta", ResourceDriftStatus::Modified),
+            drift("Beta", ResourceDriftStatus::NotChecked),
+            drift("Gamma", ResourceDriftStatus::Deleted),
+            drift("Delta", ResourceDriftStatus::Modified),
+        ];
+        sort_resources(&mut resources);
+
+        let order: Vec<&str> = resources.iter().map(|r| r.logical_id.as_str()).collect();
+        assert_eq!(order, vec!["Gamma", "Delta", "Zeta", "Alpha", "Beta"]);
+        assert_eq!(
+            ResourceDriftStatus::from_sdk("MODIFIED").label(),
+            "Modified"
+        );
+        assert!(!ResourceDriftStatus::from_sdk("IN_SYNC").is_drifted());
+        assert_eq!(
+            DetectionStatus::from_sdk("DETECTION_IN_PROGRESS"),
+            DetectionStatus::InProgress
+        );
+    }
+}
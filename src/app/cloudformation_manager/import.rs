@@ -0,0 +1,749 @@
+//! Resource Import
+//!
+//! Adopts live resources into a stack with an `IMPORT` change set. Resources
+//! selected in the Explorer become an import manifest (`ResourcesToImport`)
+//! and skeleton template entries with `DeletionPolicy: Retain`, either as a
+//! new stack's template or merged into an existing stack's template.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_cloudformation as cfn;
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+
+use super::change_set::{change_set_name, ChangeSetPlan, StackParameter, MAX_TEMPLATE_BODY_BYTES};
+use super::manager::DeploymentManager;
+use crate::app::resource_explorer::state::ResourceEntry;
+use crate::app::resource_explorer::status::{report_status, report_status_done};
+
+/// How to import one resource type
+struct ImportSpec {
+    resource_type: &'static str,
+    /// Identifier property CloudFormation matches the live resource by
+    identifier: &'static str,
+    /// Cached property holding the identifier; the resource ID when None
+    identifier_source: Option<&'static str>,
+    /// Template properties copied from cached properties (property, JSON pointer)
+    properties: &'static [(&'static str, &'static str)],
+    /// Required template properties the Explorer does not load
+    fill_in: &'static [&'static str],
+}
+
+const IMPORT_SPECS: &[ImportSpec] = &[
+    ImportSpec {
+        resource_type: "AWS::S3::Bucket",
+        identifier: "BucketName",
+        identifier_source: None,
+        properties: &[],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::DynamoDB::Table",
+        identifier: "TableName",
+        identifier_source: None,
+        properties: &[
+            ("KeySchema", "/KeySchema"),
+            ("AttributeDefinitions", "/AttributeDefinitions"),
+            ("BillingMode", "/BillingModeSummary/BillingMode"),
+        ],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::Lambda::Function",
+        identifier: "FunctionName",
+        identifier_source: None,
+        properties: &[
+            ("Role", "/Role"),
+            ("Runtime", "/Runtime"),
+            ("Handler", "/Handler"),
+        ],
+        fill_in: &["Code"],
+    },
+    ImportSpec {
+        resource_type: "AWS::SQS::Queue",
+        identifier: "QueueUrl",
+        identifier_source: Some("/QueueUrl"),
+        properties: &[],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::SNS::Topic",
+        identifier: "TopicArn",
+        identifier_source: None,
+        properties: &[],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::EC2::Instance",
+        identifier: "InstanceId",
+        identifier_source: None,
+        properties: &[
+            ("ImageId", "/ImageId"),
+            ("InstanceType", "/InstanceType"),
+            ("SubnetId", "/SubnetId"),
+        ],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::EC2::SecurityGroup",
+        identifier: "Id",
+        identifier_source: None,
+        properties: &[("GroupDescription", "/Description"), ("VpcId", "/VpcId")],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::EC2::VPC",
+        identifier: "VpcId",
+        identifier_source: None,
+        properties: &[("CidrBlock", "/CidrBlock")],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::EC2::Subnet",
+        identifier: "SubnetId",
+        identifier_source: None,
+        properties: &[("VpcId", "/VpcId"), ("CidrBlock", "/CidrBlock")],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::EC2::Volume",
+        identifier: "VolumeId",
+        identifier_source: None,
+        properties: &[
+            ("AvailabilityZone", "/AvailabilityZone"),
+            ("Size", "/Size"),
+            ("VolumeType", "/VolumeType"),
+        ],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::IAM::Role",
+        identifier: "RoleName",
+        identifier_source: None,
+        properties: &[
+            ("AssumeRolePolicyDocument", "/AssumeRolePolicyDocument"),
+            ("Path", "/Path"),
+        ],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::IAM::User",
+        identifier: "UserName",
+        identifier_source: None,
+        properties: &[("Path", "/Path")],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::Logs::LogGroup",
+        identifier: "LogGroupName",
+        identifier_source: None,
+        properties: &[("RetentionInDays", "/RetentionInDays")],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::KMS::Key",
+        identifier: "KeyId",
+        identifier_source: None,
+        properties: &[],
+        fill_in: &["KeyPolicy"],
+    },
+    ImportSpec {
+        resource_type: "AWS::ECR::Repository",
+        identifier: "RepositoryName",
+        identifier_source: None,
+        properties: &[],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::RDS::DBInstance",
+        identifier: "DBInstanceIdentifier",
+        identifier_source: None,
+        properties: &[
+            ("DBInstanceClass", "/DBInstanceClass"),
+            ("Engine", "/Engine"),
+        ],
+        fill_in: &[],
+    },
+    ImportSpec {
+        resource_type: "AWS::ECS::Cluster",
+        identifier: "ClusterName",
+        identifier_source: None,
+        properties: &[],
+        fill_in: &[],
+    },
+];
+
+fn import_spec(resource_type: &str) -> Option<&'static ImportSpec> {
+    IMPORT_SPECS
+        .iter()
+        .find(|spec| spec.resource_type == resource_type)
+}
+
+/// Whether resources of this type can be imported from the Explorer
+pub fn supports_import(resource_type: &str) -> bool {
+    import_spec(resource_type).is_some()
+}
+
+/// A live resource to adopt into a stack
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImportResource {
+    pub resource_type: String,
+    /// Logical ID in the template; editable before importing
+    pub logical_id: String,
+    pub identifier_property: String,
+    pub identifier_value: String,
+    /// Template properties taken from the cached resource
+    pub properties: Map<String, Value>,
+    /// Required properties the template still needs
+    pub fill_in: Vec<String>,
+    pub display_name: String,
+}
+
+impl ImportResource {
+    /// Template entry for this resource; imports require `DeletionPolicy`
+    pub fn template_entry(&self) -> Value {
+        let mut properties = self.properties.clone();
+        properties.insert(
+            self.identifier_property.clone(),
+            Value::String(self.identifier_value.clone()),
+        );
+        json!({
+            "Type": self.resource_type,
+            "DeletionPolicy": "Retain",
+            "UpdateReplacePolicy": "Retain",
+            "Properties": properties,
+        })
+    }
+}
+
+/// Resources that could not be prepared for import, with the reason
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedResource {
+    pub display_name: String,
+    pub resource_type: String,
+    pub reason: String,
+}
+
+/// Prepare Explorer resources for import
+///
+/// Logical IDs are generated from display names and avoid `reserved` (the
+/// logical IDs already in the target template).
+pub fn build_import_resources(
+    resources: &[ResourceEntry],
+    reserved: &HashSet<String>,
+) -> (Vec<ImportResource>, Vec<SkippedResource>) {
+    let mut taken = reserved.clone();
+    let mut imports = Vec::new();
+    let mut skipped = Vec::new();
+
+    for resource in resources {
+        match prepare_import(resource, &mut taken) {
+            Ok(import) => imports.push(import),
+            Err(skip) => skipped.push(skip),
+        }
+    }
+    (imports, skipped)
+}
+
+/// Map one Explorer resource to its template entry, claiming a logical ID in `taken`
+pub(super) fn prepare_import(
+    resource: &ResourceEntry,
+    taken: &mut HashSet<String>,
+) -> Result<ImportResource, SkippedResource> {
+    let skip = |reason: String| SkippedResource {
+        display_name: resource.display_name.clone(),
+        resource_type: resource.resource_type.clone(),
+        reason,
+    };
+    let spec = import_spec(&resource.resource_type)
+        .ok_or_else(|| skip("Import is not supported for this resource type".to_string()))?;
+
+    let identifier_value = match spec.identifier_source {
+        Some(pointer) => resource
+            .properties
+            .pointer(pointer)
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        None => Some(resource.resource_id.clone()),
+    };
+    let identifier_value = identifier_value
+        .filter(|value| !value.is_empty())
+        .ok_or_else(|| skip(format!("{} is not loaded", spec.identifier)))?;
+
+    let mut properties = Map::new();
+    let mut fill_in: Vec<String> = spec.fill_in.iter().map(|p| p.to_string()).collect();
+    for (property, pointer) in spec.properties {
+        match resource
+            .properties
+            .pointer(pointer)
+            .and_then(template_value)
+        {
+            Some(value) => {
+                properties.insert(property.to_string(), value);
+            }
+            None => fill_in.push(property.to_string()),
+        }
+    }
+
+    let logical_id = logical_id_for(&resource.display_name, &resource.resource_type, taken);
+    taken.insert(logical_id.clone());
+    Ok(ImportResource {
+        resource_type: resource.resource_type.clone(),
+        logical_id,
+        identifier_property: spec.identifier.to_string(),
+        identifier_value,
+        properties,
+        fill_in,
+        display_name: resource.display_name.clone(),
+    })
+}
+
+/// Convert a cached property into a template value
+///
+/// Policy documents are cached as JSON strings; URL-encoded documents are
+/// left for the user to fill in.
+fn template_value(value: &Value) -> Option<Value> {
+    match value {
+        Value::Null => None,
+        Value::String(text) if text.starts_with('{') => serde_json::from_str(text).ok(),
+        Value::String(text) if text.starts_with("%7B") => None,
+        other => Some(other.clone()),
+    }
+}
+
+/// Alphanumeric logical ID from a display name, unique among `taken`
+pub fn logical_id_for(display_name: &str, resource_type: &str, taken: &HashSet<String>) -> String {
+    let mut base: String = display_name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    if !base.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        let type_name = resource_type.rsplit("::").next().unwrap_or("Resource");
+        base = format!("{}{}", type_name, base);
+    }
+    // Logical IDs are limited to 255 characters
+    base.truncate(240);
+
+    if !taken.contains(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{}{}", base, n))
+        .find(|candidate| !taken.contains(candidate))
+        .unwrap_or(base)
+}
+
+/// Whether a logical ID is valid in a template
+pub fn is_valid_logical_id(logical_id: &str) -> bool {
+    !logical_id.is_empty()
+        && logical_id.len() <= 255
+        && logical_id.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+/// `ResourcesToImport` manifest in the format the AWS CLI accepts
+pub fn import_manifest(resources: &[ImportResource]) -> Value {
+    Value::Array(
+        resources
+            .iter()
+            .map(|resource| {
+                json!({
+                    "ResourceType": resource.resource_type,
+                    "LogicalResourceId": resource.logical_id,
+                    "ResourceIdentifier": { resource.identifier_property.clone(): resource.identifier_value },
+                })
+            })
+            .collect(),
+    )
+}
+
+/// Template for a new stack containing only the imported resources
+pub fn skeleton_template(resources: &[ImportResource]) -> String {
+    let entries: Map<String, Value> = resources
+        .iter()
+        .map(|resource| (resource.logical_id.clone(), resource.template_entry()))
+        .collect();
+    let template = json!({
+        "AWSTemplateFormatVersion": "2010-09-09",
+        "Description": "Resources imported with AWS Dash",
+        "Resources": entries,
+    });
+    serde_json::to_string_pretty(&template).unwrap_or_default()
+}
+
+/// Logical IDs already defined in a JSON or YAML template
+pub fn template_logical_ids(template_body: &str) -> HashSet<String> {
+    let resources = if is_json(template_body) {
+        serde_json::from_str::<Value>(template_body)
+            .ok()
+            .and_then(|template| template.get("Resources").cloned())
+            .and_then(|resources| {
+                resources
+                    .as_object()
+                    .map(|map| map.keys().cloned().collect())
+            })
+    } else {
+        serde_yaml::from_str::<serde_yaml::Value>(template_body)
+            .ok()
+            .and_then(|template| template.get("Resources").cloned())
+            .and_then(|resources| {
+                resources.as_mapping().map(|map| {
+                    map.keys()
+                        .filter_map(|key| key.as_str().map(str::to_string))
+                        .collect()
+                })
+            })
+    };
+    resources.unwrap_or_default()
+}
+
+/// Add imported resources to an existing stack's template, keeping its format
+///
+/// YAML templates keep their short-form intrinsic functions (`!Ref`, `!Sub`).
+pub fn merge_into_template(template_body: &str, resources: &[ImportResource]) -> Result<String> {
+    if is_json(template_body) {
+        let mut template: Value =
+            serde_json::from_str(template_body).context("Failed to parse the stack template")?;
+        let entries = template
+            .get_mut("Resources")
+            .and_then(Value::as_object_mut)
+            .context("The stack template has no Resources section")?;
+        for resource in resources {
+            entries.insert(resource.logical_id.clone(), resource.template_entry());
+        }
+        serde_json::to_string_pretty(&template).context("Failed to write the merged template")
+    } else {
+        let mut template: serde_yaml::Value =
+            serde_yaml::from_str(template_body).context("Failed to parse the stack template")?;
+        let entries = template
+            .get_mut("Resources")
+            .and_then(serde_yaml::Value::as_mapping_mut)
+            .context("The stack template has no Resources section")?;
+        for resource in resources {
+            entries.insert(
+                serde_yaml::Value::String(resource.logical_id.clone()),
+                serde_yaml::to_value(resource.template_entry())
+                    .context("Failed to convert template entry")?,
+            );
+        }
+        serde_yaml::to_string(&template).context("Failed to write the merged template")
+    }
+}
+
+fn is_json(template_body: &str) -> bool {
+    template_body.trim_start().starts_with('{')
+}
+
+/// Everything needed to create an import change set
+#[derive(Debug, Clone)]
+pub struct ImportRequest {
+    /// New or existing stack
+    pub stack_name: String,
+    /// Full template: the skeleton for a new stack, or the merged template
+    pub template_body: String,
+    pub resources: Vec<ImportResource>,
+    /// Existing stack parameters (kept at their current values)
+    pub parameters: Vec<StackParameter>,
+    pub capabilities: Vec<String>,
+}
+
+impl ImportRequest {
+    /// Check the request before calling CloudFormation
+    pub fn validate(&self) -> Result<()> {
+        if self.stack_name.trim().is_empty() {
+            anyhow::bail!("Enter a stack name");
+        }
+        if self.resources.is_empty() {
+            anyhow::bail!("No resources to import");
+        }
+        let mut seen = HashSet::new();
+        for resource in &self.resources {
+            if !is_valid_logical_id(&resource.logical_id) {
+                anyhow::bail!(
+                    "Logical ID '{}' must be alphanumeric (A-Z, a-z, 0-9)",
+                    resource.logical_id
+                );
+            }
+            if !seen.insert(&resource.logical_id) {
+                anyhow::bail!(
+                    "Logical ID '{}' is used more than once",
+                    resource.logical_id
+                );
+            }
+        }
+        if self.template_body.len() > MAX_TEMPLATE_BODY_BYTES {
+            anyhow::bail!(
+                "Template is {} bytes; templates over {} bytes must be uploaded to S3",
+                self.template_body.len(),
+                MAX_TEMPLATE_BODY_BYTES
+            );
+        }
+        Ok(())
+    }
+}
+
+impl DeploymentManager {
+    /// Create an import change set and wait until CloudFormation has computed it
+    pub async fn plan_import(
+        &self,
+        account_id: &str,
+        region: &str,
+        request: &ImportRequest,
+    ) -> Result<ChangeSetPlan> {
+        request.validate()?;
+        report_status(
+            "CloudFormation",
+            "create_import_change_set",
+            Some(&request.stack_name),
+        );
+        let client = self.create_client(account_id, region).await?;
+
+        let resources_to_import = request
+            .resources
+            .iter()
+            .map(|resource| {
+                cfn::types::ResourceToImport::builder()
+                    .resource_type(&resource.resource_type)
+                    .logical_resource_id(&resource.logical_id)
+                    .resource_identifier(&resource.identifier_property, &resource.identifier_value)
+                    .build()
+                    .with_context(|| format!("Invalid import for {}", resource.logical_id))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let parameters = request
+            .parameters
+            .iter()
+            .map(|parameter| {
+                cfn::types::Parameter::builder()
+                    .parameter_key(&parameter.key)
+                    .use_previous_value(true)
+                    .build()
+            })
+            .collect();
+        let capabilities = request
+            .capabilities
+            .iter()
+            .map(|capability| cfn::types::Capability::from(capability.as_str()))
+            .collect();
+
+        let response = client
+            .create_change_set()
+            .stack_name(request.stack_name.trim())
+            .change_set_name(change_set_name(chrono::Utc::now()))
+            .change_set_type(cfn::types::ChangeSetType::Import)
+            .description("Resource import created by AWS Dash")
+            .template_body(&request.template_body)
+            .set_resources_to_import(Some(resources_to_import))
+            .set_parameters(Some(parameters))
+            .set_capabilities(Some(capabilities))
+            .send()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create import change set for {}",
+                    request.stack_name
+                )
+            })?;
+        let change_set_id = response
+            .id
+            .context("CreateChangeSet returned no change set ID")?;
+
+        let plan = self
+            .wait_for_change_set(account_id, region, &change_set_id, &request.stack_name)
+            .await?;
+
+        report_status_done(
+            "CloudFormation",
+            "create_import_change_set",
+            Some(&request.stack_name),
+        );
+        Ok(plan)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(
+        resource_type: &str,
+        resource_id: &str,
+        display_name: &str,
+        properties: Value,
+    ) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: resource_id.to_string(),
+            display_name: display_name.to_string(),
+            status: None,
+            properties,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_logical_ids() {
+        let mut taken = HashSet::new();
+        assert_eq!(
+            logical_id_for("my-data_bucket", "AWS::S3::Bucket", &taken),
+            "MyDataBucket"
+        );
+        assert_eq!(
+            logical_id_for("2024 logs", "AWS::S3::Bucket", &taken),
+            "Bucket2024Logs"
+        );
+        assert_eq!(logical_id_for("---", "AWS::SQS::Queue", &taken), "Queue");
+
+        taken.insert("Orders".to_string());
+        taken.insert("Orders2".to_string());
+        assert_eq!(
+            logical_id_for("orders", "AWS::DynamoDB::Table", &taken),
+            "Orders3"
+        );
+
+        assert!(is_valid_logical_id("Orders3"));
+        assert!(!is_valid_logical_id("orders-table"));
+        assert!(!is_valid_logical_id(""));
+    }
+
+    #[test]
+    fn test_build_import_resources_and_manifest() {
+        let resources = vec![
+            entry(
+                "AWS::SQS::Queue",
+                "jobs",
+                "jobs",
+                json!({ "QueueUrl": "https://sqs.us-east-1.amazonaws.com/123456789012/jobs" }),
+            ),
+            entry(
+                "AWS::IAM::Role",
+                "app-role",
+                "app-role",
+                json!({
+                    "AssumeRolePolicyDocument": "{\"Version\":\"2012-10-17\",\"Statement\":[]}",
+                    "Path": "/"
+                }),
+            ),
+            entry(
+                "AWS::Lambda::Function",
+                "worker",
+                "worker",
+                json!({ "Runtime": "python3.12" }),
+            ),
+            entry("AWS::EC2::Snapshot", "snap-1", "snap-1", json!({})),
+            entry("AWS::SQS::Queue", "orphan", "orphan", json!({})),
+        ];
+        let reserved: HashSet<String> = ["Jobs".to_string()].into_iter().collect();
+        let (imports, skipped) = build_import_resources(&resources, &reserved);
+
+        assert_eq!(imports.len(), 3);
+        assert_eq!(imports[0].logical_id, "Jobs2");
+        assert_eq!(imports[0].identifier_property, "QueueUrl");
+        assert!(imports[0].identifier_value.ends_with("/jobs"));
+        assert_eq!(
+            imports[1].properties["AssumeRolePolicyDocument"],
+            json!({ "Version": "2012-10-17", "Statement": [] })
+        );
+        assert_eq!(imports[2].fill_in, vec!["Code", "Role", "Handler"]);
+
+        assert_eq!(skipped.len(), 2);
+        assert_eq!(skipped[0].resource_type, "AWS::EC2::Snapshot");
+        assert_eq!(skipped[1].reason, "QueueUrl is not loaded");
+
+        let manifest = import_manifest(&imports);
+        assert_eq!(manifest[1]["LogicalResourceId"], "AppRole");
+        assert_eq!(manifest[1]["ResourceIdentifier"]["RoleName"], "app-role");
+
+        let template: Value = serde_json::from_str(&skeleton_template(&imports)).unwrap();
+        let role = &template["Resources"]["AppRole"];
+        assert_eq!(role["DeletionPolicy"], "Retain");
+        assert_eq!(role["Properties"]["RoleName"], "app-role");
+        assert_eq!(role["Properties"]["Path"], "/");
+    }
+
+    #[test]
+    fn test_merge_into_json_and_yaml_templates() {
+        let (imports, _) = build_import_resources(
+            &[entry(
+                "AWS::S3::Bucket",
+                "logs-bucket",
+                "logs-bucket",
+                json!({}),
+            )],
+            &HashSet::new(),
+        );
+
+        let json_template = r#"{ "Resources": { "Queue": { "Type": "AWS::SQS::Queue" } } }"#;
+        assert_eq!(
+            template_logical_ids(json_template),
+            ["Queue".to_string()].into_iter().collect()
+        );
+        let merged: Value =
+            serde_json::from_str(&merge_into_template(json_template, &imports).unwrap()).unwrap();
+        assert_eq!(
+            merged["Resources"]["LogsBucket"]["Properties"]["BucketName"],
+            "logs-bucket"
+        );
+        assert!(merged["Resources"]["Queue"].is_object());
+
+        let yaml_template = "Resources:\n  Topic:\n    Type: AWS::SNS::Topic\n    Properties:\n      TopicName: !Sub '${AWS::StackName}-events'\n";
+        assert_eq!(
+            template_logical_ids(yaml_template),
+            ["Topic".to_string()].into_iter().collect()
+        );
+        let merged = merge_into_template(yaml_template, &imports).unwrap();
+        assert!(merged.contains("!Sub"));
+        assert!(merged.contains("LogsBucket:"));
+        assert!(merged.contains("DeletionPolicy: Retain"));
+
+        assert!(merge_into_template("{}", &imports).is_err());
+    }
+
+    #[test]
+    fn test_import_request_validation() {
+        let (imports, _) = build_import_resources(
+            &[entry("AWS::S3::Bucket", "data", "data", json!({}))],
+            &HashSet::new(),
+        );
+        let mut request = ImportRequest {
+            stack_name: "adopted".to_string(),
+            template_body: skeleton_template(&imports),
+            resources: imports,
+            parameters: Vec::new(),
+            capabilities: Vec::new(),
+        };
+        assert!(request.validate().is_ok());
+
+        request.resources.push(request.resources[0].clone());
+        assert!(request
+            .validate()
+            .unwrap_err()
+            .to_string()
+            .contains("more than once"));
+
+        request.resources.pop();
+        request.resources[0].logical_id = "data-bucket".to_string();
+        assert!(request.validate().is_err());
+
+        request.resources[0].logical_id = "Data".to_string();
+        request.stack_name = " ".to_string();
+        assert!(request.validate().is_err());
+    }
+}
@@ -0,0 +1,47 @@
+//! CloudFormation Deployment Manager
+//!
+//! Creates CloudFormation clients per account and region. Workflows such as
+//! drift detection are implemented in their own modules as `impl` blocks on
+//! [`DeploymentManager`].
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_cloudformation as cfn;
+use std::sync::Arc;
+
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+/// CloudFormation stack operations with credential management
+#[derive(Clone)]
+pub struct DeploymentManager {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl DeploymentManager {
+    /// Create a new deployment manager
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    pub(super) async fn create_client(
+        &self,
+        account_id: &str,
+        region: &str,
+    ) -> Result<cfn::Client> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        Ok(cfn::Client::new(&aws_config))
+    }
+}
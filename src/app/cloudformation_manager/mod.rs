@@ -0,0 +1,60 @@
+//! CloudFormation Stack Management
+//!
+//! Operations on existing CloudFormation stacks, driven from the Resource
+//! Explorer. [`DeploymentManager`] owns the CloudFormation clients and the
+//! long-running workflows that need polling.
+//!
+//! ## Features
+//!
+//! - Drift detection: trigger `DetectStackDrift`, wait for it to finish, and
+//!   compare each resource's expected and actual properties
+//! - Change-set-first updates: plan an update as a change set, review the
+//!   adds, modifies, and removes, and execute only after approval
+//! - Resource import: adopt live resources into a new or existing stack with
+//!   a generated import manifest and skeleton template
+//! - Template generation: describe selected live resources as a template,
+//!   locally from cached properties or with the IaC Generator
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::cloudformation_manager::DeploymentManager;
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let manager = DeploymentManager::new(credential_coordinator);
+//!
+//! let report = manager
+//!     .run_drift_detection("123456789012", "us-east-1", "my-stack")
+//!     .await?;
+//!
+//! for resource in report.drifted_resources() {
+//!     for change in resource.property_changes() {
+//!         println!("{} {} {}", resource.logical_id, change.kind.marker(), change.path);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod change_set;
+pub mod drift;
+pub mod import;
+pub mod manager;
+pub mod template_generator;
+
+// Re-export commonly used types
+pub use change_set::{
+    ChangeAction, ChangeDetail, ChangeSetPlan, ChangeSetRequest, Replacement, ResourceChange,
+    StackParameter, StackUpdateDefaults, TemplateSource,
+};
+pub use drift::{
+    DetectionStatus, DriftDetectionStatus, PropertyDifference, ResourceDrift, ResourceDriftStatus,
+    StackDriftReport,
+};
+pub use import::{ImportRequest, ImportResource, SkippedResource};
+pub use manager::DeploymentManager;
+pub use template_generator::{GeneratedTemplate, GenerationMethod};
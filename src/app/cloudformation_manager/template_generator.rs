@@ -0,0 +1,337 @@
+//! Template Generation
+//!
+//! Builds a CloudFormation template describing live resources selected in the
+//! Explorer. Templates are generated either locally, by reverse-mapping cached
+//! properties through the import specs, or by the CloudFormation IaC
+//! Generator, which reads each resource's full configuration.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_cloudformation as cfn;
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+use std::time::Duration;
+
+use super::import::{prepare_import, ImportResource};
+use super::manager::DeploymentManager;
+use crate::app::capabilities::ensure_writable;
+use crate::app::resource_explorer::state::ResourceEntry;
+use crate::app::resource_explorer::status::{report_status, report_status_done};
+
+/// How often to poll the IaC Generator while it builds a template
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Give up waiting for the IaC Generator after this long
+const GENERATE_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Where a generated template came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GenerationMethod {
+    /// Reverse-mapped from properties the Explorer has cached
+    Local,
+    /// Generated by CloudFormation's IaC Generator
+    IacGenerator,
+}
+
+impl GenerationMethod {
+    pub fn label(&self) -> &'static str {
+        match self {
+            GenerationMethod::Local => "Local",
+            GenerationMethod::IacGenerator => "IaC Generator",
+        }
+    }
+}
+
+/// A generated template with notes about what it could not capture
+#[derive(Debug, Clone)]
+pub struct GeneratedTemplate {
+    /// Template as pretty-printed JSON
+    pub body: String,
+    pub method: GenerationMethod,
+    pub resource_count: usize,
+    /// Skipped resources and properties that need filling in
+    pub warnings: Vec<String>,
+}
+
+impl GeneratedTemplate {
+    /// The template converted to YAML
+    pub fn to_yaml(&self) -> Result<String> {
+        let template: Value =
+            serde_json::from_str(&self.body).context("Failed to parse the generated template")?;
+        serde_yaml::to_string(&template).context("Failed to convert the template to YAML")
+    }
+}
+
+/// Generate a template from cached Explorer properties
+///
+/// Only properties the Explorer loads are included; required properties it
+/// does not have are reported as warnings. Tags are copied except the
+/// reserved `aws:` ones.
+pub fn template_from_resources(resources: &[ResourceEntry]) -> GeneratedTemplate {
+    let mut taken = HashSet::new();
+    let mut entries = Map::new();
+    let mut warnings = Vec::new();
+
+    for resource in resources {
+        let import = match prepare_import(resource, &mut taken) {
+            Ok(import) => import,
+            Err(skipped) => {
+                warnings.push(format!(
+                    "Skipped {} ({}): {}",
+                    skipped.display_name, skipped.resource_type, skipped.reason
+                ));
+                continue;
+            }
+        };
+        if !import.fill_in.is_empty() {
+            warnings.push(format!(
+                "{}: fill in {}",
+                import.logical_id,
+                import.fill_in.join(", ")
+            ));
+        }
+
+        let mut entry = import.template_entry();
+        let tags: Vec<Value> = resource
+            .tags
+            .iter()
+            .filter(|tag| !tag.key.starts_with("aws:"))
+            .map(|tag| json!({ "Key": tag.key, "Value": tag.value }))
+            .collect();
+        if !tags.is_empty() {
+            entry["Properties"]["Tags"] = Value::Array(tags);
+        }
+        entries.insert(import.logical_id, entry);
+    }
+
+    let resource_count = entries.len();
+    let template = json!({
+        "AWSTemplateFormatVersion": "2010-09-09",
+        "Description": "Generated by AWS Dash from live resources",
+        "Resources": entries,
+    });
+    GeneratedTemplate {
+        body: serde_json::to_string_pretty(&template).unwrap_or_default(),
+        method: GenerationMethod::Local,
+        resource_count,
+        warnings,
+    }
+}
+
+impl DeploymentManager {
+    /// Generate a template with the CloudFormation IaC Generator
+    ///
+    /// Creates a generated template in the account, waits for it, and deletes
+    /// it again after downloading the body. All resources must be in
+    /// `account_id` and `region`.
+    pub async fn generate_template(
+        &self,
+        account_id: &str,
+        region: &str,
+        resources: &[ResourceEntry],
+    ) -> Result<GeneratedTemplate> {
+        // Creates a generated template resource in the account
+        ensure_writable("Generate template with IaC Generator")?;
+
+        let mut taken = HashSet::new();
+        let mut imports: Vec<ImportResource> = Vec::new();
+        let mut warnings = Vec::new();
+        for resource in resources {
+            match prepare_import(resource, &mut taken) {
+                Ok(import) => imports.push(import),
+                Err(skipped) => warnings.push(format!(
+                    "Skipped {} ({}): {}",
+                    skipped.display_name, skipped.resource_type, skipped.reason
+                )),
+            }
+        }
+        if imports.is_empty() {
+            anyhow::bail!("None of the selected resources can be generated");
+        }
+
+        let name = format!("aws-dash-{}", chrono::Utc::now().format("%Y%m%d-%H%M%S"));
+        report_status("CloudFormation", "create_generated_template", Some(&name));
+        let client = self.create_client(account_id, region).await?;
+
+        let definitions = imports
+            .iter()
+            .map(|import| {
+                cfn::types::ResourceDefinition::builder()
+                    .resource_type(&import.resource_type)
+                    .logical_resource_id(&import.logical_id)
+                    .resource_identifier(&import.identifier_property, &import.identifier_value)
+                    .build()
+                    .with_context(|| {
+                        format!("Invalid resource definition for {}", import.logical_id)
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let configuration = cfn::types::TemplateConfiguration::builder()
+            .deletion_policy(cfn::types::GeneratedTemplateDeletionPolicy::Retain)
+            .update_replace_policy(cfn::types::GeneratedTemplateUpdateReplacePolicy::Retain)
+            .build();
+
+        client
+            .create_generated_template()
+            .generated_template_name(&name)
+            .set_resources(Some(definitions))
+            .template_configuration(configuration)
+            .send()
+            .await
+            .context("Failed to create generated template")?;
+
+        let result = self.wait_for_generated_template(&client, &name).await;
+
+        // The generated template is only a vehicle for the body; don't leave it behind
+        if let Err(e) = client
+            .delete_generated_template()
+            .generated_template_name(&name)
+            .send()
+            .await
+        {
+            tracing::warn!("Failed to delete generated template {}: {:?}", name, e);
+        }
+        report_status_done("CloudFormation", "create_generated_template", Some(&name));
+
+        let body = result?;
+        let template: Value =
+            serde_json::from_str(&body).context("IaC Generator returned an invalid template")?;
+        let resource_count = template
+            .get("Resources")
+            .and_then(Value::as_object)
+            .map(|resources| resources.len())
+            .unwrap_or_default();
+        Ok(GeneratedTemplate {
+            body: serde_json::to_string_pretty(&template).unwrap_or(body),
+            method: GenerationMethod::IacGenerator,
+            resource_count,
+            warnings,
+        })
+    }
+
+    /// Poll until the generated template is complete and return its JSON body
+    async fn wait_for_generated_template(
+        &self,
+        client: &cfn::Client,
+        name: &str,
+    ) -> Result<String> {
+        let started = std::time::Instant::now();
+        loop {
+            let description = client
+                .describe_generated_template()
+                .generated_template_name(name)
+                .send()
+                .await
+                .context("Failed to describe generated template")?;
+            match description.status.as_ref().map(|status| status.as_str()) {
+                Some("COMPLETE") => break,
+                Some("FAILED") => anyhow::bail!(
+                    "IaC Generator failed: {}",
+                    description
+                        .status_reason
+                        .as_deref()
+                        .unwrap_or("no reason given")
+                ),
+                _ => {}
+            }
+            if started.elapsed() > GENERATE_TIMEOUT {
+                anyhow::bail!(
+                    "IaC Generator did not finish within {} seconds",
+                    GENERATE_TIMEOUT.as_secs()
+                );
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+
+        let response = client
+            .get_generated_template()
+            .generated_template_name(name)
+            .format(cfn::types::TemplateFormat::Json)
+            .send()
+            .await
+            .context("Failed to get generated template")?;
+        response
+            .template_body
+            .context("IaC Generator returned no template body")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::resource_explorer::state::ResourceTag;
+
+    fn entry(resource_type: &str, resource_id: &str, properties: Value) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: resource_id.to_string(),
+            display_name: resource_id.to_string(),
+            status: None,
+            properties,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_template_from_resources() {
+        let mut bucket = entry("AWS::S3::Bucket", "app-logs", json!({}));
+        bucket.tags = vec![
+            ResourceTag {
+                key: "team".to_string(),
+                value: "platform".to_string(),
+            },
+            ResourceTag {
+                key: "aws:cloudformation:stack-name".to_string(),
+                value: "old".to_string(),
+            },
+        ];
+        let resources = vec![
+            bucket,
+            entry(
+                "AWS::Lambda::Function",
+                "worker",
+                json!({ "Runtime": "nodejs20.x", "Handler": "index.handler" }),
+            ),
+            entry("AWS::EC2::Snapshot", "snap-1", json!({})),
+        ];
+
+        let generated = template_from_resources(&resources);
+        assert_eq!(generated.method, GenerationMethod::Local);
+        assert_eq!(generated.resource_count, 2);
+        assert_eq!(
+            generated.warnings,
+            vec![
+                "Worker: fill in Code, Role".to_string(),
+                "Skipped snap-1 (AWS::EC2::Snapshot): Import is not supported for this resource type"
+                    .to_string(),
+            ]
+        );
+
+        let template: Value = serde_json::from_str(&generated.body).unwrap();
+        let bucket = &template["Resources"]["AppLogs"];
+        assert_eq!(bucket["Properties"]["BucketName"], "app-logs");
+        assert_eq!(
+            bucket["Properties"]["Tags"],
+            json!([{ "Key": "team", "Value": "platform" }])
+        );
+        assert_eq!(
+            template["Resources"]["Worker"]["Properties"]["Runtime"],
+            "nodejs20.x"
+        );
+
+        let yaml = generated.to_yaml().unwrap();
+        assert!(yaml.contains("AppLogs:"));
+        assert!(yaml.contains("BucketName: app-logs"));
+    }
+}
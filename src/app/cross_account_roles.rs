@@ -0,0 +1,198 @@
+//! Hub-and-spoke access to member accounts
+//!
+//! Identity Center often grants access to a single hub account, typically the
+//! organization's management account. [`CrossAccountRoles`] lets the
+//! [`CredentialCoordinator`](crate::app::resource_explorer::credentials::CredentialCoordinator)
+//! reach other accounts by assuming a role in them with the hub's credentials.
+//! The role comes from a name pattern such as `OrganizationAccountAccessRole`
+//! or an ARN template with `{account_id}`, and can be overridden per account.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use crate::app::aws_regions::active_partition;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Role AWS Organizations creates in accounts it provisions
+pub const DEFAULT_MEMBER_ROLE: &str = "OrganizationAccountAccessRole";
+
+/// Account name shown for member accounts not listed by Identity Center
+pub const MEMBER_ACCOUNT_NAME: &str = "Member account";
+
+/// How to reach member accounts from the hub account
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrossAccountRoles {
+    /// Account whose Identity Center credentials assume the member roles;
+    /// `None` uses the account of the default role credentials
+    pub hub_account_id: Option<String>,
+    /// Role name, or role ARN template with `{account_id}`; empty disables cross-account access
+    pub role_pattern: String,
+    pub external_id: Option<String>,
+    /// Member accounts, with a role name or ARN template overriding `role_pattern` where set
+    pub member_accounts: BTreeMap<String, Option<String>>,
+}
+
+/// The hop the coordinator takes to reach a member account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CrossAccountRoute {
+    pub hub_account_id: String,
+    pub role_arn: String,
+    pub external_id: Option<String>,
+}
+
+impl CrossAccountRoles {
+    pub fn is_enabled(&self) -> bool {
+        !self.role_pattern.trim().is_empty()
+    }
+
+    /// Role ARN to assume in `account_id`
+    pub fn role_arn(&self, account_id: &str) -> String {
+        let role = self
+            .member_accounts
+            .get(account_id)
+            .and_then(|role| role.as_deref())
+            .unwrap_or(&self.role_pattern)
+            .trim()
+            .replace("{account_id}", account_id);
+        if role.starts_with("arn:") {
+            role
+        } else {
+            format!(
+                "arn:{}:iam::{}:role/{}",
+                active_partition().id(),
+                account_id,
+                role
+            )
+        }
+    }
+
+    /// How to reach `account_id`, or `None` when Identity Center credentials are used directly
+    ///
+    /// Listed member accounts are always reached through the hub; other accounts
+    /// only when Identity Center does not grant the default role in them.
+    pub fn route(
+        &self,
+        account_id: &str,
+        hub_account_id: Option<&str>,
+        directly_accessible: bool,
+    ) -> Option<CrossAccountRoute> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let hub_account_id = self.hub_account_id.as_deref().or(hub_account_id)?;
+        if hub_account_id == account_id
+            || (directly_accessible && !self.member_accounts.contains_key(account_id))
+        {
+            return None;
+        }
+        Some(CrossAccountRoute {
+            hub_account_id: hub_account_id.to_string(),
+            role_arn: self.role_arn(account_id),
+            external_id: self.external_id.clone(),
+        })
+    }
+}
+
+fn is_account_id(value: &str) -> bool {
+    value.len() == 12 && value.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Parse member accounts, one `account-id [role-name-or-arn]` per line
+///
+/// Blank lines and lines starting with `#` are skipped.
+pub fn parse_member_accounts(text: &str) -> Result<BTreeMap<String, Option<String>>, String> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut parts = line.split_whitespace();
+            let account_id = parts.next().unwrap_or_default().to_string();
+            if !is_account_id(&account_id) {
+                return Err(format!("Not an account ID: {}", account_id));
+            }
+            let role = parts.next().map(String::from);
+            if parts.next().is_some() {
+                return Err(format!("Expected 'account-id [role]': {}", line));
+            }
+            Ok((account_id, role))
+        })
+        .collect()
+}
+
+/// Validate and normalize an optional hub account ID
+pub fn parse_hub_account_id(text: &str) -> Result<Option<String>, String> {
+    let text = text.trim();
+    if text.is_empty() {
+        Ok(None)
+    } else if is_account_id(text) {
+        Ok(Some(text.to_string()))
+    } else {
+        Err(format!("Not an account ID: {}", text))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roles() -> CrossAccountRoles {
+        CrossAccountRoles {
+            hub_account_id: None,
+            role_pattern: DEFAULT_MEMBER_ROLE.to_string(),
+            external_id: Some("ext".to_string()),
+            member_accounts: parse_member_accounts(
+                "# spokes\n222222222222\n333333333333 arn:aws-cn:iam::{account_id}:role/Audit\n444444444444 ReadOnly\n",
+            )
+            .unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_role_arn_pattern_and_overrides() {
+        let roles = roles();
+        assert_eq!(
+            roles.role_arn("222222222222"),
+            "arn:aws:iam::222222222222:role/OrganizationAccountAccessRole"
+        );
+        assert_eq!(
+            roles.role_arn("333333333333"),
+            "arn:aws-cn:iam::333333333333:role/Audit"
+        );
+        assert_eq!(
+            roles.role_arn("444444444444"),
+            "arn:aws:iam::444444444444:role/ReadOnly"
+        );
+    }
+
+    #[test]
+    fn test_route_only_for_spokes() {
+        let hub = Some("111111111111");
+        let roles = roles();
+
+        // The hub itself and directly accessible accounts use Identity Center
+        assert_eq!(roles.route("111111111111", hub, true), None);
+        assert_eq!(roles.route("555555555555", hub, true), None);
+
+        let route = roles.route("555555555555", hub, false).unwrap();
+        assert_eq!(route.hub_account_id, "111111111111");
+        assert_eq!(route.external_id.as_deref(), Some("ext"));
+
+        // Listed members go through the hub even when Identity Center grants access
+        assert!(roles.route("222222222222", hub, true).is_some());
+
+        // No hub known, or cross-account access disabled
+        assert_eq!(roles.route("222222222222", None, false), None);
+        assert_eq!(
+            CrossAccountRoles::default().route("222222222222", hub, false),
+            None
+        );
+    }
+
+    #[test]
+    fn test_member_account_parsing() {
+        assert!(parse_member_accounts("12345").is_err());
+        assert!(parse_member_accounts("222222222222 Role extra").is_err());
+        assert_eq!(parse_hub_account_id(" ").unwrap(), None);
+        assert!(parse_hub_account_id("abc").is_err());
+    }
+}
@@ -0,0 +1,335 @@
+//! Agent Cost Report Window
+//!
+//! Estimated cost of past agent conversations, read from the cost store:
+//! totals for a chosen period, cost per model and per day, and the
+//! conversations themselves. The price table used for every estimate
+//! (including the running cost in the Agent Manager) is edited here.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use chrono::{DateTime, Duration, Utc};
+use eframe::egui;
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+
+use crate::app::agent_framework::cost_tracking::{
+    format_cost, ConversationCost, CostStore, CostSummary, ModelPrice, PriceTable,
+};
+use crate::app::agent_framework::AgentModel;
+
+/// Time range covered by the report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CostPeriod {
+    Last24Hours,
+    Last7Days,
+    Last30Days,
+    AllTime,
+}
+
+impl CostPeriod {
+    fn display_name(&self) -> &'static str {
+        match self {
+            CostPeriod::Last24Hours => "Last 24 hours",
+            CostPeriod::Last7Days => "Last 7 days",
+            CostPeriod::Last30Days => "Last 30 days",
+            CostPeriod::AllTime => "All time",
+        }
+    }
+
+    fn all() -> &'static [CostPeriod] {
+        &[
+            CostPeriod::Last24Hours,
+            CostPeriod::Last7Days,
+            CostPeriod::Last30Days,
+            CostPeriod::AllTime,
+        ]
+    }
+
+    /// Start of the period (None = no limit)
+    fn since(&self) -> Option<DateTime<Utc>> {
+        let days = match self {
+            CostPeriod::Last24Hours => 1,
+            CostPeriod::Last7Days => 7,
+            CostPeriod::Last30Days => 30,
+            CostPeriod::AllTime => return None,
+        };
+        Some(Utc::now() - Duration::days(days))
+    }
+}
+
+/// State for the cost report window
+pub struct AgentCostWindow {
+    open: bool,
+    /// Recorded conversations, newest first
+    conversations: Vec<ConversationCost>,
+    period: CostPeriod,
+    /// Prices used for all estimates
+    prices: PriceTable,
+    /// Whether the price editor is shown
+    show_prices: bool,
+    /// Outcome of the last price save
+    message: Option<String>,
+}
+
+impl Default for AgentCostWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgentCostWindow {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            conversations: Vec::new(),
+            period: CostPeriod::Last7Days,
+            prices: PriceTable::load(),
+            show_prices: false,
+            message: None,
+        }
+    }
+
+    /// Open the window and read the recorded conversations
+    pub fn open(&mut self) {
+        self.open = true;
+        self.message = None;
+        self.reload();
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Prices used for cost estimates
+    pub fn prices(&self) -> &PriceTable {
+        &self.prices
+    }
+
+    fn reload(&mut self) {
+        self.conversations = CostStore::default_location()
+            .map(|store| store.list())
+            .unwrap_or_default();
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Agent Cost Report")
+            .open(&mut is_open)
+            .default_size([650.0, 500.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                self.render_content(ui);
+            });
+        self.open = is_open;
+    }
+
+    fn render_content(&mut self, ui: &mut Ui) {
+        // Toolbar
+        ui.horizontal(|ui| {
+            egui::ComboBox::from_id_salt("cost_period_selector")
+                .selected_text(self.period.display_name())
+                .show_ui(ui, |ui| {
+                    for period in CostPeriod::all() {
+                        ui.selectable_value(&mut self.period, *period, period.display_name());
+                    }
+                });
+            if ui.button("Refresh").clicked() {
+                self.reload();
+            }
+            ui.toggle_value(&mut self.show_prices, "Prices");
+        });
+        ui.separator();
+
+        if self.show_prices {
+            self.render_price_editor(ui);
+            ui.separator();
+        }
+
+        let since = self.period.since();
+        let conversations: Vec<&ConversationCost> = self
+            .conversations
+            .iter()
+            .filter(|c| !c.is_empty() && since.map_or(true, |since| c.updated_at >= since))
+            .collect();
+        let summary = CostSummary::from_conversations(conversations.iter().copied(), &self.prices);
+
+        ui.label(
+            RichText::new(format!(
+                "Estimated total: {} over {} conversations ({} input / {} output tokens)",
+                format_cost(summary.cost),
+                summary.conversation_count,
+                summary.input_tokens,
+                summary.output_tokens
+            ))
+            .strong(),
+        );
+        ui.label(
+            RichText::new(
+                "Estimates from on-demand prices; prompt caching discounts are not included.",
+            )
+            .small()
+            .weak(),
+        );
+        ui.add_space(6.0);
+
+        ScrollArea::vertical()
+            .id_salt("cost_report_scroll")
+            .show(ui, |ui| {
+                ui.collapsing("By Model", |ui| {
+                    egui::Grid::new("cost_by_model")
+                        .striped(true)
+                        .num_columns(4)
+                        .show(ui, |ui| {
+                            ui.label(RichText::new("Model").strong());
+                            ui.label(RichText::new("Input").strong());
+                            ui.label(RichText::new("Output").strong());
+                            ui.label(RichText::new("Cost").strong());
+                            ui.end_row();
+                            for (usage, cost) in &summary.by_model {
+                                ui.label(usage.model.display_name());
+                                ui.label(usage.input_tokens.to_string());
+                                ui.label(usage.output_tokens.to_string());
+                                ui.label(format_cost(*cost));
+                                ui.end_row();
+                            }
+                        });
+                });
+
+                ui.collapsing("By Day", |ui| {
+                    egui::Grid::new("cost_by_day")
+                        .striped(true)
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            for (day, cost) in &summary.by_day {
+                                ui.label(day.format("%Y-%m-%d").to_string());
+                                ui.label(format_cost(*cost));
+                                ui.end_row();
+                            }
+                        });
+                });
+
+                egui::CollapsingHeader::new("Conversations")
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        if conversations.is_empty() {
+                            ui.label(RichText::new("No agent usage in this period").weak());
+                            return;
+                        }
+                        egui::Grid::new("cost_conversations")
+                            .striped(true)
+                            .num_columns(5)
+                            .show(ui, |ui| {
+                                ui.label(RichText::new("Started").strong());
+                                ui.label(RichText::new("Agent").strong());
+                                ui.label(RichText::new("Models").strong());
+                                ui.label(RichText::new("Tokens").strong());
+                                ui.label(RichText::new("Cost").strong());
+                                ui.end_row();
+                                for conversation in &conversations {
+                                    self.render_conversation_row(ui, conversation);
+                                }
+                            });
+                    });
+            });
+    }
+
+    fn render_conversation_row(&self, ui: &mut Ui, conversation: &ConversationCost) {
+        ui.label(
+            conversation
+                .started_at
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+        );
+        // Workers are indented under the manager that spawned them
+        let name = if conversation.parent_id.is_some() {
+            format!(
+                "  {} ({})",
+                conversation.agent_name, conversation.agent_type
+            )
+        } else {
+            conversation.agent_name.clone()
+        };
+        ui.label(name);
+        let models: Vec<&str> = conversation
+            .usage
+            .iter()
+            .map(|u| u.model.display_name())
+            .collect();
+        ui.label(models.join(", "));
+        ui.label(conversation.total_tokens().to_string());
+        ui.label(format_cost(conversation.cost(&self.prices)));
+        ui.end_row();
+    }
+
+    /// Editable price per million tokens for each model
+    fn render_price_editor(&mut self, ui: &mut Ui) {
+        ui.label(RichText::new("Prices (USD per million tokens)").strong());
+        egui::Grid::new("cost_price_editor")
+            .num_columns(4)
+            .show(ui, |ui| {
+                ui.label("");
+                ui.label("Input");
+                ui.label("Output");
+                ui.label("");
+                ui.end_row();
+                for model in AgentModel::all_models() {
+                    let mut price = self.prices.price(*model);
+                    let label = if self.prices.is_overridden(*model) {
+                        RichText::new(format!("{} *", model.display_name()))
+                    } else {
+                        RichText::new(model.display_name())
+                    };
+                    ui.label(label);
+                    let input = ui.add(
+                        egui::DragValue::new(&mut price.input_per_million)
+                            .speed(0.01)
+                            .range(0.0..=1000.0)
+                            .prefix("$"),
+                    );
+                    let output = ui.add(
+                        egui::DragValue::new(&mut price.output_per_million)
+                            .speed(0.01)
+                            .range(0.0..=1000.0)
+                            .prefix("$"),
+                    );
+                    if input.changed() || output.changed() {
+                        self.prices.set_price(*model, price);
+                    }
+                    if ui
+                        .add_enabled(
+                            self.prices.is_overridden(*model),
+                            egui::Button::new("Built-in").small(),
+                        )
+                        .clicked()
+                    {
+                        self.prices.set_price(*model, ModelPrice::builtin(*model));
+                    }
+                    ui.end_row();
+                }
+            });
+
+        ui.horizontal(|ui| {
+            if ui.button("Save Prices").clicked() {
+                self.message = Some(match self.prices.save() {
+                    Ok(()) => "Prices saved".to_string(),
+                    Err(e) => format!("Failed to save prices: {:#}", e),
+                });
+            }
+            if ui.button("Reset All").clicked() {
+                self.prices.reset();
+            }
+            if let Some(message) = &self.message {
+                ui.label(RichText::new(message).small().color(Color32::GRAY));
+            }
+        });
+        ui.label(
+            RichText::new("* custom price; built-in prices are us-east-1 on-demand list prices")
+                .small()
+                .weak(),
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! Agent Guardrail Window
+//!
+//! Edits the guardrail policy of a project (whether outgoing messages with
+//! secrets are masked or blocked, and the allow-list of values that are not
+//! secrets) and shows the audit log of masked and blocked content. Saved
+//! changes apply to the next message or tool call.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+
+use crate::app::agent_framework::middleware::layers::{
+    GuardrailAction, GuardrailAuditEntry, GuardrailMode, GuardrailPolicy, GuardrailStore,
+};
+
+/// State for the guardrail window
+pub struct AgentGuardrailWindow {
+    open: bool,
+    store: Option<GuardrailStore>,
+    /// Projects with a saved policy, plus the current one
+    projects: Vec<String>,
+    /// Policy being edited
+    policy: GuardrailPolicy,
+    /// Policy as last loaded or saved, to detect unsaved changes
+    saved_policy: GuardrailPolicy,
+    /// Value to add to the allow-list
+    new_allowed: String,
+    /// Audit entries, newest first
+    audit: Vec<GuardrailAuditEntry>,
+    /// Outcome of the last action
+    message: Option<(String, bool)>,
+}
+
+impl Default for AgentGuardrailWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgentGuardrailWindow {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            store: GuardrailStore::default_location(),
+            projects: Vec::new(),
+            policy: GuardrailPolicy::default(),
+            saved_policy: GuardrailPolicy::default(),
+            new_allowed: String::new(),
+            audit: Vec::new(),
+            message: None,
+        }
+    }
+
+    /// Open the window on `project` (the signed-in organization), or the
+    /// first project with a saved policy
+    pub fn open(&mut self, project: Option<String>) {
+        self.open = true;
+        self.message = None;
+        self.projects = self
+            .store
+            .as_ref()
+            .map(|store| store.projects())
+            .unwrap_or_default();
+        if let Some(project) = &project {
+            if !self.projects.contains(project) {
+                self.projects.push(project.clone());
+                self.projects.sort();
+            }
+        }
+        match project.or_else(|| self.projects.first().cloned()) {
+            Some(project) => self.load(&project),
+            None => {
+                self.policy = GuardrailPolicy::default();
+                self.saved_policy = GuardrailPolicy::default();
+            }
+        }
+        self.refresh_audit();
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn load(&mut self, project: &str) {
+        let loaded = match &self.store {
+            Some(store) => store.load(project),
+            None => Ok(GuardrailPolicy::new(project)),
+        };
+        self.policy = loaded.unwrap_or_else(|e| {
+            self.message = Some((format!("{:#}", e), true));
+            GuardrailPolicy::new(project)
+        });
+        self.saved_policy = self.policy.clone();
+        self.new_allowed.clear();
+    }
+
+    fn refresh_audit(&mut self) {
+        self.audit = self
+            .store
+            .as_ref()
+            .map(|store| store.audit_entries())
+            .unwrap_or_default();
+    }
+
+    fn is_modified(&self) -> bool {
+        self.policy != self.saved_policy
+    }
+
+    fn save(&mut self) {
+        let Some(store) = &self.store else {
+            self.message = Some(("Data directory not available".to_string(), true));
+            return;
+        };
+        self.policy.allowed.retain(|value| !value.trim().is_empty());
+        self.message = Some(match store.save(&self.policy) {
+            Ok(()) => {
+                log::info!(
+                    "Saved guardrail policy for project {} ({} allowed values)",
+                    self.policy.project,
+                    self.policy.allowed.len()
+                );
+                self.saved_policy = self.policy.clone();
+                ("Saved".to_string(), false)
+            }
+            Err(e) => (format!("{:#}", e), true),
+        });
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Agent Guardrails")
+            .open(&mut is_open)
+            .default_size([700.0, 550.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                self.render_policy(ui);
+                ui.separator();
+                self.render_audit(ui);
+            });
+        self.open = is_open;
+    }
+
+    fn render_policy(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Project:");
+            let mut selected = self.policy.project.clone();
+            egui::ComboBox::from_id_salt("guardrail_project_selector")
+                .selected_text(if selected.is_empty() {
+                    "(none)"
+                } else {
+                    selected.as_str()
+                })
+                .show_ui(ui, |ui| {
+                    for project in &self.projects {
+                        ui.selectable_value(&mut selected, project.clone(), project);
+                    }
+                });
+            if selected != self.policy.project {
+                self.message = None;
+                self.load(&selected);
+            }
+            if self.is_modified() {
+                ui.label(RichText::new("(unsaved)").italics());
+            }
+        });
+
+        if self.policy.project.is_empty() {
+            ui.label(
+                RichText::new("Sign in to edit the guardrail policy of your organization.")
+                    .weak(),
+            );
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Messages with secrets:");
+            for mode in [GuardrailMode::Mask, GuardrailMode::Block] {
+                ui.radio_value(&mut self.policy.mode, mode, mode.label());
+            }
+        });
+        ui.label(
+            RichText::new(
+                "Access keys, secret keys, session tokens, and private keys are checked in \
+                 messages and tool inputs. Tool calls with secrets are always blocked.",
+            )
+            .small()
+            .weak(),
+        );
+        ui.add_space(4.0);
+
+        ui.label("Allowed values (never treated as secrets):");
+        let mut removed = None;
+        for (index, value) in self.policy.allowed.iter().enumerate() {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(value).monospace());
+                if ui.small_button("Remove").clicked() {
+                    removed = Some(index);
+                }
+            });
+        }
+        if let Some(index) = removed {
+            self.policy.allowed.remove(index);
+        }
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.new_allowed)
+                    .hint_text("e.g. a test key used in examples")
+                    .desired_width(ui.available_width() - 60.0),
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            let value = self.new_allowed.trim().to_string();
+            if (ui.button("Add").clicked() || submitted) && !value.is_empty() {
+                if !self.policy.allowed.contains(&value) {
+                    self.policy.allowed.push(value);
+                }
+                self.new_allowed.clear();
+            }
+        });
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(self.is_modified(), egui::Button::new("Save"))
+                .clicked()
+            {
+                self.save();
+            }
+            if ui
+                .add_enabled(self.is_modified(), egui::Button::new("Revert"))
+                .clicked()
+            {
+                self.policy = self.saved_policy.clone();
+            }
+            if let Some((message, is_error)) = &self.message {
+                let color = if *is_error {
+                    Color32::from_rgb(220, 80, 80)
+                } else {
+                    Color32::GRAY
+                };
+                ui.label(RichText::new(message).small().color(color));
+            }
+        });
+    }
+
+    fn render_audit(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.strong("Audit log");
+            ui.label(RichText::new(format!("{} entries", self.audit.len())).weak());
+            if ui.small_button("Refresh").clicked() {
+                self.refresh_audit();
+            }
+        });
+
+        ScrollArea::vertical()
+            .id_salt("guardrail_audit_scroll")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if self.audit.is_empty() {
+                    ui.label(RichText::new("Nothing masked or blocked yet").weak());
+                }
+                egui::Grid::new("guardrail_audit_grid")
+                    .striped(true)
+                    .num_columns(5)
+                    .show(ui, |ui| {
+                        for entry in &self.audit {
+                            ui.label(
+                                RichText::new(
+                                    entry
+                                        .timestamp
+                                        .with_timezone(&chrono::Local)
+                                        .format("%Y-%m-%d %H:%M:%S")
+                                        .to_string(),
+                                )
+                                .small(),
+                            );
+                            let (action, color) = match entry.action {
+                                GuardrailAction::Masked => ("Masked", Color32::from_rgb(230, 160, 60)),
+                                GuardrailAction::Blocked => ("Blocked", Color32::from_rgb(220, 80, 80)),
+                            };
+                            ui.label(RichText::new(action).small().color(color));
+                            ui.label(RichText::new(&entry.source).small().monospace());
+                            ui.label(RichText::new(entry.kinds_label()).small());
+                            ui.label(RichText::new(entry.fingerprints.join(", ")).small().weak())
+                                .on_hover_text(format!(
+                                    "Agent {} in {}",
+                                    entry.agent_id, entry.project
+                                ));
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
@@ -92,16 +92,37 @@
 //! - Maximize: `[ ]` (empty square) - tooltip "Maximize window"
 //! - Restore: `[_]` (square with line) - tooltip "Restore window"
 
+use super::agent_cost_window::AgentCostWindow;
+use super::agent_guardrail_window::AgentGuardrailWindow;
 use super::agent_log_window::AgentLogWindow;
-use super::vfs_browser_window::VfsBrowserWindow;
+use super::agent_memory_window::AgentMemoryWindow;
+use super::agent_provider_window::AgentProviderWindow;
+use super::agent_timeline_window::AgentTimelineWindow;
+use super::command_registry::{CommandCategory, PaletteCommand};
+use super::prompt_editor_window::PromptEditorWindow;
+use super::scheduled_tasks_window::ScheduledTasksWindow;
+use super::task_queue_window::TaskQueueWindow;
+use super::skills_manager_window::SkillsManagerWindow;
+use super::vfs_browser_window::{VfsBrowserWindow, VfsSnapshotRequest};
 use super::window_focus::FocusableWindow;
 use super::window_maximize::{WindowMaximizeState, MENU_BAR_HEIGHT};
+use crate::app::agent_framework::conversation::memory::project_key;
+use crate::app::agent_framework::middleware::layers::{
+    respond_to_approval, take_approval_requests, ApprovalDecision, ApprovalRequest,
+};
 use crate::app::agent_framework::{
-    get_agent_creation_receiver, get_ui_event_receiver, render_agent_chat, AgentCreationRequest,
-    AgentId, AgentInstance, AgentModel, AgentStatus, AgentType, AgentUIEvent, InlineWorkerDisplay,
-    ProcessingStatusWidget, StoodLogLevel,
+    get_agent_creation_receiver, get_ui_event_receiver, remove_worker_pool, render_agent_chat,
+    render_cost_widget, render_transcript_replay, render_worker_pool_widget, route_message,
+    worker_handle, worker_pool_status, AgentCreationRequest, AgentId, AgentInstance, AgentModel,
+    AgentModelPreferences, AgentModelRole, AgentStatus, AgentType, AgentUIEvent, ChatParticipant,
+    ChatRoute, ConversationCost, ConversationTranscript, InjectionType, InlineWorkerDisplay,
+    ModelAvailability, ProcessingStatusWidget, QueuedTask, QueuedTaskState, ScheduledTask,
+    StoodLogLevel, TaskQueue, TaskScheduler, ToolRiskLevel, TranscriptInfo, TranscriptStore,
+    MANAGER_HANDLE,
 };
+use crate::app::app_config::app_config;
 use crate::app::aws_identity::AwsIdentityCenter;
+use crate::app::notifications::{Notification, NotificationError, RelatedWindow};
 use crate::{perf_checkpoint, perf_guard, perf_timed};
 use eframe::egui;
 use egui::{Context, RichText, ScrollArea, Ui};
@@ -110,6 +131,67 @@ use std::collections::HashMap;
 use std::sync::mpsc::Receiver;
 use std::sync::{Arc, Mutex};
 
+pub const NEW_AGENT: &str = "agents.new_agent";
+pub const STOP_SELECTED_AGENT: &str = "agents.stop_selected";
+pub const OPEN_TASK_QUEUE: &str = "agents.task_queue";
+pub const OPEN_SCHEDULES: &str = "agents.schedules";
+pub const OPEN_GUARDRAILS: &str = "agents.guardrails";
+pub const OPEN_PROVIDERS: &str = "agents.providers";
+
+/// Commands the agent manager offers in the command palette
+pub fn palette_commands() -> Vec<PaletteCommand> {
+    vec![
+        PaletteCommand {
+            id: NEW_AGENT,
+            label: "New agent",
+            description: "Create a task manager agent",
+            category: CommandCategory::Agents,
+            keywords: &["create", "chat", "ai"],
+            is_available: |context| context.logged_in,
+        },
+        PaletteCommand {
+            id: STOP_SELECTED_AGENT,
+            label: "Stop selected agent",
+            description: "Cancel the running turn of the agent shown in the agent manager",
+            category: CommandCategory::Agents,
+            keywords: &["cancel", "abort", "halt"],
+            is_available: |context| context.agent_processing,
+        },
+        PaletteCommand {
+            id: OPEN_TASK_QUEUE,
+            label: "Open task queue",
+            description: "Queue agent tasks and set how many run at once",
+            category: CommandCategory::Agents,
+            keywords: &["tasks", "priority"],
+            is_available: |context| context.logged_in,
+        },
+        PaletteCommand {
+            id: OPEN_SCHEDULES,
+            label: "Open scheduled tasks",
+            description: "Agent tasks that run on a schedule",
+            category: CommandCategory::Agents,
+            keywords: &["cron", "schedule"],
+            is_available: |context| context.logged_in,
+        },
+        PaletteCommand {
+            id: OPEN_GUARDRAILS,
+            label: "Open agent guardrails",
+            description: "Secret patterns and allow-list for agent messages",
+            category: CommandCategory::Agents,
+            keywords: &["secrets", "redact"],
+            is_available: |context| context.logged_in,
+        },
+        PaletteCommand {
+            id: OPEN_PROVIDERS,
+            label: "Open model providers",
+            description: "Choose where each agent role gets its model",
+            category: CommandCategory::Agents,
+            keywords: &["bedrock", "anthropic", "openai", "ollama", "model"],
+            is_available: |context| context.logged_in,
+        },
+    ]
+}
+
 /// Context information for displaying task progress
 #[derive(Debug, Clone)]
 struct TaskContext {
@@ -238,15 +320,67 @@ pub struct AgentManagerWindow {
     // VFS browser window
     vfs_browser_window: VfsBrowserWindow,
 
+    // Cost report window (also owns the price table)
+    agent_cost_window: AgentCostWindow,
+
+    // Task manager/worker prompt override editor
+    prompt_editor_window: PromptEditorWindow,
+
+    // Remembered facts inspector
+    agent_memory_window: AgentMemoryWindow,
+
+    // Secret guardrail policy and audit log
+    agent_guardrail_window: AgentGuardrailWindow,
+
+    // Tool invocation timeline of one conversation
+    agent_timeline_window: AgentTimelineWindow,
+
+    // Model provider per agent role
+    agent_provider_window: AgentProviderWindow,
+
+    // Skill install/update/remove window
+    skills_manager_window: SkillsManagerWindow,
+
+    /// Scheduled tasks window
+    scheduled_tasks_window: ScheduledTasksWindow,
+
+    /// Saved tasks run on a schedule
+    scheduler: TaskScheduler,
+
+    /// Agents running a scheduled task, with the task and when the run started
+    scheduled_runs: HashMap<AgentId, (ScheduledTask, chrono::DateTime<chrono::Utc>)>,
+
+    /// Task queue window
+    task_queue_window: TaskQueueWindow,
+
+    /// Tasks queued by the user, started as slots free up
+    task_queue: TaskQueue,
+
+    /// Agents running a queued task, with the task id
+    queued_runs: HashMap<AgentId, String>,
+
+    /// Notifications for finished scheduled runs, taken by the app each frame
+    pending_notifications: Vec<Notification>,
+
+    /// Tool calls waiting for approval, oldest first (the dialog shows the first)
+    pending_approvals: Vec<ApprovalRequest>,
+    /// Reason entered in the approval dialog when denying a call
+    approval_deny_reason: String,
+
+    // Latest cost of each manager's workers, keyed by conversation ID, so
+    // finished workers still count towards the manager's running cost
+    worker_costs: HashMap<AgentId, HashMap<String, ConversationCost>>,
+
     // Agents
     agents: HashMap<AgentId, AgentInstance>,
     input_text: String,
 
-    // Model selection for new agents
-    selected_model: AgentModel,
+    // Model per agent role (manager, worker, page builder), persisted
+    model_preferences: AgentModelPreferences,
 
-    // Stood library log level for all agents
-    stood_log_level: StoodLogLevel,
+    // Models the Bedrock account/region can use (None = check not finished)
+    model_availability: Arc<Mutex<Option<Result<ModelAvailability, String>>>>,
+    model_availability_requested: bool,
 
     // UI event receiver for agent framework events
     ui_event_receiver: Arc<Mutex<Receiver<AgentUIEvent>>>,
@@ -268,12 +402,26 @@ pub struct AgentManagerWindow {
     /// Tracks: is_maximized, restore_pos, restore_size
     maximize_state: WindowMaximizeState,
 
+    /// Tile the window is pinned to while docked, set by the app each frame
+    pub dock_rect: Option<egui::Rect>,
+
     // Agent type selection dialog state
     show_agent_type_dialog: bool,
     selected_agent_type: Option<AgentType>,
     new_agent_name: String,
     dialog_selected_model: AgentModel,
     dialog_selected_log_level: StoodLogLevel,
+
+    // Transcript export and replay
+    /// Saved transcripts for the Transcripts menu (None = reload on next open)
+    transcripts: Option<Vec<TranscriptInfo>>,
+    /// Transcript shown read-only in the right pane instead of an agent
+    replay_transcript: Option<ConversationTranscript>,
+    /// Outcome of the last transcript export or load
+    transcript_message: Option<String>,
+
+    /// Outcome of routing the last chat message (queued, unknown @handle)
+    chat_notice: Option<String>,
 }
 
 impl Default for AgentManagerWindow {
@@ -294,29 +442,67 @@ impl AgentManagerWindow {
             temp_agent_name: String::new(),
             agent_log_window: AgentLogWindow::new(),
             vfs_browser_window: VfsBrowserWindow::new(),
+            agent_cost_window: AgentCostWindow::new(),
+            prompt_editor_window: PromptEditorWindow::new(),
+            agent_memory_window: AgentMemoryWindow::new(),
+            agent_guardrail_window: AgentGuardrailWindow::new(),
+            agent_timeline_window: AgentTimelineWindow::new(),
+            agent_provider_window: AgentProviderWindow::new(),
+            skills_manager_window: SkillsManagerWindow::new(),
+            scheduled_tasks_window: ScheduledTasksWindow::new(),
+            scheduler: TaskScheduler::default_location(),
+            scheduled_runs: HashMap::new(),
+            task_queue_window: TaskQueueWindow::new(),
+            task_queue: TaskQueue::default_location(),
+            queued_runs: HashMap::new(),
+            pending_notifications: Vec::new(),
+            pending_approvals: Vec::new(),
+            approval_deny_reason: String::new(),
+            worker_costs: HashMap::new(),
             agents: HashMap::new(),
             input_text: String::new(),
-            selected_model: AgentModel::default(),
-            stood_log_level: StoodLogLevel::default(), // Default: Debug level
+            model_preferences: AgentModelPreferences::load(),
+            model_availability: Arc::new(Mutex::new(None)),
+            model_availability_requested: false,
             ui_event_receiver: get_ui_event_receiver(), // UI event channel
             agent_creation_receiver: get_agent_creation_receiver(), // Agent creation channel
             markdown_cache: CommonMarkCache::default(),
             status_widgets: HashMap::new(),
             worker_inline_messages: HashMap::new(),
             maximize_state: WindowMaximizeState::new(),
+            dock_rect: None,
             show_agent_type_dialog: false,
             selected_agent_type: None,
             new_agent_name: String::new(),
             dialog_selected_model: AgentModel::default(),
-            dialog_selected_log_level: StoodLogLevel::default(),
+            dialog_selected_log_level: app_config().agents.log_level,
+            transcripts: None,
+            replay_transcript: None,
+            transcript_message: None,
+            chat_notice: None,
         }
     }
 
     /// Set AWS Identity for agent execution
     pub fn set_aws_identity(&mut self, aws_identity: Arc<Mutex<AwsIdentityCenter>>) {
+        // A new identity may have a different Bedrock account - check models again
+        let changed = self
+            .aws_identity
+            .as_ref()
+            .map_or(true, |current| !Arc::ptr_eq(current, &aws_identity));
+        if changed {
+            self.model_availability_requested = false;
+            *self.model_availability.lock().unwrap() = None;
+        }
         self.aws_identity = Some(aws_identity);
     }
 
+    /// Memory project of the signed-in organization, for the memory inspector
+    fn current_memory_project(&self) -> Option<String> {
+        let identity = self.aws_identity.as_ref()?.try_lock().ok()?;
+        Some(project_key(&identity.start_url))
+    }
+
     /// Set agent logging enabled state (synced from DashApp)
     pub fn set_agent_logging_enabled(&mut self, enabled: bool) {
         self.agent_logging_enabled = enabled;
@@ -325,6 +511,7 @@ impl AgentManagerWindow {
     /// Select an agent to display in the right pane
     pub fn select_agent(&mut self, agent_id: AgentId) {
         self.selected_agent_id = Some(agent_id);
+        self.replay_transcript = None;
         // Reset tab to show manager when switching agents
         self.selected_tab_agent_id = Some(agent_id);
     }
@@ -355,6 +542,61 @@ impl AgentManagerWindow {
         self.open
     }
 
+    /// Whether the selected agent is working on a message
+    pub fn is_selected_agent_processing(&self) -> bool {
+        self.selected_agent_id
+            .and_then(|id| self.agents.get(&id))
+            .is_some_and(|agent| agent.is_processing())
+    }
+
+    /// Run an agent manager palette command; false if `id` is not one
+    ///
+    /// Every command opens the window, since its dialogs render with it.
+    pub fn run_palette_command(&mut self, id: &str) -> bool {
+        match id {
+            NEW_AGENT => self.show_new_agent_dialog(),
+            STOP_SELECTED_AGENT => {
+                self.cancel_selected_agent();
+            }
+            OPEN_TASK_QUEUE => self.task_queue_window.open(),
+            OPEN_SCHEDULES => self.scheduled_tasks_window.open(),
+            OPEN_GUARDRAILS => {
+                let project = self.current_memory_project();
+                self.agent_guardrail_window.open(project);
+            }
+            OPEN_PROVIDERS => self.agent_provider_window.open(),
+            _ => return false,
+        }
+        self.open = true;
+        true
+    }
+
+    /// Show the agent creation dialog with the current global settings
+    fn show_new_agent_dialog(&mut self) {
+        self.show_agent_type_dialog = true;
+        self.selected_agent_type = Some(AgentType::TaskManager); // Default to TaskManager
+        self.new_agent_name = format!("Agent {}", self.agents.len() + 1);
+        self.dialog_selected_model = self.model_preferences.manager;
+        self.dialog_selected_log_level = app_config().agents.log_level;
+    }
+
+    /// Cancel the selected agent's running turn, as its Stop button does
+    fn cancel_selected_agent(&mut self) -> bool {
+        let Some(agent_id) = self.selected_agent_id else {
+            return false;
+        };
+        let Some(agent) = self.agents.get_mut(&agent_id) else {
+            return false;
+        };
+        if !agent.cancel() {
+            return false;
+        }
+        log::info!("Agent {} execution cancelled from command palette", agent_id);
+        agent.add_system_message("[CANCELLED] Execution stopped by user request.");
+        agent.set_status(AgentStatus::Cancelled);
+        true
+    }
+
     pub fn show(&mut self, ctx: &Context) {
         // Delegate to show_with_focus (which handles dialogs and log window)
         self.show_with_focus(ctx, (), false);
@@ -364,6 +606,8 @@ impl AgentManagerWindow {
         // NOTE: Polling now happens globally in DashApp::update() before rendering
         // to ensure agents are polled even when window is closed
 
+        self.request_model_availability();
+
         // Two-pane horizontal layout using StripBuilder for proper vertical resizing
         use egui_extras::{Size, StripBuilder};
 
@@ -385,12 +629,44 @@ impl AgentManagerWindow {
                             // [+] New Agent button
                             if ui.button("+ New Agent").clicked() {
                                 log::info!("New Agent button clicked - showing agent creation dialog");
-                                self.show_agent_type_dialog = true;
-                                self.selected_agent_type = Some(AgentType::TaskManager); // Default to TaskManager
-                                self.new_agent_name = format!("Agent {}", self.agents.len() + 1);
-                                // Initialize dialog with current global settings
-                                self.dialog_selected_model = self.selected_model;
-                                self.dialog_selected_log_level = self.stood_log_level;
+                                self.show_new_agent_dialog();
+                            }
+
+                            // Transcripts menu - open an exported conversation read-only
+                            self.render_transcripts_menu(ui);
+
+                            if ui.button("Costs").clicked() {
+                                self.agent_cost_window.open();
+                            }
+
+                            if ui.button("Prompts").clicked() {
+                                self.prompt_editor_window.open();
+                            }
+
+                            if ui.button("Memory").clicked() {
+                                let project = self.current_memory_project();
+                                self.agent_memory_window.open(project);
+                            }
+
+                            if ui.button("Guardrails").clicked() {
+                                let project = self.current_memory_project();
+                                self.agent_guardrail_window.open(project);
+                            }
+
+                            if ui.button("Providers").clicked() {
+                                self.agent_provider_window.open();
+                            }
+
+                            if ui.button("Skills").clicked() {
+                                self.skills_manager_window.open();
+                            }
+
+                            if ui.button("Schedules").clicked() {
+                                self.scheduled_tasks_window.open();
+                            }
+
+                            if ui.button("Queue").clicked() {
+                                self.task_queue_window.open();
                             }
 
                             // Space after New Agent button
@@ -513,7 +789,11 @@ impl AgentManagerWindow {
 
                 // RIGHT PANE: Agent chat view - fills remaining space
                 strip.cell(|ui| {
-                    if let Some(agent_id) = self.selected_agent_id {
+                    if let Some(transcript) = &self.replay_transcript {
+                        if render_transcript_replay(ui, transcript, &mut self.markdown_cache) {
+                            self.replay_transcript = None;
+                        }
+                    } else if let Some(agent_id) = self.selected_agent_id {
                         self.render_agent_chat_view(ui, agent_id);
                     }
                     // No empty state message - just blank space
@@ -521,10 +801,243 @@ impl AgentManagerWindow {
             });
     }
 
+    /// Transcripts menu listing exported conversations for replay
+    fn render_transcripts_menu(&mut self, ui: &mut Ui) {
+        let response = ui.menu_button("Transcripts", |ui| {
+            let transcripts = self.transcripts.get_or_insert_with(|| {
+                TranscriptStore::default_location()
+                    .map(|store| store.list())
+                    .unwrap_or_default()
+            });
+            if transcripts.is_empty() {
+                ui.label(RichText::new("No exported transcripts").weak());
+            }
+
+            let mut selected = None;
+            for transcript in transcripts.iter() {
+                let label = format!(
+                    "{} - {} ({} messages, {} tool calls)",
+                    transcript.agent_name,
+                    transcript
+                        .exported_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M"),
+                    transcript.message_count,
+                    transcript.tool_call_count
+                );
+                if ui.button(label).clicked() {
+                    selected = Some(transcript.path.clone());
+                    ui.close();
+                }
+            }
+
+            if let Some(path) = selected {
+                self.open_transcript_replay(&path);
+            }
+        });
+
+        // Re-read the directory the next time the menu opens
+        if response.inner.is_none() {
+            self.transcripts = None;
+        }
+    }
+
+    /// Load a transcript into the read-only replay view
+    fn open_transcript_replay(&mut self, path: &std::path::Path) {
+        let result = TranscriptStore::default_location()
+            .ok_or_else(|| anyhow::anyhow!("Data directory unavailable"))
+            .and_then(|store| store.load(path));
+        match result {
+            Ok(transcript) => {
+                log::info!("Replaying transcript {}", path.display());
+                self.replay_transcript = Some(transcript);
+                self.transcript_message = None;
+            }
+            Err(e) => {
+                log::error!("Failed to open transcript {}: {:#}", path.display(), e);
+                self.transcript_message = Some(format!("Failed to open transcript: {:#}", e));
+            }
+        }
+    }
+
+    /// Save an agent's conversation as a JSON transcript
+    fn export_agent_transcript(&mut self, agent_id: AgentId) {
+        let Some(agent) = self.agents.get(&agent_id) else {
+            return;
+        };
+        let transcript = agent.export_transcript();
+        let result = TranscriptStore::default_location()
+            .ok_or_else(|| anyhow::anyhow!("Data directory unavailable"))
+            .and_then(|store| store.save(&transcript));
+        self.transcript_message = Some(match result {
+            Ok(path) => {
+                log::info!(
+                    "Agent {} transcript exported to {}",
+                    agent_id,
+                    path.display()
+                );
+                self.transcripts = None;
+                format!("Transcript saved to {}", path.display())
+            }
+            Err(e) => {
+                log::error!(
+                    "Failed to export transcript for agent {}: {:#}",
+                    agent_id,
+                    e
+                );
+                format!("Failed to export transcript: {:#}", e)
+            }
+        });
+    }
+
+    /// Save or restore an agent's VFS snapshot for the VFS browser
+    fn handle_vfs_snapshot_request(&mut self, request: VfsSnapshotRequest) {
+        let result = match request {
+            VfsSnapshotRequest::Save(agent_id) => match self.agents.get(&agent_id) {
+                Some(agent) => agent.save_vfs_snapshot().map(|info| {
+                    format!(
+                        "Saved snapshot: {} files, {} KB",
+                        info.file_count,
+                        info.total_size / 1024
+                    )
+                }),
+                None => Err(anyhow::anyhow!("Agent not found")),
+            },
+            VfsSnapshotRequest::Restore {
+                agent_id,
+                session_id,
+            } => match self.agents.get_mut(&agent_id) {
+                Some(agent) => agent
+                    .restore_vfs_snapshot(&session_id)
+                    .map(|()| format!("Restored snapshot from session {}", session_id)),
+                None => Err(anyhow::anyhow!("Agent not found")),
+            },
+        };
+        self.vfs_browser_window.snapshot_finished(result);
+    }
+
+    /// Check which models the Bedrock account can use (once per identity)
+    ///
+    /// Lists foundation models and inference profiles on a background thread,
+    /// like the telemetry log group setup, so the UI is not blocked.
+    fn request_model_availability(&mut self) {
+        if self.model_availability_requested {
+            return;
+        }
+        let Some(aws_identity) = &self.aws_identity else {
+            return;
+        };
+        let Ok(mut identity) = aws_identity.try_lock() else {
+            return; // Lock held by login thread - try again next frame
+        };
+        self.model_availability_requested = true;
+
+        let bedrock_creds = match identity.get_bedrock_credentials() {
+            Ok(creds) => creds,
+            Err(e) => {
+                log::warn!("Cannot check model availability: {}", e);
+                *self.model_availability.lock().unwrap() = Some(Err(e));
+                return;
+            }
+        };
+        drop(identity);
+
+        let result_slot = self.model_availability.clone();
+        let region = bedrock_creds.region;
+        let access_key = bedrock_creds.credentials.access_key_id;
+        let secret_key = bedrock_creds.credentials.secret_access_key;
+        let session_token = bedrock_creds.credentials.session_token;
+
+        std::thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
+            let result = rt.block_on(ModelAvailability::fetch(
+                &access_key,
+                &secret_key,
+                session_token.as_deref(),
+                &region,
+            ));
+            let result = match result {
+                Ok(availability) => {
+                    log::info!(
+                        "Bedrock models available in {}: {:?}",
+                        availability.region,
+                        availability.available_models()
+                    );
+                    Ok(availability)
+                }
+                Err(e) => {
+                    log::warn!("Failed to check Bedrock model availability: {:#}", e);
+                    Err(format!("{:#}", e))
+                }
+            };
+            *result_slot.lock().unwrap() = Some(result);
+        });
+    }
+
+    /// Result of the model availability check, if it succeeded
+    fn model_availability(&self) -> Option<ModelAvailability> {
+        match &*self.model_availability.lock().unwrap() {
+            Some(Ok(availability)) => Some(availability.clone()),
+            _ => None,
+        }
+    }
+
+    /// One-line status of the model availability check for the dialog
+    fn model_availability_status(&self) -> String {
+        match &*self.model_availability.lock().unwrap() {
+            Some(Ok(availability)) => format!(
+                "{} of {} models available in {}",
+                availability.available_models().len(),
+                AgentModel::all_models().len(),
+                availability.region
+            ),
+            Some(Err(e)) => format!("Could not check model availability: {}", e),
+            None => "Checking model availability...".to_string(),
+        }
+    }
+
+    /// Switch an agent to another model and remember it for the agent's role
+    fn change_agent_model(&mut self, agent_id: AgentId, model: AgentModel) {
+        let Some(agent) = self.agents.get_mut(&agent_id) else {
+            return;
+        };
+        agent.set_model(model);
+
+        if let Some(aws_identity) = &self.aws_identity {
+            let init_result = agent.initialize(
+                &mut aws_identity.lock().unwrap(),
+                self.agent_logging_enabled,
+            );
+            match init_result {
+                Ok(_) => {
+                    log::info!("Agent {} switched to model {}", agent_id, model);
+                    agent.add_system_message(&format!(
+                        "Model changed to {}. Earlier messages are not sent to the new model.",
+                        model
+                    ));
+                }
+                Err(e) => {
+                    log::error!("Failed to switch agent {} to {}: {}", agent_id, model, e);
+                    agent.add_system_message(&format!("Failed to switch to {}: {}", model, e));
+                }
+            }
+        } else {
+            log::error!("Cannot switch agent model: AWS Identity not set");
+        }
+
+        let role = AgentModelRole::for_agent_type(agent.agent_type());
+        *self.model_preferences.get_mut(role) = model;
+        if let Err(e) = self.model_preferences.save() {
+            log::warn!("Failed to save agent model preferences: {:#}", e);
+        }
+    }
+
     /// Show agent creation dialog (simplified - always creates TaskManager)
     fn show_agent_type_selection_dialog(&mut self, ctx: &Context) {
         let mut should_create = false;
         let mut open = self.show_agent_type_dialog;
+        let availability = self.model_availability();
+        let availability_status = self.model_availability_status();
 
         egui::Window::new("Create New Agent")
             .open(&mut open)
@@ -545,19 +1058,29 @@ impl AgentManagerWindow {
                 // Model selection
                 ui.horizontal(|ui| {
                     ui.label("Model:");
-                    egui::ComboBox::from_id_salt("dialog_model_selector")
-                        .selected_text(self.dialog_selected_model.display_name())
-                        .width(200.0)
-                        .show_ui(ui, |ui| {
-                            for model in AgentModel::all_models() {
-                                ui.selectable_value(
-                                    &mut self.dialog_selected_model,
-                                    *model,
-                                    model.display_name(),
-                                );
-                            }
+                    model_selector(
+                        ui,
+                        "dialog_model_selector",
+                        &mut self.dialog_selected_model,
+                        availability.as_ref(),
+                    );
+                });
+
+                // Models for workers this agent spawns (saved as defaults)
+                ui.collapsing("Worker Models", |ui| {
+                    for role in [AgentModelRole::Worker, AgentModelRole::PageBuilder] {
+                        ui.horizontal(|ui| {
+                            ui.label(format!("{}:", role.display_name()));
+                            model_selector(
+                                ui,
+                                &format!("dialog_{:?}_model_selector", role),
+                                self.model_preferences.get_mut(role),
+                                availability.as_ref(),
+                            );
                         });
+                    }
                 });
+                ui.label(RichText::new(&availability_status).small().weak());
                 ui.add_space(10.0);
 
                 // Log level selection
@@ -579,7 +1102,19 @@ impl AgentManagerWindow {
                 ui.add_space(15.0);
 
                 // Create button
-                let can_create = !self.new_agent_name.trim().is_empty();
+                let model_available = availability
+                    .as_ref()
+                    .map_or(true, |a| a.is_available(self.dialog_selected_model));
+                if !model_available {
+                    ui.colored_label(
+                        egui::Color32::from_rgb(255, 165, 0),
+                        format!(
+                            "{} is not available in this account/region",
+                            self.dialog_selected_model
+                        ),
+                    );
+                }
+                let can_create = !self.new_agent_name.trim().is_empty() && model_available;
 
                 if ui
                     .add_enabled(can_create, egui::Button::new("Create Agent"))
@@ -593,6 +1128,10 @@ impl AgentManagerWindow {
 
         // Create agent after dialog closes to avoid borrow conflicts
         if should_create {
+            self.model_preferences.manager = self.dialog_selected_model;
+            if let Err(e) = self.model_preferences.save() {
+                log::warn!("Failed to save agent model preferences: {:#}", e);
+            }
             self.create_new_agent();
             self.show_agent_type_dialog = false;
         }
@@ -634,6 +1173,10 @@ impl AgentManagerWindow {
         perf_checkpoint!("create_new_agent.creating_agent_instance");
         let mut agent = perf_timed!("create_new_agent.AgentInstance_new", {
             AgentInstance::new(metadata, agent_type)
+                .with_token_budget_layer()
+                .with_approval_gate_layer()
+                .with_guardrail_layer()
+                .with_tool_timeline_layer()
         });
         let agent_id = agent.id();
 
@@ -688,18 +1231,22 @@ impl AgentManagerWindow {
         tracing::info!(
             "Creating agent for page edit: {} with model {}",
             agent_name,
-            self.dialog_selected_model
+            self.model_preferences.manager
         );
 
         let metadata = AgentMetadata {
             name: agent_name.clone(),
             description: format!("Editing page: {}", page_name),
-            model: self.dialog_selected_model,
+            model: self.model_preferences.manager,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
 
-        let mut agent = AgentInstance::new(metadata, agent_type);
+        let mut agent = AgentInstance::new(metadata, agent_type)
+            .with_token_budget_layer()
+            .with_approval_gate_layer()
+            .with_guardrail_layer()
+            .with_tool_timeline_layer();
         let agent_id = agent.id();
 
         // Set the page workspace so edit_page tool knows which page to modify
@@ -908,6 +1455,12 @@ impl AgentManagerWindow {
                     );
                     self.handle_worker_tokens_updated(worker_id, total_tokens);
                 }
+                // Streamed response text, shown until the full response arrives
+                AgentUIEvent::ResponseDelta { agent_id, delta } => {
+                    if let Some(agent) = self.agents.get_mut(&agent_id) {
+                        agent.append_streaming_text(&delta);
+                    }
+                }
                 // Page management events
                 AgentUIEvent::OpenPageForEdit { page_name } => {
                     tracing::info!(
@@ -1421,11 +1974,27 @@ impl AgentManagerWindow {
             }
         };
 
-        // Create metadata (inherit parent's model)
+        // Use the model chosen for this worker role, or the parent's model
+        // when the account cannot use it
+        let preferred_model = self.model_preferences.for_agent_type(&agent_type);
+        let model = match self.model_availability() {
+            Some(availability) if !availability.is_available(preferred_model) => {
+                log::warn!(
+                    "{} is not available in {}, worker uses parent model {}",
+                    preferred_model,
+                    availability.region,
+                    parent_model
+                );
+                parent_model
+            }
+            _ => preferred_model,
+        };
+
+        // Create metadata
         let metadata = AgentMetadata {
             name: agent_name.clone(),
             description: format!("Task: {}", request.task_description()),
-            model: parent_model,
+            model,
             created_at: Utc::now(),
             updated_at: Utc::now(),
         };
@@ -1434,6 +2003,9 @@ impl AgentManagerWindow {
         perf_checkpoint!("UI.handle_agent_creation_request.create_worker_instance.start");
         let mut agent = perf_timed!("UI.handle_agent_creation_request.AgentInstance_new", {
             AgentInstance::new_with_parent_logger(metadata, agent_type, parent_logger)
+                .with_approval_gate_layer()
+                .with_guardrail_layer()
+                .with_tool_timeline_layer()
         });
         let agent_id = agent.id();
         perf_checkpoint!(
@@ -1450,7 +2022,7 @@ impl AgentManagerWindow {
         }
 
         // Set the current stood log level on new worker agent
-        agent.set_stood_log_level(self.stood_log_level);
+        agent.set_stood_log_level(app_config().agents.log_level);
 
         // Initialize agent with AWS credentials
         perf_checkpoint!("UI.handle_agent_creation_request.initialize_worker.start");
@@ -1568,7 +2140,7 @@ impl AgentManagerWindow {
 
         // Find current task index
         let current_index = self
-            .selected_agent_id
+            .selected_tab_agent_id
             .and_then(|selected_id| active_tasks.iter().position(|id| *id == selected_id))
             .unwrap_or(0);
 
@@ -1585,7 +2157,15 @@ impl AgentManagerWindow {
             "Cycling to next task-agent"
         );
 
-        self.select_agent(next_agent_id);
+        // Show the worker's thread in its manager's chat
+        let parent_id = self
+            .agents
+            .get(&next_agent_id)
+            .and_then(|agent| agent.agent_type().parent_id());
+        if let Some(parent_id) = parent_id {
+            self.select_agent(parent_id);
+            self.selected_tab_agent_id = Some(next_agent_id);
+        }
     }
 
     /// Handle keyboard navigation
@@ -1610,7 +2190,7 @@ impl AgentManagerWindow {
     ///
     /// Returns Some if the selected agent is a task-worker, None otherwise
     fn get_task_context(&self) -> Option<TaskContext> {
-        let selected_id = self.selected_agent_id?;
+        let selected_id = self.selected_tab_agent_id?;
         let selected_agent = self.agents.get(&selected_id)?;
 
         // Only show context for task-workers
@@ -1653,18 +2233,124 @@ impl AgentManagerWindow {
         // Render task indicator first
         self.render_task_indicator(ui);
 
-        // Convert worker inline messages to display format for the render function
-        let inline_workers_display = self.convert_workers_to_display(agent_id);
+        // Outcome of the last transcript export
+        if let Some(message) = &self.transcript_message {
+            let mut dismissed = false;
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(message).small().weak());
+                dismissed = ui.small_button("x").clicked();
+            });
+            if dismissed {
+                self.transcript_message = None;
+            }
+        }
+
+        // Model for this agent - switching re-initializes it on the new model
+        // Running cost next to it includes the agent's workers
+        let mut timeline_clicked = false;
+        if let Some(agent) = self.agents.get(&agent_id) {
+            let mut model = agent.metadata().model;
+            let processing = agent.is_processing();
+            let availability = self.model_availability();
+
+            let worker_costs = self.worker_costs.entry(agent_id).or_default();
+            for worker in self
+                .agents
+                .values()
+                .filter(|a| a.agent_type().parent_id() == Some(agent_id))
+            {
+                worker_costs.insert(worker.cost().conversation_id.clone(), worker.cost().clone());
+            }
+            let conversation = agent.cost();
+            let workers: Vec<&ConversationCost> = worker_costs
+                .values()
+                .filter(|w| w.started_at >= conversation.started_at)
+                .collect();
+
+            let (changed, cost_clicked) = ui
+                .horizontal(|ui| {
+                    ui.label(RichText::new("Model:").small());
+                    let changed = ui
+                        .add_enabled_ui(!processing, |ui| {
+                            model_selector(
+                                ui,
+                                "chat_model_selector",
+                                &mut model,
+                                availability.as_ref(),
+                            )
+                        })
+                        .inner;
+                    ui.separator();
+                    let cost_clicked = render_cost_widget(
+                        ui,
+                        conversation,
+                        &workers,
+                        self.agent_cost_window.prices(),
+                    );
+                    if agent.agent_type().is_task_manager() {
+                        ui.separator();
+                        let pool_status = worker_pool_status(agent_id);
+                        if pool_status.as_ref().is_some_and(|s| s.is_active()) {
+                            // Keep elapsed times and queue counts moving
+                            ui.ctx()
+                                .request_repaint_after(std::time::Duration::from_millis(500));
+                        }
+                        render_worker_pool_widget(ui, pool_status.as_ref());
+                    }
+                    ui.separator();
+                    timeline_clicked = ui
+                        .small_button("Timeline")
+                        .on_hover_text("Tool calls of this conversation")
+                        .clicked();
+                    (changed, cost_clicked)
+                })
+                .inner;
+            if changed {
+                self.change_agent_model(agent_id, model);
+            }
+            if cost_clicked {
+                self.agent_cost_window.open();
+            }
+        }
+
+        // Manager and live workers each have a thread; the open one is shown
+        let participants = self.chat_participants(agent_id);
+        let display_agent_id = self
+            .selected_tab_agent_id
+            .filter(|id| participants.iter().any(|p| p.agent_id == *id))
+            .unwrap_or(agent_id);
+        if participants.len() > 1 {
+            self.render_chat_tabs(ui, &participants, display_agent_id);
+        }
+        if timeline_clicked {
+            if let Some(agent) = self.agents.get(&display_agent_id) {
+                self.agent_timeline_window
+                    .open(display_agent_id, agent.metadata().name.clone());
+            }
+        }
+        if let Some(notice) = &self.chat_notice {
+            let mut dismissed = false;
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(notice).small().weak());
+                dismissed = ui.small_button("x").clicked();
+            });
+            if dismissed {
+                self.chat_notice = None;
+            }
+        }
 
-        // Always show the selected manager agent's conversation
-        // (workers no longer have tabs - they're shown inline within conversation)
-        let display_agent_id = agent_id;
+        // Workers are also shown inline within the manager's conversation
+        let inline_workers_display = if display_agent_id == agent_id {
+            Some(self.convert_workers_to_display(agent_id))
+        } else {
+            None
+        };
 
         // Ensure status widget exists for this agent
         self.status_widgets.entry(display_agent_id).or_default();
 
         // Render UI and handle message sending/polling in a scope to release borrow
-        let (terminate_clicked, log_clicked, _clear_clicked, worker_log_to_open, vfs_clicked, vfs_info, stop_clicked) = {
+        let (message_to_send, terminate_clicked, log_clicked, _clear_clicked, worker_log_to_open, vfs_clicked, vfs_info, stop_clicked, export_clicked) = {
             // Get the agent and status widget to display
             let agent = match self.agents.get_mut(&display_agent_id) {
                 Some(agent) => agent,
@@ -1691,39 +2377,19 @@ impl AgentManagerWindow {
                 stop_clicked,
                 worker_log_clicked,
                 vfs_clicked,
+                export_clicked,
             ) = render_agent_chat(
                 ui,
                 agent,
                 &mut self.input_text,
                 &mut self.markdown_cache,
                 status_widget,
-                Some(&inline_workers_display),
+                inline_workers_display.as_ref(),
+                &participants,
             );
 
-            // Send message if requested
-            if should_send {
-                perf_checkpoint!(
-                    "UI.send_message.start",
-                    &format!("agent_id={}, msg_len={}", agent_id, self.input_text.len())
-                );
-                let message = self.input_text.clone();
-                self.input_text.clear();
-
-                // If agent was cancelled, reset token to continue (preserves conversation)
-                if *agent.status() == AgentStatus::Cancelled {
-                    log::info!(
-                        "Agent {} was cancelled, resetting token before send",
-                        agent_id
-                    );
-                    agent.reset_cancellation_token();
-                    agent.set_status(AgentStatus::Running);
-                }
-
-                // Send message to agent
-                log::info!("Sending message to agent {}: {}", agent_id, message);
-                agent.send_message(message);
-                perf_checkpoint!("UI.send_message.end", &format!("agent_id={}", agent_id));
-            }
+            // Routed to the right agent once the borrow is released
+            let message_to_send = should_send.then(|| std::mem::take(&mut self.input_text));
 
             // Handle stop button click - cancel ongoing execution
             if stop_clicked {
@@ -1743,6 +2409,7 @@ impl AgentManagerWindow {
             }
 
             (
+                message_to_send,
                 terminate_clicked,
                 log_clicked,
                 clear_clicked,
@@ -1750,11 +2417,34 @@ impl AgentManagerWindow {
                 vfs_clicked,
                 vfs_info,
                 stop_clicked,
+                export_clicked,
             )
         }; // agent borrow released here
 
-        // Handle Stop button - also cancel all child workers
-        if stop_clicked {
+        if let Some(message) = message_to_send {
+            self.route_chat_message(agent_id, display_agent_id, message);
+        }
+
+        if export_clicked {
+            self.export_agent_transcript(display_agent_id);
+        }
+
+        // A worker thread's Terminate stops just that worker; its failure is
+        // reported to the manager like any other worker result
+        if terminate_clicked && display_agent_id != agent_id {
+            if let Some(worker) = self.agents.get_mut(&display_agent_id) {
+                if worker.cancel() {
+                    worker.add_system_message("[CANCELLED] Worker stopped by user request.");
+                    worker.set_status(AgentStatus::Cancelled);
+                    log::info!("Worker {} cancelled from its thread", display_agent_id);
+                }
+            }
+            self.selected_tab_agent_id = Some(agent_id);
+        }
+        let terminate_clicked = terminate_clicked && display_agent_id == agent_id;
+
+        // Handle Stop button on the manager's thread - also cancel all child workers
+        if stop_clicked && display_agent_id == agent_id {
             // Find all workers for this agent
             let workers_to_cancel: Vec<AgentId> = self
                 .agents
@@ -1795,7 +2485,8 @@ impl AgentManagerWindow {
         // Handle VFS button click
         if vfs_clicked {
             if let Some((vfs_id, agent_name)) = vfs_info {
-                self.vfs_browser_window.open_for_vfs(vfs_id, agent_name);
+                self.vfs_browser_window
+                    .open_for_vfs(vfs_id, agent_id, agent_name);
                 tracing::info!("VFS browser opened for agent {}", agent_id);
             }
         }
@@ -1803,13 +2494,13 @@ impl AgentManagerWindow {
         // Handle log button click outside the borrow scope
         if log_clicked {
             // Get the agent again to access its logger
-            if let Some(agent) = self.agents.get(&agent_id) {
+            if let Some(agent) = self.agents.get(&display_agent_id) {
                 self.agent_log_window.show_log_for_agent(
-                    agent_id,
+                    display_agent_id,
                     agent.metadata().name.clone(),
                     agent.logger(),
                 );
-                tracing::info!("Agentlog viewer opened for agent {}", agent_id);
+                tracing::info!("Agentlog viewer opened for agent {}", display_agent_id);
             }
         }
 
@@ -1836,7 +2527,9 @@ impl AgentManagerWindow {
 
             // Remove the manager agent
             self.agents.remove(&agent_id);
+            self.worker_costs.remove(&agent_id);
             self.status_widgets.remove(&agent_id);
+            remove_worker_pool(agent_id);
             log::info!("Agent {} terminated and removed", agent_id);
 
             // Clear selections if this agent was selected
@@ -1849,6 +2542,548 @@ impl AgentManagerWindow {
         }
     }
 
+    /// Agents in a manager's chat: the manager, then its live workers
+    ///
+    /// Workers are numbered by kind in the order they were started, so a
+    /// handle keeps pointing at the same worker while others finish.
+    fn chat_participants(&self, manager_id: AgentId) -> Vec<ChatParticipant> {
+        let Some(manager) = self.agents.get(&manager_id) else {
+            return Vec::new();
+        };
+        let mut participants =
+            vec![
+                ChatParticipant::new(manager_id, MANAGER_HANDLE, manager.metadata().name.clone())
+                    .with_processing(manager.is_processing()),
+            ];
+
+        // Spawn order from the inline worker records, which outlive the workers
+        let mut message_indices: Vec<&usize> = self.worker_inline_messages.keys().collect();
+        message_indices.sort();
+        let mut started: Vec<(AgentId, String)> = message_indices
+            .into_iter()
+            .flat_map(|index| &self.worker_inline_messages[index])
+            .filter(|w| w.parent_id == manager_id)
+            .map(|w| (w.worker_id, w.short_description.clone()))
+            .collect();
+
+        // Workers whose start event has not been handled yet come last
+        let mut unlisted: Vec<&AgentInstance> = self
+            .agents
+            .values()
+            .filter(|a| a.agent_type().parent_id() == Some(manager_id))
+            .filter(|a| !started.iter().any(|(id, _)| *id == a.id()))
+            .collect();
+        unlisted.sort_by_key(|a| a.metadata().created_at);
+        started.extend(
+            unlisted
+                .into_iter()
+                .map(|a| (a.id(), a.metadata().name.clone())),
+        );
+
+        let (mut workers, mut pages) = (0, 0);
+        for (worker_id, label) in started {
+            let is_page_builder = match self.agents.get(&worker_id) {
+                Some(worker) => matches!(worker.agent_type(), AgentType::PageBuilderWorker { .. }),
+                None => self
+                    .worker_inline_messages
+                    .values()
+                    .flatten()
+                    .any(|w| w.worker_id == worker_id && w.is_tool_builder),
+            };
+            let counter = if is_page_builder {
+                &mut pages
+            } else {
+                &mut workers
+            };
+            let handle = worker_handle(is_page_builder, *counter);
+            *counter += 1;
+
+            // Finished workers keep their number but leave the chat
+            if let Some(worker) = self.agents.get(&worker_id) {
+                participants.push(
+                    ChatParticipant::new(worker_id, handle, label)
+                        .with_processing(worker.is_processing()),
+                );
+            }
+        }
+        participants
+    }
+
+    /// Thread tabs above the chat, one per participant
+    fn render_chat_tabs(
+        &mut self,
+        ui: &mut Ui,
+        participants: &[ChatParticipant],
+        display_agent_id: AgentId,
+    ) {
+        ui.horizontal_wrapped(|ui| {
+            for participant in participants {
+                let selected = participant.agent_id == display_agent_id;
+                if ui
+                    .selectable_label(selected, format!("@{}", participant.handle))
+                    .on_hover_text(&participant.label)
+                    .clicked()
+                {
+                    self.selected_tab_agent_id = Some(participant.agent_id);
+                }
+                if participant.is_processing {
+                    ui.add(egui::Spinner::new().size(10.0));
+                }
+            }
+        });
+        ui.label(
+            RichText::new("Start a message with @handle to send it to that agent")
+                .small()
+                .weak(),
+        );
+        ui.separator();
+    }
+
+    /// Send a chat message to the mentioned agent, or the open thread's agent
+    ///
+    /// A busy agent gets the message after its current response completes.
+    fn route_chat_message(&mut self, manager_id: AgentId, display_agent_id: AgentId, text: String) {
+        let participants = self.chat_participants(manager_id);
+        let (target_id, message) = match route_message(&text, &participants) {
+            ChatRoute::Viewed(message) => (display_agent_id, message),
+            ChatRoute::Agent { agent_id, message } => {
+                // A bare mention just opens that agent's thread
+                self.selected_tab_agent_id = Some(agent_id);
+                if message.is_empty() {
+                    return;
+                }
+                (agent_id, message)
+            }
+            ChatRoute::UnknownHandle(handle) => {
+                let handles: Vec<String> = participants
+                    .iter()
+                    .map(|p| format!("@{}", p.handle))
+                    .collect();
+                self.chat_notice = Some(format!(
+                    "No agent @{} in this chat (try {})",
+                    handle,
+                    handles.join(", ")
+                ));
+                // Keep the text so the handle can be fixed
+                self.input_text = text;
+                return;
+            }
+        };
+        let handle = participants
+            .iter()
+            .find(|p| p.agent_id == target_id)
+            .map(|p| p.handle.clone())
+            .unwrap_or_default();
+        let Some(agent) = self.agents.get_mut(&target_id) else {
+            return;
+        };
+
+        if agent.is_processing() {
+            log::info!("Queueing message for busy agent {}: {}", target_id, message);
+            agent.queue_immediate_injection(InjectionType::UserMessage(message));
+            self.chat_notice = Some(format!(
+                "Queued for @{} - delivered when its current response completes",
+                handle
+            ));
+            return;
+        }
+
+        perf_checkpoint!(
+            "UI.send_message.start",
+            &format!("agent_id={}, msg_len={}", target_id, message.len())
+        );
+
+        // If agent was cancelled, reset token to continue (preserves conversation)
+        if *agent.status() == AgentStatus::Cancelled {
+            log::info!(
+                "Agent {} was cancelled, resetting token before send",
+                target_id
+            );
+            agent.reset_cancellation_token();
+            agent.set_status(AgentStatus::Running);
+        }
+
+        // Send message to agent
+        log::info!("Sending message to agent {}: {}", target_id, message);
+        agent.send_message(message);
+        self.chat_notice = None;
+        perf_checkpoint!("UI.send_message.end", &format!("agent_id={}", target_id));
+    }
+
+    /// Notifications raised since the last call (finished scheduled runs)
+    pub fn take_notifications(&mut self) -> Vec<Notification> {
+        std::mem::take(&mut self.pending_notifications)
+    }
+
+    /// Time until the next scheduled task is due, so the app can wake up for it
+    pub fn time_until_next_scheduled_run(&self) -> Option<std::time::Duration> {
+        self.scheduler.time_until_next_run(chrono::Local::now())
+    }
+
+    /// Start an agent for every scheduled task that is due
+    ///
+    /// Tasks are only started when logged in; a due task whose previous run
+    /// is still going is skipped until its next scheduled time.
+    fn start_due_scheduled_tasks(&mut self) {
+        if self.aws_identity.is_none() {
+            return;
+        }
+        for task in self.scheduler.due_tasks(chrono::Local::now()) {
+            let already_running = self.scheduled_runs.values().any(|(t, _)| t.id == task.id);
+            if already_running {
+                log::warn!(
+                    "Skipping scheduled task '{}': previous run still in progress",
+                    task.name
+                );
+                continue;
+            }
+            self.start_scheduled_run(task);
+        }
+    }
+
+    /// Create a TaskManager agent for `task` and send it the task prompt
+    fn start_scheduled_run(&mut self, task: ScheduledTask) {
+        use crate::app::agent_framework::AgentMetadata;
+        use chrono::Utc;
+
+        let Some(aws_identity) = &self.aws_identity else {
+            self.notify_scheduled_run_failed(&task, "Login to AWS to run scheduled tasks");
+            return;
+        };
+
+        let metadata = AgentMetadata {
+            name: format!("Scheduled: {}", task.name),
+            description: format!("Scheduled task: {}", task.name),
+            model: self.model_preferences.manager,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let mut agent = AgentInstance::new(metadata, AgentType::TaskManager)
+            .with_token_budget_layer()
+            .with_approval_gate_layer()
+            .with_guardrail_layer()
+            .with_tool_timeline_layer();
+        let agent_id = agent.id();
+        agent.set_stood_log_level(app_config().agents.log_level);
+
+        let init_result = agent.initialize(
+            &mut aws_identity.lock().unwrap(),
+            self.agent_logging_enabled,
+        );
+        if let Err(e) = init_result {
+            log::error!("Failed to start scheduled task '{}': {}", task.name, e);
+            self.notify_scheduled_run_failed(&task, &e);
+            return;
+        }
+
+        log::info!(
+            "Starting scheduled task '{}' (agent {})",
+            task.name,
+            agent_id
+        );
+        agent.send_message(task.agent_prompt());
+        self.agents.insert(agent_id, agent);
+        self.scheduled_runs.insert(agent_id, (task, Utc::now()));
+    }
+
+    /// Record the result of a scheduled run and raise a notification
+    ///
+    /// The agent stays in the agent list so its conversation can be reviewed.
+    fn finish_scheduled_run(&mut self, agent_id: AgentId, result: Result<String, String>) {
+        let Some((task, started_at)) = self.scheduled_runs.remove(&agent_id) else {
+            return;
+        };
+
+        let vfs_session_id =
+            self.agents
+                .get(&agent_id)
+                .and_then(|agent| match agent.save_vfs_snapshot() {
+                    Ok(info) => Some(info.session_id),
+                    Err(e) => {
+                        log::debug!("No VFS snapshot for scheduled run {}: {}", agent_id, e);
+                        None
+                    }
+                });
+
+        let recorded = match self.scheduler.store() {
+            Some(store) => store
+                .record_run(&task, started_at, &result, vfs_session_id)
+                .map(Some),
+            None => Ok(None),
+        };
+        let result_path = match recorded {
+            Ok(run) => run.and_then(|run| run.result_path),
+            Err(e) => {
+                log::warn!("Failed to record scheduled run of '{}': {:#}", task.name, e);
+                None
+            }
+        };
+        self.scheduled_tasks_window.refresh_history(&self.scheduler);
+
+        let id = format!("scheduled_task_{}_{}", task.id, started_at.timestamp());
+        let source = "Scheduled Tasks".to_string();
+        match result {
+            Ok(response) => {
+                log::info!("Scheduled task '{}' completed", task.name);
+                let summary = response
+                    .lines()
+                    .map(str::trim)
+                    .find(|line| !line.is_empty())
+                    .unwrap_or("Completed")
+                    .to_string();
+                let message = match result_path {
+                    Some(path) => format!("{}\n\nResult saved to {}", summary, path.display()),
+                    None => summary,
+                };
+                self.pending_notifications.push(
+                    Notification::new_success(
+                        id,
+                        format!("Scheduled task '{}' completed", task.name),
+                        message,
+                        source,
+                    )
+                    .with_related_window(RelatedWindow::AgentSchedules),
+                );
+            }
+            Err(error) => {
+                log::warn!("Scheduled task '{}' failed: {}", task.name, error);
+                self.pending_notifications.push(
+                    Notification::new_error(
+                        id,
+                        format!("Scheduled task '{}' failed", task.name),
+                        vec![NotificationError {
+                            message: error,
+                            code: None,
+                            details: result_path
+                                .map(|path| format!("Result saved to {}", path.display())),
+                        }],
+                        source,
+                    )
+                    .with_related_window(RelatedWindow::AgentSchedules),
+                );
+            }
+        }
+    }
+
+    fn notify_scheduled_run_failed(&mut self, task: &ScheduledTask, error: &str) {
+        self.pending_notifications.push(
+            Notification::new_error(
+                format!("scheduled_task_{}_start", task.id),
+                format!("Scheduled task '{}' could not start", task.name),
+                vec![NotificationError {
+                    message: error.to_string(),
+                    code: None,
+                    details: None,
+                }],
+                "Scheduled Tasks".to_string(),
+            )
+            .with_related_window(RelatedWindow::AgentSchedules),
+        );
+    }
+
+    /// Start queued tasks while there are free slots, and stop the agents
+    /// of running tasks the user cancelled
+    fn start_queued_tasks(&mut self) {
+        let cancelled: Vec<AgentId> = self
+            .queued_runs
+            .iter()
+            .filter(|(_, task_id)| {
+                self.task_queue
+                    .task(task_id)
+                    .map_or(true, |task| task.state != QueuedTaskState::Running)
+            })
+            .map(|(agent_id, _)| *agent_id)
+            .collect();
+        for agent_id in cancelled {
+            self.queued_runs.remove(&agent_id);
+            if let Some(agent) = self.agents.get_mut(&agent_id) {
+                log::info!("Cancelling queued task agent {}", agent_id);
+                agent.cancel();
+            }
+        }
+
+        if self.aws_identity.is_none() {
+            return;
+        }
+        for task in self.task_queue.start_next() {
+            self.start_queued_run(task);
+        }
+    }
+
+    /// Create a TaskManager agent for a queued task and send it the prompt
+    fn start_queued_run(&mut self, task: QueuedTask) {
+        use crate::app::agent_framework::AgentMetadata;
+        use chrono::Utc;
+
+        let Some(aws_identity) = &self.aws_identity else {
+            return;
+        };
+
+        let metadata = AgentMetadata {
+            name: format!("Queued: {}", task.name),
+            description: format!("Queued task: {}", task.name),
+            model: self.model_preferences.manager,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        };
+        let mut agent = AgentInstance::new(metadata, AgentType::TaskManager)
+            .with_token_budget_layer()
+            .with_approval_gate_layer()
+            .with_guardrail_layer()
+            .with_tool_timeline_layer();
+        let agent_id = agent.id();
+        agent.set_stood_log_level(app_config().agents.log_level);
+
+        let init_result = agent.initialize(
+            &mut aws_identity.lock().unwrap(),
+            self.agent_logging_enabled,
+        );
+        if let Err(e) = init_result {
+            log::error!("Failed to start queued task '{}': {}", task.name, e);
+            self.task_queue.finish(&task.id, &Err(e));
+            return;
+        }
+
+        log::info!("Starting queued task '{}' (agent {})", task.name, agent_id);
+        agent.send_message(task.prompt.clone());
+        self.agents.insert(agent_id, agent);
+        self.queued_runs.insert(agent_id, task.id);
+    }
+
+    /// Record the result of a queued run; the agent stays in the agent list
+    /// so its conversation can be reviewed
+    fn finish_queued_run(&mut self, agent_id: AgentId, result: Result<String, String>) {
+        let Some(task_id) = self.queued_runs.remove(&agent_id) else {
+            return;
+        };
+        match &result {
+            Ok(_) => log::info!("Queued task {} completed", task_id),
+            Err(error) => log::warn!("Queued task {} failed: {}", task_id, error),
+        }
+        self.task_queue.finish(&task_id, &result);
+    }
+
+    /// Show the approval dialog for tool calls waiting on the user
+    ///
+    /// Called every frame, whether or not the window is open, because the
+    /// agent making the call is blocked until the user decides.
+    pub fn show_approval_requests(&mut self, ctx: &Context) {
+        self.pending_approvals.extend(take_approval_requests());
+        let Some(request) = self.pending_approvals.first().cloned() else {
+            return;
+        };
+        // The agent keeps polling while it waits; make sure the dialog appears
+        ctx.request_repaint_after(std::time::Duration::from_millis(250));
+
+        let agent_name = request
+            .agent_id
+            .map(|id| self.approval_agent_name(id))
+            .unwrap_or_else(|| "Unknown agent".to_string());
+        let parameters = serde_json::to_string_pretty(&request.parameters)
+            .unwrap_or_else(|_| request.parameters.to_string());
+
+        let mut decision = None;
+        egui::Window::new("Approve Agent Action")
+            .id(egui::Id::new("agent_approval_dialog"))
+            .collapsible(false)
+            .resizable(true)
+            .default_width(520.0)
+            .anchor(egui::Align2::CENTER_CENTER, egui::Vec2::ZERO)
+            .show(ctx, |ui| {
+                ui.label(format!("{} wants to run:", agent_name));
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new(&request.operation).monospace().strong());
+                    let color = match request.risk {
+                        ToolRiskLevel::Destructive => ui.visuals().error_fg_color,
+                        _ => ui.visuals().warn_fg_color,
+                    };
+                    ui.label(RichText::new(format!("[{}]", request.risk)).color(color));
+                });
+                ui.label(
+                    RichText::new(format!(
+                        "Requested {}",
+                        request
+                            .requested_at
+                            .with_timezone(&chrono::Local)
+                            .format("%H:%M:%S")
+                    ))
+                    .weak(),
+                );
+
+                ui.add_space(4.0);
+                ui.label("Parameters:");
+                ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut parameters.as_str())
+                            .code_editor()
+                            .desired_width(f32::INFINITY),
+                    );
+                });
+
+                ui.add_space(4.0);
+                ui.horizontal(|ui| {
+                    ui.label("Reason (optional):");
+                    ui.text_edit_singleline(&mut self.approval_deny_reason);
+                });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Approve").clicked() {
+                        decision = Some(ApprovalDecision::Approved);
+                    }
+                    if ui.button("Deny").clicked() {
+                        let reason = self.approval_deny_reason.trim();
+                        let reason = if reason.is_empty() {
+                            "no reason given".to_string()
+                        } else {
+                            reason.to_string()
+                        };
+                        decision = Some(ApprovalDecision::Denied(reason));
+                    }
+                    if self.pending_approvals.len() > 1 {
+                        ui.label(
+                            RichText::new(format!(
+                                "{} more waiting",
+                                self.pending_approvals.len() - 1
+                            ))
+                            .weak(),
+                        );
+                    }
+                });
+            });
+
+        if let Some(decision) = decision {
+            if !respond_to_approval(request.id, decision) {
+                log::warn!(
+                    "Approval request {} for {} is no longer waiting",
+                    request.id,
+                    request.operation
+                );
+            }
+            self.pending_approvals.remove(0);
+            self.approval_deny_reason.clear();
+        }
+    }
+
+    /// Display name for the agent behind an approval request
+    fn approval_agent_name(&self, agent_id: AgentId) -> String {
+        let Some(agent) = self.agents.get(&agent_id) else {
+            return format!("Agent {}", agent_id);
+        };
+        let parent = agent
+            .agent_type()
+            .parent_id()
+            .and_then(|id| self.agents.get(&id));
+        match parent {
+            Some(parent) => format!(
+                "{} (worker of {})",
+                agent.metadata().name,
+                parent.metadata().name
+            ),
+            None => agent.metadata().name.clone(),
+        }
+    }
+
     /// Poll all agents for responses (called from DashApp::update() every frame)
     ///
     /// This method is called globally before rendering to ensure agent responses
@@ -1868,16 +3103,35 @@ impl AgentManagerWindow {
             poll_start
         );
 
+        // Start scheduled tasks that are due; while the window is closed,
+        // worker requests from scheduled runs are handled here
+        self.start_due_scheduled_tasks();
+        self.start_queued_tasks();
+        if !self.open && !(self.scheduled_runs.is_empty() && self.queued_runs.is_empty()) {
+            self.process_agent_creation_requests();
+        }
+
         // Pollagents (every frame, regardless of selection)
         let v2_agent_ids: Vec<AgentId> = self.agents.keys().copied().collect();
         let mut total_responses = 0;
         let mut completed_workers: Vec<(AgentId, AgentId, Result<String, String>)> = Vec::new();
+        let mut finished_scheduled_runs: Vec<(AgentId, Result<String, String>)> = Vec::new();
+        let mut finished_queued_runs: Vec<(AgentId, Result<String, String>)> = Vec::new();
 
         for agent_id in v2_agent_ids {
             if let Some(agent) = self.agents.get_mut(&agent_id) {
                 let poll_start_v2 = std::time::Instant::now();
                 if agent.poll_response() {
                     let poll_duration = poll_start_v2.elapsed();
+
+                    // Keep the worker's cost for its manager's running total
+                    if let Some(parent_id) = agent.agent_type().parent_id() {
+                        let cost = agent.cost();
+                        self.worker_costs
+                            .entry(parent_id)
+                            .or_default()
+                            .insert(cost.conversation_id.clone(), cost.clone());
+                    }
                     perf_checkpoint!(
                         "UI.poll.response_received",
                         &format!(
@@ -1903,9 +3157,11 @@ impl AgentManagerWindow {
                         );
 
                         // Check if this is a completed worker agent
+                        // A queued user message is answered before the worker reports back
                         if let AgentType::TaskWorker { parent_id } = agent.agent_type() {
                             if last_msg.role
                                 == crate::app::agent_framework::ConversationRole::Assistant
+                                && !agent.has_pending_injections()
                             {
                                 // Worker has completed - check if it's an error or success
                                 let result = if last_msg.content.starts_with("Error: ") {
@@ -1933,6 +3189,30 @@ impl AgentManagerWindow {
                     } else {
                         log::info!("[FRAME {}] [V2 POLL] Agent {} response retrieved | poll_response took: {:?}", frame, agent_id, poll_duration);
                     }
+
+                    // A scheduled or queued run is finished once its agent is idle
+                    let is_scheduled = self.scheduled_runs.contains_key(&agent_id);
+                    let is_queued = self.queued_runs.contains_key(&agent_id);
+                    if (is_scheduled || is_queued)
+                        && !agent.is_processing()
+                        && !agent.has_pending_injections()
+                    {
+                        let last_response = agent
+                            .messages()
+                            .back()
+                            .map(|m| m.content.clone())
+                            .unwrap_or_default();
+                        let result = match agent.status() {
+                            AgentStatus::Failed(error) => Err(error.clone()),
+                            AgentStatus::Cancelled => Err("Cancelled".to_string()),
+                            _ => Ok(last_response),
+                        };
+                        if is_scheduled {
+                            finished_scheduled_runs.push((agent_id, result));
+                        } else {
+                            finished_queued_runs.push((agent_id, result));
+                        }
+                    }
                     total_responses += 1;
                 }
             }
@@ -2009,6 +3289,13 @@ impl AgentManagerWindow {
             );
         }
 
+        for (agent_id, result) in finished_scheduled_runs {
+            self.finish_scheduled_run(agent_id, result);
+        }
+        for (agent_id, result) in finished_queued_runs {
+            self.finish_queued_run(agent_id, result);
+        }
+
         let poll_duration = poll_start.elapsed();
         if total_responses > 0 || poll_duration.as_millis() > 10 {
             log::debug!(
@@ -2105,6 +3392,8 @@ impl FocusableWindow for AgentManagerWindow {
             // Prevent shrinking too small
         }
 
+        window = super::dock_layout::dock_window(window, self.dock_rect);
+
         if bring_to_front {
             window = window.order(egui::Order::Foreground);
         }
@@ -2161,7 +3450,69 @@ impl FocusableWindow for AgentManagerWindow {
 
         // Show VFS browser window if open
         if self.vfs_browser_window.is_open() {
-            self.vfs_browser_window.show(ctx);
+            if let Some(request) = self.vfs_browser_window.show(ctx) {
+                self.handle_vfs_snapshot_request(request);
+            }
+        }
+
+        // Show cost report window if open
+        if self.agent_cost_window.is_open() {
+            self.agent_cost_window.show(ctx);
+        }
+
+        // Show prompt editor window if open
+        if self.prompt_editor_window.is_open() {
+            self.prompt_editor_window.show(ctx);
+        }
+
+        // Show memory inspector window if open
+        if self.agent_memory_window.is_open() {
+            self.agent_memory_window.show(ctx);
+        }
+
+        // Show guardrail window if open
+        if self.agent_guardrail_window.is_open() {
+            self.agent_guardrail_window.show(ctx);
+        }
+
+        // Show model provider window if open
+        if self.agent_provider_window.is_open() {
+            self.agent_provider_window.show(ctx);
+        }
+
+        // Show tool timeline window if open
+        if self.agent_timeline_window.is_open() {
+            let entries = self
+                .agent_timeline_window
+                .agent_id()
+                .and_then(|id| self.agents.get(&id))
+                .map(|agent| agent.tool_timeline().entries());
+            self.agent_timeline_window.show(ctx, entries.as_deref());
+        }
+
+        // Show skills manager window if open
+        if self.skills_manager_window.is_open() {
+            self.skills_manager_window.show(ctx);
+        }
+
+        // Show scheduled tasks window if open
+        if self.scheduled_tasks_window.is_open() {
+            if let Some(task_id) = self.scheduled_tasks_window.show(ctx, &mut self.scheduler) {
+                let task = self
+                    .scheduler
+                    .tasks()
+                    .iter()
+                    .find(|t| t.id == task_id)
+                    .cloned();
+                if let Some(task) = task {
+                    self.start_scheduled_run(task);
+                }
+            }
+        }
+
+        // Show task queue window if open
+        if self.task_queue_window.is_open() {
+            self.task_queue_window.show(ctx, &mut self.task_queue);
         }
 
         // Show agent type selection dialog if open
@@ -2171,3 +3522,35 @@ impl FocusableWindow for AgentManagerWindow {
         }
     }
 }
+
+/// Model dropdown; models Bedrock reports as unavailable are disabled
+///
+/// Returns true when the selection changed.
+fn model_selector(
+    ui: &mut Ui,
+    id_salt: &str,
+    model: &mut AgentModel,
+    availability: Option<&ModelAvailability>,
+) -> bool {
+    let previous = *model;
+    egui::ComboBox::from_id_salt(id_salt)
+        .selected_text(model.display_name())
+        .width(200.0)
+        .show_ui(ui, |ui| {
+            for option in AgentModel::all_models() {
+                let available = availability.map_or(true, |a| a.is_available(*option));
+                let response = ui
+                    .add_enabled_ui(available, |ui| {
+                        ui.selectable_value(model, *option, option.display_name())
+                    })
+                    .inner;
+                if let Some(availability) = availability {
+                    response.on_disabled_hover_text(format!(
+                        "Not available in {}",
+                        availability.region
+                    ));
+                }
+            }
+        });
+    *model != previous
+}
@@ -0,0 +1,305 @@
+//! Agent Memory Window
+//!
+//! Inspects the facts task manager agents remember between sessions, per
+//! project (AWS Identity Center organization). Entries can be edited,
+//! deleted, or added by hand; saved changes apply to conversations started
+//! afterwards.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+
+use crate::app::agent_framework::conversation::memory::{
+    MemoryStore, ProjectMemory, MAX_MEMORY_ENTRIES,
+};
+
+/// State for the memory inspector window
+pub struct AgentMemoryWindow {
+    open: bool,
+    store: Option<MemoryStore>,
+    /// Projects with saved memory, plus the current one
+    projects: Vec<String>,
+    /// Memory being edited
+    memory: ProjectMemory,
+    /// Memory as last loaded or saved, to detect unsaved changes
+    saved_memory: ProjectMemory,
+    /// Text of a fact to add
+    new_fact: String,
+    /// Waiting for a second click on "Clear All"
+    confirm_clear: bool,
+    /// Outcome of the last action
+    message: Option<(String, bool)>,
+}
+
+impl Default for AgentMemoryWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgentMemoryWindow {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            store: MemoryStore::default_location(),
+            projects: Vec::new(),
+            memory: ProjectMemory::default(),
+            saved_memory: ProjectMemory::default(),
+            new_fact: String::new(),
+            confirm_clear: false,
+            message: None,
+        }
+    }
+
+    /// Open the window on `project` (the signed-in organization), or the
+    /// first project with saved memory
+    pub fn open(&mut self, project: Option<String>) {
+        self.open = true;
+        self.message = None;
+        self.confirm_clear = false;
+        self.projects = self
+            .store
+            .as_ref()
+            .map(|store| store.projects())
+            .unwrap_or_default();
+        if let Some(project) = &project {
+            if !self.projects.contains(project) {
+                self.projects.push(project.clone());
+                self.projects.sort();
+            }
+        }
+        let project = project.or_else(|| self.projects.first().cloned());
+        match project {
+            Some(project) => self.load(&project),
+            None => {
+                self.memory = ProjectMemory::default();
+                self.saved_memory = ProjectMemory::default();
+            }
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn load(&mut self, project: &str) {
+        let loaded = match &self.store {
+            Some(store) => store.load(project),
+            None => Ok(ProjectMemory::new(project)),
+        };
+        self.memory = loaded.unwrap_or_else(|e| {
+            self.message = Some((format!("{:#}", e), true));
+            ProjectMemory::new(project)
+        });
+        self.saved_memory = self.memory.clone();
+        self.new_fact.clear();
+        self.confirm_clear = false;
+    }
+
+    fn is_modified(&self) -> bool {
+        self.memory != self.saved_memory
+    }
+
+    fn save(&mut self) {
+        let Some(store) = &self.store else {
+            self.message = Some(("Data directory not available".to_string(), true));
+            return;
+        };
+        // Emptied entries are deleted rather than saved blank
+        self.memory.entries.retain(|e| !e.fact.trim().is_empty());
+        self.message = Some(match store.save(&self.memory) {
+            Ok(()) => {
+                log::info!(
+                    "Saved {} memory entries for project {}",
+                    self.memory.entries.len(),
+                    self.memory.project
+                );
+                self.saved_memory = self.memory.clone();
+                ("Saved; applies to new conversations".to_string(), false)
+            }
+            Err(e) => (format!("{:#}", e), true),
+        });
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Agent Memory")
+            .open(&mut is_open)
+            .default_size([650.0, 500.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                self.render_content(ui);
+            });
+        self.open = is_open;
+    }
+
+    fn render_content(&mut self, ui: &mut Ui) {
+        // Toolbar
+        ui.horizontal(|ui| {
+            ui.label("Project:");
+            let mut selected = self.memory.project.clone();
+            egui::ComboBox::from_id_salt("memory_project_selector")
+                .selected_text(if selected.is_empty() {
+                    "(none)"
+                } else {
+                    selected.as_str()
+                })
+                .show_ui(ui, |ui| {
+                    for project in &self.projects {
+                        ui.selectable_value(&mut selected, project.clone(), project);
+                    }
+                });
+            if selected != self.memory.project {
+                if self.is_modified() {
+                    log::info!(
+                        "Discarding unsaved memory changes for project {}",
+                        self.memory.project
+                    );
+                }
+                self.message = None;
+                self.load(&selected);
+            }
+
+            ui.label(
+                RichText::new(format!(
+                    "{} of {} entries",
+                    self.memory.entries.len(),
+                    MAX_MEMORY_ENTRIES
+                ))
+                .weak(),
+            );
+            if self.is_modified() {
+                ui.label(RichText::new("(unsaved)").italics());
+            }
+        });
+
+        if self.memory.project.is_empty() {
+            ui.separator();
+            ui.label(
+                RichText::new("No memory yet. Facts are saved when a task manager session ends.")
+                    .weak(),
+            );
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(self.is_modified(), egui::Button::new("Save"))
+                .clicked()
+            {
+                self.save();
+            }
+            if ui
+                .add_enabled(self.is_modified(), egui::Button::new("Revert"))
+                .clicked()
+            {
+                self.memory = self.saved_memory.clone();
+            }
+            let clear_label = if self.confirm_clear {
+                "Confirm Clear All"
+            } else {
+                "Clear All"
+            };
+            if ui
+                .add_enabled(
+                    !self.memory.entries.is_empty(),
+                    egui::Button::new(clear_label),
+                )
+                .clicked()
+            {
+                if self.confirm_clear {
+                    self.memory.entries.clear();
+                    self.confirm_clear = false;
+                } else {
+                    self.confirm_clear = true;
+                }
+            }
+            if let Some((message, is_error)) = &self.message {
+                let color = if *is_error {
+                    Color32::from_rgb(220, 80, 80)
+                } else {
+                    Color32::GRAY
+                };
+                ui.label(RichText::new(message).small().color(color));
+            }
+        });
+        ui.label(
+            RichText::new(
+                "Added to the system prompt of new task manager conversations. \
+                 Facts are extracted when a session is closed or cleared.",
+            )
+            .small()
+            .weak(),
+        );
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.new_fact)
+                    .hint_text("Add a fact, e.g. \"Prod workloads run in 123456789012\"")
+                    .desired_width(ui.available_width() - 60.0),
+            );
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            if (ui.button("Add").clicked() || submitted) && !self.new_fact.trim().is_empty() {
+                let fact = std::mem::take(&mut self.new_fact);
+                if self.memory.add_facts(&[fact], None) == 0 {
+                    self.message = Some(("Already remembered".to_string(), false));
+                }
+            }
+        });
+        ui.add_space(4.0);
+
+        let mut removed = None;
+        ScrollArea::vertical()
+            .id_salt("memory_entries_scroll")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if self.memory.entries.is_empty() {
+                    ui.label(RichText::new("Nothing remembered for this project").weak());
+                }
+                // Newest first
+                for entry in self.memory.entries.iter_mut().rev() {
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::multiline(&mut entry.fact)
+                                .desired_rows(1)
+                                .desired_width(ui.available_width() - 70.0),
+                        );
+                        if ui
+                            .small_button("Delete")
+                            .on_hover_text("Forget this fact")
+                            .clicked()
+                        {
+                            removed = Some(entry.id.clone());
+                        }
+                    });
+                    let source = if entry.source_session.is_some() {
+                        "from a session"
+                    } else {
+                        "added by hand"
+                    };
+                    ui.label(
+                        RichText::new(format!(
+                            "{}, {}",
+                            entry
+                                .created_at
+                                .with_timezone(&chrono::Local)
+                                .format("%Y-%m-%d %H:%M"),
+                            source
+                        ))
+                        .small()
+                        .weak(),
+                    );
+                    ui.add_space(4.0);
+                }
+            });
+        if let Some(id) = removed {
+            self.memory.remove(&id);
+        }
+    }
+}
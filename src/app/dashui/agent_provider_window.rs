@@ -0,0 +1,224 @@
+//! Agent Model Provider Window
+//!
+//! Chooses where each agent role gets its model from: Amazon Bedrock (the
+//! default), the Anthropic API, an OpenAI-compatible endpoint, or a local
+//! Ollama server. Each role shows whether its configuration can run an
+//! agent; models without tool use are refused. Saved changes apply to
+//! agents created or reinitialized afterwards.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+
+use crate::app::agent_framework::{
+    AgentModelRole, ModelProviderConfig, ModelProviderKind, ModelProviderPreferences,
+};
+
+/// State for the model provider window
+pub struct AgentProviderWindow {
+    open: bool,
+    /// Preferences being edited
+    preferences: ModelProviderPreferences,
+    /// Preferences as last loaded or saved, to detect unsaved changes
+    saved_preferences: ModelProviderPreferences,
+    /// Outcome of the last save
+    message: Option<(String, bool)>,
+}
+
+impl Default for AgentProviderWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AgentProviderWindow {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            preferences: ModelProviderPreferences::default(),
+            saved_preferences: ModelProviderPreferences::default(),
+            message: None,
+        }
+    }
+
+    /// Open the window with the saved preferences
+    pub fn open(&mut self) {
+        self.open = true;
+        self.message = None;
+        self.preferences = ModelProviderPreferences::load();
+        self.saved_preferences = self.preferences.clone();
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn is_modified(&self) -> bool {
+        self.preferences != self.saved_preferences
+    }
+
+    fn save(&mut self) {
+        self.message = Some(match self.preferences.save() {
+            Ok(()) => {
+                for role in AgentModelRole::all() {
+                    log::info!(
+                        "Agent provider for {}: {}",
+                        role.display_name(),
+                        self.preferences.get(*role).kind
+                    );
+                }
+                self.saved_preferences = self.preferences.clone();
+                (
+                    "Saved; applies to agents created or reinitialized from now on".to_string(),
+                    false,
+                )
+            }
+            Err(e) => (format!("{:#}", e), true),
+        });
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Agent Model Providers")
+            .open(&mut is_open)
+            .default_size([600.0, 500.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        for role in AgentModelRole::all() {
+                            ui.strong(role.display_name());
+                            render_provider(
+                                ui,
+                                &format!("{:?}", role),
+                                self.preferences.get_mut(*role),
+                            );
+                            ui.separator();
+                        }
+                    });
+                self.render_actions(ui);
+            });
+        self.open = is_open;
+    }
+
+    fn render_actions(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(self.is_modified(), egui::Button::new("Save"))
+                .clicked()
+            {
+                self.save();
+            }
+            if ui
+                .add_enabled(self.is_modified(), egui::Button::new("Revert"))
+                .clicked()
+            {
+                self.preferences = self.saved_preferences.clone();
+            }
+            if self.is_modified() {
+                ui.label(RichText::new("(unsaved)").italics());
+            }
+            if let Some((message, is_error)) = &self.message {
+                let color = if *is_error {
+                    Color32::from_rgb(220, 80, 80)
+                } else {
+                    Color32::GRAY
+                };
+                ui.label(RichText::new(message).small().color(color));
+            }
+        });
+    }
+}
+
+/// Editor for one role's provider
+fn render_provider(ui: &mut Ui, id: &str, config: &mut ModelProviderConfig) {
+    egui::Grid::new(format!("provider_grid_{}", id))
+        .num_columns(2)
+        .spacing([8.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("Provider:");
+            egui::ComboBox::from_id_salt(format!("provider_kind_{}", id))
+                .selected_text(config.kind.display_name())
+                .width(180.0)
+                .show_ui(ui, |ui| {
+                    for kind in ModelProviderKind::all() {
+                        ui.selectable_value(&mut config.kind, *kind, kind.display_name());
+                    }
+                });
+            ui.end_row();
+
+            if config.kind == ModelProviderKind::Bedrock {
+                ui.label("Model:");
+                ui.label(
+                    RichText::new("Chosen per agent in the model selector")
+                        .small()
+                        .weak(),
+                );
+                ui.end_row();
+                return;
+            }
+
+            ui.label("Model:");
+            ui.add(
+                egui::TextEdit::singleline(&mut config.model)
+                    .hint_text(match config.kind {
+                        ModelProviderKind::Anthropic => "e.g. claude-sonnet-4-5",
+                        ModelProviderKind::Ollama => "e.g. qwen2.5:14b",
+                        _ => "e.g. gpt-4.1",
+                    })
+                    .desired_width(300.0),
+            );
+            ui.end_row();
+
+            ui.label("Endpoint:");
+            ui.add(
+                egui::TextEdit::singleline(&mut config.endpoint)
+                    .hint_text(config.kind.default_endpoint().unwrap_or_default())
+                    .desired_width(300.0),
+            );
+            ui.end_row();
+
+            if config.kind != ModelProviderKind::Ollama {
+                ui.label("API key variable:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut config.api_key_env)
+                        .hint_text(config.kind.default_api_key_env().unwrap_or_default())
+                        .desired_width(300.0),
+                )
+                .on_hover_text("Environment variable holding the API key; keys are never saved");
+                ui.end_row();
+            }
+
+            if !config.kind.always_supports_tool_use() {
+                ui.label("");
+                ui.checkbox(&mut config.tool_use, "Model supports tool use")
+                    .on_hover_text(
+                        "Agents call tools on every task; models without tool use are refused",
+                    );
+                ui.end_row();
+            }
+        });
+
+    match config.validate() {
+        Ok(()) => {
+            ui.label(
+                RichText::new("Ready")
+                    .small()
+                    .color(Color32::from_rgb(100, 180, 100)),
+            );
+        }
+        Err(e) => {
+            ui.label(
+                RichText::new(e)
+                    .small()
+                    .color(Color32::from_rgb(255, 165, 0)),
+            );
+        }
+    }
+}
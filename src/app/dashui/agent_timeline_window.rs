@@ -0,0 +1,310 @@
+//! Agent Tool Timeline Window
+//!
+//! Shows each tool invocation of one agent conversation as a horizontal bar
+//! placed by start time and sized by duration, colored by outcome. Each row
+//! lists the tool name, duration, and output size; hovering a bar shows the
+//! VFS paths the tool wrote. Bars of running tools grow until they finish.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use std::time::{Duration, Instant};
+
+use eframe::egui;
+use egui::{Color32, Context, Rect, RichText, ScrollArea, Sense, Ui, Vec2};
+
+use crate::app::agent_framework::middleware::layers::ToolTimelineEntry;
+use crate::app::agent_framework::AgentId;
+
+const ROW_HEIGHT: f32 = 18.0;
+const NAME_WIDTH: f32 = 150.0;
+const DETAILS_WIDTH: f32 = 150.0;
+/// Shortest bar drawn, so instant tools stay visible
+const MIN_BAR_WIDTH: f32 = 2.0;
+
+const SUCCESS_COLOR: Color32 = Color32::from_rgb(100, 180, 100);
+const FAILURE_COLOR: Color32 = Color32::from_rgb(255, 100, 100);
+const RUNNING_COLOR: Color32 = Color32::from_rgb(100, 150, 250);
+
+/// State for the tool timeline window
+#[derive(Default)]
+pub struct AgentTimelineWindow {
+    open: bool,
+    /// Agent whose conversation is shown
+    agent_id: Option<AgentId>,
+    agent_name: String,
+    /// Show only failed calls
+    failures_only: bool,
+}
+
+impl AgentTimelineWindow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the window on the conversation of `agent_id`
+    pub fn open(&mut self, agent_id: AgentId, agent_name: String) {
+        self.open = true;
+        self.agent_id = Some(agent_id);
+        self.agent_name = agent_name;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Agent whose timeline is shown
+    pub fn agent_id(&self) -> Option<AgentId> {
+        self.agent_id
+    }
+
+    /// Show the window with the agent's current timeline (None once the
+    /// agent is gone)
+    pub fn show(&mut self, ctx: &Context, entries: Option<&[ToolTimelineEntry]>) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new(format!("Tool Timeline - {}", self.agent_name))
+            .id(egui::Id::new("agent_tool_timeline_window"))
+            .open(&mut is_open)
+            .default_size([800.0, 400.0])
+            .resizable(true)
+            .show(ctx, |ui| match entries {
+                Some(entries) => self.render_content(ui, entries),
+                None => {
+                    ui.label(RichText::new("Agent has been closed").weak());
+                }
+            });
+        self.open = is_open;
+    }
+
+    fn render_content(&mut self, ui: &mut Ui, entries: &[ToolTimelineEntry]) {
+        let now = Instant::now();
+        let summary = TimelineSummary::of(entries, now);
+
+        ui.horizontal(|ui| {
+            ui.label(format!("{} tool calls", entries.len()));
+            if summary.failed > 0 {
+                ui.label(
+                    RichText::new(format!("{} failed", summary.failed)).color(FAILURE_COLOR),
+                );
+            }
+            if summary.running > 0 {
+                ui.label(
+                    RichText::new(format!("{} running", summary.running)).color(RUNNING_COLOR),
+                );
+                // Keep running bars growing
+                ui.ctx().request_repaint_after(Duration::from_millis(250));
+            }
+            ui.separator();
+            ui.label(format!("Span {}", format_duration(summary.span)));
+            ui.label(format!("Output {}", format_size(summary.output_bytes)));
+            ui.separator();
+            ui.checkbox(&mut self.failures_only, "Failures only");
+        });
+        ui.separator();
+
+        if entries.is_empty() {
+            ui.label(RichText::new("No tool calls in this conversation yet").weak());
+            return;
+        }
+
+        let Some(origin) = summary.origin else {
+            return;
+        };
+        let span_secs = summary.span.as_secs_f32().max(0.001);
+
+        ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for entry in entries
+                    .iter()
+                    .filter(|e| !self.failures_only || e.success == Some(false))
+                {
+                    ui.horizontal(|ui| {
+                        ui.add_sized(
+                            [NAME_WIDTH, ROW_HEIGHT],
+                            egui::Label::new(RichText::new(&entry.tool_name).monospace())
+                                .truncate(),
+                        );
+
+                        let bar_area_width =
+                            (ui.available_width() - DETAILS_WIDTH).max(MIN_BAR_WIDTH * 10.0);
+                        let (rect, response) = ui.allocate_exact_size(
+                            Vec2::new(bar_area_width, ROW_HEIGHT),
+                            Sense::hover(),
+                        );
+                        let painter = ui.painter_at(rect);
+                        painter.rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+
+                        let offset = entry.started_at.saturating_duration_since(origin);
+                        let elapsed = entry.elapsed(now);
+                        let left = rect.left()
+                            + rect.width() * (offset.as_secs_f32() / span_secs).min(1.0);
+                        let width = (rect.width() * elapsed.as_secs_f32() / span_secs)
+                            .max(MIN_BAR_WIDTH);
+                        let bar = Rect::from_x_y_ranges(
+                            left..=(left + width).min(rect.right()),
+                            rect.top() + 3.0..=rect.bottom() - 3.0,
+                        );
+                        painter.rect_filled(bar, 2.0, entry_color(entry));
+
+                        response.on_hover_text(entry_tooltip(entry, offset, elapsed));
+
+                        let details = if entry.is_running() {
+                            format!("{} running", format_duration(elapsed))
+                        } else {
+                            format!(
+                                "{}  {}",
+                                format_duration(elapsed),
+                                format_size(entry.output_bytes)
+                            )
+                        };
+                        ui.label(RichText::new(details).small());
+                        if !entry.vfs_paths.is_empty() {
+                            ui.label(
+                                RichText::new(format!("writes: {}", entry.vfs_paths.len()))
+                                    .small()
+                                    .weak(),
+                            );
+                        }
+                    });
+                }
+            });
+    }
+}
+
+/// Totals shown above the bars
+#[derive(Debug, Default, PartialEq)]
+struct TimelineSummary {
+    /// Start of the first call
+    origin: Option<Instant>,
+    /// From the first start to the last finish (or now, while running)
+    span: Duration,
+    failed: usize,
+    running: usize,
+    output_bytes: usize,
+}
+
+impl TimelineSummary {
+    fn of(entries: &[ToolTimelineEntry], now: Instant) -> Self {
+        let origin = entries.iter().map(|e| e.started_at).min();
+        let end = entries.iter().map(|e| e.started_at + e.elapsed(now)).max();
+        Self {
+            origin,
+            span: match (origin, end) {
+                (Some(origin), Some(end)) => end.saturating_duration_since(origin),
+                _ => Duration::ZERO,
+            },
+            failed: entries.iter().filter(|e| e.success == Some(false)).count(),
+            running: entries.iter().filter(|e| e.is_running()).count(),
+            output_bytes: entries.iter().map(|e| e.output_bytes).sum(),
+        }
+    }
+}
+
+fn entry_color(entry: &ToolTimelineEntry) -> Color32 {
+    match entry.success {
+        None => RUNNING_COLOR,
+        Some(true) => SUCCESS_COLOR,
+        Some(false) => FAILURE_COLOR,
+    }
+}
+
+fn entry_tooltip(entry: &ToolTimelineEntry, offset: Duration, elapsed: Duration) -> String {
+    let status = match entry.success {
+        None => "Running",
+        Some(true) => "Succeeded",
+        Some(false) => "Failed",
+    };
+    let mut text = format!(
+        "{}\n{} after {}, took {}\nOutput: {}",
+        entry.tool_name,
+        status,
+        format_duration(offset),
+        format_duration(elapsed),
+        format_size(entry.output_bytes)
+    );
+    if !entry.vfs_paths.is_empty() {
+        text.push_str("\nVFS paths written:");
+        for path in &entry.vfs_paths {
+            text.push_str("\n  ");
+            text.push_str(path);
+        }
+    }
+    text
+}
+
+/// "850 ms", "4.2 s", "3m 05s"
+fn format_duration(duration: Duration) -> String {
+    let ms = duration.as_millis();
+    if ms < 1000 {
+        format!("{} ms", ms)
+    } else if ms < 60_000 {
+        format!("{:.1} s", ms as f64 / 1000.0)
+    } else {
+        format!("{}m {:02}s", ms / 60_000, (ms % 60_000) / 1000)
+    }
+}
+
+fn format_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{} KB", bytes / 1024)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(start: Instant, duration: Option<u64>, success: Option<bool>) -> ToolTimelineEntry {
+        ToolTimelineEntry {
+            tool_name: "execute_javascript".to_string(),
+            started_at: start,
+            duration: duration.map(Duration::from_millis),
+            success,
+            output_bytes: 100,
+            vfs_paths: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let start = Instant::now();
+        let now = start + Duration::from_millis(5000);
+        let entries = vec![
+            entry(start, Some(1000), Some(true)),
+            entry(start + Duration::from_millis(1500), Some(500), Some(false)),
+            entry(start + Duration::from_millis(3000), None, None),
+        ];
+
+        let summary = TimelineSummary::of(&entries, now);
+        assert_eq!(summary.origin, Some(start));
+        assert_eq!(summary.span, Duration::from_millis(5000));
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.running, 1);
+        assert_eq!(summary.output_bytes, 300);
+        assert_eq!(TimelineSummary::of(&[], now), TimelineSummary::default());
+    }
+
+    #[test]
+    fn test_tooltip_lists_vfs_paths() {
+        let mut call = entry(Instant::now(), Some(20), Some(true));
+        call.vfs_paths = vec!["/results/ec2.json".to_string()];
+        let text = entry_tooltip(&call, Duration::from_millis(1500), Duration::from_millis(20));
+        assert!(text.contains("Succeeded after 1.5 s, took 20 ms"));
+        assert!(text.contains("/results/ec2.json"));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_millis(850)), "850 ms");
+        assert_eq!(format_duration(Duration::from_millis(4200)), "4.2 s");
+        assert_eq!(format_duration(Duration::from_secs(185)), "3m 05s");
+    }
+}
@@ -2,6 +2,7 @@
 
 use super::super::DashApp;
 use crate::app::agent_framework::skills::initialize_skill_system;
+use crate::app::app_config::{app_config, set_app_config, AppConfigStore};
 use crate::app::fonts;
 use eframe::egui;
 use tracing::{info, warn};
@@ -22,6 +23,16 @@ impl DashApp {
             mem.data.clear();
         });
 
+        // The settings file decides the theme once it exists; until then keep
+        // the theme remembered in app state and carry it into the settings
+        let mut config = app_config();
+        if AppConfigStore::default_location().is_some_and(|store| store.path().exists()) {
+            app.theme = config.appearance.theme;
+        } else {
+            config.appearance.theme = app.theme;
+            set_app_config(config);
+        }
+
         // Apply the saved theme
         app.apply_theme(&cc.egui_ctx);
 
@@ -3,6 +3,7 @@
 use super::{DashApp, FocusedWindow, PendingWidgetAction};
 use crate::app::dashui::window_focus::FocusableWindow;
 use crate::app::dashui::{ElementAction, KeyEventResult, NavigationCommand, NavigationMode};
+use crate::app::resource_explorer::hint_targets::EXPLORER_HINT_PREFIX;
 use eframe::egui;
 use tracing::info;
 
@@ -160,7 +161,12 @@ impl DashApp {
         );
 
         // Handle ResourceFormWindow-specific elements (legacy prefix-based routing)
-        if element_id.starts_with("resource_form_") {
+        if element_id.starts_with(EXPLORER_HINT_PREFIX) {
+            // Resource Explorer tree targets act on their next render
+            if !self.explorer_manager.activate_hint(element_id, action) {
+                tracing::warn!("Explorer hint target '{}' is no longer open", element_id);
+            }
+        } else if element_id.starts_with("resource_form_") {
             self.handle_resource_form_element_activation(element_id, action);
         } else if element_id.starts_with("template_sections_") {
             self.handle_template_sections_element_activation(element_id, action);
@@ -5,23 +5,57 @@
 //! - theme: Theme management and UI dimension tracking
 //! - window_management: Window focus, shake animations, and positioning
 //! - keyboard_input: Keyboard, navigation, and hint mode input handling
+//! - palette_commands: Subsystem commands offered in the command palette's search mode
 //! - event_handling: Download handling, widget actions, and element activation
 //! - rendering: Top menu bar, status bar, and central panel rendering
 //! - window_rendering: All individual window rendering methods
 
+use super::auto_scaling_group_window::AutoScalingGroupWindow;
 use super::aws_login_window::AwsLoginWindow;
+use super::bedrock_model_catalog_window::BedrockModelCatalogWindow;
+use super::cloudfront_invalidation_window::CloudFrontInvalidationWindow;
 use super::cloudtrail_events_window::CloudTrailEventsWindow;
 use super::cloudwatch_logs_window::CloudWatchLogsWindow;
+use super::athena_query_window::AthenaQueryWindow;
+use super::cloudwatch_metrics_window::CloudWatchMetricsWindow;
+use super::config_history_window::ConfigHistoryWindow;
+use super::dns_records_window::DnsRecordsWindow;
+use super::eventbridge_browser_window::EventBridgeBrowserWindow;
+use super::health_events_window::HealthEventsWindow;
+use super::kms_key_window::KmsKeyWindow;
+use super::lambda_invoke_window::LambdaInvokeWindow;
+use super::recommendations_window::RecommendationsWindow;
+use super::relationship_graph_window::RelationshipGraphWindow;
+use super::s3_bucket_window::S3BucketWindow;
+use super::security_findings_window::SecurityFindingsWindow;
+use super::threat_findings_window::ThreatFindingsWindow;
+use super::service_quotas_window::ServiceQuotasWindow;
+use super::sqs_queue_window::SqsQueueWindow;
+use super::ssm_session_window::SsmSessionWindow;
+use super::stack_drift_window::StackDriftWindow;
+use super::step_functions_window::StepFunctionsWindow;
+use super::stack_import_window::StackImportWindow;
+use super::stack_update_window::StackUpdateWindow;
+use super::template_preview_window::TemplatePreviewWindow;
 use super::command_palette::CommandPalette;
+use super::dock_layout::DockManager;
+use super::dock_layout_window::DockLayoutWindow;
+use super::native_windows::NativeWindowManager;
 use super::help_window::HelpWindow;
+use super::key_binding_editor_window::KeyBindingEditorWindow;
+use super::settings_window::SettingsWindow;
+use super::key_mapping;
 use super::log_window::LogWindow;
 use super::verification_window::VerificationWindow;
 use super::window_focus::WindowFocusManager;
 use super::window_selector::WindowSelector;
-use super::{HintMode, HintOverlay, KeyMappingRegistry, NavigableWidgetManager, NavigationState};
+use super::{HintMode, HintOverlay, NavigableWidgetManager, NavigationState};
 use crate::app::aws_identity::AwsIdentityCenter;
+use crate::app::notifications::history_window::NotificationHistoryWindow;
+use crate::app::notifications::sinks_window::NotificationSinksWindow;
 use crate::app::notifications::NotificationManager;
 use crate::app::resource_explorer::instances::ExplorerManager;
+use crate::app::resource_explorer::resource_finder::ResourceFinderDialog;
 use eframe::egui;
 use std::collections::HashSet;
 use std::time::{Duration, Instant};
@@ -34,12 +68,13 @@ type DeploymentTaskHandle =
 mod event_handling;
 mod initialization;
 mod keyboard_input;
+mod palette_commands;
 mod rendering;
 mod theme;
 mod window_management;
 mod window_rendering;
 
-#[derive(serde::Deserialize, serde::Serialize, Clone, Copy, PartialEq, Default)]
+#[derive(serde::Deserialize, serde::Serialize, Debug, Clone, Copy, PartialEq, Default)]
 pub enum ThemeChoice {
     #[default]
     Latte,
@@ -53,6 +88,15 @@ pub struct NavigationStatusBarSettings {
     pub show_status_bar: bool,
 }
 
+impl ThemeChoice {
+    pub const ALL: [ThemeChoice; 4] = [
+        ThemeChoice::Latte,
+        ThemeChoice::Frappe,
+        ThemeChoice::Macchiato,
+        ThemeChoice::Mocha,
+    ];
+}
+
 impl std::fmt::Display for ThemeChoice {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -87,12 +131,45 @@ pub enum FocusedWindow {
     GuardViolations,
 }
 
+impl FocusedWindow {
+    pub const ALL: [FocusedWindow; 10] = [
+        FocusedWindow::CommandPalette,
+        FocusedWindow::AwsLogin,
+        FocusedWindow::AwsAccounts,
+        FocusedWindow::StartupPopup,
+        FocusedWindow::Help,
+        FocusedWindow::Log,
+        FocusedWindow::Chat,
+        FocusedWindow::AgentManager,
+        FocusedWindow::Verification,
+        FocusedWindow::GuardViolations,
+    ];
+
+    /// Id window-specific key binding overrides are stored under
+    pub fn key_binding_id(&self) -> &'static str {
+        match self {
+            FocusedWindow::CommandPalette => "command_palette",
+            FocusedWindow::AwsLogin => "aws_login",
+            FocusedWindow::AwsAccounts => "aws_accounts",
+            FocusedWindow::StartupPopup => "startup_popup",
+            FocusedWindow::Help => "help",
+            FocusedWindow::Log => "log",
+            FocusedWindow::Chat => "chat",
+            FocusedWindow::AgentManager => "agent_manager",
+            FocusedWindow::Verification => "verification",
+            FocusedWindow::GuardViolations => "guard_violations",
+        }
+    }
+}
+
 #[derive(serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 pub struct DashApp {
     pub theme: ThemeChoice,
     pub navigation_status_bar_settings: NavigationStatusBarSettings,
     pub agent_logging_enabled: bool,
+    /// Utilization at which the Service Quotas window flags a quota
+    pub quota_utilization_threshold: f64,
 
     #[serde(skip)]
     pub command_palette: CommandPalette,
@@ -112,14 +189,70 @@ pub struct DashApp {
     #[serde(skip)]
     pub cloudwatch_logs_windows: Vec<CloudWatchLogsWindow>,
     #[serde(skip)]
+    pub cloudwatch_metrics_windows: Vec<CloudWatchMetricsWindow>,
+    #[serde(skip)]
+    pub config_history_windows: Vec<ConfigHistoryWindow>,
+    #[serde(skip)]
+    pub relationship_graph_windows: Vec<RelationshipGraphWindow>,
+    #[serde(skip)]
+    pub stack_drift_windows: Vec<StackDriftWindow>,
+    #[serde(skip)]
+    pub stack_update_windows: Vec<StackUpdateWindow>,
+    #[serde(skip)]
+    pub stack_import_windows: Vec<StackImportWindow>,
+    #[serde(skip)]
+    pub template_preview_windows: Vec<TemplatePreviewWindow>,
+    #[serde(skip)]
+    pub athena_query_windows: Vec<AthenaQueryWindow>,
+    #[serde(skip)]
+    pub eventbridge_browser_windows: Vec<EventBridgeBrowserWindow>,
+    #[serde(skip)]
+    pub step_functions_windows: Vec<StepFunctionsWindow>,
+    #[serde(skip)]
+    pub lambda_invoke_windows: Vec<LambdaInvokeWindow>,
+    #[serde(skip)]
+    pub sqs_queue_windows: Vec<SqsQueueWindow>,
+    #[serde(skip)]
+    pub ssm_session_windows: Vec<SsmSessionWindow>,
+    #[serde(skip)]
+    pub kms_key_windows: Vec<KmsKeyWindow>,
+    #[serde(skip)]
+    pub s3_bucket_windows: Vec<S3BucketWindow>,
+    #[serde(skip)]
+    pub cloudfront_invalidation_windows: Vec<CloudFrontInvalidationWindow>,
+    #[serde(skip)]
+    pub auto_scaling_group_windows: Vec<AutoScalingGroupWindow>,
+    #[serde(skip)]
+    pub bedrock_model_catalog_windows: Vec<BedrockModelCatalogWindow>,
+    #[serde(skip)]
     pub cloudtrail_events_windows: Vec<CloudTrailEventsWindow>,
     #[serde(skip)]
     pub explorer_manager: ExplorerManager,
     #[serde(skip)]
+    pub resource_finder: ResourceFinderDialog,
+    #[serde(skip)]
+    pub security_findings_window: SecurityFindingsWindow,
+    #[serde(skip)]
+    pub service_quotas_window: ServiceQuotasWindow,
+    #[serde(skip)]
+    pub health_events_window: HealthEventsWindow,
+    #[serde(skip)]
+    pub recommendations_window: RecommendationsWindow,
+    #[serde(skip)]
+    pub threat_findings_window: ThreatFindingsWindow,
+    #[serde(skip)]
+    pub dns_records_window: DnsRecordsWindow,
+    #[serde(skip)]
+    pub open_pages_window: OpenPagesWindow,
+    #[serde(skip)]
     pub pending_deployment_task: Option<DeploymentTaskHandle>,
     #[serde(skip)]
     pub notification_manager: NotificationManager,
     #[serde(skip)]
+    pub notification_history_window: NotificationHistoryWindow,
+    #[serde(skip)]
+    pub notification_sinks_window: NotificationSinksWindow,
+    #[serde(skip)]
     current_template_hash: Option<u64>,
     #[serde(skip)]
     pub window_selector: WindowSelector,
@@ -156,7 +289,15 @@ pub struct DashApp {
     #[serde(skip)]
     navigation_state: NavigationState,
     #[serde(skip)]
-    key_mapping_registry: KeyMappingRegistry,
+    pub key_binding_editor_window: KeyBindingEditorWindow,
+    #[serde(skip)]
+    pub settings_window: SettingsWindow,
+    #[serde(skip)]
+    pub dock_manager: DockManager,
+    #[serde(skip)]
+    pub dock_layout_window: DockLayoutWindow,
+    #[serde(skip)]
+    pub native_windows: NativeWindowManager,
     #[serde(skip)]
     pending_scroll_request: Option<f32>,
     #[serde(skip)]
@@ -189,7 +330,9 @@ impl Default for DashApp {
             theme: ThemeChoice::default(),
             navigation_status_bar_settings: NavigationStatusBarSettings::default(),
             agent_logging_enabled: true,
-            command_palette: CommandPalette::new(),
+            quota_utilization_threshold:
+                crate::app::data_plane::service_quotas::DEFAULT_UTILIZATION_THRESHOLD,
+            command_palette: palette_commands::new_command_palette(),
             show_command_palette: false,
             aws_login_window: AwsLoginWindow::default(),
             help_window: HelpWindow::new(),
@@ -197,10 +340,38 @@ impl Default for DashApp {
             agent_manager_window: None,
             verification_window: VerificationWindow::default(),
             cloudwatch_logs_windows: Vec::new(),
+            cloudwatch_metrics_windows: Vec::new(),
+            config_history_windows: Vec::new(),
+            relationship_graph_windows: Vec::new(),
+            stack_drift_windows: Vec::new(),
+            stack_update_windows: Vec::new(),
+            stack_import_windows: Vec::new(),
+            template_preview_windows: Vec::new(),
+            athena_query_windows: Vec::new(),
+            eventbridge_browser_windows: Vec::new(),
+            step_functions_windows: Vec::new(),
+            lambda_invoke_windows: Vec::new(),
+            sqs_queue_windows: Vec::new(),
+            ssm_session_windows: Vec::new(),
+            kms_key_windows: Vec::new(),
+            s3_bucket_windows: Vec::new(),
+            cloudfront_invalidation_windows: Vec::new(),
+            auto_scaling_group_windows: Vec::new(),
+            bedrock_model_catalog_windows: Vec::new(),
             cloudtrail_events_windows: Vec::new(),
             explorer_manager: ExplorerManager::new(),
+            resource_finder: ResourceFinderDialog::new(),
+            security_findings_window: SecurityFindingsWindow::new(),
+            service_quotas_window: ServiceQuotasWindow::new(),
+            health_events_window: HealthEventsWindow::new(),
+            recommendations_window: RecommendationsWindow::new(),
+            threat_findings_window: ThreatFindingsWindow::new(),
+            dns_records_window: DnsRecordsWindow::new(),
+            open_pages_window: OpenPagesWindow::new(),
             pending_deployment_task: None,
             notification_manager: NotificationManager::new(),
+            notification_history_window: NotificationHistoryWindow::new(),
+            notification_sinks_window: NotificationSinksWindow::new(),
             current_template_hash: None,
             window_selector: WindowSelector::new(),
             aws_identity_center: None,
@@ -218,8 +389,16 @@ impl Default for DashApp {
             pending_shake_timer: None,
             logged_states: HashSet::new(),
             window_focus_manager: WindowFocusManager::new(),
-            navigation_state: NavigationState::new(),
-            key_mapping_registry: KeyMappingRegistry::new(),
+            navigation_state: {
+                let mut navigation_state = NavigationState::new();
+                navigation_state.set_key_bindings(key_mapping::load_key_bindings());
+                navigation_state
+            },
+            key_binding_editor_window: KeyBindingEditorWindow::new(),
+            settings_window: SettingsWindow::new(),
+            dock_manager: DockManager::load(),
+            dock_layout_window: DockLayoutWindow::new(),
+            native_windows: NativeWindowManager::load(),
             pending_scroll_request: None,
             hint_mode: HintMode::new(),
             hint_overlay: HintOverlay::new(),
@@ -236,6 +415,7 @@ impl Default for DashApp {
 impl eframe::App for DashApp {
     fn save(&mut self, storage: &mut dyn eframe::Storage) {
         eframe::set_value(storage, eframe::APP_KEY, self);
+        self.native_windows.save_positions();
     }
 
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
@@ -284,14 +464,15 @@ impl eframe::App for DashApp {
         // Handle UI changes and input
         self.check_ui_dimension_changes(ctx);
 
-        // Start widget collection for this frame
-        self.widget_manager.start_frame();
-
         // Process pending widget actions from previous frame
         self.process_pending_widget_actions();
 
+        // Hint mode starts from the widgets collected while rendering the previous frame
         self.handle_keyboard_input(ctx);
 
+        // Start widget collection for this frame
+        self.widget_manager.start_frame();
+
         // Process pending scroll requests
         self.process_pending_scroll_requests(ctx);
 
@@ -326,6 +507,13 @@ impl eframe::App for DashApp {
         // This ensures agents are polled every frame regardless of window visibility
         if let Some(agent_window) = &mut self.agent_manager_window {
             agent_window.poll_agent_responses_global();
+            for notification in agent_window.take_notifications() {
+                self.notification_manager.add_notification(notification);
+            }
+            // Wake up when the next scheduled agent task is due
+            if let Some(wait) = agent_window.time_until_next_scheduled_run() {
+                ctx.request_repaint_after(wait);
+            }
         }
 
         let phase3_duration = phase3_start.elapsed();
@@ -346,10 +534,14 @@ impl eframe::App for DashApp {
 
         // Handle different windows
         // Resource/template editor windows removed
+        self.native_windows.update_monitor(ctx);
         self.handle_command_palettes(ctx);
         self.handle_auth_windows(ctx);
         self.handle_startup_popup(ctx);
         self.handle_help_window(ctx);
+        self.handle_key_binding_editor_window(ctx);
+        self.handle_settings_window(ctx);
+        self.handle_dock_layout_window(ctx);
         self.handle_log_window(ctx);
         self.handle_chat_window(ctx);
         self.handle_agent_manager_window(ctx);
@@ -361,6 +553,8 @@ impl eframe::App for DashApp {
         self.handle_parameter_dialog(ctx);
         self.handle_deployment_progress_window(ctx);
         self.handle_notification_details_window(ctx);
+        self.handle_notification_history_window(ctx);
+        self.handle_notification_sinks_window(ctx);
 
         let pre_explorer = std::time::Instant::now();
         self.handle_explorer_windows(ctx);
@@ -0,0 +1,183 @@
+//! Command palette commands registered by the subsystems
+//!
+//! Collects the commands each subsystem offers, builds the
+//! [`CommandContext`] they are checked against, and routes a chosen command
+//! back to its subsystem.
+
+use super::DashApp;
+use crate::app::dashui::agent_manager_window;
+use crate::app::dashui::cloudwatch_logs_window::{
+    self, CloudWatchLogsShowParams, CloudWatchLogsWindow,
+};
+use crate::app::dashui::command_palette::CommandPalette;
+use crate::app::dashui::command_registry::CommandContext;
+use crate::app::dashui::dock_layout_window;
+use crate::app::dashui::key_binding_editor_window;
+use crate::app::dashui::settings_window;
+use crate::app::dashui::native_windows;
+use crate::app::dashui::open_pages_window;
+use crate::app::notifications::{history_window, sinks_window};
+use crate::app::resource_explorer::instances::commands as explorer_commands;
+use crate::app::webview::window_registry;
+use eframe::egui;
+
+/// Command palette with the commands of every subsystem registered
+pub(super) fn new_command_palette() -> CommandPalette {
+    let mut palette = CommandPalette::new();
+    palette.register_commands(explorer_commands::palette_commands());
+    palette.register_commands(agent_manager_window::palette_commands());
+    palette.register_commands(open_pages_window::palette_commands());
+    palette.register_commands(cloudwatch_logs_window::palette_commands());
+    palette.register_commands(settings_window::palette_commands());
+    palette.register_commands(key_binding_editor_window::palette_commands());
+    palette.register_commands(dock_layout_window::palette_commands());
+    palette.register_commands(native_windows::palette_commands());
+    palette.register_commands(history_window::palette_commands());
+    palette.register_commands(sinks_window::palette_commands());
+    palette
+}
+
+impl DashApp {
+    /// Snapshot of the app state palette commands are checked against
+    pub(super) fn command_context(&self) -> CommandContext {
+        let current_pane = self
+            .explorer_manager
+            .focused_instance()
+            .filter(|instance| instance.is_open)
+            .map(|instance| instance.current_pane());
+
+        CommandContext {
+            logged_in: self.is_aws_logged_in(),
+            explorer_focused: current_pane.is_some(),
+            pane_has_resources: current_pane.is_some_and(|pane| pane.has_resources()),
+            pane_loading: current_pane.is_some_and(|pane| pane.is_loading()),
+            selected_resource_types: self
+                .explorer_manager
+                .selected_resources()
+                .into_iter()
+                .map(|resource| resource.resource_type)
+                .collect(),
+            agent_processing: self
+                .agent_manager_window
+                .as_ref()
+                .is_some_and(|window| window.is_selected_agent_processing()),
+            cloudwatch_logs_windows: self.cloudwatch_logs_windows.len(),
+            page_windows: window_registry::list_windows().len(),
+        }
+    }
+
+    /// Route a command chosen in the palette's search mode to its subsystem
+    pub(super) fn run_palette_command(&mut self, id: &'static str, ctx: &egui::Context) {
+        tracing::info!("Command palette: running {}", id);
+
+        let handled = match id {
+            open_pages_window::OPEN_PAGES_MANAGER => {
+                self.open_pages_manager_window();
+                true
+            }
+            open_pages_window::SHOW_OPEN_PAGES => {
+                self.open_pages_window.open = true;
+                true
+            }
+            open_pages_window::RELOAD_ALL_PAGES => {
+                self.open_pages_window.reload_all();
+                true
+            }
+            cloudwatch_logs_window::TAIL_SELECTED_LAMBDA => self.open_logs_for_selection(true),
+            cloudwatch_logs_window::VIEW_SELECTED_LOGS => self.open_logs_for_selection(false),
+            cloudwatch_logs_window::STOP_TAILING => {
+                for window in &mut self.cloudwatch_logs_windows {
+                    window.stop_tail();
+                }
+                true
+            }
+            cloudwatch_logs_window::CLOSE_LOGS_WINDOWS => {
+                self.cloudwatch_logs_windows.clear();
+                true
+            }
+            settings_window::OPEN_SETTINGS => {
+                self.settings_window.open();
+                true
+            }
+            key_binding_editor_window::EDIT_KEYBOARD_SHORTCUTS => {
+                self.open_key_binding_editor();
+                true
+            }
+            dock_layout_window::MANAGE_LAYOUTS => {
+                self.dock_layout_window.open(&self.dock_manager);
+                true
+            }
+            dock_layout_window::UNDOCK_ALL => {
+                self.dock_manager.layout_mut().undock_all();
+                true
+            }
+            native_windows::MOVE_EXPLORER
+            | native_windows::MOVE_AGENTS
+            | native_windows::MOVE_LOGS
+            | native_windows::MOVE_CLOUDTRAIL => {
+                if let Some(panel) = native_windows::command_panel(id) {
+                    self.native_windows.move_out(panel);
+                }
+                true
+            }
+            native_windows::RETURN_ALL => {
+                self.native_windows.return_all();
+                true
+            }
+            history_window::SHOW_NOTIFICATION_HISTORY => {
+                self.notification_history_window.open = true;
+                true
+            }
+            sinks_window::SHOW_NOTIFICATION_SINKS => {
+                self.notification_sinks_window
+                    .open(&self.notification_manager);
+                true
+            }
+            _ => {
+                let agent_command = self
+                    .agent_manager_window
+                    .as_mut()
+                    .is_some_and(|window| window.run_palette_command(id));
+                if agent_command {
+                    self.set_focused_window(super::FocusedWindow::AgentManager);
+                    true
+                } else {
+                    self.explorer_manager.run_palette_command(id, ctx)
+                }
+            }
+        };
+
+        if !handled {
+            tracing::warn!("Command palette: {} did not run in the current context", id);
+        }
+    }
+
+    /// Open CloudWatch Logs for the first selected Explorer resource with logs
+    ///
+    /// With `tail`, only Lambda functions are considered and the window
+    /// follows new events right away.
+    fn open_logs_for_selection(&mut self, tail: bool) -> bool {
+        let params = self
+            .explorer_manager
+            .selected_resources()
+            .iter()
+            .filter(|resource| !tail || resource.resource_type == "AWS::Lambda::Function")
+            .find_map(CloudWatchLogsShowParams::for_resource);
+        let Some(params) = params else {
+            return false;
+        };
+        let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() else {
+            self.show_login_required_notification("CloudWatch Logs");
+            return false;
+        };
+
+        let mut window = CloudWatchLogsWindow::new(aws_client.get_credential_coordinator());
+        if tail {
+            window.open_tailing(params);
+        } else {
+            window.open_for_resource(params);
+        }
+        self.cloudwatch_logs_windows.push(window);
+        true
+    }
+}
@@ -1,6 +1,7 @@
 //! UI rendering for top menu, status bar, central panel, and overlays
 
 use super::{DashApp, FocusedWindow};
+use crate::app::app_config::{app_config, save_app_config};
 use crate::app::dashui::command_palette::CommandAction;
 use crate::app::dashui::menu;
 use crate::app::dashui::NavigationMode;
@@ -35,10 +36,20 @@ impl DashApp {
                     compliance_programs,
                 );
 
+                // Open AWS Health issues and scheduled changes
+                if self.health_events_window.render_badge(ui) {
+                    self.health_events_window.open = true;
+                }
+
                 // Handle menu actions
                 match menu_action {
                     menu::MenuAction::ThemeChanged => {
                         tracing::debug!("Theme changed");
+                        let mut config = app_config();
+                        config.appearance.theme = self.theme;
+                        if let Err(e) = save_app_config(config) {
+                            tracing::warn!("Failed to save theme: {:#}", e);
+                        }
                     }
                     menu::MenuAction::NavigationStatusBarChanged => {
                         tracing::info!(
@@ -72,6 +83,10 @@ impl DashApp {
                         self.aws_login_window.reset_position();
                         tracing::info!("AWS Login window opened from Dash menu");
                     }
+                    menu::MenuAction::ReauthenticateAWS => {
+                        self.aws_login_window.start_reauthentication();
+                        tracing::info!("Re-authentication started from session indicator");
+                    }
                     menu::MenuAction::AWSExplorer => {
                         // Check if logged in to AWS before creating new Explorer window
                         if self.is_aws_logged_in() {
@@ -83,6 +98,64 @@ impl DashApp {
                             tracing::warn!("AWS Explorer access denied - not logged in");
                         }
                     }
+                    menu::MenuAction::SecurityFindings => {
+                        // Scans the Explorer cache only, so no login is required
+                        self.security_findings_window
+                            .open_and_scan(self.explorer_manager.shared_context.cache.clone());
+                        tracing::info!("Security Findings window opened from Dash menu");
+                    }
+                    menu::MenuAction::ServiceQuotas => {
+                        // Checks the accounts and regions selected in the Explorer
+                        if let Some(aws_client) =
+                            self.explorer_manager.shared_context.get_aws_client()
+                        {
+                            let scope = self.explorer_manager.selected_scope();
+                            self.service_quotas_window.open_for_scope(
+                                aws_client.get_credential_coordinator(),
+                                scope.accounts,
+                                scope.regions,
+                                self.quota_utilization_threshold,
+                            );
+                            tracing::info!("Service Quotas window opened from Dash menu");
+                        } else {
+                            self.show_login_required_notification("Service Quotas");
+                            tracing::warn!("Service Quotas access denied - not logged in");
+                        }
+                    }
+                    menu::MenuAction::HealthEvents => {
+                        // The feed itself is polled in the background once logged in
+                        if self.is_aws_logged_in() {
+                            self.health_events_window.open = true;
+                            tracing::info!("AWS Health window opened from Dash menu");
+                        } else {
+                            self.show_login_required_notification("AWS Health");
+                            tracing::warn!("AWS Health access denied - not logged in");
+                        }
+                    }
+                    menu::MenuAction::Recommendations => {
+                        // Loads the accounts and regions selected in the Explorer
+                        if let Some(aws_client) =
+                            self.explorer_manager.shared_context.get_aws_client()
+                        {
+                            let scope = self.explorer_manager.selected_scope();
+                            self.recommendations_window.open_for_scope(
+                                aws_client.get_credential_coordinator(),
+                                scope.accounts,
+                                scope.regions,
+                                self.explorer_manager.shared_context.cache.clone(),
+                            );
+                            tracing::info!("Recommendations window opened from Dash menu");
+                        } else {
+                            self.show_login_required_notification("Recommendations");
+                            tracing::warn!("Recommendations access denied - not logged in");
+                        }
+                    }
+                    menu::MenuAction::DnsRecords => {
+                        // Searches the records loaded in the Explorer, so no login is required
+                        self.dns_records_window
+                            .open_for(self.explorer_manager.shared_context.cache.clone(), None);
+                        tracing::info!("DNS Records window opened from Dash menu");
+                    }
                     menu::MenuAction::AgentManager => {
                         // Check if logged in to AWS before opening Agent Manager
                         if self.is_aws_logged_in() {
@@ -100,6 +173,31 @@ impl DashApp {
                         self.open_pages_manager_window();
                         tracing::info!("Pages Manager window opened from Dash menu");
                     }
+                    menu::MenuAction::OpenPages => {
+                        self.open_pages_window.open = true;
+                        tracing::info!("Open Pages window opened from Dash menu");
+                    }
+                    menu::MenuAction::Settings => {
+                        self.settings_window.open();
+                        tracing::info!("Settings window opened from Dash menu");
+                    }
+                    menu::MenuAction::KeyboardShortcuts => {
+                        self.open_key_binding_editor();
+                        tracing::info!("Keyboard Shortcuts window opened from Dash menu");
+                    }
+                    menu::MenuAction::WindowLayouts => {
+                        self.dock_layout_window.open(&self.dock_manager);
+                        tracing::info!("Window Layouts window opened from Dash menu");
+                    }
+                    menu::MenuAction::NotificationHistory => {
+                        self.notification_history_window.open = true;
+                        tracing::info!("Notification History window opened from Dash menu");
+                    }
+                    menu::MenuAction::NotificationWebhooks => {
+                        self.notification_sinks_window
+                            .open(&self.notification_manager);
+                        tracing::info!("Notification Webhooks window opened from Dash menu");
+                    }
                     menu::MenuAction::Quit => {
                         ctx.send_viewport_cmd(egui::ViewportCommand::Close);
                         tracing::info!("Quit requested from Dash menu");
@@ -166,6 +264,13 @@ impl DashApp {
 
                     // Error/warning notifications
                     self.notification_manager.render_status_bar_indicator(ui);
+                    if ui
+                        .small_button("History")
+                        .on_hover_text("Notification history")
+                        .clicked()
+                    {
+                        self.notification_history_window.open = true;
+                    }
 
                     // Add some spacing to push the next element to the right
                     ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
@@ -194,6 +299,13 @@ impl DashApp {
 
     /// Render the main content area
     pub(super) fn render_main_content_area(&mut self, _ctx: &egui::Context, ui: &mut egui::Ui) {
+        // Lay out the docked windows' tiles; the windows pin themselves to them later
+        let open_panels = self.open_dock_panels();
+        self.dock_manager.show(ui, &open_panels);
+        if !self.dock_manager.layout().is_empty() {
+            return;
+        }
+
         // Show welcome message
         egui::Frame::default()
             .fill(ui.style().visuals.window_fill)
@@ -268,7 +380,8 @@ impl DashApp {
             }
 
             // Now we use the command palette's action return value
-            if let Some(action) = self.command_palette.show(ctx) {
+            let context = self.command_context();
+            if let Some(action) = self.command_palette.show(ctx, &context) {
                 // When an action is returned, the command palette closes itself
                 self.show_command_palette = false;
                 match action {
@@ -287,6 +400,15 @@ impl DashApp {
                             tracing::warn!("AWS Explorer access denied - not logged in");
                         }
                     }
+                    CommandAction::FindResource => {
+                        // Live lookups need AWS credentials; the cache alone is not enough
+                        if self.is_aws_logged_in() {
+                            self.resource_finder.open_dialog();
+                        } else {
+                            self.show_login_required_notification("Find Resource");
+                            tracing::warn!("Find Resource access denied - not logged in");
+                        }
+                    }
                     CommandAction::AgentManager => {
                         // Check if logged in to AWS before opening Agent Manager
                         if self.is_aws_logged_in() {
@@ -302,6 +424,9 @@ impl DashApp {
                     CommandAction::Quit => {
                         ctx.send_viewport_cmd(egui::ViewportCommand::Close);
                     }
+                    CommandAction::Run(id) => {
+                        self.run_palette_command(id, ctx);
+                    }
                 }
             }
 
@@ -310,8 +435,8 @@ impl DashApp {
                 self.show_command_palette = false;
                 // We don't need to clear focus here because we'll focus the next window
             }
-        } else {
-            self.command_palette.show = false;
+        } else if self.command_palette.show {
+            self.command_palette.close();
         }
     }
 
@@ -522,7 +647,8 @@ impl DashApp {
                 ),
             }],
             feature_name.to_string(),
-        );
+        )
+        .with_related_window(crate::app::notifications::RelatedWindow::AwsLogin);
 
         self.notification_manager.add_notification(notification);
         tracing::info!("Login required notification shown for {}", feature_name);
@@ -102,6 +102,10 @@ impl DashApp {
 
             // Remove the new window from the order if it was there
             self.window_focus_order.retain(|w| *w != window);
+
+            // Window-specific key bindings follow focus
+            self.navigation_state
+                .set_active_window(Some(window.key_binding_id().to_string()));
         }
     }
 
@@ -116,7 +120,7 @@ impl DashApp {
             match window {
                 FocusedWindow::CommandPalette => {
                     self.show_command_palette = false;
-                    self.command_palette.show = false;
+                    self.command_palette.close();
                 }
                 FocusedWindow::AwsLogin => {
                     self.aws_login_window.open = false;
@@ -155,6 +159,10 @@ impl DashApp {
 
             // Set focus to the next available window
             self.currently_focused_window = self.get_previous_window();
+            self.navigation_state.set_active_window(
+                self.currently_focused_window
+                    .map(|w| w.key_binding_id().to_string()),
+            );
 
             info!(
                 "Closed window: {:?}, new focus: {:?}",
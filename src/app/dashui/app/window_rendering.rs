@@ -3,7 +3,11 @@
 use super::{DashApp, FocusedWindow};
 use crate::app::agent_framework::v8_bindings::set_global_aws_identity;
 use crate::app::agent_framework::utils::registry::set_global_aws_client;
+use crate::app::app_config::set_app_config;
+use crate::app::dashui::agent_manager_window;
+use crate::app::dashui::dock_layout::DockPanel;
 use crate::app::dashui::window_focus::FocusableWindow;
+use crate::app::notifications::RelatedWindow;
 use crate::app::resource_explorer::set_global_bookmark_manager;
 use eframe::egui;
 use std::sync::Arc;
@@ -78,6 +82,14 @@ impl DashApp {
                 // Check if credentials are actually available (prevents race condition)
                 // Use try_lock() to avoid blocking UI when login thread holds the mutex
                 let has_credentials = if let Ok(identity) = aws_identity.try_lock() {
+                    // Regions, ARNs and console links follow the session's partition
+                    crate::app::aws_regions::set_active_partition(identity.partition());
+                    // Notification webhooks are configured per organization
+                    self.notification_manager.sinks_mut().set_project(
+                        &crate::app::agent_framework::conversation::memory::project_key(
+                            &identity.start_url,
+                        ),
+                    );
                     identity.default_role_credentials.is_some()
                 } else {
                     false // Lock held by login thread - assume not ready yet
@@ -104,6 +116,14 @@ impl DashApp {
                                 default_role,
                             ),
                         );
+                        drop(identity_center);
+
+                        // Discover the organization's accounts and OUs once per login
+                        crate::app::resource_explorer::organization_directory::ensure_discovery(
+                            &aws_identity,
+                            credential_coordinator.clone(),
+                        );
+
                         let aws_client = Arc::new(
                             crate::app::resource_explorer::AWSResourceClient::new(credential_coordinator)
                         );
@@ -151,6 +171,9 @@ impl DashApp {
                 // and the logged_out flag is set, it means user logged out
                 tracing::info!("Clearing AWS Identity Center reference due to logout");
                 self.aws_identity_center = None;
+                self.notification_manager.sinks_mut().set_project(
+                    crate::app::agent_framework::conversation::memory::DEFAULT_PROJECT,
+                );
 
                 // Clear global AwsIdentity for agent framework tools
                 set_global_aws_identity(None);
@@ -160,6 +183,8 @@ impl DashApp {
                 self.explorer_manager.set_aws_identity_center(None);
                 self.explorer_manager.set_aws_client(None);
                 self.explorer_manager.close_all_windows();
+                crate::app::resource_explorer::organization_directory::clear();
+                crate::app::aws_regions::set_active_partition(Default::default());
 
                 // Clear global AWS client for bridge tools
                 set_global_aws_client(None);
@@ -257,6 +282,16 @@ impl DashApp {
         // Track which instances should be closed (after rendering)
         let mut instances_to_close = Vec::new();
 
+        // The first open instance takes the Explorer dock tile or its own OS window
+        let placement = self.dock_manager.placement(DockPanel::Explorer);
+        let moved_out = self.native_windows.is_moved_out(DockPanel::Explorer);
+        let docked_id = self
+            .explorer_manager
+            .instances
+            .iter()
+            .find(|i| i.is_open)
+            .map(|i| i.id());
+
         // Render each Explorer instance
         for instance_id in instance_ids {
             if let Some(instance) = self
@@ -265,6 +300,25 @@ impl DashApp {
                 .iter_mut()
                 .find(|i| i.id() == instance_id)
             {
+                let is_docked = Some(instance_id) == docked_id;
+                if is_docked && moved_out {
+                    let shared_context = self.explorer_manager.shared_context.clone();
+                    self.native_windows.show(ctx, DockPanel::Explorer, |ctx, rect| {
+                        instance.dock_rect = rect;
+                        FocusableWindow::show_with_focus(
+                            &mut *instance,
+                            ctx,
+                            shared_context.clone(),
+                            false,
+                        );
+                    });
+                    continue;
+                }
+                instance.dock_rect = if is_docked { placement.tile() } else { None };
+                if is_docked && placement.is_hidden() {
+                    continue;
+                }
+
                 // Check if this window should be brought to the front
                 let window_id_str = instance.window_id();
                 let bring_to_front = self.window_focus_manager.should_bring_to_front(window_id_str);
@@ -290,11 +344,95 @@ impl DashApp {
             }
         }
 
+        // Offer the rendered tree nodes, tag badges and action buttons to hint mode;
+        // hints only cover the main window
+        if let Some(instance_id) = docked_id.filter(|_| moved_out) {
+            self.explorer_manager.discard_hint_elements(instance_id);
+        }
+        for element in self.explorer_manager.take_hint_elements() {
+            self.widget_manager.collector_mut().add_element(element);
+        }
+
         // Close instances that were marked for closure
         for instance_id in instances_to_close {
             self.explorer_manager.close_window(instance_id);
         }
 
+        // Workspaces picked with "Window" open after all instances rendered
+        self.explorer_manager.open_pending_workspace_windows(ctx);
+
+        // Find Resource searches the shared cache and every pane's accounts/regions
+        let find_scope = self.explorer_manager.selected_scope();
+        let find_request = self.resource_finder.show(
+            ctx,
+            self.explorer_manager.shared_context.cache.clone(),
+            self.explorer_manager.shared_context.aws_client.clone(),
+            &find_scope,
+        );
+
+        self.open_pages_window.show(ctx);
+
+        // Security findings show their resource the same way
+        self.security_findings_window.show(ctx);
+        let finding_request = self.security_findings_window.take_reveal_request();
+
+        self.service_quotas_window.show(ctx);
+        self.quota_utilization_threshold = self.service_quotas_window.threshold();
+
+        // AWS Health feed for every account in the identity center
+        if self.health_events_window.poll_due() && self.is_aws_logged_in() {
+            let account_ids = self
+                .aws_identity_center
+                .as_ref()
+                .and_then(|identity| identity.try_lock().ok())
+                .map(|identity| {
+                    identity
+                        .accounts
+                        .iter()
+                        .map(|account| account.account_id.clone())
+                        .collect::<Vec<_>>()
+                });
+            if let (Some(account_ids), Some(aws_client)) = (
+                account_ids,
+                self.explorer_manager.shared_context.get_aws_client(),
+            ) {
+                self.health_events_window.start_poll(
+                    aws_client.get_credential_coordinator(),
+                    account_ids,
+                    self.explorer_manager.shared_context.cache.clone(),
+                );
+            }
+        }
+        self.health_events_window.show(ctx);
+        let health_request = self.health_events_window.take_reveal_request();
+
+        // Recommendations also badge matching resources in every Explorer pane
+        self.recommendations_window.show(ctx);
+        self.explorer_manager.shared_context.recommendation_index =
+            self.recommendations_window.badge_index();
+        let recommendation_request = self.recommendations_window.take_reveal_request();
+
+        self.threat_findings_window.show(ctx);
+        let threat_request = self.threat_findings_window.take_reveal_request();
+
+        self.dns_records_window.show(ctx);
+        let dns_request = self.dns_records_window.take_reveal_request();
+
+        for request in find_request
+            .into_iter()
+            .chain(finding_request)
+            .chain(health_request)
+            .chain(recommendation_request)
+            .chain(threat_request)
+            .chain(dns_request)
+        {
+            let instance_id = self.explorer_manager.reveal_resource(request);
+            if let Some(instance) = self.explorer_manager.get_instance_mut(instance_id) {
+                let window_id = instance.window_id().to_string();
+                self.window_focus_manager.request_focus(window_id);
+            }
+        }
+
         // Process V8 ExplorerAction queue (agent scripts requesting Explorer windows)
         let v8_actions = crate::app::resource_explorer::drain_explorer_actions();
         for v8_action in v8_actions {
@@ -339,6 +477,494 @@ impl DashApp {
                         self.cloudwatch_logs_windows.push(new_window);
                     }
                 }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenCloudWatchMetrics {
+                    resource_type,
+                    resource_id,
+                    resource_name,
+                    account_id,
+                    region,
+                } => {
+                    // Create a new CloudWatch Metrics window for this resource
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window = crate::app::dashui::CloudWatchMetricsWindow::new(credential_coordinator);
+
+                        new_window.open_for_resource(
+                            crate::app::dashui::CloudWatchMetricsShowParams {
+                                resource_type,
+                                resource_id,
+                                resource_name,
+                                account_id,
+                                region,
+                            },
+                        );
+
+                        // Add to the list of open windows
+                        self.cloudwatch_metrics_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenConfigHistory {
+                    resource_type,
+                    resource_id,
+                    resource_name,
+                    account_id,
+                    region,
+                } => {
+                    // Create a new Config History window for this resource
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window = crate::app::dashui::ConfigHistoryWindow::new(credential_coordinator);
+
+                        new_window.open_for_resource(
+                            crate::app::dashui::ConfigHistoryShowParams {
+                                resource_type,
+                                resource_id,
+                                resource_name,
+                                account_id,
+                                region,
+                            },
+                        );
+
+                        // Add to the list of open windows
+                        self.config_history_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenRelationshipGraph {
+                    resource_type,
+                    resource_id,
+                    resource_name,
+                    account_id,
+                    region,
+                } => {
+                    // Create a new Relationship Graph window over the shared cache
+                    let mut new_window = crate::app::dashui::RelationshipGraphWindow::new(
+                        self.explorer_manager.shared_context.cache.clone(),
+                    );
+
+                    new_window.open_for_resource(
+                        crate::app::dashui::RelationshipGraphShowParams {
+                            resource_type,
+                            resource_id,
+                            resource_name,
+                            account_id,
+                            region,
+                        },
+                    );
+
+                    // Add to the list of open windows
+                    self.relationship_graph_windows.push(new_window);
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenStackDrift {
+                    stack_name,
+                    account_id,
+                    region,
+                } => {
+                    // Create a new Stack Drift window and start detection
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window = crate::app::dashui::StackDriftWindow::new(credential_coordinator);
+
+                        new_window.open_for_stack(crate::app::dashui::StackDriftShowParams {
+                            stack_name,
+                            account_id,
+                            region,
+                        });
+
+                        // Add to the list of open windows
+                        self.stack_drift_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenStackUpdate {
+                    stack_name,
+                    account_id,
+                    region,
+                } => {
+                    // Create a new Stack Update window for a change-set-first update
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window = crate::app::dashui::StackUpdateWindow::new(credential_coordinator);
+
+                        new_window.open_for_stack(crate::app::dashui::StackUpdateShowParams {
+                            stack_name,
+                            account_id,
+                            region,
+                        });
+
+                        // Add to the list of open windows
+                        self.stack_update_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenStackImport {
+                    resources,
+                } => {
+                    // Create a new Stack Import window for the selected resources
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window = crate::app::dashui::StackImportWindow::new(credential_coordinator);
+
+                        new_window.open_for_resources(crate::app::dashui::StackImportShowParams {
+                            resources,
+                        });
+
+                        // Add to the list of open windows
+                        self.stack_import_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenTemplateFromSelection {
+                    resources,
+                } => {
+                    // Create a new Template Preview window for the selected resources
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window = crate::app::dashui::TemplatePreviewWindow::new(credential_coordinator);
+
+                        new_window.open_for_resources(crate::app::dashui::TemplatePreviewShowParams {
+                            resources,
+                        });
+
+                        // Add to the list of open windows
+                        self.template_preview_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenAthenaQuery {
+                    workgroup,
+                    account_id,
+                    region,
+                    output_location,
+                } => {
+                    // Create a new Athena query editor for this workgroup
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window = crate::app::dashui::AthenaQueryWindow::new(credential_coordinator);
+
+                        new_window.open_for_workgroup(
+                            crate::app::dashui::AthenaQueryShowParams {
+                                workgroup,
+                                account_id,
+                                region,
+                                output_location,
+                            },
+                        );
+
+                        // Add to the list of open windows
+                        self.athena_query_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenEventBridgeBrowser {
+                    event_bus_name,
+                    rule_name,
+                    account_id,
+                    region,
+                } => {
+                    // Create a new EventBridge browser for this bus
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window =
+                            crate::app::dashui::EventBridgeBrowserWindow::new(credential_coordinator);
+
+                        new_window.open_for_bus(crate::app::dashui::EventBridgeBrowserShowParams {
+                            event_bus_name,
+                            rule_name,
+                            account_id,
+                            region,
+                        });
+
+                        // Add to the list of open windows
+                        self.eventbridge_browser_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenStepFunctionsExecutions {
+                    state_machine_arn,
+                    state_machine_name,
+                    account_id,
+                    region,
+                } => {
+                    // Create a new execution viewer for this state machine
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window =
+                            crate::app::dashui::StepFunctionsWindow::new(credential_coordinator);
+
+                        new_window.open_for_state_machine(crate::app::dashui::StepFunctionsShowParams {
+                            state_machine_arn,
+                            state_machine_name,
+                            account_id,
+                            region,
+                        });
+
+                        // Add to the list of open windows
+                        self.step_functions_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenLambdaInvoke {
+                    function_name,
+                    account_id,
+                    region,
+                } => {
+                    // Reuse the window already open for this function to keep its payload
+                    if let Some(index) = self.lambda_invoke_windows.iter().position(|w| {
+                        w.is_open() && w.targets(&function_name, &account_id, &region)
+                    }) {
+                        self.lambda_invoke_windows[index].open_for_function(crate::app::dashui::LambdaInvokeShowParams {
+                            function_name,
+                            account_id,
+                            region,
+                        });
+                    } else if let Some(aws_client) =
+                        self.explorer_manager.shared_context.get_aws_client()
+                    {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window =
+                            crate::app::dashui::LambdaInvokeWindow::new(credential_coordinator);
+
+                        new_window.open_for_function(crate::app::dashui::LambdaInvokeShowParams {
+                            function_name,
+                            account_id,
+                            region,
+                        });
+
+                        // Add to the list of open windows
+                        self.lambda_invoke_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenSqsQueueInspector {
+                    queue_name,
+                    queue_url,
+                    account_id,
+                    region,
+                } => {
+                    // Create a new inspector for this queue
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window =
+                            crate::app::dashui::SqsQueueWindow::new(credential_coordinator);
+
+                        new_window.open_for_queue(crate::app::dashui::SqsQueueShowParams {
+                            queue_name,
+                            queue_url,
+                            account_id,
+                            region,
+                        });
+
+                        // Add to the list of open windows
+                        self.sqs_queue_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenSsmSession {
+                    instance_id,
+                    instance_name,
+                    account_id,
+                    region,
+                } => {
+                    // Each window is its own session, so several can target one instance
+                    if let Some(aws_client) = self.explorer_manager.shared_context.get_aws_client() {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window =
+                            crate::app::dashui::SsmSessionWindow::new(credential_coordinator);
+
+                        new_window.open_for_instance(crate::app::dashui::SsmSessionShowParams {
+                            instance_id,
+                            instance_name,
+                            account_id,
+                            region,
+                        });
+
+                        // Add to the list of open windows
+                        self.ssm_session_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenKmsKeyDetails {
+                    key_id,
+                    key_name,
+                    account_id,
+                    region,
+                } => {
+                    // Reuse the window already open for this key
+                    if let Some(index) = self
+                        .kms_key_windows
+                        .iter()
+                        .position(|w| w.is_open() && w.targets(&key_id, &account_id, &region))
+                    {
+                        self.kms_key_windows[index].open_for_key(crate::app::dashui::KmsKeyShowParams {
+                            key_id,
+                            key_name,
+                            account_id,
+                            region,
+                        });
+                    } else if let Some(aws_client) =
+                        self.explorer_manager.shared_context.get_aws_client()
+                    {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window =
+                            crate::app::dashui::KmsKeyWindow::new(credential_coordinator);
+
+                        new_window.open_for_key(crate::app::dashui::KmsKeyShowParams {
+                            key_id,
+                            key_name,
+                            account_id,
+                            region,
+                        });
+
+                        // Add to the list of open windows
+                        self.kms_key_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenS3BucketDetails {
+                    bucket_name,
+                    account_id,
+                    region,
+                } => {
+                    // Reuse the window already open for this bucket
+                    if let Some(index) = self
+                        .s3_bucket_windows
+                        .iter()
+                        .position(|w| w.is_open() && w.targets(&bucket_name, &account_id))
+                    {
+                        self.s3_bucket_windows[index].open_for_bucket(
+                            crate::app::dashui::S3BucketShowParams {
+                                bucket_name,
+                                account_id,
+                                region,
+                            },
+                        );
+                    } else if let Some(aws_client) =
+                        self.explorer_manager.shared_context.get_aws_client()
+                    {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window =
+                            crate::app::dashui::S3BucketWindow::new(credential_coordinator);
+
+                        new_window.open_for_bucket(crate::app::dashui::S3BucketShowParams {
+                            bucket_name,
+                            account_id,
+                            region,
+                        });
+
+                        // Add to the list of open windows
+                        self.s3_bucket_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenCloudFrontInvalidations {
+                    distribution_id,
+                    distribution_name,
+                    account_id,
+                } => {
+                    let params = crate::app::dashui::CloudFrontInvalidationShowParams {
+                        distribution_id,
+                        distribution_name,
+                        account_id,
+                    };
+                    // Reuse the window for this distribution, open or still tracking
+                    if let Some(window) = self
+                        .cloudfront_invalidation_windows
+                        .iter_mut()
+                        .find(|w| w.targets(&params.distribution_id, &params.account_id))
+                    {
+                        window.open_for_distribution(params);
+                    } else if let Some(aws_client) =
+                        self.explorer_manager.shared_context.get_aws_client()
+                    {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window = crate::app::dashui::CloudFrontInvalidationWindow::new(
+                            credential_coordinator,
+                        );
+                        new_window.open_for_distribution(params);
+
+                        // Add to the list of open windows
+                        self.cloudfront_invalidation_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenAutoScalingGroup {
+                    group_name,
+                    account_id,
+                    region,
+                } => {
+                    let params = crate::app::dashui::AutoScalingGroupShowParams {
+                        group_name,
+                        account_id,
+                        region,
+                    };
+                    // Reuse the window for this group, open or still following a change
+                    if let Some(window) = self.auto_scaling_group_windows.iter_mut().find(|w| {
+                        w.targets(&params.group_name, &params.account_id, &params.region)
+                    }) {
+                        window.open_for_group(params);
+                    } else if let Some(aws_client) =
+                        self.explorer_manager.shared_context.get_aws_client()
+                    {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window =
+                            crate::app::dashui::AutoScalingGroupWindow::new(credential_coordinator);
+                        new_window.open_for_group(params);
+
+                        // Add to the list of open windows
+                        self.auto_scaling_group_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenBedrockModelCatalog {
+                    account_id,
+                    region,
+                } => {
+                    // Where agents call Bedrock, to mark the models they can use
+                    let agent_target = self
+                        .aws_identity_center
+                        .as_ref()
+                        .and_then(|identity| identity.try_lock().ok())
+                        .and_then(|identity| identity.bedrock_target());
+                    let params = crate::app::dashui::BedrockModelCatalogShowParams {
+                        account_id,
+                        region,
+                        agent_target,
+                    };
+                    // Check if we already have an open window for this account and region
+                    if let Some(index) = self
+                        .bedrock_model_catalog_windows
+                        .iter()
+                        .position(|w| w.is_open() && w.targets(&params.account_id, &params.region))
+                    {
+                        self.bedrock_model_catalog_windows[index].open_for_region(params);
+                    } else if let Some(aws_client) =
+                        self.explorer_manager.shared_context.get_aws_client()
+                    {
+                        let credential_coordinator = aws_client.get_credential_coordinator();
+                        let mut new_window =
+                            crate::app::dashui::BedrockModelCatalogWindow::new(credential_coordinator);
+                        new_window.open_for_region(params);
+
+                        // Add to the list of open windows
+                        self.bedrock_model_catalog_windows.push(new_window);
+                    }
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenThreatFindings {
+                    account_id,
+                    region,
+                    resource_id,
+                } => {
+                    self.threat_findings_window.open_for(
+                        self.explorer_manager.shared_context.get_aws_client(),
+                        self.explorer_manager.shared_context.cache.clone(),
+                        account_id,
+                        region,
+                        resource_id,
+                    );
+                    self.window_focus_manager
+                        .request_focus("threat_findings_window".to_string());
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::OpenDnsRecords {
+                    hosted_zone_id,
+                } => {
+                    self.dns_records_window.open_for(
+                        self.explorer_manager.shared_context.cache.clone(),
+                        Some(hosted_zone_id),
+                    );
+                    self.window_focus_manager
+                        .request_focus("dns_records_window".to_string());
+                }
+                crate::app::resource_explorer::ResourceExplorerAction::ShowNotification {
+                    notification,
+                } => {
+                    self.notification_manager.add_notification(notification);
+                }
                 crate::app::resource_explorer::ResourceExplorerAction::OpenAwsConsole {
                     resource_type,
                     resource_id,
@@ -505,9 +1131,217 @@ impl DashApp {
         // Remove closed windows from the list
         self.cloudwatch_logs_windows.retain(|w| w.is_open());
 
-        // Handle all CloudTrail Events windows
-        for events_window in &mut self.cloudtrail_events_windows {
-            if events_window.is_open() {
+        // Handle all CloudWatch Metrics windows
+        for metrics_window in &mut self.cloudwatch_metrics_windows {
+            if metrics_window.is_open() {
+                metrics_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.cloudwatch_metrics_windows.retain(|w| w.is_open());
+
+        // Handle all Config History windows
+        for history_window in &mut self.config_history_windows {
+            if history_window.is_open() {
+                history_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.config_history_windows.retain(|w| w.is_open());
+
+        // Handle all Relationship Graph windows
+        for graph_window in &mut self.relationship_graph_windows {
+            if graph_window.is_open() {
+                graph_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.relationship_graph_windows.retain(|w| w.is_open());
+
+        // Handle all Stack Drift windows
+        for drift_window in &mut self.stack_drift_windows {
+            if drift_window.is_open() {
+                drift_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.stack_drift_windows.retain(|w| w.is_open());
+
+        // Handle all Stack Update windows
+        for update_window in &mut self.stack_update_windows {
+            if update_window.is_open() {
+                update_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.stack_update_windows.retain(|w| w.is_open());
+
+        // Handle all Stack Import windows
+        for import_window in &mut self.stack_import_windows {
+            if import_window.is_open() {
+                import_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.stack_import_windows.retain(|w| w.is_open());
+
+        // Handle all Template Preview windows
+        for preview_window in &mut self.template_preview_windows {
+            if preview_window.is_open() {
+                preview_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.template_preview_windows.retain(|w| w.is_open());
+
+        // Handle all Athena query windows
+        for query_window in &mut self.athena_query_windows {
+            if query_window.is_open() {
+                query_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.athena_query_windows.retain(|w| w.is_open());
+
+        // Handle all EventBridge browser windows
+        for browser_window in &mut self.eventbridge_browser_windows {
+            if browser_window.is_open() {
+                browser_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.eventbridge_browser_windows.retain(|w| w.is_open());
+
+        // Handle all Step Functions execution windows
+        for executions_window in &mut self.step_functions_windows {
+            if executions_window.is_open() {
+                executions_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.step_functions_windows.retain(|w| w.is_open());
+
+        // Handle all Lambda invoke windows
+        for invoke_window in &mut self.lambda_invoke_windows {
+            if invoke_window.is_open() {
+                invoke_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.lambda_invoke_windows.retain(|w| w.is_open());
+
+        // Handle all SQS queue inspector windows
+        for queue_window in &mut self.sqs_queue_windows {
+            if queue_window.is_open() {
+                queue_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.sqs_queue_windows.retain(|w| w.is_open());
+
+        // Handle all SSM session windows
+        for session_window in &mut self.ssm_session_windows {
+            if session_window.is_open() {
+                session_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows; dropping a window ends its session
+        self.ssm_session_windows.retain(|w| w.is_open());
+
+        // Handle all KMS key windows
+        for key_window in &mut self.kms_key_windows {
+            if key_window.is_open() {
+                key_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.kms_key_windows.retain(|w| w.is_open());
+
+        // Handle all S3 bucket windows
+        for bucket_window in &mut self.s3_bucket_windows {
+            if bucket_window.is_open() {
+                bucket_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.s3_bucket_windows.retain(|w| w.is_open());
+
+        // Handle all CloudFront invalidation windows; closed windows keep
+        // polling until the invalidations created in them complete
+        for invalidation_window in &mut self.cloudfront_invalidation_windows {
+            invalidation_window.poll(ctx);
+            for notification in invalidation_window.take_notifications() {
+                self.notification_manager.add_notification(notification);
+            }
+            if invalidation_window.is_open() {
+                invalidation_window.show(ctx);
+            }
+        }
+
+        // Remove windows that are closed and no longer tracking
+        self.cloudfront_invalidation_windows
+            .retain(|w| w.is_open() || w.is_tracking());
+
+        // Handle all Bedrock model catalog windows
+        for catalog_window in &mut self.bedrock_model_catalog_windows {
+            if catalog_window.is_open() {
+                catalog_window.show(ctx);
+            }
+        }
+
+        // Remove closed windows from the list
+        self.bedrock_model_catalog_windows.retain(|w| w.is_open());
+
+        // Handle all Auto Scaling group windows; closed windows keep polling
+        // until the capacity change made in them settles
+        for group_window in &mut self.auto_scaling_group_windows {
+            group_window.poll(ctx);
+            for notification in group_window.take_notifications() {
+                self.notification_manager.add_notification(notification);
+            }
+            if group_window.is_open() {
+                group_window.show(ctx);
+            }
+        }
+
+        // Remove windows that are closed and no longer following a change
+        self.auto_scaling_group_windows
+            .retain(|w| w.is_open() || w.is_tracking());
+
+        // Handle all CloudTrail Events windows; the most recent one takes the dock tile
+        // or its own OS window
+        let placement = self.dock_manager.placement(DockPanel::CloudTrail);
+        let moved_out = self.native_windows.is_moved_out(DockPanel::CloudTrail);
+        let docked_index = self
+            .cloudtrail_events_windows
+            .iter()
+            .rposition(|w| w.is_open());
+        for (index, events_window) in self.cloudtrail_events_windows.iter_mut().enumerate() {
+            let is_docked = Some(index) == docked_index;
+            if is_docked && moved_out {
+                self.native_windows.show(ctx, DockPanel::CloudTrail, |ctx, rect| {
+                    events_window.dock_rect = rect;
+                    events_window.show(ctx);
+                });
+                continue;
+            }
+            events_window.dock_rect = if is_docked { placement.tile() } else { None };
+            if events_window.is_open() && !(is_docked && placement.is_hidden()) {
                 events_window.show(ctx);
             }
         }
@@ -542,9 +1376,87 @@ impl DashApp {
         }
     }
 
+    /// Handle the keyboard shortcuts window, putting saved bindings into effect
+    pub(super) fn handle_key_binding_editor_window(&mut self, ctx: &egui::Context) {
+        self.key_binding_editor_window.show(ctx);
+        if let Some(bindings) = self.key_binding_editor_window.take_applied() {
+            tracing::info!("Key bindings updated");
+            self.navigation_state.set_key_bindings(bindings);
+        }
+    }
+
+    /// Handle the settings window and put saved settings into effect
+    pub(super) fn handle_settings_window(&mut self, ctx: &egui::Context) {
+        self.settings_window
+            .show(ctx, self.notification_manager.sinks());
+        if let Some(config) = self.settings_window.take_applied() {
+            tracing::info!("Settings updated");
+            if config.appearance.theme != self.theme {
+                self.theme = config.appearance.theme;
+                self.apply_theme(ctx);
+            }
+            set_app_config(config);
+        }
+        if self.settings_window.take_webhooks_request() {
+            self.notification_sinks_window
+                .open(&self.notification_manager);
+        }
+    }
+
+    /// Open the keyboard shortcuts window on the bindings in effect
+    pub(super) fn open_key_binding_editor(&mut self) {
+        let window_ids = FocusedWindow::ALL
+            .iter()
+            .map(|window| window.key_binding_id())
+            .collect();
+        self.key_binding_editor_window
+            .open(self.navigation_state.key_bindings(), window_ids);
+    }
+
+    /// Handle the window layouts window
+    pub(super) fn handle_dock_layout_window(&mut self, ctx: &egui::Context) {
+        self.dock_layout_window
+            .show(ctx, &mut self.dock_manager, &mut self.native_windows);
+    }
+
+    /// Windows of the dockable kinds that are currently open in the main window
+    pub(super) fn open_dock_panels(&self) -> Vec<DockPanel> {
+        let mut panels = Vec::new();
+        if self.explorer_manager.instances.iter().any(|i| i.is_open) {
+            panels.push(DockPanel::Explorer);
+        }
+        if self
+            .agent_manager_window
+            .as_ref()
+            .is_some_and(|window| window.is_open())
+        {
+            panels.push(DockPanel::Agents);
+        }
+        if self.log_window.is_open() {
+            panels.push(DockPanel::Logs);
+        }
+        if self.cloudtrail_events_windows.iter().any(|w| w.is_open()) {
+            panels.push(DockPanel::CloudTrail);
+        }
+        panels.retain(|panel| !self.native_windows.is_moved_out(*panel));
+        panels
+    }
+
     /// Handle the log window
     pub(super) fn handle_log_window(&mut self, ctx: &egui::Context) {
-        if self.log_window.is_open() {
+        // Moved into its own OS window, the window is shown there instead
+        if self.log_window.is_open() && self.native_windows.is_moved_out(DockPanel::Logs) {
+            let log_window = &mut self.log_window;
+            self.native_windows.show(ctx, DockPanel::Logs, |ctx, rect| {
+                log_window.dock_rect = rect;
+                FocusableWindow::show_with_focus(log_window, ctx, (), false);
+            });
+            return;
+        }
+
+        let placement = self.dock_manager.placement(DockPanel::Logs);
+        self.log_window.dock_rect = placement.tile();
+        if self.log_window.is_open() && !placement.is_hidden() {
             // Only set focus if this window is not already focused to avoid stealing focus every frame
             if self.currently_focused_window != Some(FocusedWindow::Log) {
                 self.set_focused_window(FocusedWindow::Log);
@@ -567,6 +1479,24 @@ impl DashApp {
         // Sync agent logging setting to agent manager window
         if let Some(window) = &mut self.agent_manager_window {
             window.set_agent_logging_enabled(self.agent_logging_enabled);
+            // Agents waiting for approval are blocked, so show this even when closed
+            window.show_approval_requests(ctx);
+        }
+
+        // Moved into its own OS window, the window is shown there instead
+        if self.native_windows.is_moved_out(DockPanel::Agents) {
+            if let Some(window) = self.agent_manager_window.as_mut().filter(|w| w.is_open()) {
+                self.native_windows.show(ctx, DockPanel::Agents, |ctx, rect| {
+                    window.dock_rect = rect;
+                    FocusableWindow::show_with_focus(&mut *window, ctx, (), false);
+                });
+                return;
+            }
+        }
+
+        let placement = self.dock_manager.placement(DockPanel::Agents);
+        if let Some(window) = &mut self.agent_manager_window {
+            window.dock_rect = placement.tile();
         }
 
         // Check if window exists and is open before borrowing
@@ -574,7 +1504,7 @@ impl DashApp {
             .agent_manager_window
             .as_ref()
             .is_some_and(|w| w.is_open());
-        if !is_open {
+        if !is_open || placement.is_hidden() {
             return;
         }
 
@@ -653,4 +1583,53 @@ impl DashApp {
         // Show regular notification details window for other notifications
         NotificationDetailsWindow::show(&mut self.notification_manager, ctx);
     }
+
+    /// Handle the notification history window and related window links
+    pub(super) fn handle_notification_history_window(&mut self, ctx: &egui::Context) {
+        self.notification_history_window
+            .show(ctx, &mut self.notification_manager);
+        if let Some(window) = self.notification_manager.take_related_window_request() {
+            self.open_related_window(window);
+        }
+    }
+
+    /// Handle the notification webhooks window
+    pub(super) fn handle_notification_sinks_window(&mut self, ctx: &egui::Context) {
+        self.notification_sinks_window
+            .show(ctx, &mut self.notification_manager);
+    }
+
+    /// Open the window a notification links to
+    pub(super) fn open_related_window(&mut self, window: RelatedWindow) {
+        tracing::info!("Opening {} from a notification", window.label());
+        match window {
+            RelatedWindow::AwsLogin => self.focus_window("aws_login_window"),
+            RelatedWindow::Explorer => {
+                let open_instance = self
+                    .explorer_manager
+                    .instances
+                    .iter()
+                    .find(|i| i.is_open)
+                    .map(|i| i.window_id());
+                if let Some(window_id) = open_instance {
+                    self.window_focus_manager
+                        .request_focus(window_id.to_string());
+                } else if self.is_aws_logged_in() {
+                    self.explorer_manager.open_new_window();
+                } else {
+                    self.show_login_required_notification("AWS Explorer");
+                }
+            }
+            RelatedWindow::AgentManager | RelatedWindow::AgentSchedules => {
+                if let Some(agent_window) = &mut self.agent_manager_window {
+                    if window == RelatedWindow::AgentSchedules {
+                        agent_window.run_palette_command(agent_manager_window::OPEN_SCHEDULES);
+                    } else {
+                        agent_window.open();
+                    }
+                    self.set_focused_window(FocusedWindow::AgentManager);
+                }
+            }
+        }
+    }
 }
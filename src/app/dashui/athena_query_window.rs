@@ -0,0 +1,518 @@
+//! Athena Query Editor Window
+//!
+//! SQL editor for running Athena queries in a workgroup, with a result table.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::athena::{
+    format_bytes, AthenaPollOptions, AthenaQueryClient, AthenaQueryRequest, AthenaQueryResult,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Maximum rows fetched for display
+const MAX_DISPLAY_ROWS: usize = 1000;
+
+/// Maximum characters shown per table cell
+const MAX_CELL_CHARS: usize = 80;
+
+/// Default query shown in a new editor
+const DEFAULT_QUERY: &str = "SHOW TABLES";
+
+/// Parameters for showing the Athena Query window
+#[derive(Clone)]
+pub struct AthenaQueryShowParams {
+    pub workgroup: String,
+    pub account_id: String,
+    pub region: String,
+    /// Result location configured on the workgroup, if known
+    pub output_location: Option<String>,
+}
+
+/// Progress messages from the background query thread
+enum QueryEvent {
+    Started(String),
+    Finished(Result<AthenaQueryResult, String>),
+    Cancelled(Result<(), String>),
+}
+
+pub struct AthenaQueryWindow {
+    pub open: bool,
+    // Display parameters
+    workgroup: String,
+    account_id: String,
+    region: String,
+
+    // Editor state
+    query: String,
+    database: String,
+    catalog: String,
+    output_location: String,
+
+    // Query state
+    running: bool,
+    query_execution_id: Option<String>,
+    result: Option<AthenaQueryResult>,
+    error_message: Option<String>,
+
+    // Services
+    client: Arc<AthenaQueryClient>,
+
+    // Channel for receiving query events from background thread.
+    // Replaced on each run so events from an abandoned query are dropped.
+    query_sender: Option<mpsc::Sender<QueryEvent>>,
+    query_receiver: Option<mpsc::Receiver<QueryEvent>>,
+}
+
+impl AthenaQueryWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            open: false,
+            workgroup: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            query: DEFAULT_QUERY.to_string(),
+            database: "default".to_string(),
+            catalog: crate::app::data_plane::athena::types::DEFAULT_CATALOG.to_string(),
+            output_location: String::new(),
+            running: false,
+            query_execution_id: None,
+            result: None,
+            error_message: None,
+            client: Arc::new(AthenaQueryClient::new(credential_coordinator)),
+            query_sender: None,
+            query_receiver: None,
+        }
+    }
+
+    /// Open the window for a specific workgroup
+    pub fn open_for_workgroup(&mut self, params: AthenaQueryShowParams) {
+        self.workgroup = params.workgroup;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.output_location = params.output_location.unwrap_or_default();
+        self.result = None;
+        self.error_message = None;
+        self.open = true;
+    }
+
+    /// Start the query in the editor on a background thread
+    fn run_query(&mut self) {
+        if self.query.trim().is_empty() {
+            self.error_message = Some("Enter a query to run".to_string());
+            return;
+        }
+
+        self.running = true;
+        self.query_execution_id = None;
+        self.result = None;
+        self.error_message = None;
+
+        let (sender, receiver) = mpsc::channel();
+        self.query_sender = Some(sender.clone());
+        self.query_receiver = Some(receiver);
+
+        let account_id = self.account_id.clone();
+        let region = self.region.clone();
+        let request = AthenaQueryRequest {
+            query: self.query.clone(),
+            database: Some(self.database.trim().to_string()),
+            catalog: Some(self.catalog.trim().to_string()),
+            workgroup: Some(self.workgroup.clone()),
+            output_location: Some(self.output_location.trim().to_string()),
+        };
+
+        let started = sender.clone();
+        spawn_with_client(&self.client, &sender, move |client| async move {
+            let poll_options = AthenaPollOptions::new().with_max_rows(MAX_DISPLAY_ROWS);
+
+            let query_execution_id = match client.start_query(&account_id, &region, &request).await
+            {
+                Ok(id) => id,
+                Err(e) => {
+                    log::error!("Failed to start Athena query: {}", e);
+                    return QueryEvent::Finished(Err(format!("{:#}", e)));
+                }
+            };
+            let _ = started.send(QueryEvent::Started(query_execution_id.clone()));
+
+            let result = async {
+                let status = client
+                    .wait_for_query(&account_id, &region, &query_execution_id, &poll_options)
+                    .await?;
+                client
+                    .fetch_results(&account_id, &region, status, poll_options.max_rows)
+                    .await
+            }
+            .await
+            .map_err(|e| {
+                log::error!("Athena query {} failed: {}", query_execution_id, e);
+                format!("{:#}", e)
+            });
+            QueryEvent::Finished(result)
+        });
+    }
+
+    /// Cancel the running query
+    fn cancel_query(&mut self) {
+        let (Some(query_execution_id), Some(sender)) =
+            (self.query_execution_id.clone(), &self.query_sender)
+        else {
+            return;
+        };
+
+        let account_id = self.account_id.clone();
+        let region = self.region.clone();
+
+        spawn_with_client(&self.client, sender, move |client| async move {
+            let result = client
+                .stop_query(&account_id, &region, &query_execution_id)
+                .await;
+            if let Err(e) = &result {
+                log::warn!("Failed to cancel Athena query: {}", e);
+            }
+            QueryEvent::Cancelled(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    /// Poll for query events from background thread
+    fn poll_query_events(&mut self) {
+        let Some(receiver) = &self.query_receiver else {
+            return;
+        };
+
+        while let Ok(event) = receiver.try_recv() {
+            match event {
+                QueryEvent::Started(id) => {
+                    self.query_execution_id = Some(id);
+                }
+                QueryEvent::Finished(result) => {
+                    self.running = false;
+                    match result {
+                        Ok(result) => {
+                            self.result = Some(result);
+                            self.error_message = None;
+                        }
+                        Err(error_msg) => {
+                            self.result = None;
+                            self.error_message = Some(error_msg);
+                        }
+                    }
+                }
+                QueryEvent::Cancelled(Ok(())) => {}
+                QueryEvent::Cancelled(Err(error_msg)) => {
+                    self.error_message = Some(format!("Failed to cancel query: {}", error_msg));
+                }
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        // Poll for query events from background thread
+        self.poll_query_events();
+
+        // Request continuous repaint while running to show spinner
+        if self.running {
+            ctx.request_repaint();
+        }
+
+        let title = format!("Athena: {}", self.workgroup);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .open(&mut is_open)
+            .default_size([900.0, 600.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        // Header: Workgroup location
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Workgroup:").strong());
+            ui.label(&self.workgroup);
+            ui.label(RichText::new(format!("{} / {}", self.account_id, self.region)).weak());
+        });
+
+        egui::Grid::new("athena_query_settings")
+            .num_columns(2)
+            .spacing([8.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("Database:");
+                ui.text_edit_singleline(&mut self.database);
+                ui.end_row();
+
+                ui.label("Catalog:");
+                ui.text_edit_singleline(&mut self.catalog);
+                ui.end_row();
+
+                ui.label("Output location:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.output_location)
+                        .hint_text("Workgroup default"),
+                );
+                ui.end_row();
+            });
+
+        ui.separator();
+
+        // SQL editor
+        ui.add(
+            egui::TextEdit::multiline(&mut self.query)
+                .code_editor()
+                .desired_rows(6)
+                .desired_width(f32::INFINITY),
+        );
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.running, egui::Button::new("Run"))
+                .clicked()
+            {
+                self.run_query();
+            }
+
+            if self.running {
+                if ui
+                    .add_enabled(
+                        self.query_execution_id.is_some(),
+                        egui::Button::new("Cancel"),
+                    )
+                    .clicked()
+                {
+                    self.cancel_query();
+                }
+                ui.spinner();
+                ui.label("Running query...");
+            } else if let Some(result) = &self.result {
+                ui.label(query_summary(result));
+                if !result.rows.is_empty() && ui.button("Copy CSV").clicked() {
+                    ui.ctx().copy_text(result_to_csv(result));
+                }
+            }
+        });
+
+        if let Some(id) = &self.query_execution_id {
+            ui.label(RichText::new(format!("Query ID: {}", id)).weak().small());
+        }
+
+        ui.separator();
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::RED, format!("Error: {}", error));
+            return;
+        }
+
+        if let Some(result) = &self.result {
+            render_result_table(ui, result);
+        }
+    }
+}
+
+/// One-line summary of a finished query
+fn query_summary(result: &AthenaQueryResult) -> String {
+    let mut summary = format!(
+        "{} rows{}",
+        result.rows.len(),
+        if result.truncated { " (truncated)" } else { "" }
+    );
+    if let Some(bytes) = result.status.data_scanned_bytes {
+        summary.push_str(&format!(", {} scanned", format_bytes(bytes)));
+    }
+    if let Some(ms) = result.status.execution_time_ms {
+        summary.push_str(&format!(", {:.2}s", ms as f64 / 1000.0));
+    }
+    summary
+}
+
+/// Render result rows in a striped grid
+fn render_result_table(ui: &mut Ui, result: &AthenaQueryResult) {
+    if result.columns.is_empty() {
+        ui.label(RichText::new("Query returned no result set").italics());
+        return;
+    }
+
+    egui::ScrollArea::both()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            egui::Grid::new("athena_query_results")
+                .striped(true)
+                .num_columns(result.columns.len())
+                .show(ui, |ui| {
+                    for column in &result.columns {
+                        ui.label(RichText::new(&column.name).strong())
+                            .on_hover_text(&column.data_type);
+                    }
+                    ui.end_row();
+
+                    for row in &result.rows {
+                        for value in row {
+                            match value {
+                                Some(value) => {
+                                    let label =
+                                        ui.label(RichText::new(truncate_cell(value)).monospace());
+                                    if value.chars().count() > MAX_CELL_CHARS {
+                                        label.on_hover_text(value);
+                                    }
+                                }
+                                None => {
+                                    ui.label(RichText::new("NULL").weak().italics());
+                                }
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+fn truncate_cell(value: &str) -> String {
+    if value.chars().count() > MAX_CELL_CHARS {
+        let truncated: String = value.chars().take(MAX_CELL_CHARS).collect();
+        format!("{}...", truncated)
+    } else {
+        value.replace('\n', " ")
+    }
+}
+
+/// Convert a result set to CSV text (header row first, NULL as empty)
+fn result_to_csv(result: &AthenaQueryResult) -> String {
+    fn escape(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+
+    let mut csv = result
+        .columns
+        .iter()
+        .map(|c| escape(&c.name))
+        .collect::<Vec<_>>()
+        .join(",");
+    csv.push('\n');
+
+    for row in &result.rows {
+        let line = row
+            .iter()
+            .map(|v| v.as_deref().map(escape).unwrap_or_default())
+            .collect::<Vec<_>>()
+            .join(",");
+        csv.push_str(&line);
+        csv.push('\n');
+    }
+
+    csv
+}
+
+impl FocusableWindow for AthenaQueryWindow {
+    type ShowParams = AthenaQueryShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "athena_query_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Athena: {}", self.workgroup)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the workgroup first
+        self.open_for_workgroup(params);
+
+        // Then show with focus
+        AthenaQueryWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::athena::{AthenaColumn, AthenaQueryState, AthenaQueryStatus};
+
+    fn result(rows: Vec<Vec<Option<String>>>) -> AthenaQueryResult {
+        AthenaQueryResult::new(
+            AthenaQueryStatus {
+                query_execution_id: "q-1".to_string(),
+                state: AthenaQueryState::Succeeded,
+                state_change_reason: None,
+                output_location: None,
+                data_scanned_bytes: Some(2048),
+                execution_time_ms: Some(1500),
+            },
+            vec![
+                AthenaColumn {
+                    name: "name".to_string(),
+                    data_type: "varchar".to_string(),
+                },
+                AthenaColumn {
+                    name: "note".to_string(),
+                    data_type: "varchar".to_string(),
+                },
+            ],
+            rows,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_result_to_csv() {
+        let csv = result_to_csv(&result(vec![
+            vec![Some("a".to_string()), Some("x, \"y\"".to_string())],
+            vec![Some("b".to_string()), None],
+        ]));
+
+        assert_eq!(csv, "name,note\na,\"x, \"\"y\"\"\"\nb,\n");
+    }
+
+    #[test]
+    fn test_query_summary() {
+        assert_eq!(
+            query_summary(&result(vec![vec![None, None]])),
+            "1 rows, 2.0 KB scanned, 1.50s"
+        );
+    }
+
+    #[test]
+    fn test_truncate_cell() {
+        assert_eq!(truncate_cell("short"), "short");
+        let long = "x".repeat(MAX_CELL_CHARS + 5);
+        assert!(truncate_cell(&long).ends_with("..."));
+    }
+}
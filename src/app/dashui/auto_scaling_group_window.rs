@@ -0,0 +1,858 @@
+//! Auto Scaling Group Window
+//!
+//! Shows a group's desired, minimum, and maximum capacity, the health of its
+//! instances, its scaling policies, and the scaling activity history, with an
+//! inline editor for the desired capacity. After a change the group is polled
+//! until its instances are in service and its activities finish, even after
+//! the window is closed, and a notification reports the outcome. The editor is
+//! hidden in the read-only build and changes are recorded in the resource
+//! actions audit log.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::{format_time, spawn_with_client};
+use crate::app::data_plane::autoscaling_capacity::{
+    parse_desired_capacity, AutoScalingCapacityClient, AutoScalingGroupDetails, ScalingActivity,
+};
+use crate::app::notifications::{Notification, NotificationError};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use crate::app::resource_explorer::resource_actions::{ActionAuditEntry, ActionAuditLog};
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How often the group is checked while a capacity change settles
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// How long a capacity change is followed before giving up
+const PROGRESS_TIMEOUT: Duration = Duration::from_secs(20 * 60);
+
+/// Parameters for showing the Auto Scaling group window
+#[derive(Clone)]
+pub struct AutoScalingGroupShowParams {
+    pub group_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Results from background threads
+enum GroupEvent {
+    GroupLoaded(Result<AutoScalingGroupDetails, String>),
+    ActivitiesLoaded(Result<Vec<ScalingActivity>, String>),
+    /// The requested desired capacity, once accepted
+    CapacitySet(Result<i32, String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum GroupTab {
+    Instances,
+    Policies,
+    Activity,
+}
+
+/// A desired capacity change being followed until the group settles
+#[derive(Debug, Clone)]
+struct CapacityChange {
+    desired_capacity: i32,
+    /// Unix millis; activities started from here on belong to the change
+    requested_at: i64,
+    started: Instant,
+}
+
+pub struct AutoScalingGroupWindow {
+    pub open: bool,
+    // Display parameters
+    group_name: String,
+    account_id: String,
+    region: String,
+
+    // Group state
+    group: Option<AutoScalingGroupDetails>,
+    activities: Vec<ScalingActivity>,
+    loading_group: bool,
+    loading_activities: bool,
+    error_message: Option<String>,
+    tab: GroupTab,
+
+    // Desired capacity editor
+    desired_input: String,
+    honor_cooldown: bool,
+    setting: bool,
+    set_result: Option<Result<String, String>>,
+
+    // Progress of a capacity change made here
+    change: Option<CapacityChange>,
+    next_progress_poll: Option<Instant>,
+    pending_notifications: Vec<Notification>,
+
+    // Services
+    client: Arc<AutoScalingCapacityClient>,
+
+    // Channel for results from background threads.
+    // Replaced when the window opens on a group so results for another one are dropped.
+    sender: mpsc::Sender<GroupEvent>,
+    receiver: mpsc::Receiver<GroupEvent>,
+}
+
+impl AutoScalingGroupWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            group_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            group: None,
+            activities: Vec::new(),
+            loading_group: false,
+            loading_activities: false,
+            error_message: None,
+            tab: GroupTab::Instances,
+            desired_input: String::new(),
+            honor_cooldown: true,
+            setting: false,
+            set_result: None,
+            change: None,
+            next_progress_poll: None,
+            pending_notifications: Vec::new(),
+            client: Arc::new(AutoScalingCapacityClient::new(credential_coordinator)),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on a group and load its capacity and activities
+    ///
+    /// Reopening the same group keeps following its capacity change.
+    pub fn open_for_group(&mut self, params: AutoScalingGroupShowParams) {
+        if !self.targets(&params.group_name, &params.account_id, &params.region) {
+            let (sender, receiver) = mpsc::channel();
+            self.sender = sender;
+            self.receiver = receiver;
+            self.group = None;
+            self.activities.clear();
+            self.tab = GroupTab::Instances;
+            self.desired_input.clear();
+            self.setting = false;
+            self.set_result = None;
+            self.change = None;
+            self.next_progress_poll = None;
+        }
+        self.group_name = params.group_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.open = true;
+        self.load_group();
+        self.load_activities();
+    }
+
+    /// Whether this window shows the given group
+    pub fn targets(&self, group_name: &str, account_id: &str, region: &str) -> bool {
+        self.group_name == group_name && self.account_id == account_id && self.region == region
+    }
+
+    /// Whether a capacity change made here is still settling
+    pub fn is_tracking(&self) -> bool {
+        self.change.is_some()
+    }
+
+    /// Notifications raised since the last call (capacity change progress)
+    pub fn take_notifications(&mut self) -> Vec<Notification> {
+        std::mem::take(&mut self.pending_notifications)
+    }
+
+    fn load_group(&mut self) {
+        self.loading_group = true;
+        self.error_message = None;
+        let (account_id, region, group_name) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.group_name.clone(),
+        );
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client.get_group(&account_id, &region, &group_name).await;
+            if let Err(e) = &result {
+                log::error!("Failed to load Auto Scaling group {}: {}", group_name, e);
+            }
+            GroupEvent::GroupLoaded(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    fn load_activities(&mut self) {
+        self.loading_activities = true;
+        let (account_id, region, group_name) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.group_name.clone(),
+        );
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .list_activities(&account_id, &region, &group_name)
+                .await;
+            if let Err(e) = &result {
+                log::warn!("Failed to list scaling activities of {}: {}", group_name, e);
+            }
+            GroupEvent::ActivitiesLoaded(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    fn set_desired_capacity(&mut self, desired_capacity: i32) {
+        self.setting = true;
+        self.set_result = None;
+        let previous = self.group.as_ref().map(|g| g.desired_capacity);
+        let honor_cooldown = self.honor_cooldown;
+        let (account_id, region, group_name) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.group_name.clone(),
+        );
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .set_desired_capacity(
+                    &account_id,
+                    &region,
+                    &group_name,
+                    desired_capacity,
+                    honor_cooldown,
+                )
+                .await
+                .map(|()| desired_capacity)
+                .map_err(|e| format!("{:#}", e));
+
+            let entry = ActionAuditEntry {
+                timestamp: chrono::Utc::now(),
+                action_id: "autoscaling.set_desired_capacity".to_string(),
+                account_id,
+                region,
+                resource_type: "AWS::AutoScaling::AutoScalingGroup".to_string(),
+                resource_id: group_name,
+                success: result.is_ok(),
+                message: match &result {
+                    Ok(_) => format!(
+                        "Desired capacity {} -> {}{}",
+                        previous
+                            .map(|p| p.to_string())
+                            .unwrap_or_else(|| "?".to_string()),
+                        desired_capacity,
+                        if honor_cooldown {
+                            " (honoring cooldown)"
+                        } else {
+                            ""
+                        }
+                    ),
+                    Err(message) => message.clone(),
+                },
+            };
+            match ActionAuditLog::new() {
+                Ok(audit_log) => {
+                    if let Err(e) = audit_log.record(&entry) {
+                        tracing::warn!("Failed to write capacity audit entry: {:#}", e);
+                    }
+                }
+                Err(e) => tracing::warn!("Capacity audit log unavailable: {:#}", e),
+            }
+            GroupEvent::CapacitySet(result)
+        });
+    }
+
+    /// Poll for results from background threads and check a settling capacity
+    /// change; runs every frame, also while the window is closed
+    pub fn poll(&mut self, ctx: &Context) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                GroupEvent::GroupLoaded(Ok(group)) => {
+                    self.loading_group = false;
+                    // Follow the group's capacity unless a new value is being typed
+                    let shown = self.group.as_ref().map(|g| g.desired_capacity.to_string());
+                    if shown.map_or(true, |shown| shown == self.desired_input.trim()) {
+                        self.desired_input = group.desired_capacity.to_string();
+                    }
+                    self.group = Some(group);
+                    self.check_progress();
+                }
+                GroupEvent::GroupLoaded(Err(e)) => {
+                    self.loading_group = false;
+                    self.error_message = Some(e);
+                }
+                GroupEvent::ActivitiesLoaded(Ok(activities)) => {
+                    self.loading_activities = false;
+                    self.activities = activities;
+                    self.check_progress();
+                }
+                GroupEvent::ActivitiesLoaded(Err(e)) => {
+                    self.loading_activities = false;
+                    self.error_message = Some(e);
+                }
+                GroupEvent::CapacitySet(Ok(desired_capacity)) => {
+                    self.setting = false;
+                    self.set_result = Some(Ok(format!(
+                        "Desired capacity set to {}; following the scaling activities",
+                        desired_capacity
+                    )));
+                    self.desired_input = desired_capacity.to_string();
+                    self.change = Some(CapacityChange {
+                        desired_capacity,
+                        requested_at: chrono::Utc::now().timestamp_millis(),
+                        started: Instant::now(),
+                    });
+                    self.pending_notifications.push(Notification::new_info(
+                        self.notification_id(),
+                        format!("Scaling {}", self.group_name),
+                        format!(
+                            "Desired capacity set to {}; waiting for instances to be in service",
+                            desired_capacity
+                        ),
+                        "Auto Scaling".to_string(),
+                    ));
+                    self.tab = GroupTab::Activity;
+                    self.next_progress_poll = Some(Instant::now() + PROGRESS_POLL_INTERVAL);
+                    self.load_group();
+                    self.load_activities();
+                }
+                GroupEvent::CapacitySet(Err(e)) => {
+                    self.setting = false;
+                    self.set_result = Some(Err(e));
+                }
+            }
+        }
+
+        if let Some(due) = self.next_progress_poll {
+            let now = Instant::now();
+            if now >= due {
+                if !self.loading_group {
+                    self.load_group();
+                }
+                if !self.loading_activities {
+                    self.load_activities();
+                }
+                self.next_progress_poll = Some(now + PROGRESS_POLL_INTERVAL);
+                ctx.request_repaint_after(PROGRESS_POLL_INTERVAL);
+            } else {
+                ctx.request_repaint_after(due - now);
+            }
+        }
+    }
+
+    /// One notification per group, replaced as the change progresses
+    fn notification_id(&self) -> String {
+        format!(
+            "autoscaling_capacity_{}_{}_{}",
+            self.account_id, self.region, self.group_name
+        )
+    }
+
+    /// Finish a settling capacity change once its activities failed, the group
+    /// reached its capacity, or it took too long
+    fn check_progress(&mut self) {
+        let Some(change) = self.change.clone() else {
+            return;
+        };
+        let Some(group) = &self.group else {
+            return;
+        };
+
+        let failed = self.activities.iter().find(|activity| {
+            activity.is_failed()
+                && activity
+                    .start_time
+                    .is_some_and(|start| start >= change.requested_at)
+        });
+        let notification = if let Some(activity) = failed {
+            Notification::new_error(
+                self.notification_id(),
+                format!("Scaling {} failed", self.group_name),
+                vec![NotificationError {
+                    message: activity.description.clone(),
+                    code: Some(activity.status_code.clone()),
+                    details: activity.status_message.clone(),
+                }],
+                "Auto Scaling".to_string(),
+            )
+        } else if group.is_at_capacity() && self.activities.iter().all(|a| a.is_finished()) {
+            Notification::new_success(
+                self.notification_id(),
+                format!("{} scaled", self.group_name),
+                format!(
+                    "{} of {} instance(s) in service and healthy",
+                    group.healthy_count(),
+                    group.desired_capacity
+                ),
+                "Auto Scaling".to_string(),
+            )
+        } else if change.started.elapsed() > PROGRESS_TIMEOUT {
+            Notification::new_warning(
+                self.notification_id(),
+                format!("Scaling {} is taking long", self.group_name),
+                vec![NotificationError {
+                    message: format!(
+                        "{} of {} instance(s) in service after {} minutes; no longer following",
+                        group.in_service_count(),
+                        change.desired_capacity,
+                        PROGRESS_TIMEOUT.as_secs() / 60
+                    ),
+                    code: None,
+                    details: None,
+                }],
+                "Auto Scaling".to_string(),
+            )
+        } else {
+            return;
+        };
+
+        self.pending_notifications.push(notification);
+        self.change = None;
+        self.next_progress_poll = None;
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        // Request continuous repaint while waiting to show spinners
+        if self.loading_group || self.loading_activities || self.setting {
+            ctx.request_repaint();
+        }
+
+        let title = format!("Auto Scaling Group: {}", self.group_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "auto_scaling_group_window",
+                &self.account_id,
+                &self.region,
+                &self.group_name,
+            )))
+            .open(&mut is_open)
+            .default_size([820.0, 560.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        let loading = self.loading_group || self.loading_activities;
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Group:").strong());
+            ui.label(&self.group_name);
+            ui.label(RichText::new(format!("{} {}", self.account_id, self.region)).weak());
+            if ui
+                .add_enabled(!loading, egui::Button::new("Refresh"))
+                .clicked()
+            {
+                self.load_group();
+                self.load_activities();
+            }
+            if loading {
+                ui.spinner();
+            }
+        });
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error);
+        }
+        let Some(group) = self.group.clone() else {
+            return;
+        };
+        ui.separator();
+
+        self.render_capacity(ui, &group);
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.selectable_value(
+                &mut self.tab,
+                GroupTab::Instances,
+                format!("Instances ({})", group.instances.len()),
+            );
+            ui.selectable_value(
+                &mut self.tab,
+                GroupTab::Policies,
+                format!("Scaling Policies ({})", group.policies.len()),
+            );
+            ui.selectable_value(
+                &mut self.tab,
+                GroupTab::Activity,
+                format!("Activity ({})", self.activities.len()),
+            );
+        });
+        ui.separator();
+
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| match self.tab {
+                GroupTab::Instances => render_instances(ui, &group),
+                GroupTab::Policies => render_policies(ui, &group),
+                GroupTab::Activity => render_activities(ui, &self.activities),
+            });
+    }
+
+    fn render_capacity(&mut self, ui: &mut Ui, group: &AutoScalingGroupDetails) {
+        if let Some(status) = &group.status {
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                format!("Group status: {}", status),
+            );
+        }
+
+        egui::Grid::new("auto_scaling_group_capacity_grid")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Desired / Min / Max:");
+                ui.label(
+                    RichText::new(format!(
+                        "{} / {} / {}",
+                        group.desired_capacity, group.min_size, group.max_size
+                    ))
+                    .strong(),
+                );
+                ui.end_row();
+
+                ui.label("In service:");
+                ui.label(format!("{}", group.in_service_count()));
+                ui.end_row();
+
+                ui.label("Healthy:");
+                let healthy = group.healthy_count();
+                if healthy < group.instances.len() {
+                    ui.colored_label(
+                        Color32::from_rgb(255, 100, 100),
+                        format!("{} of {}", healthy, group.instances.len()),
+                    );
+                } else {
+                    ui.label(format!("{} of {}", healthy, group.instances.len()));
+                }
+                ui.end_row();
+
+                ui.label("Health check:");
+                ui.label(group.health_check_type.as_deref().unwrap_or("EC2"));
+                ui.end_row();
+
+                if let Some(cooldown) = group.default_cooldown {
+                    ui.label("Default cooldown:");
+                    ui.label(format!("{} s", cooldown));
+                    ui.end_row();
+                }
+            });
+
+        if crate::app::capabilities::READ_ONLY {
+            return;
+        }
+
+        let parsed = parse_desired_capacity(&self.desired_input, group.min_size, group.max_size);
+        let changed = parsed
+            .as_ref()
+            .is_ok_and(|desired| *desired != group.desired_capacity);
+        ui.horizontal(|ui| {
+            ui.label("Desired capacity:");
+            ui.add(egui::TextEdit::singleline(&mut self.desired_input).desired_width(50.0));
+            ui.checkbox(&mut self.honor_cooldown, "Honor cooldown")
+                .on_hover_text("Reject the change while the group is in a cooldown period");
+            if ui
+                .add_enabled(
+                    changed && !self.setting && !self.is_tracking(),
+                    egui::Button::new("Set"),
+                )
+                .on_hover_text("Launch or terminate instances to reach this capacity")
+                .clicked()
+            {
+                if let Ok(desired) = parsed.as_ref() {
+                    self.set_desired_capacity(*desired);
+                }
+            }
+            if self.setting || self.is_tracking() {
+                ui.spinner();
+            }
+            match &parsed {
+                Err(message) => {
+                    ui.colored_label(ui.visuals().warn_fg_color, message);
+                }
+                Ok(_) => match &self.set_result {
+                    Some(Ok(message)) if self.is_tracking() => {
+                        ui.colored_label(Color32::from_rgb(100, 200, 100), message);
+                    }
+                    Some(Err(message)) => {
+                        ui.colored_label(Color32::from_rgb(255, 100, 100), message);
+                    }
+                    _ => {}
+                },
+            }
+        });
+    }
+}
+
+fn render_instances(ui: &mut Ui, group: &AutoScalingGroupDetails) {
+    if group.instances.is_empty() {
+        ui.label("No instances in this group");
+        return;
+    }
+
+    egui::Grid::new("auto_scaling_group_instances_grid")
+        .num_columns(6)
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Instance");
+            ui.strong("Type");
+            ui.strong("Availability Zone");
+            ui.strong("Lifecycle");
+            ui.strong("Health");
+            ui.strong("Scale-in protection");
+            ui.end_row();
+
+            for instance in &group.instances {
+                ui.label(RichText::new(&instance.instance_id).monospace());
+                ui.label(instance.instance_type.as_deref().unwrap_or("-"));
+                ui.label(instance.availability_zone.as_deref().unwrap_or("-"));
+                if instance.lifecycle_state == "InService" {
+                    ui.label(&instance.lifecycle_state);
+                } else {
+                    ui.colored_label(ui.visuals().warn_fg_color, &instance.lifecycle_state);
+                }
+                if instance.is_healthy() {
+                    ui.label(&instance.health_status);
+                } else {
+                    ui.colored_label(Color32::from_rgb(255, 100, 100), &instance.health_status);
+                }
+                ui.label(if instance.protected_from_scale_in {
+                    "Yes"
+                } else {
+                    "No"
+                });
+                ui.end_row();
+            }
+        });
+}
+
+fn render_policies(ui: &mut Ui, group: &AutoScalingGroupDetails) {
+    if group.policies.is_empty() {
+        ui.label("No scaling policies; capacity only changes when set");
+        return;
+    }
+
+    egui::Grid::new("auto_scaling_group_policies_grid")
+        .num_columns(4)
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Policy");
+            ui.strong("Type");
+            ui.strong("Adjustment");
+            ui.strong("Enabled");
+            ui.end_row();
+
+            for policy in &group.policies {
+                ui.label(&policy.name);
+                ui.label(&policy.policy_type);
+                ui.label(&policy.adjustment);
+                if policy.enabled {
+                    ui.label("Yes");
+                } else {
+                    ui.label(RichText::new("No").weak());
+                }
+                ui.end_row();
+            }
+        });
+}
+
+fn render_activities(ui: &mut Ui, activities: &[ScalingActivity]) {
+    if activities.is_empty() {
+        ui.label("No recent scaling activities");
+        return;
+    }
+
+    egui::Grid::new("auto_scaling_group_activities_grid")
+        .num_columns(4)
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Status");
+            ui.strong("Started");
+            ui.strong("Ended");
+            ui.strong("Description");
+            ui.end_row();
+
+            for activity in activities {
+                ui.horizontal(|ui| {
+                    if activity.is_failed() {
+                        ui.colored_label(Color32::from_rgb(255, 100, 100), &activity.status_code);
+                    } else if activity.is_finished() {
+                        ui.label(&activity.status_code);
+                    } else {
+                        ui.spinner();
+                        ui.colored_label(
+                            ui.visuals().warn_fg_color,
+                            match activity.progress {
+                                Some(progress) => {
+                                    format!("{} {}%", activity.status_code, progress)
+                                }
+                                None => activity.status_code.clone(),
+                            },
+                        );
+                    }
+                });
+                ui.label(
+                    activity
+                        .start_time
+                        .map(format_time)
+                        .unwrap_or_else(|| "-".to_string()),
+                );
+                ui.label(
+                    activity
+                        .end_time
+                        .map(format_time)
+                        .unwrap_or_else(|| "-".to_string()),
+                );
+                ui.vertical(|ui| {
+                    ui.label(&activity.description)
+                        .on_hover_text(&activity.cause);
+                    if let Some(message) = activity
+                        .status_message
+                        .as_ref()
+                        .filter(|_| activity.is_failed())
+                    {
+                        ui.colored_label(Color32::from_rgb(255, 100, 100), message);
+                    }
+                });
+                ui.end_row();
+            }
+        });
+}
+
+impl FocusableWindow for AutoScalingGroupWindow {
+    type ShowParams = AutoScalingGroupShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "auto_scaling_group_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Auto Scaling Group: {}", self.group_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the group first
+        self.open_for_group(params);
+
+        // Then show with focus
+        AutoScalingGroupWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::autoscaling_capacity::GroupInstance;
+
+    fn group(desired_capacity: i32, in_service: usize) -> AutoScalingGroupDetails {
+        AutoScalingGroupDetails {
+            name: "web".to_string(),
+            min_size: 1,
+            max_size: 4,
+            desired_capacity,
+            health_check_type: None,
+            default_cooldown: None,
+            status: None,
+            instances: (0..in_service)
+                .map(|i| GroupInstance {
+                    instance_id: format!("i-{}", i),
+                    instance_type: None,
+                    availability_zone: None,
+                    lifecycle_state: "InService".to_string(),
+                    health_status: "Healthy".to_string(),
+                    protected_from_scale_in: false,
+                })
+                .collect(),
+            policies: Vec::new(),
+        }
+    }
+
+    fn activity(status_code: &str, start_time: i64) -> ScalingActivity {
+        ScalingActivity {
+            id: "a-1".to_string(),
+            description: "Launching a new EC2 instance".to_string(),
+            cause: "Desired capacity changed".to_string(),
+            status_code: status_code.to_string(),
+            status_message: None,
+            progress: None,
+            start_time: Some(start_time),
+            end_time: None,
+        }
+    }
+
+    fn window_with_change() -> AutoScalingGroupWindow {
+        let mut window = AutoScalingGroupWindow::new(Arc::new(CredentialCoordinator::new_mock()));
+        window.group_name = "web".to_string();
+        window.change = Some(CapacityChange {
+            desired_capacity: 2,
+            requested_at: 1_000,
+            started: Instant::now(),
+        });
+        window.next_progress_poll = Some(Instant::now());
+        window
+    }
+
+    #[test]
+    fn test_capacity_change_settles() {
+        let mut window = window_with_change();
+        window.group = Some(group(2, 1));
+        window.activities = vec![activity("InProgress", 2_000)];
+        window.check_progress();
+        assert!(window.is_tracking());
+        assert!(window.take_notifications().is_empty());
+
+        window.group = Some(group(2, 2));
+        window.check_progress();
+        assert!(window.is_tracking());
+
+        window.activities = vec![activity("Successful", 2_000)];
+        window.check_progress();
+        assert!(!window.is_tracking());
+        assert!(window.next_progress_poll.is_none());
+        assert_eq!(window.take_notifications().len(), 1);
+    }
+
+    #[test]
+    fn test_capacity_change_failure_notifies() {
+        let mut window = window_with_change();
+        window.group = Some(group(2, 1));
+
+        // Failures from before the change are history only
+        window.activities = vec![activity("Failed", 500)];
+        window.check_progress();
+        assert!(window.is_tracking());
+
+        window.activities.insert(0, activity("Failed", 2_000));
+        window.check_progress();
+        assert!(!window.is_tracking());
+        let notifications = window.take_notifications();
+        assert_eq!(notifications.len(), 1);
+        assert!(notifications[0].title.contains("failed"));
+    }
+}
@@ -1,15 +1,55 @@
-use crate::app::aws_identity::{AwsIdentityCenter, LoginState};
+use crate::app::aws_identity::{
+    spawn_session_refresher, AwsIdentityCenter, LoginState, SessionHealth, DEFAULT_KEEP_ALIVE_HOURS,
+};
+use crate::app::credential_sources::{
+    list_profiles, parse_assume_role_chain, BaseCredentials, CredentialSource,
+};
+use crate::app::cross_account_roles::{
+    parse_hub_account_id, parse_member_accounts, CrossAccountRoles, DEFAULT_MEMBER_ROLE,
+};
 use crate::app::dashui::window_focus::{FocusableWindow, PositionShowParams};
+use crate::app::resource_explorer::demo_data;
 use egui::{self, Context, RichText, ScrollArea, Vec2};
 use std::sync::{Arc, Mutex};
 use std::thread;
 
+/// How the login window signs in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LoginMethod {
+    IdentityCenter,
+    Profile,
+    AccessKeys,
+}
+
 /// AWS Login window component
 pub struct AwsLoginWindow {
     pub open: bool,
+    login_method: LoginMethod,
     identity_center_short_name: String, // Short name for Identity Center (e.g., "mycompany")
     identity_center_region: String,
     default_role_name: String,
+    /// Refresh the session token in the background
+    auto_refresh: bool,
+    /// Hours background refreshes keep the session alive (0 = no limit)
+    keep_alive_hours: i64,
+    /// Named profile to sign in with
+    profile_name: String,
+    /// Profiles found in the AWS shared config files, loaded on first use
+    available_profiles: Option<Vec<String>>,
+    access_key_id: String,
+    secret_access_key: String,
+    session_token: String,
+    /// Roles to assume after the profile or keys, one `role-arn [external-id]` per line
+    assume_role_chain: String,
+    /// Reach member accounts by assuming a role from the hub account
+    cross_account_enabled: bool,
+    /// Hub account ID; empty uses the account of the default role
+    cross_account_hub: String,
+    /// Member role name or ARN template with `{account_id}`
+    cross_account_role_pattern: String,
+    cross_account_external_id: String,
+    /// Member accounts, one `account-id [role]` per line
+    cross_account_members: String,
     login_in_progress: bool,
     completing_login: bool,
     error_message: Option<String>,
@@ -46,15 +86,32 @@ impl Default for AwsLoginWindow {
             )
         };
 
+        // Started with --demo: sign in to the synthetic dataset right away
+        let demo_mode = demo_data::is_demo_mode();
+
         Self {
-            open: false,
+            open: demo_mode,
+            login_method: LoginMethod::IdentityCenter,
             identity_center_short_name: short_name,
             identity_center_region: region,
             default_role_name: role_name,
+            auto_refresh: true,
+            keep_alive_hours: DEFAULT_KEEP_ALIVE_HOURS,
+            profile_name: std::env::var("AWS_PROFILE").unwrap_or_else(|_| "default".to_string()),
+            available_profiles: None,
+            access_key_id: String::new(),
+            secret_access_key: String::new(),
+            session_token: String::new(),
+            assume_role_chain: String::new(),
+            cross_account_enabled: false,
+            cross_account_hub: String::new(),
+            cross_account_role_pattern: DEFAULT_MEMBER_ROLE.to_string(),
+            cross_account_external_id: String::new(),
+            cross_account_members: String::new(),
             login_in_progress: false,
             completing_login: false,
             error_message: None,
-            aws_identity: None,
+            aws_identity: demo_mode.then(|| Arc::new(Mutex::new(demo_data::demo_identity()))),
             accounts_window_open: false,
             logged_out: false,
             first_open: true,
@@ -170,30 +227,89 @@ impl AwsLoginWindow {
         if let Some(response) = window.show(ctx, |ui| {
             // Form fields in a table layout
             ui.add_enabled_ui(!self.login_in_progress, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Sign in with:");
+                    ui.selectable_value(&mut self.login_method, LoginMethod::IdentityCenter, "Identity Center");
+                    ui.selectable_value(&mut self.login_method, LoginMethod::Profile, "Profile");
+                    ui.selectable_value(&mut self.login_method, LoginMethod::AccessKeys, "Access Keys");
+                });
+                ui.add_space(6.0);
+
                 egui::Grid::new("login_form_grid")
                     .num_columns(2)
                     .spacing([10.0, 8.0])
                     .striped(false)
                     .show(ui, |ui| {
-                        ui.label("Identity Center URL:");
-                        ui.horizontal(|ui| {
-                            // Text input for short name
-                            let response = ui.add(
-                                egui::TextEdit::singleline(&mut self.identity_center_short_name)
-                                    .desired_width(150.0)
-                                    .hint_text("your-org"),
-                            );
+                        match self.login_method {
+                            LoginMethod::IdentityCenter => {
+                                ui.label("Identity Center URL:");
+                                ui.horizontal(|ui| {
+                                    // Text input for short name
+                                    let response = ui.add(
+                                        egui::TextEdit::singleline(&mut self.identity_center_short_name)
+                                            .desired_width(150.0)
+                                            .hint_text("your-org"),
+                                    );
+
+                                    // Apply validation on text change
+                                    if response.changed() {
+                                        self.identity_center_short_name =
+                                            Self::validate_and_clean_short_name(&self.identity_center_short_name);
+                                    }
 
-                            // Apply validation on text change
-                            if response.changed() {
-                                self.identity_center_short_name =
-                                    Self::validate_and_clean_short_name(&self.identity_center_short_name);
+                                    // Static suffix label
+                                    ui.label(".awsapps.com/start/");
+                                });
+                                ui.end_row();
+                            }
+                            LoginMethod::Profile => {
+                                ui.label("Profile:");
+                                ui.horizontal(|ui| {
+                                    let profiles = self.available_profiles.get_or_insert_with(list_profiles);
+                                    egui::ComboBox::from_id_salt("login_profile")
+                                        .selected_text(&self.profile_name)
+                                        .width(250.0)
+                                        .show_ui(ui, |ui| {
+                                            if profiles.is_empty() {
+                                                ui.label("No profiles in ~/.aws/config");
+                                            }
+                                            for profile in profiles.iter() {
+                                                ui.selectable_value(&mut self.profile_name, profile.clone(), profile);
+                                            }
+                                        });
+                                    if ui.button("↻").on_hover_text("Reload profiles from ~/.aws/config and ~/.aws/credentials").clicked() {
+                                        self.available_profiles = None;
+                                    }
+                                });
+                                ui.end_row();
                             }
+                            LoginMethod::AccessKeys => {
+                                ui.label("Access Key ID:");
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut self.access_key_id)
+                                        .desired_width(300.0)
+                                        .hint_text("AKIA..."),
+                                );
+                                ui.end_row();
 
-                            // Static suffix label
-                            ui.label(".awsapps.com/start/");
-                        });
-                        ui.end_row();
+                                ui.label("Secret Access Key:");
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut self.secret_access_key)
+                                        .desired_width(300.0)
+                                        .password(true),
+                                );
+                                ui.end_row();
+
+                                ui.label("Session Token:");
+                                ui.add(
+                                    egui::TextEdit::singleline(&mut self.session_token)
+                                        .desired_width(300.0)
+                                        .password(true)
+                                        .hint_text("optional"),
+                                );
+                                ui.end_row();
+                            }
+                        }
 
                         ui.label("Region:");
                         egui::ComboBox::from_label("")
@@ -217,8 +333,6 @@ impl AwsLoginWindow {
                                 ui.selectable_value(&mut self.identity_center_region, "ap-southeast-1".to_string(), "ap-southeast-1 (Asia Pacific Singapore)");
                                 ui.selectable_value(&mut self.identity_center_region, "ap-southeast-2".to_string(), "ap-southeast-2 (Asia Pacific Sydney)");
                                 ui.selectable_value(&mut self.identity_center_region, "sa-east-1".to_string(), "sa-east-1 (South America Sao Paulo)");
-                                ui.selectable_value(&mut self.identity_center_region, "us-gov-west-1".to_string(), "us-gov-west-1 (AWS GovCloud US West)");
-                                ui.selectable_value(&mut self.identity_center_region, "us-gov-east-1".to_string(), "us-gov-east-1 (AWS GovCloud US East)");
 
                                 ui.separator();
                                 ui.label(RichText::new("Opt-in Regions").strong());
@@ -234,18 +348,59 @@ impl AwsLoginWindow {
                                 ui.selectable_value(&mut self.identity_center_region, "me-central-1".to_string(), "me-central-1 (Middle East UAE)");
                                 ui.selectable_value(&mut self.identity_center_region, "il-central-1".to_string(), "il-central-1 (Israel Tel Aviv)");
                                 ui.selectable_value(&mut self.identity_center_region, "ca-west-1".to_string(), "ca-west-1 (Canada West Calgary)");
+
+                                ui.separator();
+                                ui.label(RichText::new("AWS GovCloud (US)").strong());
+                                ui.selectable_value(&mut self.identity_center_region, "us-gov-west-1".to_string(), "us-gov-west-1 (AWS GovCloud US West)");
+                                ui.selectable_value(&mut self.identity_center_region, "us-gov-east-1".to_string(), "us-gov-east-1 (AWS GovCloud US East)");
+
+                                ui.separator();
+                                ui.label(RichText::new("AWS China").strong());
+                                ui.selectable_value(&mut self.identity_center_region, "cn-north-1".to_string(), "cn-north-1 (China Beijing)");
+                                ui.selectable_value(&mut self.identity_center_region, "cn-northwest-1".to_string(), "cn-northwest-1 (China Ningxia)");
                             });
                         ui.end_row();
 
-                        ui.label("Default Role:");
-                        ui.add(
-                            egui::TextEdit::singleline(&mut self.default_role_name)
-                                .desired_width(300.0),
-                        );
-                        ui.end_row();
+                        if self.login_method == LoginMethod::IdentityCenter {
+                            ui.label("Default Role:");
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.default_role_name)
+                                    .desired_width(300.0),
+                            );
+                            ui.end_row();
+
+                            ui.label("Session:");
+                            ui.horizontal(|ui| {
+                                ui.checkbox(&mut self.auto_refresh, "Refresh automatically")
+                                    .on_hover_text("Renew the session token in the background before it expires");
+                                ui.add_enabled_ui(self.auto_refresh, |ui| {
+                                    ui.label("keep alive for");
+                                    ui.add(
+                                        egui::DragValue::new(&mut self.keep_alive_hours)
+                                            .range(0..=168)
+                                            .suffix(" h"),
+                                    )
+                                    .on_hover_text("Stop refreshing this long after login (0 = until Identity Center ends the session)");
+                                });
+                            });
+                            ui.end_row();
+                        } else {
+                            ui.label("Assume Roles:");
+                            ui.add(
+                                egui::TextEdit::multiline(&mut self.assume_role_chain)
+                                    .desired_width(300.0)
+                                    .desired_rows(2)
+                                    .hint_text("arn:aws:iam::123456789012:role/Name [external-id]"),
+                            )
+                            .on_hover_text("Optional roles to assume in order, one per line");
+                            ui.end_row();
+                        }
                     });
             });
 
+            ui.add_space(6.0);
+            self.show_cross_account_settings(ui);
+
             ui.add_space(10.0);
 
             // Error message if any
@@ -263,13 +418,30 @@ impl AwsLoginWindow {
                 match &self.aws_identity {
                     None => {
                         // Initial login button
+                        let label = match self.login_method {
+                            LoginMethod::IdentityCenter => "Login with AWS Identity Center",
+                            LoginMethod::Profile => "Sign in with Profile",
+                            LoginMethod::AccessKeys => "Sign in with Access Keys",
+                        };
                         let response = ui.add_enabled(
                             !self.login_in_progress,
-                            egui::Button::new("Login with AWS Identity Center"),
+                            egui::Button::new(label),
                         );
 
                         if response.clicked() {
-                            self.start_login();
+                            match self.login_method {
+                                LoginMethod::IdentityCenter => self.start_login(),
+                                _ => self.start_credential_source_login(),
+                            }
+                        }
+
+                        ui.add_space(4.0);
+                        if ui
+                            .add_enabled(!self.login_in_progress, egui::Button::new("Try Demo Mode"))
+                            .on_hover_text("Explore a synthetic dataset without AWS credentials")
+                            .clicked()
+                        {
+                            self.start_demo();
                         }
                     }
                     Some(aws_identity) => {
@@ -367,6 +539,15 @@ impl AwsLoginWindow {
                                 });
                             }
                             LoginState::LoggedIn => {
+                                let (session_health, source_label) = aws_identity
+                                    .try_lock()
+                                    .map(|identity| {
+                                        (
+                                            identity.session_health(chrono::Utc::now()),
+                                            identity.credential_source.as_ref().map(CredentialSource::label),
+                                        )
+                                    })
+                                    .unwrap_or_default();
                                 ui.vertical_centered(|ui| {
                                     ui.label(
                                         RichText::new("Successfully logged in!")
@@ -379,6 +560,26 @@ impl AwsLoginWindow {
                                             .color(egui::Color32::from_rgb(255, 165, 0))
                                             .size(14.0),
                                     );
+                                    if let Some(health) = session_health {
+                                        ui.label(
+                                            RichText::new(session_health_text(health))
+                                                .weak()
+                                                .size(12.0),
+                                        );
+                                    }
+                                    if demo_data::is_demo_mode() {
+                                        ui.label(
+                                            RichText::new("Demo mode (synthetic data)")
+                                                .weak()
+                                                .size(12.0),
+                                        );
+                                    } else if let Some(label) = source_label {
+                                        ui.label(
+                                            RichText::new(format!("Signed in with {}", label))
+                                                .weak()
+                                                .size(12.0),
+                                        );
+                                    }
                                     ui.add_space(5.0);
 
                                     ui.horizontal(|ui| {
@@ -386,6 +587,14 @@ impl AwsLoginWindow {
                                             self.accounts_window_open = true;
                                         }
 
+                                        if ui
+                                            .button("Re-authenticate")
+                                            .on_hover_text("Log in again without closing any windows")
+                                            .clicked()
+                                        {
+                                            self.start_reauthentication();
+                                        }
+
                                         if ui.button("Logout").clicked() {
                                             tracing::info!("User clicked 'Logout'");
                                             self.logout();
@@ -412,8 +621,14 @@ impl AwsLoginWindow {
                                 });
                             }
                             LoginState::NotLoggedIn => {
-                                // Reset and try again
-                                self.aws_identity = None;
+                                if self.completing_login {
+                                    // Sign-in thread has not picked up the lock yet
+                                    ui.spinner();
+                                    ctx.request_repaint();
+                                } else {
+                                    // Reset and try again
+                                    self.aws_identity = None;
+                                }
                             }
                         }
                     }
@@ -468,6 +683,15 @@ impl AwsLoginWindow {
             return;
         }
 
+        let cross_account_roles = match self.cross_account_roles() {
+            Ok(roles) => roles,
+            Err(err) => {
+                tracing::warn!("Login attempt with invalid cross-account roles: {}", err);
+                self.error_message = Some(err);
+                return;
+            }
+        };
+
         self.error_message = None;
         self.login_in_progress = true;
         tracing::info!("Login validation successful, proceeding with login");
@@ -478,6 +702,9 @@ impl AwsLoginWindow {
             self.default_role_name.clone(),
             self.identity_center_region.clone(),
         );
+        identity_center.auto_refresh = self.auto_refresh;
+        identity_center.keep_alive_hours = self.keep_alive_hours;
+        identity_center.set_cross_account_roles(cross_account_roles);
 
         // Initialize (mock implementation)
         match identity_center.initialize() {
@@ -505,6 +732,238 @@ impl AwsLoginWindow {
         }
     }
 
+    /// Build the credential source from the profile or access key fields
+    fn credential_source(&self) -> Result<CredentialSource, String> {
+        let base = match self.login_method {
+            LoginMethod::Profile => {
+                if self.profile_name.trim().is_empty() {
+                    return Err("Profile is required".to_string());
+                }
+                BaseCredentials::Profile(self.profile_name.trim().to_string())
+            }
+            LoginMethod::AccessKeys => {
+                if self.access_key_id.trim().is_empty() || self.secret_access_key.is_empty() {
+                    return Err("Access Key ID and Secret Access Key are required".to_string());
+                }
+                BaseCredentials::StaticKeys {
+                    access_key_id: self.access_key_id.trim().to_string(),
+                    secret_access_key: self.secret_access_key.clone(),
+                    session_token: Some(self.session_token.trim().to_string())
+                        .filter(|token| !token.is_empty()),
+                }
+            }
+            LoginMethod::IdentityCenter => {
+                return Err("Identity Center does not use a credential source".to_string())
+            }
+        };
+
+        if self.identity_center_region.is_empty() {
+            return Err("Region is required".to_string());
+        }
+
+        Ok(CredentialSource {
+            base,
+            assume_role_chain: parse_assume_role_chain(&self.assume_role_chain)?,
+            region: self.identity_center_region.clone(),
+        })
+    }
+
+    /// Sign in with a profile or access keys, optionally assuming roles
+    fn start_credential_source_login(&mut self) {
+        let source_and_roles = self
+            .credential_source()
+            .and_then(|source| Ok((source, self.cross_account_roles()?)));
+        let (source, cross_account_roles) = match source_and_roles {
+            Ok(source_and_roles) => source_and_roles,
+            Err(err) => {
+                tracing::warn!("Sign-in attempt with invalid credential source: {}", err);
+                self.error_message = Some(err);
+                return;
+            }
+        };
+
+        tracing::info!("Starting sign-in with {}", source.label());
+        self.error_message = None;
+        self.login_in_progress = true;
+        self.completing_login = true;
+        // The session keeps its own copy of the keys
+        self.secret_access_key.clear();
+        self.session_token.clear();
+
+        let mut identity_center = AwsIdentityCenter::with_credential_source(source);
+        identity_center.set_cross_account_roles(cross_account_roles);
+        let identity_center = Arc::new(Mutex::new(identity_center));
+        Self::spawn_credential_source_sign_in(identity_center.clone());
+        self.aws_identity = Some(identity_center);
+    }
+
+    /// Build the cross-account settings from the form fields
+    fn cross_account_roles(&self) -> Result<CrossAccountRoles, String> {
+        if !self.cross_account_enabled {
+            return Ok(CrossAccountRoles::default());
+        }
+        if self.cross_account_role_pattern.trim().is_empty() {
+            return Err("Member role is required for cross-account access".to_string());
+        }
+        Ok(CrossAccountRoles {
+            hub_account_id: parse_hub_account_id(&self.cross_account_hub)?,
+            role_pattern: self.cross_account_role_pattern.trim().to_string(),
+            external_id: Some(self.cross_account_external_id.trim().to_string())
+                .filter(|id| !id.is_empty()),
+            member_accounts: parse_member_accounts(&self.cross_account_members)?,
+        })
+    }
+
+    /// Hub-and-spoke settings, applied at login or with "Apply" to the current session
+    fn show_cross_account_settings(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new("Cross-account roles")
+            .default_open(self.cross_account_enabled)
+            .show(ui, |ui| {
+                ui.checkbox(
+                    &mut self.cross_account_enabled,
+                    "Assume a role from the hub account to reach member accounts",
+                );
+                ui.add_enabled_ui(self.cross_account_enabled, |ui| {
+                    egui::Grid::new("login_cross_account_grid")
+                        .num_columns(2)
+                        .spacing([10.0, 8.0])
+                        .show(ui, |ui| {
+                            ui.label("Hub Account:");
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.cross_account_hub)
+                                    .desired_width(300.0)
+                                    .hint_text("account of the default role"),
+                            );
+                            ui.end_row();
+
+                            ui.label("Member Role:");
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.cross_account_role_pattern)
+                                    .desired_width(300.0),
+                            )
+                            .on_hover_text(
+                                "Role name, or ARN template such as arn:aws:iam::{account_id}:role/Name",
+                            );
+                            ui.end_row();
+
+                            ui.label("External ID:");
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.cross_account_external_id)
+                                    .desired_width(300.0)
+                                    .hint_text("optional"),
+                            );
+                            ui.end_row();
+
+                            ui.label("Member Accounts:");
+                            ui.add(
+                                egui::TextEdit::multiline(&mut self.cross_account_members)
+                                    .desired_width(300.0)
+                                    .desired_rows(3)
+                                    .hint_text("123456789012 [role name or ARN]"),
+                            )
+                            .on_hover_text(
+                                "Accounts to reach through the hub, one per line, with an optional role override",
+                            );
+                            ui.end_row();
+                        });
+                });
+
+                let logged_in = self.aws_identity.as_ref().is_some_and(|identity| {
+                    identity
+                        .try_lock()
+                        .is_ok_and(|identity| identity.login_state == LoginState::LoggedIn)
+                });
+                if logged_in && ui.button("Apply").clicked() {
+                    self.apply_cross_account_roles();
+                }
+            });
+    }
+
+    /// Apply the cross-account settings to the current session
+    fn apply_cross_account_roles(&mut self) {
+        let roles = match self.cross_account_roles() {
+            Ok(roles) => roles,
+            Err(err) => {
+                self.error_message = Some(err);
+                return;
+            }
+        };
+        let Some(aws_identity) = &self.aws_identity else {
+            return;
+        };
+        match aws_identity.lock() {
+            Ok(mut identity_center) => {
+                tracing::info!(
+                    "Applying cross-account roles for {} member accounts",
+                    roles.member_accounts.len()
+                );
+                identity_center.set_cross_account_roles(roles);
+                self.error_message = None;
+            }
+            Err(e) => {
+                tracing::error!(
+                    "Failed to lock AWS identity center for cross-account roles: {}",
+                    e
+                );
+            }
+        }
+    }
+
+    /// Resolve the credential source of `aws_identity` on a background thread
+    fn spawn_credential_source_sign_in(aws_identity: Arc<Mutex<AwsIdentityCenter>>) {
+        thread::spawn(move || {
+            let Ok(mut identity_center) = aws_identity.lock() else {
+                tracing::error!("Failed to lock AWS identity center for sign-in");
+                return;
+            };
+            match identity_center.sign_in_with_credential_source() {
+                Ok(()) => {
+                    crate::app::memory_profiling::memory_checkpoint("after_iam_login");
+                }
+                Err(err) => {
+                    let error_msg = format!("Failed to sign in: {}", err);
+                    tracing::error!("{}", error_msg);
+                    identity_center.login_state = LoginState::Error(error_msg);
+                }
+            }
+        });
+    }
+
+    /// Run the device authorization again for the current session
+    ///
+    /// Keeps the same identity center, so Explorer windows and agents holding it
+    /// pick up the new token once the login completes. Profile and access-key
+    /// sessions resolve their credentials again instead.
+    pub fn start_reauthentication(&mut self) {
+        self.open = true;
+        let Some(aws_identity) = &self.aws_identity else {
+            return;
+        };
+
+        tracing::info!("Starting re-authentication");
+        self.error_message = None;
+        self.completing_login = false;
+
+        let aws_identity_clone = aws_identity.clone();
+        thread::spawn(move || {
+            let Ok(mut identity_center) = aws_identity_clone.lock() else {
+                tracing::error!("Failed to lock AWS identity center for re-authentication");
+                return;
+            };
+            if identity_center.uses_credential_source() {
+                if let Err(err) = identity_center.sign_in_with_credential_source() {
+                    identity_center.login_state =
+                        LoginState::Error(format!("Failed to sign in: {}", err));
+                }
+                return;
+            }
+            if let Err(err) = identity_center.start_device_authorization() {
+                let error_msg = format!("Failed to start device authorization: {}", err);
+                identity_center.login_state = LoginState::Error(error_msg);
+            }
+        });
+    }
+
     /// Complete the login process after user has authorized in browser
     fn complete_login(&mut self, ctx: &Context) {
         tracing::info!("User clicked 'I've completed the login'");
@@ -574,7 +1033,9 @@ impl AwsLoginWindow {
                                     );
 
                                     // Memory checkpoint: After IAM login complete
-                                    crate::app::memory_profiling::memory_checkpoint("after_iam_login");
+                                    crate::app::memory_profiling::memory_checkpoint(
+                                        "after_iam_login",
+                                    );
                                 }
                                 Err(err) => {
                                     tracing::error!(
@@ -589,6 +1050,9 @@ impl AwsLoginWindow {
                         } else {
                             tracing::error!("Failed to re-acquire lock for credential storage");
                         }
+
+                        // Refresh the token in the background from now on
+                        spawn_session_refresher(&aws_identity_clone);
                     }
                     Err(err) => {
                         let error_msg = format!("Failed to complete login: {}", err);
@@ -609,8 +1073,16 @@ impl AwsLoginWindow {
         }
     }
 
+    /// Sign in to the synthetic demo dataset
+    fn start_demo(&mut self) {
+        demo_data::set_demo_mode(true);
+        self.error_message = None;
+        self.aws_identity = Some(Arc::new(Mutex::new(demo_data::demo_identity())));
+    }
+
     /// Logout from AWS Identity Center and reset window state
     fn logout(&mut self) {
+        demo_data::set_demo_mode(false);
         if let Some(aws_identity) = &self.aws_identity {
             // Create a clone for the thread
             let aws_identity_clone = aws_identity.clone();
@@ -729,3 +1201,29 @@ impl FocusableWindow for AwsLoginWindow {
         self.show_with_focus(ctx, Some(params), bring_to_front);
     }
 }
+
+/// Describe the session's remaining time for the login window and top bar
+pub fn session_health_text(health: SessionHealth) -> String {
+    match health {
+        SessionHealth::Active { remaining } => {
+            format!("Session token valid for {}", format_remaining(remaining))
+        }
+        SessionHealth::ExpiringSoon { remaining } => format!(
+            "Session expires in {} - log in again to continue",
+            format_remaining(remaining)
+        ),
+        SessionHealth::Expired => "Session expired - log in again to continue".to_string(),
+    }
+}
+
+/// Format a remaining duration as "1h 05m", "12m" or "<1m"
+pub fn format_remaining(remaining: chrono::Duration) -> String {
+    let minutes = remaining.num_minutes();
+    if minutes < 1 {
+        "<1m".to_string()
+    } else if minutes < 60 {
+        format!("{}m", minutes)
+    } else {
+        format!("{}h {:02}m", minutes / 60, minutes % 60)
+    }
+}
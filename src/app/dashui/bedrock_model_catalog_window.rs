@@ -0,0 +1,592 @@
+//! Bedrock Model Catalog Window
+//!
+//! Lists the foundation models of a region with their on-demand and
+//! provisioned throughput availability, the cross-region inference profiles
+//! that serve them, and their invocation quotas. Models the agent framework
+//! supports are marked, and in the account and region agents call Bedrock in
+//! the window shows which of them agents can currently use and which roles
+//! are set to use them.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::agent_framework::{
+    AgentModel, AgentModelPreferences, AgentModelRole, ModelAvailability,
+};
+use crate::app::data_plane::bedrock_models::{
+    BedrockModelCatalogClient, CatalogModel, ModelCatalog,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Parameters for showing the model catalog window
+#[derive(Clone)]
+pub struct BedrockModelCatalogShowParams {
+    pub account_id: String,
+    pub region: String,
+    /// Account and region agents call Bedrock in, when logged in
+    pub agent_target: Option<(String, String)>,
+}
+
+/// Results from background threads
+enum CatalogEvent {
+    Loaded(Result<ModelCatalog, String>),
+}
+
+/// What the agent framework can do with a listed model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AgentUsage {
+    /// Not one of the agent models
+    Unsupported,
+    /// An agent model, listed outside the agents' account and region
+    Supported(AgentModel),
+    /// An agent model agents cannot invoke here (no on-demand or profile access)
+    Unavailable(AgentModel),
+    /// An agent model agents can invoke here
+    Usable(AgentModel),
+}
+
+pub struct BedrockModelCatalogWindow {
+    pub open: bool,
+    // Display parameters
+    account_id: String,
+    region: String,
+    agent_target: Option<(String, String)>,
+
+    // Catalog
+    catalog: Option<ModelCatalog>,
+    availability: Option<ModelAvailability>,
+    loading: bool,
+    error_message: Option<String>,
+    selected: Option<String>,
+    preferences: AgentModelPreferences,
+
+    // Filters
+    filter: String,
+    agent_models_only: bool,
+    invocable_only: bool,
+
+    // Services
+    client: Arc<BedrockModelCatalogClient>,
+
+    // Channel for results from background threads.
+    // Replaced when the window opens on a region so results for another one are dropped.
+    sender: mpsc::Sender<CatalogEvent>,
+    receiver: mpsc::Receiver<CatalogEvent>,
+}
+
+impl BedrockModelCatalogWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            account_id: String::new(),
+            region: String::new(),
+            agent_target: None,
+            catalog: None,
+            availability: None,
+            loading: false,
+            error_message: None,
+            selected: None,
+            preferences: AgentModelPreferences::default(),
+            filter: String::new(),
+            agent_models_only: false,
+            invocable_only: false,
+            client: Arc::new(BedrockModelCatalogClient::new(credential_coordinator)),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on an account and region and load its models
+    pub fn open_for_region(&mut self, params: BedrockModelCatalogShowParams) {
+        let (sender, receiver) = mpsc::channel();
+        self.sender = sender;
+        self.receiver = receiver;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.agent_target = params.agent_target;
+        self.catalog = None;
+        self.availability = None;
+        self.selected = None;
+        // Pick up role changes made in the agent manager since the last open
+        self.preferences = AgentModelPreferences::load();
+        self.open = true;
+        self.load_catalog();
+    }
+
+    /// Whether this window shows the given account and region
+    pub fn targets(&self, account_id: &str, region: &str) -> bool {
+        self.account_id == account_id && self.region == region
+    }
+
+    /// Whether agents call Bedrock in this window's account and region
+    fn is_agent_region(&self) -> bool {
+        self.agent_target
+            .as_ref()
+            .is_some_and(|(account_id, region)| self.targets(account_id, region))
+    }
+
+    fn load_catalog(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+        let (account_id, region) = (self.account_id.clone(), self.region.clone());
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client.load_catalog(&account_id, &region).await;
+            if let Err(e) = &result {
+                log::error!("Failed to load Bedrock models in {}: {}", region, e);
+            }
+            CatalogEvent::Loaded(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    /// Poll for results from background threads
+    fn poll_events(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                CatalogEvent::Loaded(Ok(catalog)) => {
+                    self.loading = false;
+                    self.availability = Some(ModelAvailability::from_model_ids(
+                        self.region.clone(),
+                        catalog.model_ids(),
+                    ));
+                    self.catalog = Some(catalog);
+                }
+                CatalogEvent::Loaded(Err(e)) => {
+                    self.loading = false;
+                    self.error_message = Some(e);
+                }
+            }
+        }
+    }
+
+    fn agent_usage(&self, model: &CatalogModel) -> AgentUsage {
+        agent_usage(model, self.is_agent_region(), self.availability.as_ref())
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_events();
+
+        // Request continuous repaint while waiting to show spinners
+        if self.loading {
+            ctx.request_repaint();
+        }
+
+        let title = format!("Bedrock Models: {}", self.region);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "bedrock_model_catalog_window",
+                &self.account_id,
+                &self.region,
+            )))
+            .open(&mut is_open)
+            .default_size([900.0, 600.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Region:").strong());
+            ui.label(&self.region);
+            ui.label(RichText::new(&self.account_id).weak());
+            if ui
+                .add_enabled(!self.loading, egui::Button::new("Refresh"))
+                .clicked()
+            {
+                self.load_catalog();
+            }
+            if self.loading {
+                ui.spinner();
+            }
+        });
+
+        let mut switch_to = None;
+        ui.horizontal(|ui| match &self.agent_target {
+            Some((account_id, region)) if self.is_agent_region() => {
+                ui.label(
+                    RichText::new(format!(
+                        "Agents call Bedrock in this account and region ({} {})",
+                        account_id, region
+                    ))
+                    .small(),
+                );
+            }
+            Some((account_id, region)) => {
+                ui.label(
+                    RichText::new(format!(
+                        "Agents call Bedrock in {} {}; agent models are only marked as supported here",
+                        account_id, region
+                    ))
+                    .small()
+                    .weak(),
+                );
+                if ui.small_button("Show agents' region").clicked() {
+                    switch_to = Some((account_id.clone(), region.clone()));
+                }
+            }
+            None => {
+                ui.label(
+                    RichText::new("Log in to see which models agents can use")
+                        .small()
+                        .weak(),
+                );
+            }
+        });
+        if let Some((account_id, region)) = switch_to {
+            let agent_target = self.agent_target.clone();
+            self.open_for_region(BedrockModelCatalogShowParams {
+                account_id,
+                region,
+                agent_target,
+            });
+        }
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error);
+        }
+        let Some(catalog) = self.catalog.clone() else {
+            return;
+        };
+        if let Some(error) = &catalog.quota_error {
+            ui.label(
+                RichText::new(format!("Quotas unavailable: {}", error))
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.label("Filter:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.filter)
+                    .hint_text("model, provider, or ID")
+                    .desired_width(220.0),
+            );
+            ui.checkbox(&mut self.agent_models_only, "Agent models only");
+            ui.checkbox(&mut self.invocable_only, "Invocable on demand");
+        });
+
+        let filter = self.filter.trim().to_lowercase();
+        let models: Vec<&CatalogModel> = catalog
+            .models
+            .iter()
+            .filter(|model| {
+                filter.is_empty()
+                    || model.model_name.to_lowercase().contains(&filter)
+                    || model.provider_name.to_lowercase().contains(&filter)
+                    || model.model_id.to_lowercase().contains(&filter)
+            })
+            .filter(|model| {
+                !self.agent_models_only
+                    || !matches!(self.agent_usage(model), AgentUsage::Unsupported)
+            })
+            .filter(|model| !self.invocable_only || model.is_invocable())
+            .collect();
+        ui.label(
+            RichText::new(format!(
+                "{} of {} models",
+                models.len(),
+                catalog.models.len()
+            ))
+            .small()
+            .weak(),
+        );
+
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| self.render_models(ui, &models));
+    }
+
+    fn render_models(&mut self, ui: &mut Ui, models: &[&CatalogModel]) {
+        if models.is_empty() {
+            ui.label("No models match");
+            return;
+        }
+
+        let mut clicked = None;
+        egui::Grid::new("bedrock_model_catalog_grid")
+            .num_columns(6)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("Model");
+                ui.strong("Provider");
+                ui.strong("Modalities");
+                ui.strong("On-demand");
+                ui.strong("Provisioned");
+                ui.strong("Agents");
+                ui.end_row();
+
+                for model in models {
+                    let selected = self.selected.as_deref() == Some(model.model_id.as_str());
+                    ui.horizontal(|ui| {
+                        if ui
+                            .selectable_label(selected, &model.model_name)
+                            .on_hover_text(&model.model_id)
+                            .clicked()
+                        {
+                            clicked = Some(model.model_id.clone());
+                        }
+                        if model.is_legacy() {
+                            ui.colored_label(ui.visuals().warn_fg_color, "Legacy")
+                                .on_hover_text("Scheduled for end of life");
+                        }
+                    });
+                    ui.label(&model.provider_name);
+                    ui.label(format!(
+                        "{} -> {}",
+                        model.input_modalities.join(", "),
+                        model.output_modalities.join(", ")
+                    ));
+                    if model.on_demand {
+                        ui.label("Yes");
+                    } else if !model.inference_profiles.is_empty() {
+                        ui.label("Via profile").on_hover_text(format!(
+                            "Invoke through a cross-region inference profile:\n{}",
+                            model.inference_profiles.join("\n")
+                        ));
+                    } else {
+                        ui.label(RichText::new("No").weak());
+                    }
+                    ui.label(if model.provisioned { "Yes" } else { "No" });
+                    self.render_agent_usage(ui, self.agent_usage(model));
+                    ui.end_row();
+
+                    if selected {
+                        ui.label("");
+                        ui.vertical(|ui| render_model_details(ui, model));
+                        ui.end_row();
+                    }
+                }
+            });
+
+        if let Some(model_id) = clicked {
+            if self.selected.as_ref() == Some(&model_id) {
+                self.selected = None;
+            } else {
+                self.selected = Some(model_id);
+            }
+        }
+    }
+
+    fn render_agent_usage(&self, ui: &mut Ui, usage: AgentUsage) {
+        match usage {
+            AgentUsage::Unsupported => {
+                ui.label("");
+            }
+            AgentUsage::Supported(model) => {
+                ui.label(RichText::new("Supported").weak())
+                    .on_hover_text(format!(
+                        "{} is an agent model; agents call Bedrock in another account or region",
+                        model.display_name()
+                    ));
+            }
+            AgentUsage::Unavailable(model) => {
+                ui.colored_label(ui.visuals().warn_fg_color, "Not usable")
+                    .on_hover_text(format!(
+                        "{} is an agent model but cannot be invoked on demand here",
+                        model.display_name()
+                    ));
+            }
+            AgentUsage::Usable(model) => {
+                let roles: Vec<&str> = AgentModelRole::all()
+                    .iter()
+                    .filter(|role| self.preferences.get(**role) == model)
+                    .map(|role| role.display_name())
+                    .collect();
+                let text = if roles.is_empty() {
+                    "Usable".to_string()
+                } else {
+                    format!("Usable ({})", roles.join(", "))
+                };
+                ui.colored_label(Color32::from_rgb(100, 200, 100), text)
+                    .on_hover_text(format!(
+                        "Agents can run on {} in this region",
+                        model.display_name()
+                    ));
+            }
+        }
+    }
+}
+
+/// What agents can do with a model listed in a region
+fn agent_usage(
+    model: &CatalogModel,
+    agent_region: bool,
+    availability: Option<&ModelAvailability>,
+) -> AgentUsage {
+    let Some(agent_model) = AgentModel::all_models()
+        .iter()
+        .copied()
+        .find(|agent_model| agent_model.model_id() == model.model_id)
+    else {
+        return AgentUsage::Unsupported;
+    };
+    match availability {
+        Some(availability) if agent_region => {
+            if availability.is_available(agent_model) {
+                AgentUsage::Usable(agent_model)
+            } else {
+                AgentUsage::Unavailable(agent_model)
+            }
+        }
+        _ => AgentUsage::Supported(agent_model),
+    }
+}
+
+fn render_model_details(ui: &mut Ui, model: &CatalogModel) {
+    ui.label(RichText::new(&model.model_id).monospace().small());
+    ui.label(
+        RichText::new(format!(
+            "Streaming: {}",
+            if model.streaming_supported {
+                "yes"
+            } else {
+                "no"
+            }
+        ))
+        .small(),
+    );
+    if !model.inference_profiles.is_empty() {
+        ui.label(
+            RichText::new(format!(
+                "Inference profiles: {}",
+                model.inference_profiles.join(", ")
+            ))
+            .small(),
+        );
+    }
+
+    if model.quotas.is_empty() {
+        ui.label(RichText::new("No invocation quotas listed").small().weak());
+        return;
+    }
+    egui::Grid::new(("bedrock_model_quotas_grid", &model.model_id))
+        .num_columns(2)
+        .show(ui, |ui| {
+            for quota in &model.quotas {
+                ui.label(RichText::new(&quota.name).small());
+                let value = quota
+                    .value
+                    .map(|value| value.to_string())
+                    .unwrap_or_else(|| "-".to_string());
+                if quota.adjustable {
+                    ui.label(RichText::new(value).small().strong())
+                        .on_hover_text("Adjustable through Service Quotas");
+                } else {
+                    ui.label(RichText::new(value).small().strong());
+                }
+                ui.end_row();
+            }
+        });
+}
+
+impl FocusableWindow for BedrockModelCatalogWindow {
+    type ShowParams = BedrockModelCatalogShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "bedrock_model_catalog_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Bedrock Models: {}", self.region)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the region first
+        self.open_for_region(params);
+
+        // Then show with focus
+        BedrockModelCatalogWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn model(model_id: &str, on_demand: bool, inference_profiles: &[&str]) -> CatalogModel {
+        CatalogModel {
+            model_id: model_id.to_string(),
+            model_name: model_id.to_string(),
+            provider_name: "Anthropic".to_string(),
+            input_modalities: vec!["TEXT".to_string()],
+            output_modalities: vec!["TEXT".to_string()],
+            streaming_supported: true,
+            on_demand,
+            provisioned: false,
+            lifecycle_status: Some("ACTIVE".to_string()),
+            inference_profiles: inference_profiles.iter().map(|p| p.to_string()).collect(),
+            quotas: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_agent_usage() {
+        let sonnet_id = AgentModel::ClaudeSonnet45.model_id();
+        let sonnet = model(sonnet_id, false, &[&format!("us.{}", sonnet_id)]);
+        let haiku = model(AgentModel::ClaudeHaiku45.model_id(), false, &[]);
+        let other = model("meta.llama3-8b-instruct-v1:0", true, &[]);
+        let catalog = ModelCatalog {
+            models: vec![sonnet.clone(), haiku.clone(), other.clone()],
+            quota_error: None,
+        };
+        let availability = ModelAvailability::from_model_ids("us-east-1", catalog.model_ids());
+
+        assert_eq!(
+            agent_usage(&sonnet, true, Some(&availability)),
+            AgentUsage::Usable(AgentModel::ClaudeSonnet45)
+        );
+        assert_eq!(
+            agent_usage(&sonnet, false, Some(&availability)),
+            AgentUsage::Supported(AgentModel::ClaudeSonnet45)
+        );
+        assert_eq!(
+            agent_usage(&other, true, Some(&availability)),
+            AgentUsage::Unsupported
+        );
+
+        // Listed, but without on-demand throughput or a profile
+        assert_eq!(
+            agent_usage(&haiku, true, Some(&availability)),
+            AgentUsage::Unavailable(AgentModel::ClaudeHaiku45)
+        );
+    }
+}
@@ -0,0 +1,195 @@
+//! Change Set Review Panel
+//!
+//! Renders a computed change set (planned adds, modifies, and removes with
+//! replacement warnings) and collects the user's approval. Shared by the stack
+//! update and stack import windows.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use crate::app::cloudformation_manager::{ChangeAction, ChangeSetPlan, Replacement};
+use eframe::egui;
+use egui::{Color32, RichText, Ui};
+
+/// What the user decided in the review panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewDecision {
+    Execute,
+    Discard,
+}
+
+fn action_color(ui: &Ui, action: &ChangeAction) -> Color32 {
+    match action {
+        ChangeAction::Add | ChangeAction::Import => Color32::from_rgb(80, 180, 80),
+        ChangeAction::Remove => ui.visuals().error_fg_color,
+        _ => ui.visuals().warn_fg_color,
+    }
+}
+
+/// Approval state for one reviewed change set
+#[derive(Default)]
+pub struct ChangeSetReview {
+    approved: bool,
+    confirmation: String,
+}
+
+impl ChangeSetReview {
+    /// Clear the approval for a new change set
+    pub fn reset(&mut self) {
+        self.approved = false;
+        self.confirmation.clear();
+    }
+
+    /// Render the plan and approval controls
+    ///
+    /// Executing needs the review checkbox, plus the stack name typed when
+    /// anything is deleted or replaced. `busy` disables the controls while the
+    /// change set is executing.
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        plan: &ChangeSetPlan,
+        stack_name: &str,
+        busy: bool,
+    ) -> Option<ReviewDecision> {
+        if plan.changes.is_empty() {
+            ui.label(RichText::new("The change set would not change any resources").italics());
+            return ui
+                .button("Back")
+                .clicked()
+                .then_some(ReviewDecision::Discard);
+        }
+
+        let (adds, modifies, removes) = plan.counts();
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(&plan.change_set_name).strong());
+            ui.colored_label(Color32::from_rgb(80, 180, 80), format!("{} add", adds));
+            ui.colored_label(ui.visuals().warn_fg_color, format!("{} modify", modifies));
+            ui.colored_label(ui.visuals().error_fg_color, format!("{} remove", removes));
+        });
+
+        let destructive: Vec<_> = plan.destructive_changes().collect();
+        if !destructive.is_empty() {
+            ui.add_space(4.0);
+            egui::Frame::group(ui.style()).show(ui, |ui| {
+                ui.colored_label(
+                    ui.visuals().warn_fg_color,
+                    RichText::new(format!(
+                        "{} resource{} will be deleted or may be replaced; data in them can be lost",
+                        destructive.len(),
+                        if destructive.len() == 1 { "" } else { "s" }
+                    ))
+                    .strong(),
+                );
+                for change in &destructive {
+                    ui.label(format!(
+                        "{} ({}): {}",
+                        change.logical_id,
+                        change.resource_type,
+                        change.replacement_warning().unwrap_or_default()
+                    ));
+                }
+            });
+        }
+        ui.separator();
+
+        render_changes(ui, plan);
+
+        ui.separator();
+        let needs_typed_confirmation = !destructive.is_empty();
+        let mut decision = None;
+
+        ui.add_enabled_ui(!busy, |ui| {
+            ui.checkbox(&mut self.approved, "I have reviewed these changes");
+            if needs_typed_confirmation {
+                ui.horizontal(|ui| {
+                    ui.label(format!("Type {} to confirm:", stack_name));
+                    ui.add(egui::TextEdit::singleline(&mut self.confirmation).desired_width(200.0));
+                });
+            }
+            let confirmed = self.approved
+                && (!needs_typed_confirmation || self.confirmation.trim() == stack_name);
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(confirmed, egui::Button::new("Execute Change Set"))
+                    .clicked()
+                {
+                    decision = Some(ReviewDecision::Execute);
+                }
+                if ui
+                    .button("Discard")
+                    .on_hover_text("Delete the change set and go back to editing")
+                    .clicked()
+                {
+                    decision = Some(ReviewDecision::Discard);
+                }
+                if busy {
+                    ui.spinner();
+                }
+            });
+        });
+        decision
+    }
+}
+
+/// Table of planned resource changes
+fn render_changes(ui: &mut Ui, plan: &ChangeSetPlan) {
+    egui::ScrollArea::both()
+        .id_salt(("change_set_changes", &plan.change_set_id))
+        .max_height(320.0)
+        .auto_shrink([false, true])
+        .show(ui, |ui| {
+            egui::Grid::new(("change_set_changes_grid", &plan.change_set_id))
+                .num_columns(5)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.strong("Action");
+                    ui.strong("Logical ID");
+                    ui.strong("Type");
+                    ui.strong("Replacement");
+                    ui.strong("Changed");
+                    ui.end_row();
+
+                    for change in &plan.changes {
+                        ui.colored_label(
+                            action_color(ui, &change.action),
+                            format!("{} {}", change.action.marker(), change.action.label()),
+                        );
+                        let id_label = ui.label(&change.logical_id);
+                        if let Some(physical_id) = &change.physical_id {
+                            id_label.on_hover_text(physical_id);
+                        }
+                        ui.label(&change.resource_type);
+                        match change.replacement {
+                            Some(Replacement::Always) => {
+                                ui.colored_label(ui.visuals().error_fg_color, "Yes");
+                            }
+                            Some(Replacement::Conditional) => {
+                                ui.colored_label(ui.visuals().warn_fg_color, "Conditional");
+                            }
+                            Some(Replacement::Never) => {
+                                ui.label("No");
+                            }
+                            None => {
+                                ui.label("");
+                            }
+                        }
+                        ui.vertical(|ui| {
+                            for detail in &change.details {
+                                let mut text = detail.target();
+                                if let Some(recreation) = &detail.requires_recreation {
+                                    if recreation != "Never" {
+                                        text.push_str(&format!(" (recreate: {})", recreation));
+                                    }
+                                }
+                                let label = ui.label(RichText::new(text).small());
+                                if let Some(entity) = &detail.causing_entity {
+                                    label.on_hover_text(format!("Caused by {}", entity));
+                                }
+                            }
+                        });
+                        ui.end_row();
+                    }
+                });
+        });
+}
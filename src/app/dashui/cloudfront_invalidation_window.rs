@@ -0,0 +1,591 @@
+//! CloudFront Invalidation Window
+//!
+//! Clears paths from a distribution's edge caches and lists its recent
+//! invalidations. Invalidations created here are polled until CloudFront
+//! reports them complete, even after the window is closed, and a notification
+//! is raised when each one finishes. Creation is hidden in the read-only build
+//! and recorded in the resource actions audit log.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::{format_time, spawn_with_client};
+use crate::app::data_plane::cloudfront_invalidations::{
+    parse_paths, CloudFrontInvalidationsClient, Invalidation, MAX_WILDCARD_PATHS,
+};
+use crate::app::notifications::Notification;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use crate::app::resource_explorer::resource_actions::{ActionAuditEntry, ActionAuditLog};
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How often invalidations in progress are checked
+const PROGRESS_POLL_INTERVAL: Duration = Duration::from_secs(20);
+
+/// Parameters for showing the invalidation window
+#[derive(Clone)]
+pub struct CloudFrontInvalidationShowParams {
+    pub distribution_id: String,
+    pub distribution_name: String,
+    pub account_id: String,
+}
+
+/// Results from background threads
+enum InvalidationEvent {
+    ListLoaded(Result<Vec<Invalidation>, String>),
+    /// A selected or tracked invalidation was read
+    InvalidationLoaded(Result<Invalidation, String>),
+    Created(Result<Invalidation, String>),
+}
+
+pub struct CloudFrontInvalidationWindow {
+    pub open: bool,
+    // Display parameters
+    distribution_id: String,
+    distribution_name: String,
+    account_id: String,
+
+    // Recent invalidations
+    invalidations: Vec<Invalidation>,
+    loading: bool,
+    error_message: Option<String>,
+    selected: Option<String>,
+
+    // New invalidation
+    paths_input: String,
+    creating: bool,
+    create_result: Option<Result<String, String>>,
+
+    // Progress of invalidations created here
+    tracked: Vec<String>,
+    next_progress_poll: Option<Instant>,
+    pending_notifications: Vec<Notification>,
+
+    // Services
+    client: Arc<CloudFrontInvalidationsClient>,
+
+    // Channel for results from background threads.
+    // Replaced when the window opens on a distribution so results for another one are dropped.
+    sender: mpsc::Sender<InvalidationEvent>,
+    receiver: mpsc::Receiver<InvalidationEvent>,
+}
+
+impl CloudFrontInvalidationWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            distribution_id: String::new(),
+            distribution_name: String::new(),
+            account_id: String::new(),
+            invalidations: Vec::new(),
+            loading: false,
+            error_message: None,
+            selected: None,
+            paths_input: String::new(),
+            creating: false,
+            create_result: None,
+            tracked: Vec::new(),
+            next_progress_poll: None,
+            pending_notifications: Vec::new(),
+            client: Arc::new(CloudFrontInvalidationsClient::new(credential_coordinator)),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on a distribution and load its recent invalidations
+    ///
+    /// Reopening the same distribution keeps tracking its invalidations.
+    pub fn open_for_distribution(&mut self, params: CloudFrontInvalidationShowParams) {
+        if !self.targets(&params.distribution_id, &params.account_id) {
+            let (sender, receiver) = mpsc::channel();
+            self.sender = sender;
+            self.receiver = receiver;
+            self.invalidations.clear();
+            self.selected = None;
+            self.paths_input.clear();
+            self.creating = false;
+            self.create_result = None;
+            self.tracked.clear();
+            self.next_progress_poll = None;
+        }
+        self.distribution_id = params.distribution_id;
+        self.distribution_name = params.distribution_name;
+        self.account_id = params.account_id;
+        self.open = true;
+        self.load_invalidations();
+    }
+
+    /// Whether this window shows the given distribution
+    pub fn targets(&self, distribution_id: &str, account_id: &str) -> bool {
+        self.distribution_id == distribution_id && self.account_id == account_id
+    }
+
+    /// Whether invalidations created here are still in progress
+    pub fn is_tracking(&self) -> bool {
+        !self.tracked.is_empty()
+    }
+
+    /// Notifications raised since the last call (finished invalidations)
+    pub fn take_notifications(&mut self) -> Vec<Notification> {
+        std::mem::take(&mut self.pending_notifications)
+    }
+
+    fn load_invalidations(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+        let (account_id, distribution_id) = (self.account_id.clone(), self.distribution_id.clone());
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .list_invalidations(&account_id, &distribution_id)
+                .await;
+            if let Err(e) = &result {
+                log::error!("Failed to list invalidations of {}: {}", distribution_id, e);
+            }
+            InvalidationEvent::ListLoaded(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    fn load_invalidation(&self, invalidation_id: String) {
+        let (account_id, distribution_id) = (self.account_id.clone(), self.distribution_id.clone());
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .get_invalidation(&account_id, &distribution_id, &invalidation_id)
+                .await;
+            if let Err(e) = &result {
+                log::warn!("Failed to read invalidation {}: {}", invalidation_id, e);
+            }
+            InvalidationEvent::InvalidationLoaded(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    fn create_invalidation(&mut self, paths: Vec<String>) {
+        self.creating = true;
+        self.create_result = None;
+        let (account_id, distribution_id) = (self.account_id.clone(), self.distribution_id.clone());
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .create_invalidation(&account_id, &distribution_id, &paths)
+                .await
+                .map_err(|e| format!("{:#}", e));
+
+            let entry = ActionAuditEntry {
+                timestamp: chrono::Utc::now(),
+                action_id: "cloudfront.create_invalidation".to_string(),
+                account_id,
+                region: "Global".to_string(),
+                resource_type: "AWS::CloudFront::Distribution".to_string(),
+                resource_id: distribution_id,
+                success: result.is_ok(),
+                message: match &result {
+                    Ok(invalidation) => {
+                        format!("{}: {}", invalidation.id, paths.join(", "))
+                    }
+                    Err(message) => message.clone(),
+                },
+            };
+            match ActionAuditLog::new() {
+                Ok(audit_log) => {
+                    if let Err(e) = audit_log.record(&entry) {
+                        tracing::warn!("Failed to write invalidation audit entry: {:#}", e);
+                    }
+                }
+                Err(e) => tracing::warn!("Invalidation audit log unavailable: {:#}", e),
+            }
+            InvalidationEvent::Created(result)
+        });
+    }
+
+    /// Poll for results from background threads and check tracked
+    /// invalidations; runs every frame, also while the window is closed
+    pub fn poll(&mut self, ctx: &Context) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                InvalidationEvent::ListLoaded(Ok(invalidations)) => {
+                    self.loading = false;
+                    // Keep paths already read; listed invalidations carry none
+                    let previous = std::mem::take(&mut self.invalidations);
+                    self.invalidations = invalidations
+                        .into_iter()
+                        .map(|mut invalidation| {
+                            if let Some(known) = previous.iter().find(|p| p.id == invalidation.id) {
+                                if invalidation.paths.is_empty() {
+                                    invalidation.paths = known.paths.clone();
+                                }
+                            }
+                            invalidation
+                        })
+                        .collect();
+                    let completed: Vec<Invalidation> = self
+                        .invalidations
+                        .iter()
+                        .filter(|i| i.is_complete() && self.tracked.contains(&i.id))
+                        .cloned()
+                        .collect();
+                    for invalidation in completed {
+                        self.update_invalidation(invalidation);
+                    }
+                }
+                InvalidationEvent::ListLoaded(Err(e)) => {
+                    self.loading = false;
+                    self.error_message = Some(e);
+                }
+                InvalidationEvent::InvalidationLoaded(Ok(invalidation)) => {
+                    self.update_invalidation(invalidation);
+                }
+                InvalidationEvent::InvalidationLoaded(Err(e)) => {
+                    self.error_message = Some(e);
+                }
+                InvalidationEvent::Created(Ok(invalidation)) => {
+                    self.creating = false;
+                    self.create_result = Some(Ok(format!(
+                        "Invalidation {} started for {} path(s)",
+                        invalidation.id,
+                        invalidation.paths.len()
+                    )));
+                    self.paths_input.clear();
+                    self.selected = Some(invalidation.id.clone());
+                    if !invalidation.is_complete() {
+                        self.tracked.push(invalidation.id.clone());
+                        self.next_progress_poll = Some(Instant::now() + PROGRESS_POLL_INTERVAL);
+                    }
+                    self.update_invalidation(invalidation);
+                }
+                InvalidationEvent::Created(Err(e)) => {
+                    self.creating = false;
+                    self.create_result = Some(Err(e));
+                }
+            }
+        }
+
+        if let Some(due) = self.next_progress_poll {
+            let now = Instant::now();
+            if now >= due {
+                for invalidation_id in self.tracked.clone() {
+                    self.load_invalidation(invalidation_id);
+                }
+                self.next_progress_poll = Some(now + PROGRESS_POLL_INTERVAL);
+                ctx.request_repaint_after(PROGRESS_POLL_INTERVAL);
+            } else {
+                ctx.request_repaint_after(due - now);
+            }
+        }
+    }
+
+    /// Replace an invalidation in the list, notifying when a tracked one completed
+    fn update_invalidation(&mut self, invalidation: Invalidation) {
+        if invalidation.is_complete() && self.tracked.contains(&invalidation.id) {
+            self.tracked.retain(|id| id != &invalidation.id);
+            if self.tracked.is_empty() {
+                self.next_progress_poll = None;
+            }
+            self.pending_notifications.push(Notification::new_success(
+                format!("cloudfront_invalidation_{}", invalidation.id),
+                format!(
+                    "CloudFront invalidation of {} completed",
+                    self.distribution_name
+                ),
+                format!(
+                    "Invalidation {} finished; {} path(s) cleared from the edge caches",
+                    invalidation.id,
+                    invalidation.paths.len()
+                ),
+                "CloudFront".to_string(),
+            ));
+        }
+
+        match self
+            .invalidations
+            .iter_mut()
+            .find(|existing| existing.id == invalidation.id)
+        {
+            Some(existing) => *existing = invalidation,
+            None => self.invalidations.insert(0, invalidation),
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        // Request continuous repaint while waiting to show spinners
+        if self.loading || self.creating {
+            ctx.request_repaint();
+        }
+
+        let title = format!("CloudFront Invalidations: {}", self.distribution_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "cloudfront_invalidation_window",
+                &self.account_id,
+                &self.distribution_id,
+            )))
+            .open(&mut is_open)
+            .default_size([720.0, 520.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Distribution:").strong());
+            ui.label(&self.distribution_name);
+            ui.label(RichText::new(format!("{} {}", self.distribution_id, self.account_id)).weak());
+            if ui
+                .add_enabled(!self.loading, egui::Button::new("Refresh"))
+                .clicked()
+            {
+                self.load_invalidations();
+            }
+            if self.loading {
+                ui.spinner();
+            }
+        });
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error);
+        }
+        ui.separator();
+
+        if !crate::app::capabilities::READ_ONLY {
+            self.render_create(ui);
+            ui.separator();
+        }
+
+        ui.label(RichText::new("Recent invalidations").strong());
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| self.render_invalidations(ui));
+    }
+
+    fn render_create(&mut self, ui: &mut Ui) {
+        ui.label(RichText::new("New invalidation").strong());
+        ui.add(
+            egui::TextEdit::multiline(&mut self.paths_input)
+                .hint_text("/index.html\n/images/*")
+                .code_editor()
+                .desired_rows(4)
+                .desired_width(f32::INFINITY),
+        );
+        let parsed = parse_paths(&self.paths_input);
+        ui.horizontal(|ui| {
+            match &parsed {
+                Ok(paths) => {
+                    ui.label(
+                        RichText::new(format!(
+                            "{} path(s); end a path with * to match everything under it (at most {} wildcard paths in progress)",
+                            paths.len(),
+                            MAX_WILDCARD_PATHS
+                        ))
+                        .small()
+                        .weak(),
+                    );
+                }
+                Err(message) if !self.paths_input.trim().is_empty() => {
+                    ui.colored_label(ui.visuals().warn_fg_color, message);
+                }
+                Err(_) => {
+                    ui.label(
+                        RichText::new("One path per line, starting with /")
+                            .small()
+                            .weak(),
+                    );
+                }
+            }
+        });
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    parsed.is_ok() && !self.creating,
+                    egui::Button::new("Create Invalidation"),
+                )
+                .on_hover_text("Remove these paths from every edge cache of the distribution")
+                .clicked()
+            {
+                if let Ok(paths) = parsed {
+                    self.create_invalidation(paths);
+                }
+            }
+            if self.creating {
+                ui.spinner();
+            }
+            match &self.create_result {
+                Some(Ok(message)) => {
+                    ui.colored_label(Color32::from_rgb(100, 200, 100), message);
+                }
+                Some(Err(message)) => {
+                    ui.colored_label(Color32::from_rgb(255, 100, 100), message);
+                }
+                None => {}
+            }
+        });
+    }
+
+    fn render_invalidations(&mut self, ui: &mut Ui) {
+        if self.invalidations.is_empty() {
+            if !self.loading {
+                ui.label("No invalidations for this distribution");
+            }
+            return;
+        }
+
+        let mut clicked = None;
+        egui::Grid::new("cloudfront_invalidations_grid")
+            .num_columns(3)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("Invalidation");
+                ui.strong("Status");
+                ui.strong("Created");
+                ui.end_row();
+
+                for invalidation in &self.invalidations {
+                    let selected = self.selected.as_deref() == Some(invalidation.id.as_str());
+                    if ui
+                        .selectable_label(selected, &invalidation.id)
+                        .on_hover_text("Show the invalidated paths")
+                        .clicked()
+                    {
+                        clicked = Some(invalidation.id.clone());
+                    }
+                    ui.horizontal(|ui| {
+                        if invalidation.is_complete() {
+                            ui.label(&invalidation.status);
+                        } else {
+                            ui.spinner();
+                            ui.colored_label(ui.visuals().warn_fg_color, &invalidation.status);
+                        }
+                        if self.tracked.contains(&invalidation.id) {
+                            ui.label(RichText::new("(tracking)").small().weak())
+                                .on_hover_text("You will be notified when it completes");
+                        }
+                    });
+                    ui.label(
+                        invalidation
+                            .create_time
+                            .map(format_time)
+                            .unwrap_or_else(|| "-".to_string()),
+                    );
+                    ui.end_row();
+
+                    if selected && !invalidation.paths.is_empty() {
+                        ui.label("");
+                        ui.label(
+                            RichText::new(invalidation.paths.join("\n"))
+                                .monospace()
+                                .small(),
+                        );
+                        ui.end_row();
+                    }
+                }
+            });
+
+        if let Some(invalidation_id) = clicked {
+            if self.selected.as_ref() == Some(&invalidation_id) {
+                self.selected = None;
+            } else {
+                // Listed invalidations carry no paths; read them on selection
+                let needs_paths = self
+                    .invalidations
+                    .iter()
+                    .any(|i| i.id == invalidation_id && i.paths.is_empty());
+                if needs_paths {
+                    self.load_invalidation(invalidation_id.clone());
+                }
+                self.selected = Some(invalidation_id);
+            }
+        }
+    }
+}
+
+impl FocusableWindow for CloudFrontInvalidationWindow {
+    type ShowParams = CloudFrontInvalidationShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "cloudfront_invalidation_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("CloudFront Invalidations: {}", self.distribution_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the distribution first
+        self.open_for_distribution(params);
+
+        // Then show with focus
+        CloudFrontInvalidationWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalidation(id: &str, status: &str) -> Invalidation {
+        Invalidation {
+            id: id.to_string(),
+            status: status.to_string(),
+            create_time: Some(1_000),
+            paths: vec!["/*".to_string()],
+            caller_reference: None,
+        }
+    }
+
+    #[test]
+    fn test_tracked_invalidation_completion_notifies() {
+        let mut window =
+            CloudFrontInvalidationWindow::new(Arc::new(CredentialCoordinator::new_mock()));
+        window.distribution_name = "cdn.example.com".to_string();
+        window.tracked.push("I1".to_string());
+        window.next_progress_poll = Some(Instant::now());
+
+        window.update_invalidation(invalidation("I1", "InProgress"));
+        assert!(window.is_tracking());
+        assert!(window.take_notifications().is_empty());
+
+        // Completion of an untracked invalidation is only listed
+        window.update_invalidation(invalidation("I2", "Completed"));
+        assert!(window.take_notifications().is_empty());
+
+        window.update_invalidation(invalidation("I1", "Completed"));
+        assert!(!window.is_tracking());
+        assert!(window.next_progress_poll.is_none());
+        assert_eq!(window.take_notifications().len(), 1);
+        assert_eq!(window.invalidations.len(), 2);
+        assert!(window.invalidations.iter().all(Invalidation::is_complete));
+    }
+}
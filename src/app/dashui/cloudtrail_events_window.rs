@@ -1,9 +1,12 @@
 //! CloudTrail Events Viewer Window
 //!
-//! Displays CloudTrail events for AWS resources with search filtering.
+//! Displays CloudTrail events for AWS resources with search filtering and a
+//! timeline of read, write, and failed calls whose range selection filters
+//! the event list.
 
 #![warn(clippy::all, rust_2018_idioms)]
 
+use super::cloudtrail_timeline::EventTimeline;
 use super::window_focus::FocusableWindow;
 use crate::app::data_plane::cloudtrail_events::{
     CloudTrailEvent, CloudTrailEventsClient, LookupResult,
@@ -60,6 +63,8 @@ pub struct CloudTrailEventsWindow {
     loading: bool,
     error_message: Option<String>,
     selected_event: Option<usize>,
+    show_timeline: bool,
+    timeline: EventTimeline,
 
     // Services
     client: Arc<CloudTrailEventsClient>,
@@ -68,6 +73,9 @@ pub struct CloudTrailEventsWindow {
     // Channel for receiving results from background thread
     receiver: mpsc::Receiver<LoadResult>,
     sender: mpsc::Sender<LoadResult>,
+
+    /// Tile the window is pinned to while docked, set by the app each frame
+    pub dock_rect: Option<egui::Rect>,
 }
 
 impl CloudTrailEventsWindow {
@@ -87,10 +95,13 @@ impl CloudTrailEventsWindow {
             loading: false,
             error_message: None,
             selected_event: None,
+            show_timeline: true,
+            timeline: EventTimeline::default(),
             client: Arc::new(CloudTrailEventsClient::new(credential_coordinator)),
             fuzzy_matcher: SkimMatcherV2::default(),
             receiver,
             sender,
+            dock_rect: None,
         }
     }
 
@@ -104,6 +115,7 @@ impl CloudTrailEventsWindow {
         self.search_filter.clear();
         self.error_message = None;
         self.selected_event = None;
+        self.timeline.clear_selection();
         self.open = true;
 
         // Load initial data
@@ -248,6 +260,7 @@ impl CloudTrailEventsWindow {
         if bring_to_front {
             window = window.current_pos([100.0, 100.0]);
         }
+        let window = super::dock_layout::dock_window(window, self.dock_rect);
 
         window.show(ctx, |ui| {
             self.render_ui(ui);
@@ -294,6 +307,9 @@ impl CloudTrailEventsWindow {
             if ui.button("Clear").clicked() {
                 self.search_filter.clear();
             }
+
+            ui.separator();
+            ui.checkbox(&mut self.show_timeline, "Timeline");
         });
 
         ui.separator();
@@ -312,7 +328,7 @@ impl CloudTrailEventsWindow {
         }
 
         // Filter events based on search
-        let filtered_events: Vec<_> = if self.search_filter.is_empty() {
+        let search_matches: Vec<_> = if self.search_filter.is_empty() {
             self.events.iter().enumerate().collect()
         } else {
             self.events
@@ -331,6 +347,18 @@ impl CloudTrailEventsWindow {
                 .collect()
         };
 
+        // Timeline of the search matches; its range selection narrows the list
+        if self.show_timeline && !self.events.is_empty() {
+            let timeline_events: Vec<_> = search_matches.iter().map(|(_, event)| *event).collect();
+            self.timeline.show(ui, &timeline_events);
+            ui.separator();
+        }
+        let time_range = self.timeline.selection();
+        let filtered_events: Vec<_> = search_matches
+            .into_iter()
+            .filter(|(_, event)| time_range.map_or(true, |range| range.contains(event.event_time)))
+            .collect();
+
         // Events display with scrolling
         egui::ScrollArea::vertical()
             .max_height(ui.available_height() - 60.0)
@@ -485,7 +513,7 @@ impl CloudTrailEventsWindow {
             let showing = filtered_events.len().min(MAX_DISPLAY_EVENTS);
             let total = self.events.len();
 
-            if self.search_filter.is_empty() {
+            if self.search_filter.is_empty() && time_range.is_none() {
                 ui.label(format!("Showing {} events", showing));
             } else {
                 ui.label(format!(
@@ -0,0 +1,464 @@
+//! CloudTrail Event Timeline
+//!
+//! Histogram of CloudTrail events bucketed by hour or day, stacked by read,
+//! write, and failed calls. Dragging across the chart (or clicking a bar)
+//! selects a time range that the events window uses to filter its list.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use crate::app::data_plane::cloudtrail_events::CloudTrailEvent;
+use chrono::DateTime;
+use eframe::egui;
+use egui::{Color32, Rect, RichText, Sense, Stroke, Ui, Vec2};
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+const DAY_MS: i64 = 24 * HOUR_MS;
+
+/// Hourly buckets are used automatically for spans up to this long
+const AUTO_HOURLY_SPAN_MS: i64 = 3 * DAY_MS;
+
+/// Fall back to daily buckets when hourly ones would be thinner than this allows
+const MAX_BUCKETS: i64 = 500;
+
+const CHART_HEIGHT: f32 = 110.0;
+
+pub const READ_COLOR: Color32 = Color32::from_rgb(100, 150, 250);
+pub const WRITE_COLOR: Color32 = Color32::from_rgb(230, 160, 60);
+pub const ERROR_COLOR: Color32 = Color32::from_rgb(255, 100, 100);
+
+/// Width of one histogram bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BucketSize {
+    Hour,
+    Day,
+}
+
+impl BucketSize {
+    pub fn millis(&self) -> i64 {
+        match self {
+            BucketSize::Hour => HOUR_MS,
+            BucketSize::Day => DAY_MS,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            BucketSize::Hour => "Hour",
+            BucketSize::Day => "Day",
+        }
+    }
+
+    /// Hourly for short spans, daily otherwise
+    pub fn auto(span_ms: i64) -> Self {
+        if span_ms <= AUTO_HOURLY_SPAN_MS {
+            BucketSize::Hour
+        } else {
+            BucketSize::Day
+        }
+    }
+
+    fn time_format(&self) -> &'static str {
+        match self {
+            BucketSize::Hour => "%Y-%m-%d %H:00",
+            BucketSize::Day => "%Y-%m-%d",
+        }
+    }
+}
+
+/// How a call is colored in the timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventCategory {
+    Read,
+    Write,
+    Error,
+}
+
+impl EventCategory {
+    /// Failed calls are errors; calls not marked read-only count as writes
+    pub fn of(event: &CloudTrailEvent) -> Self {
+        if event.error_code.is_some() {
+            EventCategory::Error
+        } else if event.read_only.as_deref() == Some("true") {
+            EventCategory::Read
+        } else {
+            EventCategory::Write
+        }
+    }
+}
+
+/// Event counts for one bucket starting at `start` (Unix milliseconds)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TimelineBucket {
+    pub start: i64,
+    pub read: usize,
+    pub write: usize,
+    pub error: usize,
+}
+
+impl TimelineBucket {
+    pub fn total(&self) -> usize {
+        self.read + self.write + self.error
+    }
+}
+
+/// Half-open time range `[start, end)` in Unix milliseconds
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl TimeRange {
+    pub fn contains(&self, time: i64) -> bool {
+        time >= self.start && time < self.end
+    }
+}
+
+/// Count events into contiguous buckets aligned to UTC hours or days
+///
+/// Buckets run from the earliest to the latest event, including empty ones,
+/// so gaps in activity are visible.
+pub fn bucket_events<'a>(
+    events: impl IntoIterator<Item = &'a CloudTrailEvent>,
+    size: BucketSize,
+) -> Vec<TimelineBucket> {
+    let millis = size.millis();
+    let keyed: Vec<(i64, EventCategory)> = events
+        .into_iter()
+        .map(|event| {
+            (
+                event.event_time.div_euclid(millis),
+                EventCategory::of(event),
+            )
+        })
+        .collect();
+    let (Some(first), Some(last)) = (
+        keyed.iter().map(|(index, _)| *index).min(),
+        keyed.iter().map(|(index, _)| *index).max(),
+    ) else {
+        return Vec::new();
+    };
+
+    let mut buckets: Vec<TimelineBucket> = (first..=last)
+        .map(|index| TimelineBucket {
+            start: index * millis,
+            ..Default::default()
+        })
+        .collect();
+    for (index, category) in keyed {
+        let bucket = &mut buckets[(index - first) as usize];
+        match category {
+            EventCategory::Read => bucket.read += 1,
+            EventCategory::Write => bucket.write += 1,
+            EventCategory::Error => bucket.error += 1,
+        }
+    }
+    buckets
+}
+
+/// Bucket size for the events' span, unless one was chosen
+fn effective_bucket_size(chosen: Option<BucketSize>, events: &[&CloudTrailEvent]) -> BucketSize {
+    let span = match (
+        events.iter().map(|e| e.event_time).min(),
+        events.iter().map(|e| e.event_time).max(),
+    ) {
+        (Some(min), Some(max)) => max - min,
+        _ => 0,
+    };
+    match chosen {
+        Some(BucketSize::Hour) if span / HOUR_MS >= MAX_BUCKETS => BucketSize::Day,
+        Some(size) => size,
+        None => BucketSize::auto(span),
+    }
+}
+
+/// Timeline state kept by the events window
+#[derive(Default)]
+pub struct EventTimeline {
+    /// Chosen bucket size; automatic when None
+    bucket_size: Option<BucketSize>,
+    selection: Option<TimeRange>,
+    /// Pointer x where the current drag started
+    drag_origin: Option<f32>,
+}
+
+impl EventTimeline {
+    /// Selected time range, if any
+    pub fn selection(&self) -> Option<TimeRange> {
+        self.selection
+    }
+
+    pub fn clear_selection(&mut self) {
+        self.selection = None;
+        self.drag_origin = None;
+    }
+
+    /// Render bucket controls, legend, and the histogram for `events`
+    pub fn show(&mut self, ui: &mut Ui, events: &[&CloudTrailEvent]) {
+        let size = effective_bucket_size(self.bucket_size, events);
+        let buckets = bucket_events(events.iter().copied(), size);
+
+        ui.horizontal(|ui| {
+            ui.label("Bucket:");
+            ui.radio_value(&mut self.bucket_size, None, "Auto");
+            for option in [BucketSize::Hour, BucketSize::Day] {
+                ui.radio_value(&mut self.bucket_size, Some(option), option.label());
+            }
+            ui.separator();
+
+            let (read, write, error) = buckets.iter().fold((0, 0, 0), |(r, w, e), b| {
+                (r + b.read, w + b.write, e + b.error)
+            });
+            ui.colored_label(READ_COLOR, format!("Read {}", read));
+            ui.colored_label(WRITE_COLOR, format!("Write {}", write));
+            ui.colored_label(ERROR_COLOR, format!("Error {}", error));
+
+            if let Some(range) = self.selection {
+                ui.separator();
+                ui.label(RichText::new(format_range(range, size)).strong());
+                if ui.small_button("Clear Range").clicked() {
+                    self.clear_selection();
+                }
+            }
+        });
+
+        let (rect, response) = ui.allocate_exact_size(
+            Vec2::new(ui.available_width(), CHART_HEIGHT),
+            Sense::click_and_drag(),
+        );
+        let painter = ui.painter_at(rect);
+        let visuals = ui.visuals();
+        painter.rect_filled(rect, 2.0, visuals.extreme_bg_color);
+
+        if buckets.is_empty() {
+            painter.text(
+                rect.center(),
+                egui::Align2::CENTER_CENTER,
+                "No events",
+                egui::FontId::proportional(12.0),
+                visuals.weak_text_color(),
+            );
+            return;
+        }
+
+        let plot = rect.shrink2(Vec2::new(4.0, 6.0));
+        let bar_width = plot.width() / buckets.len() as f32;
+        let max_total = buckets.iter().map(|b| b.total()).max().unwrap_or(1).max(1) as f32;
+        let bucket_at = |x: f32| -> usize {
+            (((x - plot.left()) / bar_width).floor().max(0.0) as usize).min(buckets.len() - 1)
+        };
+        let bucket_rect = |index: usize| -> Rect {
+            let left = plot.left() + bar_width * index as f32;
+            Rect::from_x_y_ranges(left..=left + bar_width, plot.y_range())
+        };
+
+        // Selected range behind the bars
+        if let Some(range) = self.selection {
+            for (index, bucket) in buckets.iter().enumerate() {
+                if range.contains(bucket.start) {
+                    painter.rect_filled(
+                        bucket_rect(index),
+                        0.0,
+                        visuals.selection.bg_fill.gamma_multiply(0.4),
+                    );
+                }
+            }
+        }
+
+        // Stacked bars: read at the bottom, then write, then errors
+        let gap = if bar_width > 4.0 { 1.0 } else { 0.0 };
+        for (index, bucket) in buckets.iter().enumerate() {
+            let column = bucket_rect(index);
+            let mut bottom = plot.bottom();
+            for (count, color) in [
+                (bucket.read, READ_COLOR),
+                (bucket.write, WRITE_COLOR),
+                (bucket.error, ERROR_COLOR),
+            ] {
+                if count == 0 {
+                    continue;
+                }
+                let height = plot.height() * count as f32 / max_total;
+                let segment = Rect::from_x_y_ranges(
+                    column.left() + gap..=column.right() - gap,
+                    bottom - height..=bottom,
+                );
+                painter.rect_filled(segment, 0.0, color);
+                bottom -= height;
+            }
+        }
+
+        // Brush: drag across buckets, or click one
+        if response.drag_started() {
+            self.drag_origin = response.interact_pointer_pos().map(|pos| pos.x);
+        }
+        if let (Some(origin), Some(pos)) = (self.drag_origin, response.interact_pointer_pos()) {
+            if response.dragged() {
+                let brush =
+                    Rect::from_x_y_ranges(origin.min(pos.x)..=origin.max(pos.x), plot.y_range());
+                painter.rect_filled(brush, 0.0, visuals.selection.bg_fill.gamma_multiply(0.3));
+                painter.rect_stroke(
+                    brush,
+                    0.0,
+                    Stroke::new(1.0, visuals.selection.stroke.color),
+                    egui::StrokeKind::Inside,
+                );
+            }
+            if response.drag_stopped() {
+                let (first, last) = (bucket_at(origin.min(pos.x)), bucket_at(origin.max(pos.x)));
+                self.selection = Some(TimeRange {
+                    start: buckets[first].start,
+                    end: buckets[last].start + size.millis(),
+                });
+                self.drag_origin = None;
+            }
+        }
+        if response.clicked() {
+            if let Some(pos) = response.interact_pointer_pos() {
+                let bucket = buckets[bucket_at(pos.x)];
+                let range = TimeRange {
+                    start: bucket.start,
+                    end: bucket.start + size.millis(),
+                };
+                // Clicking the selected bucket again clears the selection
+                self.selection = (self.selection != Some(range)).then_some(range);
+            }
+        }
+
+        if let Some(pos) = response.hover_pos() {
+            let bucket = buckets[bucket_at(pos.x)];
+            painter.rect_stroke(
+                bucket_rect(bucket_at(pos.x)),
+                0.0,
+                Stroke::new(1.0, visuals.weak_text_color()),
+                egui::StrokeKind::Inside,
+            );
+            response.on_hover_text_at_pointer(format!(
+                "{} UTC\n{} events: {} read, {} write, {} error\nDrag to select a range",
+                format_time(bucket.start, size),
+                bucket.total(),
+                bucket.read,
+                bucket.write,
+                bucket.error
+            ));
+        }
+
+        // Axis labels for the first and last bucket
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(format_time(buckets[0].start, size))
+                    .small()
+                    .weak(),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                let last = buckets[buckets.len() - 1];
+                ui.label(RichText::new(format_time(last.start, size)).small().weak());
+            });
+        });
+    }
+}
+
+fn format_time(millis: i64, size: BucketSize) -> String {
+    DateTime::from_timestamp_millis(millis)
+        .map(|t| t.format(size.time_format()).to_string())
+        .unwrap_or_default()
+}
+
+fn format_range(range: TimeRange, size: BucketSize) -> String {
+    format!(
+        "{} - {} UTC",
+        format_time(range.start, size),
+        format_time(range.end, size)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(
+        event_time: i64,
+        read_only: Option<&str>,
+        error_code: Option<&str>,
+    ) -> CloudTrailEvent {
+        CloudTrailEvent {
+            event_id: event_time.to_string(),
+            event_name: "DescribeInstances".to_string(),
+            event_time,
+            event_source: "ec2.amazonaws.com".to_string(),
+            username: "admin".to_string(),
+            resources: Vec::new(),
+            cloud_trail_event: None,
+            access_key_id: None,
+            read_only: read_only.map(str::to_string),
+            error_code: error_code.map(str::to_string),
+            error_message: None,
+        }
+    }
+
+    #[test]
+    fn test_event_category() {
+        assert_eq!(
+            EventCategory::of(&event(0, Some("true"), None)),
+            EventCategory::Read
+        );
+        assert_eq!(
+            EventCategory::of(&event(0, Some("false"), None)),
+            EventCategory::Write
+        );
+        assert_eq!(
+            EventCategory::of(&event(0, None, None)),
+            EventCategory::Write
+        );
+        assert_eq!(
+            EventCategory::of(&event(0, Some("true"), Some("AccessDenied"))),
+            EventCategory::Error
+        );
+    }
+
+    #[test]
+    fn test_bucket_events_fills_gaps() {
+        let base = 1_700_000_000_000 / HOUR_MS * HOUR_MS;
+        let events = vec![
+            event(base + 5 * 60 * 1000, Some("true"), None),
+            event(base + 10 * 60 * 1000, Some("false"), None),
+            event(base + 3 * HOUR_MS + 1, Some("true"), Some("Throttling")),
+        ];
+
+        let buckets = bucket_events(&events, BucketSize::Hour);
+        assert_eq!(buckets.len(), 4);
+        assert_eq!(buckets[0].start, base);
+        assert_eq!(
+            (buckets[0].read, buckets[0].write, buckets[0].error),
+            (1, 1, 0)
+        );
+        assert_eq!(buckets[1].total(), 0);
+        assert_eq!(buckets[3].start, base + 3 * HOUR_MS);
+        assert_eq!(buckets[3].error, 1);
+
+        let daily = bucket_events(&events, BucketSize::Day);
+        assert!(daily.len() <= 2);
+        assert_eq!(daily.iter().map(|b| b.total()).sum::<usize>(), 3);
+
+        assert!(bucket_events(&Vec::new(), BucketSize::Hour).is_empty());
+    }
+
+    #[test]
+    fn test_bucket_size_selection() {
+        assert_eq!(BucketSize::auto(2 * DAY_MS), BucketSize::Hour);
+        assert_eq!(BucketSize::auto(30 * DAY_MS), BucketSize::Day);
+
+        let events = [event(0, None, None), event(90 * DAY_MS, None, None)];
+        let refs: Vec<&CloudTrailEvent> = events.iter().collect();
+        // Hourly buckets over 90 days would be too many
+        assert_eq!(
+            effective_bucket_size(Some(BucketSize::Hour), &refs),
+            BucketSize::Day
+        );
+        assert_eq!(effective_bucket_size(None, &refs[..1]), BucketSize::Hour);
+
+        let range = TimeRange { start: 10, end: 20 };
+        assert!(range.contains(10));
+        assert!(!range.contains(20));
+    }
+}
@@ -1,12 +1,22 @@
 //! CloudWatch Logs Viewer Window
 //!
 //! Displays CloudWatch Logs for AWS resources with fuzzy search filtering.
+//! Tail mode polls for new events every few seconds, follows them at the
+//! bottom of the list, and highlights error and warning lines. The filtered
+//! events can be exported to JSON, NDJSON, or CSV with sensitive values
+//! redacted.
 
 #![warn(clippy::all, rust_2018_idioms)]
 
+use super::command_registry::{CommandCategory, PaletteCommand};
 use super::window_focus::FocusableWindow;
-use crate::app::data_plane::cloudwatch_logs::{CloudWatchLogsClient, LogEvent, LogQueryResult};
+use crate::app::data_plane::cloudwatch_logs::{
+    default_export_path, export_log_events, get_log_group_name, has_cloudwatch_logs,
+    CloudWatchLogsClient, LogEvent, LogExportFormat, LogLevel, LogQueryResult, RedactionRule,
+    Redactor, TailCursor,
+};
 use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use crate::app::resource_explorer::state::ResourceEntry;
 use chrono::{DateTime, Utc};
 use eframe::egui;
 use egui::{Color32, Context, RichText, Ui};
@@ -14,10 +24,23 @@ use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 use std::sync::mpsc;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 /// Maximum number of log events to display in the UI
 const MAX_DISPLAY_EVENTS: usize = 1000;
 
+/// How often tail mode polls for new events
+const TAIL_POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Maximum events fetched by one tail poll
+const TAIL_POLL_MAX_EVENTS: usize = 500;
+
+/// Tail mode starts this far back when no events are loaded yet
+const TAIL_LOOKBACK_MS: i64 = 60_000;
+
+const ERROR_LINE_COLOR: Color32 = Color32::from_rgb(255, 100, 100);
+const WARN_LINE_COLOR: Color32 = Color32::from_rgb(230, 160, 60);
+
 /// Parameters for showing the CloudWatch Logs window
 #[derive(Clone)]
 pub struct CloudWatchLogsShowParams {
@@ -27,9 +50,118 @@ pub struct CloudWatchLogsShowParams {
     pub region: String,
 }
 
+impl CloudWatchLogsShowParams {
+    /// Parameters for a resource's log group, if its type has one
+    pub fn for_resource(resource: &ResourceEntry) -> Option<Self> {
+        if !has_cloudwatch_logs(&resource.resource_type) {
+            return None;
+        }
+        let log_group_name = get_log_group_name(
+            &resource.resource_type,
+            &resource.display_name,
+            Some(&resource.resource_id),
+        )?;
+        Some(Self {
+            log_group_name,
+            resource_name: resource.display_name.clone(),
+            account_id: resource.account_id.clone(),
+            region: resource.region.clone(),
+        })
+    }
+}
+
+pub const TAIL_SELECTED_LAMBDA: &str = "cloudwatch.tail_selected_lambda";
+pub const VIEW_SELECTED_LOGS: &str = "cloudwatch.view_selected_logs";
+pub const STOP_TAILING: &str = "cloudwatch.stop_tailing";
+pub const CLOSE_LOGS_WINDOWS: &str = "cloudwatch.close_logs_windows";
+
+/// Commands the CloudWatch Logs windows offer in the command palette
+pub fn palette_commands() -> Vec<PaletteCommand> {
+    vec![
+        PaletteCommand {
+            id: TAIL_SELECTED_LAMBDA,
+            label: "Tail logs for selected Lambda",
+            description: "Follow new log events of the Lambda function selected in the Explorer",
+            category: CommandCategory::CloudWatch,
+            keywords: &["follow", "live", "function", "logs"],
+            is_available: |context| context.has_selected("AWS::Lambda::Function"),
+        },
+        PaletteCommand {
+            id: VIEW_SELECTED_LOGS,
+            label: "View logs for selected resource",
+            description: "Open CloudWatch Logs for the resource selected in the Explorer",
+            category: CommandCategory::CloudWatch,
+            keywords: &["log group", "logs"],
+            is_available: |context| {
+                context
+                    .selected_resource_types
+                    .iter()
+                    .any(|resource_type| has_cloudwatch_logs(resource_type))
+            },
+        },
+        PaletteCommand {
+            id: STOP_TAILING,
+            label: "Stop tailing logs",
+            description: "Stop following new events in every CloudWatch Logs window",
+            category: CommandCategory::CloudWatch,
+            keywords: &["pause", "follow"],
+            is_available: |context| context.cloudwatch_logs_windows > 0,
+        },
+        PaletteCommand {
+            id: CLOSE_LOGS_WINDOWS,
+            label: "Close CloudWatch Logs windows",
+            description: "Close every open CloudWatch Logs window",
+            category: CommandCategory::CloudWatch,
+            keywords: &["dismiss"],
+            is_available: |context| context.cloudwatch_logs_windows > 0,
+        },
+    ]
+}
+
 /// Result from background log loading
 type LogLoadResult = Result<LogQueryResult, String>;
 
+/// Result from one tail poll
+type TailPollResult = Result<Vec<LogEvent>, String>;
+
+/// Live tail state for one window
+struct TailState {
+    cursor: TailCursor,
+    paused: bool,
+    /// A poll is running in the background
+    in_flight: bool,
+    last_poll: Option<Instant>,
+}
+
+/// Export dialog settings, kept while the window is open
+struct ExportOptions {
+    open: bool,
+    format: LogExportFormat,
+    redact: bool,
+    rules: Vec<RedactionRule>,
+    // Custom rule being added
+    new_name: String,
+    new_pattern: String,
+    new_replacement: String,
+    /// Outcome of the last export or rule edit
+    result: Option<Result<String, String>>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            open: false,
+            format: LogExportFormat::Json,
+            redact: true,
+            rules: RedactionRule::defaults(),
+            new_name: String::new(),
+            new_pattern: String::new(),
+            new_replacement: "<REDACTED>".to_string(),
+            result: None,
+        }
+    }
+}
+
 pub struct CloudWatchLogsWindow {
     pub open: bool,
     // Display parameters
@@ -43,6 +175,10 @@ pub struct CloudWatchLogsWindow {
     search_filter: String,
     loading: bool,
     error_message: Option<String>,
+    /// Live tail state; None when not tailing
+    tail: Option<TailState>,
+    auto_scroll: bool,
+    export: ExportOptions,
 
     // Services
     client: Arc<CloudWatchLogsClient>,
@@ -51,11 +187,16 @@ pub struct CloudWatchLogsWindow {
     // Channel for receiving log results from background thread
     log_receiver: mpsc::Receiver<LogLoadResult>,
     log_sender: mpsc::Sender<LogLoadResult>,
+
+    // Channel for receiving tail poll results
+    tail_receiver: mpsc::Receiver<TailPollResult>,
+    tail_sender: mpsc::Sender<TailPollResult>,
 }
 
 impl CloudWatchLogsWindow {
     pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
         let (log_sender, log_receiver) = mpsc::channel();
+        let (tail_sender, tail_receiver) = mpsc::channel();
 
         Self {
             open: false,
@@ -67,10 +208,15 @@ impl CloudWatchLogsWindow {
             search_filter: String::new(),
             loading: false,
             error_message: None,
+            tail: None,
+            auto_scroll: true,
+            export: ExportOptions::default(),
             client: Arc::new(CloudWatchLogsClient::new(credential_coordinator)),
             fuzzy_matcher: SkimMatcherV2::default(),
             log_receiver,
             log_sender,
+            tail_receiver,
+            tail_sender,
         }
     }
 
@@ -82,6 +228,7 @@ impl CloudWatchLogsWindow {
         self.region = params.region;
         self.search_filter.clear();
         self.error_message = None;
+        self.tail = None;
         self.open = true;
 
         // Start loading logs
@@ -131,6 +278,96 @@ impl CloudWatchLogsWindow {
         });
     }
 
+    /// Open the window for a resource and follow new events right away
+    pub fn open_tailing(&mut self, params: CloudWatchLogsShowParams) {
+        self.open_for_resource(params);
+        self.start_tail();
+    }
+
+    /// Stop following new events
+    pub fn stop_tail(&mut self) {
+        self.tail = None;
+    }
+
+    /// Start following new events from the newest one loaded
+    fn start_tail(&mut self) {
+        let cursor = TailCursor::after(&self.logs).unwrap_or_else(|| {
+            TailCursor::starting_at(Utc::now().timestamp_millis() - TAIL_LOOKBACK_MS)
+        });
+        // Tail mode lists events oldest first so new ones arrive at the bottom
+        self.logs.sort_by_key(|event| event.timestamp);
+        self.tail = Some(TailState {
+            cursor,
+            paused: false,
+            in_flight: false,
+            last_poll: None,
+        });
+    }
+
+    /// Fetch events newer than the cursor when the poll interval has passed
+    fn poll_tail(&mut self) {
+        let Some(tail) = &mut self.tail else {
+            return;
+        };
+        if tail.paused
+            || tail.in_flight
+            || tail
+                .last_poll
+                .is_some_and(|last| last.elapsed() < TAIL_POLL_INTERVAL)
+        {
+            return;
+        }
+        tail.in_flight = true;
+        tail.last_poll = Some(Instant::now());
+
+        let client = Arc::clone(&self.client);
+        let account_id = self.account_id.clone();
+        let region = self.region.clone();
+        let log_group_name = self.log_group_name.clone();
+        let start_time = tail.cursor.start_time();
+        let sender = self.tail_sender.clone();
+
+        // Create a new thread (since egui runs on a blocking thread) and run tokio inside it
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
+            let result = runtime
+                .block_on(client.tail_log_events(
+                    &account_id,
+                    &region,
+                    &log_group_name,
+                    start_time,
+                    TAIL_POLL_MAX_EVENTS,
+                ))
+                .map_err(|e| format!("{:#}", e));
+            let _ = sender.send(result);
+        });
+    }
+
+    /// Append new events from tail polls, keeping at most MAX_DISPLAY_EVENTS
+    fn poll_tail_results(&mut self) {
+        while let Ok(result) = self.tail_receiver.try_recv() {
+            // Results arriving after tailing stopped are dropped
+            let Some(tail) = &mut self.tail else {
+                continue;
+            };
+            tail.in_flight = false;
+            match result {
+                Ok(events) => {
+                    self.logs.extend(tail.cursor.accept(events));
+                    if self.logs.len() > MAX_DISPLAY_EVENTS {
+                        let excess = self.logs.len() - MAX_DISPLAY_EVENTS;
+                        self.logs.drain(..excess);
+                    }
+                    self.error_message = None;
+                }
+                Err(error) => {
+                    // Keep tailing; the next poll may succeed
+                    self.error_message = Some(error);
+                }
+            }
+        }
+    }
+
     /// Poll for log results from background thread
     fn poll_log_results(&mut self) {
         // Check for results from background thread
@@ -141,6 +378,10 @@ impl CloudWatchLogsWindow {
                 Ok(log_result) => {
                     self.logs = log_result.events;
                     self.error_message = None;
+                    // A refresh while tailing restarts from the reloaded events
+                    if self.tail.is_some() {
+                        self.start_tail();
+                    }
                 }
                 Err(error_msg) => {
                     self.error_message = Some(error_msg);
@@ -174,10 +415,15 @@ impl CloudWatchLogsWindow {
 
         // Poll for log results from background thread
         self.poll_log_results();
+        self.poll_tail_results();
+        self.poll_tail();
 
         // Request continuous repaint while loading to show spinner and update logs as they arrive
         if self.loading {
             ctx.request_repaint();
+        } else if self.tail.as_ref().is_some_and(|tail| !tail.paused) {
+            // Wake up for the next tail poll
+            ctx.request_repaint_after(Duration::from_millis(500));
         }
 
         let title = format!("CloudWatch Logs: {}", self.resource_name);
@@ -201,6 +447,10 @@ impl CloudWatchLogsWindow {
 
         // Update open state after window is shown
         self.open = is_open;
+
+        if self.open && self.export.open {
+            self.show_export_dialog(ctx);
+        }
     }
 
     fn ui_content(&mut self, ui: &mut Ui) {
@@ -225,6 +475,9 @@ impl CloudWatchLogsWindow {
             if ui.button("Refresh").clicked() {
                 self.refresh_logs();
             }
+
+            ui.separator();
+            self.render_tail_controls(ui);
         });
 
         ui.separator();
@@ -243,10 +496,12 @@ impl CloudWatchLogsWindow {
 
         // Log events display
         let available_height = ui.available_height() - 100.0; // Reserve space for footer
+        let follow = self.tail.is_some() && self.auto_scroll;
 
         egui::ScrollArea::vertical()
             .max_height(available_height)
             .auto_shrink([false, false])
+            .stick_to_bottom(follow)
             .show(ui, |ui| {
                 self.render_log_events(ui);
             });
@@ -256,18 +511,75 @@ impl CloudWatchLogsWindow {
         // Footer: Statistics
         let filtered_count = self.get_filtered_logs().len();
         let total_count = self.logs.len().min(MAX_DISPLAY_EVENTS);
-        ui.label(format!(
-            "Showing {} of {} events (latest)",
-            filtered_count, total_count
-        ));
+        ui.horizontal(|ui| {
+            ui.label(format!(
+                "Showing {} of {} events (latest)",
+                filtered_count, total_count
+            ));
+
+            let (errors, warnings) = self.logs.iter().fold((0, 0), |(errors, warnings), event| {
+                match LogLevel::detect(&event.message) {
+                    Some(LogLevel::Error) => (errors + 1, warnings),
+                    Some(LogLevel::Warn) => (errors, warnings + 1),
+                    None => (errors, warnings),
+                }
+            });
+            if errors > 0 {
+                ui.separator();
+                ui.colored_label(ERROR_LINE_COLOR, format!("{} errors", errors));
+            }
+            if warnings > 0 {
+                ui.separator();
+                ui.colored_label(WARN_LINE_COLOR, format!("{} warnings", warnings));
+            }
+        });
 
         ui.horizontal(|ui| {
-            if ui.button("Export to File").clicked() {
-                self.export_logs_to_file();
+            if ui
+                .button("Export to File...")
+                .on_hover_text("Export the filtered events to Downloads")
+                .clicked()
+            {
+                self.export.open = true;
+                self.export.result = None;
             }
         });
     }
 
+    /// Tail on/off, pause/resume, and auto-scroll controls
+    fn render_tail_controls(&mut self, ui: &mut Ui) {
+        match &mut self.tail {
+            None => {
+                if ui
+                    .button("Tail")
+                    .on_hover_text("Poll for new events every few seconds")
+                    .clicked()
+                {
+                    self.start_tail();
+                }
+            }
+            Some(tail) => {
+                if ui.button("Stop Tail").clicked() {
+                    self.tail = None;
+                    return;
+                }
+                let pause_label = if tail.paused { "Resume" } else { "Pause" };
+                if ui.button(pause_label).clicked() {
+                    tail.paused = !tail.paused;
+                    // Poll right away on resume
+                    tail.last_poll = None;
+                }
+                ui.checkbox(&mut self.auto_scroll, "Auto-scroll");
+                if tail.paused {
+                    ui.label(RichText::new("Paused").weak());
+                } else {
+                    ui.spinner();
+                    ui.label(RichText::new("Tailing").weak());
+                }
+            }
+        }
+    }
+
     fn render_log_events(&self, ui: &mut Ui) {
         if self.logs.is_empty() && !self.loading {
             ui.label(RichText::new("No log events available").italics());
@@ -305,12 +617,21 @@ impl CloudWatchLogsWindow {
 
         // Event message - try to format as JSON if possible
         let formatted_message = self.try_format_json(&event.message);
+        let level_color = match LogLevel::detect(&event.message) {
+            Some(LogLevel::Error) => Some(ERROR_LINE_COLOR),
+            Some(LogLevel::Warn) => Some(WARN_LINE_COLOR),
+            None => None,
+        };
 
         // Render message with search highlighting if filter is active
         if self.search_filter.is_empty() {
             // Render each line separately for proper formatting
             for line in formatted_message.lines() {
-                ui.label(RichText::new(line).monospace());
+                let text = RichText::new(line).monospace();
+                ui.label(match level_color {
+                    Some(color) => text.color(color),
+                    None => text,
+                });
             }
         } else {
             self.render_highlighted_text(ui, &formatted_message);
@@ -517,10 +838,139 @@ impl CloudWatchLogsWindow {
         }
     }
 
-    fn export_logs_to_file(&self) {
-        // TODO: Implement export to file functionality
-        // This would use a file picker dialog and write logs to a text file
-        log::info!("Export logs to file - not yet implemented");
+    /// Format, redaction rule, and custom rule controls for exporting
+    fn show_export_dialog(&mut self, ctx: &Context) {
+        let mut is_open = self.export.open;
+        let mut export_clicked = false;
+        let event_count = self.get_filtered_logs().len();
+        let options = &mut self.export;
+
+        egui::Window::new("Export Logs")
+            .id(egui::Id::new((
+                "cloudwatch_logs_export",
+                &self.log_group_name,
+            )))
+            .open(&mut is_open)
+            .default_width(420.0)
+            .resizable(true)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Format:");
+                    for format in LogExportFormat::all() {
+                        ui.radio_value(&mut options.format, format, format.label());
+                    }
+                });
+
+                ui.checkbox(&mut options.redact, "Redact sensitive values");
+                ui.add_enabled_ui(options.redact, |ui| {
+                    // Custom rules follow the built-in ones and can be removed
+                    let builtin_count = RedactionRule::defaults().len();
+                    let mut remove = None;
+                    for (index, rule) in options.rules.iter_mut().enumerate() {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut rule.enabled, rule.name.as_str())
+                                .on_hover_text(format!(
+                                    "{}  ->  {}",
+                                    rule.pattern, rule.replacement
+                                ));
+                            if index >= builtin_count && ui.small_button("Remove").clicked() {
+                                remove = Some(index);
+                            }
+                        });
+                    }
+                    if let Some(index) = remove {
+                        options.rules.remove(index);
+                    }
+
+                    egui::CollapsingHeader::new("Add custom rule").show(ui, |ui| {
+                        egui::Grid::new("cloudwatch_logs_export_rule")
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                ui.label("Name:");
+                                ui.text_edit_singleline(&mut options.new_name);
+                                ui.end_row();
+                                ui.label("Regex:");
+                                ui.text_edit_singleline(&mut options.new_pattern);
+                                ui.end_row();
+                                ui.label("Replace with:");
+                                ui.text_edit_singleline(&mut options.new_replacement);
+                                ui.end_row();
+                            });
+                        if ui
+                            .add_enabled(
+                                !options.new_pattern.is_empty(),
+                                egui::Button::new("Add Rule"),
+                            )
+                            .clicked()
+                        {
+                            let name = if options.new_name.trim().is_empty() {
+                                options.new_pattern.clone()
+                            } else {
+                                options.new_name.trim().to_string()
+                            };
+                            let rule = RedactionRule::new(
+                                &name,
+                                &options.new_pattern,
+                                &options.new_replacement,
+                            );
+                            match Redactor::new(std::slice::from_ref(&rule)) {
+                                Ok(_) => {
+                                    options.rules.push(rule);
+                                    options.new_name.clear();
+                                    options.new_pattern.clear();
+                                    options.result = None;
+                                }
+                                Err(e) => options.result = Some(Err(format!("{:#}", e))),
+                            }
+                        }
+                    });
+                });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(event_count > 0, egui::Button::new("Export"))
+                        .clicked()
+                    {
+                        export_clicked = true;
+                    }
+                    ui.label(format!("{} events", event_count));
+                });
+                match &options.result {
+                    Some(Ok(message)) => {
+                        ui.label(message);
+                    }
+                    Some(Err(error)) => {
+                        ui.colored_label(Color32::RED, format!("Error: {}", error));
+                    }
+                    None => {}
+                }
+            });
+
+        self.export.open = is_open;
+        if export_clicked {
+            self.export_logs_to_file();
+        }
+    }
+
+    /// Write the filtered events to Downloads in the chosen format
+    fn export_logs_to_file(&mut self) {
+        let format = self.export.format;
+        let path = default_export_path(&self.log_group_name, format);
+        let result = if self.export.redact {
+            Redactor::new(&self.export.rules).map(Some)
+        } else {
+            Ok(None)
+        }
+        .and_then(|redactor| {
+            export_log_events(&path, &self.get_filtered_logs(), format, redactor.as_ref())
+        });
+
+        self.export.result = Some(match result {
+            Ok(count) => Ok(format!("Exported {} events to {}", count, path.display())),
+            Err(e) => Err(format!("{:#}", e)),
+        });
     }
 }
 
@@ -570,4 +1020,36 @@ mod tests {
     fn test_max_display_events() {
         assert_eq!(MAX_DISPLAY_EVENTS, 1000);
     }
+
+    #[test]
+    fn test_show_params_for_lambda() {
+        let resource = ResourceEntry {
+            resource_type: "AWS::Lambda::Function".to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: "orders-handler".to_string(),
+            display_name: "orders-handler".to_string(),
+            status: None,
+            properties: serde_json::Value::Null,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: Color32::WHITE,
+            region_color: Color32::WHITE,
+            query_timestamp: Utc::now(),
+        };
+
+        let params = CloudWatchLogsShowParams::for_resource(&resource).unwrap();
+        assert_eq!(params.log_group_name, "/aws/lambda/orders-handler");
+        assert_eq!(params.account_id, "123456789012");
+
+        let bucket = ResourceEntry {
+            resource_type: "AWS::S3::Bucket".to_string(),
+            ..resource
+        };
+        assert!(CloudWatchLogsShowParams::for_resource(&bucket).is_none());
+    }
 }
@@ -0,0 +1,575 @@
+//! CloudWatch Metrics Viewer Window
+//!
+//! Displays the default CloudWatch Metrics for an AWS resource as sparkline charts.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::cloudwatch_metrics::{
+    CloudWatchMetricsClient, MetricQueryOptions, MetricQueryResult, MetricSeries,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use chrono::{DateTime, Utc};
+use eframe::egui;
+use egui::{Color32, Context, Pos2, Rect, RichText, Sense, Stroke, Ui, Vec2};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Size of each sparkline chart
+const SPARKLINE_SIZE: Vec2 = Vec2::new(320.0, 60.0);
+
+/// Parameters for showing the CloudWatch Metrics window
+#[derive(Clone)]
+pub struct CloudWatchMetricsShowParams {
+    pub resource_type: String,
+    pub resource_id: String,
+    pub resource_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Time window selectable in the viewer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricTimeRange {
+    OneHour,
+    ThreeHours,
+    TwelveHours,
+    OneDay,
+    SevenDays,
+}
+
+impl MetricTimeRange {
+    pub const ALL: [MetricTimeRange; 5] = [
+        MetricTimeRange::OneHour,
+        MetricTimeRange::ThreeHours,
+        MetricTimeRange::TwelveHours,
+        MetricTimeRange::OneDay,
+        MetricTimeRange::SevenDays,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            MetricTimeRange::OneHour => "1 hour",
+            MetricTimeRange::ThreeHours => "3 hours",
+            MetricTimeRange::TwelveHours => "12 hours",
+            MetricTimeRange::OneDay => "1 day",
+            MetricTimeRange::SevenDays => "7 days",
+        }
+    }
+
+    /// Length of the window in milliseconds
+    pub fn duration_ms(&self) -> i64 {
+        const HOUR_MS: i64 = 60 * 60 * 1000;
+        match self {
+            MetricTimeRange::OneHour => HOUR_MS,
+            MetricTimeRange::ThreeHours => 3 * HOUR_MS,
+            MetricTimeRange::TwelveHours => 12 * HOUR_MS,
+            MetricTimeRange::OneDay => 24 * HOUR_MS,
+            MetricTimeRange::SevenDays => 7 * 24 * HOUR_MS,
+        }
+    }
+
+    /// Aggregation period that keeps each chart around 60-170 points
+    pub fn period_seconds(&self) -> i32 {
+        match self {
+            MetricTimeRange::OneHour => 60,
+            MetricTimeRange::ThreeHours => 120,
+            MetricTimeRange::TwelveHours => 300,
+            MetricTimeRange::OneDay => 900,
+            MetricTimeRange::SevenDays => 3600,
+        }
+    }
+}
+
+/// Result from background metric loading
+type MetricLoadResult = Result<MetricQueryResult, String>;
+
+pub struct CloudWatchMetricsWindow {
+    pub open: bool,
+    // Display parameters
+    resource_type: String,
+    resource_id: String,
+    resource_name: String,
+    account_id: String,
+    region: String,
+
+    // State
+    series: Vec<MetricSeries>,
+    time_range: MetricTimeRange,
+    loading: bool,
+    error_message: Option<String>,
+    last_loaded: Option<DateTime<Utc>>,
+
+    // Services
+    client: Arc<CloudWatchMetricsClient>,
+
+    // Channel for receiving metric results from background thread
+    metric_receiver: mpsc::Receiver<MetricLoadResult>,
+    metric_sender: mpsc::Sender<MetricLoadResult>,
+}
+
+impl CloudWatchMetricsWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (metric_sender, metric_receiver) = mpsc::channel();
+
+        Self {
+            open: false,
+            resource_type: String::new(),
+            resource_id: String::new(),
+            resource_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            series: Vec::new(),
+            time_range: MetricTimeRange::ThreeHours,
+            loading: false,
+            error_message: None,
+            last_loaded: None,
+            client: Arc::new(CloudWatchMetricsClient::new(credential_coordinator)),
+            metric_receiver,
+            metric_sender,
+        }
+    }
+
+    /// Open the window and load metrics for a specific resource
+    pub fn open_for_resource(&mut self, params: CloudWatchMetricsShowParams) {
+        self.resource_type = params.resource_type;
+        self.resource_id = params.resource_id;
+        self.resource_name = params.resource_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.series.clear();
+        self.error_message = None;
+        self.open = true;
+
+        // Start loading metrics
+        self.refresh_metrics();
+    }
+
+    /// Refresh metrics from CloudWatch
+    fn refresh_metrics(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+
+        let account_id = self.account_id.clone();
+        let region = self.region.clone();
+        let resource_type = self.resource_type.clone();
+        let resource_id = self.resource_id.clone();
+
+        let now_ms = Utc::now().timestamp_millis();
+        let options = MetricQueryOptions::new()
+            .with_start_time(now_ms - self.time_range.duration_ms())
+            .with_end_time(now_ms)
+            .with_period(self.time_range.period_seconds());
+
+        spawn_with_client(
+            &self.client,
+            &self.metric_sender,
+            move |client| async move {
+                match client
+                    .get_resource_metrics(
+                        &account_id,
+                        &region,
+                        &resource_type,
+                        &resource_id,
+                        options,
+                    )
+                    .await
+                {
+                    Ok(result) => {
+                        log::info!(
+                            "Loaded {} metric datapoints for {}",
+                            result.total_datapoints,
+                            resource_id
+                        );
+                        Ok(result)
+                    }
+                    Err(e) => {
+                        log::error!("Failed to load metrics: {}", e);
+                        Err(e.to_string())
+                    }
+                }
+            },
+        );
+    }
+
+    /// Poll for metric results from background thread
+    fn poll_metric_results(&mut self) {
+        while let Ok(result) = self.metric_receiver.try_recv() {
+            self.loading = false;
+
+            match result {
+                Ok(metric_result) => {
+                    self.series = metric_result.series;
+                    self.error_message = None;
+                    self.last_loaded = Some(Utc::now());
+                }
+                Err(error_msg) => {
+                    self.error_message = Some(error_msg);
+                    self.series.clear();
+                }
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_offset(ctx, egui::Vec2::ZERO);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        self.show_with_offset_and_focus(ctx, egui::Vec2::ZERO, bring_to_front);
+    }
+
+    pub fn show_with_offset(&mut self, ctx: &Context, offset: egui::Vec2) {
+        self.show_with_offset_and_focus(ctx, offset, false);
+    }
+
+    fn show_with_offset_and_focus(
+        &mut self,
+        ctx: &Context,
+        _offset: egui::Vec2,
+        bring_to_front: bool,
+    ) {
+        if !self.open {
+            return;
+        }
+
+        // Poll for metric results from background thread
+        self.poll_metric_results();
+
+        // Request continuous repaint while loading to show spinner
+        if self.loading {
+            ctx.request_repaint();
+        }
+
+        let title = format!("CloudWatch Metrics: {}", self.resource_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .open(&mut is_open)
+            .default_size([720.0, 520.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        // Header: Resource identity
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Resource:").strong());
+            ui.label(format!("{} ({})", self.resource_id, self.resource_type));
+        });
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Account:").strong());
+            ui.label(&self.account_id);
+            ui.label(RichText::new("Region:").strong());
+            ui.label(&self.region);
+        });
+
+        ui.separator();
+
+        // Time range and Refresh controls
+        ui.horizontal(|ui| {
+            ui.label("Time range:");
+            let previous = self.time_range;
+            egui::ComboBox::from_id_salt("cloudwatch_metrics_time_range")
+                .selected_text(self.time_range.label())
+                .show_ui(ui, |ui| {
+                    for range in MetricTimeRange::ALL {
+                        ui.selectable_value(&mut self.time_range, range, range.label());
+                    }
+                });
+            if self.time_range != previous {
+                self.refresh_metrics();
+            }
+
+            if ui.button("Refresh").clicked() {
+                self.refresh_metrics();
+            }
+
+            ui.label(
+                RichText::new(format!("Period: {}s", self.time_range.period_seconds())).weak(),
+            );
+        });
+
+        ui.separator();
+
+        // Status message
+        if self.loading {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Loading metrics...");
+            });
+        } else if let Some(error) = &self.error_message {
+            ui.colored_label(egui::Color32::RED, format!("Error: {}", error));
+        }
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                self.render_series(ui);
+            });
+
+        if let Some(loaded) = self.last_loaded {
+            ui.separator();
+            ui.label(
+                RichText::new(format!(
+                    "Last updated {}",
+                    loaded.format("%Y-%m-%d %H:%M:%S UTC")
+                ))
+                .weak(),
+            );
+        }
+    }
+
+    fn render_series(&self, ui: &mut Ui) {
+        if self.series.is_empty() && !self.loading {
+            ui.label(RichText::new("No metrics available").italics());
+            ui.label("Click 'Refresh' to load the latest metrics.");
+            return;
+        }
+
+        for series in &self.series {
+            self.render_metric_card(ui, series);
+            ui.add_space(8.0);
+        }
+    }
+
+    fn render_metric_card(&self, ui: &mut Ui, series: &MetricSeries) {
+        ui.group(|ui| {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new(&series.label).strong());
+                if series.status != "Complete" {
+                    ui.label(RichText::new(format!("[{}]", series.status)).weak());
+                }
+            });
+
+            ui.horizontal(|ui| {
+                draw_sparkline(ui, series, SPARKLINE_SIZE);
+
+                ui.vertical(|ui| {
+                    ui.label(
+                        RichText::new(format_metric_value(series.latest()))
+                            .monospace()
+                            .size(18.0),
+                    );
+                    ui.label(
+                        RichText::new(format!(
+                            "min {}  max {}",
+                            format_metric_value(series.min()),
+                            format_metric_value(series.max())
+                        ))
+                        .monospace()
+                        .weak(),
+                    );
+                    ui.label(
+                        RichText::new(format!("avg {}", format_metric_value(series.average())))
+                            .monospace()
+                            .weak(),
+                    );
+                });
+            });
+        });
+    }
+}
+
+/// Draw a sparkline chart for a metric series, with a hover tooltip for the nearest point
+fn draw_sparkline(ui: &mut Ui, series: &MetricSeries, size: Vec2) {
+    let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+    let painter = ui.painter_at(rect);
+    let visuals = ui.visuals();
+
+    painter.rect_filled(rect, 2.0, visuals.extreme_bg_color);
+
+    if series.datapoints.is_empty() {
+        painter.text(
+            rect.center(),
+            egui::Align2::CENTER_CENTER,
+            "No data",
+            egui::FontId::proportional(12.0),
+            visuals.weak_text_color(),
+        );
+        return;
+    }
+
+    let values: Vec<(i64, f64)> = series
+        .datapoints
+        .iter()
+        .map(|p| (p.timestamp, p.value))
+        .collect();
+    let points = sparkline_points(&values, rect.shrink(4.0));
+
+    let line_color = Color32::from_rgb(66, 150, 250);
+    if points.len() == 1 {
+        painter.circle_filled(points[0], 2.5, line_color);
+    } else {
+        painter.add(egui::Shape::line(points.clone(), Stroke::new(1.5, line_color)));
+    }
+
+    // Tooltip and marker for the point nearest the pointer
+    if let Some(hover_pos) = response.hover_pos() {
+        if let Some((index, point)) = points
+            .iter()
+            .enumerate()
+            .min_by(|(_, a), (_, b)| {
+                (a.x - hover_pos.x)
+                    .abs()
+                    .total_cmp(&(b.x - hover_pos.x).abs())
+            })
+        {
+            painter.circle_filled(*point, 3.0, line_color);
+            let (timestamp, value) = values[index];
+            let time = DateTime::from_timestamp_millis(timestamp)
+                .map(|t| t.format("%Y-%m-%d %H:%M UTC").to_string())
+                .unwrap_or_default();
+            response.on_hover_text_at_pointer(format!(
+                "{}\n{}",
+                time,
+                format_metric_value(Some(value))
+            ));
+        }
+    }
+}
+
+/// Map (timestamp, value) pairs into screen positions inside `rect`
+///
+/// Time maps to the x axis and value to the y axis (higher values are drawn higher).
+/// A flat series is drawn through the vertical center.
+fn sparkline_points(values: &[(i64, f64)], rect: Rect) -> Vec<Pos2> {
+    if values.is_empty() {
+        return Vec::new();
+    }
+
+    let (min_t, max_t) = values
+        .iter()
+        .fold((i64::MAX, i64::MIN), |(lo, hi), (t, _)| (lo.min(*t), hi.max(*t)));
+    let (min_v, max_v) = values
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), (_, v)| {
+            (lo.min(*v), hi.max(*v))
+        });
+
+    let t_span = (max_t - min_t) as f32;
+    let v_span = (max_v - min_v) as f32;
+
+    values
+        .iter()
+        .map(|(t, v)| {
+            let x = if t_span > 0.0 {
+                rect.left() + rect.width() * ((t - min_t) as f32 / t_span)
+            } else {
+                rect.center().x
+            };
+            let y = if v_span > 0.0 {
+                rect.bottom() - rect.height() * ((*v - min_v) as f32 / v_span)
+            } else {
+                rect.center().y
+            };
+            Pos2::new(x, y)
+        })
+        .collect()
+}
+
+/// Format a metric value compactly (e.g., 1.23K, 4.5M)
+fn format_metric_value(value: Option<f64>) -> String {
+    let Some(value) = value else {
+        return "-".to_string();
+    };
+
+    let abs = value.abs();
+    if abs >= 1_000_000_000.0 {
+        format!("{:.2}G", value / 1_000_000_000.0)
+    } else if abs >= 1_000_000.0 {
+        format!("{:.2}M", value / 1_000_000.0)
+    } else if abs >= 10_000.0 {
+        format!("{:.2}K", value / 1_000.0)
+    } else if abs >= 100.0 || value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.2}", value)
+    }
+}
+
+impl FocusableWindow for CloudWatchMetricsWindow {
+    type ShowParams = CloudWatchMetricsShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "cloudwatch_metrics_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("CloudWatch Metrics: {}", self.resource_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the resource first
+        self.open_for_resource(params);
+
+        // Then show with focus
+        CloudWatchMetricsWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline_points_scaling() {
+        let rect = Rect::from_min_size(Pos2::new(0.0, 0.0), Vec2::new(100.0, 50.0));
+        let points = sparkline_points(&[(0, 0.0), (50, 10.0), (100, 5.0)], rect);
+
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[0], Pos2::new(0.0, 50.0));
+        assert_eq!(points[1], Pos2::new(50.0, 0.0));
+        assert_eq!(points[2], Pos2::new(100.0, 25.0));
+    }
+
+    #[test]
+    fn test_sparkline_points_flat_series() {
+        let rect = Rect::from_min_size(Pos2::new(0.0, 0.0), Vec2::new(100.0, 50.0));
+        let points = sparkline_points(&[(0, 3.0), (10, 3.0)], rect);
+        assert!(points.iter().all(|p| p.y == 25.0));
+
+        assert!(sparkline_points(&[], rect).is_empty());
+    }
+
+    #[test]
+    fn test_format_metric_value() {
+        assert_eq!(format_metric_value(None), "-");
+        assert_eq!(format_metric_value(Some(42.0)), "42");
+        assert_eq!(format_metric_value(Some(3.14159)), "3.14");
+        assert_eq!(format_metric_value(Some(12_500.0)), "12.50K");
+        assert_eq!(format_metric_value(Some(2_500_000.0)), "2.50M");
+    }
+
+    #[test]
+    fn test_time_range_periods_fit_api_limits() {
+        for range in MetricTimeRange::ALL {
+            let points = range.duration_ms() / (range.period_seconds() as i64 * 1000);
+            assert!(points <= 1440, "{} produces {} points", range.label(), points);
+            assert_eq!(range.period_seconds() % 60, 0);
+        }
+    }
+}
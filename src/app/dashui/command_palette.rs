@@ -1,11 +1,14 @@
+use super::command_registry::{CommandContext, CommandRegistry, PaletteCommand};
 use egui::{self, Align2, Context, FontId, Id, Pos2, Rect, RichText, Vec2};
 
 // Define an enum for main command palette actions
 pub enum CommandAction {
     Login,
     AWSExplorer,  // AWS resource explorer
+    FindResource, // Find a resource by ARN, ID, or IP across accounts
     AgentManager, // Agent Manager for managing multiple agents
     Quit,
+    Run(&'static str), // Registered command picked in search mode, by id
 }
 
 // Command structure for the command palette
@@ -22,6 +25,13 @@ pub struct CommandPalette {
     pub show: bool,
     palette_dimensions: Option<PaletteDimensions>,
     needs_recalculation: bool,
+    /// Commands offered by the subsystems in search mode
+    registry: CommandRegistry,
+    /// Search mode replaces the single-key commands with a fuzzy search
+    search_mode: bool,
+    search_query: String,
+    /// Highlighted row in the search results
+    search_selected: usize,
 }
 
 // Store the calculated dimensions to avoid recalculation on every frame
@@ -41,9 +51,30 @@ impl CommandPalette {
             show: false,
             palette_dimensions: None,
             needs_recalculation: true,
+            registry: CommandRegistry::new(),
+            search_mode: false,
+            search_query: String::new(),
+            search_selected: 0,
         }
     }
 
+    /// Register commands a subsystem offers in search mode
+    pub fn register_commands(&mut self, commands: impl IntoIterator<Item = PaletteCommand>) {
+        self.registry.register_all(commands);
+    }
+
+    pub fn registry(&self) -> &CommandRegistry {
+        &self.registry
+    }
+
+    /// Hide the palette; it opens with the single-key commands next time
+    pub fn close(&mut self) {
+        self.show = false;
+        self.search_mode = false;
+        self.search_query.clear();
+        self.search_selected = 0;
+    }
+
     // Calculate dimensions based on screen size
     fn calculate_dimensions(&mut self, ctx: &Context) {
         let screen_rect = ctx.screen_rect();
@@ -142,11 +173,16 @@ impl CommandPalette {
         });
     }
 
-    pub fn show(&mut self, ctx: &Context) -> Option<CommandAction> {
-        self.show_with_offset(ctx, Vec2::ZERO)
+    pub fn show(&mut self, ctx: &Context, context: &CommandContext) -> Option<CommandAction> {
+        self.show_with_offset(ctx, context, Vec2::ZERO)
     }
 
-    pub fn show_with_offset(&mut self, ctx: &Context, offset: Vec2) -> Option<CommandAction> {
+    pub fn show_with_offset(
+        &mut self,
+        ctx: &Context,
+        context: &CommandContext,
+        offset: Vec2,
+    ) -> Option<CommandAction> {
         if !self.show {
             return None;
         }
@@ -163,6 +199,12 @@ impl CommandPalette {
         // Apply offset to window position
         dimensions.window_pos += offset;
 
+        if self.search_mode {
+            let result = self.show_search(ctx, context, &dimensions);
+            self.close_on_click_outside(ctx, &dimensions);
+            return result;
+        }
+
         // Command entries with colors and descriptions
         let commands = [
             CommandEntry {
@@ -179,6 +221,13 @@ impl CommandPalette {
                 color: egui::Color32::from_rgb(255, 140, 70), // Orange-Red
                 description: "Explore AWS resources across accounts",
             },
+            CommandEntry {
+                key: egui::Key::F,
+                key_char: 'F',
+                label: "Find Resource",
+                color: egui::Color32::from_rgb(230, 200, 90), // Yellow
+                description: "Find by ARN, ID, or IP across accounts",
+            },
             CommandEntry {
                 key: egui::Key::M,
                 key_char: 'M',
@@ -186,6 +235,13 @@ impl CommandPalette {
                 color: egui::Color32::from_rgb(100, 180, 220), // Light Blue
                 description: "Manage multiple AI agents",
             },
+            CommandEntry {
+                key: egui::Key::Slash,
+                key_char: '/',
+                label: "Search Commands",
+                color: egui::Color32::from_rgb(180, 150, 230), // Purple
+                description: "Find commands for the current window",
+            },
             CommandEntry {
                 key: egui::Key::Q,
                 key_char: 'Q',
@@ -197,6 +253,7 @@ impl CommandPalette {
 
         // Create window with calculated dimensions
         let window_size = Vec2::new(dimensions.window_width, dimensions.window_height);
+        let mut open_search = false;
 
         egui::Area::new(Id::new("command_palette"))
             .fixed_pos(dimensions.window_pos)
@@ -242,11 +299,12 @@ impl CommandPalette {
 
                                 // Handle actions for first column
                                 if clicked || key_pressed {
-                                    self.show = false;
                                     match cmd.key {
                                         egui::Key::L => result = Some(CommandAction::Login),
                                         egui::Key::E => result = Some(CommandAction::AWSExplorer),
+                                        egui::Key::F => result = Some(CommandAction::FindResource),
                                         egui::Key::M => result = Some(CommandAction::AgentManager),
+                                        egui::Key::Slash => open_search = true,
                                         egui::Key::Q => result = Some(CommandAction::Quit),
                                         _ => {}
                                     }
@@ -274,11 +332,12 @@ impl CommandPalette {
 
                                 // Handle action for second column
                                 if clicked || key_pressed {
-                                    self.show = false;
                                     match cmd.key {
                                         egui::Key::L => result = Some(CommandAction::Login),
                                         egui::Key::E => result = Some(CommandAction::AWSExplorer),
+                                        egui::Key::F => result = Some(CommandAction::FindResource),
                                         egui::Key::M => result = Some(CommandAction::AgentManager),
+                                        egui::Key::Slash => open_search = true,
                                         egui::Key::Q => result = Some(CommandAction::Quit),
                                         _ => {}
                                     }
@@ -291,17 +350,162 @@ impl CommandPalette {
                 });
             });
 
-        // Close palette if clicking outside
+        if result.is_some() {
+            self.close();
+        } else if open_search {
+            self.search_mode = true;
+            self.search_query.clear();
+            self.search_selected = 0;
+        }
+
+        self.close_on_click_outside(ctx, &dimensions);
+
+        result
+    }
+
+    // Close palette if clicking outside
+    fn close_on_click_outside(&mut self, ctx: &Context, dimensions: &PaletteDimensions) {
         if ctx.input(|i| i.pointer.any_click()) {
             let mouse_pos = ctx.input(|i| i.pointer.interact_pos());
             if let Some(pos) = mouse_pos {
+                let window_size = Vec2::new(dimensions.window_width, dimensions.window_height);
                 let rect = Rect::from_min_size(dimensions.window_pos, window_size);
                 if !rect.contains(pos) {
-                    self.show = false;
+                    self.close();
                 }
             }
         }
+    }
 
-        result
+    // Search mode: fuzzy search over the registered commands
+    fn show_search(
+        &mut self,
+        ctx: &Context,
+        context: &CommandContext,
+        dimensions: &PaletteDimensions,
+    ) -> Option<CommandAction> {
+        let window_size = Vec2::new(dimensions.window_width, dimensions.window_height);
+        let matches = self.registry.search(&self.search_query, context);
+
+        // Keyboard selection moves over the results; Enter runs the highlighted one
+        let (up, down, enter) = ctx.input(|i| {
+            (
+                i.key_pressed(egui::Key::ArrowUp),
+                i.key_pressed(egui::Key::ArrowDown),
+                i.key_pressed(egui::Key::Enter),
+            )
+        });
+        if down && self.search_selected + 1 < matches.len() {
+            self.search_selected += 1;
+        }
+        if up {
+            self.search_selected = self.search_selected.saturating_sub(1);
+        }
+        self.search_selected = self.search_selected.min(matches.len().saturating_sub(1));
+
+        let mut chosen = None;
+        if enter {
+            if let Some(found) = matches.get(self.search_selected) {
+                if found.available {
+                    chosen = Some(found.command.id);
+                }
+            }
+        }
+
+        let mut query_changed = false;
+        egui::Area::new(Id::new("command_palette"))
+            .fixed_pos(dimensions.window_pos)
+            .movable(false)
+            .show(ctx, |ui| {
+                let frame = egui::Frame::NONE
+                    .fill(ui.style().visuals.extreme_bg_color)
+                    .stroke(egui::Stroke::new(
+                        1.5,
+                        ui.style().visuals.widgets.active.bg_fill,
+                    ))
+                    .inner_margin(egui::Margin {
+                        left: 25,
+                        right: 25,
+                        top: 20,
+                        bottom: 20,
+                    })
+                    .corner_radius(8.0);
+
+                frame.show(ui, |ui| {
+                    ui.set_min_size(window_size);
+                    ui.set_max_width(window_size.x);
+
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut self.search_query)
+                            .hint_text("Type to search commands")
+                            .font(FontId::proportional(16.0))
+                            .desired_width(f32::INFINITY),
+                    );
+                    if !response.has_focus() {
+                        response.request_focus();
+                    }
+                    query_changed = response.changed();
+                    ui.add_space(8.0);
+
+                    if matches.is_empty() {
+                        ui.label(RichText::new("No matching commands").weak());
+                        return;
+                    }
+
+                    egui::ScrollArea::vertical()
+                        .max_height(window_size.y - 60.0)
+                        .auto_shrink([false, true])
+                        .show(ui, |ui| {
+                            for (index, found) in matches.iter().enumerate() {
+                                let command = found.command;
+                                let selected = index == self.search_selected;
+                                let response = ui
+                                    .horizontal(|ui| {
+                                        ui.add_sized(
+                                            [90.0, 20.0],
+                                            egui::Label::new(
+                                                RichText::new(command.category.display_name())
+                                                    .size(13.0)
+                                                    .color(command.category.color()),
+                                            ),
+                                        );
+                                        let mut label = RichText::new(command.label).size(15.0);
+                                        if !found.available {
+                                            label = label.weak();
+                                        } else if selected {
+                                            label = label.strong();
+                                        }
+                                        ui.add_enabled_ui(found.available, |ui| {
+                                            ui.selectable_label(selected, label)
+                                        })
+                                        .inner
+                                    })
+                                    .inner;
+                                let response = if found.available {
+                                    response.on_hover_text(command.description)
+                                } else {
+                                    response.on_disabled_hover_text(format!(
+                                        "{} (not available here)",
+                                        command.description
+                                    ))
+                                };
+                                if selected && (up || down) {
+                                    response.scroll_to_me(None);
+                                }
+                                if response.clicked() {
+                                    chosen = Some(command.id);
+                                }
+                            }
+                        });
+                });
+            });
+
+        if query_changed {
+            self.search_selected = 0;
+        }
+
+        let id = chosen?;
+        self.close();
+        Some(CommandAction::Run(id))
     }
 }
@@ -0,0 +1,278 @@
+//! Command Registry
+//!
+//! Commands that subsystems offer through the command palette's search mode.
+//! Each subsystem registers [`PaletteCommand`]s describing what it can do
+//! ("Refresh current pane", "Tail logs for selected Lambda"); the palette
+//! lists the ones that apply to the current [`CommandContext`] first, ranked
+//! by fuzzy match on label, category, and keywords. Choosing a command hands
+//! its id back to the app, which routes it to the subsystem that owns it.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui::Color32;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+
+/// Subsystem a command belongs to, shown next to its label
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CommandCategory {
+    General,
+    Explorer,
+    Agents,
+    Pages,
+    CloudWatch,
+}
+
+impl CommandCategory {
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            CommandCategory::General => "General",
+            CommandCategory::Explorer => "Explorer",
+            CommandCategory::Agents => "Agents",
+            CommandCategory::Pages => "Pages",
+            CommandCategory::CloudWatch => "CloudWatch",
+        }
+    }
+
+    pub fn color(&self) -> Color32 {
+        match self {
+            CommandCategory::General => Color32::from_rgb(255, 190, 70),
+            CommandCategory::Explorer => Color32::from_rgb(255, 140, 70),
+            CommandCategory::Agents => Color32::from_rgb(100, 180, 220),
+            CommandCategory::Pages => Color32::from_rgb(150, 200, 120),
+            CommandCategory::CloudWatch => Color32::from_rgb(230, 200, 90),
+        }
+    }
+}
+
+/// What the user is looking at when the palette opens
+///
+/// Built by the app each frame the palette is in search mode; commands use
+/// it to decide whether they can run.
+#[derive(Debug, Clone, Default)]
+pub struct CommandContext {
+    /// Signed in to AWS
+    pub logged_in: bool,
+    /// An Explorer window is open and focused
+    pub explorer_focused: bool,
+    /// The current Explorer pane has resources loaded
+    pub pane_has_resources: bool,
+    /// The current Explorer pane is running a query
+    pub pane_loading: bool,
+    /// Resource types of the resources selected in the current pane's tree
+    pub selected_resource_types: Vec<String>,
+    /// The selected agent is working on a message
+    pub agent_processing: bool,
+    /// Number of open CloudWatch Logs windows
+    pub cloudwatch_logs_windows: usize,
+    /// Number of page windows (page previews, Pages Manager, consoles)
+    pub page_windows: usize,
+}
+
+impl CommandContext {
+    /// Whether a resource of `resource_type` is selected in the current pane
+    pub fn has_selected(&self, resource_type: &str) -> bool {
+        self.selected_resource_types
+            .iter()
+            .any(|selected| selected == resource_type)
+    }
+}
+
+/// A command a subsystem offers in the palette
+#[derive(Debug, Clone)]
+pub struct PaletteCommand {
+    /// Stable identifier the app routes on, e.g. "explorer.refresh_pane"
+    pub id: &'static str,
+    pub label: &'static str,
+    pub description: &'static str,
+    pub category: CommandCategory,
+    /// Extra words the search matches, e.g. "reload" for Refresh
+    pub keywords: &'static [&'static str],
+    /// Whether the command can run in a context
+    pub is_available: fn(&CommandContext) -> bool,
+}
+
+impl PaletteCommand {
+    /// Text the fuzzy search matches against
+    fn search_text(&self) -> String {
+        let mut text = format!("{} {}", self.label, self.category.display_name());
+        for keyword in self.keywords {
+            text.push(' ');
+            text.push_str(keyword);
+        }
+        text
+    }
+}
+
+/// A command found by [`CommandRegistry::search`]
+#[derive(Debug, Clone)]
+pub struct CommandMatch<'a> {
+    pub command: &'a PaletteCommand,
+    pub available: bool,
+    /// Fuzzy match score; 0 for an empty query
+    pub score: i64,
+}
+
+/// Commands registered by the subsystems, in registration order
+#[derive(Default)]
+pub struct CommandRegistry {
+    commands: Vec<PaletteCommand>,
+}
+
+impl CommandRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a command, replacing one registered earlier with the same id
+    pub fn register(&mut self, command: PaletteCommand) {
+        match self.commands.iter_mut().find(|c| c.id == command.id) {
+            Some(existing) => *existing = command,
+            None => self.commands.push(command),
+        }
+    }
+
+    pub fn register_all(&mut self, commands: impl IntoIterator<Item = PaletteCommand>) {
+        for command in commands {
+            self.register(command);
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&PaletteCommand> {
+        self.commands.iter().find(|c| c.id == id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.commands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.commands.is_empty()
+    }
+
+    /// Commands matching `query`, those available in `context` first
+    ///
+    /// An empty query lists every command. Within each group, better
+    /// matches come first and ties keep registration order.
+    pub fn search(&self, query: &str, context: &CommandContext) -> Vec<CommandMatch<'_>> {
+        let query = query.trim();
+        let matcher = SkimMatcherV2::default().ignore_case();
+
+        let mut matches: Vec<CommandMatch<'_>> = self
+            .commands
+            .iter()
+            .filter_map(|command| {
+                let score = if query.is_empty() {
+                    0
+                } else {
+                    matcher.fuzzy_match(&command.search_text(), query)?
+                };
+                Some(CommandMatch {
+                    command,
+                    available: (command.is_available)(context),
+                    score,
+                })
+            })
+            .collect();
+        matches.sort_by(|a, b| b.available.cmp(&a.available).then(b.score.cmp(&a.score)));
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(id: &'static str, label: &'static str) -> PaletteCommand {
+        PaletteCommand {
+            id,
+            label,
+            description: "",
+            category: CommandCategory::Explorer,
+            keywords: &[],
+            is_available: |_| true,
+        }
+    }
+
+    #[test]
+    fn test_register_replaces_same_id() {
+        let mut registry = CommandRegistry::new();
+        registry.register(command("explorer.refresh", "Refresh"));
+        registry.register(command("explorer.export", "Export"));
+        registry.register(command("explorer.refresh", "Refresh current pane"));
+
+        assert_eq!(registry.len(), 2);
+        assert_eq!(
+            registry.get("explorer.refresh").unwrap().label,
+            "Refresh current pane"
+        );
+    }
+
+    #[test]
+    fn test_empty_query_lists_all_in_order() {
+        let mut registry = CommandRegistry::new();
+        registry.register_all([command("a", "Refresh"), command("b", "Export")]);
+
+        let ids: Vec<_> = registry
+            .search("  ", &CommandContext::default())
+            .iter()
+            .map(|m| m.command.id)
+            .collect();
+        assert_eq!(ids, vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_fuzzy_search_matches_label_and_keywords() {
+        let mut registry = CommandRegistry::new();
+        registry.register_all([
+            command("refresh", "Refresh current pane"),
+            PaletteCommand {
+                keywords: &["download", "csv"],
+                ..command("export", "Export visible resources")
+            },
+        ]);
+        let context = CommandContext::default();
+
+        let ids: Vec<_> = registry
+            .search("rfrsh", &context)
+            .iter()
+            .map(|m| m.command.id)
+            .collect();
+        assert_eq!(ids, vec!["refresh"]);
+
+        let ids: Vec<_> = registry
+            .search("CSV", &context)
+            .iter()
+            .map(|m| m.command.id)
+            .collect();
+        assert_eq!(ids, vec!["export"]);
+
+        assert!(registry.search("zzz", &context).is_empty());
+    }
+
+    #[test]
+    fn test_unavailable_commands_listed_last() {
+        let mut registry = CommandRegistry::new();
+        registry.register_all([
+            PaletteCommand {
+                is_available: |context| context.has_selected("AWS::Lambda::Function"),
+                ..command("tail", "Tail logs for selected Lambda")
+            },
+            command("logs", "Close log windows"),
+        ]);
+
+        let matches = registry.search("", &CommandContext::default());
+        assert_eq!(matches[0].command.id, "logs");
+        assert!(matches[0].available);
+        assert_eq!(matches[1].command.id, "tail");
+        assert!(!matches[1].available);
+
+        let context = CommandContext {
+            selected_resource_types: vec!["AWS::Lambda::Function".to_string()],
+            ..Default::default()
+        };
+        let matches = registry.search("", &context);
+        assert_eq!(matches[0].command.id, "tail");
+        assert!(matches.iter().all(|m| m.available));
+    }
+}
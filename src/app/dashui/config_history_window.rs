@@ -0,0 +1,468 @@
+//! AWS Config History Viewer Window
+//!
+//! Shows a resource's configuration snapshots from AWS Config and a field-level
+//! diff between any two of them.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::config_history::{
+    diff_items, AwsConfigClient, ChangeKind, ConfigChange, ConfigHistoryOptions,
+    ConfigHistoryResult, ConfigurationItem,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use chrono::DateTime;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Number of snapshots to request from AWS Config
+const HISTORY_LIMIT: i32 = 50;
+
+/// Maximum characters shown for a single before/after value
+const MAX_VALUE_CHARS: usize = 300;
+
+/// Parameters for showing the Config History window
+#[derive(Clone)]
+pub struct ConfigHistoryShowParams {
+    pub resource_type: String,
+    pub resource_id: String,
+    pub resource_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Result from background history loading
+type HistoryLoadResult = Result<ConfigHistoryResult, String>;
+
+pub struct ConfigHistoryWindow {
+    pub open: bool,
+    // Display parameters
+    resource_type: String,
+    resource_id: String,
+    resource_name: String,
+    account_id: String,
+    region: String,
+
+    // State
+    /// Snapshots sorted oldest first
+    items: Vec<ConfigurationItem>,
+    /// Index of the snapshot being inspected
+    selected: usize,
+    /// Index of the snapshot to compare against (defaults to the previous one)
+    base: Option<usize>,
+    show_configuration: bool,
+    loading: bool,
+    error_message: Option<String>,
+
+    // Services
+    client: Arc<AwsConfigClient>,
+
+    // Channel for receiving history results from background thread
+    history_receiver: mpsc::Receiver<HistoryLoadResult>,
+    history_sender: mpsc::Sender<HistoryLoadResult>,
+}
+
+impl ConfigHistoryWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (history_sender, history_receiver) = mpsc::channel();
+
+        Self {
+            open: false,
+            resource_type: String::new(),
+            resource_id: String::new(),
+            resource_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            items: Vec::new(),
+            selected: 0,
+            base: None,
+            show_configuration: false,
+            loading: false,
+            error_message: None,
+            client: Arc::new(AwsConfigClient::new(credential_coordinator)),
+            history_receiver,
+            history_sender,
+        }
+    }
+
+    /// Open the window and load history for a specific resource
+    pub fn open_for_resource(&mut self, params: ConfigHistoryShowParams) {
+        self.resource_type = params.resource_type;
+        self.resource_id = params.resource_id;
+        self.resource_name = params.resource_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.items.clear();
+        self.error_message = None;
+        self.open = true;
+
+        // Start loading history
+        self.refresh_history();
+    }
+
+    /// Refresh history from AWS Config
+    fn refresh_history(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+
+        let account_id = self.account_id.clone();
+        let region = self.region.clone();
+        let resource_type = self.resource_type.clone();
+        let resource_id = self.resource_id.clone();
+
+        spawn_with_client(
+            &self.client,
+            &self.history_sender,
+            move |client| async move {
+                let options = ConfigHistoryOptions::new().with_limit(HISTORY_LIMIT);
+                match client
+                    .get_history_for_resource(
+                        &account_id,
+                        &region,
+                        &resource_type,
+                        &resource_id,
+                        options,
+                    )
+                    .await
+                {
+                    Ok(result) => {
+                        log::info!(
+                            "Loaded {} configuration items for {}",
+                            result.items.len(),
+                            resource_id
+                        );
+                        Ok(result)
+                    }
+                    Err(e) => {
+                        log::error!("Failed to load configuration history: {}", e);
+                        Err(format!("{:#}", e))
+                    }
+                }
+            },
+        );
+    }
+
+    /// Poll for history results from background thread
+    fn poll_history_results(&mut self) {
+        while let Ok(result) = self.history_receiver.try_recv() {
+            self.loading = false;
+
+            match result {
+                Ok(history) => {
+                    let mut items = history.items;
+                    items.sort_by_key(|item| item.capture_time);
+                    // Inspect the newest snapshot by default
+                    self.selected = items.len().saturating_sub(1);
+                    self.base = None;
+                    self.items = items;
+                    self.error_message = None;
+                }
+                Err(error_msg) => {
+                    self.error_message = Some(error_msg);
+                    self.items.clear();
+                }
+            }
+        }
+    }
+
+    /// Index of the snapshot the selected one is compared against
+    fn effective_base(&self) -> Option<usize> {
+        self.base
+            .filter(|b| *b < self.items.len() && *b != self.selected)
+            .or_else(|| self.selected.checked_sub(1))
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        // Poll for history results from background thread
+        self.poll_history_results();
+
+        // Request continuous repaint while loading to show spinner
+        if self.loading {
+            ctx.request_repaint();
+        }
+
+        let title = format!("Config History: {}", self.resource_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .open(&mut is_open)
+            .default_size([900.0, 600.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        // Header: Resource identity
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Resource:").strong());
+            ui.label(format!("{} ({})", self.resource_id, self.resource_type));
+            if ui.button("Refresh").clicked() {
+                self.refresh_history();
+            }
+        });
+
+        ui.separator();
+
+        // Status message
+        if self.loading {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Loading configuration history...");
+            });
+            return;
+        } else if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::RED, format!("Error: {}", error));
+            return;
+        }
+
+        if self.items.is_empty() {
+            ui.label(RichText::new("No configuration history recorded").italics());
+            ui.label("AWS Config may not be recording this resource type in this region.");
+            return;
+        }
+
+        // Split view: snapshot list on the left, diff on the right
+        egui::SidePanel::left(egui::Id::new((
+            "config_history_snapshots",
+            &self.resource_id,
+        )))
+        .default_width(240.0)
+        .min_width(180.0)
+        .show_inside(ui, |ui| {
+            self.render_snapshot_list(ui);
+        });
+
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            self.render_diff(ui);
+        });
+    }
+
+    fn render_snapshot_list(&mut self, ui: &mut Ui) {
+        ui.label(RichText::new(format!("Snapshots ({})", self.items.len())).strong());
+        ui.separator();
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                // Newest first in the list
+                for index in (0..self.items.len()).rev() {
+                    let item = &self.items[index];
+                    let text = format!(
+                        "{}  {}",
+                        format_capture_time(item.capture_time),
+                        item.status
+                    );
+                    if ui.selectable_label(index == self.selected, text).clicked() {
+                        self.selected = index;
+                        self.base = None;
+                    }
+                }
+            });
+    }
+
+    fn render_diff(&mut self, ui: &mut Ui) {
+        let selected_time = format_capture_time(self.items[self.selected].capture_time);
+        let base = self.effective_base();
+
+        // Compare-against selector
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Snapshot:").strong());
+            ui.label(&selected_time);
+            ui.label(RichText::new("compared with").weak());
+
+            let base_text = base
+                .map(|b| format_capture_time(self.items[b].capture_time))
+                .unwrap_or_else(|| "(none)".to_string());
+            egui::ComboBox::from_id_salt(("config_history_base", &self.resource_id))
+                .selected_text(base_text)
+                .show_ui(ui, |ui| {
+                    for index in (0..self.items.len()).rev() {
+                        if index == self.selected {
+                            continue;
+                        }
+                        let label = format_capture_time(self.items[index].capture_time);
+                        if ui.selectable_label(base == Some(index), label).clicked() {
+                            self.base = Some(index);
+                        }
+                    }
+                });
+        });
+
+        ui.checkbox(&mut self.show_configuration, "Show full configuration");
+        ui.separator();
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                let selected = &self.items[self.selected];
+
+                match base {
+                    Some(b) => {
+                        // Always diff older -> newer so Added/Removed read naturally
+                        let (older, newer) = if self.items[b].capture_time <= selected.capture_time
+                        {
+                            (&self.items[b], selected)
+                        } else {
+                            (selected, &self.items[b])
+                        };
+                        let changes = diff_items(older, newer);
+                        if changes.is_empty() {
+                            ui.label(RichText::new("No differences").italics());
+                        } else {
+                            ui.label(format!("{} changes", changes.len()));
+                            ui.add_space(4.0);
+                            for change in &changes {
+                                render_change(ui, change);
+                            }
+                        }
+                    }
+                    None => {
+                        ui.label(
+                            RichText::new("Oldest recorded snapshot - nothing to compare against")
+                                .italics(),
+                        );
+                    }
+                }
+
+                if self.show_configuration {
+                    ui.separator();
+                    let pretty =
+                        serde_json::to_string_pretty(&selected.configuration).unwrap_or_default();
+                    for line in pretty.lines() {
+                        ui.label(RichText::new(line).monospace());
+                    }
+                }
+            });
+    }
+}
+
+/// Render a single field change with colored before/after values
+pub(super) fn render_change(ui: &mut Ui, change: &ConfigChange) {
+    let color = change_color(change.kind);
+
+    ui.horizontal_wrapped(|ui| {
+        ui.label(RichText::new(change.kind.marker()).monospace().color(color));
+        ui.label(RichText::new(&change.path).monospace().strong());
+    });
+
+    ui.indent(("config_change", &change.path), |ui| {
+        if let Some(before) = &change.before {
+            ui.label(
+                RichText::new(format!("- {}", format_value(before)))
+                    .monospace()
+                    .color(change_color(ChangeKind::Removed)),
+            );
+        }
+        if let Some(after) = &change.after {
+            ui.label(
+                RichText::new(format!("+ {}", format_value(after)))
+                    .monospace()
+                    .color(change_color(ChangeKind::Added)),
+            );
+        }
+    });
+    ui.add_space(4.0);
+}
+
+pub(super) fn change_color(kind: ChangeKind) -> Color32 {
+    match kind {
+        ChangeKind::Added => Color32::from_rgb(80, 180, 80),
+        ChangeKind::Removed => Color32::from_rgb(220, 80, 80),
+        ChangeKind::Modified => Color32::from_rgb(220, 170, 60),
+    }
+}
+
+/// Format a JSON value on one line, truncated for display
+fn format_value(value: &serde_json::Value) -> String {
+    let text = match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    if text.chars().count() > MAX_VALUE_CHARS {
+        let truncated: String = text.chars().take(MAX_VALUE_CHARS).collect();
+        format!("{}...", truncated)
+    } else {
+        text
+    }
+}
+
+fn format_capture_time(capture_time: i64) -> String {
+    DateTime::from_timestamp_millis(capture_time)
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+impl FocusableWindow for ConfigHistoryWindow {
+    type ShowParams = ConfigHistoryShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "config_history_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Config History: {}", self.resource_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the resource first
+        self.open_for_resource(params);
+
+        // Then show with focus
+        ConfigHistoryWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_format_value() {
+        assert_eq!(format_value(&json!("plain")), "plain");
+        assert_eq!(format_value(&json!({"a": 1})), r#"{"a":1}"#);
+
+        let long = "x".repeat(MAX_VALUE_CHARS + 10);
+        let formatted = format_value(&json!(long));
+        assert!(formatted.ends_with("..."));
+        assert_eq!(formatted.chars().count(), MAX_VALUE_CHARS + 3);
+    }
+
+    #[test]
+    fn test_format_capture_time() {
+        assert_eq!(format_capture_time(0), "1970-01-01 00:00:00");
+    }
+}
@@ -0,0 +1,602 @@
+//! DNS Records Window
+//!
+//! Searches the Route53 record sets loaded with hosted zones in the Explorer
+//! by name or value across every zone and account. Alias records show the
+//! load balancer or CloudFront distribution they resolve to, and records
+//! gated by a health check show its current status.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use crate::app::resource_explorer::cache::SharedResourceCache;
+use crate::app::resource_explorer::normalizers::{
+    AsyncResourceNormalizer, Route53RecordSetNormalizer,
+};
+use crate::app::resource_explorer::resource_finder::{FindQuery, RevealRequest};
+use crate::app::resource_explorer::state::{RelationshipType, ResourceEntry};
+use eframe::egui;
+use egui::{Context, RichText, Ui};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Resource types read from the cache to resolve record targets
+const RELATED_TYPES: &[&str] = &[
+    "AWS::Route53::HostedZone",
+    "AWS::Route53::RecordSet",
+    "AWS::Route53::HealthCheck",
+    "AWS::ElasticLoadBalancing::LoadBalancer",
+    "AWS::ElasticLoadBalancingV2::LoadBalancer",
+    "AWS::CloudFront::Distribution",
+];
+
+/// A record set with its zone, alias target, and health check resolved
+#[derive(Debug, Clone)]
+struct DnsRecord {
+    record: ResourceEntry,
+    zone_name: String,
+    /// Record values, or the alias target DNS name
+    values: Vec<String>,
+    /// Load balancers and distributions the alias resolves to
+    alias_targets: Vec<ResourceEntry>,
+    health_check: Option<ResourceEntry>,
+}
+
+impl DnsRecord {
+    fn name(&self) -> &str {
+        self.record
+            .properties
+            .get("Name")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    fn record_type(&self) -> &str {
+        self.record
+            .properties
+            .get("Type")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+    }
+
+    fn is_alias(&self) -> bool {
+        self.record.properties.get("AliasTarget").is_some()
+    }
+
+    /// Case-insensitive match on the record name or any of its values
+    fn matches(&self, filter: &str) -> bool {
+        filter.is_empty()
+            || self.name().to_lowercase().contains(filter)
+            || self
+                .values
+                .iter()
+                .any(|value| value.to_lowercase().contains(filter))
+    }
+}
+
+/// Build the record list from cached Route53, load balancer, and CloudFront
+/// resources, sorted by name and type
+fn build_records(entries: &[ResourceEntry]) -> Vec<DnsRecord> {
+    let zone_names: HashMap<(&str, &str), &str> = entries
+        .iter()
+        .filter(|entry| entry.resource_type == "AWS::Route53::HostedZone")
+        .map(|zone| {
+            (
+                (zone.account_id.as_str(), zone.resource_id.as_str()),
+                zone.display_name.as_str(),
+            )
+        })
+        .collect();
+
+    let mut records: Vec<DnsRecord> = entries
+        .iter()
+        .filter(|entry| entry.resource_type == "AWS::Route53::RecordSet")
+        .map(|record| {
+            let zone_id = record
+                .properties
+                .get("HostedZoneId")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+            let values = match record
+                .properties
+                .get("AliasTarget")
+                .and_then(|alias| alias.get("DNSName"))
+                .and_then(|v| v.as_str())
+            {
+                Some(dns_name) => vec![dns_name.to_string()],
+                None => record
+                    .properties
+                    .get("ResourceRecords")
+                    .and_then(|v| v.as_array())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|v| v.as_str().map(String::from))
+                    .collect(),
+            };
+
+            let mut alias_targets = Vec::new();
+            let mut health_check = None;
+            for relationship in Route53RecordSetNormalizer.extract_relationships(record, entries) {
+                if !matches!(relationship.relationship_type, RelationshipType::Uses) {
+                    continue;
+                }
+                let Some(target) = entries.iter().find(|entry| {
+                    entry.account_id == record.account_id
+                        && entry.resource_type == relationship.target_resource_type
+                        && entry.resource_id == relationship.target_resource_id
+                }) else {
+                    continue;
+                };
+                if target.resource_type == "AWS::Route53::HealthCheck" {
+                    health_check = Some(target.clone());
+                } else {
+                    alias_targets.push(target.clone());
+                }
+            }
+
+            DnsRecord {
+                record: record.clone(),
+                zone_name: zone_names
+                    .get(&(record.account_id.as_str(), zone_id))
+                    .map(|name| name.to_string())
+                    .unwrap_or_else(|| zone_id.to_string()),
+                values,
+                alias_targets,
+                health_check,
+            }
+        })
+        .collect();
+
+    records.sort_by(|a, b| {
+        a.name()
+            .cmp(b.name())
+            .then_with(|| a.record_type().cmp(b.record_type()))
+            .then_with(|| a.record.account_id.cmp(&b.record.account_id))
+            .then_with(|| a.record.resource_id.cmp(&b.record.resource_id))
+    });
+    records
+}
+
+/// Where to show a resource in the Explorer
+///
+/// Records are listed under their hosted zone, so the zone is what gets
+/// loaded when the Explorer does not have the record yet.
+fn reveal_request(resource: &ResourceEntry) -> RevealRequest {
+    let (resource_type, resource_id) = match (
+        resource.is_child_resource,
+        &resource.parent_resource_type,
+        &resource.parent_resource_id,
+    ) {
+        (true, Some(parent_type), Some(parent_id)) => (parent_type.clone(), parent_id.clone()),
+        _ => (resource.resource_type.clone(), resource.resource_id.clone()),
+    };
+    RevealRequest {
+        query: FindQuery::ResourceId(resource_id),
+        account_id: resource.account_id.clone(),
+        region: resource.region.clone(),
+        resource_type,
+        selection_key: Some(resource.selection_key()),
+    }
+}
+
+pub struct DnsRecordsWindow {
+    pub open: bool,
+
+    // State
+    records: Vec<DnsRecord>,
+    zone_count: usize,
+    loaded_at: Option<chrono::DateTime<chrono::Utc>>,
+    search: String,
+    /// Only list the records of this hosted zone
+    zone_filter: Option<String>,
+    /// Resource picked with "Show", taken by the app
+    pending_reveal: Option<RevealRequest>,
+
+    // Services
+    cache: Option<Arc<SharedResourceCache>>,
+}
+
+impl Default for DnsRecordsWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DnsRecordsWindow {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            records: Vec::new(),
+            zone_count: 0,
+            loaded_at: None,
+            search: String::new(),
+            zone_filter: None,
+            pending_reveal: None,
+            cache: None,
+        }
+    }
+
+    /// Open the window on every loaded record, or on the records of one
+    /// hosted zone
+    pub fn open_for(&mut self, cache: Arc<SharedResourceCache>, hosted_zone_id: Option<String>) {
+        self.cache = Some(cache);
+        self.zone_filter = hosted_zone_id;
+        self.open = true;
+        self.reload();
+    }
+
+    /// Take the resource picked with "Show" to reveal in the Explorer
+    pub fn take_reveal_request(&mut self) -> Option<RevealRequest> {
+        self.pending_reveal.take()
+    }
+
+    /// Read the records from the Explorer cache
+    fn reload(&mut self) {
+        let Some(cache) = &self.cache else {
+            return;
+        };
+        let mut entries = Vec::new();
+        for key in cache.resource_keys() {
+            for entry in cache.get_resources(&key).unwrap_or_default() {
+                if RELATED_TYPES.contains(&entry.resource_type.as_str()) {
+                    entries.push((*entry).clone());
+                }
+            }
+        }
+        // Global zones are cached once per selected region
+        let mut seen = std::collections::HashSet::new();
+        entries.retain(|entry| seen.insert(entry.selection_key()));
+
+        self.zone_count = entries
+            .iter()
+            .filter(|entry| entry.resource_type == "AWS::Route53::HostedZone")
+            .count();
+        self.records = build_records(&entries);
+        self.loaded_at = Some(chrono::Utc::now());
+    }
+
+    fn visible_records(&self) -> Vec<&DnsRecord> {
+        let filter = self.search.trim().to_lowercase();
+        self.records
+            .iter()
+            .filter(|record| {
+                self.zone_filter.as_ref().map_or(true, |zone_id| {
+                    record.record.parent_resource_id.as_ref() == Some(zone_id)
+                })
+            })
+            .filter(|record| record.matches(&filter))
+            .collect()
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new("DNS Records")
+            .open(&mut is_open)
+            .default_size([900.0, 520.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.render_toolbar(ui);
+            ui.separator();
+            if let Some(request) = self.render_records(ui) {
+                self.pending_reveal = Some(request);
+            }
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn render_toolbar(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut self.search)
+                    .hint_text("Search record names or values")
+                    .desired_width(260.0),
+            );
+            if ui
+                .button("Refresh")
+                .on_hover_text("Read the records loaded in the Explorer again")
+                .clicked()
+            {
+                self.reload();
+            }
+            ui.separator();
+            match &self.zone_filter {
+                Some(zone_id) => {
+                    let zone_name = self
+                        .records
+                        .iter()
+                        .find(|record| record.record.parent_resource_id.as_ref() == Some(zone_id))
+                        .map(|record| record.zone_name.clone())
+                        .unwrap_or_else(|| zone_id.clone());
+                    ui.label(RichText::new(zone_name).strong());
+                    if ui.small_button("All zones").clicked() {
+                        self.zone_filter = None;
+                    }
+                }
+                None => {
+                    ui.label(format!(
+                        "{} records in {} hosted zones",
+                        self.records.len(),
+                        self.zone_count
+                    ));
+                }
+            }
+            if let Some(loaded_at) = self.loaded_at {
+                ui.label(
+                    RichText::new(format!(
+                        "read {}",
+                        loaded_at.with_timezone(&chrono::Local).format("%H:%M:%S")
+                    ))
+                    .weak(),
+                );
+            }
+        });
+
+        if self.records.is_empty() {
+            ui.label(
+                RichText::new("Records load with Route53 hosted zones in the Explorer")
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+    }
+
+    fn render_records(&self, ui: &mut Ui) -> Option<RevealRequest> {
+        let mut reveal = None;
+        let records = self.visible_records();
+        if records.is_empty() {
+            if !self.records.is_empty() {
+                ui.label("No records match the search");
+            }
+            return None;
+        }
+
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::Grid::new("dns_records_grid")
+                    .num_columns(7)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Name");
+                        ui.strong("Type");
+                        ui.strong("Value");
+                        ui.strong("TTL");
+                        ui.strong("Zone");
+                        ui.strong("Health");
+                        ui.label("");
+                        ui.end_row();
+
+                        for record in records {
+                            ui.label(record.name()).on_hover_text(format!(
+                                "{}\n{}",
+                                record.record.display_name, record.record.account_id
+                            ));
+                            ui.label(record.record_type());
+
+                            ui.vertical(|ui| {
+                                if record.is_alias() {
+                                    ui.label(RichText::new("Alias").small().weak());
+                                }
+                                for value in &record.values {
+                                    ui.label(RichText::new(value).monospace());
+                                }
+                                for target in &record.alias_targets {
+                                    if ui
+                                        .link(format!("-> {}", target.display_name))
+                                        .on_hover_text(format!(
+                                            "{}\nShow in the Explorer",
+                                            target.resource_type
+                                        ))
+                                        .clicked()
+                                    {
+                                        reveal = Some(reveal_request(target));
+                                    }
+                                }
+                            });
+
+                            ui.label(
+                                record
+                                    .record
+                                    .properties
+                                    .get("TTL")
+                                    .and_then(|v| v.as_i64())
+                                    .map(|ttl| ttl.to_string())
+                                    .unwrap_or_else(|| "-".to_string()),
+                            );
+                            ui.label(&record.zone_name);
+
+                            match &record.health_check {
+                                Some(health_check) => {
+                                    let status =
+                                        health_check.status.as_deref().unwrap_or("Unknown");
+                                    let color = match status {
+                                        "Healthy" => egui::Color32::from_rgb(80, 180, 80),
+                                        "Unhealthy" => ui.visuals().error_fg_color,
+                                        _ => ui.visuals().weak_text_color(),
+                                    };
+                                    if ui
+                                        .link(RichText::new(status).color(color))
+                                        .on_hover_text(&health_check.display_name)
+                                        .clicked()
+                                    {
+                                        reveal = Some(reveal_request(health_check));
+                                    }
+                                }
+                                None => {
+                                    let health_check_id = record
+                                        .record
+                                        .properties
+                                        .get("HealthCheckId")
+                                        .and_then(|v| v.as_str());
+                                    match health_check_id {
+                                        // The health check itself is not loaded in the Explorer
+                                        Some(id) => {
+                                            ui.label(RichText::new(id).small().weak());
+                                        }
+                                        None => {
+                                            ui.label("-");
+                                        }
+                                    }
+                                }
+                            }
+
+                            if ui
+                                .small_button("Show")
+                                .on_hover_text("Show the record in the Explorer")
+                                .clicked()
+                            {
+                                reveal = Some(reveal_request(&record.record));
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+        reveal
+    }
+}
+
+impl FocusableWindow for DnsRecordsWindow {
+    type ShowParams = crate::app::dashui::window_focus::SimpleShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "dns_records_window"
+    }
+
+    fn window_title(&self) -> String {
+        "DNS Records".to_string()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        _params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        DnsRecordsWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(
+        resource_type: &str,
+        resource_id: &str,
+        properties: serde_json::Value,
+    ) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "Global".to_string(),
+            resource_id: resource_id.to_string(),
+            display_name: resource_id.to_string(),
+            status: None,
+            properties,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    fn record(name: &str, record_type: &str, properties: serde_json::Value) -> ResourceEntry {
+        let mut properties = properties;
+        properties["Name"] = serde_json::json!(name);
+        properties["Type"] = serde_json::json!(record_type);
+        properties["HostedZoneId"] = serde_json::json!("/hostedzone/Z1");
+        let mut record = entry(
+            "AWS::Route53::RecordSet",
+            &format!("/hostedzone/Z1/{}/{}", name, record_type),
+            properties,
+        );
+        record.is_child_resource = true;
+        record.parent_resource_id = Some("/hostedzone/Z1".to_string());
+        record.parent_resource_type = Some("AWS::Route53::HostedZone".to_string());
+        record
+    }
+
+    #[test]
+    fn test_records_resolve_targets_and_search_by_name_or_value() {
+        let mut zone = entry(
+            "AWS::Route53::HostedZone",
+            "/hostedzone/Z1",
+            serde_json::json!({}),
+        );
+        zone.display_name = "example.com.".to_string();
+        let mut health_check = entry("AWS::Route53::HealthCheck", "hc-1", serde_json::json!({}));
+        health_check.status = Some("Unhealthy".to_string());
+        let distribution = entry(
+            "AWS::CloudFront::Distribution",
+            "E2EXAMPLE",
+            serde_json::json!({"DomainName": "d111111abcdef8.cloudfront.net"}),
+        );
+        let entries = vec![
+            zone,
+            health_check,
+            distribution,
+            record(
+                "www.example.com.",
+                "A",
+                serde_json::json!({"AliasTarget": {"DNSName": "d111111abcdef8.cloudfront.net."}}),
+            ),
+            record(
+                "api.example.com.",
+                "A",
+                serde_json::json!({"ResourceRecords": ["192.0.2.10"], "TTL": 300, "HealthCheckId": "hc-1"}),
+            ),
+        ];
+
+        let records = build_records(&entries);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].name(), "api.example.com.");
+        assert_eq!(records[0].zone_name, "example.com.");
+        assert_eq!(
+            records[0]
+                .health_check
+                .as_ref()
+                .and_then(|h| h.status.as_deref()),
+            Some("Unhealthy")
+        );
+        assert!(records[1].is_alias());
+        assert_eq!(records[1].alias_targets[0].resource_id, "E2EXAMPLE");
+
+        assert!(records[0].matches("api.example"));
+        assert!(records[0].matches("192.0.2"));
+        assert!(records[1].matches("cloudfront"));
+        assert!(!records[1].matches("api"));
+
+        // Records are revealed through their hosted zone
+        let request = reveal_request(&records[0].record);
+        assert_eq!(request.resource_type, "AWS::Route53::HostedZone");
+        assert_eq!(
+            request.selection_key,
+            Some(records[0].record.selection_key())
+        );
+    }
+}
@@ -0,0 +1,832 @@
+//! Docking Layout
+//!
+//! Lets the major windows (Explorer, Agents, Log Viewer, CloudTrail Events) be
+//! docked into a tiled layout over the central panel. A tile holds one or more
+//! docked windows as tabs; tiles are split side by side or stacked, and the
+//! gap between two tiles can be dragged to resize them.
+//!
+//! Docked windows are still egui windows: each frame [`DockManager::show`]
+//! draws the tab strips and splitters in the central panel and works out a
+//! [`DockPlacement`] per window. The app pins the active tab's window to its
+//! tile with [`dock_window`] and skips windows whose tab is not active.
+//!
+//! Layouts are saved as named profiles in `~/.config/awsdash/layouts.toml`
+//! (see [`DockLayoutStore`]); the last profile loaded or saved is restored at
+//! startup.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use eframe::egui;
+use egui::{Color32, Pos2, Rect, RichText, Ui, Vec2};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Height of the tab strip at the top of each tile
+const TAB_BAR_HEIGHT: f32 = 24.0;
+
+/// Width of the draggable gap between two tiles
+const SPLITTER_WIDTH: f32 = 6.0;
+
+/// Space a window's frame takes around its content, on each side
+const WINDOW_FRAME_MARGIN: f32 = 8.0;
+
+/// Smallest share of a split either tile can be dragged down to
+const MIN_SPLIT_FRACTION: f32 = 0.15;
+
+/// Window that can be docked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DockPanel {
+    /// The first open Explorer window
+    Explorer,
+    /// Agent Manager
+    Agents,
+    /// Application Log Viewer
+    Logs,
+    /// The most recently opened CloudTrail Events window
+    CloudTrail,
+}
+
+impl DockPanel {
+    pub const ALL: [DockPanel; 4] = [
+        DockPanel::Explorer,
+        DockPanel::Agents,
+        DockPanel::Logs,
+        DockPanel::CloudTrail,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DockPanel::Explorer => "Explorer",
+            DockPanel::Agents => "Agents",
+            DockPanel::Logs => "Log Viewer",
+            DockPanel::CloudTrail => "CloudTrail Events",
+        }
+    }
+}
+
+/// How a split arranges its two tiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SplitDirection {
+    /// Side by side
+    Horizontal,
+    /// Stacked top to bottom
+    Vertical,
+}
+
+/// Where a floating window is docked
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DockTarget {
+    /// As a tab of the first tile
+    Tab,
+    /// In a new tile right of the whole layout
+    Right,
+    /// In a new tile below the whole layout
+    Below,
+}
+
+/// Node of the layout tree
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum DockNode {
+    /// Tile showing one of its windows at a time
+    Tabs {
+        panels: Vec<DockPanel>,
+        active: usize,
+    },
+    /// Two nodes sharing an area; `fraction` is the first node's share
+    Split {
+        direction: SplitDirection,
+        fraction: f32,
+        first: Box<DockNode>,
+        second: Box<DockNode>,
+    },
+}
+
+impl DockNode {
+    fn tabs(panel: DockPanel) -> Self {
+        DockNode::Tabs {
+            panels: vec![panel],
+            active: 0,
+        }
+    }
+
+    fn contains(&self, panel: DockPanel) -> bool {
+        match self {
+            DockNode::Tabs { panels, .. } => panels.contains(&panel),
+            DockNode::Split { first, second, .. } => {
+                first.contains(panel) || second.contains(panel)
+            }
+        }
+    }
+
+    /// Tab list holding `panel`
+    fn tabs_with(&mut self, panel: DockPanel) -> Option<&mut DockNode> {
+        match self {
+            DockNode::Tabs { .. } => self.contains(panel).then_some(self),
+            DockNode::Split { first, second, .. } => first
+                .tabs_with(panel)
+                .or_else(move || second.tabs_with(panel)),
+        }
+    }
+
+    /// First tab list, in reading order
+    fn first_tabs(&mut self) -> &mut DockNode {
+        match self {
+            DockNode::Tabs { .. } => self,
+            DockNode::Split { first, .. } => first.first_tabs(),
+        }
+    }
+
+    /// Node without `panel`, or None when nothing is left in it
+    fn without(self, panel: DockPanel) -> Option<DockNode> {
+        match self {
+            DockNode::Tabs { mut panels, active } => {
+                let index = panels.iter().position(|p| *p == panel);
+                panels.retain(|p| *p != panel);
+                if panels.is_empty() {
+                    return None;
+                }
+                let active = match index {
+                    Some(index) if index < active => active - 1,
+                    _ => active.min(panels.len() - 1),
+                };
+                Some(DockNode::Tabs { panels, active })
+            }
+            DockNode::Split {
+                direction,
+                fraction,
+                first,
+                second,
+            } => match (first.without(panel), second.without(panel)) {
+                (Some(first), Some(second)) => Some(DockNode::Split {
+                    direction,
+                    fraction,
+                    first: Box::new(first),
+                    second: Box::new(second),
+                }),
+                // A split with one side left collapses into that side
+                (Some(node), None) | (None, Some(node)) => Some(node),
+                (None, None) => None,
+            },
+        }
+    }
+}
+
+/// Tile of an arranged layout
+#[derive(Debug, Clone, PartialEq)]
+pub struct DockTile {
+    pub rect: Rect,
+    pub panels: Vec<DockPanel>,
+    pub active: usize,
+}
+
+/// Draggable gap between the two sides of a split
+#[derive(Debug, Clone, PartialEq)]
+pub struct DockSplitter {
+    /// Path from the root to the split (false = first, true = second)
+    pub path: Vec<bool>,
+    pub direction: SplitDirection,
+    /// Area shared by both sides
+    pub area: Rect,
+    /// The gap itself
+    pub rect: Rect,
+}
+
+/// Tree of docked windows; empty when every window floats
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DockLayout {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub root: Option<DockNode>,
+}
+
+impl DockLayout {
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    pub fn contains(&self, panel: DockPanel) -> bool {
+        self.root.as_ref().is_some_and(|root| root.contains(panel))
+    }
+
+    /// Dock a floating window; a window that is already docked stays put
+    pub fn dock(&mut self, panel: DockPanel, target: DockTarget) {
+        if self.contains(panel) {
+            return;
+        }
+        self.root = Some(match (self.root.take(), target) {
+            (None, _) => DockNode::tabs(panel),
+            (Some(mut root), DockTarget::Tab) => {
+                if let DockNode::Tabs { panels, active } = root.first_tabs() {
+                    panels.push(panel);
+                    *active = panels.len() - 1;
+                }
+                root
+            }
+            (Some(root), DockTarget::Right) => Self::split(root, panel, SplitDirection::Horizontal),
+            (Some(root), DockTarget::Below) => Self::split(root, panel, SplitDirection::Vertical),
+        });
+    }
+
+    fn split(node: DockNode, panel: DockPanel, direction: SplitDirection) -> DockNode {
+        DockNode::Split {
+            direction,
+            fraction: 0.5,
+            first: Box::new(node),
+            second: Box::new(DockNode::tabs(panel)),
+        }
+    }
+
+    /// Let a docked window float again
+    pub fn undock(&mut self, panel: DockPanel) {
+        self.root = self.root.take().and_then(|root| root.without(panel));
+    }
+
+    /// Let every docked window float again
+    pub fn undock_all(&mut self) {
+        self.root = None;
+    }
+
+    /// Move a tab out of its tile into a new tile next to it
+    ///
+    /// Does nothing when the tab is alone in its tile.
+    pub fn split_off(&mut self, panel: DockPanel, direction: SplitDirection) {
+        let Some(node) = self.root.as_mut().and_then(|root| root.tabs_with(panel)) else {
+            return;
+        };
+        let DockNode::Tabs { panels, .. } = &*node else {
+            return;
+        };
+        if panels.len() < 2 {
+            return;
+        }
+        let Some(rest) = node.clone().without(panel) else {
+            return;
+        };
+        *node = Self::split(rest, panel, direction);
+    }
+
+    /// Make a docked window the visible tab of its tile
+    pub fn activate(&mut self, panel: DockPanel) {
+        if let Some(DockNode::Tabs { panels, active }) =
+            self.root.as_mut().and_then(|root| root.tabs_with(panel))
+        {
+            if let Some(index) = panels.iter().position(|p| *p == panel) {
+                *active = index;
+            }
+        }
+    }
+
+    /// Set the first side's share of the split at `path`
+    pub fn set_fraction(&mut self, path: &[bool], fraction: f32) {
+        let mut node = self.root.as_mut();
+        for &second in path {
+            node = match node {
+                Some(DockNode::Split {
+                    first,
+                    second: other,
+                    ..
+                }) => Some(if second {
+                    other.as_mut()
+                } else {
+                    first.as_mut()
+                }),
+                _ => None,
+            };
+        }
+        if let Some(DockNode::Split {
+            fraction: current, ..
+        }) = node
+        {
+            *current = fraction.clamp(MIN_SPLIT_FRACTION, 1.0 - MIN_SPLIT_FRACTION);
+        }
+    }
+
+    /// Place the tiles and splitters of the layout in `area`
+    pub fn arrange(&self, area: Rect) -> (Vec<DockTile>, Vec<DockSplitter>) {
+        let mut tiles = Vec::new();
+        let mut splitters = Vec::new();
+        if let Some(root) = &self.root {
+            Self::arrange_node(root, area, &mut Vec::new(), &mut tiles, &mut splitters);
+        }
+        (tiles, splitters)
+    }
+
+    fn arrange_node(
+        node: &DockNode,
+        rect: Rect,
+        path: &mut Vec<bool>,
+        tiles: &mut Vec<DockTile>,
+        splitters: &mut Vec<DockSplitter>,
+    ) {
+        match node {
+            DockNode::Tabs { panels, active } => tiles.push(DockTile {
+                rect,
+                panels: panels.clone(),
+                active: *active,
+            }),
+            DockNode::Split {
+                direction,
+                fraction,
+                first,
+                second,
+            } => {
+                let (first_rect, gap, second_rect) = split_rect(rect, *direction, *fraction);
+                splitters.push(DockSplitter {
+                    path: path.clone(),
+                    direction: *direction,
+                    area: rect,
+                    rect: gap,
+                });
+                path.push(false);
+                Self::arrange_node(first, first_rect, path, tiles, splitters);
+                path.pop();
+                path.push(true);
+                Self::arrange_node(second, second_rect, path, tiles, splitters);
+                path.pop();
+            }
+        }
+    }
+}
+
+/// Split `rect` into the first side, the gap, and the second side
+fn split_rect(rect: Rect, direction: SplitDirection, fraction: f32) -> (Rect, Rect, Rect) {
+    let half_gap = SPLITTER_WIDTH / 2.0;
+    match direction {
+        SplitDirection::Horizontal => {
+            let x = rect.min.x + rect.width() * fraction;
+            (
+                Rect::from_min_max(rect.min, Pos2::new(x - half_gap, rect.max.y)),
+                Rect::from_min_max(
+                    Pos2::new(x - half_gap, rect.min.y),
+                    Pos2::new(x + half_gap, rect.max.y),
+                ),
+                Rect::from_min_max(Pos2::new(x + half_gap, rect.min.y), rect.max),
+            )
+        }
+        SplitDirection::Vertical => {
+            let y = rect.min.y + rect.height() * fraction;
+            (
+                Rect::from_min_max(rect.min, Pos2::new(rect.max.x, y - half_gap)),
+                Rect::from_min_max(
+                    Pos2::new(rect.min.x, y - half_gap),
+                    Pos2::new(rect.max.x, y + half_gap),
+                ),
+                Rect::from_min_max(Pos2::new(rect.min.x, y + half_gap), rect.max),
+            )
+        }
+    }
+}
+
+/// Where the app shows a dockable window this frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DockPlacement {
+    /// Not docked: a normal movable window
+    Floating,
+    /// Docked and the visible tab of the tile with this content area
+    Docked(Rect),
+    /// Docked behind another tab; not shown
+    Hidden,
+}
+
+impl DockPlacement {
+    /// Tile to pin the window to, if docked and visible
+    pub fn tile(&self) -> Option<Rect> {
+        match self {
+            DockPlacement::Docked(rect) => Some(*rect),
+            _ => None,
+        }
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        matches!(self, DockPlacement::Hidden)
+    }
+}
+
+/// Pin a window to its dock tile; floating windows are returned unchanged
+///
+/// The tile's tab strip names the window, so the title bar is dropped.
+pub fn dock_window<'open>(window: egui::Window<'open>, tile: Option<Rect>) -> egui::Window<'open> {
+    let Some(tile) = tile else {
+        return window;
+    };
+    let content_size = (tile.size() - Vec2::splat(2.0 * WINDOW_FRAME_MARGIN)).max(Vec2::splat(1.0));
+    window
+        .title_bar(false)
+        .collapsible(false)
+        .movable(false)
+        .resizable(false)
+        .constrain(false)
+        .fixed_pos(tile.min)
+        .fixed_size(content_size)
+}
+
+/// Saved layout profiles
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DockProfiles {
+    /// Profile restored at startup
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active: Option<String>,
+    #[serde(default)]
+    pub profiles: BTreeMap<String, DockLayout>,
+}
+
+/// Layout profiles file in the config directory
+#[derive(Debug, Clone)]
+pub struct DockLayoutStore {
+    path: PathBuf,
+}
+
+impl DockLayoutStore {
+    /// Store backed by the TOML file at `path`
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Store in the application config directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(dirs::config_dir()?.join("awsdash/layouts.toml")))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Saved profiles; none when the file does not exist yet
+    pub fn load(&self) -> Result<DockProfiles> {
+        if !self.path.exists() {
+            return Ok(DockProfiles::default());
+        }
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read layouts {}", self.path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Invalid layouts in {}", self.path.display()))
+    }
+
+    pub fn save(&self, profiles: &DockProfiles) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create config directory {}", parent.display())
+            })?;
+        }
+        let content = toml::to_string_pretty(profiles).context("Failed to serialize layouts")?;
+        fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write layouts {}", self.path.display()))
+    }
+}
+
+/// Current layout, its saved profiles, and this frame's window placements
+#[derive(Debug)]
+pub struct DockManager {
+    layout: DockLayout,
+    profiles: DockProfiles,
+    store: Option<DockLayoutStore>,
+    placements: HashMap<DockPanel, DockPlacement>,
+}
+
+impl Default for DockManager {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl DockManager {
+    /// Manager on `store`, restoring its active profile
+    pub fn new(store: Option<DockLayoutStore>) -> Self {
+        let profiles = match store.as_ref().map(|store| store.load()) {
+            Some(Ok(profiles)) => profiles,
+            Some(Err(e)) => {
+                tracing::warn!("Ignoring saved window layouts: {:#}", e);
+                DockProfiles::default()
+            }
+            None => DockProfiles::default(),
+        };
+        let layout = profiles
+            .active
+            .as_ref()
+            .and_then(|name| profiles.profiles.get(name))
+            .cloned()
+            .unwrap_or_default();
+        Self {
+            layout,
+            profiles,
+            store,
+            placements: HashMap::new(),
+        }
+    }
+
+    /// Manager on the default store
+    pub fn load() -> Self {
+        Self::new(DockLayoutStore::default_location())
+    }
+
+    pub fn layout(&self) -> &DockLayout {
+        &self.layout
+    }
+
+    pub fn layout_mut(&mut self) -> &mut DockLayout {
+        &mut self.layout
+    }
+
+    /// Where `panel` is shown this frame
+    pub fn placement(&self, panel: DockPanel) -> DockPlacement {
+        self.placements
+            .get(&panel)
+            .copied()
+            .unwrap_or(DockPlacement::Floating)
+    }
+
+    pub fn profile_names(&self) -> impl Iterator<Item = &String> {
+        self.profiles.profiles.keys()
+    }
+
+    pub fn active_profile(&self) -> Option<&str> {
+        self.profiles.active.as_deref()
+    }
+
+    /// Save the current layout under `name`, replacing a profile of that name
+    pub fn save_profile(&mut self, name: &str) -> Result<()> {
+        let mut profiles = self.profiles.clone();
+        profiles
+            .profiles
+            .insert(name.to_string(), self.layout.clone());
+        profiles.active = Some(name.to_string());
+        self.write(profiles)
+    }
+
+    /// Switch to the profile called `name`
+    pub fn load_profile(&mut self, name: &str) -> Result<()> {
+        let layout = self
+            .profiles
+            .profiles
+            .get(name)
+            .cloned()
+            .with_context(|| format!("No layout named '{}'", name))?;
+        let mut profiles = self.profiles.clone();
+        profiles.active = Some(name.to_string());
+        self.write(profiles)?;
+        self.layout = layout;
+        Ok(())
+    }
+
+    /// Delete a profile; the current layout stays as it is
+    pub fn delete_profile(&mut self, name: &str) -> Result<()> {
+        let mut profiles = self.profiles.clone();
+        profiles.profiles.remove(name);
+        if profiles.active.as_deref() == Some(name) {
+            profiles.active = None;
+        }
+        self.write(profiles)
+    }
+
+    fn write(&mut self, profiles: DockProfiles) -> Result<()> {
+        let store = self
+            .store
+            .as_ref()
+            .context("Config directory not available")?;
+        store.save(&profiles)?;
+        self.profiles = profiles;
+        Ok(())
+    }
+
+    /// Draw the tab strips and splitters of the layout in the central panel
+    ///
+    /// Only windows in `open` get tabs. Works out where each docked window
+    /// goes this frame; see [`placement`](Self::placement).
+    pub fn show(&mut self, ui: &mut Ui, open: &[DockPanel]) {
+        self.placements.clear();
+        let (tiles, splitters) = self.layout.arrange(ui.max_rect());
+
+        for splitter in &splitters {
+            let id = ui.id().with(("dock_splitter", &splitter.path));
+            let response = ui.interact(splitter.rect, id, egui::Sense::drag());
+            if response.hovered() || response.dragged() {
+                ui.ctx().set_cursor_icon(match splitter.direction {
+                    SplitDirection::Horizontal => egui::CursorIcon::ResizeHorizontal,
+                    SplitDirection::Vertical => egui::CursorIcon::ResizeVertical,
+                });
+            }
+            if response.dragged() {
+                if let Some(pos) = response.interact_pointer_pos() {
+                    let fraction = match splitter.direction {
+                        SplitDirection::Horizontal => {
+                            (pos.x - splitter.area.min.x) / splitter.area.width()
+                        }
+                        SplitDirection::Vertical => {
+                            (pos.y - splitter.area.min.y) / splitter.area.height()
+                        }
+                    };
+                    self.layout.set_fraction(&splitter.path, fraction);
+                }
+            }
+            ui.painter().rect_filled(
+                splitter.rect.shrink(1.0),
+                2.0,
+                ui.visuals().widgets.noninteractive.bg_stroke.color,
+            );
+        }
+
+        for tile in &tiles {
+            self.show_tile(ui, tile, open);
+        }
+    }
+
+    fn show_tile(&mut self, ui: &mut Ui, tile: &DockTile, open: &[DockPanel]) {
+        let visible: Vec<DockPanel> = tile
+            .panels
+            .iter()
+            .copied()
+            .filter(|panel| open.contains(panel))
+            .collect();
+        let active = tile
+            .panels
+            .get(tile.active)
+            .copied()
+            .filter(|panel| visible.contains(panel))
+            .or_else(|| visible.first().copied());
+
+        let tab_bar =
+            Rect::from_min_size(tile.rect.min, Vec2::new(tile.rect.width(), TAB_BAR_HEIGHT));
+        let content = Rect::from_min_max(
+            Pos2::new(tile.rect.min.x, tile.rect.min.y + TAB_BAR_HEIGHT),
+            tile.rect.max,
+        );
+
+        ui.scope_builder(
+            egui::UiBuilder::new()
+                .max_rect(tab_bar)
+                .layout(egui::Layout::left_to_right(egui::Align::Center)),
+            |ui| {
+                for panel in &visible {
+                    let response = ui.selectable_label(Some(*panel) == active, panel.label());
+                    if response.clicked() {
+                        self.layout.activate(*panel);
+                    }
+                    response.context_menu(|ui| {
+                        let shares_tile = visible.len() > 1;
+                        if ui
+                            .add_enabled(shares_tile, egui::Button::new("Split Right"))
+                            .clicked()
+                        {
+                            self.layout.split_off(*panel, SplitDirection::Horizontal);
+                            ui.close();
+                        }
+                        if ui
+                            .add_enabled(shares_tile, egui::Button::new("Split Down"))
+                            .clicked()
+                        {
+                            self.layout.split_off(*panel, SplitDirection::Vertical);
+                            ui.close();
+                        }
+                        ui.separator();
+                        if ui.button("Undock").clicked() {
+                            self.layout.undock(*panel);
+                            ui.close();
+                        }
+                    });
+                }
+            },
+        );
+
+        if visible.is_empty() {
+            ui.painter().text(
+                content.center(),
+                egui::Align2::CENTER_CENTER,
+                "Windows docked here are closed",
+                egui::FontId::proportional(13.0),
+                Color32::GRAY,
+            );
+        }
+
+        for panel in &tile.panels {
+            let placement = if Some(*panel) == active {
+                DockPlacement::Docked(content)
+            } else {
+                DockPlacement::Hidden
+            };
+            self.placements.insert(*panel, placement);
+        }
+    }
+
+    /// Short description of the layout for menus and status text
+    pub fn summary(&self) -> RichText {
+        let docked = DockPanel::ALL
+            .iter()
+            .filter(|panel| self.layout.contains(**panel))
+            .count();
+        let text = match (self.active_profile(), docked) {
+            (_, 0) => "All windows floating".to_string(),
+            (Some(name), _) => format!("{} docked - layout '{}'", docked, name),
+            (None, _) => format!("{} docked", docked),
+        };
+        RichText::new(text).small()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn area() -> Rect {
+        Rect::from_min_size(Pos2::ZERO, Vec2::new(1000.0, 600.0))
+    }
+
+    #[test]
+    fn test_docking_as_tab_and_splits() {
+        let mut layout = DockLayout::default();
+        layout.dock(DockPanel::Explorer, DockTarget::Tab);
+        layout.dock(DockPanel::Logs, DockTarget::Tab);
+        layout.dock(DockPanel::Agents, DockTarget::Right);
+
+        let (tiles, splitters) = layout.arrange(area());
+        assert_eq!(tiles.len(), 2);
+        assert_eq!(tiles[0].panels, vec![DockPanel::Explorer, DockPanel::Logs]);
+        assert_eq!(tiles[0].active, 1);
+        assert_eq!(tiles[1].panels, vec![DockPanel::Agents]);
+        assert_eq!(splitters.len(), 1);
+        assert!(tiles[0].rect.max.x < tiles[1].rect.min.x);
+
+        // Docking again leaves the window where it is
+        layout.dock(DockPanel::Explorer, DockTarget::Below);
+        assert_eq!(layout.arrange(area()).0.len(), 2);
+    }
+
+    #[test]
+    fn test_undock_collapses_empty_tiles() {
+        let mut layout = DockLayout::default();
+        layout.dock(DockPanel::Explorer, DockTarget::Tab);
+        layout.dock(DockPanel::CloudTrail, DockTarget::Below);
+
+        layout.undock(DockPanel::CloudTrail);
+        assert_eq!(layout.root, Some(DockNode::tabs(DockPanel::Explorer)));
+
+        layout.undock(DockPanel::Explorer);
+        assert!(layout.is_empty());
+    }
+
+    #[test]
+    fn test_split_off_moves_tab_into_new_tile() {
+        let mut layout = DockLayout::default();
+        layout.dock(DockPanel::Explorer, DockTarget::Tab);
+        layout.split_off(DockPanel::Explorer, SplitDirection::Vertical);
+        assert_eq!(layout.arrange(area()).0.len(), 1, "a lone tab stays");
+
+        layout.dock(DockPanel::Logs, DockTarget::Tab);
+        layout.split_off(DockPanel::Logs, SplitDirection::Vertical);
+        let (tiles, _) = layout.arrange(area());
+        assert_eq!(tiles.len(), 2);
+        assert_eq!(tiles[1].panels, vec![DockPanel::Logs]);
+        assert!(tiles[0].rect.max.y < tiles[1].rect.min.y);
+    }
+
+    #[test]
+    fn test_set_fraction_is_clamped() {
+        let mut layout = DockLayout::default();
+        layout.dock(DockPanel::Explorer, DockTarget::Tab);
+        layout.dock(DockPanel::Agents, DockTarget::Right);
+        layout.set_fraction(&[], 0.01);
+
+        let (tiles, _) = layout.arrange(area());
+        let expected = area().width() * MIN_SPLIT_FRACTION - SPLITTER_WIDTH / 2.0;
+        assert!((tiles[0].rect.width() - expected).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_profiles_round_trip_through_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DockLayoutStore::new(dir.path().join("layouts.toml"));
+        let mut manager = DockManager::new(Some(store.clone()));
+        manager
+            .layout_mut()
+            .dock(DockPanel::Explorer, DockTarget::Tab);
+        manager
+            .layout_mut()
+            .dock(DockPanel::Logs, DockTarget::Below);
+        manager.save_profile("Investigate").unwrap();
+
+        let restored = DockManager::new(Some(store));
+        assert_eq!(restored.active_profile(), Some("Investigate"));
+        assert_eq!(restored.layout(), manager.layout());
+    }
+
+    #[test]
+    fn test_delete_active_profile_keeps_current_layout() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DockLayoutStore::new(dir.path().join("layouts.toml"));
+        let mut manager = DockManager::new(Some(store));
+        manager
+            .layout_mut()
+            .dock(DockPanel::Agents, DockTarget::Tab);
+        manager.save_profile("Agents").unwrap();
+
+        manager.delete_profile("Agents").unwrap();
+        assert_eq!(manager.active_profile(), None);
+        assert_eq!(manager.profile_names().count(), 0);
+        assert!(manager.layout().contains(DockPanel::Agents));
+        assert!(manager.load_profile("Agents").is_err());
+    }
+}
@@ -0,0 +1,219 @@
+//! Window Layouts Window
+//!
+//! Docks and undocks the major windows, moves them into their own OS windows,
+//! and manages the named layout profiles of the [`DockManager`]. Tabs of docked
+//! windows also offer Split and Undock from their context menu.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+
+use super::command_registry::{CommandCategory, PaletteCommand};
+use super::dock_layout::{DockManager, DockPanel, DockTarget};
+use super::native_windows::NativeWindowManager;
+
+/// Palette command id for opening the window
+pub const MANAGE_LAYOUTS: &str = "general.window_layouts";
+/// Palette command id for letting every docked window float again
+pub const UNDOCK_ALL: &str = "general.undock_all";
+
+/// Commands the window offers in the palette
+pub fn palette_commands() -> Vec<PaletteCommand> {
+    vec![
+        PaletteCommand {
+            id: MANAGE_LAYOUTS,
+            label: "Manage window layouts",
+            description: "Dock windows into tiles and save or load layout profiles",
+            category: CommandCategory::General,
+            keywords: &["dock", "tile", "split", "tabs", "profile"],
+            is_available: |_| true,
+        },
+        PaletteCommand {
+            id: UNDOCK_ALL,
+            label: "Undock all windows",
+            description: "Let every docked window float again",
+            category: CommandCategory::General,
+            keywords: &["dock", "float", "layout"],
+            is_available: |_| true,
+        },
+    ]
+}
+
+#[derive(Default)]
+pub struct DockLayoutWindow {
+    open: bool,
+    /// Name typed for saving the current layout
+    profile_name: String,
+    /// Outcome of the last action
+    message: Option<(String, bool)>,
+}
+
+impl DockLayoutWindow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn open(&mut self, manager: &DockManager) {
+        self.open = true;
+        self.profile_name = manager.active_profile().unwrap_or_default().to_string();
+        self.message = None;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        manager: &mut DockManager,
+        native_windows: &mut NativeWindowManager,
+    ) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Window Layouts")
+            .open(&mut is_open)
+            .default_size([460.0, 420.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                self.render_content(ui, manager, native_windows);
+            });
+        self.open = is_open;
+    }
+
+    fn render_content(
+        &mut self,
+        ui: &mut Ui,
+        manager: &mut DockManager,
+        native_windows: &mut NativeWindowManager,
+    ) {
+        ui.label(manager.summary());
+        ui.add_space(4.0);
+
+        ui.heading("Windows");
+        egui::Grid::new("dock_panels_grid")
+            .num_columns(3)
+            .striped(true)
+            .show(ui, |ui| {
+                for panel in DockPanel::ALL {
+                    ui.label(panel.label());
+                    let docked = manager.layout().contains(panel);
+                    let moved_out = native_windows.is_moved_out(panel);
+                    ui.label(if moved_out {
+                        "Own window"
+                    } else if docked {
+                        "Docked"
+                    } else {
+                        "Floating"
+                    });
+                    ui.horizontal(|ui| {
+                        if moved_out {
+                            if ui.button("Return to Main Window").clicked() {
+                                native_windows.return_to_main(panel);
+                            }
+                        } else if docked {
+                            if ui.button("Undock").clicked() {
+                                manager.layout_mut().undock(panel);
+                            }
+                        } else {
+                            let targets = [
+                                ("Dock as Tab", DockTarget::Tab),
+                                ("Dock Right", DockTarget::Right),
+                                ("Dock Below", DockTarget::Below),
+                            ];
+                            for (label, target) in targets {
+                                if ui.button(label).clicked() {
+                                    manager.layout_mut().dock(panel, target);
+                                }
+                            }
+                        }
+                        if !moved_out
+                            && ui
+                                .button("Own Window")
+                                .on_hover_text(
+                                    "Show in a separate OS window, e.g. on another monitor",
+                                )
+                                .clicked()
+                        {
+                            native_windows.move_out(panel);
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+        if ui
+            .add_enabled(
+                !manager.layout().is_empty(),
+                egui::Button::new("Undock All"),
+            )
+            .clicked()
+        {
+            manager.layout_mut().undock_all();
+        }
+
+        ui.separator();
+        ui.heading("Profiles");
+        let names: Vec<String> = manager.profile_names().cloned().collect();
+        if names.is_empty() {
+            ui.label(RichText::new("No saved layouts").italics());
+        }
+        for name in names {
+            ui.horizontal(|ui| {
+                let is_active = manager.active_profile() == Some(name.as_str());
+                let text = if is_active {
+                    RichText::new(&name).strong()
+                } else {
+                    RichText::new(&name)
+                };
+                ui.label(text);
+                if ui.small_button("Load").clicked() {
+                    self.message = Some(match manager.load_profile(&name) {
+                        Ok(()) => {
+                            self.profile_name = name.clone();
+                            (format!("Loaded '{}'", name), false)
+                        }
+                        Err(e) => (format!("{:#}", e), true),
+                    });
+                }
+                if ui.small_button("Delete").clicked() {
+                    self.message = Some(match manager.delete_profile(&name) {
+                        Ok(()) => (format!("Deleted '{}'", name), false),
+                        Err(e) => (format!("{:#}", e), true),
+                    });
+                }
+            });
+        }
+
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            ui.label("Name:");
+            ui.text_edit_singleline(&mut self.profile_name);
+            let name = self.profile_name.trim().to_string();
+            if ui
+                .add_enabled(!name.is_empty(), egui::Button::new("Save Current Layout"))
+                .on_hover_text(
+                    "Save the docked windows under this name, replacing a layout of the same name",
+                )
+                .clicked()
+            {
+                self.message = Some(match manager.save_profile(&name) {
+                    Ok(()) => (format!("Saved '{}'", name), false),
+                    Err(e) => (format!("{:#}", e), true),
+                });
+            }
+        });
+
+        if let Some((message, is_error)) = &self.message {
+            let color = if *is_error {
+                Color32::from_rgb(220, 80, 80)
+            } else {
+                Color32::GRAY
+            };
+            ui.label(RichText::new(message).small().color(color));
+        }
+    }
+}
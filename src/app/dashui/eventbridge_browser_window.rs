@@ -0,0 +1,693 @@
+//! EventBridge Browser Window
+//!
+//! Lists the rules of an event bus with their event patterns and targets, and
+//! lets the user check a test event against every rule pattern or send it to
+//! the bus. Checking uses TestEventPattern and has no side effects; sending
+//! invokes the targets of matching rules, so it asks for confirmation first.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::eventbridge_events::{
+    EventBridgeEventsClient, EventRule, PutEventResult, RuleTarget, TestEvent,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use crate::app::resource_explorer::normalizers::eventbridge::target_resource;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Parameters for showing the EventBridge browser
+#[derive(Clone)]
+pub struct EventBridgeBrowserShowParams {
+    pub event_bus_name: String,
+    /// Rule to select once the rules are loaded
+    pub rule_name: Option<String>,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Results from background threads
+enum BrowserEvent {
+    RulesLoaded(Result<Vec<EventRule>, String>),
+    PatternChecked {
+        rule_name: String,
+        result: Result<bool, String>,
+    },
+    EventSent(Result<PutEventResult, String>),
+}
+
+pub struct EventBridgeBrowserWindow {
+    pub open: bool,
+    // Display parameters
+    event_bus_name: String,
+    account_id: String,
+    region: String,
+
+    // Rules
+    rules: Vec<EventRule>,
+    loading: bool,
+    error_message: Option<String>,
+    filter: String,
+    selected_rule: Option<String>,
+
+    // Test event
+    test_event: TestEvent,
+    /// Resource ARNs, comma separated as typed
+    resources_input: String,
+    /// TestEventPattern result per rule name for the last check
+    pattern_matches: HashMap<String, Result<bool, String>>,
+    checks_pending: usize,
+    confirm_send: bool,
+    sending: bool,
+    send_result: Option<Result<PutEventResult, String>>,
+
+    // Services
+    client: Arc<EventBridgeEventsClient>,
+
+    // Channel for results from background threads.
+    // Replaced when the window opens on a bus so results for another bus are dropped.
+    sender: mpsc::Sender<BrowserEvent>,
+    receiver: mpsc::Receiver<BrowserEvent>,
+}
+
+impl EventBridgeBrowserWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            event_bus_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            rules: Vec::new(),
+            loading: false,
+            error_message: None,
+            filter: String::new(),
+            selected_rule: None,
+            test_event: TestEvent::default(),
+            resources_input: String::new(),
+            pattern_matches: HashMap::new(),
+            checks_pending: 0,
+            confirm_send: false,
+            sending: false,
+            send_result: None,
+            client: Arc::new(EventBridgeEventsClient::new(credential_coordinator)),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on an event bus and load its rules
+    pub fn open_for_bus(&mut self, params: EventBridgeBrowserShowParams) {
+        let (sender, receiver) = mpsc::channel();
+        self.sender = sender;
+        self.receiver = receiver;
+        self.event_bus_name = params.event_bus_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.selected_rule = params.rule_name;
+        self.pattern_matches.clear();
+        self.checks_pending = 0;
+        self.sending = false;
+        self.send_result = None;
+        self.confirm_send = false;
+        self.open = true;
+        self.load_rules();
+    }
+
+    fn load_rules(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+        let (account_id, region, bus) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.event_bus_name.clone(),
+        );
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client.list_rules(&account_id, &region, &bus).await;
+            if let Err(e) = &result {
+                log::error!("Failed to load EventBridge rules of {}: {}", bus, e);
+            }
+            BrowserEvent::RulesLoaded(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    /// Check the test event against the pattern of every rule that has one
+    fn check_patterns(&mut self) {
+        self.pattern_matches.clear();
+        self.checks_pending = 0;
+        let event = self.current_event();
+        if let Err(e) = event.validate() {
+            self.error_message = Some(e);
+            return;
+        }
+        self.error_message = None;
+
+        for rule in &self.rules {
+            let Some(pattern) = rule.event_pattern.clone() else {
+                continue;
+            };
+            self.checks_pending += 1;
+            let (account_id, region, rule_name, event) = (
+                self.account_id.clone(),
+                self.region.clone(),
+                rule.name.clone(),
+                event.clone(),
+            );
+            spawn_with_client(&self.client, &self.sender, move |client| async move {
+                let result = client
+                    .test_event_pattern(&account_id, &region, &pattern, &event)
+                    .await
+                    .map_err(|e| format!("{:#}", e));
+                BrowserEvent::PatternChecked { rule_name, result }
+            });
+        }
+    }
+
+    fn send_event(&mut self) {
+        self.confirm_send = false;
+        self.sending = true;
+        self.send_result = None;
+        let (account_id, region, bus, event) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.event_bus_name.clone(),
+            self.current_event(),
+        );
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .put_test_event(&account_id, &region, &bus, &event)
+                .await
+                .map_err(|e| {
+                    log::error!("Failed to send test event to {}: {}", bus, e);
+                    format!("{:#}", e)
+                });
+            BrowserEvent::EventSent(result)
+        });
+    }
+
+    /// The test event with the resources field parsed
+    fn current_event(&self) -> TestEvent {
+        TestEvent {
+            resources: parse_resources(&self.resources_input),
+            ..self.test_event.clone()
+        }
+    }
+
+    /// Poll for results from background threads
+    fn poll_events(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                BrowserEvent::RulesLoaded(Ok(rules)) => {
+                    self.loading = false;
+                    if !self
+                        .selected_rule
+                        .as_ref()
+                        .is_some_and(|name| rules.iter().any(|rule| &rule.name == name))
+                    {
+                        self.selected_rule = rules.first().map(|rule| rule.name.clone());
+                    }
+                    self.rules = rules;
+                }
+                BrowserEvent::RulesLoaded(Err(e)) => {
+                    self.loading = false;
+                    self.error_message = Some(e);
+                }
+                BrowserEvent::PatternChecked { rule_name, result } => {
+                    self.checks_pending = self.checks_pending.saturating_sub(1);
+                    self.pattern_matches.insert(rule_name, result);
+                }
+                BrowserEvent::EventSent(result) => {
+                    self.sending = false;
+                    self.send_result = Some(result);
+                }
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_events();
+
+        // Request continuous repaint while waiting to show spinners
+        if self.loading || self.sending || self.checks_pending > 0 {
+            ctx.request_repaint();
+        }
+
+        let title = format!("EventBridge: {}", self.event_bus_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "eventbridge_browser",
+                &self.account_id,
+                &self.region,
+                &self.event_bus_name,
+            )))
+            .open(&mut is_open)
+            .default_size([900.0, 620.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Event bus:").strong());
+            ui.label(&self.event_bus_name);
+            ui.label(RichText::new(format!("{} {}", self.account_id, self.region)).weak());
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .add_enabled(!self.loading, egui::Button::new("Reload"))
+                    .clicked()
+                {
+                    self.load_rules();
+                }
+                if self.loading {
+                    ui.spinner();
+                }
+            });
+        });
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error);
+        }
+        ui.separator();
+
+        egui::TopBottomPanel::bottom(egui::Id::new((
+            "eventbridge_test_event",
+            &self.event_bus_name,
+        )))
+        .resizable(true)
+        .default_height(220.0)
+        .show_inside(ui, |ui| {
+            self.render_test_event(ui);
+        });
+
+        egui::SidePanel::left(egui::Id::new(("eventbridge_rules", &self.event_bus_name)))
+            .resizable(true)
+            .default_width(280.0)
+            .show_inside(ui, |ui| {
+                self.render_rule_list(ui);
+            });
+
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .id_salt("eventbridge_rule_details")
+                .show(ui, |ui| match self.selected() {
+                    Some(rule) => render_rule_details(ui, rule),
+                    None if !self.loading => {
+                        ui.label("No rules on this event bus");
+                    }
+                    None => {}
+                });
+        });
+    }
+
+    fn selected(&self) -> Option<&EventRule> {
+        let name = self.selected_rule.as_ref()?;
+        self.rules.iter().find(|rule| &rule.name == name)
+    }
+
+    fn render_rule_list(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Filter:");
+            ui.text_edit_singleline(&mut self.filter);
+        });
+        ui.label(
+            RichText::new(format!("{} rules", self.rules.len()))
+                .small()
+                .weak(),
+        );
+        ui.separator();
+
+        egui::ScrollArea::vertical()
+            .id_salt("eventbridge_rule_list")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for rule in self
+                    .rules
+                    .iter()
+                    .filter(|rule| rule_matches_filter(rule, &self.filter))
+                {
+                    ui.horizontal(|ui| {
+                        // Result of the last pattern check
+                        match self.pattern_matches.get(&rule.name) {
+                            Some(Ok(true)) => {
+                                ui.label(
+                                    RichText::new("+")
+                                        .monospace()
+                                        .color(Color32::from_rgb(100, 200, 100)),
+                                )
+                                .on_hover_text("Matches the test event");
+                            }
+                            Some(Ok(false)) => {
+                                ui.label(RichText::new("-").monospace().weak())
+                                    .on_hover_text("Does not match the test event");
+                            }
+                            Some(Err(e)) => {
+                                ui.label(
+                                    RichText::new("!")
+                                        .monospace()
+                                        .color(Color32::from_rgb(255, 160, 60)),
+                                )
+                                .on_hover_text(e);
+                            }
+                            None => {}
+                        }
+                        let text = if rule.is_enabled() {
+                            RichText::new(&rule.name)
+                        } else {
+                            RichText::new(format!("{} (disabled)", rule.name)).weak()
+                        };
+                        if ui
+                            .selectable_label(self.selected_rule.as_ref() == Some(&rule.name), text)
+                            .clicked()
+                        {
+                            self.selected_rule = Some(rule.name.clone());
+                        }
+                    });
+                }
+            });
+    }
+
+    fn render_test_event(&mut self, ui: &mut Ui) {
+        ui.label(RichText::new("Test Event").strong());
+        egui::Grid::new("eventbridge_test_event_fields")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Source:");
+                ui.text_edit_singleline(&mut self.test_event.source);
+                ui.end_row();
+                ui.label("Detail type:");
+                ui.text_edit_singleline(&mut self.test_event.detail_type);
+                ui.end_row();
+                ui.label("Resources:");
+                ui.text_edit_singleline(&mut self.resources_input)
+                    .on_hover_text("Resource ARNs, comma separated");
+                ui.end_row();
+            });
+        ui.label("Detail:");
+        egui::ScrollArea::vertical()
+            .id_salt("eventbridge_test_event_detail")
+            .max_height(90.0)
+            .show(ui, |ui| {
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.test_event.detail)
+                        .code_editor()
+                        .desired_rows(4)
+                        .desired_width(f32::INFINITY),
+                );
+            });
+
+        ui.horizontal(|ui| {
+            let busy = self.loading || self.checks_pending > 0;
+            if ui
+                .add_enabled(!busy, egui::Button::new("Check Rules"))
+                .on_hover_text("Test the event against every rule pattern; nothing is sent")
+                .clicked()
+            {
+                self.check_patterns();
+            }
+            if self.checks_pending > 0 {
+                ui.spinner();
+            } else if !self.pattern_matches.is_empty() {
+                let matched = self
+                    .pattern_matches
+                    .values()
+                    .filter(|result| matches!(result, Ok(true)))
+                    .count();
+                ui.label(format!(
+                    "{} of {} rules match",
+                    matched,
+                    self.pattern_matches.len()
+                ));
+            }
+
+            if crate::app::capabilities::READ_ONLY {
+                return;
+            }
+            ui.separator();
+            if self.confirm_send {
+                ui.label(
+                    RichText::new("Matching rules will invoke their targets.")
+                        .color(Color32::from_rgb(255, 200, 100)),
+                );
+                if ui.button("Send").clicked() {
+                    self.send_event();
+                }
+                if ui.button("Cancel").clicked() {
+                    self.confirm_send = false;
+                }
+            } else if ui
+                .add_enabled(!self.sending, egui::Button::new("Send to Bus..."))
+                .clicked()
+            {
+                match self.current_event().validate() {
+                    Ok(_) => self.confirm_send = true,
+                    Err(e) => self.send_result = Some(Err(e)),
+                }
+            }
+            if self.sending {
+                ui.spinner();
+            }
+        });
+
+        match &self.send_result {
+            Some(Ok(result)) if result.is_success() => {
+                ui.colored_label(
+                    Color32::from_rgb(100, 200, 100),
+                    format!(
+                        "Sent, event ID {}",
+                        result.event_id.as_deref().unwrap_or_default()
+                    ),
+                );
+            }
+            Some(Ok(result)) => {
+                ui.colored_label(
+                    Color32::from_rgb(255, 100, 100),
+                    format!(
+                        "Rejected: {} {}",
+                        result.error_code.as_deref().unwrap_or("Error"),
+                        result.error_message.as_deref().unwrap_or_default()
+                    ),
+                );
+            }
+            Some(Err(e)) => {
+                ui.colored_label(Color32::from_rgb(255, 100, 100), e);
+            }
+            None => {}
+        }
+    }
+}
+
+/// Pattern or schedule, then the targets of a rule
+fn render_rule_details(ui: &mut Ui, rule: &EventRule) {
+    ui.heading(&rule.name);
+    if let Some(description) = &rule.description {
+        ui.label(description);
+    }
+    ui.horizontal(|ui| {
+        ui.label(RichText::new("State:").strong());
+        ui.label(rule.state.as_deref().unwrap_or("Unknown"));
+        if let Some(managed_by) = &rule.managed_by {
+            ui.label(RichText::new(format!("managed by {}", managed_by)).weak());
+        }
+    });
+    if let Some(arn) = &rule.arn {
+        ui.label(RichText::new(arn).monospace().small().weak());
+    }
+    ui.add_space(6.0);
+
+    if let Some(schedule) = &rule.schedule_expression {
+        ui.label(RichText::new("Schedule").strong());
+        ui.label(RichText::new(schedule).monospace());
+    }
+    if let Some(pattern) = rule.pretty_pattern() {
+        ui.label(RichText::new("Event Pattern").strong());
+        ui.label(RichText::new(pattern).monospace());
+    }
+    ui.add_space(6.0);
+
+    ui.label(RichText::new(format!("Targets ({})", rule.targets.len())).strong());
+    if rule.targets.is_empty() {
+        ui.label(RichText::new("No targets; matching events are dropped").weak());
+    }
+    for target in &rule.targets {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.label(RichText::new(target_summary(target)).strong());
+            ui.label(RichText::new(&target.arn).monospace().small());
+            if let Some(role_arn) = &target.role_arn {
+                ui.label(RichText::new(format!("Role: {}", role_arn)).small().weak());
+            }
+            if let Some(input) = &target.input {
+                ui.label(
+                    RichText::new(format!("Input: {}", input))
+                        .small()
+                        .monospace(),
+                );
+            }
+            if let Some(input_path) = &target.input_path {
+                ui.label(
+                    RichText::new(format!("Input path: {}", input_path))
+                        .small()
+                        .monospace(),
+                );
+            }
+            if let Some(dlq) = &target.dead_letter_arn {
+                ui.label(
+                    RichText::new(format!("Dead-letter queue: {}", dlq))
+                        .small()
+                        .weak(),
+                );
+            }
+        });
+    }
+}
+
+/// "Lambda Function: process-order", or the service and ID for unresolved targets
+fn target_summary(target: &RuleTarget) -> String {
+    match target_resource(&target.arn) {
+        Some((resource_type, resource_id)) => {
+            let type_name = resource_type
+                .strip_prefix("AWS::")
+                .unwrap_or(resource_type)
+                .replace("::", " ");
+            let name = resource_id.rsplit(':').next().unwrap_or(&resource_id);
+            format!("{}: {}", type_name, name)
+        }
+        None => {
+            let service = target.arn.split(':').nth(2).unwrap_or("target");
+            format!("{} ({})", service, target.id)
+        }
+    }
+}
+
+/// Case-insensitive match on the rule name, pattern or schedule
+fn rule_matches_filter(rule: &EventRule, filter: &str) -> bool {
+    let filter = filter.trim().to_lowercase();
+    filter.is_empty()
+        || [
+            Some(rule.name.as_str()),
+            rule.event_pattern.as_deref(),
+            rule.schedule_expression.as_deref(),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|text| text.to_lowercase().contains(&filter))
+}
+
+fn parse_resources(input: &str) -> Vec<String> {
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|arn| !arn.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+impl FocusableWindow for EventBridgeBrowserWindow {
+    type ShowParams = EventBridgeBrowserShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "eventbridge_browser_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("EventBridge: {}", self.event_bus_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open on the bus first
+        self.open_for_bus(params);
+
+        // Then show with focus
+        EventBridgeBrowserWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn target(arn: &str) -> RuleTarget {
+        RuleTarget {
+            id: "t1".to_string(),
+            arn: arn.to_string(),
+            role_arn: None,
+            input: None,
+            input_path: None,
+            dead_letter_arn: None,
+        }
+    }
+
+    #[test]
+    fn test_target_summary() {
+        assert_eq!(
+            target_summary(&target(
+                "arn:aws:lambda:us-east-1:123456789012:function:process-order"
+            )),
+            "Lambda Function: process-order"
+        );
+        assert_eq!(
+            target_summary(&target("arn:aws:sns:us-east-1:123456789012:alerts")),
+            "SNS Topic: alerts"
+        );
+        assert_eq!(
+            target_summary(&target(
+                "arn:aws:kinesis:us-east-1:123456789012:stream/clicks"
+            )),
+            "kinesis (t1)"
+        );
+    }
+
+    #[test]
+    fn test_rule_filter_and_resources() {
+        let rule = EventRule {
+            name: "orders-created".to_string(),
+            event_pattern: Some(r#"{"source":["com.shop"]}"#.to_string()),
+            ..Default::default()
+        };
+        assert!(rule_matches_filter(&rule, ""));
+        assert!(rule_matches_filter(&rule, "ORDERS"));
+        assert!(rule_matches_filter(&rule, "com.shop"));
+        assert!(!rule_matches_filter(&rule, "billing"));
+
+        assert_eq!(
+            parse_resources(" arn:a , ,arn:b"),
+            vec!["arn:a".to_string(), "arn:b".to_string()]
+        );
+    }
+}
@@ -0,0 +1,680 @@
+//! AWS Health Window
+//!
+//! Polls the AWS Health API in the background for open issues, scheduled
+//! changes, and account notifications affecting the authenticated accounts.
+//! A badge in the menu bar counts issues and scheduled changes; the window
+//! lists the events and, for the selected one, its affected entities matched
+//! against resources loaded in the Explorer so they can be shown there.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::health_events::{
+    AccountHealth, EventCategory, EventStatus, HealthEvent, HealthEventsClient, HealthSummary,
+};
+use crate::app::resource_explorer::cache::SharedResourceCache;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use crate::app::resource_explorer::resource_finder::{search_cache, FindQuery, RevealRequest};
+use crate::app::resource_explorer::state::ResourceEntry;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use futures::StreamExt;
+use std::collections::HashSet;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How often the feed is refreshed while logged in
+const POLL_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Accounts queried at the same time
+const MAX_CONCURRENT_ACCOUNTS: usize = 4;
+
+/// Results from the background thread
+enum FeedEvent {
+    AccountPolled(Result<AccountHealth, (String, String)>),
+    Finished,
+}
+
+/// An affected entity with the Explorer resources it matched
+struct EntityMatch {
+    entity_value: String,
+    status: Option<String>,
+    resources: Vec<ResourceEntry>,
+}
+
+pub struct HealthEventsWindow {
+    pub open: bool,
+
+    // Feed
+    events: Vec<HealthEvent>,
+    /// Accounts whose support plan does not include the Health API
+    unsupported_accounts: Vec<String>,
+    errors: Vec<String>,
+    polling: bool,
+    last_poll: Option<Instant>,
+    polled_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    // Filters and selection
+    categories: HashSet<EventCategory>,
+    text_filter: String,
+    selected_event: Option<String>,
+    selected_matches: Vec<EntityMatch>,
+    /// Resource picked with "Show", taken by the app
+    pending_reveal: Option<RevealRequest>,
+
+    // Services
+    client: Option<Arc<HealthEventsClient>>,
+    cache: Option<Arc<SharedResourceCache>>,
+
+    // Channel for results from the background thread.
+    // Replaced on each poll so results from an earlier poll are dropped.
+    sender: mpsc::Sender<FeedEvent>,
+    receiver: mpsc::Receiver<FeedEvent>,
+}
+
+impl Default for HealthEventsWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HealthEventsWindow {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            events: Vec::new(),
+            unsupported_accounts: Vec::new(),
+            errors: Vec::new(),
+            polling: false,
+            last_poll: None,
+            polled_at: None,
+            categories: [
+                EventCategory::Issue,
+                EventCategory::ScheduledChange,
+                EventCategory::AccountNotification,
+                EventCategory::Investigation,
+                EventCategory::Other,
+            ]
+            .into_iter()
+            .collect(),
+            text_filter: String::new(),
+            selected_event: None,
+            selected_matches: Vec::new(),
+            pending_reveal: None,
+            client: None,
+            cache: None,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Whether the feed should be refreshed
+    pub fn poll_due(&self) -> bool {
+        !self.polling
+            && self
+                .last_poll
+                .map_or(true, |last_poll| last_poll.elapsed() >= POLL_INTERVAL)
+    }
+
+    /// Refresh the feed for the given accounts in the background
+    pub fn start_poll(
+        &mut self,
+        credential_coordinator: Arc<CredentialCoordinator>,
+        account_ids: Vec<String>,
+        cache: Arc<SharedResourceCache>,
+    ) {
+        let client = Arc::new(HealthEventsClient::new(credential_coordinator));
+        self.client = Some(Arc::clone(&client));
+        self.cache = Some(cache);
+        self.last_poll = Some(Instant::now());
+        if account_ids.is_empty() {
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        self.sender = sender;
+        self.receiver = receiver;
+        self.polling = true;
+        self.errors.clear();
+        self.unsupported_accounts.clear();
+        // Earlier events stay listed until the new ones arrive
+        let sender = self.sender.clone();
+        spawn_with_client(&client, &self.sender, move |client| async move {
+            futures::stream::iter(account_ids)
+                .for_each_concurrent(MAX_CONCURRENT_ACCOUNTS, |account_id| {
+                    let client = Arc::clone(&client);
+                    let sender = sender.clone();
+                    async move {
+                        let result = client.describe_open_events(&account_id).await.map_err(|e| {
+                            log::warn!("Failed to poll AWS Health for {}: {}", account_id, e);
+                            (account_id.clone(), format!("{:#}", e))
+                        });
+                        let _ = sender.send(FeedEvent::AccountPolled(result));
+                    }
+                })
+                .await;
+            FeedEvent::Finished
+        });
+    }
+
+    /// Take the resource picked with "Show" to reveal in the Explorer
+    pub fn take_reveal_request(&mut self) -> Option<RevealRequest> {
+        self.pending_reveal.take()
+    }
+
+    /// Counts across every account polled
+    pub fn summary(&self) -> HealthSummary {
+        HealthSummary::from_events(&self.events)
+    }
+
+    /// Poll for results from the background thread
+    fn poll_events(&mut self) {
+        let mut polled = Vec::new();
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                FeedEvent::AccountPolled(Ok(health)) => {
+                    if health.subscription_required {
+                        self.unsupported_accounts.push(health.account_id.clone());
+                    }
+                    polled.push(health);
+                }
+                FeedEvent::AccountPolled(Err((account_id, e))) => {
+                    self.errors.push(format!("{}: {}", account_id, e));
+                }
+                FeedEvent::Finished => {
+                    self.polling = false;
+                    self.polled_at = Some(chrono::Utc::now());
+                    tracing::info!(
+                        "AWS Health: {} open events, {} accounts without Health API access",
+                        self.events.len(),
+                        self.unsupported_accounts.len()
+                    );
+                }
+            }
+        }
+
+        // Replace each polled account's events
+        for health in polled {
+            self.events
+                .retain(|event| event.account_id != health.account_id);
+            self.events.extend(health.events);
+        }
+        sort_events(&mut self.events);
+
+        if self
+            .selected_event
+            .as_ref()
+            .is_some_and(|arn| !self.events.iter().any(|event| &event.arn == arn))
+        {
+            self.select_event(None);
+        }
+    }
+
+    fn select_event(&mut self, arn: Option<String>) {
+        self.selected_matches.clear();
+        if let (Some(arn), Some(cache)) = (&arn, &self.cache) {
+            if let Some(event) = self.events.iter().find(|event| &event.arn == arn) {
+                self.selected_matches = event
+                    .affected_entities
+                    .iter()
+                    .map(|entity| EntityMatch {
+                        entity_value: entity.entity_value.clone(),
+                        status: entity.status.clone(),
+                        resources: cached_resources_for_entity(
+                            cache,
+                            &event.account_id,
+                            &entity.entity_value,
+                        ),
+                    })
+                    .collect();
+            }
+        }
+        self.selected_event = arn;
+    }
+
+    /// Events matching the category and text filters
+    fn visible_events(&self) -> Vec<&HealthEvent> {
+        let filter = self.text_filter.to_lowercase();
+        self.events
+            .iter()
+            .filter(|event| self.categories.contains(&event.category))
+            .filter(|event| {
+                filter.is_empty()
+                    || [
+                        event.event_type_code.as_str(),
+                        event.service.as_str(),
+                        event.account_id.as_str(),
+                        event.region.as_deref().unwrap_or_default(),
+                    ]
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&filter))
+            })
+            .collect()
+    }
+
+    /// Menu bar badge with the count of issues and scheduled changes;
+    /// returns true when clicked
+    pub fn render_badge(&self, ui: &mut Ui) -> bool {
+        let summary = self.summary();
+        if summary.attention_count() == 0 {
+            return false;
+        }
+        let color = if summary.open_issues > 0 {
+            Color32::from_rgb(230, 130, 60)
+        } else {
+            Color32::from_rgb(220, 180, 50)
+        };
+        ui.button(
+            RichText::new(format!("Health: {}", summary.attention_count()))
+                .size(12.0)
+                .strong()
+                .color(color),
+        )
+        .on_hover_text(format!(
+            "AWS Health: {} open issues, {} scheduled changes, {} notifications",
+            summary.open_issues, summary.scheduled_changes, summary.notifications
+        ))
+        .clicked()
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        // The feed updates the badge even while the window is closed
+        self.poll_events();
+        if self.polling {
+            ctx.request_repaint_after(Duration::from_millis(500));
+        } else if let Some(last_poll) = self.last_poll {
+            ctx.request_repaint_after(POLL_INTERVAL.saturating_sub(last_poll.elapsed()));
+        }
+
+        if !self.open {
+            return;
+        }
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new("AWS Health")
+            .open(&mut is_open)
+            .default_size([900.0, 520.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.render_toolbar(ui);
+            ui.separator();
+            self.render_events(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn render_toolbar(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    !self.polling && self.client.is_some(),
+                    egui::Button::new("Refresh"),
+                )
+                .on_disabled_hover_text("The feed starts after logging in")
+                .clicked()
+            {
+                // Polled again on the next frame
+                self.last_poll = None;
+            }
+            if self.polling {
+                ui.spinner();
+                ui.label("Checking AWS Health...");
+            } else if let Some(polled_at) = self.polled_at {
+                let summary = self.summary();
+                ui.label(format!(
+                    "{} issues, {} scheduled changes, {} notifications",
+                    summary.open_issues, summary.scheduled_changes, summary.notifications
+                ));
+                ui.label(
+                    RichText::new(format!(
+                        "checked {}",
+                        polled_at.with_timezone(&chrono::Local).format("%H:%M:%S")
+                    ))
+                    .weak(),
+                );
+            }
+        });
+
+        ui.horizontal(|ui| {
+            for category in [
+                EventCategory::Issue,
+                EventCategory::ScheduledChange,
+                EventCategory::AccountNotification,
+            ] {
+                let count = self
+                    .events
+                    .iter()
+                    .filter(|event| event.category == category)
+                    .count();
+                let mut shown = self.categories.contains(&category);
+                if ui
+                    .checkbox(&mut shown, format!("{} ({})", category.label(), count))
+                    .changed()
+                {
+                    if shown {
+                        self.categories.insert(category);
+                    } else {
+                        self.categories.remove(&category);
+                    }
+                }
+            }
+            ui.separator();
+            ui.add(
+                egui::TextEdit::singleline(&mut self.text_filter)
+                    .hint_text("Filter events")
+                    .desired_width(180.0),
+            );
+        });
+
+        if !self.unsupported_accounts.is_empty() {
+            ui.label(
+                RichText::new(format!(
+                    "{} accounts have no AWS Health API access (requires a Business or Enterprise support plan)",
+                    self.unsupported_accounts.len()
+                ))
+                .small()
+                .weak(),
+            )
+            .on_hover_text(self.unsupported_accounts.join(", "));
+        }
+        for error in &self.errors {
+            ui.colored_label(ui.visuals().error_fg_color, RichText::new(error).small());
+        }
+    }
+
+    fn render_events(&mut self, ui: &mut Ui) {
+        let mut clicked = None;
+        let selected = self.selected_event.clone();
+
+        egui::SidePanel::left("health_events_list")
+            .resizable(true)
+            .default_width(380.0)
+            .show_inside(ui, |ui| {
+                let events = self.visible_events();
+                if events.is_empty() {
+                    if self.polled_at.is_some() {
+                        ui.label("No open events");
+                    }
+                    return;
+                }
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .show(ui, |ui| {
+                        for event in events {
+                            let is_selected = selected.as_ref() == Some(&event.arn);
+                            let text = RichText::new(format!(
+                                "{}  {}",
+                                event.category.label(),
+                                event.title()
+                            ))
+                            .color(category_color(ui, event.category));
+                            let response = ui.selectable_label(is_selected, text);
+                            ui.label(
+                                RichText::new(format!(
+                                    "{}  {}  {}",
+                                    event.account_id,
+                                    event.region.as_deref().unwrap_or("global"),
+                                    event.start_time.map(format_time).unwrap_or_default()
+                                ))
+                                .small()
+                                .weak(),
+                            );
+                            if response.clicked() {
+                                clicked = Some(event.arn.clone());
+                            }
+                        }
+                    });
+            });
+
+        if let Some(arn) = clicked {
+            self.select_event(Some(arn));
+        }
+
+        let Some(event) = self
+            .selected_event
+            .as_ref()
+            .and_then(|arn| self.events.iter().find(|event| &event.arn == arn))
+        else {
+            ui.label("Select an event to see its details and affected resources");
+            return;
+        };
+
+        let mut reveal = None;
+        egui::ScrollArea::vertical()
+            .id_salt("health_event_detail")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                ui.heading(event.title());
+                egui::Grid::new("health_event_fields")
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        let field = |ui: &mut Ui, name: &str, value: String| {
+                            ui.label(RichText::new(name).strong());
+                            ui.label(value);
+                            ui.end_row();
+                        };
+                        field(ui, "Service", event.service.clone());
+                        field(ui, "Category", event.category.label().to_string());
+                        field(ui, "Status", status_label(event.status).to_string());
+                        field(ui, "Account", event.account_id.clone());
+                        field(
+                            ui,
+                            "Region",
+                            event.region.clone().unwrap_or_else(|| "global".to_string()),
+                        );
+                        if let Some(zone) = &event.availability_zone {
+                            field(ui, "Availability zone", zone.clone());
+                        }
+                        if let Some(start) = event.start_time {
+                            field(ui, "Start", format_time(start));
+                        }
+                        if let Some(end) = event.end_time {
+                            field(ui, "End", format_time(end));
+                        }
+                        if let Some(updated) = event.last_updated_time {
+                            field(ui, "Updated", format_time(updated));
+                        }
+                    });
+
+                if let Some(description) = &event.description {
+                    ui.add_space(6.0);
+                    ui.label(description);
+                }
+
+                ui.add_space(6.0);
+                ui.label(
+                    RichText::new(format!(
+                        "Affected entities ({})",
+                        self.selected_matches.len()
+                    ))
+                    .strong(),
+                );
+                if self.selected_matches.is_empty() {
+                    ui.label(RichText::new("The event does not name specific resources").weak());
+                }
+                egui::Grid::new("health_event_entities")
+                    .num_columns(3)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for entity in &self.selected_matches {
+                            ui.label(&entity.entity_value);
+                            ui.label(entity.status.as_deref().unwrap_or("-"));
+                            ui.horizontal(|ui| {
+                                if entity.resources.is_empty() {
+                                    ui.label(RichText::new("Not loaded in the Explorer").weak());
+                                }
+                                for resource in &entity.resources {
+                                    if ui
+                                        .small_button(format!("Show {}", resource.display_name))
+                                        .on_hover_text(format!(
+                                            "{} in {} {}",
+                                            resource.resource_type,
+                                            resource.account_id,
+                                            resource.region
+                                        ))
+                                        .clicked()
+                                    {
+                                        reveal = Some(RevealRequest {
+                                            query: FindQuery::ResourceId(
+                                                resource.resource_id.clone(),
+                                            ),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                            resource_type: resource.resource_type.clone(),
+                                            selection_key: Some(resource.selection_key()),
+                                        });
+                                    }
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        if reveal.is_some() {
+            self.pending_reveal = reveal;
+        }
+    }
+}
+
+/// Explorer resources an affected entity refers to, in the event's account
+fn cached_resources_for_entity(
+    cache: &SharedResourceCache,
+    account_id: &str,
+    entity_value: &str,
+) -> Vec<ResourceEntry> {
+    let Some(query) = FindQuery::parse(entity_value) else {
+        return Vec::new();
+    };
+    search_cache(cache, &query)
+        .into_iter()
+        .filter(|resource| resource.account_id == account_id)
+        .collect()
+}
+
+/// Issues first, then scheduled changes, then the rest; newest first within each
+fn sort_events(events: &mut [HealthEvent]) {
+    let rank = |category: EventCategory| match category {
+        EventCategory::Issue => 0,
+        EventCategory::ScheduledChange => 1,
+        EventCategory::Investigation => 2,
+        EventCategory::AccountNotification => 3,
+        EventCategory::Other => 4,
+    };
+    events.sort_by(|a, b| {
+        rank(a.category)
+            .cmp(&rank(b.category))
+            .then_with(|| b.last_updated_time.cmp(&a.last_updated_time))
+            .then_with(|| a.arn.cmp(&b.arn))
+    });
+}
+
+fn category_color(ui: &Ui, category: EventCategory) -> Color32 {
+    match category {
+        EventCategory::Issue => Color32::from_rgb(230, 130, 60),
+        EventCategory::ScheduledChange => ui.visuals().warn_fg_color,
+        _ => ui.visuals().text_color(),
+    }
+}
+
+fn status_label(status: EventStatus) -> &'static str {
+    match status {
+        EventStatus::Open => "Open",
+        EventStatus::Upcoming => "Upcoming",
+        EventStatus::Closed => "Closed",
+    }
+}
+
+fn format_time(time: chrono::DateTime<chrono::Utc>) -> String {
+    time.with_timezone(&chrono::Local)
+        .format("%Y-%m-%d %H:%M")
+        .to_string()
+}
+
+impl FocusableWindow for HealthEventsWindow {
+    type ShowParams = crate::app::dashui::window_focus::SimpleShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "health_events_window"
+    }
+
+    fn window_title(&self) -> String {
+        "AWS Health".to_string()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        _params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        HealthEventsWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(arn: &str, category: EventCategory, updated_secs: i64) -> HealthEvent {
+        HealthEvent {
+            arn: arn.to_string(),
+            account_id: "123456789012".to_string(),
+            service: "EC2".to_string(),
+            event_type_code: "AWS_EC2_OPERATIONAL_ISSUE".to_string(),
+            category,
+            status: EventStatus::Open,
+            region: Some("us-east-1".to_string()),
+            availability_zone: None,
+            start_time: None,
+            end_time: None,
+            last_updated_time: chrono::DateTime::from_timestamp(updated_secs, 0),
+            description: None,
+            affected_entities: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_events_order_and_filter() {
+        let mut window = HealthEventsWindow::new();
+        window.events = vec![
+            event("notification", EventCategory::AccountNotification, 300),
+            event("change", EventCategory::ScheduledChange, 200),
+            event("old-issue", EventCategory::Issue, 100),
+            event("new-issue", EventCategory::Issue, 400),
+        ];
+        sort_events(&mut window.events);
+
+        let arns: Vec<&str> = window
+            .visible_events()
+            .iter()
+            .map(|e| e.arn.as_str())
+            .collect();
+        assert_eq!(arns, ["new-issue", "old-issue", "change", "notification"]);
+        assert_eq!(window.summary().attention_count(), 3);
+
+        window.categories.remove(&EventCategory::Issue);
+        assert_eq!(window.visible_events().len(), 2);
+    }
+}
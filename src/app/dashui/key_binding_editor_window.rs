@@ -0,0 +1,660 @@
+//! Keyboard Shortcuts Window
+//!
+//! Edits the key bindings of the keyboard navigation system: the bindings of
+//! each navigation mode, window-specific overrides, and, for reference, the
+//! shortcuts the app handles itself. Bindings that collide or cannot be typed
+//! are flagged and keep the map from being saved. Saved maps go to the config
+//! directory (see [`KeyBindingStore`]) and take effect right away; any map can
+//! be exported to or imported from a TOML file.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+
+use super::command_registry::{CommandCategory, PaletteCommand};
+use super::key_mapping::{
+    is_valid_sequence, mode_name, KeyBindingMap, KeyBindingStore, KeyConflict, KeyMapping, COMMANDS,
+};
+use super::keyboard_navigation::NavigationMode;
+
+pub const EDIT_KEYBOARD_SHORTCUTS: &str = "general.keyboard_shortcuts";
+
+/// Commands the window offers in the palette
+pub fn palette_commands() -> Vec<PaletteCommand> {
+    vec![PaletteCommand {
+        id: EDIT_KEYBOARD_SHORTCUTS,
+        label: "Edit keyboard shortcuts",
+        description: "Rebind navigation keys and window shortcuts",
+        category: CommandCategory::General,
+        keywords: &["keybindings", "keys", "hotkeys", "vim"],
+        is_available: |_| true,
+    }]
+}
+
+const MODES: [NavigationMode; 5] = [
+    NavigationMode::Normal,
+    NavigationMode::Insert,
+    NavigationMode::Hint,
+    NavigationMode::Visual,
+    NavigationMode::Command,
+];
+
+/// Shortcuts the app handles outside the key binding map
+const FIXED_SHORTCUTS: &[(&str, &str)] = &[
+    ("Space", "Open the command palette (any mode)"),
+    ("Escape", "Clear the pending key sequence (normal mode)"),
+    ("Space L", "Login to AWS"),
+    ("Space E", "Open Explorer"),
+    ("Space F", "Find resource"),
+    ("Space M", "Open Agent Manager"),
+    ("Space /", "Search commands"),
+    ("Space Q", "Quit"),
+];
+
+/// Bindings shown in the table
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Scope {
+    Mode(NavigationMode),
+    Window(String),
+}
+
+impl Scope {
+    fn label(&self) -> String {
+        match self {
+            Scope::Mode(mode) => format!("{:?} mode", mode),
+            Scope::Window(window_id) => format!("Window: {}", window_id),
+        }
+    }
+
+    fn owns(&self, conflict: &KeyConflict) -> bool {
+        match self {
+            Scope::Mode(mode) => !conflict.window_override && conflict.scope == mode_name(*mode),
+            Scope::Window(window_id) => conflict.window_override && conflict.scope == *window_id,
+        }
+    }
+}
+
+/// Whether a mapping's keys can be typed in every mode it is active in
+fn is_valid_mapping(mapping: &KeyMapping) -> bool {
+    mapping.modes.iter().all(|m| match m.as_str() {
+        "all" => MODES
+            .iter()
+            .any(|mode| is_valid_sequence(*mode, &mapping.sequence)),
+        _ => MODES
+            .iter()
+            .find(|mode| mode_name(**mode) == m.as_str())
+            .is_some_and(|mode| is_valid_sequence(*mode, &mapping.sequence)),
+    })
+}
+
+fn command_description(command: &str) -> Option<&'static str> {
+    COMMANDS
+        .iter()
+        .find(|(name, _)| *name == command)
+        .map(|(_, description)| *description)
+}
+
+/// State for the keyboard shortcuts window
+pub struct KeyBindingEditorWindow {
+    open: bool,
+    /// Map being edited
+    bindings: KeyBindingMap,
+    /// Map in effect, to detect unsaved changes
+    saved: KeyBindingMap,
+    scope: Scope,
+    /// Window ids offered for new overrides
+    window_ids: Vec<&'static str>,
+    /// Window id typed for a new override
+    new_window_id: String,
+    /// File for import and export
+    transfer_path: String,
+    /// Outcome of the last action
+    message: Option<(String, bool)>,
+    store: Option<KeyBindingStore>,
+    /// Map saved since the app last took it
+    applied: Option<KeyBindingMap>,
+}
+
+impl Default for KeyBindingEditorWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl KeyBindingEditorWindow {
+    pub fn new() -> Self {
+        let transfer_path = dirs::home_dir()
+            .unwrap_or_default()
+            .join("awsdash-keybindings.toml")
+            .display()
+            .to_string();
+        Self {
+            open: false,
+            bindings: KeyBindingMap::default(),
+            saved: KeyBindingMap::default(),
+            scope: Scope::Mode(NavigationMode::Normal),
+            window_ids: Vec::new(),
+            new_window_id: String::new(),
+            transfer_path,
+            message: None,
+            store: KeyBindingStore::default_location(),
+            applied: None,
+        }
+    }
+
+    /// Open the window on the bindings in effect
+    ///
+    /// `window_ids` are offered when adding a window override.
+    pub fn open(&mut self, current: &KeyBindingMap, window_ids: Vec<&'static str>) {
+        self.open = true;
+        self.message = None;
+        self.bindings = current.clone();
+        self.saved = current.clone();
+        self.window_ids = window_ids;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Map saved since the last call, for the app to put into effect
+    pub fn take_applied(&mut self) -> Option<KeyBindingMap> {
+        self.applied.take()
+    }
+
+    fn is_modified(&self) -> bool {
+        self.bindings != self.saved
+    }
+
+    /// Why the map cannot be saved, if it cannot
+    fn validation_error(&self, conflicts: &[KeyConflict]) -> Option<String> {
+        let invalid = self
+            .bindings
+            .bindings
+            .values()
+            .chain(self.bindings.window_overrides.values())
+            .flatten()
+            .filter(|mapping| !is_valid_mapping(mapping))
+            .count();
+        if invalid > 0 {
+            return Some(format!(
+                "{} binding(s) use keys that cannot be typed",
+                invalid
+            ));
+        }
+        if !conflicts.is_empty() {
+            return Some(format!("{} conflict(s) to resolve", conflicts.len()));
+        }
+        None
+    }
+
+    fn save(&mut self) {
+        let Some(store) = &self.store else {
+            self.message = Some(("Config directory not available".to_string(), true));
+            return;
+        };
+        if let Some(error) = self.validation_error(&self.bindings.conflicts()) {
+            self.message = Some((error, true));
+            return;
+        }
+        self.message = Some(match store.save(&self.bindings) {
+            Ok(()) => {
+                self.saved = self.bindings.clone();
+                self.applied = Some(self.bindings.clone());
+                (format!("Saved to {}", store.path().display()), false)
+            }
+            Err(e) => (format!("{:#}", e), true),
+        });
+    }
+
+    fn reset_to_defaults(&mut self) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        self.message = Some(match store.reset() {
+            Ok(()) => {
+                tracing::info!("Reset key bindings to defaults");
+                self.bindings = KeyBindingMap::default();
+                self.saved = self.bindings.clone();
+                self.applied = Some(self.bindings.clone());
+                ("Using the default key bindings".to_string(), false)
+            }
+            Err(e) => (format!("{:#}", e), true),
+        });
+    }
+
+    fn import(&mut self) {
+        self.message = Some(match KeyBindingMap::load_from_file(&self.transfer_path) {
+            Ok(bindings) => {
+                self.bindings = bindings;
+                self.scope = Scope::Mode(NavigationMode::Normal);
+                (
+                    format!("Imported {} - review and Save", self.transfer_path),
+                    false,
+                )
+            }
+            Err(e) => (format!("Import failed: {}", e), true),
+        });
+    }
+
+    fn export(&mut self) {
+        self.message = Some(match self.bindings.save_to_file(&self.transfer_path) {
+            Ok(()) => (format!("Exported to {}", self.transfer_path), false),
+            Err(e) => (format!("Export failed: {}", e), true),
+        });
+    }
+
+    /// Mappings of the selected scope
+    fn scope_mappings(&mut self) -> &mut Vec<KeyMapping> {
+        match &self.scope {
+            Scope::Mode(mode) => self
+                .bindings
+                .bindings
+                .entry(mode_name(*mode).to_string())
+                .or_default(),
+            Scope::Window(window_id) => self
+                .bindings
+                .window_overrides
+                .entry(window_id.clone())
+                .or_default(),
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Keyboard Shortcuts")
+            .open(&mut is_open)
+            .default_size([640.0, 560.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                self.render_content(ui);
+            });
+        self.open = is_open;
+    }
+
+    fn render_content(&mut self, ui: &mut Ui) {
+        let conflicts = self.bindings.conflicts();
+
+        self.render_toolbar(ui, &conflicts);
+        ui.separator();
+        self.render_scope_selector(ui);
+
+        if !conflicts.is_empty() {
+            ui.add_space(4.0);
+            for conflict in &conflicts {
+                ui.label(
+                    RichText::new(format!("Conflict: {}", conflict.describe()))
+                        .small()
+                        .color(Color32::from_rgb(220, 80, 80)),
+                );
+            }
+        }
+
+        ui.add_space(4.0);
+        let scope_conflicts: Vec<&KeyConflict> =
+            conflicts.iter().filter(|c| self.scope.owns(c)).collect();
+        ScrollArea::vertical()
+            .id_salt("key_binding_table")
+            .max_height((ui.available_height() - 170.0).max(120.0))
+            .show(ui, |ui| {
+                self.render_bindings_table(ui, &scope_conflicts);
+            });
+
+        ui.add_space(4.0);
+        ui.horizontal(|ui| {
+            if ui.button("+ Add Binding").clicked() {
+                let mode = match &self.scope {
+                    Scope::Mode(mode) => *mode,
+                    Scope::Window(_) => NavigationMode::Normal,
+                };
+                let (command, description) = COMMANDS[0];
+                let mapping = KeyMapping::new(
+                    String::new(),
+                    command.to_string(),
+                    vec![mode_name(mode).to_string()],
+                )
+                .with_description(description.to_string());
+                self.scope_mappings().push(mapping);
+            }
+            if let Scope::Window(window_id) = &self.scope {
+                let window_id = window_id.clone();
+                if ui
+                    .button("Remove Window Overrides")
+                    .on_hover_text("Use the mode bindings in this window again")
+                    .clicked()
+                {
+                    self.bindings.window_overrides.remove(&window_id);
+                    self.scope = Scope::Mode(NavigationMode::Normal);
+                }
+            }
+        });
+
+        ui.separator();
+        self.render_import_export(ui);
+
+        ui.add_space(4.0);
+        egui::CollapsingHeader::new("Built-in shortcuts")
+            .default_open(false)
+            .show(ui, |ui| {
+                egui::Grid::new("fixed_shortcuts_grid")
+                    .num_columns(2)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (keys, description) in FIXED_SHORTCUTS {
+                            ui.label(RichText::new(*keys).monospace());
+                            ui.label(*description);
+                            ui.end_row();
+                        }
+                    });
+                ui.label(
+                    RichText::new("These keys cannot be rebound.")
+                        .small()
+                        .weak(),
+                );
+            });
+    }
+
+    fn render_toolbar(&mut self, ui: &mut Ui, conflicts: &[KeyConflict]) {
+        let blocked = self.validation_error(conflicts);
+        ui.horizontal(|ui| {
+            let save = ui.add_enabled(
+                self.is_modified() && blocked.is_none(),
+                egui::Button::new("Save"),
+            );
+            let save = match &blocked {
+                Some(reason) => save.on_disabled_hover_text(reason.as_str()),
+                None => save,
+            };
+            if save.clicked() {
+                self.save();
+            }
+            if ui
+                .add_enabled(self.is_modified(), egui::Button::new("Revert"))
+                .clicked()
+            {
+                self.bindings = self.saved.clone();
+                self.message = None;
+            }
+            if ui
+                .button("Reset to Defaults")
+                .on_hover_text("Delete the custom key bindings file")
+                .clicked()
+            {
+                self.reset_to_defaults();
+            }
+            if self.is_modified() {
+                ui.label(RichText::new("(unsaved)").italics());
+            }
+            if let Some((message, is_error)) = &self.message {
+                let color = if *is_error {
+                    Color32::from_rgb(220, 80, 80)
+                } else {
+                    Color32::GRAY
+                };
+                ui.label(RichText::new(message).small().color(color));
+            }
+        });
+    }
+
+    fn render_scope_selector(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            let mut selected = self.scope.clone();
+            let mut window_ids: Vec<String> =
+                self.bindings.window_overrides.keys().cloned().collect();
+            window_ids.sort();
+
+            egui::ComboBox::from_id_salt("key_binding_scope")
+                .selected_text(selected.label())
+                .width(200.0)
+                .show_ui(ui, |ui| {
+                    for mode in MODES {
+                        let scope = Scope::Mode(mode);
+                        let label = scope.label();
+                        ui.selectable_value(&mut selected, scope, label);
+                    }
+                    if !window_ids.is_empty() {
+                        ui.separator();
+                    }
+                    for window_id in window_ids {
+                        let scope = Scope::Window(window_id);
+                        let label = scope.label();
+                        ui.selectable_value(&mut selected, scope, label);
+                    }
+                });
+            self.scope = selected;
+
+            ui.separator();
+            ui.label("Override for window:");
+            egui::ComboBox::from_id_salt("key_binding_window_id")
+                .selected_text(if self.new_window_id.is_empty() {
+                    "Choose..."
+                } else {
+                    self.new_window_id.as_str()
+                })
+                .show_ui(ui, |ui| {
+                    for window_id in &self.window_ids {
+                        ui.selectable_value(
+                            &mut self.new_window_id,
+                            window_id.to_string(),
+                            *window_id,
+                        );
+                    }
+                });
+            if ui
+                .add_enabled(!self.new_window_id.is_empty(), egui::Button::new("Add"))
+                .on_hover_text("Bind keys that only apply while this window is focused")
+                .clicked()
+            {
+                let window_id = std::mem::take(&mut self.new_window_id);
+                self.bindings
+                    .window_overrides
+                    .entry(window_id.clone())
+                    .or_default();
+                self.scope = Scope::Window(window_id);
+            }
+        });
+    }
+
+    fn render_bindings_table(&mut self, ui: &mut Ui, conflicts: &[&KeyConflict]) {
+        let scope_is_window = matches!(self.scope, Scope::Window(_));
+        let mut remove = None;
+        let mappings = match &self.scope {
+            Scope::Mode(mode) => self.bindings.bindings.get_mut(mode_name(*mode)),
+            Scope::Window(window_id) => self.bindings.window_overrides.get_mut(window_id),
+        };
+        let Some(mappings) = mappings.filter(|mappings| !mappings.is_empty()) else {
+            ui.label(RichText::new("No bindings").weak());
+            return;
+        };
+
+        egui::Grid::new("key_bindings_grid")
+            .num_columns(if scope_is_window { 5 } else { 4 })
+            .striped(true)
+            .spacing([12.0, 4.0])
+            .show(ui, |ui| {
+                ui.label(RichText::new("Keys").strong());
+                ui.label(RichText::new("Command").strong());
+                if scope_is_window {
+                    ui.label(RichText::new("Mode").strong());
+                }
+                ui.label(RichText::new("Description").strong());
+                ui.label("");
+                ui.end_row();
+
+                for (index, mapping) in mappings.iter_mut().enumerate() {
+                    let conflict = conflicts.iter().find(|c| c.involves(mapping));
+                    let valid = is_valid_mapping(mapping);
+
+                    let mut edit = egui::TextEdit::singleline(&mut mapping.sequence)
+                        .id_salt(("key_binding_sequence", index))
+                        .desired_width(90.0)
+                        .font(egui::TextStyle::Monospace);
+                    if conflict.is_some() || !valid {
+                        edit = edit.text_color(ui.visuals().error_fg_color);
+                    }
+                    let edit = ui.add(edit);
+                    if let Some(conflict) = conflict {
+                        edit.on_hover_text(conflict.describe());
+                    } else if !valid {
+                        edit.on_hover_text(
+                            "Normal mode keys are letters, digits, and ':'; \
+                             other modes take one key name such as Escape or Enter",
+                        );
+                    }
+
+                    let mut command = mapping.command.clone();
+                    egui::ComboBox::from_id_salt(("key_binding_command", index))
+                        .selected_text(command.as_str())
+                        .width(170.0)
+                        .show_ui(ui, |ui| {
+                            for (name, description) in COMMANDS {
+                                ui.selectable_value(&mut command, name.to_string(), *name)
+                                    .on_hover_text(*description);
+                            }
+                        });
+                    if command != mapping.command {
+                        mapping.description = command_description(&command).map(str::to_string);
+                        mapping.command = command;
+                    }
+
+                    if scope_is_window {
+                        let mut mode = mapping.modes.first().cloned().unwrap_or_default();
+                        egui::ComboBox::from_id_salt(("key_binding_mode", index))
+                            .selected_text(mode.as_str())
+                            .width(80.0)
+                            .show_ui(ui, |ui| {
+                                for name in MODES.iter().map(|m| mode_name(*m)).chain(["all"]) {
+                                    ui.selectable_value(&mut mode, name.to_string(), name);
+                                }
+                            });
+                        if mapping.modes.first() != Some(&mode) {
+                            mapping.modes = vec![mode];
+                        }
+                    }
+
+                    let description = mapping
+                        .description
+                        .as_deref()
+                        .or_else(|| command_description(&mapping.command))
+                        .unwrap_or("");
+                    ui.label(description);
+
+                    if ui
+                        .small_button("Remove")
+                        .on_hover_text("Remove binding")
+                        .clicked()
+                    {
+                        remove = Some(index);
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some(index) = remove {
+            mappings.remove(index);
+        }
+    }
+
+    fn render_import_export(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("File:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.transfer_path)
+                    .desired_width(ui.available_width() - 150.0),
+            );
+            if ui
+                .button("Import")
+                .on_hover_text("Load a key binding map to review before saving")
+                .clicked()
+            {
+                self.import();
+            }
+            if ui
+                .button("Export")
+                .on_hover_text("Write the bindings shown here to the file")
+                .clicked()
+            {
+                self.export();
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mapping_validity_follows_modes() {
+        let normal = KeyMapping::new(
+            "gT".to_string(),
+            "previous_window".to_string(),
+            vec!["normal".to_string()],
+        );
+        assert!(is_valid_mapping(&normal));
+
+        let hint_letters = KeyMapping {
+            modes: vec!["hint".to_string()],
+            ..normal.clone()
+        };
+        assert!(!is_valid_mapping(&hint_letters));
+
+        let any_mode = KeyMapping {
+            sequence: "Escape".to_string(),
+            modes: vec!["all".to_string()],
+            ..normal
+        };
+        assert!(is_valid_mapping(&any_mode));
+    }
+
+    #[test]
+    fn test_save_blocked_by_conflicts() {
+        let mut window = KeyBindingEditorWindow::new();
+        window.open(&KeyBindingMap::default(), Vec::new());
+        assert!(window
+            .validation_error(&window.bindings.conflicts())
+            .is_none());
+
+        window.scope_mappings().push(KeyMapping::new(
+            "j".to_string(),
+            "next_element".to_string(),
+            vec!["normal".to_string()],
+        ));
+        assert!(window.is_modified());
+        let conflicts = window.bindings.conflicts();
+        assert_eq!(
+            window.validation_error(&conflicts).as_deref(),
+            Some("1 conflict(s) to resolve")
+        );
+
+        window.save();
+        assert!(window.take_applied().is_none());
+        assert!(window.message.as_ref().is_some_and(|(_, error)| *error));
+    }
+
+    #[test]
+    fn test_export_then_import() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut window = KeyBindingEditorWindow::new();
+        window.open(&KeyBindingMap::default(), Vec::new());
+        window.transfer_path = dir.path().join("keys.toml").display().to_string();
+
+        window.scope_mappings().retain(|m| m.sequence != "x");
+        window.export();
+
+        window.bindings = KeyBindingMap::default();
+        window.import();
+        assert!(!window
+            .bindings
+            .get_bindings_for_mode(NavigationMode::Normal)
+            .iter()
+            .any(|m| m.sequence == "x"));
+        // Imported maps still need saving
+        assert!(window.is_modified());
+    }
+}
@@ -3,11 +3,81 @@
 //! This module provides configurable key binding management for the keyboard navigation system.
 //! It supports loading keybindings from TOML configuration files and provides a registry
 //! for mapping key sequences to navigation commands.
+//!
+//! Custom maps saved from the keyboard shortcuts window live in the config
+//! directory (see [`KeyBindingStore`]) and replace the defaults at startup.
 
-use super::keyboard_navigation::{ElementAction, NavigationCommand, NavigationMode};
+use super::keyboard_navigation::{
+    ElementAction, KeyEventResult, NavigationCommand, NavigationMode,
+};
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::Path;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Command names a mapping can use, with what they do
+pub const COMMANDS: &[(&str, &str)] = &[
+    ("scroll_down", "Scroll down"),
+    ("scroll_up", "Scroll up"),
+    ("scroll_left", "Scroll left"),
+    ("scroll_right", "Scroll right"),
+    ("scroll_half_page_down", "Scroll half page down"),
+    ("scroll_half_page_up", "Scroll half page up"),
+    ("move_to_top", "Move to top"),
+    ("move_to_bottom", "Move to bottom"),
+    ("next_window", "Next window"),
+    ("previous_window", "Previous window"),
+    ("close_window", "Close window"),
+    (
+        "hint_universal",
+        "Show hints for all elements (smart actions)",
+    ),
+    ("activate_hint", "Activate selected hint"),
+    ("next_element", "Next element"),
+    ("previous_element", "Previous element"),
+    ("window_1", "Go to window 1"),
+    ("window_2", "Go to window 2"),
+    ("window_3", "Go to window 3"),
+    ("window_4", "Go to window 4"),
+    ("window_5", "Go to window 5"),
+    ("window_6", "Go to window 6"),
+    ("window_7", "Go to window 7"),
+    ("window_8", "Go to window 8"),
+    ("window_9", "Go to window 9"),
+    ("insert_mode", "Enter insert mode"),
+    ("visual_mode", "Enter visual mode"),
+    ("command_mode", "Enter command mode"),
+    ("normal_mode", "Return to normal mode"),
+    ("execute_command", "Execute command"),
+];
+
+/// Key used for a mode in [`KeyBindingMap::bindings`]
+pub fn mode_name(mode: NavigationMode) -> &'static str {
+    match mode {
+        NavigationMode::Normal => "normal",
+        NavigationMode::Insert => "insert",
+        NavigationMode::Hint => "hint",
+        NavigationMode::Visual => "visual",
+        NavigationMode::Command => "command",
+    }
+}
+
+/// Whether `sequence` can be typed in `mode`
+///
+/// Normal mode builds sequences from letters, digits, and ':' (e.g. "gT");
+/// the other modes bind a single named key such as "Escape" or "Enter".
+pub fn is_valid_sequence(mode: NavigationMode, sequence: &str) -> bool {
+    match mode {
+        NavigationMode::Normal => {
+            !sequence.is_empty()
+                && sequence
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == ':')
+        }
+        _ => eframe::egui::Key::from_name(sequence).is_some(),
+    }
+}
 
 /// A single key mapping from a key sequence to a navigation command
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -50,20 +120,13 @@ impl KeyMapping {
 
     /// Check if this mapping is active in the given mode
     pub fn is_active_in_mode(&self, mode: NavigationMode) -> bool {
-        let mode_str = match mode {
-            NavigationMode::Normal => "normal",
-            NavigationMode::Insert => "insert",
-            NavigationMode::Hint => "hint",
-            NavigationMode::Visual => "visual",
-            NavigationMode::Command => "command",
-        };
-
-        self.modes.contains(&mode_str.to_string()) || self.modes.contains(&"all".to_string())
+        let mode_str = mode_name(mode);
+        self.modes.iter().any(|m| m == mode_str || m == "all")
     }
 }
 
 /// Collection of key bindings organized by mode
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyBindingMap {
     /// Version of the key binding format
     pub version: String,
@@ -76,7 +139,7 @@ pub struct KeyBindingMap {
 }
 
 /// Settings for key binding behavior
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KeyBindingSettings {
     /// Timeout for multi-key sequences in milliseconds
     pub sequence_timeout_ms: u64,
@@ -403,6 +466,177 @@ impl KeyBindingMap {
             false
         }
     }
+
+    /// Bindings that claim the same keys within a mode or window override
+    ///
+    /// Two mappings conflict when they share a mode and use the same
+    /// sequence, or, in normal mode, when one sequence starts the other: "g"
+    /// would run before "gg" could be typed.
+    pub fn conflicts(&self) -> Vec<KeyConflict> {
+        let mut conflicts = Vec::new();
+        for (scope, mappings) in &self.bindings {
+            collect_conflicts(scope, false, mappings, &mut conflicts);
+        }
+        for (window_id, mappings) in &self.window_overrides {
+            collect_conflicts(window_id, true, mappings, &mut conflicts);
+        }
+        conflicts.sort_by(|a, b| {
+            (a.window_override, &a.scope, &a.first.sequence).cmp(&(
+                b.window_override,
+                &b.scope,
+                &b.first.sequence,
+            ))
+        });
+        conflicts
+    }
+}
+
+/// Two mappings in the same mode or window override that claim the same keys
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyConflict {
+    /// Mode name ("normal") or window id the mappings belong to
+    pub scope: String,
+    /// Whether `scope` is a window id from [`KeyBindingMap::window_overrides`]
+    pub window_override: bool,
+    pub first: KeyMapping,
+    pub second: KeyMapping,
+}
+
+impl KeyConflict {
+    /// Whether `mapping` is one of the two conflicting mappings
+    pub fn involves(&self, mapping: &KeyMapping) -> bool {
+        self.first == *mapping || self.second == *mapping
+    }
+
+    pub fn describe(&self) -> String {
+        let how = if self.first.sequence == self.second.sequence {
+            "both use"
+        } else {
+            "overlap on"
+        };
+        format!(
+            "{}: {} and {} {} \"{}\"",
+            self.scope, self.first.command, self.second.command, how, self.first.sequence
+        )
+    }
+}
+
+/// Whether two mappings share a mode and claim the same keys
+fn mappings_conflict(a: &KeyMapping, b: &KeyMapping) -> bool {
+    // Bindings still being typed claim nothing yet
+    if a.sequence.is_empty() || b.sequence.is_empty() {
+        return false;
+    }
+    let shared_mode = a.modes.iter().any(|m| m == "all" || b.modes.contains(m))
+        || b.modes.iter().any(|m| m == "all");
+    if !shared_mode {
+        return false;
+    }
+    if a.sequence == b.sequence {
+        return true;
+    }
+    a.is_active_in_mode(NavigationMode::Normal)
+        && b.is_active_in_mode(NavigationMode::Normal)
+        && (a.sequence.starts_with(&b.sequence) || b.sequence.starts_with(&a.sequence))
+}
+
+fn collect_conflicts(
+    scope: &str,
+    window_override: bool,
+    mappings: &[KeyMapping],
+    conflicts: &mut Vec<KeyConflict>,
+) {
+    for (i, a) in mappings.iter().enumerate() {
+        for b in &mappings[i + 1..] {
+            if mappings_conflict(a, b) {
+                // Shorter sequence first, since it is the one that wins
+                let (first, second) = if b.sequence.len() < a.sequence.len() {
+                    (b, a)
+                } else {
+                    (a, b)
+                };
+                conflicts.push(KeyConflict {
+                    scope: scope.to_string(),
+                    window_override,
+                    first: first.clone(),
+                    second: second.clone(),
+                });
+            }
+        }
+    }
+}
+
+/// Custom key binding map in the config directory
+#[derive(Debug, Clone)]
+pub struct KeyBindingStore {
+    path: PathBuf,
+}
+
+impl KeyBindingStore {
+    /// Store backed by the TOML file at `path`
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Store in the application config directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(
+            dirs::config_dir()?.join("awsdash/keybindings.toml"),
+        ))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The saved map, or None when there is no custom map
+    pub fn load(&self) -> Result<Option<KeyBindingMap>> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read key bindings {}", self.path.display()))?;
+        let bindings = toml::from_str(&content)
+            .with_context(|| format!("Invalid key bindings in {}", self.path.display()))?;
+        Ok(Some(bindings))
+    }
+
+    pub fn save(&self, bindings: &KeyBindingMap) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create config directory {}", parent.display())
+            })?;
+        }
+        let content =
+            toml::to_string_pretty(bindings).context("Failed to serialize key bindings")?;
+        fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write key bindings {}", self.path.display()))
+    }
+
+    /// Delete the saved map, going back to the default bindings
+    pub fn reset(&self) -> Result<()> {
+        if self.path.exists() {
+            fs::remove_file(&self.path).with_context(|| {
+                format!("Failed to delete key bindings {}", self.path.display())
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Key bindings from the default store, falling back to the defaults
+pub fn load_key_bindings() -> KeyBindingMap {
+    let Some(store) = KeyBindingStore::default_location() else {
+        return KeyBindingMap::default();
+    };
+    match store.load() {
+        Ok(Some(bindings)) => bindings,
+        Ok(None) => KeyBindingMap::default(),
+        Err(e) => {
+            tracing::warn!("Using default key bindings: {:#}", e);
+            KeyBindingMap::default()
+        }
+    }
 }
 
 /// Registry for managing key mappings and command resolution
@@ -448,23 +682,64 @@ impl KeyMappingRegistry {
         mode: NavigationMode,
         window_id: Option<&str>,
     ) -> Option<NavigationCommand> {
+        let mapping = self.lookup_mapping(sequence, mode, window_id)?;
+        self.parse_command_string(&mapping.command)
+    }
+
+    /// Look up the mapping for a key sequence, window overrides first
+    pub fn lookup_mapping(
+        &self,
+        sequence: &str,
+        mode: NavigationMode,
+        window_id: Option<&str>,
+    ) -> Option<&KeyMapping> {
         // Check window-specific overrides first
         if let Some(window_id) = window_id {
-            for mapping in self.bindings.get_window_overrides(window_id) {
-                if mapping.sequence == sequence && mapping.is_active_in_mode(mode) {
-                    return self.parse_command_string(&mapping.command);
-                }
+            if let Some(mapping) = self
+                .bindings
+                .get_window_overrides(window_id)
+                .into_iter()
+                .find(|m| m.sequence == sequence && m.is_active_in_mode(mode))
+            {
+                return Some(mapping);
             }
         }
 
         // Check mode-specific bindings
-        for mapping in self.bindings.get_bindings_for_mode(mode) {
-            if mapping.sequence == sequence {
-                return self.parse_command_string(&mapping.command);
-            }
+        self.bindings
+            .get_bindings_for_mode(mode)
+            .into_iter()
+            .find(|m| m.sequence == sequence)
+    }
+
+    /// What the navigation system should do for a key sequence
+    ///
+    /// Unlike [`Self::lookup_command`], this also resolves the mode switching
+    /// commands ("insert_mode", "normal_mode", ...).
+    pub fn resolve(
+        &self,
+        sequence: &str,
+        mode: NavigationMode,
+        window_id: Option<&str>,
+    ) -> Option<KeyEventResult> {
+        let mapping = self.lookup_mapping(sequence, mode, window_id)?;
+        match mapping.command.as_str() {
+            "normal_mode" => Some(KeyEventResult::ModeChanged(NavigationMode::Normal)),
+            "insert_mode" => Some(KeyEventResult::ModeChanged(NavigationMode::Insert)),
+            "visual_mode" => Some(KeyEventResult::ModeChanged(NavigationMode::Visual)),
+            "command_mode" => Some(KeyEventResult::ModeChanged(NavigationMode::Command)),
+            "execute_command" => Some(KeyEventResult::Command(
+                NavigationCommand::OpenCommandPalette,
+            )),
+            command => self
+                .parse_command_string(command)
+                .map(KeyEventResult::Command),
         }
+    }
 
-        None
+    /// The current key binding configuration
+    pub fn bindings(&self) -> &KeyBindingMap {
+        &self.bindings
     }
 
     /// Get all available bindings for a mode (for help display)
@@ -700,4 +975,136 @@ mod tests {
         let deserialized: Result<KeyBindingMap, _> = toml::from_str(&toml_content);
         assert!(deserialized.is_ok());
     }
+
+    #[test]
+    fn test_resolve_mode_commands() {
+        let registry = KeyMappingRegistry::new();
+
+        assert_eq!(
+            registry.resolve("i", NavigationMode::Normal, None),
+            Some(KeyEventResult::ModeChanged(NavigationMode::Insert))
+        );
+        assert_eq!(
+            registry.resolve("Escape", NavigationMode::Insert, None),
+            Some(KeyEventResult::ModeChanged(NavigationMode::Normal))
+        );
+        assert_eq!(
+            registry.resolve("Enter", NavigationMode::Command, None),
+            Some(KeyEventResult::Command(
+                NavigationCommand::OpenCommandPalette
+            ))
+        );
+        assert_eq!(registry.resolve("q", NavigationMode::Normal, None), None);
+    }
+
+    #[test]
+    fn test_default_bindings_have_no_conflicts() {
+        assert!(KeyBindingMap::create_default_bindings()
+            .conflicts()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_conflict_detection() {
+        let mut bindings = KeyBindingMap::create_default_bindings();
+        bindings.add_binding(
+            NavigationMode::Normal,
+            KeyMapping::new(
+                "j".to_string(),
+                "next_element".to_string(),
+                vec!["normal".to_string()],
+            ),
+        );
+        bindings.add_binding(
+            NavigationMode::Normal,
+            KeyMapping::new(
+                "g".to_string(),
+                "next_window".to_string(),
+                vec!["normal".to_string()],
+            ),
+        );
+
+        let conflicts = bindings.conflicts();
+        let sequences: Vec<_> = conflicts
+            .iter()
+            .map(|c| (c.first.sequence.as_str(), c.second.sequence.as_str()))
+            .collect();
+        assert!(sequences.contains(&("j", "j")));
+        // "g" shadows every sequence it starts
+        assert!(sequences.contains(&("g", "gg")));
+        assert!(sequences.contains(&("g", "gt")));
+        assert!(sequences.contains(&("g", "gT")));
+        assert!(conflicts.iter().all(|c| c.scope == "normal"));
+    }
+
+    #[test]
+    fn test_window_override_conflicts() {
+        let mut bindings = KeyBindingMap::create_default_bindings();
+        let override_j = KeyMapping::new(
+            "j".to_string(),
+            "next_element".to_string(),
+            vec!["normal".to_string()],
+        );
+
+        // Overriding a mode binding is not a conflict
+        bindings
+            .window_overrides
+            .insert("explorer".to_string(), vec![override_j.clone()]);
+        assert!(bindings.conflicts().is_empty());
+
+        // Two overrides for the same keys in one window are
+        let override_j_hint = KeyMapping::new(
+            "j".to_string(),
+            "previous_element".to_string(),
+            vec!["all".to_string()],
+        );
+        bindings
+            .window_overrides
+            .get_mut("explorer")
+            .unwrap()
+            .push(override_j_hint.clone());
+        let conflicts = bindings.conflicts();
+        assert_eq!(conflicts.len(), 1);
+        assert!(conflicts[0].window_override);
+        assert!(conflicts[0].involves(&override_j));
+        assert!(conflicts[0].involves(&override_j_hint));
+    }
+
+    #[test]
+    fn test_sequence_validation() {
+        assert!(is_valid_sequence(NavigationMode::Normal, "gT"));
+        assert!(is_valid_sequence(NavigationMode::Normal, ":"));
+        assert!(!is_valid_sequence(NavigationMode::Normal, ""));
+        assert!(!is_valid_sequence(NavigationMode::Normal, "g t"));
+        assert!(is_valid_sequence(NavigationMode::Hint, "Escape"));
+        assert!(!is_valid_sequence(NavigationMode::Hint, "Esc ape"));
+    }
+
+    #[test]
+    fn test_store_round_trip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let store = KeyBindingStore::new(dir.path().join("nested/keybindings.toml"));
+        assert!(store.load().unwrap().is_none());
+
+        let mut bindings = KeyBindingMap::create_default_bindings();
+        bindings.remove_binding(NavigationMode::Normal, "x");
+        store.save(&bindings).unwrap();
+
+        let loaded = store.load().unwrap().unwrap();
+        assert!(!loaded
+            .get_bindings_for_mode(NavigationMode::Normal)
+            .iter()
+            .any(|m| m.sequence == "x"));
+
+        store.reset().unwrap();
+        assert!(store.load().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_store_rejects_invalid_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let store = KeyBindingStore::new(dir.path().join("keybindings.toml"));
+        std::fs::write(store.path(), "version = ").unwrap();
+        assert!(store.load().is_err());
+    }
 }
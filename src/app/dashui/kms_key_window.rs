@@ -0,0 +1,621 @@
+//! KMS Key Window
+//!
+//! Answers key stewardship questions for one key in one place: its state,
+//! aliases, and rotation; who the key policy and grants let use it; and who
+//! has called Encrypt, Decrypt, and the other cryptographic operations with
+//! it recently (from CloudTrail event history).
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::{format_time, spawn_with_client};
+use crate::app::data_plane::kms_key_usage::{
+    KeyCallerReport, KeyGrant, KeyPolicyStatement, KeyUsageDetails, KmsKeyUsageClient,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Lookback choices for key callers, in days
+const LOOKBACK_OPTIONS: &[i64] = &[1, 7, 30, 90];
+
+/// Parameters for showing the key window
+#[derive(Clone)]
+pub struct KmsKeyShowParams {
+    pub key_id: String,
+    pub key_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Results from background threads
+enum KeyEvent {
+    DetailsLoaded(Result<KeyUsageDetails, String>),
+    CallersLoaded(Result<KeyCallerReport, String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeyTab {
+    Policy,
+    Grants,
+    Usage,
+}
+
+pub struct KmsKeyWindow {
+    pub open: bool,
+    // Display parameters
+    key_id: String,
+    key_name: String,
+    account_id: String,
+    region: String,
+
+    // State
+    details: Option<KeyUsageDetails>,
+    loading: bool,
+    error_message: Option<String>,
+    callers: Option<KeyCallerReport>,
+    loading_callers: bool,
+    callers_error: Option<String>,
+    lookback_days: i64,
+    tab: KeyTab,
+    show_raw_policy: bool,
+
+    // Services
+    client: Arc<KmsKeyUsageClient>,
+
+    // Channel for results from background threads.
+    // Replaced when the window opens on a key so results for another key are dropped.
+    sender: mpsc::Sender<KeyEvent>,
+    receiver: mpsc::Receiver<KeyEvent>,
+}
+
+impl KmsKeyWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            key_id: String::new(),
+            key_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            details: None,
+            loading: false,
+            error_message: None,
+            callers: None,
+            loading_callers: false,
+            callers_error: None,
+            lookback_days: 7,
+            tab: KeyTab::Policy,
+            show_raw_policy: false,
+            client: Arc::new(KmsKeyUsageClient::new(credential_coordinator)),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on a key and load its details, then its callers
+    pub fn open_for_key(&mut self, params: KmsKeyShowParams) {
+        let (sender, receiver) = mpsc::channel();
+        self.sender = sender;
+        self.receiver = receiver;
+        self.key_id = params.key_id;
+        self.key_name = params.key_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.details = None;
+        self.callers = None;
+        self.callers_error = None;
+        self.loading_callers = false;
+        self.open = true;
+        self.load_details();
+    }
+
+    /// Whether this window shows the given key
+    pub fn targets(&self, key_id: &str, account_id: &str, region: &str) -> bool {
+        self.key_id == key_id && self.account_id == account_id && self.region == region
+    }
+
+    fn load_details(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+        let (account_id, region, key_id) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.key_id.clone(),
+        );
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client.get_key_details(&account_id, &region, &key_id).await;
+            if let Err(e) = &result {
+                log::error!("Failed to load KMS key {}: {}", key_id, e);
+            }
+            KeyEvent::DetailsLoaded(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    fn load_callers(&mut self) {
+        let Some(key_arn) = self
+            .details
+            .as_ref()
+            .and_then(|details| details.key_arn.clone())
+        else {
+            return;
+        };
+        self.loading_callers = true;
+        self.callers_error = None;
+        let (account_id, region, lookback_days) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.lookback_days,
+        );
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .get_key_callers(&account_id, &region, &key_arn, lookback_days)
+                .await;
+            if let Err(e) = &result {
+                log::warn!("Failed to load callers of KMS key {}: {}", key_arn, e);
+            }
+            KeyEvent::CallersLoaded(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    /// Poll for results from background threads
+    fn poll_events(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                KeyEvent::DetailsLoaded(Ok(details)) => {
+                    self.loading = false;
+                    let first_load = self.details.is_none();
+                    self.details = Some(details);
+                    if first_load {
+                        self.load_callers();
+                    }
+                }
+                KeyEvent::DetailsLoaded(Err(e)) => {
+                    self.loading = false;
+                    self.error_message = Some(e);
+                }
+                KeyEvent::CallersLoaded(result) => {
+                    self.loading_callers = false;
+                    match result {
+                        Ok(report) => self.callers = Some(report),
+                        Err(e) => self.callers_error = Some(e),
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_events();
+
+        // Request continuous repaint while waiting to show spinners
+        if self.loading || self.loading_callers {
+            ctx.request_repaint();
+        }
+
+        let title = format!("KMS Key: {}", self.key_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "kms_key_window",
+                &self.account_id,
+                &self.region,
+                &self.key_id,
+            )))
+            .open(&mut is_open)
+            .default_size([860.0, 600.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Key:").strong());
+            ui.label(&self.key_name);
+            ui.label(RichText::new(format!("{} {}", self.account_id, self.region)).weak());
+            if ui
+                .add_enabled(!self.loading, egui::Button::new("Refresh"))
+                .clicked()
+            {
+                self.load_details();
+            }
+            if self.loading {
+                ui.spinner();
+            }
+        });
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error);
+        }
+        let Some(details) = self.details.clone() else {
+            return;
+        };
+        ui.separator();
+
+        render_overview(ui, &details);
+        for warning in &details.warnings {
+            ui.label(
+                RichText::new(warning)
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.selectable_value(
+                &mut self.tab,
+                KeyTab::Policy,
+                format!("Key Policy ({})", details.policy_statements.len()),
+            );
+            ui.selectable_value(
+                &mut self.tab,
+                KeyTab::Grants,
+                format!("Grants ({})", details.grants.len()),
+            );
+            let callers = self
+                .callers
+                .as_ref()
+                .map(|report| format!("Recent Usage ({})", report.callers.len()))
+                .unwrap_or_else(|| "Recent Usage".to_string());
+            ui.selectable_value(&mut self.tab, KeyTab::Usage, callers);
+        });
+        ui.separator();
+
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| match self.tab {
+                KeyTab::Policy => {
+                    ui.checkbox(&mut self.show_raw_policy, "Show policy JSON");
+                    if self.show_raw_policy {
+                        let mut policy = details
+                            .policy
+                            .as_deref()
+                            .and_then(|p| serde_json::from_str::<serde_json::Value>(p).ok())
+                            .and_then(|p| serde_json::to_string_pretty(&p).ok())
+                            .or_else(|| details.policy.clone())
+                            .unwrap_or_default();
+                        ui.add(
+                            egui::TextEdit::multiline(&mut policy)
+                                .code_editor()
+                                .desired_width(f32::INFINITY)
+                                .interactive(false),
+                        );
+                    } else {
+                        render_policy(ui, &details.policy_statements);
+                    }
+                }
+                KeyTab::Grants => render_grants(ui, &details.grants),
+                KeyTab::Usage => self.render_usage(ui),
+            });
+    }
+
+    fn render_usage(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Last");
+            let previous = self.lookback_days;
+            egui::ComboBox::from_id_salt("kms_key_lookback")
+                .selected_text(format!("{} days", self.lookback_days))
+                .show_ui(ui, |ui| {
+                    for days in LOOKBACK_OPTIONS {
+                        ui.selectable_value(
+                            &mut self.lookback_days,
+                            *days,
+                            format!("{} days", days),
+                        );
+                    }
+                });
+            let reload = ui
+                .add_enabled(!self.loading_callers, egui::Button::new("Reload"))
+                .clicked();
+            if (reload || self.lookback_days != previous) && !self.loading_callers {
+                self.load_callers();
+            }
+            if self.loading_callers {
+                ui.spinner();
+                ui.label("Searching CloudTrail...");
+            }
+        });
+
+        if let Some(error) = &self.callers_error {
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error);
+        }
+        let Some(report) = &self.callers else {
+            return;
+        };
+        ui.label(
+            RichText::new(format!(
+                "{} events scanned{}",
+                report.events_scanned,
+                if report.truncated {
+                    "; more events exist, so only the most recent callers are shown"
+                } else {
+                    ""
+                }
+            ))
+            .small()
+            .weak(),
+        );
+        if report.callers.is_empty() {
+            ui.label("No cryptographic operations with this key in CloudTrail for this period");
+            return;
+        }
+
+        egui::Grid::new("kms_key_callers_grid")
+            .num_columns(5)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.strong("Principal");
+                ui.strong("Via");
+                ui.strong("Operations");
+                ui.strong("Calls");
+                ui.strong("Last seen");
+                ui.end_row();
+
+                for caller in &report.callers {
+                    ui.label(&caller.principal);
+                    ui.label(caller.invoked_by.as_deref().unwrap_or("-"));
+                    ui.label(
+                        caller
+                            .operations
+                            .iter()
+                            .map(|(operation, count)| format!("{} x{}", operation, count))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    );
+                    if caller.failed_calls > 0 {
+                        ui.label(format!("{}", caller.total_calls))
+                            .on_hover_text(format!(
+                                "{} failed (e.g. access denied)",
+                                caller.failed_calls
+                            ));
+                    } else {
+                        ui.label(format!("{}", caller.total_calls));
+                    }
+                    ui.label(format_time(caller.last_seen));
+                    ui.end_row();
+                }
+            });
+    }
+}
+
+fn render_overview(ui: &mut Ui, details: &KeyUsageDetails) {
+    egui::Grid::new("kms_key_overview_grid")
+        .num_columns(4)
+        .spacing([16.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("State:");
+            let state = details.key_state.as_deref().unwrap_or("-");
+            if state == "Enabled" {
+                ui.label(state);
+            } else {
+                ui.colored_label(ui.visuals().warn_fg_color, state);
+            }
+            ui.label("Managed by:");
+            ui.label(match details.key_manager.as_deref() {
+                Some("AWS") => "AWS",
+                Some("CUSTOMER") => "Customer",
+                _ => "-",
+            });
+            ui.end_row();
+
+            ui.label("Usage:");
+            ui.label(details.key_usage.as_deref().unwrap_or("-"));
+            ui.label("Spec:");
+            ui.label(details.key_spec.as_deref().unwrap_or("-"));
+            ui.end_row();
+
+            ui.label("Rotation:");
+            ui.label(rotation_text(details));
+            ui.label("Created:");
+            ui.label(details.creation_date.as_deref().unwrap_or("-"));
+            ui.end_row();
+
+            ui.label("Aliases:");
+            ui.label(if details.aliases.is_empty() {
+                "-".to_string()
+            } else {
+                details.aliases.join(", ")
+            });
+            ui.label("Multi-Region:");
+            ui.label(if details.multi_region { "Yes" } else { "No" });
+            ui.end_row();
+        });
+    if let Some(description) = &details.description {
+        ui.label(RichText::new(description).weak());
+    }
+    if let Some(deletion_date) = &details.deletion_date {
+        ui.colored_label(
+            ui.visuals().error_fg_color,
+            format!("Scheduled for deletion on {}", deletion_date),
+        );
+    }
+    if let Some(key_arn) = &details.key_arn {
+        ui.label(RichText::new(key_arn).small().monospace());
+    }
+}
+
+fn rotation_text(details: &KeyUsageDetails) -> String {
+    match details.rotation.enabled {
+        Some(true) => {
+            let period = details
+                .rotation
+                .period_days
+                .map(|days| format!("every {} days", days))
+                .unwrap_or_else(|| "enabled".to_string());
+            match &details.rotation.next_rotation {
+                Some(next) => format!("{}, next {}", period, next),
+                None => period,
+            }
+        }
+        Some(false) => "Disabled".to_string(),
+        None => "Not supported".to_string(),
+    }
+}
+
+fn render_policy(ui: &mut Ui, statements: &[KeyPolicyStatement]) {
+    if statements.is_empty() {
+        ui.label("No key policy statements available");
+        return;
+    }
+    egui::Grid::new("kms_key_policy_grid")
+        .num_columns(5)
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Sid");
+            ui.strong("Effect");
+            ui.strong("Principals");
+            ui.strong("Actions");
+            ui.strong("Condition");
+            ui.end_row();
+
+            for statement in statements {
+                ui.label(statement.sid.as_deref().unwrap_or("-"));
+                if statement.effect == "Deny" {
+                    ui.colored_label(ui.visuals().warn_fg_color, &statement.effect);
+                } else {
+                    ui.label(&statement.effect);
+                }
+                let principals = statement.principals.join("\n");
+                if statement
+                    .principals
+                    .iter()
+                    .any(|p| p == "*" || p == "AWS: *")
+                {
+                    ui.colored_label(ui.visuals().error_fg_color, principals)
+                        .on_hover_text("Any principal; check the condition");
+                } else {
+                    ui.label(principals);
+                }
+                let actions = statement.actions.join("\n");
+                if statement.allows_all_actions() {
+                    ui.colored_label(ui.visuals().warn_fg_color, actions)
+                        .on_hover_text("Full key administration and use");
+                } else {
+                    ui.label(actions);
+                }
+                ui.label(
+                    RichText::new(statement.condition.as_deref().unwrap_or("-"))
+                        .small()
+                        .monospace(),
+                );
+                ui.end_row();
+            }
+        });
+}
+
+fn render_grants(ui: &mut Ui, grants: &[KeyGrant]) {
+    if grants.is_empty() {
+        ui.label("No grants on this key");
+        return;
+    }
+    egui::Grid::new("kms_key_grants_grid")
+        .num_columns(5)
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Grantee");
+            ui.strong("Operations");
+            ui.strong("Constraints");
+            ui.strong("Retiring principal");
+            ui.strong("Created");
+            ui.end_row();
+
+            for grant in grants {
+                ui.label(grant.grantee_principal.as_deref().unwrap_or("-"))
+                    .on_hover_text(format!(
+                        "Grant {}{}",
+                        grant.grant_id,
+                        grant
+                            .name
+                            .as_ref()
+                            .map(|name| format!(" ({})", name))
+                            .unwrap_or_default()
+                    ));
+                ui.label(grant.operations.join(", "));
+                ui.label(if grant.constraints.is_empty() {
+                    "-".to_string()
+                } else {
+                    grant.constraints.join("\n")
+                });
+                ui.label(grant.retiring_principal.as_deref().unwrap_or("-"));
+                ui.label(grant.creation_date.as_deref().unwrap_or("-"));
+                ui.end_row();
+            }
+        });
+}
+
+impl FocusableWindow for KmsKeyWindow {
+    type ShowParams = KmsKeyShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "kms_key_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("KMS Key: {}", self.key_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the key first
+        self.open_for_key(params);
+
+        // Then show with focus
+        KmsKeyWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::kms_key_usage::KeyRotation;
+
+    #[test]
+    fn test_rotation_text() {
+        let mut details = KeyUsageDetails {
+            rotation: KeyRotation {
+                enabled: Some(true),
+                period_days: Some(365),
+                next_rotation: None,
+            },
+            ..Default::default()
+        };
+        assert_eq!(rotation_text(&details), "every 365 days");
+        details.rotation.enabled = Some(false);
+        assert_eq!(rotation_text(&details), "Disabled");
+        details.rotation.enabled = None;
+        assert_eq!(rotation_text(&details), "Not supported");
+    }
+}
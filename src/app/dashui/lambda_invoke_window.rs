@@ -0,0 +1,394 @@
+//! Lambda Invoke Window
+//!
+//! Test harness for a Lambda function: edit a JSON payload, invoke the
+//! function (or dry-run the invocation) and inspect the response, the log
+//! tail, and the duration and memory figures from the REPORT line.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::lambda_invoke::{InvokeRequest, InvokeResult, LambdaInvokeClient};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Parameters for showing the Lambda invoke window
+#[derive(Clone)]
+pub struct LambdaInvokeShowParams {
+    pub function_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Result from a background invocation, with the round trip time
+type InvokeOutcome = (Result<InvokeResult, String>, std::time::Duration);
+
+pub struct LambdaInvokeWindow {
+    pub open: bool,
+    // Display parameters
+    function_name: String,
+    account_id: String,
+    region: String,
+
+    // Request
+    payload: String,
+    qualifier: String,
+    dry_run: bool,
+    payload_error: Option<String>,
+
+    // Result
+    invoking: bool,
+    result: Option<InvokeOutcome>,
+
+    // Services
+    client: Arc<LambdaInvokeClient>,
+
+    // Channel for receiving invocation results from background thread
+    result_receiver: mpsc::Receiver<InvokeOutcome>,
+    result_sender: mpsc::Sender<InvokeOutcome>,
+}
+
+impl LambdaInvokeWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (result_sender, result_receiver) = mpsc::channel();
+        Self {
+            open: false,
+            function_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            payload: InvokeRequest::default().payload,
+            qualifier: String::new(),
+            // Invocations run the function, so the read-only build can only dry-run
+            dry_run: crate::app::capabilities::READ_ONLY,
+            payload_error: None,
+            invoking: false,
+            result: None,
+            client: Arc::new(LambdaInvokeClient::new(credential_coordinator)),
+            result_receiver,
+            result_sender,
+        }
+    }
+
+    /// Open the window for a function
+    pub fn open_for_function(&mut self, params: LambdaInvokeShowParams) {
+        if !self.targets(&params.function_name, &params.account_id, &params.region) {
+            self.result = None;
+        }
+        self.function_name = params.function_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.open = true;
+    }
+
+    /// Whether this window is for the given function
+    pub fn targets(&self, function_name: &str, account_id: &str, region: &str) -> bool {
+        self.function_name == function_name
+            && self.account_id == account_id
+            && self.region == region
+    }
+
+    fn request(&self) -> InvokeRequest {
+        InvokeRequest {
+            payload: self.payload.clone(),
+            qualifier: Some(self.qualifier.clone()),
+            dry_run: self.dry_run || crate::app::capabilities::READ_ONLY,
+        }
+    }
+
+    fn invoke(&mut self) {
+        let request = self.request();
+        if let Err(e) = request.validate() {
+            self.payload_error = Some(e);
+            return;
+        }
+        self.payload_error = None;
+        self.invoking = true;
+
+        let account_id = self.account_id.clone();
+        let region = self.region.clone();
+        let function_name = self.function_name.clone();
+
+        spawn_with_client(
+            &self.client,
+            &self.result_sender,
+            move |client| async move {
+                let started = Instant::now();
+                let result = client
+                    .invoke(&account_id, &region, &function_name, &request)
+                    .await
+                    .map_err(|e| {
+                        log::error!("Failed to invoke {}: {}", function_name, e);
+                        format!("{:#}", e)
+                    });
+                (result, started.elapsed())
+            },
+        );
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        // Check for results from background thread
+        if let Ok(outcome) = self.result_receiver.try_recv() {
+            self.invoking = false;
+            self.result = Some(outcome);
+        }
+
+        // Request continuous repaint while invoking to show spinner
+        if self.invoking {
+            ctx.request_repaint();
+        }
+
+        let title = format!("Invoke: {}", self.function_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "lambda_invoke_window",
+                &self.account_id,
+                &self.region,
+                &self.function_name,
+            )))
+            .open(&mut is_open)
+            .default_size([760.0, 620.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Function:").strong());
+            ui.label(&self.function_name);
+            ui.label(RichText::new(format!("{} {}", self.account_id, self.region)).weak());
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Qualifier:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.qualifier)
+                    .hint_text("$LATEST")
+                    .desired_width(140.0),
+            )
+            .on_hover_text("Version number or alias");
+
+            ui.add_enabled(
+                !crate::app::capabilities::READ_ONLY,
+                egui::Checkbox::new(&mut self.dry_run, "Dry run"),
+            )
+            .on_hover_text("Check the payload and permissions without running the function")
+            .on_disabled_hover_text("Only dry runs are allowed in the read-only build");
+        });
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Payload").strong());
+            ui.label(
+                RichText::new(format!("{} bytes", self.payload.len()))
+                    .small()
+                    .weak(),
+            );
+            if ui.small_button("Format").clicked() {
+                match serde_json::from_str::<serde_json::Value>(&self.payload) {
+                    Ok(value) => {
+                        if let Ok(pretty) = serde_json::to_string_pretty(&value) {
+                            self.payload = pretty;
+                        }
+                        self.payload_error = None;
+                    }
+                    Err(e) => {
+                        self.payload_error = Some(format!("Payload is not valid JSON: {}", e))
+                    }
+                }
+            }
+        });
+        egui::ScrollArea::vertical()
+            .id_salt("lambda_invoke_payload")
+            .max_height(180.0)
+            .show(ui, |ui| {
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.payload)
+                        .code_editor()
+                        .desired_rows(8)
+                        .desired_width(f32::INFINITY),
+                );
+            });
+        if let Some(error) = &self.payload_error {
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error);
+        }
+
+        ui.horizontal(|ui| {
+            let label = if self.request().dry_run {
+                "Dry Run"
+            } else {
+                "Invoke"
+            };
+            if ui
+                .add_enabled(!self.invoking, egui::Button::new(label))
+                .clicked()
+            {
+                self.invoke();
+            }
+            if self.invoking {
+                ui.spinner();
+                ui.label("Waiting for the function...");
+            }
+        });
+        ui.separator();
+
+        if let Some((result, elapsed)) = &self.result {
+            egui::ScrollArea::vertical()
+                .id_salt("lambda_invoke_result")
+                .auto_shrink([false, false])
+                .show(ui, |ui| match result {
+                    Ok(result) => render_result(ui, result, *elapsed),
+                    Err(e) => {
+                        ui.colored_label(Color32::from_rgb(255, 100, 100), e);
+                    }
+                });
+        }
+    }
+}
+
+/// Status, REPORT figures, response payload, and log tail of an invocation
+fn render_result(ui: &mut Ui, result: &InvokeResult, elapsed: std::time::Duration) {
+    ui.horizontal(|ui| {
+        let (text, color) = match &result.function_error {
+            Some(error) => (
+                format!("Function error ({})", error),
+                Color32::from_rgb(255, 100, 100),
+            ),
+            None if result.dry_run => (
+                "Dry run passed".to_string(),
+                Color32::from_rgb(100, 200, 100),
+            ),
+            None => ("Succeeded".to_string(), Color32::from_rgb(100, 200, 100)),
+        };
+        ui.label(RichText::new(text).strong().color(color));
+        ui.label(RichText::new(format!("HTTP {}", result.status_code)).weak());
+        if let Some(version) = &result.executed_version {
+            ui.label(RichText::new(format!("version {}", version)).weak());
+        }
+        ui.label(RichText::new(format!("round trip {} ms", elapsed.as_millis())).weak());
+    });
+
+    if let Some(report) = &result.report {
+        egui::Grid::new("lambda_invoke_report")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Duration:");
+                ui.label(format!("{:.2} ms", report.duration_ms));
+                ui.end_row();
+                ui.label("Billed duration:");
+                ui.label(format!("{} ms", report.billed_duration_ms));
+                ui.end_row();
+                ui.label("Memory:");
+                ui.label(format!(
+                    "{} MB used of {} MB",
+                    report.max_memory_used_mb, report.memory_size_mb
+                ));
+                ui.end_row();
+                if let Some(init) = report.init_duration_ms {
+                    ui.label("Init duration:");
+                    ui.label(format!("{:.2} ms (cold start)", init));
+                    ui.end_row();
+                }
+            });
+    }
+
+    if let Some(payload) = &result.payload {
+        ui.add_space(6.0);
+        ui.label(RichText::new("Response").strong());
+        let mut text = pretty_payload(payload);
+        ui.add(
+            egui::TextEdit::multiline(&mut text)
+                .code_editor()
+                .desired_rows(6)
+                .desired_width(f32::INFINITY),
+        );
+    }
+
+    if let Some(log_tail) = &result.log_tail {
+        ui.add_space(6.0);
+        ui.label(RichText::new("Log tail (last 4 KB)").strong());
+        let mut text = log_tail.clone();
+        ui.add(
+            egui::TextEdit::multiline(&mut text)
+                .code_editor()
+                .desired_rows(8)
+                .desired_width(f32::INFINITY),
+        );
+    }
+}
+
+/// Response pretty printed when it is JSON, as returned otherwise
+fn pretty_payload(payload: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(payload)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| payload.to_string())
+}
+
+impl FocusableWindow for LambdaInvokeWindow {
+    type ShowParams = LambdaInvokeShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "lambda_invoke_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Invoke: {}", self.function_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the function first
+        self.open_for_function(params);
+
+        // Then show with focus
+        LambdaInvokeWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pretty_payload() {
+        assert_eq!(
+            pretty_payload(r#"{"statusCode":200}"#),
+            "{\n  \"statusCode\": 200\n}"
+        );
+        assert_eq!(pretty_payload("plain text"), "plain text");
+    }
+}
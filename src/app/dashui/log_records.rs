@@ -0,0 +1,391 @@
+//! Log Records
+//!
+//! Parses lines of the application's tracing log into [`LogRecord`]s (time,
+//! level, target, message, and fields) and filters them for the
+//! [`LogWindow`](super::log_window::LogWindow).
+//!
+//! The log is written by `tracing_subscriber`'s default formatter:
+//!
+//! ```text
+//! 2025-05-30T00:20:07.991790Z  INFO awsdash::app::dashui::menu: Log button clicked
+//! 2025-05-30T00:20:08.102311Z DEBUG invoke{service=s3}:try_op: aws_smithy_runtime::client: sending request attempt=1
+//! ```
+//!
+//! Lines that don't start with a timestamp continue the record before them,
+//! as when a message contains newlines.
+
+use crate::app::app_config::LogLevel;
+use chrono::{DateTime, Utc};
+use eframe::egui::Color32;
+use std::collections::{HashSet, VecDeque};
+
+/// Color of a level in the log window
+pub fn level_color(level: LogLevel) -> Color32 {
+    match level {
+        LogLevel::Error => Color32::from_rgb(255, 100, 100),
+        LogLevel::Warn => Color32::from_rgb(255, 200, 100),
+        LogLevel::Info => Color32::from_rgb(100, 200, 255),
+        LogLevel::Debug => Color32::from_rgb(150, 150, 150),
+        LogLevel::Trace | LogLevel::Off => Color32::from_rgb(120, 120, 120),
+    }
+}
+
+/// One event from the log file
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogRecord {
+    /// `None` for a line not in the tracing format
+    pub timestamp: Option<DateTime<Utc>>,
+    pub level: Option<LogLevel>,
+    /// Spans the event was recorded in, e.g. `invoke{service=s3}:try_op`
+    pub spans: String,
+    pub target: String,
+    pub message: String,
+    /// Fields logged after the message, values unquoted
+    pub fields: Vec<(String, String)>,
+    /// The line(s) as written
+    pub raw: String,
+}
+
+impl LogRecord {
+    /// Parse a line that starts a record, `None` if it doesn't
+    pub fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        let (timestamp, rest) = line.split_once(' ')?;
+        let timestamp = DateTime::parse_from_rfc3339(timestamp)
+            .ok()?
+            .with_timezone(&Utc);
+        let rest = rest.trim_start();
+        let (level, rest) = rest.split_once(' ').unwrap_or((rest, ""));
+        let level = LogLevel::parse(level)?;
+        let (spans, target, body) = split_target(rest.trim_start());
+        let (message, fields) = split_fields(body);
+
+        Some(Self {
+            timestamp: Some(timestamp),
+            level: Some(level),
+            spans: spans.to_string(),
+            target: target.to_string(),
+            message,
+            fields,
+            raw: line.to_string(),
+        })
+    }
+
+    /// Parse a line, keeping it whole as the message if it doesn't start a record
+    pub fn parse_line(line: &str) -> Self {
+        Self::parse(line).unwrap_or_else(|| {
+            let line = line.trim_end();
+            Self {
+                timestamp: None,
+                level: None,
+                spans: String::new(),
+                target: String::new(),
+                message: line.to_string(),
+                fields: Vec::new(),
+                raw: line.to_string(),
+            }
+        })
+    }
+
+    fn append_line(&mut self, line: &str) {
+        self.message.push('\n');
+        self.message.push_str(line);
+        self.raw.push('\n');
+        self.raw.push_str(line);
+    }
+}
+
+/// Add a parsed line to `records`, dropping the oldest beyond `max`. A line
+/// that didn't start a record is appended to the last one.
+pub fn push_record(records: &mut VecDeque<LogRecord>, record: LogRecord, max: usize) {
+    if record.timestamp.is_none() {
+        if let Some(last) = records.back_mut() {
+            last.append_line(&record.raw);
+            return;
+        }
+    }
+    records.push_back(record);
+    while records.len() > max {
+        records.pop_front();
+    }
+}
+
+/// Position of the first `": "` outside braces, `None` if whitespace comes first
+fn next_separator(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut depth = 0usize;
+    for (i, &b) in bytes.iter().enumerate() {
+        match b {
+            b'{' => depth += 1,
+            b'}' => depth = depth.saturating_sub(1),
+            b':' if depth == 0 && bytes.get(i + 1) == Some(&b' ') => return Some(i),
+            b if depth == 0 && b.is_ascii_whitespace() => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
+fn is_target(text: &str) -> bool {
+    !text.is_empty()
+        && text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | ':' | '-'))
+}
+
+/// Split `spans: target: body`, where the spans are optional
+fn split_target(rest: &str) -> (&str, &str, &str) {
+    let Some(first) = next_separator(rest) else {
+        return ("", "", rest);
+    };
+    let head = &rest[..first];
+    let after = &rest[first + 2..];
+
+    if let Some(second) = next_separator(after) {
+        let candidate = &after[..second];
+        // Spans have fields in braces or several names joined by single
+        // colons; a lone span name is told apart by the module path after it
+        let is_spans = head.contains('{')
+            || head.replace("::", "").contains(':')
+            || (!head.contains("::") && candidate.contains("::"));
+        if is_spans && is_target(candidate) {
+            return (head, candidate, &after[second + 2..]);
+        }
+    }
+    if is_target(head) {
+        ("", head, after)
+    } else {
+        ("", "", rest)
+    }
+}
+
+/// Split trailing `key=value` fields off a message
+fn split_fields(body: &str) -> (String, Vec<(String, String)>) {
+    let starts = std::iter::once(0).chain(body.match_indices(' ').map(|(i, _)| i + 1));
+    for start in starts {
+        if let Some(fields) = parse_fields(&body[start..]) {
+            return (body[..start].trim_end().to_string(), fields);
+        }
+    }
+    (body.to_string(), Vec::new())
+}
+
+/// Parse text made up only of space separated `key=value` pairs
+fn parse_fields(text: &str) -> Option<Vec<(String, String)>> {
+    let mut fields = Vec::new();
+    let mut rest = text;
+    while !rest.is_empty() {
+        let (key, after) = rest.split_once('=')?;
+        let mut chars = key.chars();
+        if !chars.next()?.is_ascii_alphabetic() && !key.starts_with('_') {
+            return None;
+        }
+        if !chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.') {
+            return None;
+        }
+
+        let (value, after) = if let Some(quoted) = after.strip_prefix('"') {
+            let mut escaped = false;
+            let end = quoted.char_indices().find_map(|(i, c)| {
+                let closes = c == '"' && !escaped;
+                escaped = c == '\\' && !escaped;
+                closes.then_some(i)
+            })?;
+            (&quoted[..end], &quoted[end + 1..])
+        } else {
+            let end = after.find(' ').unwrap_or(after.len());
+            (&after[..end], &after[end..])
+        };
+        if !after.is_empty() && !after.starts_with(' ') {
+            return None;
+        }
+        fields.push((key.to_string(), value.to_string()));
+        rest = after.strip_prefix(' ').unwrap_or(after);
+    }
+    (!fields.is_empty()).then_some(fields)
+}
+
+/// Which records the log window shows
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogFilter {
+    pub levels: HashSet<LogLevel>,
+    /// Comma separated target prefixes to show; a leading `-` hides a prefix
+    pub targets: String,
+    /// Case-insensitive text matched against the whole line
+    pub search: String,
+}
+
+impl Default for LogFilter {
+    fn default() -> Self {
+        Self {
+            levels: [LogLevel::Error, LogLevel::Warn, LogLevel::Info]
+                .into_iter()
+                .collect(),
+            targets: String::new(),
+            search: String::new(),
+        }
+    }
+}
+
+impl LogFilter {
+    pub fn matches(&self, record: &LogRecord) -> bool {
+        record
+            .level
+            .map_or(true, |level| self.levels.contains(&level))
+            && self.matches_target(&record.target)
+            && (self.search.is_empty()
+                || record
+                    .raw
+                    .to_lowercase()
+                    .contains(&self.search.to_lowercase()))
+    }
+
+    pub fn matches_target(&self, target: &str) -> bool {
+        let mut included = None;
+        for pattern in self.targets.split(',').map(str::trim) {
+            if let Some(hidden) = pattern.strip_prefix('-') {
+                let hidden = hidden.trim();
+                if !hidden.is_empty() && target.starts_with(hidden) {
+                    return false;
+                }
+            } else if !pattern.is_empty() {
+                included = Some(included.unwrap_or(false) || target.starts_with(pattern));
+            }
+        }
+        included.unwrap_or(true)
+    }
+
+    /// Show only records from `target`
+    pub fn only_target(&mut self, target: &str) {
+        self.targets = target.to_string();
+    }
+
+    /// Hide records from `target`, keeping the other target patterns
+    pub fn hide_target(&mut self, target: &str) {
+        if self.targets.trim().is_empty() {
+            self.targets = format!("-{}", target);
+        } else {
+            self.targets = format!("{}, -{}", self.targets.trim(), target);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_record() {
+        let record = LogRecord::parse(
+            "2025-05-30T00:20:07.991790Z  INFO awsdash::app::dashui::menu: Log button clicked\n",
+        )
+        .unwrap();
+        assert_eq!(record.level, Some(LogLevel::Info));
+        assert_eq!(record.target, "awsdash::app::dashui::menu");
+        assert_eq!(record.message, "Log button clicked");
+        assert!(record.spans.is_empty());
+        assert!(record.fields.is_empty());
+        assert_eq!(
+            record.timestamp.unwrap().to_rfc3339(),
+            "2025-05-30T00:20:07.991790+00:00"
+        );
+
+        // A colon in the message doesn't move the target
+        let record =
+            LogRecord::parse("2025-05-30T00:20:07Z  WARN awsdash: Log levels: awsdash=info")
+                .unwrap();
+        assert_eq!(record.target, "awsdash");
+        assert_eq!(record.message, "Log levels:");
+        assert_eq!(record.fields, vec![("awsdash".into(), "info".into())]);
+
+        assert!(LogRecord::parse("    at src/main.rs:10").is_none());
+        assert!(LogRecord::parse("2025-05-30T00:20:07Z NOTICE x: y").is_none());
+    }
+
+    #[test]
+    fn test_parse_spans_and_fields() {
+        let record = LogRecord::parse(
+            "2025-05-30T00:20:08.1Z DEBUG invoke{service=s3 operation=ListBuckets}:try_op: \
+             aws_smithy_runtime::client::orchestrator: sending request attempt=1 \
+             uri=\"https://s3.amazonaws.com/?x=\\\"y z\\\"\"",
+        )
+        .unwrap();
+        assert_eq!(record.level, Some(LogLevel::Debug));
+        assert_eq!(
+            record.spans,
+            "invoke{service=s3 operation=ListBuckets}:try_op"
+        );
+        assert_eq!(record.target, "aws_smithy_runtime::client::orchestrator");
+        assert_eq!(record.message, "sending request");
+        assert_eq!(
+            record.fields,
+            vec![
+                ("attempt".into(), "1".into()),
+                (
+                    "uri".into(),
+                    "https://s3.amazonaws.com/?x=\\\"y z\\\"".into()
+                ),
+            ]
+        );
+
+        let record = LogRecord::parse(
+            "2025-05-30T00:20:08Z TRACE try_attempt: aws_smithy_runtime::client: done",
+        )
+        .unwrap();
+        assert_eq!(record.spans, "try_attempt");
+        assert_eq!(record.target, "aws_smithy_runtime::client");
+        assert_eq!(record.message, "done");
+    }
+
+    #[test]
+    fn test_push_record_appends_continuation_lines() {
+        let mut records = VecDeque::new();
+        push_record(&mut records, LogRecord::parse_line("orphan line"), 2);
+        push_record(
+            &mut records,
+            LogRecord::parse_line("2025-05-30T00:20:07Z ERROR awsdash: failed:"),
+            2,
+        );
+        push_record(
+            &mut records,
+            LogRecord::parse_line("  caused by: timeout"),
+            2,
+        );
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].level, None);
+        assert_eq!(records[1].message, "failed:\n  caused by: timeout");
+
+        push_record(
+            &mut records,
+            LogRecord::parse_line("2025-05-30T00:20:09Z  INFO awsdash: next"),
+            2,
+        );
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].level, Some(LogLevel::Error));
+    }
+
+    #[test]
+    fn test_filter() {
+        let record = |line: &str| LogRecord::parse(line).unwrap();
+        let app = record("2025-05-30T00:20:07Z  INFO awsdash::app::explorer: Loaded 3 buckets");
+        let sdk = record("2025-05-30T00:20:07Z DEBUG aws_smithy_runtime::client: sent");
+
+        let mut filter = LogFilter::default();
+        assert!(filter.matches(&app));
+        assert!(!filter.matches(&sdk));
+
+        filter.levels.insert(LogLevel::Debug);
+        filter.hide_target("aws_smithy");
+        assert!(filter.matches(&app));
+        assert!(!filter.matches(&sdk));
+
+        filter.only_target("aws_smithy_runtime");
+        assert!(!filter.matches(&app));
+        assert!(filter.matches(&sdk));
+
+        filter.targets.clear();
+        filter.search = "BUCKETS".to_string();
+        assert!(filter.matches(&app));
+        assert!(!filter.matches(&sdk));
+    }
+}
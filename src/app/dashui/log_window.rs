@@ -1,82 +1,41 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
+use super::log_records::{level_color, push_record, LogFilter, LogRecord};
 use super::window_focus::FocusableWindow;
-use eframe::egui;
+use crate::app::app_config::LogLevel;
+use chrono::Local;
+use eframe::egui::{self, Color32, RichText, Ui};
 use std::collections::VecDeque;
 use std::fs::File;
 use std::io::{BufRead, BufReader, Seek, SeekFrom};
 use std::path::PathBuf;
 use std::sync::mpsc::{channel, Receiver, Sender};
-use std::sync::{Arc, Mutex};
 use std::thread;
 use std::time::Duration;
 
 const MAX_LOG_LINES: usize = 1000;
 const UPDATE_INTERVAL_MS: u64 = 100;
 
-#[derive(Debug, Clone, PartialEq)]
-pub enum LogLevel {
-    Error,
-    Warn,
-    Info,
-    Debug,
-    Trace,
-}
-
-impl LogLevel {
-    fn from_str(s: &str) -> Self {
-        match s.to_uppercase().as_str() {
-            "ERROR" | "ERRO" => LogLevel::Error,
-            "WARN" | "WARNING" => LogLevel::Warn,
-            "INFO" => LogLevel::Info,
-            "DEBUG" | "DEBG" => LogLevel::Debug,
-            "TRACE" | "TRCE" => LogLevel::Trace,
-            _ => LogLevel::Info,
-        }
-    }
-
-    fn should_show(&self, filter_level: &LogLevel) -> bool {
-        match filter_level {
-            LogLevel::Error => matches!(self, LogLevel::Error),
-            LogLevel::Warn => matches!(self, LogLevel::Error | LogLevel::Warn),
-            LogLevel::Info => matches!(self, LogLevel::Error | LogLevel::Warn | LogLevel::Info),
-            LogLevel::Debug => matches!(
-                self,
-                LogLevel::Error | LogLevel::Warn | LogLevel::Info | LogLevel::Debug
-            ),
-            LogLevel::Trace => true, // Show all
-        }
-    }
-
-    fn as_str(&self) -> &'static str {
-        match self {
-            LogLevel::Error => "ERROR",
-            LogLevel::Warn => "WARN",
-            LogLevel::Info => "INFO",
-            LogLevel::Debug => "DEBUG",
-            LogLevel::Trace => "TRACE",
-        }
-    }
-}
-
-#[derive(Clone)]
-pub struct LogMessage {
-    pub timestamp: String,
-    pub level: String,
-    pub message: String,
-    pub full_line: String,
+/// Filter change picked from a row's context menu
+enum RowAction {
+    OnlyTarget(String),
+    HideTarget(String),
 }
 
 pub struct LogWindow {
     pub open: bool,
     log_path: PathBuf,
-    log_messages: Arc<Mutex<VecDeque<LogMessage>>>,
-    log_receiver: Option<Receiver<Vec<LogMessage>>>,
-    log_sender: Option<Sender<Vec<LogMessage>>>,
-    auto_scroll: bool,
-    search_query: String,
-    filter_level: LogLevel,
+    records: VecDeque<LogRecord>,
+    log_receiver: Option<Receiver<Vec<LogRecord>>>,
+    log_sender: Option<Sender<Vec<LogRecord>>>,
+    /// Keep the newest rows in view as they arrive
+    follow: bool,
+    /// Follow was just turned back on; scroll to the newest row once
+    scroll_to_latest: bool,
+    filter: LogFilter,
     watcher_thread: Option<thread::JoinHandle<()>>,
+    /// Tile the window is pinned to while docked, set by the app each frame
+    pub dock_rect: Option<egui::Rect>,
 }
 
 impl Default for LogWindow {
@@ -96,13 +55,14 @@ impl LogWindow {
         let mut window = Self {
             open: false,
             log_path: log_path.clone(),
-            log_messages: Arc::new(Mutex::new(VecDeque::with_capacity(MAX_LOG_LINES))),
+            records: VecDeque::with_capacity(MAX_LOG_LINES),
             log_receiver: Some(receiver),
             log_sender: Some(sender),
-            auto_scroll: true,
-            search_query: String::new(),
-            filter_level: LogLevel::Info, // Default to INFO level
+            follow: true,
+            scroll_to_latest: false,
+            filter: LogFilter::default(),
             watcher_thread: None,
+            dock_rect: None,
         };
 
         // Start the file watcher thread
@@ -124,7 +84,6 @@ impl LogWindow {
     fn start_watcher(&mut self) {
         let log_path = self.log_path.clone();
         let sender = self.log_sender.as_ref().unwrap().clone();
-        let _messages = self.log_messages.clone();
 
         // Start the watcher thread
         let handle = thread::spawn(move || {
@@ -158,15 +117,13 @@ impl LogWindow {
 
                     // Seek to last position
                     if reader.seek(SeekFrom::Start(last_position)).is_ok() {
-                        let mut new_messages = Vec::new();
+                        let mut new_records = Vec::new();
                         let mut line = String::new();
 
                         // Read new lines
                         while reader.read_line(&mut line).unwrap_or(0) > 0 {
                             if !line.trim().is_empty() {
-                                if let Some(msg) = Self::parse_log_line(&line) {
-                                    new_messages.push(msg);
-                                }
+                                new_records.push(LogRecord::parse_line(&line));
                             }
                             line.clear();
                         }
@@ -176,9 +133,9 @@ impl LogWindow {
                             last_position = pos;
                         }
 
-                        // Send new messages
-                        if !new_messages.is_empty() {
-                            let _ = sender.send(new_messages);
+                        // Send new records
+                        if !new_records.is_empty() {
+                            let _ = sender.send(new_records);
                         }
                     }
                 }
@@ -188,91 +145,6 @@ impl LogWindow {
         self.watcher_thread = Some(handle);
     }
 
-    fn parse_log_line(line: &str) -> Option<LogMessage> {
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            return None;
-        }
-
-        // Parse tracing/log format: TIMESTAMP LEVEL MODULE: MESSAGE
-        // Example: 2025-05-30T00:20:07.991790Z DEBUG awsdash::app::dashui::menu: Log button clicked
-        let parts: Vec<&str> = trimmed.splitn(4, ' ').collect();
-
-        if parts.len() >= 3 {
-            // Extract timestamp (first part)
-            let timestamp = parts[0].to_string();
-
-            // Extract log level (second part)
-            let level = parts[1].to_string();
-
-            // The rest is module and message
-            if parts.len() >= 3 {
-                // Find where the module ends and message begins (after the colon)
-                let module_and_message = parts[2..].join(" ");
-
-                // Split on first colon to separate module from message
-                if let Some(colon_pos) = module_and_message.find(':') {
-                    let module = module_and_message[..colon_pos].to_string();
-                    let message = module_and_message[colon_pos + 1..].trim().to_string();
-
-                    return Some(LogMessage {
-                        timestamp,
-                        level,
-                        message: format!("{}: {}", module, message),
-                        full_line: line.to_string(),
-                    });
-                }
-            }
-        }
-
-        // Fallback for simple-logging format: TIMESTAMP [LEVEL] MESSAGE
-        // Example: 2025-05-30 12:34:56 [INFO] Some message
-        // Also handles: 2025-05-30 12:34:56.123 INFO Some message
-        if let Some(bracket_start) = trimmed.find('[') {
-            if let Some(bracket_end) = trimmed.find(']') {
-                if bracket_end > bracket_start {
-                    let timestamp = trimmed[..bracket_start].trim().to_string();
-                    let level = trimmed[bracket_start + 1..bracket_end].to_string();
-                    let message = trimmed[bracket_end + 1..].trim().to_string();
-
-                    return Some(LogMessage {
-                        timestamp,
-                        level,
-                        message,
-                        full_line: line.to_string(),
-                    });
-                }
-            }
-        }
-
-        // Another fallback for space-separated format without brackets
-        // Example: 2025-05-30 12:34:56.123 INFO Some message
-        let parts: Vec<&str> = trimmed.splitn(3, ' ').collect();
-        if parts.len() >= 3 {
-            // Check if second part looks like a log level
-            let potential_level = parts[1].to_uppercase();
-            if matches!(
-                potential_level.as_str(),
-                "ERROR" | "WARN" | "INFO" | "DEBUG" | "TRACE"
-            ) {
-                return Some(LogMessage {
-                    timestamp: parts[0].to_string(),
-                    level: potential_level,
-                    message: parts[2].to_string(),
-                    full_line: line.to_string(),
-                });
-            }
-        }
-
-        // Fallback: treat whole line as message
-        Some(LogMessage {
-            timestamp: String::new(),
-            level: "INFO".to_string(),
-            message: trimmed.to_string(),
-            full_line: line.to_string(),
-        })
-    }
-
     pub fn toggle(&mut self) {
         self.open = !self.open;
     }
@@ -286,18 +158,11 @@ impl LogWindow {
             return;
         }
 
-        // Process any new messages
+        // Process any new records
         if let Some(receiver) = &self.log_receiver {
-            while let Ok(new_messages) = receiver.try_recv() {
-                if let Ok(mut messages) = self.log_messages.lock() {
-                    for msg in new_messages {
-                        messages.push_back(msg);
-
-                        // Remove old messages if we exceed the limit
-                        while messages.len() > MAX_LOG_LINES {
-                            messages.pop_front();
-                        }
-                    }
+            while let Ok(new_records) = receiver.try_recv() {
+                for record in new_records {
+                    push_record(&mut self.records, record, MAX_LOG_LINES);
                 }
             }
         }
@@ -308,16 +173,18 @@ impl LogWindow {
         let max_height = screen_rect.height() * 0.9; // 90% of screen height
 
         // Calculate default size that fits within screen bounds
-        let default_width = 800.0_f32.min(max_width);
+        let default_width = 900.0_f32.min(max_width);
         let default_height = 400.0_f32.min(max_height);
 
+        let mut is_open = self.open;
         let mut window = egui::Window::new("Log Viewer")
-            .open(&mut self.open)
+            .open(&mut is_open)
             .default_size([default_width, default_height])
             .max_size([max_width, max_height])
             .constrain(true) // Ensure window stays within screen bounds
             .resizable(true)
             .movable(true);
+        window = super::dock_layout::dock_window(window, self.dock_rect);
 
         // Bring to front if requested
         if bring_to_front {
@@ -325,287 +192,214 @@ impl LogWindow {
         }
 
         window.show(ctx, |ui| {
-            // Top bar with file path and controls
-            ui.horizontal(|ui| {
-                ui.label("Log file:");
-                ui.monospace(self.log_path.display().to_string());
-
-                ui.separator();
-
-                ui.checkbox(&mut self.auto_scroll, "Auto-scroll");
-
-                ui.separator();
-
-                ui.label("Level:");
-                egui::ComboBox::from_label("Filter Level")
-                    .selected_text(self.filter_level.as_str())
-                    .show_ui(ui, |ui| {
-                        ui.selectable_value(&mut self.filter_level, LogLevel::Error, "ERROR");
-                        ui.selectable_value(&mut self.filter_level, LogLevel::Warn, "WARN");
-                        ui.selectable_value(&mut self.filter_level, LogLevel::Info, "INFO");
-                        ui.selectable_value(&mut self.filter_level, LogLevel::Debug, "DEBUG");
-                        ui.selectable_value(&mut self.filter_level, LogLevel::Trace, "TRACE");
-                    });
-
-                ui.separator();
+            self.render_filters(ui);
+            ui.separator();
+            self.render_records(ui);
+        });
+        self.open = is_open;
 
-                ui.label("Search:");
-                ui.text_edit_singleline(&mut self.search_query);
+        // Request repaint to show updates
+        ctx.request_repaint_after(Duration::from_millis(UPDATE_INTERVAL_MS));
+    }
 
-                if ui.button("Clear").clicked() {
-                    if let Ok(mut messages) = self.log_messages.lock() {
-                        messages.clear();
-                    }
-                }
-            });
+    fn render_filters(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Search:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.filter.search)
+                    .hint_text("Message, target, or field")
+                    .desired_width(200.0),
+            );
+
+            ui.label("Target:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.filter.targets)
+                    .hint_text("awsdash::app, -aws_smithy")
+                    .desired_width(200.0),
+            )
+            .on_hover_text(
+                "Comma separated target prefixes to show; prefix one with '-' to hide it",
+            );
 
             ui.separator();
 
-            // Log content area
-            egui::ScrollArea::both()
-                .auto_shrink([false; 2])
-                .stick_to_bottom(self.auto_scroll)
-                .show(ui, |ui| {
-                    if let Ok(messages) = self.log_messages.lock() {
-                        let total_messages = messages.len();
-                        let mut shown_messages = 0;
-
-                        for msg in messages.iter() {
-                            // Filter by log level
-                            let msg_level = LogLevel::from_str(&msg.level);
-                            if !msg_level.should_show(&self.filter_level) {
-                                continue;
-                            }
-
-                            // Filter by search query
-                            if !self.search_query.is_empty()
-                                && !msg
-                                    .full_line
-                                    .to_lowercase()
-                                    .contains(&self.search_query.to_lowercase())
-                            {
-                                continue;
-                            }
-
-                            shown_messages += 1;
-
-                            ui.horizontal(|ui| {
-                                // Set smaller font size for log lines
-                                ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
-                                ui.style_mut().text_styles.insert(
-                                    egui::TextStyle::Monospace,
-                                    egui::FontId::new(10.0, egui::FontFamily::Monospace),
-                                );
-
-                                // Timestamp
-                                if !msg.timestamp.is_empty() {
-                                    ui.monospace(&msg.timestamp);
-                                }
-
-                                // Level with color
-                                let (level_color, level_text) = match msg.level.as_str() {
-                                    "ERROR" | "ERRO" => {
-                                        (egui::Color32::from_rgb(255, 100, 100), "ERROR")
-                                    }
-                                    "WARN" | "WARNING" => {
-                                        (egui::Color32::from_rgb(255, 200, 100), "WARN")
-                                    }
-                                    "INFO" => (egui::Color32::from_rgb(100, 200, 255), "INFO"),
-                                    "DEBUG" | "DEBG" => {
-                                        (egui::Color32::from_rgb(150, 150, 150), "DEBUG")
-                                    }
-                                    "TRACE" | "TRCE" => {
-                                        (egui::Color32::from_rgb(120, 120, 120), "TRACE")
-                                    }
-                                    _ => {
-                                        (egui::Color32::from_rgb(200, 200, 200), msg.level.as_str())
-                                    }
-                                };
-
-                                ui.colored_label(level_color, level_text);
-
-                                // Message
-                                ui.monospace(&msg.message);
-                            });
-                        }
+            if ui.checkbox(&mut self.follow, "Follow").changed() && self.follow {
+                self.scroll_to_latest = true;
+            }
+            if ui.button("Clear").clicked() {
+                self.records.clear();
+            }
+        });
 
-                        // Show filter status at the bottom
-                        if shown_messages < total_messages {
-                            ui.separator();
-                            ui.label(format!(
-                                "Showing {} of {} messages (filtered by level: {})",
-                                shown_messages,
-                                total_messages,
-                                self.filter_level.as_str()
-                            ));
-                        }
+        ui.horizontal(|ui| {
+            for level in LogLevel::ALL {
+                if level == LogLevel::Off {
+                    continue;
+                }
+                let mut shown = self.filter.levels.contains(&level);
+                let text = RichText::new(level.label())
+                    .monospace()
+                    .color(level_color(level));
+                if ui.checkbox(&mut shown, text).changed() {
+                    if shown {
+                        self.filter.levels.insert(level);
+                    } else {
+                        self.filter.levels.remove(&level);
                     }
-                });
+                }
+            }
         });
-
-        // Request repaint to show updates
-        ctx.request_repaint_after(Duration::from_millis(UPDATE_INTERVAL_MS));
     }
 
-    pub fn show_with_offset(&mut self, ctx: &egui::Context, _offset: egui::Vec2) {
-        if !self.open {
-            return;
-        }
+    fn render_records(&mut self, ui: &mut Ui) {
+        let shown: Vec<&LogRecord> = self
+            .records
+            .iter()
+            .filter(|record| self.filter.matches(record))
+            .collect();
+
+        ui.horizontal(|ui| {
+            ui.label(
+                RichText::new(format!(
+                    "Showing {} of {} rows",
+                    shown.len(),
+                    self.records.len()
+                ))
+                .small(),
+            );
+            ui.label(
+                RichText::new(self.log_path.display().to_string())
+                    .small()
+                    .weak(),
+            );
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .add_enabled(!shown.is_empty(), egui::Button::new("Copy"))
+                    .on_hover_text("Copy the rows shown as they appear in the log file")
+                    .clicked()
+                {
+                    let text: Vec<&str> = shown.iter().map(|record| record.raw.as_str()).collect();
+                    ui.ctx().copy_text(text.join("\n"));
+                }
+            });
+        });
 
-        // Process any new messages
-        if let Some(receiver) = &self.log_receiver {
-            while let Ok(new_messages) = receiver.try_recv() {
-                if let Ok(mut messages) = self.log_messages.lock() {
-                    for msg in new_messages {
-                        messages.push_back(msg);
-
-                        // Remove old messages if we exceed the limit
-                        while messages.len() > MAX_LOG_LINES {
-                            messages.pop_front();
+        let mut action = None;
+        let output = egui::ScrollArea::both()
+            .auto_shrink([false; 2])
+            .stick_to_bottom(self.follow)
+            .show(ui, |ui| {
+                // Set smaller font size for log lines
+                ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
+                ui.style_mut().text_styles.insert(
+                    egui::TextStyle::Monospace,
+                    egui::FontId::new(10.0, egui::FontFamily::Monospace),
+                );
+
+                egui::Grid::new("log_window_records")
+                    .num_columns(4)
+                    .spacing([10.0, 2.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for record in &shown {
+                            if let Some(picked) = Self::render_record(ui, record) {
+                                action = Some(picked);
+                            }
+                            ui.end_row();
                         }
-                    }
+                    });
+
+                if std::mem::take(&mut self.scroll_to_latest) {
+                    ui.scroll_to_cursor(Some(egui::Align::BOTTOM));
                 }
-            }
+            });
+
+        // Scrolling back through the log stops following it
+        if self.follow
+            && ui.rect_contains_pointer(output.inner_rect)
+            && ui.input(|i| i.smooth_scroll_delta.y > 0.0)
+        {
+            self.follow = false;
         }
 
-        // Get the available screen rect to constrain window size
-        let screen_rect = ctx.screen_rect();
-        let max_width = screen_rect.width() * 0.9; // 90% of screen width
-        let max_height = screen_rect.height() * 0.9; // 90% of screen height
+        match action {
+            Some(RowAction::OnlyTarget(target)) => self.filter.only_target(&target),
+            Some(RowAction::HideTarget(target)) => self.filter.hide_target(&target),
+            None => {}
+        }
+    }
 
-        // Calculate default size that fits within screen bounds
-        let default_width = 800.0_f32.min(max_width);
-        let default_height = 400.0_f32.min(max_height);
+    fn render_record(ui: &mut Ui, record: &LogRecord) -> Option<RowAction> {
+        let mut action = None;
 
-        egui::Window::new("Log Viewer")
-            .open(&mut self.open)
-            .default_size([default_width, default_height])
-            .max_size([max_width, max_height])
-            .constrain(true) // Ensure window stays within screen bounds
-            .resizable(true)
-            .movable(true)
-            .show(ctx, |ui| {
-                // Top bar with file path and controls
-                ui.horizontal(|ui| {
-                    ui.label("Log file:");
-                    ui.monospace(self.log_path.display().to_string());
-
-                    ui.separator();
-
-                    ui.checkbox(&mut self.auto_scroll, "Auto-scroll");
-
-                    ui.separator();
-
-                    ui.label("Level:");
-                    egui::ComboBox::from_label("Filter Level")
-                        .selected_text(self.filter_level.as_str())
-                        .show_ui(ui, |ui| {
-                            ui.selectable_value(&mut self.filter_level, LogLevel::Error, "ERROR");
-                            ui.selectable_value(&mut self.filter_level, LogLevel::Warn, "WARN");
-                            ui.selectable_value(&mut self.filter_level, LogLevel::Info, "INFO");
-                            ui.selectable_value(&mut self.filter_level, LogLevel::Debug, "DEBUG");
-                            ui.selectable_value(&mut self.filter_level, LogLevel::Trace, "TRACE");
-                        });
-
-                    ui.separator();
-
-                    ui.label("Search:");
-                    ui.text_edit_singleline(&mut self.search_query);
-
-                    if ui.button("Clear").clicked() {
-                        if let Ok(mut messages) = self.log_messages.lock() {
-                            messages.clear();
-                        }
-                    }
-                });
+        let time = record
+            .timestamp
+            .map(|t| t.with_timezone(&Local).format("%H:%M:%S%.3f").to_string())
+            .unwrap_or_default();
+        let response = ui.label(RichText::new(time).monospace().weak());
+        if let Some(timestamp) = record.timestamp {
+            response.on_hover_text(timestamp.with_timezone(&Local).to_rfc3339());
+        }
 
-                ui.separator();
+        match record.level {
+            Some(level) => ui.label(
+                RichText::new(level.label())
+                    .monospace()
+                    .strong()
+                    .color(level_color(level)),
+            ),
+            None => ui.label(""),
+        };
 
-                // Log content area
-                egui::ScrollArea::both()
-                    .auto_shrink([false; 2])
-                    .stick_to_bottom(self.auto_scroll)
-                    .show(ui, |ui| {
-                        if let Ok(messages) = self.log_messages.lock() {
-                            let total_messages = messages.len();
-                            let mut shown_messages = 0;
-
-                            for msg in messages.iter() {
-                                // Filter by log level
-                                let msg_level = LogLevel::from_str(&msg.level);
-                                if !msg_level.should_show(&self.filter_level) {
-                                    continue;
-                                }
-
-                                // Filter by search query
-                                if !self.search_query.is_empty()
-                                    && !msg
-                                        .full_line
-                                        .to_lowercase()
-                                        .contains(&self.search_query.to_lowercase())
-                                {
-                                    continue;
-                                }
-
-                                shown_messages += 1;
-
-                                ui.horizontal(|ui| {
-                                    // Set smaller font size for log lines
-                                    ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
-                                    ui.style_mut().text_styles.insert(
-                                        egui::TextStyle::Monospace,
-                                        egui::FontId::new(10.0, egui::FontFamily::Monospace),
-                                    );
-
-                                    // Timestamp
-                                    if !msg.timestamp.is_empty() {
-                                        ui.monospace(&msg.timestamp);
-                                    }
-
-                                    // Level with color
-                                    let (level_color, level_text) = match msg.level.as_str() {
-                                        "ERROR" | "ERRO" => {
-                                            (egui::Color32::from_rgb(255, 100, 100), "ERROR")
-                                        }
-                                        "WARN" | "WARNING" => {
-                                            (egui::Color32::from_rgb(255, 200, 100), "WARN")
-                                        }
-                                        "INFO" => (egui::Color32::from_rgb(100, 200, 255), "INFO"),
-                                        "DEBUG" | "DEBG" => {
-                                            (egui::Color32::from_rgb(150, 150, 150), "DEBUG")
-                                        }
-                                        "TRACE" | "TRCE" => {
-                                            (egui::Color32::from_rgb(120, 120, 120), "TRACE")
-                                        }
-                                        _ => (
-                                            egui::Color32::from_rgb(200, 200, 200),
-                                            msg.level.as_str(),
-                                        ),
-                                    };
-
-                                    ui.colored_label(level_color, level_text);
-
-                                    // Message
-                                    ui.monospace(&msg.message);
-                                });
-                            }
+        let response = ui.add(
+            egui::Label::new(RichText::new(&record.target).monospace().weak())
+                .sense(egui::Sense::click()),
+        );
+        let response = if record.spans.is_empty() {
+            response
+        } else {
+            response.on_hover_text(format!("Spans: {}", record.spans))
+        };
+        if !record.target.is_empty() {
+            response.context_menu(|ui| {
+                if ui.button("Show Only This Target").clicked() {
+                    action = Some(RowAction::OnlyTarget(record.target.clone()));
+                    ui.close();
+                }
+                if ui.button("Hide This Target").clicked() {
+                    action = Some(RowAction::HideTarget(record.target.clone()));
+                    ui.close();
+                }
+            });
+        }
 
-                            // Show filter status at the bottom
-                            if shown_messages < total_messages {
-                                ui.separator();
-                                ui.label(format!(
-                                    "Showing {} of {} messages (filtered by level: {})",
-                                    shown_messages,
-                                    total_messages,
-                                    self.filter_level.as_str()
-                                ));
-                            }
-                        }
-                    });
+        ui.horizontal(|ui| {
+            let mut message = RichText::new(&record.message).monospace();
+            match record.level {
+                Some(level @ (LogLevel::Error | LogLevel::Warn)) => {
+                    message = message.color(level_color(level));
+                }
+                None => message = message.weak(),
+                _ => {}
+            }
+            let response = ui.add(egui::Label::new(message).sense(egui::Sense::click()));
+            response.context_menu(|ui| {
+                if ui.button("Copy Line").clicked() {
+                    ui.ctx().copy_text(record.raw.clone());
+                    ui.close();
+                }
             });
 
-        // Request repaint to show updates
-        ctx.request_repaint_after(Duration::from_millis(UPDATE_INTERVAL_MS));
+            for (key, value) in &record.fields {
+                ui.label(
+                    RichText::new(format!("{}={}", key, value))
+                        .monospace()
+                        .color(Color32::from_rgb(150, 180, 150)),
+                );
+            }
+        });
+
+        action
     }
 }
 
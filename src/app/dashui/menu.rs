@@ -1,5 +1,7 @@
-use crate::app::aws_identity::LoginState;
+use crate::app::aws_identity::{LoginState, SessionHealth};
 use crate::app::dashui::app::{NavigationStatusBarSettings, ThemeChoice};
+use crate::app::dashui::app::{NavigationStatusBarSettings, ThemeChoice};
+use crate::app::dashui::aws_login_window::{format_remaining, session_health_text};
 use eframe::egui;
 use egui::{Color32, RichText};
 use std::sync::{Arc, Mutex};
@@ -13,9 +15,21 @@ pub enum MenuAction {
     ShowComplianceDetails,
     ValidateCompliance,
     LoginAWS,
+    ReauthenticateAWS,
     AWSExplorer,
+    SecurityFindings,
+    ServiceQuotas,
+    HealthEvents,
+    Recommendations,
+    DnsRecords,
     AgentManager,
     PagesManager,
+    OpenPages,
+    Settings,
+    KeyboardShortcuts,
+    WindowLayouts,
+    NotificationHistory,
+    NotificationWebhooks,
     Quit,
 }
 
@@ -64,12 +78,46 @@ pub fn build_menu(
         if ui.button("Explorer").clicked() {
             menu_action = MenuAction::AWSExplorer;
         }
+        if ui.button("Security Findings").clicked() {
+            menu_action = MenuAction::SecurityFindings;
+        }
+        if ui.button("Service Quotas").clicked() {
+            menu_action = MenuAction::ServiceQuotas;
+        }
+        if ui.button("AWS Health").clicked() {
+            menu_action = MenuAction::HealthEvents;
+        }
+        if ui.button("Recommendations").clicked() {
+            menu_action = MenuAction::Recommendations;
+        }
+        if ui.button("DNS Records").clicked() {
+            menu_action = MenuAction::DnsRecords;
+        }
         if ui.button("Agents").clicked() {
             menu_action = MenuAction::AgentManager;
         }
         if ui.button("Pages").clicked() {
             menu_action = MenuAction::PagesManager;
         }
+        if ui.button("Open Pages").clicked() {
+            menu_action = MenuAction::OpenPages;
+        }
+        ui.separator();
+        if ui.button("Settings").clicked() {
+            menu_action = MenuAction::Settings;
+        }
+        if ui.button("Keyboard Shortcuts").clicked() {
+            menu_action = MenuAction::KeyboardShortcuts;
+        }
+        if ui.button("Window Layouts").clicked() {
+            menu_action = MenuAction::WindowLayouts;
+        }
+        if ui.button("Notification History").clicked() {
+            menu_action = MenuAction::NotificationHistory;
+        }
+        if ui.button("Notification Webhooks").clicked() {
+            menu_action = MenuAction::NotificationWebhooks;
+        }
         ui.separator();
         if ui.button("Quit").clicked() {
             menu_action = MenuAction::Quit;
@@ -133,7 +181,9 @@ pub fn build_menu(
     }
 
     // AWS login status indicator
-    show_aws_login_status(ui, aws_identity_center);
+    if show_aws_login_status(ui, aws_identity_center) {
+        menu_action = MenuAction::ReauthenticateAWS;
+    }
 
     // Compliance programs display and validation button
     if let Some(validation_action) =
@@ -198,22 +248,30 @@ pub fn build_menu(
 }
 
 /// Displays the AWS login status indicator
+///
+/// While logged in, also shows how long the session token has left. When it
+/// expires soon without a refresh, a re-authenticate button appears; returns
+/// true when it is clicked.
 fn show_aws_login_status(
     ui: &mut egui::Ui,
     aws_identity_center: Option<&Arc<Mutex<crate::app::aws_identity::AwsIdentityCenter>>>,
-) {
+) -> bool {
     // Get the LoginState as the single source of truth
     // Use try_lock() to avoid blocking UI when login thread holds the mutex
-    let (login_state, lock_busy) = if let Some(aws_identity) = aws_identity_center {
+    let (login_state, session_health, lock_busy) = if let Some(aws_identity) = aws_identity_center {
         match aws_identity.try_lock() {
-            Ok(identity) => (Some(identity.login_state.clone()), false),
+            Ok(identity) => (
+                Some(identity.login_state.clone()),
+                identity.session_health(chrono::Utc::now()),
+                false,
+            ),
             Err(_) => {
                 // Lock is held by login thread - indicate busy state
-                (None, true)
+                (None, None, true)
             }
         }
     } else {
-        (None, false)
+        (None, None, false)
     };
 
     // Display the indicator based on LoginState (or busy state if lock contended)
@@ -260,6 +318,38 @@ fn show_aws_login_status(
         log_debug!("AWS login indicator clicked");
         // Could trigger login window here if needed
     }
+
+    let Some(health) = session_health else {
+        return false;
+    };
+
+    // Keep the countdown current while the app is idle
+    ui.ctx()
+        .request_repaint_after(std::time::Duration::from_secs(30));
+
+    match health {
+        SessionHealth::Active { remaining } => {
+            ui.label(RichText::new(format_remaining(remaining)).size(11.0).weak())
+                .on_hover_text(session_health_text(health));
+            false
+        }
+        SessionHealth::ExpiringSoon { remaining } => ui
+            .button(
+                RichText::new(format!("Re-authenticate ({})", format_remaining(remaining)))
+                    .size(12.0)
+                    .color(Color32::from_rgb(220, 180, 50)),
+            )
+            .on_hover_text(session_health_text(health))
+            .clicked(),
+        SessionHealth::Expired => ui
+            .button(
+                RichText::new("Session expired - log in")
+                    .size(12.0)
+                    .color(Color32::from_rgb(200, 50, 50)),
+            )
+            .on_hover_text(session_health_text(health))
+            .clicked(),
+    }
 }
 
 /// Displays the compliance status indicator for CloudFormation Guard validation
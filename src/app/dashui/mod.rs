@@ -17,6 +17,7 @@
 //!
 //! ## Command Palette System
 //! - **General Commands**: [`command_palette::CommandPalette`] for application-wide operations
+//! - **Subsystem Commands**: [`command_registry::CommandRegistry`] for context-aware commands found by search
 //! - **Project Commands**: [`project_command_palette::ProjectCommandPalette`] for project management
 //! - **CloudFormation Commands**: [`cloudformation_command_palette::CloudFormationCommandPalette`] for template operations
 //! - **Fuzzy Search**: All palettes include intelligent search and filtering
@@ -51,7 +52,8 @@
 //! - **Drag and Drop**: Support for template files and project imports
 //!
 //! ## Development Tools
-//! - **Log Viewer**: [`log_window::LogWindow`] for real-time application logging
+//! - **Log Viewer**: [`log_window::LogWindow`] for real-time application logging, parsed into
+//!   filterable rows by [`log_records`]
 //! - **Verification Tools**: [`verification_window::VerificationWindow`] for template validation
 //!
 //! # Window Categories
@@ -61,6 +63,10 @@
 //! ## Core Application Windows
 //! - [`app::DashApp`] - Main application coordinator and state manager
 //! - [`help_window::HelpWindow`] - User documentation and guidance
+//! - [`settings_window::SettingsWindow`] - Application settings (see [`crate::app::app_config`])
+//! - [`key_binding_editor_window::KeyBindingEditorWindow`] - Keyboard shortcut rebinding
+//! - [`dock_layout_window::DockLayoutWindow`] - Docked window layouts and layout profiles
+//! - [`native_windows::NativeWindowManager`] - Major windows moved into their own OS windows
 //! - [`download_manager::DownloadManager`] - Background download coordination
 //!
 //! ## AWS Integration Windows
@@ -76,47 +82,136 @@
 //! detailed implementation patterns and the [UI testing guide](../../../docs/technical/ui-component-testing.wiki)
 //! for testing strategies.
 
+pub mod agent_cost_window;
+pub mod agent_guardrail_window;
 pub mod agent_log_window;
+pub mod agent_memory_window;
 pub mod agent_manager_window;
+pub mod agent_provider_window;
+pub mod agent_timeline_window;
 pub mod app;
+pub mod athena_query_window;
+pub mod auto_scaling_group_window;
 pub mod aws_login_window;
+pub mod bedrock_model_catalog_window;
+pub mod change_set_review;
+pub mod cloudfront_invalidation_window;
 pub mod cloudtrail_events_window;
+pub mod cloudtrail_timeline;
 pub mod cloudwatch_logs_window;
+pub mod cloudwatch_metrics_window;
 pub mod command_palette;
+pub mod command_registry;
+pub mod config_history_window;
+pub mod dns_records_window;
+pub mod dock_layout;
+pub mod dock_layout_window;
+pub mod eventbridge_browser_window;
+pub mod health_events_window;
 pub mod help_window;
 pub mod hint_mode;
+pub mod key_binding_editor_window;
 pub mod key_mapping;
 pub mod keyboard_navigation;
+pub mod kms_key_window;
+pub mod lambda_invoke_window;
+pub mod log_records;
 pub mod log_window;
 pub mod menu;
+pub mod native_windows;
 pub mod navigable_widgets;
 pub mod navigation_state;
+pub mod open_pages_window;
+pub mod prompt_editor_window;
+pub mod recommendations_window;
+pub mod relationship_graph_window;
+pub mod s3_bucket_window;
+pub mod scheduled_tasks_window;
+pub mod security_findings_window;
+pub mod service_quotas_window;
+pub mod settings_window;
+pub mod skills_manager_window;
+pub mod sqs_queue_window;
+pub mod ssm_session_window;
+pub mod stack_drift_window;
+pub mod stack_import_window;
+pub mod stack_update_window;
+pub mod step_functions_window;
+pub mod task_queue_window;
+pub mod template_preview_window;
+pub mod threat_findings_window;
 pub mod verification_window;
 pub mod vfs_browser_window;
 pub mod window_focus;
 pub mod window_maximize;
 pub mod window_selector;
+pub mod window_utils;
 
+pub use agent_cost_window::AgentCostWindow;
+pub use agent_guardrail_window::AgentGuardrailWindow;
 pub use agent_log_window::AgentLogWindow;
+pub use agent_memory_window::AgentMemoryWindow;
 pub use agent_manager_window::AgentManagerWindow;
+pub use agent_provider_window::AgentProviderWindow;
+pub use agent_timeline_window::AgentTimelineWindow;
 pub use app::DashApp;
+pub use athena_query_window::{AthenaQueryShowParams, AthenaQueryWindow};
+pub use auto_scaling_group_window::{AutoScalingGroupShowParams, AutoScalingGroupWindow};
 pub use aws_login_window::AwsLoginWindow;
+pub use bedrock_model_catalog_window::{BedrockModelCatalogShowParams, BedrockModelCatalogWindow};
+pub use cloudfront_invalidation_window::{
+    CloudFrontInvalidationShowParams, CloudFrontInvalidationWindow,
+};
 pub use cloudtrail_events_window::{CloudTrailEventsShowParams, CloudTrailEventsWindow};
 pub use cloudwatch_logs_window::{CloudWatchLogsShowParams, CloudWatchLogsWindow};
+pub use cloudwatch_metrics_window::{CloudWatchMetricsShowParams, CloudWatchMetricsWindow};
 pub use command_palette::CommandPalette;
+pub use command_registry::{CommandCategory, CommandContext, CommandRegistry, PaletteCommand};
+pub use config_history_window::{ConfigHistoryShowParams, ConfigHistoryWindow};
+pub use dns_records_window::DnsRecordsWindow;
+pub use dock_layout::{DockManager, DockPanel, DockPlacement};
+pub use dock_layout_window::DockLayoutWindow;
+pub use eventbridge_browser_window::{EventBridgeBrowserShowParams, EventBridgeBrowserWindow};
+pub use health_events_window::HealthEventsWindow;
 pub use help_window::HelpWindow;
 pub use hint_mode::{HintConfig, HintGenerator, HintMarker, HintMode, HintOverlay};
-pub use key_mapping::{KeyBindingMap, KeyBindingSettings, KeyMapping, KeyMappingRegistry};
+pub use key_binding_editor_window::KeyBindingEditorWindow;
+pub use key_mapping::{
+    KeyBindingMap, KeyBindingSettings, KeyBindingStore, KeyConflict, KeyMapping,
+    KeyMappingRegistry,
+};
 pub use keyboard_navigation::{
     ElementAction, KeyEventResult, KeyboardNavigable, NavigableElement, NavigableElementType,
     NavigableWindow, NavigationCommand, NavigationContext, NavigationMode,
 };
+pub use kms_key_window::{KmsKeyShowParams, KmsKeyWindow};
+pub use lambda_invoke_window::{LambdaInvokeShowParams, LambdaInvokeWindow};
 pub use log_window::LogWindow;
+pub use native_windows::NativeWindowManager;
 pub use navigable_widgets::{
     DefaultNavigableContainer, FocusState, FocusStyle, NavigableContainer,
     NavigableElementCollector, NavigableWidget, NavigableWidgetManager, WidgetState,
 };
 pub use navigation_state::NavigationState;
+pub use open_pages_window::OpenPagesWindow;
+pub use prompt_editor_window::PromptEditorWindow;
+pub use recommendations_window::RecommendationsWindow;
+pub use relationship_graph_window::{RelationshipGraphShowParams, RelationshipGraphWindow};
+pub use s3_bucket_window::{S3BucketShowParams, S3BucketWindow};
+pub use scheduled_tasks_window::ScheduledTasksWindow;
+pub use security_findings_window::SecurityFindingsWindow;
+pub use service_quotas_window::ServiceQuotasWindow;
+pub use settings_window::SettingsWindow;
+pub use skills_manager_window::SkillsManagerWindow;
+pub use sqs_queue_window::{SqsQueueShowParams, SqsQueueWindow};
+pub use ssm_session_window::{SsmSessionShowParams, SsmSessionWindow};
+pub use stack_drift_window::{StackDriftShowParams, StackDriftWindow};
+pub use stack_import_window::{StackImportShowParams, StackImportWindow};
+pub use stack_update_window::{StackUpdateShowParams, StackUpdateWindow};
+pub use step_functions_window::{StepFunctionsShowParams, StepFunctionsWindow};
+pub use task_queue_window::TaskQueueWindow;
+pub use template_preview_window::{TemplatePreviewShowParams, TemplatePreviewWindow};
+pub use threat_findings_window::ThreatFindingsWindow;
 pub use verification_window::VerificationWindow;
 pub use vfs_browser_window::VfsBrowserWindow;
 pub use window_focus::{
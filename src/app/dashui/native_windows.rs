@@ -0,0 +1,445 @@
+//! Native Windows
+//!
+//! Lets a major window (Explorer, Agents, Log Viewer, CloudTrail Events) be
+//! moved out of the main window into its own OS-level window, so it can sit on
+//! another monitor. Each moved window is an egui immediate viewport: the app
+//! renders the window inside [`NativeWindowManager::show`] and pins it to the
+//! viewport with [`dock_window`](super::dock_layout::dock_window).
+//!
+//! Positions and sizes are remembered per monitor configuration in
+//! `~/.config/awsdash/viewports.toml` (see [`MonitorLayoutStore`]). The
+//! configuration is identified by the monitor the main window is on, since
+//! that is all egui reports. When it changes, windows already moved out jump
+//! to the positions saved for the new configuration.
+//!
+//! Closing the OS window puts the window back into the main window. On
+//! backends without multi-viewport support the window stays in the main
+//! window and floats as usual.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context as _, Result};
+use eframe::egui;
+use egui::{Context, Rect, ViewportBuilder, ViewportClass, ViewportId, ViewportInfo};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::command_registry::{CommandCategory, PaletteCommand};
+use super::dock_layout::DockPanel;
+
+/// Size of a moved-out window with no saved position for this monitor configuration
+const DEFAULT_SIZE: [f32; 2] = [1000.0, 700.0];
+
+/// Palette command id for moving the Explorer into its own window
+pub const MOVE_EXPLORER: &str = "general.native_window_explorer";
+/// Palette command id for moving the Agent Manager into its own window
+pub const MOVE_AGENTS: &str = "general.native_window_agents";
+/// Palette command id for moving the Log Viewer into its own window
+pub const MOVE_LOGS: &str = "general.native_window_logs";
+/// Palette command id for moving a CloudTrail Events window into its own window
+pub const MOVE_CLOUDTRAIL: &str = "general.native_window_cloudtrail";
+/// Palette command id for putting every moved-out window back
+pub const RETURN_ALL: &str = "general.native_windows_return_all";
+
+/// Commands the manager offers in the palette
+pub fn palette_commands() -> Vec<PaletteCommand> {
+    vec![
+        PaletteCommand {
+            id: MOVE_EXPLORER,
+            label: "Move Explorer to its own window",
+            description: "Show the Explorer in a separate OS window, e.g. on another monitor",
+            category: CommandCategory::General,
+            keywords: &["native", "monitor", "viewport", "pop out", "detach"],
+            is_available: |_| true,
+        },
+        PaletteCommand {
+            id: MOVE_AGENTS,
+            label: "Move Agent Manager to its own window",
+            description: "Show the Agent Manager in a separate OS window",
+            category: CommandCategory::General,
+            keywords: &["native", "monitor", "viewport", "pop out", "detach"],
+            is_available: |_| true,
+        },
+        PaletteCommand {
+            id: MOVE_LOGS,
+            label: "Move Log Viewer to its own window",
+            description: "Show the Log Viewer in a separate OS window",
+            category: CommandCategory::General,
+            keywords: &["native", "monitor", "viewport", "pop out", "detach"],
+            is_available: |_| true,
+        },
+        PaletteCommand {
+            id: MOVE_CLOUDTRAIL,
+            label: "Move CloudTrail Events to its own window",
+            description: "Show the most recent CloudTrail Events window in a separate OS window",
+            category: CommandCategory::General,
+            keywords: &["native", "monitor", "viewport", "pop out", "detach"],
+            is_available: |_| true,
+        },
+        PaletteCommand {
+            id: RETURN_ALL,
+            label: "Return windows to main window",
+            description: "Put every window shown in its own OS window back into the main window",
+            category: CommandCategory::General,
+            keywords: &["native", "monitor", "viewport", "attach"],
+            is_available: |_| true,
+        },
+    ]
+}
+
+/// Window a move command is for
+pub fn command_panel(id: &str) -> Option<DockPanel> {
+    match id {
+        MOVE_EXPLORER => Some(DockPanel::Explorer),
+        MOVE_AGENTS => Some(DockPanel::Agents),
+        MOVE_LOGS => Some(DockPanel::Logs),
+        MOVE_CLOUDTRAIL => Some(DockPanel::CloudTrail),
+        _ => None,
+    }
+}
+
+/// Key identifying the monitor configuration, e.g. `2560x1440@2`
+///
+/// Uses the monitor of the viewport `info` describes; `unknown` when the
+/// backend does not report it.
+pub fn monitor_key(info: &ViewportInfo) -> String {
+    match info.monitor_size {
+        Some(size) => format!(
+            "{}x{}@{}",
+            size.x.round(),
+            size.y.round(),
+            info.native_pixels_per_point.unwrap_or(1.0)
+        ),
+        None => "unknown".to_string(),
+    }
+}
+
+/// Saved position and size of a moved-out window
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SavedViewport {
+    pub panel: DockPanel,
+    /// Outer position in desktop points
+    pub pos: [f32; 2],
+    /// Inner size in points
+    pub size: [f32; 2],
+}
+
+/// Saved window positions per monitor configuration
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MonitorLayouts {
+    #[serde(default)]
+    pub monitors: BTreeMap<String, Vec<SavedViewport>>,
+}
+
+impl MonitorLayouts {
+    pub fn get(&self, monitor: &str, panel: DockPanel) -> Option<SavedViewport> {
+        self.monitors
+            .get(monitor)?
+            .iter()
+            .find(|saved| saved.panel == panel)
+            .copied()
+    }
+
+    /// Remember `saved` for `monitor`; returns whether anything changed
+    pub fn set(&mut self, monitor: &str, saved: SavedViewport) -> bool {
+        let entries = self.monitors.entry(monitor.to_string()).or_default();
+        match entries.iter_mut().find(|entry| entry.panel == saved.panel) {
+            Some(entry) if *entry == saved => false,
+            Some(entry) => {
+                *entry = saved;
+                true
+            }
+            None => {
+                entries.push(saved);
+                true
+            }
+        }
+    }
+}
+
+/// Window positions file in the config directory
+#[derive(Debug, Clone)]
+pub struct MonitorLayoutStore {
+    path: PathBuf,
+}
+
+impl MonitorLayoutStore {
+    /// Store backed by the TOML file at `path`
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Store in the application config directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(
+            dirs::config_dir()?.join("awsdash/viewports.toml"),
+        ))
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Saved positions; none when the file does not exist yet
+    pub fn load(&self) -> Result<MonitorLayouts> {
+        if !self.path.exists() {
+            return Ok(MonitorLayouts::default());
+        }
+        let content = fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read window positions {}", self.path.display()))?;
+        toml::from_str(&content)
+            .with_context(|| format!("Invalid window positions in {}", self.path.display()))
+    }
+
+    pub fn save(&self, layouts: &MonitorLayouts) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).with_context(|| {
+                format!("Failed to create config directory {}", parent.display())
+            })?;
+        }
+        let content =
+            toml::to_string_pretty(layouts).context("Failed to serialize window positions")?;
+        fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write window positions {}", self.path.display()))
+    }
+}
+
+/// Viewport builder for a moved-out window placed at `saved`
+fn viewport_builder(panel: DockPanel, saved: Option<SavedViewport>) -> ViewportBuilder {
+    let builder = ViewportBuilder::default()
+        .with_title(format!("{} - AWS Dash", panel.label()))
+        .with_min_inner_size([400.0, 300.0]);
+    match saved {
+        Some(saved) => builder.with_position(saved.pos).with_inner_size(saved.size),
+        None => builder.with_inner_size(DEFAULT_SIZE),
+    }
+}
+
+/// Windows moved into their own OS windows and their saved positions
+#[derive(Debug)]
+pub struct NativeWindowManager {
+    layouts: MonitorLayouts,
+    store: Option<MonitorLayoutStore>,
+    /// Monitor configuration of the main window
+    monitor: String,
+    /// Moved-out windows and the placement their viewport was opened with
+    moved: HashMap<DockPanel, Option<SavedViewport>>,
+    /// Positions changed since the last save
+    dirty: bool,
+}
+
+impl Default for NativeWindowManager {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl NativeWindowManager {
+    /// Manager on `store`, with its saved positions
+    pub fn new(store: Option<MonitorLayoutStore>) -> Self {
+        let layouts = match store.as_ref().map(|store| store.load()) {
+            Some(Ok(layouts)) => layouts,
+            Some(Err(e)) => {
+                tracing::warn!("Ignoring saved window positions: {:#}", e);
+                MonitorLayouts::default()
+            }
+            None => MonitorLayouts::default(),
+        };
+        Self {
+            layouts,
+            store,
+            monitor: "unknown".to_string(),
+            moved: HashMap::new(),
+            dirty: false,
+        }
+    }
+
+    /// Manager on the default store
+    pub fn load() -> Self {
+        Self::new(MonitorLayoutStore::default_location())
+    }
+
+    pub fn is_moved_out(&self, panel: DockPanel) -> bool {
+        self.moved.contains_key(&panel)
+    }
+
+    /// Show `panel` in its own OS window from the next frame on
+    pub fn move_out(&mut self, panel: DockPanel) {
+        let saved = self.layouts.get(&self.monitor, panel);
+        self.moved.entry(panel).or_insert(saved);
+    }
+
+    /// Put `panel` back into the main window
+    pub fn return_to_main(&mut self, panel: DockPanel) {
+        if self.moved.remove(&panel).is_some() {
+            self.save_positions();
+        }
+    }
+
+    /// Put every moved-out window back into the main window
+    pub fn return_all(&mut self) {
+        self.moved.clear();
+        self.save_positions();
+    }
+
+    /// Follow the monitor configuration of the main window
+    ///
+    /// Call once per frame with the main window's context. Moved-out windows
+    /// jump to the positions saved for a new configuration.
+    pub fn update_monitor(&mut self, ctx: &Context) {
+        let monitor = ctx.input(|i| monitor_key(i.viewport()));
+        if monitor == self.monitor {
+            return;
+        }
+        tracing::info!(
+            "Monitor configuration changed from {} to {}",
+            self.monitor,
+            monitor
+        );
+        self.save_positions();
+        self.monitor = monitor;
+        for (panel, placement) in &mut self.moved {
+            if let Some(saved) = self.layouts.get(&self.monitor, *panel) {
+                *placement = Some(saved);
+            }
+        }
+    }
+
+    /// Render `panel` in its own OS window if it was moved out
+    ///
+    /// `add_contents` shows the window on the given context, pinned to the
+    /// rect when there is one. Returns None without calling it when the panel
+    /// belongs in the main window.
+    pub fn show<R>(
+        &mut self,
+        ctx: &Context,
+        panel: DockPanel,
+        mut add_contents: impl FnMut(&Context, Option<Rect>) -> R,
+    ) -> Option<R> {
+        let placement = *self.moved.get(&panel)?;
+        let mut close_requested = false;
+        let mut current = None;
+        let result = ctx.show_viewport_immediate(
+            ViewportId::from_hash_of(("awsdash_native_window", panel)),
+            viewport_builder(panel, placement),
+            |ctx, class| {
+                if class == ViewportClass::Embedded {
+                    // No multi-viewport support: the window floats in the main window
+                    return add_contents(ctx, None);
+                }
+                ctx.input(|i| {
+                    let info = i.viewport();
+                    close_requested = info.close_requested();
+                    current = info.outer_rect.zip(info.inner_rect);
+                });
+                add_contents(ctx, Some(ctx.screen_rect()))
+            },
+        );
+
+        if let Some((outer, inner)) = current {
+            let saved = SavedViewport {
+                panel,
+                pos: [outer.min.x, outer.min.y],
+                size: [inner.width(), inner.height()],
+            };
+            self.dirty |= self.layouts.set(&self.monitor, saved);
+        }
+        if close_requested {
+            self.return_to_main(panel);
+        }
+        Some(result)
+    }
+
+    /// Write positions that changed since the last save
+    pub fn save_positions(&mut self) {
+        if !self.dirty {
+            return;
+        }
+        if let Some(store) = &self.store {
+            if let Err(e) = store.save(&self.layouts) {
+                tracing::warn!("Failed to save window positions: {:#}", e);
+                return;
+            }
+        }
+        self.dirty = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn saved(panel: DockPanel, x: f32) -> SavedViewport {
+        SavedViewport {
+            panel,
+            pos: [x, 40.0],
+            size: [900.0, 600.0],
+        }
+    }
+
+    #[test]
+    fn test_positions_are_kept_per_monitor_configuration() {
+        let mut layouts = MonitorLayouts::default();
+        assert!(layouts.set("1920x1080@1", saved(DockPanel::Explorer, 100.0)));
+        assert!(layouts.set("2560x1440@2", saved(DockPanel::Explorer, 2000.0)));
+        assert!(!layouts.set("2560x1440@2", saved(DockPanel::Explorer, 2000.0)));
+        assert!(layouts.set("2560x1440@2", saved(DockPanel::Logs, 2100.0)));
+
+        assert_eq!(
+            layouts.get("1920x1080@1", DockPanel::Explorer),
+            Some(saved(DockPanel::Explorer, 100.0))
+        );
+        assert_eq!(
+            layouts.get("2560x1440@2", DockPanel::Explorer),
+            Some(saved(DockPanel::Explorer, 2000.0))
+        );
+        assert_eq!(layouts.get("1920x1080@1", DockPanel::Logs), None);
+    }
+
+    #[test]
+    fn test_positions_round_trip_through_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = MonitorLayoutStore::new(dir.path().join("viewports.toml"));
+        let mut layouts = MonitorLayouts::default();
+        layouts.set("2560x1440@2", saved(DockPanel::Agents, 1800.0));
+        layouts.set("2560x1440@2", saved(DockPanel::CloudTrail, 300.0));
+        store.save(&layouts).unwrap();
+
+        assert_eq!(store.load().unwrap(), layouts);
+    }
+
+    #[test]
+    fn test_moved_out_window_opens_at_saved_position() {
+        let mut manager = NativeWindowManager::new(None);
+        manager
+            .layouts
+            .set("unknown", saved(DockPanel::Logs, 1500.0));
+
+        manager.move_out(DockPanel::Logs);
+        manager.move_out(DockPanel::Agents);
+        assert!(manager.is_moved_out(DockPanel::Logs));
+        assert_eq!(
+            manager.moved.get(&DockPanel::Logs),
+            Some(&Some(saved(DockPanel::Logs, 1500.0)))
+        );
+        assert_eq!(manager.moved.get(&DockPanel::Agents), Some(&None));
+
+        manager.return_to_main(DockPanel::Logs);
+        assert!(!manager.is_moved_out(DockPanel::Logs));
+        manager.return_all();
+        assert!(!manager.is_moved_out(DockPanel::Agents));
+    }
+
+    #[test]
+    fn test_command_ids_map_to_windows() {
+        for command in palette_commands() {
+            if command.id != RETURN_ALL {
+                assert!(command_panel(command.id).is_some(), "{}", command.id);
+            }
+        }
+        assert_eq!(command_panel(MOVE_LOGS), Some(DockPanel::Logs));
+        assert_eq!(command_panel(RETURN_ALL), None);
+    }
+}
@@ -2,7 +2,10 @@
 //!
 //! This module manages the global navigation state for the keyboard navigation system,
 //! including mode tracking, key sequence processing, and command count parsing.
+//! Key sequences are resolved through a [`KeyMappingRegistry`], so rebinding a
+//! key in the keyboard shortcuts window changes what it does here.
 
+use super::key_mapping::{KeyBindingMap, KeyMappingRegistry};
 use super::keyboard_navigation::{KeyEventResult, NavigationCommand, NavigationMode};
 use eframe::egui;
 use std::collections::VecDeque;
@@ -35,6 +38,8 @@ pub struct NavigationState {
     active_window_id: Option<String>,
     /// Whether navigation is globally enabled
     navigation_enabled: bool,
+    /// Key bindings sequences are resolved against
+    key_mappings: KeyMappingRegistry,
 }
 
 /// Represents a single key press with timing information
@@ -65,9 +70,21 @@ impl NavigationState {
             escape_next_key: false,
             active_window_id: None,
             navigation_enabled: true,
+            key_mappings: KeyMappingRegistry::new(),
         }
     }
 
+    /// Replace the key bindings, e.g. after they were edited or loaded
+    pub fn set_key_bindings(&mut self, bindings: KeyBindingMap) {
+        self.key_mappings.update_bindings(bindings);
+        self.clear_key_sequence();
+    }
+
+    /// Key bindings currently in effect
+    pub fn key_bindings(&self) -> &KeyBindingMap {
+        self.key_mappings.bindings()
+    }
+
     /// Get the current navigation mode
     pub fn current_mode(&self) -> NavigationMode {
         self.current_mode
@@ -230,21 +247,7 @@ impl NavigationState {
             return KeyEventResult::Handled;
         }
 
-        // Handle mode switching keys
-        match key {
-            egui::Key::I if modifiers.is_none() => {
-                return KeyEventResult::ModeChanged(NavigationMode::Insert);
-            }
-            egui::Key::V if modifiers.is_none() => {
-                return KeyEventResult::ModeChanged(NavigationMode::Visual);
-            }
-            egui::Key::Colon if modifiers.is_none() => {
-                return KeyEventResult::ModeChanged(NavigationMode::Command);
-            }
-            _ => {}
-        }
-
-        // Add key to sequence and try to parse
+        // Add key to sequence and try to parse (mode switching keys included)
         if let Some(ch) = self.key_to_char(key, modifiers) {
             self.add_to_key_sequence(&ch.to_string());
         }
@@ -258,10 +261,8 @@ impl NavigationState {
         key: egui::Key,
         _modifiers: egui::Modifiers,
     ) -> KeyEventResult {
-        match key {
-            egui::Key::Escape => KeyEventResult::ModeChanged(NavigationMode::Normal),
-            _ => KeyEventResult::PassThrough,
-        }
+        self.resolve_named_key(key)
+            .unwrap_or(KeyEventResult::PassThrough)
     }
 
     /// Handle keys in hint mode
@@ -270,17 +271,14 @@ impl NavigationState {
         key: egui::Key,
         _modifiers: egui::Modifiers,
     ) -> KeyEventResult {
-        match key {
-            egui::Key::Escape => KeyEventResult::ModeChanged(NavigationMode::Normal),
-            egui::Key::Enter => KeyEventResult::Command(NavigationCommand::ActivateElement),
-            _ => {
-                // Filter hints based on key input
-                if let Some(ch) = self.key_to_char(key, egui::Modifiers::NONE) {
-                    self.add_to_key_sequence(&ch.to_string());
-                }
-                KeyEventResult::Handled
-            }
+        if let Some(result) = self.resolve_named_key(key) {
+            return result;
+        }
+        // Filter hints based on key input
+        if let Some(ch) = self.key_to_char(key, egui::Modifiers::NONE) {
+            self.add_to_key_sequence(&ch.to_string());
         }
+        KeyEventResult::Handled
     }
 
     /// Handle keys in visual mode
@@ -289,10 +287,8 @@ impl NavigationState {
         key: egui::Key,
         _modifiers: egui::Modifiers,
     ) -> KeyEventResult {
-        match key {
-            egui::Key::Escape => KeyEventResult::ModeChanged(NavigationMode::Normal),
-            _ => KeyEventResult::PassThrough,
-        }
+        self.resolve_named_key(key)
+            .unwrap_or(KeyEventResult::PassThrough)
     }
 
     /// Handle keys in command mode
@@ -301,98 +297,85 @@ impl NavigationState {
         key: egui::Key,
         _modifiers: egui::Modifiers,
     ) -> KeyEventResult {
-        match key {
-            egui::Key::Escape => KeyEventResult::ModeChanged(NavigationMode::Normal),
-            egui::Key::Enter => {
+        match self.resolve_named_key(key) {
+            Some(result @ KeyEventResult::Command(_)) => {
                 // Execute command
-                let result = KeyEventResult::Command(NavigationCommand::OpenCommandPalette);
                 self.clear_key_sequence();
                 result
             }
-            _ => KeyEventResult::PassThrough,
+            Some(result) => result,
+            None => KeyEventResult::PassThrough,
         }
     }
 
+    /// Resolve a named key (e.g. "Escape") against the current mode's bindings
+    fn resolve_named_key(&self, key: egui::Key) -> Option<KeyEventResult> {
+        self.key_mappings.resolve(
+            key.name(),
+            self.current_mode,
+            self.active_window_id.as_deref(),
+        )
+    }
+
     /// Try to parse the current key sequence as a command
     fn try_parse_command(&mut self) -> KeyEventResult {
         let sequence = self.key_sequence.clone();
+        let window_id = self.active_window_id.clone();
 
-        // Parse number prefix for command count
-        if let Some(command_with_count) = self.extract_command_count(&sequence) {
-            if let Some(command) = self.parse_navigation_command(&command_with_count) {
-                self.clear_key_sequence();
-                return KeyEventResult::Command(command);
-            }
+        if let Some(result) =
+            self.key_mappings
+                .resolve(&sequence, NavigationMode::Normal, window_id.as_deref())
+        {
+            self.clear_key_sequence();
+            return result;
         }
 
-        // Check for single-key commands
-        match sequence.as_str() {
-            "j" => {
-                self.clear_key_sequence();
-                let count = self.take_command_count().unwrap_or(1) as i32;
-                KeyEventResult::Command(NavigationCommand::ScrollVertical(count))
-            }
-            "k" => {
-                self.clear_key_sequence();
-                let count = self.take_command_count().unwrap_or(1) as i32;
-                KeyEventResult::Command(NavigationCommand::ScrollVertical(-count))
-            }
-            "h" => {
-                self.clear_key_sequence();
-                let count = self.take_command_count().unwrap_or(1) as i32;
-                KeyEventResult::Command(NavigationCommand::ScrollHorizontal(-count))
-            }
-            "l" => {
-                self.clear_key_sequence();
-                let count = self.take_command_count().unwrap_or(1) as i32;
-                KeyEventResult::Command(NavigationCommand::ScrollHorizontal(count))
-            }
-            "gg" => {
-                self.clear_key_sequence();
-                KeyEventResult::Command(NavigationCommand::MoveToTop)
-            }
-            "G" => {
-                self.clear_key_sequence();
-                KeyEventResult::Command(NavigationCommand::MoveToBottom)
-            }
-            "x" => {
-                self.clear_key_sequence();
-                KeyEventResult::Command(NavigationCommand::CloseWindow)
-            }
-            "gt" => {
-                self.clear_key_sequence();
-                KeyEventResult::Command(NavigationCommand::NextWindow)
-            }
-            "gT" => {
-                self.clear_key_sequence();
-                KeyEventResult::Command(NavigationCommand::PreviousWindow)
-            }
-            "f" => {
-                self.clear_key_sequence();
-                KeyEventResult::Command(NavigationCommand::EnterHintMode(
-                    super::keyboard_navigation::ElementAction::Smart,
-                ))
-            }
-            // Single digit window selection
-            s if s.len() == 1 && s.chars().next().unwrap().is_ascii_digit() => {
-                if let Ok(index) = s.parse::<u8>() {
-                    if (1..=9).contains(&index) {
+        // Wait for the rest of a multi-key binding such as "gg"
+        if self
+            .key_mappings
+            .is_sequence_prefix(&sequence, NavigationMode::Normal)
+        {
+            return KeyEventResult::Handled;
+        }
+
+        // Count prefix such as "5j" when digits are not bound themselves
+        if self.key_mappings.settings().enable_count_prefixes {
+            match self.extract_command_count(&sequence) {
+                // Only digits so far
+                None => return KeyEventResult::Handled,
+                Some(command) if command != sequence => {
+                    let repeatable = self
+                        .key_mappings
+                        .lookup_mapping(&command, NavigationMode::Normal, window_id.as_deref())
+                        .is_some_and(|mapping| mapping.repeatable);
+                    let resolved = self.key_mappings.lookup_command(
+                        &command,
+                        NavigationMode::Normal,
+                        window_id.as_deref(),
+                    );
+                    if let Some(resolved) = resolved {
+                        let count = self.take_command_count().unwrap_or(1) as i32;
                         self.clear_key_sequence();
-                        return KeyEventResult::Command(NavigationCommand::WindowByIndex(index));
+                        return KeyEventResult::Command(if repeatable {
+                            repeat_command(resolved, count)
+                        } else {
+                            resolved
+                        });
+                    }
+                    if self
+                        .key_mappings
+                        .is_sequence_prefix(&command, NavigationMode::Normal)
+                    {
+                        return KeyEventResult::Handled;
                     }
                 }
-                KeyEventResult::Handled
-            }
-            _ => {
-                // Continue building sequence or timeout
-                if sequence.len() > 3 || self.is_sequence_expired() {
-                    self.clear_key_sequence();
-                    KeyEventResult::Handled
-                } else {
-                    KeyEventResult::Handled
-                }
+                Some(_) => {}
             }
         }
+
+        // No binding starts with this sequence
+        self.clear_key_sequence();
+        KeyEventResult::Handled
     }
 
     /// Extract command count from key sequence
@@ -425,19 +408,6 @@ impl NavigationState {
         None
     }
 
-    /// Parse a navigation command from the command string
-    fn parse_navigation_command(&self, command: &str) -> Option<NavigationCommand> {
-        match command {
-            "d" => Some(NavigationCommand::ScrollVertical(
-                self.command_count.unwrap_or(1) as i32 * 10,
-            )),
-            "u" => Some(NavigationCommand::ScrollVertical(
-                -(self.command_count.unwrap_or(1) as i32 * 10),
-            )),
-            _ => None,
-        }
-    }
-
     /// Add a key press to the history
     fn add_key_to_history(&mut self, key_press: KeyPress) {
         self.key_history.push_back(key_press);
@@ -547,6 +517,19 @@ impl NavigationState {
     }
 }
 
+/// Scale a scrolling command by a count prefix ("5j" scrolls 5 lines)
+fn repeat_command(command: NavigationCommand, count: i32) -> NavigationCommand {
+    match command {
+        NavigationCommand::ScrollVertical(amount) => {
+            NavigationCommand::ScrollVertical(amount * count)
+        }
+        NavigationCommand::ScrollHorizontal(amount) => {
+            NavigationCommand::ScrollHorizontal(amount * count)
+        }
+        other => other,
+    }
+}
+
 impl Default for NavigationState {
     fn default() -> Self {
         Self::new()
@@ -720,4 +703,102 @@ mod tests {
         state.sequence_start_time = Some(Instant::now() - Duration::from_secs(5));
         assert!(state.is_sequence_expired());
     }
+
+    fn press(state: &mut NavigationState, key: egui::Key) -> KeyEventResult {
+        let event = egui::Event::Key {
+            key,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers: egui::Modifiers::NONE,
+        };
+        state.process_key_event(&event, &egui::Context::default())
+    }
+
+    #[test]
+    fn test_default_bindings_resolve() {
+        let mut state = NavigationState::new();
+
+        assert_eq!(
+            press(&mut state, egui::Key::J),
+            KeyEventResult::Command(NavigationCommand::ScrollVertical(1))
+        );
+        assert_eq!(press(&mut state, egui::Key::G), KeyEventResult::Handled);
+        assert_eq!(state.current_key_sequence(), "g");
+        assert_eq!(
+            press(&mut state, egui::Key::G),
+            KeyEventResult::Command(NavigationCommand::MoveToTop)
+        );
+        assert_eq!(
+            press(&mut state, egui::Key::I),
+            KeyEventResult::ModeChanged(NavigationMode::Insert)
+        );
+
+        // Unbound keys reset the sequence
+        assert_eq!(press(&mut state, egui::Key::Q), KeyEventResult::Handled);
+        assert_eq!(state.current_key_sequence(), "");
+    }
+
+    #[test]
+    fn test_rebound_keys_take_effect() {
+        let mut bindings = KeyBindingMap::create_default_bindings();
+        for mapping in bindings.bindings.get_mut("normal").unwrap() {
+            if mapping.command == "scroll_down" {
+                mapping.sequence = "n".to_string();
+            }
+        }
+        let mut state = NavigationState::new();
+        state.set_key_bindings(bindings);
+
+        assert_eq!(
+            press(&mut state, egui::Key::N),
+            KeyEventResult::Command(NavigationCommand::ScrollVertical(1))
+        );
+        assert_eq!(press(&mut state, egui::Key::J), KeyEventResult::Handled);
+        assert_eq!(state.current_key_sequence(), "");
+    }
+
+    #[test]
+    fn test_count_prefix_repeats_binding() {
+        let mut bindings = KeyBindingMap::create_default_bindings();
+        bindings
+            .bindings
+            .get_mut("normal")
+            .unwrap()
+            .retain(|mapping| !mapping.command.starts_with("window_"));
+        let mut state = NavigationState::new();
+        state.set_key_bindings(bindings);
+
+        assert_eq!(press(&mut state, egui::Key::Num5), KeyEventResult::Handled);
+        assert_eq!(
+            press(&mut state, egui::Key::J),
+            KeyEventResult::Command(NavigationCommand::ScrollVertical(5))
+        );
+        assert_eq!(state.current_key_sequence(), "");
+
+        // gg is not repeatable, so it runs once
+        press(&mut state, egui::Key::Num3);
+        press(&mut state, egui::Key::G);
+        assert_eq!(
+            press(&mut state, egui::Key::G),
+            KeyEventResult::Command(NavigationCommand::MoveToTop)
+        );
+    }
+
+    #[test]
+    fn test_named_keys_in_other_modes() {
+        let mut state = NavigationState::new();
+        state.set_mode(NavigationMode::Hint);
+        assert_eq!(
+            press(&mut state, egui::Key::Enter),
+            KeyEventResult::Command(NavigationCommand::ActivateElement)
+        );
+        assert_eq!(
+            press(&mut state, egui::Key::Escape),
+            KeyEventResult::ModeChanged(NavigationMode::Normal)
+        );
+
+        state.set_mode(NavigationMode::Insert);
+        assert_eq!(press(&mut state, egui::Key::J), KeyEventResult::PassThrough);
+    }
 }
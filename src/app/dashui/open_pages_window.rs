@@ -0,0 +1,240 @@
+//! Open Pages Window
+//!
+//! Lists the webview windows spawned by the app (page previews, the Pages
+//! Manager, AWS Console windows) from the
+//! [`window_registry`](crate::app::webview::window_registry) with their
+//! process and heartbeat status. Focus, Reload and Close are delivered with
+//! the window's next heartbeat; Terminate kills the process, which also works
+//! for windows that stopped responding.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::command_registry::{CommandCategory, PaletteCommand};
+use super::window_focus::FocusableWindow;
+use crate::app::webview::window_registry::{self, WebviewControl, WebviewInfo, WebviewStatus};
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::time::Duration;
+
+/// How often the list is refreshed while the window is open
+const REFRESH_INTERVAL: Duration = Duration::from_secs(1);
+
+pub const OPEN_PAGES_MANAGER: &str = "pages.open_manager";
+pub const SHOW_OPEN_PAGES: &str = "pages.show_open";
+pub const RELOAD_ALL_PAGES: &str = "pages.reload_all";
+
+/// Commands for page windows offered in the command palette
+pub fn palette_commands() -> Vec<PaletteCommand> {
+    vec![
+        PaletteCommand {
+            id: OPEN_PAGES_MANAGER,
+            label: "Open Pages Manager",
+            description: "Browse and open the pages built by agents",
+            category: CommandCategory::Pages,
+            keywords: &["dashboard", "webview"],
+            is_available: |_| true,
+        },
+        PaletteCommand {
+            id: SHOW_OPEN_PAGES,
+            label: "Show open pages",
+            description: "List page windows with their process and heartbeat status",
+            category: CommandCategory::Pages,
+            keywords: &["windows", "webview", "processes"],
+            is_available: |_| true,
+        },
+        PaletteCommand {
+            id: RELOAD_ALL_PAGES,
+            label: "Reload all pages",
+            description: "Reload every responding page window",
+            category: CommandCategory::Pages,
+            keywords: &["refresh", "webview"],
+            is_available: |context| context.page_windows > 0,
+        },
+    ]
+}
+
+fn status_color(ui: &Ui, status: WebviewStatus) -> Color32 {
+    match status {
+        WebviewStatus::Running => Color32::from_rgb(80, 180, 80),
+        WebviewStatus::Starting => ui.visuals().weak_text_color(),
+        WebviewStatus::Unresponsive => ui.visuals().warn_fg_color,
+    }
+}
+
+#[derive(Default)]
+pub struct OpenPagesWindow {
+    pub open: bool,
+    /// Outcome of the last action
+    message: Option<(String, bool)>,
+}
+
+impl OpenPagesWindow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    /// Send Reload to every responding window and show the outcome
+    pub fn reload_all(&mut self) {
+        let mut reloaded = 0;
+        let mut errors = Vec::new();
+        for info in window_registry::list_windows() {
+            if info.status == WebviewStatus::Unresponsive {
+                continue;
+            }
+            match window_registry::send_control(&info.id, WebviewControl::Reload) {
+                Ok(()) => reloaded += 1,
+                Err(e) => errors.push(format!("{}: {}", info.title, e)),
+            }
+        }
+        self.message = Some(if errors.is_empty() {
+            (format!("Sent Reload to {} windows", reloaded), false)
+        } else {
+            (errors.join("; "), true)
+        });
+        self.open = true;
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        // Heartbeats arrive from other processes, so poll while open
+        ctx.request_repaint_after(REFRESH_INTERVAL);
+
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new("Open Pages")
+            .open(&mut is_open)
+            .default_size([760.0, 320.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.render_content(ui);
+        });
+
+        self.open = is_open;
+    }
+
+    fn render_content(&mut self, ui: &mut Ui) {
+        let windows = window_registry::list_windows();
+
+        ui.label(format!("{} webview windows", windows.len()));
+        if let Some((message, is_error)) = &self.message {
+            let color = if *is_error {
+                ui.visuals().error_fg_color
+            } else {
+                Color32::from_rgb(80, 180, 80)
+            };
+            ui.label(RichText::new(message).color(color));
+        }
+        ui.separator();
+
+        if windows.is_empty() {
+            ui.label(RichText::new("No page windows are open.").weak());
+            return;
+        }
+
+        egui::ScrollArea::vertical()
+            .id_salt("open_pages_scroll")
+            .show(ui, |ui| {
+                egui::Grid::new("open_pages_grid")
+                    .num_columns(6)
+                    .striped(true)
+                    .spacing([12.0, 6.0])
+                    .show(ui, |ui| {
+                        ui.strong("Title");
+                        ui.strong("Page");
+                        ui.strong("PID");
+                        ui.strong("Status");
+                        ui.strong("Last heartbeat");
+                        ui.strong("");
+                        ui.end_row();
+
+                        for info in &windows {
+                            self.render_row(ui, info);
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    fn render_row(&mut self, ui: &mut Ui, info: &WebviewInfo) {
+        ui.label(&info.title);
+        ui.label(info.page_name.as_deref().unwrap_or("-"));
+        ui.label(info.pid.to_string());
+        ui.label(RichText::new(info.status.label()).color(status_color(ui, info.status)));
+        match info.last_heartbeat {
+            Some(at) => {
+                let ago = (chrono::Utc::now() - at).num_seconds().max(0);
+                ui.label(format!("{}s ago", ago));
+            }
+            None => {
+                ui.label(RichText::new("never").weak());
+            }
+        }
+
+        ui.horizontal(|ui| {
+            let responsive = info.status != WebviewStatus::Unresponsive;
+            for (label, control) in [
+                ("Focus", WebviewControl::Focus),
+                ("Reload", WebviewControl::Reload),
+                ("Close", WebviewControl::Close),
+            ] {
+                if ui
+                    .add_enabled(responsive, egui::Button::new(label).small())
+                    .clicked()
+                {
+                    self.message = Some(match window_registry::send_control(&info.id, control) {
+                        Ok(()) => (format!("{}: sent {}", info.title, label), false),
+                        Err(e) => (e.to_string(), true),
+                    });
+                }
+            }
+            if ui
+                .small_button("Terminate")
+                .on_hover_text("Kill the webview process")
+                .clicked()
+            {
+                self.message = Some(match window_registry::terminate_window(&info.id) {
+                    Ok(()) => (format!("Terminated {}", info.title), false),
+                    Err(e) => (format!("Failed to terminate {}: {}", info.title, e), true),
+                });
+            }
+        });
+    }
+}
+
+impl FocusableWindow for OpenPagesWindow {
+    type ShowParams = crate::app::dashui::window_focus::SimpleShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "open_pages_window"
+    }
+
+    fn window_title(&self) -> String {
+        "Open Pages".to_string()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        _params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        OpenPagesWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
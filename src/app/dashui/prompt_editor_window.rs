@@ -0,0 +1,252 @@
+//! Agent Prompt Editor Window
+//!
+//! Edits the task manager and task worker prompt overrides stored in the
+//! config directory. A preview shows the prompt with its `{{VARIABLES}}`
+//! filled in as an agent would receive it. Saved prompts apply to agents
+//! created afterwards.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+
+use crate::app::agent_framework::prompts::overrides::{interpolate, unknown_variables};
+use crate::app::agent_framework::prompts::{PromptKind, PromptOverrides};
+
+/// State for the prompt editor window
+pub struct PromptEditorWindow {
+    open: bool,
+    /// Prompt being edited
+    kind: PromptKind,
+    /// Editor contents
+    text: String,
+    /// Contents as last loaded or saved, to detect unsaved changes
+    saved_text: String,
+    /// Whether an override file exists for `kind`
+    overridden: bool,
+    /// Show the interpolated prompt instead of the editor
+    show_preview: bool,
+    /// Outcome of the last action
+    message: Option<(String, bool)>,
+    store: Option<PromptOverrides>,
+}
+
+impl Default for PromptEditorWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PromptEditorWindow {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            kind: PromptKind::TaskManager,
+            text: String::new(),
+            saved_text: String::new(),
+            overridden: false,
+            show_preview: false,
+            message: None,
+            store: PromptOverrides::default_location(),
+        }
+    }
+
+    /// Open the window and load the current prompt
+    pub fn open(&mut self) {
+        self.open = true;
+        self.message = None;
+        self.load(self.kind);
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Load the override for `kind`, or the built-in prompt when there is none
+    fn load(&mut self, kind: PromptKind) {
+        self.kind = kind;
+        let loaded = match &self.store {
+            Some(store) => store.load(kind),
+            None => Ok(None),
+        };
+        let text = match loaded {
+            Ok(text) => text,
+            Err(e) => {
+                self.message = Some((format!("{:#}", e), true));
+                None
+            }
+        };
+        self.overridden = text.is_some();
+        self.text = text.unwrap_or_else(|| kind.builtin().to_string());
+        self.saved_text = self.text.clone();
+    }
+
+    fn is_modified(&self) -> bool {
+        self.text != self.saved_text
+    }
+
+    fn save(&mut self) {
+        let Some(store) = &self.store else {
+            self.message = Some(("Config directory not available".to_string(), true));
+            return;
+        };
+        self.message = Some(match store.save(self.kind, &self.text) {
+            Ok(()) => {
+                self.overridden = !self.text.trim().is_empty();
+                self.saved_text = self.text.clone();
+                (
+                    format!("Saved to {}", store.path(self.kind).display()),
+                    false,
+                )
+            }
+            Err(e) => (format!("{:#}", e), true),
+        });
+    }
+
+    fn reset_to_builtin(&mut self) {
+        let Some(store) = &self.store else {
+            return;
+        };
+        self.message = Some(match store.reset(self.kind) {
+            Ok(()) => {
+                log::info!("Reset {} prompt to built-in", self.kind.display_name());
+                self.load(self.kind);
+                ("Using the built-in prompt".to_string(), false)
+            }
+            Err(e) => (format!("{:#}", e), true),
+        });
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Agent Prompts")
+            .open(&mut is_open)
+            .default_size([700.0, 550.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                self.render_content(ui);
+            });
+        self.open = is_open;
+    }
+
+    fn render_content(&mut self, ui: &mut Ui) {
+        // Toolbar
+        ui.horizontal(|ui| {
+            let mut selected = self.kind;
+            egui::ComboBox::from_id_salt("prompt_kind_selector")
+                .selected_text(selected.display_name())
+                .show_ui(ui, |ui| {
+                    for kind in PromptKind::all() {
+                        ui.selectable_value(&mut selected, *kind, kind.display_name());
+                    }
+                });
+            if selected != self.kind {
+                if self.is_modified() {
+                    log::info!(
+                        "Discarding unsaved {} prompt changes",
+                        self.kind.display_name()
+                    );
+                }
+                self.message = None;
+                self.load(selected);
+            }
+
+            ui.toggle_value(&mut self.show_preview, "Preview");
+
+            let status = if self.overridden {
+                RichText::new("Custom").color(Color32::from_rgb(220, 160, 60))
+            } else {
+                RichText::new("Built-in").weak()
+            };
+            ui.label(status);
+            if self.is_modified() {
+                ui.label(RichText::new("(unsaved)").italics());
+            }
+        });
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(self.is_modified(), egui::Button::new("Save"))
+                .clicked()
+            {
+                self.save();
+            }
+            if ui
+                .add_enabled(self.is_modified(), egui::Button::new("Revert"))
+                .clicked()
+            {
+                self.text = self.saved_text.clone();
+            }
+            if ui
+                .add_enabled(
+                    self.overridden || self.is_modified(),
+                    egui::Button::new("Reset to Built-in"),
+                )
+                .on_hover_text("Delete the custom prompt file")
+                .clicked()
+            {
+                self.reset_to_builtin();
+            }
+            if let Some((message, is_error)) = &self.message {
+                let color = if *is_error {
+                    Color32::from_rgb(220, 80, 80)
+                } else {
+                    Color32::GRAY
+                };
+                ui.label(RichText::new(message).small().color(color));
+            }
+        });
+
+        let variables = self.kind.variables();
+        let names: Vec<String> = variables
+            .iter()
+            .map(|(name, _)| format!("{{{{{}}}}}", name))
+            .collect();
+        ui.label(
+            RichText::new(format!(
+                "Variables: {}. Changes apply to agents created after saving.",
+                names.join(", ")
+            ))
+            .small()
+            .weak(),
+        );
+        let unknown = unknown_variables(&self.text, &variables);
+        if !unknown.is_empty() {
+            ui.label(
+                RichText::new(format!(
+                    "Unknown variables are sent unchanged: {}",
+                    unknown.join(", ")
+                ))
+                .small()
+                .color(Color32::from_rgb(220, 160, 60)),
+            );
+        }
+        ui.separator();
+
+        ScrollArea::vertical()
+            .id_salt("prompt_editor_scroll")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if self.show_preview {
+                    let mut preview = interpolate(&self.text, &variables);
+                    ui.add(
+                        egui::TextEdit::multiline(&mut preview)
+                            .code_editor()
+                            .interactive(false)
+                            .desired_width(f32::INFINITY),
+                    );
+                } else {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.text)
+                            .code_editor()
+                            .desired_rows(25)
+                            .desired_width(f32::INFINITY),
+                    );
+                }
+            });
+    }
+}
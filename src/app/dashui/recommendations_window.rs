@@ -0,0 +1,658 @@
+//! Recommendations Window
+//!
+//! Consolidated report of Compute Optimizer rightsizing findings and
+//! Trusted Advisor flagged resources across the accounts and regions selected
+//! in the Explorer. The results also feed the recommendation badges on
+//! Explorer resource nodes, and each row can be shown in the Explorer.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::recommendations::{
+    total_monthly_savings, Recommendation, RecommendationSource, RecommendationsClient,
+    UnavailableSource,
+};
+use crate::app::resource_explorer::cache::SharedResourceCache;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use crate::app::resource_explorer::recommendation_badges::RecommendationIndex;
+use crate::app::resource_explorer::resource_finder::{search_cache, FindQuery, RevealRequest};
+use eframe::egui;
+use egui::{Context, RichText, Ui};
+use futures::StreamExt;
+use std::collections::HashSet;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Account and region pairs queried at the same time
+const MAX_CONCURRENT_REQUESTS: usize = 4;
+
+/// One unit of work for the background thread
+enum RecommendationJob {
+    ComputeOptimizer { account_id: String, region: String },
+    TrustedAdvisor { account_id: String },
+}
+
+/// Results from the background thread
+enum RecommendationEvent {
+    Loaded {
+        source: RecommendationSource,
+        account_id: String,
+        region: Option<String>,
+        result: Result<Vec<Recommendation>, String>,
+    },
+    Finished,
+}
+
+pub struct RecommendationsWindow {
+    pub open: bool,
+
+    // Scope
+    accounts: Vec<String>,
+    regions: Vec<String>,
+
+    // State
+    recommendations: Vec<Recommendation>,
+    unavailable: Vec<UnavailableSource>,
+    loading: bool,
+    loaded_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Built when loading finishes; feeds the Explorer badges
+    index: Option<Arc<RecommendationIndex>>,
+    show_badges: bool,
+
+    // Filters
+    sources: HashSet<RecommendationSource>,
+    text_filter: String,
+    /// Resource picked with "Show", taken by the app
+    pending_reveal: Option<RevealRequest>,
+
+    // Services
+    client: Option<Arc<RecommendationsClient>>,
+    cache: Option<Arc<SharedResourceCache>>,
+
+    // Channel for results from the background thread.
+    // Replaced on refresh so results from an earlier load are dropped.
+    sender: mpsc::Sender<RecommendationEvent>,
+    receiver: mpsc::Receiver<RecommendationEvent>,
+}
+
+impl Default for RecommendationsWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RecommendationsWindow {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            accounts: Vec::new(),
+            regions: Vec::new(),
+            recommendations: Vec::new(),
+            unavailable: Vec::new(),
+            loading: false,
+            loaded_at: None,
+            index: None,
+            show_badges: true,
+            sources: [
+                RecommendationSource::ComputeOptimizer,
+                RecommendationSource::TrustedAdvisor,
+            ]
+            .into_iter()
+            .collect(),
+            text_filter: String::new(),
+            pending_reveal: None,
+            client: None,
+            cache: None,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on the given accounts and regions and load their recommendations
+    pub fn open_for_scope(
+        &mut self,
+        credential_coordinator: Arc<CredentialCoordinator>,
+        accounts: Vec<String>,
+        regions: Vec<String>,
+        cache: Arc<SharedResourceCache>,
+    ) {
+        self.client = Some(Arc::new(RecommendationsClient::new(credential_coordinator)));
+        self.cache = Some(cache);
+        self.accounts = accounts;
+        self.regions = regions;
+        self.open = true;
+        self.refresh();
+    }
+
+    /// Recommendations for the Explorer badges, unless hidden
+    pub fn badge_index(&self) -> Option<Arc<RecommendationIndex>> {
+        self.index.clone().filter(|_| self.show_badges)
+    }
+
+    /// Take the resource picked with "Show" to reveal in the Explorer
+    pub fn take_reveal_request(&mut self) -> Option<RevealRequest> {
+        self.pending_reveal.take()
+    }
+
+    fn refresh(&mut self) {
+        let Some(client) = self.client.clone() else {
+            return;
+        };
+        let (sender, receiver) = mpsc::channel();
+        self.sender = sender;
+        self.receiver = receiver;
+        self.recommendations.clear();
+        self.unavailable.clear();
+        self.loaded_at = None;
+
+        let mut jobs: Vec<RecommendationJob> = self
+            .accounts
+            .iter()
+            .flat_map(|account| {
+                self.regions
+                    .iter()
+                    .map(move |region| RecommendationJob::ComputeOptimizer {
+                        account_id: account.clone(),
+                        region: region.clone(),
+                    })
+            })
+            .collect();
+        jobs.extend(
+            self.accounts
+                .iter()
+                .map(|account| RecommendationJob::TrustedAdvisor {
+                    account_id: account.clone(),
+                }),
+        );
+        if jobs.is_empty() {
+            return;
+        }
+        self.loading = true;
+
+        let sender = self.sender.clone();
+        spawn_with_client(&client, &self.sender, move |client| async move {
+            futures::stream::iter(jobs)
+                .for_each_concurrent(MAX_CONCURRENT_REQUESTS, |job| {
+                    let client = Arc::clone(&client);
+                    let sender = sender.clone();
+                    async move {
+                        let event = match job {
+                            RecommendationJob::ComputeOptimizer { account_id, region } => {
+                                let result = client
+                                    .compute_optimizer_recommendations(&account_id, &region)
+                                    .await;
+                                RecommendationEvent::Loaded {
+                                    source: RecommendationSource::ComputeOptimizer,
+                                    account_id,
+                                    region: Some(region),
+                                    result: result.map_err(|e| format!("{:#}", e)),
+                                }
+                            }
+                            RecommendationJob::TrustedAdvisor { account_id } => {
+                                let result =
+                                    client.trusted_advisor_recommendations(&account_id).await;
+                                RecommendationEvent::Loaded {
+                                    source: RecommendationSource::TrustedAdvisor,
+                                    account_id,
+                                    region: None,
+                                    result: result.map_err(|e| format!("{:#}", e)),
+                                }
+                            }
+                        };
+                        let _ = sender.send(event);
+                    }
+                })
+                .await;
+            RecommendationEvent::Finished
+        });
+    }
+
+    /// Poll for results from the background thread
+    fn poll_events(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                RecommendationEvent::Loaded {
+                    result: Ok(recommendations),
+                    ..
+                } => {
+                    // Trusted Advisor covers every region; keep the ones in scope and global ones
+                    let regions = &self.regions;
+                    self.recommendations
+                        .extend(recommendations.into_iter().filter(|recommendation| {
+                            is_global_region(&recommendation.region)
+                                || regions.contains(&recommendation.region)
+                        }));
+                }
+                RecommendationEvent::Loaded {
+                    source,
+                    account_id,
+                    region,
+                    result: Err(message),
+                } => {
+                    log::warn!(
+                        "{} recommendations unavailable for {} {}: {}",
+                        source.label(),
+                        account_id,
+                        region.as_deref().unwrap_or_default(),
+                        message
+                    );
+                    self.unavailable.push(UnavailableSource {
+                        source,
+                        account_id,
+                        region,
+                        message,
+                    });
+                }
+                RecommendationEvent::Finished => {
+                    self.loading = false;
+                    self.loaded_at = Some(chrono::Utc::now());
+                    self.index = Some(Arc::new(RecommendationIndex::from_recommendations(
+                        &self.recommendations,
+                    )));
+                }
+            }
+        }
+    }
+
+    /// Recommendations to list: most severe first, then by estimated savings
+    fn visible_recommendations(&self) -> Vec<&Recommendation> {
+        let filter = self.text_filter.to_lowercase();
+        let mut recommendations: Vec<&Recommendation> = self
+            .recommendations
+            .iter()
+            .filter(|recommendation| self.sources.contains(&recommendation.source))
+            .filter(|recommendation| {
+                filter.is_empty()
+                    || [
+                        recommendation.resource.as_str(),
+                        recommendation.title.as_str(),
+                        recommendation.account_id.as_str(),
+                        recommendation.region.as_str(),
+                    ]
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&filter))
+            })
+            .collect();
+        recommendations.sort_by(|a, b| {
+            b.finding
+                .severity()
+                .cmp(&a.finding.severity())
+                .then_with(|| {
+                    b.estimated_monthly_savings
+                        .unwrap_or_default()
+                        .total_cmp(&a.estimated_monthly_savings.unwrap_or_default())
+                })
+                .then_with(|| a.account_id.cmp(&b.account_id))
+                .then_with(|| a.resource.cmp(&b.resource))
+        });
+        recommendations
+    }
+
+    /// Where to show a recommendation's resource in the Explorer
+    ///
+    /// Cached resources are focused directly; ARNs of types the Explorer can
+    /// load are added to a pane's scope like Find Resource does.
+    fn reveal_request(&self, recommendation: &Recommendation) -> Option<RevealRequest> {
+        let query = FindQuery::parse(&recommendation.resource)?;
+        if let Some(cache) = &self.cache {
+            if let Some(resource) = search_cache(cache, &query)
+                .into_iter()
+                .find(|resource| resource.account_id == recommendation.account_id)
+            {
+                return Some(RevealRequest {
+                    query: FindQuery::ResourceId(resource.resource_id.clone()),
+                    account_id: resource.account_id.clone(),
+                    region: resource.region.clone(),
+                    resource_type: resource.resource_type.clone(),
+                    selection_key: Some(resource.selection_key()),
+                });
+            }
+        }
+        let FindQuery::Arn(arn) = &query else {
+            return None;
+        };
+        let resource_type = recommendation
+            .resource_type
+            .clone()
+            .or_else(|| arn.resource_type().map(String::from))?;
+        Some(RevealRequest {
+            query: query.clone(),
+            account_id: recommendation.account_id.clone(),
+            region: recommendation.region.clone(),
+            resource_type,
+            selection_key: None,
+        })
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_events();
+        if self.loading {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new("Recommendations")
+            .open(&mut is_open)
+            .default_size([960.0, 500.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.render_toolbar(ui);
+            ui.separator();
+            self.render_recommendations(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn render_toolbar(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.loading, egui::Button::new("Refresh"))
+                .clicked()
+            {
+                self.refresh();
+            }
+            if self.loading {
+                ui.spinner();
+                ui.label("Loading recommendations...");
+            } else if let Some(loaded_at) = self.loaded_at {
+                ui.label(format!(
+                    "{} recommendations, ~{:.2} USD/month estimated savings",
+                    self.recommendations.len(),
+                    total_monthly_savings(&self.recommendations)
+                ));
+                ui.label(
+                    RichText::new(format!(
+                        "{} accounts, {} regions, loaded {}",
+                        self.accounts.len(),
+                        self.regions.len(),
+                        loaded_at.with_timezone(&chrono::Local).format("%H:%M:%S")
+                    ))
+                    .weak(),
+                );
+            }
+        });
+
+        ui.horizontal(|ui| {
+            for source in [
+                RecommendationSource::ComputeOptimizer,
+                RecommendationSource::TrustedAdvisor,
+            ] {
+                let count = self
+                    .recommendations
+                    .iter()
+                    .filter(|recommendation| recommendation.source == source)
+                    .count();
+                let mut shown = self.sources.contains(&source);
+                if ui
+                    .checkbox(&mut shown, format!("{} ({})", source.label(), count))
+                    .changed()
+                {
+                    if shown {
+                        self.sources.insert(source);
+                    } else {
+                        self.sources.remove(&source);
+                    }
+                }
+            }
+            ui.separator();
+            ui.add(
+                egui::TextEdit::singleline(&mut self.text_filter)
+                    .hint_text("Filter resources")
+                    .desired_width(180.0),
+            );
+            ui.separator();
+            ui.checkbox(&mut self.show_badges, "Badges in Explorer")
+                .on_hover_text("Show a recommendation badge on matching Explorer resources");
+        });
+
+        if self.accounts.is_empty() || self.regions.is_empty() {
+            ui.label(
+                RichText::new(
+                    "Select accounts and regions in an Explorer window to load their recommendations",
+                )
+                .color(ui.visuals().warn_fg_color),
+            );
+        }
+        if !self.unavailable.is_empty() {
+            ui.collapsing(
+                format!("{} sources unavailable", self.unavailable.len()),
+                |ui| {
+                    for unavailable in &self.unavailable {
+                        ui.label(
+                            RichText::new(format!(
+                                "{} {} {}: {}",
+                                unavailable.source.label(),
+                                unavailable.account_id,
+                                unavailable.region.as_deref().unwrap_or_default(),
+                                unavailable.message
+                            ))
+                            .small()
+                            .weak(),
+                        );
+                    }
+                },
+            );
+        }
+    }
+
+    fn render_recommendations(&mut self, ui: &mut Ui) {
+        let recommendations = self.visible_recommendations();
+        if recommendations.is_empty() {
+            if !self.loading && self.loaded_at.is_some() {
+                ui.label("No recommendations");
+            }
+            return;
+        }
+
+        let mut reveal = None;
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::Grid::new("recommendations_grid")
+                    .num_columns(8)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Finding");
+                        ui.strong("Resource");
+                        ui.strong("Account");
+                        ui.strong("Region");
+                        ui.strong("Recommendation");
+                        ui.strong("Change");
+                        ui.strong("Savings/month");
+                        ui.label("");
+                        ui.end_row();
+
+                        for recommendation in recommendations {
+                            let color = if recommendation.finding.severity() >= 2 {
+                                ui.visuals().error_fg_color
+                            } else {
+                                ui.visuals().warn_fg_color
+                            };
+                            ui.colored_label(color, recommendation.finding.label());
+                            ui.label(short_resource(&recommendation.resource))
+                                .on_hover_text(&recommendation.resource);
+                            ui.label(&recommendation.account_id);
+                            ui.label(&recommendation.region);
+                            ui.label(&recommendation.title)
+                                .on_hover_text(recommendation.source.label());
+                            ui.label(
+                                match (&recommendation.current, &recommendation.recommended) {
+                                    (Some(current), Some(recommended)) => {
+                                        format!("{} -> {}", current, recommended)
+                                    }
+                                    (Some(current), None) => current.clone(),
+                                    _ => "-".to_string(),
+                                },
+                            );
+                            ui.label(
+                                recommendation
+                                    .estimated_monthly_savings
+                                    .map(|amount| {
+                                        format!(
+                                            "{:.2} {}",
+                                            amount,
+                                            recommendation.currency.as_deref().unwrap_or("USD")
+                                        )
+                                    })
+                                    .unwrap_or_else(|| "-".to_string()),
+                            );
+                            if ui
+                                .small_button("Show")
+                                .on_hover_text("Show the resource in the Explorer")
+                                .clicked()
+                            {
+                                reveal = Some(recommendation.clone());
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+
+        if let Some(recommendation) = reveal {
+            self.pending_reveal = self.reveal_request(&recommendation);
+            if self.pending_reveal.is_none() {
+                log::info!(
+                    "Recommendation resource {} is not loaded in the Explorer",
+                    recommendation.resource
+                );
+            }
+        }
+    }
+}
+
+/// Regions Trusted Advisor uses for resources that are not regional
+fn is_global_region(region: &str) -> bool {
+    matches!(region, "" | "-" | "global" | "aws-global")
+}
+
+/// Last part of an ARN, or the value itself
+fn short_resource(resource: &str) -> &str {
+    if resource.starts_with("arn:") {
+        resource
+            .rsplit(['/', ':'])
+            .find(|part| !part.starts_with('$'))
+            .unwrap_or(resource)
+    } else {
+        resource
+    }
+}
+
+impl FocusableWindow for RecommendationsWindow {
+    type ShowParams = crate::app::dashui::window_focus::SimpleShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "recommendations_window"
+    }
+
+    fn window_title(&self) -> String {
+        "Recommendations".to_string()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        _params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        RecommendationsWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::recommendations::RecommendationFinding;
+
+    fn recommendation(
+        source: RecommendationSource,
+        resource: &str,
+        finding: RecommendationFinding,
+        savings: Option<f64>,
+    ) -> Recommendation {
+        Recommendation {
+            source,
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_type: None,
+            resource: resource.to_string(),
+            finding,
+            title: "Check".to_string(),
+            current: None,
+            recommended: None,
+            estimated_monthly_savings: savings,
+            currency: None,
+        }
+    }
+
+    #[test]
+    fn test_visible_recommendations_order() {
+        let mut window = RecommendationsWindow::new();
+        window.recommendations = vec![
+            recommendation(
+                RecommendationSource::ComputeOptimizer,
+                "i-small",
+                RecommendationFinding::OverProvisioned,
+                Some(5.0),
+            ),
+            recommendation(
+                RecommendationSource::ComputeOptimizer,
+                "i-large",
+                RecommendationFinding::OverProvisioned,
+                Some(50.0),
+            ),
+            recommendation(
+                RecommendationSource::TrustedAdvisor,
+                "sg-0abc",
+                RecommendationFinding::Error,
+                None,
+            ),
+        ];
+
+        let resources: Vec<&str> = window
+            .visible_recommendations()
+            .iter()
+            .map(|r| r.resource.as_str())
+            .collect();
+        assert_eq!(resources, ["sg-0abc", "i-large", "i-small"]);
+
+        window.sources.remove(&RecommendationSource::TrustedAdvisor);
+        assert_eq!(window.visible_recommendations().len(), 2);
+    }
+
+    #[test]
+    fn test_short_resource() {
+        assert_eq!(
+            short_resource("arn:aws:lambda:us-east-1:123456789012:function:orders:$LATEST"),
+            "orders"
+        );
+        assert_eq!(
+            short_resource("arn:aws:ec2:us-east-1:123456789012:instance/i-0abc"),
+            "i-0abc"
+        );
+        assert_eq!(short_resource("sg-0abc"), "sg-0abc");
+    }
+}
@@ -0,0 +1,800 @@
+//! Relationship Graph Window
+//!
+//! Draws the relationships extracted by the normalizers as an interactive
+//! force-directed graph around one resource. Clicking a node expands its
+//! neighbors, both the resources it points to and the resources pointing to
+//! it. Nodes are filled with their account color and outlined with their
+//! region color.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use crate::app::resource_explorer::cache::SharedResourceCache;
+use crate::app::resource_explorer::colors::{assign_account_color, assign_region_color};
+use crate::app::resource_explorer::state::{RelationshipType, ResourceEntry};
+use eframe::egui;
+use egui::{Color32, Context, Pos2, Rect, RichText, Sense, Stroke, Ui, Vec2};
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Ideal edge length in graph units
+const EDGE_LENGTH: f32 = 120.0;
+
+/// Node radius in graph units
+const NODE_RADIUS: f32 = 14.0;
+
+/// Layout stops moving once the largest node step falls below this
+const SETTLE_THRESHOLD: f32 = 0.2;
+
+/// Parameters for showing the Relationship Graph window
+#[derive(Clone)]
+pub struct RelationshipGraphShowParams {
+    pub resource_type: String,
+    pub resource_id: String,
+    pub resource_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Resource type and ID; relationships identify their targets this way
+pub type NodeKey = (String, String);
+
+/// Small filled circle in `color`, sized to sit next to a line of text
+fn color_dot(ui: &mut Ui, color: Color32) {
+    let (rect, _) = ui.allocate_exact_size(Vec2::splat(10.0), Sense::hover());
+    ui.painter().circle_filled(rect.center(), 4.0, color);
+}
+
+fn relationship_label(relationship: &RelationshipType) -> &'static str {
+    match relationship {
+        RelationshipType::Uses => "uses",
+        RelationshipType::Contains => "contains",
+        RelationshipType::ChildOf => "child of",
+        RelationshipType::ParentOf => "parent of",
+        RelationshipType::AttachedTo => "attached to",
+        RelationshipType::MemberOf => "member of",
+        RelationshipType::DeployedIn => "deployed in",
+        RelationshipType::ProtectedBy => "protected by",
+        RelationshipType::DeadLetterQueue => "dead-letter queue",
+        RelationshipType::ServesAsDlq => "DLQ for",
+        RelationshipType::Triggers => "triggers",
+    }
+}
+
+/// Cached resources of one account with their incoming relationships
+#[derive(Default)]
+pub struct ResourceIndex {
+    resources: HashMap<NodeKey, Arc<ResourceEntry>>,
+    /// Target -> (source, label) for relationships pointing at the target
+    incoming: HashMap<NodeKey, Vec<(NodeKey, &'static str)>>,
+}
+
+impl ResourceIndex {
+    pub fn from_resources(resources: impl IntoIterator<Item = Arc<ResourceEntry>>) -> Self {
+        let mut index = Self::default();
+        for resource in resources {
+            let key = (resource.resource_type.clone(), resource.resource_id.clone());
+            for relationship in &resource.relationships {
+                let target = (
+                    relationship.target_resource_type.clone(),
+                    relationship.target_resource_id.clone(),
+                );
+                index.incoming.entry(target).or_default().push((
+                    key.clone(),
+                    relationship_label(&relationship.relationship_type),
+                ));
+            }
+            index.resources.insert(key, resource);
+        }
+        index
+    }
+
+    /// Index every cached resource of an account
+    ///
+    /// Relationships rarely cross accounts, and one account keeps the number of
+    /// decompressed cache entries bounded.
+    pub fn for_account(cache: &SharedResourceCache, account_id: &str) -> Self {
+        let resources = cache
+            .resource_keys()
+            .into_iter()
+            .filter_map(|key| cache.get_resources(&key))
+            .flatten()
+            .filter(|resource| resource.account_id == account_id);
+        Self::from_resources(resources)
+    }
+
+    pub fn get(&self, key: &NodeKey) -> Option<&Arc<ResourceEntry>> {
+        self.resources.get(key)
+    }
+
+    /// Neighbors of a resource: (neighbor, label, true if the edge points at the neighbor)
+    pub fn neighbors(&self, key: &NodeKey) -> Vec<(NodeKey, &'static str, bool)> {
+        let outgoing = self.resources.get(key).into_iter().flat_map(|resource| {
+            resource.relationships.iter().map(|relationship| {
+                (
+                    (
+                        relationship.target_resource_type.clone(),
+                        relationship.target_resource_id.clone(),
+                    ),
+                    relationship_label(&relationship.relationship_type),
+                    true,
+                )
+            })
+        });
+        let incoming = self
+            .incoming
+            .get(key)
+            .into_iter()
+            .flatten()
+            .map(|(source, label)| (source.clone(), *label, false));
+        outgoing.chain(incoming).collect()
+    }
+}
+
+struct GraphNode {
+    key: NodeKey,
+    /// Loaded resource, None for relationship targets not in the cache
+    resource: Option<Arc<ResourceEntry>>,
+    pos: Vec2,
+    velocity: Vec2,
+    expanded: bool,
+    /// Dragged by the user; excluded from the layout
+    pinned: bool,
+}
+
+impl GraphNode {
+    fn label(&self) -> &str {
+        self.resource
+            .as_ref()
+            .map(|r| r.display_name.as_str())
+            .unwrap_or(&self.key.1)
+    }
+}
+
+struct GraphEdge {
+    from: usize,
+    to: usize,
+    label: &'static str,
+}
+
+/// Nodes and edges shown in the window, with a force-directed layout
+#[derive(Default)]
+pub struct RelationshipGraph {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    node_index: HashMap<NodeKey, usize>,
+}
+
+impl RelationshipGraph {
+    pub fn new(root: NodeKey, index: &ResourceIndex) -> Self {
+        let mut graph = Self::default();
+        graph.add_node(root, Vec2::ZERO, index);
+        graph
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edges.len()
+    }
+
+    fn add_node(&mut self, key: NodeKey, pos: Vec2, index: &ResourceIndex) -> usize {
+        if let Some(&existing) = self.node_index.get(&key) {
+            return existing;
+        }
+        let id = self.nodes.len();
+        self.nodes.push(GraphNode {
+            resource: index.get(&key).cloned(),
+            key: key.clone(),
+            pos,
+            velocity: Vec2::ZERO,
+            expanded: false,
+            pinned: false,
+        });
+        self.node_index.insert(key, id);
+        id
+    }
+
+    /// Add a node's neighbors around it; returns the number of new nodes
+    pub fn expand(&mut self, node: usize, index: &ResourceIndex) -> usize {
+        if self.nodes[node].expanded {
+            return 0;
+        }
+        self.nodes[node].expanded = true;
+
+        let before = self.nodes.len();
+        let center = self.nodes[node].pos;
+        let neighbors = index.neighbors(&self.nodes[node].key);
+        let count = neighbors.len().max(1) as f32;
+        for (i, (key, label, outgoing)) in neighbors.into_iter().enumerate() {
+            // Start new neighbors on a circle so the layout untangles quickly
+            let angle = i as f32 / count * std::f32::consts::TAU;
+            let pos = center + Vec2::angled(angle) * EDGE_LENGTH;
+            let neighbor = self.add_node(key, pos, index);
+            let (from, to) = if outgoing {
+                (node, neighbor)
+            } else {
+                (neighbor, node)
+            };
+            if !self
+                .edges
+                .iter()
+                .any(|e| e.from == from && e.to == to && e.label == label)
+            {
+                self.edges.push(GraphEdge { from, to, label });
+            }
+        }
+        self.nodes.len() - before
+    }
+
+    /// Advance the layout one step; returns the largest node movement
+    pub fn step_layout(&mut self) -> f32 {
+        let count = self.nodes.len();
+        let mut forces = vec![Vec2::ZERO; count];
+
+        // Nodes repel each other
+        for a in 0..count {
+            for b in (a + 1)..count {
+                let mut delta = self.nodes[a].pos - self.nodes[b].pos;
+                if delta.length_sq() < 0.01 {
+                    // Separate coincident nodes in a stable direction
+                    delta = Vec2::angled(a as f32 + b as f32);
+                }
+                let distance = delta.length().max(1.0);
+                let force = delta / distance * (EDGE_LENGTH * EDGE_LENGTH / distance);
+                forces[a] += force;
+                forces[b] -= force;
+            }
+        }
+
+        // Edges pull their ends together
+        for edge in &self.edges {
+            let delta = self.nodes[edge.to].pos - self.nodes[edge.from].pos;
+            let distance = delta.length().max(1.0);
+            let force = delta / distance * (distance * distance / EDGE_LENGTH);
+            forces[edge.from] += force;
+            forces[edge.to] -= force;
+        }
+
+        let mut max_step: f32 = 0.0;
+        for (node, force) in self.nodes.iter_mut().zip(forces) {
+            if node.pinned {
+                node.velocity = Vec2::ZERO;
+                continue;
+            }
+            // Weak gravity keeps disconnected parts on screen
+            let force = force - node.pos * 0.05;
+            node.velocity = (node.velocity + force * 0.02) * 0.6;
+            let step = node.velocity.length();
+            if step > EDGE_LENGTH {
+                node.velocity *= EDGE_LENGTH / step;
+            }
+            node.pos += node.velocity;
+            max_step = max_step.max(node.velocity.length());
+        }
+        max_step
+    }
+}
+
+pub struct RelationshipGraphWindow {
+    pub open: bool,
+    // Display parameters
+    resource_type: String,
+    resource_id: String,
+    resource_name: String,
+    account_id: String,
+    region: String,
+
+    // State
+    index: Option<ResourceIndex>,
+    graph: RelationshipGraph,
+    selected: Option<usize>,
+    pan: Vec2,
+    zoom: f32,
+    settled: bool,
+
+    // Services
+    cache: Arc<SharedResourceCache>,
+
+    // Channel for receiving the index from the background thread
+    index_receiver: mpsc::Receiver<ResourceIndex>,
+    index_sender: mpsc::Sender<ResourceIndex>,
+}
+
+impl RelationshipGraphWindow {
+    pub fn new(cache: Arc<SharedResourceCache>) -> Self {
+        let (index_sender, index_receiver) = mpsc::channel();
+
+        Self {
+            open: false,
+            resource_type: String::new(),
+            resource_id: String::new(),
+            resource_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            index: None,
+            graph: RelationshipGraph::default(),
+            selected: None,
+            pan: Vec2::ZERO,
+            zoom: 1.0,
+            settled: false,
+            cache,
+            index_receiver,
+            index_sender,
+        }
+    }
+
+    /// Open the window and index the cached resources of the resource's account
+    pub fn open_for_resource(&mut self, params: RelationshipGraphShowParams) {
+        self.resource_type = params.resource_type;
+        self.resource_id = params.resource_id;
+        self.resource_name = params.resource_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.index = None;
+        self.graph = RelationshipGraph::default();
+        self.selected = None;
+        self.open = true;
+
+        let cache = Arc::clone(&self.cache);
+        let account_id = self.account_id.clone();
+        let sender = self.index_sender.clone();
+        std::thread::spawn(move || {
+            let index = ResourceIndex::for_account(&cache, &account_id);
+            let _ = sender.send(index);
+        });
+    }
+
+    fn poll_index(&mut self) {
+        if let Ok(index) = self.index_receiver.try_recv() {
+            let root = (self.resource_type.clone(), self.resource_id.clone());
+            self.graph = RelationshipGraph::new(root, &index);
+            self.graph.expand(0, &index);
+            self.selected = Some(0);
+            self.index = Some(index);
+            self.reset_view();
+        }
+    }
+
+    fn reset_view(&mut self) {
+        self.pan = Vec2::ZERO;
+        self.zoom = 1.0;
+        self.settled = false;
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_index();
+
+        // Keep repainting while indexing or while the layout is moving
+        if self.index.is_none() || !self.settled {
+            ctx.request_repaint();
+        }
+
+        let title = format!("Relationships: {}", self.resource_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "relationship_graph_window",
+                &self.account_id,
+                &self.resource_id,
+            )))
+            .open(&mut is_open)
+            .default_size([900.0, 600.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        if self.index.is_none() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Indexing cached resources...");
+            });
+            return;
+        }
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Resource:").strong());
+            ui.label(format!("{} ({})", self.resource_id, self.resource_type));
+            ui.separator();
+            ui.label(format!(
+                "{} nodes, {} relationships",
+                self.graph.node_count(),
+                self.graph.edge_count()
+            ));
+            if ui.button("Reset View").clicked() {
+                self.reset_view();
+            }
+        });
+        ui.label(
+            RichText::new(
+                "Click a node to expand its neighbors. Drag nodes to pin them, drag the background to pan, scroll to zoom.",
+            )
+            .small()
+            .weak(),
+        );
+        ui.separator();
+
+        egui::SidePanel::right(egui::Id::new((
+            "relationship_graph_details",
+            &self.resource_id,
+        )))
+        .resizable(true)
+        .default_width(240.0)
+        .show_inside(ui, |ui| {
+            self.render_details(ui);
+        });
+
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            self.render_graph(ui);
+        });
+    }
+
+    fn render_graph(&mut self, ui: &mut Ui) {
+        if !self.settled {
+            let mut max_step = 0.0;
+            for _ in 0..4 {
+                max_step = self.graph.step_layout();
+            }
+            self.settled = max_step < SETTLE_THRESHOLD;
+        }
+
+        let (response, painter) = ui.allocate_painter(ui.available_size(), Sense::click_and_drag());
+        let rect = response.rect;
+        painter.rect_filled(rect, 4.0, ui.visuals().extreme_bg_color);
+
+        // Pan with background drag, zoom around the pointer with scroll
+        if response.dragged() {
+            self.pan += response.drag_delta() / self.zoom;
+        }
+        if response.hovered() {
+            let scroll = ui.input(|i| i.smooth_scroll_delta.y);
+            if scroll != 0.0 {
+                self.zoom = (self.zoom * (1.0 + scroll * 0.002)).clamp(0.2, 3.0);
+            }
+        }
+
+        let center = rect.center().to_vec2();
+        let pan = self.pan;
+        let zoom = self.zoom;
+        let to_screen = |pos: Vec2| Pos2::ZERO + center + (pos + pan) * zoom;
+        let painter = painter.with_clip_rect(rect);
+        let text_color = ui.visuals().text_color();
+        let weak_color = ui.visuals().weak_text_color();
+        let radius = NODE_RADIUS * zoom;
+
+        for edge in &self.graph.edges {
+            let from = to_screen(self.graph.nodes[edge.from].pos);
+            let to = to_screen(self.graph.nodes[edge.to].pos);
+            if from == to {
+                continue;
+            }
+            let direction = (to - from).normalized();
+            // Stop at the node border and draw an arrowhead
+            let tip = to - direction * radius;
+            let stroke = Stroke::new(1.5, weak_color);
+            painter.line_segment([from + direction * radius, tip], stroke);
+            let back = tip - direction * 8.0 * zoom;
+            let side = direction.rot90() * 4.0 * zoom;
+            painter.line_segment([tip, back + side], stroke);
+            painter.line_segment([tip, back - side], stroke);
+            if zoom >= 0.7 {
+                painter.text(
+                    from + (to - from) * 0.5,
+                    egui::Align2::CENTER_CENTER,
+                    edge.label,
+                    egui::FontId::proportional(10.0),
+                    weak_color,
+                );
+            }
+        }
+
+        let mut clicked = None;
+        for (i, node) in self.graph.nodes.iter_mut().enumerate() {
+            let pos = to_screen(node.pos);
+            let node_rect = Rect::from_center_size(pos, Vec2::splat(radius * 2.0));
+            let node_response = ui.interact(
+                node_rect,
+                ui.id().with(("relationship_graph_node", i)),
+                Sense::click_and_drag(),
+            );
+            if node_response.dragged() {
+                node.pos += node_response.drag_delta() / zoom;
+                node.pinned = true;
+                self.settled = false;
+            }
+            if node_response.clicked() {
+                clicked = Some(i);
+            }
+
+            let (fill, outline) = match &node.resource {
+                Some(resource) => (
+                    assign_account_color(&resource.account_id),
+                    assign_region_color(&resource.region),
+                ),
+                None => (Color32::GRAY, weak_color),
+            };
+            painter.circle(pos, radius, fill, Stroke::new(3.0, outline));
+            if self.selected == Some(i) {
+                painter.circle_stroke(pos, radius + 4.0, Stroke::new(2.0, text_color));
+            }
+            if !node.expanded && node.resource.is_some() {
+                // "+" marks nodes with neighbors still to expand
+                painter.text(
+                    pos,
+                    egui::Align2::CENTER_CENTER,
+                    "+",
+                    egui::FontId::proportional(14.0 * zoom),
+                    Color32::BLACK,
+                );
+            }
+            painter.text(
+                pos + Vec2::new(0.0, radius + 4.0),
+                egui::Align2::CENTER_TOP,
+                node.label(),
+                egui::FontId::proportional(12.0),
+                if node.resource.is_some() {
+                    text_color
+                } else {
+                    weak_color
+                },
+            );
+
+            node_response.on_hover_text(format!(
+                "{}\n{}{}",
+                node.key.0,
+                node.key.1,
+                if node.resource.is_some() {
+                    ""
+                } else {
+                    "\nNot loaded in the Explorer"
+                }
+            ));
+        }
+
+        if let Some(i) = clicked {
+            self.selected = Some(i);
+            if let Some(index) = &self.index {
+                if self.graph.expand(i, index) > 0 {
+                    self.settled = false;
+                }
+            }
+        }
+    }
+
+    fn render_details(&mut self, ui: &mut Ui) {
+        let Some(node) = self.selected.and_then(|i| self.graph.nodes.get(i)) else {
+            ui.label("Select a node");
+            return;
+        };
+
+        ui.label(RichText::new(node.label()).strong());
+        ui.label(&node.key.0);
+        ui.label(RichText::new(&node.key.1).small().monospace());
+        ui.separator();
+
+        match &node.resource {
+            Some(resource) => {
+                ui.horizontal(|ui| {
+                    color_dot(ui, assign_account_color(&resource.account_id));
+                    ui.label(format!("Account {}", resource.account_id));
+                });
+                ui.horizontal(|ui| {
+                    color_dot(ui, assign_region_color(&resource.region));
+                    ui.label(format!("Region {}", resource.region));
+                });
+                if let Some(status) = &resource.status {
+                    ui.label(format!("Status: {}", status));
+                }
+            }
+            None => {
+                ui.label(
+                    RichText::new(
+                        "Not loaded in the Explorer; query its resource type to expand it",
+                    )
+                    .weak(),
+                );
+            }
+        }
+
+        let selected = self.selected;
+        let relationships: Vec<String> = self
+            .graph
+            .edges
+            .iter()
+            .filter_map(|edge| {
+                if Some(edge.from) == selected {
+                    Some(format!(
+                        "{} {}",
+                        edge.label,
+                        self.graph.nodes[edge.to].label()
+                    ))
+                } else if Some(edge.to) == selected {
+                    Some(format!(
+                        "{} {} this",
+                        self.graph.nodes[edge.from].label(),
+                        edge.label
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        if !relationships.is_empty() {
+            ui.separator();
+            ui.label(RichText::new("Relationships").strong());
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                for relationship in relationships {
+                    ui.label(relationship);
+                }
+            });
+        }
+    }
+}
+
+impl FocusableWindow for RelationshipGraphWindow {
+    type ShowParams = RelationshipGraphShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "relationship_graph_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Relationships: {}", self.resource_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the resource first
+        self.open_for_resource(params);
+
+        // Then show with focus
+        RelationshipGraphWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::resource_explorer::state::ResourceRelationship;
+
+    fn resource(
+        resource_type: &str,
+        resource_id: &str,
+        targets: &[(&str, &str)],
+    ) -> Arc<ResourceEntry> {
+        Arc::new(ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: resource_id.to_string(),
+            display_name: resource_id.to_string(),
+            status: None,
+            properties: serde_json::json!({}),
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: targets
+                .iter()
+                .map(|(target_type, target_id)| ResourceRelationship {
+                    relationship_type: RelationshipType::Uses,
+                    target_resource_id: target_id.to_string(),
+                    target_resource_type: target_type.to_string(),
+                })
+                .collect(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: Color32::WHITE,
+            region_color: Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        })
+    }
+
+    fn key(resource_type: &str, resource_id: &str) -> NodeKey {
+        (resource_type.to_string(), resource_id.to_string())
+    }
+
+    fn sample_index() -> ResourceIndex {
+        ResourceIndex::from_resources([
+            resource(
+                "AWS::EC2::Instance",
+                "i-1",
+                &[
+                    ("AWS::EC2::SecurityGroup", "sg-1"),
+                    ("AWS::EC2::Subnet", "subnet-missing"),
+                ],
+            ),
+            resource(
+                "AWS::EC2::Instance",
+                "i-2",
+                &[("AWS::EC2::SecurityGroup", "sg-1")],
+            ),
+            resource("AWS::EC2::SecurityGroup", "sg-1", &[]),
+        ])
+    }
+
+    #[test]
+    fn test_expand_adds_outgoing_and_incoming_neighbors() {
+        let index = sample_index();
+        let mut graph = RelationshipGraph::new(key("AWS::EC2::Instance", "i-1"), &index);
+        assert_eq!(graph.expand(0, &index), 2);
+        assert_eq!(graph.edge_count(), 2);
+
+        // The security group is pointed at by both instances
+        let sg = graph.node_index[&key("AWS::EC2::SecurityGroup", "sg-1")];
+        assert_eq!(graph.expand(sg, &index), 1);
+        assert_eq!(graph.node_count(), 4);
+        assert_eq!(graph.edge_count(), 3);
+
+        // Expanding again adds nothing
+        assert_eq!(graph.expand(sg, &index), 0);
+
+        // Targets missing from the cache become nodes without a resource
+        let missing = graph.node_index[&key("AWS::EC2::Subnet", "subnet-missing")];
+        assert!(graph.nodes[missing].resource.is_none());
+        assert_eq!(graph.expand(missing, &index), 0);
+    }
+
+    #[test]
+    fn test_layout_separates_nodes_and_settles() {
+        let index = sample_index();
+        let mut graph = RelationshipGraph::new(key("AWS::EC2::Instance", "i-1"), &index);
+        graph.expand(0, &index);
+        for node in &mut graph.nodes {
+            node.pos = Vec2::ZERO;
+        }
+
+        let mut max_step = f32::MAX;
+        for _ in 0..2000 {
+            max_step = graph.step_layout();
+            if max_step < SETTLE_THRESHOLD {
+                break;
+            }
+        }
+        assert!(max_step < SETTLE_THRESHOLD);
+        for a in 0..graph.node_count() {
+            for b in (a + 1)..graph.node_count() {
+                let distance = (graph.nodes[a].pos - graph.nodes[b].pos).length();
+                assert!(
+                    distance > NODE_RADIUS * 2.0,
+                    "nodes {} and {} overlap",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,545 @@
+//! S3 Bucket Window
+//!
+//! Storage analytics for one bucket: how much it holds per storage class and
+//! how that changed recently (from the daily CloudWatch storage metrics), its
+//! lifecycle and replication rules, and its Block Public Access settings.
+//! Publicly readable buckets get a warning banner at the top.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::s3_bucket_analytics::{
+    format_bytes, BucketAnalytics, LifecycleRule, PublicAccessBlock, PublicAccessStatus,
+    ReplicationConfig, S3BucketAnalyticsClient, StorageMetrics, STORAGE_LOOKBACK_DAYS,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Parameters for showing the bucket window
+#[derive(Clone)]
+pub struct S3BucketShowParams {
+    pub bucket_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BucketTab {
+    Lifecycle,
+    Replication,
+    PublicAccess,
+}
+
+pub struct S3BucketWindow {
+    pub open: bool,
+    // Display parameters
+    bucket_name: String,
+    account_id: String,
+    region: String,
+
+    // State
+    analytics: Option<BucketAnalytics>,
+    loading: bool,
+    error_message: Option<String>,
+    tab: BucketTab,
+
+    // Services
+    client: Arc<S3BucketAnalyticsClient>,
+
+    // Channel for results from background threads.
+    // Replaced when the window opens on a bucket so results for another bucket are dropped.
+    sender: mpsc::Sender<Result<BucketAnalytics, String>>,
+    receiver: mpsc::Receiver<Result<BucketAnalytics, String>>,
+}
+
+impl S3BucketWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            bucket_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            analytics: None,
+            loading: false,
+            error_message: None,
+            tab: BucketTab::Lifecycle,
+            client: Arc::new(S3BucketAnalyticsClient::new(credential_coordinator)),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on a bucket and load its analytics
+    pub fn open_for_bucket(&mut self, params: S3BucketShowParams) {
+        let (sender, receiver) = mpsc::channel();
+        self.sender = sender;
+        self.receiver = receiver;
+        self.bucket_name = params.bucket_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.analytics = None;
+        self.open = true;
+        self.load_analytics();
+    }
+
+    /// Whether this window shows the given bucket
+    pub fn targets(&self, bucket_name: &str, account_id: &str) -> bool {
+        self.bucket_name == bucket_name && self.account_id == account_id
+    }
+
+    fn load_analytics(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+        let (account_id, region, bucket_name) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.bucket_name.clone(),
+        );
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .get_bucket_analytics(&account_id, &region, &bucket_name)
+                .await;
+            if let Err(e) = &result {
+                log::error!("Failed to load S3 bucket {}: {}", bucket_name, e);
+            }
+            result.map_err(|e| format!("{:#}", e))
+        });
+    }
+
+    /// Poll for results from background threads
+    fn poll_results(&mut self) {
+        while let Ok(result) = self.receiver.try_recv() {
+            self.loading = false;
+            match result {
+                Ok(analytics) => self.analytics = Some(analytics),
+                Err(e) => self.error_message = Some(e),
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_results();
+
+        // Request continuous repaint while waiting to show spinners
+        if self.loading {
+            ctx.request_repaint();
+        }
+
+        let title = format!("S3 Bucket: {}", self.bucket_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "s3_bucket_window",
+                &self.account_id,
+                &self.bucket_name,
+            )))
+            .open(&mut is_open)
+            .default_size([780.0, 560.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Bucket:").strong());
+            ui.label(&self.bucket_name);
+            ui.label(RichText::new(format!("{} {}", self.account_id, self.region)).weak());
+            if ui
+                .add_enabled(!self.loading, egui::Button::new("Refresh"))
+                .clicked()
+            {
+                self.load_analytics();
+            }
+            if self.loading {
+                ui.spinner();
+            }
+        });
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error);
+        }
+        let Some(analytics) = self.analytics.clone() else {
+            return;
+        };
+
+        let reasons = analytics.public_access.public_read_reasons();
+        if !reasons.is_empty() {
+            egui::Frame::new()
+                .fill(ui.visuals().error_fg_color.gamma_multiply(0.15))
+                .stroke(egui::Stroke::new(1.0, ui.visuals().error_fg_color))
+                .inner_margin(8.0)
+                .corner_radius(4.0)
+                .show(ui, |ui| {
+                    ui.colored_label(
+                        ui.visuals().error_fg_color,
+                        RichText::new("This bucket is publicly readable").strong(),
+                    );
+                    for reason in &reasons {
+                        ui.label(format!("- {}", reason));
+                    }
+                });
+        }
+        ui.separator();
+
+        render_storage(ui, &analytics.storage);
+        for warning in &analytics.warnings {
+            ui.label(
+                RichText::new(warning)
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+            );
+        }
+        ui.separator();
+
+        ui.horizontal(|ui| {
+            ui.selectable_value(
+                &mut self.tab,
+                BucketTab::Lifecycle,
+                format!("Lifecycle ({})", analytics.lifecycle_rules.len()),
+            );
+            let replication = analytics
+                .replication
+                .as_ref()
+                .map(|replication| format!("Replication ({})", replication.rules.len()))
+                .unwrap_or_else(|| "Replication".to_string());
+            ui.selectable_value(&mut self.tab, BucketTab::Replication, replication);
+            ui.selectable_value(&mut self.tab, BucketTab::PublicAccess, "Public Access");
+        });
+        ui.separator();
+
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| match self.tab {
+                BucketTab::Lifecycle => render_lifecycle(ui, &analytics.lifecycle_rules),
+                BucketTab::Replication => render_replication(ui, analytics.replication.as_ref()),
+                BucketTab::PublicAccess => render_public_access(ui, &analytics.public_access),
+            });
+    }
+}
+
+fn render_storage(ui: &mut Ui, storage: &StorageMetrics) {
+    egui::Grid::new("s3_bucket_storage_grid")
+        .num_columns(4)
+        .spacing([16.0, 4.0])
+        .show(ui, |ui| {
+            ui.label("Total size:");
+            ui.label(
+                storage
+                    .total_bytes
+                    .map(format_bytes)
+                    .unwrap_or_else(|| "-".to_string()),
+            );
+            ui.label("Objects:");
+            ui.label(
+                storage
+                    .object_count
+                    .map(|count| format!("{:.0}", count))
+                    .unwrap_or_else(|| "-".to_string()),
+            );
+            ui.end_row();
+
+            ui.label(format!("Change ({} days):", STORAGE_LOOKBACK_DAYS));
+            ui.label(size_change_text(storage.size_change_bytes));
+            ui.label("Measured:");
+            ui.label(
+                storage
+                    .measured_at
+                    .map(format_date)
+                    .unwrap_or_else(|| "-".to_string()),
+            );
+            ui.end_row();
+        });
+
+    if storage.total_bytes.is_none() {
+        ui.label(
+            RichText::new(
+                "No storage metrics yet; S3 publishes them once a day for buckets with objects",
+            )
+            .small()
+            .weak(),
+        );
+        return;
+    }
+
+    ui.add_space(4.0);
+    egui::Grid::new("s3_bucket_storage_classes_grid")
+        .num_columns(3)
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Storage class");
+            ui.strong("Size");
+            ui.strong("Share");
+            ui.end_row();
+
+            let total = storage.total_bytes.unwrap_or_default();
+            for class in &storage.by_storage_class {
+                ui.label(&class.label).on_hover_text(&class.storage_type);
+                ui.label(format_bytes(class.bytes));
+                let share = if total > 0.0 {
+                    (class.bytes / total) as f32
+                } else {
+                    0.0
+                };
+                ui.add(
+                    egui::ProgressBar::new(share)
+                        .desired_width(160.0)
+                        .text(format!("{:.1}%", share * 100.0)),
+                );
+                ui.end_row();
+            }
+        });
+}
+
+fn size_change_text(change: Option<f64>) -> String {
+    match change {
+        Some(bytes) if bytes > 0.0 => format!("+{}", format_bytes(bytes)),
+        Some(bytes) if bytes < 0.0 => format_bytes(bytes),
+        Some(_) => "No change".to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn render_lifecycle(ui: &mut Ui, rules: &[LifecycleRule]) {
+    if rules.is_empty() {
+        ui.label("No lifecycle rules; objects stay in their storage class until deleted");
+        return;
+    }
+    egui::Grid::new("s3_bucket_lifecycle_grid")
+        .num_columns(4)
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Rule");
+            ui.strong("Status");
+            ui.strong("Applies to");
+            ui.strong("Actions");
+            ui.end_row();
+
+            for rule in rules {
+                ui.label(rule.id.as_deref().unwrap_or("-"));
+                render_status(ui, &rule.status);
+                ui.label(&rule.scope);
+                ui.label(if rule.actions.is_empty() {
+                    "-".to_string()
+                } else {
+                    rule.actions.join("\n")
+                });
+                ui.end_row();
+            }
+        });
+}
+
+fn render_replication(ui: &mut Ui, replication: Option<&ReplicationConfig>) {
+    let Some(replication) = replication else {
+        ui.label("Replication is not configured for this bucket");
+        return;
+    };
+    ui.horizontal(|ui| {
+        ui.label("Role:");
+        ui.label(RichText::new(&replication.role).monospace());
+    });
+    egui::Grid::new("s3_bucket_replication_grid")
+        .num_columns(7)
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Rule");
+            ui.strong("Status");
+            ui.strong("Priority");
+            ui.strong("Applies to");
+            ui.strong("Destination");
+            ui.strong("Storage class");
+            ui.strong("Delete markers");
+            ui.end_row();
+
+            for rule in &replication.rules {
+                ui.label(rule.id.as_deref().unwrap_or("-"));
+                render_status(ui, &rule.status);
+                ui.label(
+                    rule.priority
+                        .map(|priority| priority.to_string())
+                        .unwrap_or_else(|| "-".to_string()),
+                );
+                ui.label(&rule.scope);
+                let destination = ui.label(&rule.destination_bucket);
+                let mut details = Vec::new();
+                if let Some(account) = &rule.destination_account {
+                    details.push(format!("Account {}", account));
+                }
+                if let Some(key) = &rule.replica_kms_key {
+                    details.push(format!("Replicas encrypted with {}", key));
+                }
+                if !details.is_empty() {
+                    destination.on_hover_text(details.join("\n"));
+                }
+                ui.label(rule.storage_class.as_deref().unwrap_or("Same as source"));
+                ui.label(if rule.replicate_delete_markers {
+                    "Replicated"
+                } else {
+                    "Not replicated"
+                });
+                ui.end_row();
+            }
+        });
+}
+
+fn render_public_access(ui: &mut Ui, status: &PublicAccessStatus) {
+    let effective = status.effective_block();
+    if effective.all_blocked() {
+        ui.label("All public access is blocked");
+    }
+
+    egui::Grid::new("s3_bucket_public_access_grid")
+        .num_columns(4)
+        .striped(true)
+        .show(ui, |ui| {
+            ui.strong("Setting");
+            ui.strong("Bucket");
+            ui.strong("Account");
+            ui.strong("In effect");
+            ui.end_row();
+
+            let settings: [(&str, fn(&PublicAccessBlock) -> bool); 4] = [
+                ("BlockPublicAcls", |b| b.block_public_acls),
+                ("IgnorePublicAcls", |b| b.ignore_public_acls),
+                ("BlockPublicPolicy", |b| b.block_public_policy),
+                ("RestrictPublicBuckets", |b| b.restrict_public_buckets),
+            ];
+            for (name, setting) in settings {
+                ui.label(name);
+                ui.label(block_setting_text(
+                    status.bucket_block.as_ref().map(setting),
+                ));
+                ui.label(block_setting_text(
+                    status.account_block.as_ref().map(setting),
+                ));
+                if setting(&effective) {
+                    ui.label("On");
+                } else {
+                    ui.colored_label(ui.visuals().warn_fg_color, "Off");
+                }
+                ui.end_row();
+            }
+        });
+    ui.separator();
+
+    ui.horizontal(|ui| {
+        ui.label("Bucket policy:");
+        match status.policy_is_public {
+            Some(true) => {
+                ui.colored_label(ui.visuals().error_fg_color, "Public");
+            }
+            Some(false) => {
+                ui.label("Not public");
+            }
+            None => {
+                ui.label("Unknown");
+            }
+        }
+    });
+    ui.horizontal(|ui| {
+        ui.label("Public ACL grants:");
+        if status.public_acl_grants.is_empty() {
+            ui.label("None");
+        } else {
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                status.public_acl_grants.join(", "),
+            );
+        }
+    });
+}
+
+fn block_setting_text(setting: Option<bool>) -> &'static str {
+    match setting {
+        Some(true) => "On",
+        Some(false) => "Off",
+        None => "Not configured",
+    }
+}
+
+fn render_status(ui: &mut Ui, status: &str) {
+    if status == "Enabled" {
+        ui.label(status);
+    } else {
+        ui.colored_label(ui.visuals().warn_fg_color, status);
+    }
+}
+
+/// Unix millis as local date
+fn format_date(millis: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(millis)
+        .map(|time| {
+            time.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d")
+                .to_string()
+        })
+        .unwrap_or_else(|| "-".to_string())
+}
+
+impl FocusableWindow for S3BucketWindow {
+    type ShowParams = S3BucketShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "s3_bucket_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("S3 Bucket: {}", self.bucket_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the bucket first
+        self.open_for_bucket(params);
+
+        // Then show with focus
+        S3BucketWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_size_change_text() {
+        assert_eq!(size_change_text(Some(2048.0)), "+2.0 KiB");
+        assert_eq!(size_change_text(Some(-512.0)), "-512 B");
+        assert_eq!(size_change_text(Some(0.0)), "No change");
+        assert_eq!(size_change_text(None), "-");
+    }
+}
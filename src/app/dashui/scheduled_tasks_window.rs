@@ -0,0 +1,403 @@
+//! Scheduled Tasks Window
+//!
+//! Creates, edits and removes scheduled agent tasks (see
+//! [`TaskScheduler`](crate::app::agent_framework::TaskScheduler)) and shows
+//! each task's next run and recent run history. "Run Now" asks the agent
+//! manager to start the task immediately.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+
+use crate::app::agent_framework::{CronSchedule, ScheduledRun, ScheduledTask, TaskScheduler};
+
+/// Runs shown per task in the history section
+const HISTORY_ROWS: usize = 5;
+
+/// Task editor contents
+#[derive(Debug, Clone, Default)]
+struct TaskForm {
+    /// Task being edited (None = new task)
+    id: Option<String>,
+    name: String,
+    prompt: String,
+    skill: String,
+    scope: String,
+    schedule: String,
+    enabled: bool,
+}
+
+impl TaskForm {
+    fn new_task() -> Self {
+        Self {
+            schedule: "0 9 * * 1-5".to_string(),
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    fn from_task(task: &ScheduledTask) -> Self {
+        Self {
+            id: Some(task.id.clone()),
+            name: task.name.clone(),
+            prompt: task.prompt.clone(),
+            skill: task.skill.clone().unwrap_or_default(),
+            scope: task.scope.clone().unwrap_or_default(),
+            schedule: task.schedule.to_string(),
+            enabled: task.enabled,
+        }
+    }
+
+    /// Build the task, or explain what is missing
+    fn to_task(&self) -> Result<ScheduledTask, String> {
+        if self.name.trim().is_empty() {
+            return Err("Name is required".to_string());
+        }
+        if self.prompt.trim().is_empty() {
+            return Err("Prompt is required".to_string());
+        }
+        let schedule = CronSchedule::parse(&self.schedule)?;
+        let mut task = ScheduledTask::new(self.name.trim(), self.prompt.trim(), schedule);
+        if let Some(id) = &self.id {
+            task.id = id.clone();
+        }
+        task.skill = Some(self.skill.trim().to_string()).filter(|s| !s.is_empty());
+        task.scope = Some(self.scope.trim().to_string()).filter(|s| !s.is_empty());
+        task.enabled = self.enabled;
+        Ok(task)
+    }
+}
+
+/// State for the scheduled tasks window
+#[derive(Default)]
+pub struct ScheduledTasksWindow {
+    open: bool,
+    form: Option<TaskForm>,
+    /// Outcome of the last action
+    message: Option<(String, bool)>,
+    /// Task whose history is expanded, with its runs
+    history: Option<(String, Vec<ScheduledRun>)>,
+}
+
+impl ScheduledTasksWindow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.message = None;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Show the window; returns the id of a task to run now
+    pub fn show(&mut self, ctx: &Context, scheduler: &mut TaskScheduler) -> Option<String> {
+        if !self.open {
+            return None;
+        }
+
+        let mut run_now = None;
+        let mut is_open = self.open;
+        egui::Window::new("Scheduled Tasks")
+            .open(&mut is_open)
+            .default_size([700.0, 500.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                run_now = self.render_content(ui, scheduler);
+            });
+        self.open = is_open;
+        run_now
+    }
+
+    /// Reload the expanded history (after a run finishes)
+    pub fn refresh_history(&mut self, scheduler: &TaskScheduler) {
+        if let (Some((task_id, runs)), Some(store)) = (&mut self.history, scheduler.store()) {
+            *runs = store.runs(task_id);
+        }
+    }
+
+    fn render_content(&mut self, ui: &mut Ui, scheduler: &mut TaskScheduler) -> Option<String> {
+        ui.horizontal(|ui| {
+            if ui.button("New Task").clicked() {
+                self.form = Some(TaskForm::new_task());
+                self.message = None;
+            }
+            if let Some(store) = scheduler.store() {
+                ui.label(
+                    RichText::new(format!("Results: {}", store.directory().display()))
+                        .small()
+                        .weak(),
+                );
+            }
+        });
+        ui.label(
+            RichText::new(
+                "Tasks run while the application is open and logged in; missed runs are skipped.",
+            )
+            .small()
+            .weak(),
+        );
+
+        if let Some((message, is_error)) = &self.message {
+            let color = if *is_error {
+                Color32::from_rgb(220, 80, 80)
+            } else {
+                Color32::from_rgb(80, 180, 80)
+            };
+            ui.label(RichText::new(message).color(color));
+        }
+        ui.separator();
+
+        if self.form.is_some() {
+            self.render_form(ui, scheduler);
+            ui.separator();
+        }
+
+        let mut run_now = None;
+        ScrollArea::vertical()
+            .id_salt("scheduled_tasks_scroll")
+            .show(ui, |ui| {
+                run_now = self.render_task_list(ui, scheduler);
+            });
+        run_now
+    }
+
+    fn render_form(&mut self, ui: &mut Ui, scheduler: &mut TaskScheduler) {
+        let Some(form) = &mut self.form else {
+            return;
+        };
+
+        egui::Grid::new("scheduled_task_form")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Name:");
+                ui.add(egui::TextEdit::singleline(&mut form.name).desired_width(400.0));
+                ui.end_row();
+
+                ui.label("Schedule:");
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut form.schedule)
+                            .hint_text("minute hour day month weekday")
+                            .desired_width(160.0),
+                    );
+                    match CronSchedule::parse(&form.schedule) {
+                        Ok(schedule) => match schedule.next_after(chrono::Local::now()) {
+                            Some(next) => {
+                                ui.label(
+                                    RichText::new(format!(
+                                        "Next: {}",
+                                        next.format("%Y-%m-%d %H:%M")
+                                    ))
+                                    .weak(),
+                                );
+                            }
+                            None => {
+                                ui.label(
+                                    RichText::new("Never runs")
+                                        .color(Color32::from_rgb(220, 160, 60)),
+                                );
+                            }
+                        },
+                        Err(e) => {
+                            ui.label(RichText::new(e).color(Color32::from_rgb(220, 80, 80)));
+                        }
+                    }
+                });
+                ui.end_row();
+
+                ui.label("Skill:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut form.skill)
+                        .hint_text("skill name (optional)")
+                        .desired_width(400.0),
+                );
+                ui.end_row();
+
+                ui.label("Scope:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut form.scope)
+                        .hint_text("accounts, regions or resources (optional)")
+                        .desired_width(400.0),
+                );
+                ui.end_row();
+
+                ui.label("Prompt:");
+                ui.add(
+                    egui::TextEdit::multiline(&mut form.prompt)
+                        .desired_rows(4)
+                        .desired_width(400.0),
+                );
+                ui.end_row();
+
+                ui.label("");
+                ui.checkbox(&mut form.enabled, "Enabled");
+                ui.end_row();
+            });
+
+        let mut close = false;
+        ui.horizontal(|ui| {
+            if ui.button("Save").clicked() {
+                self.message = Some(match form.to_task() {
+                    Ok(task) => {
+                        let name = task.name.clone();
+                        match scheduler.upsert(task) {
+                            Ok(()) => {
+                                close = true;
+                                (format!("Saved '{}'", name), false)
+                            }
+                            Err(e) => (format!("{:#}", e), true),
+                        }
+                    }
+                    Err(e) => (e, true),
+                });
+            }
+            if ui.button("Cancel").clicked() {
+                close = true;
+            }
+        });
+        if close {
+            self.form = None;
+        }
+    }
+
+    fn render_task_list(&mut self, ui: &mut Ui, scheduler: &mut TaskScheduler) -> Option<String> {
+        if scheduler.tasks().is_empty() {
+            ui.label(RichText::new("No scheduled tasks").weak());
+            return None;
+        }
+
+        let mut run_now = None;
+        let mut edit: Option<ScheduledTask> = None;
+        let mut remove: Option<String> = None;
+        let mut toggle_history: Option<String> = None;
+        let mut toggle_enabled: Option<ScheduledTask> = None;
+
+        egui::Grid::new("scheduled_tasks_list")
+            .striped(true)
+            .num_columns(4)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Task").strong());
+                ui.label(RichText::new("Schedule").strong());
+                ui.label(RichText::new("Next Run").strong());
+                ui.label("");
+                ui.end_row();
+
+                for task in scheduler.tasks() {
+                    let mut enabled = task.enabled;
+                    ui.horizontal(|ui| {
+                        if ui.checkbox(&mut enabled, "").changed() {
+                            let mut task = task.clone();
+                            task.enabled = enabled;
+                            toggle_enabled = Some(task);
+                        }
+                        ui.label(&task.name).on_hover_text(task.agent_prompt());
+                    });
+                    ui.monospace(task.schedule.expression());
+                    ui.label(
+                        scheduler
+                            .next_run(&task.id)
+                            .map(|next| next.format("%Y-%m-%d %H:%M").to_string())
+                            .unwrap_or_else(|| "-".to_string()),
+                    );
+                    ui.horizontal(|ui| {
+                        if ui.small_button("Run Now").clicked() {
+                            run_now = Some(task.id.clone());
+                        }
+                        if ui.small_button("History").clicked() {
+                            toggle_history = Some(task.id.clone());
+                        }
+                        if ui.small_button("Edit").clicked() {
+                            edit = Some(task.clone());
+                        }
+                        if ui.small_button("Delete").clicked() {
+                            remove = Some(task.id.clone());
+                        }
+                    });
+                    ui.end_row();
+                }
+            });
+
+        if let Some((task_id, runs)) = &self.history {
+            ui.separator();
+            let name = scheduler
+                .tasks()
+                .iter()
+                .find(|t| &t.id == task_id)
+                .map(|t| t.name.clone())
+                .unwrap_or_default();
+            ui.label(RichText::new(format!("Recent runs: {}", name)).strong());
+            if runs.is_empty() {
+                ui.label(RichText::new("Not run yet").weak());
+            }
+            for run in runs.iter().take(HISTORY_ROWS) {
+                render_run(ui, run);
+            }
+        }
+
+        if let Some(task) = toggle_enabled {
+            if let Err(e) = scheduler.upsert(task) {
+                self.message = Some((format!("{:#}", e), true));
+            }
+        }
+        if let Some(task) = edit {
+            self.form = Some(TaskForm::from_task(&task));
+            self.message = None;
+        }
+        if let Some(task_id) = toggle_history {
+            let already_open = self.history.as_ref().is_some_and(|(id, _)| *id == task_id);
+            self.history = if already_open {
+                None
+            } else {
+                let runs = scheduler
+                    .store()
+                    .map(|store| store.runs(&task_id))
+                    .unwrap_or_default();
+                Some((task_id, runs))
+            };
+        }
+        if let Some(task_id) = remove {
+            if self.history.as_ref().is_some_and(|(id, _)| *id == task_id) {
+                self.history = None;
+            }
+            self.message = Some(match scheduler.remove(&task_id) {
+                Ok(()) => ("Task deleted".to_string(), false),
+                Err(e) => (format!("{:#}", e), true),
+            });
+        }
+        run_now
+    }
+}
+
+fn render_run(ui: &mut Ui, run: &ScheduledRun) {
+    let (status, color) = if run.success {
+        ("[OK]", Color32::from_rgb(80, 180, 80))
+    } else {
+        ("[Failed]", Color32::from_rgb(220, 80, 80))
+    };
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(status).color(color));
+        ui.label(
+            run.started_at
+                .with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+        );
+        ui.label(RichText::new(&run.summary).weak());
+        if let Some(path) = &run.result_path {
+            if ui
+                .small_button("Open")
+                .on_hover_text(path.display().to_string())
+                .clicked()
+            {
+                if let Err(e) = open::that(path) {
+                    log::warn!("Failed to open {}: {}", path.display(), e);
+                }
+            }
+        }
+    });
+}
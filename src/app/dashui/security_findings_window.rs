@@ -0,0 +1,359 @@
+//! Security Findings Window
+//!
+//! Runs the `security_insights` rules over the Explorer cache and lists the
+//! findings with severity filtering, CSV export, and a shortcut to show the
+//! resource in the Explorer.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use crate::app::resource_explorer::cache::SharedResourceCache;
+use crate::app::resource_explorer::resource_finder::{FindQuery, RevealRequest};
+use crate::app::resource_explorer::security_insights::{
+    default_findings_path, export_findings_csv, scan_cache, Finding, ScanReport, Severity,
+};
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::collections::HashSet;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+pub(crate) fn severity_color(ui: &Ui, severity: Severity) -> Color32 {
+    match severity {
+        Severity::Critical => ui.visuals().error_fg_color,
+        Severity::High => Color32::from_rgb(230, 130, 60),
+        Severity::Medium => ui.visuals().warn_fg_color,
+        Severity::Low => ui.visuals().weak_text_color(),
+    }
+}
+
+pub struct SecurityFindingsWindow {
+    pub open: bool,
+
+    // State
+    report: Option<ScanReport>,
+    /// Severities shown in the list
+    severities: HashSet<Severity>,
+    text_filter: String,
+    scanning: bool,
+    message: Option<String>,
+    /// Resource picked with "Show", taken by the app
+    pending_reveal: Option<RevealRequest>,
+
+    // Services
+    cache: Option<Arc<SharedResourceCache>>,
+
+    // Channel for receiving scan results from the background thread
+    scan_receiver: mpsc::Receiver<ScanReport>,
+    scan_sender: mpsc::Sender<ScanReport>,
+}
+
+impl Default for SecurityFindingsWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SecurityFindingsWindow {
+    pub fn new() -> Self {
+        let (scan_sender, scan_receiver) = mpsc::channel();
+
+        Self {
+            open: false,
+            report: None,
+            severities: Severity::all().into_iter().collect(),
+            text_filter: String::new(),
+            scanning: false,
+            message: None,
+            pending_reveal: None,
+            cache: None,
+            scan_receiver,
+            scan_sender,
+        }
+    }
+
+    /// Open the window and scan the cache
+    pub fn open_and_scan(&mut self, cache: Arc<SharedResourceCache>) {
+        self.open = true;
+        self.cache = Some(cache);
+        self.start_scan();
+    }
+
+    /// Take the resource picked with "Show" to reveal in the Explorer
+    pub fn take_reveal_request(&mut self) -> Option<RevealRequest> {
+        self.pending_reveal.take()
+    }
+
+    fn start_scan(&mut self) {
+        let Some(cache) = self.cache.clone() else {
+            return;
+        };
+        if self.scanning {
+            return;
+        }
+        self.scanning = true;
+        self.message = None;
+
+        let sender = self.scan_sender.clone();
+        std::thread::spawn(move || {
+            let _ = sender.send(scan_cache(&cache));
+        });
+    }
+
+    fn poll_scan(&mut self) {
+        if let Ok(report) = self.scan_receiver.try_recv() {
+            tracing::info!(
+                "Security scan: {} findings in {} resources",
+                report.findings.len(),
+                report.resources_checked
+            );
+            self.report = Some(report);
+            self.scanning = false;
+        }
+    }
+
+    /// Findings matching the severity and text filters
+    fn visible_findings(&self) -> Vec<&Finding> {
+        let filter = self.text_filter.to_lowercase();
+        self.report
+            .iter()
+            .flat_map(|report| &report.findings)
+            .filter(|finding| self.severities.contains(&finding.severity))
+            .filter(|finding| {
+                filter.is_empty()
+                    || [
+                        finding.title,
+                        finding.detail.as_str(),
+                        finding.display_name.as_str(),
+                        finding.resource_id.as_str(),
+                        finding.account_id.as_str(),
+                    ]
+                    .iter()
+                    .any(|field| field.to_lowercase().contains(&filter))
+            })
+            .collect()
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_scan();
+        if self.scanning {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+        let mut rescan = false;
+
+        let mut window = egui::Window::new("Security Findings")
+            .open(&mut is_open)
+            .default_size([960.0, 560.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            rescan = self.render_toolbar(ui);
+            ui.separator();
+            if let Some(request) = self.render_findings(ui) {
+                self.pending_reveal = Some(request);
+            }
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+
+        if rescan {
+            self.start_scan();
+        }
+    }
+
+    /// Toolbar with scan, filters, and export; returns true when a rescan was requested
+    fn render_toolbar(&mut self, ui: &mut Ui) -> bool {
+        let mut rescan = false;
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.scanning, egui::Button::new("Scan Cache"))
+                .on_hover_text("Check every resource loaded in the Explorer")
+                .clicked()
+            {
+                rescan = true;
+            }
+            if self.scanning {
+                ui.spinner();
+                ui.label("Scanning...");
+            } else if let Some(report) = &self.report {
+                ui.label(format!(
+                    "{} findings in {} resources",
+                    report.findings.len(),
+                    report.resources_checked
+                ));
+                if let Some(scanned_at) = report.scanned_at {
+                    ui.label(
+                        RichText::new(format!(
+                            "scanned {}",
+                            scanned_at.with_timezone(&chrono::Local).format("%H:%M:%S")
+                        ))
+                        .weak(),
+                    );
+                }
+            }
+        });
+
+        if let Some(report) = &self.report {
+            if report.resources_missing_details > 0 {
+                ui.label(
+                    RichText::new(format!(
+                        "{} resources were loaded without details (S3 ACLs, IAM MFA) and could not be fully checked; load their details in the Explorer and scan again",
+                        report.resources_missing_details
+                    ))
+                    .small()
+                    .color(ui.visuals().warn_fg_color),
+                );
+            }
+        }
+
+        ui.horizontal(|ui| {
+            for severity in Severity::all() {
+                let count = self
+                    .report
+                    .iter()
+                    .flat_map(|report| &report.findings)
+                    .filter(|finding| finding.severity == severity)
+                    .count();
+                let mut shown = self.severities.contains(&severity);
+                let label = RichText::new(format!("{} ({})", severity.label(), count))
+                    .color(severity_color(ui, severity));
+                if ui.checkbox(&mut shown, label).changed() {
+                    if shown {
+                        self.severities.insert(severity);
+                    } else {
+                        self.severities.remove(&severity);
+                    }
+                }
+            }
+            ui.separator();
+            ui.add(
+                egui::TextEdit::singleline(&mut self.text_filter)
+                    .hint_text("Filter findings")
+                    .desired_width(180.0),
+            );
+            ui.separator();
+
+            let visible: Vec<Finding> = self.visible_findings().into_iter().cloned().collect();
+            if ui
+                .add_enabled(!visible.is_empty(), egui::Button::new("Export CSV"))
+                .on_hover_text("Export the findings shown to a CSV file")
+                .clicked()
+            {
+                let path = default_findings_path();
+                self.message = Some(match export_findings_csv(&visible, &path) {
+                    Ok(()) => format!("Exported {} findings to {}", visible.len(), path.display()),
+                    Err(e) => format!("Export failed: {:#}", e),
+                });
+            }
+        });
+
+        if let Some(message) = &self.message {
+            ui.label(RichText::new(message).small());
+        }
+        rescan
+    }
+
+    fn render_findings(&self, ui: &mut Ui) -> Option<RevealRequest> {
+        let mut reveal = None;
+        let findings = self.visible_findings();
+
+        if findings.is_empty() {
+            if self.report.is_some() && !self.scanning {
+                ui.label("No findings match the filters");
+            }
+            return None;
+        }
+
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::Grid::new("security_findings_grid")
+                    .num_columns(6)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Severity");
+                        ui.strong("Finding");
+                        ui.strong("Resource");
+                        ui.strong("Account");
+                        ui.strong("Region");
+                        ui.label("");
+                        ui.end_row();
+
+                        for finding in findings {
+                            ui.colored_label(
+                                severity_color(ui, finding.severity),
+                                finding.severity.label(),
+                            );
+                            ui.vertical(|ui| {
+                                ui.label(finding.title);
+                                ui.label(RichText::new(&finding.detail).small());
+                            });
+                            ui.label(&finding.display_name).on_hover_text(format!(
+                                "{}\n{}",
+                                finding.resource_type, finding.resource_id
+                            ));
+                            ui.label(&finding.account_id);
+                            ui.label(&finding.region);
+                            if ui
+                                .small_button("Show")
+                                .on_hover_text("Show the resource in the Explorer")
+                                .clicked()
+                            {
+                                reveal = Some(RevealRequest {
+                                    query: FindQuery::ResourceId(finding.resource_id.clone()),
+                                    account_id: finding.account_id.clone(),
+                                    region: finding.region.clone(),
+                                    resource_type: finding.resource_type.clone(),
+                                    selection_key: Some(finding.selection_key.clone()),
+                                });
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+        reveal
+    }
+}
+
+impl FocusableWindow for SecurityFindingsWindow {
+    type ShowParams = crate::app::dashui::window_focus::SimpleShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "security_findings_window"
+    }
+
+    fn window_title(&self) -> String {
+        "Security Findings".to_string()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        _params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        SecurityFindingsWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
@@ -0,0 +1,465 @@
+//! Service Quotas Window
+//!
+//! Dashboard of key regional limits (VPCs, Elastic IPs, Lambda concurrency)
+//! across the accounts and regions selected in the Explorer. Each quota shows
+//! its applied limit, current usage, and utilization; quotas at or above the
+//! utilization threshold are flagged and listed first.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::service_quotas::{
+    QuotaStatus, ServiceQuotasClient, DEFAULT_UTILIZATION_THRESHOLD,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use futures::StreamExt;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Account and region pairs checked at the same time
+const MAX_CONCURRENT_CHECKS: usize = 4;
+
+/// Results from the background thread
+enum QuotaEvent {
+    Checked {
+        account_id: String,
+        region: String,
+        result: Result<Vec<QuotaStatus>, String>,
+    },
+    Finished,
+}
+
+pub struct ServiceQuotasWindow {
+    pub open: bool,
+
+    // Scope
+    accounts: Vec<String>,
+    regions: Vec<String>,
+
+    // State
+    quotas: Vec<QuotaStatus>,
+    /// Accounts and regions that could not be checked at all
+    errors: Vec<String>,
+    /// Utilization (0.0 to 1.0) at which a quota is flagged
+    threshold: f64,
+    only_flagged: bool,
+    loading: bool,
+    checked_at: Option<chrono::DateTime<chrono::Utc>>,
+
+    // Services
+    client: Option<Arc<ServiceQuotasClient>>,
+
+    // Channel for results from the background thread.
+    // Replaced on refresh so results from an earlier check are dropped.
+    sender: mpsc::Sender<QuotaEvent>,
+    receiver: mpsc::Receiver<QuotaEvent>,
+}
+
+impl Default for ServiceQuotasWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ServiceQuotasWindow {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            accounts: Vec::new(),
+            regions: Vec::new(),
+            quotas: Vec::new(),
+            errors: Vec::new(),
+            threshold: DEFAULT_UTILIZATION_THRESHOLD,
+            only_flagged: false,
+            loading: false,
+            checked_at: None,
+            client: None,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on the given accounts and regions and check their quotas
+    pub fn open_for_scope(
+        &mut self,
+        credential_coordinator: Arc<CredentialCoordinator>,
+        accounts: Vec<String>,
+        regions: Vec<String>,
+        threshold: f64,
+    ) {
+        self.client = Some(Arc::new(ServiceQuotasClient::new(credential_coordinator)));
+        self.accounts = accounts;
+        self.regions = regions;
+        self.threshold = threshold.clamp(0.0, 1.0);
+        self.open = true;
+        self.refresh();
+    }
+
+    /// Current utilization threshold, for the app to persist
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+
+    fn refresh(&mut self) {
+        let Some(client) = self.client.clone() else {
+            return;
+        };
+        let (sender, receiver) = mpsc::channel();
+        self.sender = sender;
+        self.receiver = receiver;
+        self.quotas.clear();
+        self.errors.clear();
+        self.checked_at = None;
+
+        let pairs: Vec<(String, String)> = self
+            .accounts
+            .iter()
+            .flat_map(|account| {
+                self.regions
+                    .iter()
+                    .map(move |region| (account.clone(), region.clone()))
+            })
+            .collect();
+        if pairs.is_empty() {
+            return;
+        }
+        self.loading = true;
+
+        let sender = self.sender.clone();
+        spawn_with_client(&client, &self.sender, move |client| async move {
+            futures::stream::iter(pairs)
+                .for_each_concurrent(MAX_CONCURRENT_CHECKS, |(account_id, region)| {
+                    let client = Arc::clone(&client);
+                    let sender = sender.clone();
+                    async move {
+                        let result = client
+                            .check_quotas(&account_id, &region)
+                            .await
+                            .map_err(|e| {
+                                log::error!(
+                                    "Failed to check quotas in {}/{}: {}",
+                                    account_id,
+                                    region,
+                                    e
+                                );
+                                format!("{:#}", e)
+                            });
+                        let _ = sender.send(QuotaEvent::Checked {
+                            account_id,
+                            region,
+                            result,
+                        });
+                    }
+                })
+                .await;
+            QuotaEvent::Finished
+        });
+    }
+
+    /// Poll for results from the background thread
+    fn poll_events(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                QuotaEvent::Checked {
+                    result: Ok(quotas), ..
+                } => self.quotas.extend(quotas),
+                QuotaEvent::Checked {
+                    account_id,
+                    region,
+                    result: Err(e),
+                } => self
+                    .errors
+                    .push(format!("{} {}: {}", account_id, region, e)),
+                QuotaEvent::Finished => {
+                    self.loading = false;
+                    self.checked_at = Some(chrono::Utc::now());
+                }
+            }
+        }
+    }
+
+    /// Quotas to list: flagged ones first by utilization, then the rest by account and region
+    fn visible_quotas(&self) -> Vec<&QuotaStatus> {
+        let mut quotas: Vec<&QuotaStatus> = self
+            .quotas
+            .iter()
+            .filter(|quota| !self.only_flagged || quota.exceeds(self.threshold))
+            .collect();
+        quotas.sort_by(|a, b| {
+            b.exceeds(self.threshold)
+                .cmp(&a.exceeds(self.threshold))
+                .then_with(|| {
+                    if a.exceeds(self.threshold) {
+                        b.utilization()
+                            .unwrap_or_default()
+                            .total_cmp(&a.utilization().unwrap_or_default())
+                    } else {
+                        std::cmp::Ordering::Equal
+                    }
+                })
+                .then_with(|| a.account_id.cmp(&b.account_id))
+                .then_with(|| a.region.cmp(&b.region))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        quotas
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_events();
+        if self.loading {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new("Service Quotas")
+            .open(&mut is_open)
+            .default_size([860.0, 480.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.render_toolbar(ui);
+            ui.separator();
+            self.render_quotas(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn render_toolbar(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(!self.loading, egui::Button::new("Refresh"))
+                .clicked()
+            {
+                self.refresh();
+            }
+            if self.loading {
+                ui.spinner();
+                ui.label("Checking quotas...");
+            } else if let Some(checked_at) = self.checked_at {
+                ui.label(
+                    RichText::new(format!(
+                        "{} accounts, {} regions, checked {}",
+                        self.accounts.len(),
+                        self.regions.len(),
+                        checked_at.with_timezone(&chrono::Local).format("%H:%M:%S")
+                    ))
+                    .weak(),
+                );
+            }
+        });
+
+        ui.horizontal(|ui| {
+            let mut percent = (self.threshold * 100.0).round() as u32;
+            if ui
+                .add(
+                    egui::Slider::new(&mut percent, 10..=100)
+                        .text("Flag at utilization")
+                        .suffix("%"),
+                )
+                .changed()
+            {
+                self.threshold = f64::from(percent) / 100.0;
+            }
+            ui.separator();
+            let flagged = self
+                .quotas
+                .iter()
+                .filter(|quota| quota.exceeds(self.threshold))
+                .count();
+            ui.checkbox(
+                &mut self.only_flagged,
+                format!("Only flagged ({})", flagged),
+            );
+        });
+
+        if self.accounts.is_empty() || self.regions.is_empty() {
+            ui.label(
+                RichText::new(
+                    "Select accounts and regions in an Explorer window to check their quotas",
+                )
+                .color(ui.visuals().warn_fg_color),
+            );
+        }
+        for error in &self.errors {
+            ui.colored_label(ui.visuals().error_fg_color, RichText::new(error).small());
+        }
+    }
+
+    fn render_quotas(&self, ui: &mut Ui) {
+        let quotas = self.visible_quotas();
+        if quotas.is_empty() {
+            if !self.loading && self.checked_at.is_some() {
+                ui.label(if self.only_flagged {
+                    "No quotas are over the threshold"
+                } else {
+                    "No quotas were checked"
+                });
+            }
+            return;
+        }
+
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::Grid::new("service_quotas_grid")
+                    .num_columns(6)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Quota");
+                        ui.strong("Account");
+                        ui.strong("Region");
+                        ui.strong("Usage");
+                        ui.strong("Limit");
+                        ui.strong("Utilization");
+                        ui.end_row();
+
+                        for quota in quotas {
+                            let flagged = quota.exceeds(self.threshold);
+                            let name = RichText::new(&quota.name);
+                            ui.label(if flagged { name.strong() } else { name })
+                                .on_hover_text(format!(
+                                    "{} {}",
+                                    quota.service_code, quota.quota_code
+                                ));
+                            ui.label(&quota.account_id);
+                            ui.label(&quota.region);
+                            ui.label(
+                                quota
+                                    .usage
+                                    .map(format_quantity)
+                                    .unwrap_or_else(|| "-".to_string()),
+                            );
+
+                            let limit = ui.label(
+                                quota
+                                    .limit
+                                    .map(format_quantity)
+                                    .unwrap_or_else(|| "-".to_string()),
+                            );
+                            if quota.is_default_limit {
+                                limit.on_hover_text("AWS default; no increase has been applied");
+                            }
+
+                            match (quota.utilization(), &quota.error) {
+                                (Some(utilization), _) => {
+                                    let color = if flagged {
+                                        ui.visuals().error_fg_color
+                                    } else if utilization >= self.threshold * 0.75 {
+                                        ui.visuals().warn_fg_color
+                                    } else {
+                                        Color32::from_rgb(100, 180, 100)
+                                    };
+                                    ui.add(
+                                        egui::ProgressBar::new(utilization.min(1.0) as f32)
+                                            .fill(color)
+                                            .desired_width(160.0)
+                                            .text(format!("{:.0}%", utilization * 100.0)),
+                                    );
+                                }
+                                (None, Some(error)) => {
+                                    ui.colored_label(ui.visuals().error_fg_color, "Unavailable")
+                                        .on_hover_text(error);
+                                }
+                                (None, None) => {
+                                    ui.label("-");
+                                }
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+}
+
+/// Whole numbers without decimals, fractions with one
+fn format_quantity(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.1}", value)
+    }
+}
+
+impl FocusableWindow for ServiceQuotasWindow {
+    type ShowParams = crate::app::dashui::window_focus::SimpleShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "service_quotas_window"
+    }
+
+    fn window_title(&self) -> String {
+        "Service Quotas".to_string()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        _params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        ServiceQuotasWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::service_quotas::KEY_QUOTAS;
+
+    #[test]
+    fn test_visible_quotas_order() {
+        let mut window = ServiceQuotasWindow::new();
+        let quota = |account: &str, usage: f64, limit: f64| QuotaStatus {
+            usage: Some(usage),
+            limit: Some(limit),
+            ..QuotaStatus::new(&KEY_QUOTAS[0], account, "us-east-1")
+        };
+        window.quotas = vec![
+            quota("111111111111", 1.0, 5.0),
+            quota("222222222222", 4.0, 5.0),
+            quota("333333333333", 5.0, 5.0),
+        ];
+
+        let accounts: Vec<&str> = window
+            .visible_quotas()
+            .iter()
+            .map(|q| q.account_id.as_str())
+            .collect();
+        assert_eq!(accounts, ["333333333333", "222222222222", "111111111111"]);
+
+        window.only_flagged = true;
+        window.threshold = 0.9;
+        assert_eq!(window.visible_quotas().len(), 1);
+    }
+
+    #[test]
+    fn test_format_quantity() {
+        assert_eq!(format_quantity(5.0), "5");
+        assert_eq!(format_quantity(12.34), "12.3");
+    }
+}
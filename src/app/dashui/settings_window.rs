@@ -0,0 +1,467 @@
+//! Settings Window
+//!
+//! Edits the [`AppConfig`]: theme, log levels, cache lifetimes, concurrency
+//! limits, default regions, notification webhook defaults, and agent defaults.
+//! Saved settings go to the config directory (see [`AppConfigStore`]); log
+//! levels and concurrency limits take effect right away, cache lifetimes with
+//! the next sign-in or restart.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+
+use super::app::ThemeChoice;
+use super::command_registry::{CommandCategory, PaletteCommand};
+use crate::app::agent_framework::{StoodLogLevel, MAX_CONCURRENT_WORKERS_LIMIT};
+use crate::app::app_config::{app_config, AppConfig, AppConfigStore, LogLevel};
+use crate::app::notifications::sinks::{NotificationSinks, Severity};
+use crate::app::resource_explorer::adaptive_concurrency::SERVICE_CONCURRENCY_LIMIT;
+
+pub const OPEN_SETTINGS: &str = "general.settings";
+
+/// Commands the window offers in the palette
+pub fn palette_commands() -> Vec<PaletteCommand> {
+    vec![PaletteCommand {
+        id: OPEN_SETTINGS,
+        label: "Open settings",
+        description: "Theme, log levels, caches, concurrency, regions, and agent defaults",
+        category: CommandCategory::General,
+        keywords: &["preferences", "config", "options", "theme", "logging"],
+        is_available: |_| true,
+    }]
+}
+
+/// State for the settings window
+pub struct SettingsWindow {
+    open: bool,
+    /// Settings being edited
+    config: AppConfig,
+    /// Settings in effect, to detect unsaved changes
+    saved: AppConfig,
+    /// Default regions as typed, comma separated
+    regions_text: String,
+    /// Outcome of the last action
+    message: Option<(String, bool)>,
+    store: Option<AppConfigStore>,
+    /// Settings saved since the app last took them
+    applied: Option<AppConfig>,
+    /// The notification webhooks window was asked for
+    webhooks_requested: bool,
+}
+
+impl Default for SettingsWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SettingsWindow {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            config: AppConfig::default(),
+            saved: AppConfig::default(),
+            regions_text: String::new(),
+            message: None,
+            store: AppConfigStore::default_location(),
+            applied: None,
+            webhooks_requested: false,
+        }
+    }
+
+    /// Open the window on the settings in effect
+    pub fn open(&mut self) {
+        self.open = true;
+        self.message = None;
+        self.set_config(app_config());
+        self.saved = self.config.clone();
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Settings saved since the last call, for the app to put into effect
+    pub fn take_applied(&mut self) -> Option<AppConfig> {
+        self.applied.take()
+    }
+
+    /// Whether "Edit Webhooks" was clicked since the last call
+    pub fn take_webhooks_request(&mut self) -> bool {
+        std::mem::take(&mut self.webhooks_requested)
+    }
+
+    fn set_config(&mut self, config: AppConfig) {
+        self.regions_text = config.regions.default_regions.join(", ");
+        self.config = config;
+    }
+
+    fn is_modified(&self) -> bool {
+        self.config != self.saved
+    }
+
+    fn save(&mut self) {
+        let Some(store) = &self.store else {
+            self.message = Some(("Config directory not available".to_string(), true));
+            return;
+        };
+        self.message = Some(match store.save(&self.config) {
+            Ok(()) => {
+                tracing::info!("Saved settings to {}", store.path().display());
+                self.saved = self.config.clone();
+                self.applied = Some(self.config.clone());
+                (format!("Saved to {}", store.path().display()), false)
+            }
+            Err(e) => (format!("{:#}", e), true),
+        });
+    }
+
+    pub fn show(&mut self, ctx: &Context, sinks: &NotificationSinks) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Settings")
+            .open(&mut is_open)
+            .default_size([560.0, 620.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                ScrollArea::vertical()
+                    .auto_shrink([false, true])
+                    .max_height(ui.available_height() - 60.0)
+                    .show(ui, |ui| {
+                        self.render_appearance(ui);
+                        self.render_logging(ui);
+                        self.render_caches(ui);
+                        self.render_concurrency(ui);
+                        self.render_regions(ui);
+                        self.render_notifications(ui, sinks);
+                        self.render_agents(ui);
+                    });
+                ui.separator();
+                self.render_actions(ui);
+            });
+        self.open = is_open;
+    }
+
+    fn render_appearance(&mut self, ui: &mut Ui) {
+        egui::CollapsingHeader::new(RichText::new("Appearance").strong())
+            .default_open(true)
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Theme:");
+                    egui::ComboBox::from_id_salt("settings_theme")
+                        .selected_text(self.config.appearance.theme.to_string())
+                        .show_ui(ui, |ui| {
+                            for theme in ThemeChoice::ALL {
+                                ui.selectable_value(
+                                    &mut self.config.appearance.theme,
+                                    theme,
+                                    theme.to_string(),
+                                );
+                            }
+                        });
+                });
+            });
+    }
+
+    fn render_logging(&mut self, ui: &mut Ui) {
+        let logging = &mut self.config.logging;
+        egui::CollapsingHeader::new(RichText::new("Logging").strong())
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::Grid::new("settings_logging")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        log_level_row(ui, "AWS Dash:", &mut logging.app);
+                        log_level_row(ui, "AWS SDK clients:", &mut logging.aws_sdk);
+                        log_level_row(ui, "AWS config and HTTP:", &mut logging.aws_runtime);
+                        log_level_row(ui, "GUI framework:", &mut logging.gui);
+                    });
+                hint(
+                    ui,
+                    "Levels of the global awsdash.log; agent logs use the agent level below",
+                );
+            });
+    }
+
+    fn render_caches(&mut self, ui: &mut Ui) {
+        let cache = &mut self.config.cache;
+        egui::CollapsingHeader::new(RichText::new("Caches").strong())
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::Grid::new("settings_caches")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("Drop unused resources from memory after:");
+                        ui.add(
+                            egui::DragValue::new(&mut cache.memory_idle_minutes)
+                                .range(1..=1440)
+                                .suffix(" min"),
+                        );
+                        ui.end_row();
+
+                        ui.label("Restore query results from disk up to:");
+                        ui.add(
+                            egui::DragValue::new(&mut cache.disk_ttl_hours)
+                                .range(0..=720)
+                                .suffix(" h"),
+                        );
+                        ui.end_row();
+
+                        ui.label("Reuse resource tags for:");
+                        ui.add(
+                            egui::DragValue::new(&mut cache.tag_ttl_minutes)
+                                .range(1..=1440)
+                                .suffix(" min"),
+                        );
+                        ui.end_row();
+                    });
+                hint(
+                    ui,
+                    "Memory and disk lifetimes apply after a restart, tags after the next sign-in",
+                );
+            });
+    }
+
+    fn render_concurrency(&mut self, ui: &mut Ui) {
+        let concurrency = &mut self.config.concurrency;
+        egui::CollapsingHeader::new(RichText::new("Concurrency").strong())
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::Grid::new("settings_concurrency")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("Explorer queries at once:");
+                        ui.add(
+                            egui::DragValue::new(&mut concurrency.explorer_queries).range(1..=64),
+                        );
+                        ui.end_row();
+
+                        ui.label("Calls per AWS service at once:");
+                        ui.add(
+                            egui::DragValue::new(&mut concurrency.per_service)
+                                .range(1..=SERVICE_CONCURRENCY_LIMIT),
+                        );
+                        ui.end_row();
+                    });
+                hint(
+                    ui,
+                    "Throttling lowers the per-service limit; the query limit applies after sign-in",
+                );
+            });
+    }
+
+    fn render_regions(&mut self, ui: &mut Ui) {
+        egui::CollapsingHeader::new(RichText::new("Regions").strong())
+            .default_open(true)
+            .show(ui, |ui| {
+                ui.label("Regions offered when choosing what to query:");
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut self.regions_text)
+                        .hint_text("Built-in list, e.g. us-east-1, eu-west-1")
+                        .desired_width(f32::INFINITY),
+                );
+                if response.changed() {
+                    self.config.regions.default_regions = self
+                        .regions_text
+                        .split(|c: char| c == ',' || c.is_whitespace())
+                        .map(str::trim)
+                        .filter(|region| !region.is_empty())
+                        .map(str::to_string)
+                        .collect();
+                }
+                hint(
+                    ui,
+                    "Commercial regions only; GovCloud and China list all their regions",
+                );
+            });
+    }
+
+    fn render_notifications(&mut self, ui: &mut Ui, sinks: &NotificationSinks) {
+        let notifications = &mut self.config.notifications;
+        egui::CollapsingHeader::new(RichText::new("Notifications").strong())
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::Grid::new("settings_notifications")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("New webhooks send:");
+                        egui::ComboBox::from_id_salt("settings_webhook_severity")
+                            .selected_text(notifications.new_webhook_severity.label())
+                            .show_ui(ui, |ui| {
+                                for severity in Severity::ALL {
+                                    ui.selectable_value(
+                                        &mut notifications.new_webhook_severity,
+                                        severity,
+                                        severity.label(),
+                                    );
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label("Webhook timeout:");
+                        ui.add(
+                            egui::DragValue::new(&mut notifications.delivery_timeout_secs)
+                                .range(1..=120)
+                                .suffix(" s"),
+                        );
+                        ui.end_row();
+                    });
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "{} webhook(s) for project {}",
+                        sinks.config().sinks.len(),
+                        sinks.project()
+                    ));
+                    if ui.button("Edit Webhooks...").clicked() {
+                        self.webhooks_requested = true;
+                    }
+                });
+                hint(ui, "The timeout applies after a restart");
+            });
+    }
+
+    fn render_agents(&mut self, ui: &mut Ui) {
+        let agents = &mut self.config.agents;
+        egui::CollapsingHeader::new(RichText::new("Agents").strong())
+            .default_open(true)
+            .show(ui, |ui| {
+                egui::Grid::new("settings_agents")
+                    .num_columns(2)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("Agent log level:");
+                        egui::ComboBox::from_id_salt("settings_agent_log_level")
+                            .selected_text(agents.log_level.display_name())
+                            .show_ui(ui, |ui| {
+                                for level in StoodLogLevel::all() {
+                                    ui.selectable_value(
+                                        &mut agents.log_level,
+                                        *level,
+                                        level.display_name(),
+                                    );
+                                }
+                            });
+                        ui.end_row();
+
+                        ui.label("Workers per task manager:");
+                        ui.add(
+                            egui::DragValue::new(&mut agents.max_workers)
+                                .range(1..=MAX_CONCURRENT_WORKERS_LIMIT),
+                        );
+                        ui.end_row();
+
+                        ui.label("Stream responses:");
+                        ui.checkbox(&mut agents.stream_responses, "Task managers (experimental)");
+                        ui.end_row();
+                    });
+                hint(
+                    ui,
+                    "The log level and streaming apply to agents created afterwards",
+                );
+            });
+    }
+
+    fn render_actions(&mut self, ui: &mut Ui) {
+        let errors = self.config.validation_errors();
+        for error in &errors {
+            ui.label(
+                RichText::new(error)
+                    .small()
+                    .color(Color32::from_rgb(220, 80, 80)),
+            );
+        }
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(
+                    self.is_modified() && errors.is_empty(),
+                    egui::Button::new("Save"),
+                )
+                .clicked()
+            {
+                self.save();
+            }
+            if ui
+                .add_enabled(self.is_modified(), egui::Button::new("Revert"))
+                .clicked()
+            {
+                self.set_config(self.saved.clone());
+                self.message = None;
+            }
+            if ui
+                .button("Reset to Defaults")
+                .on_hover_text("Fill in the default settings; Save to keep them")
+                .clicked()
+            {
+                self.set_config(AppConfig::default());
+                self.message = None;
+            }
+        });
+
+        if let Some((message, is_error)) = &self.message {
+            let color = if *is_error {
+                Color32::from_rgb(220, 80, 80)
+            } else {
+                Color32::GRAY
+            };
+            ui.label(RichText::new(message).small().color(color));
+        } else if self.is_modified() {
+            ui.label(
+                RichText::new("Unsaved changes")
+                    .small()
+                    .color(Color32::GRAY),
+            );
+        }
+    }
+}
+
+fn log_level_row(ui: &mut Ui, label: &str, level: &mut LogLevel) {
+    ui.label(label);
+    egui::ComboBox::from_id_salt(label)
+        .selected_text(level.label())
+        .show_ui(ui, |ui| {
+            for option in LogLevel::ALL {
+                ui.selectable_value(level, option, option.label());
+            }
+        });
+    ui.end_row();
+}
+
+fn hint(ui: &mut Ui, text: &str) {
+    ui.label(RichText::new(text).small().weak());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_hands_settings_to_the_app() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut window = SettingsWindow::new();
+        window.store = Some(AppConfigStore::new(dir.path().join("config.toml")));
+        window.open();
+        assert!(!window.is_modified());
+
+        window.config.appearance.theme = ThemeChoice::Mocha;
+        window.config.agents.max_workers = 8;
+        assert!(window.is_modified());
+        window.save();
+
+        assert!(!window.is_modified());
+        let applied = window.take_applied().unwrap();
+        assert_eq!(applied.appearance.theme, ThemeChoice::Mocha);
+        assert_eq!(applied.agents.max_workers, 8);
+        assert!(window.take_applied().is_none());
+        let stored = window.store.as_ref().unwrap().load().unwrap();
+        assert_eq!(stored, Some(applied));
+    }
+}
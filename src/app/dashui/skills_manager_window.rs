@@ -0,0 +1,455 @@
+//! Skills Manager Window
+//!
+//! Lists the skills agents can use and installs new ones from a Git
+//! repository or zip archive (see
+//! [`SkillInstaller`](crate::app::agent_framework::skills::SkillInstaller)).
+//! Skills installed here can be updated from their pinned source, verified
+//! against their install checksum, or removed. Installs run on a background
+//! thread and the skill system is refreshed when they finish.
+//!
+//! Development mode hot-reloads skills as their files are edited, and skills
+//! with lint issues are listed so authors can see why a skill is missing.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+use std::sync::{Arc, Mutex};
+
+use crate::app::agent_framework::skills::{
+    get_global_skill_manager, InstallRequest, InstalledSkill, LintSeverity, SkillInstaller,
+    SkillLintReport, SkillMetadata, SkillSource,
+};
+
+/// Kind of source in the install form
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SourceKind {
+    Git,
+    Archive,
+}
+
+/// Result of a background operation: message and whether it failed
+type OperationResult = Arc<Mutex<Option<(String, bool)>>>;
+
+/// State for the skills manager window
+pub struct SkillsManagerWindow {
+    open: bool,
+    installer: Option<SkillInstaller>,
+    /// Skills offered to agents (all skill directories)
+    skills: Vec<SkillMetadata>,
+    /// Skills installed from a source
+    installed: Vec<InstalledSkill>,
+    /// Skills with lint issues from the last discovery
+    lint_reports: Vec<SkillLintReport>,
+    /// Skill manager generation the lists were read at
+    generation: u64,
+    // Install form
+    source_kind: SourceKind,
+    location: String,
+    reference: String,
+    checksum: String,
+    /// Background operation in progress (description)
+    busy: Option<String>,
+    result: OperationResult,
+    message: Option<(String, bool)>,
+}
+
+impl Default for SkillsManagerWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SkillsManagerWindow {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            installer: SkillInstaller::default_location(),
+            skills: Vec::new(),
+            installed: Vec::new(),
+            lint_reports: Vec::new(),
+            generation: 0,
+            source_kind: SourceKind::Git,
+            location: String::new(),
+            reference: String::new(),
+            checksum: String::new(),
+            busy: None,
+            result: Arc::new(Mutex::new(None)),
+            message: None,
+        }
+    }
+
+    /// Open the window and read the installed skills
+    pub fn open(&mut self) {
+        self.open = true;
+        self.reload();
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn reload(&mut self) {
+        let manager = get_global_skill_manager();
+        self.skills = manager
+            .as_ref()
+            .map(|manager| manager.get_all_skill_metadata())
+            .unwrap_or_default();
+        self.skills.sort_by(|a, b| a.name.cmp(&b.name));
+        self.lint_reports = manager
+            .as_ref()
+            .map(|manager| manager.get_lint_reports())
+            .unwrap_or_default();
+        self.generation = manager.map(|manager| manager.generation()).unwrap_or(0);
+        self.installed = self
+            .installer
+            .as_ref()
+            .map(|installer| installer.installed())
+            .unwrap_or_default();
+    }
+
+    /// Run `operation` on a background thread, then rediscover skills
+    fn run<F>(&mut self, description: String, operation: F)
+    where
+        F: FnOnce(&SkillInstaller) -> anyhow::Result<String> + Send + 'static,
+    {
+        let Some(installer) = self.installer.clone() else {
+            self.message = Some(("Home directory not available".to_string(), true));
+            return;
+        };
+        self.busy = Some(description);
+        self.message = None;
+        let result = self.result.clone();
+        std::thread::spawn(move || {
+            let outcome = match operation(&installer) {
+                Ok(message) => {
+                    if let Some(manager) = get_global_skill_manager() {
+                        if let Err(e) = manager.refresh() {
+                            log::warn!("Failed to refresh skills: {}", e);
+                        }
+                    }
+                    (message, false)
+                }
+                Err(e) => (format!("{:#}", e), true),
+            };
+            if let Ok(mut guard) = result.lock() {
+                *guard = Some(outcome);
+            }
+        });
+    }
+
+    /// Pick up the result of a finished background operation
+    fn poll_result(&mut self) {
+        let finished = self
+            .result
+            .try_lock()
+            .ok()
+            .and_then(|mut guard| guard.take());
+        if let Some(outcome) = finished {
+            self.busy = None;
+            self.message = Some(outcome);
+            self.reload();
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        if !self.open {
+            return;
+        }
+        self.poll_result();
+        if self.busy.is_some() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(200));
+        }
+        // Pick up hot reloads from development mode
+        if let Some(manager) = get_global_skill_manager() {
+            if manager.is_development_mode() {
+                if manager.generation() != self.generation {
+                    self.reload();
+                }
+                ctx.request_repaint_after(std::time::Duration::from_secs(1));
+            }
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Skills Manager")
+            .open(&mut is_open)
+            .default_size([650.0, 500.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                self.render_content(ui);
+            });
+        self.open = is_open;
+    }
+
+    fn render_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            if let Some(installer) = &self.installer {
+                ui.label(
+                    RichText::new(format!(
+                        "Install directory: {}",
+                        installer.skills_dir().display()
+                    ))
+                    .small()
+                    .weak(),
+                );
+            }
+            if ui
+                .add_enabled(self.busy.is_none(), egui::Button::new("Refresh"))
+                .clicked()
+            {
+                self.run("Rediscovering skills".to_string(), |_| {
+                    Ok("Skills refreshed".to_string())
+                });
+            }
+            if let Some(manager) = get_global_skill_manager() {
+                let mut development_mode = manager.is_development_mode();
+                if ui
+                    .checkbox(&mut development_mode, "Development mode")
+                    .on_hover_text("Reload skills automatically when their files change")
+                    .changed()
+                {
+                    manager.set_development_mode(development_mode);
+                }
+            }
+        });
+
+        if let Some(busy) = &self.busy {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(busy);
+            });
+        } else if let Some((message, is_error)) = &self.message {
+            let color = if *is_error {
+                Color32::from_rgb(220, 80, 80)
+            } else {
+                Color32::from_rgb(80, 180, 80)
+            };
+            ui.label(RichText::new(message).color(color));
+        }
+        ui.separator();
+
+        egui::CollapsingHeader::new("Install Skill")
+            .default_open(self.skills.is_empty())
+            .show(ui, |ui| {
+                self.render_install_form(ui);
+            });
+        ui.separator();
+
+        ScrollArea::vertical()
+            .id_salt("skills_manager_scroll")
+            .show(ui, |ui| {
+                self.render_skill_list(ui);
+                if !self.lint_reports.is_empty() {
+                    ui.separator();
+                    self.render_lint_reports(ui);
+                }
+            });
+    }
+
+    fn render_lint_reports(&self, ui: &mut Ui) {
+        ui.label(RichText::new("Lint Issues").strong());
+        ui.label(
+            RichText::new("Skills with errors are not offered to agents.")
+                .small()
+                .weak(),
+        );
+        for report in &self.lint_reports {
+            let (status, color) = if report.has_errors() {
+                ("[Not loaded]", Color32::from_rgb(220, 80, 80))
+            } else {
+                ("[Warnings]", Color32::from_rgb(220, 160, 60))
+            };
+            ui.label(RichText::new(format!("{} {}", status, report.display_name())).color(color))
+                .on_hover_text(report.skill_md_path.display().to_string());
+            for issue in &report.issues {
+                let prefix = match issue.severity {
+                    LintSeverity::Error => "Error",
+                    LintSeverity::Warning => "Warning",
+                };
+                ui.label(format!("    {}: {}", prefix, issue.message));
+            }
+        }
+    }
+
+    fn render_install_form(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.radio_value(&mut self.source_kind, SourceKind::Git, "Git repository");
+            ui.radio_value(&mut self.source_kind, SourceKind::Archive, "Zip archive");
+        });
+
+        egui::Grid::new("skill_install_form")
+            .num_columns(2)
+            .show(ui, |ui| {
+                match self.source_kind {
+                    SourceKind::Git => {
+                        ui.label("URL:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.location)
+                                .hint_text("https://github.com/org/skill.git")
+                                .desired_width(380.0),
+                        );
+                        ui.end_row();
+                        ui.label("Pin:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.reference)
+                                .hint_text("tag, branch or commit (optional)")
+                                .desired_width(380.0),
+                        );
+                        ui.end_row();
+                    }
+                    SourceKind::Archive => {
+                        ui.label("Path or URL:");
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.location)
+                                .hint_text("/path/to/skill.zip or https://...")
+                                .desired_width(380.0),
+                        );
+                        ui.end_row();
+                    }
+                }
+                ui.label("SHA-256:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.checksum)
+                        .hint_text("expected checksum (optional)")
+                        .desired_width(380.0),
+                );
+                ui.end_row();
+            });
+
+        let hint = match self.source_kind {
+            SourceKind::Git => "The checksum covers the skill's files (as shown after install).",
+            SourceKind::Archive => "The checksum covers the archive file.",
+        };
+        ui.label(RichText::new(hint).small().weak());
+
+        let can_install = self.busy.is_none() && !self.location.trim().is_empty();
+        if ui
+            .add_enabled(can_install, egui::Button::new("Install"))
+            .clicked()
+        {
+            let location = self.location.trim().to_string();
+            let source = match self.source_kind {
+                SourceKind::Git => SkillSource::Git {
+                    url: location,
+                    reference: Some(self.reference.trim().to_string()).filter(|r| !r.is_empty()),
+                },
+                SourceKind::Archive => SkillSource::Archive { location },
+            };
+            let request = InstallRequest {
+                source,
+                expected_sha256: Some(self.checksum.trim().to_string()).filter(|c| !c.is_empty()),
+            };
+            self.run(
+                format!("Installing from {}", request.source.describe()),
+                move |installer| {
+                    let skill = installer.install(&request)?;
+                    Ok(format!("Installed skill '{}'", skill.name))
+                },
+            );
+        }
+    }
+
+    fn render_skill_list(&mut self, ui: &mut Ui) {
+        if self.skills.is_empty() && self.installed.is_empty() {
+            ui.label(RichText::new("No skills found").weak());
+            return;
+        }
+
+        let mut action: Option<(&'static str, String)> = None;
+        egui::Grid::new("skills_list")
+            .striped(true)
+            .num_columns(4)
+            .show(ui, |ui| {
+                ui.label(RichText::new("Skill").strong());
+                ui.label(RichText::new("Version").strong());
+                ui.label(RichText::new("Source").strong());
+                ui.label("");
+                ui.end_row();
+
+                for skill in &self.skills {
+                    let installed = self.installed.iter().find(|i| i.name == skill.name);
+                    ui.label(&skill.name).on_hover_text(&skill.description);
+                    match installed {
+                        Some(installed) => {
+                            ui.label(installed.version.as_deref().unwrap_or("-"));
+                            let source = ui.label(installed.source.describe());
+                            source.on_hover_text(format!(
+                                "Commit: {}\nSHA-256: {}\nInstalled: {}",
+                                installed.commit.as_deref().unwrap_or("-"),
+                                installed.content_sha256,
+                                installed
+                                    .installed_at
+                                    .with_timezone(&chrono::Local)
+                                    .format("%Y-%m-%d %H:%M")
+                            ));
+                            ui.add_enabled_ui(self.busy.is_none(), |ui| {
+                                ui.horizontal(|ui| {
+                                    if ui.small_button("Update").clicked() {
+                                        action = Some(("update", skill.name.clone()));
+                                    }
+                                    if ui.small_button("Verify").clicked() {
+                                        action = Some(("verify", skill.name.clone()));
+                                    }
+                                    if ui.small_button("Remove").clicked() {
+                                        action = Some(("remove", skill.name.clone()));
+                                    }
+                                });
+                            });
+                        }
+                        None => {
+                            ui.label("-");
+                            ui.label(RichText::new("local").weak())
+                                .on_hover_text(skill.directory_path.display().to_string());
+                            ui.label("");
+                        }
+                    }
+                    ui.end_row();
+                }
+
+                // Installed but not discovered (e.g. SKILL.md deleted by hand)
+                for installed in &self.installed {
+                    if self.skills.iter().any(|s| s.name == installed.name) {
+                        continue;
+                    }
+                    ui.label(RichText::new(&installed.name).color(Color32::from_rgb(220, 160, 60)))
+                        .on_hover_text("Installed but not found by skill discovery");
+                    ui.label(installed.version.as_deref().unwrap_or("-"));
+                    ui.label(installed.source.describe());
+                    ui.add_enabled_ui(self.busy.is_none(), |ui| {
+                        ui.horizontal(|ui| {
+                            if ui.small_button("Update").clicked() {
+                                action = Some(("update", installed.name.clone()));
+                            }
+                            if ui.small_button("Remove").clicked() {
+                                action = Some(("remove", installed.name.clone()));
+                            }
+                        });
+                    });
+                    ui.end_row();
+                }
+            });
+
+        match action {
+            Some(("update", name)) => self.run(format!("Updating '{}'", name), move |installer| {
+                let skill = installer.update(&name)?;
+                Ok(format!(
+                    "Updated '{}' to {}",
+                    skill.name,
+                    skill.version.as_deref().unwrap_or("latest")
+                ))
+            }),
+            Some(("verify", name)) => self.run(format!("Verifying '{}'", name), move |installer| {
+                if installer.verify(&name)? {
+                    Ok(format!("'{}' matches its install checksum", name))
+                } else {
+                    anyhow::bail!("'{}' was modified since it was installed", name)
+                }
+            }),
+            Some(("remove", name)) => self.run(format!("Removing '{}'", name), move |installer| {
+                installer.remove(&name)?;
+                Ok(format!("Removed '{}'", name))
+            }),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,794 @@
+//! SQS Queue Inspector Window
+//!
+//! Shows a queue's depth, dead-letter linkage, and age of its oldest message,
+//! and lets the user peek at waiting messages. Peeks use a visibility timeout
+//! of 0 so consumers are not held up, but they still add to each message's
+//! receive count. Purge and dead-letter redrive ask for confirmation first;
+//! purge needs the queue name typed.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::sqs_messages::{
+    queue_name_from_url, MoveTaskStatus, PeekResult, PeekedMessage, QueueDetails,
+    SqsMessagesClient, DEFAULT_PEEK_COUNT, MAX_PEEK_COUNT,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Parameters for showing the queue inspector
+#[derive(Clone)]
+pub struct SqsQueueShowParams {
+    pub queue_name: String,
+    pub queue_url: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Results from background threads
+enum InspectorEvent {
+    DetailsLoaded(Result<QueueDetails, String>),
+    MessagesPeeked(Result<PeekResult, String>),
+    MoveTasksLoaded(Result<Vec<MoveTaskStatus>, String>),
+    /// Purge or redrive finished; Ok carries the message to show
+    ActionFinished(Result<String, String>),
+}
+
+/// Mutation waiting for the user to confirm
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PendingAction {
+    Purge,
+    Redrive,
+}
+
+pub struct SqsQueueWindow {
+    pub open: bool,
+    // Display parameters
+    queue_name: String,
+    queue_url: String,
+    account_id: String,
+    region: String,
+
+    // Queue
+    details: Option<QueueDetails>,
+    loading: bool,
+    error_message: Option<String>,
+    move_tasks: Vec<MoveTaskStatus>,
+
+    // Peek
+    peek_count: usize,
+    peeking: bool,
+    peek: Option<PeekResult>,
+    selected_message: Option<String>,
+
+    // Purge and redrive
+    pending_action: Option<PendingAction>,
+    confirmation: String,
+    action_running: bool,
+    action_result: Option<Result<String, String>>,
+
+    // Services
+    client: Arc<SqsMessagesClient>,
+
+    // Channel for results from background threads.
+    // Replaced when the window opens on a queue so results for another queue are dropped.
+    sender: mpsc::Sender<InspectorEvent>,
+    receiver: mpsc::Receiver<InspectorEvent>,
+}
+
+impl SqsQueueWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            queue_name: String::new(),
+            queue_url: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            details: None,
+            loading: false,
+            error_message: None,
+            move_tasks: Vec::new(),
+            peek_count: DEFAULT_PEEK_COUNT,
+            peeking: false,
+            peek: None,
+            selected_message: None,
+            pending_action: None,
+            confirmation: String::new(),
+            action_running: false,
+            action_result: None,
+            client: Arc::new(SqsMessagesClient::new(credential_coordinator)),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on a queue and load its attributes
+    pub fn open_for_queue(&mut self, params: SqsQueueShowParams) {
+        let (sender, receiver) = mpsc::channel();
+        self.sender = sender;
+        self.receiver = receiver;
+        self.queue_name = params.queue_name;
+        self.queue_url = params.queue_url;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.details = None;
+        self.move_tasks.clear();
+        self.peeking = false;
+        self.peek = None;
+        self.selected_message = None;
+        self.pending_action = None;
+        self.confirmation.clear();
+        self.action_running = false;
+        self.action_result = None;
+        self.open = true;
+        self.load_details();
+    }
+
+    fn load_details(&mut self) {
+        self.loading = true;
+        self.error_message = None;
+        let (account_id, region, queue_url) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.queue_url.clone(),
+        );
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .get_queue_details(&account_id, &region, &queue_url)
+                .await;
+            if let Err(e) = &result {
+                log::error!("Failed to load queue details of {}: {}", queue_url, e);
+            }
+            InspectorEvent::DetailsLoaded(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    fn load_move_tasks(&self, dead_letter_queue_arn: String) {
+        let (account_id, region) = (self.account_id.clone(), self.region.clone());
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .list_move_tasks(&account_id, &region, &dead_letter_queue_arn)
+                .await
+                .map_err(|e| format!("{:#}", e));
+            InspectorEvent::MoveTasksLoaded(result)
+        });
+    }
+
+    fn peek_messages(&mut self) {
+        self.peeking = true;
+        let (account_id, region, queue_url, count) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.queue_url.clone(),
+            self.peek_count,
+        );
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .peek_messages(&account_id, &region, &queue_url, count)
+                .await
+                .map_err(|e| {
+                    log::error!("Failed to peek messages of {}: {}", queue_url, e);
+                    format!("{:#}", e)
+                });
+            InspectorEvent::MessagesPeeked(result)
+        });
+    }
+
+    fn run_action(&mut self, action: PendingAction) {
+        self.pending_action = None;
+        self.confirmation.clear();
+        self.action_running = true;
+        self.action_result = None;
+        let (account_id, region, queue_url) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.queue_url.clone(),
+        );
+        let queue_arn = self
+            .details
+            .as_ref()
+            .and_then(|details| details.queue_arn.clone())
+            .unwrap_or_default();
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = match action {
+                PendingAction::Purge => client
+                    .purge_queue(&account_id, &region, &queue_url)
+                    .await
+                    .map(|_| "Purge started; it can take up to 60 seconds".to_string()),
+                PendingAction::Redrive => client
+                    .start_redrive(&account_id, &region, &queue_arn)
+                    .await
+                    .map(|_| "Redrive started".to_string()),
+            };
+            if let Err(e) = &result {
+                log::error!("Failed to {:?} {}: {}", action, queue_url, e);
+            }
+            InspectorEvent::ActionFinished(result.map_err(|e| format!("{:#}", e)))
+        });
+    }
+
+    /// Poll for results from background threads
+    fn poll_events(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                InspectorEvent::DetailsLoaded(Ok(details)) => {
+                    self.loading = false;
+                    if details.is_dead_letter_queue() {
+                        if let Some(arn) = &details.queue_arn {
+                            self.load_move_tasks(arn.clone());
+                        }
+                    }
+                    self.details = Some(details);
+                }
+                InspectorEvent::DetailsLoaded(Err(e)) => {
+                    self.loading = false;
+                    self.error_message = Some(e);
+                }
+                InspectorEvent::MessagesPeeked(Ok(peek)) => {
+                    self.peeking = false;
+                    if !self.selected_message.as_ref().is_some_and(|id| {
+                        peek.messages
+                            .iter()
+                            .any(|message| &message.message_id == id)
+                    }) {
+                        self.selected_message = peek
+                            .messages
+                            .first()
+                            .map(|message| message.message_id.clone());
+                    }
+                    self.peek = Some(peek);
+                }
+                InspectorEvent::MessagesPeeked(Err(e)) => {
+                    self.peeking = false;
+                    self.error_message = Some(e);
+                }
+                InspectorEvent::MoveTasksLoaded(Ok(tasks)) => {
+                    self.move_tasks = tasks;
+                }
+                InspectorEvent::MoveTasksLoaded(Err(e)) => {
+                    log::warn!("Failed to list redrive tasks: {}", e);
+                }
+                InspectorEvent::ActionFinished(result) => {
+                    self.action_running = false;
+                    let succeeded = result.is_ok();
+                    self.action_result = Some(result);
+                    if succeeded {
+                        self.peek = None;
+                        self.selected_message = None;
+                        self.load_details();
+                    }
+                }
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_events();
+
+        // Request continuous repaint while waiting to show spinners
+        if self.loading || self.peeking || self.action_running {
+            ctx.request_repaint();
+        }
+
+        let title = format!("Queue: {}", self.queue_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "sqs_queue_window",
+                &self.account_id,
+                &self.region,
+                &self.queue_url,
+            )))
+            .open(&mut is_open)
+            .default_size([820.0, 640.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        self.show_confirmation(ctx);
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Queue:").strong());
+            ui.label(&self.queue_name);
+            ui.label(RichText::new(format!("{} {}", self.account_id, self.region)).weak());
+            if ui
+                .add_enabled(!self.loading, egui::Button::new("Refresh"))
+                .clicked()
+            {
+                self.load_details();
+            }
+            if self.loading {
+                ui.spinner();
+            }
+        });
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::from_rgb(255, 100, 100), error);
+        }
+        ui.separator();
+
+        if let Some(details) = &self.details {
+            render_details(ui, details);
+            if !self.move_tasks.is_empty() {
+                ui.add_space(4.0);
+                render_move_tasks(ui, &self.move_tasks);
+            }
+        }
+
+        if !crate::app::capabilities::READ_ONLY {
+            ui.add_space(4.0);
+            self.render_actions(ui);
+        }
+        ui.separator();
+
+        self.render_peek(ui);
+    }
+
+    fn render_actions(&mut self, ui: &mut Ui) {
+        let is_dead_letter_queue = self
+            .details
+            .as_ref()
+            .is_some_and(|details| details.is_dead_letter_queue() && details.queue_arn.is_some());
+        ui.horizontal(|ui| {
+            let idle = !self.action_running && self.details.is_some();
+            if ui
+                .add_enabled(idle, egui::Button::new("Purge..."))
+                .on_hover_text("Delete every message in the queue")
+                .clicked()
+            {
+                self.pending_action = Some(PendingAction::Purge);
+                self.confirmation.clear();
+            }
+            if ui
+                .add_enabled(
+                    idle && is_dead_letter_queue,
+                    egui::Button::new("Redrive to Source..."),
+                )
+                .on_hover_text("Move messages back to the queues they came from")
+                .on_disabled_hover_text("Only dead-letter queues can be redriven")
+                .clicked()
+            {
+                self.pending_action = Some(PendingAction::Redrive);
+            }
+            if self.action_running {
+                ui.spinner();
+            }
+            match &self.action_result {
+                Some(Ok(message)) => {
+                    ui.colored_label(Color32::from_rgb(100, 200, 100), message);
+                }
+                Some(Err(e)) => {
+                    ui.colored_label(Color32::from_rgb(255, 100, 100), e);
+                }
+                None => {}
+            }
+        });
+    }
+
+    fn render_peek(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Messages").strong());
+            ui.add(
+                egui::DragValue::new(&mut self.peek_count)
+                    .range(1..=MAX_PEEK_COUNT)
+                    .suffix(" messages"),
+            );
+            let dead_letters = self
+                .details
+                .as_ref()
+                .and_then(|details| details.redrive_policy.as_ref())
+                .is_some_and(|policy| policy.peek_dead_letters());
+            let peek_button = ui
+                .add_enabled(
+                    !self.peeking && !dead_letters && !crate::app::capabilities::READ_ONLY,
+                    egui::Button::new("Peek"),
+                )
+                .on_disabled_hover_text(if dead_letters {
+                    "maxReceiveCount is 1, so peeked messages would move to the dead-letter queue"
+                } else {
+                    "Peeking counts as a receive, so the read-only build cannot peek"
+                });
+            if peek_button.clicked() {
+                self.peek_messages();
+            }
+            if self.peeking {
+                ui.spinner();
+            }
+        });
+        ui.label(
+            RichText::new(
+                "Peeked messages stay visible to consumers, but each peek counts toward maxReceiveCount.",
+            )
+            .small()
+            .weak(),
+        );
+
+        let Some(peek) = &self.peek else {
+            return;
+        };
+        if peek.messages.is_empty() {
+            ui.label(
+                "No messages received; SQS samples its servers, so peeking again may find some",
+            );
+            return;
+        }
+
+        let at_limit = peek.messages_at_receive_limit();
+        if at_limit > 0 {
+            ui.label(
+                RichText::new(format!(
+                    "Warning: {} of {} messages reached maxReceiveCount and move to the dead-letter queue on their next receive",
+                    at_limit,
+                    peek.messages.len()
+                ))
+                .color(Color32::from_rgb(255, 200, 100)),
+            );
+        }
+
+        let policy = peek.redrive_policy.as_ref();
+        let mut clicked = None;
+        ui.columns(2, |columns| {
+            egui::ScrollArea::vertical()
+                .id_salt("sqs_peek_list")
+                .auto_shrink([false, false])
+                .show(&mut columns[0], |ui| {
+                    for message in &peek.messages {
+                        let selected = self.selected_message.as_ref() == Some(&message.message_id);
+                        let mut text = RichText::new(format!(
+                            "{}  ({}x)",
+                            short_id(&message.message_id),
+                            message.receive_count
+                        ))
+                        .monospace();
+                        if message.at_receive_limit(policy) {
+                            text = text.color(Color32::from_rgb(255, 200, 100));
+                        }
+                        let response = ui.selectable_label(selected, text);
+                        let response = if message.at_receive_limit(policy) {
+                            response.on_hover_text(
+                                "At maxReceiveCount; the next receive moves it to the dead-letter queue",
+                            )
+                        } else {
+                            response
+                        };
+                        if response.clicked() {
+                            clicked = Some(message.message_id.clone());
+                        }
+                    }
+                });
+
+            let selected = self.selected_message.as_ref().and_then(|id| {
+                peek.messages
+                    .iter()
+                    .find(|message| &message.message_id == id)
+            });
+            egui::ScrollArea::vertical()
+                .id_salt("sqs_peek_detail")
+                .auto_shrink([false, false])
+                .show(&mut columns[1], |ui| {
+                    if let Some(message) = selected {
+                        render_message(ui, message);
+                    }
+                });
+        });
+        if clicked.is_some() {
+            self.selected_message = clicked;
+        }
+    }
+
+    /// Confirmation dialog for a pending purge or redrive
+    fn show_confirmation(&mut self, ctx: &Context) {
+        let Some(action) = self.pending_action else {
+            return;
+        };
+        let source_count = self
+            .details
+            .as_ref()
+            .map(|details| details.dead_letter_sources.len())
+            .unwrap_or_default();
+
+        let mut confirmed = false;
+        let mut cancelled = false;
+        let title = match action {
+            PendingAction::Purge => "Purge Queue",
+            PendingAction::Redrive => "Redrive Dead-Letter Queue",
+        };
+        egui::Window::new(title)
+            .id(egui::Id::new((
+                "sqs_queue_confirm",
+                &self.account_id,
+                &self.region,
+                &self.queue_url,
+            )))
+            .collapsible(false)
+            .resizable(false)
+            .order(egui::Order::Foreground)
+            .show(ctx, |ui| {
+                match action {
+                    PendingAction::Purge => {
+                        ui.label(
+                            RichText::new(format!(
+                                "Every message in {} will be deleted. This cannot be undone.",
+                                self.queue_name
+                            ))
+                            .color(Color32::from_rgb(255, 100, 100)),
+                        );
+                        ui.label(format!("Type {} to confirm:", self.queue_name));
+                        ui.text_edit_singleline(&mut self.confirmation);
+                    }
+                    PendingAction::Redrive => {
+                        ui.label(format!(
+                            "Messages in {} will be moved back to their {} source queue(s), \
+                             where consumers will process them again.",
+                            self.queue_name, source_count
+                        ));
+                    }
+                }
+                ui.horizontal(|ui| {
+                    let ready = action != PendingAction::Purge
+                        || self.confirmation.trim() == self.queue_name;
+                    let label = match action {
+                        PendingAction::Purge => "Purge",
+                        PendingAction::Redrive => "Redrive",
+                    };
+                    if ui.add_enabled(ready, egui::Button::new(label)).clicked() {
+                        confirmed = true;
+                    }
+                    if ui.button("Cancel").clicked() {
+                        cancelled = true;
+                    }
+                });
+            });
+
+        if confirmed {
+            self.run_action(action);
+        } else if cancelled {
+            self.pending_action = None;
+            self.confirmation.clear();
+        }
+    }
+}
+
+/// Depth, age of the oldest message, and dead-letter linkage
+fn render_details(ui: &mut Ui, details: &QueueDetails) {
+    egui::Grid::new("sqs_queue_details")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Visible:");
+            ui.label(details.visible_messages.to_string());
+            ui.end_row();
+            ui.label("In flight:");
+            ui.label(details.in_flight_messages.to_string());
+            ui.end_row();
+            ui.label("Delayed:");
+            ui.label(details.delayed_messages.to_string());
+            ui.end_row();
+            ui.label("Oldest message:");
+            ui.label(
+                details
+                    .oldest_message_age_seconds
+                    .map(format_age)
+                    .unwrap_or_else(|| "-".to_string()),
+            )
+            .on_hover_text(
+                "ApproximateAgeOfOldestMessage, latest datapoint in the last 15 minutes",
+            );
+            ui.end_row();
+            if let Some(timeout) = details.visibility_timeout_seconds {
+                ui.label("Visibility timeout:");
+                ui.label(format!("{} s", timeout));
+                ui.end_row();
+            }
+            if let Some(retention) = details.retention_period_seconds {
+                ui.label("Retention:");
+                ui.label(format_age(retention as f64));
+                ui.end_row();
+            }
+            if details.fifo {
+                ui.label("Type:");
+                ui.label("FIFO");
+                ui.end_row();
+            }
+            if let Some(policy) = &details.redrive_policy {
+                ui.label("Dead-letter queue:");
+                ui.label(format!(
+                    "{} after {} receives",
+                    arn_name(&policy.dead_letter_target_arn),
+                    policy.max_receive_count
+                ))
+                .on_hover_text(&policy.dead_letter_target_arn);
+                ui.end_row();
+            }
+            if details.is_dead_letter_queue() {
+                ui.label("Dead-letter for:");
+                ui.vertical(|ui| {
+                    for source in &details.dead_letter_sources {
+                        ui.label(queue_name_from_url(source)).on_hover_text(source);
+                    }
+                });
+                ui.end_row();
+            }
+        });
+}
+
+/// Recent redrive tasks of a dead-letter queue
+fn render_move_tasks(ui: &mut Ui, tasks: &[MoveTaskStatus]) {
+    ui.label(RichText::new("Redrive tasks").strong());
+    for task in tasks {
+        let progress = match task.to_move {
+            Some(total) => format!("{} of {} moved", task.moved, total),
+            None => format!("{} moved", task.moved),
+        };
+        let color = match task.status.as_str() {
+            "FAILED" => Color32::from_rgb(255, 100, 100),
+            "COMPLETED" => Color32::from_rgb(100, 200, 100),
+            _ => Color32::from_rgb(255, 200, 100),
+        };
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(&task.status).color(color));
+            ui.label(progress);
+            ui.label(RichText::new(format_timestamp(task.started_timestamp)).weak());
+            if let Some(reason) = &task.failure_reason {
+                ui.label(RichText::new(reason).color(Color32::from_rgb(255, 100, 100)));
+            }
+        });
+    }
+}
+
+/// Body and attributes of a peeked message
+fn render_message(ui: &mut Ui, message: &PeekedMessage) {
+    egui::Grid::new("sqs_message_details")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label("Message ID:");
+            ui.label(RichText::new(&message.message_id).monospace());
+            ui.end_row();
+            ui.label("Receive count:");
+            ui.label(message.receive_count.to_string());
+            ui.end_row();
+            if let Some(sent) = message.sent_timestamp {
+                ui.label("Sent:");
+                ui.label(format_timestamp(sent));
+                ui.end_row();
+            }
+            if let Some(first) = message.first_receive_timestamp {
+                ui.label("First received:");
+                ui.label(format_timestamp(first));
+                ui.end_row();
+            }
+            if let Some(group) = &message.message_group_id {
+                ui.label("Group:");
+                ui.label(group);
+                ui.end_row();
+            }
+            for (name, value) in &message.message_attributes {
+                ui.label(format!("{}:", name));
+                ui.label(value);
+                ui.end_row();
+            }
+        });
+
+    ui.add_space(6.0);
+    ui.label(RichText::new("Body").strong());
+    let mut body = pretty_body(&message.body);
+    ui.add(
+        egui::TextEdit::multiline(&mut body)
+            .code_editor()
+            .desired_rows(10)
+            .desired_width(f32::INFINITY),
+    );
+}
+
+/// Body pretty printed when it is JSON, as received otherwise
+fn pretty_body(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| body.to_string())
+}
+
+/// Compact age, e.g. "45s", "12m 5s", "3h 20m", "4d 2h"
+fn format_age(seconds: f64) -> String {
+    let seconds = seconds.max(0.0) as u64;
+    match seconds {
+        0..=59 => format!("{}s", seconds),
+        60..=3599 => format!("{}m {}s", seconds / 60, seconds % 60),
+        3600..=86399 => format!("{}h {}m", seconds / 3600, seconds % 3600 / 60),
+        _ => format!("{}d {}h", seconds / 86400, seconds % 86400 / 3600),
+    }
+}
+
+fn format_timestamp(millis: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(millis)
+        .map(|time| time.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| millis.to_string())
+}
+
+/// Resource name at the end of an ARN
+fn arn_name(arn: &str) -> &str {
+    arn.rsplit(':').next().unwrap_or(arn)
+}
+
+/// First block of a message ID, enough to tell messages apart in the list
+fn short_id(message_id: &str) -> &str {
+    message_id.split('-').next().unwrap_or(message_id)
+}
+
+impl FocusableWindow for SqsQueueWindow {
+    type ShowParams = SqsQueueShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "sqs_queue_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Queue: {}", self.queue_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the queue first
+        self.open_for_queue(params);
+
+        // Then show with focus
+        SqsQueueWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_age() {
+        assert_eq!(format_age(42.0), "42s");
+        assert_eq!(format_age(725.0), "12m 5s");
+        assert_eq!(format_age(12_000.0), "3h 20m");
+        assert_eq!(format_age(345_600.0), "4d 0h");
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!(
+            arn_name("arn:aws:sqs:us-east-1:123456789012:orders-dlq"),
+            "orders-dlq"
+        );
+        assert_eq!(short_id("5fea7756-0ea4-451a-a703-a558b933e274"), "5fea7756");
+    }
+}
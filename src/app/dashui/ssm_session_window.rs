@@ -0,0 +1,834 @@
+//! SSM Session Window
+//!
+//! An interactive shell on an EC2 instance through Session Manager. The
+//! session is started with StartSession and its data channel is run by the
+//! `session-manager-plugin`; keystrokes are sent to the plugin and its output
+//! is rendered in a scrolling monospace terminal. The terminal handles the
+//! line-oriented subset of VT100 that shells use (carriage return, backspace,
+//! erase in line, clear screen) and drops colors and other escape sequences.
+//!
+//! Closing the window stops the plugin and terminates the session. Session
+//! start, end, and failure are written to the session audit log.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use crate::app::data_plane::ssm_session::{
+    find_session_manager_plugin, PluginOutput, PluginSession, SessionAuditEntry, SessionAuditEvent,
+    SessionAuditLog, SessionStart, SsmSessionClient,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use chrono::{DateTime, Utc};
+use eframe::egui;
+use egui::{Color32, Context, Key, Modifiers, RichText, Ui};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Lines kept in the scrollback
+const MAX_SCROLLBACK_LINES: usize = 5000;
+
+/// How often to poll the plugin for output while connected
+const OUTPUT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+const PLUGIN_INSTALL_URL: &str = "https://docs.aws.amazon.com/systems-manager/latest/userguide/session-manager-working-with-install-plugin.html";
+
+/// Distinguishes windows for the same instance
+static NEXT_WINDOW_SEQUENCE: AtomicU64 = AtomicU64::new(1);
+
+/// Parameters for showing a session window
+#[derive(Clone)]
+pub struct SsmSessionShowParams {
+    pub instance_id: String,
+    pub instance_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Results from background threads
+enum SessionEvent {
+    Started(SessionStart),
+    StartFailed(String),
+}
+
+/// Where the window's session is in its lifecycle
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SessionStatus {
+    Idle,
+    Starting,
+    Connected,
+    Ended(String),
+    Failed(String),
+}
+
+/// Session that is open on both ends
+struct ActiveSession {
+    session_id: String,
+    plugin: PluginSession,
+    started_at: DateTime<Utc>,
+}
+
+pub struct SsmSessionWindow {
+    pub open: bool,
+    sequence: u64,
+    // Display parameters
+    instance_id: String,
+    instance_name: String,
+    account_id: String,
+    region: String,
+
+    // Session
+    status: SessionStatus,
+    session: Option<ActiveSession>,
+    terminal: TerminalBuffer,
+    focus_terminal: bool,
+
+    // Services
+    client: Arc<SsmSessionClient>,
+    audit_log: Option<SessionAuditLog>,
+
+    // Channel for results from background threads.
+    // Replaced when a session starts so results for an abandoned start are dropped.
+    sender: mpsc::Sender<SessionEvent>,
+    receiver: mpsc::Receiver<SessionEvent>,
+}
+
+impl SsmSessionWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        let audit_log = SessionAuditLog::new()
+            .map_err(|e| log::warn!("SSM session audit log unavailable: {}", e))
+            .ok();
+        Self {
+            open: false,
+            sequence: NEXT_WINDOW_SEQUENCE.fetch_add(1, Ordering::Relaxed),
+            instance_id: String::new(),
+            instance_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            status: SessionStatus::Idle,
+            session: None,
+            terminal: TerminalBuffer::default(),
+            focus_terminal: false,
+            client: Arc::new(SsmSessionClient::new(credential_coordinator)),
+            audit_log,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on an instance and start a session
+    pub fn open_for_instance(&mut self, params: SsmSessionShowParams) {
+        self.end_session("Window reopened on another instance");
+        self.instance_id = params.instance_id;
+        self.instance_name = params.instance_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.terminal = TerminalBuffer::default();
+        self.open = true;
+        self.connect();
+    }
+
+    fn connect(&mut self) {
+        if find_session_manager_plugin().is_none() {
+            self.status = SessionStatus::Failed(format!(
+                "session-manager-plugin was not found on PATH. Install it from {}",
+                PLUGIN_INSTALL_URL
+            ));
+            return;
+        }
+
+        let (sender, receiver) = mpsc::channel();
+        self.sender = sender;
+        self.receiver = receiver;
+        self.status = SessionStatus::Starting;
+
+        let client = Arc::clone(&self.client);
+        let sender = self.sender.clone();
+        let (account_id, region, instance_id) = (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.instance_id.clone(),
+        );
+        // Sends itself instead of using spawn_with_client: a failed send means
+        // the window closed while starting, so the new session is terminated
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
+            runtime.block_on(async move {
+                match client
+                    .start_session(&account_id, &region, &instance_id)
+                    .await
+                {
+                    Ok(session) => {
+                        let session_id = session.session_id.clone();
+                        // The window closed while the session was starting
+                        if sender.send(SessionEvent::Started(session)).is_err() {
+                            if let Err(e) = client
+                                .terminate_session(&account_id, &region, &session_id)
+                                .await
+                            {
+                                log::warn!("Failed to terminate abandoned session: {}", e);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        log::error!("Failed to start session on {}: {}", instance_id, e);
+                        let _ = sender.send(SessionEvent::StartFailed(format!("{:#}", e)));
+                    }
+                }
+            });
+        });
+    }
+
+    /// Stop the plugin, terminate the session, and record the end
+    fn end_session(&mut self, reason: &str) {
+        let Some(mut session) = self.session.take() else {
+            return;
+        };
+        session.plugin.kill();
+        self.terminate_in_background(session.session_id.clone());
+        self.record_audit(
+            SessionAuditEvent::Ended,
+            Some(session.session_id),
+            Some((Utc::now() - session.started_at).num_seconds()),
+            Some(reason.to_string()),
+        );
+        self.status = SessionStatus::Ended(reason.to_string());
+    }
+
+    fn terminate_in_background(&self, session_id: String) {
+        let client = Arc::clone(&self.client);
+        let (account_id, region) = (self.account_id.clone(), self.region.clone());
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
+            if let Err(e) =
+                runtime.block_on(client.terminate_session(&account_id, &region, &session_id))
+            {
+                log::warn!("{:#}", e);
+            }
+        });
+    }
+
+    fn record_audit(
+        &self,
+        event: SessionAuditEvent,
+        session_id: Option<String>,
+        duration_seconds: Option<i64>,
+        message: Option<String>,
+    ) {
+        let Some(audit_log) = &self.audit_log else {
+            return;
+        };
+        let entry = SessionAuditEntry {
+            timestamp: Utc::now(),
+            event,
+            session_id,
+            account_id: self.account_id.clone(),
+            region: self.region.clone(),
+            instance_id: self.instance_id.clone(),
+            duration_seconds,
+            message,
+        };
+        if let Err(e) = audit_log.record(&entry) {
+            log::warn!("Failed to write SSM session audit entry: {:#}", e);
+        }
+    }
+
+    /// Poll for results from background threads and output from the plugin
+    fn poll_events(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                SessionEvent::Started(start) => self.attach_plugin(start),
+                SessionEvent::StartFailed(e) => {
+                    self.record_audit(SessionAuditEvent::Failed, None, None, Some(e.clone()));
+                    self.status = SessionStatus::Failed(e);
+                }
+            }
+        }
+
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+        let mut exit_code = None;
+        for output in session.plugin.drain_output() {
+            match output {
+                PluginOutput::Data(bytes) => self.terminal.feed(&bytes),
+                PluginOutput::Exited(code) => exit_code = Some(code),
+            }
+        }
+        if let Some(code) = exit_code {
+            let reason = match code {
+                Some(0) => "Session ended".to_string(),
+                Some(code) => format!("session-manager-plugin exited with code {}", code),
+                None => "session-manager-plugin was stopped".to_string(),
+            };
+            self.end_session(&reason);
+        }
+    }
+
+    fn attach_plugin(&mut self, start: SessionStart) {
+        let spawned = find_session_manager_plugin()
+            .ok_or_else(|| anyhow::anyhow!("session-manager-plugin was not found"))
+            .and_then(|plugin| {
+                PluginSession::spawn(&plugin, &start, &self.region, &self.instance_id)
+            });
+        match spawned {
+            Ok(plugin) => {
+                self.record_audit(
+                    SessionAuditEvent::Started,
+                    Some(start.session_id.clone()),
+                    None,
+                    None,
+                );
+                self.session = Some(ActiveSession {
+                    session_id: start.session_id,
+                    plugin,
+                    started_at: Utc::now(),
+                });
+                self.status = SessionStatus::Connected;
+                self.focus_terminal = true;
+            }
+            Err(e) => {
+                log::error!("Failed to run session-manager-plugin: {:#}", e);
+                self.terminate_in_background(start.session_id.clone());
+                let message = format!("{:#}", e);
+                self.record_audit(
+                    SessionAuditEvent::Failed,
+                    Some(start.session_id),
+                    None,
+                    Some(message.clone()),
+                );
+                self.status = SessionStatus::Failed(message);
+            }
+        }
+    }
+
+    fn send_input(&mut self, bytes: &[u8]) {
+        let Some(session) = self.session.as_mut() else {
+            return;
+        };
+        if let Err(e) = session.plugin.write(bytes) {
+            log::warn!("{:#}", e);
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_events();
+
+        match self.status {
+            SessionStatus::Starting => ctx.request_repaint(),
+            SessionStatus::Connected => ctx.request_repaint_after(OUTPUT_POLL_INTERVAL),
+            _ => {}
+        }
+
+        let title = self.window_title();
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new(("ssm_session_window", self.sequence)))
+            .open(&mut is_open)
+            .default_size([820.0, 520.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+        if !self.open {
+            self.end_session("Closed by user");
+        }
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Instance:").strong());
+            ui.label(&self.instance_id);
+            if !self.instance_name.is_empty() && self.instance_name != self.instance_id {
+                ui.label(format!("({})", self.instance_name));
+            }
+            ui.label(RichText::new(format!("{} {}", self.account_id, self.region)).weak());
+        });
+
+        ui.horizontal(|ui| {
+            match &self.status {
+                SessionStatus::Idle => {}
+                SessionStatus::Starting => {
+                    ui.spinner();
+                    ui.label("Starting session...");
+                }
+                SessionStatus::Connected => {
+                    ui.colored_label(Color32::from_rgb(100, 200, 100), "Connected");
+                    if let Some(session) = &self.session {
+                        ui.label(RichText::new(&session.session_id).weak());
+                    }
+                }
+                SessionStatus::Ended(reason) => {
+                    ui.label(reason);
+                }
+                SessionStatus::Failed(e) => {
+                    ui.colored_label(Color32::from_rgb(255, 100, 100), e);
+                }
+            }
+
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if self.status == SessionStatus::Connected {
+                    if ui.button("Disconnect").clicked() {
+                        self.end_session("Disconnected by user");
+                    }
+                } else if ui
+                    .add_enabled(
+                        self.status != SessionStatus::Starting,
+                        egui::Button::new("Reconnect"),
+                    )
+                    .clicked()
+                {
+                    self.connect();
+                }
+                if ui.button("Clear").clicked() {
+                    self.terminal.clear();
+                }
+                if ui
+                    .button("Copy All")
+                    .on_hover_text("Copy the scrollback to the clipboard")
+                    .clicked()
+                {
+                    ui.ctx().copy_text(self.terminal.text());
+                }
+            });
+        });
+        ui.separator();
+
+        self.render_terminal(ui);
+    }
+
+    fn render_terminal(&mut self, ui: &mut Ui) {
+        let connected = self.status == SessionStatus::Connected;
+        let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+
+        let frame = egui::Frame::canvas(ui.style())
+            .fill(Color32::from_gray(18))
+            .inner_margin(4.0)
+            .show(ui, |ui| {
+                ui.set_min_size(ui.available_size());
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, false])
+                    .stick_to_bottom(true)
+                    .show_rows(ui, row_height, self.terminal.line_count(), |ui, rows| {
+                        let cursor_row = self.terminal.line_count().saturating_sub(1);
+                        for row in rows {
+                            let mut line = self.terminal.line(row);
+                            if connected && row == cursor_row {
+                                line = self.terminal.line_with_cursor(row);
+                            }
+                            ui.label(
+                                RichText::new(line)
+                                    .monospace()
+                                    .color(Color32::from_gray(220)),
+                            );
+                        }
+                    });
+            });
+
+        let response = frame.response.interact(egui::Sense::click());
+        if response.clicked() || std::mem::take(&mut self.focus_terminal) {
+            response.request_focus();
+        }
+        if !connected || !response.has_focus() {
+            return;
+        }
+
+        // Keep Tab, arrows, and Escape in the terminal instead of moving focus
+        ui.memory_mut(|memory| {
+            memory.set_focus_lock_filter(
+                response.id,
+                egui::EventFilter {
+                    tab: true,
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    escape: true,
+                },
+            )
+        });
+
+        let events = ui.input(|input| input.events.clone());
+        for event in events {
+            if let Some(bytes) = event_bytes(&event) {
+                self.send_input(&bytes);
+            }
+        }
+    }
+
+    fn window_title(&self) -> String {
+        if self.instance_name.is_empty() || self.instance_name == self.instance_id {
+            format!("Session: {}", self.instance_id)
+        } else {
+            format!("Session: {} ({})", self.instance_name, self.instance_id)
+        }
+    }
+}
+
+impl Drop for SsmSessionWindow {
+    fn drop(&mut self) {
+        self.end_session("Application closed");
+    }
+}
+
+/// Bytes to send to the remote shell for an input event
+fn event_bytes(event: &egui::Event) -> Option<Vec<u8>> {
+    match event {
+        egui::Event::Text(text) => Some(text.as_bytes().to_vec()),
+        egui::Event::Paste(text) => {
+            Some(text.replace("\r\n", "\r").replace('\n', "\r").into_bytes())
+        }
+        egui::Event::Key {
+            key,
+            pressed: true,
+            modifiers,
+            ..
+        } => key_bytes(*key, *modifiers),
+        _ => None,
+    }
+}
+
+/// Terminal input for a key press; printable characters arrive as text events
+fn key_bytes(key: Key, modifiers: Modifiers) -> Option<Vec<u8>> {
+    let sequence: &[u8] = match key {
+        Key::Enter => b"\r",
+        Key::Backspace => b"\x7f",
+        Key::Tab => b"\t",
+        Key::Escape => b"\x1b",
+        Key::ArrowUp => b"\x1b[A",
+        Key::ArrowDown => b"\x1b[B",
+        Key::ArrowRight => b"\x1b[C",
+        Key::ArrowLeft => b"\x1b[D",
+        Key::Home => b"\x1b[H",
+        Key::End => b"\x1b[F",
+        Key::Delete => b"\x1b[3~",
+        Key::PageUp => b"\x1b[5~",
+        Key::PageDown => b"\x1b[6~",
+        // Ctrl+V stays a paste
+        _ if modifiers.ctrl && !(key == Key::V && modifiers.command) => {
+            let name = key.name();
+            let letter = name.as_bytes().first().filter(|_| name.len() == 1)?;
+            return letter.is_ascii_uppercase().then(|| vec![letter - b'A' + 1]);
+        }
+        _ => return None,
+    };
+    Some(sequence.to_vec())
+}
+
+/// Escape sequence parsing state
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+enum EscapeState {
+    #[default]
+    Ground,
+    /// After ESC
+    Escape,
+    /// After ESC ( or ESC ), which select a character set
+    Charset,
+    /// Inside ESC [ ... with the parameters so far
+    Csi(String),
+    /// Inside ESC ] ... (window title and similar)
+    Osc,
+    /// ESC seen inside an OSC, possibly starting the terminator
+    OscEscape,
+}
+
+/// Scrollback of a line-oriented terminal
+///
+/// The cursor is always on the last line; sequences that move it to other
+/// lines (full-screen programs) are ignored.
+#[derive(Debug, Clone)]
+struct TerminalBuffer {
+    lines: VecDeque<Vec<char>>,
+    /// Column of the cursor on the last line
+    cursor: usize,
+    state: EscapeState,
+    /// Trailing bytes of a character split across reads
+    partial_utf8: Vec<u8>,
+}
+
+impl Default for TerminalBuffer {
+    fn default() -> Self {
+        Self {
+            lines: VecDeque::from([Vec::new()]),
+            cursor: 0,
+            state: EscapeState::Ground,
+            partial_utf8: Vec::new(),
+        }
+    }
+}
+
+impl TerminalBuffer {
+    fn feed(&mut self, bytes: &[u8]) {
+        let mut pending = std::mem::take(&mut self.partial_utf8);
+        pending.extend_from_slice(bytes);
+        let mut rest = pending.as_slice();
+        loop {
+            match std::str::from_utf8(rest) {
+                Ok(text) => {
+                    text.chars().for_each(|c| self.process(c));
+                    break;
+                }
+                Err(e) => {
+                    let (valid, invalid) = rest.split_at(e.valid_up_to());
+                    std::str::from_utf8(valid)
+                        .unwrap_or_default()
+                        .chars()
+                        .for_each(|c| self.process(c));
+                    match e.error_len() {
+                        Some(len) => {
+                            self.process(char::REPLACEMENT_CHARACTER);
+                            rest = &invalid[len..];
+                        }
+                        None => {
+                            self.partial_utf8 = invalid.to_vec();
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn process(&mut self, c: char) {
+        match std::mem::take(&mut self.state) {
+            EscapeState::Ground => match c {
+                '\x1b' => self.state = EscapeState::Escape,
+                '\r' => self.cursor = 0,
+                '\n' => self.new_line(),
+                '\x08' => self.cursor = self.cursor.saturating_sub(1),
+                '\t' => {
+                    let spaces = 8 - self.cursor % 8;
+                    (0..spaces).for_each(|_| self.put(' '));
+                }
+                c if c.is_control() => {}
+                c => self.put(c),
+            },
+            EscapeState::Escape => match c {
+                '[' => self.state = EscapeState::Csi(String::new()),
+                ']' => self.state = EscapeState::Osc,
+                '(' | ')' => self.state = EscapeState::Charset,
+                _ => {}
+            },
+            EscapeState::Charset => {}
+            EscapeState::Csi(mut params) => {
+                if ('\x40'..='\x7e').contains(&c) {
+                    self.apply_csi(&params, c);
+                } else {
+                    params.push(c);
+                    self.state = EscapeState::Csi(params);
+                }
+            }
+            EscapeState::Osc => match c {
+                '\x07' => {}
+                '\x1b' => self.state = EscapeState::OscEscape,
+                _ => self.state = EscapeState::Osc,
+            },
+            EscapeState::OscEscape => {
+                if c != '\\' {
+                    self.state = EscapeState::Osc;
+                }
+            }
+        }
+    }
+
+    fn apply_csi(&mut self, params: &str, command: char) {
+        let first = params
+            .trim_start_matches('?')
+            .split(';')
+            .next()
+            .and_then(|n| n.parse::<usize>().ok());
+        let count = first.unwrap_or(1).max(1);
+        let cursor = self.cursor;
+        let line = self.lines.back_mut().expect("terminal always has a line");
+        match command {
+            // Erase in line
+            'K' => match first.unwrap_or(0) {
+                0 => line.truncate(cursor),
+                1 => line.iter_mut().take(cursor + 1).for_each(|c| *c = ' '),
+                _ => line.clear(),
+            },
+            // Erase in display; only a full clear is meaningful here
+            'J' if matches!(first, Some(2) | Some(3)) => self.clear(),
+            // Delete characters
+            'P' => {
+                let end = (cursor + count).min(line.len());
+                if cursor < end {
+                    line.drain(cursor..end);
+                }
+            }
+            // Insert blanks
+            '@' if cursor <= line.len() => {
+                line.splice(cursor..cursor, std::iter::repeat(' ').take(count));
+            }
+            'C' => self.cursor += count,
+            'D' => self.cursor = cursor.saturating_sub(count),
+            'G' => self.cursor = count - 1,
+            // Colors, modes, and vertical movement are ignored
+            _ => {}
+        }
+    }
+
+    fn put(&mut self, c: char) {
+        let cursor = self.cursor;
+        let line = self.lines.back_mut().expect("terminal always has a line");
+        if line.len() < cursor {
+            line.resize(cursor, ' ');
+        }
+        if cursor < line.len() {
+            line[cursor] = c;
+        } else {
+            line.push(c);
+        }
+        self.cursor += 1;
+    }
+
+    fn new_line(&mut self) {
+        self.lines.push_back(Vec::new());
+        self.cursor = 0;
+        while self.lines.len() > MAX_SCROLLBACK_LINES {
+            self.lines.pop_front();
+        }
+    }
+
+    fn clear(&mut self) {
+        self.lines = VecDeque::from([Vec::new()]);
+        self.cursor = 0;
+    }
+
+    fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    fn line(&self, row: usize) -> String {
+        self.lines
+            .get(row)
+            .map(|line| line.iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// The line with a block cursor drawn at the cursor column
+    fn line_with_cursor(&self, row: usize) -> String {
+        let mut line: Vec<char> = self.lines.get(row).cloned().unwrap_or_default();
+        if line.len() <= self.cursor {
+            line.resize(self.cursor + 1, ' ');
+        }
+        line[self.cursor] = '\u{2588}';
+        line.into_iter().collect()
+    }
+
+    fn text(&self) -> String {
+        (0..self.lines.len())
+            .map(|row| self.line(row))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl FocusableWindow for SsmSessionWindow {
+    type ShowParams = SsmSessionShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "ssm_session_window"
+    }
+
+    fn window_title(&self) -> String {
+        SsmSessionWindow::window_title(self)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the instance first
+        self.open_for_instance(params);
+
+        // Then show with focus
+        SsmSessionWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terminal_line_editing() {
+        let mut terminal = TerminalBuffer::default();
+        terminal.feed(b"progress 10%\rprogress 100%\r\n");
+        terminal.feed(b"sh-5.2$ lss\x08 \x08\r\n");
+        terminal.feed(b"abcdef\x1b[3D\x1b[K\r\n");
+        terminal.feed(b"tab\there");
+        assert_eq!(
+            terminal.text(),
+            "progress 100%\nsh-5.2$ ls \nabc\ntab     here"
+        );
+        assert_eq!(terminal.cursor, 12);
+
+        terminal.feed(b"\x1b[H\x1b[2J$ ");
+        assert_eq!(terminal.text(), "$ ");
+    }
+
+    #[test]
+    fn test_terminal_strips_escape_sequences() {
+        let mut terminal = TerminalBuffer::default();
+        terminal.feed(b"\x1b]0;ssm-user@ip-10-0-0-5\x07\x1b[?2004h");
+        terminal.feed(b"\x1b[01;32mgreen\x1b[0m \x1b(Bplain\x1b]2;title\x1b\\!");
+        assert_eq!(terminal.text(), "green plain!");
+    }
+
+    #[test]
+    fn test_terminal_split_utf8() {
+        let mut terminal = TerminalBuffer::default();
+        let text = "caf\u{e9} \u{2713}".as_bytes();
+        terminal.feed(&text[..4]);
+        terminal.feed(&text[4..7]);
+        terminal.feed(&text[7..]);
+        terminal.feed(b"\xff");
+        assert_eq!(terminal.text(), "caf\u{e9} \u{2713}\u{fffd}");
+    }
+
+    #[test]
+    fn test_key_bytes() {
+        assert_eq!(key_bytes(Key::Enter, Modifiers::NONE), Some(b"\r".to_vec()));
+        assert_eq!(key_bytes(Key::Backspace, Modifiers::NONE), Some(vec![0x7f]));
+        assert_eq!(
+            key_bytes(Key::ArrowUp, Modifiers::NONE),
+            Some(b"\x1b[A".to_vec())
+        );
+        assert_eq!(key_bytes(Key::C, Modifiers::CTRL), Some(vec![0x03]));
+        assert_eq!(key_bytes(Key::D, Modifiers::CTRL), Some(vec![0x04]));
+        // Letters without Ctrl arrive as text events
+        assert_eq!(key_bytes(Key::C, Modifiers::NONE), None);
+        assert_eq!(
+            event_bytes(&egui::Event::Paste("ls\nuptime\n".to_string())),
+            Some(b"ls\ruptime\r".to_vec())
+        );
+    }
+}
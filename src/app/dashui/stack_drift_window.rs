@@ -0,0 +1,451 @@
+//! CloudFormation Stack Drift Window
+//!
+//! Runs drift detection on a stack and shows each resource's drift status with
+//! a structured diff of the template's expected properties against the live
+//! resource.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::config_history_window::render_change;
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::cloudformation_manager::{
+    DeploymentManager, ResourceDrift, ResourceDriftStatus, StackDriftReport,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Parameters for showing the Stack Drift window
+#[derive(Clone)]
+pub struct StackDriftShowParams {
+    pub stack_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Result from background drift detection
+type DriftResult = Result<StackDriftReport, String>;
+
+fn status_color(ui: &Ui, status: &ResourceDriftStatus) -> Color32 {
+    match status {
+        ResourceDriftStatus::Deleted => ui.visuals().error_fg_color,
+        ResourceDriftStatus::Modified => ui.visuals().warn_fg_color,
+        ResourceDriftStatus::InSync => Color32::from_rgb(80, 180, 80),
+        ResourceDriftStatus::NotChecked | ResourceDriftStatus::Unknown(_) => {
+            ui.visuals().weak_text_color()
+        }
+    }
+}
+
+pub struct StackDriftWindow {
+    pub open: bool,
+    // Display parameters
+    stack_name: String,
+    account_id: String,
+    region: String,
+
+    // State
+    report: Option<StackDriftReport>,
+    /// Logical ID of the resource being inspected
+    selected: Option<String>,
+    show_in_sync: bool,
+    show_properties: bool,
+    detecting: bool,
+    error_message: Option<String>,
+
+    // Services
+    manager: Arc<DeploymentManager>,
+
+    // Channel for receiving drift results from background thread
+    drift_receiver: mpsc::Receiver<DriftResult>,
+    drift_sender: mpsc::Sender<DriftResult>,
+}
+
+impl StackDriftWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (drift_sender, drift_receiver) = mpsc::channel();
+
+        Self {
+            open: false,
+            stack_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            report: None,
+            selected: None,
+            show_in_sync: false,
+            show_properties: false,
+            detecting: false,
+            error_message: None,
+            manager: Arc::new(DeploymentManager::new(credential_coordinator)),
+            drift_receiver,
+            drift_sender,
+        }
+    }
+
+    /// Open the window and start drift detection for a stack
+    pub fn open_for_stack(&mut self, params: StackDriftShowParams) {
+        self.stack_name = params.stack_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.report = None;
+        self.selected = None;
+        self.open = true;
+
+        self.start_detection();
+    }
+
+    /// Start drift detection in the background
+    fn start_detection(&mut self) {
+        if self.detecting {
+            return;
+        }
+        self.detecting = true;
+        self.error_message = None;
+
+        let account_id = self.account_id.clone();
+        let region = self.region.clone();
+        let stack_name = self.stack_name.clone();
+
+        spawn_with_client(
+            &self.manager,
+            &self.drift_sender,
+            move |manager| async move {
+                match manager
+                    .run_drift_detection(&account_id, &region, &stack_name)
+                    .await
+                {
+                    Ok(report) => {
+                        log::info!(
+                            "Drift detection for {}: {} ({} resources drifted)",
+                            stack_name,
+                            report.stack_drift_status,
+                            report.drifted_resources().count()
+                        );
+                        Ok(report)
+                    }
+                    Err(e) => {
+                        log::error!("Drift detection failed for {}: {:#}", stack_name, e);
+                        Err(format!("{:#}", e))
+                    }
+                }
+            },
+        );
+    }
+
+    /// Poll for drift results from background thread
+    fn poll_drift_results(&mut self) {
+        while let Ok(result) = self.drift_receiver.try_recv() {
+            self.detecting = false;
+
+            match result {
+                Ok(report) => {
+                    // Inspect the first drifted resource by default
+                    self.selected = report
+                        .drifted_resources()
+                        .next()
+                        .map(|resource| resource.logical_id.clone());
+                    // Nothing drifted: list everything that was checked instead of nothing
+                    if self.selected.is_none() {
+                        self.show_in_sync = true;
+                    }
+                    self.report = Some(report);
+                }
+                Err(error_msg) => {
+                    self.error_message = Some(error_msg);
+                }
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_drift_results();
+
+        // Detection polls CloudFormation every few seconds; keep the spinner moving
+        if self.detecting {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        let title = format!("Stack Drift: {}", self.stack_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "stack_drift_window",
+                &self.account_id,
+                &self.region,
+                &self.stack_name,
+            )))
+            .open(&mut is_open)
+            .default_size([900.0, 600.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        // Header: Stack identity and overall status
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Stack:").strong());
+            ui.label(format!(
+                "{} ({} / {})",
+                self.stack_name, self.account_id, self.region
+            ));
+            if ui
+                .add_enabled(!self.detecting, egui::Button::new("Detect Again"))
+                .clicked()
+            {
+                self.start_detection();
+            }
+        });
+
+        if let Some(report) = &self.report {
+            ui.horizontal(|ui| {
+                let color = if report.stack_drift_status == "DRIFTED" {
+                    ui.visuals().warn_fg_color
+                } else {
+                    ui.visuals().text_color()
+                };
+                ui.label(
+                    RichText::new(&report.stack_drift_status)
+                        .strong()
+                        .color(color),
+                );
+                ui.label(format!(
+                    "{} of {} resources drifted",
+                    report.drifted_resources().count(),
+                    report.resources.len()
+                ));
+                ui.label(
+                    RichText::new(format!(
+                        "detected {}",
+                        report
+                            .detected_at
+                            .with_timezone(&chrono::Local)
+                            .format("%H:%M:%S")
+                    ))
+                    .weak(),
+                );
+            });
+            if let Some(reason) = &report.reason {
+                ui.label(
+                    RichText::new(reason)
+                        .small()
+                        .color(ui.visuals().warn_fg_color),
+                );
+            }
+        }
+
+        ui.separator();
+
+        // Status message
+        if self.detecting {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label("Detecting drift... this can take a few minutes for large stacks");
+            });
+            return;
+        } else if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::RED, format!("Error: {}", error));
+            return;
+        }
+
+        let Some(report) = &self.report else {
+            return;
+        };
+        if report.resources.is_empty() {
+            ui.label(RichText::new("No resources support drift detection in this stack").italics());
+            return;
+        }
+
+        // Split view: resource list on the left, diff on the right
+        let mut selected = self.selected.clone();
+        let mut show_in_sync = self.show_in_sync;
+        let mut show_properties = self.show_properties;
+
+        egui::SidePanel::left(egui::Id::new((
+            "stack_drift_resources",
+            &self.account_id,
+            &self.region,
+            &self.stack_name,
+        )))
+        .default_width(280.0)
+        .min_width(200.0)
+        .show_inside(ui, |ui| {
+            render_resource_list(ui, report, &mut selected, &mut show_in_sync);
+        });
+
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            match selected
+                .as_ref()
+                .and_then(|id| report.resources.iter().find(|r| &r.logical_id == id))
+            {
+                Some(resource) => render_resource_drift(ui, resource, &mut show_properties),
+                None => {
+                    ui.label(RichText::new("Select a resource to see its drift").italics());
+                }
+            }
+        });
+
+        self.selected = selected;
+        self.show_in_sync = show_in_sync;
+        self.show_properties = show_properties;
+    }
+}
+
+fn render_resource_list(
+    ui: &mut Ui,
+    report: &StackDriftReport,
+    selected: &mut Option<String>,
+    show_in_sync: &mut bool,
+) {
+    ui.label(RichText::new(format!("Resources ({})", report.resources.len())).strong());
+    ui.checkbox(show_in_sync, "Show in-sync and unchecked");
+    ui.separator();
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            for resource in &report.resources {
+                if !*show_in_sync && !resource.status.is_drifted() {
+                    continue;
+                }
+                let is_selected = selected.as_deref() == Some(resource.logical_id.as_str());
+                ui.horizontal(|ui| {
+                    ui.label(
+                        RichText::new(resource.status.label())
+                            .small()
+                            .color(status_color(ui, &resource.status)),
+                    );
+                    if ui
+                        .selectable_label(is_selected, &resource.logical_id)
+                        .on_hover_text(&resource.resource_type)
+                        .clicked()
+                    {
+                        *selected = Some(resource.logical_id.clone());
+                    }
+                });
+            }
+        });
+}
+
+fn render_resource_drift(ui: &mut Ui, resource: &ResourceDrift, show_properties: &mut bool) {
+    ui.horizontal(|ui| {
+        ui.label(RichText::new(&resource.logical_id).strong());
+        ui.label(RichText::new(&resource.resource_type).weak());
+        ui.label(RichText::new(resource.status.label()).color(status_color(ui, &resource.status)));
+    });
+    if let Some(physical_id) = &resource.physical_id {
+        ui.label(RichText::new(physical_id).monospace().small());
+    }
+    ui.checkbox(show_properties, "Show expected and actual properties");
+    ui.separator();
+
+    egui::ScrollArea::vertical()
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            match resource.status {
+                ResourceDriftStatus::Deleted => {
+                    ui.label(
+                        RichText::new("The resource was deleted outside of CloudFormation")
+                            .italics(),
+                    );
+                }
+                ResourceDriftStatus::NotChecked => {
+                    ui.label(
+                        RichText::new("CloudFormation does not check this resource type for drift")
+                            .italics(),
+                    );
+                }
+                _ => {
+                    let changes = resource.property_changes();
+                    if changes.is_empty() {
+                        ui.label(RichText::new("No differences").italics());
+                    } else {
+                        ui.label(format!(
+                            "{} differences (expected -> actual)",
+                            changes.len()
+                        ));
+                        ui.add_space(4.0);
+                        for change in &changes {
+                            render_change(ui, change);
+                        }
+                    }
+                }
+            }
+
+            if *show_properties {
+                ui.separator();
+                ui.columns(2, |columns| {
+                    render_properties(&mut columns[0], "Expected", &resource.expected_properties);
+                    render_properties(&mut columns[1], "Actual", &resource.actual_properties);
+                });
+            }
+        });
+}
+
+fn render_properties(ui: &mut Ui, heading: &str, properties: &Option<serde_json::Value>) {
+    ui.label(RichText::new(heading).strong());
+    match properties {
+        Some(properties) => {
+            let pretty = serde_json::to_string_pretty(properties).unwrap_or_default();
+            for line in pretty.lines() {
+                ui.label(RichText::new(line).monospace());
+            }
+        }
+        None => {
+            ui.label(RichText::new("(not available)").weak());
+        }
+    }
+}
+
+impl FocusableWindow for StackDriftWindow {
+    type ShowParams = StackDriftShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "stack_drift_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Stack Drift: {}", self.stack_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the stack first
+        self.open_for_stack(params);
+
+        // Then show with focus
+        StackDriftWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
@@ -0,0 +1,690 @@
+//! CloudFormation Stack Import Window
+//!
+//! Adopts resources selected in the Explorer into a new or existing stack.
+//! Shows the generated import manifest and template for review or export,
+//! then creates an `IMPORT` change set and executes it after approval.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::change_set_review::{ChangeSetReview, ReviewDecision};
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::cloudformation_manager::change_set::IAM_CAPABILITIES;
+use crate::app::cloudformation_manager::import::{
+    build_import_resources, import_manifest, is_valid_logical_id, merge_into_template,
+    skeleton_template, template_logical_ids,
+};
+use crate::app::cloudformation_manager::{
+    ChangeSetPlan, DeploymentManager, ImportRequest, ImportResource, SkippedResource,
+    StackUpdateDefaults,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use crate::app::resource_explorer::state::ResourceEntry;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Parameters for showing the Stack Import window
+#[derive(Clone)]
+pub struct StackImportShowParams {
+    pub resources: Vec<ResourceEntry>,
+}
+
+/// Results from background CloudFormation calls
+enum ImportEvent {
+    Defaults(String, Result<StackUpdateDefaults, String>),
+    Planned(Result<ChangeSetPlan, String>),
+    Executed(Result<(), String>),
+    Discarded,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Editing,
+    Loading,
+    Planning,
+    Review,
+    Executing,
+    Done,
+}
+
+pub struct StackImportWindow {
+    pub open: bool,
+    account_id: String,
+    region: String,
+
+    // Import selection
+    source: Vec<ResourceEntry>,
+    resources: Vec<ImportResource>,
+    skipped: Vec<SkippedResource>,
+
+    // Target stack
+    existing_stack: bool,
+    stack_name: String,
+    /// Template and parameters of the existing stack, once loaded
+    existing: Option<StackUpdateDefaults>,
+    template_body: String,
+    acknowledge_capabilities: bool,
+
+    // Review state
+    plan: Option<ChangeSetPlan>,
+    review: ChangeSetReview,
+
+    phase: Phase,
+    error_message: Option<String>,
+    message: Option<String>,
+
+    // Services
+    manager: Arc<DeploymentManager>,
+
+    // Channel for receiving results from background threads
+    event_receiver: mpsc::Receiver<ImportEvent>,
+    event_sender: mpsc::Sender<ImportEvent>,
+}
+
+impl StackImportWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (event_sender, event_receiver) = mpsc::channel();
+
+        Self {
+            open: false,
+            account_id: String::new(),
+            region: String::new(),
+            source: Vec::new(),
+            resources: Vec::new(),
+            skipped: Vec::new(),
+            existing_stack: false,
+            stack_name: String::new(),
+            existing: None,
+            template_body: String::new(),
+            acknowledge_capabilities: false,
+            plan: None,
+            review: ChangeSetReview::default(),
+            phase: Phase::Editing,
+            error_message: None,
+            message: None,
+            manager: Arc::new(DeploymentManager::new(credential_coordinator)),
+            event_receiver,
+            event_sender,
+        }
+    }
+
+    /// Open the window for the selected resources
+    ///
+    /// A change set targets one stack, so only resources in the first
+    /// resource's account and region are imported.
+    pub fn open_for_resources(&mut self, params: StackImportShowParams) {
+        let Some(first) = params.resources.first() else {
+            return;
+        };
+        self.account_id = first.account_id.clone();
+        self.region = first.region.clone();
+
+        let (source, other_location): (Vec<_>, Vec<_>) =
+            params.resources.into_iter().partition(|resource| {
+                resource.account_id == self.account_id && resource.region == self.region
+            });
+        self.source = source;
+        self.existing = None;
+        self.plan = None;
+        self.error_message = None;
+        self.message = None;
+        self.phase = Phase::Editing;
+        self.open = true;
+
+        self.rebuild_resources();
+        self.skipped
+            .extend(other_location.into_iter().map(|resource| SkippedResource {
+                reason: format!(
+                    "Not in {} / {}; import it separately",
+                    resource.account_id, resource.region
+                ),
+                display_name: resource.display_name,
+                resource_type: resource.resource_type,
+            }));
+    }
+
+    /// Regenerate import entries, avoiding logical IDs in the target template
+    fn rebuild_resources(&mut self) {
+        let reserved = self
+            .existing
+            .as_ref()
+            .map(|existing| template_logical_ids(&existing.template_body))
+            .unwrap_or_default();
+        let (resources, skipped) = build_import_resources(&self.source, &reserved);
+        self.resources = resources;
+        self.skipped = skipped;
+        self.regenerate_template();
+    }
+
+    fn regenerate_template(&mut self) {
+        match &self.existing {
+            Some(existing) => match merge_into_template(&existing.template_body, &self.resources) {
+                Ok(template) => self.template_body = template,
+                Err(e) => self.error_message = Some(format!("{:#}", e)),
+            },
+            None => self.template_body = skeleton_template(&self.resources),
+        }
+    }
+
+    /// Load the existing stack's template to merge the imports into
+    fn load_existing_stack(&mut self) {
+        let stack_name = self.stack_name.trim().to_string();
+        if stack_name.is_empty() {
+            self.error_message = Some("Enter the name of the existing stack".to_string());
+            return;
+        }
+        self.phase = Phase::Loading;
+        self.error_message = None;
+        let (account_id, region) = (self.account_id.clone(), self.region.clone());
+        spawn_with_client(
+            &self.manager,
+            &self.event_sender,
+            move |manager| async move {
+                let result = manager
+                    .stack_update_defaults(&account_id, &region, &stack_name)
+                    .await
+                    .map_err(|e| format!("{:#}", e));
+                ImportEvent::Defaults(stack_name, result)
+            },
+        );
+    }
+
+    fn import_request(&self) -> ImportRequest {
+        let (parameters, stack_capabilities) = match &self.existing {
+            Some(existing) => (existing.parameters.clone(), existing.capabilities.clone()),
+            None => (Vec::new(), Vec::new()),
+        };
+        ImportRequest {
+            stack_name: self.stack_name.trim().to_string(),
+            template_body: self.template_body.clone(),
+            resources: self.resources.clone(),
+            parameters,
+            capabilities: if self.acknowledge_capabilities {
+                IAM_CAPABILITIES.iter().map(|c| c.to_string()).collect()
+            } else {
+                stack_capabilities
+            },
+        }
+    }
+
+    fn create_change_set(&mut self) {
+        let request = self.import_request();
+        if let Err(e) = request.validate() {
+            self.error_message = Some(format!("{:#}", e));
+            return;
+        }
+
+        self.phase = Phase::Planning;
+        self.error_message = None;
+        let (account_id, region) = (self.account_id.clone(), self.region.clone());
+        spawn_with_client(
+            &self.manager,
+            &self.event_sender,
+            move |manager| async move {
+                ImportEvent::Planned(
+                    manager
+                        .plan_import(&account_id, &region, &request)
+                        .await
+                        .map_err(|e| format!("{:#}", e)),
+                )
+            },
+        );
+    }
+
+    fn execute_change_set(&mut self) {
+        let Some(plan) = self.plan.clone() else {
+            return;
+        };
+        self.phase = Phase::Executing;
+        self.error_message = None;
+        let (account_id, region) = (self.account_id.clone(), self.region.clone());
+
+        spawn_with_client(
+            &self.manager,
+            &self.event_sender,
+            move |manager| async move {
+                ImportEvent::Executed(
+                    manager
+                        .execute_change_set(&account_id, &region, &plan)
+                        .await
+                        .map_err(|e| format!("{:#}", e)),
+                )
+            },
+        );
+    }
+
+    /// Delete the reviewed change set without executing it
+    fn discard_change_set(&mut self) {
+        if let Some(plan) = self.plan.take() {
+            if plan.executable {
+                let (account_id, region) = (self.account_id.clone(), self.region.clone());
+                spawn_with_client(
+                    &self.manager,
+                    &self.event_sender,
+                    move |manager| async move {
+                        if let Err(e) = manager
+                            .delete_change_set(&account_id, &region, &plan.change_set_id)
+                            .await
+                        {
+                            tracing::warn!("Failed to delete change set: {:#}", e);
+                        }
+                        ImportEvent::Discarded
+                    },
+                );
+            }
+        }
+        self.phase = Phase::Editing;
+    }
+
+    /// Write the template and manifest to the Downloads directory
+    fn save_files(&self) -> anyhow::Result<(PathBuf, PathBuf)> {
+        let directory = dirs::download_dir()
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let base = if self.stack_name.trim().is_empty() {
+            "imported-resources".to_string()
+        } else {
+            self.stack_name.trim().to_string()
+        };
+        let extension = if self.template_body.trim_start().starts_with('{') {
+            "json"
+        } else {
+            "yaml"
+        };
+
+        let template_path = directory.join(format!("{}-import-template.{}", base, extension));
+        let manifest_path = directory.join(format!("{}-import-manifest.json", base));
+        std::fs::write(&template_path, &self.template_body)?;
+        std::fs::write(
+            &manifest_path,
+            serde_json::to_string_pretty(&import_manifest(&self.resources))?,
+        )?;
+        Ok((template_path, manifest_path))
+    }
+
+    /// Poll for results from background threads
+    fn poll_events(&mut self) {
+        while let Ok(event) = self.event_receiver.try_recv() {
+            match event {
+                ImportEvent::Defaults(stack_name, Ok(defaults)) => {
+                    self.message = Some(format!(
+                        "Loaded template of {} ({} parameters)",
+                        stack_name,
+                        defaults.parameters.len()
+                    ));
+                    self.acknowledge_capabilities = !defaults.capabilities.is_empty();
+                    self.existing = Some(defaults);
+                    self.rebuild_resources();
+                    self.phase = Phase::Editing;
+                }
+                ImportEvent::Defaults(_, Err(error)) => {
+                    self.error_message = Some(error);
+                    self.phase = Phase::Editing;
+                }
+                ImportEvent::Planned(Ok(plan)) => {
+                    self.review.reset();
+                    self.plan = Some(plan);
+                    self.phase = Phase::Review;
+                }
+                ImportEvent::Planned(Err(error)) => {
+                    self.error_message = Some(error);
+                    self.phase = Phase::Editing;
+                }
+                ImportEvent::Executed(result) => match result {
+                    Ok(()) => {
+                        self.message = Some(format!(
+                            "Import into {} started; follow its progress in the stack events",
+                            self.stack_name.trim()
+                        ));
+                        self.plan = None;
+                        self.phase = Phase::Done;
+                    }
+                    Err(error) => {
+                        self.error_message = Some(error);
+                        self.phase = Phase::Review;
+                    }
+                },
+                // Deletion failures are only logged
+                ImportEvent::Discarded => {}
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_events();
+
+        // Request repaints while CloudFormation works to keep the spinner moving
+        if matches!(
+            self.phase,
+            Phase::Loading | Phase::Planning | Phase::Executing
+        ) {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(self.window_title())
+            .id(egui::Id::new((
+                "stack_import_window",
+                &self.account_id,
+                &self.region,
+                self.source.first().map(|r| r.selection_key()),
+            )))
+            .open(&mut is_open)
+            .default_size([900.0, 680.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // A change set left unexecuted is deleted when the window closes
+        if !is_open && self.phase == Phase::Review {
+            self.discard_change_set();
+        }
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Location:").strong());
+            ui.label(format!("{} / {}", self.account_id, self.region));
+        });
+        ui.separator();
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::RED, format!("Error: {}", error));
+        }
+
+        match self.phase {
+            Phase::Editing => self.render_editor(ui),
+            Phase::Loading => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Loading the stack's template...");
+                });
+            }
+            Phase::Planning => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Creating import change set...");
+                });
+            }
+            Phase::Review | Phase::Executing => self.render_review(ui),
+            Phase::Done => {
+                if let Some(message) = &self.message {
+                    ui.label(message);
+                }
+                if ui.button("Close").clicked() {
+                    self.open = false;
+                }
+            }
+        }
+    }
+
+    fn render_editor(&mut self, ui: &mut Ui) {
+        self.render_target(ui);
+        ui.separator();
+        self.render_resources(ui);
+        ui.separator();
+        self.render_template(ui);
+
+        ui.separator();
+        if let Some(message) = &self.message {
+            ui.label(message);
+        }
+        if crate::app::capabilities::READ_ONLY {
+            ui.label(
+                RichText::new("Read-only build: export the files and import with the AWS CLI")
+                    .italics(),
+            );
+            return;
+        }
+        ui.checkbox(
+            &mut self.acknowledge_capabilities,
+            "Allow IAM resources and macros (CAPABILITY_IAM, CAPABILITY_NAMED_IAM, CAPABILITY_AUTO_EXPAND)",
+        );
+        ui.add_space(4.0);
+        let ready = !self.resources.is_empty() && (!self.existing_stack || self.existing.is_some());
+        if ui
+            .add_enabled(ready, egui::Button::new("Create Import Change Set"))
+            .on_hover_text("Preview the import; nothing changes until the change set is executed")
+            .clicked()
+        {
+            self.create_change_set();
+        }
+    }
+
+    fn render_target(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Import into:").strong());
+            let new_clicked = ui
+                .radio_value(&mut self.existing_stack, false, "New stack")
+                .clicked();
+            let existing_clicked = ui
+                .radio_value(&mut self.existing_stack, true, "Existing stack")
+                .clicked();
+            if (new_clicked || existing_clicked) && self.existing.is_some() {
+                // Switching target drops the loaded template
+                self.existing = None;
+                self.rebuild_resources();
+            }
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Stack name:");
+            let response = ui.add(
+                egui::TextEdit::singleline(&mut self.stack_name)
+                    .hint_text(if self.existing_stack {
+                        "Existing stack name"
+                    } else {
+                        "New stack name"
+                    })
+                    .desired_width(280.0),
+            );
+            if response.changed() && self.existing.is_some() {
+                self.existing = None;
+                self.rebuild_resources();
+            }
+            if self.existing_stack
+                && ui
+                    .add_enabled(
+                        !self.stack_name.trim().is_empty(),
+                        egui::Button::new("Load Template"),
+                    )
+                    .on_hover_text("Merge the imports into the stack's current template")
+                    .clicked()
+            {
+                self.load_existing_stack();
+            }
+        });
+        if self.existing_stack && self.existing.is_none() {
+            ui.label(
+                RichText::new("Load the stack's template before creating the change set").small(),
+            );
+        }
+    }
+
+    fn render_resources(&mut self, ui: &mut Ui) {
+        ui.label(RichText::new(format!("Resources to import ({})", self.resources.len())).strong());
+        let mut ids_changed = false;
+
+        egui::ScrollArea::vertical()
+            .id_salt("stack_import_resources")
+            .max_height(180.0)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                egui::Grid::new("stack_import_resources_grid")
+                    .num_columns(4)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.strong("Logical ID");
+                        ui.strong("Type");
+                        ui.strong("Identifier");
+                        ui.strong("Fill in");
+                        ui.end_row();
+
+                        for resource in &mut self.resources {
+                            let mut edit = egui::TextEdit::singleline(&mut resource.logical_id)
+                                .desired_width(180.0);
+                            if !is_valid_logical_id(&resource.logical_id) {
+                                edit = edit.text_color(ui.visuals().error_fg_color);
+                            }
+                            if ui.add(edit).changed() {
+                                ids_changed = true;
+                            }
+                            ui.label(&resource.resource_type);
+                            ui.label(format!(
+                                "{} = {}",
+                                resource.identifier_property, resource.identifier_value
+                            ));
+                            if resource.fill_in.is_empty() {
+                                ui.label("");
+                            } else {
+                                ui.colored_label(
+                                    ui.visuals().warn_fg_color,
+                                    resource.fill_in.join(", "),
+                                )
+                                .on_hover_text(
+                                    "Required properties that were not loaded; add them to the template",
+                                );
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                if !self.skipped.is_empty() {
+                    ui.add_space(4.0);
+                    ui.label(RichText::new(format!("Skipped ({})", self.skipped.len())).strong());
+                    for skipped in &self.skipped {
+                        ui.label(format!(
+                            "{} ({}): {}",
+                            skipped.display_name, skipped.resource_type, skipped.reason
+                        ));
+                    }
+                }
+            });
+
+        if ids_changed {
+            self.regenerate_template();
+        }
+    }
+
+    fn render_template(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Template").strong());
+            if ui
+                .button("Regenerate")
+                .on_hover_text("Discard template edits and rebuild it from the resources")
+                .clicked()
+            {
+                self.regenerate_template();
+            }
+            if ui.button("Copy Template").clicked() {
+                ui.ctx().copy_text(self.template_body.clone());
+            }
+            if ui.button("Copy Manifest").clicked() {
+                ui.ctx().copy_text(
+                    serde_json::to_string_pretty(&import_manifest(&self.resources))
+                        .unwrap_or_default(),
+                );
+            }
+            if ui
+                .button("Save Files")
+                .on_hover_text("Save the template and import manifest to Downloads")
+                .clicked()
+            {
+                match self.save_files() {
+                    Ok((template_path, manifest_path)) => {
+                        self.message = Some(format!(
+                            "Saved {} and {}",
+                            template_path.display(),
+                            manifest_path.display()
+                        ));
+                    }
+                    Err(e) => self.error_message = Some(format!("Failed to save files: {:#}", e)),
+                }
+            }
+        });
+        ui.label(
+            RichText::new(
+                "Edits are kept until a logical ID changes. Imported resources must match their live configuration.",
+            )
+            .small(),
+        );
+        egui::ScrollArea::vertical()
+            .id_salt("stack_import_template")
+            .max_height(260.0)
+            .show(ui, |ui| {
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.template_body)
+                        .code_editor()
+                        .desired_rows(12)
+                        .desired_width(f32::INFINITY),
+                );
+            });
+    }
+
+    fn render_review(&mut self, ui: &mut Ui) {
+        let Some(plan) = &self.plan else {
+            return;
+        };
+        let busy = self.phase == Phase::Executing;
+
+        match self.review.show(ui, plan, self.stack_name.trim(), busy) {
+            Some(ReviewDecision::Execute) => self.execute_change_set(),
+            Some(ReviewDecision::Discard) => self.discard_change_set(),
+            None => {}
+        }
+    }
+}
+
+impl FocusableWindow for StackImportWindow {
+    type ShowParams = StackImportShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "stack_import_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!(
+            "Import Resources ({})",
+            self.resources.len() + self.skipped.len()
+        )
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the resources first
+        self.open_for_resources(params);
+
+        // Then show with focus
+        StackImportWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
@@ -0,0 +1,481 @@
+//! CloudFormation Stack Update Window
+//!
+//! Updates a stack through a change set: edit the template and parameters,
+//! create the change set, review the planned adds, modifies, and removes with
+//! replacement warnings, and execute only after explicit approval.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::change_set_review::{ChangeSetReview, ReviewDecision};
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::cloudformation_manager::change_set::IAM_CAPABILITIES;
+use crate::app::cloudformation_manager::{
+    ChangeSetPlan, ChangeSetRequest, DeploymentManager, StackParameter, StackUpdateDefaults,
+    TemplateSource,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Parameters for showing the Stack Update window
+#[derive(Clone)]
+pub struct StackUpdateShowParams {
+    pub stack_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Results from background CloudFormation calls
+enum UpdateEvent {
+    Defaults(Result<StackUpdateDefaults, String>),
+    Planned(Result<ChangeSetPlan, String>),
+    Executed(Result<(), String>),
+    Discarded,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Loading,
+    Editing,
+    Planning,
+    Review,
+    Executing,
+    Done,
+}
+
+pub struct StackUpdateWindow {
+    pub open: bool,
+    // Display parameters
+    stack_name: String,
+    account_id: String,
+    region: String,
+
+    // Editing state
+    use_current_template: bool,
+    template_body: String,
+    template_path: String,
+    parameters: Vec<StackParameter>,
+    acknowledge_capabilities: bool,
+
+    // Review state
+    plan: Option<ChangeSetPlan>,
+    review: ChangeSetReview,
+
+    phase: Phase,
+    error_message: Option<String>,
+    message: Option<String>,
+
+    // Services
+    manager: Arc<DeploymentManager>,
+
+    // Channel for receiving results from background threads
+    event_receiver: mpsc::Receiver<UpdateEvent>,
+    event_sender: mpsc::Sender<UpdateEvent>,
+}
+
+impl StackUpdateWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (event_sender, event_receiver) = mpsc::channel();
+
+        Self {
+            open: false,
+            stack_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            use_current_template: true,
+            template_body: String::new(),
+            template_path: String::new(),
+            parameters: Vec::new(),
+            acknowledge_capabilities: false,
+            plan: None,
+            review: ChangeSetReview::default(),
+            phase: Phase::Loading,
+            error_message: None,
+            message: None,
+            manager: Arc::new(DeploymentManager::new(credential_coordinator)),
+            event_receiver,
+            event_sender,
+        }
+    }
+
+    /// Open the window and load the stack's current template and parameters
+    pub fn open_for_stack(&mut self, params: StackUpdateShowParams) {
+        self.stack_name = params.stack_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.plan = None;
+        self.error_message = None;
+        self.message = None;
+        self.open = true;
+
+        self.load_defaults();
+    }
+
+    fn load_defaults(&mut self) {
+        self.phase = Phase::Loading;
+        let (account_id, region, stack_name) = self.target();
+        spawn_with_client(
+            &self.manager,
+            &self.event_sender,
+            move |manager| async move {
+                UpdateEvent::Defaults(
+                    manager
+                        .stack_update_defaults(&account_id, &region, &stack_name)
+                        .await
+                        .map_err(|e| format!("{:#}", e)),
+                )
+            },
+        );
+    }
+
+    fn create_change_set(&mut self) {
+        let request = ChangeSetRequest {
+            stack_name: self.stack_name.clone(),
+            template: if self.use_current_template {
+                TemplateSource::Current
+            } else {
+                TemplateSource::Body(self.template_body.clone())
+            },
+            parameters: self.parameters.clone(),
+            capabilities: if self.acknowledge_capabilities {
+                IAM_CAPABILITIES.iter().map(|c| c.to_string()).collect()
+            } else {
+                Vec::new()
+            },
+        };
+        if let Err(e) = request.validate() {
+            self.error_message = Some(format!("{:#}", e));
+            return;
+        }
+
+        self.phase = Phase::Planning;
+        self.error_message = None;
+        let (account_id, region, _) = self.target();
+        spawn_with_client(
+            &self.manager,
+            &self.event_sender,
+            move |manager| async move {
+                UpdateEvent::Planned(
+                    manager
+                        .plan_stack_update(&account_id, &region, &request)
+                        .await
+                        .map_err(|e| format!("{:#}", e)),
+                )
+            },
+        );
+    }
+
+    fn execute_change_set(&mut self) {
+        let Some(plan) = self.plan.clone() else {
+            return;
+        };
+        self.phase = Phase::Executing;
+        self.error_message = None;
+        let (account_id, region, _) = self.target();
+
+        spawn_with_client(
+            &self.manager,
+            &self.event_sender,
+            move |manager| async move {
+                UpdateEvent::Executed(
+                    manager
+                        .execute_change_set(&account_id, &region, &plan)
+                        .await
+                        .map_err(|e| format!("{:#}", e)),
+                )
+            },
+        );
+    }
+
+    /// Delete the reviewed change set without executing it
+    fn discard_change_set(&mut self) {
+        if let Some(plan) = self.plan.take() {
+            if plan.executable {
+                let (account_id, region, _) = self.target();
+                spawn_with_client(
+                    &self.manager,
+                    &self.event_sender,
+                    move |manager| async move {
+                        if let Err(e) = manager
+                            .delete_change_set(&account_id, &region, &plan.change_set_id)
+                            .await
+                        {
+                            tracing::warn!("Failed to delete change set: {:#}", e);
+                        }
+                        UpdateEvent::Discarded
+                    },
+                );
+            }
+        }
+        self.phase = Phase::Editing;
+    }
+
+    fn target(&self) -> (String, String, String) {
+        (
+            self.account_id.clone(),
+            self.region.clone(),
+            self.stack_name.clone(),
+        )
+    }
+
+    /// Poll for results from background threads
+    fn poll_events(&mut self) {
+        while let Ok(event) = self.event_receiver.try_recv() {
+            match event {
+                UpdateEvent::Defaults(Ok(defaults)) => {
+                    self.template_body = defaults.template_body;
+                    self.parameters = defaults.parameters;
+                    self.acknowledge_capabilities = !defaults.capabilities.is_empty();
+                    self.phase = Phase::Editing;
+                }
+                UpdateEvent::Defaults(Err(error)) => {
+                    // Still allow pasting a template when the current one can't be read
+                    self.error_message = Some(error);
+                    self.use_current_template = false;
+                    self.phase = Phase::Editing;
+                }
+                UpdateEvent::Planned(Ok(plan)) => {
+                    self.review.reset();
+                    self.plan = Some(plan);
+                    self.phase = Phase::Review;
+                }
+                UpdateEvent::Planned(Err(error)) => {
+                    self.error_message = Some(error);
+                    self.phase = Phase::Editing;
+                }
+                UpdateEvent::Executed(result) => match result {
+                    Ok(()) => {
+                        self.message = Some(format!(
+                            "Update of {} started; follow its progress in the stack events",
+                            self.stack_name
+                        ));
+                        self.plan = None;
+                        self.phase = Phase::Done;
+                    }
+                    Err(error) => {
+                        self.error_message = Some(error);
+                        self.phase = Phase::Review;
+                    }
+                },
+                // Deletion failures are only logged
+                UpdateEvent::Discarded => {}
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_events();
+
+        // Request repaints while CloudFormation works to keep the spinner moving
+        if matches!(
+            self.phase,
+            Phase::Loading | Phase::Planning | Phase::Executing
+        ) {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        let title = format!("Update Stack: {}", self.stack_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "stack_update_window",
+                &self.account_id,
+                &self.region,
+                &self.stack_name,
+            )))
+            .open(&mut is_open)
+            .default_size([900.0, 640.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // A change set left unexecuted is deleted when the window closes
+        if !is_open && self.phase == Phase::Review {
+            self.discard_change_set();
+        }
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Stack:").strong());
+            ui.label(format!(
+                "{} ({} / {})",
+                self.stack_name, self.account_id, self.region
+            ));
+        });
+        ui.separator();
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::RED, format!("Error: {}", error));
+        }
+
+        match self.phase {
+            Phase::Loading => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Loading current template and parameters...");
+                });
+            }
+            Phase::Editing => self.render_editor(ui),
+            Phase::Planning => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Creating change set...");
+                });
+            }
+            Phase::Review | Phase::Executing => self.render_review(ui),
+            Phase::Done => {
+                if let Some(message) = &self.message {
+                    ui.label(message);
+                }
+                if ui.button("Close").clicked() {
+                    self.open = false;
+                }
+            }
+        }
+    }
+
+    fn render_editor(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Template:").strong());
+            ui.radio_value(&mut self.use_current_template, true, "Use current template");
+            ui.radio_value(&mut self.use_current_template, false, "Edit template");
+        });
+
+        if !self.use_current_template {
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.template_path)
+                        .hint_text("Path to a JSON or YAML template")
+                        .desired_width(360.0),
+                );
+                if ui
+                    .add_enabled(
+                        !self.template_path.trim().is_empty(),
+                        egui::Button::new("Load File"),
+                    )
+                    .clicked()
+                {
+                    match std::fs::read_to_string(self.template_path.trim()) {
+                        Ok(body) => {
+                            self.template_body = body;
+                            self.error_message = None;
+                        }
+                        Err(e) => {
+                            self.error_message =
+                                Some(format!("Failed to read {}: {}", self.template_path, e));
+                        }
+                    }
+                }
+            });
+            egui::ScrollArea::vertical()
+                .id_salt("stack_update_template")
+                .max_height(280.0)
+                .show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.template_body)
+                            .code_editor()
+                            .desired_rows(14)
+                            .desired_width(f32::INFINITY),
+                    );
+                });
+        }
+
+        ui.separator();
+        ui.label(RichText::new(format!("Parameters ({})", self.parameters.len())).strong());
+        if !self.parameters.is_empty() {
+            egui::Grid::new("stack_update_parameters")
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    for parameter in &mut self.parameters {
+                        ui.label(&parameter.key);
+                        ui.checkbox(&mut parameter.use_previous, "Keep current");
+                        ui.add_enabled(
+                            !parameter.use_previous,
+                            egui::TextEdit::singleline(&mut parameter.value).desired_width(280.0),
+                        );
+                        ui.end_row();
+                    }
+                });
+        }
+
+        ui.separator();
+        ui.checkbox(
+            &mut self.acknowledge_capabilities,
+            "Allow IAM resources and macros (CAPABILITY_IAM, CAPABILITY_NAMED_IAM, CAPABILITY_AUTO_EXPAND)",
+        );
+        ui.add_space(4.0);
+        if ui
+            .button("Create Change Set")
+            .on_hover_text("Preview the update; nothing changes until the change set is executed")
+            .clicked()
+        {
+            self.create_change_set();
+        }
+    }
+
+    fn render_review(&mut self, ui: &mut Ui) {
+        let Some(plan) = &self.plan else {
+            return;
+        };
+        let busy = self.phase == Phase::Executing;
+
+        match self.review.show(ui, plan, &self.stack_name, busy) {
+            Some(ReviewDecision::Execute) => self.execute_change_set(),
+            Some(ReviewDecision::Discard) => self.discard_change_set(),
+            None => {}
+        }
+    }
+}
+
+impl FocusableWindow for StackUpdateWindow {
+    type ShowParams = StackUpdateShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "stack_update_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Update Stack: {}", self.stack_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the stack first
+        self.open_for_stack(params);
+
+        // Then show with focus
+        StackUpdateWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
@@ -0,0 +1,733 @@
+//! Step Functions Execution Viewer Window
+//!
+//! Lists the executions of a state machine and shows the state transitions of
+//! the selected one as a collapsible timeline, with the input and output of
+//! every state.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::data_plane::step_functions::{
+    ExecutionHistory, ExecutionListOptions, ExecutionListResult, ExecutionSummary, StateStatus,
+    StateTransition, StepFunctionsClient,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use chrono::DateTime;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::collections::HashMap;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Executions requested per page
+const PAGE_SIZE: i32 = 50;
+
+/// Width of the duration bar next to each state
+const BAR_WIDTH: f32 = 140.0;
+
+/// Status filters offered above the execution list
+const STATUS_FILTERS: &[(&str, Option<&str>)] = &[
+    ("All", None),
+    ("Running", Some("RUNNING")),
+    ("Succeeded", Some("SUCCEEDED")),
+    ("Failed", Some("FAILED")),
+    ("Timed out", Some("TIMED_OUT")),
+    ("Aborted", Some("ABORTED")),
+];
+
+const SUCCEEDED_COLOR: Color32 = Color32::from_rgb(100, 200, 100);
+const FAILED_COLOR: Color32 = Color32::from_rgb(255, 100, 100);
+const RUNNING_COLOR: Color32 = Color32::from_rgb(100, 160, 255);
+
+/// Parameters for showing the Step Functions window
+#[derive(Clone)]
+pub struct StepFunctionsShowParams {
+    pub state_machine_arn: String,
+    pub state_machine_name: String,
+    pub account_id: String,
+    pub region: String,
+}
+
+/// Results from background threads
+enum ExecutionEvent {
+    ExecutionsLoaded {
+        /// Next page appended to the list rather than replacing it
+        append: bool,
+        result: Result<ExecutionListResult, String>,
+    },
+    HistoryLoaded {
+        execution_arn: String,
+        result: Result<ExecutionHistory, String>,
+    },
+}
+
+pub struct StepFunctionsWindow {
+    pub open: bool,
+    // Display parameters
+    state_machine_arn: String,
+    state_machine_name: String,
+    account_id: String,
+    region: String,
+
+    // Executions
+    executions: Vec<ExecutionSummary>,
+    next_token: Option<String>,
+    status_filter: Option<&'static str>,
+    loading: bool,
+    error_message: Option<String>,
+
+    // Selected execution
+    selected: Option<String>,
+    histories: HashMap<String, Result<ExecutionHistory, String>>,
+    loading_history: Option<String>,
+
+    // Services
+    client: Arc<StepFunctionsClient>,
+
+    // Channel for results from background threads
+    sender: mpsc::Sender<ExecutionEvent>,
+    receiver: mpsc::Receiver<ExecutionEvent>,
+}
+
+impl StepFunctionsWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            state_machine_arn: String::new(),
+            state_machine_name: String::new(),
+            account_id: String::new(),
+            region: String::new(),
+            executions: Vec::new(),
+            next_token: None,
+            status_filter: None,
+            loading: false,
+            error_message: None,
+            selected: None,
+            histories: HashMap::new(),
+            loading_history: None,
+            client: Arc::new(StepFunctionsClient::new(credential_coordinator)),
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on a state machine and load its executions
+    pub fn open_for_state_machine(&mut self, params: StepFunctionsShowParams) {
+        self.state_machine_arn = params.state_machine_arn;
+        self.state_machine_name = params.state_machine_name;
+        self.account_id = params.account_id;
+        self.region = params.region;
+        self.selected = None;
+        self.histories.clear();
+        self.open = true;
+        self.load_executions(false);
+    }
+
+    /// Load the first page of executions, or the next one when appending
+    fn load_executions(&mut self, append: bool) {
+        self.loading = true;
+        self.error_message = None;
+
+        let mut options = ExecutionListOptions::new().with_max_results(PAGE_SIZE);
+        if let Some(status) = self.status_filter {
+            options = options.with_status_filter(status);
+        }
+        if append {
+            if let Some(token) = &self.next_token {
+                options = options.with_next_token(token.clone());
+            }
+        }
+
+        let account_id = self.account_id.clone();
+        let region = self.region.clone();
+        let state_machine_arn = self.state_machine_arn.clone();
+
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .list_executions(&account_id, &region, &state_machine_arn, &options)
+                .await
+                .map_err(|e| {
+                    log::error!("Failed to list executions of {}: {}", state_machine_arn, e);
+                    format!("{:#}", e)
+                });
+            ExecutionEvent::ExecutionsLoaded { append, result }
+        });
+    }
+
+    /// Select an execution, loading its history unless already loaded
+    fn select_execution(&mut self, execution_arn: String) {
+        self.selected = Some(execution_arn.clone());
+        if self.histories.contains_key(&execution_arn) {
+            return;
+        }
+        self.loading_history = Some(execution_arn.clone());
+
+        let account_id = self.account_id.clone();
+        let region = self.region.clone();
+
+        spawn_with_client(&self.client, &self.sender, move |client| async move {
+            let result = client
+                .get_execution_history(&account_id, &region, &execution_arn)
+                .await
+                .map_err(|e| {
+                    log::error!("Failed to get history of {}: {}", execution_arn, e);
+                    format!("{:#}", e)
+                });
+            ExecutionEvent::HistoryLoaded {
+                execution_arn,
+                result,
+            }
+        });
+    }
+
+    /// Poll for results from background threads
+    fn poll_events(&mut self) {
+        while let Ok(event) = self.receiver.try_recv() {
+            match event {
+                ExecutionEvent::ExecutionsLoaded { append, result } => {
+                    self.loading = false;
+                    match result {
+                        Ok(page) => {
+                            if !append {
+                                // Histories of running executions and failed loads are stale
+                                self.histories.retain(|execution_arn, history| {
+                                    history.is_ok()
+                                        && !page.executions.iter().any(|execution| {
+                                            &execution.execution_arn == execution_arn
+                                                && execution.status == "RUNNING"
+                                        })
+                                });
+                                self.executions.clear();
+                            }
+                            self.executions.extend(page.executions);
+                            self.next_token = page.next_token;
+
+                            if let Some(selected) = self.selected.clone() {
+                                if !self.histories.contains_key(&selected)
+                                    && self.loading_history.is_none()
+                                {
+                                    self.select_execution(selected);
+                                }
+                            }
+                        }
+                        Err(e) => self.error_message = Some(e),
+                    }
+                }
+                ExecutionEvent::HistoryLoaded {
+                    execution_arn,
+                    result,
+                } => {
+                    if self.loading_history.as_ref() == Some(&execution_arn) {
+                        self.loading_history = None;
+                    }
+                    self.histories.insert(execution_arn, result);
+                }
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_events();
+
+        // Request continuous repaint while loading to show spinners
+        if self.loading || self.loading_history.is_some() {
+            ctx.request_repaint();
+        }
+
+        let title = format!("Executions: {}", self.state_machine_name);
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(title)
+            .id(egui::Id::new((
+                "step_functions_window",
+                &self.state_machine_arn,
+            )))
+            .open(&mut is_open)
+            .default_size([960.0, 640.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("State machine:").strong());
+            ui.label(&self.state_machine_name);
+            ui.label(RichText::new(format!("{} {}", self.account_id, self.region)).weak());
+        });
+
+        ui.horizontal(|ui| {
+            ui.label("Status:");
+            let current = STATUS_FILTERS
+                .iter()
+                .find(|(_, status)| *status == self.status_filter)
+                .map(|(label, _)| *label)
+                .unwrap_or("All");
+            let mut changed = false;
+            egui::ComboBox::from_id_salt(("sfn_status_filter", &self.state_machine_arn))
+                .selected_text(current)
+                .show_ui(ui, |ui| {
+                    for (label, status) in STATUS_FILTERS {
+                        changed |= ui
+                            .selectable_value(&mut self.status_filter, *status, *label)
+                            .changed();
+                    }
+                });
+            if ui
+                .add_enabled(!self.loading, egui::Button::new("Refresh"))
+                .clicked()
+                || changed
+            {
+                self.load_executions(false);
+            }
+            if self.loading {
+                ui.spinner();
+            }
+        });
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(FAILED_COLOR, error);
+        }
+        ui.separator();
+
+        egui::SidePanel::left(egui::Id::new(("sfn_executions", &self.state_machine_arn)))
+            .resizable(true)
+            .default_width(300.0)
+            .show_inside(ui, |ui| {
+                self.render_execution_list(ui);
+            });
+
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .id_salt("sfn_execution_details")
+                .auto_shrink([false, false])
+                .show(ui, |ui| {
+                    self.render_selected(ui);
+                });
+        });
+    }
+
+    fn render_execution_list(&mut self, ui: &mut Ui) {
+        let mut clicked = None;
+        egui::ScrollArea::vertical()
+            .id_salt("sfn_execution_list")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                if self.executions.is_empty() && !self.loading {
+                    ui.label(RichText::new("No executions").weak());
+                }
+                for execution in &self.executions {
+                    let selected = self.selected.as_ref() == Some(&execution.execution_arn);
+                    let response = ui
+                        .horizontal(|ui| {
+                            ui.label(
+                                RichText::new(status_label(&execution.status))
+                                    .monospace()
+                                    .color(execution_status_color(&execution.status)),
+                            )
+                            .on_hover_text(&execution.status);
+                            ui.vertical(|ui| {
+                                let response = ui.selectable_label(selected, &execution.name);
+                                ui.label(
+                                    RichText::new(format!(
+                                        "{}  {}",
+                                        format_timestamp(execution.start_date),
+                                        execution
+                                            .duration_ms()
+                                            .map(format_duration)
+                                            .unwrap_or_else(|| "running".to_string())
+                                    ))
+                                    .small()
+                                    .weak(),
+                                );
+                                response
+                            })
+                            .inner
+                        })
+                        .inner;
+                    if response.clicked() {
+                        clicked = Some(execution.execution_arn.clone());
+                    }
+                }
+
+                if self.next_token.is_some()
+                    && ui
+                        .add_enabled(!self.loading, egui::Button::new("Load more"))
+                        .clicked()
+                {
+                    self.load_executions(true);
+                }
+            });
+
+        if let Some(execution_arn) = clicked {
+            self.select_execution(execution_arn);
+        }
+    }
+
+    fn render_selected(&self, ui: &mut Ui) {
+        let Some(execution_arn) = &self.selected else {
+            ui.label("Select an execution to see its state transitions");
+            return;
+        };
+        let Some(summary) = self
+            .executions
+            .iter()
+            .find(|execution| &execution.execution_arn == execution_arn)
+        else {
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            ui.heading(&summary.name);
+            ui.label(
+                RichText::new(&summary.status)
+                    .strong()
+                    .color(execution_status_color(&summary.status)),
+            );
+        });
+        ui.label(
+            RichText::new(format!(
+                "Started {}{}",
+                format_timestamp(summary.start_date),
+                summary
+                    .duration_ms()
+                    .map(|ms| format!(", took {}", format_duration(ms)))
+                    .unwrap_or_default()
+            ))
+            .weak(),
+        );
+        ui.label(
+            RichText::new(&summary.execution_arn)
+                .monospace()
+                .small()
+                .weak(),
+        );
+        ui.add_space(6.0);
+
+        let history = match self.histories.get(execution_arn) {
+            Some(Ok(history)) => history,
+            Some(Err(e)) => {
+                ui.colored_label(FAILED_COLOR, e);
+                return;
+            }
+            None => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label("Loading history...");
+                });
+                return;
+            }
+        };
+
+        if let Some(error) = &history.error {
+            ui.colored_label(FAILED_COLOR, format!("Error: {}", error));
+            if let Some(cause) = &history.cause {
+                ui.label(RichText::new(cause).monospace().small());
+            }
+        }
+        render_payload(
+            ui,
+            "Execution input",
+            history.input.as_deref(),
+            execution_arn,
+        );
+        render_payload(
+            ui,
+            "Execution output",
+            history.output.as_deref(),
+            execution_arn,
+        );
+        ui.add_space(6.0);
+
+        ui.label(
+            RichText::new(format!("State transitions ({})", history.transitions.len())).strong(),
+        );
+        if history.truncated {
+            ui.label(
+                RichText::new("History is long; only the first events are shown")
+                    .color(Color32::from_rgb(255, 200, 100)),
+            );
+        }
+
+        let span = timeline_span(summary, history);
+        for (index, transition) in history.transitions.iter().enumerate() {
+            render_transition(ui, execution_arn, index, transition, span);
+        }
+    }
+}
+
+/// One state of the timeline: a header with status, offset, and duration bar,
+/// expanding to the state's input, output, error, and events
+fn render_transition(
+    ui: &mut Ui,
+    execution_arn: &str,
+    index: usize,
+    transition: &StateTransition,
+    (start, end): (i64, i64),
+) {
+    let id = ui.make_persistent_id(("sfn_transition", execution_arn, index));
+    egui::collapsing_header::CollapsingState::load_with_default_open(ui.ctx(), id, false)
+        .show_header(ui, |ui| {
+            let color = state_status_color(transition.status);
+            ui.label(
+                RichText::new(state_status_label(transition.status))
+                    .monospace()
+                    .color(color),
+            );
+            ui.label(RichText::new(&transition.name).strong());
+            ui.label(RichText::new(&transition.state_type).small().weak());
+
+            // Duration bar positioned within the execution's time span
+            let (rect, _) =
+                ui.allocate_exact_size(egui::vec2(BAR_WIDTH, 10.0), egui::Sense::hover());
+            let painter = ui.painter();
+            painter.rect_filled(rect, 2.0, ui.visuals().faint_bg_color);
+            let total = (end - start).max(1) as f32;
+            let from = (transition.entered_at - start) as f32 / total;
+            let to = (transition.exited_at.unwrap_or(end) - start) as f32 / total;
+            let bar = egui::Rect::from_min_max(
+                egui::pos2(
+                    rect.left() + rect.width() * from.clamp(0.0, 1.0),
+                    rect.top(),
+                ),
+                egui::pos2(
+                    // Keep instant states visible
+                    (rect.left() + rect.width() * to.clamp(0.0, 1.0))
+                        .max(rect.left() + rect.width() * from.clamp(0.0, 1.0) + 2.0),
+                    rect.bottom(),
+                ),
+            );
+            painter.rect_filled(bar, 2.0, color);
+
+            ui.label(
+                RichText::new(format!(
+                    "+{}  {}",
+                    format_duration(transition.entered_at - start),
+                    transition
+                        .duration_ms()
+                        .map(format_duration)
+                        .unwrap_or_else(|| "-".to_string())
+                ))
+                .small()
+                .monospace(),
+            );
+            if transition.retries > 0 {
+                ui.label(
+                    RichText::new(format!("{} retries", transition.retries))
+                        .small()
+                        .color(Color32::from_rgb(255, 200, 100)),
+                );
+            }
+        })
+        .body(|ui| {
+            if let Some(resource) = &transition.resource {
+                ui.label(RichText::new(format!("Resource: {}", resource)).small());
+            }
+            if let Some(error) = &transition.error {
+                ui.colored_label(FAILED_COLOR, format!("Error: {}", error));
+                if let Some(cause) = &transition.cause {
+                    ui.label(RichText::new(cause).monospace().small());
+                }
+            }
+            let salt = format!("{}#{}", execution_arn, index);
+            render_payload(ui, "Input", transition.input.as_deref(), &salt);
+            render_payload(ui, "Output", transition.output.as_deref(), &salt);
+
+            if !transition.events.is_empty() {
+                egui::CollapsingHeader::new(format!("Events ({})", transition.events.len()))
+                    .id_salt(("sfn_transition_events", &salt))
+                    .show(ui, |ui| {
+                        for event in &transition.events {
+                            ui.label(
+                                RichText::new(format!(
+                                    "#{:<4} +{:<8} {}{}",
+                                    event.id,
+                                    format_duration(event.timestamp - transition.entered_at),
+                                    event.event_type,
+                                    event
+                                        .error
+                                        .as_ref()
+                                        .map(|e| format!(" ({})", e))
+                                        .unwrap_or_default()
+                                ))
+                                .monospace()
+                                .small(),
+                            );
+                        }
+                    });
+            }
+        });
+}
+
+/// Collapsible JSON payload, pretty printed when it parses
+fn render_payload(ui: &mut Ui, label: &str, payload: Option<&str>, salt: &str) {
+    let Some(payload) = payload else {
+        return;
+    };
+    egui::CollapsingHeader::new(format!("{} ({} bytes)", label, payload.len()))
+        .id_salt(("sfn_payload", label, salt))
+        .show(ui, |ui| {
+            let mut text = pretty_json(payload);
+            egui::ScrollArea::vertical()
+                .id_salt(("sfn_payload_scroll", label, salt))
+                .max_height(220.0)
+                .show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut text)
+                            .code_editor()
+                            .desired_width(f32::INFINITY),
+                    );
+                });
+        });
+}
+
+/// Start and end of the timeline: the execution's run, widened to cover every state
+fn timeline_span(summary: &ExecutionSummary, history: &ExecutionHistory) -> (i64, i64) {
+    let start = history
+        .transitions
+        .iter()
+        .map(|transition| transition.entered_at)
+        .chain(std::iter::once(summary.start_date))
+        .min()
+        .unwrap_or(summary.start_date);
+    let end = history
+        .events
+        .iter()
+        .map(|event| event.timestamp)
+        .chain(summary.stop_date)
+        .max()
+        .unwrap_or(start);
+    (start, end)
+}
+
+fn pretty_json(payload: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(payload)
+        .ok()
+        .and_then(|value| serde_json::to_string_pretty(&value).ok())
+        .unwrap_or_else(|| payload.to_string())
+}
+
+/// "850 ms", "4.2 s", "3m 05s", "2h 03m"
+fn format_duration(ms: i64) -> String {
+    let ms = ms.max(0);
+    if ms < 1000 {
+        format!("{} ms", ms)
+    } else if ms < 60_000 {
+        format!("{:.1} s", ms as f64 / 1000.0)
+    } else if ms < 3_600_000 {
+        format!("{}m {:02}s", ms / 60_000, (ms % 60_000) / 1000)
+    } else {
+        format!("{}h {:02}m", ms / 3_600_000, (ms % 3_600_000) / 60_000)
+    }
+}
+
+fn format_timestamp(millis: i64) -> String {
+    DateTime::from_timestamp_millis(millis)
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| millis.to_string())
+}
+
+fn status_label(status: &str) -> &'static str {
+    match status {
+        "SUCCEEDED" => "OK",
+        "RUNNING" => "RUN",
+        "FAILED" | "TIMED_OUT" | "ABORTED" => "FAIL",
+        _ => "-",
+    }
+}
+
+fn execution_status_color(status: &str) -> Color32 {
+    match status {
+        "SUCCEEDED" => SUCCEEDED_COLOR,
+        "RUNNING" => RUNNING_COLOR,
+        "FAILED" | "TIMED_OUT" | "ABORTED" => FAILED_COLOR,
+        _ => Color32::GRAY,
+    }
+}
+
+fn state_status_label(status: StateStatus) -> &'static str {
+    match status {
+        StateStatus::Succeeded => "OK",
+        StateStatus::Failed => "FAIL",
+        StateStatus::Running => "RUN",
+    }
+}
+
+fn state_status_color(status: StateStatus) -> Color32 {
+    match status {
+        StateStatus::Succeeded => SUCCEEDED_COLOR,
+        StateStatus::Failed => FAILED_COLOR,
+        StateStatus::Running => RUNNING_COLOR,
+    }
+}
+
+impl FocusableWindow for StepFunctionsWindow {
+    type ShowParams = StepFunctionsShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "step_functions_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Executions: {}", self.state_machine_name)
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open on the state machine first
+        self.open_for_state_machine(params);
+
+        // Then show with focus
+        StepFunctionsWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(850), "850 ms");
+        assert_eq!(format_duration(4200), "4.2 s");
+        assert_eq!(format_duration(185_000), "3m 05s");
+        assert_eq!(format_duration(7_380_000), "2h 03m");
+        assert_eq!(format_duration(-5), "0 ms");
+    }
+
+    #[test]
+    fn test_pretty_json_keeps_invalid_payloads() {
+        assert_eq!(pretty_json(r#"{"a":1}"#), "{\n  \"a\": 1\n}");
+        assert_eq!(pretty_json("not json"), "not json");
+    }
+}
@@ -0,0 +1,305 @@
+//! Task Queue Window
+//!
+//! Adds agent tasks to the [`TaskQueue`](crate::app::agent_framework::TaskQueue),
+//! shows each task's state, and lets the user change priorities, reorder
+//! waiting tasks, cancel, and choose whether tasks run one at a time or
+//! several in parallel. The agent manager starts the tasks.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use eframe::egui;
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+
+use crate::app::agent_framework::{
+    QueuedTask, QueuedTaskState, TaskPriority, TaskQueue, MAX_PARALLEL_TASKS,
+};
+
+/// Change requested from a task row, applied after the list is drawn
+enum RowAction {
+    MoveUp(String),
+    MoveDown(String),
+    SetPriority(String, TaskPriority),
+    Cancel(String),
+    Requeue(String),
+    Remove(String),
+}
+
+/// State for the task queue window
+pub struct TaskQueueWindow {
+    open: bool,
+    name: String,
+    prompt: String,
+    priority: TaskPriority,
+    /// Validation message for the new task form
+    message: Option<String>,
+}
+
+impl Default for TaskQueueWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TaskQueueWindow {
+    pub fn new() -> Self {
+        Self {
+            open: false,
+            name: String::new(),
+            prompt: String::new(),
+            priority: TaskPriority::Normal,
+            message: None,
+        }
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.message = None;
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn show(&mut self, ctx: &Context, queue: &mut TaskQueue) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Task Queue")
+            .open(&mut is_open)
+            .default_size([750.0, 550.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                self.render_form(ui, queue);
+                ui.separator();
+                render_concurrency(ui, queue);
+                ui.separator();
+                render_tasks(ui, queue);
+            });
+        self.open = is_open;
+    }
+
+    fn render_form(&mut self, ui: &mut Ui, queue: &mut TaskQueue) {
+        egui::Grid::new("task_queue_form")
+            .num_columns(2)
+            .spacing([8.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("Name:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut self.name)
+                        .hint_text("e.g. Unused EBS volumes")
+                        .desired_width(400.0),
+                );
+                ui.end_row();
+
+                ui.label("Prompt:");
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.prompt)
+                        .hint_text("Instructions for the agent")
+                        .desired_rows(3)
+                        .desired_width(400.0),
+                );
+                ui.end_row();
+
+                ui.label("Priority:");
+                priority_selector(ui, "task_queue_new_priority", &mut self.priority);
+                ui.end_row();
+            });
+
+        ui.horizontal(|ui| {
+            if ui.button("Add to Queue").clicked() {
+                if self.prompt.trim().is_empty() {
+                    self.message = Some("Prompt is required".to_string());
+                } else {
+                    let name = match self.name.trim() {
+                        "" => self
+                            .prompt
+                            .trim()
+                            .lines()
+                            .next()
+                            .unwrap_or_default()
+                            .chars()
+                            .take(40)
+                            .collect(),
+                        name => name.to_string(),
+                    };
+                    log::info!("Queued agent task '{}'", name);
+                    queue.enqueue(QueuedTask::new(name, self.prompt.trim(), self.priority));
+                    self.name.clear();
+                    self.prompt.clear();
+                    self.message = None;
+                }
+            }
+            if let Some(message) = &self.message {
+                ui.label(
+                    RichText::new(message)
+                        .small()
+                        .color(Color32::from_rgb(220, 80, 80)),
+                );
+            }
+        });
+    }
+}
+
+fn render_concurrency(ui: &mut Ui, queue: &mut TaskQueue) {
+    ui.horizontal(|ui| {
+        ui.label("Run:");
+        let mut max_parallel = queue.max_parallel();
+        let mut parallel = max_parallel > 1;
+        ui.radio_value(&mut parallel, false, "One at a time");
+        ui.radio_value(&mut parallel, true, "In parallel, up to");
+        ui.add_enabled(
+            parallel,
+            egui::DragValue::new(&mut max_parallel).range(2..=MAX_PARALLEL_TASKS),
+        );
+        let max_parallel = match (parallel, max_parallel) {
+            (false, _) => 1,
+            (true, 1) => 2,
+            (true, n) => n,
+        };
+        queue.set_max_parallel(max_parallel);
+
+        ui.separator();
+        ui.label(format!(
+            "{} queued, {} running",
+            queue.count(QueuedTaskState::Queued),
+            queue.count(QueuedTaskState::Running)
+        ));
+    });
+    ui.label(
+        RichText::new("Tasks run while the application is open and logged in.")
+            .small()
+            .weak(),
+    );
+}
+
+fn render_tasks(ui: &mut Ui, queue: &mut TaskQueue) {
+    ui.horizontal(|ui| {
+        ui.strong("Tasks");
+        let finished = queue.tasks().iter().any(|t| t.state.is_finished());
+        if ui
+            .add_enabled(finished, egui::Button::new("Clear Finished").small())
+            .clicked()
+        {
+            queue.clear_finished();
+        }
+    });
+
+    let mut action = None;
+    ScrollArea::vertical()
+        .id_salt("task_queue_scroll")
+        .auto_shrink([false, false])
+        .show(ui, |ui| {
+            if queue.tasks().is_empty() {
+                ui.label(RichText::new("No tasks queued").weak());
+                return;
+            }
+            egui::Grid::new("task_queue_grid")
+                .striped(true)
+                .num_columns(5)
+                .show(ui, |ui| {
+                    for task in queue.tasks() {
+                        if let Some(row_action) = render_task_row(ui, task) {
+                            action = Some(row_action);
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+
+    match action {
+        Some(RowAction::MoveUp(id)) => queue.move_task(&id, true),
+        Some(RowAction::MoveDown(id)) => queue.move_task(&id, false),
+        Some(RowAction::SetPriority(id, priority)) => queue.set_priority(&id, priority),
+        Some(RowAction::Cancel(id)) => queue.cancel(&id),
+        Some(RowAction::Requeue(id)) => queue.requeue(&id),
+        Some(RowAction::Remove(id)) => queue.remove(&id),
+        None => {}
+    }
+}
+
+fn render_task_row(ui: &mut Ui, task: &QueuedTask) -> Option<RowAction> {
+    let mut action = None;
+
+    let color = match task.state {
+        QueuedTaskState::Queued => Color32::GRAY,
+        QueuedTaskState::Running => Color32::from_rgb(100, 150, 250),
+        QueuedTaskState::Completed => Color32::from_rgb(100, 180, 100),
+        QueuedTaskState::Failed => Color32::from_rgb(220, 80, 80),
+        QueuedTaskState::Cancelled => Color32::from_rgb(230, 160, 60),
+    };
+    ui.label(RichText::new(task.state.display_name()).color(color));
+
+    let tooltip = match &task.summary {
+        Some(summary) => format!("{}\n\nResult: {}", task.prompt, summary),
+        None => task.prompt.clone(),
+    };
+    ui.label(&task.name).on_hover_text(tooltip);
+
+    let mut priority = task.priority;
+    ui.add_enabled_ui(!task.state.is_finished(), |ui| {
+        priority_selector(
+            ui,
+            &format!("task_queue_priority_{}", task.id),
+            &mut priority,
+        );
+    });
+    if priority != task.priority {
+        action = Some(RowAction::SetPriority(task.id.clone(), priority));
+    }
+
+    let time = task
+        .finished_at
+        .or(task.started_at)
+        .unwrap_or(task.enqueued_at);
+    ui.label(
+        RichText::new(
+            time.with_timezone(&chrono::Local)
+                .format("%H:%M:%S")
+                .to_string(),
+        )
+        .small()
+        .weak(),
+    );
+
+    ui.horizontal(|ui| match task.state {
+        QueuedTaskState::Queued => {
+            if ui.small_button("⬆").on_hover_text("Move up").clicked() {
+                action = Some(RowAction::MoveUp(task.id.clone()));
+            }
+            if ui.small_button("⬇").on_hover_text("Move down").clicked() {
+                action = Some(RowAction::MoveDown(task.id.clone()));
+            }
+            if ui.small_button("Cancel").clicked() {
+                action = Some(RowAction::Cancel(task.id.clone()));
+            }
+        }
+        QueuedTaskState::Running => {
+            if ui.small_button("Cancel").clicked() {
+                action = Some(RowAction::Cancel(task.id.clone()));
+            }
+        }
+        _ => {
+            if ui.small_button("Requeue").clicked() {
+                action = Some(RowAction::Requeue(task.id.clone()));
+            }
+            if ui.small_button("Remove").clicked() {
+                action = Some(RowAction::Remove(task.id.clone()));
+            }
+        }
+    });
+
+    action
+}
+
+fn priority_selector(ui: &mut Ui, id: &str, priority: &mut TaskPriority) {
+    egui::ComboBox::from_id_salt(id)
+        .selected_text(priority.display_name())
+        .width(80.0)
+        .show_ui(ui, |ui| {
+            for option in TaskPriority::all() {
+                ui.selectable_value(priority, *option, option.display_name());
+            }
+        });
+}
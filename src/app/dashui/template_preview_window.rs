@@ -0,0 +1,347 @@
+//! Template Preview Window
+//!
+//! Shows a CloudFormation template generated from resources selected in the
+//! Explorer, in JSON or YAML, with copy and save-to-file export. The template
+//! can be regenerated with the IaC Generator for resources whose full
+//! configuration the Explorer does not load.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::cloudformation_manager::template_generator::template_from_resources;
+use crate::app::cloudformation_manager::{DeploymentManager, GeneratedTemplate, GenerationMethod};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use crate::app::resource_explorer::state::ResourceEntry;
+use eframe::egui;
+use egui::{Color32, Context, RichText, Ui};
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Parameters for showing the Template Preview window
+#[derive(Clone)]
+pub struct TemplatePreviewShowParams {
+    pub resources: Vec<ResourceEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TemplateFormat {
+    Json,
+    Yaml,
+}
+
+impl TemplateFormat {
+    fn extension(&self) -> &'static str {
+        match self {
+            TemplateFormat::Json => "json",
+            TemplateFormat::Yaml => "yaml",
+        }
+    }
+}
+
+pub struct TemplatePreviewWindow {
+    pub open: bool,
+    resources: Vec<ResourceEntry>,
+    template: Option<GeneratedTemplate>,
+    format: TemplateFormat,
+    /// Template text in the selected format
+    body: String,
+
+    generating: bool,
+    error_message: Option<String>,
+    message: Option<String>,
+
+    // Services
+    manager: Arc<DeploymentManager>,
+
+    // Channel for receiving results from background threads
+    result_receiver: mpsc::Receiver<Result<GeneratedTemplate, String>>,
+    result_sender: mpsc::Sender<Result<GeneratedTemplate, String>>,
+}
+
+impl TemplatePreviewWindow {
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let (result_sender, result_receiver) = mpsc::channel();
+
+        Self {
+            open: false,
+            resources: Vec::new(),
+            template: None,
+            format: TemplateFormat::Json,
+            body: String::new(),
+            generating: false,
+            error_message: None,
+            message: None,
+            manager: Arc::new(DeploymentManager::new(credential_coordinator)),
+            result_receiver,
+            result_sender,
+        }
+    }
+
+    /// Open the window with a template generated locally from the resources
+    pub fn open_for_resources(&mut self, params: TemplatePreviewShowParams) {
+        self.resources = params.resources;
+        self.error_message = None;
+        self.message = None;
+        self.open = true;
+        self.set_template(template_from_resources(&self.resources));
+    }
+
+    fn set_template(&mut self, template: GeneratedTemplate) {
+        self.template = Some(template);
+        self.render_body();
+    }
+
+    /// Render the template in the selected format
+    fn render_body(&mut self) {
+        let Some(template) = &self.template else {
+            return;
+        };
+        match self.format {
+            TemplateFormat::Json => self.body = template.body.clone(),
+            TemplateFormat::Yaml => match template.to_yaml() {
+                Ok(yaml) => self.body = yaml,
+                Err(e) => {
+                    self.error_message = Some(format!("{:#}", e));
+                    self.format = TemplateFormat::Json;
+                    self.body = template.body.clone();
+                }
+            },
+        }
+    }
+
+    /// Regenerate with the IaC Generator in the background
+    ///
+    /// The IaC Generator works on one account and region; resources elsewhere
+    /// are left out.
+    fn generate_with_iac_generator(&mut self) {
+        let Some(first) = self.resources.first() else {
+            return;
+        };
+        let account_id = first.account_id.clone();
+        let region = first.region.clone();
+        let resources: Vec<ResourceEntry> = self
+            .resources
+            .iter()
+            .filter(|r| r.account_id == account_id && r.region == region)
+            .cloned()
+            .collect();
+        let left_out = self.resources.len() - resources.len();
+
+        self.generating = true;
+        self.error_message = None;
+        self.message = None;
+
+        spawn_with_client(
+            &self.manager,
+            &self.result_sender,
+            move |manager| async move {
+                manager
+                    .generate_template(&account_id, &region, &resources)
+                    .await
+                    .map(|mut template| {
+                        if left_out > 0 {
+                            template.warnings.push(format!(
+                                "{} resource{} outside {} / {} left out",
+                                left_out,
+                                if left_out == 1 { "" } else { "s" },
+                                account_id,
+                                region
+                            ));
+                        }
+                        template
+                    })
+                    .map_err(|e| format!("{:#}", e))
+            },
+        );
+    }
+
+    /// Write the template to the Downloads directory
+    fn save_file(&self) -> anyhow::Result<PathBuf> {
+        let directory = dirs::download_dir()
+            .or_else(dirs::home_dir)
+            .unwrap_or_else(|| PathBuf::from("."));
+        let path = directory.join(format!(
+            "template-{}.{}",
+            chrono::Local::now().format("%Y%m%d-%H%M%S"),
+            self.format.extension()
+        ));
+        std::fs::write(&path, &self.body)?;
+        Ok(path)
+    }
+
+    /// Poll for results from background threads
+    fn poll_results(&mut self) {
+        while let Ok(result) = self.result_receiver.try_recv() {
+            self.generating = false;
+            match result {
+                Ok(template) => self.set_template(template),
+                Err(error) => self.error_message = Some(error),
+            }
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_results();
+
+        // Request repaints while generating to keep the spinner moving
+        if self.generating {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new(self.window_title())
+            .id(egui::Id::new((
+                "template_preview_window",
+                self.resources.first().map(|r| r.selection_key()),
+                self.resources.len(),
+            )))
+            .open(&mut is_open)
+            .default_size([800.0, 640.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.ui_content(ui);
+        });
+
+        self.open = is_open;
+    }
+
+    fn ui_content(&mut self, ui: &mut Ui) {
+        if let Some(template) = &self.template {
+            ui.horizontal(|ui| {
+                ui.label(RichText::new("Generated:").strong());
+                ui.label(format!(
+                    "{} of {} resources ({})",
+                    template.resource_count,
+                    self.resources.len(),
+                    template.method.label()
+                ));
+            });
+        }
+
+        ui.horizontal(|ui| {
+            let before = self.format;
+            ui.radio_value(&mut self.format, TemplateFormat::Json, "JSON");
+            ui.radio_value(&mut self.format, TemplateFormat::Yaml, "YAML");
+            if self.format != before {
+                self.render_body();
+            }
+            ui.separator();
+
+            if ui.button("Copy").clicked() {
+                ui.ctx().copy_text(self.body.clone());
+            }
+            if ui
+                .button("Save File")
+                .on_hover_text("Save the template to Downloads")
+                .clicked()
+            {
+                match self.save_file() {
+                    Ok(path) => self.message = Some(format!("Saved {}", path.display())),
+                    Err(e) => {
+                        self.error_message = Some(format!("Failed to save template: {:#}", e))
+                    }
+                }
+            }
+
+            ui.separator();
+            let is_local = self
+                .template
+                .as_ref()
+                .is_some_and(|t| t.method == GenerationMethod::Local);
+            if is_local && !crate::app::capabilities::READ_ONLY {
+                if ui
+                    .add_enabled(!self.generating, egui::Button::new("Use IaC Generator"))
+                    .on_hover_text(
+                        "Generate with CloudFormation, which reads each resource's full configuration",
+                    )
+                    .clicked()
+                {
+                    self.generate_with_iac_generator();
+                }
+            } else if !is_local && ui.button("Use Local").clicked() {
+                self.set_template(template_from_resources(&self.resources));
+            }
+            if self.generating {
+                ui.spinner();
+                ui.label("Generating...");
+            }
+        });
+
+        if let Some(error) = &self.error_message {
+            ui.colored_label(Color32::RED, format!("Error: {}", error));
+        }
+        if let Some(message) = &self.message {
+            ui.label(message);
+        }
+        if let Some(template) = &self.template {
+            if !template.warnings.is_empty() {
+                egui::CollapsingHeader::new(format!("Notes ({})", template.warnings.len()))
+                    .default_open(true)
+                    .show(ui, |ui| {
+                        for warning in &template.warnings {
+                            ui.colored_label(ui.visuals().warn_fg_color, warning);
+                        }
+                    });
+            }
+        }
+        ui.separator();
+
+        egui::ScrollArea::both()
+            .id_salt("template_preview_body")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                ui.add(
+                    egui::TextEdit::multiline(&mut self.body.as_str())
+                        .code_editor()
+                        .desired_width(f32::INFINITY),
+                );
+            });
+    }
+}
+
+impl FocusableWindow for TemplatePreviewWindow {
+    type ShowParams = TemplatePreviewShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "template_preview_window"
+    }
+
+    fn window_title(&self) -> String {
+        format!("Template from Selection ({})", self.resources.len())
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        // Open for the resources first
+        self.open_for_resources(params);
+
+        // Then show with focus
+        TemplatePreviewWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
@@ -0,0 +1,812 @@
+//! Threat Findings Window
+//!
+//! Lists the active GuardDuty and Security Hub findings loaded with the
+//! Explorer's detectors and hubs. Findings can be narrowed to one account and
+//! region or to the findings naming one resource (from a tree badge), their
+//! affected resources shown in the Explorer, and selected findings archived
+//! (GuardDuty) or suppressed with a note (Security Hub).
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::security_findings_window::severity_color;
+use super::window_focus::FocusableWindow;
+use super::window_utils::spawn_with_client;
+use crate::app::resource_explorer::aws_client::AWSResourceClient;
+use crate::app::resource_explorer::cache::SharedResourceCache;
+use crate::app::resource_explorer::resource_actions::{ActionAuditEntry, ActionAuditLog};
+use crate::app::resource_explorer::resource_finder::{
+    explorer_resource_id, search_cache, FindQuery, RevealRequest,
+};
+use crate::app::resource_explorer::security_insights::Severity;
+use crate::app::resource_explorer::threat_findings::{
+    global_findings, severity_counts, FindingSource, ThreatFinding,
+};
+use eframe::egui;
+use egui::{Context, RichText, Ui};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::mpsc;
+use std::sync::Arc;
+
+/// Which findings the window lists
+#[derive(Debug, Clone, PartialEq)]
+enum FindingScope {
+    All,
+    Region {
+        account_id: String,
+        region: String,
+    },
+    Resource {
+        account_id: String,
+        resource_id: String,
+    },
+}
+
+impl FindingScope {
+    fn includes(&self, finding: &ThreatFinding) -> bool {
+        match self {
+            Self::All => true,
+            Self::Region { account_id, region } => {
+                finding.account_id == *account_id && finding.region == *region
+            }
+            Self::Resource {
+                account_id,
+                resource_id,
+            } => {
+                finding.account_id == *account_id
+                    && finding
+                        .resources
+                        .iter()
+                        .any(|reference| explorer_resource_id(reference) == *resource_id)
+            }
+        }
+    }
+
+    fn label(&self) -> String {
+        match self {
+            Self::All => "All loaded accounts and regions".to_string(),
+            Self::Region { account_id, region } => format!("{} / {}", account_id, region),
+            Self::Resource {
+                account_id,
+                resource_id,
+            } => format!("{} in {}", resource_id, account_id),
+        }
+    }
+}
+
+/// Archive or suppress request waiting for confirmation
+#[derive(Debug, Clone)]
+struct PendingChange {
+    findings: Vec<ThreatFinding>,
+}
+
+/// Result of an archive/suppress run on the background thread
+struct ChangeOutcome {
+    /// Findings archived or suppressed
+    changed: Vec<String>,
+    errors: Vec<String>,
+}
+
+pub struct ThreatFindingsWindow {
+    pub open: bool,
+
+    // State
+    findings: Vec<ThreatFinding>,
+    /// Store version the list was copied at
+    findings_version: Option<u64>,
+    selected: HashSet<String>,
+    /// Finding shown in the details pane
+    details: Option<String>,
+    pending_change: Option<PendingChange>,
+    suppress_note: String,
+    running: bool,
+    status: Option<Result<String, String>>,
+
+    // Filters
+    scope: FindingScope,
+    sources: HashSet<FindingSource>,
+    severities: HashSet<Severity>,
+    text_filter: String,
+    /// Resource picked with "Show", taken by the app
+    pending_reveal: Option<RevealRequest>,
+
+    // Services
+    aws_client: Option<Arc<AWSResourceClient>>,
+    cache: Option<Arc<SharedResourceCache>>,
+
+    // Channel for archive/suppress results from the background thread
+    sender: mpsc::Sender<ChangeOutcome>,
+    receiver: mpsc::Receiver<ChangeOutcome>,
+}
+
+impl Default for ThreatFindingsWindow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThreatFindingsWindow {
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            open: false,
+            findings: Vec::new(),
+            findings_version: None,
+            selected: HashSet::new(),
+            details: None,
+            pending_change: None,
+            suppress_note: String::new(),
+            running: false,
+            status: None,
+            scope: FindingScope::All,
+            sources: [FindingSource::GuardDuty, FindingSource::SecurityHub]
+                .into_iter()
+                .collect(),
+            severities: Severity::all().into_iter().collect(),
+            text_filter: String::new(),
+            pending_reveal: None,
+            aws_client: None,
+            cache: None,
+            sender,
+            receiver,
+        }
+    }
+
+    /// Open the window on the findings of one account and region, or of one
+    /// resource when `resource_id` is given
+    pub fn open_for(
+        &mut self,
+        aws_client: Option<Arc<AWSResourceClient>>,
+        cache: Arc<SharedResourceCache>,
+        account_id: String,
+        region: String,
+        resource_id: Option<String>,
+    ) {
+        self.aws_client = aws_client;
+        self.cache = Some(cache);
+        self.scope = match resource_id {
+            Some(resource_id) => FindingScope::Resource {
+                account_id,
+                resource_id,
+            },
+            None => FindingScope::Region { account_id, region },
+        };
+        self.selected.clear();
+        self.pending_change = None;
+        self.open = true;
+    }
+
+    /// Take the resource picked with "Show" to reveal in the Explorer
+    pub fn take_reveal_request(&mut self) -> Option<RevealRequest> {
+        self.pending_reveal.take()
+    }
+
+    /// Copy the findings store when it changed
+    fn sync_findings(&mut self) {
+        let version = global_findings().version();
+        if self.findings_version == Some(version) {
+            return;
+        }
+        self.findings = global_findings().all();
+        self.findings_version = Some(version);
+        let ids: HashSet<&str> = self.findings.iter().map(|f| f.id.as_str()).collect();
+        self.selected.retain(|id| ids.contains(id.as_str()));
+        if self
+            .details
+            .as_ref()
+            .is_some_and(|id| !ids.contains(id.as_str()))
+        {
+            self.details = None;
+        }
+    }
+
+    /// Findings to list: most severe first, then most recently updated
+    fn visible_findings(&self) -> Vec<&ThreatFinding> {
+        let filter = self.text_filter.to_lowercase();
+        let mut findings: Vec<&ThreatFinding> = self
+            .findings
+            .iter()
+            .filter(|finding| self.scope.includes(finding))
+            .filter(|finding| self.sources.contains(&finding.source))
+            .filter(|finding| self.severities.contains(&finding.severity))
+            .filter(|finding| {
+                filter.is_empty()
+                    || [
+                        finding.title.as_str(),
+                        finding.finding_type.as_str(),
+                        finding.account_id.as_str(),
+                    ]
+                    .into_iter()
+                    .chain(finding.resources.iter().map(String::as_str))
+                    .any(|field| field.to_lowercase().contains(&filter))
+            })
+            .collect();
+        findings.sort_by(|a, b| {
+            a.severity
+                .cmp(&b.severity)
+                .then_with(|| b.updated_at.cmp(&a.updated_at))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+        findings
+    }
+
+    /// Where to show a finding's resource in the Explorer
+    ///
+    /// Only resources already loaded in the Explorer can be shown; findings
+    /// also name resources of types the Explorer does not list.
+    fn reveal_request(&self, account_id: &str, reference: &str) -> Option<RevealRequest> {
+        let query = FindQuery::parse(reference)?;
+        let cache = self.cache.as_ref()?;
+        let resource = search_cache(cache, &query)
+            .into_iter()
+            .find(|resource| resource.account_id == account_id)?;
+        Some(RevealRequest {
+            query: FindQuery::ResourceId(resource.resource_id.clone()),
+            account_id: resource.account_id.clone(),
+            region: resource.region.clone(),
+            resource_type: resource.resource_type.clone(),
+            selection_key: Some(resource.selection_key()),
+        })
+    }
+
+    /// Archive the selected GuardDuty findings and suppress the selected
+    /// Security Hub findings on a background thread
+    fn apply_change(&mut self, change: PendingChange) {
+        let Some(aws_client) = self.aws_client.clone() else {
+            self.status = Some(Err("Not logged in to AWS".to_string()));
+            return;
+        };
+        let note = self.suppress_note.trim().to_string();
+        self.running = true;
+        self.status = None;
+
+        spawn_with_client(&aws_client, &self.sender, move |aws_client| {
+            archive_or_suppress(aws_client, change.findings, note)
+        });
+    }
+
+    /// Poll for results from the background thread
+    fn poll_results(&mut self) {
+        while let Ok(outcome) = self.receiver.try_recv() {
+            self.running = false;
+            global_findings().remove(&outcome.changed);
+            self.status = Some(if outcome.errors.is_empty() {
+                Ok(format!(
+                    "{} findings archived or suppressed",
+                    outcome.changed.len()
+                ))
+            } else {
+                Err(outcome.errors.join("\n"))
+            });
+        }
+    }
+
+    pub fn show(&mut self, ctx: &Context) {
+        self.show_with_focus(ctx, false);
+    }
+
+    pub fn show_with_focus(&mut self, ctx: &Context, bring_to_front: bool) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_results();
+        self.sync_findings();
+        if self.running {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        // Store open state locally to avoid borrow checker issues
+        let mut is_open = self.open;
+
+        let mut window = egui::Window::new("Threat Findings")
+            .open(&mut is_open)
+            .default_size([980.0, 520.0])
+            .resizable(true)
+            .collapsible(true);
+
+        if bring_to_front {
+            window = window.order(egui::Order::Foreground);
+        }
+
+        window.show(ctx, |ui| {
+            self.render_toolbar(ui);
+            ui.separator();
+            self.render_actions(ui);
+            ui.separator();
+            egui::SidePanel::right("threat_finding_details")
+                .resizable(true)
+                .default_width(320.0)
+                .show_inside(ui, |ui| self.render_details(ui));
+            egui::CentralPanel::default().show_inside(ui, |ui| self.render_findings(ui));
+        });
+
+        // Update open state after window is shown
+        self.open = is_open;
+    }
+
+    fn render_toolbar(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(self.scope.label()).strong());
+            if self.scope != FindingScope::All && ui.small_button("Show all").clicked() {
+                self.scope = FindingScope::All;
+            }
+            ui.separator();
+            let scoped: Vec<ThreatFinding> = self
+                .findings
+                .iter()
+                .filter(|finding| self.scope.includes(finding))
+                .cloned()
+                .collect();
+            for (severity, count) in Severity::all().into_iter().zip(severity_counts(&scoped)) {
+                let mut shown = self.severities.contains(&severity);
+                let text = RichText::new(format!("{} ({})", severity.label(), count))
+                    .color(severity_color(ui, severity));
+                if ui.checkbox(&mut shown, text).changed() {
+                    if shown {
+                        self.severities.insert(severity);
+                    } else {
+                        self.severities.remove(&severity);
+                    }
+                }
+            }
+        });
+
+        ui.horizontal(|ui| {
+            for source in [FindingSource::GuardDuty, FindingSource::SecurityHub] {
+                let mut shown = self.sources.contains(&source);
+                if ui.checkbox(&mut shown, source.label()).changed() {
+                    if shown {
+                        self.sources.insert(source);
+                    } else {
+                        self.sources.remove(&source);
+                    }
+                }
+            }
+            ui.separator();
+            ui.add(
+                egui::TextEdit::singleline(&mut self.text_filter)
+                    .hint_text("Filter findings")
+                    .desired_width(180.0),
+            );
+        });
+
+        if self.findings.is_empty() {
+            ui.label(
+                RichText::new(
+                    "Findings load with GuardDuty detectors and Security Hub hubs in the Explorer",
+                )
+                .color(ui.visuals().warn_fg_color),
+            );
+        }
+    }
+
+    fn render_actions(&mut self, ui: &mut Ui) {
+        let selected: Vec<ThreatFinding> = self
+            .findings
+            .iter()
+            .filter(|finding| self.selected.contains(&finding.id))
+            .cloned()
+            .collect();
+
+        ui.horizontal(|ui| {
+            ui.label(format!("{} selected", selected.len()));
+            if !selected.is_empty() && ui.small_button("Clear").clicked() {
+                self.selected.clear();
+            }
+            if crate::app::capabilities::READ_ONLY {
+                return;
+            }
+            ui.separator();
+            ui.label("Suppression note:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.suppress_note)
+                    .hint_text("Why these findings are not actionable")
+                    .desired_width(240.0),
+            );
+            if ui
+                .add_enabled(
+                    !selected.is_empty() && !self.running && self.pending_change.is_none(),
+                    egui::Button::new("Archive / Suppress..."),
+                )
+                .on_hover_text(
+                    "Archive GuardDuty findings and set Security Hub findings to SUPPRESSED",
+                )
+                .clicked()
+            {
+                self.pending_change = Some(PendingChange {
+                    findings: selected.clone(),
+                });
+            }
+            if self.running {
+                ui.spinner();
+            }
+        });
+
+        if let Some(change) = self.pending_change.clone() {
+            let guardduty = change
+                .findings
+                .iter()
+                .filter(|finding| finding.source == FindingSource::GuardDuty)
+                .count();
+            let securityhub = change.findings.len() - guardduty;
+            ui.horizontal(|ui| {
+                ui.label(
+                    RichText::new(format!(
+                        "Archive {} GuardDuty and suppress {} Security Hub findings?",
+                        guardduty, securityhub
+                    ))
+                    .color(ui.visuals().warn_fg_color),
+                );
+                if ui.button("Confirm").clicked() {
+                    self.pending_change = None;
+                    self.apply_change(change);
+                }
+                if ui.button("Cancel").clicked() {
+                    self.pending_change = None;
+                }
+            });
+        }
+
+        match &self.status {
+            Some(Ok(message)) => {
+                ui.label(RichText::new(message).small());
+            }
+            Some(Err(message)) => {
+                ui.label(
+                    RichText::new(message)
+                        .small()
+                        .color(ui.visuals().error_fg_color),
+                );
+            }
+            None => {}
+        }
+    }
+
+    fn render_findings(&mut self, ui: &mut Ui) {
+        let findings: Vec<ThreatFinding> = self.visible_findings().into_iter().cloned().collect();
+        if findings.is_empty() {
+            if !self.findings.is_empty() {
+                ui.label("No findings match the filters");
+            }
+            return;
+        }
+
+        egui::ScrollArea::both()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::Grid::new("threat_findings_grid")
+                    .num_columns(6)
+                    .striped(true)
+                    .show(ui, |ui| {
+                        ui.label("");
+                        ui.strong("Severity");
+                        ui.strong("Finding");
+                        ui.strong("Source");
+                        ui.strong("Account / Region");
+                        ui.strong("Updated");
+                        ui.end_row();
+
+                        for finding in &findings {
+                            let mut checked = self.selected.contains(&finding.id);
+                            if ui.checkbox(&mut checked, "").changed() {
+                                if checked {
+                                    self.selected.insert(finding.id.clone());
+                                } else {
+                                    self.selected.remove(&finding.id);
+                                }
+                            }
+                            ui.colored_label(
+                                severity_color(ui, finding.severity),
+                                finding.severity.label(),
+                            );
+                            let is_current = self.details.as_deref() == Some(finding.id.as_str());
+                            if ui
+                                .selectable_label(is_current, &finding.title)
+                                .on_hover_text(&finding.finding_type)
+                                .clicked()
+                            {
+                                self.details = Some(finding.id.clone());
+                            }
+                            ui.label(finding.source.label());
+                            ui.label(format!("{} / {}", finding.account_id, finding.region));
+                            ui.label(finding.updated_at.as_deref().unwrap_or("-"));
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    fn render_details(&mut self, ui: &mut Ui) {
+        let Some(finding) = self
+            .details
+            .as_ref()
+            .and_then(|id| self.findings.iter().find(|finding| finding.id == *id))
+            .cloned()
+        else {
+            ui.label(RichText::new("Select a finding to see its details").weak());
+            return;
+        };
+
+        let mut reveal = None;
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                ui.label(RichText::new(&finding.title).strong());
+                ui.colored_label(
+                    severity_color(ui, finding.severity),
+                    format!("{} - {}", finding.severity.label(), finding.source.label()),
+                );
+                ui.label(RichText::new(&finding.finding_type).small().monospace());
+                ui.add_space(4.0);
+                if !finding.description.is_empty() {
+                    ui.label(&finding.description);
+                    ui.add_space(4.0);
+                }
+
+                ui.strong("Affected resources");
+                if finding.resources.is_empty() {
+                    ui.label(RichText::new("None reported").weak());
+                }
+                for reference in &finding.resources {
+                    ui.horizontal(|ui| {
+                        let request = self.reveal_request(&finding.account_id, reference);
+                        if ui
+                            .add_enabled(request.is_some(), egui::Button::new("Show").small())
+                            .on_hover_text("Show the resource in the Explorer")
+                            .on_disabled_hover_text("The resource is not loaded in the Explorer")
+                            .clicked()
+                        {
+                            reveal = request;
+                        }
+                        ui.label(RichText::new(reference).small());
+                    });
+                }
+
+                ui.add_space(4.0);
+                ui.label(RichText::new(format!("ID: {}", finding.id)).small().weak());
+                if let Some(updated_at) = &finding.updated_at {
+                    ui.label(
+                        RichText::new(format!("Updated: {}", updated_at))
+                            .small()
+                            .weak(),
+                    );
+                }
+            });
+
+        if reveal.is_some() {
+            self.pending_reveal = reveal;
+        }
+    }
+}
+
+/// Archive GuardDuty findings per detector and suppress Security Hub findings
+/// per account and region, recording one audit entry per finding
+async fn archive_or_suppress(
+    aws_client: Arc<AWSResourceClient>,
+    findings: Vec<ThreatFinding>,
+    note: String,
+) -> ChangeOutcome {
+    let mut groups: BTreeMap<(&'static str, String, String, String), Vec<&ThreatFinding>> =
+        BTreeMap::new();
+    for finding in &findings {
+        let owner = match finding.source {
+            FindingSource::GuardDuty => finding.owner.clone(),
+            // Security Hub updates name each finding's product, not a detector
+            FindingSource::SecurityHub => String::new(),
+        };
+        groups
+            .entry((
+                finding.source.label(),
+                finding.account_id.clone(),
+                finding.region.clone(),
+                owner,
+            ))
+            .or_default()
+            .push(finding);
+    }
+
+    let audit_log = ActionAuditLog::new()
+        .inspect_err(|e| tracing::warn!("Finding action audit log unavailable: {:#}", e))
+        .ok();
+    let mut outcome = ChangeOutcome {
+        changed: Vec::new(),
+        errors: Vec::new(),
+    };
+
+    for ((_, account_id, region, detector_id), group) in groups {
+        let source = group[0].source;
+        let ids: Vec<String> = group.iter().map(|finding| finding.id.clone()).collect();
+        let (action_id, result): (&str, Result<Vec<String>, String>) = match source {
+            FindingSource::GuardDuty => (
+                "guardduty.archive_findings",
+                aws_client
+                    .get_guardduty_service()
+                    .archive_findings(&account_id, &region, &detector_id, &ids)
+                    .await
+                    .map(|_| Vec::new())
+                    .map_err(|e| format!("{:#}", e)),
+            ),
+            FindingSource::SecurityHub => {
+                let pairs: Vec<(String, String)> = group
+                    .iter()
+                    .map(|finding| (finding.id.clone(), finding.owner.clone()))
+                    .collect();
+                (
+                    "securityhub.suppress_findings",
+                    aws_client
+                        .get_securityhub_service()
+                        .suppress_findings(&account_id, &region, &pairs, &note)
+                        .await
+                        .map_err(|e| format!("{:#}", e)),
+                )
+            }
+        };
+
+        let mut changed = 0;
+        for id in &ids {
+            let finding_result = match &result {
+                Ok(unprocessed) if unprocessed.contains(id) => {
+                    Err("Security Hub did not process the update".to_string())
+                }
+                Ok(_) => Ok(()),
+                Err(message) => Err(message.clone()),
+            };
+            if let Some(audit_log) = &audit_log {
+                let entry = ActionAuditEntry {
+                    timestamp: chrono::Utc::now(),
+                    action_id: action_id.to_string(),
+                    account_id: account_id.clone(),
+                    region: region.clone(),
+                    resource_type: source.resource_type().to_string(),
+                    resource_id: id.clone(),
+                    success: finding_result.is_ok(),
+                    message: match &finding_result {
+                        Ok(()) if note.is_empty() => "Done".to_string(),
+                        Ok(()) => format!("Done: {}", note),
+                        Err(message) => message.clone(),
+                    },
+                };
+                if let Err(e) = audit_log.record(&entry) {
+                    tracing::warn!("Failed to write finding audit entry: {:#}", e);
+                }
+            }
+            if finding_result.is_ok() {
+                outcome.changed.push(id.clone());
+                changed += 1;
+            }
+        }
+        if let Err(message) = result {
+            outcome.errors.push(format!(
+                "{} {} / {}: {}",
+                source.label(),
+                account_id,
+                region,
+                message
+            ));
+        } else if changed < ids.len() {
+            outcome.errors.push(format!(
+                "{} {} / {}: some findings were not updated",
+                source.label(),
+                account_id,
+                region
+            ));
+        }
+    }
+
+    outcome
+}
+
+impl FocusableWindow for ThreatFindingsWindow {
+    type ShowParams = crate::app::dashui::window_focus::SimpleShowParams;
+
+    fn window_id(&self) -> &'static str {
+        "threat_findings_window"
+    }
+
+    fn window_title(&self) -> String {
+        "Threat Findings".to_string()
+    }
+
+    fn is_open(&self) -> bool {
+        self.open
+    }
+
+    fn show_with_focus(
+        &mut self,
+        ctx: &egui::Context,
+        _params: Self::ShowParams,
+        bring_to_front: bool,
+    ) {
+        ThreatFindingsWindow::show_with_focus(self, ctx, bring_to_front);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(
+        id: &str,
+        source: FindingSource,
+        severity: Severity,
+        region: &str,
+        updated_at: &str,
+    ) -> ThreatFinding {
+        ThreatFinding {
+            source,
+            id: id.to_string(),
+            owner: "detector-1".to_string(),
+            account_id: "123456789012".to_string(),
+            region: region.to_string(),
+            severity,
+            finding_type: "UnauthorizedAccess:EC2/SSHBruteForce".to_string(),
+            title: format!("Finding {}", id),
+            description: String::new(),
+            resources: vec![format!(
+                "arn:aws:ec2:{}:123456789012:instance/i-{}",
+                region, id
+            )],
+            updated_at: Some(updated_at.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_visible_findings_order_and_scope() {
+        let mut window = ThreatFindingsWindow::new();
+        window.findings = vec![
+            finding(
+                "low",
+                FindingSource::SecurityHub,
+                Severity::Low,
+                "us-east-1",
+                "2026-01-03",
+            ),
+            finding(
+                "old",
+                FindingSource::GuardDuty,
+                Severity::High,
+                "us-east-1",
+                "2026-01-01",
+            ),
+            finding(
+                "new",
+                FindingSource::GuardDuty,
+                Severity::High,
+                "us-east-1",
+                "2026-01-02",
+            ),
+            finding(
+                "west",
+                FindingSource::GuardDuty,
+                Severity::Critical,
+                "us-west-2",
+                "2026-01-01",
+            ),
+        ];
+
+        let ids: Vec<&str> = window
+            .visible_findings()
+            .iter()
+            .map(|f| f.id.as_str())
+            .collect();
+        assert_eq!(ids, ["west", "new", "old", "low"]);
+
+        window.scope = FindingScope::Region {
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+        };
+        window.sources.remove(&FindingSource::SecurityHub);
+        assert_eq!(window.visible_findings().len(), 2);
+
+        window.scope = FindingScope::Resource {
+            account_id: "123456789012".to_string(),
+            resource_id: "i-west".to_string(),
+        };
+        let ids: Vec<&str> = window
+            .visible_findings()
+            .iter()
+            .map(|f| f.id.as_str())
+            .collect();
+        assert_eq!(ids, ["west"]);
+    }
+}
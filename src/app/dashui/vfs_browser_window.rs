@@ -2,13 +2,32 @@
 //!
 //! A file browser for viewing the Virtual File System (VFS) associated with an agent.
 //! Allows traversing directories, selecting files, and viewing/editing file contents.
+//! The toolbar saves the VFS as a snapshot on disk, or restores one saved by an
+//! earlier agent session. Clicking the usage figure shows per-directory usage,
+//! quotas, and the largest files.
 
 #![warn(clippy::all, rust_2018_idioms)]
 
 use eframe::egui;
 use egui::{Color32, Context, RichText, ScrollArea, TextEdit, Ui};
 
-use crate::app::agent_framework::vfs::{with_vfs, with_vfs_mut, VfsDirEntry};
+use crate::app::agent_framework::vfs::{
+    with_vfs, with_vfs_mut, EvictionPolicy, VfsDirEntry, VfsSnapshotInfo, VfsSnapshotStore,
+    VfsStats,
+};
+use crate::app::agent_framework::AgentId;
+
+/// Snapshot action requested from the toolbar, carried out by the Agent Manager
+#[derive(Debug, Clone)]
+pub enum VfsSnapshotRequest {
+    /// Save the agent's VFS
+    Save(AgentId),
+    /// Replace the agent's VFS with the snapshot of another session
+    Restore {
+        agent_id: AgentId,
+        session_id: String,
+    },
+}
 
 /// State for the VFS browser window
 pub struct VfsBrowserWindow {
@@ -16,6 +35,13 @@ pub struct VfsBrowserWindow {
     open: bool,
     /// Current VFS ID being browsed
     vfs_id: Option<String>,
+    /// Agent owning the VFS
+    agent_id: Option<AgentId>,
+    /// Saved snapshots, newest first
+    snapshots: Vec<VfsSnapshotInfo>,
+    /// Outcome of the last snapshot save or restore
+    snapshot_message: Option<String>,
+    pending_request: Option<VfsSnapshotRequest>,
     /// Display name for the window title
     display_name: String,
     /// Current directory path
@@ -32,6 +58,8 @@ pub struct VfsBrowserWindow {
     error_message: Option<String>,
     /// Navigation history for back button
     path_history: Vec<String>,
+    /// Whether the usage panel is shown
+    show_usage: bool,
 }
 
 impl Default for VfsBrowserWindow {
@@ -45,6 +73,10 @@ impl VfsBrowserWindow {
         Self {
             open: false,
             vfs_id: None,
+            agent_id: None,
+            snapshots: Vec::new(),
+            snapshot_message: None,
+            pending_request: None,
             display_name: String::new(),
             current_path: "/".to_string(),
             entries: Vec::new(),
@@ -53,14 +85,18 @@ impl VfsBrowserWindow {
             is_modified: false,
             error_message: None,
             path_history: Vec::new(),
+            show_usage: false,
         }
     }
 
     /// Open the VFS browser for a specific VFS instance
-    pub fn open_for_vfs(&mut self, vfs_id: String, display_name: String) {
+    pub fn open_for_vfs(&mut self, vfs_id: String, agent_id: AgentId, display_name: String) {
         self.open = true;
         self.vfs_id = Some(vfs_id);
+        self.agent_id = Some(agent_id);
         self.display_name = display_name;
+        self.snapshot_message = None;
+        self.reload_snapshots();
         self.current_path = "/".to_string();
         self.selected_file = None;
         self.file_content = None;
@@ -74,6 +110,7 @@ impl VfsBrowserWindow {
     pub fn close(&mut self) {
         self.open = false;
         self.vfs_id = None;
+        self.agent_id = None;
         self.file_content = None;
         self.is_modified = false;
     }
@@ -83,6 +120,27 @@ impl VfsBrowserWindow {
         self.open
     }
 
+    /// Report the outcome of a snapshot request and show the current contents
+    pub fn snapshot_finished(&mut self, result: anyhow::Result<String>) {
+        match result {
+            Ok(message) => {
+                self.snapshot_message = Some(message);
+                self.selected_file = None;
+                self.file_content = None;
+                self.is_modified = false;
+                self.refresh_entries();
+            }
+            Err(e) => self.error_message = Some(format!("Snapshot failed: {:#}", e)),
+        }
+        self.reload_snapshots();
+    }
+
+    fn reload_snapshots(&mut self) {
+        self.snapshots = VfsSnapshotStore::default_location()
+            .map(|store| store.list())
+            .unwrap_or_default();
+    }
+
     /// Refresh the directory listing
     fn refresh_entries(&mut self) {
         self.entries.clear();
@@ -205,9 +263,11 @@ impl VfsBrowserWindow {
     }
 
     /// Show the VFS browser window
-    pub fn show(&mut self, ctx: &Context) {
+    ///
+    /// Returns a snapshot request when Save Snapshot or Restore was clicked.
+    pub fn show(&mut self, ctx: &Context) -> Option<VfsSnapshotRequest> {
         if !self.open {
-            return;
+            return None;
         }
 
         let mut is_open = self.open;
@@ -221,6 +281,7 @@ impl VfsBrowserWindow {
             });
 
         self.open = is_open;
+        self.pending_request.take()
     }
 
     /// Render the main content
@@ -233,6 +294,18 @@ impl VfsBrowserWindow {
         if let Some(ref error) = self.error_message {
             ui.label(RichText::new(error).color(Color32::RED));
             ui.separator();
+        } else if let Some(ref message) = self.snapshot_message {
+            ui.label(RichText::new(message).color(Color32::GRAY));
+            ui.separator();
+        }
+
+        if self.show_usage {
+            egui::TopBottomPanel::bottom("vfs_usage")
+                .resizable(true)
+                .default_height(180.0)
+                .show_inside(ui, |ui| {
+                    self.render_usage_panel(ui);
+                });
         }
 
         // Split view: file browser on left, editor on right
@@ -269,6 +342,8 @@ impl VfsBrowserWindow {
                 self.refresh_entries();
             }
 
+            ui.separator();
+            self.render_snapshot_controls(ui);
             ui.separator();
 
             // Current path display
@@ -283,17 +358,134 @@ impl VfsBrowserWindow {
                         let usage_pct = (total as f64 / max as f64 * 100.0) as u32;
                         let total_kb = total / 1024;
                         let max_mb = max / (1024 * 1024);
-                        ui.label(
-                            RichText::new(format!("{}KB / {}MB ({}%)", total_kb, max_mb, usage_pct))
+                        if ui
+                            .selectable_label(
+                                self.show_usage,
+                                RichText::new(format!(
+                                    "{}KB / {}MB ({}%)",
+                                    total_kb, max_mb, usage_pct
+                                ))
                                 .small()
                                 .color(Color32::GRAY),
-                        );
+                            )
+                            .on_hover_text("Show what is using VFS memory")
+                            .clicked()
+                        {
+                            self.show_usage = !self.show_usage;
+                        }
                     }
                 }
             });
         });
     }
 
+    /// Save Snapshot button and Restore menu
+    fn render_snapshot_controls(&mut self, ui: &mut Ui) {
+        let Some(agent_id) = self.agent_id else {
+            return;
+        };
+        let session_id = agent_id.to_string();
+
+        if ui
+            .button("Save Snapshot")
+            .on_hover_text("Save this VFS to disk so a later session can reopen it")
+            .clicked()
+        {
+            self.pending_request = Some(VfsSnapshotRequest::Save(agent_id));
+        }
+
+        ui.add_enabled_ui(!self.snapshots.is_empty(), |ui| {
+            ui.menu_button("Restore", |ui| {
+                ui.label(
+                    RichText::new("Replaces the current VFS contents")
+                        .small()
+                        .weak(),
+                );
+                for snapshot in &self.snapshots {
+                    let mut label = format!(
+                        "{} - {} ({} files, {} KB)",
+                        snapshot.label,
+                        snapshot
+                            .saved_at
+                            .with_timezone(&chrono::Local)
+                            .format("%Y-%m-%d %H:%M"),
+                        snapshot.file_count,
+                        snapshot.total_size / 1024
+                    );
+                    if snapshot.session_id == session_id {
+                        label.push_str(" [this session]");
+                    }
+                    if ui.button(label).clicked() {
+                        self.pending_request = Some(VfsSnapshotRequest::Restore {
+                            agent_id,
+                            session_id: snapshot.session_id.clone(),
+                        });
+                        ui.close();
+                    }
+                }
+            });
+        });
+    }
+
+    /// Render per-directory usage, quotas, and the largest files
+    fn render_usage_panel(&mut self, ui: &mut Ui) {
+        let Some(stats) = self
+            .vfs_id
+            .as_ref()
+            .and_then(|vfs_id| with_vfs(vfs_id, |vfs| vfs.vfs_stats()))
+        else {
+            return;
+        };
+
+        ui.horizontal(|ui| {
+            ui.strong("Usage");
+            ui.label(format!(
+                "{} in {} files of {}",
+                format_size(stats.total_size),
+                stats.file_count,
+                format_size(stats.max_size)
+            ));
+            if stats.evicted_files > 0 {
+                ui.label(
+                    RichText::new(format!(
+                        "{} files ({}) evicted",
+                        stats.evicted_files,
+                        format_size(stats.evicted_bytes)
+                    ))
+                    .color(ui.visuals().warn_fg_color),
+                );
+            }
+        });
+        ui.separator();
+
+        let mut open: Option<String> = None;
+        ScrollArea::vertical()
+            .id_salt("vfs_usage_scroll")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                ui.columns(2, |columns| {
+                    render_directory_usage(&mut columns[0], &stats);
+
+                    columns[1].label(RichText::new("Largest files").small().strong());
+                    for file in &stats.largest_files {
+                        columns[1].horizontal(|ui| {
+                            if ui
+                                .link(RichText::new(&file.path).monospace().small())
+                                .clicked()
+                            {
+                                open = Some(file.path.clone());
+                            }
+                            ui.label(RichText::new(format_size(file.size)).small().weak());
+                        });
+                    }
+                });
+            });
+
+        if let Some(path) = open {
+            self.open_file(path);
+        }
+    }
+
     /// Render the file browser panel
     fn render_file_browser(&mut self, ui: &mut Ui) {
         ui.heading("Files");
@@ -430,3 +622,53 @@ enum FileAction {
     NavigateTo(String),
     OpenFile(String),
 }
+
+/// Usage bar per top-level directory, against its quota when it has one
+fn render_directory_usage(ui: &mut Ui, stats: &VfsStats) {
+    ui.label(RichText::new("Directories").small().strong());
+    for usage in &stats.directories {
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(&usage.directory).monospace().small());
+            let (limit, hover) = match &usage.quota {
+                Some(quota) => (
+                    quota.max_size,
+                    match quota.eviction {
+                        EvictionPolicy::Lru => "Quota; least recently used files are evicted",
+                        EvictionPolicy::Reject => "Quota; writes beyond it fail",
+                    },
+                ),
+                None => (stats.max_size, "No quota; shares the VFS limit"),
+            };
+            let fraction = if limit == 0 {
+                0.0
+            } else {
+                usage.size as f32 / limit as f32
+            };
+            ui.add(
+                egui::ProgressBar::new(fraction.min(1.0))
+                    .desired_width(120.0)
+                    .text(format!(
+                        "{} / {}",
+                        format_size(usage.size),
+                        format_size(limit)
+                    )),
+            )
+            .on_hover_text(hover);
+            ui.label(
+                RichText::new(format!("{} files", usage.file_count))
+                    .small()
+                    .weak(),
+            );
+        });
+    }
+}
+
+fn format_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{} KB", bytes / 1024)
+    } else {
+        format!("{:.1} MB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
@@ -0,0 +1,60 @@
+//! Window Utilities
+//!
+//! Helpers shared by the resource windows that load data through a data plane
+//! client on a background thread and show the results on the next frame.
+
+use std::future::Future;
+use std::sync::mpsc::Sender;
+use std::sync::Arc;
+
+/// Run an async operation on a background thread and send its result to the window
+///
+/// egui runs on a blocking thread, so each operation gets its own thread with a
+/// tokio runtime. The window drains `sender`'s receiver while rendering.
+pub fn spawn_with_client<C, E, F, Fut>(client: &Arc<C>, sender: &Sender<E>, operation: F)
+where
+    C: Send + Sync + 'static,
+    E: Send + 'static,
+    F: FnOnce(Arc<C>) -> Fut + Send + 'static,
+    Fut: Future<Output = E>,
+{
+    let client = Arc::clone(client);
+    let sender = sender.clone();
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Runtime::new().expect("Failed to create tokio runtime");
+        let event = runtime.block_on(operation(client));
+        let _ = sender.send(event);
+    });
+}
+
+/// Unix millis as local date and time
+pub fn format_time(millis: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(millis)
+        .map(|time| {
+            time.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_spawn_with_client_sends_result() {
+        let (sender, receiver) = mpsc::channel();
+        let client = Arc::new(20);
+        spawn_with_client(&client, &sender, |client| async move { *client + 1 });
+        assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(21));
+    }
+
+    #[test]
+    fn test_format_time_out_of_range() {
+        assert_eq!(format_time(i64::MAX), "-");
+        assert_eq!(format_time(0).len(), "1970-01-01 00:00:00".len());
+    }
+}
@@ -0,0 +1,388 @@
+//! Athena Client Wrapper
+//!
+//! Provides a simplified interface to Athena query execution with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Context, Result};
+use aws_sdk_athena as athena;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{
+    is_read_only_statement, AthenaColumn, AthenaPollOptions, AthenaQueryRequest, AthenaQueryResult,
+    AthenaQueryState, AthenaQueryStatus, RESULTS_PAGE_SIZE,
+};
+
+/// Athena client wrapper
+#[derive(Clone)]
+pub struct AthenaQueryClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl AthenaQueryClient {
+    /// Create a new Athena client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<athena::Client> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        Ok(athena::Client::new(&aws_config))
+    }
+
+    /// Run a query to completion and fetch its results
+    ///
+    /// Starts the query, polls until it finishes (cancelling it on timeout),
+    /// then reads up to `poll_options.max_rows` rows from the result set in S3.
+    pub async fn run_query(
+        &self,
+        account_id: &str,
+        region: &str,
+        request: &AthenaQueryRequest,
+        poll_options: &AthenaPollOptions,
+    ) -> Result<AthenaQueryResult> {
+        let query_execution_id = self.start_query(account_id, region, request).await?;
+        let status = self
+            .wait_for_query(account_id, region, &query_execution_id, poll_options)
+            .await?;
+        self.fetch_results(account_id, region, status, poll_options.max_rows)
+            .await
+    }
+
+    /// Start a query and return its execution ID
+    pub async fn start_query(
+        &self,
+        account_id: &str,
+        region: &str,
+        request: &AthenaQueryRequest,
+    ) -> Result<String> {
+        if !is_read_only_statement(&request.query) {
+            crate::app::capabilities::ensure_writable("Athena write statement")?;
+        }
+
+        let client = self.create_client(account_id, region).await?;
+
+        let mut context = athena::types::QueryExecutionContext::builder();
+        if let Some(database) = request.database.as_deref().filter(|d| !d.is_empty()) {
+            context = context.database(database);
+        }
+        if let Some(catalog) = request.catalog.as_deref().filter(|c| !c.is_empty()) {
+            context = context.catalog(catalog);
+        }
+
+        let mut start = client
+            .start_query_execution()
+            .query_string(&request.query)
+            .query_execution_context(context.build())
+            .work_group(request.effective_workgroup());
+
+        if let Some(output_location) = request.output_location.as_deref().filter(|o| !o.is_empty())
+        {
+            start = start.result_configuration(
+                athena::types::ResultConfiguration::builder()
+                    .output_location(output_location)
+                    .build(),
+            );
+        }
+
+        let response = start.send().await.with_context(|| {
+            format!(
+                "Failed to start Athena query in workgroup {}",
+                request.effective_workgroup()
+            )
+        })?;
+
+        let query_execution_id = response
+            .query_execution_id()
+            .ok_or_else(|| anyhow!("Athena did not return a query execution ID"))?
+            .to_string();
+
+        log::info!(
+            "Started Athena query {} in {} ({})",
+            query_execution_id,
+            region,
+            request.effective_workgroup()
+        );
+
+        Ok(query_execution_id)
+    }
+
+    /// Get the current status of a query
+    pub async fn get_query_status(
+        &self,
+        account_id: &str,
+        region: &str,
+        query_execution_id: &str,
+    ) -> Result<AthenaQueryStatus> {
+        let client = self.create_client(account_id, region).await?;
+
+        let response = client
+            .get_query_execution()
+            .query_execution_id(query_execution_id)
+            .send()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to get status of Athena query {}",
+                    query_execution_id
+                )
+            })?;
+
+        let execution = response
+            .query_execution()
+            .ok_or_else(|| anyhow!("Athena query {} not found", query_execution_id))?;
+
+        let status = execution.status();
+        let state = status
+            .and_then(|s| s.state())
+            .and_then(|s| AthenaQueryState::parse(s.as_str()))
+            .unwrap_or(AthenaQueryState::Queued);
+
+        Ok(AthenaQueryStatus {
+            query_execution_id: query_execution_id.to_string(),
+            state,
+            state_change_reason: status
+                .and_then(|s| s.state_change_reason())
+                .map(|r| r.to_string()),
+            output_location: execution
+                .result_configuration()
+                .and_then(|r| r.output_location())
+                .map(|o| o.to_string()),
+            data_scanned_bytes: execution
+                .statistics()
+                .and_then(|s| s.data_scanned_in_bytes()),
+            execution_time_ms: execution
+                .statistics()
+                .and_then(|s| s.engine_execution_time_in_millis()),
+        })
+    }
+
+    /// Poll a query until it reaches a terminal state
+    ///
+    /// Cancels the query and returns an error if `poll_options.timeout` elapses.
+    pub async fn wait_for_query(
+        &self,
+        account_id: &str,
+        region: &str,
+        query_execution_id: &str,
+        poll_options: &AthenaPollOptions,
+    ) -> Result<AthenaQueryStatus> {
+        let started = Instant::now();
+
+        loop {
+            let status = self
+                .get_query_status(account_id, region, query_execution_id)
+                .await?;
+
+            if status.state.is_terminal() {
+                return Ok(status);
+            }
+
+            if started.elapsed() >= poll_options.timeout {
+                if let Err(e) = self
+                    .stop_query(account_id, region, query_execution_id)
+                    .await
+                {
+                    log::warn!("Failed to cancel timed out Athena query: {}", e);
+                }
+                return Err(anyhow!(
+                    "Athena query {} did not finish within {} seconds and was cancelled",
+                    query_execution_id,
+                    poll_options.timeout.as_secs()
+                ));
+            }
+
+            tokio::time::sleep(poll_options.poll_interval).await;
+        }
+    }
+
+    /// Fetch results for a finished query
+    ///
+    /// Returns an error if the query failed or was cancelled.
+    pub async fn fetch_results(
+        &self,
+        account_id: &str,
+        region: &str,
+        status: AthenaQueryStatus,
+        max_rows: usize,
+    ) -> Result<AthenaQueryResult> {
+        match status.state {
+            AthenaQueryState::Succeeded => {}
+            AthenaQueryState::Failed | AthenaQueryState::Cancelled => {
+                return Err(anyhow!(
+                    "Athena query {} {}: {}",
+                    status.query_execution_id,
+                    status.state.as_str().to_lowercase(),
+                    status
+                        .state_change_reason
+                        .as_deref()
+                        .unwrap_or("no reason given")
+                ));
+            }
+            AthenaQueryState::Queued | AthenaQueryState::Running => {
+                return Err(anyhow!(
+                    "Athena query {} has not finished",
+                    status.query_execution_id
+                ));
+            }
+        }
+
+        let client = self.create_client(account_id, region).await?;
+
+        let mut columns: Vec<AthenaColumn> = Vec::new();
+        let mut rows: Vec<Vec<Option<String>>> = Vec::new();
+        let mut next_token: Option<String> = None;
+        let mut first_page = true;
+        let mut truncated = false;
+
+        loop {
+            let mut request = client
+                .get_query_results()
+                .query_execution_id(&status.query_execution_id)
+                .max_results(RESULTS_PAGE_SIZE);
+
+            if let Some(token) = next_token.take() {
+                request = request.next_token(token);
+            }
+
+            let response = request.send().await.with_context(|| {
+                format!(
+                    "Failed to fetch results of Athena query {}",
+                    status.query_execution_id
+                )
+            })?;
+
+            if let Some(result_set) = response.result_set() {
+                if first_page {
+                    columns = result_set
+                        .result_set_metadata()
+                        .map(|metadata| {
+                            metadata
+                                .column_info()
+                                .iter()
+                                .map(|column| AthenaColumn {
+                                    name: column.name().to_string(),
+                                    data_type: column.r#type().to_string(),
+                                })
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                }
+
+                let mut page_rows: Vec<Vec<Option<String>>> = result_set
+                    .rows()
+                    .iter()
+                    .map(|row| {
+                        row.data()
+                            .iter()
+                            .map(|datum| datum.var_char_value().map(|v| v.to_string()))
+                            .collect()
+                    })
+                    .collect();
+
+                // SELECT results repeat the column names as the first row
+                if first_page
+                    && page_rows
+                        .first()
+                        .is_some_and(|first| is_header_row(first, &columns))
+                {
+                    page_rows.remove(0);
+                }
+
+                rows.extend(page_rows);
+            }
+
+            first_page = false;
+            next_token = response.next_token().map(|t| t.to_string());
+
+            if rows.len() >= max_rows {
+                truncated = rows.len() > max_rows || next_token.is_some();
+                rows.truncate(max_rows);
+                break;
+            }
+
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        log::info!(
+            "Fetched {} rows from Athena query {}{}",
+            rows.len(),
+            status.query_execution_id,
+            if truncated { " (truncated)" } else { "" }
+        );
+
+        Ok(AthenaQueryResult::new(status, columns, rows, truncated))
+    }
+
+    /// Cancel a running query
+    pub async fn stop_query(
+        &self,
+        account_id: &str,
+        region: &str,
+        query_execution_id: &str,
+    ) -> Result<()> {
+        let client = self.create_client(account_id, region).await?;
+
+        client
+            .stop_query_execution()
+            .query_execution_id(query_execution_id)
+            .send()
+            .await
+            .with_context(|| format!("Failed to cancel Athena query {}", query_execution_id))?;
+
+        log::info!("Cancelled Athena query {}", query_execution_id);
+        Ok(())
+    }
+}
+
+/// Check whether a row just repeats the column names
+fn is_header_row(row: &[Option<String>], columns: &[AthenaColumn]) -> bool {
+    !columns.is_empty()
+        && row.len() == columns.len()
+        && row
+            .iter()
+            .zip(columns)
+            .all(|(value, column)| value.as_deref() == Some(column.name.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn column(name: &str) -> AthenaColumn {
+        AthenaColumn {
+            name: name.to_string(),
+            data_type: "varchar".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_is_header_row() {
+        let columns = vec![column("eventname"), column("total")];
+
+        let header = vec![Some("eventname".to_string()), Some("total".to_string())];
+        assert!(is_header_row(&header, &columns));
+
+        let data = vec![Some("RunInstances".to_string()), Some("4".to_string())];
+        assert!(!is_header_row(&data, &columns));
+
+        assert!(!is_header_row(&header, &[]));
+    }
+}
@@ -0,0 +1,59 @@
+//! Athena Integration Module
+//!
+//! Provides functionality for running SQL against data lakes with Amazon Athena
+//! within the application.
+//!
+//! ## Features
+//!
+//! - Start queries in any workgroup, database, and data catalog
+//! - Poll execution status with timeout and cancellation
+//! - Fetch paginated result sets written to S3 by Athena
+//! - Write statements (CTAS, INSERT, DROP, ...) refused in the read-only build
+//! - Integration with Resource Explorer ("Query" on workgroups) and agents (`runAthenaQuery()`)
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::athena::{
+//!     AthenaPollOptions, AthenaQueryClient, AthenaQueryRequest,
+//! };
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = AthenaQueryClient::new(credential_coordinator);
+//!
+//! let request = AthenaQueryRequest::new("SELECT eventname, count(*) AS total FROM cloudtrail_logs GROUP BY 1")
+//!     .with_database("security_lake")
+//!     .with_workgroup("primary");
+//!
+//! let result = client.run_query(
+//!     "123456789012",            // account_id
+//!     "us-east-1",               // region
+//!     &request,
+//!     &AthenaPollOptions::new().with_max_rows(100),
+//! ).await?;
+//!
+//! for record in result.to_records() {
+//!     println!("{:?}", record);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The workgroup must have a query result location configured, or the request
+//! must set `output_location` to an S3 prefix the caller can write to.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::AthenaQueryClient;
+pub use types::{
+    format_bytes, is_read_only_statement, AthenaColumn, AthenaPollOptions, AthenaQueryRequest,
+    AthenaQueryResult, AthenaQueryState, AthenaQueryStatus,
+};
@@ -0,0 +1,383 @@
+//! Athena Data Types
+//!
+//! Data structures for Athena query requests, execution status, and result sets.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Default data catalog for Glue-backed tables
+pub const DEFAULT_CATALOG: &str = "AwsDataCatalog";
+
+/// Default workgroup when none is specified
+pub const DEFAULT_WORKGROUP: &str = "primary";
+
+/// Default maximum number of result rows fetched
+pub const DEFAULT_MAX_ROWS: usize = 1000;
+
+/// Rows per GetQueryResults page (API maximum)
+pub const RESULTS_PAGE_SIZE: i32 = 1000;
+
+/// A SQL statement to run in Athena
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AthenaQueryRequest {
+    /// SQL query text
+    pub query: String,
+    /// Database for unqualified table names
+    pub database: Option<String>,
+    /// Data catalog (defaults to AwsDataCatalog)
+    pub catalog: Option<String>,
+    /// Workgroup to run in (defaults to "primary")
+    pub workgroup: Option<String>,
+    /// S3 location for results (e.g., "s3://bucket/prefix/"); the workgroup
+    /// setting is used when omitted
+    pub output_location: Option<String>,
+}
+
+impl AthenaQueryRequest {
+    /// Create a new query request
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            database: None,
+            catalog: None,
+            workgroup: None,
+            output_location: None,
+        }
+    }
+
+    /// Set the database
+    pub fn with_database(mut self, database: impl Into<String>) -> Self {
+        self.database = Some(database.into());
+        self
+    }
+
+    /// Set the data catalog
+    pub fn with_catalog(mut self, catalog: impl Into<String>) -> Self {
+        self.catalog = Some(catalog.into());
+        self
+    }
+
+    /// Set the workgroup
+    pub fn with_workgroup(mut self, workgroup: impl Into<String>) -> Self {
+        self.workgroup = Some(workgroup.into());
+        self
+    }
+
+    /// Set the S3 output location
+    pub fn with_output_location(mut self, output_location: impl Into<String>) -> Self {
+        self.output_location = Some(output_location.into());
+        self
+    }
+
+    /// Workgroup the query will run in
+    pub fn effective_workgroup(&self) -> &str {
+        self.workgroup
+            .as_deref()
+            .filter(|w| !w.is_empty())
+            .unwrap_or(DEFAULT_WORKGROUP)
+    }
+}
+
+/// Options controlling how long to wait for a query and how much to fetch
+#[derive(Debug, Clone, PartialEq)]
+pub struct AthenaPollOptions {
+    /// Delay between status checks
+    pub poll_interval: Duration,
+    /// Give up (and cancel the query) after this long
+    pub timeout: Duration,
+    /// Maximum number of result rows to fetch
+    pub max_rows: usize,
+}
+
+impl Default for AthenaPollOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_millis(1000),
+            timeout: Duration::from_secs(300),
+            max_rows: DEFAULT_MAX_ROWS,
+        }
+    }
+}
+
+impl AthenaPollOptions {
+    /// Create default poll options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the timeout
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the maximum number of rows to fetch
+    pub fn with_max_rows(mut self, max_rows: usize) -> Self {
+        self.max_rows = max_rows;
+        self
+    }
+}
+
+/// Athena query execution state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AthenaQueryState {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+    Cancelled,
+}
+
+impl AthenaQueryState {
+    /// Parse the state string returned by the Athena API
+    pub fn parse(state: &str) -> Option<Self> {
+        match state {
+            "QUEUED" => Some(Self::Queued),
+            "RUNNING" => Some(Self::Running),
+            "SUCCEEDED" => Some(Self::Succeeded),
+            "FAILED" => Some(Self::Failed),
+            "CANCELLED" => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+
+    /// API string for this state
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Queued => "QUEUED",
+            Self::Running => "RUNNING",
+            Self::Succeeded => "SUCCEEDED",
+            Self::Failed => "FAILED",
+            Self::Cancelled => "CANCELLED",
+        }
+    }
+
+    /// Whether the query has finished (successfully or not)
+    pub fn is_terminal(&self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed | Self::Cancelled)
+    }
+}
+
+/// Status of a query execution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AthenaQueryStatus {
+    /// Query execution ID
+    pub query_execution_id: String,
+    /// Current state
+    pub state: AthenaQueryState,
+    /// Reason for a failure or cancellation
+    pub state_change_reason: Option<String>,
+    /// S3 location of the result file
+    pub output_location: Option<String>,
+    /// Bytes scanned (drives query cost)
+    pub data_scanned_bytes: Option<i64>,
+    /// Engine execution time in milliseconds
+    pub execution_time_ms: Option<i64>,
+}
+
+/// A result set column
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AthenaColumn {
+    /// Column name
+    pub name: String,
+    /// Athena data type (varchar, bigint, timestamp, ...)
+    pub data_type: String,
+}
+
+/// Result of a completed Athena query
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AthenaQueryResult {
+    /// Final execution status
+    pub status: AthenaQueryStatus,
+    /// Result columns
+    pub columns: Vec<AthenaColumn>,
+    /// Row values in column order (None for SQL NULL)
+    pub rows: Vec<Vec<Option<String>>>,
+    /// True when more rows exist than were fetched
+    pub truncated: bool,
+}
+
+impl AthenaQueryResult {
+    /// Create a new query result
+    pub fn new(
+        status: AthenaQueryStatus,
+        columns: Vec<AthenaColumn>,
+        rows: Vec<Vec<Option<String>>>,
+        truncated: bool,
+    ) -> Self {
+        Self {
+            status,
+            columns,
+            rows,
+            truncated,
+        }
+    }
+
+    /// Rows as JSON objects keyed by column name
+    pub fn to_records(&self) -> Vec<serde_json::Map<String, serde_json::Value>> {
+        self.rows
+            .iter()
+            .map(|row| {
+                self.columns
+                    .iter()
+                    .zip(row.iter())
+                    .map(|(column, value)| {
+                        let value = value
+                            .as_ref()
+                            .map(|v| serde_json::Value::String(v.clone()))
+                            .unwrap_or(serde_json::Value::Null);
+                        (column.name.clone(), value)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+/// Check whether a SQL statement only reads data
+///
+/// Leading comments are skipped; the first keyword decides. Anything not
+/// recognized as a read (CTAS, INSERT, DROP, MSCK, ...) counts as a write.
+pub fn is_read_only_statement(sql: &str) -> bool {
+    let mut rest = sql.trim_start();
+    loop {
+        if let Some(after) = rest.strip_prefix("--") {
+            rest = after
+                .split_once('\n')
+                .map(|(_, r)| r)
+                .unwrap_or("")
+                .trim_start();
+        } else if let Some(after) = rest.strip_prefix("/*") {
+            rest = after
+                .split_once("*/")
+                .map(|(_, r)| r)
+                .unwrap_or("")
+                .trim_start();
+        } else {
+            break;
+        }
+    }
+
+    let keyword: String = rest
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .collect::<String>()
+        .to_ascii_uppercase();
+
+    matches!(
+        keyword.as_str(),
+        "SELECT" | "WITH" | "SHOW" | "DESCRIBE" | "DESC" | "EXPLAIN" | "VALUES"
+    )
+}
+
+/// Format a byte count for display (e.g., "1.5 MB")
+pub fn format_bytes(bytes: i64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+    let mut value = bytes.max(0) as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes.max(0))
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_state_round_trip() {
+        for state in [
+            AthenaQueryState::Queued,
+            AthenaQueryState::Running,
+            AthenaQueryState::Succeeded,
+            AthenaQueryState::Failed,
+            AthenaQueryState::Cancelled,
+        ] {
+            assert_eq!(AthenaQueryState::parse(state.as_str()), Some(state));
+        }
+        assert!(AthenaQueryState::parse("UNKNOWN").is_none());
+        assert!(!AthenaQueryState::Running.is_terminal());
+        assert!(AthenaQueryState::Cancelled.is_terminal());
+    }
+
+    #[test]
+    fn test_effective_workgroup() {
+        assert_eq!(
+            AthenaQueryRequest::new("SELECT 1").effective_workgroup(),
+            "primary"
+        );
+        assert_eq!(
+            AthenaQueryRequest::new("SELECT 1")
+                .with_workgroup("analytics")
+                .effective_workgroup(),
+            "analytics"
+        );
+    }
+
+    #[test]
+    fn test_is_read_only_statement() {
+        assert!(is_read_only_statement("SELECT * FROM t"));
+        assert!(is_read_only_statement(
+            "  with x as (select 1) select * from x"
+        ));
+        assert!(is_read_only_statement("-- top events\nSELECT 1"));
+        assert!(is_read_only_statement("/* note */ SHOW TABLES"));
+        assert!(!is_read_only_statement("CREATE TABLE t AS SELECT 1"));
+        assert!(!is_read_only_statement("DROP TABLE t"));
+        assert!(!is_read_only_statement("MSCK REPAIR TABLE t"));
+        assert!(!is_read_only_statement(""));
+    }
+
+    #[test]
+    fn test_to_records() {
+        let status = AthenaQueryStatus {
+            query_execution_id: "q-1".to_string(),
+            state: AthenaQueryState::Succeeded,
+            state_change_reason: None,
+            output_location: None,
+            data_scanned_bytes: None,
+            execution_time_ms: None,
+        };
+        let columns = vec![
+            AthenaColumn {
+                name: "name".to_string(),
+                data_type: "varchar".to_string(),
+            },
+            AthenaColumn {
+                name: "count".to_string(),
+                data_type: "bigint".to_string(),
+            },
+        ];
+        let result = AthenaQueryResult::new(
+            status,
+            columns,
+            vec![vec![Some("a".to_string()), None]],
+            false,
+        );
+
+        let records = result.to_records();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0]["name"], serde_json::json!("a"));
+        assert!(records[0]["count"].is_null());
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KB");
+        assert_eq!(format_bytes(10 * 1024 * 1024), "10.0 MB");
+    }
+}
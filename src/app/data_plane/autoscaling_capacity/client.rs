@@ -0,0 +1,216 @@
+//! Auto Scaling Capacity Client Wrapper
+//!
+//! Reads an Auto Scaling group's capacity, instances, scaling policies, and
+//! scaling activities, and sets its desired capacity, with credential
+//! management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_autoscaling as autoscaling;
+use std::sync::Arc;
+
+use crate::app::capabilities::ensure_writable;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{
+    AutoScalingGroupDetails, GroupInstance, ScalingActivity, ScalingPolicySummary,
+    ACTIVITY_HISTORY_LIMIT,
+};
+
+/// Auto Scaling capacity client wrapper
+#[derive(Clone)]
+pub struct AutoScalingCapacityClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl AutoScalingCapacityClient {
+    /// Create a new Auto Scaling capacity client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<autoscaling::Client> {
+        let config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+        Ok(autoscaling::Client::new(&config))
+    }
+
+    /// A group's capacity, instances with their health, and scaling policies
+    pub async fn get_group(
+        &self,
+        account_id: &str,
+        region: &str,
+        group_name: &str,
+    ) -> Result<AutoScalingGroupDetails> {
+        let client = self.create_client(account_id, region).await?;
+
+        let response = client
+            .describe_auto_scaling_groups()
+            .auto_scaling_group_names(group_name)
+            .send()
+            .await
+            .with_context(|| format!("Failed to describe Auto Scaling group {}", group_name))?;
+        let group = response
+            .auto_scaling_groups
+            .and_then(|groups| groups.into_iter().next())
+            .with_context(|| format!("Auto Scaling group {} not found", group_name))?;
+
+        let mut policies = Vec::new();
+        let mut paginator = client
+            .describe_policies()
+            .auto_scaling_group_name(group_name)
+            .into_paginator()
+            .send();
+        while let Some(page) = paginator.next().await {
+            let page =
+                page.with_context(|| format!("Failed to list scaling policies of {}", group_name))?;
+            policies.extend(
+                page.scaling_policies
+                    .unwrap_or_default()
+                    .iter()
+                    .map(policy_summary),
+            );
+        }
+
+        let mut instances: Vec<GroupInstance> = group
+            .instances
+            .unwrap_or_default()
+            .into_iter()
+            .map(|instance| GroupInstance {
+                instance_id: instance.instance_id.unwrap_or_default(),
+                instance_type: instance.instance_type,
+                availability_zone: instance.availability_zone,
+                lifecycle_state: instance
+                    .lifecycle_state
+                    .map(|state| state.as_str().to_string())
+                    .unwrap_or_default(),
+                health_status: instance.health_status.unwrap_or_default(),
+                protected_from_scale_in: instance.protected_from_scale_in.unwrap_or(false),
+            })
+            .collect();
+        instances.sort_by(|a, b| a.instance_id.cmp(&b.instance_id));
+
+        Ok(AutoScalingGroupDetails {
+            name: group
+                .auto_scaling_group_name
+                .unwrap_or_else(|| group_name.to_string()),
+            min_size: group.min_size.unwrap_or(0),
+            max_size: group.max_size.unwrap_or(0),
+            desired_capacity: group.desired_capacity.unwrap_or(0),
+            health_check_type: group.health_check_type,
+            default_cooldown: group.default_cooldown,
+            status: group.status,
+            instances,
+            policies,
+        })
+    }
+
+    /// Most recent scaling activities of a group, newest first
+    pub async fn list_activities(
+        &self,
+        account_id: &str,
+        region: &str,
+        group_name: &str,
+    ) -> Result<Vec<ScalingActivity>> {
+        let client = self.create_client(account_id, region).await?;
+        let response = client
+            .describe_scaling_activities()
+            .auto_scaling_group_name(group_name)
+            .max_records(ACTIVITY_HISTORY_LIMIT)
+            .send()
+            .await
+            .with_context(|| format!("Failed to list scaling activities of {}", group_name))?;
+
+        let mut activities: Vec<ScalingActivity> = response
+            .activities
+            .unwrap_or_default()
+            .into_iter()
+            .map(|activity| ScalingActivity {
+                id: activity.activity_id.unwrap_or_default(),
+                description: activity.description.unwrap_or_default(),
+                cause: activity.cause.unwrap_or_default(),
+                status_code: activity
+                    .status_code
+                    .map(|code| code.as_str().to_string())
+                    .unwrap_or_default(),
+                status_message: activity.status_message,
+                progress: activity.progress,
+                start_time: activity.start_time.and_then(|t| t.to_millis().ok()),
+                end_time: activity.end_time.and_then(|t| t.to_millis().ok()),
+            })
+            .collect();
+        activities.sort_by(|a, b| b.start_time.cmp(&a.start_time));
+        Ok(activities)
+    }
+
+    /// Set a group's desired capacity
+    ///
+    /// The value should come from `parse_desired_capacity`. With
+    /// `honor_cooldown` the request is rejected while the group is in a
+    /// cooldown period.
+    pub async fn set_desired_capacity(
+        &self,
+        account_id: &str,
+        region: &str,
+        group_name: &str,
+        desired_capacity: i32,
+        honor_cooldown: bool,
+    ) -> Result<()> {
+        ensure_writable("Set Auto Scaling desired capacity")?;
+        let client = self.create_client(account_id, region).await?;
+        client
+            .set_desired_capacity()
+            .auto_scaling_group_name(group_name)
+            .desired_capacity(desired_capacity)
+            .honor_cooldown(honor_cooldown)
+            .send()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to set desired capacity of {} to {}",
+                    group_name, desired_capacity
+                )
+            })?;
+        Ok(())
+    }
+}
+
+fn policy_summary(policy: &autoscaling::types::ScalingPolicy) -> ScalingPolicySummary {
+    let policy_type = policy
+        .policy_type
+        .clone()
+        .unwrap_or_else(|| "SimpleScaling".to_string());
+    let adjustment_type = policy
+        .adjustment_type
+        .as_deref()
+        .unwrap_or("ChangeInCapacity");
+    let adjustment = if let Some(target) = &policy.target_tracking_configuration {
+        target
+            .target_value
+            .map(|value| format!("Target {}", value))
+            .unwrap_or_else(|| "Target tracking".to_string())
+    } else if let Some(steps) = policy.step_adjustments.as_ref().filter(|s| !s.is_empty()) {
+        format!("{} {} step(s)", adjustment_type, steps.len())
+    } else if let Some(adjustment) = policy.scaling_adjustment {
+        format!("{} {:+}", adjustment_type, adjustment)
+    } else {
+        "-".to_string()
+    };
+    ScalingPolicySummary {
+        name: policy.policy_name.clone().unwrap_or_default(),
+        policy_type,
+        adjustment,
+        enabled: policy.enabled.unwrap_or(true),
+    }
+}
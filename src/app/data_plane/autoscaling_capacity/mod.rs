@@ -0,0 +1,63 @@
+//! Auto Scaling Capacity Integration Module
+//!
+//! Backs the Auto Scaling group window: capacity, instance health, scaling
+//! policies, and the scaling activity history, with a desired capacity
+//! editor.
+//!
+//! ## Features
+//!
+//! - Desired, minimum, and maximum capacity with instance lifecycle state and
+//!   health per Availability Zone
+//! - Scaling policies with their type and adjustment
+//! - Recent scaling activities with their status and cause
+//! - Desired capacity changes, validated against the group's size limits and
+//!   refused in the read-only build
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::autoscaling_capacity::{
+//!     parse_desired_capacity, AutoScalingCapacityClient,
+//! };
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = AutoScalingCapacityClient::new(credential_coordinator);
+//! let group = client
+//!     .get_group("123456789012", "us-east-1", "web-asg")
+//!     .await?;
+//!
+//! let desired = parse_desired_capacity("4", group.min_size, group.max_size)
+//!     .map_err(anyhow::Error::msg)?;
+//! client
+//!     .set_desired_capacity("123456789012", "us-east-1", "web-asg", desired, true)
+//!     .await?;
+//!
+//! for activity in client
+//!     .list_activities("123456789012", "us-east-1", "web-asg")
+//!     .await?
+//! {
+//!     println!("{}: {}", activity.status_code, activity.description);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `autoscaling:DescribeAutoScalingGroups`,
+//! `autoscaling:DescribePolicies`, and `autoscaling:DescribeScalingActivities`,
+//! plus `autoscaling:SetDesiredCapacity` on the group to change its capacity.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::AutoScalingCapacityClient;
+pub use types::{
+    parse_desired_capacity, AutoScalingGroupDetails, GroupInstance, ScalingActivity,
+    ScalingPolicySummary, ACTIVITY_HISTORY_LIMIT,
+};
@@ -0,0 +1,184 @@
+//! Auto Scaling Capacity Data Types
+//!
+//! Capacity, instances, scaling policies, and scaling activities of an Auto
+//! Scaling group, and validation of a new desired capacity.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+
+/// Recent scaling activities listed per group
+pub const ACTIVITY_HISTORY_LIMIT: i32 = 50;
+
+/// An Auto Scaling group's capacity with its instances and policies
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutoScalingGroupDetails {
+    pub name: String,
+    pub min_size: i32,
+    pub max_size: i32,
+    pub desired_capacity: i32,
+    /// "EC2" or "ELB"
+    pub health_check_type: Option<String>,
+    pub default_cooldown: Option<i32>,
+    /// Set while the group is being deleted
+    pub status: Option<String>,
+    pub instances: Vec<GroupInstance>,
+    pub policies: Vec<ScalingPolicySummary>,
+}
+
+impl AutoScalingGroupDetails {
+    pub fn in_service_count(&self) -> usize {
+        self.instances
+            .iter()
+            .filter(|instance| instance.lifecycle_state == "InService")
+            .count()
+    }
+
+    pub fn healthy_count(&self) -> usize {
+        self.instances.iter().filter(|i| i.is_healthy()).count()
+    }
+
+    /// Whether exactly the desired number of instances is in service and
+    /// none are launching or terminating
+    pub fn is_at_capacity(&self) -> bool {
+        self.instances.len() == self.desired_capacity as usize
+            && self.in_service_count() == self.instances.len()
+    }
+}
+
+/// An instance of the group
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupInstance {
+    pub instance_id: String,
+    pub instance_type: Option<String>,
+    pub availability_zone: Option<String>,
+    /// "Pending", "InService", "Terminating", "Standby", ...
+    pub lifecycle_state: String,
+    /// "Healthy" or "Unhealthy"
+    pub health_status: String,
+    pub protected_from_scale_in: bool,
+}
+
+impl GroupInstance {
+    pub fn is_healthy(&self) -> bool {
+        self.health_status == "Healthy"
+    }
+}
+
+/// A scaling policy attached to the group
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScalingPolicySummary {
+    pub name: String,
+    /// "SimpleScaling", "StepScaling", "TargetTrackingScaling", or "PredictiveScaling"
+    pub policy_type: String,
+    /// What the policy does, e.g. "ChangeInCapacity +2" or "Target 50"
+    pub adjustment: String,
+    pub enabled: bool,
+}
+
+/// A scaling activity of the group
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScalingActivity {
+    pub id: String,
+    pub description: String,
+    pub cause: String,
+    /// "InProgress", "Successful", "Failed", "Cancelled", or a waiting state
+    pub status_code: String,
+    pub status_message: Option<String>,
+    pub progress: Option<i32>,
+    /// Unix millis
+    pub start_time: Option<i64>,
+    /// Unix millis
+    pub end_time: Option<i64>,
+}
+
+impl ScalingActivity {
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status_code.as_str(),
+            "Successful" | "Failed" | "Cancelled"
+        )
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self.status_code.as_str(), "Failed" | "Cancelled")
+    }
+}
+
+/// A new desired capacity, which must lie within the group's min and max size
+pub fn parse_desired_capacity(input: &str, min_size: i32, max_size: i32) -> Result<i32, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("Enter a desired capacity".to_string());
+    }
+    let desired: i32 = input
+        .parse()
+        .map_err(|_| format!("Not a whole number: {}", input))?;
+    if desired < min_size || desired > max_size {
+        return Err(format!(
+            "Desired capacity must be between the minimum ({}) and maximum ({}) size",
+            min_size, max_size
+        ));
+    }
+    Ok(desired)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn instance(id: &str, lifecycle_state: &str, health_status: &str) -> GroupInstance {
+        GroupInstance {
+            instance_id: id.to_string(),
+            instance_type: Some("t3.micro".to_string()),
+            availability_zone: Some("us-east-1a".to_string()),
+            lifecycle_state: lifecycle_state.to_string(),
+            health_status: health_status.to_string(),
+            protected_from_scale_in: false,
+        }
+    }
+
+    #[test]
+    fn test_parse_desired_capacity() {
+        assert_eq!(parse_desired_capacity(" 3 ", 1, 5), Ok(3));
+        assert_eq!(parse_desired_capacity("1", 1, 5), Ok(1));
+        assert_eq!(parse_desired_capacity("5", 1, 5), Ok(5));
+        assert!(parse_desired_capacity("6", 1, 5)
+            .unwrap_err()
+            .contains("between"));
+        assert!(parse_desired_capacity("0", 1, 5).is_err());
+        assert!(parse_desired_capacity("2.5", 1, 5)
+            .unwrap_err()
+            .contains("whole number"));
+        assert!(parse_desired_capacity("", 1, 5).is_err());
+    }
+
+    #[test]
+    fn test_is_at_capacity() {
+        let mut group = AutoScalingGroupDetails {
+            name: "web".to_string(),
+            min_size: 1,
+            max_size: 4,
+            desired_capacity: 2,
+            health_check_type: Some("ELB".to_string()),
+            default_cooldown: Some(300),
+            status: None,
+            instances: vec![
+                instance("i-1", "InService", "Healthy"),
+                instance("i-2", "Pending", "Healthy"),
+            ],
+            policies: Vec::new(),
+        };
+        assert!(!group.is_at_capacity());
+        assert_eq!(group.in_service_count(), 1);
+
+        group.instances[1].lifecycle_state = "InService".to_string();
+        assert!(group.is_at_capacity());
+
+        group.instances[1].health_status = "Unhealthy".to_string();
+        assert_eq!(group.healthy_count(), 1);
+
+        group.desired_capacity = 1;
+        assert!(!group.is_at_capacity());
+    }
+}
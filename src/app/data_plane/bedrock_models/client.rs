@@ -0,0 +1,187 @@
+//! Bedrock Model Catalog Client Wrapper
+//!
+//! Lists the foundation models and inference profiles of a region and
+//! attaches the Bedrock invocation quotas of each model, with credential
+//! management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_bedrock as bedrock;
+use aws_sdk_servicequotas as servicequotas;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{
+    profile_serves_model, quota_applies_to, CatalogModel, ModelCatalog, ModelQuota,
+    BEDROCK_SERVICE_CODE,
+};
+
+/// Bedrock model catalog client wrapper
+#[derive(Clone)]
+pub struct BedrockModelCatalogClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl BedrockModelCatalogClient {
+    /// Create a new Bedrock model catalog client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_config(&self, account_id: &str, region: &str) -> Result<aws_config::SdkConfig> {
+        self.credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })
+    }
+
+    /// Foundation models of a region, sorted by provider and name
+    ///
+    /// Quotas that cannot be read leave `quota_error` set instead of failing
+    /// the catalog.
+    pub async fn load_catalog(&self, account_id: &str, region: &str) -> Result<ModelCatalog> {
+        let config = self.create_config(account_id, region).await?;
+        let client = bedrock::Client::new(&config);
+
+        let response = client
+            .list_foundation_models()
+            .send()
+            .await
+            .with_context(|| format!("Failed to list Bedrock foundation models in {}", region))?;
+
+        let mut profile_ids = Vec::new();
+        let mut paginator = client.list_inference_profiles().into_paginator().send();
+        while let Some(page) = paginator.next().await {
+            let page = page.with_context(|| {
+                format!("Failed to list Bedrock inference profiles in {}", region)
+            })?;
+            profile_ids.extend(
+                page.inference_profile_summaries
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|profile| profile.inference_profile_id),
+            );
+        }
+
+        let (quotas, quota_error) = match list_bedrock_quotas(&config).await {
+            Ok(quotas) => (quotas, None),
+            Err(e) => {
+                log::warn!("Bedrock quotas in {}/{}: {:#}", account_id, region, e);
+                (Vec::new(), Some(format!("{:#}", e)))
+            }
+        };
+
+        let mut models: Vec<CatalogModel> = response
+            .model_summaries
+            .unwrap_or_default()
+            .into_iter()
+            .map(|summary| {
+                let model_name = summary
+                    .model_name
+                    .clone()
+                    .unwrap_or_else(|| summary.model_id.clone());
+                let inference_types = summary.inference_types_supported.unwrap_or_default();
+                CatalogModel {
+                    inference_profiles: profile_ids
+                        .iter()
+                        .filter(|profile_id| profile_serves_model(profile_id, &summary.model_id))
+                        .cloned()
+                        .collect(),
+                    quotas: quotas
+                        .iter()
+                        .filter(|quota| quota_applies_to(&quota.name, &model_name))
+                        .cloned()
+                        .collect(),
+                    model_id: summary.model_id,
+                    model_name,
+                    provider_name: summary.provider_name.unwrap_or_default(),
+                    input_modalities: modality_names(summary.input_modalities),
+                    output_modalities: modality_names(summary.output_modalities),
+                    streaming_supported: summary.response_streaming_supported.unwrap_or(false),
+                    on_demand: inference_types
+                        .iter()
+                        .any(|t| matches!(t, bedrock::types::InferenceType::OnDemand)),
+                    provisioned: inference_types
+                        .iter()
+                        .any(|t| matches!(t, bedrock::types::InferenceType::Provisioned)),
+                    lifecycle_status: summary
+                        .model_lifecycle
+                        .map(|lifecycle| lifecycle.status.as_str().to_string()),
+                }
+            })
+            .collect();
+        models.sort_by(|a, b| {
+            (a.provider_name.as_str(), a.model_name.as_str())
+                .cmp(&(b.provider_name.as_str(), b.model_name.as_str()))
+        });
+
+        Ok(ModelCatalog {
+            models,
+            quota_error,
+        })
+    }
+}
+
+fn modality_names(modalities: Option<Vec<bedrock::types::ModelModality>>) -> Vec<String> {
+    modalities
+        .unwrap_or_default()
+        .iter()
+        .map(|modality| modality.as_str().to_string())
+        .collect()
+}
+
+/// Bedrock quotas with the account's applied values, falling back to the AWS
+/// defaults for quotas the account has no applied value for
+async fn list_bedrock_quotas(config: &aws_config::SdkConfig) -> Result<Vec<ModelQuota>> {
+    let client = servicequotas::Client::new(config);
+    let mut quotas: BTreeMap<String, ModelQuota> = BTreeMap::new();
+
+    let mut defaults = client
+        .list_aws_default_service_quotas()
+        .service_code(BEDROCK_SERVICE_CODE)
+        .into_paginator()
+        .send();
+    while let Some(page) = defaults.next().await {
+        let page = page.context("Failed to list default Bedrock quotas")?;
+        for quota in page.quotas() {
+            if let Some(quota) = model_quota(quota) {
+                quotas.insert(quota.quota_code.clone(), quota);
+            }
+        }
+    }
+
+    let mut applied = client
+        .list_service_quotas()
+        .service_code(BEDROCK_SERVICE_CODE)
+        .into_paginator()
+        .send();
+    while let Some(page) = applied.next().await {
+        let page = page.context("Failed to list applied Bedrock quotas")?;
+        for quota in page.quotas() {
+            if let Some(quota) = model_quota(quota) {
+                quotas.insert(quota.quota_code.clone(), quota);
+            }
+        }
+    }
+
+    Ok(quotas.into_values().collect())
+}
+
+fn model_quota(quota: &servicequotas::types::ServiceQuota) -> Option<ModelQuota> {
+    Some(ModelQuota {
+        quota_code: quota.quota_code()?.to_string(),
+        name: quota.quota_name()?.to_string(),
+        value: quota.value(),
+        adjustable: quota.adjustable(),
+    })
+}
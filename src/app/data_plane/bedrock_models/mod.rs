@@ -0,0 +1,50 @@
+//! Bedrock Model Catalog Integration Module
+//!
+//! Backs the Bedrock model catalog window: the foundation models of a region,
+//! how they can be invoked, and how often.
+//!
+//! ## Features
+//!
+//! - Foundation models with provider, modalities, streaming, and lifecycle
+//!   status
+//! - On-demand and provisioned throughput availability, including the
+//!   cross-region inference profiles that serve a model
+//! - Invocation quotas (requests and tokens per minute) matched to each model,
+//!   with the account's applied values where they differ from the defaults
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::bedrock_models::BedrockModelCatalogClient;
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = BedrockModelCatalogClient::new(credential_coordinator);
+//! let catalog = client.load_catalog("123456789012", "us-east-1").await?;
+//!
+//! for model in catalog.models.iter().filter(|m| m.is_invocable()) {
+//!     println!("{} ({} quotas)", model.model_id, model.quotas.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `bedrock:ListFoundationModels`,
+//! `bedrock:ListInferenceProfiles`, `servicequotas:ListServiceQuotas`, and
+//! `servicequotas:ListAWSDefaultServiceQuotas`. Listing a model does not mean
+//! the account was granted access to it.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::BedrockModelCatalogClient;
+pub use types::{
+    profile_serves_model, quota_applies_to, CatalogModel, ModelCatalog, ModelQuota,
+    BEDROCK_SERVICE_CODE,
+};
@@ -0,0 +1,142 @@
+//! Bedrock Model Catalog Data Types
+//!
+//! Foundation models of a region with their throughput options, the
+//! cross-region inference profiles that serve them, and their invocation
+//! quotas.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+
+/// Service Quotas service code of Bedrock
+pub const BEDROCK_SERVICE_CODE: &str = "bedrock";
+
+/// A foundation model listed in a region
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CatalogModel {
+    pub model_id: String,
+    pub model_name: String,
+    pub provider_name: String,
+    /// e.g. "TEXT", "IMAGE"
+    pub input_modalities: Vec<String>,
+    pub output_modalities: Vec<String>,
+    pub streaming_supported: bool,
+    /// Invocable on demand with the model ID
+    pub on_demand: bool,
+    /// Purchasable as provisioned throughput
+    pub provisioned: bool,
+    /// "ACTIVE" or "LEGACY"
+    pub lifecycle_status: Option<String>,
+    /// Cross-region inference profiles serving the model, e.g. "us.anthropic..."
+    pub inference_profiles: Vec<String>,
+    /// Invocation quotas (requests and tokens per minute)
+    pub quotas: Vec<ModelQuota>,
+}
+
+impl CatalogModel {
+    /// Whether the model can be invoked on demand, directly or through an
+    /// inference profile
+    pub fn is_invocable(&self) -> bool {
+        self.on_demand || !self.inference_profiles.is_empty()
+    }
+
+    pub fn is_legacy(&self) -> bool {
+        self.lifecycle_status.as_deref() == Some("LEGACY")
+    }
+}
+
+/// An invocation quota of a model
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelQuota {
+    pub quota_code: String,
+    pub name: String,
+    pub value: Option<f64>,
+    pub adjustable: bool,
+}
+
+/// Foundation models of one account and region
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelCatalog {
+    pub models: Vec<CatalogModel>,
+    /// Set when the quotas could not be read; the models are still listed
+    pub quota_error: Option<String>,
+}
+
+impl ModelCatalog {
+    /// IDs that can be invoked on demand, for `ModelAvailability`: models
+    /// with on-demand throughput and every inference profile
+    pub fn model_ids(&self) -> Vec<String> {
+        self.models
+            .iter()
+            .flat_map(|model| {
+                model
+                    .on_demand
+                    .then(|| model.model_id.clone())
+                    .into_iter()
+                    .chain(model.inference_profiles.iter().cloned())
+            })
+            .collect()
+    }
+}
+
+/// Whether an inference profile ID serves a model: the model ID behind a
+/// geography prefix (`us.`, `eu.`, `global.`)
+pub fn profile_serves_model(profile_id: &str, model_id: &str) -> bool {
+    profile_id
+        .strip_suffix(model_id)
+        .is_some_and(|prefix| prefix.ends_with('.'))
+}
+
+/// Whether a Bedrock quota limits invocations of a model
+///
+/// Invocation quotas are named after the model, e.g. "On-demand model
+/// inference tokens per minute for Anthropic Claude 3.5 Sonnet". The name
+/// must end with the model name so "Claude 3.5 Sonnet" does not also match
+/// the quotas of "Claude 3.5 Sonnet V2".
+pub fn quota_applies_to(quota_name: &str, model_name: &str) -> bool {
+    let quota_name = quota_name.to_lowercase();
+    let model_name = model_name.to_lowercase();
+    quota_name.contains("model inference")
+        && !model_name.is_empty()
+        && quota_name
+            .strip_suffix(&model_name)
+            .is_some_and(|prefix| prefix.ends_with(' '))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_serves_model() {
+        let model_id = "anthropic.claude-sonnet-4-5-20250929-v1:0";
+        assert!(profile_serves_model(
+            "us.anthropic.claude-sonnet-4-5-20250929-v1:0",
+            model_id
+        ));
+        assert!(profile_serves_model(
+            "global.anthropic.claude-sonnet-4-5-20250929-v1:0",
+            model_id
+        ));
+        assert!(!profile_serves_model(model_id, model_id));
+        assert!(!profile_serves_model(
+            "xanthropic.claude-sonnet-4-5-20250929-v1:0",
+            model_id
+        ));
+    }
+
+    #[test]
+    fn test_quota_applies_to() {
+        let sonnet = "On-demand model inference tokens per minute for Anthropic Claude 3.5 Sonnet";
+        let sonnet_v2 =
+            "On-demand model inference requests per minute for Anthropic Claude 3.5 Sonnet V2";
+        assert!(quota_applies_to(sonnet, "Claude 3.5 Sonnet"));
+        assert!(!quota_applies_to(sonnet_v2, "Claude 3.5 Sonnet"));
+        assert!(quota_applies_to(sonnet_v2, "Claude 3.5 Sonnet v2"));
+        assert!(!quota_applies_to(
+            "Model units per provisioned model for Anthropic Claude 3.5 Sonnet",
+            "Claude 3.5 Sonnet"
+        ));
+        assert!(!quota_applies_to(sonnet, ""));
+    }
+}
@@ -0,0 +1,153 @@
+//! CloudFront Invalidations Client Wrapper
+//!
+//! Creates and lists cache invalidations of a distribution, with credential
+//! management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_cloudfront as cloudfront;
+use std::sync::Arc;
+
+use crate::app::aws_regions::active_partition;
+use crate::app::capabilities::ensure_writable;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{Invalidation, RECENT_INVALIDATION_LIMIT};
+
+/// CloudFront invalidations client wrapper
+#[derive(Clone)]
+pub struct CloudFrontInvalidationsClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl CloudFrontInvalidationsClient {
+    /// Create a new CloudFront invalidations client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str) -> Result<cloudfront::Client> {
+        // CloudFront is a global service served from the partition's global region
+        let region = active_partition().global_region();
+        let config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+        Ok(cloudfront::Client::new(&config))
+    }
+
+    /// Invalidate paths in a distribution's edge caches
+    ///
+    /// Paths should come from `parse_paths`. Returns the new invalidation,
+    /// which starts as "InProgress".
+    pub async fn create_invalidation(
+        &self,
+        account_id: &str,
+        distribution_id: &str,
+        paths: &[String],
+    ) -> Result<Invalidation> {
+        ensure_writable("Create CloudFront invalidation")?;
+        let client = self.create_client(account_id).await?;
+
+        let batch = cloudfront::types::InvalidationBatch::builder()
+            .paths(
+                cloudfront::types::Paths::builder()
+                    .quantity(paths.len() as i32)
+                    .set_items(Some(paths.to_vec()))
+                    .build()
+                    .context("Failed to build invalidation paths")?,
+            )
+            // Unique per request so a retry of the same paths is a new invalidation
+            .caller_reference(format!("awsdash-{}", chrono::Utc::now().timestamp_millis()))
+            .build()
+            .context("Failed to build invalidation batch")?;
+
+        let response = client
+            .create_invalidation()
+            .distribution_id(distribution_id)
+            .invalidation_batch(batch)
+            .send()
+            .await
+            .with_context(|| format!("Failed to create invalidation of {}", distribution_id))?;
+        response
+            .invalidation()
+            .map(invalidation_from_sdk)
+            .context("CloudFront returned no invalidation")
+    }
+
+    /// Most recent invalidations of a distribution, newest first
+    pub async fn list_invalidations(
+        &self,
+        account_id: &str,
+        distribution_id: &str,
+    ) -> Result<Vec<Invalidation>> {
+        let client = self.create_client(account_id).await?;
+        let response = client
+            .list_invalidations()
+            .distribution_id(distribution_id)
+            .max_items(RECENT_INVALIDATION_LIMIT)
+            .send()
+            .await
+            .with_context(|| format!("Failed to list invalidations of {}", distribution_id))?;
+
+        let mut invalidations: Vec<Invalidation> = response
+            .invalidation_list()
+            .map(|list| list.items())
+            .unwrap_or_default()
+            .iter()
+            .map(|summary| Invalidation {
+                id: summary.id().to_string(),
+                status: summary.status().to_string(),
+                create_time: summary.create_time().to_millis().ok(),
+                paths: Vec::new(),
+                caller_reference: None,
+            })
+            .collect();
+        invalidations.sort_by(|a, b| b.create_time.cmp(&a.create_time));
+        Ok(invalidations)
+    }
+
+    /// One invalidation with its paths and current status
+    pub async fn get_invalidation(
+        &self,
+        account_id: &str,
+        distribution_id: &str,
+        invalidation_id: &str,
+    ) -> Result<Invalidation> {
+        let client = self.create_client(account_id).await?;
+        let response = client
+            .get_invalidation()
+            .distribution_id(distribution_id)
+            .id(invalidation_id)
+            .send()
+            .await
+            .with_context(|| format!("Failed to read invalidation {}", invalidation_id))?;
+        response
+            .invalidation()
+            .map(invalidation_from_sdk)
+            .context("CloudFront returned no invalidation")
+    }
+}
+
+fn invalidation_from_sdk(invalidation: &cloudfront::types::Invalidation) -> Invalidation {
+    let batch = invalidation.invalidation_batch();
+    Invalidation {
+        id: invalidation.id().to_string(),
+        status: invalidation.status().to_string(),
+        create_time: invalidation.create_time().to_millis().ok(),
+        paths: batch
+            .and_then(|batch| batch.paths())
+            .map(|paths| paths.items().to_vec())
+            .unwrap_or_default(),
+        caller_reference: batch.map(|batch| batch.caller_reference().to_string()),
+    }
+}
@@ -0,0 +1,55 @@
+//! CloudFront Invalidations Integration Module
+//!
+//! Backs the CloudFront invalidation window: clear paths from a
+//! distribution's edge caches and follow the invalidation until it completes.
+//!
+//! ## Features
+//!
+//! - Path validation (leading `/`, trailing `*` wildcards, CloudFront's path
+//!   and wildcard limits) before anything is sent
+//! - Invalidation creation, refused in the read-only build
+//! - Recent invalidations of a distribution with their status
+//! - Single invalidation lookup with its paths, for progress polling
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::cloudfront_invalidations::{
+//!     parse_paths, CloudFrontInvalidationsClient,
+//! };
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = CloudFrontInvalidationsClient::new(credential_coordinator);
+//! let paths = parse_paths("/index.html\n/images/*").map_err(anyhow::Error::msg)?;
+//! let invalidation = client
+//!     .create_invalidation("123456789012", "E1ABCDEF2GHIJK", &paths)
+//!     .await?;
+//!
+//! let current = client
+//!     .get_invalidation("123456789012", "E1ABCDEF2GHIJK", &invalidation.id)
+//!     .await?;
+//! println!("{}: {}", current.id, current.status);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `cloudfront:CreateInvalidation`,
+//! `cloudfront:ListInvalidations`, and `cloudfront:GetInvalidation` on the
+//! distribution. The first 1,000 invalidated paths each month are free; a
+//! wildcard path counts as one path.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::CloudFrontInvalidationsClient;
+pub use types::{
+    parse_paths, Invalidation, MAX_PATHS_PER_INVALIDATION, MAX_WILDCARD_PATHS,
+    RECENT_INVALIDATION_LIMIT,
+};
@@ -0,0 +1,102 @@
+//! CloudFront Invalidation Data Types
+//!
+//! Invalidations of a distribution and validation of the paths to invalidate.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+
+/// Paths CloudFront accepts in one invalidation
+pub const MAX_PATHS_PER_INVALIDATION: usize = 3000;
+
+/// Wildcard paths CloudFront accepts in progress at once per distribution
+pub const MAX_WILDCARD_PATHS: usize = 15;
+
+/// Recent invalidations listed per distribution
+pub const RECENT_INVALIDATION_LIMIT: i32 = 25;
+
+/// An invalidation of a distribution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Invalidation {
+    pub id: String,
+    /// "InProgress" or "Completed"
+    pub status: String,
+    /// Unix millis
+    pub create_time: Option<i64>,
+    /// Empty when listed; filled when the invalidation is read on its own
+    pub paths: Vec<String>,
+    pub caller_reference: Option<String>,
+}
+
+impl Invalidation {
+    pub fn is_complete(&self) -> bool {
+        self.status == "Completed"
+    }
+}
+
+/// Paths to invalidate, one per line
+///
+/// Blank lines and duplicates are dropped. Every path must start with `/`,
+/// and `*` is only allowed as the last character (e.g. `/images/*` or `/*`).
+pub fn parse_paths(input: &str) -> Result<Vec<String>, String> {
+    let mut paths: Vec<String> = Vec::new();
+    for line in input.lines() {
+        let path = line.trim();
+        if path.is_empty() || paths.iter().any(|p| p == path) {
+            continue;
+        }
+        if !path.starts_with('/') {
+            return Err(format!("Path must start with /: {}", path));
+        }
+        if path.trim_end_matches('*').contains('*') {
+            return Err(format!(
+                "Wildcard * is only allowed at the end of a path: {}",
+                path
+            ));
+        }
+        paths.push(path.to_string());
+    }
+
+    if paths.is_empty() {
+        return Err("Enter at least one path".to_string());
+    }
+    if paths.len() > MAX_PATHS_PER_INVALIDATION {
+        return Err(format!(
+            "{} paths; CloudFront accepts at most {} per invalidation",
+            paths.len(),
+            MAX_PATHS_PER_INVALIDATION
+        ));
+    }
+    let wildcards = paths.iter().filter(|path| path.ends_with('*')).count();
+    if wildcards > MAX_WILDCARD_PATHS {
+        return Err(format!(
+            "{} wildcard paths; CloudFront accepts at most {} in progress",
+            wildcards, MAX_WILDCARD_PATHS
+        ));
+    }
+    Ok(paths)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_paths() {
+        let paths = parse_paths("/index.html\n\n  /images/*  \n/index.html\n/*").unwrap();
+        assert_eq!(paths, ["/index.html", "/images/*", "/*"]);
+
+        assert!(parse_paths("index.html")
+            .unwrap_err()
+            .contains("start with /"));
+        assert!(parse_paths("/images/*.png")
+            .unwrap_err()
+            .contains("at the end"));
+        assert!(parse_paths(" \n").is_err());
+
+        let wildcards: String = (0..=MAX_WILDCARD_PATHS)
+            .map(|i| format!("/dir{}/*\n", i))
+            .collect();
+        assert!(parse_paths(&wildcards).unwrap_err().contains("wildcard"));
+    }
+}
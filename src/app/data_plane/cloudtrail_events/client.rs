@@ -5,10 +5,21 @@
 use anyhow::{Context, Result};
 use aws_sdk_cloudtrail as cloudtrail_sdk;
 use aws_smithy_types::DateTime;
+use futures::stream::{self, StreamExt};
 use std::sync::Arc;
 
-use super::types::{CloudTrailEvent, EventResource, LookupAttribute, LookupOptions, LookupResult};
+use super::types::{
+    CloudTrailEvent, EventResource, FanoutResult, LookupAttribute, LookupOptions, LookupResult,
+    LookupTarget,
+};
 use crate::app::resource_explorer::credentials::CredentialCoordinator;
+use crate::app::resource_explorer::status::StatusChannel;
+
+/// Maximum number of account/region lookups running at once in a fan-out
+///
+/// LookupEvents is throttled per account and region, so targets can run in
+/// parallel; the bound keeps credential setup and connections manageable.
+pub const MAX_CONCURRENT_LOOKUPS: usize = 8;
 
 /// Client for querying AWS CloudTrail Events
 #[derive(Clone)]
@@ -151,6 +162,100 @@ impl CloudTrailEventsClient {
         Ok(result)
     }
 
+    /// Look up events across several accounts and regions concurrently
+    ///
+    /// Runs `options` against every target with at most
+    /// [`MAX_CONCURRENT_LOOKUPS`] in flight, following pagination until
+    /// `max_events_per_target` events are collected for each. Each target
+    /// reports starting and finishing through `status`. A failing target is
+    /// recorded in [`FanoutResult::failures`] without stopping the others.
+    ///
+    /// # Example
+    /// ```rust
+    /// let targets = vec![
+    ///     LookupTarget::new("123456789012", "us-east-1"),
+    ///     LookupTarget::new("123456789012", "eu-west-1"),
+    /// ];
+    /// let options = LookupOptions::new().with_event_name("DeleteBucket".to_string());
+    ///
+    /// let result = client
+    ///     .lookup_events_fanout(&targets, options, 100, global_status())
+    ///     .await;
+    /// for event in &result.events {
+    ///     println!("{} {} {}", event.account_id, event.region, event.event.event_name);
+    /// }
+    /// ```
+    pub async fn lookup_events_fanout(
+        &self,
+        targets: &[LookupTarget],
+        options: LookupOptions,
+        max_events_per_target: usize,
+        status: &StatusChannel,
+    ) -> FanoutResult {
+        let results: Vec<(LookupTarget, Result<Vec<CloudTrailEvent>, String>)> =
+            stream::iter(targets.iter().cloned())
+                .map(|target| {
+                    let options = options.clone();
+                    async move {
+                        let operation = format!("lookup_events {}", target.label());
+                        status.report_starting("CloudTrail", &operation, None);
+
+                        let result = self
+                            .lookup_all_pages(
+                                &target.account_id,
+                                &target.region,
+                                options,
+                                max_events_per_target,
+                            )
+                            .await;
+                        let detail = match &result {
+                            Ok(events) => format!("{} events", events.len()),
+                            Err(_) => "failed".to_string(),
+                        };
+                        status.report_completed("CloudTrail", &operation, Some(&detail));
+
+                        let result = result.map_err(|e| {
+                            log::warn!("CloudTrail lookup failed for {}: {:#}", target.label(), e);
+                            format!("{:#}", e)
+                        });
+                        (target, result)
+                    }
+                })
+                .buffer_unordered(MAX_CONCURRENT_LOOKUPS)
+                .collect()
+                .await;
+
+        FanoutResult::from_target_results(results)
+    }
+
+    /// Follow pagination for one account and region until `max_events` are collected
+    async fn lookup_all_pages(
+        &self,
+        account_id: &str,
+        region: &str,
+        options: LookupOptions,
+        max_events: usize,
+    ) -> Result<Vec<CloudTrailEvent>> {
+        let mut events = Vec::new();
+        let mut next_token = options.next_token.clone();
+
+        loop {
+            let mut page_options = options.clone();
+            page_options.next_token = next_token;
+
+            let result = self.lookup_events(account_id, region, page_options).await?;
+            events.extend(result.events);
+            next_token = result.next_token;
+
+            if events.len() >= max_events || next_token.is_none() {
+                break;
+            }
+        }
+
+        events.truncate(max_events);
+        Ok(events)
+    }
+
     /// Get recent events with automatic pagination (fetches at least 2 pages, 100 events)
     ///
     /// This method automatically fetches at least 2 pages of events (up to 100 events total)
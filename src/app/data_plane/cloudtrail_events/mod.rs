@@ -5,7 +5,8 @@
 //!
 //! ## Features
 //!
-//! - **Multi-Account/Multi-Region**: Query CloudTrail across all configured AWS accounts and regions
+//! - **Multi-Account/Multi-Region**: Query CloudTrail across all configured AWS accounts and regions;
+//!   `lookup_events_fanout()` queries many targets concurrently and merges the results newest first
 //! - **Flexible Filtering**: Filter by resource type, event name, username, time ranges
 //! - **Automatic Pagination**: Fetches at least 2 pages (100 events) automatically
 //! - **Agent Integration**: Available to agents via JavaScript V8 bindings (`getCloudTrailEvents()`)
@@ -167,6 +168,6 @@ pub use resource_mapping::{
     get_cloudtrail_lookup_value, get_common_event_names, has_cloudtrail_support,
 };
 pub use types::{
-    CloudTrailEvent, EventResource, LookupAttribute, LookupAttributeKey, LookupOptions,
-    LookupResult,
+    CloudTrailEvent, EventResource, FanoutResult, LookupAttribute, LookupAttributeKey,
+    LookupOptions, LookupResult, LookupTarget, TargetFailure, TargetedEvent,
 };
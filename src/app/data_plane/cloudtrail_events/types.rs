@@ -281,6 +281,96 @@ impl LookupResult {
     }
 }
 
+/// An account and region to query in a fan-out lookup
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LookupTarget {
+    pub account_id: String,
+    pub region: String,
+}
+
+impl LookupTarget {
+    pub fn new(account_id: impl Into<String>, region: impl Into<String>) -> Self {
+        Self {
+            account_id: account_id.into(),
+            region: region.into(),
+        }
+    }
+
+    /// Short label for status messages (e.g., "123456789012/us-east-1")
+    pub fn label(&self) -> String {
+        format!("{}/{}", self.account_id, self.region)
+    }
+}
+
+/// CloudTrail event with the account and region it was looked up in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetedEvent {
+    pub account_id: String,
+    pub region: String,
+
+    #[serde(flatten)]
+    pub event: CloudTrailEvent,
+}
+
+/// A target whose lookup failed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TargetFailure {
+    pub target: LookupTarget,
+    pub error: String,
+}
+
+/// Result of a lookup across several accounts and regions
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FanoutResult {
+    /// Events from all targets, newest first
+    pub events: Vec<TargetedEvent>,
+
+    /// Targets that could not be queried; the others still return events
+    pub failures: Vec<TargetFailure>,
+
+    /// Number of targets queried, including failed ones
+    pub targets_queried: usize,
+}
+
+impl FanoutResult {
+    /// Merge per-target results into one newest-first list
+    ///
+    /// Global service events can be returned by more than one region of an
+    /// account; each event ID is kept once per account.
+    pub fn from_target_results(
+        results: Vec<(LookupTarget, Result<Vec<CloudTrailEvent>, String>)>,
+    ) -> Self {
+        let targets_queried = results.len();
+        let mut events = Vec::new();
+        let mut failures = Vec::new();
+
+        for (target, result) in results {
+            match result {
+                Ok(target_events) => {
+                    events.extend(target_events.into_iter().map(|event| TargetedEvent {
+                        account_id: target.account_id.clone(),
+                        region: target.region.clone(),
+                        event,
+                    }))
+                }
+                Err(error) => failures.push(TargetFailure { target, error }),
+            }
+        }
+
+        // Stable sort keeps each target's own order for events at the same time
+        events.sort_by(|a, b| b.event.event_time.cmp(&a.event.event_time));
+        let mut seen = std::collections::HashSet::new();
+        events.retain(|e| seen.insert((e.account_id.clone(), e.event.event_id.clone())));
+        failures.sort_by(|a, b| a.target.label().cmp(&b.target.label()));
+
+        Self {
+            events,
+            failures,
+            targets_queried,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -341,6 +431,57 @@ mod tests {
         assert_eq!(deserialized.events[0].event_name, "RunInstances");
     }
 
+    fn event(event_id: &str, event_time: i64) -> CloudTrailEvent {
+        CloudTrailEvent {
+            event_id: event_id.to_string(),
+            event_name: "CreateRole".to_string(),
+            event_time,
+            event_source: "iam.amazonaws.com".to_string(),
+            username: "test-user".to_string(),
+            resources: vec![],
+            cloud_trail_event: None,
+            access_key_id: None,
+            read_only: Some("false".to_string()),
+            error_code: None,
+            error_message: None,
+        }
+    }
+
+    #[test]
+    fn test_fanout_result_merges_newest_first() {
+        let east = LookupTarget::new("111111111111", "us-east-1");
+        let west = LookupTarget::new("111111111111", "us-west-2");
+        let other = LookupTarget::new("222222222222", "us-east-1");
+        let failed = LookupTarget::new("333333333333", "eu-west-1");
+
+        let result = FanoutResult::from_target_results(vec![
+            (east.clone(), Ok(vec![event("a", 300), event("global", 100)])),
+            (west, Ok(vec![event("b", 200), event("global", 100)])),
+            (other, Ok(vec![event("global", 100)])),
+            (failed.clone(), Err("AccessDenied".to_string())),
+        ]);
+
+        assert_eq!(result.targets_queried, 4);
+        let ids: Vec<_> = result
+            .events
+            .iter()
+            .map(|e| (e.event.event_id.as_str(), e.account_id.as_str()))
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                ("a", "111111111111"),
+                ("b", "111111111111"),
+                ("global", "111111111111"),
+                ("global", "222222222222"),
+            ]
+        );
+        assert_eq!(result.events[2].region, east.region);
+        assert_eq!(result.failures.len(), 1);
+        assert_eq!(result.failures[0].target, failed);
+        assert_eq!(failed.label(), "333333333333/eu-west-1");
+    }
+
     #[test]
     fn test_lookup_result_merge() {
         let event1 = CloudTrailEvent {
@@ -125,6 +125,68 @@ impl CloudWatchLogsClient {
             .await
     }
 
+    /// Get events at or after `start_time`, oldest first, for live tailing
+    ///
+    /// Follows pagination until `max_events` are collected or no pages remain.
+    /// Polls are expected to overlap at `start_time`; use a
+    /// [`TailCursor`](super::tail::TailCursor) to drop repeated events.
+    pub async fn tail_log_events(
+        &self,
+        account_id: &str,
+        region: &str,
+        log_group_name: &str,
+        start_time: i64,
+        max_events: usize,
+    ) -> Result<Vec<LogEvent>> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = cloudwatchlogs::Client::new(&aws_config);
+        let mut events = Vec::new();
+        let mut next_token: Option<String> = None;
+
+        loop {
+            let response = client
+                .filter_log_events()
+                .log_group_name(log_group_name)
+                .start_time(start_time)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .with_context(|| {
+                    format!(
+                        "Failed to tail log events from log group: {}",
+                        log_group_name
+                    )
+                })?;
+
+            for event in response.events.unwrap_or_default() {
+                events.push(LogEvent::with_ingestion_time(
+                    event.timestamp.unwrap_or(0),
+                    event.message.unwrap_or_default(),
+                    event.ingestion_time.unwrap_or(0),
+                    event.log_stream_name.unwrap_or_default(),
+                ));
+            }
+
+            next_token = response.next_token;
+            if next_token.is_none() || events.len() >= max_events {
+                break;
+            }
+        }
+
+        events.truncate(max_events);
+        Ok(events)
+    }
+
     /// List log groups in a region
     pub async fn list_log_groups(
         &self,
@@ -7,6 +7,8 @@
 //! - Query log events with flexible filtering options
 //! - Time range and pattern-based filtering
 //! - Pagination support for large result sets
+//! - Live tailing by polling from the newest event seen, with ERROR/WARN detection
+//! - Export to JSON, NDJSON, or CSV with regex redaction of sensitive values
 //! - Integration with Resource Explorer and Agent V2
 //!
 //! ## Usage
@@ -37,10 +39,16 @@
 #![warn(clippy::all, rust_2018_idioms)]
 
 pub mod client;
+pub mod export;
 pub mod resource_mapping;
+pub mod tail;
 pub mod types;
 
 // Re-export commonly used types
 pub use client::CloudWatchLogsClient;
+pub use export::{
+    default_export_path, export_log_events, LogExportFormat, RedactionRule, Redactor,
+};
 pub use resource_mapping::{get_all_log_group_patterns, get_log_group_name, has_cloudwatch_logs};
+pub use tail::{LogLevel, TailCursor};
 pub use types::{LogEvent, LogQueryResult, QueryOptions, QueryStatistics};
@@ -0,0 +1,145 @@
+//! Live Log Tailing
+//!
+//! Support for following a log group by polling `FilterLogEvents` from the
+//! newest timestamp seen so far. [`TailCursor`] drops events already shown
+//! (polls overlap at the boundary millisecond) and [`LogLevel::detect`] picks
+//! out error and warning lines for highlighting.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::collections::HashSet;
+
+use super::types::LogEvent;
+
+static ERROR_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\b(error|fatal|critical|exception|panic(ked)?|traceback)\b")
+        .expect("valid error pattern")
+});
+
+static WARN_PATTERN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(warn|warning)\b").expect("valid warning pattern"));
+
+/// Severity of a log line, when it looks like an error or warning
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+}
+
+impl LogLevel {
+    /// Detect ERROR/WARN style lines (`ERROR`, `[warn]`, `Traceback`, `panicked at`)
+    pub fn detect(message: &str) -> Option<Self> {
+        if ERROR_PATTERN.is_match(message) {
+            Some(LogLevel::Error)
+        } else if WARN_PATTERN.is_match(message) {
+            Some(LogLevel::Warn)
+        } else {
+            None
+        }
+    }
+}
+
+/// Position in a log group for incremental polling
+#[derive(Debug, Clone, Default)]
+pub struct TailCursor {
+    /// Newest event timestamp seen (Unix milliseconds)
+    last_timestamp: i64,
+    /// Events seen at `last_timestamp`, by stream and message
+    seen_at_last: HashSet<(String, String)>,
+}
+
+impl TailCursor {
+    /// Cursor that returns events from `timestamp` onwards
+    pub fn starting_at(timestamp: i64) -> Self {
+        Self {
+            last_timestamp: timestamp,
+            seen_at_last: HashSet::new(),
+        }
+    }
+
+    /// Cursor positioned after the newest of `events`
+    pub fn after(events: &[LogEvent]) -> Option<Self> {
+        let mut cursor = Self::starting_at(events.iter().map(|e| e.timestamp).max()?);
+        cursor.accept(events.to_vec());
+        Some(cursor)
+    }
+
+    /// Start time for the next poll; inclusive, so boundary events repeat
+    pub fn start_time(&self) -> i64 {
+        self.last_timestamp
+    }
+
+    /// Keep only events not seen before, oldest first, and advance the cursor
+    pub fn accept(&mut self, mut events: Vec<LogEvent>) -> Vec<LogEvent> {
+        events.sort_by_key(|event| event.timestamp);
+        events.retain(|event| {
+            if event.timestamp < self.last_timestamp {
+                return false;
+            }
+            if event.timestamp > self.last_timestamp {
+                self.last_timestamp = event.timestamp;
+                self.seen_at_last.clear();
+            }
+            self.seen_at_last
+                .insert((event.log_stream_name.clone(), event.message.clone()))
+        });
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(timestamp: i64, stream: &str, message: &str) -> LogEvent {
+        LogEvent::new(timestamp, message.to_string(), stream.to_string())
+    }
+
+    #[test]
+    fn test_detect_log_level() {
+        assert_eq!(
+            LogLevel::detect("2024-01-01 ERROR Failed to connect"),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(
+            LogLevel::detect("thread 'main' panicked at src/main.rs"),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(
+            LogLevel::detect("[warn] retrying request"),
+            Some(LogLevel::Warn)
+        );
+        assert_eq!(
+            LogLevel::detect("level=WARNING error budget at 80%"),
+            Some(LogLevel::Error)
+        );
+        assert_eq!(LogLevel::detect("INFO processed 12 errors_total"), None);
+        assert_eq!(LogLevel::detect("START RequestId: abc"), None);
+    }
+
+    #[test]
+    fn test_tail_cursor_skips_seen_events() {
+        let mut cursor =
+            TailCursor::after(&[event(1_000, "a", "first"), event(2_000, "a", "second")]).unwrap();
+        assert_eq!(cursor.start_time(), 2_000);
+
+        // The next poll starts at 2_000 and repeats "second"
+        let new = cursor.accept(vec![
+            event(2_000, "a", "second"),
+            event(2_000, "b", "second"),
+            event(3_000, "a", "third"),
+            event(1_500, "a", "late"),
+        ]);
+        let messages: Vec<_> = new
+            .iter()
+            .map(|e| (e.log_stream_name.as_str(), e.message.as_str()))
+            .collect();
+        assert_eq!(messages, vec![("b", "second"), ("a", "third")]);
+        assert_eq!(cursor.start_time(), 3_000);
+
+        assert!(cursor.accept(vec![event(3_000, "a", "third")]).is_empty());
+        assert!(TailCursor::after(&[]).is_none());
+    }
+}
@@ -0,0 +1,488 @@
+//! CloudWatch Metrics Client Wrapper
+//!
+//! Provides a simplified interface to AWS CloudWatch Metrics with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_cloudwatch as cloudwatch;
+use std::sync::Arc;
+
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::resource_mapping::get_default_metrics;
+use super::types::{
+    expected_datapoints, split_statistics, AlarmResourceFilter, MetricAlarmInfo, MetricDataPoint,
+    MetricDimension, MetricQuery, MetricQueryOptions, MetricQueryResult, MetricSeries,
+    MetricStatisticsDatapoint, MetricStatisticsResult, MAX_STATISTICS_DATAPOINTS,
+};
+
+/// Maximum number of metric queries accepted by a single GetMetricData call
+const MAX_QUERIES_PER_REQUEST: usize = 500;
+
+/// CloudWatch Metrics client wrapper
+#[derive(Clone)]
+pub struct CloudWatchMetricsClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl CloudWatchMetricsClient {
+    /// Create a new CloudWatch Metrics client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<cloudwatch::Client> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        Ok(cloudwatch::Client::new(&aws_config))
+    }
+
+    /// Fetch time series for one or more metrics
+    ///
+    /// Series are returned in the same order as `queries`. All pages of
+    /// GetMetricData results are collected before returning.
+    pub async fn get_metric_data(
+        &self,
+        account_id: &str,
+        region: &str,
+        queries: &[MetricQuery],
+        options: MetricQueryOptions,
+    ) -> Result<MetricQueryResult> {
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let (start_time, end_time) = options.resolve_time_range(now_ms);
+
+        if queries.is_empty() {
+            return Ok(MetricQueryResult::new(
+                Vec::new(),
+                start_time,
+                end_time,
+                options.period,
+            ));
+        }
+
+        if queries.len() > MAX_QUERIES_PER_REQUEST {
+            anyhow::bail!(
+                "Too many metric queries: {} (maximum {})",
+                queries.len(),
+                MAX_QUERIES_PER_REQUEST
+            );
+        }
+
+        let client = self.create_client(account_id, region).await?;
+
+        let mut data_queries = Vec::with_capacity(queries.len());
+        for (index, query) in queries.iter().enumerate() {
+            data_queries.push(build_metric_data_query(index, query, options.period)?);
+        }
+
+        let mut series: Vec<MetricSeries> = queries.iter().map(MetricSeries::from_query).collect();
+        let mut next_token: Option<String> = None;
+
+        loop {
+            let mut request = client
+                .get_metric_data()
+                .set_metric_data_queries(Some(data_queries.clone()))
+                .start_time(cloudwatch::primitives::DateTime::from_millis(start_time))
+                .end_time(cloudwatch::primitives::DateTime::from_millis(end_time))
+                .scan_by(cloudwatch::types::ScanBy::TimestampAscending);
+
+            if let Some(max) = options.max_datapoints {
+                request = request.max_datapoints(max);
+            }
+
+            if let Some(token) = next_token.take() {
+                request = request.next_token(token);
+            }
+
+            let response = request.send().await.with_context(|| {
+                format!(
+                    "Failed to get metric data for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+            for result in response.metric_data_results() {
+                let Some(index) = result.id().and_then(parse_query_id) else {
+                    continue;
+                };
+                let Some(target) = series.get_mut(index) else {
+                    continue;
+                };
+
+                target.datapoints.extend(
+                    result
+                        .timestamps()
+                        .iter()
+                        .zip(result.values().iter())
+                        .map(|(timestamp, value)| MetricDataPoint {
+                            timestamp: timestamp.to_millis().unwrap_or(0),
+                            value: *value,
+                        }),
+                );
+
+                if let Some(status) = result.status_code() {
+                    target.status = status.as_str().to_string();
+                }
+            }
+
+            match response.next_token() {
+                Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+                _ => break,
+            }
+        }
+
+        for s in &mut series {
+            s.sort();
+        }
+
+        Ok(MetricQueryResult::new(
+            series,
+            start_time,
+            end_time,
+            options.period,
+        ))
+    }
+
+    /// Fetch the default metrics for a resource (see `resource_mapping`)
+    pub async fn get_resource_metrics(
+        &self,
+        account_id: &str,
+        region: &str,
+        resource_type: &str,
+        resource_id: &str,
+        options: MetricQueryOptions,
+    ) -> Result<MetricQueryResult> {
+        let queries = get_default_metrics(resource_type, resource_id);
+        if queries.is_empty() {
+            anyhow::bail!("No default CloudWatch metrics for {}", resource_type);
+        }
+
+        self.get_metric_data(account_id, region, &queries, options)
+            .await
+    }
+
+    /// List available metrics, optionally filtered by namespace and dimensions
+    pub async fn list_metrics(
+        &self,
+        account_id: &str,
+        region: &str,
+        namespace: Option<String>,
+        dimensions: Vec<MetricDimension>,
+    ) -> Result<Vec<MetricQuery>> {
+        let client = self.create_client(account_id, region).await?;
+
+        let mut request = client.list_metrics();
+
+        if let Some(namespace) = namespace {
+            request = request.namespace(namespace);
+        }
+
+        for dimension in dimensions {
+            let filter = cloudwatch::types::DimensionFilter::builder()
+                .name(dimension.name)
+                .value(dimension.value)
+                .build()
+                .context("Failed to build dimension filter")?;
+            request = request.dimensions(filter);
+        }
+
+        let mut metrics = Vec::new();
+        let mut paginator = request.into_paginator().send();
+
+        while let Some(page) = paginator.next().await {
+            let page = page.with_context(|| {
+                format!(
+                    "Failed to list metrics for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+            for metric in page.metrics() {
+                let mut query = MetricQuery::new(
+                    metric.namespace().unwrap_or_default(),
+                    metric.metric_name().unwrap_or_default(),
+                );
+                for dimension in metric.dimensions() {
+                    query = query.with_dimension(dimension.name(), dimension.value());
+                }
+                metrics.push(query);
+            }
+        }
+
+        Ok(metrics)
+    }
+
+    /// Fetch several statistics for one metric with GetMetricStatistics
+    ///
+    /// Unlike `get_metric_data`, every requested statistic (including
+    /// percentiles) is returned on each datapoint. CloudWatch limits a call to
+    /// 1440 datapoints, so long ranges need a larger period.
+    pub async fn get_metric_statistics(
+        &self,
+        account_id: &str,
+        region: &str,
+        query: &MetricQuery,
+        statistics: &[String],
+        options: MetricQueryOptions,
+    ) -> Result<MetricStatisticsResult> {
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let (start_time, end_time) = options.resolve_time_range(now_ms);
+
+        if statistics.is_empty() {
+            anyhow::bail!("At least one statistic is required");
+        }
+
+        let datapoints = expected_datapoints(start_time, end_time, options.period);
+        if datapoints > MAX_STATISTICS_DATAPOINTS {
+            anyhow::bail!(
+                "Time range needs {} datapoints at a {}s period (maximum {}); use a larger period",
+                datapoints,
+                options.period,
+                MAX_STATISTICS_DATAPOINTS
+            );
+        }
+
+        let client = self.create_client(account_id, region).await?;
+
+        let (standard, extended) = split_statistics(statistics);
+
+        let mut request = client
+            .get_metric_statistics()
+            .namespace(&query.namespace)
+            .metric_name(&query.metric_name)
+            .start_time(cloudwatch::primitives::DateTime::from_millis(start_time))
+            .end_time(cloudwatch::primitives::DateTime::from_millis(end_time))
+            .period(options.period);
+
+        for dimension in &query.dimensions {
+            request = request.dimensions(
+                cloudwatch::types::Dimension::builder()
+                    .name(&dimension.name)
+                    .value(&dimension.value)
+                    .build()
+                    .context("Failed to build metric dimension")?,
+            );
+        }
+
+        for statistic in &standard {
+            request = request.statistics(cloudwatch::types::Statistic::from(statistic.as_str()));
+        }
+
+        for statistic in extended {
+            request = request.extended_statistics(statistic);
+        }
+
+        let response = request.send().await.with_context(|| {
+            format!(
+                "Failed to get metric statistics for account {} in region {}",
+                account_id, region
+            )
+        })?;
+
+        let mut datapoints: Vec<MetricStatisticsDatapoint> = response
+            .datapoints()
+            .iter()
+            .map(|point| MetricStatisticsDatapoint {
+                timestamp: point
+                    .timestamp()
+                    .and_then(|t| t.to_millis().ok())
+                    .unwrap_or(0),
+                average: point.average(),
+                sum: point.sum(),
+                minimum: point.minimum(),
+                maximum: point.maximum(),
+                sample_count: point.sample_count(),
+                extended: point
+                    .extended_statistics()
+                    .map(|stats| stats.iter().map(|(k, v)| (k.clone(), *v)).collect())
+                    .unwrap_or_default(),
+                unit: point.unit().map(|u| u.as_str().to_string()),
+            })
+            .collect();
+        datapoints.sort_by_key(|p| p.timestamp);
+
+        Ok(MetricStatisticsResult {
+            namespace: query.namespace.clone(),
+            metric_name: query.metric_name.clone(),
+            dimensions: query.dimensions.clone(),
+            statistics: statistics.to_vec(),
+            datapoints,
+            start_time,
+            end_time,
+            period: options.period,
+        })
+    }
+
+    /// List metric alarms that watch a resource
+    ///
+    /// CloudWatch has no server-side filter by dimension, so all metric alarms
+    /// in the region are fetched (optionally by state) and matched here.
+    /// Metric math alarms match on the dimensions of any metric they use.
+    pub async fn list_alarms_for_resource(
+        &self,
+        account_id: &str,
+        region: &str,
+        filter: &AlarmResourceFilter,
+        state_value: Option<&str>,
+    ) -> Result<Vec<MetricAlarmInfo>> {
+        let client = self.create_client(account_id, region).await?;
+
+        let mut request = client
+            .describe_alarms()
+            .alarm_types(cloudwatch::types::AlarmType::MetricAlarm);
+
+        if let Some(state) = state_value {
+            request = request.state_value(cloudwatch::types::StateValue::from(state));
+        }
+
+        let mut alarms = Vec::new();
+        let mut paginator = request.into_paginator().send();
+
+        while let Some(page) = paginator.next().await {
+            let page = page.with_context(|| {
+                format!(
+                    "Failed to describe alarms for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+            for alarm in page.metric_alarms() {
+                let info = convert_metric_alarm(alarm);
+                if filter.matches(&info.dimensions) {
+                    alarms.push(info);
+                }
+            }
+        }
+
+        Ok(alarms)
+    }
+}
+
+/// Build the GetMetricData query for one requested metric
+///
+/// Query IDs are `m{index}` so results can be matched back to the request order.
+fn build_metric_data_query(
+    index: usize,
+    query: &MetricQuery,
+    period: i32,
+) -> Result<cloudwatch::types::MetricDataQuery> {
+    let mut metric = cloudwatch::types::Metric::builder()
+        .namespace(&query.namespace)
+        .metric_name(&query.metric_name);
+
+    for dimension in &query.dimensions {
+        metric = metric.dimensions(
+            cloudwatch::types::Dimension::builder()
+                .name(&dimension.name)
+                .value(&dimension.value)
+                .build()
+                .context("Failed to build metric dimension")?,
+        );
+    }
+
+    let metric_stat = cloudwatch::types::MetricStat::builder()
+        .metric(metric.build())
+        .period(period)
+        .stat(&query.statistic)
+        .build()
+        .context("Failed to build metric stat")?;
+
+    cloudwatch::types::MetricDataQuery::builder()
+        .id(format!("m{}", index))
+        .label(query.label())
+        .metric_stat(metric_stat)
+        .return_data(true)
+        .build()
+        .context("Failed to build metric data query")
+}
+
+/// Convert an SDK metric alarm, collecting dimensions from metric math queries too
+fn convert_metric_alarm(alarm: &cloudwatch::types::MetricAlarm) -> MetricAlarmInfo {
+    let mut dimensions: Vec<MetricDimension> = alarm
+        .dimensions()
+        .iter()
+        .map(|d| MetricDimension::new(d.name(), d.value()))
+        .collect();
+
+    for metric in alarm
+        .metrics()
+        .iter()
+        .filter_map(|q| q.metric_stat().and_then(|stat| stat.metric()))
+    {
+        for d in metric.dimensions() {
+            let dimension = MetricDimension::new(d.name(), d.value());
+            if !dimensions.contains(&dimension) {
+                dimensions.push(dimension);
+            }
+        }
+    }
+
+    MetricAlarmInfo {
+        alarm_name: alarm.alarm_name().unwrap_or_default().to_string(),
+        alarm_arn: alarm.alarm_arn().map(String::from),
+        description: alarm.alarm_description().map(String::from),
+        state_value: alarm
+            .state_value()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        state_reason: alarm.state_reason().map(String::from),
+        state_updated_at: alarm
+            .state_updated_timestamp()
+            .and_then(|t| t.to_millis().ok()),
+        namespace: alarm.namespace().unwrap_or_default().to_string(),
+        metric_name: alarm.metric_name().unwrap_or_default().to_string(),
+        dimensions,
+        statistic: alarm
+            .statistic()
+            .map(|s| s.as_str().to_string())
+            .or_else(|| alarm.extended_statistic().map(String::from)),
+        comparison_operator: alarm.comparison_operator().map(|c| c.as_str().to_string()),
+        threshold: alarm.threshold(),
+        evaluation_periods: alarm.evaluation_periods(),
+        period: alarm.period(),
+        actions_enabled: alarm.actions_enabled().unwrap_or(false),
+        alarm_actions: alarm.alarm_actions().to_vec(),
+    }
+}
+
+/// Parse a `m{index}` query ID back into the request index
+fn parse_query_id(id: &str) -> Option<usize> {
+    id.strip_prefix('m')?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_query_id() {
+        assert_eq!(parse_query_id("m0"), Some(0));
+        assert_eq!(parse_query_id("m12"), Some(12));
+        assert_eq!(parse_query_id("x1"), None);
+        assert_eq!(parse_query_id("m"), None);
+    }
+
+    #[test]
+    fn test_build_metric_data_query() {
+        let query = MetricQuery::new("AWS/EC2", "CPUUtilization")
+            .with_dimension("InstanceId", "i-0abc")
+            .with_statistic("Maximum");
+
+        let data_query = build_metric_data_query(3, &query, 300).unwrap();
+        assert_eq!(data_query.id(), "m3");
+        assert_eq!(data_query.label(), Some("CPUUtilization (Maximum)"));
+        assert!(data_query.metric_stat().is_some());
+    }
+}
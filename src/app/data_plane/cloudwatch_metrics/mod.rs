@@ -0,0 +1,58 @@
+//! CloudWatch Metrics Integration Module
+//!
+//! Provides functionality for querying and charting AWS CloudWatch Metrics within the application.
+//!
+//! ## Features
+//!
+//! - Fetch time series for any namespace/metric/dimension combination
+//! - Default metric sets for EC2, Lambda, RDS, and DynamoDB resources
+//! - Configurable time range, aggregation period, and statistic
+//! - Multi-statistic queries (GetMetricStatistics) and alarms watching a resource
+//! - Integration with Resource Explorer and agents
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::cloudwatch_metrics::{
+//!     CloudWatchMetricsClient, MetricQuery, MetricQueryOptions,
+//! };
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = CloudWatchMetricsClient::new(credential_coordinator);
+//!
+//! let cpu = MetricQuery::new("AWS/EC2", "CPUUtilization")
+//!     .with_dimension("InstanceId", "i-0123456789abcdef0")
+//!     .with_statistic("Average");
+//!
+//! let result = client.get_metric_data(
+//!     "123456789012",            // account_id
+//!     "us-east-1",               // region
+//!     &[cpu],
+//!     MetricQueryOptions::new().with_period(300),
+//! ).await?;
+//!
+//! for point in &result.series[0].datapoints {
+//!     println!("{}: {}", point.timestamp, point.value);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod resource_mapping;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::CloudWatchMetricsClient;
+pub use resource_mapping::{
+    get_default_metrics, get_metric_dimension, get_metric_namespace, has_cloudwatch_metrics,
+};
+pub use types::{
+    AlarmResourceFilter, MetricAlarmInfo, MetricDataPoint, MetricDimension, MetricQuery,
+    MetricQueryOptions, MetricQueryResult, MetricSeries, MetricStatisticsDatapoint,
+    MetricStatisticsResult,
+};
@@ -0,0 +1,134 @@
+//! Resource to CloudWatch Metrics Mapping
+//!
+//! Maps AWS resource types to their CloudWatch namespace, identifying dimension,
+//! and the default set of metrics shown in the metrics viewer.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use super::types::{MetricDimension, MetricQuery};
+
+/// Check if a resource type has default CloudWatch Metrics
+pub fn has_cloudwatch_metrics(resource_type: &str) -> bool {
+    get_metric_namespace(resource_type).is_some()
+}
+
+/// Get the CloudWatch namespace for a resource type
+pub fn get_metric_namespace(resource_type: &str) -> Option<&'static str> {
+    match resource_type {
+        "AWS::EC2::Instance" => Some("AWS/EC2"),
+        "AWS::Lambda::Function" => Some("AWS/Lambda"),
+        "AWS::RDS::DBInstance" => Some("AWS/RDS"),
+        "AWS::DynamoDB::Table" => Some("AWS/DynamoDB"),
+        _ => None,
+    }
+}
+
+/// Get the dimension that identifies a resource in its namespace
+///
+/// The Resource Explorer stores the native identifier in `resource_id`
+/// (instance ID, function name, DB identifier, table name), which is
+/// exactly the dimension value CloudWatch expects.
+pub fn get_metric_dimension(resource_type: &str, resource_id: &str) -> Option<MetricDimension> {
+    let name = match resource_type {
+        "AWS::EC2::Instance" => "InstanceId",
+        "AWS::Lambda::Function" => "FunctionName",
+        "AWS::RDS::DBInstance" => "DBInstanceIdentifier",
+        "AWS::DynamoDB::Table" => "TableName",
+        _ => return None,
+    };
+    Some(MetricDimension::new(name, resource_id))
+}
+
+/// Get the default metrics to chart for a resource
+///
+/// Returns an empty list for unsupported resource types.
+pub fn get_default_metrics(resource_type: &str, resource_id: &str) -> Vec<MetricQuery> {
+    let (Some(namespace), Some(dimension)) = (
+        get_metric_namespace(resource_type),
+        get_metric_dimension(resource_type, resource_id),
+    ) else {
+        return Vec::new();
+    };
+
+    let metrics: &[(&str, &str)] = match resource_type {
+        "AWS::EC2::Instance" => &[
+            ("CPUUtilization", "Average"),
+            ("NetworkIn", "Sum"),
+            ("NetworkOut", "Sum"),
+            ("StatusCheckFailed", "Maximum"),
+        ],
+        "AWS::Lambda::Function" => &[
+            ("Invocations", "Sum"),
+            ("Errors", "Sum"),
+            ("Duration", "Average"),
+            ("Throttles", "Sum"),
+        ],
+        "AWS::RDS::DBInstance" => &[
+            ("CPUUtilization", "Average"),
+            ("DatabaseConnections", "Average"),
+            ("FreeStorageSpace", "Minimum"),
+            ("ReadLatency", "Average"),
+        ],
+        "AWS::DynamoDB::Table" => &[
+            ("ConsumedReadCapacityUnits", "Sum"),
+            ("ConsumedWriteCapacityUnits", "Sum"),
+            ("ReadThrottleEvents", "Sum"),
+            ("WriteThrottleEvents", "Sum"),
+        ],
+        _ => &[],
+    };
+
+    metrics
+        .iter()
+        .map(|(metric_name, statistic)| {
+            MetricQuery::new(namespace, *metric_name)
+                .with_dimension(dimension.name.clone(), dimension.value.clone())
+                .with_statistic(*statistic)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_cloudwatch_metrics() {
+        assert!(has_cloudwatch_metrics("AWS::EC2::Instance"));
+        assert!(has_cloudwatch_metrics("AWS::Lambda::Function"));
+        assert!(has_cloudwatch_metrics("AWS::RDS::DBInstance"));
+        assert!(has_cloudwatch_metrics("AWS::DynamoDB::Table"));
+        assert!(!has_cloudwatch_metrics("AWS::S3::Bucket"));
+        assert!(!has_cloudwatch_metrics("AWS::IAM::Role"));
+    }
+
+    #[test]
+    fn test_get_metric_dimension() {
+        assert_eq!(
+            get_metric_dimension("AWS::EC2::Instance", "i-0abc"),
+            Some(MetricDimension::new("InstanceId", "i-0abc"))
+        );
+        assert_eq!(
+            get_metric_dimension("AWS::RDS::DBInstance", "my-db"),
+            Some(MetricDimension::new("DBInstanceIdentifier", "my-db"))
+        );
+        assert_eq!(get_metric_dimension("AWS::S3::Bucket", "my-bucket"), None);
+    }
+
+    #[test]
+    fn test_get_default_metrics_lambda() {
+        let metrics = get_default_metrics("AWS::Lambda::Function", "my-function");
+        assert_eq!(metrics.len(), 4);
+        assert!(metrics.iter().all(|m| m.namespace == "AWS/Lambda"));
+        assert!(metrics
+            .iter()
+            .all(|m| m.dimensions == vec![MetricDimension::new("FunctionName", "my-function")]));
+        assert_eq!(metrics[1].metric_name, "Errors");
+        assert_eq!(metrics[1].statistic, "Sum");
+    }
+
+    #[test]
+    fn test_get_default_metrics_unsupported() {
+        assert!(get_default_metrics("AWS::S3::Bucket", "my-bucket").is_empty());
+    }
+}
@@ -0,0 +1,540 @@
+//! CloudWatch Metrics Data Types
+//!
+//! Data structures for CloudWatch Metrics queries, time series results, and configuration.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+
+/// Default aggregation period in seconds (5 minutes)
+pub const DEFAULT_PERIOD_SECONDS: i32 = 300;
+
+/// Default lookback window in milliseconds (3 hours)
+pub const DEFAULT_LOOKBACK_MS: i64 = 3 * 60 * 60 * 1000;
+
+/// A CloudWatch metric dimension (name/value pair)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricDimension {
+    /// Dimension name (e.g., "InstanceId", "FunctionName")
+    pub name: String,
+    /// Dimension value (e.g., "i-0123456789abcdef0")
+    pub value: String,
+}
+
+impl MetricDimension {
+    /// Create a new dimension
+    pub fn new(name: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// Identifies a single metric series to fetch
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricQuery {
+    /// Metric namespace (e.g., "AWS/EC2")
+    pub namespace: String,
+    /// Metric name (e.g., "CPUUtilization")
+    pub metric_name: String,
+    /// Dimensions identifying the resource
+    pub dimensions: Vec<MetricDimension>,
+    /// Statistic to aggregate by (Average, Sum, Minimum, Maximum, SampleCount, p99, ...)
+    pub statistic: String,
+}
+
+impl MetricQuery {
+    /// Create a new metric query using the Average statistic
+    pub fn new(namespace: impl Into<String>, metric_name: impl Into<String>) -> Self {
+        Self {
+            namespace: namespace.into(),
+            metric_name: metric_name.into(),
+            dimensions: Vec::new(),
+            statistic: "Average".to_string(),
+        }
+    }
+
+    /// Add a dimension
+    pub fn with_dimension(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.dimensions.push(MetricDimension::new(name, value));
+        self
+    }
+
+    /// Set the statistic
+    pub fn with_statistic(mut self, statistic: impl Into<String>) -> Self {
+        self.statistic = statistic.into();
+        self
+    }
+
+    /// Human-readable label, e.g. "CPUUtilization (Average)"
+    pub fn label(&self) -> String {
+        format!("{} ({})", self.metric_name, self.statistic)
+    }
+}
+
+/// Query options for CloudWatch Metrics
+#[derive(Debug, Clone)]
+pub struct MetricQueryOptions {
+    /// Start time (Unix timestamp in milliseconds, default: 3 hours ago)
+    pub start_time: Option<i64>,
+    /// End time (Unix timestamp in milliseconds, default: now)
+    pub end_time: Option<i64>,
+    /// Aggregation period in seconds (multiple of 60)
+    pub period: i32,
+    /// Maximum datapoints per series
+    pub max_datapoints: Option<i32>,
+}
+
+impl MetricQueryOptions {
+    /// Create new MetricQueryOptions with default values
+    pub fn new() -> Self {
+        Self {
+            start_time: None,
+            end_time: None,
+            period: DEFAULT_PERIOD_SECONDS,
+            max_datapoints: None,
+        }
+    }
+
+    /// Set start time
+    pub fn with_start_time(mut self, start_time: i64) -> Self {
+        self.start_time = Some(start_time);
+        self
+    }
+
+    /// Set end time
+    pub fn with_end_time(mut self, end_time: i64) -> Self {
+        self.end_time = Some(end_time);
+        self
+    }
+
+    /// Set aggregation period (rounded up to a multiple of 60 seconds)
+    pub fn with_period(mut self, period: i32) -> Self {
+        self.period = normalize_period(period);
+        self
+    }
+
+    /// Set maximum datapoints per series
+    pub fn with_max_datapoints(mut self, max_datapoints: i32) -> Self {
+        self.max_datapoints = Some(max_datapoints);
+        self
+    }
+
+    /// Resolve the effective time range, filling in defaults relative to `now_ms`
+    pub fn resolve_time_range(&self, now_ms: i64) -> (i64, i64) {
+        let end = self.end_time.unwrap_or(now_ms);
+        let start = self.start_time.unwrap_or(end - DEFAULT_LOOKBACK_MS);
+        (start, end)
+    }
+}
+
+impl Default for MetricQueryOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Round a period up to a multiple of 60 seconds (CloudWatch requirement for standard resolution)
+pub fn normalize_period(period: i32) -> i32 {
+    if period <= 60 {
+        60
+    } else {
+        ((period + 59) / 60) * 60
+    }
+}
+
+/// A single datapoint in a metric time series
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricDataPoint {
+    /// Timestamp (Unix milliseconds)
+    pub timestamp: i64,
+    /// Aggregated value for the period
+    pub value: f64,
+}
+
+/// Time series returned for one metric query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricSeries {
+    /// Label (metric name and statistic)
+    pub label: String,
+    /// Metric namespace
+    pub namespace: String,
+    /// Metric name
+    pub metric_name: String,
+    /// Statistic used for aggregation
+    pub statistic: String,
+    /// Dimensions identifying the resource
+    pub dimensions: Vec<MetricDimension>,
+    /// Datapoints sorted by timestamp ascending
+    pub datapoints: Vec<MetricDataPoint>,
+    /// CloudWatch status code (Complete, PartialData, InternalError, Forbidden)
+    pub status: String,
+}
+
+impl MetricSeries {
+    /// Create an empty series for a query
+    pub fn from_query(query: &MetricQuery) -> Self {
+        Self {
+            label: query.label(),
+            namespace: query.namespace.clone(),
+            metric_name: query.metric_name.clone(),
+            statistic: query.statistic.clone(),
+            dimensions: query.dimensions.clone(),
+            datapoints: Vec::new(),
+            status: "Complete".to_string(),
+        }
+    }
+
+    /// Sort datapoints chronologically
+    pub fn sort(&mut self) {
+        self.datapoints.sort_by_key(|p| p.timestamp);
+    }
+
+    /// Minimum value in the series
+    pub fn min(&self) -> Option<f64> {
+        self.datapoints.iter().map(|p| p.value).reduce(f64::min)
+    }
+
+    /// Maximum value in the series
+    pub fn max(&self) -> Option<f64> {
+        self.datapoints.iter().map(|p| p.value).reduce(f64::max)
+    }
+
+    /// Mean of all values in the series
+    pub fn average(&self) -> Option<f64> {
+        if self.datapoints.is_empty() {
+            return None;
+        }
+        let sum: f64 = self.datapoints.iter().map(|p| p.value).sum();
+        Some(sum / self.datapoints.len() as f64)
+    }
+
+    /// Most recent value in the series
+    pub fn latest(&self) -> Option<f64> {
+        self.datapoints
+            .iter()
+            .max_by_key(|p| p.timestamp)
+            .map(|p| p.value)
+    }
+}
+
+/// Result of a CloudWatch Metrics query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricQueryResult {
+    /// One series per requested metric, in request order
+    pub series: Vec<MetricSeries>,
+    /// Query start time (Unix milliseconds)
+    pub start_time: i64,
+    /// Query end time (Unix milliseconds)
+    pub end_time: i64,
+    /// Aggregation period in seconds
+    pub period: i32,
+    /// Total number of datapoints across all series
+    pub total_datapoints: usize,
+}
+
+impl MetricQueryResult {
+    /// Create a new result, computing the datapoint total
+    pub fn new(series: Vec<MetricSeries>, start_time: i64, end_time: i64, period: i32) -> Self {
+        let total_datapoints = series.iter().map(|s| s.datapoints.len()).sum();
+        Self {
+            series,
+            start_time,
+            end_time,
+            period,
+            total_datapoints,
+        }
+    }
+}
+
+/// Maximum datapoints returned by a single GetMetricStatistics call
+pub const MAX_STATISTICS_DATAPOINTS: i64 = 1440;
+
+/// Statistics GetMetricStatistics accepts by name; anything else (p99, tm90, ...)
+/// is sent as an extended statistic
+pub const STANDARD_STATISTICS: [&str; 5] = ["SampleCount", "Average", "Sum", "Minimum", "Maximum"];
+
+/// Split requested statistics into standard and extended (percentile) statistics
+pub fn split_statistics(statistics: &[String]) -> (Vec<String>, Vec<String>) {
+    statistics
+        .iter()
+        .cloned()
+        .partition(|s| STANDARD_STATISTICS.contains(&s.as_str()))
+}
+
+/// Number of datapoints a time range produces at the given period
+pub fn expected_datapoints(start_time: i64, end_time: i64, period: i32) -> i64 {
+    let period_ms = i64::from(period.max(1)) * 1000;
+    ((end_time - start_time).max(0) + period_ms - 1) / period_ms
+}
+
+/// A GetMetricStatistics datapoint with every requested statistic
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricStatisticsDatapoint {
+    /// Timestamp (Unix milliseconds)
+    pub timestamp: i64,
+    /// Average value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average: Option<f64>,
+    /// Sum of values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sum: Option<f64>,
+    /// Minimum value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<f64>,
+    /// Maximum value
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<f64>,
+    /// Number of samples
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_count: Option<f64>,
+    /// Percentile and trimmed statistics (e.g., "p99")
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub extended: std::collections::BTreeMap<String, f64>,
+    /// Unit reported by CloudWatch (e.g., "Percent", "Count")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+}
+
+impl MetricStatisticsDatapoint {
+    /// Value of a statistic by name (standard or extended)
+    pub fn value(&self, statistic: &str) -> Option<f64> {
+        match statistic {
+            "Average" => self.average,
+            "Sum" => self.sum,
+            "Minimum" => self.minimum,
+            "Maximum" => self.maximum,
+            "SampleCount" => self.sample_count,
+            other => self.extended.get(other).copied(),
+        }
+    }
+}
+
+/// Result of a GetMetricStatistics query for one metric
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricStatisticsResult {
+    /// Metric namespace
+    pub namespace: String,
+    /// Metric name
+    pub metric_name: String,
+    /// Dimensions identifying the resource
+    pub dimensions: Vec<MetricDimension>,
+    /// Statistics requested
+    pub statistics: Vec<String>,
+    /// Datapoints sorted by timestamp ascending
+    pub datapoints: Vec<MetricStatisticsDatapoint>,
+    /// Query start time (Unix milliseconds)
+    pub start_time: i64,
+    /// Query end time (Unix milliseconds)
+    pub end_time: i64,
+    /// Aggregation period in seconds
+    pub period: i32,
+}
+
+/// Selects the alarms that watch a resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlarmResourceFilter {
+    /// Alarm metric must carry every one of these dimensions
+    Dimensions(Vec<MetricDimension>),
+    /// Alarm metric must have some dimension with this value (any name)
+    DimensionValue(String),
+}
+
+impl AlarmResourceFilter {
+    /// Check whether an alarm metric's dimensions match the filter
+    pub fn matches(&self, dimensions: &[MetricDimension]) -> bool {
+        match self {
+            Self::Dimensions(wanted) => {
+                !wanted.is_empty() && wanted.iter().all(|w| dimensions.contains(w))
+            }
+            Self::DimensionValue(value) => dimensions.iter().any(|d| &d.value == value),
+        }
+    }
+}
+
+/// A CloudWatch metric alarm
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricAlarmInfo {
+    /// Alarm name
+    pub alarm_name: String,
+    /// Alarm ARN
+    pub alarm_arn: Option<String>,
+    /// Alarm description
+    pub description: Option<String>,
+    /// Current state (OK, ALARM, INSUFFICIENT_DATA)
+    pub state_value: String,
+    /// Explanation for the current state
+    pub state_reason: Option<String>,
+    /// When the state last changed (Unix milliseconds)
+    pub state_updated_at: Option<i64>,
+    /// Metric namespace (empty for metric math alarms)
+    pub namespace: String,
+    /// Metric name (empty for metric math alarms)
+    pub metric_name: String,
+    /// Dimensions of the alarm metric (all metrics for metric math alarms)
+    pub dimensions: Vec<MetricDimension>,
+    /// Statistic or extended statistic evaluated
+    pub statistic: Option<String>,
+    /// Comparison operator (e.g., GreaterThanThreshold)
+    pub comparison_operator: Option<String>,
+    /// Threshold value
+    pub threshold: Option<f64>,
+    /// Periods evaluated before changing state
+    pub evaluation_periods: Option<i32>,
+    /// Evaluation period in seconds
+    pub period: Option<i32>,
+    /// Whether alarm actions are enabled
+    pub actions_enabled: bool,
+    /// Actions run when the alarm fires (SNS topics, Auto Scaling policies, ...)
+    pub alarm_actions: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series_with(values: &[(i64, f64)]) -> MetricSeries {
+        let mut series = MetricSeries::from_query(&MetricQuery::new("AWS/EC2", "CPUUtilization"));
+        series.datapoints = values
+            .iter()
+            .map(|(timestamp, value)| MetricDataPoint {
+                timestamp: *timestamp,
+                value: *value,
+            })
+            .collect();
+        series
+    }
+
+    #[test]
+    fn test_metric_query_builder() {
+        let query = MetricQuery::new("AWS/Lambda", "Errors")
+            .with_dimension("FunctionName", "my-function")
+            .with_statistic("Sum");
+
+        assert_eq!(query.namespace, "AWS/Lambda");
+        assert_eq!(query.statistic, "Sum");
+        assert_eq!(
+            query.dimensions,
+            vec![MetricDimension::new("FunctionName", "my-function")]
+        );
+        assert_eq!(query.label(), "Errors (Sum)");
+    }
+
+    #[test]
+    fn test_normalize_period() {
+        assert_eq!(normalize_period(0), 60);
+        assert_eq!(normalize_period(60), 60);
+        assert_eq!(normalize_period(61), 120);
+        assert_eq!(normalize_period(300), 300);
+        assert_eq!(normalize_period(3599), 3600);
+    }
+
+    #[test]
+    fn test_resolve_time_range_defaults() {
+        let now = 10 * DEFAULT_LOOKBACK_MS;
+        let (start, end) = MetricQueryOptions::new().resolve_time_range(now);
+        assert_eq!(end, now);
+        assert_eq!(start, now - DEFAULT_LOOKBACK_MS);
+
+        let (start, end) = MetricQueryOptions::new()
+            .with_start_time(1000)
+            .with_end_time(2000)
+            .resolve_time_range(now);
+        assert_eq!((start, end), (1000, 2000));
+    }
+
+    #[test]
+    fn test_series_statistics() {
+        let series = series_with(&[(3, 5.0), (1, 1.0), (2, 9.0)]);
+        assert_eq!(series.min(), Some(1.0));
+        assert_eq!(series.max(), Some(9.0));
+        assert_eq!(series.average(), Some(5.0));
+        assert_eq!(series.latest(), Some(5.0));
+
+        let empty = series_with(&[]);
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.average(), None);
+        assert_eq!(empty.latest(), None);
+    }
+
+    #[test]
+    fn test_result_serialization() {
+        let mut series = series_with(&[(2, 2.0), (1, 1.0)]);
+        series.sort();
+        assert_eq!(series.datapoints[0].timestamp, 1);
+
+        let result = MetricQueryResult::new(vec![series], 0, 10, 60);
+        assert_eq!(result.total_datapoints, 2);
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["totalDatapoints"], 2);
+        assert_eq!(json["series"][0]["metricName"], "CPUUtilization");
+
+        let deserialized: MetricQueryResult = serde_json::from_value(json).unwrap();
+        assert_eq!(deserialized.series[0].datapoints.len(), 2);
+    }
+
+    #[test]
+    fn test_split_statistics() {
+        let requested: Vec<String> = ["Average", "p99", "Maximum", "tm90"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let (standard, extended) = split_statistics(&requested);
+        assert_eq!(standard, vec!["Average", "Maximum"]);
+        assert_eq!(extended, vec!["p99", "tm90"]);
+    }
+
+    #[test]
+    fn test_expected_datapoints() {
+        let day_ms = 24 * 60 * 60 * 1000;
+        assert_eq!(expected_datapoints(0, day_ms, 60), 1440);
+        assert_eq!(expected_datapoints(0, day_ms, 300), 288);
+        assert_eq!(expected_datapoints(0, 61_000, 60), 2);
+        assert_eq!(expected_datapoints(10, 0, 60), 0);
+    }
+
+    #[test]
+    fn test_statistics_datapoint_value() {
+        let mut point = MetricStatisticsDatapoint {
+            average: Some(12.5),
+            maximum: Some(40.0),
+            ..Default::default()
+        };
+        point.extended.insert("p99".to_string(), 38.0);
+
+        assert_eq!(point.value("Average"), Some(12.5));
+        assert_eq!(point.value("p99"), Some(38.0));
+        assert_eq!(point.value("Sum"), None);
+
+        let json = serde_json::to_value(&point).unwrap();
+        assert!(json.get("sum").is_none());
+        assert_eq!(json["extended"]["p99"], 38.0);
+    }
+
+    #[test]
+    fn test_alarm_resource_filter() {
+        let dims = vec![
+            MetricDimension::new("AutoScalingGroupName", "web"),
+            MetricDimension::new("InstanceId", "i-0abc"),
+        ];
+
+        let by_dimension =
+            AlarmResourceFilter::Dimensions(vec![MetricDimension::new("InstanceId", "i-0abc")]);
+        assert!(by_dimension.matches(&dims));
+        assert!(!by_dimension.matches(&dims[..1]));
+        assert!(!AlarmResourceFilter::Dimensions(Vec::new()).matches(&dims));
+
+        assert!(AlarmResourceFilter::DimensionValue("web".to_string()).matches(&dims));
+        assert!(!AlarmResourceFilter::DimensionValue("api".to_string()).matches(&dims));
+    }
+}
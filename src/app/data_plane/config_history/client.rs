@@ -0,0 +1,238 @@
+//! AWS Config Client Wrapper
+//!
+//! Provides a simplified interface to AWS Config resource history with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_config as config;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::resource_mapping::requires_resource_id_lookup;
+use super::types::{
+    parse_configuration, ConfigHistoryOptions, ConfigHistoryResult, ConfigRelationship,
+    ConfigurationItem,
+};
+
+/// AWS Config client wrapper
+#[derive(Clone)]
+pub struct AwsConfigClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl AwsConfigClient {
+    /// Create a new AWS Config client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<config::Client> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        Ok(config::Client::new(&aws_config))
+    }
+
+    /// Get the configuration timeline for a resource
+    ///
+    /// `resource_id` is the ID AWS Config records the resource under. Use
+    /// [`Self::get_history_for_resource`] when starting from an Explorer resource.
+    pub async fn get_resource_config_history(
+        &self,
+        account_id: &str,
+        region: &str,
+        resource_type: &str,
+        resource_id: &str,
+        options: ConfigHistoryOptions,
+    ) -> Result<ConfigHistoryResult> {
+        let client = self.create_client(account_id, region).await?;
+
+        let mut request = client
+            .get_resource_config_history()
+            .resource_type(config::types::ResourceType::from(resource_type))
+            .resource_id(resource_id);
+
+        if let Some(earlier_time) = options.earlier_time {
+            request = request.earlier_time(config::primitives::DateTime::from_millis(earlier_time));
+        }
+
+        if let Some(later_time) = options.later_time {
+            request = request.later_time(config::primitives::DateTime::from_millis(later_time));
+        }
+
+        if options.chronological {
+            request = request.chronological_order(config::types::ChronologicalOrder::Forward);
+        } else {
+            request = request.chronological_order(config::types::ChronologicalOrder::Reverse);
+        }
+
+        if let Some(limit) = options.limit {
+            request = request.limit(limit);
+        }
+
+        if let Some(token) = options.next_token {
+            request = request.next_token(token);
+        }
+
+        let response = request.send().await.with_context(|| {
+            format!(
+                "Failed to get configuration history for {} {}",
+                resource_type, resource_id
+            )
+        })?;
+
+        let items = response
+            .configuration_items()
+            .iter()
+            .map(convert_configuration_item)
+            .collect();
+
+        Ok(ConfigHistoryResult::new(
+            items,
+            response.next_token().map(|t| t.to_string()),
+        ))
+    }
+
+    /// Get the configuration timeline for a resource as identified by the Resource Explorer
+    ///
+    /// Resolves IAM names to the unique IDs AWS Config uses before querying.
+    pub async fn get_history_for_resource(
+        &self,
+        account_id: &str,
+        region: &str,
+        resource_type: &str,
+        resource_id: &str,
+        options: ConfigHistoryOptions,
+    ) -> Result<ConfigHistoryResult> {
+        let config_resource_id = if requires_resource_id_lookup(resource_type) {
+            self.resolve_resource_id(account_id, region, resource_type, resource_id)
+                .await?
+                .with_context(|| {
+                    format!(
+                        "AWS Config has not recorded {} named {}",
+                        resource_type, resource_id
+                    )
+                })?
+        } else {
+            resource_id.to_string()
+        };
+
+        self.get_resource_config_history(
+            account_id,
+            region,
+            resource_type,
+            &config_resource_id,
+            options,
+        )
+        .await
+    }
+
+    /// Look up the AWS Config resource ID for a resource name
+    pub async fn resolve_resource_id(
+        &self,
+        account_id: &str,
+        region: &str,
+        resource_type: &str,
+        resource_name: &str,
+    ) -> Result<Option<String>> {
+        let client = self.create_client(account_id, region).await?;
+
+        let response = client
+            .list_discovered_resources()
+            .resource_type(config::types::ResourceType::from(resource_type))
+            .resource_name(resource_name)
+            .send()
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to list discovered {} resources named {}",
+                    resource_type, resource_name
+                )
+            })?;
+
+        Ok(response
+            .resource_identifiers()
+            .iter()
+            .find_map(|identifier| identifier.resource_id().map(|id| id.to_string())))
+    }
+}
+
+/// Convert an SDK configuration item into our type
+fn convert_configuration_item(item: &config::types::ConfigurationItem) -> ConfigurationItem {
+    let supplementary_configuration: BTreeMap<String, serde_json::Value> = item
+        .supplementary_configuration()
+        .map(|map| {
+            map.iter()
+                .map(|(key, value)| (key.clone(), parse_configuration(value)))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let tags: BTreeMap<String, String> = item
+        .tags()
+        .map(|map| {
+            map.iter()
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let mut relationships: Vec<ConfigRelationship> = item
+        .relationships()
+        .iter()
+        .map(|relationship| ConfigRelationship {
+            relationship_name: relationship
+                .relationship_name()
+                .unwrap_or_default()
+                .to_string(),
+            resource_type: relationship
+                .resource_type()
+                .map(|t| t.as_str().to_string())
+                .unwrap_or_default(),
+            resource_id: relationship.resource_id().unwrap_or_default().to_string(),
+            resource_name: relationship.resource_name().map(|n| n.to_string()),
+        })
+        .collect();
+    relationships.sort();
+
+    ConfigurationItem {
+        capture_time: item
+            .configuration_item_capture_time()
+            .and_then(|t| t.to_millis().ok())
+            .unwrap_or(0),
+        status: item
+            .configuration_item_status()
+            .map(|s| s.as_str().to_string())
+            .unwrap_or_default(),
+        configuration_state_id: item.configuration_state_id().map(|s| s.to_string()),
+        resource_type: item
+            .resource_type()
+            .map(|t| t.as_str().to_string())
+            .unwrap_or_default(),
+        resource_id: item.resource_id().unwrap_or_default().to_string(),
+        resource_name: item.resource_name().map(|n| n.to_string()),
+        arn: item.arn().map(|a| a.to_string()),
+        aws_region: item.aws_region().map(|r| r.to_string()),
+        availability_zone: item.availability_zone().map(|z| z.to_string()),
+        configuration: item
+            .configuration()
+            .map(parse_configuration)
+            .unwrap_or(serde_json::Value::Null),
+        supplementary_configuration,
+        tags,
+        relationships,
+    }
+}
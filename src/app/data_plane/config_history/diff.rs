@@ -0,0 +1,307 @@
+//! Configuration Snapshot Diffing
+//!
+//! Computes field-level changes between two AWS Config configuration items.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::types::ConfigurationItem;
+
+/// Kind of change for a single field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+impl ChangeKind {
+    /// Short marker for text display
+    pub fn marker(&self) -> &'static str {
+        match self {
+            ChangeKind::Added => "+",
+            ChangeKind::Removed => "-",
+            ChangeKind::Modified => "~",
+        }
+    }
+}
+
+/// A single field-level change between two snapshots
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigChange {
+    /// Dotted path to the changed field (e.g., "configuration.ipPermissions[0].toPort")
+    pub path: String,
+    /// Kind of change
+    pub kind: ChangeKind,
+    /// Value in the older snapshot
+    pub before: Option<Value>,
+    /// Value in the newer snapshot
+    pub after: Option<Value>,
+}
+
+/// All changes between two consecutive snapshots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotDiff {
+    /// Capture time of the older snapshot (Unix milliseconds)
+    pub from_capture_time: i64,
+    /// Capture time of the newer snapshot (Unix milliseconds)
+    pub to_capture_time: i64,
+    /// Status of the newer snapshot
+    pub to_status: String,
+    /// Field-level changes
+    pub changes: Vec<ConfigChange>,
+}
+
+/// Diff two configuration items (`older` -> `newer`)
+///
+/// Compares the resource configuration, supplementary configuration, tags,
+/// and relationships. Paths are prefixed with the section name.
+pub fn diff_items(older: &ConfigurationItem, newer: &ConfigurationItem) -> Vec<ConfigChange> {
+    let mut changes = Vec::new();
+
+    diff_values(
+        "configuration",
+        &older.configuration,
+        &newer.configuration,
+        &mut changes,
+    );
+
+    let older_supplementary =
+        serde_json::to_value(&older.supplementary_configuration).unwrap_or(Value::Null);
+    let newer_supplementary =
+        serde_json::to_value(&newer.supplementary_configuration).unwrap_or(Value::Null);
+    diff_values(
+        "supplementaryConfiguration",
+        &older_supplementary,
+        &newer_supplementary,
+        &mut changes,
+    );
+
+    let older_tags = serde_json::to_value(&older.tags).unwrap_or(Value::Null);
+    let newer_tags = serde_json::to_value(&newer.tags).unwrap_or(Value::Null);
+    diff_values("tags", &older_tags, &newer_tags, &mut changes);
+
+    // Relationships are unordered; compare as sets of descriptions
+    let older_relationships: Vec<String> =
+        older.relationships.iter().map(|r| r.describe()).collect();
+    let newer_relationships: Vec<String> =
+        newer.relationships.iter().map(|r| r.describe()).collect();
+    for removed in older_relationships
+        .iter()
+        .filter(|r| !newer_relationships.contains(r))
+    {
+        changes.push(ConfigChange {
+            path: "relationships".to_string(),
+            kind: ChangeKind::Removed,
+            before: Some(Value::String(removed.clone())),
+            after: None,
+        });
+    }
+    for added in newer_relationships
+        .iter()
+        .filter(|r| !older_relationships.contains(r))
+    {
+        changes.push(ConfigChange {
+            path: "relationships".to_string(),
+            kind: ChangeKind::Added,
+            before: None,
+            after: Some(Value::String(added.clone())),
+        });
+    }
+
+    changes
+}
+
+/// Diff each pair of adjacent snapshots
+///
+/// `items` may be in either order; the result is always oldest-first.
+pub fn diff_timeline(items: &[ConfigurationItem]) -> Vec<SnapshotDiff> {
+    let mut sorted: Vec<&ConfigurationItem> = items.iter().collect();
+    sorted.sort_by_key(|item| item.capture_time);
+
+    sorted
+        .windows(2)
+        .map(|pair| SnapshotDiff {
+            from_capture_time: pair[0].capture_time,
+            to_capture_time: pair[1].capture_time,
+            to_status: pair[1].status.clone(),
+            changes: diff_items(pair[0], pair[1]),
+        })
+        .collect()
+}
+
+/// Recursively diff two JSON values, appending changes under `path`
+pub fn diff_values(path: &str, before: &Value, after: &Value, changes: &mut Vec<ConfigChange>) {
+    if before == after {
+        return;
+    }
+
+    match (before, after) {
+        (Value::Object(before_map), Value::Object(after_map)) => {
+            for (key, before_value) in before_map {
+                let child = format!("{}.{}", path, key);
+                match after_map.get(key) {
+                    Some(after_value) => diff_values(&child, before_value, after_value, changes),
+                    None => changes.push(ConfigChange {
+                        path: child,
+                        kind: ChangeKind::Removed,
+                        before: Some(before_value.clone()),
+                        after: None,
+                    }),
+                }
+            }
+            for (key, after_value) in after_map {
+                if !before_map.contains_key(key) {
+                    changes.push(ConfigChange {
+                        path: format!("{}.{}", path, key),
+                        kind: ChangeKind::Added,
+                        before: None,
+                        after: Some(after_value.clone()),
+                    });
+                }
+            }
+        }
+        (Value::Array(before_items), Value::Array(after_items)) => {
+            let len = before_items.len().max(after_items.len());
+            for index in 0..len {
+                let child = format!("{}[{}]", path, index);
+                match (before_items.get(index), after_items.get(index)) {
+                    (Some(b), Some(a)) => diff_values(&child, b, a, changes),
+                    (Some(b), None) => changes.push(ConfigChange {
+                        path: child,
+                        kind: ChangeKind::Removed,
+                        before: Some(b.clone()),
+                        after: None,
+                    }),
+                    (None, Some(a)) => changes.push(ConfigChange {
+                        path: child,
+                        kind: ChangeKind::Added,
+                        before: None,
+                        after: Some(a.clone()),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        (Value::Null, _) => changes.push(ConfigChange {
+            path: path.to_string(),
+            kind: ChangeKind::Added,
+            before: None,
+            after: Some(after.clone()),
+        }),
+        (_, Value::Null) => changes.push(ConfigChange {
+            path: path.to_string(),
+            kind: ChangeKind::Removed,
+            before: Some(before.clone()),
+            after: None,
+        }),
+        _ => changes.push(ConfigChange {
+            path: path.to_string(),
+            kind: ChangeKind::Modified,
+            before: Some(before.clone()),
+            after: Some(after.clone()),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::types::ConfigRelationship;
+    use super::*;
+    use serde_json::json;
+    use std::collections::BTreeMap;
+
+    fn item(capture_time: i64, configuration: Value) -> ConfigurationItem {
+        ConfigurationItem {
+            capture_time,
+            status: "OK".to_string(),
+            configuration_state_id: None,
+            resource_type: "AWS::EC2::SecurityGroup".to_string(),
+            resource_id: "sg-123".to_string(),
+            resource_name: None,
+            arn: None,
+            aws_region: Some("us-east-1".to_string()),
+            availability_zone: None,
+            configuration,
+            supplementary_configuration: BTreeMap::new(),
+            tags: BTreeMap::new(),
+            relationships: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_diff_values_nested() {
+        let before = json!({"a": 1, "b": {"c": "x"}, "gone": true});
+        let after = json!({"a": 2, "b": {"c": "x", "d": [1]}});
+        let mut changes = Vec::new();
+        diff_values("root", &before, &after, &mut changes);
+
+        assert_eq!(changes.len(), 3);
+        assert!(changes
+            .iter()
+            .any(|c| c.path == "root.a" && c.kind == ChangeKind::Modified));
+        assert!(changes
+            .iter()
+            .any(|c| c.path == "root.gone" && c.kind == ChangeKind::Removed));
+        assert!(changes
+            .iter()
+            .any(|c| c.path == "root.b.d" && c.kind == ChangeKind::Added));
+    }
+
+    #[test]
+    fn test_diff_values_arrays() {
+        let mut changes = Vec::new();
+        diff_values(
+            "ports",
+            &json!([80, 443]),
+            &json!([80, 8443, 22]),
+            &mut changes,
+        );
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].path, "ports[1]");
+        assert_eq!(changes[0].kind, ChangeKind::Modified);
+        assert_eq!(changes[1].path, "ports[2]");
+        assert_eq!(changes[1].kind, ChangeKind::Added);
+    }
+
+    #[test]
+    fn test_diff_items_tags_and_relationships() {
+        let mut older = item(1, json!({"groupName": "web"}));
+        let mut newer = item(2, json!({"groupName": "web"}));
+        older.tags.insert("env".to_string(), "dev".to_string());
+        newer.tags.insert("env".to_string(), "prod".to_string());
+        newer.relationships.push(ConfigRelationship {
+            relationship_name: "Is associated with".to_string(),
+            resource_type: "AWS::EC2::Instance".to_string(),
+            resource_id: "i-1".to_string(),
+            resource_name: None,
+        });
+
+        let changes = diff_items(&older, &newer);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].path, "tags.env");
+        assert_eq!(changes[1].path, "relationships");
+        assert_eq!(changes[1].kind, ChangeKind::Added);
+    }
+
+    #[test]
+    fn test_diff_timeline_sorts_oldest_first() {
+        let items = vec![
+            item(30, json!({"v": 3})),
+            item(10, json!({"v": 1})),
+            item(20, json!({"v": 2})),
+        ];
+
+        let diffs = diff_timeline(&items);
+        assert_eq!(diffs.len(), 2);
+        assert_eq!(diffs[0].from_capture_time, 10);
+        assert_eq!(diffs[1].to_capture_time, 30);
+        assert_eq!(diffs[1].changes[0].before, Some(json!(2)));
+    }
+}
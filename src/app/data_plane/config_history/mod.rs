@@ -0,0 +1,58 @@
+//! AWS Config History Integration Module
+//!
+//! Provides functionality for querying a resource's configuration timeline from
+//! AWS Config and showing what changed between snapshots.
+//!
+//! ## Features
+//!
+//! - Query recorded configuration items for any resource AWS Config tracks
+//! - Resolve IAM names to the unique IDs AWS Config records them under
+//! - Field-level diffs of configuration, supplementary configuration, tags, and relationships
+//! - Integration with Resource Explorer ("View Config History") and agents (`getConfigHistory()`)
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::config_history::{
+//!     diff_timeline, AwsConfigClient, ConfigHistoryOptions,
+//! };
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = AwsConfigClient::new(credential_coordinator);
+//!
+//! let history = client.get_history_for_resource(
+//!     "123456789012",
+//!     "us-east-1",
+//!     "AWS::EC2::SecurityGroup",
+//!     "sg-0123456789abcdef0",
+//!     ConfigHistoryOptions::new().with_limit(10),
+//! ).await?;
+//!
+//! for diff in diff_timeline(&history.items) {
+//!     for change in diff.changes {
+//!         println!("{} {}", change.kind.marker(), change.path);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! AWS Config must be recording in the target account and region. Resources
+//! that are not recorded return an empty history.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod diff;
+pub mod resource_mapping;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::AwsConfigClient;
+pub use diff::{diff_items, diff_timeline, ChangeKind, ConfigChange, SnapshotDiff};
+pub use resource_mapping::{has_config_history, requires_resource_id_lookup};
+pub use types::{ConfigHistoryOptions, ConfigHistoryResult, ConfigRelationship, ConfigurationItem};
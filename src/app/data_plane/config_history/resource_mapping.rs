@@ -0,0 +1,81 @@
+//! Resource type mapping for AWS Config history
+//!
+//! Maps Resource Explorer resources to the identifiers AWS Config records them under.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+/// Check if AWS Config can record configuration history for a resource type
+///
+/// AWS Config uses CloudFormation-style type names. This covers the services
+/// most commonly recorded; unsupported types simply return no history.
+pub fn has_config_history(resource_type: &str) -> bool {
+    let service = resource_type.split("::").nth(1).unwrap_or_default();
+    matches!(
+        service,
+        "ApiGateway"
+            | "ApiGatewayV2"
+            | "AutoScaling"
+            | "CloudFormation"
+            | "CloudFront"
+            | "CloudTrail"
+            | "CloudWatch"
+            | "CodeBuild"
+            | "CodePipeline"
+            | "DynamoDB"
+            | "EC2"
+            | "ECR"
+            | "ECS"
+            | "EFS"
+            | "EKS"
+            | "ElastiCache"
+            | "ElasticBeanstalk"
+            | "ElasticLoadBalancing"
+            | "ElasticLoadBalancingV2"
+            | "Elasticsearch"
+            | "IAM"
+            | "KMS"
+            | "Lambda"
+            | "OpenSearch"
+            | "RDS"
+            | "Redshift"
+            | "Route53"
+            | "S3"
+            | "SecretsManager"
+            | "SNS"
+            | "SQS"
+            | "SSM"
+            | "StepFunctions"
+            | "WAFv2"
+    )
+}
+
+/// Check if the Explorer's resource ID differs from the ID AWS Config records
+///
+/// AWS Config keys IAM entities by their unique ID (e.g., `AROA...`) rather
+/// than by name, so those must be resolved with `ListDiscoveredResources`.
+pub fn requires_resource_id_lookup(resource_type: &str) -> bool {
+    matches!(
+        resource_type,
+        "AWS::IAM::Role" | "AWS::IAM::User" | "AWS::IAM::Group" | "AWS::IAM::Policy"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_config_history() {
+        assert!(has_config_history("AWS::EC2::SecurityGroup"));
+        assert!(has_config_history("AWS::S3::Bucket"));
+        assert!(has_config_history("AWS::IAM::Role"));
+        assert!(!has_config_history("AWS::Bedrock::Agent"));
+        assert!(!has_config_history("not-a-type"));
+    }
+
+    #[test]
+    fn test_requires_resource_id_lookup() {
+        assert!(requires_resource_id_lookup("AWS::IAM::Role"));
+        assert!(!requires_resource_id_lookup("AWS::EC2::Instance"));
+    }
+}
@@ -0,0 +1,195 @@
+//! AWS Config History Data Types
+//!
+//! Data structures for resource configuration history queries and results.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Query options for AWS Config resource history
+#[derive(Debug, Clone)]
+pub struct ConfigHistoryOptions {
+    /// Only return items captured at or after this time (Unix milliseconds)
+    pub earlier_time: Option<i64>,
+    /// Only return items captured at or before this time (Unix milliseconds)
+    pub later_time: Option<i64>,
+    /// Return oldest items first (default: newest first)
+    pub chronological: bool,
+    /// Maximum number of configuration items to return
+    pub limit: Option<i32>,
+    /// Pagination token from a previous query
+    pub next_token: Option<String>,
+}
+
+impl ConfigHistoryOptions {
+    /// Create new ConfigHistoryOptions with default values
+    pub fn new() -> Self {
+        Self {
+            earlier_time: None,
+            later_time: None,
+            chronological: false,
+            limit: Some(25),
+            next_token: None,
+        }
+    }
+
+    /// Set earliest capture time
+    pub fn with_earlier_time(mut self, earlier_time: i64) -> Self {
+        self.earlier_time = Some(earlier_time);
+        self
+    }
+
+    /// Set latest capture time
+    pub fn with_later_time(mut self, later_time: i64) -> Self {
+        self.later_time = Some(later_time);
+        self
+    }
+
+    /// Set chronological (oldest first) ordering
+    pub fn with_chronological(mut self, chronological: bool) -> Self {
+        self.chronological = chronological;
+        self
+    }
+
+    /// Set limit
+    pub fn with_limit(mut self, limit: i32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Set pagination token
+    pub fn with_next_token(mut self, token: String) -> Self {
+        self.next_token = Some(token);
+        self
+    }
+}
+
+impl Default for ConfigHistoryOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Relationship between a resource and another resource
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigRelationship {
+    /// Relationship name (e.g., "Is attached to SecurityGroup")
+    pub relationship_name: String,
+    /// Related resource type
+    pub resource_type: String,
+    /// Related resource ID
+    pub resource_id: String,
+    /// Related resource name (if any)
+    pub resource_name: Option<String>,
+}
+
+impl ConfigRelationship {
+    /// One-line description used in diffs
+    pub fn describe(&self) -> String {
+        format!(
+            "{} {} {}",
+            self.relationship_name, self.resource_type, self.resource_id
+        )
+    }
+}
+
+/// A single configuration snapshot recorded by AWS Config
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigurationItem {
+    /// When the configuration was captured (Unix milliseconds)
+    pub capture_time: i64,
+    /// Item status (OK, ResourceDiscovered, ResourceNotRecorded, ResourceDeleted, ...)
+    pub status: String,
+    /// Monotonic ID of this configuration state
+    pub configuration_state_id: Option<String>,
+    /// Resource type (e.g., "AWS::EC2::SecurityGroup")
+    pub resource_type: String,
+    /// Resource ID as recorded by AWS Config
+    pub resource_id: String,
+    /// Resource name (if any)
+    pub resource_name: Option<String>,
+    /// Resource ARN
+    pub arn: Option<String>,
+    /// AWS region
+    pub aws_region: Option<String>,
+    /// Availability zone (if applicable)
+    pub availability_zone: Option<String>,
+    /// Parsed resource configuration
+    pub configuration: serde_json::Value,
+    /// Parsed supplementary configuration (bucket policies, versioning, ...)
+    pub supplementary_configuration: BTreeMap<String, serde_json::Value>,
+    /// Resource tags
+    pub tags: BTreeMap<String, String>,
+    /// Relationships to other resources
+    pub relationships: Vec<ConfigRelationship>,
+}
+
+/// Parse a configuration JSON string from AWS Config
+///
+/// AWS Config returns configuration blobs as JSON-encoded strings. Values that
+/// are not valid JSON are kept as plain strings.
+pub fn parse_configuration(raw: &str) -> serde_json::Value {
+    if raw.is_empty() {
+        return serde_json::Value::Null;
+    }
+    serde_json::from_str(raw).unwrap_or_else(|_| serde_json::Value::String(raw.to_string()))
+}
+
+/// Result of a configuration history query
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigHistoryResult {
+    /// Configuration items in the requested order
+    pub items: Vec<ConfigurationItem>,
+    /// Token for fetching the next page
+    pub next_token: Option<String>,
+    /// Number of items in this result
+    pub total_items: usize,
+}
+
+impl ConfigHistoryResult {
+    /// Create a new result
+    pub fn new(items: Vec<ConfigurationItem>, next_token: Option<String>) -> Self {
+        let total_items = items.len();
+        Self {
+            items,
+            next_token,
+            total_items,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options_builder() {
+        let options = ConfigHistoryOptions::new()
+            .with_earlier_time(1000)
+            .with_later_time(2000)
+            .with_chronological(true)
+            .with_limit(10);
+
+        assert_eq!(options.earlier_time, Some(1000));
+        assert_eq!(options.later_time, Some(2000));
+        assert!(options.chronological);
+        assert_eq!(options.limit, Some(10));
+    }
+
+    #[test]
+    fn test_parse_configuration() {
+        assert_eq!(
+            parse_configuration(r#"{"instanceType":"t3.micro"}"#)["instanceType"],
+            "t3.micro"
+        );
+        assert_eq!(
+            parse_configuration("not json"),
+            serde_json::Value::String("not json".to_string())
+        );
+        assert_eq!(parse_configuration(""), serde_json::Value::Null);
+    }
+}
@@ -0,0 +1,212 @@
+//! Cost Explorer Client Wrapper
+//!
+//! Provides a simplified interface to Cost Explorer with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_costexplorer as costexplorer;
+use aws_sdk_costexplorer::types::{
+    DateInterval, Dimension, DimensionValues, Expression, Granularity, GroupDefinition,
+    GroupDefinitionType, Metric,
+};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+use crate::app::aws_regions::active_partition;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{CostDataResult, CostGroup, CostMetric, CostPeriod, CostQueryOptions};
+
+/// Cost Explorer client wrapper
+#[derive(Clone)]
+pub struct CostExplorerClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl CostExplorerClient {
+    /// Create a new Cost Explorer client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str) -> Result<costexplorer::Client> {
+        // Cost Explorer is a global service served from the partition's global region
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, active_partition().global_region())
+            .await
+            .with_context(|| format!("Failed to create AWS config for account {}", account_id))?;
+
+        Ok(costexplorer::Client::new(&aws_config))
+    }
+
+    /// Filter expression restricting costs to one linked account
+    fn linked_account_filter(options: &CostQueryOptions) -> Option<Expression> {
+        options.linked_account.as_ref().map(|account_id| {
+            Expression::builder()
+                .dimensions(
+                    DimensionValues::builder()
+                        .key(Dimension::LinkedAccount)
+                        .values(account_id)
+                        .build(),
+                )
+                .build()
+        })
+    }
+
+    /// Get month-to-date costs (and optionally the month-end forecast)
+    ///
+    /// Uses today's date in UTC, matching Cost Explorer's billing days. On the
+    /// first day of the month there is no completed day, so the result is empty.
+    pub async fn get_cost_data(
+        &self,
+        account_id: &str,
+        options: &CostQueryOptions,
+    ) -> Result<CostDataResult> {
+        let today = chrono::Utc::now().date_naive();
+        let client = self.create_client(account_id).await?;
+
+        let period = CostPeriod::month_to_date(today);
+        let (groups, currency) = match period {
+            Some(period) => self.fetch_groups(&client, period, options).await?,
+            None => (Vec::new(), "USD".to_string()),
+        };
+
+        let mut result = CostDataResult::new(
+            account_id,
+            options.group_by.clone(),
+            period,
+            currency,
+            groups,
+        );
+
+        if options.include_forecast {
+            match self.fetch_forecast(&client, today, options).await {
+                Ok(Some(rest_of_month)) => result.apply_forecast(rest_of_month),
+                Ok(None) => {}
+                Err(e) => {
+                    // New accounts or accounts with little history cannot be forecast
+                    warn!(
+                        "Cost forecast unavailable for account {}: {:#}",
+                        account_id, e
+                    );
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Fetch grouped month-to-date amounts, following pagination
+    async fn fetch_groups(
+        &self,
+        client: &costexplorer::Client,
+        period: CostPeriod,
+        options: &CostQueryOptions,
+    ) -> Result<(Vec<CostGroup>, String)> {
+        let metric_name = options.metric.usage_metric_name();
+        let mut groups = Vec::new();
+        let mut currency = None;
+        let mut next_page_token: Option<String> = None;
+
+        loop {
+            let mut request = client
+                .get_cost_and_usage()
+                .time_period(
+                    DateInterval::builder()
+                        .start(period.start_str())
+                        .end(period.end_str())
+                        .build()
+                        .context("Invalid cost period")?,
+                )
+                .granularity(Granularity::Monthly)
+                .metrics(metric_name)
+                .set_filter(Self::linked_account_filter(options))
+                .set_next_page_token(next_page_token.take());
+
+            for group_by in &options.group_by {
+                request = request.group_by(
+                    GroupDefinition::builder()
+                        .r#type(GroupDefinitionType::Dimension)
+                        .key(group_by.dimension())
+                        .build(),
+                );
+            }
+
+            let response = request
+                .send()
+                .await
+                .context("Failed to get cost and usage")?;
+
+            for result in response.results_by_time() {
+                for group in result.groups() {
+                    let Some(metric) = group.metrics().and_then(|m| m.get(metric_name)) else {
+                        continue;
+                    };
+                    let amount = metric
+                        .amount()
+                        .and_then(|a| a.parse::<f64>().ok())
+                        .unwrap_or(0.0);
+                    if currency.is_none() {
+                        currency = metric.unit().map(str::to_string);
+                    }
+                    groups.push(CostGroup {
+                        keys: group.keys().to_vec(),
+                        amount,
+                        forecast: None,
+                    });
+                }
+            }
+
+            match response.next_page_token() {
+                Some(token) if !token.is_empty() => next_page_token = Some(token.to_string()),
+                _ => break,
+            }
+        }
+
+        debug!("Fetched {} cost groups", groups.len());
+        Ok((groups, currency.unwrap_or_else(|| "USD".to_string())))
+    }
+
+    /// Forecast for the rest of the month (None when the month is over)
+    async fn fetch_forecast(
+        &self,
+        client: &costexplorer::Client,
+        today: chrono::NaiveDate,
+        options: &CostQueryOptions,
+    ) -> Result<Option<f64>> {
+        let Some(period) = CostPeriod::rest_of_month(today) else {
+            return Ok(None);
+        };
+
+        let forecast_metric = match options.metric {
+            CostMetric::Unblended => Metric::UnblendedCost,
+            CostMetric::Amortized => Metric::AmortizedCost,
+            CostMetric::Blended => Metric::BlendedCost,
+        };
+
+        let response = client
+            .get_cost_forecast()
+            .time_period(
+                DateInterval::builder()
+                    .start(period.start_str())
+                    .end(period.end_str())
+                    .build()
+                    .context("Invalid forecast period")?,
+            )
+            .metric(forecast_metric)
+            .granularity(Granularity::Monthly)
+            .set_filter(Self::linked_account_filter(options))
+            .send()
+            .await
+            .context("Failed to get cost forecast")?;
+
+        Ok(response
+            .total()
+            .and_then(|total| total.amount())
+            .and_then(|amount| amount.parse::<f64>().ok()))
+    }
+}
@@ -0,0 +1,56 @@
+//! Cost Explorer Integration Module
+//!
+//! Provides month-to-date and forecast spend from AWS Cost Explorer within the
+//! application.
+//!
+//! ## Features
+//!
+//! - Month-to-date costs grouped by service, linked account, and/or region
+//! - Month-end forecast, shared across groups by their month-to-date share
+//! - Mapping from CloudFormation resource types to Cost Explorer services
+//! - Integration with Resource Explorer (cost badges on tree groups) and
+//!   agents (`getCostData()`)
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::cost_explorer::{
+//!     CostExplorerClient, CostGroupBy, CostQueryOptions,
+//! };
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = CostExplorerClient::new(credential_coordinator);
+//!
+//! let options = CostQueryOptions::new()
+//!     .with_group_by(vec![CostGroupBy::Region, CostGroupBy::Service]);
+//!
+//! let result = client.get_cost_data("123456789012", &options).await?;
+//!
+//! println!("Month to date: {} {}", result.total, result.currency);
+//! for group in &result.groups {
+//!     println!("{:?}: {:.2}", group.keys, group.amount);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! Cost Explorer must be enabled for the account and the role needs
+//! `ce:GetCostAndUsage` and `ce:GetCostForecast`. A management (payer) account
+//! sees costs for all linked accounts; a member account sees only its own.
+//! Each API request is billed by AWS ($0.01 per request).
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::CostExplorerClient;
+pub use types::{
+    cost_regions_for_region, cost_services_for_resource_type, format_cost, CostDataResult,
+    CostGroup, CostGroupBy, CostMetric, CostPeriod, CostQueryOptions,
+};
@@ -0,0 +1,388 @@
+//! Cost Explorer Data Types
+//!
+//! Data structures for month-to-date cost queries, forecasts, and the mapping
+//! between Cost Explorer service names and CloudFormation resource types.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// Cost Explorer allows at most two group-by dimensions per query
+pub const MAX_GROUP_BY: usize = 2;
+
+/// Dimension to break costs down by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CostGroupBy {
+    Service,
+    Account,
+    Region,
+}
+
+impl CostGroupBy {
+    /// Cost Explorer dimension key
+    pub fn dimension(&self) -> &'static str {
+        match self {
+            CostGroupBy::Service => "SERVICE",
+            CostGroupBy::Account => "LINKED_ACCOUNT",
+            CostGroupBy::Region => "REGION",
+        }
+    }
+}
+
+/// Cost metric to report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CostMetric {
+    #[default]
+    Unblended,
+    Amortized,
+    Blended,
+}
+
+impl CostMetric {
+    /// Metric name used by GetCostAndUsage
+    pub fn usage_metric_name(&self) -> &'static str {
+        match self {
+            CostMetric::Unblended => "UnblendedCost",
+            CostMetric::Amortized => "AmortizedCost",
+            CostMetric::Blended => "BlendedCost",
+        }
+    }
+}
+
+/// Options for a cost query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CostQueryOptions {
+    /// Dimensions to group by (1-2; extras are ignored)
+    pub group_by: Vec<CostGroupBy>,
+    pub metric: CostMetric,
+    /// Also fetch the month-end forecast
+    pub include_forecast: bool,
+    /// Only include costs of this linked account (useful with management
+    /// account credentials, which otherwise see every linked account)
+    pub linked_account: Option<String>,
+}
+
+impl Default for CostQueryOptions {
+    fn default() -> Self {
+        Self {
+            group_by: vec![CostGroupBy::Service],
+            metric: CostMetric::default(),
+            include_forecast: true,
+            linked_account: None,
+        }
+    }
+}
+
+impl CostQueryOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the group-by dimensions (truncated to the Cost Explorer limit)
+    pub fn with_group_by(mut self, group_by: Vec<CostGroupBy>) -> Self {
+        self.group_by = group_by;
+        self.group_by.truncate(MAX_GROUP_BY);
+        self
+    }
+
+    pub fn with_metric(mut self, metric: CostMetric) -> Self {
+        self.metric = metric;
+        self
+    }
+
+    pub fn with_forecast(mut self, include_forecast: bool) -> Self {
+        self.include_forecast = include_forecast;
+        self
+    }
+
+    pub fn with_linked_account(mut self, account_id: impl Into<String>) -> Self {
+        self.linked_account = Some(account_id.into());
+        self
+    }
+}
+
+/// A date range with an exclusive end, as Cost Explorer expects
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostPeriod {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl CostPeriod {
+    /// First of the month up to (not including) today
+    ///
+    /// None on the first day of the month, when there is no completed day yet.
+    pub fn month_to_date(today: NaiveDate) -> Option<Self> {
+        let start = today.with_day(1)?;
+        (start < today).then_some(Self { start, end: today })
+    }
+
+    /// Today up to the first day of next month (the forecast window)
+    pub fn rest_of_month(today: NaiveDate) -> Option<Self> {
+        let end = first_of_next_month(today)?;
+        Some(Self { start: today, end })
+    }
+
+    /// Dates in Cost Explorer's YYYY-MM-DD format
+    pub fn start_str(&self) -> String {
+        self.start.format("%Y-%m-%d").to_string()
+    }
+
+    pub fn end_str(&self) -> String {
+        self.end.format("%Y-%m-%d").to_string()
+    }
+}
+
+fn first_of_next_month(date: NaiveDate) -> Option<NaiveDate> {
+    if date.month() == 12 {
+        NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1)
+    }
+}
+
+/// Cost for one combination of group-by keys
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CostGroup {
+    /// One key per group-by dimension, in query order
+    pub keys: Vec<String>,
+    /// Month-to-date amount
+    pub amount: f64,
+    /// Estimated month-end amount (share of the forecast, see `apply_forecast`)
+    pub forecast: Option<f64>,
+}
+
+/// Month-to-date costs for one account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CostDataResult {
+    /// Account whose credentials were used (a management account sees all
+    /// linked accounts, a member account only itself)
+    pub account_id: String,
+    pub group_by: Vec<CostGroupBy>,
+    /// Period start (inclusive, YYYY-MM-DD)
+    pub period_start: String,
+    /// Period end (exclusive, YYYY-MM-DD)
+    pub period_end: String,
+    /// Currency unit (e.g., "USD")
+    pub currency: String,
+    /// Groups sorted by amount, largest first
+    pub groups: Vec<CostGroup>,
+    /// Month-to-date total
+    pub total: f64,
+    /// Forecast month-end total (None when not requested or not available)
+    pub forecast_total: Option<f64>,
+}
+
+impl CostDataResult {
+    /// Build a result from raw groups, sorting them and summing the total
+    pub fn new(
+        account_id: impl Into<String>,
+        group_by: Vec<CostGroupBy>,
+        period: Option<CostPeriod>,
+        currency: impl Into<String>,
+        mut groups: Vec<CostGroup>,
+    ) -> Self {
+        groups.sort_by(|a, b| b.amount.total_cmp(&a.amount));
+        let total = groups.iter().map(|g| g.amount).sum();
+        Self {
+            account_id: account_id.into(),
+            group_by,
+            period_start: period.map(|p| p.start_str()).unwrap_or_default(),
+            period_end: period.map(|p| p.end_str()).unwrap_or_default(),
+            currency: currency.into(),
+            groups,
+            total,
+            forecast_total: None,
+        }
+    }
+
+    /// Add the forecast for the rest of the month
+    ///
+    /// Cost Explorer only forecasts totals, so each group's month-end
+    /// estimate is its month-to-date amount plus its share of the remaining
+    /// forecast (proportional to its month-to-date share).
+    pub fn apply_forecast(&mut self, rest_of_month: f64) {
+        self.forecast_total = Some(self.total + rest_of_month);
+        for group in &mut self.groups {
+            let share = if self.total > 0.0 {
+                group.amount / self.total
+            } else {
+                0.0
+            };
+            group.forecast = Some(group.amount + rest_of_month * share);
+        }
+    }
+}
+
+/// Format an amount for display, e.g. "$1,234.57" for USD
+pub fn format_cost(amount: f64, currency: &str) -> String {
+    let cents = (amount * 100.0).round() as i64;
+    let whole = (cents.abs() / 100).to_string();
+    let mut grouped = String::new();
+    for (i, c) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    let sign = if cents < 0 { "-" } else { "" };
+    let digits = format!("{}.{:02}", grouped, cents.abs() % 100);
+    if currency.is_empty() || currency == "USD" {
+        format!("{}${}", sign, digits)
+    } else {
+        format!("{}{} {}", sign, digits, currency)
+    }
+}
+
+/// Cost Explorer SERVICE values billed for a CloudFormation resource type
+///
+/// Returns an empty slice when the service is unknown or not billed directly.
+pub fn cost_services_for_resource_type(resource_type: &str) -> &'static [&'static str] {
+    let service = resource_type.split("::").nth(1).unwrap_or_default();
+    match (service, resource_type) {
+        (_, "AWS::EC2::Instance") => &["Amazon Elastic Compute Cloud - Compute"],
+        ("EC2", _) => &["EC2 - Other"],
+        ("S3", _) => &["Amazon Simple Storage Service"],
+        ("Lambda", _) => &["AWS Lambda"],
+        ("RDS", _) => &["Amazon Relational Database Service"],
+        ("DynamoDB", _) => &["Amazon DynamoDB"],
+        ("ECS", _) => &["Amazon Elastic Container Service"],
+        ("EKS", _) => &["Amazon Elastic Container Service for Kubernetes"],
+        ("ECR", _) => &["Amazon EC2 Container Registry (ECR)"],
+        ("ElastiCache", _) => &["Amazon ElastiCache"],
+        ("ElasticLoadBalancing" | "ElasticLoadBalancingV2", _) => {
+            &["Amazon Elastic Load Balancing"]
+        }
+        ("EFS", _) => &["Amazon Elastic File System"],
+        ("CloudFront", _) => &["Amazon CloudFront"],
+        ("Route53", _) => &["Amazon Route 53"],
+        ("SNS", _) => &["Amazon Simple Notification Service"],
+        ("SQS", _) => &["Amazon Simple Queue Service"],
+        ("KMS", _) => &["AWS Key Management Service"],
+        ("SecretsManager", _) => &["AWS Secrets Manager"],
+        ("Logs" | "CloudWatch", _) => &["AmazonCloudWatch"],
+        ("Events", _) => &["CloudWatch Events"],
+        ("StepFunctions", _) => &["AWS Step Functions"],
+        ("ApiGateway" | "ApiGatewayV2", _) => &["Amazon API Gateway"],
+        ("Kinesis", _) => &["Amazon Kinesis"],
+        ("KinesisFirehose", _) => &["Amazon Kinesis Firehose"],
+        ("Redshift", _) => &["Amazon Redshift"],
+        ("OpenSearchService", _) => &["Amazon OpenSearch Service"],
+        ("SageMaker", _) => &["Amazon SageMaker"],
+        ("Glue", _) => &["AWS Glue"],
+        ("Athena", _) => &["Amazon Athena"],
+        ("Backup", _) => &["AWS Backup"],
+        ("WAFv2", _) => &["AWS WAF"],
+        ("GuardDuty", _) => &["Amazon GuardDuty"],
+        ("SecurityHub", _) => &["AWS Security Hub"],
+        ("Config", _) => &["AWS Config"],
+        ("CloudTrail", _) => &["AWS CloudTrail"],
+        ("Bedrock", _) => &["Amazon Bedrock"],
+        ("CodeBuild", _) => &["CodeBuild"],
+        ("CodePipeline", _) => &["AWS CodePipeline"],
+        ("MSK", _) => &["Amazon Managed Streaming for Apache Kafka"],
+        ("AmazonMQ", _) => &["Amazon MQ"],
+        ("Neptune", _) => &["Amazon Neptune"],
+        ("DocDB", _) => &["Amazon DocumentDB (with MongoDB compatibility)"],
+        ("FSx", _) => &["Amazon FSx"],
+        ("Transfer", _) => &["AWS Transfer Family"],
+        ("WorkSpaces", _) => &["Amazon WorkSpaces"],
+        ("AppRunner", _) => &["AWS App Runner"],
+        _ => &[],
+    }
+}
+
+/// Cost Explorer REGION values for an Explorer region code
+///
+/// Global services are billed under "global" or "NoRegion".
+pub fn cost_regions_for_region(region: &str) -> Vec<&str> {
+    if region.eq_ignore_ascii_case("global") {
+        vec!["global", "NoRegion"]
+    } else {
+        vec![region]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_periods() {
+        let mtd = CostPeriod::month_to_date(date(2024, 3, 15)).unwrap();
+        assert_eq!(mtd.start_str(), "2024-03-01");
+        assert_eq!(mtd.end_str(), "2024-03-15");
+        assert!(CostPeriod::month_to_date(date(2024, 3, 1)).is_none());
+
+        let rest = CostPeriod::rest_of_month(date(2024, 12, 31)).unwrap();
+        assert_eq!(rest.start_str(), "2024-12-31");
+        assert_eq!(rest.end_str(), "2025-01-01");
+    }
+
+    #[test]
+    fn test_result_totals_and_forecast_shares() {
+        let mut result = CostDataResult::new(
+            "123456789012",
+            vec![CostGroupBy::Service],
+            CostPeriod::month_to_date(date(2024, 3, 15)),
+            "USD",
+            vec![
+                CostGroup {
+                    keys: vec!["AWS Lambda".to_string()],
+                    amount: 25.0,
+                    forecast: None,
+                },
+                CostGroup {
+                    keys: vec!["Amazon Simple Storage Service".to_string()],
+                    amount: 75.0,
+                    forecast: None,
+                },
+            ],
+        );
+        assert_eq!(result.total, 100.0);
+        assert_eq!(result.groups[0].keys[0], "Amazon Simple Storage Service");
+
+        result.apply_forecast(100.0);
+        assert_eq!(result.forecast_total, Some(200.0));
+        assert_eq!(result.groups[0].forecast, Some(150.0));
+        assert_eq!(result.groups[1].forecast, Some(50.0));
+    }
+
+    #[test]
+    fn test_format_cost() {
+        assert_eq!(format_cost(1234.567, "USD"), "$1,234.57");
+        assert_eq!(format_cost(0.004, "USD"), "$0.00");
+        assert_eq!(format_cost(-5.5, "USD"), "-$5.50");
+        assert_eq!(format_cost(1000000.0, "EUR"), "1,000,000.00 EUR");
+    }
+
+    #[test]
+    fn test_cost_services_for_resource_type() {
+        assert_eq!(
+            cost_services_for_resource_type("AWS::EC2::Instance"),
+            &["Amazon Elastic Compute Cloud - Compute"]
+        );
+        assert_eq!(
+            cost_services_for_resource_type("AWS::EC2::Volume"),
+            &["EC2 - Other"]
+        );
+        assert_eq!(
+            cost_services_for_resource_type("AWS::Lambda::Function"),
+            &["AWS Lambda"]
+        );
+        assert!(cost_services_for_resource_type("AWS::IAM::Role").is_empty());
+        assert_eq!(
+            cost_regions_for_region("Global"),
+            vec!["global", "NoRegion"]
+        );
+    }
+}
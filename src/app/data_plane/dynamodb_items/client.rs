@@ -0,0 +1,308 @@
+//! DynamoDB Items Client Wrapper
+//!
+//! Provides a simplified interface to DynamoDB Query and Scan with credential
+//! management. Reads follow pagination until the end of the table or one of
+//! the item, size, or page caps is reached.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_dynamodb as dynamodb;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnConsumedCapacity};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::debug;
+
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{
+    expression_values, item_to_json, json_to_item, resume_key, ItemReadOptions, ItemReadResult,
+    TruncatedBy, MAX_PAGES,
+};
+
+type Item = HashMap<String, AttributeValue>;
+
+/// Which read API to call
+#[derive(Clone, Copy)]
+enum ReadOperation<'a> {
+    Query { key_condition_expression: &'a str },
+    Scan,
+}
+
+/// One page of a Query or Scan response
+struct ReadPage {
+    items: Vec<Item>,
+    scanned_count: i32,
+    last_evaluated_key: Option<Item>,
+    capacity_units: Option<f64>,
+}
+
+/// DynamoDB items client wrapper
+#[derive(Clone)]
+pub struct DynamoDbItemsClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl DynamoDbItemsClient {
+    /// Create a new DynamoDB items client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<dynamodb::Client> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        Ok(dynamodb::Client::new(&aws_config))
+    }
+
+    /// Query a table or index by key condition
+    pub async fn query_items(
+        &self,
+        account_id: &str,
+        region: &str,
+        table_name: &str,
+        key_condition_expression: &str,
+        options: &ItemReadOptions,
+    ) -> Result<ItemReadResult> {
+        self.read_items(
+            account_id,
+            region,
+            table_name,
+            ReadOperation::Query {
+                key_condition_expression,
+            },
+            options,
+        )
+        .await
+    }
+
+    /// Scan a table or index
+    pub async fn scan_items(
+        &self,
+        account_id: &str,
+        region: &str,
+        table_name: &str,
+        options: &ItemReadOptions,
+    ) -> Result<ItemReadResult> {
+        self.read_items(account_id, region, table_name, ReadOperation::Scan, options)
+            .await
+    }
+
+    async fn read_items(
+        &self,
+        account_id: &str,
+        region: &str,
+        table_name: &str,
+        operation: ReadOperation<'_>,
+        options: &ItemReadOptions,
+    ) -> Result<ItemReadResult> {
+        let client = self.create_client(account_id, region).await?;
+
+        let names = (!options.expression_attribute_names.is_empty())
+            .then(|| options.expression_attribute_names.clone());
+        let values = if options.expression_attribute_values.is_empty() {
+            None
+        } else {
+            Some(expression_values(&options.expression_attribute_values)?)
+        };
+
+        let mut start_key = options.exclusive_start_key.as_ref().map(json_to_item);
+        let mut items: Vec<Value> = Vec::new();
+        let mut total_bytes = 0;
+        let mut scanned_count = 0;
+        let mut consumed_capacity: Option<f64> = None;
+        let mut pages = 0;
+
+        let (truncated_by, last_evaluated_key) = loop {
+            // Without a filter every item read is returned, so read no more than needed
+            let limit = options
+                .filter_expression
+                .is_none()
+                .then(|| (options.max_items - items.len()).min(i32::MAX as usize) as i32);
+
+            let page_start_key = start_key.clone();
+            let page = match operation {
+                ReadOperation::Query {
+                    key_condition_expression,
+                } => {
+                    let response = client
+                        .query()
+                        .table_name(table_name)
+                        .key_condition_expression(key_condition_expression)
+                        .set_index_name(options.index_name.clone())
+                        .set_filter_expression(options.filter_expression.clone())
+                        .set_projection_expression(options.projection_expression.clone())
+                        .set_expression_attribute_names(names.clone())
+                        .set_expression_attribute_values(values.clone())
+                        .set_exclusive_start_key(start_key.take())
+                        .set_limit(limit)
+                        .consistent_read(options.consistent_read)
+                        .scan_index_forward(options.scan_index_forward)
+                        .return_consumed_capacity(ReturnConsumedCapacity::Total)
+                        .send()
+                        .await
+                        .with_context(|| format!("Failed to query table {}", table_name))?;
+                    ReadPage {
+                        items: response.items().to_vec(),
+                        scanned_count: response.scanned_count(),
+                        last_evaluated_key: response.last_evaluated_key().cloned(),
+                        capacity_units: response
+                            .consumed_capacity()
+                            .and_then(|c| c.capacity_units()),
+                    }
+                }
+                ReadOperation::Scan => {
+                    let response = client
+                        .scan()
+                        .table_name(table_name)
+                        .set_index_name(options.index_name.clone())
+                        .set_filter_expression(options.filter_expression.clone())
+                        .set_projection_expression(options.projection_expression.clone())
+                        .set_expression_attribute_names(names.clone())
+                        .set_expression_attribute_values(values.clone())
+                        .set_exclusive_start_key(start_key.take())
+                        .set_limit(limit)
+                        .consistent_read(options.consistent_read)
+                        .return_consumed_capacity(ReturnConsumedCapacity::Total)
+                        .send()
+                        .await
+                        .with_context(|| format!("Failed to scan table {}", table_name))?;
+                    ReadPage {
+                        items: response.items().to_vec(),
+                        scanned_count: response.scanned_count(),
+                        last_evaluated_key: response.last_evaluated_key().cloned(),
+                        capacity_units: response
+                            .consumed_capacity()
+                            .and_then(|c| c.capacity_units()),
+                    }
+                }
+            };
+
+            pages += 1;
+            scanned_count += page.scanned_count.max(0) as usize;
+            if let Some(units) = page.capacity_units {
+                *consumed_capacity.get_or_insert(0.0) += units;
+            }
+
+            // Take items until a cap is hit; remember where the page was cut
+            let mut cut = None;
+            for (index, item) in page.items.iter().enumerate() {
+                if items.len() >= options.max_items {
+                    cut = Some((index, TruncatedBy::MaxItems));
+                    break;
+                }
+                let json = Value::Object(item_to_json(item));
+                let size = serde_json::to_vec(&json).map(|b| b.len()).unwrap_or(0);
+                if total_bytes + size > options.max_bytes {
+                    cut = Some((index, TruncatedBy::MaxBytes));
+                    break;
+                }
+                total_bytes += size;
+                items.push(json);
+            }
+
+            // DynamoDB's LastEvaluatedKey would skip the rest of a cut page,
+            // so resume from the last item returned instead
+            if let Some((index, reason)) = cut {
+                let key = if index == 0 {
+                    page_start_key
+                } else {
+                    let key_attributes = self
+                        .key_attributes(&client, table_name, options.index_name.as_deref())
+                        .await?;
+                    resume_key(&page.items[index - 1], &key_attributes).map(|k| json_to_item(&k))
+                };
+                break (Some(reason), key);
+            }
+
+            match page.last_evaluated_key {
+                None => break (None, None),
+                Some(key) if items.len() >= options.max_items => {
+                    break (Some(TruncatedBy::MaxItems), Some(key))
+                }
+                Some(key) if pages >= MAX_PAGES => break (Some(TruncatedBy::MaxPages), Some(key)),
+                Some(key) => start_key = Some(key),
+            }
+        };
+
+        debug!(
+            "Read {} items ({} bytes, {} scanned) from {} in {} pages",
+            items.len(),
+            total_bytes,
+            scanned_count,
+            table_name,
+            pages
+        );
+
+        Ok(ItemReadResult {
+            table_name: table_name.to_string(),
+            index_name: options.index_name.clone(),
+            items,
+            scanned_count,
+            total_bytes,
+            last_evaluated_key: last_evaluated_key.as_ref().map(item_to_json),
+            truncated_by,
+            consumed_capacity,
+        })
+    }
+
+    /// Key attribute names of a table, plus the index keys when reading an index
+    ///
+    /// These are the attributes DynamoDB puts in LastEvaluatedKey.
+    async fn key_attributes(
+        &self,
+        client: &dynamodb::Client,
+        table_name: &str,
+        index_name: Option<&str>,
+    ) -> Result<Vec<String>> {
+        let response = client
+            .describe_table()
+            .table_name(table_name)
+            .send()
+            .await
+            .with_context(|| format!("Failed to describe table {}", table_name))?;
+
+        let Some(table) = response.table() else {
+            anyhow::bail!("Table {} not found", table_name);
+        };
+
+        let mut attributes: Vec<String> = table
+            .key_schema()
+            .iter()
+            .map(|k| k.attribute_name().to_string())
+            .collect();
+
+        if let Some(index_name) = index_name {
+            let global = table
+                .global_secondary_indexes()
+                .iter()
+                .find(|i| i.index_name() == Some(index_name))
+                .map(|i| i.key_schema());
+            let local = table
+                .local_secondary_indexes()
+                .iter()
+                .find(|i| i.index_name() == Some(index_name))
+                .map(|i| i.key_schema());
+
+            for key in global.or(local).unwrap_or_default() {
+                if !attributes.iter().any(|a| a == key.attribute_name()) {
+                    attributes.push(key.attribute_name().to_string());
+                }
+            }
+        }
+
+        Ok(attributes)
+    }
+}
@@ -0,0 +1,57 @@
+//! DynamoDB Items Integration Module
+//!
+//! Provides read access to the items inside DynamoDB tables within the
+//! application, complementing the table configuration from the Resource
+//! Explorer.
+//!
+//! ## Features
+//!
+//! - Query a table or secondary index by key condition
+//! - Scan a table or index, with filter and projection expressions
+//! - Item count, JSON size, and page caps so a read never pulls a whole table
+//! - Items converted to plain JSON, with a resumable `last_evaluated_key`
+//! - Integration with agents (`queryDynamoTable()`, `scanDynamoTable()`)
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::dynamodb_items::{DynamoDbItemsClient, ItemReadOptions};
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = DynamoDbItemsClient::new(credential_coordinator);
+//!
+//! let result = client.query_items(
+//!     "123456789012",
+//!     "us-east-1",
+//!     "orders",
+//!     "pk = :pk",
+//!     &ItemReadOptions::new()
+//!         .with_attribute_value(":pk", serde_json::json!("customer#42"))
+//!         .with_max_items(50),
+//! ).await?;
+//!
+//! for item in &result.items {
+//!     println!("{}", item);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `dynamodb:Query`, `dynamodb:Scan`, and `dynamodb:DescribeTable`
+//! on the table and its indexes (plus `kms:Decrypt` for customer managed keys).
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::DynamoDbItemsClient;
+pub use types::{
+    attribute_to_json, item_to_json, json_to_attribute, json_to_item, ItemReadOptions,
+    ItemReadResult, TruncatedBy, MAX_BYTES, MAX_ITEMS, MAX_PAGES,
+};
@@ -0,0 +1,361 @@
+//! DynamoDB Item Data Types
+//!
+//! Options and results for reading table items, the caps applied to item
+//! reads, and conversion between DynamoDB attribute values and plain JSON.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use aws_sdk_dynamodb::types::AttributeValue;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Default number of items returned by one read
+pub const DEFAULT_MAX_ITEMS: usize = 100;
+
+/// Hard limit on items returned by one read
+pub const MAX_ITEMS: usize = 5000;
+
+/// Default limit on the JSON size of returned items
+pub const DEFAULT_MAX_BYTES: usize = 1024 * 1024;
+
+/// Hard limit on the JSON size of returned items
+pub const MAX_BYTES: usize = 16 * 1024 * 1024;
+
+/// Most pages read by one call (each page reads up to 1 MB from the table)
+pub const MAX_PAGES: usize = 50;
+
+/// Options shared by Query and Scan
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemReadOptions {
+    /// Read a global or local secondary index instead of the table
+    pub index_name: Option<String>,
+    /// Filter applied after items are read (does not reduce consumed capacity)
+    pub filter_expression: Option<String>,
+    /// Attributes to return
+    pub projection_expression: Option<String>,
+    /// Placeholders for attribute names (`#name` -> `name`)
+    pub expression_attribute_names: HashMap<String, String>,
+    /// Placeholders for values (`:value` -> plain JSON value)
+    pub expression_attribute_values: HashMap<String, Value>,
+    /// Resume from a previous `last_evaluated_key`
+    pub exclusive_start_key: Option<Map<String, Value>>,
+    /// Strongly consistent read (not supported on global secondary indexes)
+    pub consistent_read: bool,
+    /// Query sort order, ascending by sort key (ignored by Scan)
+    pub scan_index_forward: bool,
+    /// Maximum items to return (capped at MAX_ITEMS)
+    pub max_items: usize,
+    /// Maximum JSON size of returned items in bytes (capped at MAX_BYTES)
+    pub max_bytes: usize,
+}
+
+impl Default for ItemReadOptions {
+    fn default() -> Self {
+        Self {
+            index_name: None,
+            filter_expression: None,
+            projection_expression: None,
+            expression_attribute_names: HashMap::new(),
+            expression_attribute_values: HashMap::new(),
+            exclusive_start_key: None,
+            consistent_read: false,
+            scan_index_forward: true,
+            max_items: DEFAULT_MAX_ITEMS,
+            max_bytes: DEFAULT_MAX_BYTES,
+        }
+    }
+}
+
+impl ItemReadOptions {
+    /// Create default read options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read a secondary index
+    pub fn with_index_name(mut self, index_name: impl Into<String>) -> Self {
+        self.index_name = Some(index_name.into());
+        self
+    }
+
+    /// Set the filter expression
+    pub fn with_filter_expression(mut self, expression: impl Into<String>) -> Self {
+        self.filter_expression = Some(expression.into());
+        self
+    }
+
+    /// Set the projection expression
+    pub fn with_projection_expression(mut self, expression: impl Into<String>) -> Self {
+        self.projection_expression = Some(expression.into());
+        self
+    }
+
+    /// Add an attribute name placeholder
+    pub fn with_attribute_name(
+        mut self,
+        placeholder: impl Into<String>,
+        name: impl Into<String>,
+    ) -> Self {
+        self.expression_attribute_names
+            .insert(placeholder.into(), name.into());
+        self
+    }
+
+    /// Add a value placeholder
+    pub fn with_attribute_value(mut self, placeholder: impl Into<String>, value: Value) -> Self {
+        self.expression_attribute_values
+            .insert(placeholder.into(), value);
+        self
+    }
+
+    /// Resume from a previous read
+    pub fn with_exclusive_start_key(mut self, key: Map<String, Value>) -> Self {
+        self.exclusive_start_key = Some(key);
+        self
+    }
+
+    /// Use strongly consistent reads
+    pub fn with_consistent_read(mut self, consistent_read: bool) -> Self {
+        self.consistent_read = consistent_read;
+        self
+    }
+
+    /// Set the Query sort order
+    pub fn with_scan_index_forward(mut self, forward: bool) -> Self {
+        self.scan_index_forward = forward;
+        self
+    }
+
+    /// Set the maximum number of items (capped at MAX_ITEMS)
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = max_items.clamp(1, MAX_ITEMS);
+        self
+    }
+
+    /// Set the maximum JSON size of items (capped at MAX_BYTES)
+    pub fn with_max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes.clamp(1, MAX_BYTES);
+        self
+    }
+}
+
+/// Which cap stopped a read before the end of the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TruncatedBy {
+    /// `max_items` items were returned
+    MaxItems,
+    /// The next item would have exceeded `max_bytes`
+    MaxBytes,
+    /// MAX_PAGES pages were read
+    MaxPages,
+}
+
+/// Result of a Query or Scan
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemReadResult {
+    pub table_name: String,
+    pub index_name: Option<String>,
+    /// Items as plain JSON objects
+    pub items: Vec<Value>,
+    /// Items read from the table before the filter expression
+    pub scanned_count: usize,
+    /// JSON size of the returned items in bytes
+    pub total_bytes: usize,
+    /// Pass as the start key to continue; None when the read reached the end
+    pub last_evaluated_key: Option<Map<String, Value>>,
+    /// Set when a cap stopped the read early
+    pub truncated_by: Option<TruncatedBy>,
+    /// Read capacity units consumed
+    pub consumed_capacity: Option<f64>,
+}
+
+/// Convert a DynamoDB attribute value to plain JSON
+///
+/// Numbers become JSON numbers when they fit (otherwise strings), binary
+/// values become base64 strings, and sets become arrays.
+pub fn attribute_to_json(value: &AttributeValue) -> Value {
+    match value {
+        AttributeValue::S(s) => Value::String(s.clone()),
+        AttributeValue::N(n) => number_to_json(n),
+        AttributeValue::Bool(b) => Value::Bool(*b),
+        AttributeValue::Null(_) => Value::Null,
+        AttributeValue::B(blob) => Value::String(aws_smithy_types::base64::encode(blob.as_ref())),
+        AttributeValue::Ss(values) => values.iter().cloned().map(Value::String).collect(),
+        AttributeValue::Ns(values) => values.iter().map(|n| number_to_json(n)).collect(),
+        AttributeValue::Bs(values) => values
+            .iter()
+            .map(|blob| Value::String(aws_smithy_types::base64::encode(blob.as_ref())))
+            .collect(),
+        AttributeValue::L(values) => values.iter().map(attribute_to_json).collect(),
+        AttributeValue::M(map) => Value::Object(item_to_json(map)),
+        _ => Value::Null,
+    }
+}
+
+/// Convert a DynamoDB item (or key) to a JSON object
+pub fn item_to_json(item: &HashMap<String, AttributeValue>) -> Map<String, Value> {
+    item.iter()
+        .map(|(name, value)| (name.clone(), attribute_to_json(value)))
+        .collect()
+}
+
+/// Convert plain JSON to a DynamoDB attribute value
+///
+/// Strings become S, numbers N, booleans BOOL, null NULL, arrays L and
+/// objects M. Sets and binary values cannot be expressed this way.
+pub fn json_to_attribute(value: &Value) -> AttributeValue {
+    match value {
+        Value::String(s) => AttributeValue::S(s.clone()),
+        Value::Number(n) => AttributeValue::N(n.to_string()),
+        Value::Bool(b) => AttributeValue::Bool(*b),
+        Value::Null => AttributeValue::Null(true),
+        Value::Array(values) => AttributeValue::L(values.iter().map(json_to_attribute).collect()),
+        Value::Object(map) => AttributeValue::M(json_to_item(map)),
+    }
+}
+
+/// Convert a JSON object to a DynamoDB item (or key)
+pub fn json_to_item(map: &Map<String, Value>) -> HashMap<String, AttributeValue> {
+    map.iter()
+        .map(|(name, value)| (name.clone(), json_to_attribute(value)))
+        .collect()
+}
+
+/// Build expression attribute values, checking placeholders start with ':'
+pub fn expression_values(
+    values: &HashMap<String, Value>,
+) -> Result<HashMap<String, AttributeValue>> {
+    values
+        .iter()
+        .map(|(placeholder, value)| {
+            if !placeholder.starts_with(':') {
+                return Err(anyhow!(
+                    "Expression attribute value '{}' must start with ':'",
+                    placeholder
+                ));
+            }
+            Ok((placeholder.clone(), json_to_attribute(value)))
+        })
+        .collect()
+}
+
+/// Build a start key from the key attributes of the last returned item
+///
+/// Used when a cap stops a read in the middle of a page, where DynamoDB's own
+/// LastEvaluatedKey would skip the rest of the page. Returns None if the item
+/// lacks a key attribute (e.g. projected away).
+pub fn resume_key(
+    item: &HashMap<String, AttributeValue>,
+    key_attributes: &[String],
+) -> Option<Map<String, Value>> {
+    key_attributes
+        .iter()
+        .map(|name| {
+            item.get(name)
+                .map(|value| (name.clone(), attribute_to_json(value)))
+        })
+        .collect()
+}
+
+/// Convert a DynamoDB number, keeping it as a string if JSON would lose precision
+fn number_to_json(n: &str) -> Value {
+    if let Ok(i) = n.parse::<i64>() {
+        return Value::from(i);
+    }
+    if let Ok(u) = n.parse::<u64>() {
+        return Value::from(u);
+    }
+    let is_integer = !n.contains(['.', 'e', 'E']);
+    match n.parse::<f64>() {
+        Ok(f) if f.is_finite() && !is_integer => Value::from(f),
+        _ => Value::String(n.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_dynamodb::primitives::Blob;
+    use serde_json::json;
+
+    #[test]
+    fn test_read_options_caps() {
+        let options = ItemReadOptions::new()
+            .with_max_items(1_000_000)
+            .with_max_bytes(0);
+        assert_eq!(options.max_items, MAX_ITEMS);
+        assert_eq!(options.max_bytes, 1);
+        assert!(options.scan_index_forward);
+    }
+
+    #[test]
+    fn test_attribute_to_json() {
+        let mut item = HashMap::new();
+        item.insert("pk".to_string(), AttributeValue::S("order#1".to_string()));
+        item.insert("qty".to_string(), AttributeValue::N("3".to_string()));
+        item.insert("price".to_string(), AttributeValue::N("9.5".to_string()));
+        item.insert(
+            "big".to_string(),
+            AttributeValue::N("123456789012345678901234567890".to_string()),
+        );
+        item.insert(
+            "tags".to_string(),
+            AttributeValue::Ss(vec!["a".to_string(), "b".to_string()]),
+        );
+        item.insert(
+            "raw".to_string(),
+            AttributeValue::B(Blob::new(b"hi".to_vec())),
+        );
+        item.insert("gone".to_string(), AttributeValue::Null(true));
+
+        let json = Value::Object(item_to_json(&item));
+        assert_eq!(json["pk"], "order#1");
+        assert_eq!(json["qty"], 3);
+        assert_eq!(json["price"], 9.5);
+        assert_eq!(json["big"], "123456789012345678901234567890");
+        assert_eq!(json["tags"], json!(["a", "b"]));
+        assert_eq!(json["raw"], "aGk=");
+        assert!(json["gone"].is_null());
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let value = json!({ "pk": "user#1", "sk": 42, "nested": { "ok": true, "list": [1, "x"] } });
+        let item = json_to_item(value.as_object().unwrap());
+        assert_eq!(item["sk"], AttributeValue::N("42".to_string()));
+        assert_eq!(Value::Object(item_to_json(&item)), value);
+    }
+
+    #[test]
+    fn test_expression_values() {
+        let mut values = HashMap::new();
+        values.insert(":status".to_string(), json!("FAILED"));
+        assert_eq!(
+            expression_values(&values).unwrap()[":status"],
+            AttributeValue::S("FAILED".to_string())
+        );
+
+        values.insert("status".to_string(), json!("x"));
+        assert!(expression_values(&values).is_err());
+    }
+
+    #[test]
+    fn test_resume_key() {
+        let mut item = HashMap::new();
+        item.insert("pk".to_string(), AttributeValue::S("a".to_string()));
+        item.insert("sk".to_string(), AttributeValue::N("1".to_string()));
+        item.insert("data".to_string(), AttributeValue::S("x".to_string()));
+
+        let keys = vec!["pk".to_string(), "sk".to_string()];
+        let key = resume_key(&item, &keys).unwrap();
+        assert_eq!(Value::Object(key), json!({ "pk": "a", "sk": 1 }));
+
+        let missing = vec!["pk".to_string(), "gsi_pk".to_string()];
+        assert!(resume_key(&item, &missing).is_none());
+    }
+}
@@ -0,0 +1,191 @@
+//! EventBridge Events Client Wrapper
+//!
+//! Lists the rules of a bus with their targets, checks test events against rule
+//! patterns, and sends test events, with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_eventbridge as eventbridge;
+use std::sync::Arc;
+
+use crate::app::capabilities::ensure_writable;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{EventRule, PutEventResult, RuleTarget, TestEvent};
+
+/// EventBridge events client wrapper
+#[derive(Clone)]
+pub struct EventBridgeEventsClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl EventBridgeEventsClient {
+    /// Create a new EventBridge events client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<eventbridge::Client> {
+        let config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+        Ok(eventbridge::Client::new(&config))
+    }
+
+    /// All rules on an event bus, each with its targets
+    pub async fn list_rules(
+        &self,
+        account_id: &str,
+        region: &str,
+        event_bus_name: &str,
+    ) -> Result<Vec<EventRule>> {
+        let client = self.create_client(account_id, region).await?;
+
+        let mut rules = Vec::new();
+        let mut next_token: Option<String> = None;
+        loop {
+            let response = client
+                .list_rules()
+                .event_bus_name(event_bus_name)
+                .set_next_token(next_token.take())
+                .send()
+                .await
+                .with_context(|| format!("Failed to list rules on event bus {}", event_bus_name))?;
+
+            for rule in response.rules() {
+                let name = rule.name().unwrap_or_default().to_string();
+                let targets = list_targets(&client, &name, event_bus_name).await?;
+                rules.push(EventRule {
+                    arn: rule.arn().map(String::from),
+                    state: rule.state().map(|state| state.as_str().to_string()),
+                    description: rule.description().map(String::from),
+                    event_pattern: rule.event_pattern().map(String::from),
+                    schedule_expression: rule.schedule_expression().map(String::from),
+                    managed_by: rule.managed_by().map(String::from),
+                    name,
+                    targets,
+                });
+            }
+
+            match response.next_token() {
+                Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+                _ => break,
+            }
+        }
+
+        rules.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(rules)
+    }
+
+    /// Whether a rule's event pattern matches the test event
+    pub async fn test_event_pattern(
+        &self,
+        account_id: &str,
+        region: &str,
+        event_pattern: &str,
+        event: &TestEvent,
+    ) -> Result<bool> {
+        let envelope = event
+            .to_envelope(account_id, region)
+            .map_err(anyhow::Error::msg)?;
+        let client = self.create_client(account_id, region).await?;
+
+        let response = client
+            .test_event_pattern()
+            .event_pattern(event_pattern)
+            .event(envelope.to_string())
+            .send()
+            .await
+            .context("Failed to test event pattern")?;
+
+        Ok(response.result())
+    }
+
+    /// Send a test event to a bus; rules matching it invoke their targets
+    pub async fn put_test_event(
+        &self,
+        account_id: &str,
+        region: &str,
+        event_bus_name: &str,
+        event: &TestEvent,
+    ) -> Result<PutEventResult> {
+        ensure_writable("Send test event")?;
+        event.validate().map_err(anyhow::Error::msg)?;
+        let client = self.create_client(account_id, region).await?;
+
+        let entry = eventbridge::types::PutEventsRequestEntry::builder()
+            .event_bus_name(event_bus_name)
+            .source(&event.source)
+            .detail_type(&event.detail_type)
+            .detail(&event.detail)
+            .set_resources((!event.resources.is_empty()).then(|| event.resources.clone()))
+            .build();
+
+        let response = client
+            .put_events()
+            .entries(entry)
+            .send()
+            .await
+            .with_context(|| format!("Failed to send test event to {}", event_bus_name))?;
+
+        let result = response
+            .entries()
+            .first()
+            .context("PutEvents returned no result entry")?;
+        Ok(PutEventResult {
+            event_id: result.event_id().map(String::from),
+            error_code: result.error_code().map(String::from),
+            error_message: result.error_message().map(String::from),
+        })
+    }
+}
+
+/// All targets of a rule
+async fn list_targets(
+    client: &eventbridge::Client,
+    rule_name: &str,
+    event_bus_name: &str,
+) -> Result<Vec<RuleTarget>> {
+    let mut targets = Vec::new();
+    let mut next_token: Option<String> = None;
+    loop {
+        let response = client
+            .list_targets_by_rule()
+            .rule(rule_name)
+            .event_bus_name(event_bus_name)
+            .set_next_token(next_token.take())
+            .send()
+            .await
+            .with_context(|| format!("Failed to list targets of rule {}", rule_name))?;
+
+        targets.extend(response.targets().iter().map(|target| {
+            RuleTarget {
+                id: target.id().to_string(),
+                arn: target.arn().to_string(),
+                role_arn: target.role_arn().map(String::from),
+                input: target.input().map(String::from),
+                input_path: target.input_path().map(String::from),
+                dead_letter_arn: target
+                    .dead_letter_config()
+                    .and_then(|config| config.arn())
+                    .map(String::from),
+            }
+        }));
+
+        match response.next_token() {
+            Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+            _ => break,
+        }
+    }
+    Ok(targets)
+}
@@ -0,0 +1,48 @@
+//! EventBridge Events Integration Module
+//!
+//! Backs the EventBridge browser: the rules of an event bus with their event
+//! patterns and targets, and test events checked against those patterns or
+//! sent to the bus.
+//!
+//! ## Features
+//!
+//! - List every rule on a bus with its targets (Lambda, SQS, Step Functions, ...)
+//! - Check a test event against a rule pattern with TestEventPattern (no side effects)
+//! - Send a test event with PutEvents; refused in the read-only build
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::eventbridge_events::{EventBridgeEventsClient, TestEvent};
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = EventBridgeEventsClient::new(credential_coordinator);
+//! let event = TestEvent::default();
+//!
+//! for rule in client.list_rules("123456789012", "us-east-1", "default").await? {
+//!     if let Some(pattern) = &rule.event_pattern {
+//!         let matched = client
+//!             .test_event_pattern("123456789012", "us-east-1", pattern, &event)
+//!             .await?;
+//!         println!("{}: {} ({} targets)", rule.name, matched, rule.targets.len());
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `events:ListRules`, `events:ListTargetsByRule` and
+//! `events:TestEventPattern`; sending needs `events:PutEvents` on the bus.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::EventBridgeEventsClient;
+pub use types::{EventRule, PutEventResult, RuleTarget, TestEvent, MAX_EVENT_BYTES};
@@ -0,0 +1,220 @@
+//! EventBridge Events Data Types
+//!
+//! Rules with their targets as the EventBridge browser shows them, and the
+//! test events sent to a bus.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Largest PutEvents entry EventBridge accepts
+pub const MAX_EVENT_BYTES: usize = 256 * 1024;
+
+/// Sources starting with this are reserved for AWS services and rejected by PutEvents
+const RESERVED_SOURCE_PREFIX: &str = "aws.";
+
+/// Where a rule sends matching events
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleTarget {
+    pub id: String,
+    pub arn: String,
+    /// Role EventBridge assumes to invoke the target
+    pub role_arn: Option<String>,
+    /// Constant JSON sent instead of the event
+    pub input: Option<String>,
+    /// JSONPath selecting the part of the event to send
+    pub input_path: Option<String>,
+    /// Queue receiving events the target could not take
+    pub dead_letter_arn: Option<String>,
+}
+
+/// An EventBridge rule and its targets
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventRule {
+    pub name: String,
+    pub arn: Option<String>,
+    /// ENABLED, DISABLED or ENABLED_WITH_ALL_CLOUDTRAIL_MANAGEMENT_EVENTS
+    pub state: Option<String>,
+    pub description: Option<String>,
+    /// Event pattern JSON; None for schedule rules
+    pub event_pattern: Option<String>,
+    pub schedule_expression: Option<String>,
+    /// Service that manages the rule, if not the account owner
+    pub managed_by: Option<String>,
+    pub targets: Vec<RuleTarget>,
+}
+
+impl EventRule {
+    pub fn is_enabled(&self) -> bool {
+        self.state
+            .as_deref()
+            .is_some_and(|state| state.starts_with("ENABLED"))
+    }
+
+    /// Event pattern pretty printed, or as stored if it isn't valid JSON
+    pub fn pretty_pattern(&self) -> Option<String> {
+        let pattern = self.event_pattern.as_deref()?;
+        Some(
+            serde_json::from_str::<Value>(pattern)
+                .ok()
+                .and_then(|value| serde_json::to_string_pretty(&value).ok())
+                .unwrap_or_else(|| pattern.to_string()),
+        )
+    }
+}
+
+/// A custom event to send to a bus or check against rule patterns
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TestEvent {
+    pub source: String,
+    pub detail_type: String,
+    /// Detail JSON object as typed by the user
+    pub detail: String,
+    /// ARNs of resources the event concerns
+    pub resources: Vec<String>,
+}
+
+impl Default for TestEvent {
+    fn default() -> Self {
+        Self {
+            source: "com.example.test".to_string(),
+            detail_type: "Test Event".to_string(),
+            detail: "{\n  \"message\": \"hello\"\n}".to_string(),
+            resources: Vec::new(),
+        }
+    }
+}
+
+impl TestEvent {
+    /// Check the event the way PutEvents would, so mistakes show before sending
+    pub fn validate(&self) -> Result<Value, String> {
+        if self.source.trim().is_empty() {
+            return Err("Source is required".to_string());
+        }
+        if self.source.starts_with(RESERVED_SOURCE_PREFIX) {
+            return Err(format!(
+                "Sources starting with \"{}\" are reserved for AWS services",
+                RESERVED_SOURCE_PREFIX
+            ));
+        }
+        if self.detail_type.trim().is_empty() {
+            return Err("Detail type is required".to_string());
+        }
+        let detail: Value = serde_json::from_str(&self.detail)
+            .map_err(|e| format!("Detail is not valid JSON: {}", e))?;
+        if !detail.is_object() {
+            return Err("Detail must be a JSON object".to_string());
+        }
+        let size = self.source.len()
+            + self.detail_type.len()
+            + self.detail.len()
+            + self.resources.iter().map(String::len).sum::<usize>();
+        if size > MAX_EVENT_BYTES {
+            return Err(format!(
+                "Event is {} bytes; EventBridge accepts at most {}",
+                size, MAX_EVENT_BYTES
+            ));
+        }
+        Ok(detail)
+    }
+
+    /// The event as EventBridge would deliver it, for TestEventPattern
+    pub fn to_envelope(&self, account_id: &str, region: &str) -> Result<Value, String> {
+        let detail = self.validate()?;
+        Ok(serde_json::json!({
+            "version": "0",
+            "id": "00000000-0000-0000-0000-000000000000",
+            "detail-type": self.detail_type,
+            "source": self.source,
+            "account": account_id,
+            "time": chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            "region": region,
+            "resources": self.resources,
+            "detail": detail,
+        }))
+    }
+}
+
+/// Outcome of sending one test event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PutEventResult {
+    pub event_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl PutEventResult {
+    pub fn is_success(&self) -> bool {
+        self.event_id.is_some() && self.error_code.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_rejects_what_put_events_would() {
+        assert!(TestEvent::default().validate().is_ok());
+
+        let reserved = TestEvent {
+            source: "aws.ec2".to_string(),
+            ..Default::default()
+        };
+        assert!(reserved.validate().unwrap_err().contains("reserved"));
+
+        let not_object = TestEvent {
+            detail: "[1, 2]".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            not_object.validate().unwrap_err(),
+            "Detail must be a JSON object"
+        );
+
+        let too_big = TestEvent {
+            detail: format!("{{\"blob\": \"{}\"}}", "x".repeat(MAX_EVENT_BYTES)),
+            ..Default::default()
+        };
+        assert!(too_big.validate().is_err());
+    }
+
+    #[test]
+    fn test_envelope_uses_event_field_names() {
+        let envelope = TestEvent::default()
+            .to_envelope("123456789012", "us-east-1")
+            .unwrap();
+        assert_eq!(envelope["detail-type"], "Test Event");
+        assert_eq!(envelope["source"], "com.example.test");
+        assert_eq!(envelope["account"], "123456789012");
+        assert_eq!(envelope["detail"]["message"], "hello");
+    }
+
+    #[test]
+    fn test_rule_pattern_and_state() {
+        let rule = EventRule {
+            name: "orders".to_string(),
+            state: Some("ENABLED".to_string()),
+            event_pattern: Some(r#"{"source":["com.shop"]}"#.to_string()),
+            ..Default::default()
+        };
+        assert!(rule.is_enabled());
+        assert_eq!(
+            rule.pretty_pattern().unwrap(),
+            "{\n  \"source\": [\n    \"com.shop\"\n  ]\n}"
+        );
+
+        let schedule = EventRule {
+            state: Some("DISABLED".to_string()),
+            schedule_expression: Some("rate(5 minutes)".to_string()),
+            ..Default::default()
+        };
+        assert!(!schedule.is_enabled());
+        assert!(schedule.pretty_pattern().is_none());
+    }
+}
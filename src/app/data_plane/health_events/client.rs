@@ -0,0 +1,214 @@
+//! AWS Health Client Wrapper
+//!
+//! Lists open and upcoming Health events of an account with their
+//! descriptions and affected entities, with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_health as health;
+use aws_sdk_health::error::ProvideErrorMetadata;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::app::aws_regions::active_partition;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{AccountHealth, AffectedEntity, EventCategory, EventStatus, HealthEvent};
+
+/// DescribeEventDetails and entity filters accept at most 10 event ARNs
+const EVENT_ARN_BATCH: usize = 10;
+
+/// AWS Health client wrapper
+#[derive(Clone)]
+pub struct HealthEventsClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl HealthEventsClient {
+    /// Create a new AWS Health client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str) -> Result<health::Client> {
+        // The Health API is served from the partition's global endpoint
+        let region = active_partition().global_region();
+        let config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+        Ok(health::Client::new(&config))
+    }
+
+    /// Open and upcoming events of an account with descriptions and affected entities
+    ///
+    /// Accounts without a support plan that includes the Health API are
+    /// returned with `subscription_required` set instead of an error.
+    pub async fn describe_open_events(&self, account_id: &str) -> Result<AccountHealth> {
+        let client = self.create_client(account_id).await?;
+        let mut account_health = AccountHealth {
+            account_id: account_id.to_string(),
+            ..Default::default()
+        };
+
+        let filter = health::types::EventFilter::builder()
+            .event_status_codes(health::types::EventStatusCode::Open)
+            .event_status_codes(health::types::EventStatusCode::Upcoming)
+            .build();
+        let mut next_token = None;
+        loop {
+            let response = match client
+                .describe_events()
+                .filter(filter.clone())
+                .max_results(100)
+                .set_next_token(next_token)
+                .send()
+                .await
+            {
+                Ok(response) => response,
+                Err(e) if e.code() == Some("SubscriptionRequiredException") => {
+                    account_health.subscription_required = true;
+                    return Ok(account_health);
+                }
+                Err(e) => {
+                    return Err(e).with_context(|| {
+                        format!("Failed to describe Health events for {}", account_id)
+                    })
+                }
+            };
+            account_health.events.extend(
+                response
+                    .events()
+                    .iter()
+                    .filter_map(|event| convert_event(event, account_id)),
+            );
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        let arns: Vec<String> = account_health
+            .events
+            .iter()
+            .map(|e| e.arn.clone())
+            .collect();
+        let descriptions = describe_details(&client, &arns).await?;
+        let mut entities = describe_entities(&client, &arns).await?;
+        for event in &mut account_health.events {
+            event.description = descriptions.get(&event.arn).cloned();
+            event.affected_entities = entities.remove(&event.arn).unwrap_or_default();
+        }
+
+        Ok(account_health)
+    }
+}
+
+/// Latest description of each event, by event ARN
+async fn describe_details(
+    client: &health::Client,
+    arns: &[String],
+) -> Result<HashMap<String, String>> {
+    let mut descriptions = HashMap::new();
+    for batch in arns.chunks(EVENT_ARN_BATCH) {
+        let response = client
+            .describe_event_details()
+            .set_event_arns(Some(batch.to_vec()))
+            .send()
+            .await
+            .context("Failed to describe Health event details")?;
+        for details in response.successful_set() {
+            let arn = details.event().and_then(|event| event.arn());
+            let text = details
+                .event_description()
+                .and_then(|description| description.latest_description());
+            if let (Some(arn), Some(text)) = (arn, text) {
+                descriptions.insert(arn.to_string(), text.to_string());
+            }
+        }
+    }
+    Ok(descriptions)
+}
+
+/// Entities affected by each event, by event ARN
+async fn describe_entities(
+    client: &health::Client,
+    arns: &[String],
+) -> Result<HashMap<String, Vec<AffectedEntity>>> {
+    let mut entities: HashMap<String, Vec<AffectedEntity>> = HashMap::new();
+    for batch in arns.chunks(EVENT_ARN_BATCH) {
+        let filter = health::types::EntityFilter::builder()
+            .set_event_arns(Some(batch.to_vec()))
+            .build()
+            .context("Failed to build Health entity filter")?;
+        let mut next_token = None;
+        loop {
+            let response = client
+                .describe_affected_entities()
+                .filter(filter.clone())
+                .set_next_token(next_token)
+                .send()
+                .await
+                .context("Failed to describe Health affected entities")?;
+            for entity in response.entities() {
+                let (Some(event_arn), Some(value)) = (entity.event_arn(), entity.entity_value())
+                else {
+                    continue;
+                };
+                // Events without specific resources list the account itself
+                if value == "UNKNOWN" || value.is_empty() {
+                    continue;
+                }
+                entities
+                    .entry(event_arn.to_string())
+                    .or_default()
+                    .push(AffectedEntity {
+                        entity_value: value.to_string(),
+                        status: entity.status_code().map(|s| s.as_str().to_string()),
+                        last_updated_time: entity.last_updated_time().and_then(to_chrono),
+                    });
+            }
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+    }
+    Ok(entities)
+}
+
+fn convert_event(event: &health::types::Event, account_id: &str) -> Option<HealthEvent> {
+    Some(HealthEvent {
+        arn: event.arn()?.to_string(),
+        account_id: account_id.to_string(),
+        service: event.service().unwrap_or_default().to_string(),
+        event_type_code: event.event_type_code().unwrap_or_default().to_string(),
+        category: EventCategory::from_api(
+            event
+                .event_type_category()
+                .map(|c| c.as_str())
+                .unwrap_or_default(),
+        ),
+        status: EventStatus::from_api(event.status_code().map(|s| s.as_str()).unwrap_or_default()),
+        region: event.region().map(String::from),
+        availability_zone: event.availability_zone().map(String::from),
+        start_time: event.start_time().and_then(to_chrono),
+        end_time: event.end_time().and_then(to_chrono),
+        last_updated_time: event.last_updated_time().and_then(to_chrono),
+        description: None,
+        affected_entities: Vec::new(),
+    })
+}
+
+fn to_chrono(time: &aws_smithy_types::DateTime) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp(time.secs(), time.subsec_nanos())
+}
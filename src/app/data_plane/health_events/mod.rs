@@ -0,0 +1,51 @@
+//! AWS Health Integration Module
+//!
+//! Backs the Health feed: open issues, scheduled changes, and account
+//! notifications from the AWS Health API (the Personal Health Dashboard) for
+//! each authenticated account.
+//!
+//! ## Features
+//!
+//! - Open and upcoming events per account, with the latest description
+//! - Affected entities (resource ARNs or IDs) for each event, ready to match
+//!   against resources loaded in the Explorer
+//! - Counts of issues and scheduled changes for the status bar badge
+//! - Accounts without a qualifying support plan reported, not failed
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::health_events::{HealthEventsClient, HealthSummary};
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = HealthEventsClient::new(credential_coordinator);
+//! let health = client.describe_open_events("123456789012").await?;
+//!
+//! let summary = HealthSummary::from_events(&health.events);
+//! println!("{} issues, {} scheduled changes", summary.open_issues, summary.scheduled_changes);
+//! for event in &health.events {
+//!     println!("{} {:?}: {} entities", event.title(), event.region, event.affected_entities.len());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! - A Business, Enterprise On-Ramp, or Enterprise support plan on each
+//!   account (the Health API is not available otherwise)
+//! - The role needs `health:DescribeEvents`, `health:DescribeEventDetails`,
+//!   and `health:DescribeAffectedEntities`
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::HealthEventsClient;
+pub use types::{
+    AccountHealth, AffectedEntity, EventCategory, EventStatus, HealthEvent, HealthSummary,
+};
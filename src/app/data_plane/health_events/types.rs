@@ -0,0 +1,218 @@
+//! AWS Health Data Types
+//!
+//! Open and upcoming Health events per account, the entities they affect, and
+//! the counts shown in the status bar badge.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Kind of Health event (`eventTypeCategory`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EventCategory {
+    Issue,
+    ScheduledChange,
+    AccountNotification,
+    Investigation,
+    Other,
+}
+
+impl EventCategory {
+    pub fn from_api(value: &str) -> Self {
+        match value {
+            "issue" => Self::Issue,
+            "scheduledChange" => Self::ScheduledChange,
+            "accountNotification" => Self::AccountNotification,
+            "investigation" => Self::Investigation,
+            _ => Self::Other,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Issue => "Issue",
+            Self::ScheduledChange => "Scheduled change",
+            Self::AccountNotification => "Notification",
+            Self::Investigation => "Investigation",
+            Self::Other => "Other",
+        }
+    }
+}
+
+/// Lifecycle of a Health event (`statusCode`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EventStatus {
+    Open,
+    Upcoming,
+    Closed,
+}
+
+impl EventStatus {
+    pub fn from_api(value: &str) -> Self {
+        match value {
+            "upcoming" => Self::Upcoming,
+            "closed" => Self::Closed,
+            _ => Self::Open,
+        }
+    }
+}
+
+/// A resource or other entity named by a Health event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AffectedEntity {
+    /// Usually a resource ARN or ID (e.g. an instance ID)
+    pub entity_value: String,
+    /// IMPAIRED, UNIMPAIRED, UNKNOWN, PENDING, or RESOLVED
+    pub status: Option<String>,
+    pub last_updated_time: Option<DateTime<Utc>>,
+}
+
+/// One Health event seen by an account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthEvent {
+    pub arn: String,
+    pub account_id: String,
+    /// Service code, e.g. "EC2"
+    pub service: String,
+    /// e.g. "AWS_EC2_INSTANCE_RETIREMENT_SCHEDULED"
+    pub event_type_code: String,
+    pub category: EventCategory,
+    pub status: EventStatus,
+    /// Region, or "global"
+    pub region: Option<String>,
+    pub availability_zone: Option<String>,
+    pub start_time: Option<DateTime<Utc>>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub last_updated_time: Option<DateTime<Utc>>,
+    /// Latest description text
+    pub description: Option<String>,
+    pub affected_entities: Vec<AffectedEntity>,
+}
+
+impl HealthEvent {
+    /// Whether the event counts toward the status bar badge
+    pub fn needs_attention(&self) -> bool {
+        self.status != EventStatus::Closed
+            && matches!(
+                self.category,
+                EventCategory::Issue | EventCategory::ScheduledChange
+            )
+    }
+
+    /// Event type as words, e.g. "Ec2 Instance Retirement Scheduled"
+    pub fn title(&self) -> String {
+        self.event_type_code
+            .trim_start_matches("AWS_")
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let lower = word.to_lowercase();
+                let mut chars = lower.chars();
+                chars
+                    .next()
+                    .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<String>>()
+            .join(" ")
+    }
+}
+
+/// Open and upcoming events of one account
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountHealth {
+    pub account_id: String,
+    pub events: Vec<HealthEvent>,
+    /// The Health API needs a Business, Enterprise On-Ramp, or Enterprise
+    /// support plan; accounts without one are skipped
+    pub subscription_required: bool,
+}
+
+/// Event counts across accounts
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HealthSummary {
+    pub open_issues: usize,
+    pub scheduled_changes: usize,
+    pub notifications: usize,
+}
+
+impl HealthSummary {
+    pub fn from_events<'a>(events: impl IntoIterator<Item = &'a HealthEvent>) -> Self {
+        let mut summary = Self::default();
+        for event in events {
+            if event.status == EventStatus::Closed {
+                continue;
+            }
+            match event.category {
+                EventCategory::Issue => summary.open_issues += 1,
+                EventCategory::ScheduledChange => summary.scheduled_changes += 1,
+                _ => summary.notifications += 1,
+            }
+        }
+        summary
+    }
+
+    /// Events shown on the badge
+    pub fn attention_count(&self) -> usize {
+        self.open_issues + self.scheduled_changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(category: &str, status: &str) -> HealthEvent {
+        HealthEvent {
+            arn: format!(
+                "arn:aws:health:us-east-1::event/EC2/{}/{}",
+                category, status
+            ),
+            account_id: "123456789012".to_string(),
+            service: "EC2".to_string(),
+            event_type_code: "AWS_EC2_INSTANCE_RETIREMENT_SCHEDULED".to_string(),
+            category: EventCategory::from_api(category),
+            status: EventStatus::from_api(status),
+            region: Some("us-east-1".to_string()),
+            availability_zone: None,
+            start_time: None,
+            end_time: None,
+            last_updated_time: None,
+            description: None,
+            affected_entities: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let events = [
+            event("issue", "open"),
+            event("issue", "closed"),
+            event("scheduledChange", "upcoming"),
+            event("accountNotification", "open"),
+        ];
+        let summary = HealthSummary::from_events(&events);
+        assert_eq!(summary.open_issues, 1);
+        assert_eq!(summary.scheduled_changes, 1);
+        assert_eq!(summary.notifications, 1);
+        assert_eq!(summary.attention_count(), 2);
+
+        assert!(events[0].needs_attention());
+        assert!(!events[1].needs_attention());
+        assert!(!events[3].needs_attention());
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(
+            event("scheduledChange", "upcoming").title(),
+            "Ec2 Instance Retirement Scheduled"
+        );
+    }
+}
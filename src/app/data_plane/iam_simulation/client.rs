@@ -0,0 +1,161 @@
+//! IAM Simulation Client Wrapper
+//!
+//! Provides caller identity (STS) and principal policy simulation (IAM) with
+//! credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_iam as iam;
+use aws_sdk_sts as sts;
+use std::sync::Arc;
+
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{
+    ActionEvaluation, CallerIdentity, MatchedStatement, SimulationOptions, SimulationResult,
+};
+
+/// IAM simulation client wrapper
+#[derive(Clone)]
+pub struct IamSimulationClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl IamSimulationClient {
+    /// Create a new IAM simulation client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_config(&self, account_id: &str, region: &str) -> Result<aws_config::SdkConfig> {
+        self.credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })
+    }
+
+    /// Identity of the credentials the application uses for an account
+    pub async fn get_caller_identity(
+        &self,
+        account_id: &str,
+        region: &str,
+    ) -> Result<CallerIdentity> {
+        let config = self.create_config(account_id, region).await?;
+        let client = sts::Client::new(&config);
+
+        let response =
+            client.get_caller_identity().send().await.with_context(|| {
+                format!("Failed to get caller identity for account {}", account_id)
+            })?;
+
+        Ok(CallerIdentity::new(
+            response.account().unwrap_or(account_id),
+            response.arn().unwrap_or_default(),
+            response.user_id().unwrap_or_default(),
+        ))
+    }
+
+    /// Simulate whether a principal's policies allow actions on resources
+    ///
+    /// Evaluates the identity policies attached to `policy_source_arn` (a user,
+    /// group, or role), plus any SCPs and permissions boundary, and optionally
+    /// a resource policy. All pages of results are collected.
+    pub async fn simulate_principal_policy(
+        &self,
+        account_id: &str,
+        region: &str,
+        policy_source_arn: &str,
+        actions: &[String],
+        options: &SimulationOptions,
+    ) -> Result<SimulationResult> {
+        let config = self.create_config(account_id, region).await?;
+        let client = iam::Client::new(&config);
+
+        let mut context_entries = Vec::with_capacity(options.context_entries.len());
+        for entry in &options.context_entries {
+            context_entries.push(
+                iam::types::ContextEntry::builder()
+                    .context_key_name(&entry.key)
+                    .set_context_key_values(Some(entry.values.clone()))
+                    .context_key_type(iam::types::ContextKeyTypeEnum::from(
+                        entry.key_type.as_str(),
+                    ))
+                    .build(),
+            );
+        }
+
+        let resource_arns =
+            (!options.resource_arns.is_empty()).then(|| options.resource_arns.clone());
+        let context_entries = (!context_entries.is_empty()).then_some(context_entries);
+        let boundary_policies = (!options.permissions_boundary_policies.is_empty())
+            .then(|| options.permissions_boundary_policies.clone());
+
+        let mut evaluations = Vec::new();
+        let mut marker: Option<String> = None;
+
+        loop {
+            let response = client
+                .simulate_principal_policy()
+                .policy_source_arn(policy_source_arn)
+                .set_action_names(Some(actions.to_vec()))
+                .set_resource_arns(resource_arns.clone())
+                .set_context_entries(context_entries.clone())
+                .set_caller_arn(options.caller_arn.clone())
+                .set_resource_policy(options.resource_policy.clone())
+                .set_resource_owner(options.resource_owner.clone())
+                .set_permissions_boundary_policy_input_list(boundary_policies.clone())
+                .set_marker(marker.take())
+                .send()
+                .await
+                .with_context(|| format!("Failed to simulate policies of {}", policy_source_arn))?;
+
+            evaluations.extend(response.evaluation_results().iter().map(convert_evaluation));
+
+            match response.marker() {
+                Some(next) if response.is_truncated() && !next.is_empty() => {
+                    marker = Some(next.to_string())
+                }
+                _ => break,
+            }
+        }
+
+        Ok(SimulationResult::new(policy_source_arn, evaluations))
+    }
+}
+
+/// Convert an SDK evaluation result
+fn convert_evaluation(result: &iam::types::EvaluationResult) -> ActionEvaluation {
+    let decision = result.eval_decision().as_str().to_string();
+
+    ActionEvaluation {
+        action: result.eval_action_name().to_string(),
+        resource: result.eval_resource_name().unwrap_or("*").to_string(),
+        allowed: decision == "allowed",
+        decision,
+        matched_statements: result
+            .matched_statements()
+            .iter()
+            .map(|statement| MatchedStatement {
+                source_policy_id: statement.source_policy_id().map(String::from),
+                source_policy_type: statement
+                    .source_policy_type()
+                    .map(|t| t.as_str().to_string()),
+            })
+            .collect(),
+        missing_context_values: result.missing_context_values().to_vec(),
+        allowed_by_organizations: result
+            .organizations_decision_detail()
+            .map(|d| d.allowed_by_organizations()),
+        allowed_by_permissions_boundary: result
+            .permissions_boundary_decision_detail()
+            .map(|d| d.allowed_by_permissions_boundary()),
+    }
+}
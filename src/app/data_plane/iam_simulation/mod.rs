@@ -0,0 +1,60 @@
+//! IAM Simulation Integration Module
+//!
+//! Answers "can principal X call action Y on resource Z" with the IAM policy
+//! simulator instead of reading policy documents by hand.
+//!
+//! ## Features
+//!
+//! - Caller identity for the credentials used per account (STS)
+//! - Principal policy simulation for users, groups, and roles, including SCPs,
+//!   permissions boundaries, context keys, and an optional resource policy
+//! - Helpers for role/user ARNs and assumed-role session ARNs
+//! - Integration with agents (`getCallerIdentity()`, `simulatePrincipalPolicy()`)
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::iam_simulation::{
+//!     role_arn, IamSimulationClient, SimulationOptions,
+//! };
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = IamSimulationClient::new(credential_coordinator);
+//!
+//! let result = client.simulate_principal_policy(
+//!     "123456789012",
+//!     "us-east-1",
+//!     &role_arn("aws", "123456789012", "app-lambda-role"),
+//!     &["s3:GetObject".to_string()],
+//!     &SimulationOptions {
+//!         resource_arns: vec!["arn:aws:s3:::my-bucket/data/*".to_string()],
+//!         ..Default::default()
+//!     },
+//! ).await?;
+//!
+//! for evaluation in &result.evaluations {
+//!     println!("{} on {}: {}", evaluation.action, evaluation.resource, evaluation.decision);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `iam:SimulatePrincipalPolicy` (and `iam:GetContextKeysForPrincipalPolicy`
+//! is useful for debugging conditions). `sts:GetCallerIdentity` needs no permissions.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::IamSimulationClient;
+pub use types::{
+    assumed_role_to_role_arn, partition_for_region, principal_type, role_arn, user_arn,
+    ActionEvaluation, CallerIdentity, MatchedStatement, SimulationContextEntry, SimulationOptions,
+    SimulationResult, MAX_SIMULATION_ACTIONS, MAX_SIMULATION_RESOURCES,
+};
@@ -0,0 +1,267 @@
+//! IAM Simulation Data Types
+//!
+//! Data structures for caller identity and IAM policy simulation results,
+//! plus helpers for building and parsing principal ARNs.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+
+/// Most actions simulated in one call
+pub const MAX_SIMULATION_ACTIONS: usize = 100;
+
+/// Most resource ARNs simulated in one call
+pub const MAX_SIMULATION_RESOURCES: usize = 100;
+
+/// Identity of the credentials used for an account
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallerIdentity {
+    pub account: String,
+    pub arn: String,
+    pub user_id: String,
+    /// Principal kind from the ARN: "assumed-role", "user", "root", "federated-user"
+    pub principal_type: String,
+    /// IAM role ARN behind an assumed-role session (usable as a simulation source)
+    pub role_arn: Option<String>,
+}
+
+impl CallerIdentity {
+    /// Build an identity from the GetCallerIdentity fields
+    pub fn new(
+        account: impl Into<String>,
+        arn: impl Into<String>,
+        user_id: impl Into<String>,
+    ) -> Self {
+        let arn = arn.into();
+        Self {
+            account: account.into(),
+            principal_type: principal_type(&arn).to_string(),
+            role_arn: assumed_role_to_role_arn(&arn),
+            arn,
+            user_id: user_id.into(),
+        }
+    }
+}
+
+/// A context key passed to the simulation (e.g. aws:SourceIp)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationContextEntry {
+    /// Context key name (e.g. "aws:SourceIp")
+    pub key: String,
+    /// One or more values
+    pub values: Vec<String>,
+    /// Key type: string, stringList, numeric, boolean, date, ip, binary, ... (default "string")
+    #[serde(rename = "type", default = "default_context_type")]
+    pub key_type: String,
+}
+
+fn default_context_type() -> String {
+    "string".to_string()
+}
+
+/// Options for a principal policy simulation
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationOptions {
+    /// Resources to evaluate against (default: all resources, "*")
+    pub resource_arns: Vec<String>,
+    /// Context keys referenced by policy conditions
+    pub context_entries: Vec<SimulationContextEntry>,
+    /// Caller ARN, needed when the source is a group or resource policies name a principal
+    pub caller_arn: Option<String>,
+    /// Resource-based policy (JSON) to include, e.g. a bucket policy
+    pub resource_policy: Option<String>,
+    /// Account that owns the resources in `resource_arns`
+    pub resource_owner: Option<String>,
+    /// Permissions boundary policies (JSON) to apply instead of the attached one
+    pub permissions_boundary_policies: Vec<String>,
+}
+
+/// A policy statement that contributed to a decision
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchedStatement {
+    /// Policy identifier (policy name or ARN)
+    pub source_policy_id: Option<String>,
+    /// Policy kind: user, group, role, aws-managed, user-managed, resource, ...
+    pub source_policy_type: Option<String>,
+}
+
+/// Decision for one action on one resource
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionEvaluation {
+    pub action: String,
+    pub resource: String,
+    /// "allowed", "explicitDeny", or "implicitDeny"
+    pub decision: String,
+    pub allowed: bool,
+    /// Statements that allowed or explicitly denied the action
+    pub matched_statements: Vec<MatchedStatement>,
+    /// Condition keys the policies use that were not supplied; the decision may differ at runtime
+    pub missing_context_values: Vec<String>,
+    /// False when an SCP denies the action
+    pub allowed_by_organizations: Option<bool>,
+    /// False when the permissions boundary denies the action
+    pub allowed_by_permissions_boundary: Option<bool>,
+}
+
+/// Result of a principal policy simulation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulationResult {
+    /// Principal whose policies were evaluated
+    pub policy_source_arn: String,
+    /// One entry per action and resource
+    pub evaluations: Vec<ActionEvaluation>,
+    pub allowed_count: usize,
+    pub denied_count: usize,
+}
+
+impl SimulationResult {
+    /// Create a result, counting allowed and denied evaluations
+    pub fn new(policy_source_arn: impl Into<String>, evaluations: Vec<ActionEvaluation>) -> Self {
+        let allowed_count = evaluations.iter().filter(|e| e.allowed).count();
+        Self {
+            policy_source_arn: policy_source_arn.into(),
+            denied_count: evaluations.len() - allowed_count,
+            allowed_count,
+            evaluations,
+        }
+    }
+}
+
+/// AWS partition for a region
+pub fn partition_for_region(region: &str) -> &'static str {
+    if region.starts_with("cn-") {
+        "aws-cn"
+    } else if region.starts_with("us-gov-") {
+        "aws-us-gov"
+    } else {
+        "aws"
+    }
+}
+
+/// Build an IAM role ARN; `role_name` may include a path ("service-role/my-role")
+pub fn role_arn(partition: &str, account_id: &str, role_name: &str) -> String {
+    format!(
+        "arn:{}:iam::{}:role/{}",
+        partition,
+        account_id,
+        role_name.trim_start_matches('/')
+    )
+}
+
+/// Build an IAM user ARN; `user_name` may include a path
+pub fn user_arn(partition: &str, account_id: &str, user_name: &str) -> String {
+    format!(
+        "arn:{}:iam::{}:user/{}",
+        partition,
+        account_id,
+        user_name.trim_start_matches('/')
+    )
+}
+
+/// Principal kind from an IAM or STS ARN ("assumed-role", "user", "role", "root", ...)
+pub fn principal_type(arn: &str) -> &str {
+    let resource = arn.splitn(6, ':').nth(5).unwrap_or_default();
+    if resource == "root" {
+        return "root";
+    }
+    resource.split('/').next().unwrap_or_default()
+}
+
+/// Convert an STS assumed-role session ARN to the IAM role ARN
+///
+/// `arn:aws:sts::123456789012:assumed-role/MyRole/session` becomes
+/// `arn:aws:iam::123456789012:role/MyRole`. The session ARN does not carry
+/// the role path, so roles with a path must be named explicitly.
+pub fn assumed_role_to_role_arn(arn: &str) -> Option<String> {
+    let parts: Vec<&str> = arn.splitn(6, ':').collect();
+    if parts.len() != 6 || parts[2] != "sts" {
+        return None;
+    }
+    let role_name = parts[5].strip_prefix("assumed-role/")?.split('/').next()?;
+    if role_name.is_empty() {
+        return None;
+    }
+    Some(role_arn(parts[1], parts[4], role_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partition_and_arns() {
+        assert_eq!(partition_for_region("us-east-1"), "aws");
+        assert_eq!(partition_for_region("cn-north-1"), "aws-cn");
+        assert_eq!(partition_for_region("us-gov-west-1"), "aws-us-gov");
+
+        assert_eq!(
+            role_arn("aws", "123456789012", "service-role/app"),
+            "arn:aws:iam::123456789012:role/service-role/app"
+        );
+        assert_eq!(
+            user_arn("aws", "123456789012", "/alice"),
+            "arn:aws:iam::123456789012:user/alice"
+        );
+    }
+
+    #[test]
+    fn test_caller_identity_from_assumed_role() {
+        let identity = CallerIdentity::new(
+            "123456789012",
+            "arn:aws:sts::123456789012:assumed-role/AWSReservedSSO_Admin_abc/alice@example.com",
+            "AROAEXAMPLE:alice@example.com",
+        );
+        assert_eq!(identity.principal_type, "assumed-role");
+        assert_eq!(
+            identity.role_arn.as_deref(),
+            Some("arn:aws:iam::123456789012:role/AWSReservedSSO_Admin_abc")
+        );
+
+        let user = CallerIdentity::new(
+            "123456789012",
+            "arn:aws:iam::123456789012:user/ci",
+            "AIDAEXAMPLE",
+        );
+        assert_eq!(user.principal_type, "user");
+        assert!(user.role_arn.is_none());
+
+        assert_eq!(principal_type("arn:aws:iam::123456789012:root"), "root");
+    }
+
+    #[test]
+    fn test_simulation_result_counts() {
+        let evaluation = |allowed: bool| ActionEvaluation {
+            action: "s3:GetObject".to_string(),
+            resource: "*".to_string(),
+            decision: if allowed { "allowed" } else { "implicitDeny" }.to_string(),
+            allowed,
+            matched_statements: Vec::new(),
+            missing_context_values: Vec::new(),
+            allowed_by_organizations: None,
+            allowed_by_permissions_boundary: None,
+        };
+
+        let result = SimulationResult::new(
+            "arn:aws:iam::123456789012:role/app",
+            vec![evaluation(true), evaluation(false), evaluation(false)],
+        );
+        assert_eq!(result.allowed_count, 1);
+        assert_eq!(result.denied_count, 2);
+    }
+
+    #[test]
+    fn test_context_entry_default_type() {
+        let entry: SimulationContextEntry = serde_json::from_value(serde_json::json!({
+            "key": "aws:SourceIp",
+            "values": ["10.0.0.1"]
+        }))
+        .unwrap();
+        assert_eq!(entry.key_type, "string");
+    }
+}
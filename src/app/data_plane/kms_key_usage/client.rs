@@ -0,0 +1,237 @@
+//! KMS Key Usage Client Wrapper
+//!
+//! Reads a key's metadata, rotation status, aliases, key policy, and grants,
+//! and the principals calling cryptographic operations with it from
+//! CloudTrail event history, with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Context, Result};
+use aws_sdk_kms as kms;
+use std::sync::Arc;
+
+use crate::app::data_plane::cloudtrail_events::{
+    CloudTrailEventsClient, LookupAttribute, LookupOptions,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{
+    KeyCallerReport, KeyGrant, KeyPolicyStatement, KeyRotation, KeyUsageDetails, MAX_LOOKBACK_DAYS,
+};
+
+/// CloudTrail pages (50 events each) scanned for key callers
+const MAX_CLOUDTRAIL_PAGES: usize = 20;
+
+/// KMS key usage client wrapper
+#[derive(Clone)]
+pub struct KmsKeyUsageClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl KmsKeyUsageClient {
+    /// Create a new KMS key usage client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<kms::Client> {
+        let config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+        Ok(kms::Client::new(&config))
+    }
+
+    /// Metadata, rotation status, aliases, key policy statements, and grants
+    ///
+    /// Only DescribeKey must succeed; the other parts are left empty with a
+    /// warning when they cannot be read.
+    pub async fn get_key_details(
+        &self,
+        account_id: &str,
+        region: &str,
+        key_id: &str,
+    ) -> Result<KeyUsageDetails> {
+        let client = self.create_client(account_id, region).await?;
+
+        let response = client
+            .describe_key()
+            .key_id(key_id)
+            .send()
+            .await
+            .with_context(|| format!("Failed to describe key {}", key_id))?;
+        let metadata = response
+            .key_metadata()
+            .ok_or_else(|| anyhow!("Key {} not found", key_id))?;
+
+        let mut details = KeyUsageDetails {
+            key_id: metadata.key_id().to_string(),
+            key_arn: metadata.arn().map(String::from),
+            description: metadata
+                .description()
+                .filter(|d| !d.is_empty())
+                .map(String::from),
+            key_state: metadata.key_state().map(|s| s.as_str().to_string()),
+            key_manager: metadata.key_manager().map(|m| m.as_str().to_string()),
+            key_usage: metadata.key_usage().map(|u| u.as_str().to_string()),
+            key_spec: metadata.key_spec().map(|s| s.as_str().to_string()),
+            creation_date: metadata.creation_date().map(|d| d.to_string()),
+            deletion_date: metadata.deletion_date().map(|d| d.to_string()),
+            multi_region: metadata.multi_region().unwrap_or_default(),
+            ..Default::default()
+        };
+
+        match client.list_aliases().key_id(&details.key_id).send().await {
+            Ok(response) => {
+                details.aliases = response
+                    .aliases()
+                    .iter()
+                    .filter_map(|alias| alias.alias_name().map(String::from))
+                    .collect();
+            }
+            Err(e) => details.warnings.push(format!("Aliases unavailable: {}", e)),
+        }
+
+        match client
+            .get_key_rotation_status()
+            .key_id(&details.key_id)
+            .send()
+            .await
+        {
+            Ok(response) => {
+                details.rotation = KeyRotation {
+                    enabled: Some(response.key_rotation_enabled()),
+                    period_days: response.rotation_period_in_days(),
+                    next_rotation: response.next_rotation_date().map(|d| d.to_string()),
+                };
+            }
+            // Asymmetric, HMAC, imported, and custom key store keys do not rotate
+            Err(e) if format!("{:?}", e).contains("UnsupportedOperation") => {}
+            Err(e) => details
+                .warnings
+                .push(format!("Rotation status unavailable: {}", e)),
+        }
+
+        match client
+            .get_key_policy()
+            .key_id(&details.key_id)
+            .policy_name("default")
+            .send()
+            .await
+        {
+            Ok(response) => {
+                if let Some(policy) = response.policy() {
+                    details.policy_statements = KeyPolicyStatement::parse_policy(policy);
+                    details.policy = Some(policy.to_string());
+                }
+            }
+            Err(e) => details
+                .warnings
+                .push(format!("Key policy unavailable: {}", e)),
+        }
+
+        match self.list_grants(&client, &details.key_id).await {
+            Ok(grants) => details.grants = grants,
+            Err(e) => details
+                .warnings
+                .push(format!("Grants unavailable: {:#}", e)),
+        }
+
+        Ok(details)
+    }
+
+    async fn list_grants(&self, client: &kms::Client, key_id: &str) -> Result<Vec<KeyGrant>> {
+        let mut grants = Vec::new();
+        let mut marker = None;
+        loop {
+            let response = client
+                .list_grants()
+                .key_id(key_id)
+                .set_marker(marker)
+                .send()
+                .await
+                .context("Failed to list grants")?;
+            grants.extend(response.grants().iter().map(|grant| {
+                let mut constraints: Vec<String> = Vec::new();
+                if let Some(grant_constraints) = grant.constraints() {
+                    for (kind, context) in [
+                        ("equals", grant_constraints.encryption_context_equals()),
+                        ("subset", grant_constraints.encryption_context_subset()),
+                    ] {
+                        for (key, value) in context.into_iter().flatten() {
+                            constraints.push(format!("{} {}={}", kind, key, value));
+                        }
+                    }
+                }
+                constraints.sort();
+                KeyGrant {
+                    grant_id: grant.grant_id().unwrap_or_default().to_string(),
+                    name: grant.name().filter(|n| !n.is_empty()).map(String::from),
+                    grantee_principal: grant.grantee_principal().map(String::from),
+                    retiring_principal: grant.retiring_principal().map(String::from),
+                    issuing_account: grant.issuing_account().map(String::from),
+                    operations: grant
+                        .operations()
+                        .iter()
+                        .map(|operation| operation.as_str().to_string())
+                        .collect(),
+                    constraints,
+                    creation_date: grant.creation_date().map(|d| d.to_string()),
+                }
+            }));
+            marker = response.next_marker().map(String::from);
+            if !response.truncated() || marker.is_none() {
+                break;
+            }
+        }
+        Ok(grants)
+    }
+
+    /// Principals that called cryptographic operations with the key in the
+    /// last `lookback_days` days (at most 90, the CloudTrail event history)
+    ///
+    /// Events are looked up by the key ARN; the scan stops after
+    /// 1,000 events, so very busy keys show the most recent callers.
+    pub async fn get_key_callers(
+        &self,
+        account_id: &str,
+        region: &str,
+        key_arn: &str,
+        lookback_days: i64,
+    ) -> Result<KeyCallerReport> {
+        let cloudtrail = CloudTrailEventsClient::new(Arc::clone(&self.credential_coordinator));
+        let start_time =
+            chrono::Utc::now() - chrono::Duration::days(lookback_days.clamp(1, MAX_LOOKBACK_DAYS));
+
+        let mut events = Vec::new();
+        let mut next_token: Option<String> = None;
+        for _ in 0..MAX_CLOUDTRAIL_PAGES {
+            let mut options = LookupOptions::new()
+                .with_start_time(start_time.timestamp_millis())
+                .with_max_results(50)
+                .with_lookup_attribute(LookupAttribute::resource_name(key_arn.to_string()));
+            if let Some(token) = next_token.take() {
+                options = options.with_next_token(token);
+            }
+            let result = cloudtrail
+                .lookup_events(account_id, region, options)
+                .await
+                .context("Failed to look up key usage in CloudTrail")?;
+            events.extend(result.events);
+            next_token = result.next_token;
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(KeyCallerReport::from_events(&events, next_token.is_some()))
+    }
+}
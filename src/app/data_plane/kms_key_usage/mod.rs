@@ -0,0 +1,58 @@
+//! KMS Key Usage Integration Module
+//!
+//! Backs the KMS key detail window: who can use a key, and who has been.
+//!
+//! ## Features
+//!
+//! - Key metadata (state, manager, usage, spec) with aliases and rotation status
+//! - Key policy statements flattened to effect, principals, actions, and conditions
+//! - Grants with grantee, retiring principal, operations, and encryption
+//!   context constraints
+//! - Recent Encrypt/Decrypt/GenerateDataKey (and other cryptographic
+//!   operation) callers from CloudTrail event history, grouped by principal
+//!   and the AWS service calling on its behalf
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::kms_key_usage::KmsKeyUsageClient;
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = KmsKeyUsageClient::new(credential_coordinator);
+//! let details = client
+//!     .get_key_details("123456789012", "us-east-1", "1234abcd-12ab-34cd-56ef-1234567890ab")
+//!     .await?;
+//! println!("{} grants, aliases {:?}", details.grants.len(), details.aliases);
+//!
+//! if let Some(key_arn) = &details.key_arn {
+//!     let report = client
+//!         .get_key_callers("123456789012", "us-east-1", key_arn, 7)
+//!         .await?;
+//!     for caller in &report.callers {
+//!         println!("{}: {} calls", caller.principal, caller.total_calls);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `kms:DescribeKey`, `kms:ListAliases`,
+//! `kms:GetKeyRotationStatus`, `kms:GetKeyPolicy`, and `kms:ListGrants` on
+//! the key, and `cloudtrail:LookupEvents` for usage. CloudTrail event history
+//! covers the last 90 days in the key's region.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::KmsKeyUsageClient;
+pub use types::{
+    KeyCaller, KeyCallerReport, KeyGrant, KeyPolicyStatement, KeyRotation, KeyUsageDetails,
+    CRYPTOGRAPHIC_OPERATIONS, MAX_LOOKBACK_DAYS,
+};
@@ -0,0 +1,335 @@
+//! KMS Key Usage Data Types
+//!
+//! A key's metadata, rotation status, aliases, key policy statements, and
+//! grants, plus the principals seen using it in CloudTrail.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use crate::app::data_plane::cloudtrail_events::CloudTrailEvent;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// Cryptographic operations counted as key usage
+pub const CRYPTOGRAPHIC_OPERATIONS: &[&str] = &[
+    "Encrypt",
+    "Decrypt",
+    "ReEncrypt",
+    "GenerateDataKey",
+    "GenerateDataKeyWithoutPlaintext",
+    "GenerateDataKeyPair",
+    "GenerateDataKeyPairWithoutPlaintext",
+    "Sign",
+    "Verify",
+    "GenerateMac",
+    "VerifyMac",
+];
+
+/// CloudTrail event history only covers the last 90 days
+pub const MAX_LOOKBACK_DAYS: i64 = 90;
+
+/// Rotation status of a key
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KeyRotation {
+    /// None when the key type does not support rotation (asymmetric, HMAC,
+    /// imported, or custom key store keys)
+    pub enabled: Option<bool>,
+    pub period_days: Option<i32>,
+    pub next_rotation: Option<String>,
+}
+
+/// One statement of a key policy, flattened for display
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyPolicyStatement {
+    pub sid: Option<String>,
+    /// "Allow" or "Deny"
+    pub effect: String,
+    /// Principals as "AWS: arn", "Service: name", or "*"
+    pub principals: Vec<String>,
+    pub actions: Vec<String>,
+    /// Condition block as compact JSON, if any
+    pub condition: Option<String>,
+}
+
+impl KeyPolicyStatement {
+    /// Statements of a key policy document
+    ///
+    /// Accepts a single statement object as well as an array, and string or
+    /// list values for principals and actions.
+    pub fn parse_policy(policy: &str) -> Vec<Self> {
+        let Ok(document) = serde_json::from_str::<serde_json::Value>(policy) else {
+            return Vec::new();
+        };
+        let statements = match document.get("Statement") {
+            Some(serde_json::Value::Array(statements)) => statements.clone(),
+            Some(statement @ serde_json::Value::Object(_)) => vec![statement.clone()],
+            _ => return Vec::new(),
+        };
+        statements
+            .iter()
+            .map(|statement| {
+                let principals = match statement.get("Principal") {
+                    Some(serde_json::Value::String(principal)) => vec![principal.clone()],
+                    Some(serde_json::Value::Object(map)) => map
+                        .iter()
+                        .flat_map(|(kind, values)| {
+                            string_list(values)
+                                .into_iter()
+                                .map(move |value| format!("{}: {}", kind, value))
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                Self {
+                    sid: statement
+                        .get("Sid")
+                        .and_then(|v| v.as_str())
+                        .map(String::from),
+                    effect: statement
+                        .get("Effect")
+                        .and_then(|v| v.as_str())
+                        .unwrap_or("Allow")
+                        .to_string(),
+                    principals,
+                    actions: statement.get("Action").map(string_list).unwrap_or_default(),
+                    condition: statement.get("Condition").map(|c| c.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    /// Whether the statement grants every KMS action
+    pub fn allows_all_actions(&self) -> bool {
+        self.effect == "Allow"
+            && self
+                .actions
+                .iter()
+                .any(|action| action == "kms:*" || action == "*")
+    }
+}
+
+/// String or array of strings
+fn string_list(value: &serde_json::Value) -> Vec<String> {
+    match value {
+        serde_json::Value::String(value) => vec![value.clone()],
+        serde_json::Value::Array(values) => values
+            .iter()
+            .filter_map(|v| v.as_str().map(String::from))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// A grant on the key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyGrant {
+    pub grant_id: String,
+    pub name: Option<String>,
+    pub grantee_principal: Option<String>,
+    pub retiring_principal: Option<String>,
+    pub issuing_account: Option<String>,
+    pub operations: Vec<String>,
+    /// Encryption context constraints, e.g. "subset aws:ebs:id=vol-0abc"
+    pub constraints: Vec<String>,
+    pub creation_date: Option<String>,
+}
+
+/// Key metadata with everything needed to answer who can use it
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KeyUsageDetails {
+    pub key_id: String,
+    pub key_arn: Option<String>,
+    pub description: Option<String>,
+    /// "Enabled", "Disabled", "PendingDeletion", ...
+    pub key_state: Option<String>,
+    /// "AWS" or "CUSTOMER"
+    pub key_manager: Option<String>,
+    /// "ENCRYPT_DECRYPT", "SIGN_VERIFY", or "GENERATE_VERIFY_MAC"
+    pub key_usage: Option<String>,
+    pub key_spec: Option<String>,
+    pub creation_date: Option<String>,
+    pub deletion_date: Option<String>,
+    pub multi_region: bool,
+    pub aliases: Vec<String>,
+    pub rotation: KeyRotation,
+    pub policy_statements: Vec<KeyPolicyStatement>,
+    /// Raw key policy document
+    pub policy: Option<String>,
+    pub grants: Vec<KeyGrant>,
+    /// Parts that could not be read (e.g. access denied on the key policy)
+    pub warnings: Vec<String>,
+}
+
+/// A principal seen calling cryptographic operations with the key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct KeyCaller {
+    /// IAM ARN of the caller, or the CloudTrail user name
+    pub principal: String,
+    /// AWS service calling on the principal's behalf, e.g. "s3.amazonaws.com"
+    pub invoked_by: Option<String>,
+    /// Calls per operation
+    pub operations: BTreeMap<String, usize>,
+    pub total_calls: usize,
+    /// Calls that failed (e.g. AccessDenied)
+    pub failed_calls: usize,
+    /// Unix millis of the most recent call
+    pub last_seen: i64,
+}
+
+/// Callers found in CloudTrail for a key
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KeyCallerReport {
+    /// Most calls first
+    pub callers: Vec<KeyCaller>,
+    pub events_scanned: usize,
+    /// True when more events were available than were scanned
+    pub truncated: bool,
+}
+
+impl KeyCallerReport {
+    /// Group cryptographic operation events by caller and calling service
+    pub fn from_events(events: &[CloudTrailEvent], truncated: bool) -> Self {
+        let mut callers: HashMap<(String, Option<String>), KeyCaller> = HashMap::new();
+        for event in events {
+            if !CRYPTOGRAPHIC_OPERATIONS.contains(&event.event_name.as_str()) {
+                continue;
+            }
+            let identity = event
+                .cloud_trail_event
+                .as_deref()
+                .and_then(|json| serde_json::from_str::<serde_json::Value>(json).ok())
+                .and_then(|record| record.get("userIdentity").cloned());
+            let principal = identity
+                .as_ref()
+                .and_then(|identity| {
+                    identity
+                        .get("arn")
+                        .or_else(|| identity.get("invokedBy"))
+                        .and_then(|v| v.as_str())
+                        .map(String::from)
+                })
+                .unwrap_or_else(|| event.username.clone());
+            let invoked_by = identity
+                .as_ref()
+                .and_then(|identity| identity.get("invokedBy"))
+                .and_then(|v| v.as_str())
+                .filter(|service| *service != principal)
+                .map(String::from);
+
+            let caller = callers
+                .entry((principal.clone(), invoked_by.clone()))
+                .or_insert_with(|| KeyCaller {
+                    principal,
+                    invoked_by,
+                    operations: BTreeMap::new(),
+                    total_calls: 0,
+                    failed_calls: 0,
+                    last_seen: 0,
+                });
+            *caller
+                .operations
+                .entry(event.event_name.clone())
+                .or_default() += 1;
+            caller.total_calls += 1;
+            if event.error_code.is_some() {
+                caller.failed_calls += 1;
+            }
+            caller.last_seen = caller.last_seen.max(event.event_time);
+        }
+
+        let mut callers: Vec<KeyCaller> = callers.into_values().collect();
+        callers.sort_by(|a, b| {
+            b.total_calls
+                .cmp(&a.total_calls)
+                .then_with(|| b.last_seen.cmp(&a.last_seen))
+                .then_with(|| a.principal.cmp(&b.principal))
+        });
+        Self {
+            callers,
+            events_scanned: events.len(),
+            truncated,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(name: &str, identity: &str, time: i64, error: bool) -> CloudTrailEvent {
+        CloudTrailEvent {
+            event_id: format!("{}-{}", name, time),
+            event_name: name.to_string(),
+            event_time: time,
+            event_source: "kms.amazonaws.com".to_string(),
+            username: "fallback".to_string(),
+            resources: Vec::new(),
+            cloud_trail_event: Some(format!(r#"{{"userIdentity":{}}}"#, identity)),
+            access_key_id: None,
+            read_only: Some("true".to_string()),
+            error_code: error.then(|| "AccessDenied".to_string()),
+            error_message: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_policy_statements() {
+        let policy = r#"{
+            "Version": "2012-10-17",
+            "Statement": [
+                {"Sid": "Enable IAM User Permissions", "Effect": "Allow",
+                 "Principal": {"AWS": "arn:aws:iam::123456789012:root"}, "Action": "kms:*", "Resource": "*"},
+                {"Effect": "Allow",
+                 "Principal": {"Service": ["logs.amazonaws.com"]},
+                 "Action": ["kms:Encrypt", "kms:Decrypt"],
+                 "Condition": {"ArnLike": {"kms:EncryptionContext:aws:logs:arn": "arn:aws:logs:*"}}}
+            ]
+        }"#;
+        let statements = KeyPolicyStatement::parse_policy(policy);
+        assert_eq!(statements.len(), 2);
+        assert_eq!(
+            statements[0].principals,
+            ["AWS: arn:aws:iam::123456789012:root"]
+        );
+        assert!(statements[0].allows_all_actions());
+        assert_eq!(statements[1].principals, ["Service: logs.amazonaws.com"]);
+        assert_eq!(statements[1].actions.len(), 2);
+        assert!(statements[1].condition.is_some());
+        assert!(!statements[1].allows_all_actions());
+
+        assert!(KeyPolicyStatement::parse_policy("not json").is_empty());
+    }
+
+    #[test]
+    fn test_callers_grouped_by_principal_and_service() {
+        let role = r#"{"arn":"arn:aws:sts::123456789012:assumed-role/app/i-0abc"}"#;
+        let via_s3 = r#"{"arn":"arn:aws:sts::123456789012:assumed-role/app/i-0abc","invokedBy":"s3.amazonaws.com"}"#;
+        let service = r#"{"type":"AWSService","invokedBy":"logs.amazonaws.com"}"#;
+        let events = vec![
+            event("Decrypt", role, 1_000, false),
+            event("Decrypt", role, 3_000, true),
+            event("GenerateDataKey", via_s3, 2_000, false),
+            event("Decrypt", service, 500, false),
+            event("DescribeKey", role, 4_000, false),
+        ];
+
+        let report = KeyCallerReport::from_events(&events, false);
+        assert_eq!(report.events_scanned, 5);
+        assert_eq!(report.callers.len(), 3);
+
+        let top = &report.callers[0];
+        assert_eq!(top.total_calls, 2);
+        assert_eq!(top.failed_calls, 1);
+        assert_eq!(top.last_seen, 3_000);
+        assert_eq!(top.invoked_by, None);
+
+        assert!(report
+            .callers
+            .iter()
+            .any(|c| c.invoked_by.as_deref() == Some("s3.amazonaws.com")
+                && c.operations.get("GenerateDataKey") == Some(&1)));
+        assert!(report
+            .callers
+            .iter()
+            .any(|c| c.principal == "logs.amazonaws.com" && c.invoked_by.is_none()));
+    }
+}
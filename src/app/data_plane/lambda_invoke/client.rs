@@ -0,0 +1,97 @@
+//! Lambda Invoke Client Wrapper
+//!
+//! Invokes a function synchronously with the log tail, or as a dry run, with
+//! credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_lambda as lambda;
+use std::sync::Arc;
+
+use crate::app::capabilities::ensure_writable;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{InvocationReport, InvokeRequest, InvokeResult};
+
+/// Lambda invoke client wrapper
+#[derive(Clone)]
+pub struct LambdaInvokeClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl LambdaInvokeClient {
+    /// Create a new Lambda invoke client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<lambda::Client> {
+        let config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+        Ok(lambda::Client::new(&config))
+    }
+
+    /// Invoke a function and wait for its response
+    ///
+    /// A real invocation runs the function and is refused in the read-only
+    /// build; a dry run only checks the payload and permissions.
+    pub async fn invoke(
+        &self,
+        account_id: &str,
+        region: &str,
+        function_name: &str,
+        request: &InvokeRequest,
+    ) -> Result<InvokeResult> {
+        if !request.dry_run {
+            ensure_writable("Invoke Lambda function")?;
+        }
+        request.validate().map_err(anyhow::Error::msg)?;
+        let client = self.create_client(account_id, region).await?;
+
+        let invocation_type = if request.dry_run {
+            lambda::types::InvocationType::DryRun
+        } else {
+            lambda::types::InvocationType::RequestResponse
+        };
+
+        let response = client
+            .invoke()
+            .function_name(function_name)
+            .set_qualifier(request.qualifier().map(String::from))
+            .invocation_type(invocation_type)
+            .log_type(lambda::types::LogType::Tail)
+            .payload(lambda::primitives::Blob::new(request.payload.as_bytes()))
+            .send()
+            .await
+            .with_context(|| format!("Failed to invoke {}", function_name))?;
+
+        // The log tail comes back base64 encoded
+        let log_tail = response
+            .log_result()
+            .and_then(|encoded| aws_smithy_types::base64::decode(encoded).ok())
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned());
+
+        Ok(InvokeResult {
+            status_code: response.status_code(),
+            function_error: response.function_error().map(String::from),
+            payload: response
+                .payload()
+                .map(|blob| String::from_utf8_lossy(blob.as_ref()).into_owned()),
+            report: log_tail.as_deref().and_then(InvocationReport::parse),
+            log_tail,
+            executed_version: response.executed_version().map(String::from),
+            dry_run: request.dry_run,
+        })
+    }
+}
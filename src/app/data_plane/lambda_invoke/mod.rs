@@ -0,0 +1,54 @@
+//! Lambda Invoke Integration Module
+//!
+//! Backs the Lambda test harness: invoke a function with a JSON payload and
+//! see its response, log tail, duration, and memory use.
+//!
+//! ## Features
+//!
+//! - Synchronous invocation of $LATEST, a version, or an alias
+//! - Dry runs that check the payload and permissions without running the function
+//! - Log tail decoded, with the REPORT line parsed into duration, billed
+//!   duration, memory, and cold start init time
+//! - Real invocations refused in the read-only build
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::lambda_invoke::{InvokeRequest, LambdaInvokeClient};
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = LambdaInvokeClient::new(credential_coordinator);
+//!
+//! let result = client
+//!     .invoke(
+//!         "123456789012",
+//!         "us-east-1",
+//!         "process-order",
+//!         &InvokeRequest {
+//!             payload: r#"{"orderId": 42}"#.to_string(),
+//!             ..Default::default()
+//!         },
+//!     )
+//!     .await?;
+//!
+//! if let Some(report) = &result.report {
+//!     println!("{} ms, {} MB used", report.duration_ms, report.max_memory_used_mb);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `lambda:InvokeFunction` on the function (dry runs check it too).
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::LambdaInvokeClient;
+pub use types::{InvocationReport, InvokeRequest, InvokeResult, MAX_PAYLOAD_BYTES};
@@ -0,0 +1,168 @@
+//! Lambda Invocation Data Types
+//!
+//! Test invocation requests, their results, and the REPORT line Lambda writes
+//! at the end of every invocation log.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+
+/// Largest payload a synchronous invocation accepts
+pub const MAX_PAYLOAD_BYTES: usize = 6 * 1024 * 1024;
+
+/// A test invocation of a function
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvokeRequest {
+    /// JSON event passed to the handler
+    pub payload: String,
+    /// Version or alias; None invokes $LATEST
+    pub qualifier: Option<String>,
+    /// Only check the payload and permissions; the function does not run
+    pub dry_run: bool,
+}
+
+impl Default for InvokeRequest {
+    fn default() -> Self {
+        Self {
+            payload: "{}".to_string(),
+            qualifier: None,
+            dry_run: false,
+        }
+    }
+}
+
+impl InvokeRequest {
+    /// Check the payload before sending it
+    pub fn validate(&self) -> Result<(), String> {
+        if self.payload.len() > MAX_PAYLOAD_BYTES {
+            return Err(format!(
+                "Payload is {} bytes; synchronous invocations accept at most {}",
+                self.payload.len(),
+                MAX_PAYLOAD_BYTES
+            ));
+        }
+        serde_json::from_str::<serde_json::Value>(&self.payload)
+            .map(|_| ())
+            .map_err(|e| format!("Payload is not valid JSON: {}", e))
+    }
+
+    /// Qualifier with surrounding whitespace removed, None when blank
+    pub fn qualifier(&self) -> Option<&str> {
+        self.qualifier
+            .as_deref()
+            .map(str::trim)
+            .filter(|qualifier| !qualifier.is_empty())
+    }
+}
+
+/// Figures from the REPORT line at the end of an invocation log
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvocationReport {
+    pub duration_ms: f64,
+    pub billed_duration_ms: u64,
+    pub memory_size_mb: u32,
+    pub max_memory_used_mb: u32,
+    /// Present on cold starts
+    pub init_duration_ms: Option<f64>,
+}
+
+impl InvocationReport {
+    /// Parse the last REPORT line of a log tail
+    ///
+    /// `REPORT RequestId: ...\tDuration: 12.34 ms\tBilled Duration: 13 ms\tMemory Size: 128 MB\tMax Memory Used: 70 MB`
+    pub fn parse(log: &str) -> Option<Self> {
+        let line = log.lines().rev().find(|line| line.starts_with("REPORT "))?;
+
+        let mut report = Self::default();
+        let mut has_duration = false;
+        for field in line.split('\t') {
+            let Some((key, value)) = field.split_once(": ") else {
+                continue;
+            };
+            // "12.34 ms" or "128 MB"
+            let number = value.split_whitespace().next().unwrap_or_default();
+            match key.trim() {
+                "Duration" => {
+                    report.duration_ms = number.parse().ok()?;
+                    has_duration = true;
+                }
+                "Billed Duration" => report.billed_duration_ms = number.parse().ok()?,
+                "Memory Size" => report.memory_size_mb = number.parse().ok()?,
+                "Max Memory Used" => report.max_memory_used_mb = number.parse().ok()?,
+                "Init Duration" => report.init_duration_ms = number.parse().ok(),
+                _ => {}
+            }
+        }
+        has_duration.then_some(report)
+    }
+}
+
+/// Outcome of a test invocation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvokeResult {
+    /// 200 for invocations, 204 for dry runs
+    pub status_code: i32,
+    /// "Unhandled" or "Handled" when the function returned an error
+    pub function_error: Option<String>,
+    /// Response payload as returned by the handler
+    pub payload: Option<String>,
+    /// Last 4 KB of the invocation log
+    pub log_tail: Option<String>,
+    /// Version that ran, resolved from the qualifier
+    pub executed_version: Option<String>,
+    pub report: Option<InvocationReport>,
+    pub dry_run: bool,
+}
+
+impl InvokeResult {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status_code) && self.function_error.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_report_line() {
+        let log = "START RequestId: 1f2e Version: $LATEST\n\
+                   hello\n\
+                   END RequestId: 1f2e\n\
+                   REPORT RequestId: 1f2e\tDuration: 12.34 ms\tBilled Duration: 13 ms\tMemory Size: 128 MB\tMax Memory Used: 70 MB\tInit Duration: 150.20 ms\t\n";
+        let report = InvocationReport::parse(log).unwrap();
+        assert_eq!(report.duration_ms, 12.34);
+        assert_eq!(report.billed_duration_ms, 13);
+        assert_eq!(report.memory_size_mb, 128);
+        assert_eq!(report.max_memory_used_mb, 70);
+        assert_eq!(report.init_duration_ms, Some(150.2));
+
+        let warm = "REPORT RequestId: 9a\tDuration: 1.02 ms\tBilled Duration: 2 ms\tMemory Size: 256 MB\tMax Memory Used: 80 MB\t";
+        assert_eq!(
+            InvocationReport::parse(warm).unwrap().init_duration_ms,
+            None
+        );
+
+        assert!(InvocationReport::parse("END RequestId: 1f2e\n").is_none());
+    }
+
+    #[test]
+    fn test_validate_request() {
+        assert!(InvokeRequest::default().validate().is_ok());
+
+        let invalid = InvokeRequest {
+            payload: "{not json".to_string(),
+            ..Default::default()
+        };
+        assert!(invalid.validate().unwrap_err().contains("not valid JSON"));
+
+        let blank_qualifier = InvokeRequest {
+            qualifier: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(blank_qualifier.qualifier(), None);
+    }
+}
@@ -7,13 +7,30 @@
 //! ## Available Services
 //!
 //! - **CloudWatch Logs**: Query log events from Lambda, API Gateway, RDS, and other resources
+//! - **CloudWatch Metrics**: Query metric time series for EC2, Lambda, RDS, DynamoDB, and more
 //! - **CloudTrail Events**: Query API call history and governance/compliance events
+//! - **AWS Config History**: Query resource configuration timelines and diff snapshots
+//! - **CloudFront Invalidations**: Invalidate distribution paths and follow invalidations to completion
+//! - **Auto Scaling Capacity**: Group capacity, instance health, scaling policies and activities, and desired capacity changes
+//! - **Bedrock Models**: Foundation model catalog with throughput options and invocation quotas
+//! - **Athena**: Run SQL queries against data in S3
+//! - **Cost Explorer**: Month-to-date and forecast spend per service, account, and region
+//! - **S3 Objects**: List, inspect, and read (size-limited) objects in buckets
+//! - **S3 Bucket Analytics**: Storage size by class, lifecycle and replication rules, and public access status
+//! - **DynamoDB Items**: Query and scan table contents with item and size caps
+//! - **EventBridge Events**: Rules with their targets, and test events checked or sent to a bus
+//! - **IAM Simulation**: Caller identity and "can principal X do Y on Z" policy simulation
+//! - **KMS Key Usage**: Key policy statements, grants, aliases, rotation, and recent key callers from CloudTrail
+//! - **Lambda Invoke**: Test invocations with the response, log tail, duration, and memory
+//! - **SQS Messages**: Queue depth and dead-letter linkage, message peek, purge, and redrive
+//! - **SSM Session**: Session Manager shells on EC2 instances, with a session audit log
+//! - **Service Quotas**: Applied limits and current usage of key regional quotas
+//! - **Health Events**: Open issues and scheduled changes from AWS Health, with affected entities
+//! - **Recommendations**: Compute Optimizer rightsizing findings and flagged Trusted Advisor checks
+//! - **Step Functions**: State machine executions and their per-state transition history
 //!
 //! ## Future Services
 //!
-//! - CloudWatch Metrics: Query metrics and statistics
-//! - AWS Config: Query resource configuration history
-//! - Athena: Run SQL queries against data in S3
 //! - X-Ray: Trace distributed application requests
 //!
 //! ## Architecture
@@ -52,7 +69,7 @@
 //!    ```
 //!
 //! 2. **UI Access** - Via Resource Explorer buttons:
-//!    - Click "View Logs" or "View Events" on any resource
+//!    - Click "View Logs", "View Metrics", or "View Events" on any resource
 //!    - Opens dedicated viewer window
 //!    - Supports search, filtering, and pagination
 //!
@@ -61,16 +78,81 @@
 //! See `docs/technical/aws-data-plane-integration-guide.md` for the complete
 //! integration pattern and step-by-step guide.
 
+pub mod athena;
+pub mod autoscaling_capacity;
+pub mod bedrock_models;
+pub mod cloudfront_invalidations;
 pub mod cloudtrail_events;
 pub mod cloudwatch_logs;
+pub mod cloudwatch_metrics;
+pub mod config_history;
+pub mod cost_explorer;
+pub mod dynamodb_items;
+pub mod eventbridge_events;
+pub mod health_events;
+pub mod iam_simulation;
+pub mod kms_key_usage;
+pub mod lambda_invoke;
+pub mod recommendations;
+pub mod s3_bucket_analytics;
+pub mod s3_objects;
+pub mod service_quotas;
+pub mod sqs_messages;
+pub mod ssm_session;
+pub mod step_functions;
 
 // Re-export commonly used types from each service
+pub use athena::{AthenaQueryClient, AthenaQueryRequest, AthenaQueryResult};
+
+pub use autoscaling_capacity::{AutoScalingCapacityClient, AutoScalingGroupDetails};
+
+pub use bedrock_models::{BedrockModelCatalogClient, ModelCatalog};
+
+pub use cloudfront_invalidations::{CloudFrontInvalidationsClient, Invalidation};
+
 pub use cloudwatch_logs::{
     CloudWatchLogsClient, LogQueryResult as CloudWatchLogsQueryResult,
     QueryOptions as CloudWatchLogsQueryOptions,
 };
 
+pub use cloudwatch_metrics::{
+    CloudWatchMetricsClient, MetricQueryOptions as CloudWatchMetricsQueryOptions,
+    MetricQueryResult as CloudWatchMetricsQueryResult,
+};
+
 pub use cloudtrail_events::{
     CloudTrailEventsClient, LookupOptions as CloudTrailLookupOptions,
     LookupResult as CloudTrailLookupResult,
 };
+
+pub use config_history::{
+    AwsConfigClient, ConfigHistoryOptions, ConfigHistoryResult, ConfigurationItem,
+};
+
+pub use cost_explorer::{CostDataResult, CostExplorerClient, CostQueryOptions};
+
+pub use dynamodb_items::{DynamoDbItemsClient, ItemReadOptions};
+
+pub use eventbridge_events::{EventBridgeEventsClient, TestEvent};
+
+pub use health_events::{HealthEvent, HealthEventsClient};
+
+pub use iam_simulation::{IamSimulationClient, SimulationOptions, SimulationResult};
+
+pub use kms_key_usage::{KeyUsageDetails, KmsKeyUsageClient};
+
+pub use lambda_invoke::{InvokeRequest, LambdaInvokeClient};
+
+pub use recommendations::{Recommendation, RecommendationsClient};
+
+pub use s3_bucket_analytics::{BucketAnalytics, S3BucketAnalyticsClient};
+
+pub use s3_objects::{ListObjectsOptions, S3ObjectsClient};
+
+pub use service_quotas::{QuotaStatus, ServiceQuotasClient};
+
+pub use sqs_messages::{QueueDetails, SqsMessagesClient};
+
+pub use ssm_session::{SessionAuditLog, SsmSessionClient};
+
+pub use step_functions::{ExecutionHistory, ExecutionListOptions, StepFunctionsClient};
@@ -0,0 +1,301 @@
+//! Recommendations Client Wrapper
+//!
+//! Reads EC2 instance and Lambda memory findings from Compute Optimizer and
+//! flagged resources from Trusted Advisor checks, with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Context, Result};
+use aws_sdk_computeoptimizer as computeoptimizer;
+use aws_sdk_computeoptimizer::error::ProvideErrorMetadata;
+use aws_sdk_trustedadvisor as trustedadvisor;
+use std::sync::Arc;
+
+use crate::app::aws_regions::active_partition;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{Recommendation, RecommendationFinding, RecommendationSource};
+
+/// Recommendations client wrapper
+#[derive(Clone)]
+pub struct RecommendationsClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl RecommendationsClient {
+    /// Create a new recommendations client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_config(&self, account_id: &str, region: &str) -> Result<aws_config::SdkConfig> {
+        self.credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })
+    }
+
+    /// Over- and under-provisioned EC2 instances and Lambda functions in one
+    /// account and region; optimized resources are left out
+    ///
+    /// Fails with a readable message when the account has not opted in to
+    /// Compute Optimizer.
+    pub async fn compute_optimizer_recommendations(
+        &self,
+        account_id: &str,
+        region: &str,
+    ) -> Result<Vec<Recommendation>> {
+        let config = self.create_config(account_id, region).await?;
+        let client = computeoptimizer::Client::new(&config);
+
+        let mut recommendations = Vec::new();
+        let mut next_token = None;
+        loop {
+            let response = client
+                .get_ec2_instance_recommendations()
+                .account_ids(account_id)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| compute_optimizer_error(e, "EC2 instance"))?;
+            recommendations.extend(
+                response
+                    .instance_recommendations()
+                    .iter()
+                    .filter_map(|r| convert_instance(r, account_id, region)),
+            );
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        let mut next_token = None;
+        loop {
+            let response = client
+                .get_lambda_function_recommendations()
+                .account_ids(account_id)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| compute_optimizer_error(e, "Lambda function"))?;
+            recommendations.extend(
+                response
+                    .lambda_function_recommendations()
+                    .iter()
+                    .filter_map(|r| convert_lambda(r, account_id, region)),
+            );
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(recommendations)
+    }
+
+    /// Resources flagged by Trusted Advisor checks in one account
+    ///
+    /// Only checks in warning or error status are expanded into their
+    /// resources. The Trusted Advisor API needs a Business, Enterprise
+    /// On-Ramp, or Enterprise support plan.
+    pub async fn trusted_advisor_recommendations(
+        &self,
+        account_id: &str,
+    ) -> Result<Vec<Recommendation>> {
+        // The Trusted Advisor API is served from the partition's global endpoint
+        let config = self
+            .create_config(account_id, active_partition().global_region())
+            .await?;
+        let client = trustedadvisor::Client::new(&config);
+
+        let mut checks = Vec::new();
+        let mut next_token = None;
+        loop {
+            let response = client
+                .list_recommendations()
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| {
+                    let message = e.message().unwrap_or_default().to_string();
+                    match e.code() {
+                        Some("AccessDeniedException") => anyhow!(
+                            "Trusted Advisor is not available ({}); it needs a Business or Enterprise support plan and trustedadvisor:List* permissions",
+                            message
+                        ),
+                        _ => anyhow!(e).context("Failed to list Trusted Advisor recommendations"),
+                    }
+                })?;
+            checks.extend(
+                response
+                    .recommendation_summaries()
+                    .iter()
+                    .filter(|check| check.status().as_str() != "ok")
+                    .map(|check| (check.arn().to_string(), check.name().to_string())),
+            );
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        let mut recommendations = Vec::new();
+        for (check_arn, check_name) in checks {
+            let mut next_token = None;
+            loop {
+                let response = client
+                    .list_recommendation_resources()
+                    .recommendation_identifier(&check_arn)
+                    .set_next_token(next_token)
+                    .send()
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Failed to list resources of Trusted Advisor check {}",
+                            check_name
+                        )
+                    })?;
+                for resource in response.recommendation_resource_summaries() {
+                    let finding = match resource.status().as_str() {
+                        "warning" => RecommendationFinding::Warning,
+                        "error" => RecommendationFinding::Error,
+                        _ => continue,
+                    };
+                    recommendations.push(Recommendation {
+                        source: RecommendationSource::TrustedAdvisor,
+                        account_id: account_id.to_string(),
+                        region: resource.region_code().to_string(),
+                        resource_type: None,
+                        resource: resource.aws_resource_id().to_string(),
+                        finding,
+                        title: check_name.clone(),
+                        current: None,
+                        recommended: None,
+                        estimated_monthly_savings: None,
+                        currency: None,
+                    });
+                }
+                next_token = response.next_token().map(String::from);
+                if next_token.is_none() {
+                    break;
+                }
+            }
+        }
+
+        Ok(recommendations)
+    }
+}
+
+fn compute_optimizer_error<E>(error: E, resource_kind: &str) -> anyhow::Error
+where
+    E: ProvideErrorMetadata + std::error::Error + Send + Sync + 'static,
+{
+    match error.code() {
+        Some("OptInRequiredException") => {
+            anyhow!("Compute Optimizer is not enabled for this account")
+        }
+        _ => anyhow!(error).context(format!(
+            "Failed to get Compute Optimizer {} recommendations",
+            resource_kind
+        )),
+    }
+}
+
+/// Savings of the top-ranked option
+fn savings(
+    opportunity: Option<&computeoptimizer::types::SavingsOpportunity>,
+) -> (Option<f64>, Option<String>) {
+    match opportunity.and_then(|o| o.estimated_monthly_savings()) {
+        Some(estimate) => (
+            Some(estimate.value()),
+            estimate.currency().map(|c| c.as_str().to_string()),
+        ),
+        None => (None, None),
+    }
+}
+
+fn convert_instance(
+    recommendation: &computeoptimizer::types::InstanceRecommendation,
+    account_id: &str,
+    region: &str,
+) -> Option<Recommendation> {
+    let finding = match recommendation.finding()?.as_str() {
+        "Overprovisioned" => RecommendationFinding::OverProvisioned,
+        "Underprovisioned" => RecommendationFinding::UnderProvisioned,
+        "NotOptimized" => RecommendationFinding::NotOptimized,
+        _ => return None,
+    };
+    let best = recommendation
+        .recommendation_options()
+        .iter()
+        .min_by_key(|option| option.rank());
+    let (estimated_monthly_savings, currency) =
+        savings(best.and_then(|option| option.savings_opportunity()));
+
+    Some(Recommendation {
+        source: RecommendationSource::ComputeOptimizer,
+        account_id: account_id.to_string(),
+        region: region.to_string(),
+        resource_type: Some("AWS::EC2::Instance".to_string()),
+        resource: recommendation.instance_arn()?.to_string(),
+        finding,
+        title: "EC2 instance type".to_string(),
+        current: recommendation.current_instance_type().map(String::from),
+        recommended: best
+            .and_then(|option| option.instance_type())
+            .map(String::from),
+        estimated_monthly_savings,
+        currency,
+    })
+}
+
+fn convert_lambda(
+    recommendation: &computeoptimizer::types::LambdaFunctionRecommendation,
+    account_id: &str,
+    region: &str,
+) -> Option<Recommendation> {
+    if recommendation.finding()?.as_str() != "NotOptimized" {
+        return None;
+    }
+    let reasons: Vec<&str> = recommendation
+        .finding_reason_codes()
+        .iter()
+        .map(|reason| reason.as_str())
+        .collect();
+    let finding = match (
+        reasons.contains(&"MemoryOverprovisioned"),
+        reasons.contains(&"MemoryUnderprovisioned"),
+    ) {
+        (true, false) => RecommendationFinding::OverProvisioned,
+        (false, true) => RecommendationFinding::UnderProvisioned,
+        _ => RecommendationFinding::NotOptimized,
+    };
+    let best = recommendation
+        .memory_size_recommendation_options()
+        .iter()
+        .min_by_key(|option| option.rank());
+    let (estimated_monthly_savings, currency) =
+        savings(best.and_then(|option| option.savings_opportunity()));
+
+    Some(Recommendation {
+        source: RecommendationSource::ComputeOptimizer,
+        account_id: account_id.to_string(),
+        region: region.to_string(),
+        resource_type: Some("AWS::Lambda::Function".to_string()),
+        resource: recommendation.function_arn()?.to_string(),
+        finding,
+        title: "Lambda memory size".to_string(),
+        current: Some(format!("{} MB", recommendation.current_memory_size())),
+        recommended: best.map(|option| format!("{} MB", option.memory_size())),
+        estimated_monthly_savings,
+        currency,
+    })
+}
@@ -0,0 +1,59 @@
+//! Recommendations Integration Module
+//!
+//! Backs the recommendations report and Explorer badges: rightsizing findings
+//! from Compute Optimizer and flagged resources from Trusted Advisor checks.
+//!
+//! ## Features
+//!
+//! - Over- and under-provisioned EC2 instances with the top-ranked instance
+//!   type and its estimated monthly savings
+//! - Lambda functions with over- or under-provisioned memory and the
+//!   recommended memory size
+//! - Resources flagged by Trusted Advisor checks (warning or error status)
+//! - Readable errors for accounts not opted in to Compute Optimizer or
+//!   without a support plan that includes Trusted Advisor
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::recommendations::{total_monthly_savings, RecommendationsClient};
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = RecommendationsClient::new(credential_coordinator);
+//! let mut recommendations = client
+//!     .compute_optimizer_recommendations("123456789012", "us-east-1")
+//!     .await?;
+//! recommendations.extend(client.trusted_advisor_recommendations("123456789012").await?);
+//!
+//! for recommendation in &recommendations {
+//!     println!("{} {}", recommendation.resource, recommendation.badge_text());
+//! }
+//! println!("Estimated savings: {:.2}/month", total_monthly_savings(&recommendations));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! - Compute Optimizer opted in for the account (or the organization); it
+//!   needs about 14 days of metrics before producing findings
+//! - A Business, Enterprise On-Ramp, or Enterprise support plan for Trusted
+//!   Advisor
+//! - The role needs `compute-optimizer:GetEC2InstanceRecommendations`,
+//!   `compute-optimizer:GetLambdaFunctionRecommendations`,
+//!   `trustedadvisor:ListRecommendations`, and
+//!   `trustedadvisor:ListRecommendationResources`
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::RecommendationsClient;
+pub use types::{
+    total_monthly_savings, Recommendation, RecommendationFinding, RecommendationSource,
+    UnavailableSource,
+};
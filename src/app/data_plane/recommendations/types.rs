@@ -0,0 +1,153 @@
+//! Recommendation Data Types
+//!
+//! Rightsizing findings from Compute Optimizer and flagged resources from
+//! Trusted Advisor checks, in one shape for the report and the Explorer badges.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+
+/// Service a recommendation came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecommendationSource {
+    ComputeOptimizer,
+    TrustedAdvisor,
+}
+
+impl RecommendationSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::ComputeOptimizer => "Compute Optimizer",
+            Self::TrustedAdvisor => "Trusted Advisor",
+        }
+    }
+}
+
+/// What is wrong with the resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecommendationFinding {
+    OverProvisioned,
+    UnderProvisioned,
+    /// Compute Optimizer found both over- and under-provisioned dimensions
+    NotOptimized,
+    /// Trusted Advisor check status "warning"
+    Warning,
+    /// Trusted Advisor check status "error"
+    Error,
+}
+
+impl RecommendationFinding {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::OverProvisioned => "Over-provisioned",
+            Self::UnderProvisioned => "Under-provisioned",
+            Self::NotOptimized => "Not optimized",
+            Self::Warning => "Warning",
+            Self::Error => "Action recommended",
+        }
+    }
+
+    /// Under-provisioned resources and Trusted Advisor errors come first
+    pub fn severity(self) -> u8 {
+        match self {
+            Self::Error | Self::UnderProvisioned => 2,
+            Self::NotOptimized | Self::Warning => 1,
+            Self::OverProvisioned => 0,
+        }
+    }
+}
+
+/// One recommendation for one resource
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Recommendation {
+    pub source: RecommendationSource,
+    pub account_id: String,
+    pub region: String,
+    /// CloudFormation type when known (Compute Optimizer results)
+    pub resource_type: Option<String>,
+    /// Resource ARN or ID as reported by the service
+    pub resource: String,
+    pub finding: RecommendationFinding,
+    /// Compute Optimizer resource kind or Trusted Advisor check name
+    pub title: String,
+    /// Current configuration, e.g. "m5.xlarge" or "1024 MB"
+    pub current: Option<String>,
+    /// Top-ranked option, e.g. "m5.large" or "512 MB"
+    pub recommended: Option<String>,
+    pub estimated_monthly_savings: Option<f64>,
+    pub currency: Option<String>,
+}
+
+impl Recommendation {
+    /// Short text for Explorer badges, e.g. "Over-provisioned: m5.xlarge -> m5.large"
+    pub fn badge_text(&self) -> String {
+        match (&self.current, &self.recommended) {
+            (Some(current), Some(recommended)) => {
+                format!("{}: {} -> {}", self.finding.label(), current, recommended)
+            }
+            _ => format!("{}: {}", self.finding.label(), self.title),
+        }
+    }
+}
+
+/// Accounts or regions a source could not be read for (not opted in,
+/// no support plan, missing permissions)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnavailableSource {
+    pub source: RecommendationSource,
+    pub account_id: String,
+    pub region: Option<String>,
+    pub message: String,
+}
+
+/// Estimated monthly savings of the recommendations that report them
+pub fn total_monthly_savings(recommendations: &[Recommendation]) -> f64 {
+    recommendations
+        .iter()
+        .filter_map(|recommendation| recommendation.estimated_monthly_savings)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recommendation(current: Option<&str>, savings: Option<f64>) -> Recommendation {
+        Recommendation {
+            source: RecommendationSource::ComputeOptimizer,
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_type: Some("AWS::EC2::Instance".to_string()),
+            resource: "arn:aws:ec2:us-east-1:123456789012:instance/i-0abc".to_string(),
+            finding: RecommendationFinding::OverProvisioned,
+            title: "EC2 instance".to_string(),
+            current: current.map(String::from),
+            recommended: current.map(|_| "m5.large".to_string()),
+            estimated_monthly_savings: savings,
+            currency: Some("USD".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_badge_text_and_savings() {
+        assert_eq!(
+            recommendation(Some("m5.xlarge"), None).badge_text(),
+            "Over-provisioned: m5.xlarge -> m5.large"
+        );
+        assert_eq!(
+            recommendation(None, None).badge_text(),
+            "Over-provisioned: EC2 instance"
+        );
+
+        let recommendations = [
+            recommendation(None, Some(12.5)),
+            recommendation(None, None),
+            recommendation(None, Some(7.5)),
+        ];
+        assert_eq!(total_monthly_savings(&recommendations), 20.0);
+    }
+}
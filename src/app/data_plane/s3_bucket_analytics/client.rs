@@ -0,0 +1,385 @@
+//! S3 Bucket Analytics Client Wrapper
+//!
+//! Reads a bucket's daily CloudWatch storage metrics, lifecycle rules,
+//! replication configuration, and public access settings, with credential
+//! management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_s3 as s3;
+use aws_sdk_s3control as s3control;
+use std::sync::Arc;
+
+use crate::app::data_plane::cloudwatch_metrics::{
+    CloudWatchMetricsClient, MetricQuery, MetricQueryOptions,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{
+    BucketAnalytics, LifecycleRule, PublicAccessBlock, PublicAccessStatus, ReplicationConfig,
+    ReplicationRule, StorageMetrics, STORAGE_LOOKBACK_DAYS, STORAGE_TYPES,
+};
+
+/// Storage metrics are published once a day
+const STORAGE_METRIC_PERIOD_SECONDS: i32 = 86_400;
+
+/// S3 bucket analytics client wrapper
+#[derive(Clone)]
+pub struct S3BucketAnalyticsClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl S3BucketAnalyticsClient {
+    /// Create a new S3 bucket analytics client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_config(&self, account_id: &str, region: &str) -> Result<aws_config::SdkConfig> {
+        self.credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })
+    }
+
+    /// Storage metrics, lifecycle rules, replication, and public access of a
+    /// bucket in its own region
+    ///
+    /// Each part is read independently; parts that cannot be read are left
+    /// empty with a warning.
+    pub async fn get_bucket_analytics(
+        &self,
+        account_id: &str,
+        region: &str,
+        bucket: &str,
+    ) -> Result<BucketAnalytics> {
+        let config = self.create_config(account_id, region).await?;
+        let client = s3::Client::new(&config);
+
+        let mut analytics = BucketAnalytics {
+            bucket: bucket.to_string(),
+            region: region.to_string(),
+            ..Default::default()
+        };
+
+        match self.get_storage_metrics(account_id, region, bucket).await {
+            Ok(storage) => analytics.storage = storage,
+            Err(e) => analytics
+                .warnings
+                .push(format!("Storage metrics unavailable: {:#}", e)),
+        }
+
+        match client
+            .get_bucket_lifecycle_configuration()
+            .bucket(bucket)
+            .send()
+            .await
+        {
+            Ok(response) => {
+                analytics.lifecycle_rules = response.rules().iter().map(lifecycle_rule).collect();
+            }
+            Err(e) if format!("{:?}", e).contains("NoSuchLifecycleConfiguration") => {}
+            Err(e) => analytics
+                .warnings
+                .push(format!("Lifecycle rules unavailable: {}", e)),
+        }
+
+        match client.get_bucket_replication().bucket(bucket).send().await {
+            Ok(response) => {
+                analytics.replication =
+                    response
+                        .replication_configuration()
+                        .map(|configuration| ReplicationConfig {
+                            role: configuration.role().to_string(),
+                            rules: configuration.rules().iter().map(replication_rule).collect(),
+                        });
+            }
+            Err(e) if format!("{:?}", e).contains("ReplicationConfigurationNotFoundError") => {}
+            Err(e) => analytics
+                .warnings
+                .push(format!("Replication configuration unavailable: {}", e)),
+        }
+
+        analytics.public_access = self
+            .get_public_access(
+                &client,
+                &config,
+                account_id,
+                bucket,
+                &mut analytics.warnings,
+            )
+            .await;
+
+        Ok(analytics)
+    }
+
+    /// Latest `BucketSizeBytes` per storage class and `NumberOfObjects`
+    pub async fn get_storage_metrics(
+        &self,
+        account_id: &str,
+        region: &str,
+        bucket: &str,
+    ) -> Result<StorageMetrics> {
+        let mut queries: Vec<MetricQuery> = STORAGE_TYPES
+            .iter()
+            .map(|(storage_type, _)| {
+                MetricQuery::new("AWS/S3", "BucketSizeBytes")
+                    .with_dimension("BucketName", bucket)
+                    .with_dimension("StorageType", *storage_type)
+            })
+            .collect();
+        queries.push(
+            MetricQuery::new("AWS/S3", "NumberOfObjects")
+                .with_dimension("BucketName", bucket)
+                .with_dimension("StorageType", "AllStorageTypes"),
+        );
+
+        let end_time = chrono::Utc::now();
+        let start_time = end_time - chrono::Duration::days(STORAGE_LOOKBACK_DAYS);
+        let options = MetricQueryOptions::new()
+            .with_start_time(start_time.timestamp_millis())
+            .with_end_time(end_time.timestamp_millis())
+            .with_period(STORAGE_METRIC_PERIOD_SECONDS);
+
+        let result = CloudWatchMetricsClient::new(Arc::clone(&self.credential_coordinator))
+            .get_metric_data(account_id, region, &queries, options)
+            .await?;
+        Ok(StorageMetrics::from_series(&result.series))
+    }
+
+    async fn get_public_access(
+        &self,
+        client: &s3::Client,
+        config: &aws_config::SdkConfig,
+        account_id: &str,
+        bucket: &str,
+        warnings: &mut Vec<String>,
+    ) -> PublicAccessStatus {
+        let mut status = PublicAccessStatus::default();
+
+        match client.get_public_access_block().bucket(bucket).send().await {
+            Ok(response) => {
+                status.bucket_block =
+                    response
+                        .public_access_block_configuration()
+                        .map(|c| PublicAccessBlock {
+                            block_public_acls: c.block_public_acls().unwrap_or(false),
+                            ignore_public_acls: c.ignore_public_acls().unwrap_or(false),
+                            block_public_policy: c.block_public_policy().unwrap_or(false),
+                            restrict_public_buckets: c.restrict_public_buckets().unwrap_or(false),
+                        });
+            }
+            Err(e) if format!("{:?}", e).contains("NoSuchPublicAccessBlockConfiguration") => {}
+            Err(e) => warnings.push(format!("Bucket Block Public Access unavailable: {}", e)),
+        }
+
+        let control = s3control::Client::new(config);
+        match control
+            .get_public_access_block()
+            .account_id(account_id)
+            .send()
+            .await
+        {
+            Ok(response) => {
+                status.account_block =
+                    response
+                        .public_access_block_configuration()
+                        .map(|c| PublicAccessBlock {
+                            block_public_acls: c.block_public_acls().unwrap_or(false),
+                            ignore_public_acls: c.ignore_public_acls().unwrap_or(false),
+                            block_public_policy: c.block_public_policy().unwrap_or(false),
+                            restrict_public_buckets: c.restrict_public_buckets().unwrap_or(false),
+                        });
+            }
+            Err(e) if format!("{:?}", e).contains("NoSuchPublicAccessBlockConfiguration") => {}
+            Err(e) => warnings.push(format!("Account Block Public Access unavailable: {}", e)),
+        }
+
+        match client
+            .get_bucket_policy_status()
+            .bucket(bucket)
+            .send()
+            .await
+        {
+            Ok(response) => {
+                status.policy_is_public = response
+                    .policy_status()
+                    .and_then(|policy_status| policy_status.is_public());
+            }
+            // No bucket policy means nothing public through a policy
+            Err(e) if format!("{:?}", e).contains("NoSuchBucketPolicy") => {
+                status.policy_is_public = Some(false);
+            }
+            Err(e) => warnings.push(format!("Bucket policy status unavailable: {}", e)),
+        }
+
+        match client.get_bucket_acl().bucket(bucket).send().await {
+            Ok(response) => {
+                status.public_acl_grants = response
+                    .grants()
+                    .iter()
+                    .filter_map(|grant| {
+                        let uri = grant.grantee().and_then(|grantee| grantee.uri())?;
+                        let permission = grant.permission()?.as_str();
+                        PublicAccessStatus::public_grant(uri, permission)
+                    })
+                    .collect();
+            }
+            Err(e) => warnings.push(format!("Bucket ACL unavailable: {}", e)),
+        }
+
+        status
+    }
+}
+
+/// Objects a lifecycle or replication rule applies to
+fn filter_scope(prefix: Option<&str>, tags: &[(String, String)]) -> String {
+    let mut parts = Vec::new();
+    if let Some(prefix) = prefix.filter(|p| !p.is_empty()) {
+        parts.push(format!("prefix {}", prefix));
+    }
+    parts.extend(
+        tags.iter()
+            .map(|(key, value)| format!("tag {}={}", key, value)),
+    );
+    if parts.is_empty() {
+        "whole bucket".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+fn lifecycle_rule(rule: &s3::types::LifecycleRule) -> LifecycleRule {
+    let (prefix, tags) = match rule.filter() {
+        Some(filter) => {
+            let mut tags: Vec<(String, String)> = filter
+                .tag()
+                .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+                .into_iter()
+                .collect();
+            let mut prefix = filter.prefix().map(String::from);
+            if let Some(and) = filter.and() {
+                prefix = prefix.or_else(|| and.prefix().map(String::from));
+                tags.extend(
+                    and.tags()
+                        .iter()
+                        .map(|tag| (tag.key().to_string(), tag.value().to_string())),
+                );
+            }
+            (prefix, tags)
+        }
+        #[allow(deprecated)]
+        None => (rule.prefix().map(String::from), Vec::new()),
+    };
+
+    let mut actions = Vec::new();
+    for transition in rule.transitions() {
+        let class = transition
+            .storage_class()
+            .map(|class| class.as_str().to_string())
+            .unwrap_or_else(|| "?".to_string());
+        match (transition.days(), transition.date()) {
+            (Some(days), _) => actions.push(format!("Transition to {} after {} days", class, days)),
+            (None, Some(date)) => actions.push(format!("Transition to {} on {}", class, date)),
+            _ => actions.push(format!("Transition to {}", class)),
+        }
+    }
+    if let Some(expiration) = rule.expiration() {
+        if let Some(days) = expiration.days() {
+            actions.push(format!("Expire after {} days", days));
+        } else if let Some(date) = expiration.date() {
+            actions.push(format!("Expire on {}", date));
+        }
+        if expiration.expired_object_delete_marker() == Some(true) {
+            actions.push("Remove expired delete markers".to_string());
+        }
+    }
+    for transition in rule.noncurrent_version_transitions() {
+        if let (Some(days), Some(class)) =
+            (transition.noncurrent_days(), transition.storage_class())
+        {
+            actions.push(format!(
+                "Transition noncurrent versions to {} after {} days",
+                class.as_str(),
+                days
+            ));
+        }
+    }
+    if let Some(days) = rule
+        .noncurrent_version_expiration()
+        .and_then(|expiration| expiration.noncurrent_days())
+    {
+        actions.push(format!("Delete noncurrent versions after {} days", days));
+    }
+    if let Some(days) = rule
+        .abort_incomplete_multipart_upload()
+        .and_then(|abort| abort.days_after_initiation())
+    {
+        actions.push(format!(
+            "Abort incomplete multipart uploads after {} days",
+            days
+        ));
+    }
+
+    LifecycleRule {
+        id: rule.id().map(String::from),
+        status: rule.status().as_str().to_string(),
+        scope: filter_scope(prefix.as_deref(), &tags),
+        actions,
+    }
+}
+
+fn replication_rule(rule: &s3::types::ReplicationRule) -> ReplicationRule {
+    let (prefix, tags) = match rule.filter() {
+        Some(filter) => {
+            let mut tags: Vec<(String, String)> = filter
+                .tag()
+                .map(|tag| (tag.key().to_string(), tag.value().to_string()))
+                .into_iter()
+                .collect();
+            let mut prefix = filter.prefix().map(String::from);
+            if let Some(and) = filter.and() {
+                prefix = prefix.or_else(|| and.prefix().map(String::from));
+                tags.extend(
+                    and.tags()
+                        .iter()
+                        .map(|tag| (tag.key().to_string(), tag.value().to_string())),
+                );
+            }
+            (prefix, tags)
+        }
+        #[allow(deprecated)]
+        None => (rule.prefix().map(String::from), Vec::new()),
+    };
+
+    let destination = rule.destination();
+    ReplicationRule {
+        id: rule.id().map(String::from),
+        status: rule.status().as_str().to_string(),
+        priority: rule.priority(),
+        scope: filter_scope(prefix.as_deref(), &tags),
+        destination_bucket: destination
+            .map(|d| d.bucket().to_string())
+            .unwrap_or_default(),
+        destination_account: destination.and_then(|d| d.account().map(String::from)),
+        storage_class: destination
+            .and_then(|d| d.storage_class())
+            .map(|class| class.as_str().to_string()),
+        replicate_delete_markers: rule
+            .delete_marker_replication()
+            .and_then(|replication| replication.status())
+            .is_some_and(|status| status.as_str() == "Enabled"),
+        replica_kms_key: destination
+            .and_then(|d| d.encryption_configuration())
+            .and_then(|encryption| encryption.replica_kms_key_id())
+            .map(String::from),
+    }
+}
@@ -0,0 +1,60 @@
+//! S3 Bucket Analytics Integration Module
+//!
+//! Backs the S3 bucket storage window: how much a bucket holds, what happens
+//! to its objects over time, and whether it is open to the public.
+//!
+//! ## Features
+//!
+//! - Bucket size per storage class and object count from the daily
+//!   CloudWatch storage metrics, with the change over the last two weeks
+//! - Lifecycle rules with their scope and transition/expiration actions
+//! - Replication rules with destination bucket, account, and storage class
+//! - Block Public Access settings of the bucket and the account, combined
+//!   with the bucket policy status and ACL grants to flag publicly readable
+//!   buckets
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::s3_bucket_analytics::{format_bytes, S3BucketAnalyticsClient};
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = S3BucketAnalyticsClient::new(credential_coordinator);
+//! let analytics = client
+//!     .get_bucket_analytics("123456789012", "us-east-1", "my-bucket")
+//!     .await?;
+//! println!(
+//!     "{} in {:?} objects",
+//!     format_bytes(analytics.storage.total_bytes),
+//!     analytics.storage.object_count
+//! );
+//! for reason in analytics.public_access.public_read_reasons() {
+//!     println!("Public: {}", reason);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `cloudwatch:GetMetricData`, `s3:GetLifecycleConfiguration`,
+//! `s3:GetReplicationConfiguration`, `s3:GetBucketPublicAccessBlock`,
+//! `s3:GetBucketPolicyStatus`, and `s3:GetBucketAcl` on the bucket, and
+//! `s3:GetAccountPublicAccessBlock` for the account settings. S3 publishes
+//! storage metrics once a day, so a new bucket shows no size until the next
+//! day.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::S3BucketAnalyticsClient;
+pub use types::{
+    format_bytes, BucketAnalytics, LifecycleRule, PublicAccessBlock, PublicAccessStatus,
+    ReplicationConfig, ReplicationRule, StorageClassSize, StorageMetrics, STORAGE_LOOKBACK_DAYS,
+    STORAGE_TYPES,
+};
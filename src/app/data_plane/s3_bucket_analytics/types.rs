@@ -0,0 +1,361 @@
+//! S3 Bucket Analytics Data Types
+//!
+//! Storage size and object counts from the daily CloudWatch storage metrics,
+//! lifecycle rules, replication configuration, and public access settings of
+//! one bucket.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use crate::app::data_plane::cloudwatch_metrics::MetricSeries;
+use serde::{Deserialize, Serialize};
+
+/// `StorageType` dimension values of the `BucketSizeBytes` metric, with
+/// display names
+pub const STORAGE_TYPES: &[(&str, &str)] = &[
+    ("StandardStorage", "Standard"),
+    (
+        "IntelligentTieringFAStorage",
+        "Intelligent-Tiering Frequent Access",
+    ),
+    (
+        "IntelligentTieringIAStorage",
+        "Intelligent-Tiering Infrequent Access",
+    ),
+    (
+        "IntelligentTieringAIAStorage",
+        "Intelligent-Tiering Archive Instant",
+    ),
+    ("StandardIAStorage", "Standard-IA"),
+    ("OneZoneIAStorage", "One Zone-IA"),
+    ("ReducedRedundancyStorage", "Reduced Redundancy"),
+    (
+        "GlacierInstantRetrievalStorage",
+        "Glacier Instant Retrieval",
+    ),
+    ("GlacierStorage", "Glacier Flexible Retrieval"),
+    ("DeepArchiveStorage", "Glacier Deep Archive"),
+];
+
+/// Storage metrics are published once a day; two weeks of history shows the trend
+pub const STORAGE_LOOKBACK_DAYS: i64 = 14;
+
+/// Grantee URIs that make an ACL grant public
+const PUBLIC_GRANTEES: &[(&str, &str)] = &[
+    (
+        "http://acs.amazonaws.com/groups/global/AllUsers",
+        "everyone",
+    ),
+    (
+        "http://acs.amazonaws.com/groups/global/AuthenticatedUsers",
+        "any AWS account",
+    ),
+];
+
+/// Bytes stored in one storage class
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StorageClassSize {
+    /// `StorageType` dimension value, e.g. "StandardIAStorage"
+    pub storage_type: String,
+    pub label: String,
+    pub bytes: f64,
+}
+
+/// Latest daily storage metrics of a bucket
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StorageMetrics {
+    /// Storage classes holding data, largest first
+    pub by_storage_class: Vec<StorageClassSize>,
+    pub total_bytes: Option<f64>,
+    pub object_count: Option<f64>,
+    /// Change in total size over the lookback period
+    pub size_change_bytes: Option<f64>,
+    /// Unix millis of the latest datapoint
+    pub measured_at: Option<i64>,
+}
+
+impl StorageMetrics {
+    /// Build from `BucketSizeBytes` series in `STORAGE_TYPES` order followed
+    /// by the `NumberOfObjects` series
+    pub fn from_series(series: &[MetricSeries]) -> Self {
+        let (size_series, count_series) = series.split_at(series.len().min(STORAGE_TYPES.len()));
+
+        let mut by_storage_class: Vec<StorageClassSize> = size_series
+            .iter()
+            .zip(STORAGE_TYPES)
+            .filter_map(|(series, (storage_type, label))| {
+                series
+                    .latest()
+                    .filter(|bytes| *bytes > 0.0)
+                    .map(|bytes| StorageClassSize {
+                        storage_type: storage_type.to_string(),
+                        label: label.to_string(),
+                        bytes,
+                    })
+            })
+            .collect();
+        by_storage_class.sort_by(|a, b| b.bytes.total_cmp(&a.bytes));
+
+        // Total per day across storage classes
+        let mut daily_totals: std::collections::BTreeMap<i64, f64> = Default::default();
+        for point in size_series.iter().flat_map(|series| &series.datapoints) {
+            *daily_totals.entry(point.timestamp).or_default() += point.value;
+        }
+        let first = daily_totals.values().next().copied();
+        let latest = daily_totals.iter().next_back().map(|(t, v)| (*t, *v));
+
+        let object_count = count_series.first().and_then(|series| series.latest());
+        let measured_at = latest.map(|(timestamp, _)| timestamp).or_else(|| {
+            count_series
+                .first()
+                .and_then(|series| series.datapoints.last())
+                .map(|point| point.timestamp)
+        });
+
+        Self {
+            by_storage_class,
+            total_bytes: latest.map(|(_, total)| total),
+            object_count,
+            size_change_bytes: match (first, latest) {
+                (Some(first), Some((_, latest))) if daily_totals.len() > 1 => Some(latest - first),
+                _ => None,
+            },
+            measured_at,
+        }
+    }
+}
+
+/// A lifecycle rule, with its actions described for display
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LifecycleRule {
+    pub id: Option<String>,
+    /// "Enabled" or "Disabled"
+    pub status: String,
+    /// Objects the rule applies to, e.g. "prefix logs/" or "whole bucket"
+    pub scope: String,
+    /// e.g. "Transition to GLACIER after 30 days", "Expire after 365 days"
+    pub actions: Vec<String>,
+}
+
+/// A replication rule
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReplicationRule {
+    pub id: Option<String>,
+    /// "Enabled" or "Disabled"
+    pub status: String,
+    pub priority: Option<i32>,
+    pub scope: String,
+    /// Destination bucket ARN
+    pub destination_bucket: String,
+    pub destination_account: Option<String>,
+    pub storage_class: Option<String>,
+    pub replicate_delete_markers: bool,
+    /// KMS key used to encrypt replicas
+    pub replica_kms_key: Option<String>,
+}
+
+/// Replication configuration of a bucket
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplicationConfig {
+    /// IAM role S3 assumes to replicate
+    pub role: String,
+    pub rules: Vec<ReplicationRule>,
+}
+
+/// The four Block Public Access settings
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PublicAccessBlock {
+    pub block_public_acls: bool,
+    pub ignore_public_acls: bool,
+    pub block_public_policy: bool,
+    pub restrict_public_buckets: bool,
+}
+
+impl PublicAccessBlock {
+    /// Whether every setting is on
+    pub fn all_blocked(&self) -> bool {
+        self.block_public_acls
+            && self.ignore_public_acls
+            && self.block_public_policy
+            && self.restrict_public_buckets
+    }
+
+    /// Settings in effect when both bucket and account settings apply
+    pub fn combine(bucket: Option<Self>, account: Option<Self>) -> Self {
+        let bucket = bucket.unwrap_or_default();
+        let account = account.unwrap_or_default();
+        Self {
+            block_public_acls: bucket.block_public_acls || account.block_public_acls,
+            ignore_public_acls: bucket.ignore_public_acls || account.ignore_public_acls,
+            block_public_policy: bucket.block_public_policy || account.block_public_policy,
+            restrict_public_buckets: bucket.restrict_public_buckets
+                || account.restrict_public_buckets,
+        }
+    }
+}
+
+/// Public access settings and grants of a bucket
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PublicAccessStatus {
+    /// Bucket-level Block Public Access; None when not configured
+    pub bucket_block: Option<PublicAccessBlock>,
+    /// Account-level Block Public Access; None when not configured
+    pub account_block: Option<PublicAccessBlock>,
+    /// Whether S3 evaluates the bucket policy as public; None when unknown
+    pub policy_is_public: Option<bool>,
+    /// ACL grants to everyone or any AWS account, e.g. "READ to everyone"
+    pub public_acl_grants: Vec<String>,
+}
+
+impl PublicAccessStatus {
+    /// Block Public Access settings in effect
+    pub fn effective_block(&self) -> PublicAccessBlock {
+        PublicAccessBlock::combine(self.bucket_block, self.account_block)
+    }
+
+    /// Why the bucket is publicly readable; empty when it is not
+    ///
+    /// A public policy counts unless RestrictPublicBuckets is in effect, and
+    /// public ACL grants count unless IgnorePublicAcls is.
+    pub fn public_read_reasons(&self) -> Vec<String> {
+        let block = self.effective_block();
+        let mut reasons = Vec::new();
+        if self.policy_is_public == Some(true) && !block.restrict_public_buckets {
+            reasons.push("The bucket policy grants public access".to_string());
+        }
+        if !block.ignore_public_acls {
+            reasons.extend(
+                self.public_acl_grants
+                    .iter()
+                    .filter(|grant| {
+                        grant.starts_with("READ ") || grant.starts_with("FULL_CONTROL ")
+                    })
+                    .map(|grant| format!("The bucket ACL grants {}", grant)),
+            );
+        }
+        reasons
+    }
+
+    /// Describe an ACL grant when it is to a public group
+    pub fn public_grant(grantee_uri: &str, permission: &str) -> Option<String> {
+        PUBLIC_GRANTEES
+            .iter()
+            .find(|(uri, _)| *uri == grantee_uri)
+            .map(|(_, who)| format!("{} to {}", permission, who))
+    }
+}
+
+/// Storage, lifecycle, replication, and public access of a bucket
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BucketAnalytics {
+    pub bucket: String,
+    pub region: String,
+    pub storage: StorageMetrics,
+    pub lifecycle_rules: Vec<LifecycleRule>,
+    /// None when replication is not configured
+    pub replication: Option<ReplicationConfig>,
+    pub public_access: PublicAccessStatus,
+    /// Parts that could not be read (e.g. access denied on the ACL)
+    pub warnings: Vec<String>,
+}
+
+/// Bytes as a human-readable size, e.g. "1.5 GiB"
+pub fn format_bytes(bytes: f64) -> String {
+    const UNITS: &[&str] = &["B", "KiB", "MiB", "GiB", "TiB", "PiB"];
+    let mut value = bytes.abs();
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    let sign = if bytes < 0.0 { "-" } else { "" };
+    if unit == 0 {
+        format!("{}{} {}", sign, value as u64, UNITS[unit])
+    } else {
+        format!("{}{:.1} {}", sign, value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::cloudwatch_metrics::{MetricDataPoint, MetricQuery};
+
+    fn series(points: &[(i64, f64)]) -> MetricSeries {
+        let mut series = MetricSeries::from_query(&MetricQuery::new("AWS/S3", "BucketSizeBytes"));
+        series.datapoints = points
+            .iter()
+            .map(|(timestamp, value)| MetricDataPoint {
+                timestamp: *timestamp,
+                value: *value,
+            })
+            .collect();
+        series
+    }
+
+    #[test]
+    fn test_storage_metrics_from_series() {
+        let mut all: Vec<MetricSeries> = STORAGE_TYPES.iter().map(|_| series(&[])).collect();
+        all[0] = series(&[(1, 100.0), (2, 150.0)]);
+        all[4] = series(&[(1, 400.0), (2, 400.0)]);
+        all.push(series(&[(1, 10.0), (2, 12.0)]));
+
+        let metrics = StorageMetrics::from_series(&all);
+        assert_eq!(metrics.total_bytes, Some(550.0));
+        assert_eq!(metrics.size_change_bytes, Some(50.0));
+        assert_eq!(metrics.object_count, Some(12.0));
+        assert_eq!(metrics.measured_at, Some(2));
+        assert_eq!(metrics.by_storage_class.len(), 2);
+        assert_eq!(metrics.by_storage_class[0].label, "Standard-IA");
+
+        let empty = StorageMetrics::from_series(&[]);
+        assert_eq!(empty, StorageMetrics::default());
+    }
+
+    #[test]
+    fn test_public_read_reasons() {
+        let mut status = PublicAccessStatus {
+            policy_is_public: Some(true),
+            public_acl_grants: vec![
+                PublicAccessStatus::public_grant(
+                    "http://acs.amazonaws.com/groups/global/AllUsers",
+                    "READ",
+                )
+                .unwrap(),
+                PublicAccessStatus::public_grant(
+                    "http://acs.amazonaws.com/groups/global/AuthenticatedUsers",
+                    "WRITE_ACP",
+                )
+                .unwrap(),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            status.public_read_reasons(),
+            [
+                "The bucket policy grants public access",
+                "The bucket ACL grants READ to everyone"
+            ]
+        );
+
+        // Account-level settings apply on top of the bucket's
+        status.account_block = Some(PublicAccessBlock {
+            ignore_public_acls: true,
+            restrict_public_buckets: true,
+            ..Default::default()
+        });
+        assert!(status.public_read_reasons().is_empty());
+        assert!(!status.effective_block().all_blocked());
+
+        assert_eq!(
+            PublicAccessStatus::public_grant("arn:aws:iam::123:root", "READ"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512.0), "512 B");
+        assert_eq!(format_bytes(1536.0), "1.5 KiB");
+        assert_eq!(format_bytes(-2.0 * 1024.0 * 1024.0 * 1024.0), "-2.0 GiB");
+    }
+}
@@ -0,0 +1,243 @@
+//! S3 Objects Client Wrapper
+//!
+//! Provides a simplified interface to S3 object listing, metadata, and
+//! downloads with credential management. Downloads use a Range request, so
+//! no more than the requested number of bytes is transferred.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_s3 as s3;
+use aws_sdk_s3::presigning::PresigningConfig;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::debug;
+
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{
+    range_header, ListObjectsOptions, ListObjectsResult, PresignedUrl, S3ObjectBody,
+    S3ObjectMetadata, S3ObjectSummary, DEFAULT_MAX_KEYS,
+};
+
+/// S3 objects client wrapper
+#[derive(Clone)]
+pub struct S3ObjectsClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl S3ObjectsClient {
+    /// Create a new S3 objects client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<s3::Client> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        Ok(s3::Client::new(&aws_config))
+    }
+
+    /// List objects in a bucket, following pagination up to `options.max_keys`
+    pub async fn list_objects(
+        &self,
+        account_id: &str,
+        region: &str,
+        bucket: &str,
+        options: &ListObjectsOptions,
+    ) -> Result<ListObjectsResult> {
+        let client = self.create_client(account_id, region).await?;
+
+        let mut objects = Vec::new();
+        let mut common_prefixes = Vec::new();
+        let mut continuation_token = options.continuation_token.clone();
+        let mut is_truncated;
+
+        loop {
+            let remaining = options
+                .max_keys
+                .saturating_sub(objects.len() + common_prefixes.len());
+            let response = client
+                .list_objects_v2()
+                .bucket(bucket)
+                .set_prefix(options.prefix.clone())
+                .set_delimiter(options.delimiter.clone())
+                .max_keys((remaining as i32).min(DEFAULT_MAX_KEYS))
+                .set_continuation_token(continuation_token.take())
+                .send()
+                .await
+                .with_context(|| format!("Failed to list objects in bucket {}", bucket))?;
+
+            objects.extend(response.contents().iter().map(|object| S3ObjectSummary {
+                key: object.key().unwrap_or_default().to_string(),
+                size: object.size().unwrap_or(0),
+                last_modified: object.last_modified().and_then(|t| t.to_millis().ok()),
+                storage_class: object.storage_class().map(|c| c.as_str().to_string()),
+                etag: object.e_tag().map(str::to_string),
+            }));
+            common_prefixes.extend(
+                response
+                    .common_prefixes()
+                    .iter()
+                    .filter_map(|p| p.prefix().map(str::to_string)),
+            );
+
+            is_truncated = response.is_truncated().unwrap_or(false);
+            continuation_token = response.next_continuation_token().map(str::to_string);
+            if !is_truncated
+                || continuation_token.is_none()
+                || objects.len() + common_prefixes.len() >= options.max_keys
+            {
+                break;
+            }
+        }
+
+        debug!(
+            "Listed {} objects and {} prefixes in s3://{}/{}",
+            objects.len(),
+            common_prefixes.len(),
+            bucket,
+            options.prefix.as_deref().unwrap_or_default()
+        );
+
+        let total_size = objects.iter().map(|o| o.size).sum();
+        Ok(ListObjectsResult {
+            bucket: bucket.to_string(),
+            prefix: options.prefix.clone(),
+            objects,
+            common_prefixes,
+            is_truncated,
+            next_continuation_token: if is_truncated {
+                continuation_token
+            } else {
+                None
+            },
+            total_size,
+        })
+    }
+
+    /// Get object metadata without downloading the body
+    pub async fn head_object(
+        &self,
+        account_id: &str,
+        region: &str,
+        bucket: &str,
+        key: &str,
+    ) -> Result<S3ObjectMetadata> {
+        let client = self.create_client(account_id, region).await?;
+        let response = client
+            .head_object()
+            .bucket(bucket)
+            .key(key)
+            .send()
+            .await
+            .with_context(|| format!("Failed to get metadata of s3://{}/{}", bucket, key))?;
+
+        Ok(S3ObjectMetadata {
+            bucket: bucket.to_string(),
+            key: key.to_string(),
+            size: response.content_length().unwrap_or(0),
+            content_type: response.content_type().map(str::to_string),
+            content_encoding: response.content_encoding().map(str::to_string),
+            last_modified: response.last_modified().and_then(|t| t.to_millis().ok()),
+            etag: response.e_tag().map(str::to_string),
+            storage_class: response.storage_class().map(|c| c.as_str().to_string()),
+            version_id: response.version_id().map(str::to_string),
+            server_side_encryption: response
+                .server_side_encryption()
+                .map(|e| e.as_str().to_string()),
+            kms_key_id: response.ssekms_key_id().map(str::to_string),
+            metadata: response.metadata().cloned().unwrap_or_default(),
+        })
+    }
+
+    /// Read at most `max_bytes` from the start of an object
+    pub async fn get_object(
+        &self,
+        account_id: &str,
+        region: &str,
+        bucket: &str,
+        key: &str,
+        max_bytes: usize,
+    ) -> Result<S3ObjectBody> {
+        let client = self.create_client(account_id, region).await?;
+        let response = client
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .range(range_header(max_bytes))
+            .send()
+            .await
+            .with_context(|| format!("Failed to get s3://{}/{}", bucket, key))?;
+
+        // "bytes 0-1023/52000" holds the full size of a ranged response
+        let size = response
+            .content_range()
+            .and_then(|range| range.rsplit('/').next())
+            .and_then(|total| total.parse::<i64>().ok())
+            .or(response.content_length())
+            .unwrap_or(0);
+        let content_type = response.content_type().map(str::to_string);
+
+        let mut bytes = response
+            .body
+            .collect()
+            .await
+            .with_context(|| format!("Failed to read body of s3://{}/{}", bucket, key))?
+            .into_bytes()
+            .to_vec();
+        // Never return more than asked for, even if the range was ignored
+        bytes.truncate(max_bytes);
+        let truncated = (bytes.len() as i64) < size;
+
+        Ok(S3ObjectBody::from_bytes(
+            bucket,
+            key,
+            size,
+            content_type,
+            &bytes,
+            truncated,
+        ))
+    }
+
+    /// Create a presigned GET URL valid for `expires_in`
+    pub async fn presign_get_object(
+        &self,
+        account_id: &str,
+        region: &str,
+        bucket: &str,
+        key: &str,
+        expires_in: Duration,
+    ) -> Result<PresignedUrl> {
+        let client = self.create_client(account_id, region).await?;
+        let config =
+            PresigningConfig::expires_in(expires_in).context("Invalid presigned URL expiry")?;
+        let request = client
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .presigned(config)
+            .await
+            .with_context(|| format!("Failed to presign s3://{}/{}", bucket, key))?;
+
+        Ok(PresignedUrl {
+            bucket: bucket.to_string(),
+            key: key.to_string(),
+            url: request.uri().to_string(),
+            expires_at: (chrono::Utc::now()
+                + chrono::Duration::from_std(expires_in).unwrap_or_default())
+            .timestamp_millis(),
+        })
+    }
+}
@@ -0,0 +1,56 @@
+//! S3 Objects Integration Module
+//!
+//! Provides read access to the objects inside S3 buckets within the
+//! application, complementing the bucket metadata from the Resource Explorer.
+//!
+//! ## Features
+//!
+//! - List objects by prefix, with delimiter grouping and pagination
+//! - Read object metadata (HeadObject)
+//! - Read small object bodies, as text or base64, with a size limit enforced
+//!   by a Range request (default 256 KB, max 5 MB)
+//! - Generate presigned GET URLs (default 15 minutes, max 7 days)
+//! - Integration with agents (`s3Objects.list()`, `s3Objects.head()`,
+//!   `s3Objects.get()`, `s3Objects.presignUrl()`)
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::s3_objects::{ListObjectsOptions, S3ObjectsClient};
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = S3ObjectsClient::new(credential_coordinator);
+//!
+//! let listing = client.list_objects(
+//!     "123456789012",
+//!     "us-east-1",
+//!     "my-log-bucket",
+//!     &ListObjectsOptions::new().with_prefix("AWSLogs/").with_delimiter("/"),
+//! ).await?;
+//!
+//! for object in &listing.objects {
+//!     println!("{} ({} bytes)", object.key, object.size);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `s3:ListBucket` and `s3:GetObject` on the bucket (plus
+//! `kms:Decrypt` for SSE-KMS objects). Requests must use the bucket's region.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::S3ObjectsClient;
+pub use types::{
+    clamp_body_bytes, clamp_presign_expiry, parse_s3_uri, BodyEncoding, ListObjectsOptions,
+    ListObjectsResult, PresignedUrl, S3ObjectBody, S3ObjectMetadata, S3ObjectSummary,
+    MAX_BODY_BYTES, MAX_LIST_KEYS,
+};
@@ -0,0 +1,287 @@
+//! S3 Object Data Types
+//!
+//! Data structures for listing S3 objects, reading object metadata and
+//! bodies, and presigned URLs, with the size limits applied to downloads.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Default number of keys per list request
+pub const DEFAULT_MAX_KEYS: i32 = 1000;
+
+/// Maximum keys per list call (across pages)
+pub const MAX_LIST_KEYS: usize = 10_000;
+
+/// Default maximum bytes read from an object body
+pub const DEFAULT_MAX_BODY_BYTES: usize = 256 * 1024;
+
+/// Hard limit on bytes read from an object body
+pub const MAX_BODY_BYTES: usize = 5 * 1024 * 1024;
+
+/// Default lifetime of a presigned URL
+pub const DEFAULT_PRESIGN_EXPIRES_SECS: u64 = 900;
+
+/// Maximum lifetime of a presigned URL (SigV4 allows at most 7 days)
+pub const MAX_PRESIGN_EXPIRES_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Options for listing objects in a bucket
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListObjectsOptions {
+    /// Only keys starting with this prefix
+    pub prefix: Option<String>,
+    /// Group keys by this delimiter (usually "/") into common prefixes
+    pub delimiter: Option<String>,
+    /// Maximum keys to return (capped at MAX_LIST_KEYS)
+    pub max_keys: usize,
+    /// Continuation token from a previous truncated listing
+    pub continuation_token: Option<String>,
+}
+
+impl Default for ListObjectsOptions {
+    fn default() -> Self {
+        Self {
+            prefix: None,
+            delimiter: None,
+            max_keys: DEFAULT_MAX_KEYS as usize,
+            continuation_token: None,
+        }
+    }
+}
+
+impl ListObjectsOptions {
+    /// Create default list options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the key prefix
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = Some(prefix.into());
+        self
+    }
+
+    /// Set the delimiter
+    pub fn with_delimiter(mut self, delimiter: impl Into<String>) -> Self {
+        self.delimiter = Some(delimiter.into());
+        self
+    }
+
+    /// Set the maximum number of keys (capped at MAX_LIST_KEYS)
+    pub fn with_max_keys(mut self, max_keys: usize) -> Self {
+        self.max_keys = max_keys.clamp(1, MAX_LIST_KEYS);
+        self
+    }
+
+    /// Continue a previous truncated listing
+    pub fn with_continuation_token(mut self, token: impl Into<String>) -> Self {
+        self.continuation_token = Some(token.into());
+        self
+    }
+}
+
+/// An object in a listing
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3ObjectSummary {
+    pub key: String,
+    /// Size in bytes
+    pub size: i64,
+    /// Last modified time (milliseconds since epoch)
+    pub last_modified: Option<i64>,
+    pub storage_class: Option<String>,
+    pub etag: Option<String>,
+}
+
+/// Result of listing objects
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListObjectsResult {
+    pub bucket: String,
+    pub prefix: Option<String>,
+    pub objects: Vec<S3ObjectSummary>,
+    /// "Folders" when a delimiter is used
+    pub common_prefixes: Vec<String>,
+    /// True when more keys exist; pass `next_continuation_token` to continue
+    pub is_truncated: bool,
+    pub next_continuation_token: Option<String>,
+    /// Total size of the listed objects in bytes
+    pub total_size: i64,
+}
+
+/// Object metadata from HeadObject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3ObjectMetadata {
+    pub bucket: String,
+    pub key: String,
+    /// Size in bytes
+    pub size: i64,
+    pub content_type: Option<String>,
+    pub content_encoding: Option<String>,
+    /// Last modified time (milliseconds since epoch)
+    pub last_modified: Option<i64>,
+    pub etag: Option<String>,
+    pub storage_class: Option<String>,
+    pub version_id: Option<String>,
+    pub server_side_encryption: Option<String>,
+    pub kms_key_id: Option<String>,
+    /// User-defined metadata (x-amz-meta-*)
+    pub metadata: HashMap<String, String>,
+}
+
+/// How an object body is returned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BodyEncoding {
+    /// Body is valid UTF-8 text
+    Utf8,
+    /// Binary body, base64 encoded
+    Base64,
+}
+
+/// (Part of) an object body from GetObject
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3ObjectBody {
+    pub bucket: String,
+    pub key: String,
+    /// Full object size in bytes
+    pub size: i64,
+    pub content_type: Option<String>,
+    pub encoding: BodyEncoding,
+    pub body: String,
+    /// Bytes read from the object
+    pub bytes_read: usize,
+    /// True when the object is larger than the bytes read
+    pub truncated: bool,
+}
+
+impl S3ObjectBody {
+    /// Build a body from the bytes read, as text when they are UTF-8
+    ///
+    /// A truncated read can end in the middle of a UTF-8 character; the
+    /// incomplete tail is dropped rather than switching to base64.
+    pub fn from_bytes(
+        bucket: &str,
+        key: &str,
+        size: i64,
+        content_type: Option<String>,
+        bytes: &[u8],
+        truncated: bool,
+    ) -> Self {
+        let (encoding, body) = match std::str::from_utf8(bytes) {
+            Ok(text) => (BodyEncoding::Utf8, text.to_string()),
+            Err(e) if truncated && e.error_len().is_none() => (
+                BodyEncoding::Utf8,
+                String::from_utf8_lossy(&bytes[..e.valid_up_to()]).into_owned(),
+            ),
+            Err(_) => (
+                BodyEncoding::Base64,
+                aws_smithy_types::base64::encode(bytes),
+            ),
+        };
+        Self {
+            bucket: bucket.to_string(),
+            key: key.to_string(),
+            size,
+            content_type,
+            encoding,
+            body,
+            bytes_read: bytes.len(),
+            truncated,
+        }
+    }
+}
+
+/// A presigned GET URL
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresignedUrl {
+    pub bucket: String,
+    pub key: String,
+    pub url: String,
+    /// Expiry time (milliseconds since epoch)
+    pub expires_at: i64,
+}
+
+/// HTTP Range header reading at most `max_bytes` from the start of an object
+pub fn range_header(max_bytes: usize) -> String {
+    format!("bytes=0-{}", max_bytes.max(1) - 1)
+}
+
+/// Clamp a requested body size to the allowed range
+pub fn clamp_body_bytes(requested: Option<usize>) -> usize {
+    requested
+        .unwrap_or(DEFAULT_MAX_BODY_BYTES)
+        .clamp(1, MAX_BODY_BYTES)
+}
+
+/// Clamp a requested presigned URL lifetime to the allowed range
+pub fn clamp_presign_expiry(requested: Option<u64>) -> u64 {
+    requested
+        .unwrap_or(DEFAULT_PRESIGN_EXPIRES_SECS)
+        .clamp(1, MAX_PRESIGN_EXPIRES_SECS)
+}
+
+/// Split an `s3://bucket/key` URI into bucket and key
+pub fn parse_s3_uri(uri: &str) -> Option<(String, String)> {
+    let rest = uri.strip_prefix("s3://")?;
+    let (bucket, key) = rest.split_once('/').unwrap_or((rest, ""));
+    if bucket.is_empty() {
+        return None;
+    }
+    Some((bucket.to_string(), key.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limits() {
+        assert_eq!(range_header(1024), "bytes=0-1023");
+        assert_eq!(clamp_body_bytes(None), DEFAULT_MAX_BODY_BYTES);
+        assert_eq!(clamp_body_bytes(Some(usize::MAX)), MAX_BODY_BYTES);
+        assert_eq!(clamp_body_bytes(Some(0)), 1);
+        assert_eq!(
+            clamp_presign_expiry(Some(u64::MAX)),
+            MAX_PRESIGN_EXPIRES_SECS
+        );
+        assert_eq!(
+            ListObjectsOptions::new().with_max_keys(1_000_000).max_keys,
+            MAX_LIST_KEYS
+        );
+    }
+
+    #[test]
+    fn test_body_encoding() {
+        let text = S3ObjectBody::from_bytes("b", "k", 5, None, b"hello", false);
+        assert_eq!(text.encoding, BodyEncoding::Utf8);
+        assert_eq!(text.body, "hello");
+
+        // Truncated in the middle of a two-byte character
+        let cut = S3ObjectBody::from_bytes("b", "k", 10, None, &"ab\u{e9}".as_bytes()[..3], true);
+        assert_eq!(cut.encoding, BodyEncoding::Utf8);
+        assert_eq!(cut.body, "ab");
+
+        let binary = S3ObjectBody::from_bytes("b", "k", 3, None, &[0xff, 0x00, 0xfe], false);
+        assert_eq!(binary.encoding, BodyEncoding::Base64);
+        assert_eq!(binary.body, "/wD+");
+    }
+
+    #[test]
+    fn test_parse_s3_uri() {
+        assert_eq!(
+            parse_s3_uri("s3://logs/2024/app.log"),
+            Some(("logs".to_string(), "2024/app.log".to_string()))
+        );
+        assert_eq!(
+            parse_s3_uri("s3://logs"),
+            Some(("logs".to_string(), String::new()))
+        );
+        assert_eq!(parse_s3_uri("https://logs"), None);
+    }
+}
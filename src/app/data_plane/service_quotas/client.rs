@@ -0,0 +1,223 @@
+//! Service Quotas Client Wrapper
+//!
+//! Reads applied quota values from Service Quotas and measures current usage
+//! with the owning service's API or CloudWatch, with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_ec2 as ec2;
+use aws_sdk_servicequotas as servicequotas;
+use std::sync::Arc;
+
+use crate::app::data_plane::cloudwatch_metrics::{
+    CloudWatchMetricsClient, MetricQuery, MetricQueryOptions,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{QuotaDefinition, QuotaStatus, UsageSource, KEY_QUOTAS};
+
+/// Lookback for usage measured from CloudWatch
+const METRIC_USAGE_LOOKBACK_MS: i64 = 60 * 60 * 1000;
+
+/// Service Quotas client wrapper
+#[derive(Clone)]
+pub struct ServiceQuotasClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+/// Quota value as returned by Service Quotas
+struct QuotaValue {
+    value: Option<f64>,
+    is_default: bool,
+    adjustable: bool,
+}
+
+impl ServiceQuotasClient {
+    /// Create a new Service Quotas client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_config(&self, account_id: &str, region: &str) -> Result<aws_config::SdkConfig> {
+        self.credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })
+    }
+
+    /// Limit and usage of every quota in [`KEY_QUOTAS`] for one account and region
+    ///
+    /// A quota whose limit or usage cannot be read is returned with `error`
+    /// set rather than failing the whole check.
+    pub async fn check_quotas(&self, account_id: &str, region: &str) -> Result<Vec<QuotaStatus>> {
+        let config = self.create_config(account_id, region).await?;
+        let quotas = servicequotas::Client::new(&config);
+
+        let mut statuses = Vec::with_capacity(KEY_QUOTAS.len());
+        for definition in KEY_QUOTAS {
+            let mut status = QuotaStatus::new(definition, account_id, region);
+            let mut errors = Vec::new();
+
+            match get_quota_value(&quotas, definition).await {
+                Ok(quota) => {
+                    status.limit = quota.value;
+                    status.is_default_limit = quota.is_default;
+                    status.adjustable = quota.adjustable;
+                }
+                Err(e) => errors.push(format!("{:#}", e)),
+            }
+            match self
+                .measure_usage(&config, account_id, region, definition.usage_source)
+                .await
+            {
+                Ok(usage) => status.usage = Some(usage),
+                Err(e) => errors.push(format!("{:#}", e)),
+            }
+
+            if !errors.is_empty() {
+                log::warn!(
+                    "Quota {} in {}/{}: {}",
+                    definition.quota_code,
+                    account_id,
+                    region,
+                    errors.join("; ")
+                );
+                status.error = Some(errors.join("; "));
+            }
+            statuses.push(status);
+        }
+        Ok(statuses)
+    }
+
+    async fn measure_usage(
+        &self,
+        config: &aws_config::SdkConfig,
+        account_id: &str,
+        region: &str,
+        source: UsageSource,
+    ) -> Result<f64> {
+        match source {
+            UsageSource::VpcCount => {
+                let client = ec2::Client::new(config);
+                let mut count = 0usize;
+                let mut next_token = None;
+                loop {
+                    let response = client
+                        .describe_vpcs()
+                        .set_next_token(next_token)
+                        .send()
+                        .await
+                        .context("Failed to count VPCs")?;
+                    count += response.vpcs().len();
+                    next_token = response.next_token().map(String::from);
+                    if next_token.is_none() {
+                        break;
+                    }
+                }
+                Ok(count as f64)
+            }
+            UsageSource::ElasticIpCount => {
+                let client = ec2::Client::new(config);
+                let response = client
+                    .describe_addresses()
+                    .filters(
+                        ec2::types::Filter::builder()
+                            .name("domain")
+                            .values("vpc")
+                            .build(),
+                    )
+                    .send()
+                    .await
+                    .context("Failed to count Elastic IPs")?;
+                Ok(response.addresses().len() as f64)
+            }
+            UsageSource::LambdaConcurrentExecutions => {
+                let metrics =
+                    CloudWatchMetricsClient::new(Arc::clone(&self.credential_coordinator));
+                let now_ms = chrono::Utc::now().timestamp_millis();
+                let result = metrics
+                    .get_metric_statistics(
+                        account_id,
+                        region,
+                        &MetricQuery::new("AWS/Lambda", "ConcurrentExecutions"),
+                        &["Maximum".to_string()],
+                        MetricQueryOptions::new()
+                            .with_start_time(now_ms - METRIC_USAGE_LOOKBACK_MS)
+                            .with_period(60),
+                    )
+                    .await
+                    .context("Failed to read Lambda concurrent executions")?;
+                // No datapoints means nothing ran in the last hour
+                Ok(result
+                    .datapoints
+                    .iter()
+                    .filter_map(|datapoint| datapoint.value("Maximum"))
+                    .fold(0.0, f64::max))
+            }
+        }
+    }
+}
+
+/// Applied value of a quota, falling back to the AWS default when the account
+/// has no applied value
+async fn get_quota_value(
+    client: &servicequotas::Client,
+    definition: &QuotaDefinition,
+) -> Result<QuotaValue> {
+    let applied = client
+        .get_service_quota()
+        .service_code(definition.service_code)
+        .quota_code(definition.quota_code)
+        .send()
+        .await;
+    match applied {
+        Ok(response) => {
+            let quota = response.quota();
+            return Ok(QuotaValue {
+                value: quota.and_then(|q| q.value()),
+                is_default: false,
+                adjustable: quota.is_some_and(|q| q.adjustable()),
+            });
+        }
+        Err(e)
+            if !e
+                .as_service_error()
+                .is_some_and(|e| e.is_no_such_resource_exception()) =>
+        {
+            return Err(e).with_context(|| {
+                format!(
+                    "Failed to get quota {} {}",
+                    definition.service_code, definition.quota_code
+                )
+            });
+        }
+        Err(_) => {}
+    }
+
+    let response = client
+        .get_aws_default_service_quota()
+        .service_code(definition.service_code)
+        .quota_code(definition.quota_code)
+        .send()
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to get default quota {} {}",
+                definition.service_code, definition.quota_code
+            )
+        })?;
+    let quota = response.quota();
+    Ok(QuotaValue {
+        value: quota.and_then(|q| q.value()),
+        is_default: true,
+        adjustable: quota.is_some_and(|q| q.adjustable()),
+    })
+}
@@ -0,0 +1,52 @@
+//! Service Quotas Integration Module
+//!
+//! Backs the quota dashboard: applied limits and current usage for regional
+//! limits that tend to block deployments when they run out.
+//!
+//! ## Features
+//!
+//! - Applied quota values from Service Quotas, falling back to the AWS
+//!   default when the account has none
+//! - Usage measured directly: VPC and Elastic IP counts from EC2, and peak
+//!   Lambda concurrency over the last hour from CloudWatch
+//! - Utilization against a configurable threshold, with the quotas over it
+//!   flagged most utilized first
+//! - Per-quota errors, so one missing permission does not hide the rest
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::service_quotas::{
+//!     flagged_quotas, ServiceQuotasClient, DEFAULT_UTILIZATION_THRESHOLD,
+//! };
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = ServiceQuotasClient::new(credential_coordinator);
+//! let quotas = client.check_quotas("123456789012", "us-east-1").await?;
+//!
+//! for quota in flagged_quotas(&quotas, DEFAULT_UTILIZATION_THRESHOLD) {
+//!     println!("{}: {:?} of {:?}", quota.name, quota.usage, quota.limit);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `servicequotas:GetServiceQuota`,
+//! `servicequotas:GetAWSDefaultServiceQuota`, `ec2:DescribeVpcs`,
+//! `ec2:DescribeAddresses`, and `cloudwatch:GetMetricStatistics`.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::ServiceQuotasClient;
+pub use types::{
+    flagged_quotas, QuotaDefinition, QuotaStatus, UsageSource, DEFAULT_UTILIZATION_THRESHOLD,
+    KEY_QUOTAS,
+};
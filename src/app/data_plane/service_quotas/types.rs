@@ -0,0 +1,157 @@
+//! Service Quotas Data Types
+//!
+//! The quotas the dashboard watches, where their usage comes from, and the
+//! applied value and usage found for each account and region.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+
+/// Utilization (0.0 to 1.0) above which a quota is flagged by default
+pub const DEFAULT_UTILIZATION_THRESHOLD: f64 = 0.8;
+
+/// How current usage of a quota is measured
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UsageSource {
+    /// Number of VPCs (DescribeVpcs)
+    VpcCount,
+    /// Number of VPC Elastic IP addresses (DescribeAddresses)
+    ElasticIpCount,
+    /// Peak of the `AWS/Lambda` `ConcurrentExecutions` metric over the last hour
+    LambdaConcurrentExecutions,
+}
+
+/// A quota checked by the dashboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuotaDefinition {
+    /// Service Quotas service code (e.g. "vpc")
+    pub service_code: &'static str,
+    /// Service Quotas quota code (e.g. "L-F678F1CE")
+    pub quota_code: &'static str,
+    pub name: &'static str,
+    pub usage_source: UsageSource,
+}
+
+/// Key regional limits that are easy to hit and slow to raise
+pub const KEY_QUOTAS: &[QuotaDefinition] = &[
+    QuotaDefinition {
+        service_code: "vpc",
+        quota_code: "L-F678F1CE",
+        name: "VPCs per Region",
+        usage_source: UsageSource::VpcCount,
+    },
+    QuotaDefinition {
+        service_code: "ec2",
+        quota_code: "L-0263D0A3",
+        name: "EC2-VPC Elastic IPs",
+        usage_source: UsageSource::ElasticIpCount,
+    },
+    QuotaDefinition {
+        service_code: "lambda",
+        quota_code: "L-B99A9384",
+        name: "Lambda concurrent executions",
+        usage_source: UsageSource::LambdaConcurrentExecutions,
+    },
+];
+
+/// Applied value and current usage of one quota in one account and region
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuotaStatus {
+    pub account_id: String,
+    pub region: String,
+    pub service_code: String,
+    pub quota_code: String,
+    pub name: String,
+    /// Value in effect for the account; None when it could not be read
+    pub limit: Option<f64>,
+    /// True when `limit` is the AWS default because no applied value exists
+    pub is_default_limit: bool,
+    pub adjustable: bool,
+    pub usage: Option<f64>,
+    /// Why the limit or usage is missing
+    pub error: Option<String>,
+}
+
+impl QuotaStatus {
+    /// Status for a quota before anything has been read
+    pub fn new(definition: &QuotaDefinition, account_id: &str, region: &str) -> Self {
+        Self {
+            account_id: account_id.to_string(),
+            region: region.to_string(),
+            service_code: definition.service_code.to_string(),
+            quota_code: definition.quota_code.to_string(),
+            name: definition.name.to_string(),
+            limit: None,
+            is_default_limit: false,
+            adjustable: false,
+            usage: None,
+            error: None,
+        }
+    }
+
+    /// Usage as a fraction of the limit
+    pub fn utilization(&self) -> Option<f64> {
+        match (self.usage, self.limit) {
+            (Some(usage), Some(limit)) if limit > 0.0 => Some(usage / limit),
+            _ => None,
+        }
+    }
+
+    /// Whether utilization is at or above `threshold` (0.0 to 1.0)
+    pub fn exceeds(&self, threshold: f64) -> bool {
+        self.utilization()
+            .is_some_and(|utilization| utilization >= threshold)
+    }
+}
+
+/// Quotas over `threshold`, most utilized first
+pub fn flagged_quotas(quotas: &[QuotaStatus], threshold: f64) -> Vec<&QuotaStatus> {
+    let mut flagged: Vec<&QuotaStatus> = quotas.iter().filter(|q| q.exceeds(threshold)).collect();
+    flagged.sort_by(|a, b| {
+        b.utilization()
+            .unwrap_or_default()
+            .total_cmp(&a.utilization().unwrap_or_default())
+    });
+    flagged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(usage: Option<f64>, limit: Option<f64>) -> QuotaStatus {
+        QuotaStatus {
+            usage,
+            limit,
+            ..QuotaStatus::new(&KEY_QUOTAS[0], "123456789012", "us-east-1")
+        }
+    }
+
+    #[test]
+    fn test_utilization() {
+        assert_eq!(status(Some(4.0), Some(5.0)).utilization(), Some(0.8));
+        assert_eq!(status(Some(4.0), None).utilization(), None);
+        assert_eq!(status(None, Some(5.0)).utilization(), None);
+        assert_eq!(status(Some(1.0), Some(0.0)).utilization(), None);
+
+        assert!(status(Some(4.0), Some(5.0)).exceeds(DEFAULT_UTILIZATION_THRESHOLD));
+        assert!(!status(Some(3.0), Some(5.0)).exceeds(DEFAULT_UTILIZATION_THRESHOLD));
+        assert!(!status(None, Some(5.0)).exceeds(0.0));
+    }
+
+    #[test]
+    fn test_flagged_quotas() {
+        let quotas = vec![
+            status(Some(4.0), Some(5.0)),
+            status(Some(1.0), Some(5.0)),
+            status(Some(950.0), Some(1000.0)),
+            status(None, None),
+        ];
+        let flagged = flagged_quotas(&quotas, 0.75);
+        assert_eq!(flagged.len(), 2);
+        assert_eq!(flagged[0].usage, Some(950.0));
+        assert_eq!(flagged[1].usage, Some(4.0));
+    }
+}
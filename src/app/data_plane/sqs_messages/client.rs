@@ -0,0 +1,412 @@
+//! SQS Messages Client Wrapper
+//!
+//! Reads queue attributes and messages, and purges or redrives queues, with
+//! credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{bail, Context, Result};
+use aws_sdk_sqs as sqs;
+use std::collections::{BTreeMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::app::capabilities::ensure_writable;
+use crate::app::data_plane::cloudwatch_metrics::{
+    CloudWatchMetricsClient, MetricQuery, MetricQueryOptions,
+};
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{
+    queue_name_from_url, MoveTaskStatus, PeekResult, PeekedMessage, QueueDetails, RedrivePolicy,
+    MAX_PEEK_COUNT,
+};
+
+/// Lookback for the age of oldest message metric
+const OLDEST_MESSAGE_LOOKBACK_MS: i64 = 15 * 60 * 1000;
+
+/// SQS messages client wrapper
+#[derive(Clone)]
+pub struct SqsMessagesClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl SqsMessagesClient {
+    /// Create a new SQS messages client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<sqs::Client> {
+        let config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+        Ok(sqs::Client::new(&config))
+    }
+
+    /// Queue attributes, the queues that use it as a dead-letter queue, and
+    /// the age of its oldest message
+    pub async fn get_queue_details(
+        &self,
+        account_id: &str,
+        region: &str,
+        queue_url: &str,
+    ) -> Result<QueueDetails> {
+        let client = self.create_client(account_id, region).await?;
+
+        let response = client
+            .get_queue_attributes()
+            .queue_url(queue_url)
+            .attribute_names(sqs::types::QueueAttributeName::All)
+            .send()
+            .await
+            .with_context(|| format!("Failed to get attributes of {}", queue_url))?;
+        let attributes: BTreeMap<String, String> = response
+            .attributes()
+            .map(|attributes| {
+                attributes
+                    .iter()
+                    .map(|(name, value)| (name.as_str().to_string(), value.clone()))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let mut details = QueueDetails::from_attributes(queue_url, attributes);
+
+        let mut next_token = None;
+        loop {
+            let response = client
+                .list_dead_letter_source_queues()
+                .queue_url(queue_url)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .with_context(|| format!("Failed to list dead-letter sources of {}", queue_url))?;
+            details
+                .dead_letter_sources
+                .extend(response.queue_urls().iter().cloned());
+            next_token = response.next_token().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        // SQS has no age attribute; the metric is best effort
+        details.oldest_message_age_seconds = self
+            .oldest_message_age(account_id, region, queue_name_from_url(queue_url))
+            .await
+            .unwrap_or_else(|e| {
+                log::warn!(
+                    "Could not read age of oldest message for {}: {}",
+                    queue_url,
+                    e
+                );
+                None
+            });
+
+        Ok(details)
+    }
+
+    async fn oldest_message_age(
+        &self,
+        account_id: &str,
+        region: &str,
+        queue_name: &str,
+    ) -> Result<Option<f64>> {
+        let metrics = CloudWatchMetricsClient::new(Arc::clone(&self.credential_coordinator));
+        let now_ms = chrono::Utc::now().timestamp_millis();
+        let result = metrics
+            .get_metric_statistics(
+                account_id,
+                region,
+                &MetricQuery::new("AWS/SQS", "ApproximateAgeOfOldestMessage")
+                    .with_dimension("QueueName", queue_name),
+                &["Maximum".to_string()],
+                MetricQueryOptions::new()
+                    .with_start_time(now_ms - OLDEST_MESSAGE_LOOKBACK_MS)
+                    .with_period(60),
+            )
+            .await?;
+        Ok(result
+            .datapoints
+            .last()
+            .and_then(|datapoint| datapoint.value("Maximum")))
+    }
+
+    /// Read up to `max_messages` messages without hiding them from consumers
+    ///
+    /// Messages are received with a visibility timeout of 0, so they stay
+    /// available to consumers. Each receive still counts toward the queue's
+    /// `maxReceiveCount`, which is why a peek is refused in the read-only build
+    /// and makes a single ReceiveMessage call. SQS samples a subset of its
+    /// servers, so a peek may return fewer messages than the queue holds.
+    pub async fn peek_messages(
+        &self,
+        account_id: &str,
+        region: &str,
+        queue_url: &str,
+        max_messages: usize,
+    ) -> Result<PeekResult> {
+        ensure_writable("Peek messages")?;
+        let client = self.create_client(account_id, region).await?;
+        let redrive_policy = redrive_policy(&client, queue_url).await?;
+
+        peek_once(
+            redrive_policy,
+            max_messages,
+            |max_number_of_messages| async move {
+                let response = client
+                    .receive_message()
+                    .queue_url(queue_url)
+                    .max_number_of_messages(max_number_of_messages)
+                    .visibility_timeout(0)
+                    .wait_time_seconds(0)
+                    .message_system_attribute_names(sqs::types::MessageSystemAttributeName::All)
+                    .message_attribute_names("All")
+                    .send()
+                    .await
+                    .with_context(|| format!("Failed to receive messages from {}", queue_url))?;
+                Ok(response.messages().to_vec())
+            },
+        )
+        .await
+    }
+
+    /// Delete every message in a queue
+    ///
+    /// SQS allows one purge per queue every 60 seconds.
+    pub async fn purge_queue(&self, account_id: &str, region: &str, queue_url: &str) -> Result<()> {
+        ensure_writable("Purge queue")?;
+        let client = self.create_client(account_id, region).await?;
+        client
+            .purge_queue()
+            .queue_url(queue_url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to purge {}", queue_url))?;
+        Ok(())
+    }
+
+    /// Move messages from a dead-letter queue back to their source queues
+    ///
+    /// Returns the task handle, which can be used to cancel the move.
+    pub async fn start_redrive(
+        &self,
+        account_id: &str,
+        region: &str,
+        dead_letter_queue_arn: &str,
+    ) -> Result<String> {
+        ensure_writable("Redrive dead-letter queue")?;
+        let client = self.create_client(account_id, region).await?;
+        // Without a destination, each message returns to the queue it came from
+        let response = client
+            .start_message_move_task()
+            .source_arn(dead_letter_queue_arn)
+            .send()
+            .await
+            .with_context(|| format!("Failed to start redrive of {}", dead_letter_queue_arn))?;
+        Ok(response.task_handle().unwrap_or_default().to_string())
+    }
+
+    /// Recent redrive tasks of a dead-letter queue, newest first
+    pub async fn list_move_tasks(
+        &self,
+        account_id: &str,
+        region: &str,
+        dead_letter_queue_arn: &str,
+    ) -> Result<Vec<MoveTaskStatus>> {
+        let client = self.create_client(account_id, region).await?;
+        let response = client
+            .list_message_move_tasks()
+            .source_arn(dead_letter_queue_arn)
+            .max_results(10)
+            .send()
+            .await
+            .with_context(|| {
+                format!("Failed to list redrive tasks of {}", dead_letter_queue_arn)
+            })?;
+        Ok(response
+            .results()
+            .iter()
+            .map(|task| MoveTaskStatus {
+                task_handle: task.task_handle().map(String::from),
+                status: task.status().unwrap_or("UNKNOWN").to_string(),
+                source_arn: task.source_arn().unwrap_or_default().to_string(),
+                destination_arn: task.destination_arn().map(String::from),
+                moved: task.approximate_number_of_messages_moved(),
+                to_move: task.approximate_number_of_messages_to_move(),
+                failure_reason: task.failure_reason().map(String::from),
+                started_timestamp: task.started_timestamp(),
+            })
+            .collect())
+    }
+}
+
+/// The queue's dead-letter settings, read right before a peek
+async fn redrive_policy(client: &sqs::Client, queue_url: &str) -> Result<Option<RedrivePolicy>> {
+    let response = client
+        .get_queue_attributes()
+        .queue_url(queue_url)
+        .attribute_names(sqs::types::QueueAttributeName::RedrivePolicy)
+        .send()
+        .await
+        .with_context(|| format!("Failed to get redrive policy of {}", queue_url))?;
+    Ok(response
+        .attributes()
+        .and_then(|attributes| attributes.get(&sqs::types::QueueAttributeName::RedrivePolicy))
+        .and_then(|policy| RedrivePolicy::parse(policy)))
+}
+
+/// Make the one receive of a peek, unless it would send messages to the
+/// dead-letter queue
+///
+/// `receive` is called with the number of messages to ask for.
+async fn peek_once<F, Fut>(
+    redrive_policy: Option<RedrivePolicy>,
+    max_messages: usize,
+    receive: F,
+) -> Result<PeekResult>
+where
+    F: FnOnce(i32) -> Fut,
+    Fut: Future<Output = Result<Vec<sqs::types::Message>>>,
+{
+    if let Some(policy) = redrive_policy
+        .as_ref()
+        .filter(|policy| policy.peek_dead_letters())
+    {
+        bail!(
+            "Peeking would move the messages to {} on their next receive (maxReceiveCount is {})",
+            policy.dead_letter_target_arn,
+            policy.max_receive_count
+        );
+    }
+    let max_messages = max_messages.clamp(1, MAX_PEEK_COUNT);
+
+    // Without a visibility timeout the same message can come back
+    let mut seen = HashSet::new();
+    let messages = receive(max_messages as i32)
+        .await?
+        .iter()
+        .filter(|message| {
+            message
+                .message_id()
+                .is_some_and(|message_id| seen.insert(message_id.to_string()))
+        })
+        .take(max_messages)
+        .map(convert_message)
+        .collect();
+
+    Ok(PeekResult {
+        messages,
+        redrive_policy,
+    })
+}
+
+fn convert_message(message: &sqs::types::Message) -> PeekedMessage {
+    let attributes: BTreeMap<String, String> = message
+        .attributes()
+        .map(|attributes| {
+            attributes
+                .iter()
+                .map(|(name, value)| (name.as_str().to_string(), value.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let timestamp = |name: &str| attributes.get(name).and_then(|v| v.parse::<i64>().ok());
+
+    PeekedMessage {
+        message_id: message.message_id().unwrap_or_default().to_string(),
+        body: message.body().unwrap_or_default().to_string(),
+        receive_count: attributes
+            .get("ApproximateReceiveCount")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default(),
+        sent_timestamp: timestamp("SentTimestamp"),
+        first_receive_timestamp: timestamp("ApproximateFirstReceiveTimestamp"),
+        message_group_id: attributes.get("MessageGroupId").cloned(),
+        message_attributes: message
+            .message_attributes()
+            .map(|values| {
+                values
+                    .iter()
+                    .filter_map(|(name, value)| {
+                        value.string_value().map(|v| (name.clone(), v.to_string()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default(),
+        attributes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn message(message_id: &str, receive_count: u32) -> sqs::types::Message {
+        sqs::types::Message::builder()
+            .message_id(message_id)
+            .body("{}")
+            .attributes(
+                sqs::types::MessageSystemAttributeName::ApproximateReceiveCount,
+                receive_count.to_string(),
+            )
+            .build()
+    }
+
+    fn policy(max_receive_count: u32) -> RedrivePolicy {
+        RedrivePolicy {
+            dead_letter_target_arn: "arn:aws:sqs:us-east-1:123456789012:orders-dlq".to_string(),
+            max_receive_count,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_peek_receives_once() {
+        let calls = AtomicU32::new(0);
+        let result = peek_once(Some(policy(3)), 5, |max_number_of_messages| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            assert_eq!(max_number_of_messages, 5);
+            async {
+                Ok(vec![
+                    message("m-1", 1),
+                    message("m-2", 3),
+                    message("m-1", 1),
+                ])
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        let ids: Vec<&str> = result
+            .messages
+            .iter()
+            .map(|message| message.message_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["m-1", "m-2"]);
+        assert_eq!(result.messages_at_receive_limit(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_peek_refused_when_it_would_dead_letter() {
+        let calls = AtomicU32::new(0);
+        let result = peek_once(Some(policy(1)), 10, |_| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            async { Ok(Vec::new()) }
+        })
+        .await;
+
+        assert!(result.is_err());
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+}
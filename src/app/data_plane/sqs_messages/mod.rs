@@ -0,0 +1,61 @@
+//! SQS Messages Integration Module
+//!
+//! Backs the queue inspector: queue depth and dead-letter linkage, a look at
+//! waiting messages, and purge or redrive of a queue.
+//!
+//! ## Features
+//!
+//! - Visible, in-flight, and delayed message counts with the age of the oldest
+//!   message (from the `ApproximateAgeOfOldestMessage` metric)
+//! - Dead-letter linkage in both directions: the queue's redrive policy and
+//!   the source queues that send failed messages to it
+//! - Peek: messages received with a visibility timeout of 0, so consumers
+//!   still see them; each peek adds to their receive count
+//! - Purge, and redrive of a dead-letter queue back to its source queues
+//! - Peek, purge, and redrive refused in the read-only build
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::sqs_messages::SqsMessagesClient;
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = SqsMessagesClient::new(credential_coordinator);
+//! let queue_url = "https://sqs.us-east-1.amazonaws.com/123456789012/orders-dlq";
+//!
+//! let details = client
+//!     .get_queue_details("123456789012", "us-east-1", queue_url)
+//!     .await?;
+//! println!("{} visible, sources: {:?}", details.visible_messages, details.dead_letter_sources);
+//!
+//! let peek = client
+//!     .peek_messages("123456789012", "us-east-1", queue_url, 10)
+//!     .await?;
+//! for message in &peek.messages {
+//!     println!("{} (received {}x): {}", message.message_id, message.receive_count, message.body);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `sqs:GetQueueAttributes`, `sqs:ListDeadLetterSourceQueues`,
+//! and `cloudwatch:GetMetricStatistics` to inspect; `sqs:ReceiveMessage` to
+//! peek; `sqs:PurgeQueue` to purge; and `sqs:StartMessageMoveTask` and
+//! `sqs:ListMessageMoveTasks` (plus send and delete on the queues involved)
+//! to redrive.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::SqsMessagesClient;
+pub use types::{
+    queue_name_from_url, MoveTaskStatus, PeekResult, PeekedMessage, QueueDetails, RedrivePolicy,
+    DEFAULT_PEEK_COUNT, MAX_PEEK_COUNT,
+};
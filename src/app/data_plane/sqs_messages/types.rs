@@ -0,0 +1,266 @@
+//! SQS Queue Inspection Data Types
+//!
+//! Queue attributes with dead-letter linkage, messages read with peek
+//! semantics, and the status of dead-letter redrive tasks.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// ReceiveMessage returns at most 10 messages per call
+pub const MAX_MESSAGES_PER_RECEIVE: i32 = 10;
+
+/// Default number of messages to peek
+pub const DEFAULT_PEEK_COUNT: usize = 10;
+
+/// Largest peek allowed: a peek makes one receive, since each one adds to
+/// the messages' receive counts
+pub const MAX_PEEK_COUNT: usize = MAX_MESSAGES_PER_RECEIVE as usize;
+
+/// Dead-letter queue settings of a source queue (the `RedrivePolicy` attribute)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedrivePolicy {
+    pub dead_letter_target_arn: String,
+    /// Receives before a message moves to the dead-letter queue
+    pub max_receive_count: u32,
+}
+
+impl RedrivePolicy {
+    /// Parse the attribute JSON; `maxReceiveCount` may be a string or a number
+    pub fn parse(json: &str) -> Option<Self> {
+        let value: serde_json::Value = serde_json::from_str(json).ok()?;
+        let dead_letter_target_arn = value.get("deadLetterTargetArn")?.as_str()?.to_string();
+        let max_receive_count = match value.get("maxReceiveCount")? {
+            serde_json::Value::String(count) => count.parse().ok()?,
+            serde_json::Value::Number(count) => u32::try_from(count.as_u64()?).ok()?,
+            _ => return None,
+        };
+        Some(Self {
+            dead_letter_target_arn,
+            max_receive_count,
+        })
+    }
+
+    /// Whether a peek leaves messages at `maxReceiveCount`, so their next
+    /// receive moves them to the dead-letter queue
+    ///
+    /// A peek adds one receive, so this holds for every message when the
+    /// limit is 1.
+    pub fn peek_dead_letters(&self) -> bool {
+        self.max_receive_count <= 1
+    }
+}
+
+/// Queue attributes relevant to inspecting its contents
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueDetails {
+    pub queue_url: String,
+    pub queue_arn: Option<String>,
+    /// Messages available for retrieval
+    pub visible_messages: u64,
+    /// Messages received but not yet deleted
+    pub in_flight_messages: u64,
+    pub delayed_messages: u64,
+    pub visibility_timeout_seconds: Option<u32>,
+    pub retention_period_seconds: Option<u32>,
+    pub fifo: bool,
+    /// Where this queue sends failed messages
+    pub redrive_policy: Option<RedrivePolicy>,
+    /// Queues that send failed messages here; non-empty for a dead-letter queue
+    pub dead_letter_sources: Vec<String>,
+    /// ApproximateAgeOfOldestMessage from CloudWatch; None when no recent datapoint
+    pub oldest_message_age_seconds: Option<f64>,
+    /// Every attribute as returned by GetQueueAttributes
+    pub attributes: BTreeMap<String, String>,
+}
+
+impl QueueDetails {
+    /// Build from GetQueueAttributes output
+    pub fn from_attributes(queue_url: &str, attributes: BTreeMap<String, String>) -> Self {
+        let number = |name: &str| attributes.get(name).and_then(|v| v.parse::<u64>().ok());
+        Self {
+            queue_url: queue_url.to_string(),
+            queue_arn: attributes.get("QueueArn").cloned(),
+            visible_messages: number("ApproximateNumberOfMessages").unwrap_or_default(),
+            in_flight_messages: number("ApproximateNumberOfMessagesNotVisible").unwrap_or_default(),
+            delayed_messages: number("ApproximateNumberOfMessagesDelayed").unwrap_or_default(),
+            visibility_timeout_seconds: number("VisibilityTimeout").map(|v| v as u32),
+            retention_period_seconds: number("MessageRetentionPeriod").map(|v| v as u32),
+            fifo: attributes.get("FifoQueue").map(String::as_str) == Some("true"),
+            redrive_policy: attributes
+                .get("RedrivePolicy")
+                .and_then(|policy| RedrivePolicy::parse(policy)),
+            dead_letter_sources: Vec::new(),
+            oldest_message_age_seconds: None,
+            attributes,
+        }
+    }
+
+    /// Messages in the queue, counting in-flight and delayed ones
+    pub fn total_messages(&self) -> u64 {
+        self.visible_messages + self.in_flight_messages + self.delayed_messages
+    }
+
+    /// Whether other queues use this one as their dead-letter queue
+    pub fn is_dead_letter_queue(&self) -> bool {
+        !self.dead_letter_sources.is_empty()
+    }
+}
+
+/// A message read without consuming it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeekedMessage {
+    pub message_id: String,
+    pub body: String,
+    /// Times the message has been received, including this peek
+    pub receive_count: u32,
+    /// When the message was first sent (Unix milliseconds)
+    pub sent_timestamp: Option<i64>,
+    /// When the message was first received (Unix milliseconds)
+    pub first_receive_timestamp: Option<i64>,
+    pub message_group_id: Option<String>,
+    /// System attributes (SenderId, MessageDeduplicationId, ...)
+    pub attributes: BTreeMap<String, String>,
+    /// String and number message attributes; binary values are omitted
+    pub message_attributes: BTreeMap<String, String>,
+}
+
+impl PeekedMessage {
+    /// Whether the next receive moves this message to the dead-letter queue
+    pub fn at_receive_limit(&self, policy: Option<&RedrivePolicy>) -> bool {
+        policy.is_some_and(|policy| self.receive_count >= policy.max_receive_count)
+    }
+}
+
+/// Messages returned by a peek
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeekResult {
+    pub messages: Vec<PeekedMessage>,
+    /// Dead-letter settings of the queue when it was peeked
+    pub redrive_policy: Option<RedrivePolicy>,
+}
+
+impl PeekResult {
+    /// Peeked messages whose next receive moves them to the dead-letter queue
+    pub fn messages_at_receive_limit(&self) -> usize {
+        self.messages
+            .iter()
+            .filter(|message| message.at_receive_limit(self.redrive_policy.as_ref()))
+            .count()
+    }
+}
+
+/// Progress of a dead-letter redrive (an SQS message move task)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveTaskStatus {
+    pub task_handle: Option<String>,
+    /// RUNNING, COMPLETED, CANCELLING, CANCELLED, or FAILED
+    pub status: String,
+    pub source_arn: String,
+    /// None when messages return to their original source queues
+    pub destination_arn: Option<String>,
+    pub moved: i64,
+    pub to_move: Option<i64>,
+    pub failure_reason: Option<String>,
+    /// Unix milliseconds
+    pub started_timestamp: i64,
+}
+
+impl MoveTaskStatus {
+    pub fn is_running(&self) -> bool {
+        self.status == "RUNNING" || self.status == "CANCELLING"
+    }
+}
+
+/// Queue name from a queue URL (`https://sqs.<region>.amazonaws.com/<account>/<name>`)
+pub fn queue_name_from_url(queue_url: &str) -> &str {
+    queue_url
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or(queue_url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_redrive_policy() {
+        let as_string = RedrivePolicy::parse(
+            r#"{"deadLetterTargetArn":"arn:aws:sqs:us-east-1:123456789012:orders-dlq","maxReceiveCount":"5"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            as_string.dead_letter_target_arn,
+            "arn:aws:sqs:us-east-1:123456789012:orders-dlq"
+        );
+        assert_eq!(as_string.max_receive_count, 5);
+
+        let as_number = RedrivePolicy::parse(
+            r#"{"deadLetterTargetArn":"arn:aws:sqs:us-east-1:123456789012:orders-dlq","maxReceiveCount":3}"#,
+        )
+        .unwrap();
+        assert_eq!(as_number.max_receive_count, 3);
+
+        assert!(RedrivePolicy::parse(r#"{"maxReceiveCount":3}"#).is_none());
+        assert!(RedrivePolicy::parse("not json").is_none());
+    }
+
+    #[test]
+    fn test_queue_details_from_attributes() {
+        let attributes: BTreeMap<String, String> = [
+            ("QueueArn", "arn:aws:sqs:us-east-1:123456789012:orders"),
+            ("ApproximateNumberOfMessages", "12"),
+            ("ApproximateNumberOfMessagesNotVisible", "3"),
+            ("ApproximateNumberOfMessagesDelayed", "1"),
+            ("VisibilityTimeout", "30"),
+            (
+                "RedrivePolicy",
+                r#"{"deadLetterTargetArn":"arn:aws:sqs:us-east-1:123456789012:orders-dlq","maxReceiveCount":"4"}"#,
+            ),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+
+        let details = QueueDetails::from_attributes(
+            "https://sqs.us-east-1.amazonaws.com/123456789012/orders",
+            attributes,
+        );
+        assert_eq!(details.visible_messages, 12);
+        assert_eq!(details.total_messages(), 16);
+        assert_eq!(details.visibility_timeout_seconds, Some(30));
+        assert!(!details.fifo);
+        assert!(!details.is_dead_letter_queue());
+
+        let policy = details.redrive_policy.as_ref().unwrap();
+        let message = PeekedMessage {
+            message_id: "m-1".to_string(),
+            body: "{}".to_string(),
+            receive_count: 4,
+            sent_timestamp: None,
+            first_receive_timestamp: None,
+            message_group_id: None,
+            attributes: BTreeMap::new(),
+            message_attributes: BTreeMap::new(),
+        };
+        assert!(message.at_receive_limit(Some(policy)));
+        assert!(!message.at_receive_limit(None));
+    }
+
+    #[test]
+    fn test_queue_name_from_url() {
+        assert_eq!(
+            queue_name_from_url("https://sqs.us-east-1.amazonaws.com/123456789012/orders.fifo"),
+            "orders.fifo"
+        );
+        assert_eq!(queue_name_from_url("orders"), "orders");
+    }
+}
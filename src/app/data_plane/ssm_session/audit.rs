@@ -0,0 +1,108 @@
+//! SSM Session Audit Log
+//!
+//! Every session start, end, and failure is appended as one JSON line to
+//! `{data_dir}/logs/ssm_sessions.jsonl`, next to the application log, and
+//! mirrored to the application log. Keystrokes and output are not recorded.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use super::types::SessionAuditEntry;
+
+/// Append-only JSON Lines audit file for SSM sessions
+#[derive(Debug, Clone)]
+pub struct SessionAuditLog {
+    path: PathBuf,
+}
+
+impl SessionAuditLog {
+    /// Audit log in the application log directory
+    pub fn new() -> Result<Self> {
+        let proj_dirs = directories::ProjectDirs::from("com", "", "awsdash")
+            .context("Failed to get application data directory")?;
+        Ok(Self::with_path(
+            proj_dirs.data_dir().join("logs").join("ssm_sessions.jsonl"),
+        ))
+    }
+
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append an entry; the file and its directory are created on first use
+    pub fn record(&self, entry: &SessionAuditEntry) -> Result<()> {
+        tracing::info!(
+            target: "ssm_session::audit",
+            "{:?} session {} to {} in {}/{}{}",
+            entry.event,
+            entry.session_id.as_deref().unwrap_or("-"),
+            entry.instance_id,
+            entry.account_id,
+            entry.region,
+            entry
+                .message
+                .as_deref()
+                .map(|message| format!(": {}", message))
+                .unwrap_or_default()
+        );
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).context("Failed to create audit log directory")?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open audit log {}", self.path.display()))?;
+        let line = serde_json::to_string(entry).context("Failed to serialize audit entry")?;
+        writeln!(file, "{}", line).context("Failed to write audit entry")?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::types::SessionAuditEvent;
+    use super::*;
+
+    #[test]
+    fn test_record_appends_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = SessionAuditLog::with_path(dir.path().join("logs/ssm_sessions.jsonl"));
+
+        let started = SessionAuditEntry {
+            timestamp: chrono::Utc::now(),
+            event: SessionAuditEvent::Started,
+            session_id: Some("admin-0abc".to_string()),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            instance_id: "i-0123456789abcdef0".to_string(),
+            duration_seconds: None,
+            message: None,
+        };
+        let ended = SessionAuditEntry {
+            event: SessionAuditEvent::Ended,
+            duration_seconds: Some(95),
+            message: Some("Closed by user".to_string()),
+            ..started.clone()
+        };
+        log.record(&started).unwrap();
+        log.record(&ended).unwrap();
+
+        let contents = fs::read_to_string(log.path()).unwrap();
+        let lines: Vec<SessionAuditEntry> = contents
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines, vec![started, ended]);
+        assert!(contents.contains("\"event\":\"ended\""));
+    }
+}
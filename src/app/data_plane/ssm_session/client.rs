@@ -0,0 +1,96 @@
+//! SSM Session Client Wrapper
+//!
+//! Starts and terminates Session Manager sessions with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Context, Result};
+use aws_sdk_ssm as ssm;
+use std::sync::Arc;
+
+use crate::app::capabilities::ensure_writable;
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::SessionStart;
+
+/// SSM session client wrapper
+#[derive(Clone)]
+pub struct SsmSessionClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl SsmSessionClient {
+    /// Create a new SSM session client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<ssm::Client> {
+        let config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+        Ok(ssm::Client::new(&config))
+    }
+
+    /// Start a shell session on an instance
+    ///
+    /// The session stays open on the service side until it is terminated or
+    /// times out from inactivity, so callers should pair this with
+    /// [`terminate_session`](Self::terminate_session).
+    pub async fn start_session(
+        &self,
+        account_id: &str,
+        region: &str,
+        instance_id: &str,
+    ) -> Result<SessionStart> {
+        ensure_writable("Start SSM session")?;
+        let client = self.create_client(account_id, region).await?;
+        let response = client
+            .start_session()
+            .target(instance_id)
+            .send()
+            .await
+            .with_context(|| format!("Failed to start session on {}", instance_id))?;
+
+        Ok(SessionStart {
+            session_id: response
+                .session_id()
+                .ok_or_else(|| anyhow!("StartSession returned no session ID"))?
+                .to_string(),
+            stream_url: response
+                .stream_url()
+                .ok_or_else(|| anyhow!("StartSession returned no stream URL"))?
+                .to_string(),
+            token_value: response
+                .token_value()
+                .ok_or_else(|| anyhow!("StartSession returned no token"))?
+                .to_string(),
+        })
+    }
+
+    /// End a session so the agent closes the shell on the instance
+    pub async fn terminate_session(
+        &self,
+        account_id: &str,
+        region: &str,
+        session_id: &str,
+    ) -> Result<()> {
+        let client = self.create_client(account_id, region).await?;
+        client
+            .terminate_session()
+            .session_id(session_id)
+            .send()
+            .await
+            .with_context(|| format!("Failed to terminate session {}", session_id))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+//! SSM Session Manager Integration Module
+//!
+//! Backs the instance terminal: a Session Manager shell on an EC2 instance,
+//! without SSH keys or open inbound ports.
+//!
+//! ## Features
+//!
+//! - StartSession and TerminateSession against a managed instance
+//! - Data channel through the `session-manager-plugin`, found on PATH or in
+//!   its default install location, with the session token passed in the
+//!   environment rather than on the command line
+//! - Audit records (start, end with duration, failure) appended to
+//!   `ssm_sessions.jsonl` in the application log directory
+//! - Sessions refused in the read-only build
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::ssm_session::{
+//!     find_session_manager_plugin, PluginSession, SsmSessionClient,
+//! };
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = SsmSessionClient::new(credential_coordinator);
+//! let plugin = find_session_manager_plugin().expect("session-manager-plugin not installed");
+//!
+//! let session = client
+//!     .start_session("123456789012", "us-east-1", "i-0123456789abcdef0")
+//!     .await?;
+//! let mut terminal = PluginSession::spawn(&plugin, &session, "us-east-1", "i-0123456789abcdef0")?;
+//! terminal.write(b"uptime\r")?;
+//!
+//! terminal.kill();
+//! client
+//!     .terminate_session("123456789012", "us-east-1", &session.session_id)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! - The instance runs the SSM Agent and has an instance profile that allows
+//!   Session Manager (e.g. `AmazonSSMManagedInstanceCore`)
+//! - The role needs `ssm:StartSession` on the instance and
+//!   `ssm:TerminateSession` on its own sessions
+//! - The Session Manager plugin is installed locally
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod audit;
+pub mod client;
+pub mod plugin;
+pub mod types;
+
+// Re-export commonly used types
+pub use audit::SessionAuditLog;
+pub use client::SsmSessionClient;
+pub use plugin::{find_session_manager_plugin, PluginOutput, PluginSession};
+pub use types::{SessionAuditEntry, SessionAuditEvent, SessionStart};
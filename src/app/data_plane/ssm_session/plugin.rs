@@ -0,0 +1,173 @@
+//! Session Manager Plugin Process
+//!
+//! The data channel of a session is a WebSocket protocol implemented by the
+//! `session-manager-plugin` binary that the AWS CLI also uses. The plugin is
+//! run with piped stdio: keystrokes are written to its stdin, and its stdout
+//! and stderr are forwarded as raw bytes for the terminal window to render.
+//! The remote shell runs under a pseudo-terminal on the instance, so echo and
+//! line editing happen there.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Context, Result};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::mpsc;
+
+use super::types::{SessionStart, RESPONSE_ENV_VAR};
+
+#[cfg(windows)]
+const PLUGIN_BINARY: &str = "session-manager-plugin.exe";
+#[cfg(not(windows))]
+const PLUGIN_BINARY: &str = "session-manager-plugin";
+
+/// Install locations used by the official packages, checked after PATH
+#[cfg(windows)]
+const PLUGIN_INSTALL_DIRS: &[&str] = &[r"C:\Program Files\Amazon\SessionManagerPlugin\bin"];
+#[cfg(not(windows))]
+const PLUGIN_INSTALL_DIRS: &[&str] = &[
+    "/usr/local/bin",
+    "/usr/local/sessionmanagerplugin/bin",
+    "/usr/bin",
+    "/opt/homebrew/bin",
+];
+
+/// Locate `session-manager-plugin` on PATH or in its default install locations
+pub fn find_session_manager_plugin() -> Option<PathBuf> {
+    let path_dirs = std::env::var_os("PATH")
+        .map(|path| std::env::split_paths(&path).collect::<Vec<_>>())
+        .unwrap_or_default();
+    path_dirs
+        .into_iter()
+        .chain(PLUGIN_INSTALL_DIRS.iter().map(PathBuf::from))
+        .map(|dir| dir.join(PLUGIN_BINARY))
+        .find(|candidate| candidate.is_file())
+}
+
+/// Output of a running plugin process
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PluginOutput {
+    /// Bytes written to stdout or stderr
+    Data(Vec<u8>),
+    /// The process exited; None when it was killed by a signal
+    Exited(Option<i32>),
+}
+
+/// A running `session-manager-plugin` connected to one session
+pub struct PluginSession {
+    child: Child,
+    stdin: Option<ChildStdin>,
+    output: mpsc::Receiver<PluginOutput>,
+    exit_reported: bool,
+}
+
+impl PluginSession {
+    /// Run the plugin for a started session
+    pub fn spawn(
+        plugin: &Path,
+        session: &SessionStart,
+        region: &str,
+        instance_id: &str,
+    ) -> Result<Self> {
+        let mut child = Command::new(plugin)
+            .args(session.plugin_arguments(region, instance_id))
+            .env(RESPONSE_ENV_VAR, session.response_json())
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Failed to run {}", plugin.display()))?;
+
+        let stdin = child.stdin.take();
+        let stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| anyhow!("Plugin stdout was not captured"))?;
+        let stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| anyhow!("Plugin stderr was not captured"))?;
+
+        let (sender, output) = mpsc::channel();
+        forward_output(stdout, sender.clone());
+        forward_output(stderr, sender);
+
+        Ok(Self {
+            child,
+            stdin,
+            output,
+            exit_reported: false,
+        })
+    }
+
+    /// Send keystrokes to the remote shell
+    pub fn write(&mut self, bytes: &[u8]) -> Result<()> {
+        let stdin = self
+            .stdin
+            .as_mut()
+            .ok_or_else(|| anyhow!("Session input is closed"))?;
+        stdin
+            .write_all(bytes)
+            .and_then(|_| stdin.flush())
+            .context("Failed to write to session")
+    }
+
+    /// Output received since the last call; reports the exit once the
+    /// process has ended
+    pub fn drain_output(&mut self) -> Vec<PluginOutput> {
+        let mut output: Vec<PluginOutput> = self.output.try_iter().collect();
+        if self.exit_reported {
+            return output;
+        }
+        if let Ok(Some(status)) = self.child.try_wait() {
+            // Collect what the reader threads forwarded before the exit
+            output.extend(self.output.try_iter());
+            output.push(PluginOutput::Exited(status.code()));
+            self.exit_reported = true;
+            self.stdin = None;
+        }
+        output
+    }
+
+    /// Whether the process is still running
+    pub fn is_running(&mut self) -> bool {
+        matches!(self.child.try_wait(), Ok(None))
+    }
+
+    /// Stop the plugin; the service-side session is left to the caller
+    pub fn kill(&mut self) {
+        self.stdin = None;
+        if self.is_running() {
+            if let Err(e) = self.child.kill() {
+                log::warn!("Failed to stop session-manager-plugin: {}", e);
+            }
+        }
+        let _ = self.child.wait();
+    }
+}
+
+impl Drop for PluginSession {
+    fn drop(&mut self) {
+        self.kill();
+    }
+}
+
+fn forward_output(mut reader: impl Read + Send + 'static, sender: mpsc::Sender<PluginOutput>) {
+    std::thread::spawn(move || {
+        let mut buffer = [0u8; 4096];
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(read) => {
+                    if sender
+                        .send(PluginOutput::Data(buffer[..read].to_vec()))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
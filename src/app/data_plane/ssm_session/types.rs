@@ -0,0 +1,100 @@
+//! SSM Session Data Types
+//!
+//! Started sessions as handed to the session-manager-plugin, and the audit
+//! records kept for every session.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A session started with StartSession, in the shape the plugin expects
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SessionStart {
+    pub session_id: String,
+    /// WebSocket URL the plugin connects to
+    pub stream_url: String,
+    /// Token that authenticates the WebSocket connection
+    pub token_value: String,
+}
+
+impl SessionStart {
+    /// Arguments for `session-manager-plugin`, the same ones the AWS CLI passes
+    ///
+    /// The StartSession response itself travels in the
+    /// `AWS_SSM_START_SESSION_RESPONSE` environment variable (see
+    /// [`RESPONSE_ENV_VAR`]) so the token does not show up in process listings.
+    pub fn plugin_arguments(&self, region: &str, target: &str) -> Vec<String> {
+        vec![
+            RESPONSE_ENV_VAR.to_string(),
+            region.to_string(),
+            "StartSession".to_string(),
+            // Profile; the plugin only uses it for its own logging
+            String::new(),
+            serde_json::json!({ "Target": target }).to_string(),
+            format!("https://ssm.{}.amazonaws.com", region),
+        ]
+    }
+
+    /// Value of [`RESPONSE_ENV_VAR`]
+    pub fn response_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Environment variable holding the StartSession response for the plugin
+pub const RESPONSE_ENV_VAR: &str = "AWS_SSM_START_SESSION_RESPONSE";
+
+/// Lifecycle point of a session recorded in the audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SessionAuditEvent {
+    Started,
+    Ended,
+    Failed,
+}
+
+/// One audit record for an SSM session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionAuditEntry {
+    pub timestamp: DateTime<Utc>,
+    pub event: SessionAuditEvent,
+    /// None when StartSession itself failed
+    pub session_id: Option<String>,
+    pub account_id: String,
+    pub region: String,
+    pub instance_id: String,
+    /// Set on Ended records
+    pub duration_seconds: Option<i64>,
+    /// Why the session ended or failed
+    pub message: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plugin_arguments() {
+        let start = SessionStart {
+            session_id: "admin-0abc".to_string(),
+            stream_url: "wss://ssmmessages.us-east-1.amazonaws.com/v1/data-channel/admin-0abc"
+                .to_string(),
+            token_value: "secret-token".to_string(),
+        };
+
+        let args = start.plugin_arguments("us-east-1", "i-0123456789abcdef0");
+        assert_eq!(args.len(), 6);
+        assert_eq!(args[0], RESPONSE_ENV_VAR);
+        assert_eq!(args[2], "StartSession");
+        assert_eq!(args[4], r#"{"Target":"i-0123456789abcdef0"}"#);
+        assert_eq!(args[5], "https://ssm.us-east-1.amazonaws.com");
+        assert!(!args.iter().any(|arg| arg.contains("secret-token")));
+
+        let response: serde_json::Value = serde_json::from_str(&start.response_json()).unwrap();
+        assert_eq!(response["SessionId"], "admin-0abc");
+        assert_eq!(response["TokenValue"], "secret-token");
+    }
+}
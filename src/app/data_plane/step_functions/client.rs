@@ -0,0 +1,237 @@
+//! Step Functions Client Wrapper
+//!
+//! Lists the executions of a state machine and reads execution histories,
+//! with credential management.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use aws_sdk_sfn as sfn;
+use std::sync::Arc;
+
+use crate::app::resource_explorer::credentials::CredentialCoordinator;
+
+use super::types::{
+    ExecutionHistory, ExecutionListOptions, ExecutionListResult, ExecutionSummary, HistoryEvent,
+    MAX_EXECUTIONS, MAX_HISTORY_EVENTS,
+};
+
+/// Step Functions client wrapper
+#[derive(Clone)]
+pub struct StepFunctionsClient {
+    credential_coordinator: Arc<CredentialCoordinator>,
+}
+
+impl StepFunctionsClient {
+    /// Create a new Step Functions client wrapper
+    pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        Self {
+            credential_coordinator,
+        }
+    }
+
+    async fn create_client(&self, account_id: &str, region: &str) -> Result<sfn::Client> {
+        let config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+        Ok(sfn::Client::new(&config))
+    }
+
+    /// Executions of a state machine, newest first
+    ///
+    /// Express state machines have no execution history and are rejected by the API.
+    pub async fn list_executions(
+        &self,
+        account_id: &str,
+        region: &str,
+        state_machine_arn: &str,
+        options: &ExecutionListOptions,
+    ) -> Result<ExecutionListResult> {
+        let client = self.create_client(account_id, region).await?;
+
+        let response = client
+            .list_executions()
+            .state_machine_arn(state_machine_arn)
+            .set_status_filter(
+                options
+                    .status_filter
+                    .as_deref()
+                    .map(sfn::types::ExecutionStatus::from),
+            )
+            .max_results(options.max_results.clamp(1, MAX_EXECUTIONS))
+            .set_next_token(options.next_token.clone())
+            .send()
+            .await
+            .with_context(|| format!("Failed to list executions of {}", state_machine_arn))?;
+
+        let executions = response
+            .executions()
+            .iter()
+            .map(|execution| ExecutionSummary {
+                execution_arn: execution.execution_arn().to_string(),
+                name: execution.name().to_string(),
+                status: execution.status().as_str().to_string(),
+                start_date: execution.start_date().to_millis().unwrap_or(0),
+                stop_date: execution.stop_date().and_then(|date| date.to_millis().ok()),
+            })
+            .collect();
+
+        Ok(ExecutionListResult {
+            executions,
+            next_token: response.next_token().map(String::from),
+        })
+    }
+
+    /// History of one execution with its state transitions
+    pub async fn get_execution_history(
+        &self,
+        account_id: &str,
+        region: &str,
+        execution_arn: &str,
+    ) -> Result<ExecutionHistory> {
+        let client = self.create_client(account_id, region).await?;
+
+        let mut events = Vec::new();
+        let mut next_token: Option<String> = None;
+        let mut truncated = false;
+        loop {
+            let response = client
+                .get_execution_history()
+                .execution_arn(execution_arn)
+                .include_execution_data(true)
+                .max_results(MAX_EXECUTIONS)
+                .set_next_token(next_token.take())
+                .send()
+                .await
+                .with_context(|| format!("Failed to get history of {}", execution_arn))?;
+
+            events.extend(response.events().iter().map(convert_event));
+
+            if events.len() >= MAX_HISTORY_EVENTS {
+                truncated = response.next_token().is_some() || events.len() > MAX_HISTORY_EVENTS;
+                events.truncate(MAX_HISTORY_EVENTS);
+                break;
+            }
+            match response.next_token() {
+                Some(token) if !token.is_empty() => next_token = Some(token.to_string()),
+                _ => break,
+            }
+        }
+
+        Ok(ExecutionHistory::from_events(
+            execution_arn,
+            events,
+            truncated,
+        ))
+    }
+}
+
+/// Convert an SDK history event, keeping the details the viewer shows
+fn convert_event(event: &sfn::types::HistoryEvent) -> HistoryEvent {
+    let mut converted = HistoryEvent {
+        id: event.id(),
+        previous_event_id: event.previous_event_id(),
+        timestamp: event.timestamp().to_millis().unwrap_or(0),
+        event_type: event.r#type().as_str().to_string(),
+        ..Default::default()
+    };
+
+    if let Some(details) = event.state_entered_event_details() {
+        converted.state_name = Some(details.name().to_string());
+        converted.input = details.input().map(String::from);
+    }
+    if let Some(details) = event.state_exited_event_details() {
+        converted.state_name = Some(details.name().to_string());
+        converted.output = details.output().map(String::from);
+    }
+    if let Some(details) = event.execution_started_event_details() {
+        converted.input = details.input().map(String::from);
+    }
+    if let Some(details) = event.execution_succeeded_event_details() {
+        converted.output = details.output().map(String::from);
+    }
+    if let Some(details) = event.task_scheduled_event_details() {
+        converted.resource = Some(format!(
+            "{}:{}",
+            details.resource_type(),
+            details.resource()
+        ));
+        converted.input = Some(details.parameters().to_string());
+    }
+    if let Some(details) = event.task_succeeded_event_details() {
+        converted.output = details.output().map(String::from);
+    }
+    if let Some(details) = event.lambda_function_scheduled_event_details() {
+        converted.resource = Some(details.resource().to_string());
+        converted.input = details.input().map(String::from);
+    }
+    if let Some(details) = event.lambda_function_succeeded_event_details() {
+        converted.output = details.output().map(String::from);
+    }
+
+    // Failure details live in a different field for each event type
+    let failure = [
+        event
+            .execution_failed_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .execution_aborted_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .execution_timed_out_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .task_failed_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .task_start_failed_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .task_submit_failed_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .task_timed_out_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .lambda_function_failed_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .lambda_function_schedule_failed_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .lambda_function_start_failed_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .lambda_function_timed_out_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .activity_failed_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .activity_schedule_failed_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .activity_timed_out_event_details()
+            .map(|d| (d.error(), d.cause())),
+        event
+            .map_run_failed_event_details()
+            .map(|d| (d.error(), d.cause())),
+    ]
+    .into_iter()
+    .flatten()
+    .next();
+    if let Some((error, cause)) = failure {
+        // Some failures carry neither field; keep the event type so the state still fails
+        converted.error = Some(error.unwrap_or(converted.event_type.as_str()).to_string());
+        converted.cause = cause.map(String::from);
+    }
+
+    converted
+}
@@ -0,0 +1,64 @@
+//! Step Functions Integration Module
+//!
+//! Provides access to the executions of Step Functions state machines within
+//! the application: which runs happened, and what each state received and
+//! returned along the way.
+//!
+//! ## Features
+//!
+//! - List the executions of a state machine, optionally by status
+//! - Read an execution history with input and output payloads
+//! - Pair StateEntered/StateExited events into a per-state timeline, with
+//!   retries and the error that failed a state
+//! - Integration with agents (`getStateMachineExecutions()`)
+//!
+//! ## Usage
+//!
+//! ```rust,no_run
+//! use awsdash::app::data_plane::step_functions::{ExecutionListOptions, StepFunctionsClient};
+//! # use std::sync::Arc;
+//! # use awsdash::app::resource_explorer::credentials::CredentialCoordinator;
+//!
+//! # async fn example(credential_coordinator: Arc<CredentialCoordinator>) -> anyhow::Result<()> {
+//! let client = StepFunctionsClient::new(credential_coordinator);
+//! let state_machine_arn = "arn:aws:states:us-east-1:123456789012:stateMachine:orders";
+//!
+//! let failed = client
+//!     .list_executions(
+//!         "123456789012",
+//!         "us-east-1",
+//!         state_machine_arn,
+//!         &ExecutionListOptions::new().with_status_filter("FAILED"),
+//!     )
+//!     .await?;
+//!
+//! for execution in &failed.executions {
+//!     let history = client
+//!         .get_execution_history("123456789012", "us-east-1", &execution.execution_arn)
+//!         .await?;
+//!     for state in &history.transitions {
+//!         println!("{} {:?} {:?}", state.name, state.status, state.error);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Prerequisites
+//!
+//! The role needs `states:ListExecutions` on the state machine and
+//! `states:GetExecutionHistory` on its executions. Express state machines
+//! keep no history; their runs are only visible in CloudWatch Logs.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+pub mod client;
+pub mod types;
+
+// Re-export commonly used types
+pub use client::StepFunctionsClient;
+pub use types::{
+    build_transitions, ExecutionHistory, ExecutionListOptions, ExecutionListResult,
+    ExecutionSummary, HistoryEvent, StateStatus, StateTransition, MAX_EXECUTIONS,
+    MAX_HISTORY_EVENTS,
+};
@@ -0,0 +1,413 @@
+//! Step Functions Execution Data Types
+//!
+//! Executions of a state machine, the raw events of an execution history, and
+//! the per-state transitions built from those events for the timeline view.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::{Deserialize, Serialize};
+
+/// Default number of executions returned by one list call
+pub const DEFAULT_MAX_EXECUTIONS: i32 = 50;
+
+/// Hard limit on executions returned by one list call (the ListExecutions page size)
+pub const MAX_EXECUTIONS: i32 = 1000;
+
+/// Most history events read for one execution; longer histories are truncated
+pub const MAX_HISTORY_EVENTS: usize = 5000;
+
+/// Options for listing the executions of a state machine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionListOptions {
+    /// RUNNING, SUCCEEDED, FAILED, TIMED_OUT, ABORTED or PENDING_REDRIVE
+    pub status_filter: Option<String>,
+    /// Maximum executions to return (capped at MAX_EXECUTIONS)
+    pub max_results: i32,
+    /// Pagination token from a previous call
+    pub next_token: Option<String>,
+}
+
+impl Default for ExecutionListOptions {
+    fn default() -> Self {
+        Self {
+            status_filter: None,
+            max_results: DEFAULT_MAX_EXECUTIONS,
+            next_token: None,
+        }
+    }
+}
+
+impl ExecutionListOptions {
+    /// Create default list options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only return executions with this status
+    pub fn with_status_filter(mut self, status: impl Into<String>) -> Self {
+        self.status_filter = Some(status.into());
+        self
+    }
+
+    /// Set the maximum executions to return
+    pub fn with_max_results(mut self, max_results: i32) -> Self {
+        self.max_results = max_results.clamp(1, MAX_EXECUTIONS);
+        self
+    }
+
+    /// Continue from a previous call
+    pub fn with_next_token(mut self, token: impl Into<String>) -> Self {
+        self.next_token = Some(token.into());
+        self
+    }
+}
+
+/// One execution of a state machine
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionSummary {
+    pub execution_arn: String,
+    pub name: String,
+    /// RUNNING, SUCCEEDED, FAILED, TIMED_OUT, ABORTED or PENDING_REDRIVE
+    pub status: String,
+    /// Start time (Unix milliseconds)
+    pub start_date: i64,
+    /// Stop time (Unix milliseconds), None while running
+    pub stop_date: Option<i64>,
+}
+
+impl ExecutionSummary {
+    pub fn duration_ms(&self) -> Option<i64> {
+        self.stop_date.map(|stop| stop - self.start_date)
+    }
+}
+
+/// A page of executions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionListResult {
+    /// Executions, newest first
+    pub executions: Vec<ExecutionSummary>,
+    pub next_token: Option<String>,
+}
+
+/// One event of an execution history, with the details the viewer shows
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEvent {
+    pub id: i64,
+    pub previous_event_id: i64,
+    /// Event time (Unix milliseconds)
+    pub timestamp: i64,
+    /// e.g. TaskStateEntered, LambdaFunctionFailed, ExecutionSucceeded
+    pub event_type: String,
+    /// State name for StateEntered and StateExited events
+    pub state_name: Option<String>,
+    /// Input payload (state, execution, or task parameters)
+    pub input: Option<String>,
+    /// Output payload (state, execution, or task result)
+    pub output: Option<String>,
+    pub error: Option<String>,
+    pub cause: Option<String>,
+    /// Invoked resource, e.g. "lambda:invoke" or a Lambda function ARN
+    pub resource: Option<String>,
+}
+
+impl HistoryEvent {
+    fn is_state_entered(&self) -> bool {
+        self.event_type.ends_with("StateEntered")
+    }
+
+    fn is_state_exited(&self) -> bool {
+        self.event_type.ends_with("StateExited")
+    }
+
+    fn is_failure(&self) -> bool {
+        self.error.is_some() || self.cause.is_some()
+    }
+
+    fn is_execution_event(&self) -> bool {
+        self.event_type.starts_with("Execution")
+    }
+}
+
+/// Outcome of one state in the timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StateStatus {
+    Succeeded,
+    Failed,
+    /// Entered but not exited (execution running, or stopped inside the state)
+    Running,
+}
+
+/// One visit of a state, from StateEntered to StateExited
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateTransition {
+    pub name: String,
+    /// State type from the entered event, e.g. "Task", "Choice", "Map"
+    pub state_type: String,
+    pub status: StateStatus,
+    /// Time the state was entered (Unix milliseconds)
+    pub entered_at: i64,
+    /// Time the state was exited (Unix milliseconds)
+    pub exited_at: Option<i64>,
+    pub input: Option<String>,
+    pub output: Option<String>,
+    /// Error that failed the state (cleared when a retry succeeds)
+    pub error: Option<String>,
+    pub cause: Option<String>,
+    /// Resource the state invoked, for Task states
+    pub resource: Option<String>,
+    /// Number of failed attempts before the final one
+    pub retries: usize,
+    /// Events between entering and exiting the state
+    pub events: Vec<HistoryEvent>,
+}
+
+impl StateTransition {
+    pub fn duration_ms(&self) -> Option<i64> {
+        self.exited_at.map(|exited| exited - self.entered_at)
+    }
+}
+
+/// Full history of one execution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionHistory {
+    pub execution_arn: String,
+    /// Execution input
+    pub input: Option<String>,
+    /// Execution output, once succeeded
+    pub output: Option<String>,
+    /// Error that stopped the execution (failed, timed out, or aborted)
+    pub error: Option<String>,
+    pub cause: Option<String>,
+    /// Raw events, oldest first
+    pub events: Vec<HistoryEvent>,
+    /// State visits in the order they were entered
+    pub transitions: Vec<StateTransition>,
+    /// True when the history was cut at MAX_HISTORY_EVENTS
+    pub truncated: bool,
+}
+
+impl ExecutionHistory {
+    /// Build the execution payloads and state transitions from raw events
+    pub fn from_events(
+        execution_arn: impl Into<String>,
+        events: Vec<HistoryEvent>,
+        truncated: bool,
+    ) -> Self {
+        let mut history = Self {
+            execution_arn: execution_arn.into(),
+            input: None,
+            output: None,
+            error: None,
+            cause: None,
+            transitions: build_transitions(&events),
+            events: Vec::new(),
+            truncated,
+        };
+        for event in events.iter().filter(|event| event.is_execution_event()) {
+            match event.event_type.as_str() {
+                "ExecutionStarted" => history.input = event.input.clone(),
+                "ExecutionSucceeded" => history.output = event.output.clone(),
+                _ if event.is_failure() => {
+                    history.error = event.error.clone();
+                    history.cause = event.cause.clone();
+                }
+                _ => {}
+            }
+        }
+        history.events = events;
+        history
+    }
+}
+
+/// Pair StateEntered and StateExited events into state transitions
+///
+/// Events between the two belong to the innermost open state, so the tasks of
+/// Map and Parallel branches land in their own states rather than the parent.
+pub fn build_transitions(events: &[HistoryEvent]) -> Vec<StateTransition> {
+    let mut transitions: Vec<StateTransition> = Vec::new();
+    // Indexes into `transitions` of states entered but not yet exited
+    let mut open: Vec<usize> = Vec::new();
+
+    let mut sorted: Vec<&HistoryEvent> = events.iter().collect();
+    sorted.sort_by_key(|event| event.id);
+
+    for event in sorted {
+        if event.is_state_entered() {
+            open.push(transitions.len());
+            transitions.push(StateTransition {
+                name: event.state_name.clone().unwrap_or_default(),
+                state_type: event
+                    .event_type
+                    .trim_end_matches("StateEntered")
+                    .to_string(),
+                status: StateStatus::Running,
+                entered_at: event.timestamp,
+                exited_at: None,
+                input: event.input.clone(),
+                output: None,
+                error: None,
+                cause: None,
+                resource: None,
+                retries: 0,
+                events: Vec::new(),
+            });
+        } else if event.is_state_exited() {
+            // Exit the innermost open state of that name
+            let position = open.iter().rposition(|&index| {
+                transitions[index].name == event.state_name.as_deref().unwrap_or_default()
+            });
+            if let Some(position) = position {
+                let transition = &mut transitions[open.remove(position)];
+                transition.exited_at = Some(event.timestamp);
+                transition.output = event.output.clone();
+                if transition.status == StateStatus::Running {
+                    transition.status = StateStatus::Succeeded;
+                }
+            }
+        } else if event.is_execution_event() {
+            // A stopped execution leaves its open states failed
+            if event.is_failure() {
+                for &index in &open {
+                    let transition = &mut transitions[index];
+                    transition.status = StateStatus::Failed;
+                    if transition.error.is_none() {
+                        transition.error = event.error.clone();
+                        transition.cause = event.cause.clone();
+                    }
+                }
+            }
+        } else if let Some(&index) = open.last() {
+            let transition = &mut transitions[index];
+            if transition.resource.is_none() {
+                transition.resource = event.resource.clone();
+            }
+            if event.is_failure() {
+                if transition.status == StateStatus::Failed {
+                    transition.retries += 1;
+                }
+                transition.status = StateStatus::Failed;
+                transition.error = event.error.clone();
+                transition.cause = event.cause.clone();
+            } else if event.event_type.ends_with("Succeeded") {
+                // A retry succeeded
+                if transition.status == StateStatus::Failed {
+                    transition.retries += 1;
+                }
+                transition.status = StateStatus::Running;
+                transition.error = None;
+                transition.cause = None;
+            }
+            transition.events.push(event.clone());
+        }
+    }
+
+    transitions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(id: i64, event_type: &str, state_name: Option<&str>) -> HistoryEvent {
+        HistoryEvent {
+            id,
+            previous_event_id: id - 1,
+            timestamp: id * 1000,
+            event_type: event_type.to_string(),
+            state_name: state_name.map(String::from),
+            ..Default::default()
+        }
+    }
+
+    fn failed(id: i64, event_type: &str, error: &str) -> HistoryEvent {
+        HistoryEvent {
+            error: Some(error.to_string()),
+            cause: Some("boom".to_string()),
+            ..event(id, event_type, None)
+        }
+    }
+
+    #[test]
+    fn test_transitions_pair_entered_and_exited() {
+        let events = vec![
+            HistoryEvent {
+                input: Some(r#"{"order":1}"#.to_string()),
+                ..event(1, "ExecutionStarted", None)
+            },
+            HistoryEvent {
+                input: Some(r#"{"order":1}"#.to_string()),
+                ..event(2, "TaskStateEntered", Some("Charge"))
+            },
+            HistoryEvent {
+                resource: Some("lambda:invoke".to_string()),
+                ..event(3, "TaskScheduled", None)
+            },
+            failed(4, "TaskFailed", "Lambda.ServiceException"),
+            event(5, "TaskScheduled", None),
+            event(6, "TaskSucceeded", None),
+            HistoryEvent {
+                output: Some(r#"{"charged":true}"#.to_string()),
+                ..event(7, "TaskStateExited", Some("Charge"))
+            },
+            event(8, "PassStateEntered", Some("Ship")),
+            HistoryEvent {
+                output: Some(r#"{"done":true}"#.to_string()),
+                ..event(9, "ExecutionSucceeded", None)
+            },
+        ];
+        let history = ExecutionHistory::from_events("arn:exec", events, false);
+
+        assert_eq!(history.input.as_deref(), Some(r#"{"order":1}"#));
+        assert_eq!(history.output.as_deref(), Some(r#"{"done":true}"#));
+        assert_eq!(history.transitions.len(), 2);
+
+        let charge = &history.transitions[0];
+        assert_eq!(charge.state_type, "Task");
+        assert_eq!(charge.status, StateStatus::Succeeded);
+        assert_eq!(charge.retries, 1);
+        assert!(charge.error.is_none());
+        assert_eq!(charge.resource.as_deref(), Some("lambda:invoke"));
+        assert_eq!(charge.duration_ms(), Some(5000));
+        assert_eq!(charge.events.len(), 4);
+
+        assert_eq!(history.transitions[1].status, StateStatus::Running);
+    }
+
+    #[test]
+    fn test_failed_execution_fails_open_states() {
+        let events = vec![
+            event(1, "ExecutionStarted", None),
+            event(2, "MapStateEntered", Some("EachItem")),
+            event(3, "TaskStateEntered", Some("Process")),
+            failed(4, "LambdaFunctionFailed", "Timeout"),
+            failed(5, "ExecutionFailed", "States.TaskFailed"),
+        ];
+        let history = ExecutionHistory::from_events("arn:exec", events, false);
+
+        assert_eq!(history.error.as_deref(), Some("States.TaskFailed"));
+        let map = &history.transitions[0];
+        let task = &history.transitions[1];
+        // The Lambda failure belongs to the innermost state
+        assert_eq!(task.error.as_deref(), Some("Timeout"));
+        assert_eq!(task.status, StateStatus::Failed);
+        assert_eq!(map.status, StateStatus::Failed);
+        assert_eq!(map.error.as_deref(), Some("States.TaskFailed"));
+        assert!(map.events.is_empty());
+    }
+
+    #[test]
+    fn test_list_options_clamp() {
+        let options = ExecutionListOptions::new()
+            .with_status_filter("FAILED")
+            .with_max_results(5000);
+        assert_eq!(options.max_results, MAX_EXECUTIONS);
+        assert_eq!(options.status_filter.as_deref(), Some("FAILED"));
+    }
+}
@@ -8,12 +8,16 @@
 //! ## AWS Integration
 //! - [`aws_identity`] - AWS Identity Center authentication and credential management
 //! - [`resource_explorer`] - Multi-account AWS resource discovery and visualization
+//! - [`cloudformation_manager`] - Stack operations such as drift detection
 //!
 //! ## AI Agent System
 //! - [`agent_framework`] - AI agent tools for AWS resource operations and analysis
 //!
 //! ## UI and Infrastructure
 //! - [`dashui`] - Complete user interface implementation with window management
+//! - [`app_config`] - Settings file shared by the subsystems, edited in the Settings window
+//! - [`capabilities`] - Build-time gates for the read-only viewer build
+//! - [`cli`] - Headless `--cli` commands that print JSON for scripting
 //! - [`fonts`] - Font loading and management
 //! - [`notifications`] - Notification system for user feedback
 //!
@@ -26,8 +30,14 @@
 //! - [`dashui`] coordinates the user interface and window management
 
 pub mod agent_framework;
+pub mod app_config;
 pub mod aws_identity;
 pub mod aws_regions;
+pub mod capabilities;
+pub mod cli;
+pub mod cloudformation_manager;
+pub mod credential_sources;
+pub mod cross_account_roles;
 pub mod dashui;
 pub mod data_plane;
 pub mod fonts;
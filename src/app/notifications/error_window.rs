@@ -138,6 +138,12 @@ impl NotificationDetailsWindow {
                 manager.dismiss_notification(&notification.id);
             }
 
+            if let Some(window) = notification.related_window {
+                if ui.button(format!("Open {}", window.label())).clicked() {
+                    manager.request_related_window(window);
+                }
+            }
+
             // Additional actions based on notification type and source
             match notification.source.as_str() {
                 "CloudFormation Validation" => {
@@ -0,0 +1,396 @@
+//! Notification history
+//!
+//! Every notification added to the [`NotificationManager`](super::NotificationManager)
+//! is also recorded here, so it can be found again after it expires or is
+//! dismissed. Records are appended as JSON lines to
+//! `{data_local_dir}/awsdash/notification_history.jsonl`; the most recent
+//! [`MAX_HISTORY`] are loaded at startup.
+
+use super::{Notification, NotificationError, NotificationType, RelatedWindow};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, VecDeque};
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// Records kept in memory and loaded at startup
+pub const MAX_HISTORY: usize = 1000;
+
+/// One notification as it was added
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEntry {
+    pub timestamp: DateTime<Utc>,
+    pub notification_id: String,
+    pub title: String,
+    pub notification_type: NotificationType,
+    pub source: String,
+    pub messages: Vec<NotificationError>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub related_window: Option<RelatedWindow>,
+}
+
+impl HistoryEntry {
+    pub fn from_notification(notification: &Notification, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            timestamp,
+            notification_id: notification.id.clone(),
+            title: notification.title.clone(),
+            notification_type: notification.notification_type,
+            source: notification.source.clone(),
+            messages: notification.errors.clone(),
+            related_window: notification.related_window,
+        }
+    }
+
+    /// Whether the title, source, or a message contains `query`, ignoring case
+    pub fn contains_text(&self, query: &str) -> bool {
+        let query = query.to_lowercase();
+        self.title.to_lowercase().contains(&query)
+            || self.source.to_lowercase().contains(&query)
+            || self.messages.iter().any(|message| {
+                message.message.to_lowercase().contains(&query)
+                    || message
+                        .details
+                        .as_ref()
+                        .is_some_and(|details| details.to_lowercase().contains(&query))
+            })
+    }
+}
+
+/// How far back the history window looks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeRange {
+    LastHour,
+    #[default]
+    Last24Hours,
+    Last7Days,
+    All,
+}
+
+impl TimeRange {
+    pub const ALL: [TimeRange; 4] = [
+        TimeRange::LastHour,
+        TimeRange::Last24Hours,
+        TimeRange::Last7Days,
+        TimeRange::All,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            TimeRange::LastHour => "Last hour",
+            TimeRange::Last24Hours => "Last 24 hours",
+            TimeRange::Last7Days => "Last 7 days",
+            TimeRange::All => "All time",
+        }
+    }
+
+    /// Oldest timestamp in the range, or None for no limit
+    pub fn since(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            TimeRange::LastHour => Some(now - Duration::hours(1)),
+            TimeRange::Last24Hours => Some(now - Duration::hours(24)),
+            TimeRange::Last7Days => Some(now - Duration::days(7)),
+            TimeRange::All => None,
+        }
+    }
+}
+
+/// Search text, source, types, and time range the history is narrowed to
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistoryFilter {
+    pub search: String,
+    /// Only this source; None for every source
+    pub source: Option<String>,
+    pub types: BTreeSet<NotificationType>,
+    pub time_range: TimeRange,
+}
+
+impl Default for HistoryFilter {
+    fn default() -> Self {
+        Self {
+            search: String::new(),
+            source: None,
+            types: NotificationType::ALL.into_iter().collect(),
+            time_range: TimeRange::default(),
+        }
+    }
+}
+
+impl HistoryFilter {
+    pub fn matches(&self, entry: &HistoryEntry, now: DateTime<Utc>) -> bool {
+        if !self.types.contains(&entry.notification_type) {
+            return false;
+        }
+        if self
+            .source
+            .as_ref()
+            .is_some_and(|source| *source != entry.source)
+        {
+            return false;
+        }
+        if self
+            .time_range
+            .since(now)
+            .is_some_and(|since| entry.timestamp < since)
+        {
+            return false;
+        }
+        let search = self.search.trim();
+        search.is_empty() || entry.contains_text(search)
+    }
+}
+
+/// Recent notifications, backed by an append-only JSON Lines file
+#[derive(Debug, Default)]
+pub struct NotificationHistory {
+    entries: VecDeque<HistoryEntry>,
+    /// None keeps the history in memory only
+    path: Option<PathBuf>,
+}
+
+impl NotificationHistory {
+    /// History saved at `path`, with its most recent records loaded
+    pub fn new(path: Option<PathBuf>) -> Self {
+        let entries = match &path {
+            Some(path) if path.exists() => Self::load_from(path).unwrap_or_else(|e| {
+                tracing::warn!("Failed to load notification history: {:#}", e);
+                VecDeque::new()
+            }),
+            _ => VecDeque::new(),
+        };
+        Self { entries, path }
+    }
+
+    /// History in the application data directory
+    pub fn default_location() -> Self {
+        Self::new(dirs::data_local_dir().map(|dir| dir.join("awsdash/notification_history.jsonl")))
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Most recent records of the file; it is rewritten without older ones
+    /// once it holds more than twice as many
+    fn load_from(path: &Path) -> Result<VecDeque<HistoryEntry>> {
+        let file =
+            fs::File::open(path).with_context(|| format!("Failed to open {}", path.display()))?;
+        let lines: Vec<String> = BufReader::new(file)
+            .lines()
+            .map_while(|line| line.ok())
+            .collect();
+        let mut entries: VecDeque<HistoryEntry> = lines
+            .iter()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+        while entries.len() > MAX_HISTORY {
+            entries.pop_front();
+        }
+        if lines.len() > 2 * MAX_HISTORY {
+            Self::write_all(path, &entries)?;
+        }
+        Ok(entries)
+    }
+
+    fn write_all(path: &Path, entries: &VecDeque<HistoryEntry>) -> Result<()> {
+        let mut content = String::new();
+        for entry in entries {
+            content.push_str(&serde_json::to_string(entry)?);
+            content.push('\n');
+        }
+        fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    fn append(path: &Path, entry: &HistoryEntry) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        let line = serde_json::to_string(entry).context("Failed to serialize notification")?;
+        writeln!(file, "{}", line).context("Failed to write notification")
+    }
+
+    /// Add a record, dropping the oldest beyond [`MAX_HISTORY`]
+    pub fn record(&mut self, entry: HistoryEntry) {
+        if let Some(path) = &self.path {
+            if let Err(e) = Self::append(path, &entry) {
+                tracing::warn!("Failed to record notification history: {:#}", e);
+            }
+        }
+        self.entries.push_back(entry);
+        while self.entries.len() > MAX_HISTORY {
+            self.entries.pop_front();
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Records matching `filter`, newest first
+    pub fn filtered(&self, filter: &HistoryFilter, now: DateTime<Utc>) -> Vec<&HistoryEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|entry| filter.matches(entry, now))
+            .collect()
+    }
+
+    /// Sources seen in the history, sorted
+    pub fn sources(&self) -> Vec<String> {
+        let sources: BTreeSet<&str> = self
+            .entries
+            .iter()
+            .map(|entry| entry.source.as_str())
+            .collect();
+        sources.into_iter().map(str::to_string).collect()
+    }
+
+    /// Whether `entry` is the latest record of its notification
+    pub fn is_latest(&self, entry: &HistoryEntry) -> bool {
+        self.entries
+            .iter()
+            .rev()
+            .find(|e| e.notification_id == entry.notification_id)
+            .is_some_and(|latest| std::ptr::eq(latest, entry))
+    }
+
+    /// Forget every record, in memory and on disk
+    pub fn clear(&mut self) -> Result<()> {
+        self.entries.clear();
+        match &self.path {
+            Some(path) if path.exists() => fs::remove_file(path)
+                .with_context(|| format!("Failed to remove {}", path.display())),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(title: &str, notification_type: NotificationType, source: &str) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: Utc::now(),
+            notification_id: title.to_lowercase().replace(' ', "_"),
+            title: title.to_string(),
+            notification_type,
+            source: source.to_string(),
+            messages: vec![NotificationError {
+                message: format!("{} happened", title),
+                code: None,
+                details: Some("Check the Explorer cache".to_string()),
+            }],
+            related_window: None,
+        }
+    }
+
+    #[test]
+    fn test_filter_by_type_source_search_and_time() {
+        let now = Utc::now();
+        let mut old = entry("Task failed", NotificationType::Error, "Scheduled Tasks");
+        old.timestamp = now - Duration::days(2);
+        let recent = entry(
+            "Bucket changed",
+            NotificationType::Info,
+            "Explorer Auto-Refresh",
+        );
+        let warning = entry("Login Required", NotificationType::Warning, "Agent Manager");
+
+        let mut filter = HistoryFilter::default();
+        assert!(!filter.matches(&old, now));
+        assert!(filter.matches(&recent, now));
+
+        filter.time_range = TimeRange::All;
+        assert!(filter.matches(&old, now));
+
+        filter.types.remove(&NotificationType::Error);
+        assert!(!filter.matches(&old, now));
+
+        filter.source = Some("Agent Manager".to_string());
+        assert!(filter.matches(&warning, now));
+        assert!(!filter.matches(&recent, now));
+
+        filter.source = None;
+        filter.search = "explorer cache".to_string();
+        assert!(filter.matches(&recent, now));
+        filter.search = "BUCKET".to_string();
+        assert!(filter.matches(&recent, now));
+        assert!(!filter.matches(&warning, now));
+    }
+
+    #[test]
+    fn test_history_is_capped_and_listed_newest_first() {
+        let mut history = NotificationHistory::new(None);
+        for i in 0..MAX_HISTORY + 5 {
+            history.record(entry(
+                &format!("Update {}", i),
+                NotificationType::Info,
+                "Explorer",
+            ));
+        }
+
+        assert_eq!(history.len(), MAX_HISTORY);
+        let filter = HistoryFilter::default();
+        let entries = history.filtered(&filter, Utc::now());
+        assert_eq!(entries[0].title, format!("Update {}", MAX_HISTORY + 4));
+        assert_eq!(entries.last().unwrap().title, "Update 5");
+    }
+
+    #[test]
+    fn test_history_survives_reload_and_clear() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("notification_history.jsonl");
+        let mut history = NotificationHistory::new(Some(path.clone()));
+        history.record(entry(
+            "Task failed",
+            NotificationType::Error,
+            "Scheduled Tasks",
+        ));
+        let mut linked = entry("Login Required", NotificationType::Warning, "Agent Manager");
+        linked.related_window = Some(RelatedWindow::AwsLogin);
+        history.record(linked.clone());
+
+        let mut reloaded = NotificationHistory::new(Some(path.clone()));
+        assert_eq!(reloaded.len(), 2);
+        assert_eq!(
+            reloaded.filtered(&HistoryFilter::default(), Utc::now())[0],
+            &linked
+        );
+        assert_eq!(reloaded.sources(), vec!["Agent Manager", "Scheduled Tasks"]);
+
+        reloaded.clear().unwrap();
+        assert!(reloaded.is_empty());
+        assert!(NotificationHistory::new(Some(path)).is_empty());
+    }
+
+    #[test]
+    fn test_only_latest_record_of_a_notification_is_latest() {
+        let mut history = NotificationHistory::new(None);
+        history.record(entry("Bucket changed", NotificationType::Info, "Explorer"));
+        history.record(entry(
+            "Task failed",
+            NotificationType::Error,
+            "Scheduled Tasks",
+        ));
+        history.record(entry("Bucket changed", NotificationType::Info, "Explorer"));
+
+        let entries = history.filtered(&HistoryFilter::default(), Utc::now());
+        assert!(history.is_latest(entries[0]));
+        assert!(history.is_latest(entries[1]));
+        assert!(!history.is_latest(entries[2]));
+    }
+}
@@ -0,0 +1,260 @@
+//! Notification History Window
+//!
+//! Lists the notifications recorded in the [`NotificationHistory`], newest
+//! first, with search and filters for source, type, and time range. Entries
+//! that link to a window can open it; entries still active can be inspected or
+//! dismissed.
+
+use super::history::{HistoryEntry, HistoryFilter, NotificationHistory, TimeRange};
+use super::{NotificationManager, NotificationType};
+use crate::app::dashui::command_registry::{CommandCategory, PaletteCommand};
+use chrono::{Local, Utc};
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+
+/// Palette command id for opening the window
+pub const SHOW_NOTIFICATION_HISTORY: &str = "general.notification_history";
+
+/// Commands the window offers in the palette
+pub fn palette_commands() -> Vec<PaletteCommand> {
+    vec![PaletteCommand {
+        id: SHOW_NOTIFICATION_HISTORY,
+        label: "Show notification history",
+        description: "Search and filter past notifications, including expired and dismissed ones",
+        category: CommandCategory::General,
+        keywords: &["notifications", "errors", "warnings", "alerts", "history"],
+        is_available: |_| true,
+    }]
+}
+
+/// An entry as listed this frame
+struct ListedEntry {
+    entry: HistoryEntry,
+    /// Still shown in the status bar
+    active: bool,
+    dismissible: bool,
+}
+
+#[derive(Default)]
+pub struct NotificationHistoryWindow {
+    pub open: bool,
+    filter: HistoryFilter,
+    /// Outcome of the last action
+    message: Option<(String, bool)>,
+}
+
+impl NotificationHistoryWindow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn show(&mut self, ctx: &Context, manager: &mut NotificationManager) {
+        if !self.open {
+            return;
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Notification History")
+            .open(&mut is_open)
+            .default_size([720.0, 520.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                self.render_filters(ui, manager.history());
+                ui.separator();
+                self.render_entries(ui, manager);
+            });
+        self.open = is_open;
+    }
+
+    fn render_filters(&mut self, ui: &mut Ui, history: &NotificationHistory) {
+        ui.horizontal(|ui| {
+            ui.label("Search:");
+            ui.add(
+                egui::TextEdit::singleline(&mut self.filter.search)
+                    .hint_text("Title, source, or message")
+                    .desired_width(220.0),
+            );
+
+            let source_label = self.filter.source.as_deref().unwrap_or("All sources");
+            egui::ComboBox::from_id_salt("notification_history_source")
+                .selected_text(source_label)
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.filter.source, None, "All sources");
+                    for source in history.sources() {
+                        let label = source.clone();
+                        ui.selectable_value(&mut self.filter.source, Some(source), label);
+                    }
+                });
+
+            egui::ComboBox::from_id_salt("notification_history_time_range")
+                .selected_text(self.filter.time_range.label())
+                .show_ui(ui, |ui| {
+                    for range in TimeRange::ALL {
+                        ui.selectable_value(&mut self.filter.time_range, range, range.label());
+                    }
+                });
+        });
+
+        ui.horizontal(|ui| {
+            for notification_type in NotificationType::ALL {
+                let mut shown = self.filter.types.contains(&notification_type);
+                let text = RichText::new(format!(
+                    "{} {}",
+                    notification_type.icon(),
+                    notification_type.label()
+                ))
+                .color(notification_type.color());
+                if ui.checkbox(&mut shown, text).changed() {
+                    if shown {
+                        self.filter.types.insert(notification_type);
+                    } else {
+                        self.filter.types.remove(&notification_type);
+                    }
+                }
+            }
+        });
+    }
+
+    fn render_entries(&mut self, ui: &mut Ui, manager: &mut NotificationManager) {
+        let history = manager.history();
+        let listed: Vec<ListedEntry> = history
+            .filtered(&self.filter, Utc::now())
+            .into_iter()
+            .map(|entry| {
+                let current = manager
+                    .get_notification(&entry.notification_id)
+                    .filter(|_| history.is_latest(entry));
+                ListedEntry {
+                    entry: entry.clone(),
+                    active: current.is_some(),
+                    dismissible: current.is_some_and(|n| n.dismissible),
+                }
+            })
+            .collect();
+        let total = history.len();
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new(format!("{} of {} notifications", listed.len(), total)).small());
+            ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                if ui
+                    .add_enabled(total > 0, egui::Button::new("Clear History"))
+                    .on_hover_text("Forget every recorded notification; active ones stay")
+                    .clicked()
+                {
+                    self.message = Some(match manager.history_mut().clear() {
+                        Ok(()) => ("History cleared".to_string(), false),
+                        Err(e) => (format!("{:#}", e), true),
+                    });
+                }
+            });
+        });
+        if let Some((message, is_error)) = &self.message {
+            let color = if *is_error {
+                Color32::from_rgb(220, 80, 80)
+            } else {
+                Color32::GRAY
+            };
+            ui.label(RichText::new(message).small().color(color));
+        }
+
+        if listed.is_empty() {
+            ui.add_space(8.0);
+            ui.label(RichText::new("No notifications match the filters").italics());
+            return;
+        }
+
+        ScrollArea::vertical()
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                for (index, listed) in listed.iter().enumerate() {
+                    Self::render_entry(ui, index, listed, manager);
+                }
+            });
+    }
+
+    fn render_entry(
+        ui: &mut Ui,
+        index: usize,
+        listed: &ListedEntry,
+        manager: &mut NotificationManager,
+    ) {
+        let entry = &listed.entry;
+        let notification_type = entry.notification_type;
+        let time = entry
+            .timestamp
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M:%S");
+        let mut header = RichText::new(format!(
+            "{} {}  {}",
+            notification_type.icon(),
+            time,
+            entry.title
+        ))
+        .color(notification_type.color());
+        if listed.active {
+            header = header.strong();
+        }
+
+        egui::CollapsingHeader::new(header)
+            .id_salt(("notification_history_entry", index, entry.timestamp))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(RichText::new("Source:").strong());
+                    ui.label(&entry.source);
+                    if listed.active {
+                        ui.label(RichText::new("active").small().weak());
+                    }
+                });
+                for message in &entry.messages {
+                    ui.label(&message.message);
+                    if let Some(code) = &message.code {
+                        ui.label(RichText::new(format!("Code: {}", code)).size(11.0).weak());
+                    }
+                    if let Some(details) = &message.details {
+                        ui.label(RichText::new(details).size(11.0).weak());
+                    }
+                }
+
+                ui.horizontal(|ui| {
+                    if let Some(window) = entry.related_window {
+                        if ui.button(format!("Open {}", window.label())).clicked() {
+                            manager.request_related_window(window);
+                        }
+                    }
+                    if listed.active && ui.button("Details").clicked() {
+                        manager.show_notification_details(entry.notification_id.clone());
+                    }
+                    if listed.dismissible && ui.button("Dismiss").clicked() {
+                        manager.dismiss_notification(&entry.notification_id);
+                    }
+                    if ui.button("Copy").clicked() {
+                        ui.ctx().copy_text(Self::clipboard_text(entry));
+                    }
+                });
+            });
+    }
+
+    fn clipboard_text(entry: &HistoryEntry) -> String {
+        let mut text = format!(
+            "{} {} - {}\nSource: {}\nTime: {}\n",
+            entry.notification_type.icon(),
+            entry.notification_type.label(),
+            entry.title,
+            entry.source,
+            entry.timestamp.with_timezone(&Local).to_rfc3339()
+        );
+        for message in &entry.messages {
+            text.push_str(&format!("• {}\n", message.message));
+            if let Some(code) = &message.code {
+                text.push_str(&format!("  Code: {}\n", code));
+            }
+            if let Some(details) = &message.details {
+                text.push_str(&format!("  Details: {}\n", details));
+            }
+        }
+        text
+    }
+}
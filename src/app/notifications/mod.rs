@@ -1,11 +1,19 @@
+use chrono::Utc;
 use egui::Color32;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::time::{Duration, Instant};
 
 pub mod error_window;
+pub mod history;
+pub mod history_window;
+pub mod sinks;
+pub mod sinks_window;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use history::{HistoryEntry, NotificationHistory};
+use sinks::NotificationSinks;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum NotificationType {
     Error,
     Warning,
@@ -15,7 +23,69 @@ pub enum NotificationType {
     DeploymentStatus,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl NotificationType {
+    pub const ALL: [NotificationType; 5] = [
+        NotificationType::Error,
+        NotificationType::Warning,
+        NotificationType::Info,
+        NotificationType::Success,
+        NotificationType::DeploymentStatus,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            NotificationType::Error => "Error",
+            NotificationType::Warning => "Warning",
+            NotificationType::Info => "Info",
+            NotificationType::Success => "Success",
+            NotificationType::DeploymentStatus => "Deployment",
+        }
+    }
+
+    pub fn color(&self) -> Color32 {
+        match self {
+            NotificationType::Error => Color32::from_rgb(220, 50, 50),
+            NotificationType::Warning => Color32::from_rgb(255, 150, 0),
+            NotificationType::Info | NotificationType::DeploymentStatus => {
+                Color32::from_rgb(70, 130, 200)
+            }
+            NotificationType::Success => Color32::from_rgb(40, 180, 40),
+        }
+    }
+
+    pub fn icon(&self) -> &'static str {
+        match self {
+            NotificationType::Error => "✗",
+            NotificationType::Warning => "⚠",
+            NotificationType::Info => "ℹ",
+            NotificationType::Success => "✓",
+            NotificationType::DeploymentStatus => "☁",
+        }
+    }
+}
+
+/// Window a notification links to, opened from its details or history entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelatedWindow {
+    AwsLogin,
+    Explorer,
+    AgentManager,
+    AgentSchedules,
+}
+
+impl RelatedWindow {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RelatedWindow::AwsLogin => "AWS Login",
+            RelatedWindow::Explorer => "Explorer",
+            RelatedWindow::AgentManager => "Agent Manager",
+            RelatedWindow::AgentSchedules => "Scheduled Tasks",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NotificationError {
     pub message: String,
     pub code: Option<String>,
@@ -37,6 +107,10 @@ pub struct Notification {
 
     /// Additional data for deployment status notifications
     pub deployment_data: Option<DeploymentNotificationData>,
+
+    /// Window the notification is about
+    #[serde(default)]
+    pub related_window: Option<RelatedWindow>,
 }
 
 /// Additional data for deployment status notifications
@@ -65,6 +139,7 @@ impl Notification {
             dismissible: true,
             source,
             deployment_data: None,
+            related_window: None,
         }
     }
 
@@ -84,6 +159,7 @@ impl Notification {
             dismissible: true,
             source,
             deployment_data: None,
+            related_window: None,
         }
     }
 
@@ -102,6 +178,7 @@ impl Notification {
             dismissible: true,
             source,
             deployment_data: None,
+            related_window: None,
         }
     }
 
@@ -120,6 +197,7 @@ impl Notification {
             dismissible: true,
             source,
             deployment_data: None,
+            related_window: None,
         }
     }
 
@@ -150,9 +228,16 @@ impl Notification {
                 deployment_id,
                 is_polling,
             }),
+            related_window: None,
         }
     }
 
+    /// Link the notification to the window it is about
+    pub fn with_related_window(mut self, window: RelatedWindow) -> Self {
+        self.related_window = Some(window);
+        self
+    }
+
     pub fn is_expired(&self) -> bool {
         if let Some(expires_at) = self.expires_at {
             Instant::now() > expires_at
@@ -163,10 +248,6 @@ impl Notification {
 
     pub fn get_color(&self) -> Color32 {
         match self.notification_type {
-            NotificationType::Error => Color32::from_rgb(220, 50, 50),
-            NotificationType::Warning => Color32::from_rgb(255, 150, 0),
-            NotificationType::Info => Color32::from_rgb(70, 130, 200),
-            NotificationType::Success => Color32::from_rgb(40, 180, 40),
             NotificationType::DeploymentStatus => {
                 // Color based on deployment data if available
                 if let Some(deployment_data) = &self.deployment_data {
@@ -179,15 +260,12 @@ impl Notification {
                     Color32::from_rgb(70, 130, 200) // Default blue
                 }
             }
+            notification_type => notification_type.color(),
         }
     }
 
     pub fn get_icon(&self) -> &'static str {
         match self.notification_type {
-            NotificationType::Error => "✗",
-            NotificationType::Warning => "⚠",
-            NotificationType::Info => "ℹ",
-            NotificationType::Success => "✓",
             NotificationType::DeploymentStatus => {
                 if let Some(deployment_data) = &self.deployment_data {
                     if deployment_data.is_polling {
@@ -199,6 +277,7 @@ impl Notification {
                     "☁"
                 }
             }
+            notification_type => notification_type.icon(),
         }
     }
 }
@@ -208,6 +287,12 @@ pub struct NotificationManager {
     notifications: HashMap<String, Notification>,
     pub show_details_window: bool,
     pub selected_notification_id: Option<String>,
+    /// Every notification added, kept after it expires or is dismissed
+    history: NotificationHistory,
+    /// Related window a details or history link asked to open
+    related_window_request: Option<RelatedWindow>,
+    /// Webhooks notifications are forwarded to
+    sinks: NotificationSinks,
 }
 
 impl NotificationManager {
@@ -216,14 +301,45 @@ impl NotificationManager {
             notifications: HashMap::new(),
             show_details_window: false,
             selected_notification_id: None,
+            history: NotificationHistory::default_location(),
+            related_window_request: None,
+            sinks: NotificationSinks::default_location(),
         }
     }
 
     pub fn add_notification(&mut self, notification: Notification) {
+        let entry = HistoryEntry::from_notification(&notification, Utc::now());
+        self.sinks.dispatch(&entry);
+        self.history.record(entry);
         self.notifications
             .insert(notification.id.clone(), notification);
     }
 
+    pub fn history(&self) -> &NotificationHistory {
+        &self.history
+    }
+
+    pub fn history_mut(&mut self) -> &mut NotificationHistory {
+        &mut self.history
+    }
+
+    pub fn sinks(&self) -> &NotificationSinks {
+        &self.sinks
+    }
+
+    pub fn sinks_mut(&mut self) -> &mut NotificationSinks {
+        &mut self.sinks
+    }
+
+    /// Ask the app to open the window a notification links to
+    pub fn request_related_window(&mut self, window: RelatedWindow) {
+        self.related_window_request = Some(window);
+    }
+
+    pub fn take_related_window_request(&mut self) -> Option<RelatedWindow> {
+        self.related_window_request.take()
+    }
+
     pub fn dismiss_notification(&mut self, id: &str) {
         self.notifications.remove(id);
         if let Some(selected_id) = &self.selected_notification_id {
@@ -281,6 +397,13 @@ impl NotificationManager {
             .count()
     }
 
+    pub fn get_info_count(&self) -> usize {
+        self.notifications
+            .values()
+            .filter(|n| matches!(n.notification_type, NotificationType::Info))
+            .count()
+    }
+
     pub fn show_notification_details(&mut self, notification_id: String) {
         self.selected_notification_id = Some(notification_id);
         self.show_details_window = true;
@@ -336,6 +459,7 @@ impl NotificationManager {
 
         let error_count = self.get_error_count();
         let warning_count = self.get_warning_count();
+        let info_count = self.get_info_count();
 
         // Check if we have deployment status notifications
         let has_deployment_notifications = self
@@ -343,8 +467,8 @@ impl NotificationManager {
             .values()
             .any(|n| matches!(n.notification_type, NotificationType::DeploymentStatus));
 
-        // Always show if we have deployment status, errors, or warnings
-        if error_count > 0 || warning_count > 0 || has_deployment_notifications {
+        // Always show if we have deployment status, errors, warnings, or updates
+        if error_count > 0 || warning_count > 0 || info_count > 0 || has_deployment_notifications {
             ui.separator();
 
             // Show deployment status notifications first (permanent)
@@ -448,6 +572,31 @@ impl NotificationManager {
                     }
                 }
             }
+
+            if info_count > 0 {
+                let info_text = if info_count == 1 {
+                    "1 update".to_string()
+                } else {
+                    format!("{} updates", info_count)
+                };
+
+                if ui
+                    .colored_label(
+                        Color32::from_rgb(70, 140, 220),
+                        format!("Info: {}", info_text),
+                    )
+                    .clicked()
+                {
+                    // Find the first info notification and show it
+                    if let Some(info_notification) = self
+                        .get_active_notifications()
+                        .iter()
+                        .find(|n| matches!(n.notification_type, NotificationType::Info))
+                    {
+                        self.show_notification_details(info_notification.id.clone());
+                    }
+                }
+            }
         }
     }
 }
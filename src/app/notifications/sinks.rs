@@ -0,0 +1,495 @@
+//! Notification sinks
+//!
+//! Forwards notifications at or above a chosen severity to Slack or Microsoft
+//! Teams incoming webhooks, or as JSON to any HTTP endpoint, so people who are
+//! not watching the app hear about failures. Sinks are configured per project
+//! (the Identity Center organization, see [`project_key`]) and saved in
+//! `{data_local_dir}/awsdash/notification_sinks/{project}.json`.
+//!
+//! Deliveries are posted from a background thread, with the timeout set in
+//! Settings. Failures are logged and reported to the Notification Sinks
+//! window, never as notifications, which would be forwarded again.
+//!
+//! [`project_key`]: crate::app::agent_framework::conversation::memory::project_key
+
+use super::history::HistoryEntry;
+use super::NotificationType;
+use crate::app::agent_framework::conversation::memory::DEFAULT_PROJECT;
+use crate::app::app_config::app_config;
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::time::Duration;
+
+/// How serious a notification is, for a sink's threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// Info, success, and deployment status
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 3] = [Severity::Info, Severity::Warning, Severity::Error];
+
+    pub fn of(notification_type: NotificationType) -> Self {
+        match notification_type {
+            NotificationType::Error => Severity::Error,
+            NotificationType::Warning => Severity::Warning,
+            NotificationType::Info
+            | NotificationType::Success
+            | NotificationType::DeploymentStatus => Severity::Info,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Info => "Everything",
+            Severity::Warning => "Warnings and errors",
+            Severity::Error => "Errors only",
+        }
+    }
+}
+
+/// Service a sink posts to, which decides the payload format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SinkKind {
+    /// Slack incoming webhook
+    Slack,
+    /// Microsoft Teams incoming webhook (MessageCard)
+    Teams,
+    /// Any endpoint accepting the notification as JSON
+    Http,
+}
+
+impl SinkKind {
+    pub const ALL: [SinkKind; 3] = [SinkKind::Slack, SinkKind::Teams, SinkKind::Http];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            SinkKind::Slack => "Slack",
+            SinkKind::Teams => "Microsoft Teams",
+            SinkKind::Http => "HTTP (JSON)",
+        }
+    }
+}
+
+/// One webhook notifications are forwarded to
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NotificationSink {
+    pub name: String,
+    pub kind: SinkKind,
+    pub url: String,
+    /// Least severe notifications forwarded
+    pub min_severity: Severity,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl NotificationSink {
+    pub fn new(name: impl Into<String>, kind: SinkKind) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            url: String::new(),
+            min_severity: Severity::Error,
+            enabled: true,
+        }
+    }
+
+    /// Whether this sink forwards `entry`
+    pub fn accepts(&self, entry: &HistoryEntry) -> bool {
+        self.enabled
+            && !self.url.trim().is_empty()
+            && Severity::of(entry.notification_type) >= self.min_severity
+    }
+
+    /// Request body for `entry` in the format the sink's service expects
+    pub fn payload(&self, project: &str, entry: &HistoryEntry) -> Value {
+        let notification_type = entry.notification_type;
+        let messages: Vec<String> = entry
+            .messages
+            .iter()
+            .map(|message| match &message.details {
+                Some(details) => format!("{}\n{}", message.message, details),
+                None => message.message.clone(),
+            })
+            .collect();
+
+        match self.kind {
+            SinkKind::Slack => json!({
+                "text": format!(
+                    "*{} {}: {}*\n{} · {}\n{}",
+                    notification_type.icon(),
+                    notification_type.label(),
+                    entry.title,
+                    entry.source,
+                    project,
+                    messages.join("\n")
+                ),
+            }),
+            SinkKind::Teams => {
+                let color = notification_type.color();
+                json!({
+                    "@type": "MessageCard",
+                    "@context": "https://schema.org/extensions",
+                    "summary": entry.title,
+                    "themeColor": format!("{:02X}{:02X}{:02X}", color.r(), color.g(), color.b()),
+                    "title": format!("{} {}", notification_type.icon(), entry.title),
+                    "text": messages.join("\n\n"),
+                    "sections": [{
+                        "facts": [
+                            { "name": "Type", "value": notification_type.label() },
+                            { "name": "Source", "value": entry.source },
+                            { "name": "Project", "value": project },
+                        ],
+                    }],
+                })
+            }
+            SinkKind::Http => json!({
+                "project": project,
+                "severity": Severity::of(notification_type),
+                "notification": entry,
+            }),
+        }
+    }
+}
+
+/// Sinks of one project
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SinkConfig {
+    pub project: String,
+    #[serde(default)]
+    pub sinks: Vec<NotificationSink>,
+}
+
+impl SinkConfig {
+    pub fn new(project: &str) -> Self {
+        Self {
+            project: project.to_string(),
+            sinks: Vec::new(),
+        }
+    }
+}
+
+/// Directory of per-project sink configurations
+#[derive(Debug, Clone)]
+pub struct SinkStore {
+    directory: PathBuf,
+}
+
+impl SinkStore {
+    /// Create a store rooted at `directory`
+    pub fn new(directory: PathBuf) -> Self {
+        Self { directory }
+    }
+
+    /// Store in the application data directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        Some(Self::new(
+            dirs::data_local_dir()?.join("awsdash/notification_sinks"),
+        ))
+    }
+
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn path(&self, project: &str) -> PathBuf {
+        self.directory.join(format!("{}.json", project))
+    }
+
+    /// Sinks of `project`; none if nothing was saved
+    pub fn load(&self, project: &str) -> Result<SinkConfig> {
+        let path = self.path(project);
+        if !path.exists() {
+            return Ok(SinkConfig::new(project));
+        }
+        let json = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Write (or overwrite) the file for `config.project`
+    pub fn save(&self, config: &SinkConfig) -> Result<()> {
+        fs::create_dir_all(&self.directory)
+            .with_context(|| format!("Failed to create {}", self.directory.display()))?;
+        let path = self.path(&config.project);
+        let json = serde_json::to_string_pretty(config)?;
+        fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+/// A payload waiting to be posted
+struct Delivery {
+    sink: String,
+    url: String,
+    payload: Value,
+}
+
+/// Outcome of posting to a sink
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeliveryResult {
+    pub sink: String,
+    pub at: DateTime<Utc>,
+    pub result: Result<(), String>,
+}
+
+/// Thread posting deliveries in order and reporting each outcome
+fn spawn_delivery_worker(results: Sender<DeliveryResult>) -> Sender<Delivery> {
+    let (sender, receiver) = mpsc::channel::<Delivery>();
+    let timeout = app_config().notifications.delivery_timeout_secs;
+    std::thread::spawn(move || {
+        let client = match reqwest::blocking::Client::builder()
+            .timeout(Duration::from_secs(timeout))
+            .build()
+        {
+            Ok(client) => client,
+            Err(e) => {
+                tracing::warn!("Notification sinks disabled: {}", e);
+                return;
+            }
+        };
+        for delivery in receiver {
+            let result = client
+                .post(&delivery.url)
+                .json(&delivery.payload)
+                .send()
+                .and_then(|response| response.error_for_status())
+                .map(|_| ())
+                .map_err(|e| e.without_url().to_string());
+            if let Err(e) = &result {
+                tracing::warn!("Notification sink '{}' failed: {}", delivery.sink, e);
+            }
+            let _ = results.send(DeliveryResult {
+                sink: delivery.sink,
+                at: Utc::now(),
+                result,
+            });
+        }
+    });
+    sender
+}
+
+/// Sinks of the current project and the deliveries made to them
+pub struct NotificationSinks {
+    store: Option<SinkStore>,
+    config: SinkConfig,
+    /// Started with the first delivery
+    deliveries: Option<Sender<Delivery>>,
+    results_sender: Sender<DeliveryResult>,
+    results: Receiver<DeliveryResult>,
+    /// Latest outcome per sink name
+    last_results: HashMap<String, DeliveryResult>,
+}
+
+impl Default for NotificationSinks {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
+impl NotificationSinks {
+    /// Sinks from `store`, starting with the default project
+    pub fn new(store: Option<SinkStore>) -> Self {
+        let (results_sender, results) = mpsc::channel();
+        let mut sinks = Self {
+            store,
+            config: SinkConfig::new(DEFAULT_PROJECT),
+            deliveries: None,
+            results_sender,
+            results,
+            last_results: HashMap::new(),
+        };
+        sinks.config = sinks.load(DEFAULT_PROJECT);
+        sinks
+    }
+
+    /// Sinks in the application data directory
+    pub fn default_location() -> Self {
+        Self::new(SinkStore::default_location())
+    }
+
+    fn load(&self, project: &str) -> SinkConfig {
+        let Some(store) = &self.store else {
+            return SinkConfig::new(project);
+        };
+        store.load(project).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring notification sinks of {}: {:#}", project, e);
+            SinkConfig::new(project)
+        })
+    }
+
+    pub fn config(&self) -> &SinkConfig {
+        &self.config
+    }
+
+    pub fn project(&self) -> &str {
+        &self.config.project
+    }
+
+    /// Switch to the sinks of `project`, e.g. after signing in
+    pub fn set_project(&mut self, project: &str) {
+        if self.config.project != project {
+            self.config = self.load(project);
+            self.last_results.clear();
+        }
+    }
+
+    /// Save `config` and use it from now on
+    pub fn save_config(&mut self, config: SinkConfig) -> Result<()> {
+        if let Some(store) = &self.store {
+            store.save(&config)?;
+        }
+        self.config = config;
+        Ok(())
+    }
+
+    /// Forward `entry` to every sink that accepts it
+    pub fn dispatch(&mut self, entry: &HistoryEntry) {
+        let accepting: Vec<NotificationSink> = self
+            .config
+            .sinks
+            .iter()
+            .filter(|sink| sink.accepts(entry))
+            .cloned()
+            .collect();
+        for sink in accepting {
+            self.send(&sink, entry);
+        }
+    }
+
+    /// Post `entry` to `sink` whatever its threshold, e.g. to try a new webhook
+    pub fn send(&mut self, sink: &NotificationSink, entry: &HistoryEntry) {
+        let delivery = Delivery {
+            sink: sink.name.clone(),
+            url: sink.url.trim().to_string(),
+            payload: sink.payload(&self.config.project, entry),
+        };
+        let deliveries = self
+            .deliveries
+            .get_or_insert_with(|| spawn_delivery_worker(self.results_sender.clone()));
+        if deliveries.send(delivery).is_err() {
+            tracing::warn!("Notification sink worker stopped; '{}' not sent", sink.name);
+            self.deliveries = None;
+        }
+    }
+
+    /// Latest outcome of posting to the sink named `sink`
+    pub fn last_result(&mut self, sink: &str) -> Option<&DeliveryResult> {
+        while let Ok(result) = self.results.try_recv() {
+            self.last_results.insert(result.sink.clone(), result);
+        }
+        self.last_results.get(sink)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::notifications::NotificationError;
+
+    fn entry(notification_type: NotificationType) -> HistoryEntry {
+        HistoryEntry {
+            timestamp: Utc::now(),
+            notification_id: "deployment_failed_prod".to_string(),
+            title: "Deployment failed".to_string(),
+            notification_type,
+            source: "CloudFormation Deployment".to_string(),
+            messages: vec![NotificationError {
+                message: "Stack prod-api rolled back".to_string(),
+                code: Some("ROLLBACK_COMPLETE".to_string()),
+                details: Some("Resource LambdaRole failed to create".to_string()),
+            }],
+            related_window: None,
+        }
+    }
+
+    fn sink(kind: SinkKind, min_severity: Severity) -> NotificationSink {
+        NotificationSink {
+            url: "https://hooks.example.com/T000/B000".to_string(),
+            min_severity,
+            ..NotificationSink::new("Team channel", kind)
+        }
+    }
+
+    #[test]
+    fn test_sink_accepts_notifications_at_or_above_its_severity() {
+        let errors_only = sink(SinkKind::Slack, Severity::Error);
+        assert!(errors_only.accepts(&entry(NotificationType::Error)));
+        assert!(!errors_only.accepts(&entry(NotificationType::Warning)));
+
+        let warnings = sink(SinkKind::Slack, Severity::Warning);
+        assert!(warnings.accepts(&entry(NotificationType::Error)));
+        assert!(warnings.accepts(&entry(NotificationType::Warning)));
+        assert!(!warnings.accepts(&entry(NotificationType::Success)));
+
+        let mut disabled = sink(SinkKind::Slack, Severity::Info);
+        disabled.enabled = false;
+        assert!(!disabled.accepts(&entry(NotificationType::Error)));
+
+        let mut no_url = sink(SinkKind::Slack, Severity::Info);
+        no_url.url = "  ".to_string();
+        assert!(!no_url.accepts(&entry(NotificationType::Error)));
+    }
+
+    #[test]
+    fn test_payloads_match_each_service() {
+        let failed = entry(NotificationType::Error);
+
+        let slack = sink(SinkKind::Slack, Severity::Error).payload("d-123.awsapps.com", &failed);
+        let text = slack["text"].as_str().unwrap();
+        assert!(text.starts_with("*✗ Error: Deployment failed*"));
+        assert!(text.contains("d-123.awsapps.com"));
+        assert!(text.contains("Resource LambdaRole failed to create"));
+
+        let teams = sink(SinkKind::Teams, Severity::Error).payload("d-123.awsapps.com", &failed);
+        assert_eq!(teams["@type"], "MessageCard");
+        assert_eq!(teams["themeColor"], "DC3232");
+        assert_eq!(
+            teams["sections"][0]["facts"][1]["value"],
+            "CloudFormation Deployment"
+        );
+
+        let http = sink(SinkKind::Http, Severity::Error).payload("d-123.awsapps.com", &failed);
+        assert_eq!(http["project"], "d-123.awsapps.com");
+        assert_eq!(http["severity"], "error");
+        assert_eq!(http["notification"]["notificationType"], "Error");
+        assert_eq!(
+            http["notification"]["messages"][0]["code"],
+            "ROLLBACK_COMPLETE"
+        );
+    }
+
+    #[test]
+    fn test_sinks_are_saved_per_project() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SinkStore::new(dir.path().to_path_buf());
+        let mut sinks = NotificationSinks::new(Some(store.clone()));
+        sinks.set_project("d-123.awsapps.com");
+        let mut config = sinks.config().clone();
+        config.sinks.push(sink(SinkKind::Teams, Severity::Warning));
+        sinks.save_config(config.clone()).unwrap();
+
+        sinks.set_project("d-456.awsapps.com");
+        assert!(sinks.config().sinks.is_empty());
+
+        let reloaded = NotificationSinks::new(Some(store));
+        assert!(reloaded.config().sinks.is_empty());
+        let mut reloaded = reloaded;
+        reloaded.set_project("d-123.awsapps.com");
+        assert_eq!(reloaded.config(), &config);
+    }
+}
@@ -0,0 +1,255 @@
+//! Notification Sinks Window
+//!
+//! Edits the webhooks the current project's notifications are forwarded to:
+//! the service, URL, and least severity of each, and whether it is enabled.
+//! A test message can be sent to a sink before saving, and the outcome of the
+//! last delivery to each sink is shown next to it.
+
+use super::history::HistoryEntry;
+use super::sinks::{NotificationSink, Severity, SinkConfig, SinkKind};
+use super::{NotificationError, NotificationManager, NotificationType};
+use crate::app::app_config::app_config;
+use crate::app::dashui::command_registry::{CommandCategory, PaletteCommand};
+use chrono::{Local, Utc};
+use egui::{Color32, Context, RichText, ScrollArea, Ui};
+
+/// Palette command id for opening the window
+pub const SHOW_NOTIFICATION_SINKS: &str = "general.notification_sinks";
+
+/// Commands the window offers in the palette
+pub fn palette_commands() -> Vec<PaletteCommand> {
+    vec![PaletteCommand {
+        id: SHOW_NOTIFICATION_SINKS,
+        label: "Configure notification webhooks",
+        description: "Forward notifications to Slack, Microsoft Teams, or an HTTP endpoint",
+        category: CommandCategory::General,
+        keywords: &[
+            "notifications",
+            "webhook",
+            "slack",
+            "teams",
+            "alerts",
+            "sinks",
+        ],
+        is_available: |_| true,
+    }]
+}
+
+/// Message posted by "Send Test"
+fn test_entry() -> HistoryEntry {
+    HistoryEntry {
+        timestamp: Utc::now(),
+        notification_id: "notification_sink_test".to_string(),
+        title: "Test notification from AWS Dash".to_string(),
+        notification_type: NotificationType::Info,
+        source: "Notification Sinks".to_string(),
+        messages: vec![NotificationError {
+            message: "This webhook will receive AWS Dash notifications".to_string(),
+            code: None,
+            details: None,
+        }],
+        related_window: None,
+    }
+}
+
+#[derive(Default)]
+pub struct NotificationSinksWindow {
+    open: bool,
+    /// Sinks being edited
+    config: Option<SinkConfig>,
+    /// Outcome of the last action
+    message: Option<(String, bool)>,
+}
+
+impl NotificationSinksWindow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the window on the sinks of the manager's current project
+    pub fn open(&mut self, manager: &NotificationManager) {
+        self.open = true;
+        self.message = None;
+        self.config = Some(manager.sinks().config().clone());
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn show(&mut self, ctx: &Context, manager: &mut NotificationManager) {
+        if !self.open {
+            return;
+        }
+        // The project changed (sign-in or sign-out) while the window was open
+        if self
+            .config
+            .as_ref()
+            .map_or(true, |config| config.project != manager.sinks().project())
+        {
+            self.config = Some(manager.sinks().config().clone());
+        }
+
+        let mut is_open = self.open;
+        egui::Window::new("Notification Webhooks")
+            .open(&mut is_open)
+            .default_size([640.0, 420.0])
+            .resizable(true)
+            .show(ctx, |ui| {
+                self.render_contents(ui, manager);
+            });
+        self.open = is_open;
+    }
+
+    fn render_contents(&mut self, ui: &mut Ui, manager: &mut NotificationManager) {
+        let Some(config) = self.config.as_mut() else {
+            return;
+        };
+        let modified = config != manager.sinks().config();
+
+        ui.horizontal(|ui| {
+            ui.label(RichText::new("Project:").strong());
+            ui.label(&config.project);
+        });
+        ui.label(
+            RichText::new("Notifications at or above each sink's severity are posted to it")
+                .small()
+                .weak(),
+        );
+        ui.separator();
+
+        let mut remove = None;
+        let mut test = None;
+        ScrollArea::vertical()
+            .auto_shrink([false, true])
+            .max_height(300.0)
+            .show(ui, |ui| {
+                if config.sinks.is_empty() {
+                    ui.label(RichText::new("No webhooks configured").italics());
+                }
+                for (index, sink) in config.sinks.iter_mut().enumerate() {
+                    ui.push_id(("notification_sink", index), |ui| {
+                        Self::render_sink(ui, sink);
+                        ui.horizontal(|ui| {
+                            if ui
+                                .add_enabled(
+                                    !sink.url.trim().is_empty(),
+                                    egui::Button::new("Send Test"),
+                                )
+                                .clicked()
+                            {
+                                test = Some(sink.clone());
+                            }
+                            if ui.button("Remove").clicked() {
+                                remove = Some(index);
+                            }
+                            if let Some(delivery) = manager.sinks_mut().last_result(&sink.name) {
+                                let time = delivery.at.with_timezone(&Local).format("%H:%M:%S");
+                                let (text, color) = match &delivery.result {
+                                    Ok(()) => (format!("Delivered at {}", time), Color32::GRAY),
+                                    Err(e) => (
+                                        format!("Failed at {}: {}", time, e),
+                                        Color32::from_rgb(220, 80, 80),
+                                    ),
+                                };
+                                ui.label(RichText::new(text).small().color(color));
+                            }
+                        });
+                    });
+                    ui.separator();
+                }
+            });
+
+        if let Some(index) = remove {
+            config.sinks.remove(index);
+        }
+        if let Some(sink) = test {
+            manager.sinks_mut().send(&sink, &test_entry());
+            self.message = Some((format!("Sending test message to '{}'", sink.name), false));
+        }
+
+        ui.horizontal(|ui| {
+            if ui.button("Add Webhook").clicked() {
+                let name = format!("Webhook {}", config.sinks.len() + 1);
+                config.sinks.push(NotificationSink {
+                    min_severity: app_config().notifications.new_webhook_severity,
+                    ..NotificationSink::new(name, SinkKind::Slack)
+                });
+            }
+            if ui
+                .add_enabled(modified, egui::Button::new("Save"))
+                .clicked()
+            {
+                config.sinks.retain(|sink| !sink.url.trim().is_empty());
+                self.message = Some(match manager.sinks_mut().save_config(config.clone()) {
+                    Ok(()) => {
+                        tracing::info!(
+                            "Saved {} notification sinks for project {}",
+                            config.sinks.len(),
+                            config.project
+                        );
+                        ("Saved".to_string(), false)
+                    }
+                    Err(e) => (format!("{:#}", e), true),
+                });
+            }
+            if ui
+                .add_enabled(modified, egui::Button::new("Revert"))
+                .clicked()
+            {
+                *config = manager.sinks().config().clone();
+                self.message = None;
+            }
+        });
+        if let Some((message, is_error)) = &self.message {
+            let color = if *is_error {
+                Color32::from_rgb(220, 80, 80)
+            } else {
+                Color32::GRAY
+            };
+            ui.label(RichText::new(message).small().color(color));
+        }
+    }
+
+    fn render_sink(ui: &mut Ui, sink: &mut NotificationSink) {
+        egui::Grid::new("notification_sink_fields")
+            .num_columns(2)
+            .spacing([8.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("Name:");
+                ui.horizontal(|ui| {
+                    ui.add(egui::TextEdit::singleline(&mut sink.name).desired_width(200.0));
+                    ui.checkbox(&mut sink.enabled, "Enabled");
+                });
+                ui.end_row();
+
+                ui.label("Service:");
+                egui::ComboBox::from_id_salt("notification_sink_kind")
+                    .selected_text(sink.kind.label())
+                    .show_ui(ui, |ui| {
+                        for kind in SinkKind::ALL {
+                            ui.selectable_value(&mut sink.kind, kind, kind.label());
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("URL:");
+                ui.add(
+                    egui::TextEdit::singleline(&mut sink.url)
+                        .hint_text("https://hooks.slack.com/services/...")
+                        .desired_width(420.0),
+                );
+                ui.end_row();
+
+                ui.label("Send:");
+                egui::ComboBox::from_id_salt("notification_sink_severity")
+                    .selected_text(sink.min_severity.label())
+                    .show_ui(ui, |ui| {
+                        for severity in Severity::ALL {
+                            ui.selectable_value(&mut sink.min_severity, severity, severity.label());
+                        }
+                    });
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,314 @@
+//! Adaptive concurrency and throttling backoff for AWS list calls.
+//!
+//! Each AWS service gets its own in-flight limit on top of the global query
+//! semaphore. A throttling response halves the service's limit and starts an
+//! exponential backoff with jitter; calls for that service wait out the
+//! backoff before starting. Successful calls grow the limit back one step at a
+//! time. Throttled services are reported to the status channel so the status
+//! bar shows which service is being slowed down.
+
+use super::status::global_status;
+use once_cell::sync::Lazy;
+use rand::Rng;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default starting (and maximum) number of in-flight calls per service
+pub const DEFAULT_SERVICE_CONCURRENCY: usize = 10;
+
+/// Upper bound for the per-service concurrency setting
+pub const SERVICE_CONCURRENCY_LIMIT: usize = 50;
+
+/// Throttled calls are retried this many times before the error is returned
+pub const MAX_THROTTLE_RETRIES: u32 = 4;
+
+/// Backoff after the first throttle; doubles for each consecutive one
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Upper bound for a single backoff
+const MAX_BACKOFF: Duration = Duration::from_secs(20);
+
+/// How often a waiting call re-checks for a free slot
+const POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+static LIMITER: Lazy<AdaptiveLimiter> = Lazy::new(AdaptiveLimiter::new);
+
+static MAX_SERVICE_CONCURRENCY: AtomicUsize = AtomicUsize::new(DEFAULT_SERVICE_CONCURRENCY);
+
+/// Set the starting (and maximum) number of in-flight calls per service (1-50)
+pub fn set_max_service_concurrency(max: usize) {
+    let max = max.clamp(1, SERVICE_CONCURRENCY_LIMIT);
+    MAX_SERVICE_CONCURRENCY.store(max, Ordering::SeqCst);
+}
+
+/// Starting (and maximum) number of in-flight calls per service
+pub fn max_service_concurrency() -> usize {
+    MAX_SERVICE_CONCURRENCY.load(Ordering::SeqCst)
+}
+
+/// Get the global adaptive limiter
+pub fn adaptive_limiter() -> &'static AdaptiveLimiter {
+    &LIMITER
+}
+
+/// Service name of a CloudFormation resource type ("AWS::EC2::Instance" -> "EC2")
+pub fn service_for_resource_type(resource_type: &str) -> &str {
+    resource_type
+        .strip_prefix("AWS::")
+        .and_then(|rest| rest.split("::").next())
+        .unwrap_or(resource_type)
+}
+
+/// Throttle state of one service, for status display
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServiceThrottle {
+    pub service: String,
+    /// Current in-flight limit
+    pub limit: usize,
+    /// Calls currently running
+    pub in_flight: usize,
+    /// Throttles since the last successful call
+    pub consecutive_throttles: u32,
+    /// Remaining backoff, if any
+    pub backoff_remaining: Option<Duration>,
+}
+
+#[derive(Debug)]
+struct ServiceState {
+    limit: usize,
+    in_flight: usize,
+    consecutive_throttles: u32,
+    successes_since_change: usize,
+    backoff_until: Option<Instant>,
+}
+
+impl ServiceState {
+    fn new() -> Self {
+        Self {
+            limit: max_service_concurrency(),
+            in_flight: 0,
+            consecutive_throttles: 0,
+            successes_since_change: 0,
+            backoff_until: None,
+        }
+    }
+
+    fn is_degraded(&self) -> bool {
+        self.limit < max_service_concurrency() || self.consecutive_throttles > 0
+    }
+}
+
+/// Per-service in-flight limits driven by throttling responses
+pub struct AdaptiveLimiter {
+    services: Mutex<HashMap<String, ServiceState>>,
+}
+
+/// A running call; frees its service slot when dropped
+pub struct ServicePermit<'a> {
+    limiter: &'a AdaptiveLimiter,
+    service: String,
+}
+
+impl Drop for ServicePermit<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut services) = self.limiter.services.lock() {
+            if let Some(state) = services.get_mut(&self.service) {
+                state.in_flight = state.in_flight.saturating_sub(1);
+            }
+        }
+    }
+}
+
+impl AdaptiveLimiter {
+    fn new() -> Self {
+        Self {
+            services: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Wait for the service's backoff to pass and a slot to free up
+    pub async fn acquire(&self, service: &str) -> ServicePermit<'_> {
+        loop {
+            let wait = match self.try_acquire(service, Instant::now()) {
+                Ok(permit) => return permit,
+                Err(wait) => wait,
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Take a slot now, or return how long to wait before trying again
+    fn try_acquire(&self, service: &str, now: Instant) -> Result<ServicePermit<'_>, Duration> {
+        let permit = || ServicePermit {
+            limiter: self,
+            service: service.to_string(),
+        };
+        let Ok(mut services) = self.services.lock() else {
+            // A poisoned lock shouldn't stall queries; run unthrottled
+            return Ok(permit());
+        };
+        let state = services
+            .entry(service.to_string())
+            .or_insert_with(ServiceState::new);
+
+        if let Some(until) = state.backoff_until {
+            if now < until {
+                return Err(until - now);
+            }
+            state.backoff_until = None;
+        }
+        // The setting may have been lowered since the service started
+        if state.in_flight >= state.limit.min(max_service_concurrency()) {
+            return Err(POLL_INTERVAL);
+        }
+        state.in_flight += 1;
+        drop(services);
+        Ok(permit())
+    }
+
+    /// Record a throttling response: halve the limit and back off
+    ///
+    /// Returns the backoff applied.
+    pub fn record_throttle(&self, service: &str) -> Duration {
+        let Ok(mut services) = self.services.lock() else {
+            return BASE_BACKOFF;
+        };
+        let state = services
+            .entry(service.to_string())
+            .or_insert_with(ServiceState::new);
+
+        state.limit = (state.limit / 2).max(1);
+        state.consecutive_throttles += 1;
+        state.successes_since_change = 0;
+        let backoff = backoff_with_jitter(state.consecutive_throttles);
+        let until = Instant::now() + backoff;
+        // Concurrent throttles keep the latest deadline
+        if state.backoff_until.map_or(true, |current| current < until) {
+            state.backoff_until = Some(until);
+        }
+
+        let detail = format!(
+            "backing off {:.1}s, {} parallel",
+            backoff.as_secs_f32(),
+            state.limit
+        );
+        drop(services);
+        tracing::warn!("{} throttled: {}", service, detail);
+        global_status().report_throttled(service, &detail);
+        backoff
+    }
+
+    /// Record a successful call; grows the limit back after a run of successes
+    pub fn record_success(&self, service: &str) {
+        let Ok(mut services) = self.services.lock() else {
+            return;
+        };
+        let Some(state) = services.get_mut(service) else {
+            return;
+        };
+        if !state.is_degraded() {
+            return;
+        }
+
+        state.consecutive_throttles = 0;
+        state.successes_since_change += 1;
+        // Additive increase: one more slot per `limit` successful calls
+        if state.successes_since_change >= state.limit {
+            state.limit = (state.limit + 1).min(max_service_concurrency());
+            state.successes_since_change = 0;
+        }
+        if state.limit >= max_service_concurrency() {
+            drop(services);
+            global_status().report_throttle_cleared(service);
+        }
+    }
+
+    /// Services currently running below full concurrency or backing off
+    pub fn throttled_services(&self) -> Vec<ServiceThrottle> {
+        let Ok(services) = self.services.lock() else {
+            return Vec::new();
+        };
+        let now = Instant::now();
+        let mut throttled: Vec<ServiceThrottle> = services
+            .iter()
+            .filter(|(_, state)| state.is_degraded())
+            .map(|(service, state)| ServiceThrottle {
+                service: service.clone(),
+                limit: state.limit,
+                in_flight: state.in_flight,
+                consecutive_throttles: state.consecutive_throttles,
+                backoff_remaining: state
+                    .backoff_until
+                    .and_then(|until| until.checked_duration_since(now)),
+            })
+            .collect();
+        throttled.sort_by(|a, b| a.service.cmp(&b.service));
+        throttled
+    }
+}
+
+/// Exponential backoff for the nth consecutive throttle, with full jitter on the upper half
+fn backoff_with_jitter(consecutive_throttles: u32) -> Duration {
+    let exponent = consecutive_throttles.saturating_sub(1).min(10);
+    let ceiling = BASE_BACKOFF.saturating_mul(1 << exponent).min(MAX_BACKOFF);
+    let half = ceiling / 2;
+    let jitter_ms = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+    half + Duration::from_millis(jitter_ms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_service_for_resource_type() {
+        assert_eq!(service_for_resource_type("AWS::EC2::Instance"), "EC2");
+        assert_eq!(service_for_resource_type("AWS::Lambda::Function"), "Lambda");
+        assert_eq!(service_for_resource_type("Custom"), "Custom");
+    }
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        for attempt in 1..=15 {
+            let backoff = backoff_with_jitter(attempt);
+            let ceiling = BASE_BACKOFF
+                .saturating_mul(1 << (attempt - 1).min(10))
+                .min(MAX_BACKOFF);
+            assert!(
+                backoff >= ceiling / 2 && backoff <= ceiling,
+                "{:?}",
+                backoff
+            );
+        }
+    }
+
+    #[test]
+    fn test_throttle_halves_limit_and_successes_restore_it() {
+        let limiter = AdaptiveLimiter::new();
+        let service = "TestService";
+        let now = Instant::now();
+
+        // Fill every slot, then the next call has to wait
+        let permits: Vec<_> = (0..max_service_concurrency())
+            .map(|_| limiter.try_acquire(service, now).ok().unwrap())
+            .collect();
+        assert!(limiter.try_acquire(service, now).is_err());
+        drop(permits);
+        assert!(limiter.try_acquire(service, now).is_ok());
+
+        limiter.record_throttle(service);
+        let throttled = limiter.throttled_services();
+        assert_eq!(throttled.len(), 1);
+        assert_eq!(throttled[0].limit, max_service_concurrency() / 2);
+        assert!(throttled[0].backoff_remaining.is_some());
+        // Calls wait out the backoff
+        assert!(limiter.try_acquire(service, Instant::now()).is_err());
+
+        for _ in 0..200 {
+            limiter.record_success(service);
+        }
+        assert!(limiter.throttled_services().is_empty());
+    }
+}
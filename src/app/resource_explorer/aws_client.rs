@@ -1,8 +1,11 @@
 use super::{
+    adaptive_concurrency::{adaptive_limiter, service_for_resource_type, MAX_THROTTLE_RETRIES},
     aws_services::*, cache::SharedResourceCache, child_resources::*, credentials::*,
     global_services::*, normalizers::*, query_timing::*, retry_tracker::retry_tracker,
-    sdk_errors::categorize_error_string, state::*, tag_cache::TagCache,
+    sdk_errors::{categorize_error, categorize_error_string, ErrorCategory},
+    state::*, tag_cache::TagCache,
 };
+use crate::app::aws_regions::arn_partition;
 use anyhow::{Context, Result};
 use chrono::Utc;
 use futures::future::BoxFuture;
@@ -77,11 +80,18 @@ pub struct AWSResourceClient {
 
 impl AWSResourceClient {
     pub fn new(credential_coordinator: Arc<CredentialCoordinator>) -> Self {
+        let settings = crate::app::app_config::app_config();
         Self {
             normalizer_factory: NormalizerFactory,
             credential_coordinator,
-            pagination_config: PaginationConfig::default(),
-            tag_cache: Arc::new(TagCache::new()),
+            pagination_config: PaginationConfig {
+                max_concurrent_requests: settings.concurrency.explorer_queries,
+                ..PaginationConfig::default()
+            },
+            tag_cache: Arc::new(TagCache::with_config(
+                settings.cache.tag_ttl_minutes,
+                TagCache::DEFAULT_MAX_ENTRIES,
+            )),
         }
     }
 
@@ -264,11 +274,11 @@ impl AWSResourceClient {
         ElastiCacheService::new(Arc::clone(&self.credential_coordinator))
     }
 
-    fn get_guardduty_service(&self) -> GuardDutyService {
+    pub fn get_guardduty_service(&self) -> GuardDutyService {
         GuardDutyService::new(Arc::clone(&self.credential_coordinator))
     }
 
-    fn get_securityhub_service(&self) -> SecurityHubService {
+    pub fn get_securityhub_service(&self) -> SecurityHubService {
         SecurityHubService::new(Arc::clone(&self.credential_coordinator))
     }
 
@@ -461,6 +471,16 @@ impl AWSResourceClient {
         account: &str,
         region: &str,
     ) -> Result<Vec<ResourceTag>> {
+        if super::demo_data::is_demo_mode() {
+            return Ok(super::demo_data::demo_resources()
+                .iter()
+                .find(|resource| {
+                    resource.resource_id == resource_id && resource.account_id == account
+                })
+                .map(|resource| resource.tags.clone())
+                .unwrap_or_default());
+        }
+
         let start = Instant::now();
 
         // Check cache first
@@ -523,12 +543,12 @@ impl AWSResourceClient {
             }
             "AWS::Lambda::Function" => {
                 // Lambda list_tags requires full ARN, not just function name
-                let arn = format!("arn:aws:lambda:{}:{}:function:{}", region, account, resource_id);
+                let arn = format!("arn:{}:lambda:{}:{}:function:{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_lambda_tags(account, region, &arn).await?
             }
             "AWS::Lambda::EventSourceMapping" => {
                 // Event source mapping ARN format
-                let arn = format!("arn:aws:lambda:{}:{}:event-source-mapping:{}", region, account, resource_id);
+                let arn = format!("arn:{}:lambda:{}:{}:event-source-mapping:{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_lambda_tags(account, region, &arn).await?
             }
             "AWS::Lambda::LayerVersion" => {
@@ -537,7 +557,7 @@ impl AWSResourceClient {
                     tagging_service.get_lambda_tags(account, region, resource_id).await?
                 } else {
                     // resource_id might be layer:version format
-                    let arn = format!("arn:aws:lambda:{}:{}:layer:{}", region, account, resource_id);
+                    let arn = format!("arn:{}:lambda:{}:{}:layer:{}", arn_partition(region), region, account, resource_id);
                     tagging_service.get_lambda_tags(account, region, &arn).await?
                 }
             }
@@ -587,7 +607,7 @@ impl AWSResourceClient {
                 let arn = if resource_id.starts_with("arn:") {
                     resource_id.to_string()
                 } else {
-                    format!("arn:aws:eks:{}:{}:cluster/{}", region, account, resource_id)
+                    format!("arn:{}:eks:{}:{}:cluster/{}", arn_partition(region), region, account, resource_id)
                 };
                 tagging_service.get_eks_resource_tags(account, region, &arn).await?
             }
@@ -597,11 +617,11 @@ impl AWSResourceClient {
                     tagging_service.get_eks_resource_tags(account, region, resource_id).await?
                 } else {
                     // Fallback to universal API with constructed ARN
-                    let arn = format!("arn:aws:eks:{}:{}:fargateprofile/{}", region, account, resource_id);
+                    let arn = format!("arn:{}:eks:{}:{}:fargateprofile/{}", arn_partition(region), region, account, resource_id);
                     tagging_service.get_tags_for_arn(account, region, &arn).await?
                 }
             }
-            "AWS::EKS::Addon" | "AWS::EKS::IdentityProviderConfig" => {
+            "AWS::EKS::Addon" | "AWS::EKS::IdentityProviderConfig" | "AWS::EKS::Nodegroup" => {
                 if resource_id.starts_with("arn:") {
                     tagging_service.get_eks_resource_tags(account, region, resource_id).await?
                 } else {
@@ -619,7 +639,7 @@ impl AWSResourceClient {
                 let arn = if resource_id.starts_with("arn:") {
                     resource_id.to_string()
                 } else {
-                    format!("arn:aws:ecs:{}:{}:cluster/{}", region, account, resource_id)
+                    format!("arn:{}:ecs:{}:{}:cluster/{}", arn_partition(region), region, account, resource_id)
                 };
                 tagging_service.get_ecs_resource_tags(account, region, &arn).await?
             }
@@ -631,7 +651,7 @@ impl AWSResourceClient {
                 } else {
                     // Try to use universal tagging API by constructing partial ARN
                     // Services require cluster context, so this may fail
-                    let arn = format!("arn:aws:ecs:{}:{}:service/{}", region, account, resource_id);
+                    let arn = format!("arn:{}:ecs:{}:{}:service/{}", arn_partition(region), region, account, resource_id);
                     tagging_service.get_tags_for_arn(account, region, &arn).await?
                 }
             }
@@ -639,7 +659,7 @@ impl AWSResourceClient {
                 if resource_id.starts_with("arn:") {
                     tagging_service.get_ecs_resource_tags(account, region, resource_id).await?
                 } else {
-                    let arn = format!("arn:aws:ecs:{}:{}:task/{}", region, account, resource_id);
+                    let arn = format!("arn:{}:ecs:{}:{}:task/{}", arn_partition(region), region, account, resource_id);
                     tagging_service.get_tags_for_arn(account, region, &arn).await?
                 }
             }
@@ -648,7 +668,7 @@ impl AWSResourceClient {
                     resource_id.to_string()
                 } else {
                     // TaskDefinition ARN: arn:aws:ecs:region:account:task-definition/family:revision
-                    format!("arn:aws:ecs:{}:{}:task-definition/{}", region, account, resource_id)
+                    format!("arn:{}:ecs:{}:{}:task-definition/{}", arn_partition(region), region, account, resource_id)
                 };
                 tagging_service.get_ecs_resource_tags(account, region, &arn).await?
             }
@@ -656,7 +676,7 @@ impl AWSResourceClient {
                 if resource_id.starts_with("arn:") {
                     tagging_service.get_ecs_resource_tags(account, region, resource_id).await?
                 } else {
-                    let arn = format!("arn:aws:ecs:{}:{}:capacity-provider/{}", region, account, resource_id);
+                    let arn = format!("arn:{}:ecs:{}:{}:capacity-provider/{}", arn_partition(region), region, account, resource_id);
                     tagging_service.get_tags_for_arn(account, region, &arn).await?
                 }
             }
@@ -667,19 +687,19 @@ impl AWSResourceClient {
                     tagging_service.get_tags_for_arn(account, region, resource_id).await?
                 } else {
                     // Fallback: construct ARN from stack name (less reliable)
-                    let arn = format!("arn:aws:cloudformation:{}:{}:stack/{}", region, account, resource_id);
+                    let arn = format!("arn:{}:cloudformation:{}:{}:stack/{}", arn_partition(region), region, account, resource_id);
                     tagging_service.get_tags_for_arn(account, region, &arn).await?
                 }
             }
             // Amazon MQ - construct ARN from broker ID
             "AWS::AmazonMQ::Broker" => {
                 // MQ broker ARN format: arn:aws:mq:region:account:broker:broker-id
-                let arn = format!("arn:aws:mq:{}:{}:broker:{}", region, account, resource_id);
+                let arn = format!("arn:{}:mq:{}:{}:broker:{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // CodePipeline - construct ARN from pipeline name
             "AWS::CodePipeline::Pipeline" => {
-                let arn = format!("arn:aws:codepipeline:{}:{}:{}", region, account, resource_id);
+                let arn = format!("arn:{}:codepipeline:{}:{}:{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // MSK - resource_id should already be ClusterArn, but handle both cases
@@ -688,20 +708,20 @@ impl AWSResourceClient {
                     tagging_service.get_tags_for_arn(account, region, resource_id).await?
                 } else {
                     // Construct ARN if only cluster name provided
-                    let arn = format!("arn:aws:kafka:{}:{}:cluster/{}", region, account, resource_id);
+                    let arn = format!("arn:{}:kafka:{}:{}:cluster/{}", arn_partition(region), region, account, resource_id);
                     tagging_service.get_tags_for_arn(account, region, &arn).await?
                 }
             }
             // API Gateway v1 - construct ARN from REST API ID
             "AWS::ApiGateway::RestApi" => {
                 // API Gateway ARN format: arn:aws:apigateway:region::/restapis/api-id
-                let arn = format!("arn:aws:apigateway:{}::/restapis/{}", region, resource_id);
+                let arn = format!("arn:{}:apigateway:{}::/restapis/{}", arn_partition(region), region, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // API Gateway v2 - construct ARN from API ID
             "AWS::ApiGatewayV2::Api" => {
                 // API Gateway v2 ARN format: arn:aws:apigateway:region::/apis/api-id
-                let arn = format!("arn:aws:apigateway:{}::/apis/{}", region, resource_id);
+                let arn = format!("arn:{}:apigateway:{}::/apis/{}", arn_partition(region), region, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // Route53 - uses dedicated tag API
@@ -710,13 +730,13 @@ impl AWSResourceClient {
             }
             // EventBridge - construct ARN and use universal API
             "AWS::Events::EventBus" => {
-                let arn = format!("arn:aws:events:{}:{}:event-bus/{}", region, account, resource_id);
+                let arn = format!("arn:{}:events:{}:{}:event-bus/{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             "AWS::Events::Rule" => {
                 // Rule ARN format: arn:aws:events:region:account:rule/[event-bus-name/]rule-name
                 // Since we only have rule name, assume default bus
-                let arn = format!("arn:aws:events:{}:{}:rule/{}", region, account, resource_id);
+                let arn = format!("arn:{}:events:{}:{}:rule/{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // Lex v2 - uses dedicated tag API
@@ -751,22 +771,22 @@ impl AWSResourceClient {
             }
             // Timestream - construct ARN and use universal API
             "AWS::Timestream::Database" => {
-                let arn = format!("arn:aws:timestream:{}:{}:database/{}", region, account, resource_id);
+                let arn = format!("arn:{}:timestream:{}:{}:database/{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             "AWS::Timestream::Table" => {
                 // Table ARN includes database name, resource_id should be "database/table"
-                let arn = format!("arn:aws:timestream:{}:{}:database/{}", region, account, resource_id);
+                let arn = format!("arn:{}:timestream:{}:{}:database/{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // XRay - construct ARN and use universal API
             "AWS::XRay::SamplingRule" => {
-                let arn = format!("arn:aws:xray:{}:{}:sampling-rule/{}", region, account, resource_id);
+                let arn = format!("arn:{}:xray:{}:{}:sampling-rule/{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // CodeBuild - construct ARN from project name
             "AWS::CodeBuild::Project" => {
-                let arn = format!("arn:aws:codebuild:{}:{}:project/{}", region, account, resource_id);
+                let arn = format!("arn:{}:codebuild:{}:{}:project/{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // SSM Parameter - construct ARN from parameter name
@@ -777,34 +797,34 @@ impl AWSResourceClient {
                 } else {
                     format!("/{}", resource_id)
                 };
-                let arn = format!("arn:aws:ssm:{}:{}:parameter{}", region, account, param_path);
+                let arn = format!("arn:{}:ssm:{}:{}:parameter{}", arn_partition(region), region, account, param_path);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // SSM Document - construct ARN from document name
             "AWS::SSM::Document" => {
-                let arn = format!("arn:aws:ssm:{}:{}:document/{}", region, account, resource_id);
+                let arn = format!("arn:{}:ssm:{}:{}:document/{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // CloudWatch Logs - construct ARN from log group name
             "AWS::Logs::LogGroup" => {
                 // Log group names can contain / and special characters
-                let arn = format!("arn:aws:logs:{}:{}:log-group:{}", region, account, resource_id);
+                let arn = format!("arn:{}:logs:{}:{}:log-group:{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // Cognito Identity Pool - construct ARN from pool ID
             "AWS::Cognito::IdentityPool" => {
                 // Identity pool ID format: region:guid
-                let arn = format!("arn:aws:cognito-identity:{}:{}:identitypool/{}", region, account, resource_id);
+                let arn = format!("arn:{}:cognito-identity:{}:{}:identitypool/{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // Cognito User Pool - construct ARN from pool ID
             "AWS::Cognito::UserPool" => {
-                let arn = format!("arn:aws:cognito-idp:{}:{}:userpool/{}", region, account, resource_id);
+                let arn = format!("arn:{}:cognito-idp:{}:{}:userpool/{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // CodeCommit - construct ARN from repository name
             "AWS::CodeCommit::Repository" => {
-                let arn = format!("arn:aws:codecommit:{}:{}:{}", region, account, resource_id);
+                let arn = format!("arn:{}:codecommit:{}:{}:{}", arn_partition(region), region, account, resource_id);
                 tagging_service.get_tags_for_arn(account, region, &arn).await?
             }
             // Resources that explicitly don't support tagging - return empty immediately
@@ -882,15 +902,348 @@ impl AWSResourceClient {
         self.tag_cache.invalidate_all().await;
     }
 
+    /// Add/update and remove tags on a resource using its service's tagging API
+    ///
+    /// EC2, S3, Lambda, and IAM use their own APIs; everything else goes through
+    /// the Resource Groups Tagging API, which needs the resource ARN (from the
+    /// resource properties, the resource ID, or built from the resource type).
+    /// The cached tags for the resource are dropped on success.
+    pub async fn apply_tag_changes(
+        &self,
+        resource: &ResourceEntry,
+        changes: &TagChanges,
+    ) -> Result<()> {
+        crate::app::capabilities::ensure_writable("Edit tags")?;
+
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let resource_type = resource.resource_type.as_str();
+        let resource_id = resource.resource_id.as_str();
+        let account = resource.account_id.as_str();
+        let region = resource.region.as_str();
+
+        log_query_op(
+            "TAGS",
+            "write_start",
+            &format!(
+                "{}:{} (+{} -{})",
+                resource_type,
+                resource_id,
+                changes.set.len(),
+                changes.remove.len()
+            ),
+        );
+
+        let tagging_service = self.get_resource_tagging_service();
+        match resource_type {
+            "AWS::EC2::VolumeAttachment" => {
+                anyhow::bail!("Volume attachments cannot be tagged; tag the volume instead")
+            }
+            _ if resource_type.starts_with("AWS::EC2::") => {
+                tagging_service
+                    .apply_ec2_tags(account, region, resource_id, changes)
+                    .await?
+            }
+            "AWS::S3::Bucket" => {
+                tagging_service
+                    .apply_s3_bucket_tags(account, region, resource_id, changes)
+                    .await?
+            }
+            "AWS::Lambda::Function" | "AWS::Lambda::LayerVersion" | "AWS::Lambda::EventSourceMapping" => {
+                let arn = Self::tagging_arn(resource)
+                    .context("Lambda resource has no ARN for tagging")?;
+                tagging_service
+                    .apply_lambda_tags(account, region, &arn, changes)
+                    .await?
+            }
+            "AWS::IAM::Role" => {
+                tagging_service
+                    .apply_iam_role_tags(account, resource_id, changes)
+                    .await?
+            }
+            "AWS::IAM::User" => {
+                tagging_service
+                    .apply_iam_user_tags(account, resource_id, changes)
+                    .await?
+            }
+            "AWS::IAM::Policy" => {
+                let arn = Self::tagging_arn(resource)
+                    .context("IAM policy has no ARN for tagging")?;
+                tagging_service
+                    .apply_iam_policy_tags(account, &arn, changes)
+                    .await?
+            }
+            _ => {
+                let arn = Self::tagging_arn(resource).with_context(|| {
+                    format!(
+                        "Tag editing is not supported for {} (no ARN available)",
+                        resource_type
+                    )
+                })?;
+                tagging_service
+                    .apply_tags_for_arn(account, region, &arn, changes)
+                    .await?
+            }
+        }
+
+        self.tag_cache
+            .invalidate(resource_type, resource_id, account, region)
+            .await;
+
+        log_query_op("TAGS", "write_done", &format!("{}:{}", resource_type, resource_id));
+        Ok(())
+    }
+
+    /// ARN used to address a resource in tag write calls
+    ///
+    /// Prefers an ARN reported by AWS, then an ARN-shaped resource ID, then
+    /// an ARN built from the resource type for services whose IDs are names.
+    fn tagging_arn(resource: &ResourceEntry) -> Option<String> {
+        const ARN_PROPERTIES: [&str; 8] = [
+            "Arn",
+            "ARN",
+            "ResourceArn",
+            "FunctionArn",
+            "LayerVersionArn",
+            "EventSourceMappingArn",
+            "TableArn",
+            "TopicArn",
+        ];
+        if let Some(arn) = ARN_PROPERTIES.iter().find_map(|name| {
+            resource
+                .properties
+                .get(*name)
+                .and_then(|v| v.as_str())
+                .filter(|v| v.starts_with("arn:"))
+        }) {
+            return Some(arn.to_string());
+        }
+        if resource.resource_id.starts_with("arn:") {
+            return Some(resource.resource_id.clone());
+        }
+
+        let (region, account, id) = (
+            resource.region.as_str(),
+            resource.account_id.as_str(),
+            resource.resource_id.as_str(),
+        );
+        let arn = match resource.resource_type.as_str() {
+            "AWS::Lambda::Function" => format!("arn:{}:lambda:{}:{}:function:{}", arn_partition(region), region, account, id),
+            "AWS::DynamoDB::Table" => format!("arn:{}:dynamodb:{}:{}:table/{}", arn_partition(region), region, account, id),
+            "AWS::EKS::Cluster" => format!("arn:{}:eks:{}:{}:cluster/{}", arn_partition(region), region, account, id),
+            "AWS::ECS::Cluster" => format!("arn:{}:ecs:{}:{}:cluster/{}", arn_partition(region), region, account, id),
+            "AWS::Logs::LogGroup" => format!("arn:{}:logs:{}:{}:log-group:{}", arn_partition(region), region, account, id),
+            "AWS::CodeBuild::Project" => format!("arn:{}:codebuild:{}:{}:project/{}", arn_partition(region), region, account, id),
+            "AWS::CodePipeline::Pipeline" => format!("arn:{}:codepipeline:{}:{}:{}", arn_partition(region), region, account, id),
+            "AWS::Events::EventBus" => format!("arn:{}:events:{}:{}:event-bus/{}", arn_partition(region), region, account, id),
+            "AWS::SSM::Document" => format!("arn:{}:ssm:{}:{}:document/{}", arn_partition(region), region, account, id),
+            "AWS::ApiGateway::RestApi" => format!("arn:{}:apigateway:{}::/restapis/{}", arn_partition(region), region, id),
+            "AWS::ApiGatewayV2::Api" => format!("arn:{}:apigateway:{}::/apis/{}", arn_partition(region), region, id),
+            "AWS::RDS::DBInstance" => format!("arn:{}:rds:{}:{}:db:{}", arn_partition(region), region, account, id),
+            "AWS::RDS::DBCluster" => format!("arn:{}:rds:{}:{}:cluster:{}", arn_partition(region), region, account, id),
+            "AWS::SSM::Parameter" => {
+                let path = if id.starts_with('/') { id.to_string() } else { format!("/{}", id) };
+                format!("arn:{}:ssm:{}:{}:parameter{}", arn_partition(region), region, account, path)
+            }
+            _ => return None,
+        };
+        Some(arn)
+    }
+
     /// Get tag cache statistics
     pub async fn get_tag_cache_stats(&self) -> super::tag_cache::CacheStats {
         self.tag_cache.get_stats().await
     }
 
+    // ============================================================================
+    // Delta Refresh
+    // ============================================================================
+
+    /// Drop only the cached results that may have changed since they were fetched
+    ///
+    /// Looks up CloudTrail write events in each account/region of the scope
+    /// since the oldest cached fetch, then removes the cache keys whose service
+    /// saw writes. Keys proven unchanged are re-stamped with the current time.
+    /// A following `query_aws_resources_parallel` call re-fetches only the
+    /// removed keys (plus anything never cached) and serves the rest from cache.
+    pub async fn invalidate_changed_resources(
+        &self,
+        scope: &QueryScope,
+        cache: &SharedResourceCache,
+    ) -> super::delta_refresh::DeltaRefreshPlan {
+        use super::delta_refresh::{
+            change_lookup_start, change_lookup_targets, keys_in_scope, plan_delta_refresh,
+            ChangeSet, MAX_CHANGE_EVENT_PAGES,
+        };
+        use crate::app::data_plane::cloudtrail_events::{
+            CloudTrailEventsClient, LookupAttribute, LookupAttributeKey, LookupOptions,
+        };
+
+        let account_ids: Vec<String> = scope.accounts.iter().map(|a| a.account_id.clone()).collect();
+        let region_codes: Vec<String> = scope.regions.iter().map(|r| r.region_code.clone()).collect();
+        let resource_types: Vec<String> = scope
+            .resource_types
+            .iter()
+            .map(|t| t.resource_type.clone())
+            .collect();
+
+        let cached_keys = keys_in_scope(
+            &cache.resource_keys(),
+            &account_ids,
+            &region_codes,
+            &resource_types,
+        );
+
+        // Fetch times per account/lookup region; a key evicted since listing
+        // has no time and no change set, so the plan marks it stale
+        let mut fetched_at: HashMap<(String, String), Vec<chrono::DateTime<Utc>>> = HashMap::new();
+        for key in &cached_keys {
+            if let (Some(timestamp), Some(target)) = (
+                cache.cached_at(key),
+                change_lookup_targets(std::slice::from_ref(key)).pop_first(),
+            ) {
+                fetched_at.entry(target).or_default().push(timestamp);
+            }
+        }
+
+        let cloudtrail = CloudTrailEventsClient::new(self.get_credential_coordinator());
+        let mut changes: HashMap<(String, String), ChangeSet> = HashMap::new();
+
+        for (account_id, region) in change_lookup_targets(&cached_keys) {
+            let target = (account_id.clone(), region.clone());
+            let Some(start) = fetched_at.get(&target).and_then(|t| change_lookup_start(t.iter().copied())) else {
+                continue;
+            };
+
+            let mut events = Vec::new();
+            let mut next_token: Option<String> = None;
+            let mut complete = false;
+            let mut failed = false;
+
+            for _ in 0..MAX_CHANGE_EVENT_PAGES {
+                let mut options = LookupOptions::new()
+                    .with_start_time(start.timestamp_millis())
+                    .with_lookup_attribute(LookupAttribute::new(
+                        LookupAttributeKey::ReadOnly,
+                        "false".to_string(),
+                    ));
+                if let Some(token) = next_token.take() {
+                    options = options.with_next_token(token);
+                }
+
+                match cloudtrail.lookup_events(&account_id, &region, options).await {
+                    Ok(result) => {
+                        events.extend(result.events);
+                        match result.next_token {
+                            Some(token) => next_token = Some(token),
+                            None => {
+                                complete = true;
+                                break;
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Delta refresh: CloudTrail lookup failed for {}:{}, refreshing all: {}",
+                            account_id, region, e
+                        );
+                        failed = true;
+                        break;
+                    }
+                }
+            }
+
+            if failed {
+                continue;
+            }
+            if !complete {
+                info!(
+                    "Delta refresh: more than {} pages of changes in {}:{}, refreshing all",
+                    MAX_CHANGE_EVENT_PAGES, account_id, region
+                );
+            }
+
+            changes.insert(target, ChangeSet::from_events(&events, complete));
+        }
+
+        let plan = plan_delta_refresh(&cached_keys, &changes);
+
+        for key in &plan.stale_keys {
+            cache.remove_resources(key);
+        }
+
+        // Unchanged results are as good as freshly fetched
+        let now = Utc::now();
+        for key in &plan.unchanged_keys {
+            if let Some(entries) = cache.get_resources_owned(key) {
+                let entries = entries
+                    .into_iter()
+                    .map(|mut entry| {
+                        entry.query_timestamp = now;
+                        entry
+                    })
+                    .collect();
+                cache.insert_resources_owned(key.clone(), entries);
+            }
+        }
+
+        info!(
+            "Delta refresh: {} of {} cached queries changed, {} unchanged",
+            plan.stale_keys.len(),
+            plan.total(),
+            plan.unchanged_keys.len()
+        );
+
+        plan
+    }
+
     // ============================================================================
     // Resource Query Methods
     // ============================================================================
 
+    /// Answer a query from the synthetic dataset while demo mode is on
+    async fn send_demo_results(
+        scope: &QueryScope,
+        result_sender: &mpsc::Sender<QueryResult>,
+        progress_sender: Option<&mpsc::Sender<QueryProgress>>,
+        cache: &SharedResourceCache,
+    ) -> Result<()> {
+        for (account_id, region, resource_type) in global_registry().query_plan(scope) {
+            let resources = super::demo_data::query_resources(&account_id, &region, &resource_type);
+            let cache_key = format!("{}:{}:{}", account_id, region, resource_type);
+            cache.insert_resources_owned(cache_key.clone(), resources.clone());
+
+            if let Some(sender) = progress_sender {
+                let _ = sender
+                    .send(QueryProgress {
+                        account: account_id.clone(),
+                        region: region.clone(),
+                        resource_type: resource_type.clone(),
+                        status: QueryStatus::Completed,
+                        message: format!("Found {} demo resources", resources.len()),
+                        items_processed: Some(resources.len()),
+                        estimated_total: Some(resources.len()),
+                    })
+                    .await;
+            }
+
+            let result = QueryResult {
+                account_id,
+                region,
+                resource_type,
+                resources: Ok(resources),
+                cache_key,
+            };
+            if let Err(e) = result_sender.send(result).await {
+                warn!("Failed to send demo result: {}", e);
+            }
+        }
+
+        Ok(())
+    }
+
     /// Query AWS resources for all combinations of accounts, regions, and resource types in parallel
     /// Results are sent back as they arrive via the progress_sender channel
     pub async fn query_aws_resources_parallel(
@@ -907,27 +1260,19 @@ impl AWSResourceClient {
             scope.resource_types.len()
         );
 
-        // Build list of expected queries for tracking
-        let global_registry = GlobalServiceRegistry::new();
-        let mut expected_queries: Vec<String> = Vec::new();
-        let mut seen_globals: HashSet<(String, String)> = HashSet::new();
-
-        for account in &scope.accounts {
-            for resource_type in &scope.resource_types {
-                if global_registry.is_global(&resource_type.resource_type) {
-                    let key = (account.account_id.clone(), resource_type.resource_type.clone());
-                    if !seen_globals.contains(&key) {
-                        seen_globals.insert(key);
-                        expected_queries.push(format!("{}:Global:{}", account.account_id, resource_type.resource_type));
-                    }
-                } else {
-                    for region in &scope.regions {
-                        expected_queries.push(format!("{}:{}:{}", account.account_id, region.region_code, resource_type.resource_type));
-                    }
-                }
-            }
+        if super::demo_data::is_demo_mode() {
+            return Self::send_demo_results(scope, &result_sender, progress_sender.as_ref(), &cache)
+                .await;
         }
 
+        // Build list of expected queries for tracking
+        let global_registry = global_registry();
+        let expected_queries: Vec<String> = global_registry
+            .query_plan(scope)
+            .into_iter()
+            .map(|(account_id, region, resource_type)| format!("{}:{}:{}", account_id, region, resource_type))
+            .collect();
+
         // Start phase tracking with expected queries
         super::query_timing::start_phase("PHASE1", expected_queries);
 
@@ -1042,7 +1387,7 @@ impl AWSResourceClient {
                         // Execute the query from the global region
                         info!("🔍 [API CALL START] {} - calling AWS API (global)", query_id);
                         let query_result = client
-                            .query_resource_type(&account_id, &query_region, &resource_type_str, progress_sender_clone.as_ref())
+                            .query_resource_type_adaptive(&account_id, &query_region, &resource_type_str, progress_sender_clone.as_ref())
                             .await;
                         let elapsed = start_time.elapsed();
                         info!("📊 [API CALL END] {} - completed in {:?} (global)", query_id, elapsed);
@@ -1227,7 +1572,7 @@ impl AWSResourceClient {
                             // Execute the query
                             info!("🔍 [API CALL START] {} - calling AWS API", query_id);
                             let query_result = client
-                                .query_resource_type(&account_id, &region_code, &resource_type_str, progress_sender_clone.as_ref())
+                                .query_resource_type_adaptive(&account_id, &region_code, &resource_type_str, progress_sender_clone.as_ref())
                                 .await;
                             let elapsed = start_time.elapsed();
                             info!("📊 [API CALL END] {} - completed in {:?}", query_id, elapsed);
@@ -1447,6 +1792,55 @@ impl AWSResourceClient {
         Ok(all_resources)
     }
 
+    /// Query a resource type under its service's adaptive concurrency limit
+    ///
+    /// Throttled calls shrink the service's limit, back off with jitter and are
+    /// retried up to `MAX_THROTTLE_RETRIES` times before the error is returned.
+    async fn query_resource_type_adaptive(
+        &self,
+        account: &str,
+        region: &str,
+        resource_type: &str,
+        progress_sender: Option<&mpsc::Sender<QueryProgress>>,
+    ) -> Result<Vec<ResourceEntry>> {
+        let service = service_for_resource_type(resource_type);
+        let limiter = adaptive_limiter();
+        let mut attempt = 0;
+
+        loop {
+            let permit = limiter.acquire(service).await;
+            let result = self
+                .query_resource_type(account, region, resource_type, progress_sender)
+                .await;
+            drop(permit);
+
+            match result {
+                Ok(resources) => {
+                    limiter.record_success(service);
+                    return Ok(resources);
+                }
+                Err(e) => {
+                    if !matches!(
+                        categorize_error(&e, service, "query"),
+                        ErrorCategory::Throttled { .. }
+                    ) {
+                        return Err(e);
+                    }
+                    // The next acquire waits out the backoff
+                    limiter.record_throttle(service);
+                    if attempt >= MAX_THROTTLE_RETRIES {
+                        return Err(e);
+                    }
+                    attempt += 1;
+                    info!(
+                        "Retrying throttled {} query in {} ({}/{})",
+                        resource_type, region, attempt, MAX_THROTTLE_RETRIES
+                    );
+                }
+            }
+        }
+    }
+
     /// Query a specific resource type for a given account and region
     ///
     /// If progress_sender is provided, sends FetchingTags progress updates during normalization.
@@ -1459,7 +1853,7 @@ impl AWSResourceClient {
     ) -> Result<Vec<ResourceEntry>> {
         // Use "Global" for tracking key if this is a global service
         // This matches the Phase 1 tracking key format
-        let global_registry = GlobalServiceRegistry::new();
+        let global_registry = global_registry();
         let tracking_region = if global_registry.is_global(resource_type) {
             "Global"
         } else {
@@ -1896,6 +2290,11 @@ impl AWSResourceClient {
                     .list_hosted_zones(account, region)
                     .await?
             }
+            "AWS::Route53::HealthCheck" => {
+                self.get_route53_service()
+                    .list_health_checks(account, region)
+                    .await?
+            }
             "AWS::EFS::FileSystem" => {
                 self.get_efs_service()
                     .list_file_systems(account, region)
@@ -2524,6 +2923,22 @@ impl AWSResourceClient {
                     .list_flow_aliases(account, region, parent_id)
                     .await?
             }
+            "AWS::ECS::ContainerDefinition" => {
+                // Container definitions come from the task's task definition
+                self.get_ecs_service()
+                    .list_task_containers(account, region, &parent.properties)
+                    .await?
+            }
+            "AWS::EKS::Nodegroup" => {
+                self.get_eks_service()
+                    .list_nodegroups(account, region, parent_id)
+                    .await?
+            }
+            "AWS::Route53::RecordSet" => {
+                self.get_route53_service()
+                    .list_record_sets(account, region, parent_id)
+                    .await?
+            }
             _ => {
                 warn!("Unsupported child resource type: {}", child_type);
                 return Ok(vec![]);
@@ -2573,6 +2988,18 @@ impl AWSResourceClient {
                     .list_agent_action_groups(account, region, agent_id, agent_version)
                     .await?
             }
+            "AWS::ECS::Task" => {
+                let cluster_arn = parent_params
+                    .get("cluster_arn")
+                    .context("Missing cluster_arn")?;
+                let service_name = parent_params
+                    .get("service_name")
+                    .context("Missing service_name")?;
+
+                self.get_ecs_service()
+                    .list_service_tasks(account, region, cluster_arn, service_name)
+                    .await?
+            }
             _ => {
                 warn!(
                     "Unsupported multi-parent child resource type: {}",
@@ -2628,6 +3055,17 @@ impl AWSResourceClient {
             params.insert("agent_version".to_string(), version);
         }
 
+        // For Service querying its running Tasks, we need the cluster and service name
+        if parent.resource_type == "AWS::ECS::Service" {
+            let cluster_arn = parent
+                .properties
+                .get("ClusterArn")
+                .and_then(|v| v.as_str())
+                .context("Service missing ClusterArn")?;
+            params.insert("cluster_arn".to_string(), cluster_arn.to_string());
+            params.insert("service_name".to_string(), parent.resource_id.clone());
+        }
+
         Ok(params)
     }
 
@@ -3047,6 +3485,10 @@ impl AWSResourceClient {
 
     /// Generic describe method that routes to the appropriate resource-specific method
     pub async fn describe_resource(&self, resource: &ResourceEntry) -> Result<serde_json::Value> {
+        if super::demo_data::is_demo_mode() {
+            return Ok(resource.properties.clone());
+        }
+
         match resource.resource_type.as_str() {
             "AWS::EC2::Instance" => {
                 self.get_ec2_service()
@@ -4341,6 +4783,11 @@ impl AWSResourceClient {
         progress_sender: Option<mpsc::Sender<QueryProgress>>,
         cache: Arc<SharedResourceCache>,
     ) {
+        // Demo resources already carry their detailed properties
+        if super::demo_data::is_demo_mode() {
+            return;
+        }
+
         let client = self.clone();
 
         tokio::spawn(async move {
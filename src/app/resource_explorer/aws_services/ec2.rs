@@ -964,6 +964,35 @@ impl EC2Service {
                             }
                         }
 
+                        // Add referenced security groups
+                        if let Some(ref group_pairs) = rule.user_id_group_pairs {
+                            if !group_pairs.is_empty() {
+                                let group_pairs_json: Vec<serde_json::Value> = group_pairs
+                                    .iter()
+                                    .map(|pair| {
+                                        let mut pair_json = serde_json::Map::new();
+                                        if let Some(group_id) = &pair.group_id {
+                                            pair_json.insert(
+                                                "GroupId".to_string(),
+                                                serde_json::Value::String(group_id.clone()),
+                                            );
+                                        }
+                                        if let Some(user_id) = &pair.user_id {
+                                            pair_json.insert(
+                                                "UserId".to_string(),
+                                                serde_json::Value::String(user_id.clone()),
+                                            );
+                                        }
+                                        serde_json::Value::Object(pair_json)
+                                    })
+                                    .collect();
+                                rule_json.insert(
+                                    "UserIdGroupPairs".to_string(),
+                                    serde_json::Value::Array(group_pairs_json),
+                                );
+                            }
+                        }
+
                         serde_json::Value::Object(rule_json)
                     })
                     .collect();
@@ -999,6 +1028,59 @@ impl EC2Service {
                                 serde_json::Value::Number((*to_port).into()),
                             );
                         }
+
+                        // Add IP ranges
+                        if let Some(ref ip_ranges) = rule.ip_ranges {
+                            if !ip_ranges.is_empty() {
+                                let ip_ranges_json: Vec<serde_json::Value> = ip_ranges
+                                    .iter()
+                                    .map(|ip_range| {
+                                        let mut ip_json = serde_json::Map::new();
+                                        if let Some(cidr) = &ip_range.cidr_ip {
+                                            ip_json.insert(
+                                                "CidrIp".to_string(),
+                                                serde_json::Value::String(cidr.clone()),
+                                            );
+                                        }
+                                        serde_json::Value::Object(ip_json)
+                                    })
+                                    .collect();
+                                rule_json.insert(
+                                    "IpRanges".to_string(),
+                                    serde_json::Value::Array(ip_ranges_json),
+                                );
+                            }
+                        }
+
+                        // Add referenced security groups
+                        if let Some(ref group_pairs) = rule.user_id_group_pairs {
+                            if !group_pairs.is_empty() {
+                                let group_pairs_json: Vec<serde_json::Value> = group_pairs
+                                    .iter()
+                                    .map(|pair| {
+                                        let mut pair_json = serde_json::Map::new();
+                                        if let Some(group_id) = &pair.group_id {
+                                            pair_json.insert(
+                                                "GroupId".to_string(),
+                                                serde_json::Value::String(group_id.clone()),
+                                            );
+                                        }
+                                        if let Some(user_id) = &pair.user_id {
+                                            pair_json.insert(
+                                                "UserId".to_string(),
+                                                serde_json::Value::String(user_id.clone()),
+                                            );
+                                        }
+                                        serde_json::Value::Object(pair_json)
+                                    })
+                                    .collect();
+                                rule_json.insert(
+                                    "UserIdGroupPairs".to_string(),
+                                    serde_json::Value::Array(group_pairs_json),
+                                );
+                            }
+                        }
+
                         serde_json::Value::Object(rule_json)
                     })
                     .collect();
@@ -3260,6 +3342,10 @@ impl EC2Service {
                         );
                     }
 
+                    if let Some(egress) = entry.egress {
+                        entry_json.insert("Egress".to_string(), serde_json::Value::Bool(egress));
+                    }
+
                     if let Some(rule_action) = &entry.rule_action {
                         entry_json.insert(
                             "RuleAction".to_string(),
@@ -947,6 +947,132 @@ impl ECSService {
         ))
     }
 
+    /// List the running tasks of an ECS service, with their containers
+    pub async fn list_service_tasks(
+        &self,
+        account_id: &str,
+        region: &str,
+        cluster_arn: &str,
+        service_name: &str,
+    ) -> Result<Vec<serde_json::Value>> {
+        report_status("ECS", "list_service_tasks", Some(service_name));
+
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = ecs::Client::new(&aws_config);
+        let mut tasks = Vec::new();
+
+        let mut paginator = client
+            .list_tasks()
+            .cluster(cluster_arn)
+            .service_name(service_name)
+            .desired_status(ecs::types::DesiredStatus::Running)
+            .into_paginator()
+            .send();
+
+        while let Some(page) = paginator.next().await {
+            let page = page?;
+            if let Some(task_arns) = page.task_arns {
+                if !task_arns.is_empty() {
+                    tasks.extend(
+                        self.describe_tasks_internal(&client, cluster_arn, &task_arns, true)
+                            .await?,
+                    );
+                }
+            }
+        }
+
+        report_status_done("ECS", "list_service_tasks", Some(service_name));
+        Ok(tasks)
+    }
+
+    /// List the container definitions of a running task
+    ///
+    /// `task` is a task as returned by `list_service_tasks`. Each container
+    /// gets the runtime status reported by the task and, when it logs with the
+    /// awslogs driver, its log group and stream.
+    pub async fn list_task_containers(
+        &self,
+        account_id: &str,
+        region: &str,
+        task: &serde_json::Value,
+    ) -> Result<Vec<serde_json::Value>> {
+        let task_definition_arn = task
+            .get("TaskDefinitionArn")
+            .and_then(|v| v.as_str())
+            .context("Task has no TaskDefinitionArn")?;
+        let task_arn = task
+            .get("TaskArn")
+            .and_then(|v| v.as_str())
+            .context("Task has no TaskArn")?;
+        let task_id = task_arn.split('/').next_back().unwrap_or(task_arn);
+
+        report_status("ECS", "describe_task_definition", Some(task_definition_arn));
+
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = ecs::Client::new(&aws_config);
+        let response = timeout(
+            Duration::from_secs(10),
+            client
+                .describe_task_definition()
+                .task_definition(task_definition_arn)
+                .send(),
+        )
+        .await
+        .with_context(|| "describe_task_definition timed out")?
+        .with_context(|| format!("Failed to describe task definition {}", task_definition_arn))?;
+
+        report_status_done("ECS", "describe_task_definition", Some(task_definition_arn));
+
+        let runtime_containers = task
+            .get("Containers")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+
+        Ok(response
+            .task_definition
+            .and_then(|task_definition| task_definition.container_definitions)
+            .unwrap_or_default()
+            .iter()
+            .map(|container| {
+                let mut json =
+                    container_definition_to_json(container, task_definition_arn, task_id);
+                // Runtime status comes from the task, not the definition
+                let runtime = runtime_containers.iter().find(|c| {
+                    c.get("Name").and_then(|v| v.as_str()) == container.name.as_deref()
+                });
+                if let (Some(runtime), Some(object)) = (runtime, json.as_object_mut()) {
+                    for key in ["LastStatus", "ExitCode"] {
+                        if let Some(value) = runtime.get(key) {
+                            object.insert(key.to_string(), value.clone());
+                        }
+                    }
+                }
+                json
+            })
+            .collect())
+    }
+
     async fn describe_clusters_internal(
         &self,
         client: &ecs::Client,
@@ -1638,3 +1764,145 @@ impl ECSService {
         serde_json::Value::Object(json)
     }
 }
+
+/// Container definition of a running task as a child resource
+///
+/// `ContainerId` is "{task-id}/{container-name}" so the same container in
+/// different tasks of a service stays distinct.
+fn container_definition_to_json(
+    container: &ecs::types::ContainerDefinition,
+    task_definition_arn: &str,
+    task_id: &str,
+) -> serde_json::Value {
+    let mut json = serde_json::Map::new();
+    let name = container.name.clone().unwrap_or_default();
+
+    json.insert(
+        "ContainerId".to_string(),
+        serde_json::Value::String(format!("{}/{}", task_id, name)),
+    );
+    json.insert("Name".to_string(), serde_json::Value::String(name.clone()));
+    json.insert(
+        "TaskId".to_string(),
+        serde_json::Value::String(task_id.to_string()),
+    );
+    json.insert(
+        "TaskDefinitionArn".to_string(),
+        serde_json::Value::String(task_definition_arn.to_string()),
+    );
+
+    if let Some(image) = &container.image {
+        json.insert("Image".to_string(), serde_json::Value::String(image.clone()));
+    }
+    json.insert(
+        "Cpu".to_string(),
+        serde_json::Value::Number(container.cpu.into()),
+    );
+    if let Some(memory) = container.memory {
+        json.insert("Memory".to_string(), serde_json::Value::Number(memory.into()));
+    }
+    if let Some(memory_reservation) = container.memory_reservation {
+        json.insert(
+            "MemoryReservation".to_string(),
+            serde_json::Value::Number(memory_reservation.into()),
+        );
+    }
+    if let Some(essential) = container.essential {
+        json.insert("Essential".to_string(), serde_json::Value::Bool(essential));
+    }
+
+    if let Some(environment) = &container.environment {
+        let environment_json: serde_json::Map<String, serde_json::Value> = environment
+            .iter()
+            .filter_map(|variable| {
+                variable.name.as_ref().map(|name| {
+                    (
+                        name.clone(),
+                        serde_json::Value::String(variable.value.clone().unwrap_or_default()),
+                    )
+                })
+            })
+            .collect();
+        json.insert(
+            "Environment".to_string(),
+            serde_json::Value::Object(environment_json),
+        );
+    }
+
+    // Only where secrets come from; their values are resolved at task start
+    if let Some(secrets) = &container.secrets {
+        let secrets_json: serde_json::Map<String, serde_json::Value> = secrets
+            .iter()
+            .map(|secret| {
+                (
+                    secret.name.clone(),
+                    serde_json::Value::String(secret.value_from.clone()),
+                )
+            })
+            .collect();
+        json.insert("Secrets".to_string(), serde_json::Value::Object(secrets_json));
+    }
+
+    if let Some(log_configuration) = &container.log_configuration {
+        let options = log_configuration.options.clone().unwrap_or_default();
+        json.insert(
+            "LogDriver".to_string(),
+            serde_json::Value::String(log_configuration.log_driver.as_str().to_string()),
+        );
+        if log_configuration.log_driver == ecs::types::LogDriver::Awslogs {
+            if let Some(group) = options.get("awslogs-group") {
+                json.insert("LogGroup".to_string(), serde_json::Value::String(group.clone()));
+            }
+            // awslogs names streams {prefix}/{container-name}/{task-id}
+            if let Some(prefix) = options.get("awslogs-stream-prefix") {
+                json.insert(
+                    "LogStream".to_string(),
+                    serde_json::Value::String(format!("{}/{}/{}", prefix, name, task_id)),
+                );
+            }
+        }
+    }
+
+    serde_json::Value::Object(json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_container_definition_to_json() {
+        let container = ecs::types::ContainerDefinition::builder()
+            .name("web")
+            .image("123456789012.dkr.ecr.us-east-1.amazonaws.com/web:1.4")
+            .cpu(256)
+            .memory(512)
+            .environment(
+                ecs::types::KeyValuePair::builder()
+                    .name("STAGE")
+                    .value("prod")
+                    .build(),
+            )
+            .log_configuration(
+                ecs::types::LogConfiguration::builder()
+                    .log_driver(ecs::types::LogDriver::Awslogs)
+                    .options("awslogs-group", "/ecs/web")
+                    .options("awslogs-stream-prefix", "ecs")
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+
+        let json = container_definition_to_json(
+            &container,
+            "arn:aws:ecs:us-east-1:123456789012:task-definition/web:7",
+            "0a1b2c3d",
+        );
+        assert_eq!(json["ContainerId"], "0a1b2c3d/web");
+        assert_eq!(json["Cpu"], 256);
+        assert_eq!(json["Memory"], 512);
+        assert_eq!(json["Environment"]["STAGE"], "prod");
+        assert_eq!(json["LogGroup"], "/ecs/web");
+        assert_eq!(json["LogStream"], "ecs/web/0a1b2c3d");
+    }
+}
@@ -160,6 +160,66 @@ impl EKSService {
         Ok(fargate_profiles)
     }
 
+    /// List the managed node groups of an EKS cluster
+    pub async fn list_nodegroups(
+        &self,
+        account_id: &str,
+        region: &str,
+        cluster_name: &str,
+    ) -> Result<Vec<serde_json::Value>> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = eks::Client::new(&aws_config);
+        let mut nodegroups = Vec::new();
+
+        let mut paginator = client
+            .list_nodegroups()
+            .cluster_name(cluster_name)
+            .into_paginator()
+            .send();
+
+        while let Some(page) = paginator.next().await {
+            let page = page?;
+            if let Some(nodegroup_names) = page.nodegroups {
+                for nodegroup_name in nodegroup_names {
+                    let response = client
+                        .describe_nodegroup()
+                        .cluster_name(cluster_name)
+                        .nodegroup_name(&nodegroup_name)
+                        .send()
+                        .await;
+                    match response.ok().and_then(|r| r.nodegroup) {
+                        Some(nodegroup) => nodegroups.push(self.nodegroup_to_json(&nodegroup)),
+                        None => {
+                            // Fallback to basic node group info if describe fails
+                            let mut basic_nodegroup = serde_json::Map::new();
+                            basic_nodegroup.insert(
+                                "NodegroupName".to_string(),
+                                serde_json::Value::String(nodegroup_name.clone()),
+                            );
+                            basic_nodegroup.insert(
+                                "ClusterName".to_string(),
+                                serde_json::Value::String(cluster_name.to_string()),
+                            );
+                            nodegroups.push(serde_json::Value::Object(basic_nodegroup));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(nodegroups)
+    }
+
     async fn describe_fargate_profile_internal(
         &self,
         client: &eks::Client,
@@ -344,6 +404,158 @@ impl EKSService {
         serde_json::Value::Object(json)
     }
 
+    fn nodegroup_to_json(&self, nodegroup: &eks::types::Nodegroup) -> serde_json::Value {
+        let mut json = serde_json::Map::new();
+
+        if let Some(nodegroup_name) = &nodegroup.nodegroup_name {
+            json.insert(
+                "NodegroupName".to_string(),
+                serde_json::Value::String(nodegroup_name.clone()),
+            );
+        }
+
+        if let Some(nodegroup_arn) = &nodegroup.nodegroup_arn {
+            json.insert(
+                "NodegroupArn".to_string(),
+                serde_json::Value::String(nodegroup_arn.clone()),
+            );
+        }
+
+        if let Some(cluster_name) = &nodegroup.cluster_name {
+            json.insert(
+                "ClusterName".to_string(),
+                serde_json::Value::String(cluster_name.clone()),
+            );
+        }
+
+        if let Some(status) = &nodegroup.status {
+            json.insert(
+                "Status".to_string(),
+                serde_json::Value::String(status.as_str().to_string()),
+            );
+        }
+
+        if let Some(version) = &nodegroup.version {
+            json.insert(
+                "Version".to_string(),
+                serde_json::Value::String(version.clone()),
+            );
+        }
+
+        if let Some(release_version) = &nodegroup.release_version {
+            json.insert(
+                "ReleaseVersion".to_string(),
+                serde_json::Value::String(release_version.clone()),
+            );
+        }
+
+        if let Some(capacity_type) = &nodegroup.capacity_type {
+            json.insert(
+                "CapacityType".to_string(),
+                serde_json::Value::String(capacity_type.as_str().to_string()),
+            );
+        }
+
+        if let Some(ami_type) = &nodegroup.ami_type {
+            json.insert(
+                "AmiType".to_string(),
+                serde_json::Value::String(ami_type.as_str().to_string()),
+            );
+        }
+
+        if let Some(instance_types) = &nodegroup.instance_types {
+            json.insert(
+                "InstanceTypes".to_string(),
+                serde_json::Value::Array(
+                    instance_types
+                        .iter()
+                        .map(|t| serde_json::Value::String(t.clone()))
+                        .collect(),
+                ),
+            );
+        }
+
+        if let Some(scaling_config) = &nodegroup.scaling_config {
+            let mut scaling_json = serde_json::Map::new();
+            if let Some(min_size) = scaling_config.min_size {
+                scaling_json.insert(
+                    "MinSize".to_string(),
+                    serde_json::Value::Number(min_size.into()),
+                );
+            }
+            if let Some(max_size) = scaling_config.max_size {
+                scaling_json.insert(
+                    "MaxSize".to_string(),
+                    serde_json::Value::Number(max_size.into()),
+                );
+            }
+            if let Some(desired_size) = scaling_config.desired_size {
+                scaling_json.insert(
+                    "DesiredSize".to_string(),
+                    serde_json::Value::Number(desired_size.into()),
+                );
+            }
+            json.insert(
+                "ScalingConfig".to_string(),
+                serde_json::Value::Object(scaling_json),
+            );
+        }
+
+        if let Some(subnets) = &nodegroup.subnets {
+            json.insert(
+                "Subnets".to_string(),
+                serde_json::Value::Array(
+                    subnets
+                        .iter()
+                        .map(|s| serde_json::Value::String(s.clone()))
+                        .collect(),
+                ),
+            );
+        }
+
+        if let Some(node_role) = &nodegroup.node_role {
+            json.insert(
+                "NodeRole".to_string(),
+                serde_json::Value::String(node_role.clone()),
+            );
+        }
+
+        if let Some(health) = &nodegroup.health {
+            let issues: Vec<serde_json::Value> = health
+                .issues
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .map(|issue| {
+                    let mut issue_json = serde_json::Map::new();
+                    if let Some(code) = &issue.code {
+                        issue_json.insert(
+                            "Code".to_string(),
+                            serde_json::Value::String(code.as_str().to_string()),
+                        );
+                    }
+                    if let Some(message) = &issue.message {
+                        issue_json.insert(
+                            "Message".to_string(),
+                            serde_json::Value::String(message.clone()),
+                        );
+                    }
+                    serde_json::Value::Object(issue_json)
+                })
+                .collect();
+            json.insert("HealthIssues".to_string(), serde_json::Value::Array(issues));
+        }
+
+        if let Some(created_at) = nodegroup.created_at {
+            json.insert(
+                "CreatedAt".to_string(),
+                serde_json::Value::String(created_at.to_string()),
+            );
+        }
+
+        serde_json::Value::Object(json)
+    }
+
     fn fargate_profile_to_json(&self, profile: &eks::types::FargateProfile) -> serde_json::Value {
         let mut json = serde_json::Map::new();
 
@@ -217,12 +217,17 @@ impl EventBridgeService {
         Ok(serde_json::Value::Object(event_bus_details))
     }
 
-    /// List EventBridge rules
+    /// List EventBridge rules on every event bus, each with its targets
+    ///
+    /// Targets are included so rule relationships to Lambda functions, queues and
+    /// state machines can be extracted without a detail fetch.
     pub async fn list_rules(
         &self,
         account_id: &str,
         region: &str,
     ) -> Result<Vec<serde_json::Value>> {
+        report_status("EventBridge", "list_rules", Some(region));
+
         let aws_config = self
             .credential_coordinator
             .create_aws_config_for_account(account_id, region)
@@ -235,16 +240,39 @@ impl EventBridgeService {
             })?;
 
         let client = eventbridge::Client::new(&aws_config);
-        let response = client.list_rules().send().await?;
+
+        // ListRules without a bus name only covers the default bus
+        let mut bus_names: Vec<String> = client
+            .list_event_buses()
+            .send()
+            .await?
+            .event_buses()
+            .iter()
+            .filter_map(|bus| bus.name().map(String::from))
+            .collect();
+        if bus_names.is_empty() {
+            bus_names.push("default".to_string());
+        }
 
         let mut rules = Vec::new();
-        if let Some(rules_list) = response.rules {
-            for rule in rules_list {
-                let rule_json = self.rule_to_json(&rule);
-                rules.push(rule_json);
+        for bus_name in &bus_names {
+            let bus_rules = self.list_rules_for_bus_internal(&client, bus_name).await?;
+            for mut rule in bus_rules.as_array().cloned().unwrap_or_default() {
+                if let Some(rule_name) = rule.get("Name").and_then(|v| v.as_str()) {
+                    if let Ok(targets) = self
+                        .list_targets_by_rule_internal(&client, rule_name, bus_name)
+                        .await
+                    {
+                        if let Some(obj) = rule.as_object_mut() {
+                            obj.insert("Targets".to_string(), targets);
+                        }
+                    }
+                }
+                rules.push(rule);
             }
         }
 
+        report_status_done("EventBridge", "list_rules", Some(region));
         Ok(rules)
     }
 
@@ -1,8 +1,15 @@
 use super::super::credentials::CredentialCoordinator;
+use super::super::threat_findings::{guardduty_severity, FindingSource, ThreatFinding};
 use anyhow::{Context, Result};
 use aws_sdk_guardduty as guardduty;
 use std::sync::Arc;
 
+/// GetFindings accepts at most 50 finding IDs
+const FINDING_ID_BATCH: usize = 50;
+
+/// Findings fetched per detector; the rest are left for the GuardDuty console
+const MAX_ACTIVE_FINDINGS: usize = 500;
+
 pub struct GuardDutyService {
     credential_coordinator: Arc<CredentialCoordinator>,
 }
@@ -77,6 +84,169 @@ impl GuardDutyService {
         self.get_detector_internal(&client, detector_id).await
     }
 
+    /// Active (not archived) findings of a detector, newest first
+    pub async fn list_active_findings(
+        &self,
+        account_id: &str,
+        region: &str,
+        detector_id: &str,
+    ) -> Result<Vec<ThreatFinding>> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = guardduty::Client::new(&aws_config);
+
+        let criteria = guardduty::types::FindingCriteria::builder()
+            .criterion(
+                "service.archived",
+                guardduty::types::Condition::builder()
+                    .equals("false")
+                    .build(),
+            )
+            .build();
+        let sort = guardduty::types::SortCriteria::builder()
+            .attribute_name("updatedAt")
+            .order_by(guardduty::types::OrderBy::Desc)
+            .build();
+
+        let mut finding_ids = Vec::new();
+        let mut next_token = None;
+        loop {
+            let response = client
+                .list_findings()
+                .detector_id(detector_id)
+                .finding_criteria(criteria.clone())
+                .sort_criteria(sort.clone())
+                .max_results(50)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .context("Failed to list GuardDuty findings")?;
+            finding_ids.extend(response.finding_ids().iter().cloned());
+            next_token = response
+                .next_token()
+                .filter(|token| !token.is_empty())
+                .map(String::from);
+            if next_token.is_none() || finding_ids.len() >= MAX_ACTIVE_FINDINGS {
+                break;
+            }
+        }
+        finding_ids.truncate(MAX_ACTIVE_FINDINGS);
+
+        let mut findings = Vec::new();
+        for batch in finding_ids.chunks(FINDING_ID_BATCH) {
+            let response = client
+                .get_findings()
+                .detector_id(detector_id)
+                .set_finding_ids(Some(batch.to_vec()))
+                .send()
+                .await
+                .context("Failed to get GuardDuty findings")?;
+            findings.extend(
+                response
+                    .findings()
+                    .iter()
+                    .map(|finding| self.finding_to_threat_finding(finding, detector_id)),
+            );
+        }
+
+        Ok(findings)
+    }
+
+    /// Archive findings so they no longer show as active
+    pub async fn archive_findings(
+        &self,
+        account_id: &str,
+        region: &str,
+        detector_id: &str,
+        finding_ids: &[String],
+    ) -> Result<()> {
+        crate::app::capabilities::ensure_writable("Archive GuardDuty findings")?;
+
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = guardduty::Client::new(&aws_config);
+        for batch in finding_ids.chunks(FINDING_ID_BATCH) {
+            client
+                .archive_findings()
+                .detector_id(detector_id)
+                .set_finding_ids(Some(batch.to_vec()))
+                .send()
+                .await
+                .context("Failed to archive GuardDuty findings")?;
+        }
+        Ok(())
+    }
+
+    fn finding_to_threat_finding(
+        &self,
+        finding: &guardduty::types::Finding,
+        detector_id: &str,
+    ) -> ThreatFinding {
+        let mut resources = Vec::new();
+        if let Some(resource) = finding.resource() {
+            if let Some(instance_id) = resource.instance_details().and_then(|d| d.instance_id()) {
+                resources.push(instance_id.to_string());
+            }
+            if let Some(user_name) = resource.access_key_details().and_then(|d| d.user_name()) {
+                resources.push(user_name.to_string());
+            }
+            for bucket in resource.s3_bucket_details() {
+                if let Some(arn) = bucket.arn() {
+                    resources.push(arn.to_string());
+                } else if let Some(name) = bucket.name() {
+                    resources.push(name.to_string());
+                }
+            }
+            if let Some(cluster) = resource.eks_cluster_details().and_then(|d| d.arn()) {
+                resources.push(cluster.to_string());
+            }
+            if let Some(function) = resource.lambda_details().and_then(|d| d.function_arn()) {
+                resources.push(function.to_string());
+            }
+            if let Some(database) = resource
+                .rds_db_instance_details()
+                .and_then(|d| d.db_instance_identifier())
+            {
+                resources.push(database.to_string());
+            }
+            if let Some(cluster) = resource.ecs_cluster_details().and_then(|d| d.arn()) {
+                resources.push(cluster.to_string());
+            }
+        }
+
+        ThreatFinding {
+            source: FindingSource::GuardDuty,
+            id: finding.id().to_string(),
+            owner: detector_id.to_string(),
+            account_id: finding.account_id().to_string(),
+            region: finding.region().to_string(),
+            severity: guardduty_severity(finding.severity().unwrap_or_default()),
+            finding_type: finding.r#type().to_string(),
+            title: finding.title().unwrap_or_default().to_string(),
+            description: finding.description().unwrap_or_default().to_string(),
+            resources,
+            updated_at: Some(finding.updated_at().to_string()),
+        }
+    }
+
     async fn get_detector_internal(
         &self,
         client: &guardduty::Client,
@@ -1,5 +1,6 @@
 use super::super::credentials::CredentialCoordinator;
 use super::super::status::{report_status, report_status_done};
+use crate::app::aws_regions::arn_partition;
 use anyhow::{Context, Result};
 use aws_sdk_opensearch as opensearch;
 use std::sync::Arc;
@@ -632,8 +633,11 @@ impl OpenSearchService {
 
         // Build the domain ARN
         let domain_arn = format!(
-            "arn:aws:es:{}:{}:domain/{}",
-            region, account_id, domain_name
+            "arn:{}:es:{}:{}:domain/{}",
+            arn_partition(region),
+            region,
+            account_id,
+            domain_name
         );
 
         let result = timeout(timeout_duration, client.list_tags().arn(&domain_arn).send())
@@ -1,6 +1,8 @@
 use super::super::credentials::CredentialCoordinator;
+use super::super::organization_directory::OrganizationAccount;
 use anyhow::{Context, Result};
 use aws_sdk_organizations as organizations;
+use std::collections::VecDeque;
 use std::sync::Arc;
 
 pub struct OrganizationsService {
@@ -185,6 +187,76 @@ impl OrganizationsService {
         Ok(accounts)
     }
 
+    /// Walk the organization from its roots, returning every account with its OU path
+    pub async fn discover_account_tree(
+        &self,
+        account_id: &str,
+        region: &str,
+    ) -> Result<Vec<OrganizationAccount>> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = organizations::Client::new(&aws_config);
+
+        let mut parents: VecDeque<(String, Vec<String>)> = VecDeque::new();
+        let mut paginator = client.list_roots().into_paginator().send();
+        while let Some(page) = paginator.next().await {
+            for root in page?.roots.unwrap_or_default() {
+                if let Some(root_id) = root.id {
+                    parents.push_back((root_id, Vec::new()));
+                }
+            }
+        }
+
+        let mut accounts = Vec::new();
+        while let Some((parent_id, ou_path)) = parents.pop_front() {
+            let mut paginator = client
+                .list_accounts_for_parent()
+                .parent_id(&parent_id)
+                .into_paginator()
+                .send();
+            while let Some(page) = paginator.next().await {
+                for account in page?.accounts.unwrap_or_default() {
+                    let Some(id) = account.id else {
+                        continue;
+                    };
+                    accounts.push(OrganizationAccount {
+                        name: account.name.unwrap_or_else(|| id.clone()),
+                        account_id: id,
+                        email: account.email,
+                        status: account.status.map(|status| status.as_str().to_string()),
+                        ou_path: ou_path.clone(),
+                    });
+                }
+            }
+
+            let mut paginator = client
+                .list_organizational_units_for_parent()
+                .parent_id(&parent_id)
+                .into_paginator()
+                .send();
+            while let Some(page) = paginator.next().await {
+                for ou in page?.organizational_units.unwrap_or_default() {
+                    if let Some(ou_id) = ou.id {
+                        let mut child_path = ou_path.clone();
+                        child_path.push(ou.name.unwrap_or_else(|| ou_id.clone()));
+                        parents.push_back((ou_id, child_path));
+                    }
+                }
+            }
+        }
+
+        Ok(accounts)
+    }
+
     /// List policies attached to a specific target (OU, Account, or Root)
     pub async fn list_policies_for_target(
         &self,
@@ -449,21 +449,97 @@ impl RDSService {
             );
         }
 
-        // TODO: Manually convert complex types to avoid serialization issues
-        // if let Some(vpc_security_groups) = &instance.vpc_security_groups {
-        //     instance_map.insert("VpcSecurityGroups".to_string(),
-        //         serde_json::to_value(vpc_security_groups).unwrap_or(serde_json::Value::Null));
-        // }
+        if let Some(vpc_security_groups) = &instance.vpc_security_groups {
+            let groups_json: Vec<serde_json::Value> = vpc_security_groups
+                .iter()
+                .map(|group| {
+                    let mut group_json = serde_json::Map::new();
+                    if let Some(group_id) = &group.vpc_security_group_id {
+                        group_json.insert(
+                            "VpcSecurityGroupId".to_string(),
+                            serde_json::Value::String(group_id.clone()),
+                        );
+                    }
+                    if let Some(status) = &group.status {
+                        group_json.insert(
+                            "Status".to_string(),
+                            serde_json::Value::String(status.clone()),
+                        );
+                    }
+                    serde_json::Value::Object(group_json)
+                })
+                .collect();
+            instance_map.insert(
+                "VpcSecurityGroups".to_string(),
+                serde_json::Value::Array(groups_json),
+            );
+        }
 
-        // if let Some(db_subnet_group) = &instance.db_subnet_group {
-        //     instance_map.insert("DBSubnetGroup".to_string(),
-        //         serde_json::to_value(db_subnet_group).unwrap_or(serde_json::Value::Null));
-        // }
+        if let Some(db_subnet_group) = &instance.db_subnet_group {
+            let mut subnet_group_json = serde_json::Map::new();
+            if let Some(name) = &db_subnet_group.db_subnet_group_name {
+                subnet_group_json.insert(
+                    "DBSubnetGroupName".to_string(),
+                    serde_json::Value::String(name.clone()),
+                );
+            }
+            if let Some(vpc_id) = &db_subnet_group.vpc_id {
+                subnet_group_json.insert(
+                    "VpcId".to_string(),
+                    serde_json::Value::String(vpc_id.clone()),
+                );
+            }
+            if let Some(subnets) = &db_subnet_group.subnets {
+                let subnets_json: Vec<serde_json::Value> = subnets
+                    .iter()
+                    .map(|subnet| {
+                        let mut subnet_json = serde_json::Map::new();
+                        if let Some(subnet_id) = &subnet.subnet_identifier {
+                            subnet_json.insert(
+                                "SubnetIdentifier".to_string(),
+                                serde_json::Value::String(subnet_id.clone()),
+                            );
+                        }
+                        if let Some(zone) = subnet
+                            .subnet_availability_zone
+                            .as_ref()
+                            .and_then(|zone| zone.name.as_ref())
+                        {
+                            subnet_json.insert(
+                                "SubnetAvailabilityZone".to_string(),
+                                serde_json::json!({ "Name": zone }),
+                            );
+                        }
+                        serde_json::Value::Object(subnet_json)
+                    })
+                    .collect();
+                subnet_group_json.insert(
+                    "Subnets".to_string(),
+                    serde_json::Value::Array(subnets_json),
+                );
+            }
+            instance_map.insert(
+                "DBSubnetGroup".to_string(),
+                serde_json::Value::Object(subnet_group_json),
+            );
+        }
 
-        // if let Some(endpoint) = &instance.endpoint {
-        //     instance_map.insert("Endpoint".to_string(),
-        //         serde_json::to_value(endpoint).unwrap_or(serde_json::Value::Null));
-        // }
+        if let Some(endpoint) = &instance.endpoint {
+            let mut endpoint_json = serde_json::Map::new();
+            if let Some(address) = &endpoint.address {
+                endpoint_json.insert(
+                    "Address".to_string(),
+                    serde_json::Value::String(address.clone()),
+                );
+            }
+            if let Some(port) = endpoint.port {
+                endpoint_json.insert("Port".to_string(), serde_json::Value::Number(port.into()));
+            }
+            instance_map.insert(
+                "Endpoint".to_string(),
+                serde_json::Value::Object(endpoint_json),
+            );
+        }
 
         if let Some(multi_az) = instance.multi_az {
             instance_map.insert("MultiAZ".to_string(), serde_json::Value::Bool(multi_az));
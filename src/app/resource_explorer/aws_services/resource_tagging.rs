@@ -1,6 +1,7 @@
 use super::super::credentials::CredentialCoordinator;
+use super::super::global_services::get_global_query_region;
 use super::super::query_timing;
-use super::super::state::ResourceTag;
+use super::super::state::{ResourceTag, TagChanges};
 use anyhow::{Context, Result};
 
 /// Redact sensitive string, showing only last 4 characters
@@ -693,7 +694,7 @@ impl ResourceTaggingService {
     ) -> Result<Vec<ResourceTag>> {
         let aws_config = self
             .credential_coordinator
-            .create_aws_config_for_account(account_id, "us-east-1")
+            .create_aws_config_for_account(account_id, get_global_query_region())
             .await?;
 
         let client = iam::Client::new(&aws_config);
@@ -735,7 +736,7 @@ impl ResourceTaggingService {
     ) -> Result<Vec<ResourceTag>> {
         let aws_config = self
             .credential_coordinator
-            .create_aws_config_for_account(account_id, "us-east-1")
+            .create_aws_config_for_account(account_id, get_global_query_region())
             .await?;
 
         let client = iam::Client::new(&aws_config);
@@ -780,7 +781,7 @@ impl ResourceTaggingService {
     ) -> Result<Vec<ResourceTag>> {
         let aws_config = self
             .credential_coordinator
-            .create_aws_config_for_account(account_id, "us-east-1")
+            .create_aws_config_for_account(account_id, get_global_query_region())
             .await?;
 
         let client = iam::Client::new(&aws_config);
@@ -822,7 +823,7 @@ impl ResourceTaggingService {
     ) -> Result<Vec<ResourceTag>> {
         let aws_config = self
             .credential_coordinator
-            .create_aws_config_for_account(account_id, "us-east-1")
+            .create_aws_config_for_account(account_id, get_global_query_region())
             .await?;
 
         let client = iam::Client::new(&aws_config);
@@ -866,7 +867,7 @@ impl ResourceTaggingService {
     ) -> Result<Vec<ResourceTag>> {
         let aws_config = self
             .credential_coordinator
-            .create_aws_config_for_account(account_id, "us-east-1")
+            .create_aws_config_for_account(account_id, get_global_query_region())
             .await?;
 
         let client = organizations::Client::new(&aws_config);
@@ -1155,7 +1156,7 @@ impl ResourceTaggingService {
     ) -> Result<Vec<ResourceTag>> {
         let aws_config = self
             .credential_coordinator
-            .create_aws_config_for_account(account_id, "us-east-1")
+            .create_aws_config_for_account(account_id, get_global_query_region())
             .await?;
 
         let client = cloudfront::Client::new(&aws_config);
@@ -1297,7 +1298,7 @@ impl ResourceTaggingService {
     ) -> Result<Vec<ResourceTag>> {
         let aws_config = self
             .credential_coordinator
-            .create_aws_config_for_account(account_id, "us-east-1")
+            .create_aws_config_for_account(account_id, get_global_query_region())
             .await?;
 
         let client = route53::Client::new(&aws_config);
@@ -1663,6 +1664,369 @@ impl ResourceTaggingService {
         );
         Ok(tags)
     }
+
+    //
+    // Tag Writing Methods
+    //
+    // Mirror the fetch methods above: the Resource Groups Tagging API handles
+    // anything addressable by ARN, and services it does not cover (IAM) or
+    // that are addressed by ID rather than ARN (EC2, S3) use their own APIs.
+    //
+
+    /// Add/update and remove tags on a resource using the Resource Groups Tagging API
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let changes = TagChanges { set: vec![tag("Owner", "alice")], remove: vec!["Temp".into()] };
+    /// service.apply_tags_for_arn("123456789012", "us-east-1", arn, &changes).await?;
+    /// ```
+    pub async fn apply_tags_for_arn(
+        &self,
+        account_id: &str,
+        region: &str,
+        resource_arn: &str,
+        changes: &TagChanges,
+    ) -> Result<()> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await?;
+
+        let client = tagging::Client::new(&aws_config);
+
+        if !changes.set.is_empty() {
+            let tags: HashMap<String, String> = changes
+                .set
+                .iter()
+                .map(|tag| (tag.key.clone(), tag.value.clone()))
+                .collect();
+            let response = client
+                .tag_resources()
+                .resource_arn_list(resource_arn)
+                .set_tags(Some(tags))
+                .send()
+                .await
+                .context("Failed to tag resource")?;
+            if let Some(failure) = response
+                .failed_resources_map()
+                .and_then(|failures| failures.get(resource_arn))
+            {
+                anyhow::bail!(
+                    "Failed to tag {}: {}",
+                    resource_arn,
+                    failure.error_message().unwrap_or("unknown error")
+                );
+            }
+        }
+
+        if !changes.remove.is_empty() {
+            let response = client
+                .untag_resources()
+                .resource_arn_list(resource_arn)
+                .set_tag_keys(Some(changes.remove.clone()))
+                .send()
+                .await
+                .context("Failed to untag resource")?;
+            if let Some(failure) = response
+                .failed_resources_map()
+                .and_then(|failures| failures.get(resource_arn))
+            {
+                anyhow::bail!(
+                    "Failed to untag {}: {}",
+                    resource_arn,
+                    failure.error_message().unwrap_or("unknown error")
+                );
+            }
+        }
+
+        tracing::debug!(
+            "Applied {} tag updates and {} removals to {}",
+            changes.set.len(),
+            changes.remove.len(),
+            resource_arn
+        );
+        Ok(())
+    }
+
+    /// Add/update and remove tags on EC2 resources by resource ID
+    pub async fn apply_ec2_tags(
+        &self,
+        account_id: &str,
+        region: &str,
+        resource_id: &str,
+        changes: &TagChanges,
+    ) -> Result<()> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await?;
+
+        let client = ec2::Client::new(&aws_config);
+
+        if !changes.set.is_empty() {
+            let tags = changes
+                .set
+                .iter()
+                .map(|tag| {
+                    ec2::types::Tag::builder()
+                        .key(&tag.key)
+                        .value(&tag.value)
+                        .build()
+                })
+                .collect();
+            client
+                .create_tags()
+                .resources(resource_id)
+                .set_tags(Some(tags))
+                .send()
+                .await
+                .context("Failed to create EC2 tags")?;
+        }
+
+        if !changes.remove.is_empty() {
+            // A tag with only a key deletes it regardless of value
+            let tags = changes
+                .remove
+                .iter()
+                .map(|key| ec2::types::Tag::builder().key(key).build())
+                .collect();
+            client
+                .delete_tags()
+                .resources(resource_id)
+                .set_tags(Some(tags))
+                .send()
+                .await
+                .context("Failed to delete EC2 tags")?;
+        }
+
+        Ok(())
+    }
+
+    /// Replace the tag set of an S3 bucket
+    ///
+    /// S3 has no incremental tag API, so the current tags are fetched, the
+    /// changes applied, and the whole set written back.
+    pub async fn apply_s3_bucket_tags(
+        &self,
+        account_id: &str,
+        region: &str,
+        bucket_name: &str,
+        changes: &TagChanges,
+    ) -> Result<()> {
+        let mut tags = self
+            .get_s3_bucket_tags(account_id, region, bucket_name)
+            .await?;
+        changes.apply_to(&mut tags);
+
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await?;
+
+        let client = s3::Client::new(&aws_config);
+
+        if tags.is_empty() {
+            client
+                .delete_bucket_tagging()
+                .bucket(bucket_name)
+                .send()
+                .await
+                .context("Failed to delete S3 bucket tags")?;
+            return Ok(());
+        }
+
+        let tag_set = tags
+            .iter()
+            .map(|tag| {
+                s3::types::Tag::builder()
+                    .key(&tag.key)
+                    .value(&tag.value)
+                    .build()
+                    .context("Invalid S3 tag")
+            })
+            .collect::<Result<Vec<_>>>()?;
+        client
+            .put_bucket_tagging()
+            .bucket(bucket_name)
+            .tagging(
+                s3::types::Tagging::builder()
+                    .set_tag_set(Some(tag_set))
+                    .build()
+                    .context("Invalid S3 tag set")?,
+            )
+            .send()
+            .await
+            .context("Failed to put S3 bucket tags")?;
+
+        Ok(())
+    }
+
+    /// Add/update and remove tags on Lambda functions, layers, and event source mappings
+    pub async fn apply_lambda_tags(
+        &self,
+        account_id: &str,
+        region: &str,
+        resource_arn: &str,
+        changes: &TagChanges,
+    ) -> Result<()> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await?;
+
+        let client = lambda::Client::new(&aws_config);
+
+        if !changes.set.is_empty() {
+            let tags: HashMap<String, String> = changes
+                .set
+                .iter()
+                .map(|tag| (tag.key.clone(), tag.value.clone()))
+                .collect();
+            client
+                .tag_resource()
+                .resource(resource_arn)
+                .set_tags(Some(tags))
+                .send()
+                .await
+                .context("Failed to tag Lambda resource")?;
+        }
+
+        if !changes.remove.is_empty() {
+            client
+                .untag_resource()
+                .resource(resource_arn)
+                .set_tag_keys(Some(changes.remove.clone()))
+                .send()
+                .await
+                .context("Failed to untag Lambda resource")?;
+        }
+
+        Ok(())
+    }
+
+    /// Add/update and remove tags on IAM roles
+    pub async fn apply_iam_role_tags(
+        &self,
+        account_id: &str,
+        role_name: &str,
+        changes: &TagChanges,
+    ) -> Result<()> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, get_global_query_region())
+            .await?;
+
+        let client = iam::Client::new(&aws_config);
+
+        if !changes.set.is_empty() {
+            client
+                .tag_role()
+                .role_name(role_name)
+                .set_tags(Some(Self::iam_tags(&changes.set)?))
+                .send()
+                .await
+                .context("Failed to tag IAM role")?;
+        }
+
+        if !changes.remove.is_empty() {
+            client
+                .untag_role()
+                .role_name(role_name)
+                .set_tag_keys(Some(changes.remove.clone()))
+                .send()
+                .await
+                .context("Failed to untag IAM role")?;
+        }
+
+        Ok(())
+    }
+
+    /// Add/update and remove tags on IAM users
+    pub async fn apply_iam_user_tags(
+        &self,
+        account_id: &str,
+        user_name: &str,
+        changes: &TagChanges,
+    ) -> Result<()> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, get_global_query_region())
+            .await?;
+
+        let client = iam::Client::new(&aws_config);
+
+        if !changes.set.is_empty() {
+            client
+                .tag_user()
+                .user_name(user_name)
+                .set_tags(Some(Self::iam_tags(&changes.set)?))
+                .send()
+                .await
+                .context("Failed to tag IAM user")?;
+        }
+
+        if !changes.remove.is_empty() {
+            client
+                .untag_user()
+                .user_name(user_name)
+                .set_tag_keys(Some(changes.remove.clone()))
+                .send()
+                .await
+                .context("Failed to untag IAM user")?;
+        }
+
+        Ok(())
+    }
+
+    /// Add/update and remove tags on customer managed IAM policies (by ARN)
+    pub async fn apply_iam_policy_tags(
+        &self,
+        account_id: &str,
+        policy_arn: &str,
+        changes: &TagChanges,
+    ) -> Result<()> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, get_global_query_region())
+            .await?;
+
+        let client = iam::Client::new(&aws_config);
+
+        if !changes.set.is_empty() {
+            client
+                .tag_policy()
+                .policy_arn(policy_arn)
+                .set_tags(Some(Self::iam_tags(&changes.set)?))
+                .send()
+                .await
+                .context("Failed to tag IAM policy")?;
+        }
+
+        if !changes.remove.is_empty() {
+            client
+                .untag_policy()
+                .policy_arn(policy_arn)
+                .set_tag_keys(Some(changes.remove.clone()))
+                .send()
+                .await
+                .context("Failed to untag IAM policy")?;
+        }
+
+        Ok(())
+    }
+
+    fn iam_tags(tags: &[ResourceTag]) -> Result<Vec<iam::types::Tag>> {
+        tags.iter()
+            .map(|tag| {
+                iam::types::Tag::builder()
+                    .key(&tag.key)
+                    .value(&tag.value)
+                    .build()
+                    .context("Invalid IAM tag")
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -170,4 +170,424 @@ impl Route53Service {
         // In a real implementation, we might fetch additional details like record sets
         self.hosted_zone_to_json(hosted_zone)
     }
+
+    /// List the record sets of a hosted zone
+    pub async fn list_record_sets(
+        &self,
+        account_id: &str,
+        region: &str,
+        hosted_zone_id: &str,
+    ) -> Result<Vec<serde_json::Value>> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = route53::Client::new(&aws_config);
+        let zone_id = hosted_zone_id.trim_start_matches("/hostedzone/");
+
+        // ListResourceRecordSets pages by the next record name, type, and
+        // set identifier rather than a token
+        let mut record_sets = Vec::new();
+        let mut start_name: Option<String> = None;
+        let mut start_type: Option<route53::types::RrType> = None;
+        let mut start_identifier: Option<String> = None;
+        loop {
+            let response = client
+                .list_resource_record_sets()
+                .hosted_zone_id(zone_id)
+                .set_start_record_name(start_name.take())
+                .set_start_record_type(start_type.take())
+                .set_start_record_identifier(start_identifier.take())
+                .send()
+                .await
+                .with_context(|| format!("Failed to list record sets of {}", hosted_zone_id))?;
+
+            for record_set in response.resource_record_sets() {
+                record_sets.push(self.record_set_to_json(hosted_zone_id, record_set));
+            }
+
+            if !response.is_truncated() {
+                break;
+            }
+            start_name = response.next_record_name().map(String::from);
+            start_type = response.next_record_type().cloned();
+            start_identifier = response.next_record_identifier().map(String::from);
+            if start_name.is_none() {
+                break;
+            }
+        }
+
+        Ok(record_sets)
+    }
+
+    fn record_set_to_json(
+        &self,
+        hosted_zone_id: &str,
+        record_set: &route53::types::ResourceRecordSet,
+    ) -> serde_json::Value {
+        let mut json = serde_json::Map::new();
+
+        let name = decode_record_name(record_set.name());
+        let record_type = record_set.r#type().as_str().to_string();
+
+        // Names repeat across types and routing policies within a zone
+        let mut id = format!("{}/{}/{}", hosted_zone_id, name, record_type);
+        if let Some(set_identifier) = record_set.set_identifier() {
+            id.push('/');
+            id.push_str(set_identifier);
+        }
+        json.insert("Id".to_string(), serde_json::Value::String(id));
+        json.insert("Name".to_string(), serde_json::Value::String(name));
+        json.insert("Type".to_string(), serde_json::Value::String(record_type));
+        json.insert(
+            "HostedZoneId".to_string(),
+            serde_json::Value::String(hosted_zone_id.to_string()),
+        );
+
+        if let Some(ttl) = record_set.ttl() {
+            json.insert(
+                "TTL".to_string(),
+                serde_json::Value::Number(serde_json::Number::from(ttl)),
+            );
+        }
+
+        if !record_set.resource_records().is_empty() {
+            json.insert(
+                "ResourceRecords".to_string(),
+                serde_json::Value::Array(
+                    record_set
+                        .resource_records()
+                        .iter()
+                        .map(|record| serde_json::Value::String(record.value().to_string()))
+                        .collect(),
+                ),
+            );
+        }
+
+        if let Some(alias_target) = record_set.alias_target() {
+            let mut alias_json = serde_json::Map::new();
+            alias_json.insert(
+                "DNSName".to_string(),
+                serde_json::Value::String(alias_target.dns_name().to_string()),
+            );
+            alias_json.insert(
+                "HostedZoneId".to_string(),
+                serde_json::Value::String(alias_target.hosted_zone_id().to_string()),
+            );
+            alias_json.insert(
+                "EvaluateTargetHealth".to_string(),
+                serde_json::Value::Bool(alias_target.evaluate_target_health()),
+            );
+            json.insert(
+                "AliasTarget".to_string(),
+                serde_json::Value::Object(alias_json),
+            );
+        }
+
+        if let Some(set_identifier) = record_set.set_identifier() {
+            json.insert(
+                "SetIdentifier".to_string(),
+                serde_json::Value::String(set_identifier.to_string()),
+            );
+        }
+
+        if let Some(weight) = record_set.weight() {
+            json.insert(
+                "Weight".to_string(),
+                serde_json::Value::Number(serde_json::Number::from(weight)),
+            );
+        }
+
+        if let Some(record_region) = record_set.region() {
+            json.insert(
+                "Region".to_string(),
+                serde_json::Value::String(record_region.as_str().to_string()),
+            );
+        }
+
+        if let Some(failover) = record_set.failover() {
+            json.insert(
+                "Failover".to_string(),
+                serde_json::Value::String(failover.as_str().to_string()),
+            );
+        }
+
+        if let Some(health_check_id) = record_set.health_check_id() {
+            json.insert(
+                "HealthCheckId".to_string(),
+                serde_json::Value::String(health_check_id.to_string()),
+            );
+        }
+
+        serde_json::Value::Object(json)
+    }
+
+    /// List Route53 Health Checks with their current status
+    ///
+    /// Each check's status comes from the latest observations of the
+    /// Route53 health checkers.
+    pub async fn list_health_checks(
+        &self,
+        account_id: &str,
+        region: &str,
+    ) -> Result<Vec<serde_json::Value>> {
+        use futures::future::join_all;
+
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = route53::Client::new(&aws_config);
+        let mut paginator = client.list_health_checks().into_paginator().send();
+
+        let mut health_checks = Vec::new();
+        while let Some(page) = paginator.next().await {
+            let page = page?;
+            health_checks.extend(page.health_checks);
+        }
+
+        let futures = health_checks.iter().map(|health_check| {
+            let client = &client;
+            async move {
+                let mut json = self.health_check_to_json(health_check);
+                let (status, healthy, total) = self.health_check_status(client, health_check).await;
+                json.insert(
+                    "Status".to_string(),
+                    serde_json::Value::String(status.to_string()),
+                );
+                if total > 0 {
+                    json.insert(
+                        "HealthyCheckers".to_string(),
+                        serde_json::Value::Number(serde_json::Number::from(healthy)),
+                    );
+                    json.insert(
+                        "CheckerCount".to_string(),
+                        serde_json::Value::Number(serde_json::Number::from(total)),
+                    );
+                }
+                serde_json::Value::Object(json)
+            }
+        });
+
+        Ok(join_all(futures).await)
+    }
+
+    /// Status of a health check with the number of healthy and reporting
+    /// checkers
+    async fn health_check_status(
+        &self,
+        client: &route53::Client,
+        health_check: &route53::types::HealthCheck,
+    ) -> (&'static str, usize, usize) {
+        let config = health_check.health_check_config();
+        if config.and_then(|c| c.disabled()).unwrap_or(false) {
+            return ("Disabled", 0, 0);
+        }
+        // Calculated checks have no checker observations of their own
+        if config.is_some_and(|c| c.r#type() == &route53::types::HealthCheckType::Calculated) {
+            return ("Unknown", 0, 0);
+        }
+
+        match client
+            .get_health_check_status()
+            .health_check_id(health_check.id())
+            .send()
+            .await
+        {
+            Ok(response) => {
+                let statuses: Vec<&str> = response
+                    .health_check_observations()
+                    .iter()
+                    .filter_map(|observation| {
+                        observation
+                            .status_report()
+                            .and_then(|report| report.status())
+                    })
+                    .collect();
+                let healthy = statuses
+                    .iter()
+                    .filter(|status| status.starts_with("Success"))
+                    .count();
+                (
+                    health_status_label(healthy, statuses.len()),
+                    healthy,
+                    statuses.len(),
+                )
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to get status of health check {}: {}",
+                    health_check.id(),
+                    e
+                );
+                ("Unknown", 0, 0)
+            }
+        }
+    }
+
+    fn health_check_to_json(
+        &self,
+        health_check: &route53::types::HealthCheck,
+    ) -> serde_json::Map<String, serde_json::Value> {
+        let mut json = serde_json::Map::new();
+
+        json.insert(
+            "Id".to_string(),
+            serde_json::Value::String(health_check.id().to_string()),
+        );
+        json.insert(
+            "HealthCheckVersion".to_string(),
+            serde_json::Value::Number(serde_json::Number::from(
+                health_check.health_check_version(),
+            )),
+        );
+
+        if let Some(config) = health_check.health_check_config() {
+            json.insert(
+                "Type".to_string(),
+                serde_json::Value::String(config.r#type().as_str().to_string()),
+            );
+
+            // Name the check after the endpoint it probes
+            let endpoint = config
+                .fully_qualified_domain_name()
+                .or(config.ip_address())
+                .map(|host| {
+                    let mut endpoint = host.to_string();
+                    if let Some(port) = config.port() {
+                        endpoint.push_str(&format!(":{}", port));
+                    }
+                    if let Some(path) = config.resource_path() {
+                        endpoint.push_str(path);
+                    }
+                    endpoint
+                });
+            if let Some(endpoint) = endpoint {
+                json.insert(
+                    "Name".to_string(),
+                    serde_json::Value::String(endpoint.clone()),
+                );
+                json.insert("Endpoint".to_string(), serde_json::Value::String(endpoint));
+            }
+
+            if let Some(fqdn) = config.fully_qualified_domain_name() {
+                json.insert(
+                    "FullyQualifiedDomainName".to_string(),
+                    serde_json::Value::String(fqdn.to_string()),
+                );
+            }
+            if let Some(ip_address) = config.ip_address() {
+                json.insert(
+                    "IPAddress".to_string(),
+                    serde_json::Value::String(ip_address.to_string()),
+                );
+            }
+            if let Some(interval) = config.request_interval() {
+                json.insert(
+                    "RequestInterval".to_string(),
+                    serde_json::Value::Number(serde_json::Number::from(interval)),
+                );
+            }
+            if let Some(threshold) = config.failure_threshold() {
+                json.insert(
+                    "FailureThreshold".to_string(),
+                    serde_json::Value::Number(serde_json::Number::from(threshold)),
+                );
+            }
+            if let Some(inverted) = config.inverted() {
+                json.insert("Inverted".to_string(), serde_json::Value::Bool(inverted));
+            }
+            if !config.child_health_checks().is_empty() {
+                json.insert(
+                    "ChildHealthChecks".to_string(),
+                    serde_json::Value::Array(
+                        config
+                            .child_health_checks()
+                            .iter()
+                            .map(|id| serde_json::Value::String(id.clone()))
+                            .collect(),
+                    ),
+                );
+            }
+        }
+
+        if let Some(alarm) = health_check.cloud_watch_alarm_configuration() {
+            json.insert(
+                "CloudWatchAlarm".to_string(),
+                serde_json::Value::String(alarm.metric_name().to_string()),
+            );
+        }
+
+        json
+    }
+}
+
+/// Route53 reports a health check healthy while more than 18% of its
+/// checkers see the endpoint as healthy
+fn health_status_label(healthy: usize, total: usize) -> &'static str {
+    if total == 0 {
+        "Unknown"
+    } else if healthy * 100 > total * 18 {
+        "Healthy"
+    } else {
+        "Unhealthy"
+    }
+}
+
+/// Record names come back with special characters octal-escaped (e.g. the
+/// wildcard "*" as "\052")
+fn decode_record_name(name: &str) -> String {
+    let mut decoded = String::with_capacity(name.len());
+    let mut chars = name.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            let digits: String = chars.clone().take(3).collect();
+            if digits.len() == 3 && digits.chars().all(|d| ('0'..='7').contains(&d)) {
+                if let Some(byte) = u8::from_str_radix(&digits, 8).ok().filter(u8::is_ascii) {
+                    decoded.push(byte as char);
+                    for _ in 0..3 {
+                        chars.next();
+                    }
+                    continue;
+                }
+            }
+        }
+        decoded.push(c);
+    }
+    decoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_record_name() {
+        assert_eq!(decode_record_name("\\052.example.com."), "*.example.com.");
+        assert_eq!(decode_record_name("www.example.com."), "www.example.com.");
+        assert_eq!(decode_record_name("a\\b.example.com."), "a\\b.example.com.");
+    }
+
+    #[test]
+    fn test_health_status_label() {
+        assert_eq!(health_status_label(0, 0), "Unknown");
+        assert_eq!(health_status_label(16, 16), "Healthy");
+        // 3 of 16 checkers (18.75%) is still healthy, 2 of 16 is not
+        assert_eq!(health_status_label(3, 16), "Healthy");
+        assert_eq!(health_status_label(2, 16), "Unhealthy");
+    }
 }
@@ -1,4 +1,5 @@
 use super::super::credentials::CredentialCoordinator;
+use super::super::global_services::get_global_query_region;
 use super::super::status::{report_status, report_status_done};
 use anyhow::{Context, Result};
 use aws_sdk_s3 as s3;
@@ -19,18 +20,18 @@ impl S3Service {
     /// Convert S3 LocationConstraint to standard region code
     ///
     /// S3's get_bucket_location returns LocationConstraint which has special cases:
-    /// - None or empty string = us-east-1 (buckets created before regions)
+    /// - None or empty string = the partition's global region, us-east-1 in commercial AWS
     /// - "EU" = eu-west-1 (legacy EU region)
     /// - Otherwise = direct region code (e.g., "us-west-2")
     fn location_constraint_to_region(
         constraint: Option<&s3::types::BucketLocationConstraint>,
     ) -> String {
         match constraint {
-            None => "us-east-1".to_string(),
+            None => get_global_query_region().to_string(),
             Some(loc) => {
                 let loc_str = loc.as_str();
                 if loc_str.is_empty() {
-                    "us-east-1".to_string()
+                    get_global_query_region().to_string()
                 } else if loc_str == "EU" {
                     "eu-west-1".to_string()
                 } else {
@@ -46,10 +47,10 @@ impl S3Service {
         account_id: &str,
         bucket_name: &str,
     ) -> Result<String> {
-        // Use us-east-1 for location queries - this works for all buckets regardless of their actual region
+        // Use the global region for location queries - this works for all buckets regardless of their actual region
         let aws_config = self
             .credential_coordinator
-            .create_aws_config_for_account(account_id, "us-east-1")
+            .create_aws_config_for_account(account_id, get_global_query_region())
             .await?;
 
         let client = s3::Client::new(&aws_config);
@@ -87,7 +88,7 @@ impl S3Service {
                                 "[S3] Failed to get location for bucket '{}': {}, defaulting to us-east-1",
                                 name, e
                             );
-                            "us-east-1".to_string()
+                            get_global_query_region().to_string()
                         }
                     };
                     (name, region)
@@ -116,10 +117,10 @@ impl S3Service {
     ) -> Result<Vec<serde_json::Value>> {
         report_status("S3", "list_buckets", Some(account_id));
 
-        // Use us-east-1 for the global list_buckets operation
+        // Use the global region (us-east-1 in commercial AWS) for the global list_buckets operation
         let aws_config = self
             .credential_coordinator
-            .create_aws_config_for_account(account_id, "us-east-1")
+            .create_aws_config_for_account(account_id, get_global_query_region())
             .await
             .with_context(|| {
                 format!(
@@ -152,7 +153,7 @@ impl S3Service {
                     let actual_region = locations
                         .get(name)
                         .cloned()
-                        .unwrap_or_else(|| "us-east-1".to_string());
+                        .unwrap_or_else(|| get_global_query_region().to_string());
 
                     let bucket_json = self
                         .bucket_to_json(&bucket, account_id, &actual_region, include_details)
@@ -1,8 +1,15 @@
 use super::super::credentials::CredentialCoordinator;
+use super::super::threat_findings::{securityhub_severity, FindingSource, ThreatFinding};
 use anyhow::{Context, Result};
 use aws_sdk_securityhub as securityhub;
 use std::sync::Arc;
 
+/// Findings fetched per region; the rest are left for the Security Hub console
+const MAX_ACTIVE_FINDINGS: usize = 500;
+
+/// BatchUpdateFindings accepts at most 100 findings
+const FINDING_UPDATE_BATCH: usize = 100;
+
 pub struct SecurityHubService {
     credential_coordinator: Arc<CredentialCoordinator>,
 }
@@ -66,6 +73,175 @@ impl SecurityHubService {
         self.describe_hub_internal(&client).await
     }
 
+    /// Active findings that still need attention (workflow NEW or NOTIFIED), newest first
+    pub async fn list_active_findings(
+        &self,
+        account_id: &str,
+        region: &str,
+    ) -> Result<Vec<ThreatFinding>> {
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = securityhub::Client::new(&aws_config);
+
+        let equals = |value: &str| {
+            securityhub::types::StringFilter::builder()
+                .value(value)
+                .comparison(securityhub::types::StringFilterComparison::Equals)
+                .build()
+        };
+        let filters = securityhub::types::AwsSecurityFindingFilters::builder()
+            .record_state(equals("ACTIVE"))
+            .workflow_status(equals("NEW"))
+            .workflow_status(equals("NOTIFIED"))
+            .aws_account_id(equals(account_id))
+            .build();
+        let sort = securityhub::types::SortCriterion::builder()
+            .field("UpdatedAt")
+            .sort_order(securityhub::types::SortOrder::Descending)
+            .build();
+
+        let mut findings = Vec::new();
+        let mut next_token = None;
+        loop {
+            let response = client
+                .get_findings()
+                .filters(filters.clone())
+                .sort_criteria(sort.clone())
+                .max_results(100)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .context("Failed to get Security Hub findings")?;
+            findings.extend(
+                response
+                    .findings()
+                    .iter()
+                    .map(|finding| self.finding_to_threat_finding(finding, account_id, region)),
+            );
+            next_token = response
+                .next_token()
+                .filter(|token| !token.is_empty())
+                .map(String::from);
+            if next_token.is_none() || findings.len() >= MAX_ACTIVE_FINDINGS {
+                break;
+            }
+        }
+        findings.truncate(MAX_ACTIVE_FINDINGS);
+
+        Ok(findings)
+    }
+
+    /// Set the workflow status of findings to SUPPRESSED with a note
+    ///
+    /// Each finding is identified by its ID and product ARN. Returns the IDs
+    /// Security Hub could not update.
+    pub async fn suppress_findings(
+        &self,
+        account_id: &str,
+        region: &str,
+        findings: &[(String, String)],
+        note: &str,
+    ) -> Result<Vec<String>> {
+        crate::app::capabilities::ensure_writable("Suppress Security Hub findings")?;
+
+        let aws_config = self
+            .credential_coordinator
+            .create_aws_config_for_account(account_id, region)
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to create AWS config for account {} in region {}",
+                    account_id, region
+                )
+            })?;
+
+        let client = securityhub::Client::new(&aws_config);
+        let mut unprocessed = Vec::new();
+        for batch in findings.chunks(FINDING_UPDATE_BATCH) {
+            let identifiers = batch
+                .iter()
+                .map(|(id, product_arn)| {
+                    securityhub::types::AwsSecurityFindingIdentifier::builder()
+                        .id(id)
+                        .product_arn(product_arn)
+                        .build()
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .context("Failed to build Security Hub finding identifiers")?;
+            let note = securityhub::types::NoteUpdate::builder()
+                .text(note)
+                .updated_by("aws-dash")
+                .build()
+                .context("Failed to build Security Hub note")?;
+            let response = client
+                .batch_update_findings()
+                .set_finding_identifiers(Some(identifiers))
+                .workflow(
+                    securityhub::types::WorkflowUpdate::builder()
+                        .status(securityhub::types::WorkflowStatus::Suppressed)
+                        .build(),
+                )
+                .note(note)
+                .send()
+                .await
+                .context("Failed to suppress Security Hub findings")?;
+            unprocessed.extend(
+                response
+                    .unprocessed_findings()
+                    .iter()
+                    .filter_map(|failed| failed.finding_identifier())
+                    .map(|identifier| identifier.id().to_string()),
+            );
+        }
+        Ok(unprocessed)
+    }
+
+    fn finding_to_threat_finding(
+        &self,
+        finding: &securityhub::types::AwsSecurityFinding,
+        account_id: &str,
+        region: &str,
+    ) -> ThreatFinding {
+        ThreatFinding {
+            source: FindingSource::SecurityHub,
+            id: finding.id().unwrap_or_default().to_string(),
+            owner: finding.product_arn().unwrap_or_default().to_string(),
+            account_id: finding
+                .aws_account_id()
+                .unwrap_or(account_id)
+                .to_string(),
+            region: finding.region().unwrap_or(region).to_string(),
+            severity: securityhub_severity(
+                finding
+                    .severity()
+                    .and_then(|severity| severity.label())
+                    .map(|label| label.as_str())
+                    .unwrap_or_default(),
+            ),
+            finding_type: finding.generator_id().unwrap_or_default().to_string(),
+            title: finding.title().unwrap_or_default().to_string(),
+            description: finding.description().unwrap_or_default().to_string(),
+            resources: finding
+                .resources()
+                .iter()
+                .filter_map(|resource| resource.id())
+                // Account-level findings name the account, not a resource
+                .filter(|id| !id.starts_with("AWS::::Account:"))
+                .map(String::from)
+                .collect(),
+            updated_at: finding.updated_at().map(String::from),
+        }
+    }
+
     async fn describe_hub_internal(
         &self,
         client: &securityhub::Client,
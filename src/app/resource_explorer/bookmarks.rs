@@ -148,12 +148,109 @@ impl Bookmark {
     }
 }
 
+/// A named query scope that can be opened in any pane or window
+///
+/// Unlike a bookmark, a workspace leaves out the search text: it describes
+/// what to load and how to show it, not a momentary filter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Workspace {
+    pub id: String, // UUID
+    pub name: String,
+    pub description: Option<String>,
+
+    pub account_ids: Vec<String>,
+    pub region_codes: Vec<String>,
+    pub resource_type_ids: Vec<String>,
+    pub grouping: GroupingMode,
+    pub tag_filters: TagFilterGroup,
+
+    pub created_at: DateTime<Utc>,
+    pub modified_at: DateTime<Utc>,
+    pub open_count: usize,
+    pub last_opened: Option<DateTime<Utc>>,
+}
+
+impl Workspace {
+    /// Create a workspace from the current Explorer state
+    pub fn new(name: String, state: &ResourceExplorerState) -> Self {
+        let scope = Bookmark::new(name, state);
+        Self {
+            id: scope.id,
+            name: scope.name,
+            description: None,
+            account_ids: scope.account_ids,
+            region_codes: scope.region_codes,
+            resource_type_ids: scope.resource_type_ids,
+            grouping: scope.grouping,
+            tag_filters: scope.tag_filters,
+            created_at: scope.created_at,
+            modified_at: scope.modified_at,
+            open_count: 0,
+            last_opened: None,
+        }
+    }
+
+    /// Replace the saved scope with the current Explorer state, keeping identity
+    pub fn update_from_state(&mut self, state: &ResourceExplorerState) {
+        let scope = Self::new(self.name.clone(), state);
+        self.account_ids = scope.account_ids;
+        self.region_codes = scope.region_codes;
+        self.resource_type_ids = scope.resource_type_ids;
+        self.grouping = scope.grouping;
+        self.tag_filters = scope.tag_filters;
+        self.modified_at = Utc::now();
+    }
+
+    /// The workspace as a bookmark, for restoring it with the bookmark code path
+    pub fn to_bookmark(&self) -> Bookmark {
+        Bookmark {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            icon: None,
+            folder_id: None,
+            account_ids: self.account_ids.clone(),
+            region_codes: self.region_codes.clone(),
+            resource_type_ids: self.resource_type_ids.clone(),
+            grouping: self.grouping.clone(),
+            tag_filters: self.tag_filters.clone(),
+            search_filter: String::new(),
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+            access_count: self.open_count,
+            last_accessed: self.last_opened,
+        }
+    }
+
+    /// Check if the Explorer state shows this workspace (search text is ignored)
+    pub fn matches_state(&self, state: &ResourceExplorerState) -> bool {
+        let current = Self::new(String::new(), state);
+        self.account_ids == current.account_ids
+            && self.region_codes == current.region_codes
+            && self.resource_type_ids == current.resource_type_ids
+            && self.grouping == current.grouping
+            && self.tag_filters == current.tag_filters
+    }
+
+    /// Short scope description (e.g., "2 accounts, 3 regions, 5 types")
+    pub fn summary(&self) -> String {
+        format!(
+            "{} accounts, {} regions, {} types",
+            self.account_ids.len(),
+            self.region_codes.len(),
+            self.resource_type_ids.len()
+        )
+    }
+}
+
 /// Collection of bookmarks with version tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BookmarkCollection {
     pub bookmarks: Vec<Bookmark>,
     #[serde(default)]
     pub folders: Vec<BookmarkFolder>,
+    #[serde(default)]
+    pub workspaces: Vec<Workspace>,
     pub version: u32, // Schema version for future migrations
 }
 
@@ -162,6 +259,7 @@ impl BookmarkCollection {
         Self {
             bookmarks: Vec::new(),
             folders: Vec::new(),
+            workspaces: Vec::new(),
             version: 1,
         }
     }
@@ -404,14 +502,70 @@ impl BookmarkManager {
         let imported: BookmarkCollection =
             serde_json::from_str(&contents).context("Failed to parse import file")?;
 
-        let count = imported.bookmarks.len();
+        let count = imported.bookmarks.len() + imported.workspaces.len();
         for bookmark in imported.bookmarks {
             self.add_bookmark(bookmark);
         }
+        for workspace in imported.workspaces {
+            self.add_workspace(workspace);
+        }
 
         Ok(count)
     }
 
+    /// Add a workspace
+    pub fn add_workspace(&mut self, workspace: Workspace) {
+        self.collection.workspaces.push(workspace);
+        self.dirty = true;
+    }
+
+    /// Save the Explorer state as a workspace, replacing one with the same name
+    ///
+    /// Returns the workspace ID.
+    pub fn save_workspace(&mut self, name: &str, state: &ResourceExplorerState) -> String {
+        let name = name.trim();
+        self.dirty = true;
+        if let Some(existing) = self
+            .collection
+            .workspaces
+            .iter_mut()
+            .find(|w| w.name.eq_ignore_ascii_case(name))
+        {
+            existing.update_from_state(state);
+            return existing.id.clone();
+        }
+        let workspace = Workspace::new(name.to_string(), state);
+        let id = workspace.id.clone();
+        self.collection.workspaces.push(workspace);
+        id
+    }
+
+    /// Remove a workspace
+    pub fn remove_workspace(&mut self, id: &str) -> Option<Workspace> {
+        let index = self.collection.workspaces.iter().position(|w| w.id == id)?;
+        self.dirty = true;
+        Some(self.collection.workspaces.remove(index))
+    }
+
+    /// Get all workspaces
+    pub fn get_workspaces(&self) -> &[Workspace] {
+        &self.collection.workspaces
+    }
+
+    /// Get a workspace by ID
+    pub fn get_workspace(&self, id: &str) -> Option<&Workspace> {
+        self.collection.workspaces.iter().find(|w| w.id == id)
+    }
+
+    /// Update usage tracking after a workspace was opened
+    pub fn record_workspace_opened(&mut self, id: &str) {
+        if let Some(workspace) = self.collection.workspaces.iter_mut().find(|w| w.id == id) {
+            workspace.open_count += 1;
+            workspace.last_opened = Some(Utc::now());
+            self.dirty = true;
+        }
+    }
+
     /// Find bookmark matching current state
     pub fn find_matching_bookmark(&self, state: &ResourceExplorerState) -> Option<&Bookmark> {
         self.collection
@@ -652,4 +806,28 @@ mod tests {
         assert_eq!(bookmark.access_count, 1);
         assert!(bookmark.last_accessed.is_some());
     }
+
+    #[test]
+    fn test_workspace_round_trip() {
+        let mut state = ResourceExplorerState::default();
+        state.search_filter = "web".to_string();
+        let workspace = Workspace::new("Prod".to_string(), &state);
+
+        // Search text is not part of a workspace
+        let bookmark = workspace.to_bookmark();
+        assert_eq!(bookmark.id, workspace.id);
+        assert!(bookmark.search_filter.is_empty());
+        assert!(workspace.matches_state(&state));
+
+        state.primary_grouping = GroupingMode::ByRegion;
+        assert!(!workspace.matches_state(&state));
+    }
+
+    #[test]
+    fn test_workspaces_load_from_older_files() {
+        // Files written before workspaces existed have no "workspaces" key
+        let json = r#"{"bookmarks": [], "folders": [], "version": 1}"#;
+        let collection: BookmarkCollection = serde_json::from_str(json).unwrap();
+        assert!(collection.workspaces.is_empty());
+    }
 }
@@ -11,6 +11,7 @@
 //! - Auto-sizing based on system memory
 //! - Runtime-adjustable cache size
 //! - Smart eviction (TinyLFU + LRU)
+//! - Optional write-through to disk so results survive restarts (see `disk_cache`)
 
 use moka::sync::Cache;
 use once_cell::sync::OnceCell;
@@ -18,7 +19,9 @@ use std::io::{Read, Write};
 use std::sync::Arc;
 use std::time::Duration;
 
+use super::disk_cache::{key_matches, DiskResourceCache};
 use super::state::ResourceEntry;
+use chrono::{DateTime, Utc};
 
 // ============================================================================
 // Types
@@ -84,7 +87,7 @@ impl CacheConfig {
         Self {
             max_resource_bytes: cache_mb * 1024 * 1024 * 80 / 100, // 80% for resources
             max_detailed_bytes: cache_mb * 1024 * 1024 * 20 / 100, // 20% for details
-            idle_timeout_secs: 1800,
+            idle_timeout_secs: crate::app::app_config::app_config().cache.memory_idle_minutes * 60,
         }
     }
 
@@ -113,6 +116,8 @@ impl Default for CacheConfig {
 struct CompressedData {
     data: Vec<u8>,
     uncompressed_size: usize,
+    /// When the entries were stored (restored entries keep their save time)
+    cached_at: DateTime<Utc>,
 }
 
 impl CompressedData {
@@ -130,6 +135,7 @@ impl CompressedData {
         Self {
             data: compressed,
             uncompressed_size,
+            cached_at: Utc::now(),
         }
     }
 
@@ -165,6 +171,9 @@ pub struct SharedResourceCache {
 
     /// Track total uncompressed size for compression ratio stats
     total_uncompressed: std::sync::atomic::AtomicU64,
+
+    /// Persistent layer mirroring inserts and invalidations (None = memory only)
+    disk: Option<DiskResourceCache>,
 }
 
 impl SharedResourceCache {
@@ -188,7 +197,57 @@ impl SharedResourceCache {
             resources,
             config,
             total_uncompressed: std::sync::atomic::AtomicU64::new(0),
+            disk: None,
+        }
+    }
+
+    /// Mirror cache writes and invalidations to a disk cache
+    pub fn with_disk_cache(mut self, disk: DiskResourceCache) -> Self {
+        self.disk = Some(disk);
+        self
+    }
+
+    /// Load unexpired results persisted by a previous session
+    ///
+    /// Entries keep their original query timestamps so the Explorer shows
+    /// them as stale until refreshed. Returns the number of keys restored.
+    pub fn load_from_disk(&self) -> usize {
+        let Some(disk) = &self.disk else {
+            return 0;
+        };
+
+        let start = std::time::Instant::now();
+        let records = disk.load_all();
+        let restored = records.len();
+        let mut entry_count = 0;
+
+        for record in records {
+            entry_count += record.header.entry_count;
+            self.total_uncompressed.fetch_add(
+                record.header.uncompressed_size as u64,
+                std::sync::atomic::Ordering::Relaxed,
+            );
+            self.resources.insert(
+                record.header.key,
+                CompressedData {
+                    data: record.compressed,
+                    uncompressed_size: record.header.uncompressed_size,
+                    cached_at: record.header.saved_at,
+                },
+            );
+        }
+
+        if restored > 0 {
+            tracing::info!(
+                "Restored {} cached queries ({} resources) from {} in {}ms",
+                restored,
+                entry_count,
+                disk.directory().display(),
+                start.elapsed().as_millis()
+            );
         }
+
+        restored
     }
 
     // ========================================================================
@@ -211,6 +270,12 @@ impl SharedResourceCache {
         self.total_uncompressed
             .fetch_add(uncompressed_size as u64, std::sync::atomic::Ordering::Relaxed);
 
+        if let Some(disk) = &self.disk {
+            if let Err(e) = disk.write(&key, &compressed.data, entries.len(), uncompressed_size) {
+                tracing::warn!("Failed to persist cache entry '{}': {}", key, e);
+            }
+        }
+
         self.resources.insert(key.clone(), compressed.clone());
 
         let elapsed_ms = start.elapsed().as_millis();
@@ -254,9 +319,50 @@ impl SharedResourceCache {
         self.resources.contains_key(key)
     }
 
-    /// Remove resources by key
+    /// When the results for a key were stored, if present
+    pub fn cached_at(&self, key: &str) -> Option<DateTime<Utc>> {
+        self.resources.get(key).map(|compressed| compressed.cached_at)
+    }
+
+    /// Remove resources by key (from memory and disk)
     pub fn remove_resources(&self, key: &str) {
         self.resources.invalidate(key);
+        if let Some(disk) = &self.disk {
+            disk.remove(key);
+        }
+    }
+
+    /// Remove all results matching an account, region, and/or resource type
+    ///
+    /// `None` matches anything. Returns the number of in-memory keys removed.
+    pub fn invalidate_matching(
+        &self,
+        account_id: Option<&str>,
+        region: Option<&str>,
+        resource_type: Option<&str>,
+    ) -> usize {
+        let keys: Vec<String> = self
+            .resource_keys()
+            .into_iter()
+            .filter(|key| key_matches(key, account_id, region, resource_type))
+            .collect();
+
+        for key in &keys {
+            self.resources.invalidate(key);
+        }
+
+        if let Some(disk) = &self.disk {
+            disk.invalidate_matching(account_id, region, resource_type);
+        }
+
+        tracing::info!(
+            "Invalidated {} cached queries (account={:?}, region={:?}, type={:?})",
+            keys.len(),
+            account_id,
+            region,
+            resource_type
+        );
+        keys.len()
     }
 
     /// Get all cache keys (for iteration/debugging)
@@ -367,9 +473,12 @@ impl SharedResourceCache {
         );
     }
 
-    /// Clear all cached data
+    /// Clear all cached data (memory and disk)
     pub fn clear(&self) {
         self.resources.invalidate_all();
+        if let Some(disk) = &self.disk {
+            disk.clear();
+        }
         self.total_uncompressed
             .store(0, std::sync::atomic::Ordering::Relaxed);
         tracing::info!("Cache cleared");
@@ -391,18 +500,7 @@ static SHARED_CACHE: OnceCell<Arc<SharedResourceCache>> = OnceCell::new();
 /// Initialize the global shared cache with auto-detected configuration.
 /// This should be called once at application startup.
 pub fn init_shared_cache() -> Arc<SharedResourceCache> {
-    SHARED_CACHE
-        .get_or_init(|| {
-            let config = CacheConfig::auto_detect();
-            tracing::info!(
-                "Initializing shared resource cache: resources={}MB, detailed={}MB, idle_timeout={}s",
-                config.max_resource_bytes / 1024 / 1024,
-                config.max_detailed_bytes / 1024 / 1024,
-                config.idle_timeout_secs
-            );
-            Arc::new(SharedResourceCache::new(config))
-        })
-        .clone()
+    init_shared_cache_with_config(CacheConfig::auto_detect())
 }
 
 /// Initialize the global shared cache with specific configuration.
@@ -415,11 +513,27 @@ pub fn init_shared_cache_with_config(config: CacheConfig) -> Arc<SharedResourceC
                 config.max_detailed_bytes / 1024 / 1024,
                 config.idle_timeout_secs
             );
-            Arc::new(SharedResourceCache::new(config))
+            Arc::new(create_persistent_cache(config))
         })
         .clone()
 }
 
+/// Create a cache backed by the default disk location and restore its contents
+fn create_persistent_cache(config: CacheConfig) -> SharedResourceCache {
+    let cache = SharedResourceCache::new(config);
+    match DiskResourceCache::default_location() {
+        Some(disk) => {
+            let cache = cache.with_disk_cache(disk);
+            cache.load_from_disk();
+            cache
+        }
+        None => {
+            tracing::warn!("No data directory available - resource cache will not persist");
+            cache
+        }
+    }
+}
+
 /// Get the global shared cache instance.
 /// Returns None if init_shared_cache() hasn't been called yet.
 pub fn get_shared_cache() -> Option<Arc<SharedResourceCache>> {
@@ -86,6 +86,56 @@ impl ChildResourceConfig {
             }],
         );
 
+        // ============ ECS Container Workload Hierarchy ============
+
+        // Service -> running Task
+        parent_to_children.insert(
+            "AWS::ECS::Service".to_string(),
+            vec![ChildResourceDef {
+                child_type: "AWS::ECS::Task".to_string(),
+                query_method: ChildQueryMethod::MultiParent {
+                    params: vec!["cluster_arn", "service_name"],
+                },
+            }],
+        );
+
+        // Task -> ContainerDefinition (read from the task's task definition)
+        parent_to_children.insert(
+            "AWS::ECS::Task".to_string(),
+            vec![ChildResourceDef {
+                child_type: "AWS::ECS::ContainerDefinition".to_string(),
+                query_method: ChildQueryMethod::SingleParent {
+                    param_name: "task_id",
+                },
+            }],
+        );
+
+        // ============ EKS Hierarchy ============
+
+        // Cluster -> Nodegroup
+        parent_to_children.insert(
+            "AWS::EKS::Cluster".to_string(),
+            vec![ChildResourceDef {
+                child_type: "AWS::EKS::Nodegroup".to_string(),
+                query_method: ChildQueryMethod::SingleParent {
+                    param_name: "cluster_name",
+                },
+            }],
+        );
+
+        // ============ Route53 Hierarchy ============
+
+        // HostedZone -> RecordSet
+        parent_to_children.insert(
+            "AWS::Route53::HostedZone".to_string(),
+            vec![ChildResourceDef {
+                child_type: "AWS::Route53::RecordSet".to_string(),
+                query_method: ChildQueryMethod::SingleParent {
+                    param_name: "hosted_zone_id",
+                },
+            }],
+        );
+
         Self { parent_to_children }
     }
 
@@ -154,6 +204,37 @@ mod tests {
         assert_eq!(children[0].child_type, "AWS::Bedrock::FlowAlias");
     }
 
+    #[test]
+    fn test_ecs_service_drills_down_to_containers() {
+        let config = ChildResourceConfig::new();
+
+        let tasks = config.get_children("AWS::ECS::Service").unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].child_type, "AWS::ECS::Task");
+
+        let containers = config.get_children("AWS::ECS::Task").unwrap();
+        assert_eq!(containers.len(), 1);
+        assert_eq!(containers[0].child_type, "AWS::ECS::ContainerDefinition");
+        assert!(!config.has_children("AWS::ECS::ContainerDefinition"));
+    }
+
+    #[test]
+    fn test_eks_cluster_has_nodegroups() {
+        let config = ChildResourceConfig::new();
+        let children = config.get_children("AWS::EKS::Cluster").unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].child_type, "AWS::EKS::Nodegroup");
+    }
+
+    #[test]
+    fn test_hosted_zone_has_record_sets() {
+        let config = ChildResourceConfig::new();
+        let children = config.get_children("AWS::Route53::HostedZone").unwrap();
+        assert_eq!(children.len(), 1);
+        assert_eq!(children[0].child_type, "AWS::Route53::RecordSet");
+        assert!(!config.has_children("AWS::Route53::RecordSet"));
+    }
+
     #[test]
     fn test_model_has_no_children() {
         let config = ChildResourceConfig::new();
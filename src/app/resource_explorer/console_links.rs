@@ -1,3 +1,4 @@
+use crate::app::aws_regions::{partition_for_region, Partition};
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 
 fn encode(value: &str) -> String {
@@ -388,11 +389,29 @@ fn service_console_url(resource_type: &str, region: &str) -> Option<String> {
     Some(url)
 }
 
+/// Console URL for a resource, on the console of the region's partition
 pub fn build_console_destination(
     resource_type: &str,
     resource_id: &str,
     region: &str,
     resource_arn: Option<&str>,
+) -> String {
+    let partition = resource_arn
+        .and_then(Partition::from_arn)
+        .unwrap_or_else(|| partition_for_region(region));
+    partition.console_url(&commercial_console_destination(
+        resource_type,
+        resource_id,
+        region,
+        resource_arn,
+    ))
+}
+
+fn commercial_console_destination(
+    resource_type: &str,
+    resource_id: &str,
+    region: &str,
+    resource_arn: Option<&str>,
 ) -> String {
     match resource_type {
         "AWS::EC2::Instance" => format!(
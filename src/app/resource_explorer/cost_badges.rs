@@ -0,0 +1,224 @@
+//! Cost badges for Explorer tree groups
+//!
+//! Month-to-date costs from Cost Explorer are fetched per account, grouped by
+//! region and service, and indexed here. Tree group nodes (account, region,
+//! and resource type) then show the cost of the services their resources
+//! belong to. Cost Explorer has no per-resource costs without resource-level
+//! billing data, so a badge covers every resource of that service in the
+//! account and region, not only the ones in the tree.
+
+use super::state::ResourceEntry;
+use crate::app::data_plane::cost_explorer::{
+    cost_regions_for_region, cost_services_for_resource_type, format_cost, CostDataResult,
+    CostGroupBy,
+};
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeSet, HashMap};
+
+/// Group-by dimensions the index is built from
+pub const COST_BADGE_GROUP_BY: [CostGroupBy; 2] = [CostGroupBy::Region, CostGroupBy::Service];
+
+/// Month-to-date and forecast amounts
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CostAmount {
+    pub month_to_date: f64,
+    pub forecast: Option<f64>,
+}
+
+impl CostAmount {
+    fn add(&mut self, other: CostAmount) {
+        self.month_to_date += other.month_to_date;
+        self.forecast = match (self.forecast, other.forecast) {
+            (Some(a), Some(b)) => Some(a + b),
+            (a, b) => a.or(b),
+        };
+    }
+}
+
+/// Costs by (account, Cost Explorer region, Cost Explorer service)
+#[derive(Debug, Clone)]
+pub struct CostIndex {
+    amounts: HashMap<(String, String, String), CostAmount>,
+    accounts: BTreeSet<String>,
+    pub currency: String,
+    /// Accounts whose costs could not be fetched, with the error message
+    pub failed_accounts: Vec<(String, String)>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl CostIndex {
+    /// Build the index from per-account results grouped by region and service
+    pub fn from_results(
+        results: &[CostDataResult],
+        failed_accounts: Vec<(String, String)>,
+    ) -> Self {
+        let mut amounts = HashMap::new();
+        let mut accounts = BTreeSet::new();
+        let mut currency = None;
+
+        for result in results {
+            accounts.insert(result.account_id.clone());
+            if result.group_by != COST_BADGE_GROUP_BY {
+                continue;
+            }
+            currency.get_or_insert_with(|| result.currency.clone());
+            for group in &result.groups {
+                if let [region, service] = group.keys.as_slice() {
+                    amounts.insert(
+                        (result.account_id.clone(), region.clone(), service.clone()),
+                        CostAmount {
+                            month_to_date: group.amount,
+                            forecast: group.forecast,
+                        },
+                    );
+                }
+            }
+        }
+
+        Self {
+            amounts,
+            accounts,
+            currency: currency.unwrap_or_else(|| "USD".to_string()),
+            failed_accounts,
+            fetched_at: Utc::now(),
+        }
+    }
+
+    /// Whether costs were loaded for an account
+    pub fn has_account(&self, account_id: &str) -> bool {
+        self.accounts.contains(account_id)
+    }
+
+    /// Cost of the services the given resources belong to
+    ///
+    /// Each (account, region, service) combination is counted once, so two
+    /// resource types billed under the same service do not double the amount.
+    /// Returns None when no resource maps to a billed service in a loaded account.
+    pub fn cost_for_resources<'a>(
+        &self,
+        resources: impl IntoIterator<Item = &'a ResourceEntry>,
+    ) -> Option<CostAmount> {
+        let mut combinations = BTreeSet::new();
+        for resource in resources {
+            if !self.has_account(&resource.account_id) {
+                continue;
+            }
+            for service in cost_services_for_resource_type(&resource.resource_type) {
+                combinations.insert((
+                    resource.account_id.as_str(),
+                    resource.region.as_str(),
+                    *service,
+                ));
+            }
+        }
+
+        if combinations.is_empty() {
+            return None;
+        }
+
+        let mut total = CostAmount::default();
+        for (account_id, region, service) in combinations {
+            for cost_region in cost_regions_for_region(region) {
+                let key = (
+                    account_id.to_string(),
+                    cost_region.to_string(),
+                    service.to_string(),
+                );
+                if let Some(amount) = self.amounts.get(&key) {
+                    total.add(*amount);
+                }
+            }
+        }
+        Some(total)
+    }
+
+    /// Badge text, e.g. "$123.45 MTD, ~$250.00 forecast"
+    pub fn badge_text(&self, amount: &CostAmount) -> String {
+        match amount.forecast {
+            Some(forecast) => format!(
+                "{} MTD, ~{} forecast",
+                format_cost(amount.month_to_date, &self.currency),
+                format_cost(forecast, &self.currency)
+            ),
+            None => format!("{} MTD", format_cost(amount.month_to_date, &self.currency)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::cost_explorer::CostGroup;
+
+    fn resource(resource_type: &str, region: &str) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: region.to_string(),
+            resource_id: "id".to_string(),
+            display_name: "name".to_string(),
+            status: None,
+            properties: serde_json::json!({}),
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: Utc::now(),
+        }
+    }
+
+    fn index() -> CostIndex {
+        let group = |region: &str, service: &str, amount: f64| CostGroup {
+            keys: vec![region.to_string(), service.to_string()],
+            amount,
+            forecast: None,
+        };
+        let result = CostDataResult::new(
+            "123456789012",
+            COST_BADGE_GROUP_BY.to_vec(),
+            None,
+            "USD",
+            vec![
+                group("us-east-1", "AWS Lambda", 10.0),
+                group("us-west-2", "AWS Lambda", 5.0),
+                group("us-east-1", "EC2 - Other", 3.0),
+                group("global", "Amazon Route 53", 1.5),
+            ],
+        );
+        CostIndex::from_results(&[result], Vec::new())
+    }
+
+    #[test]
+    fn test_cost_for_resources_counts_each_service_once() {
+        let index = index();
+        let resources = [
+            resource("AWS::Lambda::Function", "us-east-1"),
+            resource("AWS::Lambda::LayerVersion", "us-east-1"),
+            resource("AWS::EC2::Volume", "us-east-1"),
+            resource("AWS::EC2::SecurityGroup", "us-east-1"),
+        ];
+        let amount = index.cost_for_resources(resources.iter()).unwrap();
+        assert_eq!(amount.month_to_date, 13.0);
+        assert_eq!(index.badge_text(&amount), "$13.00 MTD");
+    }
+
+    #[test]
+    fn test_cost_for_resources_global_and_unmapped() {
+        let index = index();
+        let route53 = [resource("AWS::Route53::HostedZone", "Global")];
+        assert_eq!(
+            index
+                .cost_for_resources(route53.iter())
+                .unwrap()
+                .month_to_date,
+            1.5
+        );
+
+        let iam = [resource("AWS::IAM::Role", "Global")];
+        assert!(index.cost_for_resources(iam.iter()).is_none());
+    }
+}
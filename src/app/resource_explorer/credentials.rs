@@ -1,4 +1,7 @@
 use crate::app::aws_identity::AwsIdentityCenter;
+use crate::app::aws_regions::active_partition;
+use crate::app::credential_sources::{assume_role, role_name_from_arn, AssumeRoleStep};
+use crate::app::cross_account_roles::CrossAccountRoute;
 use crate::app::resource_explorer::query_timing;
 use anyhow::{Context, Result};
 use aws_config::BehaviorVersion;
@@ -35,7 +38,8 @@ impl AccountCredentials {
         Credentials::from_keys(
             &self.access_key_id,
             &self.secret_access_key,
-            Some(self.session_token.clone()),
+            // Long-term access keys have no session token
+            Some(self.session_token.clone()).filter(|t| !t.is_empty()),
         )
     }
 }
@@ -227,6 +231,13 @@ impl CredentialCoordinator {
             account_id
         );
 
+        // Member accounts of a hub-and-spoke setup are reached through the hub
+        if let Some(route) = identity_center_clone.cross_account_route(account_id) {
+            return self
+                .assume_member_role(account_id, &route, &identity_center_clone)
+                .await;
+        }
+
         debug!(
             "🔑 CREDS: Calling Identity Center get_role_credentials for account {} with role '{}'",
             account_id, self.default_role_name
@@ -278,6 +289,68 @@ impl CredentialCoordinator {
         Ok(role_credentials)
     }
 
+    /// Assume the member role of `account_id` with the hub account's credentials
+    async fn assume_member_role(
+        &self,
+        account_id: &str,
+        route: &CrossAccountRoute,
+        identity_center: &AwsIdentityCenter,
+    ) -> Result<AccountCredentials> {
+        debug!(
+            "🔑 CREDS: Assuming {} for account {} from hub account {}",
+            route.role_arn, account_id, route.hub_account_id
+        );
+
+        // The hub is always reached with Identity Center credentials, never through another hop
+        let hub_credentials = match self.get_cached_credentials(&route.hub_account_id).await {
+            Some(cached) if !cached.is_expired() => cached,
+            _ => {
+                let fresh = identity_center
+                    .get_role_credentials(&route.hub_account_id, &self.default_role_name)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Failed to get credentials for hub account {}",
+                            route.hub_account_id
+                        )
+                    })?;
+                self.cache_credentials(&route.hub_account_id, &fresh).await;
+                fresh
+            }
+        };
+
+        let step = AssumeRoleStep {
+            role_arn: route.role_arn.clone(),
+            external_id: route.external_id.clone(),
+        };
+        let assumed = assume_role(
+            hub_credentials.to_aws_credentials(),
+            &step,
+            &Region::new(identity_center.identity_center_region.clone()),
+        )
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to reach account {} from hub account {}",
+                account_id, route.hub_account_id
+            )
+        })?;
+
+        Ok(AccountCredentials {
+            account_id: account_id.to_string(),
+            role_name: role_name_from_arn(&route.role_arn)
+                .unwrap_or(&route.role_arn)
+                .to_string(),
+            access_key_id: assumed.access_key_id().to_string(),
+            secret_access_key: assumed.secret_access_key().to_string(),
+            session_token: assumed.session_token().unwrap_or_default().to_string(),
+            expiration: assumed
+                .expiry()
+                .map(DateTime::<Utc>::from)
+                .unwrap_or_else(|| Utc::now() + chrono::Duration::hours(1)),
+        })
+    }
+
     /// Create AWS SDK config with account-specific credentials
     pub async fn create_aws_config_for_account(
         &self,
@@ -501,7 +574,14 @@ impl CredentialCoordinator {
     /// but is not discoverable through Identity Center role enumeration.
     pub fn get_cloudformation_service_role_arn(&self, account_id: &str) -> Option<String> {
         self.get_cloudformation_deployment_role_name()
-            .map(|role_name| format!("arn:aws:iam::{}:role/{}", account_id, role_name))
+            .map(|role_name| {
+                format!(
+                    "arn:{}:iam::{}:role/{}",
+                    active_partition().id(),
+                    account_id,
+                    role_name
+                )
+            })
     }
 
     /// Resolve the actual IAM role name from Identity Center discovery
@@ -737,7 +817,13 @@ impl CredentialCoordinator {
             actual_role_name: actual_role.unwrap_or("Unknown".to_string()),
             cloudformation_deployment_role: cf_role.clone(),
             is_management_account: is_mgmt,
-            requires_role_assumption: false, // We don't assume the CloudFormation service role
+            // Only member accounts reached through the hub assume a role
+            requires_role_assumption: self
+                .identity_center
+                .lock()
+                .ok()
+                .and_then(|identity| identity.cross_account_route(target_account_id))
+                .is_some(),
             discovered_roles: self.get_discovered_roles(target_account_id),
         }
     }
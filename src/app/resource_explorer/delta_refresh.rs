@@ -0,0 +1,408 @@
+//! Changed-only ("delta") refresh planning for the Explorer cache.
+//!
+//! A full refresh re-lists every resource type in every region. When the
+//! cache is already warm, most of those listings return exactly what we
+//! already have. CloudTrail records every mutating API call, so the write
+//! events since a cache entry was fetched tell us which services changed:
+//!
+//! ```text
+//! cached key                       write events since fetch       action
+//! 123:us-east-1:AWS::EC2::Instance ec2.amazonaws.com RunInstances re-fetch
+//! 123:us-east-1:AWS::Lambda::...   (none for lambda)              keep
+//! 123:eu-west-1:AWS::SQS::Queue    lookup failed                  re-fetch
+//! ```
+//!
+//! Anything we cannot prove unchanged (lookup failure, too many events to
+//! page through, cache entry without a timestamp) is re-fetched, so a delta
+//! refresh is never less accurate than the cache it starts from.
+
+use super::disk_cache::key_matches;
+use super::global_services::GlobalServiceRegistry;
+use crate::app::data_plane::cloudtrail_events::CloudTrailEvent;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Maximum LookupEvents pages (50 events each) read per account/region before
+/// giving up and treating the whole region as changed
+pub const MAX_CHANGE_EVENT_PAGES: usize = 10;
+
+/// CloudTrail usually delivers events within 5-15 minutes; look back this far
+/// before the oldest cached fetch so late deliveries are not missed
+pub const CLOUDTRAIL_DELIVERY_MARGIN_MINUTES: i64 = 15;
+
+/// Services whose CloudTrail event source differs from the CloudFormation
+/// service segment (lowercased) of the resource types they manage
+const EVENT_SOURCE_ALIASES: &[(&str, &[&str])] = &[
+    (
+        "elasticloadbalancing",
+        &["elasticloadbalancing", "elasticloadbalancingv2"],
+    ),
+    ("states", &["stepfunctions"]),
+    ("monitoring", &["cloudwatch"]),
+    ("es", &["elasticsearch", "opensearchservice"]),
+    ("apigateway", &["apigateway", "apigatewayv2"]),
+    ("elasticfilesystem", &["efs"]),
+    ("cognito-idp", &["cognito"]),
+    ("cognito-identity", &["cognito"]),
+    ("bedrock-agent", &["bedrock"]),
+    ("email", &["ses"]),
+    ("kafka", &["msk"]),
+];
+
+/// Write activity observed in one account/region
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangeSet {
+    /// Resource types named in event resources (e.g., "AWS::EC2::Instance")
+    pub resource_types: HashSet<String>,
+    /// Lowercased service segments touched (e.g., "ec2", "stepfunctions")
+    pub services: HashSet<String>,
+    /// False when not every event could be read, so absence proves nothing
+    pub complete: bool,
+}
+
+impl ChangeSet {
+    /// Build a change set from CloudTrail events
+    ///
+    /// Read-only and failed calls are ignored; they cannot change resources.
+    pub fn from_events(events: &[CloudTrailEvent], complete: bool) -> Self {
+        let mut change_set = Self {
+            complete,
+            ..Self::default()
+        };
+
+        for event in events {
+            if event.read_only.as_deref() == Some("true") || event.error_code.is_some() {
+                continue;
+            }
+
+            change_set
+                .services
+                .extend(services_for_event_source(&event.event_source));
+
+            for resource in &event.resources {
+                if let Some(resource_type) = &resource.resource_type {
+                    change_set.resource_types.insert(resource_type.clone());
+                }
+            }
+        }
+
+        change_set
+    }
+
+    /// Whether any write could have affected resources of this type
+    pub fn affects(&self, resource_type: &str) -> bool {
+        self.resource_types.contains(resource_type)
+            || resource_type_service(resource_type).is_some_and(|s| self.services.contains(&s))
+    }
+}
+
+/// Result of comparing cached keys against recent changes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeltaRefreshPlan {
+    /// Cache keys to drop and re-query
+    pub stale_keys: Vec<String>,
+    /// Cache keys confirmed unchanged since they were fetched
+    pub unchanged_keys: Vec<String>,
+}
+
+impl DeltaRefreshPlan {
+    /// Total number of keys considered
+    pub fn total(&self) -> usize {
+        self.stale_keys.len() + self.unchanged_keys.len()
+    }
+}
+
+/// Cached keys belonging to a query scope
+pub fn keys_in_scope<'a>(
+    keys: impl IntoIterator<Item = &'a String>,
+    account_ids: &[String],
+    region_codes: &[String],
+    resource_types: &[String],
+) -> Vec<String> {
+    keys.into_iter()
+        .filter(|key| {
+            account_ids
+                .iter()
+                .any(|a| key_matches(key, Some(a), None, None))
+                && resource_types
+                    .iter()
+                    .any(|t| key_matches(key, None, None, Some(t)))
+                && (key_matches(key, None, Some("Global"), None)
+                    || region_codes
+                        .iter()
+                        .any(|r| key_matches(key, None, Some(r), None)))
+        })
+        .cloned()
+        .collect()
+}
+
+/// Account/region pairs whose CloudTrail history is needed for these keys
+///
+/// `Global` keys are looked up in the global query region (us-east-1), where
+/// CloudTrail records IAM, Route 53, CloudFront and other global calls.
+pub fn change_lookup_targets(keys: &[String]) -> BTreeSet<(String, String)> {
+    keys.iter()
+        .filter_map(|key| split_key(key))
+        .map(|(account, region, _)| (account.to_string(), lookup_region(region).to_string()))
+        .collect()
+}
+
+/// Earliest time CloudTrail must be searched from for a set of fetch times
+pub fn change_lookup_start(
+    fetched_at: impl IntoIterator<Item = DateTime<Utc>>,
+) -> Option<DateTime<Utc>> {
+    fetched_at
+        .into_iter()
+        .min()
+        .map(|oldest| oldest - chrono::Duration::minutes(CLOUDTRAIL_DELIVERY_MARGIN_MINUTES))
+}
+
+/// Split cached keys into stale and unchanged using per-region change sets
+///
+/// `changes` is keyed by `(account_id, lookup_region)`. A key with no change
+/// set, or an incomplete one, is always stale. `Global` keys are also stale
+/// when any region of the same account reports a matching change, since
+/// hybrid-global services like S3 log bucket calls in the bucket's region.
+pub fn plan_delta_refresh(
+    cached_keys: &[String],
+    changes: &HashMap<(String, String), ChangeSet>,
+) -> DeltaRefreshPlan {
+    let mut plan = DeltaRefreshPlan::default();
+
+    for key in cached_keys {
+        let Some((account, region, resource_type)) = split_key(key) else {
+            plan.stale_keys.push(key.clone());
+            continue;
+        };
+
+        let lookup = (account.to_string(), lookup_region(region).to_string());
+        let stale = match changes.get(&lookup) {
+            Some(change_set) if change_set.complete => {
+                change_set.affects(resource_type)
+                    || (region == "Global"
+                        && changes
+                            .iter()
+                            .any(|((a, _), c)| a == account && c.affects(resource_type)))
+            }
+            _ => true,
+        };
+
+        if stale {
+            plan.stale_keys.push(key.clone());
+        } else {
+            plan.unchanged_keys.push(key.clone());
+        }
+    }
+
+    plan
+}
+
+/// Lowercased CloudFormation service segment ("AWS::EC2::Instance" -> "ec2")
+fn resource_type_service(resource_type: &str) -> Option<String> {
+    resource_type
+        .split("::")
+        .nth(1)
+        .map(|service| service.to_ascii_lowercase())
+}
+
+/// Service segments written by an event source ("states.amazonaws.com" -> ["stepfunctions"])
+fn services_for_event_source(event_source: &str) -> Vec<String> {
+    let prefix = event_source
+        .strip_suffix(".amazonaws.com")
+        .unwrap_or(event_source)
+        .to_ascii_lowercase();
+
+    match EVENT_SOURCE_ALIASES
+        .iter()
+        .find(|(source, _)| *source == prefix)
+    {
+        Some((_, services)) => services.iter().map(|s| s.to_string()).collect(),
+        None => vec![prefix],
+    }
+}
+
+fn split_key(key: &str) -> Option<(&str, &str, &str)> {
+    let mut parts = key.splitn(3, ':');
+    Some((parts.next()?, parts.next()?, parts.next()?))
+}
+
+fn lookup_region(region: &str) -> &str {
+    if region == "Global" {
+        GlobalServiceRegistry::new().get_query_region()
+    } else {
+        region
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::cloudtrail_events::EventResource;
+
+    fn event(source: &str, resource_type: Option<&str>, read_only: &str) -> CloudTrailEvent {
+        CloudTrailEvent {
+            event_id: "e-1".to_string(),
+            event_name: "Change".to_string(),
+            event_time: 0,
+            event_source: source.to_string(),
+            username: "admin".to_string(),
+            resources: resource_type
+                .map(|t| {
+                    vec![EventResource {
+                        resource_type: Some(t.to_string()),
+                        resource_name: None,
+                    }]
+                })
+                .unwrap_or_default(),
+            cloud_trail_event: None,
+            access_key_id: None,
+            read_only: Some(read_only.to_string()),
+            error_code: None,
+            error_message: None,
+        }
+    }
+
+    fn changes(entries: Vec<(&str, &str, ChangeSet)>) -> HashMap<(String, String), ChangeSet> {
+        entries
+            .into_iter()
+            .map(|(a, r, c)| ((a.to_string(), r.to_string()), c))
+            .collect()
+    }
+
+    #[test]
+    fn test_change_set_service_matching() {
+        let change_set = ChangeSet::from_events(
+            &[
+                event("ec2.amazonaws.com", None, "false"),
+                event("states.amazonaws.com", None, "false"),
+                event("lambda.amazonaws.com", None, "true"),
+            ],
+            true,
+        );
+
+        assert!(change_set.affects("AWS::EC2::Instance"));
+        assert!(change_set.affects("AWS::EC2::SecurityGroup"));
+        assert!(change_set.affects("AWS::StepFunctions::StateMachine"));
+        assert!(!change_set.affects("AWS::Lambda::Function"));
+    }
+
+    #[test]
+    fn test_change_set_ignores_failed_calls() {
+        let mut failed = event("sqs.amazonaws.com", None, "false");
+        failed.error_code = Some("AccessDenied".to_string());
+        let change_set = ChangeSet::from_events(&[failed], true);
+        assert!(!change_set.affects("AWS::SQS::Queue"));
+    }
+
+    #[test]
+    fn test_change_set_event_resource_types() {
+        let change_set = ChangeSet::from_events(
+            &[event(
+                "unknown.amazonaws.com",
+                Some("AWS::DynamoDB::Table"),
+                "false",
+            )],
+            true,
+        );
+        assert!(change_set.affects("AWS::DynamoDB::Table"));
+    }
+
+    #[test]
+    fn test_plan_delta_refresh() {
+        let keys = vec![
+            "111:us-east-1:AWS::EC2::Instance".to_string(),
+            "111:us-east-1:AWS::Lambda::Function".to_string(),
+            "111:eu-west-1:AWS::SQS::Queue".to_string(),
+            "111:Global:AWS::IAM::Role".to_string(),
+        ];
+        let change_set = ChangeSet::from_events(&[event("ec2.amazonaws.com", None, "false")], true);
+        let plan = plan_delta_refresh(&keys, &changes(vec![("111", "us-east-1", change_set)]));
+
+        assert_eq!(
+            plan.stale_keys,
+            vec![
+                "111:us-east-1:AWS::EC2::Instance".to_string(),
+                // No change set for eu-west-1 -> cannot prove unchanged
+                "111:eu-west-1:AWS::SQS::Queue".to_string(),
+            ]
+        );
+        assert_eq!(
+            plan.unchanged_keys,
+            vec![
+                "111:us-east-1:AWS::Lambda::Function".to_string(),
+                "111:Global:AWS::IAM::Role".to_string(),
+            ]
+        );
+        assert_eq!(plan.total(), 4);
+    }
+
+    #[test]
+    fn test_plan_incomplete_change_set_is_stale() {
+        let keys = vec!["111:us-east-1:AWS::Lambda::Function".to_string()];
+        let plan = plan_delta_refresh(
+            &keys,
+            &changes(vec![("111", "us-east-1", ChangeSet::default())]),
+        );
+        assert_eq!(plan.stale_keys, keys);
+    }
+
+    #[test]
+    fn test_plan_global_key_sees_regional_changes() {
+        let keys = vec!["111:Global:AWS::S3::Bucket".to_string()];
+        let s3 = ChangeSet::from_events(&[event("s3.amazonaws.com", None, "false")], true);
+        let plan = plan_delta_refresh(
+            &keys,
+            &changes(vec![
+                ("111", "us-east-1", ChangeSet::from_events(&[], true)),
+                ("111", "eu-west-1", s3),
+            ]),
+        );
+        assert_eq!(plan.stale_keys, keys);
+    }
+
+    #[test]
+    fn test_keys_in_scope_and_targets() {
+        let cached = vec![
+            "111:us-east-1:AWS::EC2::Instance".to_string(),
+            "111:eu-west-1:AWS::EC2::Instance".to_string(),
+            "222:us-east-1:AWS::EC2::Instance".to_string(),
+            "111:Global:AWS::IAM::Role".to_string(),
+        ];
+        let keys = keys_in_scope(
+            &cached,
+            &["111".to_string()],
+            &["eu-west-1".to_string()],
+            &[
+                "AWS::EC2::Instance".to_string(),
+                "AWS::IAM::Role".to_string(),
+            ],
+        );
+        assert_eq!(
+            keys,
+            vec![
+                "111:eu-west-1:AWS::EC2::Instance".to_string(),
+                "111:Global:AWS::IAM::Role".to_string(),
+            ]
+        );
+
+        let targets: Vec<(String, String)> = change_lookup_targets(&keys).into_iter().collect();
+        assert_eq!(
+            targets,
+            vec![
+                ("111".to_string(), "eu-west-1".to_string()),
+                ("111".to_string(), "us-east-1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_change_lookup_start() {
+        let fetched = Utc::now();
+        let earlier = fetched - chrono::Duration::hours(1);
+        assert_eq!(
+            change_lookup_start([fetched, earlier]),
+            Some(earlier - chrono::Duration::minutes(CLOUDTRAIL_DELIVERY_MARGIN_MINUTES))
+        );
+        assert_eq!(change_lookup_start(std::iter::empty()), None);
+    }
+}
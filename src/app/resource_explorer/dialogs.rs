@@ -1,8 +1,10 @@
+use super::organization_directory;
 use super::state::*;
 use crate::app::aws_identity::AwsAccount;
-use egui::{Context, Window};
+use egui::{Context, RichText, Window};
 use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::{Arc, Mutex};
 
 #[derive(Default)]
 pub struct FuzzySearchDialog {
@@ -180,7 +182,20 @@ impl FuzzySearchDialog {
                                 .spacing([10.0, 4.0])
                                 .striped(true)
                                 .show(ui, |ui| {
+                                    let show_ou_headers = self.search_term.is_empty()
+                                        && organization_directory::has_accounts();
+                                    let mut current_ou = None;
                                     for account in &filtered_accounts {
+                                        // OU header row when the organization is known
+                                        let ou = account_ou_label(account);
+                                        if show_ou_headers && current_ou.as_ref() != Some(&ou) {
+                                            ui.label(RichText::new(&ou).strong());
+                                            ui.label("");
+                                            ui.label("");
+                                            ui.end_row();
+                                            current_ou = Some(ou);
+                                        }
+
                                         // Checkbox column
                                         let mut is_checked = self
                                             .selected_accounts
@@ -739,7 +754,16 @@ impl FuzzySearchDialog {
                                         ui.label("No matching accounts");
                                     }
                                 } else {
+                                    let show_ou_headers = self.unified_account_search.is_empty()
+                                        && organization_directory::has_accounts();
+                                    let mut current_ou = None;
                                     for account in &filtered_accounts {
+                                        let ou = account_ou_label(account);
+                                        if show_ou_headers && current_ou.as_ref() != Some(&ou) {
+                                            ui.label(RichText::new(&ou).strong());
+                                            current_ou = Some(ou);
+                                        }
+
                                         let mut is_checked = self
                                             .selected_accounts
                                             .get(&account.account_id)
@@ -996,7 +1020,7 @@ impl FuzzySearchDialog {
     ) -> Vec<AwsAccount> {
         if search_term.is_empty() {
             let mut sorted_accounts = accounts.to_vec();
-            sorted_accounts.sort_by(|a, b| a.account_name.cmp(&b.account_name));
+            sort_accounts_by_ou(&mut sorted_accounts);
             return sorted_accounts;
         }
 
@@ -1086,9 +1110,9 @@ impl FuzzySearchDialog {
 
     fn filter_accounts(&self, accounts: &[AwsAccount]) -> Vec<AwsAccount> {
         if self.search_term.is_empty() {
-            // Sort by account name when no search term
+            // Sort by OU path, then account name, when no search term
             let mut sorted_accounts = accounts.to_vec();
-            sorted_accounts.sort_by(|a, b| a.account_name.cmp(&b.account_name));
+            sort_accounts_by_ou(&mut sorted_accounts);
             return sorted_accounts;
         }
 
@@ -1223,6 +1247,371 @@ impl FuzzySearchDialog {
     }
 }
 
+// ============================================================================
+// Tag Editor Dialog
+// ============================================================================
+
+/// AWS limits shared by all services' tagging APIs
+const MAX_TAG_KEY_LENGTH: usize = 128;
+const MAX_TAG_VALUE_LENGTH: usize = 256;
+
+/// One tag key in the editor
+#[derive(Debug, Clone, PartialEq)]
+struct TagEditRow {
+    key: String,
+    value: String,
+    /// Value shared by every target before editing; None when the values
+    /// differ between targets or some targets lack the key
+    original_value: Option<String>,
+    /// Number of targets that have this key (0 for rows added in the editor)
+    present_on: usize,
+    removed: bool,
+}
+
+impl TagEditRow {
+    fn is_new(&self) -> bool {
+        self.present_on == 0
+    }
+}
+
+/// Progress of a running tag update, shared with its background thread
+#[derive(Debug, Default)]
+pub struct TagUpdateJob {
+    pub total: usize,
+    pub completed: usize,
+    /// (resource display name, error) for each resource that failed
+    pub failures: Vec<(String, String)>,
+    pub finished: bool,
+}
+
+/// Add, change, and remove tags on one resource or a multi-selection
+///
+/// With several targets, keys present on only some of them or with differing
+/// values show as "(mixed)" and are left alone unless a new value is typed.
+/// The owning pane applies the returned `TagChanges` and hands back a
+/// `TagUpdateJob` so progress and failures show in the dialog.
+#[derive(Default)]
+pub struct TagEditorDialog {
+    pub open: bool,
+    targets: Vec<ResourceEntry>,
+    rows: Vec<TagEditRow>,
+    job: Option<Arc<Mutex<TagUpdateJob>>>,
+    message: Option<String>,
+}
+
+impl TagEditorDialog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the editor for the given resources, starting from their current tags
+    pub fn open_for(&mut self, targets: Vec<ResourceEntry>) {
+        let mut values: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for target in &targets {
+            for tag in &target.tags {
+                values.entry(&tag.key).or_default().push(&tag.value);
+            }
+        }
+
+        self.rows = values
+            .into_iter()
+            .map(|(key, values)| {
+                let shared = values.len() == targets.len() && values.iter().all(|v| *v == values[0]);
+                let original_value = shared.then(|| values[0].to_string());
+                TagEditRow {
+                    key: key.to_string(),
+                    value: original_value.clone().unwrap_or_default(),
+                    original_value,
+                    present_on: values.len(),
+                    removed: false,
+                }
+            })
+            .collect();
+        self.targets = targets;
+        self.job = None;
+        self.message = None;
+        self.open = true;
+    }
+
+    /// Selection keys of the resources being edited
+    pub fn target_keys(&self) -> HashSet<String> {
+        self.targets.iter().map(|r| r.selection_key()).collect()
+    }
+
+    /// Resources being edited, as they were when the editor opened
+    pub fn targets(&self) -> &[ResourceEntry] {
+        &self.targets
+    }
+
+    /// Show progress of an update started by the owner
+    pub fn track_job(&mut self, job: Arc<Mutex<TagUpdateJob>>) {
+        self.job = Some(job);
+        self.message = None;
+    }
+
+    /// Changes described by the current rows
+    fn build_changes(&self) -> TagChanges {
+        let mut changes = TagChanges::default();
+        for row in &self.rows {
+            let key = row.key.trim();
+            if key.is_empty() {
+                continue;
+            }
+            if row.removed {
+                if !row.is_new() {
+                    changes.remove.push(key.to_string());
+                }
+                continue;
+            }
+            let changed = match &row.original_value {
+                Some(original) => *original != row.value,
+                // Mixed keys only change when a value is typed; new keys always apply
+                None => row.is_new() || !row.value.is_empty(),
+            };
+            if changed {
+                changes.set.push(ResourceTag {
+                    key: key.to_string(),
+                    value: row.value.clone(),
+                });
+            }
+        }
+        changes
+    }
+
+    /// First problem that would make AWS reject the changes
+    fn validation_error(&self) -> Option<String> {
+        let mut seen = HashSet::new();
+        for row in self.rows.iter().filter(|row| !row.removed) {
+            let key = row.key.trim();
+            if key.is_empty() {
+                if row.is_new() && !row.value.is_empty() {
+                    return Some("Tag key cannot be empty".to_string());
+                }
+                continue;
+            }
+            if !seen.insert(key) {
+                return Some(format!("Duplicate tag key '{}'", key));
+            }
+            if row.is_new() && key.to_lowercase().starts_with("aws:") {
+                return Some("Keys starting with 'aws:' are reserved by AWS".to_string());
+            }
+            if key.chars().count() > MAX_TAG_KEY_LENGTH {
+                return Some(format!(
+                    "Tag key '{}' is longer than {} characters",
+                    key, MAX_TAG_KEY_LENGTH
+                ));
+            }
+            if row.value.chars().count() > MAX_TAG_VALUE_LENGTH {
+                return Some(format!(
+                    "Value of '{}' is longer than {} characters",
+                    key, MAX_TAG_VALUE_LENGTH
+                ));
+            }
+        }
+        None
+    }
+
+    /// Render the dialog; returns the changes to apply when Apply is clicked
+    ///
+    /// `id_salt` keeps dialogs from different panes apart.
+    pub fn show(&mut self, ctx: &Context, id_salt: impl std::hash::Hash) -> Option<TagChanges> {
+        if !self.open {
+            return None;
+        }
+
+        self.poll_job();
+        let running = self.job.is_some();
+        if running {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        let mut open = self.open;
+        let mut apply = None;
+        let changes = self.build_changes();
+        let validation_error = self.validation_error();
+
+        Window::new("Edit Tags")
+            .id(egui::Id::new(("tag_editor_dialog", id_salt)))
+            .open(&mut open)
+            .default_size([560.0, 420.0])
+            .resizable(true)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                match self.targets.as_slice() {
+                    [single] => {
+                        ui.label(format!("{} ({})", single.display_name, single.resource_type));
+                        ui.label(
+                            egui::RichText::new(format!("{} / {}", single.account_id, single.region))
+                                .small(),
+                        );
+                    }
+                    targets => {
+                        ui.label(format!("{} selected resources", targets.len()));
+                        ui.label(
+                            egui::RichText::new(
+                                "Mixed values are kept unless you type a new value",
+                            )
+                            .small(),
+                        );
+                    }
+                }
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .max_height(260.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        egui::Grid::new("tag_editor_grid")
+                            .num_columns(3)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.strong("Key");
+                                ui.strong("Value");
+                                ui.label("");
+                                ui.end_row();
+
+                                let target_count = self.targets.len();
+                                for row in &mut self.rows {
+                                    ui.add_enabled_ui(!running, |ui| {
+                                        if row.is_new() {
+                                            ui.add(
+                                                egui::TextEdit::singleline(&mut row.key)
+                                                    .hint_text("New key")
+                                                    .desired_width(180.0),
+                                            );
+                                        } else if row.removed {
+                                            ui.label(egui::RichText::new(&row.key).strikethrough());
+                                        } else {
+                                            ui.label(&row.key);
+                                        }
+                                    });
+
+                                    ui.add_enabled_ui(!running && !row.removed, |ui| {
+                                        let hint = if row.original_value.is_none() && !row.is_new() {
+                                            format!("(mixed, on {} of {})", row.present_on, target_count)
+                                        } else {
+                                            String::new()
+                                        };
+                                        ui.add(
+                                            egui::TextEdit::singleline(&mut row.value)
+                                                .hint_text(hint)
+                                                .desired_width(240.0),
+                                        );
+                                    });
+
+                                    ui.add_enabled_ui(!running, |ui| {
+                                        let label = if row.removed { "Undo" } else { "Remove" };
+                                        if ui.small_button(label).clicked() {
+                                            row.removed = !row.removed;
+                                        }
+                                    });
+                                    ui.end_row();
+                                }
+                            });
+                    });
+
+                // Rows removed before they were ever applied can simply go away
+                self.rows.retain(|row| !(row.is_new() && row.removed));
+
+                if ui
+                    .add_enabled(!running, egui::Button::new("Add Tag"))
+                    .clicked()
+                {
+                    self.rows.push(TagEditRow {
+                        key: String::new(),
+                        value: String::new(),
+                        original_value: None,
+                        present_on: 0,
+                        removed: false,
+                    });
+                }
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let can_apply = !running && !changes.is_empty() && validation_error.is_none();
+                    if ui
+                        .add_enabled(can_apply, egui::Button::new("Apply"))
+                        .clicked()
+                    {
+                        apply = Some(changes.clone());
+                    }
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "{} to set, {} to remove",
+                            changes.set.len(),
+                            changes.remove.len()
+                        ))
+                        .small(),
+                    );
+
+                    if let Some(job) = &self.job {
+                        if let Ok(job) = job.lock() {
+                            ui.spinner();
+                            ui.label(format!("{} / {} resources", job.completed, job.total));
+                        }
+                    } else if let Some(error) = &validation_error {
+                        ui.colored_label(ui.visuals().warn_fg_color, error);
+                    } else if let Some(message) = &self.message {
+                        ui.label(message);
+                    }
+                });
+            });
+
+        self.open = open;
+        apply
+    }
+
+    fn poll_job(&mut self) {
+        let finished = self.job.as_ref().and_then(|job| {
+            job.lock().ok().and_then(|job| {
+                job.finished
+                    .then(|| (job.total, job.failures.clone()))
+            })
+        });
+
+        if let Some((total, failures)) = finished {
+            self.job = None;
+            self.message = Some(if failures.is_empty() {
+                format!("Updated {} resource{}", total, if total == 1 { "" } else { "s" })
+            } else {
+                let (name, error) = &failures[0];
+                format!(
+                    "Updated {} of {}; {} failed and were rolled back ({}: {})",
+                    total - failures.len(),
+                    total,
+                    failures.len(),
+                    name,
+                    error
+                )
+            });
+            // Start the next edit from the tags that are now in place
+            if failures.is_empty() {
+                let changes = self.build_changes();
+                for target in &mut self.targets {
+                    changes.apply_to(&mut target.tags);
+                }
+                let message = self.message.take();
+                self.open_for(std::mem::take(&mut self.targets));
+                self.message = message;
+            }
+        }
+    }
+}
+
+/// OU path of an account, or the label for accounts outside the organization
+fn account_ou_label(account: &AwsAccount) -> String {
+    organization_directory::ou_path(&account.account_id)
+        .unwrap_or_else(|| organization_directory::NO_OU_LABEL.to_string())
+}
+
+/// Sort accounts by OU path, then name; accounts outside the organization come last
+fn sort_accounts_by_ou(accounts: &mut [AwsAccount]) {
+    accounts.sort_by_cached_key(|account| {
+        let ou = organization_directory::ou_path(&account.account_id);
+        (ou.is_none(), ou, account.account_name.clone())
+    });
+}
+
 // Default available options for testing
 pub fn get_default_accounts() -> Vec<String> {
     // Return empty list instead of fake accounts
@@ -1231,6 +1620,19 @@ pub fn get_default_accounts() -> Vec<String> {
 }
 
 pub fn get_default_regions() -> Vec<String> {
+    let partition = crate::app::aws_regions::active_partition();
+    if partition != crate::app::aws_regions::Partition::Aws {
+        return partition.regions().iter().map(|r| r.to_string()).collect();
+    }
+
+    // Regions chosen in Settings replace the built-in list
+    let configured = crate::app::app_config::app_config()
+        .regions
+        .default_regions;
+    if !configured.is_empty() {
+        return configured;
+    }
+
     vec![
         "us-east-1".to_string(),
         "us-east-2".to_string(),
@@ -1999,6 +2401,11 @@ pub fn get_default_resource_types() -> Vec<ResourceTypeSelection> {
             "Route53 Hosted Zone".to_string(),
             "Route53".to_string(),
         ),
+        ResourceTypeSelection::new(
+            "AWS::Route53::HealthCheck".to_string(),
+            "Route53 Health Check".to_string(),
+            "Route53".to_string(),
+        ),
         // EFS Resources
         ResourceTypeSelection::new(
             "AWS::EFS::FileSystem".to_string(),
@@ -2299,3 +2706,132 @@ pub fn get_default_resource_types() -> Vec<ResourceTypeSelection> {
         ),
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(id: &str, tags: &[(&str, &str)]) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: "AWS::EC2::Instance".to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: id.to_string(),
+            display_name: id.to_string(),
+            status: None,
+            properties: serde_json::json!({}),
+            detailed_timestamp: None,
+            tags: tags
+                .iter()
+                .map(|(k, v)| ResourceTag {
+                    key: k.to_string(),
+                    value: v.to_string(),
+                })
+                .collect(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    fn row_mut<'a>(dialog: &'a mut TagEditorDialog, key: &str) -> &'a mut TagEditRow {
+        dialog.rows.iter_mut().find(|row| row.key == key).unwrap()
+    }
+
+    #[test]
+    fn test_tag_changes_apply_to() {
+        let mut tags = resource("i-1", &[("Env", "dev"), ("Temp", "yes")]).tags;
+        let changes = TagChanges {
+            set: vec![
+                ResourceTag {
+                    key: "Env".to_string(),
+                    value: "prod".to_string(),
+                },
+                ResourceTag {
+                    key: "Owner".to_string(),
+                    value: "ops".to_string(),
+                },
+            ],
+            remove: vec!["Temp".to_string()],
+        };
+        changes.apply_to(&mut tags);
+        assert_eq!(tags, resource("i-1", &[("Env", "prod"), ("Owner", "ops")]).tags);
+    }
+
+    #[test]
+    fn test_tag_editor_single_resource_changes() {
+        let mut dialog = TagEditorDialog::new();
+        dialog.open_for(vec![resource("i-1", &[("Env", "dev"), ("Temp", "yes")])]);
+        assert!(dialog.build_changes().is_empty());
+
+        row_mut(&mut dialog, "Env").value = "prod".to_string();
+        row_mut(&mut dialog, "Temp").removed = true;
+        dialog.rows.push(TagEditRow {
+            key: "Owner".to_string(),
+            value: "ops".to_string(),
+            original_value: None,
+            present_on: 0,
+            removed: false,
+        });
+
+        let changes = dialog.build_changes();
+        let set: Vec<_> = changes.set.iter().map(|t| t.key.as_str()).collect();
+        assert_eq!(set, vec!["Env", "Owner"]);
+        assert_eq!(changes.remove, vec!["Temp".to_string()]);
+        assert!(dialog.validation_error().is_none());
+    }
+
+    #[test]
+    fn test_tag_editor_bulk_mixed_values() {
+        let mut dialog = TagEditorDialog::new();
+        dialog.open_for(vec![
+            resource("i-1", &[("Env", "dev"), ("Team", "a")]),
+            resource("i-2", &[("Env", "prod"), ("Team", "a")]),
+            resource("i-3", &[("Team", "a")]),
+        ]);
+
+        let env = row_mut(&mut dialog, "Env").clone();
+        assert_eq!(env.original_value, None);
+        assert_eq!(env.present_on, 2);
+        assert_eq!(
+            row_mut(&mut dialog, "Team").original_value.as_deref(),
+            Some("a")
+        );
+
+        // Untouched mixed keys are left alone
+        assert!(dialog.build_changes().is_empty());
+
+        row_mut(&mut dialog, "Env").value = "test".to_string();
+        let changes = dialog.build_changes();
+        assert_eq!(changes.set.len(), 1);
+        assert_eq!(changes.set[0].value, "test");
+    }
+
+    #[test]
+    fn test_tag_editor_validation() {
+        let mut dialog = TagEditorDialog::new();
+        dialog.open_for(vec![resource("i-1", &[("Env", "dev")])]);
+        let new_row = |key: &str| TagEditRow {
+            key: key.to_string(),
+            value: "v".to_string(),
+            original_value: None,
+            present_on: 0,
+            removed: false,
+        };
+
+        dialog.rows.push(new_row("Env"));
+        assert!(dialog.validation_error().unwrap().contains("Duplicate"));
+
+        dialog.rows.pop();
+        dialog.rows.push(new_row("aws:cloudformation:stack-name"));
+        assert!(dialog.validation_error().unwrap().contains("reserved"));
+
+        dialog.rows.pop();
+        dialog.rows.push(new_row(&"k".repeat(MAX_TAG_KEY_LENGTH + 1)));
+        assert!(dialog.validation_error().is_some());
+    }
+}
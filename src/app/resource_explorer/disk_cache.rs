@@ -0,0 +1,460 @@
+//! Persistent on-disk layer for the shared resource cache.
+//!
+//! Query results written to [`SharedResourceCache`](super::cache::SharedResourceCache)
+//! are mirrored to disk so the Explorer repopulates instantly after a restart
+//! instead of re-querying every account/region/resource type. Restored entries
+//! keep their original `query_timestamp`, so the tree's age badges mark them as
+//! stale until they are refreshed.
+//!
+//! Each cache key (`account:region:resource_type`) is stored in its own file:
+//!
+//! ```text
+//! ~/.local/share/awsdash/explorer_cache/
+//!   123456789012_us-east-1_AWS__EC2__Instance.cache
+//! ```
+//!
+//! A file holds a small JSON header (key, save time, sizes) followed by the
+//! zstd-compressed entries exactly as held in memory, so loading never has to
+//! recompress. Files older than the TTL are deleted on load.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Default time-to-live for persisted results (24 hours)
+pub const DEFAULT_DISK_TTL_SECS: u64 = 24 * 60 * 60;
+
+/// File extension for cache files
+const CACHE_FILE_EXTENSION: &str = "cache";
+
+/// Magic bytes identifying a cache file (and its format version)
+const MAGIC: &[u8; 4] = b"ADC1";
+
+/// Metadata stored at the start of each cache file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiskCacheHeader {
+    /// Full cache key (account:region:resource_type)
+    pub key: String,
+    /// When the entries were written
+    pub saved_at: DateTime<Utc>,
+    /// Number of resource entries
+    pub entry_count: usize,
+    /// Size of the entries before compression
+    pub uncompressed_size: usize,
+}
+
+/// A cache file read back from disk
+#[derive(Debug, Clone)]
+pub struct DiskCacheRecord {
+    pub header: DiskCacheHeader,
+    /// zstd-compressed JSON array of `ResourceEntry`
+    pub compressed: Vec<u8>,
+}
+
+/// Persistent store for compressed query results
+#[derive(Debug, Clone)]
+pub struct DiskResourceCache {
+    directory: PathBuf,
+    ttl: Duration,
+}
+
+impl DiskResourceCache {
+    /// Create a disk cache rooted at `directory`
+    pub fn new(directory: PathBuf, ttl: Duration) -> Self {
+        Self { directory, ttl }
+    }
+
+    /// Disk cache in the application data directory, or None if it is unavailable
+    pub fn default_location() -> Option<Self> {
+        let directory = dirs::data_local_dir()?.join("awsdash/explorer_cache");
+        let ttl_hours = crate::app::app_config::app_config().cache.disk_ttl_hours;
+        Some(Self::new(directory, Duration::from_secs(ttl_hours * 60 * 60)))
+    }
+
+    /// Directory holding cache files
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Time-to-live for persisted results
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Persist compressed entries for a cache key
+    ///
+    /// Writes to a temporary file first so a crash or a concurrent write for
+    /// the same key never leaves a torn file.
+    pub fn write(
+        &self,
+        key: &str,
+        compressed: &[u8],
+        entry_count: usize,
+        uncompressed_size: usize,
+    ) -> Result<()> {
+        fs::create_dir_all(&self.directory).with_context(|| {
+            format!(
+                "Failed to create cache directory {}",
+                self.directory.display()
+            )
+        })?;
+
+        let header = DiskCacheHeader {
+            key: key.to_string(),
+            saved_at: Utc::now(),
+            entry_count,
+            uncompressed_size,
+        };
+        let header_json = serde_json::to_vec(&header)?;
+
+        let mut contents = Vec::with_capacity(8 + header_json.len() + compressed.len());
+        contents.extend_from_slice(MAGIC);
+        contents.extend_from_slice(&(header_json.len() as u32).to_le_bytes());
+        contents.extend_from_slice(&header_json);
+        contents.extend_from_slice(compressed);
+
+        let path = self.path_for_key(key);
+        let mut tmp_file = tempfile::NamedTempFile::new_in(&self.directory)
+            .context("Failed to create temporary cache file")?;
+        tmp_file
+            .write_all(&contents)
+            .with_context(|| format!("Failed to write cache file for '{}'", key))?;
+        tmp_file
+            .persist(&path)
+            .with_context(|| format!("Failed to move cache file into {}", path.display()))?;
+
+        Ok(())
+    }
+
+    /// Load every unexpired cache file, deleting expired or unreadable ones
+    pub fn load_all(&self) -> Vec<DiskCacheRecord> {
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(), // Nothing persisted yet
+        };
+
+        let now = Utc::now();
+        let mut records = Vec::new();
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(CACHE_FILE_EXTENSION) {
+                continue;
+            }
+
+            match read_record(&path) {
+                Ok(record) if !self.is_expired(&record.header, now) => records.push(record),
+                Ok(record) => {
+                    tracing::debug!("Disk cache expired for '{}'", record.header.key);
+                    let _ = fs::remove_file(&path);
+                }
+                Err(e) => {
+                    tracing::warn!("Discarding unreadable cache file {}: {}", path.display(), e);
+                    let _ = fs::remove_file(&path);
+                }
+            }
+        }
+
+        records
+    }
+
+    /// Remove the persisted entries for a cache key
+    pub fn remove(&self, key: &str) {
+        let path = self.path_for_key(key);
+        if path.exists() {
+            if let Err(e) = fs::remove_file(&path) {
+                tracing::warn!("Failed to remove cache file {}: {}", path.display(), e);
+            }
+        }
+    }
+
+    /// Remove every persisted key matching the given account/region/resource type
+    ///
+    /// `None` matches anything, so `(Some(account), None, None)` drops all
+    /// results for one account. Returns the keys that were removed.
+    pub fn invalidate_matching(
+        &self,
+        account_id: Option<&str>,
+        region: Option<&str>,
+        resource_type: Option<&str>,
+    ) -> Vec<String> {
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+
+        let mut removed = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(CACHE_FILE_EXTENSION) {
+                continue;
+            }
+            let Ok(header) = read_header(&path) else {
+                continue;
+            };
+            if key_matches(&header.key, account_id, region, resource_type) {
+                let _ = fs::remove_file(&path);
+                removed.push(header.key);
+            }
+        }
+        removed
+    }
+
+    /// Remove all persisted results
+    pub fn clear(&self) {
+        if let Ok(entries) = fs::read_dir(&self.directory) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().and_then(|e| e.to_str()) == Some(CACHE_FILE_EXTENSION) {
+                    let _ = fs::remove_file(&path);
+                }
+            }
+        }
+    }
+
+    fn is_expired(&self, header: &DiskCacheHeader, now: DateTime<Utc>) -> bool {
+        let age = now.signed_duration_since(header.saved_at);
+        age.num_seconds() < 0 || age.num_seconds() as u64 > self.ttl.as_secs()
+    }
+
+    fn path_for_key(&self, key: &str) -> PathBuf {
+        self.directory.join(format!(
+            "{}.{}",
+            file_stem_for_key(key),
+            CACHE_FILE_EXTENSION
+        ))
+    }
+}
+
+/// Filesystem-safe file name for a cache key
+fn file_stem_for_key(key: &str) -> String {
+    key.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Check whether an `account:region:resource_type` key matches the filters
+pub fn key_matches(
+    key: &str,
+    account_id: Option<&str>,
+    region: Option<&str>,
+    resource_type: Option<&str>,
+) -> bool {
+    let mut parts = key.splitn(3, ':');
+    let (Some(key_account), Some(key_region), Some(key_type)) =
+        (parts.next(), parts.next(), parts.next())
+    else {
+        return false;
+    };
+
+    account_id.map_or(true, |a| a == key_account)
+        && region.map_or(true, |r| r == key_region)
+        && resource_type.map_or(true, |t| t == key_type)
+}
+
+fn read_header_bytes(contents: &[u8]) -> Result<(DiskCacheHeader, usize)> {
+    if contents.len() < 8 || &contents[..4] != MAGIC {
+        bail!("not a cache file");
+    }
+    let header_len =
+        u32::from_le_bytes([contents[4], contents[5], contents[6], contents[7]]) as usize;
+    let header_end = 8 + header_len;
+    if contents.len() < header_end {
+        bail!("truncated header");
+    }
+    let header: DiskCacheHeader =
+        serde_json::from_slice(&contents[8..header_end]).context("invalid header")?;
+    Ok((header, header_end))
+}
+
+fn read_header(path: &Path) -> Result<DiskCacheHeader> {
+    read_record(path).map(|record| record.header)
+}
+
+fn read_record(path: &Path) -> Result<DiskCacheRecord> {
+    let contents = fs::read(path)?;
+    let (header, header_end) = read_header_bytes(&contents)?;
+    Ok(DiskCacheRecord {
+        header,
+        compressed: contents[header_end..].to_vec(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_cache(ttl: Duration) -> (tempfile::TempDir, DiskResourceCache) {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskResourceCache::new(dir.path().join("explorer_cache"), ttl);
+        (dir, cache)
+    }
+
+    #[test]
+    fn test_write_and_load_round_trip() {
+        let (_dir, cache) = temp_cache(Duration::from_secs(3600));
+
+        cache
+            .write(
+                "123456789012:us-east-1:AWS::EC2::Instance",
+                b"compressed",
+                3,
+                42,
+            )
+            .unwrap();
+
+        let records = cache.load_all();
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].header.key,
+            "123456789012:us-east-1:AWS::EC2::Instance"
+        );
+        assert_eq!(records[0].header.entry_count, 3);
+        assert_eq!(records[0].header.uncompressed_size, 42);
+        assert_eq!(records[0].compressed, b"compressed");
+    }
+
+    #[test]
+    fn test_expired_files_are_deleted() {
+        let (_dir, cache) = temp_cache(Duration::from_secs(0));
+
+        cache
+            .write("a:us-east-1:AWS::S3::Bucket", b"x", 1, 1)
+            .unwrap();
+        // Backdate the file so it is past the zero TTL
+        let path = cache.path_for_key("a:us-east-1:AWS::S3::Bucket");
+        let mut contents = fs::read(&path).unwrap();
+        let (mut header, header_end) = read_header_bytes(&contents).unwrap();
+        header.saved_at -= chrono::Duration::seconds(10);
+        let header_json = serde_json::to_vec(&header).unwrap();
+        let mut rewritten = MAGIC.to_vec();
+        rewritten.extend_from_slice(&(header_json.len() as u32).to_le_bytes());
+        rewritten.extend_from_slice(&header_json);
+        rewritten.extend_from_slice(&contents.split_off(header_end));
+        fs::write(&path, rewritten).unwrap();
+
+        assert!(cache.load_all().is_empty());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_invalidate_matching() {
+        let (_dir, cache) = temp_cache(Duration::from_secs(3600));
+        cache
+            .write("111:us-east-1:AWS::EC2::Instance", b"x", 1, 1)
+            .unwrap();
+        cache
+            .write("111:us-west-2:AWS::EC2::Instance", b"x", 1, 1)
+            .unwrap();
+        cache
+            .write("222:us-east-1:AWS::EC2::Instance", b"x", 1, 1)
+            .unwrap();
+        cache
+            .write("222:Global:AWS::IAM::Role", b"x", 1, 1)
+            .unwrap();
+
+        let mut removed = cache.invalidate_matching(Some("111"), None, None);
+        removed.sort();
+        assert_eq!(
+            removed,
+            vec![
+                "111:us-east-1:AWS::EC2::Instance",
+                "111:us-west-2:AWS::EC2::Instance"
+            ]
+        );
+
+        let removed = cache.invalidate_matching(None, None, Some("AWS::IAM::Role"));
+        assert_eq!(removed, vec!["222:Global:AWS::IAM::Role"]);
+
+        assert_eq!(cache.load_all().len(), 1);
+
+        cache.remove("222:us-east-1:AWS::EC2::Instance");
+        assert!(cache.load_all().is_empty());
+    }
+
+    #[test]
+    fn test_key_matches() {
+        let key = "123456789012:us-east-1:AWS::EC2::Instance";
+        assert!(key_matches(key, None, None, None));
+        assert!(key_matches(
+            key,
+            Some("123456789012"),
+            Some("us-east-1"),
+            None
+        ));
+        assert!(key_matches(key, None, None, Some("AWS::EC2::Instance")));
+        assert!(!key_matches(key, Some("999999999999"), None, None));
+        assert!(!key_matches("malformed", None, None, None));
+    }
+
+    #[test]
+    fn test_shared_cache_restores_from_disk() {
+        use super::super::cache::{CacheConfig, SharedResourceCache};
+        use super::super::state::ResourceEntry;
+        use std::sync::Arc;
+
+        let (_dir, disk) = temp_cache(Duration::from_secs(3600));
+        let queried_at = Utc::now() - chrono::Duration::hours(2);
+        let entry = ResourceEntry {
+            resource_type: "AWS::EC2::Instance".to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: "i-0123456789abcdef0".to_string(),
+            display_name: "web".to_string(),
+            status: Some("running".to_string()),
+            properties: serde_json::json!({"InstanceType": "t3.micro"}),
+            detailed_timestamp: None,
+            tags: vec![],
+            relationships: vec![],
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: queried_at,
+        };
+        let key = "123456789012:us-east-1:AWS::EC2::Instance";
+
+        // First session writes through to disk
+        let session =
+            SharedResourceCache::new(CacheConfig::with_size_mb(16)).with_disk_cache(disk.clone());
+        session.insert_resources(key.to_string(), vec![Arc::new(entry)]);
+
+        // Next session restores with the original timestamp (shown as stale)
+        let restarted =
+            SharedResourceCache::new(CacheConfig::with_size_mb(16)).with_disk_cache(disk.clone());
+        assert_eq!(restarted.load_from_disk(), 1);
+        let restored = restarted.get_resources(key).expect("restored entries");
+        assert_eq!(restored[0].resource_id, "i-0123456789abcdef0");
+        assert_eq!(restored[0].query_timestamp, queried_at);
+        assert!(restored[0].is_stale(15));
+
+        // Invalidation removes both layers
+        assert_eq!(
+            restarted.invalidate_matching(Some("123456789012"), None, None),
+            1
+        );
+        assert!(disk.load_all().is_empty());
+    }
+
+    #[test]
+    fn test_unreadable_files_are_discarded() {
+        let (_dir, cache) = temp_cache(Duration::from_secs(3600));
+        fs::create_dir_all(cache.directory()).unwrap();
+        let junk = cache.directory().join("junk.cache");
+        fs::write(&junk, b"not a cache file").unwrap();
+
+        assert!(cache.load_all().is_empty());
+        assert!(!junk.exists());
+    }
+}
@@ -0,0 +1,895 @@
+//! Export the visible Explorer tree to CSV, JSON, or Excel.
+//!
+//! The export covers exactly the resources the tree is showing: tag filters,
+//! property filters, and the search box (once it has 3+ characters, as in the
+//! tree) are applied first. Columns are picked from three groups:
+//!
+//! - **Fields**: resource type, name, ID, account, region, status, query time
+//! - **Tags**: one column per tag key found on the exported resources
+//! - **Properties**: normalized properties flattened with dot notation
+//!   (e.g., `State.Name`); arrays are written as JSON text
+//!
+//! Exports run on a background thread and report progress through the
+//! global [`StatusChannel`](super::status::StatusChannel).
+
+use super::instances::PaneRenderer;
+use super::state::{ResourceEntry, ResourceExplorerState};
+use super::status::{report_status, report_status_done};
+use super::tree::TreeBuilder;
+use anyhow::{Context as _, Result};
+use egui::{Context, Window};
+use serde_json::Value;
+use std::collections::{BTreeSet, HashSet};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Report progress every this many rows
+const PROGRESS_INTERVAL: usize = 1000;
+
+/// Excel's per-cell character limit
+const XLSX_MAX_CELL_CHARS: usize = 32_767;
+
+/// Output file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Json,
+    Xlsx,
+}
+
+impl ExportFormat {
+    /// All formats, in display order
+    pub fn all() -> [ExportFormat; 3] {
+        [ExportFormat::Csv, ExportFormat::Json, ExportFormat::Xlsx]
+    }
+
+    /// File extension (without dot)
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Json => "json",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+
+    /// Display label
+    pub fn label(&self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "CSV",
+            ExportFormat::Json => "JSON",
+            ExportFormat::Xlsx => "Excel (.xlsx)",
+        }
+    }
+}
+
+/// Built-in resource fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExportField {
+    ResourceType,
+    DisplayName,
+    ResourceId,
+    AccountId,
+    Region,
+    Status,
+    QueryTimestamp,
+}
+
+impl ExportField {
+    /// All fields, in default column order
+    pub fn all() -> [ExportField; 7] {
+        [
+            ExportField::ResourceType,
+            ExportField::DisplayName,
+            ExportField::ResourceId,
+            ExportField::AccountId,
+            ExportField::Region,
+            ExportField::Status,
+            ExportField::QueryTimestamp,
+        ]
+    }
+
+    /// Column header
+    pub fn header(&self) -> &'static str {
+        match self {
+            ExportField::ResourceType => "ResourceType",
+            ExportField::DisplayName => "Name",
+            ExportField::ResourceId => "ResourceId",
+            ExportField::AccountId => "AccountId",
+            ExportField::Region => "Region",
+            ExportField::Status => "Status",
+            ExportField::QueryTimestamp => "QueriedAt",
+        }
+    }
+
+    fn value(&self, resource: &ResourceEntry) -> Value {
+        match self {
+            ExportField::ResourceType => Value::String(resource.resource_type.clone()),
+            ExportField::DisplayName => Value::String(resource.display_name.clone()),
+            ExportField::ResourceId => Value::String(resource.resource_id.clone()),
+            ExportField::AccountId => Value::String(resource.account_id.clone()),
+            ExportField::Region => Value::String(resource.region.clone()),
+            ExportField::Status => resource
+                .status
+                .as_ref()
+                .map(|s| Value::String(s.clone()))
+                .unwrap_or(Value::Null),
+            ExportField::QueryTimestamp => Value::String(resource.query_timestamp.to_rfc3339()),
+        }
+    }
+}
+
+/// A column in the export
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ExportColumn {
+    /// Built-in resource field
+    Field(ExportField),
+    /// Value of a tag key
+    Tag(String),
+    /// Flattened property path (e.g., "State.Name")
+    Property(String),
+}
+
+impl ExportColumn {
+    /// Column header; tag and property columns are prefixed so they cannot
+    /// collide with each other or with built-in fields
+    pub fn header(&self) -> String {
+        match self {
+            ExportColumn::Field(field) => field.header().to_string(),
+            ExportColumn::Tag(key) => format!("tag:{}", key),
+            ExportColumn::Property(path) => format!("prop:{}", path),
+        }
+    }
+
+    /// Cell value for a resource (Null when absent)
+    pub fn value(&self, resource: &ResourceEntry) -> Value {
+        match self {
+            ExportColumn::Field(field) => field.value(resource),
+            ExportColumn::Tag(key) => resource
+                .tags
+                .iter()
+                .find(|tag| &tag.key == key)
+                .map(|tag| Value::String(tag.value.clone()))
+                .unwrap_or(Value::Null),
+            ExportColumn::Property(path) => property_at(&resource.properties, path)
+                .cloned()
+                .unwrap_or(Value::Null),
+        }
+    }
+}
+
+/// All columns available for a set of resources: fields, then sorted tag
+/// keys, then sorted property paths
+pub fn available_columns(resources: &[ResourceEntry]) -> Vec<ExportColumn> {
+    let mut tag_keys = BTreeSet::new();
+    let mut property_paths = BTreeSet::new();
+
+    for resource in resources {
+        tag_keys.extend(resource.tags.iter().map(|tag| tag.key.clone()));
+        collect_property_paths("", &resource.properties, &mut property_paths);
+    }
+
+    ExportField::all()
+        .into_iter()
+        .map(ExportColumn::Field)
+        .chain(tag_keys.into_iter().map(ExportColumn::Tag))
+        .chain(property_paths.into_iter().map(ExportColumn::Property))
+        .collect()
+}
+
+/// Resources currently visible in the tree for this state
+///
+/// Applies the same tag, property, and search filters as the tree view and
+/// leaves out nothing else, so child resources are included.
+pub fn visible_resources(state: &ResourceExplorerState) -> Vec<ResourceEntry> {
+    let filtered: Vec<ResourceEntry> = state
+        .resources
+        .iter()
+        .filter(|resource| {
+            PaneRenderer::apply_tag_filters(resource, state)
+                && PaneRenderer::apply_property_filters(resource, state)
+        })
+        .cloned()
+        .collect();
+
+    // The tree only starts search filtering at 3 characters
+    if state.search_filter.len() < 3 {
+        filtered
+    } else {
+        TreeBuilder::filter_resources(&filtered, &state.search_filter)
+    }
+}
+
+/// Default output path: Downloads (or home) directory with a timestamped name
+pub fn default_export_path(format: ExportFormat) -> PathBuf {
+    let directory = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."));
+    directory.join(format!(
+        "aws-resources-{}.{}",
+        chrono::Local::now().format("%Y%m%d-%H%M%S"),
+        format.extension()
+    ))
+}
+
+/// Write resources to `path` in the given format
+///
+/// `on_progress` is called with the number of rows written so far.
+pub fn export_resources(
+    resources: &[ResourceEntry],
+    columns: &[ExportColumn],
+    format: ExportFormat,
+    path: &Path,
+    mut on_progress: impl FnMut(usize),
+) -> Result<()> {
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create export file {}", path.display()))?;
+
+    match format {
+        ExportFormat::Csv => write_csv(BufWriter::new(file), resources, columns, &mut on_progress),
+        ExportFormat::Json => {
+            write_json(BufWriter::new(file), resources, columns, &mut on_progress)
+        }
+        ExportFormat::Xlsx => write_xlsx(file, resources, columns, &mut on_progress),
+    }
+    .with_context(|| format!("Failed to write export file {}", path.display()))
+}
+
+fn write_csv<W: Write>(
+    mut writer: W,
+    resources: &[ResourceEntry],
+    columns: &[ExportColumn],
+    on_progress: &mut dyn FnMut(usize),
+) -> Result<()> {
+    let headers: Vec<String> = columns.iter().map(|c| csv_field(&c.header())).collect();
+    writeln!(writer, "{}", headers.join(","))?;
+
+    for (index, resource) in resources.iter().enumerate() {
+        let cells: Vec<String> = columns
+            .iter()
+            .map(|column| csv_field(&cell_text(&column.value(resource))))
+            .collect();
+        writeln!(writer, "{}", cells.join(","))?;
+        report_every(index + 1, on_progress);
+    }
+
+    writer.flush()?;
+    Ok(())
+}
+
+fn write_json<W: Write>(
+    mut writer: W,
+    resources: &[ResourceEntry],
+    columns: &[ExportColumn],
+    on_progress: &mut dyn FnMut(usize),
+) -> Result<()> {
+    let headers: Vec<String> = columns.iter().map(|c| c.header()).collect();
+
+    writer.write_all(b"[\n")?;
+    for (index, resource) in resources.iter().enumerate() {
+        let record: serde_json::Map<String, Value> = headers
+            .iter()
+            .zip(columns)
+            .map(|(header, column)| (header.clone(), column.value(resource)))
+            .collect();
+        if index > 0 {
+            writer.write_all(b",\n")?;
+        }
+        writer.write_all(b"  ")?;
+        serde_json::to_writer(&mut writer, &record)?;
+        report_every(index + 1, on_progress);
+    }
+    writer.write_all(b"\n]\n")?;
+
+    writer.flush()?;
+    Ok(())
+}
+
+/// Write a single-sheet workbook
+///
+/// Uses inline strings so no shared string table is needed; numbers and
+/// booleans are written as typed cells.
+fn write_xlsx<W: Write + std::io::Seek>(
+    writer: W,
+    resources: &[ResourceEntry],
+    columns: &[ExportColumn],
+    on_progress: &mut dyn FnMut(usize),
+) -> Result<()> {
+    let mut zip = zip::ZipWriter::new(writer);
+    let options =
+        zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    zip.start_file("[Content_Types].xml", options)?;
+    zip.write_all(XLSX_CONTENT_TYPES.as_bytes())?;
+    zip.start_file("_rels/.rels", options)?;
+    zip.write_all(XLSX_ROOT_RELS.as_bytes())?;
+    zip.start_file("xl/workbook.xml", options)?;
+    zip.write_all(XLSX_WORKBOOK.as_bytes())?;
+    zip.start_file("xl/_rels/workbook.xml.rels", options)?;
+    zip.write_all(XLSX_WORKBOOK_RELS.as_bytes())?;
+
+    zip.start_file("xl/worksheets/sheet1.xml", options)?;
+    zip.write_all(
+        concat!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+            r#"<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main">"#,
+            r#"<sheetViews><sheetView workbookViewId="0"><pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/></sheetView></sheetViews>"#,
+            "<sheetData>"
+        )
+        .as_bytes(),
+    )?;
+
+    let headers: Vec<Value> = columns.iter().map(|c| Value::String(c.header())).collect();
+    write_xlsx_row(&mut zip, 1, &headers)?;
+
+    for (index, resource) in resources.iter().enumerate() {
+        let values: Vec<Value> = columns.iter().map(|c| c.value(resource)).collect();
+        write_xlsx_row(&mut zip, index + 2, &values)?;
+        report_every(index + 1, on_progress);
+    }
+
+    zip.write_all(b"</sheetData></worksheet>")?;
+    zip.finish()?;
+    Ok(())
+}
+
+fn write_xlsx_row<W: Write>(writer: &mut W, row_number: usize, values: &[Value]) -> Result<()> {
+    write!(writer, r#"<row r="{}">"#, row_number)?;
+    for (column_index, value) in values.iter().enumerate() {
+        let cell_ref = format!("{}{}", column_letters(column_index), row_number);
+        match value {
+            Value::Null => {}
+            Value::Number(n) => write!(writer, r#"<c r="{}"><v>{}</v></c>"#, cell_ref, n)?,
+            Value::Bool(b) => write!(
+                writer,
+                r#"<c r="{}" t="b"><v>{}</v></c>"#,
+                cell_ref,
+                if *b { 1 } else { 0 }
+            )?,
+            _ => {
+                let text: String = cell_text(value).chars().take(XLSX_MAX_CELL_CHARS).collect();
+                write!(
+                    writer,
+                    r#"<c r="{}" t="inlineStr"><is><t xml:space="preserve">{}</t></is></c>"#,
+                    cell_ref,
+                    xml_escape(&text)
+                )?
+            }
+        }
+    }
+    writer.write_all(b"</row>")?;
+    Ok(())
+}
+
+const XLSX_CONTENT_TYPES: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">"#,
+    r#"<Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/>"#,
+    r#"<Default Extension="xml" ContentType="application/xml"/>"#,
+    r#"<Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/>"#,
+    r#"<Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/>"#,
+    "</Types>"
+);
+
+const XLSX_ROOT_RELS: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+    r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/>"#,
+    "</Relationships>"
+);
+
+const XLSX_WORKBOOK: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">"#,
+    r#"<sheets><sheet name="Resources" sheetId="1" r:id="rId1"/></sheets>"#,
+    "</workbook>"
+);
+
+const XLSX_WORKBOOK_RELS: &str = concat!(
+    r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#,
+    r#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">"#,
+    r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/>"#,
+    "</Relationships>"
+);
+
+fn report_every(rows_written: usize, on_progress: &mut dyn FnMut(usize)) {
+    if rows_written % PROGRESS_INTERVAL == 0 {
+        on_progress(rows_written);
+    }
+}
+
+/// Text form of a cell: strings as-is, Null empty, arrays/objects as JSON
+fn cell_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Quote a CSV field when it contains a delimiter, quote, or line break
+pub(crate) fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+/// Escape XML text, dropping control characters XML 1.0 does not allow
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Spreadsheet column letters for a zero-based index (0 -> A, 26 -> AA)
+fn column_letters(index: usize) -> String {
+    let mut letters = Vec::new();
+    let mut n = index + 1;
+    while n > 0 {
+        let rem = (n - 1) % 26;
+        letters.push((b'A' + rem as u8) as char);
+        n = (n - 1) / 26;
+    }
+    letters.iter().rev().collect()
+}
+
+/// Leaf paths of a properties object; arrays count as leaves
+fn collect_property_paths(prefix: &str, value: &Value, paths: &mut BTreeSet<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                collect_property_paths(&path, child, paths);
+            }
+        }
+        _ if !prefix.is_empty() => {
+            paths.insert(prefix.to_string());
+        }
+        _ => {}
+    }
+}
+
+fn property_at<'a>(properties: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(properties, |current, segment| current.get(segment))
+}
+
+// ============================================================================
+// Export Dialog
+// ============================================================================
+
+/// Progress of a running export, shared with its background thread
+#[derive(Debug, Default)]
+struct ExportJob {
+    total: usize,
+    rows_written: usize,
+    result: Option<Result<PathBuf, String>>,
+}
+
+/// Column picker and format chooser for exporting the visible tree
+#[derive(Default)]
+pub struct ExportDialog {
+    pub open: bool,
+    format: Option<ExportFormat>,
+    path: String,
+    resources: Vec<ResourceEntry>,
+    columns: Vec<ExportColumn>,
+    selected: HashSet<ExportColumn>,
+    column_filter: String,
+    job: Option<Arc<Mutex<ExportJob>>>,
+    message: Option<String>,
+}
+
+impl ExportDialog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the dialog for a snapshot of the visible resources
+    ///
+    /// Built-in fields and tag columns start selected; property columns start
+    /// cleared since there can be hundreds of them.
+    pub fn open_for(&mut self, resources: Vec<ResourceEntry>) {
+        let format = self.format.unwrap_or(ExportFormat::Csv);
+        self.columns = available_columns(&resources);
+        self.selected = self
+            .columns
+            .iter()
+            .filter(|c| !matches!(c, ExportColumn::Property(_)))
+            .cloned()
+            .collect();
+        self.resources = resources;
+        self.format = Some(format);
+        self.path = default_export_path(format).display().to_string();
+        self.column_filter.clear();
+        self.message = None;
+        self.open = true;
+    }
+
+    /// Render the dialog; `id_salt` keeps dialogs from different panes apart
+    pub fn show(&mut self, ctx: &Context, id_salt: impl std::hash::Hash) {
+        if !self.open {
+            return;
+        }
+
+        self.poll_job();
+        let running = self.job.is_some();
+        if running {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        let mut open = self.open;
+        let mut start_export = false;
+
+        Window::new("Export Resources")
+            .id(egui::Id::new(("export_dialog", id_salt)))
+            .open(&mut open)
+            .default_size([520.0, 480.0])
+            .resizable(true)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "{} resources in the current view",
+                    self.resources.len()
+                ));
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    ui.label("Format:");
+                    let mut format = self.format.unwrap_or(ExportFormat::Csv);
+                    for candidate in ExportFormat::all() {
+                        if ui
+                            .radio_value(&mut format, candidate, candidate.label())
+                            .clicked()
+                        {
+                            self.path = swap_extension(&self.path, candidate);
+                        }
+                    }
+                    self.format = Some(format);
+                });
+
+                ui.horizontal(|ui| {
+                    ui.label("File:");
+                    ui.add(egui::TextEdit::singleline(&mut self.path).desired_width(f32::INFINITY));
+                });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    ui.label(format!(
+                        "Columns ({} of {} selected)",
+                        self.selected.len(),
+                        self.columns.len()
+                    ));
+                    if ui.small_button("All").clicked() {
+                        self.selected = self.visible_columns().cloned().collect();
+                    }
+                    if ui.small_button("None").clicked() {
+                        self.selected.clear();
+                    }
+                });
+                ui.horizontal(|ui| {
+                    ui.label("Filter:");
+                    ui.text_edit_singleline(&mut self.column_filter);
+                });
+
+                egui::ScrollArea::vertical()
+                    .max_height(260.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        let columns: Vec<ExportColumn> = self.visible_columns().cloned().collect();
+                        for column in columns {
+                            let mut checked = self.selected.contains(&column);
+                            if ui.checkbox(&mut checked, column.header()).changed() {
+                                if checked {
+                                    self.selected.insert(column);
+                                } else {
+                                    self.selected.remove(&column);
+                                }
+                            }
+                        }
+                    });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    let can_export = !running
+                        && !self.selected.is_empty()
+                        && !self.resources.is_empty()
+                        && !self.path.trim().is_empty();
+                    if ui
+                        .add_enabled(can_export, egui::Button::new("Export"))
+                        .clicked()
+                    {
+                        start_export = true;
+                    }
+
+                    if let Some(job) = &self.job {
+                        if let Ok(job) = job.lock() {
+                            ui.spinner();
+                            ui.label(format!("{} / {} rows", job.rows_written, job.total));
+                        }
+                    } else if let Some(message) = &self.message {
+                        ui.label(message);
+                    }
+                });
+            });
+
+        self.open = open;
+        if start_export {
+            self.start_export();
+        }
+    }
+
+    fn visible_columns(&self) -> impl Iterator<Item = &ExportColumn> {
+        let filter = self.column_filter.to_lowercase();
+        self.columns
+            .iter()
+            .filter(move |c| filter.is_empty() || c.header().to_lowercase().contains(&filter))
+    }
+
+    fn start_export(&mut self) {
+        let format = self.format.unwrap_or(ExportFormat::Csv);
+        let path = PathBuf::from(self.path.trim());
+        // Keep picker order rather than selection order
+        let columns: Vec<ExportColumn> = self
+            .columns
+            .iter()
+            .filter(|c| self.selected.contains(c))
+            .cloned()
+            .collect();
+        let resources = self.resources.clone();
+
+        let job = Arc::new(Mutex::new(ExportJob {
+            total: resources.len(),
+            ..ExportJob::default()
+        }));
+        self.job = Some(job.clone());
+        self.message = None;
+
+        std::thread::spawn(move || {
+            let total = resources.len();
+            let operation = format.extension();
+            report_status("Export", operation, Some(&format!("0/{} rows", total)));
+
+            let result = export_resources(&resources, &columns, format, &path, |rows| {
+                if let Ok(mut job) = job.lock() {
+                    job.rows_written = rows;
+                }
+                report_status(
+                    "Export",
+                    operation,
+                    Some(&format!("{}/{} rows", rows, total)),
+                );
+            });
+
+            match &result {
+                Ok(()) => {
+                    tracing::info!("Exported {} resources to {}", total, path.display());
+                    report_status_done(
+                        "Export",
+                        operation,
+                        Some(&format!("{} rows to {}", total, path.display())),
+                    );
+                }
+                Err(e) => tracing::error!("Resource export failed: {:#}", e),
+            }
+
+            if let Ok(mut job) = job.lock() {
+                job.rows_written = total;
+                job.result = Some(result.map(|()| path).map_err(|e| format!("{:#}", e)));
+            }
+        });
+    }
+
+    fn poll_job(&mut self) {
+        let finished = self
+            .job
+            .as_ref()
+            .and_then(|job| job.lock().ok().and_then(|mut job| job.result.take()));
+
+        if let Some(result) = finished {
+            self.job = None;
+            self.message = Some(match result {
+                Ok(path) => format!("Saved {}", path.display()),
+                Err(e) => format!("Export failed: {}", e),
+            });
+        }
+    }
+}
+
+fn swap_extension(path: &str, format: ExportFormat) -> String {
+    Path::new(path)
+        .with_extension(format.extension())
+        .display()
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::resource_explorer::state::ResourceTag;
+    use serde_json::json;
+
+    fn resource(id: &str, tags: &[(&str, &str)], properties: Value) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: "AWS::EC2::Instance".to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: id.to_string(),
+            display_name: format!("{}-name", id),
+            status: Some("running".to_string()),
+            properties,
+            detailed_timestamp: None,
+            tags: tags
+                .iter()
+                .map(|(k, v)| ResourceTag {
+                    key: k.to_string(),
+                    value: v.to_string(),
+                })
+                .collect(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_available_columns() {
+        let resources = vec![
+            resource(
+                "i-1",
+                &[("Name", "web")],
+                json!({"State": {"Name": "running"}, "CpuCount": 2}),
+            ),
+            resource(
+                "i-2",
+                &[("Env", "prod")],
+                json!({"SecurityGroups": ["sg-1"]}),
+            ),
+        ];
+
+        let columns = available_columns(&resources);
+        assert_eq!(columns[0], ExportColumn::Field(ExportField::ResourceType));
+        assert!(columns.contains(&ExportColumn::Tag("Env".to_string())));
+        assert!(columns.contains(&ExportColumn::Tag("Name".to_string())));
+        assert!(columns.contains(&ExportColumn::Property("State.Name".to_string())));
+        assert!(columns.contains(&ExportColumn::Property("SecurityGroups".to_string())));
+        assert!(!columns.contains(&ExportColumn::Property("State".to_string())));
+    }
+
+    #[test]
+    fn test_column_values() {
+        let r = resource(
+            "i-1",
+            &[("Name", "web")],
+            json!({"State": {"Name": "running"}, "Ids": [1, 2]}),
+        );
+
+        assert_eq!(
+            ExportColumn::Tag("Name".to_string()).value(&r),
+            json!("web")
+        );
+        assert_eq!(
+            ExportColumn::Tag("Missing".to_string()).value(&r),
+            Value::Null
+        );
+        assert_eq!(
+            ExportColumn::Property("State.Name".to_string()).value(&r),
+            json!("running")
+        );
+        assert_eq!(
+            cell_text(&ExportColumn::Property("Ids".to_string()).value(&r)),
+            "[1,2]"
+        );
+    }
+
+    #[test]
+    fn test_write_csv_escapes_fields() {
+        let resources = vec![resource(
+            "i-1",
+            &[("Note", "a, \"quoted\" value")],
+            json!({}),
+        )];
+        let columns = vec![
+            ExportColumn::Field(ExportField::ResourceId),
+            ExportColumn::Tag("Note".to_string()),
+        ];
+
+        let mut output = Vec::new();
+        write_csv(&mut output, &resources, &columns, &mut |_| {}).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "ResourceId,tag:Note\ni-1,\"a, \"\"quoted\"\" value\"\n"
+        );
+    }
+
+    #[test]
+    fn test_write_json_keeps_types() {
+        let resources = vec![resource("i-1", &[], json!({"CpuCount": 2}))];
+        let columns = vec![
+            ExportColumn::Field(ExportField::ResourceId),
+            ExportColumn::Property("CpuCount".to_string()),
+        ];
+
+        let mut output = Vec::new();
+        write_json(&mut output, &resources, &columns, &mut |_| {}).unwrap();
+        let parsed: Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(parsed, json!([{"ResourceId": "i-1", "prop:CpuCount": 2}]));
+    }
+
+    #[test]
+    fn test_write_xlsx_produces_workbook() {
+        let resources = vec![resource("i-1", &[("Name", "<web>")], json!({}))];
+        let columns = vec![
+            ExportColumn::Field(ExportField::ResourceId),
+            ExportColumn::Tag("Name".to_string()),
+        ];
+
+        let mut output = std::io::Cursor::new(Vec::new());
+        write_xlsx(&mut output, &resources, &columns, &mut |_| {}).unwrap();
+
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(output.into_inner())).unwrap();
+        let mut sheet = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name("xl/worksheets/sheet1.xml").unwrap(),
+            &mut sheet,
+        )
+        .unwrap();
+        assert!(sheet.contains(
+            r#"<c r="B2" t="inlineStr"><is><t xml:space="preserve">&lt;web&gt;</t></is></c>"#
+        ));
+        assert!(archive.by_name("xl/workbook.xml").is_ok());
+    }
+
+    #[test]
+    fn test_progress_reporting() {
+        let resources: Vec<ResourceEntry> = (0..2500)
+            .map(|i| resource(&format!("i-{}", i), &[], json!({})))
+            .collect();
+        let columns = vec![ExportColumn::Field(ExportField::ResourceId)];
+
+        let mut reported = Vec::new();
+        write_csv(std::io::sink(), &resources, &columns, &mut |rows| {
+            reported.push(rows)
+        })
+        .unwrap();
+        assert_eq!(reported, vec![1000, 2000]);
+    }
+
+    #[test]
+    fn test_column_letters() {
+        assert_eq!(column_letters(0), "A");
+        assert_eq!(column_letters(25), "Z");
+        assert_eq!(column_letters(26), "AA");
+        assert_eq!(column_letters(701), "ZZ");
+        assert_eq!(column_letters(702), "AAA");
+    }
+
+    #[test]
+    fn test_swap_extension() {
+        assert_eq!(
+            swap_extension("/tmp/out.csv", ExportFormat::Xlsx),
+            "/tmp/out.xlsx"
+        );
+    }
+}
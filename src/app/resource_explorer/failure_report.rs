@@ -0,0 +1,374 @@
+//! Partial failure reporting for multi-account queries.
+//!
+//! A query over many accounts, regions and resource types can fail for some
+//! combinations (a missing permission in one account, a disabled region) while
+//! the rest load fine. This module turns the failed query keys and their raw
+//! SDK errors into a structured report, and renders it in a panel grouped by
+//! account, region or resource type.
+
+use super::sdk_errors::{categorize_error_string, ErrorCategory};
+use egui::{Color32, Context, RichText, Window};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// One failed account/region/resource type query
+#[derive(Debug, Clone)]
+pub struct QueryFailure {
+    pub account_id: String,
+    pub region: String,
+    pub resource_type: String,
+    pub category: ErrorCategory,
+    /// Error text as returned by the SDK
+    pub raw_error: String,
+}
+
+impl QueryFailure {
+    /// Parse a query key ("account:region:AWS::Service::Type") and its error
+    pub fn from_query_key(query_key: &str, raw_error: &str) -> Option<Self> {
+        let mut parts = query_key.splitn(3, ':');
+        let account_id = parts.next()?.to_string();
+        let region = parts.next()?.to_string();
+        let resource_type = parts.next()?.to_string();
+        let service = resource_type
+            .strip_prefix("AWS::")
+            .and_then(|rest| rest.split("::").next())
+            .unwrap_or(&resource_type);
+        Some(Self {
+            category: categorize_error_string(raw_error, service, "query"),
+            account_id,
+            region,
+            resource_type,
+            raw_error: raw_error.to_string(),
+        })
+    }
+
+    /// Short label of the error category
+    pub fn category_label(&self) -> String {
+        match &self.category {
+            ErrorCategory::NonRetryable {
+                is_permission_error: true,
+                code,
+                ..
+            } => format!("Permission denied ({})", code),
+            ErrorCategory::NonRetryable { code, .. } => code.clone(),
+            other => other.user_message(),
+        }
+    }
+
+    pub fn category_color(&self) -> Color32 {
+        match &self.category {
+            ErrorCategory::NonRetryable {
+                is_permission_error: true,
+                ..
+            } => Color32::from_rgb(255, 100, 100),
+            ErrorCategory::NonRetryable { .. } => Color32::from_rgb(255, 120, 120),
+            ErrorCategory::Throttled { .. } => Color32::from_rgb(255, 200, 100),
+            _ => Color32::from_rgb(255, 160, 60),
+        }
+    }
+
+    fn key_for(&self, grouping: FailureGrouping) -> &str {
+        match grouping {
+            FailureGrouping::Account => &self.account_id,
+            FailureGrouping::Region => &self.region,
+            FailureGrouping::ResourceType => &self.resource_type,
+        }
+    }
+}
+
+/// How the failure report groups its entries
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FailureGrouping {
+    #[default]
+    Account,
+    Region,
+    ResourceType,
+}
+
+impl FailureGrouping {
+    pub const ALL: [FailureGrouping; 3] = [
+        FailureGrouping::Account,
+        FailureGrouping::Region,
+        FailureGrouping::ResourceType,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FailureGrouping::Account => "Account",
+            FailureGrouping::Region => "Region",
+            FailureGrouping::ResourceType => "Resource Type",
+        }
+    }
+}
+
+/// Failed queries of the last load
+#[derive(Debug, Clone, Default)]
+pub struct FailureReport {
+    pub failures: Vec<QueryFailure>,
+    /// Queries attempted in total, for the "N of M failed" summary
+    pub total_queries: usize,
+}
+
+impl FailureReport {
+    /// Build the report from failed query keys and the raw error recorded for each
+    pub fn new(
+        failed_queries: &HashSet<String>,
+        errors: &HashMap<String, String>,
+        total_queries: usize,
+    ) -> Self {
+        let mut failures: Vec<QueryFailure> = failed_queries
+            .iter()
+            .filter_map(|key| {
+                let raw_error = errors
+                    .get(key)
+                    .map(String::as_str)
+                    .unwrap_or("Query failed (no error details recorded)");
+                QueryFailure::from_query_key(key, raw_error)
+            })
+            .collect();
+        failures.sort_by(|a, b| {
+            (&a.account_id, &a.region, &a.resource_type).cmp(&(
+                &b.account_id,
+                &b.region,
+                &b.resource_type,
+            ))
+        });
+        Self {
+            failures,
+            total_queries,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Failures grouped by account, region or resource type, in key order
+    pub fn grouped(&self, grouping: FailureGrouping) -> BTreeMap<&str, Vec<&QueryFailure>> {
+        let mut groups: BTreeMap<&str, Vec<&QueryFailure>> = BTreeMap::new();
+        for failure in &self.failures {
+            groups
+                .entry(failure.key_for(grouping))
+                .or_default()
+                .push(failure);
+        }
+        groups
+    }
+
+    /// "3 of 12 queries failed", or "3 queries failed" once the total is no longer known
+    pub fn summary(&self) -> String {
+        if self.total_queries >= self.failures.len() && self.total_queries > 0 {
+            format!(
+                "{} of {} queries failed",
+                self.failures.len(),
+                self.total_queries
+            )
+        } else {
+            format!("{} queries failed", self.failures.len())
+        }
+    }
+
+    /// Plain-text report for copying into a ticket or chat
+    pub fn to_text(&self) -> String {
+        let mut text = format!("{}\n", self.summary());
+        for failure in &self.failures {
+            text.push_str(&format!(
+                "\n{} {} {}\n  {}\n  {}\n",
+                failure.account_id,
+                failure.region,
+                failure.resource_type,
+                failure.category_label(),
+                failure.raw_error
+            ));
+        }
+        text
+    }
+}
+
+/// Panel listing the failed queries of a pane
+#[derive(Default)]
+pub struct FailureReportPanel {
+    pub open: bool,
+    grouping: FailureGrouping,
+    report: FailureReport,
+}
+
+/// What the user asked for in the failure panel
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailureReportAction {
+    /// Clear the failures and hide the status bar indicator
+    Dismiss,
+}
+
+impl FailureReportPanel {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the panel on a snapshot of the failed queries
+    pub fn open_for(&mut self, report: FailureReport) {
+        self.report = report;
+        self.open = true;
+    }
+
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        id_salt: impl std::hash::Hash,
+    ) -> Option<FailureReportAction> {
+        if !self.open {
+            return None;
+        }
+        let report = &self.report;
+        let grouping = &mut self.grouping;
+
+        let mut open = self.open;
+        let mut action = None;
+
+        Window::new("Query Failures")
+            .id(egui::Id::new(("failure_report", id_salt)))
+            .open(&mut open)
+            .default_size([560.0, 420.0])
+            .resizable(true)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                if report.is_empty() {
+                    ui.label("All queries completed successfully.");
+                    return;
+                }
+
+                ui.label(
+                    RichText::new(format!(
+                        "{}; the tree is missing their resources",
+                        report.summary()
+                    ))
+                    .strong(),
+                );
+                ui.horizontal(|ui| {
+                    ui.label("Group by:");
+                    for option in FailureGrouping::ALL {
+                        ui.radio_value(grouping, option, option.label());
+                    }
+                });
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .max_height(300.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        for (key, failures) in report.grouped(*grouping) {
+                            egui::CollapsingHeader::new(
+                                RichText::new(format!("{} ({})", key, failures.len()))
+                                    .color(Color32::from_rgb(255, 180, 100)),
+                            )
+                            .id_salt(("failure_group", grouping.label(), key))
+                            .default_open(true)
+                            .show(ui, |ui| {
+                                for failure in failures {
+                                    show_failure(ui, failure, *grouping);
+                                }
+                            });
+                        }
+                    });
+
+                ui.separator();
+                ui.horizontal(|ui| {
+                    if ui.button("Copy Report").clicked() {
+                        ui.ctx().copy_text(report.to_text());
+                    }
+                    if ui
+                        .button("Dismiss")
+                        .on_hover_text("Clear the failures and hide the status bar indicator")
+                        .clicked()
+                    {
+                        action = Some(FailureReportAction::Dismiss);
+                    }
+                });
+            });
+
+        self.open = open && action.is_none();
+        action
+    }
+}
+
+/// One failure: the parts not already shown by the group header, its category and raw error
+fn show_failure(ui: &mut egui::Ui, failure: &QueryFailure, grouping: FailureGrouping) {
+    let location: Vec<&str> = [
+        (FailureGrouping::Account, failure.account_id.as_str()),
+        (FailureGrouping::Region, failure.region.as_str()),
+        (
+            FailureGrouping::ResourceType,
+            failure.resource_type.as_str(),
+        ),
+    ]
+    .into_iter()
+    .filter(|(key, _)| *key != grouping)
+    .map(|(_, value)| value)
+    .collect();
+
+    ui.horizontal(|ui| {
+        ui.label(location.join("  "));
+        ui.label(
+            RichText::new(format!("[{}]", failure.category_label()))
+                .color(failure.category_color())
+                .small(),
+        );
+    });
+    ui.label(RichText::new(&failure.raw_error).monospace().small().weak())
+        .on_hover_text("Raw SDK error");
+    ui.add_space(4.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_groups_failures_with_raw_errors() {
+        let failed: HashSet<String> = [
+            "111111111111:us-east-1:AWS::EC2::Instance",
+            "111111111111:eu-west-1:AWS::EC2::Instance",
+            "222222222222:us-east-1:AWS::Lambda::Function",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        let errors: HashMap<String, String> = [(
+            "222222222222:us-east-1:AWS::Lambda::Function".to_string(),
+            "AccessDeniedException: not authorized to perform lambda:ListFunctions".to_string(),
+        )]
+        .into_iter()
+        .collect();
+
+        let report = FailureReport::new(&failed, &errors, 12);
+        assert_eq!(report.failures.len(), 3);
+
+        let by_account = report.grouped(FailureGrouping::Account);
+        assert_eq!(by_account["111111111111"].len(), 2);
+        let lambda = by_account["222222222222"][0];
+        assert!(lambda.raw_error.contains("lambda:ListFunctions"));
+        assert!(matches!(
+            lambda.category,
+            ErrorCategory::NonRetryable {
+                is_permission_error: true,
+                ..
+            }
+        ));
+
+        let by_type = report.grouped(FailureGrouping::ResourceType);
+        assert_eq!(by_type["AWS::EC2::Instance"].len(), 2);
+        assert!(report.to_text().starts_with("3 of 12 queries failed"));
+    }
+
+    #[test]
+    fn test_query_key_keeps_resource_type_colons() {
+        let failure = QueryFailure::from_query_key(
+            "111111111111:Global:AWS::IAM::Role",
+            "ThrottlingException: Rate exceeded",
+        )
+        .unwrap();
+        assert_eq!(failure.region, "Global");
+        assert_eq!(failure.resource_type, "AWS::IAM::Role");
+        assert!(matches!(failure.category, ErrorCategory::Throttled { .. }));
+        assert!(QueryFailure::from_query_key("bad-key", "error").is_none());
+    }
+}
@@ -1,4 +1,20 @@
-use std::collections::HashSet;
+use super::state::{QueryScope, ResourceEntry};
+use crate::app::aws_regions::active_partition;
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+
+/// Pseudo-region that global resources are stored and grouped under
+pub const GLOBAL_REGION: &str = "Global";
+
+/// Global types whose list call is global but whose resources live in a real region
+const HYBRID_GLOBAL_TYPES: &[&str] = &["AWS::S3::Bucket"];
+
+static REGISTRY: Lazy<GlobalServiceRegistry> = Lazy::new(GlobalServiceRegistry::new);
+
+/// Shared registry instance, for per-resource checks in hot paths
+pub fn global_registry() -> &'static GlobalServiceRegistry {
+    &REGISTRY
+}
 
 /// Registry of AWS global services that operate across all regions
 /// These services return the same data regardless of which region is queried
@@ -27,6 +43,7 @@ impl GlobalServiceRegistry {
 
         // Route53 - Global DNS service
         registry.insert("AWS::Route53::HostedZone");
+        registry.insert("AWS::Route53::RecordSet");
         registry.insert("AWS::Route53::HealthCheck");
         registry.insert("AWS::Route53::Domain");
 
@@ -84,20 +101,107 @@ impl GlobalServiceRegistry {
         self.global_resource_types.contains(resource_type)
     }
 
-    /// Get the query region for global services (default: us-east-1)
+    /// Check if a global type keeps each resource's actual region (S3 buckets)
+    pub fn is_hybrid_global(&self, resource_type: &str) -> bool {
+        HYBRID_GLOBAL_TYPES.contains(&resource_type)
+    }
+
+    /// Get the query region for global services in the signed-in partition
+    /// (us-east-1 for commercial AWS, us-gov-west-1 for GovCloud, cn-north-1 for China)
     pub fn get_query_region(&self) -> &'static str {
-        "us-east-1"
+        active_partition().global_region()
+    }
+
+    /// Region a resource is shown under: "Global" for true global types
+    pub fn display_region<'a>(&self, resource_type: &str, region: &'a str) -> &'a str {
+        if self.is_global(resource_type) && !self.is_hybrid_global(resource_type) {
+            GLOBAL_REGION
+        } else {
+            region
+        }
+    }
+
+    /// Queries needed for a scope as (account_id, region, resource_type)
+    ///
+    /// Global types get one query per account in the "Global" pseudo-region
+    /// instead of one per selected region.
+    pub fn query_plan(&self, scope: &QueryScope) -> Vec<(String, String, String)> {
+        let mut seen = HashSet::new();
+        let mut queries = Vec::new();
+        for account in &scope.accounts {
+            for resource_type in &scope.resource_types {
+                let resource_type = resource_type.resource_type.as_str();
+                let regions: Vec<&str> = if self.is_global(resource_type) {
+                    vec![GLOBAL_REGION]
+                } else {
+                    scope
+                        .regions
+                        .iter()
+                        .map(|region| region.region_code.as_str())
+                        .collect()
+                };
+                for region in regions {
+                    let query = (
+                        account.account_id.clone(),
+                        region.to_string(),
+                        resource_type.to_string(),
+                    );
+                    if seen.insert(query.clone()) {
+                        queries.push(query);
+                    }
+                }
+            }
+        }
+        queries
+    }
+
+    /// Collapse copies of the same global resource picked up from several regions
+    ///
+    /// True global resources move to the "Global" pseudo-region. Regional
+    /// resources are kept as they are. When copies differ, the most recently
+    /// enriched one wins.
+    pub fn dedupe_resources(&self, resources: Vec<ResourceEntry>) -> Vec<ResourceEntry> {
+        let mut deduped: Vec<ResourceEntry> = Vec::with_capacity(resources.len());
+        let mut positions: HashMap<(String, String, String), usize> = HashMap::new();
+
+        for mut resource in resources {
+            if !self.is_global(&resource.resource_type) {
+                deduped.push(resource);
+                continue;
+            }
+            if !self.is_hybrid_global(&resource.resource_type) {
+                resource.region = GLOBAL_REGION.to_string();
+            }
+            let key = (
+                resource.account_id.clone(),
+                resource.resource_type.clone(),
+                resource.resource_id.clone(),
+            );
+            match positions.get(&key) {
+                Some(&position) => {
+                    if resource.detailed_timestamp > deduped[position].detailed_timestamp {
+                        deduped[position] = resource;
+                    }
+                }
+                None => {
+                    positions.insert(key, deduped.len());
+                    deduped.push(resource);
+                }
+            }
+        }
+
+        deduped
     }
 }
 
 /// Convenience function to check if a resource type is global
 pub fn is_global_service(resource_type: &str) -> bool {
-    GlobalServiceRegistry::new().is_global(resource_type)
+    global_registry().is_global(resource_type)
 }
 
 /// Get the default region to query for global services
 pub fn get_global_query_region() -> &'static str {
-    GlobalServiceRegistry::new().get_query_region()
+    global_registry().get_query_region()
 }
 
 #[cfg(test)]
@@ -130,6 +234,57 @@ mod tests {
         assert!(!is_global_service("AWS::EC2::Instance"));
     }
 
+    fn resource(resource_type: &str, region: &str, id: &str) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: region.to_string(),
+            resource_id: id.to_string(),
+            display_name: id.to_string(),
+            status: None,
+            properties: serde_json::json!({}),
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_dedupe_collapses_global_resources_across_regions() {
+        let registry = GlobalServiceRegistry::new();
+        let mut enriched = resource("AWS::IAM::Role", "eu-west-1", "admin");
+        enriched.detailed_timestamp = Some(chrono::Utc::now());
+        let resources = vec![
+            resource("AWS::IAM::Role", "us-east-1", "admin"),
+            enriched,
+            resource("AWS::S3::Bucket", "eu-west-1", "logs"),
+            resource("AWS::S3::Bucket", "us-east-1", "logs"),
+            resource("AWS::EC2::Instance", "us-east-1", "i-1"),
+            resource("AWS::EC2::Instance", "eu-west-1", "i-1"),
+        ];
+
+        let deduped = registry.dedupe_resources(resources);
+        assert_eq!(deduped.len(), 4);
+        assert_eq!(deduped[0].region, GLOBAL_REGION);
+        assert!(deduped[0].detailed_timestamp.is_some());
+        // S3 buckets keep their actual region
+        assert_eq!(deduped[1].region, "eu-west-1");
+        assert_eq!(
+            registry.display_region("AWS::IAM::Role", "us-east-1"),
+            "Global"
+        );
+        assert_eq!(
+            registry.display_region("AWS::S3::Bucket", "eu-west-1"),
+            "eu-west-1"
+        );
+    }
+
     #[test]
     fn test_query_region() {
         let registry = GlobalServiceRegistry::new();
@@ -0,0 +1,159 @@
+//! Hint mode targets in the Explorer tree
+//!
+//! While rendering, the tree registers its group headers, resource rows, tag
+//! badges and action buttons here. The app hands them to the widget manager as
+//! navigable elements, and a hint picked from the keyboard comes back as an
+//! activation that the matching widget consumes on its next render.
+
+use crate::app::dashui::{ElementAction, NavigableElement, NavigableElementType};
+use uuid::Uuid;
+
+/// Prefix of the element IDs the Explorer hands to the widget manager
+pub const EXPLORER_HINT_PREFIX: &str = "explorer_";
+
+/// Element ID of a tree target in the pane with this ID
+pub fn element_id(pane_id: Uuid, local_id: &str) -> String {
+    format!("{}{}_{}", EXPLORER_HINT_PREFIX, pane_id, local_id)
+}
+
+/// Split an Explorer element ID into its pane ID and tree-local target ID
+pub fn parse_element_id(element_id: &str) -> Option<(Uuid, &str)> {
+    let rest = element_id.strip_prefix(EXPLORER_HINT_PREFIX)?;
+    let (pane_id, local_id) = rest.split_once('_')?;
+    let pane_id = Uuid::parse_str(pane_id).ok()?;
+    Some((pane_id, local_id))
+}
+
+/// Targets registered by one tree renderer and the activation waiting for it
+#[derive(Debug, Default)]
+pub struct TreeHintTargets {
+    elements: Vec<NavigableElement>,
+    activation: Option<(String, ElementAction)>,
+}
+
+impl TreeHintTargets {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a widget rendered this frame; widgets scrolled out of view are skipped
+    pub fn register(
+        &mut self,
+        response: &egui::Response,
+        local_id: String,
+        element_type: NavigableElementType,
+        label: &str,
+    ) {
+        // interact_rect is already clipped to the visible part of the scroll area
+        let rect = response.interact_rect;
+        if !rect.is_positive() {
+            return;
+        }
+        let mut element = NavigableElement::new(local_id, element_type, rect);
+        element.label = Some(label.to_string());
+        self.elements.push(element);
+    }
+
+    /// Register a clickable widget and report whether it was clicked or picked by a hint
+    pub fn clicked(
+        &mut self,
+        response: &egui::Response,
+        local_id: String,
+        element_type: NavigableElementType,
+        label: &str,
+    ) -> bool {
+        let activated = self.take_activation(&local_id).is_some();
+        self.register(response, local_id, element_type, label);
+        response.clicked() || activated
+    }
+
+    /// Open state to force on a collapsing header picked by a hint
+    ///
+    /// `Close` collapses the header; any other action toggles it.
+    pub fn header_open_state(
+        &mut self,
+        ui: &egui::Ui,
+        id_salt: impl std::hash::Hash,
+        local_id: &str,
+        default_open: bool,
+    ) -> Option<bool> {
+        let action = self.take_activation(local_id)?;
+        let id = ui.make_persistent_id(id_salt);
+        let is_open = egui::collapsing_header::CollapsingState::load(ui.ctx(), id)
+            .map(|state| state.is_open())
+            .unwrap_or(default_open);
+        Some(match action {
+            ElementAction::Close => false,
+            _ => !is_open,
+        })
+    }
+
+    /// Ask the target with this tree-local ID to act on its next render
+    pub fn activate(&mut self, local_id: &str, action: ElementAction) {
+        self.activation = Some((local_id.to_string(), action));
+    }
+
+    /// Take the pending activation if it is for this target
+    pub fn take_activation(&mut self, local_id: &str) -> Option<ElementAction> {
+        match &self.activation {
+            Some((id, _)) if id == local_id => self.activation.take().map(|(_, action)| action),
+            _ => None,
+        }
+    }
+
+    /// Drop an activation whose target was not rendered this frame
+    pub fn end_frame(&mut self) {
+        if let Some((id, _)) = self.activation.take() {
+            tracing::debug!(
+                "Explorer hint target '{}' was not rendered, dropping activation",
+                id
+            );
+        }
+    }
+
+    /// Take the targets registered since the last call
+    pub fn take_elements(&mut self) -> Vec<NavigableElement> {
+        std::mem::take(&mut self.elements)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_element_id_round_trips_through_parse() {
+        let pane_id = Uuid::new_v4();
+        let id = element_id(pane_id, "row:resource_AWS::S3::Bucket:logs_bucket");
+
+        assert!(id.starts_with(EXPLORER_HINT_PREFIX));
+        assert_eq!(
+            parse_element_id(&id),
+            Some((pane_id, "row:resource_AWS::S3::Bucket:logs_bucket"))
+        );
+        assert_eq!(parse_element_id("template_sections_save"), None);
+        assert_eq!(parse_element_id("explorer_not-a-uuid_row:x"), None);
+    }
+
+    #[test]
+    fn test_activation_is_taken_only_by_its_target() {
+        let mut targets = TreeHintTargets::new();
+        targets.activate("button:View Logs:r1", ElementAction::Click);
+
+        assert_eq!(targets.take_activation("button:View Logs:r2"), None);
+        assert_eq!(
+            targets.take_activation("button:View Logs:r1"),
+            Some(ElementAction::Click)
+        );
+        assert_eq!(targets.take_activation("button:View Logs:r1"), None);
+    }
+
+    #[test]
+    fn test_unrendered_activation_is_dropped_at_end_of_frame() {
+        let mut targets = TreeHintTargets::new();
+        targets.activate("node:account-1", ElementAction::Open);
+        targets.end_frame();
+
+        assert_eq!(targets.take_activation("node:account-1"), None);
+    }
+}
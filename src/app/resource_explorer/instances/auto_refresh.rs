@@ -0,0 +1,345 @@
+//! Scheduled background refresh for Explorer panes
+//!
+//! Each pane can re-run its query scope every 5, 15, or 60 minutes. Before a
+//! scheduled run the pane snapshots which resources it is showing; when the
+//! run completes the new resources are compared against the snapshot and a
+//! summary ("3 new EC2 Instances since last refresh") is sent to the
+//! application's `NotificationManager`.
+//!
+//! Scheduling is driven by the UI frame loop: the pane asks egui to repaint
+//! when the next run is due, so no extra thread or timer is needed.
+
+use crate::app::resource_explorer::state::{ResourceEntry, ResourceTypeSelection};
+use std::collections::{BTreeMap, HashSet};
+use std::time::{Duration, Instant};
+
+/// How often a pane re-runs its query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AutoRefreshInterval {
+    #[default]
+    Off,
+    FiveMinutes,
+    FifteenMinutes,
+    SixtyMinutes,
+}
+
+impl AutoRefreshInterval {
+    /// All intervals, in menu order
+    pub fn all() -> [AutoRefreshInterval; 4] {
+        [
+            AutoRefreshInterval::Off,
+            AutoRefreshInterval::FiveMinutes,
+            AutoRefreshInterval::FifteenMinutes,
+            AutoRefreshInterval::SixtyMinutes,
+        ]
+    }
+
+    /// Time between runs (None when off)
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            AutoRefreshInterval::Off => None,
+            AutoRefreshInterval::FiveMinutes => Some(Duration::from_secs(5 * 60)),
+            AutoRefreshInterval::FifteenMinutes => Some(Duration::from_secs(15 * 60)),
+            AutoRefreshInterval::SixtyMinutes => Some(Duration::from_secs(60 * 60)),
+        }
+    }
+
+    /// Display label
+    pub fn label(&self) -> &'static str {
+        match self {
+            AutoRefreshInterval::Off => "Off",
+            AutoRefreshInterval::FiveMinutes => "Every 5 min",
+            AutoRefreshInterval::FifteenMinutes => "Every 15 min",
+            AutoRefreshInterval::SixtyMinutes => "Every 60 min",
+        }
+    }
+}
+
+/// Per-pane auto-refresh configuration and progress
+#[derive(Debug, Default)]
+pub struct AutoRefreshSchedule {
+    interval: AutoRefreshInterval,
+    next_run: Option<Instant>,
+    /// Snapshot taken when the current run started (Some while in flight)
+    baseline: Option<ResourceSnapshot>,
+    /// Summary of the last completed run, shown next to the selector
+    pub last_summary: Option<String>,
+}
+
+impl AutoRefreshSchedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current interval
+    pub fn interval(&self) -> AutoRefreshInterval {
+        self.interval
+    }
+
+    /// Change the interval; the first run happens one full interval from now
+    pub fn set_interval(&mut self, interval: AutoRefreshInterval, now: Instant) {
+        self.interval = interval;
+        self.next_run = interval.duration().map(|d| now + d);
+        if interval == AutoRefreshInterval::Off {
+            self.baseline = None;
+            self.last_summary = None;
+        }
+    }
+
+    /// Whether a scheduled run should start now
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.baseline.is_none() && self.next_run.is_some_and(|next| now >= next)
+    }
+
+    /// Time until the next run (None when off or a run is in flight)
+    pub fn time_until_due(&self, now: Instant) -> Option<Duration> {
+        if self.baseline.is_some() {
+            return None;
+        }
+        self.next_run
+            .map(|next| next.saturating_duration_since(now))
+    }
+
+    /// Whether a scheduled run is waiting for its query to finish
+    pub fn is_running(&self) -> bool {
+        self.baseline.is_some()
+    }
+
+    /// Record the start of a run and schedule the next one
+    pub fn start_run(&mut self, baseline: ResourceSnapshot, now: Instant) {
+        self.baseline = Some(baseline);
+        self.next_run = self.interval.duration().map(|d| now + d);
+    }
+
+    /// Skip a due run (e.g. pane busy or no scope) and wait a full interval
+    pub fn skip_run(&mut self, now: Instant) {
+        self.next_run = self.interval.duration().map(|d| now + d);
+    }
+
+    /// Finish the in-flight run, returning the snapshot it started from
+    pub fn finish_run(&mut self) -> Option<ResourceSnapshot> {
+        self.baseline.take()
+    }
+}
+
+/// Resource identities per resource type at a point in time
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceSnapshot {
+    by_type: BTreeMap<String, HashSet<String>>,
+}
+
+impl ResourceSnapshot {
+    /// Snapshot the given resources (identity = account, region, resource ID)
+    pub fn from_resources(resources: &[ResourceEntry]) -> Self {
+        let mut by_type: BTreeMap<String, HashSet<String>> = BTreeMap::new();
+        for resource in resources {
+            by_type
+                .entry(resource.resource_type.clone())
+                .or_default()
+                .insert(format!(
+                    "{}:{}:{}",
+                    resource.account_id, resource.region, resource.resource_id
+                ));
+        }
+        Self { by_type }
+    }
+}
+
+/// Added/removed counts for one resource type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceTypeChange {
+    pub resource_type: String,
+    pub display_name: String,
+    pub added: usize,
+    pub removed: usize,
+}
+
+/// Differences between two snapshots
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceChangeSummary {
+    pub changes: Vec<ResourceTypeChange>,
+}
+
+impl ResourceChangeSummary {
+    /// Compare snapshots; `resource_types` supplies display names
+    pub fn between(
+        before: &ResourceSnapshot,
+        after: &ResourceSnapshot,
+        resource_types: &[ResourceTypeSelection],
+    ) -> Self {
+        let empty = HashSet::new();
+        let types: std::collections::BTreeSet<&String> =
+            before.by_type.keys().chain(after.by_type.keys()).collect();
+
+        let changes = types
+            .into_iter()
+            .filter_map(|resource_type| {
+                let old = before.by_type.get(resource_type).unwrap_or(&empty);
+                let new = after.by_type.get(resource_type).unwrap_or(&empty);
+                let added = new.difference(old).count();
+                let removed = old.difference(new).count();
+                if added == 0 && removed == 0 {
+                    return None;
+                }
+
+                let display_name = resource_types
+                    .iter()
+                    .find(|t| &t.resource_type == resource_type)
+                    .map(|t| t.display_name.clone())
+                    .unwrap_or_else(|| resource_type.clone());
+
+                Some(ResourceTypeChange {
+                    resource_type: resource_type.clone(),
+                    display_name,
+                    added,
+                    removed,
+                })
+            })
+            .collect();
+
+        Self { changes }
+    }
+
+    /// True when nothing was added or removed
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// One line per change, e.g. "3 new EC2 Instances since last refresh"
+    pub fn messages(&self) -> Vec<String> {
+        let mut messages = Vec::new();
+        for change in &self.changes {
+            if change.added > 0 {
+                messages.push(format!(
+                    "{} new {} since last refresh",
+                    change.added,
+                    pluralize(&change.display_name, change.added)
+                ));
+            }
+            if change.removed > 0 {
+                messages.push(format!(
+                    "{} {} removed since last refresh",
+                    change.removed,
+                    pluralize(&change.display_name, change.removed)
+                ));
+            }
+        }
+        messages
+    }
+
+    /// Short headline for the notification title
+    pub fn headline(&self) -> String {
+        let added: usize = self.changes.iter().map(|c| c.added).sum();
+        let removed: usize = self.changes.iter().map(|c| c.removed).sum();
+        match (added, removed) {
+            (a, 0) => format!("{} new resource{}", a, if a == 1 { "" } else { "s" }),
+            (0, r) => format!("{} resource{} removed", r, if r == 1 { "" } else { "s" }),
+            (a, r) => format!("{} new, {} removed", a, r),
+        }
+    }
+}
+
+fn pluralize(name: &str, count: usize) -> String {
+    if count == 1 || name.ends_with('s') {
+        name.to_string()
+    } else if let Some(stem) = name
+        .strip_suffix('y')
+        .filter(|stem| !stem.ends_with(['a', 'e', 'i', 'o', 'u']))
+    {
+        format!("{}ies", stem)
+    } else {
+        format!("{}s", name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resource(resource_type: &str, id: &str) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: id.to_string(),
+            display_name: id.to_string(),
+            status: None,
+            properties: serde_json::json!({}),
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_schedule_due_and_in_flight() {
+        let start = Instant::now();
+        let mut schedule = AutoRefreshSchedule::new();
+        assert!(!schedule.is_due(start + Duration::from_secs(3600)));
+
+        schedule.set_interval(AutoRefreshInterval::FiveMinutes, start);
+        assert!(!schedule.is_due(start + Duration::from_secs(60)));
+        assert!(schedule.is_due(start + Duration::from_secs(300)));
+
+        let run_at = start + Duration::from_secs(300);
+        schedule.start_run(ResourceSnapshot::default(), run_at);
+        assert!(schedule.is_running());
+        assert!(!schedule.is_due(run_at + Duration::from_secs(600)));
+        assert_eq!(schedule.time_until_due(run_at), None);
+
+        assert!(schedule.finish_run().is_some());
+        assert_eq!(
+            schedule.time_until_due(run_at),
+            Some(Duration::from_secs(300))
+        );
+
+        schedule.set_interval(AutoRefreshInterval::Off, run_at);
+        assert_eq!(schedule.time_until_due(run_at), None);
+    }
+
+    #[test]
+    fn test_change_summary() {
+        let before = ResourceSnapshot::from_resources(&[
+            resource("AWS::EC2::Instance", "i-1"),
+            resource("AWS::S3::Bucket", "old-bucket"),
+        ]);
+        let after = ResourceSnapshot::from_resources(&[
+            resource("AWS::EC2::Instance", "i-1"),
+            resource("AWS::EC2::Instance", "i-2"),
+            resource("AWS::EC2::Instance", "i-3"),
+            resource("AWS::EC2::Instance", "i-4"),
+        ]);
+        let types = vec![ResourceTypeSelection::new(
+            "AWS::EC2::Instance".to_string(),
+            "EC2 Instance".to_string(),
+            "EC2".to_string(),
+        )];
+
+        let summary = ResourceChangeSummary::between(&before, &after, &types);
+        assert_eq!(
+            summary.messages(),
+            vec![
+                "3 new EC2 Instances since last refresh".to_string(),
+                "1 AWS::S3::Bucket removed since last refresh".to_string(),
+            ]
+        );
+        assert_eq!(summary.headline(), "3 new, 1 removed");
+
+        assert!(ResourceChangeSummary::between(&after, &after, &types).is_empty());
+    }
+
+    #[test]
+    fn test_pluralize() {
+        assert_eq!(pluralize("EC2 Instance", 2), "EC2 Instances");
+        assert_eq!(pluralize("IAM Policy", 2), "IAM Policies");
+        assert_eq!(pluralize("Gateway", 2), "Gateways");
+        assert_eq!(pluralize("Lambda Function", 1), "Lambda Function");
+        assert_eq!(pluralize("Logs", 3), "Logs");
+    }
+}
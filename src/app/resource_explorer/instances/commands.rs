@@ -0,0 +1,160 @@
+//! Explorer Commands - Command palette commands for Explorer windows
+//!
+//! Commands act on the current pane of the focused Explorer window (see
+//! [`ExplorerInstance::current_pane`](super::ExplorerInstance::current_pane)).
+//! The app registers [`palette_commands`] with the command palette and hands
+//! the chosen id to [`ExplorerManager::run_palette_command`].
+
+use super::manager::ExplorerManager;
+use crate::app::dashui::command_registry::{CommandCategory, PaletteCommand};
+use crate::app::resource_explorer::state::ResourceEntry;
+use egui::Context;
+
+pub const NEW_WINDOW: &str = "explorer.new_window";
+pub const SELECT_SCOPE: &str = "explorer.select_scope";
+pub const REFRESH_PANE: &str = "explorer.refresh_pane";
+pub const REFRESH_CHANGED: &str = "explorer.refresh_changed";
+pub const EXPORT_VISIBLE: &str = "explorer.export_visible";
+pub const TOGGLE_SPLIT: &str = "explorer.toggle_split";
+pub const CLEAR_SELECTION: &str = "explorer.clear_selection";
+
+/// Commands the Explorer offers in the palette
+pub fn palette_commands() -> Vec<PaletteCommand> {
+    vec![
+        PaletteCommand {
+            id: NEW_WINDOW,
+            label: "New Explorer window",
+            description: "Open another Explorer window",
+            category: CommandCategory::Explorer,
+            keywords: &["open", "resources"],
+            is_available: |context| context.logged_in,
+        },
+        PaletteCommand {
+            id: SELECT_SCOPE,
+            label: "Select accounts, regions, and types",
+            description: "Change the query scope of the current pane",
+            category: CommandCategory::Explorer,
+            keywords: &["scope", "query", "add account", "add region"],
+            is_available: |context| context.explorer_focused,
+        },
+        PaletteCommand {
+            id: REFRESH_PANE,
+            label: "Refresh current pane",
+            description: "Re-fetch every resource in the current pane's scope",
+            category: CommandCategory::Explorer,
+            keywords: &["reload", "requery"],
+            is_available: |context| context.explorer_focused && !context.pane_loading,
+        },
+        PaletteCommand {
+            id: REFRESH_CHANGED,
+            label: "Refresh changed resource types",
+            description: "Re-fetch only types with CloudTrail write events since they were cached",
+            category: CommandCategory::Explorer,
+            keywords: &["delta", "reload", "cloudtrail"],
+            is_available: |context| {
+                context.explorer_focused && context.pane_has_resources && !context.pane_loading
+            },
+        },
+        PaletteCommand {
+            id: EXPORT_VISIBLE,
+            label: "Export visible resources",
+            description: "Export the current pane's tree to CSV, JSON, or Excel",
+            category: CommandCategory::Explorer,
+            keywords: &["csv", "json", "excel", "download", "save"],
+            is_available: |context| context.explorer_focused && context.pane_has_resources,
+        },
+        PaletteCommand {
+            id: TOGGLE_SPLIT,
+            label: "Toggle split pane",
+            description: "Show or hide the second pane of the focused Explorer window",
+            category: CommandCategory::Explorer,
+            keywords: &["right pane", "compare", "side by side"],
+            is_available: |context| context.explorer_focused,
+        },
+        PaletteCommand {
+            id: CLEAR_SELECTION,
+            label: "Clear tree selection",
+            description: "Deselect the resources picked in the current pane",
+            category: CommandCategory::Explorer,
+            keywords: &["deselect", "unselect"],
+            is_available: |context| !context.selected_resource_types.is_empty(),
+        },
+    ]
+}
+
+impl ExplorerManager {
+    /// Run an Explorer palette command
+    ///
+    /// Returns false when `id` is not an Explorer command or there is no
+    /// focused window for it to act on.
+    pub fn run_palette_command(&mut self, id: &str, ctx: &Context) -> bool {
+        if id == NEW_WINDOW {
+            let instance = self.open_new_window();
+            tracing::info!(
+                "New Explorer window created from command palette: instance {}",
+                instance.instance_number()
+            );
+            return true;
+        }
+
+        let focused_id = self.focused_instance_id;
+        let shared_context = &self.shared_context;
+        let Some(instance) = self
+            .instances
+            .iter_mut()
+            .find(|i| i.is_open && Some(i.id()) == focused_id)
+        else {
+            return false;
+        };
+
+        match id {
+            TOGGLE_SPLIT => {
+                instance.toggle_right_pane();
+                true
+            }
+            REFRESH_PANE => instance.current_pane_mut().refresh_now(ctx, shared_context),
+            CLEAR_SELECTION => {
+                let pane = instance.current_pane_mut();
+                pane.renderer.tree_renderer.selected_resources.clear();
+                true
+            }
+            SELECT_SCOPE | REFRESH_CHANGED | EXPORT_VISIBLE => {
+                // The pane picks these up like its toolbar buttons
+                let pane = instance.current_pane();
+                let Ok(mut state) = pane.state.try_write() else {
+                    return false;
+                };
+                match id {
+                    SELECT_SCOPE => state.show_unified_selection_dialog = true,
+                    REFRESH_CHANGED => state.delta_refresh_requested = true,
+                    _ => state.show_export_dialog = true,
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Resources selected in the focused window's current pane
+    pub fn selected_resources(&self) -> Vec<ResourceEntry> {
+        let Some(instance) = self.focused_instance().filter(|i| i.is_open) else {
+            return Vec::new();
+        };
+        let pane = instance.current_pane();
+        let selected = &pane.renderer.tree_renderer.selected_resources;
+        if selected.is_empty() {
+            return Vec::new();
+        }
+        pane.state
+            .try_read()
+            .map(|state| {
+                state
+                    .resources
+                    .iter()
+                    .filter(|r| selected.contains(&r.selection_key()))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,397 @@
+//! Pane comparison - diff the resource sets of the left and right panes
+//!
+//! Used by the "Compare Panes" mode of split Explorer tabs (for example a
+//! production account in the left pane and a staging account in the right).
+//! Resources are matched by resource type plus either their display name or
+//! their resource ID. Matched pairs are then compared on status and on their
+//! normalized properties, skipping fields that always differ between copies of
+//! the same resource (IDs, ARNs, timestamps, account and region values).
+
+use crate::app::resource_explorer::state::ResourceEntry;
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/// Property name suffixes that identify a resource rather than describe it
+const IGNORED_PROPERTY_SUFFIXES: &[&str] = &[
+    "arn",
+    "id",
+    "ids",
+    "time",
+    "date",
+    "timestamp",
+    "account",
+    "region",
+    "owner",
+];
+
+/// How resources in the two panes are paired up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompareMatchBy {
+    /// Same resource type and display name (works across accounts)
+    #[default]
+    Name,
+    /// Same resource type and resource ID (works across regions of one account)
+    ResourceId,
+}
+
+impl CompareMatchBy {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CompareMatchBy::Name => "Name",
+            CompareMatchBy::ResourceId => "Resource ID",
+        }
+    }
+
+    fn key_of(&self, resource: &ResourceEntry) -> String {
+        match self {
+            CompareMatchBy::Name => resource.display_name.clone(),
+            CompareMatchBy::ResourceId => resource.resource_id.clone(),
+        }
+    }
+}
+
+/// Outcome for one matched (or unmatched) resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum CompareStatus {
+    OnlyLeft,
+    OnlyRight,
+    Different,
+    Same,
+}
+
+impl CompareStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CompareStatus::OnlyLeft => "Only in left",
+            CompareStatus::OnlyRight => "Only in right",
+            CompareStatus::Different => "Different",
+            CompareStatus::Same => "Same",
+        }
+    }
+}
+
+/// One side of a comparison row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompareSide {
+    pub account_id: String,
+    pub region: String,
+    pub resource_id: String,
+    pub display_name: String,
+}
+
+impl From<&ResourceEntry> for CompareSide {
+    fn from(resource: &ResourceEntry) -> Self {
+        Self {
+            account_id: resource.account_id.clone(),
+            region: resource.region.clone(),
+            resource_id: resource.resource_id.clone(),
+            display_name: resource.display_name.clone(),
+        }
+    }
+}
+
+/// A key property whose value differs between the two sides
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropertyDifference {
+    pub path: String,
+    pub left: Option<String>,
+    pub right: Option<String>,
+}
+
+/// One row of the comparison table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompareRow {
+    pub resource_type: String,
+    pub match_key: String,
+    pub status: CompareStatus,
+    pub left: Option<CompareSide>,
+    pub right: Option<CompareSide>,
+    pub differences: Vec<PropertyDifference>,
+}
+
+/// Result of comparing two panes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PaneComparison {
+    pub rows: Vec<CompareRow>,
+}
+
+impl PaneComparison {
+    /// Compare two resource sets
+    ///
+    /// When several resources on one side share a key (e.g. the same name in
+    /// two regions) they are paired in account/region/ID order and any extras
+    /// are reported as present on one side only.
+    pub fn between(
+        left: &[ResourceEntry],
+        right: &[ResourceEntry],
+        match_by: CompareMatchBy,
+    ) -> Self {
+        let left_groups = group_resources(left, match_by);
+        let right_groups = group_resources(right, match_by);
+        let keys: BTreeSet<&(String, String)> =
+            left_groups.keys().chain(right_groups.keys()).collect();
+
+        let mut rows = Vec::new();
+        for key in keys {
+            let lefts = left_groups.get(key).map(Vec::as_slice).unwrap_or_default();
+            let rights = right_groups.get(key).map(Vec::as_slice).unwrap_or_default();
+
+            for index in 0..lefts.len().max(rights.len()) {
+                let left = lefts.get(index).copied();
+                let right = rights.get(index).copied();
+                let differences = match (left, right) {
+                    (Some(l), Some(r)) => property_differences(l, r),
+                    _ => Vec::new(),
+                };
+                let status = match (left, right) {
+                    (Some(_), None) => CompareStatus::OnlyLeft,
+                    (None, Some(_)) => CompareStatus::OnlyRight,
+                    _ if differences.is_empty() => CompareStatus::Same,
+                    _ => CompareStatus::Different,
+                };
+
+                rows.push(CompareRow {
+                    resource_type: key.0.clone(),
+                    match_key: key.1.clone(),
+                    status,
+                    left: left.map(CompareSide::from),
+                    right: right.map(CompareSide::from),
+                    differences,
+                });
+            }
+        }
+
+        Self { rows }
+    }
+
+    /// Number of rows with the given status
+    pub fn count(&self, status: CompareStatus) -> usize {
+        self.rows.iter().filter(|row| row.status == status).count()
+    }
+}
+
+/// UI state for the Compare Panes view of a split Explorer tab
+#[derive(Debug)]
+pub struct PaneCompareView {
+    /// Whether the tab shows the comparison instead of the two panes
+    pub active: bool,
+    pub match_by: CompareMatchBy,
+    /// Statuses hidden from the table (identical resources start hidden)
+    pub hidden_statuses: HashSet<CompareStatus>,
+    /// Last computed comparison (None forces a recompute)
+    pub comparison: Option<PaneComparison>,
+    /// Resource counts of (left, right) when the comparison was computed
+    pub compared_counts: (usize, usize),
+}
+
+impl Default for PaneCompareView {
+    fn default() -> Self {
+        Self {
+            active: false,
+            match_by: CompareMatchBy::default(),
+            hidden_statuses: HashSet::from([CompareStatus::Same]),
+            comparison: None,
+            compared_counts: (0, 0),
+        }
+    }
+}
+
+impl PaneCompareView {
+    /// Drop the cached comparison so it is recomputed on the next frame
+    pub fn invalidate(&mut self) {
+        self.comparison = None;
+    }
+}
+
+fn group_resources(
+    resources: &[ResourceEntry],
+    match_by: CompareMatchBy,
+) -> BTreeMap<(String, String), Vec<&ResourceEntry>> {
+    let mut groups: BTreeMap<(String, String), Vec<&ResourceEntry>> = BTreeMap::new();
+    for resource in resources {
+        groups
+            .entry((resource.resource_type.clone(), match_by.key_of(resource)))
+            .or_default()
+            .push(resource);
+    }
+    for group in groups.values_mut() {
+        group.sort_by(|a, b| {
+            (&a.account_id, &a.region, &a.resource_id).cmp(&(
+                &b.account_id,
+                &b.region,
+                &b.resource_id,
+            ))
+        });
+    }
+    groups
+}
+
+/// Status and key property values that differ between two resources
+fn property_differences(left: &ResourceEntry, right: &ResourceEntry) -> Vec<PropertyDifference> {
+    let mut differences = Vec::new();
+    if left.status != right.status {
+        differences.push(PropertyDifference {
+            path: "Status".to_string(),
+            left: left.status.clone(),
+            right: right.status.clone(),
+        });
+    }
+
+    let left_values = key_property_values(&left.properties);
+    let right_values = key_property_values(&right.properties);
+    let paths: BTreeSet<&String> = left_values.keys().chain(right_values.keys()).collect();
+    for path in paths {
+        let l = left_values.get(path);
+        let r = right_values.get(path);
+        if l != r {
+            differences.push(PropertyDifference {
+                path: path.clone(),
+                left: l.cloned(),
+                right: r.cloned(),
+            });
+        }
+    }
+    differences
+}
+
+/// Flatten properties to dot paths, skipping identifying fields
+///
+/// Arrays are compared as JSON text.
+fn key_property_values(properties: &Value) -> BTreeMap<String, String> {
+    let mut values = BTreeMap::new();
+    collect_key_properties("", properties, &mut values);
+    values
+}
+
+fn collect_key_properties(prefix: &str, value: &Value, values: &mut BTreeMap<String, String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                if is_identifying_property(key) {
+                    continue;
+                }
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                collect_key_properties(&path, child, values);
+            }
+        }
+        Value::Null => {}
+        _ if prefix.is_empty() => {}
+        Value::String(s) => {
+            values.insert(prefix.to_string(), s.clone());
+        }
+        other => {
+            values.insert(prefix.to_string(), other.to_string());
+        }
+    }
+}
+
+fn is_identifying_property(key: &str) -> bool {
+    let lower = key.to_lowercase();
+    IGNORED_PROPERTY_SUFFIXES
+        .iter()
+        .any(|suffix| lower.ends_with(suffix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn resource(account: &str, name: &str, id: &str, properties: Value) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: "AWS::EC2::Instance".to_string(),
+            account_id: account.to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: id.to_string(),
+            display_name: name.to_string(),
+            status: Some("running".to_string()),
+            properties,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_compare_by_name_across_accounts() {
+        let left = vec![
+            resource(
+                "111",
+                "web",
+                "i-1",
+                json!({"InstanceType": "m5.large", "InstanceId": "i-1"}),
+            ),
+            resource("111", "db", "i-2", json!({"InstanceType": "r5.large"})),
+            resource("111", "bastion", "i-3", json!({})),
+        ];
+        let right = vec![
+            resource(
+                "222",
+                "web",
+                "i-9",
+                json!({"InstanceType": "t3.small", "InstanceId": "i-9"}),
+            ),
+            resource("222", "db", "i-8", json!({"InstanceType": "r5.large"})),
+            resource("222", "batch", "i-7", json!({})),
+        ];
+
+        let comparison = PaneComparison::between(&left, &right, CompareMatchBy::Name);
+        assert_eq!(comparison.count(CompareStatus::OnlyLeft), 1);
+        assert_eq!(comparison.count(CompareStatus::OnlyRight), 1);
+        assert_eq!(comparison.count(CompareStatus::Same), 1);
+        assert_eq!(comparison.count(CompareStatus::Different), 1);
+
+        let web = comparison
+            .rows
+            .iter()
+            .find(|row| row.match_key == "web")
+            .unwrap();
+        assert_eq!(
+            web.differences,
+            vec![PropertyDifference {
+                path: "InstanceType".to_string(),
+                left: Some("m5.large".to_string()),
+                right: Some("t3.small".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_keys_pair_in_order() {
+        let left = vec![resource("111", "web", "i-1", json!({}))];
+        let right = vec![
+            resource("222", "web", "i-1", json!({})),
+            resource("222", "web", "i-2", json!({})),
+        ];
+
+        let comparison = PaneComparison::between(&left, &right, CompareMatchBy::Name);
+        assert_eq!(comparison.rows.len(), 2);
+        assert_eq!(comparison.count(CompareStatus::Same), 1);
+        assert_eq!(comparison.count(CompareStatus::OnlyRight), 1);
+    }
+
+    #[test]
+    fn test_identifying_properties_ignored() {
+        let values = key_property_values(&json!({
+            "Arn": "arn:aws:ec2:...",
+            "VpcId": "vpc-1",
+            "LaunchTime": "2024-01-01",
+            "State": {"Name": "running", "Code": 16},
+            "Tags": [],
+        }));
+        assert_eq!(
+            values.keys().cloned().collect::<Vec<_>>(),
+            vec!["State.Code", "State.Name", "Tags"]
+        );
+    }
+}
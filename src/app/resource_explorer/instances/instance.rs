@@ -6,11 +6,14 @@
 //! - Its own window position and size
 //! - Open/close state
 
+use super::compare::PaneCompareView;
 use super::manager::ExplorerSharedContext;
 use super::pane::ExplorerPane;
-use super::pane_renderer::PaneAction;
+use super::pane_renderer::{PaneAction, PaneRenderer, WorkspaceTarget};
+use crate::app::dashui::dock_layout::dock_window;
 use crate::app::dashui::window_focus::FocusableWindow;
-use crate::app::resource_explorer::bookmarks::BookmarkFolder;
+use crate::app::resource_explorer::bookmarks::{BookmarkFolder, Workspace};
+use crate::app::resource_explorer::resource_finder::RevealRequest;
 use egui::{Context, Ui, Window};
 use std::collections::HashSet;
 use uuid::Uuid;
@@ -58,6 +61,8 @@ pub struct ExplorerInstance {
     pub right_pane: Option<ExplorerPane>,
     /// Whether to show the right pane
     pub show_right_pane: bool,
+    /// Compare Panes mode (diff of left and right pane resources)
+    pub compare_view: PaneCompareView,
     /// Whether the window is open (visible)
     pub is_open: bool,
     /// Window title (e.g., "Explorer 1", "Explorer 2")
@@ -66,6 +71,10 @@ pub struct ExplorerInstance {
     instance_number: usize,
     /// Cached static window ID (leaked string for FocusableWindow trait)
     window_id_static: Option<&'static str>,
+    /// Workspace IDs to open in new windows, taken by the manager
+    pending_workspace_windows: Vec<String>,
+    /// Tile the window is pinned to while docked, set by the app each frame
+    pub dock_rect: Option<egui::Rect>,
 
     // ========================================================================
     // Bookmark Manager Dialog State
@@ -119,10 +128,13 @@ impl ExplorerInstance {
             left_pane: ExplorerPane::new(),
             right_pane: None,
             show_right_pane: false,
+            compare_view: PaneCompareView::default(),
             is_open: true,
             title,
             instance_number,
             window_id_static: None,
+            pending_workspace_windows: Vec::new(),
+            dock_rect: None,
 
             // Bookmark manager dialog state
             show_bookmark_edit_dialog: false,
@@ -163,12 +175,94 @@ impl ExplorerInstance {
         }
     }
 
+    /// Load a workspace into the left pane (used for new windows)
+    pub fn open_workspace(
+        &mut self,
+        workspace: &Workspace,
+        ctx: &Context,
+        shared_context: &ExplorerSharedContext,
+    ) {
+        Self::apply_bookmark_to_pane(
+            &mut self.left_pane,
+            &workspace.to_bookmark(),
+            ctx,
+            shared_context,
+        );
+    }
+
+    /// Check whether a visible pane has loaded the resource a reveal request points to
+    pub fn contains_match(&self, request: &RevealRequest) -> bool {
+        self.left_pane.contains_match(request)
+            || (self.show_right_pane
+                && self
+                    .right_pane
+                    .as_ref()
+                    .is_some_and(|p| p.contains_match(request)))
+    }
+
+    /// Show a resource from Find Resource in one of this window's panes
+    ///
+    /// A pane that already loaded the resource focuses it. Otherwise the
+    /// resource's account, region, and type are added to the left pane's scope
+    /// and the pane re-queries before focusing it.
+    pub fn reveal_resource(&mut self, request: RevealRequest, shared_context: &ExplorerSharedContext) {
+        self.is_open = true;
+        if self.show_right_pane {
+            if let Some(pane) = self
+                .right_pane
+                .as_mut()
+                .filter(|p| p.contains_match(&request))
+            {
+                pane.reveal(request);
+                return;
+            }
+        }
+        if !self.left_pane.contains_match(&request) {
+            Self::add_to_pane_scope(&mut self.left_pane, &request, shared_context);
+        }
+        self.left_pane.reveal(request);
+    }
+
+    /// Take workspace IDs the panes asked to open in new windows
+    pub fn take_workspace_window_requests(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.pending_workspace_windows)
+    }
+
     /// Check if any pane has resources
     pub fn has_resources(&self) -> bool {
         self.left_pane.has_resources()
             || self.right_pane.as_ref().is_some_and(|p| p.has_resources())
     }
 
+    /// Pane that window-level commands act on
+    ///
+    /// The visible right pane when it has resources selected in its tree and
+    /// the left pane has none; otherwise the left pane.
+    pub fn current_pane(&self) -> &ExplorerPane {
+        match &self.right_pane {
+            Some(right) if self.right_pane_is_current(right) => right,
+            _ => &self.left_pane,
+        }
+    }
+
+    /// Mutable access to [`current_pane`](Self::current_pane)
+    pub fn current_pane_mut(&mut self) -> &mut ExplorerPane {
+        let right_is_current = self
+            .right_pane
+            .as_ref()
+            .is_some_and(|right| self.right_pane_is_current(right));
+        match &mut self.right_pane {
+            Some(right) if right_is_current => right,
+            _ => &mut self.left_pane,
+        }
+    }
+
+    fn right_pane_is_current(&self, right: &ExplorerPane) -> bool {
+        self.show_right_pane
+            && self.left_pane.renderer.tree_renderer.selected_resources.is_empty()
+            && !right.renderer.tree_renderer.selected_resources.is_empty()
+    }
+
     /// Check if any pane is loading
     pub fn is_loading(&self) -> bool {
         self.left_pane.is_loading()
@@ -195,10 +289,45 @@ impl ExplorerInstance {
             if ui.button(button_text).clicked() {
                 self.toggle_right_pane();
             }
+
+            if self.show_right_pane {
+                let compare_text = if self.compare_view.active {
+                    "Back to Panes"
+                } else {
+                    "Compare Panes"
+                };
+                if ui
+                    .button(compare_text)
+                    .on_hover_text("Diff the resources shown in the left and right panes")
+                    .clicked()
+                {
+                    self.compare_view.active = !self.compare_view.active;
+                    self.compare_view.invalidate();
+                }
+            }
         });
         ui.separator();
 
-        if self.show_right_pane {
+        if self.show_right_pane && self.compare_view.active {
+            if let Some(ref right_pane) = self.right_pane {
+                match (self.left_pane.state.try_read(), right_pane.state.try_read()) {
+                    (Ok(left), Ok(right)) => {
+                        PaneRenderer::render_compare_view(
+                            ui,
+                            &mut self.compare_view,
+                            &left,
+                            &right,
+                        );
+                    }
+                    _ => {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label("Waiting for panes to finish loading...");
+                        });
+                    }
+                }
+            }
+        } else if self.show_right_pane {
             // Split view: two panes side by side
             ui.columns(2, |columns| {
                 // Left pane
@@ -284,6 +413,7 @@ impl FocusableWindow for ExplorerInstance {
             .default_size([1200.0, 800.0])
             .resizable(true)
             .open(&mut is_open);
+        window = dock_window(window, self.dock_rect);
 
         if bring_to_front {
             window = window.order(egui::Order::Foreground);
@@ -316,6 +446,12 @@ impl FocusableWindow for ExplorerInstance {
             right_pane.execute_pending_query(ctx, &shared_context);
         }
 
+        // Drive scheduled background refreshes
+        self.left_pane.poll_auto_refresh(ctx, &shared_context);
+        if let Some(ref mut right_pane) = self.right_pane {
+            right_pane.poll_auto_refresh(ctx, &shared_context);
+        }
+
         // Write back the is_open state
         self.is_open = is_open;
     }
@@ -1208,6 +1344,54 @@ impl ExplorerInstance {
                     tracing::warn!("Bookmark not found: {}", bookmark_id);
                 }
             }
+            PaneAction::OpenWorkspace { workspace_id, target, source_pane_id } => {
+                let workspace = shared_context
+                    .bookmarks
+                    .read()
+                    .unwrap()
+                    .get_workspace(&workspace_id)
+                    .cloned();
+                let Some(workspace) = workspace else {
+                    tracing::warn!("Workspace not found: {}", workspace_id);
+                    return;
+                };
+
+                let source_is_right = self
+                    .right_pane
+                    .as_ref()
+                    .is_some_and(|right| right.id() == source_pane_id);
+                let target_pane = match target {
+                    WorkspaceTarget::NewWindow => {
+                        tracing::info!("Opening workspace '{}' in a new window", workspace.name);
+                        self.pending_workspace_windows.push(workspace_id);
+                        return;
+                    }
+                    WorkspaceTarget::ThisPane if source_is_right => self.right_pane.as_mut(),
+                    WorkspaceTarget::ThisPane => Some(&mut self.left_pane),
+                    WorkspaceTarget::OtherPane => {
+                        if !self.show_right_pane {
+                            self.toggle_right_pane();
+                        }
+                        self.compare_view.active = false;
+                        if source_is_right {
+                            Some(&mut self.left_pane)
+                        } else {
+                            self.right_pane.as_mut()
+                        }
+                    }
+                };
+
+                if let Some(pane) = target_pane {
+                    tracing::info!("Opening workspace '{}' in pane {}", workspace.name, pane.id());
+                    Self::apply_bookmark_to_pane(pane, &workspace.to_bookmark(), ctx, shared_context);
+                }
+
+                let mut bookmarks = shared_context.bookmarks.write().unwrap();
+                bookmarks.record_workspace_opened(&workspace_id);
+                if let Err(e) = bookmarks.save() {
+                    tracing::error!("Failed to save workspace access tracking: {}", e);
+                }
+            }
             PaneAction::RemoveAccount { account_id, source_pane_id } => {
                 // Validate pane ID and get target pane (strict validation - reject if not found)
                 let target_pane = if self.left_pane.id() == source_pane_id {
@@ -1354,9 +1538,7 @@ impl ExplorerInstance {
                 }
             }
             PaneAction::ShowFailedQueriesDialog => {
-                // TODO: Implement failed queries dialog for multi-pane architecture
-                // For now, log a warning
-                tracing::warn!("Failed queries dialog not yet implemented for multi-pane architecture");
+                // Handled by the pane itself (state.show_failure_report)
             }
         }
     }
@@ -1518,6 +1700,62 @@ impl ExplorerInstance {
         }
     }
 
+    /// Add a revealed resource's account, region, and type to a pane's query scope
+    fn add_to_pane_scope(
+        pane: &mut ExplorerPane,
+        request: &RevealRequest,
+        shared_context: &ExplorerSharedContext,
+    ) {
+        use crate::app::resource_explorer::dialogs::get_default_resource_types;
+        use crate::app::resource_explorer::state::{AccountSelection, RegionSelection};
+
+        let Ok(mut state) = pane.state.try_write() else {
+            tracing::warn!("Could not acquire write lock on pane state to reveal resource");
+            return;
+        };
+
+        let account_name = shared_context
+            .aws_identity_center
+            .as_ref()
+            .and_then(|identity_center| identity_center.lock().ok())
+            .and_then(|ic| {
+                ic.accounts
+                    .iter()
+                    .find(|a| a.account_id == request.account_id)
+                    .map(|a| a.account_name.clone())
+            })
+            .unwrap_or_else(|| request.account_id.clone());
+        state.add_account(AccountSelection::new(request.account_id.clone(), account_name));
+
+        // Global resources are loaded with any region selected
+        let region_code = if request.region == "Global" {
+            state
+                .query_scope
+                .regions
+                .first()
+                .map(|r| r.region_code.clone())
+                .unwrap_or_else(|| "us-east-1".to_string())
+        } else {
+            request.region.clone()
+        };
+        let display_name = Self::format_region_display_name(&region_code);
+        state.add_region(RegionSelection::new(region_code, display_name));
+
+        match get_default_resource_types()
+            .into_iter()
+            .find(|rt| rt.resource_type == request.resource_type)
+        {
+            Some(resource_type) => state.add_resource_type(resource_type),
+            None => tracing::warn!(
+                "Resource type {} not available in the Explorer",
+                request.resource_type
+            ),
+        }
+
+        drop(state);
+        pane.mark_pending_query();
+    }
+
     /// Format region code into human-readable display name
     fn format_region_display_name(region_code: &str) -> String {
         // Special case for global
@@ -7,8 +7,11 @@
 
 use super::instance::ExplorerInstance;
 use crate::app::aws_identity::AwsIdentityCenter;
+use crate::app::dashui::{ElementAction, NavigableElement};
 use crate::app::resource_explorer::bookmarks::BookmarkManager;
 use crate::app::resource_explorer::cache::SharedResourceCache;
+use crate::app::resource_explorer::hint_targets;
+use crate::app::resource_explorer::resource_finder::{FindScope, RevealRequest};
 use crate::app::resource_explorer::AWSResourceClient;
 use std::sync::{Arc, Mutex, RwLock as StdRwLock};
 use uuid::Uuid;
@@ -28,6 +31,10 @@ pub struct ExplorerSharedContext {
     pub console_role_menu_updates: Arc<Mutex<Vec<crate::app::resource_explorer::ConsoleRoleMenuUpdate>>>,
     /// Modular query engine (created when AWS client is available)
     pub query_engine: Option<Arc<super::super::ResourceQueryEngine>>,
+    /// Compute Optimizer and Trusted Advisor findings for resource badges
+    /// (set by the recommendations report; None hides the badges)
+    pub recommendation_index:
+        Option<Arc<crate::app::resource_explorer::recommendation_badges::RecommendationIndex>>,
 }
 
 impl ExplorerSharedContext {
@@ -44,6 +51,7 @@ impl ExplorerSharedContext {
             aws_identity_center: None,
             console_role_menu_updates: Arc::new(Mutex::new(Vec::new())),
             query_engine: None,
+            recommendation_index: None,
         }
     }
 
@@ -139,6 +147,91 @@ impl ExplorerManager {
         self.instances.last_mut().unwrap()
     }
 
+    /// Open new windows for workspaces the panes asked to open
+    ///
+    /// Called once per frame after the instances were rendered.
+    pub fn open_pending_workspace_windows(&mut self, ctx: &egui::Context) {
+        let requests: Vec<String> = self
+            .instances
+            .iter_mut()
+            .flat_map(|instance| instance.take_workspace_window_requests())
+            .collect();
+
+        for workspace_id in requests {
+            let workspace = self
+                .shared_context
+                .bookmarks
+                .read()
+                .unwrap()
+                .get_workspace(&workspace_id)
+                .cloned();
+            let Some(workspace) = workspace else {
+                tracing::warn!("Workspace not found: {}", workspace_id);
+                continue;
+            };
+
+            let shared_context = self.shared_context.clone();
+            let instance = self.open_new_window();
+            instance.title = format!("{} - {}", instance.title, workspace.name);
+            instance.open_workspace(&workspace, ctx, &shared_context);
+
+            let mut bookmarks = shared_context.bookmarks.write().unwrap();
+            bookmarks.record_workspace_opened(&workspace_id);
+            if let Err(e) = bookmarks.save() {
+                tracing::error!("Failed to save workspace access tracking: {}", e);
+            }
+        }
+    }
+
+    /// Accounts and regions selected in any pane, searched by Find Resource
+    pub fn selected_scope(&self) -> FindScope {
+        let mut scope = FindScope::default();
+        let panes = self
+            .instances
+            .iter()
+            .flat_map(|i| std::iter::once(&i.left_pane).chain(i.right_pane.as_ref()));
+        for pane in panes {
+            let Ok(state) = pane.state.try_read() else {
+                continue;
+            };
+            for account in &state.query_scope.accounts {
+                if !scope.accounts.contains(&account.account_id) {
+                    scope.accounts.push(account.account_id.clone());
+                }
+            }
+            for region in &state.query_scope.regions {
+                if !scope.regions.contains(&region.region_code) {
+                    scope.regions.push(region.region_code.clone());
+                }
+            }
+        }
+        scope
+    }
+
+    /// Show a resource from Find Resource and return the window showing it
+    ///
+    /// Prefers a window that already loaded the resource, then the focused
+    /// window, then opens a new one.
+    pub fn reveal_resource(&mut self, request: RevealRequest) -> Uuid {
+        let shared_context = self.shared_context.clone();
+        let target = self
+            .instances
+            .iter()
+            .find(|i| i.contains_match(&request))
+            .map(|i| i.id())
+            .or(self.focused_instance_id)
+            .filter(|id| self.instances.iter().any(|i| i.id() == *id));
+        let instance = match target {
+            Some(id) => {
+                self.focused_instance_id = Some(id);
+                self.instances.iter_mut().find(|i| i.id() == id).unwrap()
+            }
+            None => self.open_new_window(),
+        };
+        instance.reveal_resource(request, &shared_context);
+        instance.id()
+    }
+
     /// Close an Explorer window by ID
     pub fn close_window(&mut self, id: Uuid) {
         if let Some(pos) = self.instances.iter().position(|i| i.id() == id) {
@@ -244,4 +337,64 @@ impl ExplorerManager {
 
         actions
     }
+
+    /// Take the hint targets every pane's tree registered while rendering
+    ///
+    /// Element IDs are scoped to their pane so the hint picked from them can be
+    /// routed back with [`activate_hint`](Self::activate_hint).
+    pub fn take_hint_elements(&mut self) -> Vec<NavigableElement> {
+        let panes = self
+            .instances
+            .iter_mut()
+            .flat_map(|i| std::iter::once(&mut i.left_pane).chain(i.right_pane.as_mut()));
+        let mut elements = Vec::new();
+        for pane in panes {
+            let pane_id = pane.id;
+            elements.extend(
+                pane.renderer
+                    .tree_renderer
+                    .hints
+                    .take_elements()
+                    .into_iter()
+                    .map(|mut element| {
+                        element.id = hint_targets::element_id(pane_id, &element.id);
+                        element
+                    }),
+            );
+        }
+        elements
+    }
+
+    /// Drop the hint targets of an instance shown outside the main window
+    pub fn discard_hint_elements(&mut self, instance_id: Uuid) {
+        if let Some(instance) = self.instances.iter_mut().find(|i| i.id() == instance_id) {
+            let panes =
+                std::iter::once(&mut instance.left_pane).chain(instance.right_pane.as_mut());
+            for pane in panes {
+                pane.renderer.tree_renderer.hints.take_elements();
+            }
+        }
+    }
+
+    /// Hand a hint picked in hint mode to the tree widget it was generated for
+    ///
+    /// The widget acts on its next render. Returns false when the element ID is
+    /// not an Explorer target or its pane has been closed.
+    pub fn activate_hint(&mut self, element_id: &str, action: ElementAction) -> bool {
+        let Some((pane_id, local_id)) = hint_targets::parse_element_id(element_id) else {
+            return false;
+        };
+        let pane = self
+            .instances
+            .iter_mut()
+            .flat_map(|i| std::iter::once(&mut i.left_pane).chain(i.right_pane.as_mut()))
+            .find(|pane| pane.id == pane_id);
+        match pane {
+            Some(pane) => {
+                pane.renderer.tree_renderer.hints.activate(local_id, action);
+                true
+            }
+            None => false,
+        }
+    }
 }
@@ -6,12 +6,17 @@
 //! - Independent state per pane: Own selections, queries, tree view, filters
 //! - Shared resources: Global bookmarks, shared Moka cache, and query engine
 
+pub mod auto_refresh;
+pub mod commands;
+pub mod compare;
 pub mod instance;
 pub mod manager;
 pub mod pane;
 pub mod pane_renderer;
 
+pub use auto_refresh::{AutoRefreshInterval, AutoRefreshSchedule, ResourceChangeSummary};
+pub use compare::{CompareMatchBy, CompareStatus, PaneCompareView, PaneComparison};
 pub use instance::ExplorerInstance;
 pub use manager::{ExplorerManager, ExplorerSharedContext};
 pub use pane::ExplorerPane;
-pub use pane_renderer::{PaneAction, PaneRenderer};
+pub use pane_renderer::{PaneAction, PaneRenderer, WorkspaceTarget};
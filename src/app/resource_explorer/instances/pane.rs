@@ -6,14 +6,27 @@
 //! - Navigate the tree view
 //! - Apply bookmarks
 
+use super::auto_refresh::{
+    AutoRefreshInterval, AutoRefreshSchedule, ResourceChangeSummary, ResourceSnapshot,
+};
 use super::pane_renderer::{PaneAction, PaneRenderer};
-use crate::app::resource_explorer::dialogs::FuzzySearchDialog;
-use crate::app::resource_explorer::state::ResourceExplorerState;
+use crate::app::resource_explorer::dialogs::{FuzzySearchDialog, TagEditorDialog};
+use crate::app::resource_explorer::export::{visible_resources, ExportDialog};
+use crate::app::resource_explorer::failure_report::{
+    FailureReport, FailureReportAction, FailureReportPanel,
+};
+use crate::app::resource_explorer::reachability::ReachabilityWindow;
+use crate::app::resource_explorer::resource_actions::ResourceActionDialog;
+use crate::app::resource_explorer::resource_finder::RevealRequest;
+use crate::app::resource_explorer::state::{ResourceExplorerState, TagChanges};
 use egui::{Context, Ui};
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use uuid::Uuid;
 
+/// How long a revealed resource may take to load before the pane gives up
+const REVEAL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(120);
+
 /// A single explorer pane with independent state
 pub struct ExplorerPane {
     /// Unique identifier for this pane
@@ -25,10 +38,26 @@ pub struct ExplorerPane {
     pub renderer: PaneRenderer,
     /// Fuzzy search dialog for account/region/resource type selection
     pub fuzzy_dialog: FuzzySearchDialog,
+    /// Export dialog for the visible tree
+    pub export_dialog: ExportDialog,
+    /// Failed queries of the last load, grouped by account/region/type
+    pub failure_panel: FailureReportPanel,
+    /// Tag editor for the resource(s) picked in the tree
+    pub tag_editor: TagEditorDialog,
+    /// Dry-run preview and confirmation for resource actions
+    pub resource_action_dialog: ResourceActionDialog,
+    /// Network reachability between two picked resources
+    pub reachability_window: ReachabilityWindow,
     /// UI state: scroll offset in the tree view
     pub scroll_offset: f32,
     /// UI state: currently selected resource ARN
     pub selected_resource: Option<String>,
+    /// Scheduled background refresh of this pane's query scope
+    pub auto_refresh: AutoRefreshSchedule,
+    /// Notifications raised by scheduled refreshes, handed to the app
+    pending_notifications: Vec<crate::app::resource_explorer::ResourceExplorerAction>,
+    /// Resource from Find Resource to focus once it is loaded, and when it was requested
+    pending_reveal: Option<(RevealRequest, std::time::Instant)>,
 
     // Dialog flags (local to avoid borrow conflicts)
     show_refresh_dialog: bool,
@@ -56,8 +85,16 @@ impl ExplorerPane {
             state: Arc::new(RwLock::new(ResourceExplorerState::new())),
             renderer: PaneRenderer::new(),
             fuzzy_dialog: FuzzySearchDialog::new(),
+            export_dialog: ExportDialog::new(),
+            failure_panel: FailureReportPanel::new(),
+            tag_editor: TagEditorDialog::new(),
+            resource_action_dialog: ResourceActionDialog::new(),
+            reachability_window: ReachabilityWindow::new(),
             scroll_offset: 0.0,
             selected_resource: None,
+            auto_refresh: AutoRefreshSchedule::new(),
+            pending_notifications: Vec::new(),
+            pending_reveal: None,
             show_refresh_dialog: false,
             show_bookmark_dialog: false,
             show_bookmark_manager: false,
@@ -107,6 +144,19 @@ impl ExplorerPane {
             .unwrap_or(0)
     }
 
+    /// Check whether the pane has loaded the resource a reveal request points to
+    pub fn contains_match(&self, request: &RevealRequest) -> bool {
+        self.state
+            .try_read()
+            .map(|s| s.resources.iter().any(|r| request.matches(r)))
+            .unwrap_or(false)
+    }
+
+    /// Focus a resource in the tree, waiting for it to load if needed
+    pub fn reveal(&mut self, request: RevealRequest) {
+        self.pending_reveal = Some((request, std::time::Instant::now()));
+    }
+
     /// Clear the pane state (like terminate, but preserves pane identity)
     pub fn clear(&mut self) {
         if let Ok(mut state) = self.state.try_write() {
@@ -114,8 +164,14 @@ impl ExplorerPane {
         }
         self.renderer.reset();
         self.fuzzy_dialog = FuzzySearchDialog::new();
+        self.export_dialog = ExportDialog::new();
+        self.failure_panel = FailureReportPanel::new();
+        self.reachability_window = ReachabilityWindow::new();
         self.scroll_offset = 0.0;
         self.selected_resource = None;
+        self.auto_refresh = AutoRefreshSchedule::new();
+        self.pending_notifications.clear();
+        self.pending_reveal = None;
         self.show_refresh_dialog = false;
         self.show_bookmark_dialog = false;
         self.show_bookmark_manager = false;
@@ -139,6 +195,8 @@ impl ExplorerPane {
         ui: &mut Ui,
         shared_context: &super::manager::ExplorerSharedContext,
     ) -> Vec<PaneAction> {
+        self.render_auto_refresh_bar(ui);
+
         // Try to acquire write lock on state (non-blocking to avoid UI freeze)
         if let Ok(mut state) = self.state.try_write() {
             // Pass pane ID and shared context to renderer for unique widget IDs and bookmarks access
@@ -160,7 +218,133 @@ impl ExplorerPane {
     pub fn take_pending_actions(
         &mut self,
     ) -> Vec<crate::app::resource_explorer::ResourceExplorerAction> {
-        self.renderer.take_pending_actions()
+        let mut actions = self.renderer.take_pending_actions();
+        actions.append(&mut self.pending_notifications);
+        actions
+    }
+
+    /// Render the auto-refresh interval selector and last-run summary
+    fn render_auto_refresh_bar(&mut self, ui: &mut Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Auto-refresh:");
+            let mut interval = self.auto_refresh.interval();
+            egui::ComboBox::from_id_salt(format!("auto_refresh_{}", self.id))
+                .selected_text(interval.label())
+                .show_ui(ui, |ui| {
+                    for candidate in AutoRefreshInterval::all() {
+                        ui.selectable_value(&mut interval, candidate, candidate.label());
+                    }
+                });
+            if interval != self.auto_refresh.interval() {
+                self.auto_refresh.set_interval(interval, std::time::Instant::now());
+            }
+
+            if self.auto_refresh.is_running() {
+                ui.spinner();
+                ui.label("Refreshing...");
+            } else if let Some(remaining) =
+                self.auto_refresh.time_until_due(std::time::Instant::now())
+            {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "next in {} min",
+                        remaining.as_secs().div_ceil(60)
+                    ))
+                    .small()
+                    .weak(),
+                );
+            }
+
+            if let Some(summary) = &self.auto_refresh.last_summary {
+                ui.label(egui::RichText::new(summary).small().weak());
+            }
+        });
+    }
+
+    /// Drive the auto-refresh schedule (called once per frame after rendering)
+    ///
+    /// Starts a run when one is due: snapshots the visible resources, drops the
+    /// scope's cached results so the query re-fetches from AWS, and triggers the
+    /// pane query. Once that query finishes, compares the new resources with
+    /// the snapshot and queues a notification describing what changed.
+    pub fn poll_auto_refresh(
+        &mut self,
+        ctx: &Context,
+        shared_context: &super::manager::ExplorerSharedContext,
+    ) {
+        let now = std::time::Instant::now();
+
+        if self.auto_refresh.is_running() {
+            let summary = match self.state.try_read() {
+                Ok(state) if !state.is_loading() => {
+                    let after = ResourceSnapshot::from_resources(&state.resources);
+                    self.auto_refresh.finish_run().map(|before| {
+                        ResourceChangeSummary::between(
+                            &before,
+                            &after,
+                            &state.query_scope.resource_types,
+                        )
+                    })
+                }
+                _ => {
+                    ctx.request_repaint_after(std::time::Duration::from_secs(1));
+                    return;
+                }
+            };
+
+            if let Some(summary) = summary {
+                self.report_auto_refresh(&summary);
+            }
+        } else if self.auto_refresh.is_due(now) {
+            let baseline = match self.state.try_write() {
+                Ok(mut state) if !state.query_scope.is_empty() && !state.is_loading() => {
+                    evict_scope_from_cache(&mut state, shared_context);
+                    Some(ResourceSnapshot::from_resources(&state.resources))
+                }
+                Ok(_) => {
+                    // Nothing to refresh yet (or busy); try again next interval
+                    self.auto_refresh.skip_run(now);
+                    None
+                }
+                Err(_) => None,
+            };
+
+            if let Some(baseline) = baseline {
+                tracing::info!("Pane {}: Starting scheduled refresh", self.id);
+                self.auto_refresh.start_run(baseline, now);
+                self.trigger_query_if_ready(ctx, shared_context);
+            }
+        }
+
+        if let Some(remaining) = self.auto_refresh.time_until_due(now) {
+            ctx.request_repaint_after(remaining);
+        }
+    }
+
+    fn report_auto_refresh(&mut self, summary: &ResourceChangeSummary) {
+        let time = chrono::Local::now().format("%H:%M");
+        if summary.is_empty() {
+            self.auto_refresh.last_summary = Some(format!("No changes at {}", time));
+            return;
+        }
+
+        self.auto_refresh.last_summary = Some(format!("{} at {}", summary.headline(), time));
+
+        let mut notification = crate::app::notifications::Notification::new_info(
+            format!("explorer_auto_refresh_{}", self.id),
+            format!("Explorer: {}", summary.headline()),
+            summary.messages().join("\n"),
+            "Explorer Auto-Refresh".to_string(),
+        )
+        .with_related_window(crate::app::notifications::RelatedWindow::Explorer);
+        // Background changes stay visible until dismissed
+        notification.expires_at = None;
+
+        self.pending_notifications.push(
+            crate::app::resource_explorer::ResourceExplorerAction::ShowNotification {
+                notification,
+            },
+        );
     }
 
     /// Render all dialogs for this pane
@@ -259,9 +443,468 @@ impl ExplorerPane {
             }
         }
 
+        // "Refresh Changed" is a one-shot request rather than a dialog
+        let delta_refresh_requested = self
+            .state
+            .try_write()
+            .map(|mut state| std::mem::take(&mut state.delta_refresh_requested))
+            .unwrap_or(false);
+        if delta_refresh_requested {
+            self.trigger_delta_refresh(ctx, shared_context);
+        }
+
+        let cost_fetch_requested = self
+            .state
+            .try_write()
+            .map(|mut state| std::mem::take(&mut state.cost_fetch_requested))
+            .unwrap_or(false);
+        if cost_fetch_requested {
+            self.trigger_cost_fetch(ctx, shared_context);
+        }
+
+        // Export dialog works on a snapshot of what the tree shows right now
+        if let Ok(mut state) = self.state.try_write() {
+            if std::mem::take(&mut state.show_export_dialog) {
+                self.export_dialog.open_for(visible_resources(&state));
+            }
+        }
+        self.export_dialog.show(ctx, self.id);
+
+        // Failure report works on a snapshot of the failed queries and their errors
+        if let Ok(mut state) = self.state.try_write() {
+            if std::mem::take(&mut state.show_failure_report) {
+                self.failure_panel.open_for(FailureReport::new(
+                    &state.phase1_failed_queries,
+                    &state.phase1_query_errors,
+                    state.phase1_total_queries,
+                ));
+            }
+        }
+        if let Some(FailureReportAction::Dismiss) = self.failure_panel.show(ctx, self.id) {
+            if let Ok(mut state) = self.state.try_write() {
+                state.clear_phase1_failures();
+            }
+        }
+
+        // Tag editor opens on a snapshot of the resources picked in the tree
+        if let Some(keys) = self.renderer.tree_renderer.pending_tag_edit.take() {
+            match self.state.try_read() {
+                Ok(state) => {
+                    let keys: std::collections::HashSet<String> = keys.into_iter().collect();
+                    let targets: Vec<_> = state
+                        .resources
+                        .iter()
+                        .filter(|resource| keys.contains(&resource.selection_key()))
+                        .cloned()
+                        .collect();
+                    if !targets.is_empty() {
+                        self.tag_editor.open_for(targets);
+                    }
+                }
+                Err(_) => {
+                    // State is busy; try again next frame
+                    self.renderer.tree_renderer.pending_tag_edit = Some(keys);
+                }
+            }
+        }
+        if let Some(changes) = self.tag_editor.show(ctx, self.id) {
+            self.trigger_tag_update(ctx, shared_context, changes);
+        }
+
+        // Resource actions preview against a snapshot of the picked resources
+        if let Some((action_id, keys)) = self.renderer.tree_renderer.pending_resource_action.take() {
+            match (self.state.try_read(), shared_context.aws_client.clone()) {
+                (Ok(state), Some(aws_client)) => {
+                    let keys: std::collections::HashSet<String> = keys.into_iter().collect();
+                    let targets: Vec<_> = state
+                        .resources
+                        .iter()
+                        .filter(|resource| keys.contains(&resource.selection_key()))
+                        .cloned()
+                        .collect();
+                    if !targets.is_empty() {
+                        self.resource_action_dialog
+                            .open_for(&action_id, targets, aws_client);
+                    }
+                }
+                (Ok(_), None) => {
+                    tracing::warn!(
+                        "Pane {}: Cannot run {} - AWS client not configured",
+                        self.id,
+                        action_id
+                    );
+                }
+                (Err(_), _) => {
+                    // State is busy; try again next frame
+                    self.renderer.tree_renderer.pending_resource_action = Some((action_id, keys));
+                }
+            }
+        }
+        if let Some(completed) = self.resource_action_dialog.show(ctx, self.id) {
+            if let Some(status) = completed.status_after {
+                let keys: std::collections::HashSet<String> =
+                    completed.selection_keys.into_iter().collect();
+                match self.state.try_write() {
+                    Ok(mut state) => state.set_resource_status(&keys, status),
+                    Err(_) => tracing::warn!(
+                        "Pane {}: State busy, resource status not updated",
+                        self.id
+                    ),
+                }
+            }
+        }
+
+        // Reachability works on the two picked resources plus the pane's network resources
+        if let Some((source_key, destination_key)) =
+            self.renderer.tree_renderer.pending_reachability.take()
+        {
+            match self.state.try_read() {
+                Ok(state) => {
+                    let find = |key: &str| {
+                        state
+                            .resources
+                            .iter()
+                            .find(|resource| resource.selection_key() == key)
+                            .cloned()
+                    };
+                    if let (Some(source), Some(destination)) =
+                        (find(&source_key), find(&destination_key))
+                    {
+                        self.reachability_window
+                            .open_for(source, destination, &state.resources);
+                    }
+                }
+                Err(_) => {
+                    // State is busy; try again next frame
+                    self.renderer.tree_renderer.pending_reachability =
+                        Some((source_key, destination_key));
+                }
+            }
+        }
+        self.reachability_window.show(ctx, self.id);
+
+        // Focus a resource from Find Resource once the pane has loaded it
+        if let Some((request, requested_at)) = self.pending_reveal.take() {
+            if requested_at.elapsed() > REVEAL_TIMEOUT {
+                tracing::warn!(
+                    "Pane {}: {} not loaded, giving up on revealing it",
+                    self.id,
+                    request.query.describe()
+                );
+            } else {
+                let found = self.state.try_write().ok().and_then(|mut state| {
+                    let key = state
+                        .resources
+                        .iter()
+                        .find(|r| request.matches(r))
+                        .map(|r| r.selection_key())?;
+                    // The search filter could hide the resource
+                    state.search_filter.clear();
+                    Some(key)
+                });
+                match found {
+                    Some(key) => self.renderer.tree_renderer.focus_resource(key),
+                    None => {
+                        self.pending_reveal = Some((request, requested_at));
+                        ctx.request_repaint_after(std::time::Duration::from_millis(500));
+                    }
+                }
+            }
+        }
+
         // TODO: Add other dialogs (refresh, bookmark, etc.)
     }
 
+    /// Re-query only the cached results that changed since they were fetched
+    ///
+    /// Drops changed cache keys using CloudTrail write events, then runs the
+    /// normal pane query, which re-fetches the dropped keys and serves the
+    /// rest from cache.
+    fn trigger_delta_refresh(
+        &self,
+        ctx: &Context,
+        shared_context: &super::manager::ExplorerSharedContext,
+    ) {
+        let (Some(aws_client), Some(query_engine)) = (
+            shared_context.aws_client.clone(),
+            shared_context.query_engine.clone(),
+        ) else {
+            tracing::warn!("Pane {}: Delta refresh unavailable - AWS client not configured", self.id);
+            return;
+        };
+
+        let scope = match self.state.try_read() {
+            Ok(state) if !state.query_scope.is_empty() && !state.is_loading() => {
+                state.query_scope.clone()
+            }
+            _ => return,
+        };
+
+        let cache_key = if let Ok(mut state) = self.state.try_write() {
+            state.start_loading_task(&format!("pane_{}_delta_refresh", self.id))
+        } else {
+            return;
+        };
+
+        let state_arc = self.state.clone();
+        let cache = shared_context.cache.clone();
+        let ctx = ctx.clone();
+        let pane_id = self.id;
+
+        ctx.request_repaint_after(std::time::Duration::from_millis(50));
+
+        std::thread::spawn(move || {
+            let runtime = match tokio::runtime::Runtime::new() {
+                Ok(rt) => rt,
+                Err(e) => {
+                    tracing::error!("Pane {}: Failed to create Tokio runtime: {}", pane_id, e);
+                    if let Ok(mut state) = state_arc.try_write() {
+                        state.loading_tasks.remove(&cache_key);
+                    }
+                    return;
+                }
+            };
+
+            let plan = runtime.block_on(aws_client.invalidate_changed_resources(&scope, &cache));
+            tracing::info!(
+                "Pane {}: Delta refresh re-fetching {} of {} cached queries",
+                pane_id,
+                plan.stale_keys.len(),
+                plan.total()
+            );
+
+            // Hand over from the delta check to the regular query task
+            for attempt in 0..30 {
+                if let Ok(mut state) = state_arc.try_write() {
+                    for key in &plan.stale_keys {
+                        state.cached_queries.remove(key);
+                    }
+                    state.loading_tasks.remove(&cache_key);
+                    break;
+                } else if attempt < 29 {
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                }
+            }
+
+            let query_key = format!("pane_{}_query_{}", pane_id, chrono::Utc::now().timestamp_millis());
+            if let Ok(mut state) = state_arc.try_write() {
+                state.loading_tasks.insert(query_key.clone());
+            }
+            let ui_adapter = crate::app::resource_explorer::UIQueryAdapter::new(query_engine);
+            ui_adapter.execute_for_pane(state_arc, scope, query_key, ctx);
+        });
+    }
+
+    /// Fetch Cost Explorer data for the accounts in scope and attach cost badges
+    ///
+    /// Each account is queried with its own credentials, filtered to that
+    /// account and grouped by region and service.
+    fn trigger_cost_fetch(
+        &self,
+        ctx: &Context,
+        shared_context: &super::manager::ExplorerSharedContext,
+    ) {
+        use crate::app::data_plane::cost_explorer::{CostExplorerClient, CostQueryOptions};
+        use crate::app::resource_explorer::cost_badges::{CostIndex, COST_BADGE_GROUP_BY};
+        use crate::app::resource_explorer::status::{report_status, report_status_done};
+
+        let Some(aws_client) = shared_context.aws_client.clone() else {
+            tracing::warn!("Pane {}: Cost data unavailable - AWS client not configured", self.id);
+            return;
+        };
+
+        let account_ids: Vec<String> = match self.state.try_write() {
+            Ok(mut state) if !state.cost_loading => {
+                state.cost_loading = true;
+                state
+                    .query_scope
+                    .accounts
+                    .iter()
+                    .map(|a| a.account_id.clone())
+                    .collect()
+            }
+            _ => return,
+        };
+
+        let state_arc = self.state.clone();
+        let ctx = ctx.clone();
+        let pane_id = self.id;
+
+        std::thread::spawn(move || {
+            let runtime = match tokio::runtime::Runtime::new() {
+                Ok(rt) => rt,
+                Err(e) => {
+                    tracing::error!("Pane {}: Failed to create Tokio runtime: {}", pane_id, e);
+                    if let Ok(mut state) = state_arc.try_write() {
+                        state.cost_loading = false;
+                    }
+                    return;
+                }
+            };
+
+            let client = CostExplorerClient::new(aws_client.get_credential_coordinator());
+            let mut results = Vec::new();
+            let mut failed_accounts = Vec::new();
+
+            for (index, account_id) in account_ids.iter().enumerate() {
+                report_status(
+                    "CostExplorer",
+                    "get_cost_data",
+                    Some(&format!("account {} of {}", index + 1, account_ids.len())),
+                );
+                let options = CostQueryOptions::new()
+                    .with_group_by(COST_BADGE_GROUP_BY.to_vec())
+                    .with_linked_account(account_id.clone());
+                match runtime.block_on(client.get_cost_data(account_id, &options)) {
+                    Ok(result) => results.push(result),
+                    Err(e) => {
+                        tracing::warn!(
+                            "Pane {}: Cost data failed for account {}: {:#}",
+                            pane_id,
+                            account_id,
+                            e
+                        );
+                        failed_accounts.push((account_id.clone(), format!("{:#}", e)));
+                    }
+                }
+            }
+            let failure_detail = (!failed_accounts.is_empty())
+                .then(|| format!("{} account(s) without cost data", failed_accounts.len()));
+            report_status_done("CostExplorer", "get_cost_data", failure_detail.as_deref());
+
+            let index = std::sync::Arc::new(CostIndex::from_results(&results, failed_accounts));
+            for attempt in 0..30 {
+                if let Ok(mut state) = state_arc.try_write() {
+                    state.cost_index = Some(index.clone());
+                    state.cost_loading = false;
+                    break;
+                } else if attempt < 29 {
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                }
+            }
+            ctx.request_repaint();
+        });
+    }
+
+    /// Apply tag changes to the tag editor's resources in the background
+    ///
+    /// The pane state (resources and session cache) is updated first so the
+    /// tree shows the new tags immediately; resources whose update fails get
+    /// their previous tags back.
+    fn trigger_tag_update(
+        &mut self,
+        ctx: &Context,
+        shared_context: &super::manager::ExplorerSharedContext,
+        changes: TagChanges,
+    ) {
+        use crate::app::resource_explorer::dialogs::TagUpdateJob;
+        use crate::app::resource_explorer::status::{report_status, report_status_done};
+        use std::sync::Mutex;
+
+        let Some(aws_client) = shared_context.aws_client.clone() else {
+            tracing::warn!("Pane {}: Cannot edit tags - AWS client not configured", self.id);
+            return;
+        };
+
+        let targets = self.tag_editor.targets().to_vec();
+        let previous = match self.state.try_write() {
+            Ok(mut state) => {
+                state.apply_tag_changes_optimistic(&self.tag_editor.target_keys(), &changes)
+            }
+            Err(_) => {
+                tracing::warn!("Pane {}: State busy, tag update not started", self.id);
+                return;
+            }
+        };
+
+        let job = Arc::new(Mutex::new(TagUpdateJob {
+            total: targets.len(),
+            ..TagUpdateJob::default()
+        }));
+        self.tag_editor.track_job(job.clone());
+
+        let state_arc = self.state.clone();
+        let ctx = ctx.clone();
+        let pane_id = self.id;
+
+        tracing::info!(
+            "Pane {}: Applying {} tag updates and {} removals to {} resources",
+            pane_id,
+            changes.set.len(),
+            changes.remove.len(),
+            targets.len()
+        );
+
+        std::thread::spawn(move || {
+            let mut failed_keys = std::collections::HashSet::new();
+
+            match tokio::runtime::Runtime::new() {
+                Ok(runtime) => {
+                    for (index, resource) in targets.iter().enumerate() {
+                        report_status(
+                            "Tags",
+                            "apply_tag_changes",
+                            Some(&format!("resource {} of {}", index + 1, targets.len())),
+                        );
+                        if let Err(e) =
+                            runtime.block_on(aws_client.apply_tag_changes(resource, &changes))
+                        {
+                            tracing::warn!(
+                                "Pane {}: Tag update failed for {}: {:#}",
+                                pane_id,
+                                resource.resource_id,
+                                e
+                            );
+                            failed_keys.insert(resource.selection_key());
+                            if let Ok(mut job) = job.lock() {
+                                job.failures
+                                    .push((resource.display_name.clone(), format!("{:#}", e)));
+                            }
+                        }
+                        if let Ok(mut job) = job.lock() {
+                            job.completed = index + 1;
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("Pane {}: Failed to create Tokio runtime: {}", pane_id, e);
+                    if let Ok(mut job) = job.lock() {
+                        for resource in &targets {
+                            failed_keys.insert(resource.selection_key());
+                            job.failures
+                                .push((resource.display_name.clone(), e.to_string()));
+                        }
+                    }
+                }
+            }
+
+            let failure_detail = (!failed_keys.is_empty())
+                .then(|| format!("{} resource(s) failed", failed_keys.len()));
+            report_status_done("Tags", "apply_tag_changes", failure_detail.as_deref());
+
+            // Roll back the optimistic update for resources AWS did not accept
+            if !failed_keys.is_empty() {
+                let rollback: Vec<_> = previous
+                    .into_iter()
+                    .filter(|(key, _)| failed_keys.contains(key))
+                    .collect();
+                for attempt in 0..30 {
+                    if let Ok(mut state) = state_arc.try_write() {
+                        state.restore_resource_tags(&rollback);
+                        break;
+                    } else if attempt < 29 {
+                        std::thread::sleep(std::time::Duration::from_millis(100));
+                    }
+                }
+            }
+
+            if let Ok(mut job) = job.lock() {
+                job.finished = true;
+            }
+            ctx.request_repaint();
+        });
+    }
+
     /// Trigger resource query if selections are ready and not currently loading
     ///
     /// Based on window.rs trigger_query_if_ready (lines 3434-3474)
@@ -330,6 +973,28 @@ impl ExplorerPane {
         ui_adapter.execute_for_pane(state_arc, scope, cache_key, ctx.clone());
     }
 
+    /// Re-fetch the pane's query scope instead of using cached results
+    ///
+    /// Returns false when there is nothing to refresh or a query is running.
+    pub fn refresh_now(
+        &mut self,
+        ctx: &Context,
+        shared_context: &super::manager::ExplorerSharedContext,
+    ) -> bool {
+        let refreshed = match self.state.try_write() {
+            Ok(mut state) if !state.query_scope.is_empty() && !state.is_loading() => {
+                evict_scope_from_cache(&mut state, shared_context);
+                true
+            }
+            _ => false,
+        };
+        if refreshed {
+            tracing::info!("Pane {}: Refreshing query scope", self.id);
+            self.trigger_query_if_ready(ctx, shared_context);
+        }
+        refreshed
+    }
+
     /// Mark this pane to trigger a query after rendering completes
     ///
     /// Used by bookmark loading and other operations that need to trigger
@@ -352,3 +1017,39 @@ impl ExplorerPane {
         }
     }
 }
+
+/// Drop the cached results of every query in the pane's scope
+fn evict_scope_from_cache(
+    state: &mut ResourceExplorerState,
+    shared_context: &super::manager::ExplorerSharedContext,
+) {
+    let account_ids: Vec<String> = state
+        .query_scope
+        .accounts
+        .iter()
+        .map(|a| a.account_id.clone())
+        .collect();
+    let region_codes: Vec<String> = state
+        .query_scope
+        .regions
+        .iter()
+        .map(|r| r.region_code.clone())
+        .collect();
+    let resource_types: Vec<String> = state
+        .query_scope
+        .resource_types
+        .iter()
+        .map(|t| t.resource_type.clone())
+        .collect();
+
+    let keys = crate::app::resource_explorer::delta_refresh::keys_in_scope(
+        &shared_context.cache.resource_keys(),
+        &account_ids,
+        &region_codes,
+        &resource_types,
+    );
+    for key in &keys {
+        shared_context.cache.remove_resources(key);
+        state.cached_queries.remove(key);
+    }
+}
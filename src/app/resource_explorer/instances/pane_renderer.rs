@@ -4,14 +4,19 @@
 //! allowing each pane to independently render its tree view, search bar,
 //! sidebar, and active selection tags.
 
+use super::compare::{CompareMatchBy, CompareSide, CompareStatus, PaneCompareView, PaneComparison};
+use crate::app::cloudformation_manager::import;
 use crate::app::resource_explorer::aws_client::AWSResourceClient;
+use crate::app::resource_explorer::export::visible_resources;
+use crate::app::resource_explorer::network_view::NetworkView;
+use crate::app::resource_explorer::resource_actions::ResourceActionRegistry;
 use crate::app::resource_explorer::state::{
     BooleanOperator, GroupingMode, ResourceEntry, ResourceExplorerState, TagClickAction,
     TagFilter, TagFilterGroup, TagFilterType,
 };
 use crate::app::resource_explorer::tree::TreeRenderer;
 use crate::app::resource_explorer::widgets::tag_filter_builder::TagFilterBuilderWidget;
-use crate::app::resource_explorer::PropertyFilterGroup;
+use crate::app::resource_explorer::{PropertyFilterGroup, ResourceExplorerAction};
 use egui::{Color32, Context, Ui};
 use std::collections::HashSet;
 use std::sync::{Arc, Mutex};
@@ -73,10 +78,27 @@ pub enum PaneAction {
     /// Apply a bookmark to this pane's state
     /// Contains bookmark_id and the source pane_id that requested it
     ApplyBookmark { bookmark_id: String, source_pane_id: Uuid },
+    /// Open a saved workspace in this pane, the other split pane, or a new window
+    OpenWorkspace {
+        workspace_id: String,
+        target: WorkspaceTarget,
+        source_pane_id: Uuid,
+    },
     /// Show the failed queries dialog with error details
     ShowFailedQueriesDialog,
 }
 
+/// Where to open a workspace, relative to the pane it was picked in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceTarget {
+    /// Replace this pane's scope
+    ThisPane,
+    /// Show the split view and load the workspace into the other pane
+    OtherPane,
+    /// Open a new Explorer window
+    NewWindow,
+}
+
 /// Renderer for a single explorer pane
 ///
 /// Contains rendering state and logic for displaying:
@@ -87,6 +109,8 @@ pub enum PaneAction {
 pub struct PaneRenderer {
     /// Tree renderer for hierarchical resource display
     pub tree_renderer: TreeRenderer,
+    /// VPC diagram shown instead of the tree in Network View
+    pub network_view: NetworkView,
     /// Track failed detail requests to avoid retrying
     pub failed_detail_requests: Arc<RwLock<HashSet<String>>>,
     /// Frame counter for debouncing logs and operations
@@ -104,6 +128,7 @@ impl PaneRenderer {
     pub fn new() -> Self {
         Self {
             tree_renderer: TreeRenderer::new(),
+            network_view: NetworkView::new(),
             failed_detail_requests: Arc::new(RwLock::new(HashSet::new())),
             frame_count: 0,
         }
@@ -142,6 +167,7 @@ impl PaneRenderer {
     /// Reset the renderer state (for Terminate action)
     pub fn reset(&mut self) {
         self.tree_renderer = TreeRenderer::new();
+        self.network_view = NetworkView::new();
         // Clear failed requests synchronously if possible
         if let Ok(mut set) = self.failed_detail_requests.try_write() {
             set.clear();
@@ -199,17 +225,20 @@ impl PaneRenderer {
             egui::TopBottomPanel::bottom(format!("pane_status_bar_{}", pane_id))
                 .show_separator_line(false) // No thick horizontal line at bottom
                 .show_inside(ui, |ui| {
-                    if let Some(status_action) = Self::render_status_bar(ui, state) {
-                        actions.push(status_action);
+                    match Self::render_status_bar(ui, state) {
+                        // The owning pane shows the failure report
+                        Some(PaneAction::ShowFailedQueriesDialog) => {
+                            state.show_failure_report = true;
+                        }
+                        Some(status_action) => actions.push(status_action),
+                        None => {}
                     }
                 });
 
             // Main content area
             egui::CentralPanel::default().show_inside(ui, |ui| {
                 // Render toolbar (Bookmarks, Select, Refresh, Reset, Cache)
-                if let Some((bookmark_id, source_pane_id)) = Self::render_toolbar(ui, state, shared_context, pane_id) {
-                    actions.push(PaneAction::ApplyBookmark { bookmark_id, source_pane_id });
-                }
+                actions.extend(Self::render_toolbar(ui, state, shared_context, pane_id));
                 ui.separator();
 
                 // Render active selection tags (closeable tags for accounts, regions, resource types)
@@ -222,14 +251,111 @@ impl PaneRenderer {
                 Self::render_search_bar(ui, state);
                 ui.separator();
 
-                // Render tree view with unique ID (uses self.tree_renderer)
-                Self::render_tree_view_with_id(ui, state, &mut self.tree_renderer, pane_id);
+                // Multi-selection summary (Ctrl+click resources in the tree)
+                if !self.tree_renderer.selected_resources.is_empty() {
+                    Self::render_selection_bar(ui, state, &mut self.tree_renderer);
+                    ui.separator();
+                }
+
+                if state.show_network_view && !state.resources.is_empty() {
+                    // Clicking a resource in the diagram reveals it in the tree
+                    if let Some(key) = self.network_view.show(
+                        ui,
+                        &state.resources,
+                        state.enrichment_version,
+                        pane_id,
+                    ) {
+                        state.show_network_view = false;
+                        self.tree_renderer.focus_resource(key);
+                    }
+                } else {
+                    // Render tree view with unique ID (uses self.tree_renderer)
+                    self.tree_renderer
+                        .set_recommendation_index(shared_context.recommendation_index.clone());
+                    Self::render_tree_view_with_id(ui, state, &mut self.tree_renderer, pane_id);
+                }
             });
         });
 
         actions
     }
 
+    /// Render the selected resource count with bulk actions
+    fn render_selection_bar(
+        ui: &mut Ui,
+        state: &ResourceExplorerState,
+        tree_renderer: &mut TreeRenderer,
+    ) {
+        ui.horizontal(|ui| {
+            let count = tree_renderer.selected_resources.len();
+            ui.label(format!(
+                "{} resource{} selected",
+                count,
+                if count == 1 { "" } else { "s" }
+            ));
+            if !crate::app::capabilities::READ_ONLY && ui.button("Edit Tags...").clicked() {
+                tree_renderer.pending_tag_edit =
+                    Some(tree_renderer.selected_resources.iter().cloned().collect());
+            }
+            if !crate::app::capabilities::READ_ONLY {
+                let selected = state
+                    .resources
+                    .iter()
+                    .filter(|r| tree_renderer.selected_resources.contains(&r.selection_key()));
+                let actions = ResourceActionRegistry::actions_for_resources(selected);
+                if !actions.is_empty() {
+                    ui.menu_button("Actions", |ui| {
+                        for action in &actions {
+                            if ui.button(format!("{}...", action.label())).clicked() {
+                                tree_renderer.pending_resource_action = Some((
+                                    action.id().to_string(),
+                                    tree_renderer.selected_resources.iter().cloned().collect(),
+                                ));
+                                ui.close();
+                            }
+                        }
+                    });
+                }
+            }
+            let importable: Vec<ResourceEntry> = state
+                .resources
+                .iter()
+                .filter(|r| {
+                    tree_renderer.selected_resources.contains(&r.selection_key())
+                        && import::supports_import(&r.resource_type)
+                })
+                .cloned()
+                .collect();
+            if !importable.is_empty() {
+                if ui
+                    .button("Template from Selection")
+                    .on_hover_text("Generate a CloudFormation template describing the selected resources")
+                    .clicked()
+                {
+                    tree_renderer
+                        .pending_explorer_actions
+                        .push(ResourceExplorerAction::OpenTemplateFromSelection {
+                            resources: importable.clone(),
+                        });
+                }
+                if ui
+                    .button("Import to Stack...")
+                    .on_hover_text("Generate an import template and manifest for the selected resources")
+                    .clicked()
+                {
+                    tree_renderer
+                        .pending_explorer_actions
+                        .push(ResourceExplorerAction::OpenStackImport {
+                            resources: importable,
+                        });
+                }
+            }
+            if ui.button("Clear Selection").clicked() {
+                tree_renderer.selected_resources.clear();
+            }
+        });
+    }
+
     /// Legacy render method (kept for backwards compatibility)
     ///
     /// Calls render_with_id with a default UUID and empty shared context
@@ -250,14 +376,15 @@ impl PaneRenderer {
     /// Render the toolbar with main action buttons
     ///
     /// Based on window.rs render_unified_toolbar (lines 1808-1942)
-    /// Returns: clicked_bookmark_id
+    /// Returns: bookmark and workspace actions
     pub fn render_toolbar(
         ui: &mut Ui,
         state: &mut ResourceExplorerState,
         shared_context: &super::manager::ExplorerSharedContext,
         pane_id: Uuid,
-    ) -> Option<(String, Uuid)> {
+    ) -> Vec<PaneAction> {
         let mut clicked_bookmark_id: Option<String> = None;
+        let mut workspace_action: Option<PaneAction> = None;
 
         ui.horizontal(|ui| {
             // Bookmarks menu button with full hierarchy
@@ -282,6 +409,10 @@ impl PaneRenderer {
                 }
             });
 
+            ui.menu_button("Workspaces", |ui| {
+                workspace_action = Self::render_workspaces_menu(ui, state, shared_context, pane_id);
+            });
+
             // Separator before action buttons
             if ui.available_width() > 400.0 {
                 ui.separator();
@@ -314,6 +445,52 @@ impl PaneRenderer {
                 state.show_refresh_dialog = true;
             }
 
+            if ui
+                .add_enabled(!state.is_loading(), egui::Button::new("Refresh Changed"))
+                .on_hover_text(
+                    "Re-fetch only resource types with CloudTrail write events since they were cached",
+                )
+                .clicked()
+            {
+                state.delta_refresh_requested = true;
+            }
+
+            if ui
+                .add_enabled(!state.resources.is_empty(), egui::Button::new("Export"))
+                .on_hover_text("Export the visible tree to CSV, JSON, or Excel")
+                .clicked()
+            {
+                state.show_export_dialog = true;
+            }
+
+            ui.add_enabled_ui(!state.resources.is_empty(), |ui| {
+                ui.toggle_value(&mut state.show_network_view, "Network View")
+                    .on_hover_text(
+                        "Draw VPCs, subnets, gateways and instances as a diagram; click a resource to show it in the tree",
+                    );
+            });
+
+            if state.cost_index.is_some() {
+                if ui
+                    .button("Hide Costs")
+                    .on_hover_text("Remove cost badges from the tree")
+                    .clicked()
+                {
+                    state.cost_index = None;
+                }
+            } else if state.cost_loading {
+                ui.add_enabled(false, egui::Button::new("Loading Costs..."));
+            } else if ui
+                .add_enabled(!state.query_scope.accounts.is_empty(), egui::Button::new("Costs"))
+                .on_hover_text(
+                    "Show month-to-date and forecast costs from Cost Explorer on tree groups
+(Cost Explorer bills $0.01 per API request)",
+                )
+                .clicked()
+            {
+                state.cost_fetch_requested = true;
+            }
+
             if ui
                 .button("Reset")
                 .on_hover_text("Reset all selections to default state")
@@ -335,7 +512,104 @@ impl PaneRenderer {
             }
         });
 
-        clicked_bookmark_id.map(|id| (id, pane_id))
+        let mut actions: Vec<PaneAction> = clicked_bookmark_id
+            .map(|bookmark_id| PaneAction::ApplyBookmark {
+                bookmark_id,
+                source_pane_id: pane_id,
+            })
+            .into_iter()
+            .collect();
+        actions.extend(workspace_action);
+        actions
+    }
+
+    /// Render saved workspaces with open targets, and saving the current scope
+    fn render_workspaces_menu(
+        ui: &mut Ui,
+        state: &mut ResourceExplorerState,
+        shared_context: &super::manager::ExplorerSharedContext,
+        pane_id: Uuid,
+    ) -> Option<PaneAction> {
+        let mut action = None;
+        let workspaces: Vec<_> = shared_context
+            .bookmarks
+            .read()
+            .unwrap()
+            .get_workspaces()
+            .to_vec();
+
+        ui.set_min_width(340.0);
+        let mut workspace_to_delete: Option<String> = None;
+        if workspaces.is_empty() {
+            ui.label(egui::RichText::new("(no workspaces)").italics().weak());
+        }
+        for workspace in &workspaces {
+            ui.horizontal(|ui| {
+                let name = if workspace.matches_state(state) {
+                    format!("[Active] {}", workspace.name)
+                } else {
+                    workspace.name.clone()
+                };
+                ui.label(name).on_hover_text(workspace.summary());
+
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    if ui.small_button("x").on_hover_text("Delete workspace").clicked() {
+                        workspace_to_delete = Some(workspace.id.clone());
+                    }
+                    let targets = [
+                        ("Window", "Open in a new Explorer window", WorkspaceTarget::NewWindow),
+                        ("Split", "Open in the other split pane", WorkspaceTarget::OtherPane),
+                        ("Here", "Replace this pane's scope", WorkspaceTarget::ThisPane),
+                    ];
+                    for (label, hover, target) in targets {
+                        if ui.small_button(label).on_hover_text(hover).clicked() {
+                            action = Some(PaneAction::OpenWorkspace {
+                                workspace_id: workspace.id.clone(),
+                                target,
+                                source_pane_id: pane_id,
+                            });
+                            ui.close();
+                        }
+                    }
+                });
+            });
+        }
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            ui.add(
+                egui::TextEdit::singleline(&mut state.workspace_name_input)
+                    .hint_text("Workspace name")
+                    .desired_width(180.0),
+            );
+            let name = state.workspace_name_input.trim().to_string();
+            let can_save = !name.is_empty() && !state.query_scope.is_empty();
+            if ui
+                .add_enabled(can_save, egui::Button::new("Save Current"))
+                .on_hover_text(
+                    "Save accounts, regions, resource types, tag filters, and grouping
+(a workspace with the same name is replaced)",
+                )
+                .clicked()
+            {
+                let mut bookmarks = shared_context.bookmarks.write().unwrap();
+                bookmarks.save_workspace(&name, state);
+                if let Err(e) = bookmarks.save() {
+                    tracing::error!("Failed to save workspace: {}", e);
+                }
+                state.workspace_name_input.clear();
+            }
+        });
+
+        if let Some(id) = workspace_to_delete {
+            let mut bookmarks = shared_context.bookmarks.write().unwrap();
+            bookmarks.remove_workspace(&id);
+            if let Err(e) = bookmarks.save() {
+                tracing::error!("Failed to save after deleting workspace: {}", e);
+            }
+        }
+
+        action
     }
 
     /// Recursively render a level of the bookmark menu hierarchy
@@ -586,6 +860,157 @@ impl PaneRenderer {
         action
     }
 
+    /// Render the Compare Panes view (diff of the left and right pane resources)
+    ///
+    /// Compares the resources each pane is currently showing (tag, property,
+    /// and search filters applied). The comparison is cached in `view` and
+    /// recomputed when either pane's resource count changes, the match mode
+    /// changes, or "Recompare" is clicked.
+    pub fn render_compare_view(
+        ui: &mut Ui,
+        view: &mut PaneCompareView,
+        left: &ResourceExplorerState,
+        right: &ResourceExplorerState,
+    ) {
+        let counts = (left.resources.len(), right.resources.len());
+        if view.compared_counts != counts {
+            view.invalidate();
+        }
+
+        ui.horizontal(|ui| {
+            ui.label("Match by:");
+            let previous = view.match_by;
+            egui::ComboBox::from_id_salt("compare_panes_match_by")
+                .selected_text(view.match_by.label())
+                .show_ui(ui, |ui| {
+                    for match_by in [CompareMatchBy::Name, CompareMatchBy::ResourceId] {
+                        ui.selectable_value(&mut view.match_by, match_by, match_by.label());
+                    }
+                });
+            if view.match_by != previous {
+                view.invalidate();
+            }
+
+            if ui.button("Recompare").clicked() {
+                view.invalidate();
+            }
+        });
+
+        let comparison = view.comparison.get_or_insert_with(|| {
+            PaneComparison::between(
+                &visible_resources(left),
+                &visible_resources(right),
+                view.match_by,
+            )
+        });
+        view.compared_counts = counts;
+
+        // Status toggles with counts and highlight colors
+        ui.horizontal(|ui| {
+            for status in [
+                CompareStatus::OnlyLeft,
+                CompareStatus::OnlyRight,
+                CompareStatus::Different,
+                CompareStatus::Same,
+            ] {
+                let mut shown = !view.hidden_statuses.contains(&status);
+                let text = egui::RichText::new(format!(
+                    "{} ({})",
+                    status.label(),
+                    comparison.count(status)
+                ))
+                .color(Self::compare_status_color(status));
+                if ui.checkbox(&mut shown, text).changed() {
+                    if shown {
+                        view.hidden_statuses.remove(&status);
+                    } else {
+                        view.hidden_statuses.insert(status);
+                    }
+                }
+            }
+        });
+        ui.separator();
+
+        if comparison.rows.is_empty() {
+            ui.label("Both panes are empty. Select accounts, regions, and resource types in each pane to compare them.");
+            return;
+        }
+
+        let describe_side = |side: &Option<CompareSide>| match side {
+            Some(side) => format!(
+                "{} / {} / {}",
+                side.account_id, side.region, side.resource_id
+            ),
+            None => "-".to_string(),
+        };
+
+        egui::ScrollArea::both()
+            .id_salt("compare_panes_scroll")
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                egui::Grid::new("compare_panes_grid")
+                    .num_columns(5)
+                    .striped(true)
+                    .spacing([12.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.strong("Status");
+                        ui.strong("Resource");
+                        ui.strong("Left");
+                        ui.strong("Right");
+                        ui.strong("Differences");
+                        ui.end_row();
+
+                        for row in comparison
+                            .rows
+                            .iter()
+                            .filter(|row| !view.hidden_statuses.contains(&row.status))
+                        {
+                            ui.colored_label(
+                                Self::compare_status_color(row.status),
+                                row.status.label(),
+                            );
+                            ui.label(format!("{}  {}", row.match_key, row.resource_type));
+                            ui.label(describe_side(&row.left));
+                            ui.label(describe_side(&row.right));
+
+                            if row.differences.is_empty() {
+                                ui.label("");
+                            } else {
+                                let details: Vec<String> = row
+                                    .differences
+                                    .iter()
+                                    .map(|difference| {
+                                        format!(
+                                            "{}: {} -> {}",
+                                            difference.path,
+                                            difference.left.as_deref().unwrap_or("(none)"),
+                                            difference.right.as_deref().unwrap_or("(none)")
+                                        )
+                                    })
+                                    .collect();
+                                let summary = if details.len() == 1 {
+                                    details[0].clone()
+                                } else {
+                                    format!("{} (+{} more)", details[0], details.len() - 1)
+                                };
+                                ui.label(summary).on_hover_text(details.join("\n"));
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+    }
+
+    /// Highlight color for a comparison status
+    fn compare_status_color(status: CompareStatus) -> Color32 {
+        match status {
+            CompareStatus::OnlyLeft => Color32::from_rgb(100, 180, 255),
+            CompareStatus::OnlyRight => Color32::from_rgb(255, 170, 60),
+            CompareStatus::Different => Color32::from_rgb(230, 200, 60),
+            CompareStatus::Same => Color32::GRAY,
+        }
+    }
+
     /// Render the search bar
     pub fn render_search_bar(ui: &mut Ui, state: &mut ResourceExplorerState) {
         ui.horizontal(|ui| {
@@ -839,8 +1264,9 @@ impl PaneRenderer {
         tree_renderer: &mut TreeRenderer,
         pane_id: Uuid,
     ) {
-        // Update Phase 2 status for tree renderer
+        // Update Phase 2 status and cost badges for tree renderer
         tree_renderer.phase2_in_progress = state.phase2_enrichment_in_progress;
+        tree_renderer.set_cost_index(state.cost_index.clone());
 
         // Use remaining available space for the tree view with scrolling
         // Use pane_id to make ScrollArea unique across split panes
@@ -857,16 +1283,26 @@ impl PaneRenderer {
                         ui.label("No resources found for the current selection");
                     });
                 } else if !state.resources.is_empty() {
-                    // Apply all filters (tag + property) before rendering
-                    let filtered_resources: Vec<_> = state
-                        .resources
-                        .iter()
-                        .filter(|resource| {
-                            Self::apply_tag_filters(resource, state)
-                                && Self::apply_property_filters(resource, state)
-                        })
-                        .cloned()
-                        .collect();
+                    // Apply all filters (tag + property) before rendering; the result is
+                    // memoized so filters are only re-evaluated when something changes
+                    let filters_active = state.show_only_tagged
+                        || state.show_only_untagged
+                        || !state.tag_filter_group.is_empty()
+                        || !state.property_filter_group.is_empty();
+                    let memoized;
+                    let filtered_resources: &[ResourceEntry] = if filters_active {
+                        memoized = tree_renderer.memoized_filter(
+                            &state.resources,
+                            Self::filter_signature(state),
+                            |resource| {
+                                Self::apply_tag_filters(resource, state)
+                                    && Self::apply_property_filters(resource, state)
+                            },
+                        );
+                        &memoized
+                    } else {
+                        &state.resources
+                    };
 
                     // Show filter stats if filters are active
                     let tag_filter_count =
@@ -894,7 +1330,7 @@ impl PaneRenderer {
                         // Use cached tree rendering to prevent unnecessary rebuilds
                         tree_renderer.render_tree_cached(
                             ui,
-                            &filtered_resources,
+                            filtered_resources,
                             state.primary_grouping.clone(),
                             &state.search_filter,
                             &state.badge_selector,
@@ -1063,6 +1499,20 @@ impl PaneRenderer {
         true
     }
 
+    /// Summary of the active tag and property filters, for memoizing filter results
+    fn filter_signature(state: &ResourceExplorerState) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        state.show_only_tagged.hash(&mut hasher);
+        state.show_only_untagged.hash(&mut hasher);
+        state.tag_filter_group.hash(&mut hasher);
+        format!("{:?}", state.property_filter_group).hash(&mut hasher);
+        state.enrichment_version.hash(&mut hasher);
+        hasher.finish()
+    }
+
     /// Apply property filters to a resource
     pub fn apply_property_filters(resource: &ResourceEntry, state: &ResourceExplorerState) -> bool {
         // Empty filter groups match everything (no filtering)
@@ -16,6 +16,130 @@ pub enum ResourceExplorerAction {
         account_id: String,
         region: String,
     },
+    /// Request to open CloudWatch Metrics for a resource
+    OpenCloudWatchMetrics {
+        resource_type: String,
+        resource_id: String,
+        resource_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to open AWS Config configuration history for a resource
+    OpenConfigHistory {
+        resource_type: String,
+        resource_id: String,
+        resource_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to open the relationship graph centered on a resource
+    OpenRelationshipGraph {
+        resource_type: String,
+        resource_id: String,
+        resource_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to run drift detection on a CloudFormation stack
+    OpenStackDrift {
+        stack_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to update a CloudFormation stack through a reviewed change set
+    OpenStackUpdate {
+        stack_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to import selected resources into a CloudFormation stack
+    OpenStackImport { resources: Vec<state::ResourceEntry> },
+    /// Request to generate a CloudFormation template from selected resources
+    OpenTemplateFromSelection { resources: Vec<state::ResourceEntry> },
+    /// Request to open the Athena query editor for a workgroup
+    OpenAthenaQuery {
+        workgroup: String,
+        account_id: String,
+        region: String,
+        output_location: Option<String>,
+    },
+    /// Request to open the EventBridge browser for an event bus
+    OpenEventBridgeBrowser {
+        event_bus_name: String,
+        /// Rule to select once the bus's rules are loaded
+        rule_name: Option<String>,
+        account_id: String,
+        region: String,
+    },
+    /// Request to open the invoke test harness for a Lambda function
+    OpenLambdaInvoke {
+        function_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to open the queue inspector for an SQS queue
+    OpenSqsQueueInspector {
+        queue_name: String,
+        queue_url: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to open a Session Manager terminal on an EC2 instance
+    OpenSsmSession {
+        instance_id: String,
+        instance_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to open the KMS key window (policy, grants, aliases, rotation, callers)
+    OpenKmsKeyDetails {
+        key_id: String,
+        key_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to open the S3 bucket window (storage by class, lifecycle,
+    /// replication, public access)
+    OpenS3BucketDetails {
+        bucket_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to open the CloudFront invalidation window of a distribution
+    OpenCloudFrontInvalidations {
+        distribution_id: String,
+        distribution_name: String,
+        account_id: String,
+    },
+    /// Request to open the Auto Scaling group window (capacity, instance
+    /// health, scaling policies, activity history)
+    OpenAutoScalingGroup {
+        group_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Request to open the Bedrock model catalog of an account and region
+    OpenBedrockModelCatalog { account_id: String, region: String },
+    /// Request to open the GuardDuty / Security Hub findings window, optionally
+    /// filtered to the findings naming one resource
+    OpenThreatFindings {
+        account_id: String,
+        region: String,
+        resource_id: Option<String>,
+    },
+    /// Request to open the DNS record search on the records of a hosted zone
+    OpenDnsRecords { hosted_zone_id: String },
+    /// Request to open the Step Functions execution viewer for a state machine
+    OpenStepFunctionsExecutions {
+        state_machine_arn: String,
+        state_machine_name: String,
+        account_id: String,
+        region: String,
+    },
+    /// Show a notification in the main status bar (e.g., scheduled refresh changes)
+    ShowNotification {
+        notification: crate::app::notifications::Notification,
+    },
     /// Request available AWS Identity Center roles for an account (AWS Console submenu)
     RequestAwsConsoleRoles {
         request_id: u64,
@@ -161,24 +285,40 @@ pub fn drain_explorer_actions() -> Vec<ExplorerAction> {
     }
 }
 
+pub mod adaptive_concurrency;
 pub mod aws_client;
 pub mod aws_services;
 pub mod bookmarks;
 pub mod cache;
 pub mod console_links;
+pub mod cost_badges;
+pub mod recommendation_badges;
+pub mod threat_findings;
 pub mod memory_budget;
 pub mod child_resources;
 pub mod colors;
 pub mod credentials;
+pub mod delta_refresh;
+pub mod demo_data;
 pub mod dialogs;
+pub mod disk_cache;
+pub mod export;
+pub mod failure_report;
 pub mod global_services;
+pub mod hint_targets;
+pub mod network_view;
 pub mod normalizers;
+pub mod organization_directory;
 pub mod property_system;
 pub mod query_engine;
 pub mod query_timing;
+pub mod reachability;
+pub mod resource_actions;
+pub mod resource_finder;
 pub mod retry_tracker;
 pub mod ui_query_adapter;
 pub mod sdk_errors;
+pub mod security_insights;
 pub mod state;
 pub mod status;
 pub mod tag_badges;
@@ -207,7 +347,10 @@ pub use colors::{
     ColorCacheStats,
 };
 pub use credentials::{AccountCredentials, CredentialCacheStats, CredentialCoordinator};
+pub use delta_refresh::{ChangeSet, DeltaRefreshPlan};
 pub use dialogs::FuzzySearchDialog;
+pub use disk_cache::DiskResourceCache;
+pub use export::{ExportColumn, ExportDialog, ExportFormat};
 pub use global_services::{get_global_query_region, is_global_service, GlobalServiceRegistry};
 pub use normalizers::NormalizerFactory;
 pub use property_system::{
@@ -0,0 +1,760 @@
+//! VPC topology diagram for the Resource Explorer.
+//!
+//! The Network View replaces the tree with nested boxes: each VPC contains its
+//! subnets, and each subnet the instances, network interfaces and NAT gateways
+//! placed in it. Internet gateways and route tables are listed along the top of
+//! their VPC, and each subnet names the route table it uses. Clicking a box
+//! switches back to the tree and reveals that resource.
+//!
+//! Only the pane's loaded resources are drawn. A VPC or subnet that a loaded
+//! resource points to but that isn't loaded itself is drawn as a gray
+//! placeholder so its members still have a home.
+
+use super::state::ResourceEntry;
+use super::tree::TreeRenderer;
+use egui::{Align2, Color32, FontId, Pos2, Rect, Sense, Stroke, Ui, Vec2};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use uuid::Uuid;
+
+/// Resource types the diagram draws; add these to the selection to fill it in
+pub const TOPOLOGY_TYPES: &[&str] = &[
+    "AWS::EC2::VPC",
+    "AWS::EC2::Subnet",
+    "AWS::EC2::RouteTable",
+    "AWS::EC2::InternetGateway",
+    "AWS::EC2::NatGateway",
+    "AWS::EC2::NetworkInterface",
+    "AWS::EC2::Instance",
+];
+
+const PADDING: f32 = 8.0;
+const GAP: f32 = 6.0;
+/// Title and detail line of a VPC or subnet box
+const HEADER_HEIGHT: f32 = 38.0;
+const CHIP_HEIGHT: f32 = 20.0;
+const CHIP_WIDTH: f32 = 200.0;
+const SUBNET_MIN_WIDTH: f32 = 240.0;
+
+/// A resource drawn as a box or chip
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopologyItem {
+    pub selection_key: String,
+    pub resource_type: String,
+    pub resource_id: String,
+    pub label: String,
+}
+
+impl TopologyItem {
+    fn from_resource(resource: &ResourceEntry) -> Self {
+        let label =
+            if resource.display_name.is_empty() || resource.display_name == resource.resource_id {
+                resource.resource_id.clone()
+            } else {
+                format!("{} ({})", resource.display_name, resource.resource_id)
+            };
+        Self {
+            selection_key: resource.selection_key(),
+            resource_type: resource.resource_type.clone(),
+            resource_id: resource.resource_id.clone(),
+            label,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SubnetNode {
+    pub subnet_id: String,
+    /// The subnet resource, or None for a placeholder
+    pub item: Option<TopologyItem>,
+    pub cidr: Option<String>,
+    pub availability_zone: Option<String>,
+    /// Explicitly associated route table, else the VPC's main one
+    pub route_table_id: Option<String>,
+    /// Instances, network interfaces and NAT gateways in the subnet
+    pub members: Vec<TopologyItem>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct VpcNode {
+    pub vpc_id: String,
+    pub account_id: String,
+    pub region: String,
+    /// The VPC resource, or None for a placeholder
+    pub item: Option<TopologyItem>,
+    pub cidr: Option<String>,
+    /// Internet gateways attached to the VPC
+    pub gateways: Vec<TopologyItem>,
+    pub route_tables: Vec<TopologyItem>,
+    pub main_route_table_id: Option<String>,
+    pub subnets: Vec<SubnetNode>,
+}
+
+/// VPCs of the loaded resources with everything placed inside them
+#[derive(Debug, Clone, Default)]
+pub struct NetworkTopology {
+    pub vpcs: Vec<VpcNode>,
+}
+
+/// Account, region and resource ID; VPC and subnet IDs are only unique within these
+type ScopedId = (String, String, String);
+
+fn scoped(resource: &ResourceEntry, id: &str) -> ScopedId {
+    (
+        resource.account_id.clone(),
+        resource.region.clone(),
+        id.to_string(),
+    )
+}
+
+fn str_prop<'a>(properties: &'a Value, key: &str) -> Option<&'a str> {
+    properties
+        .get(key)
+        .and_then(Value::as_str)
+        .filter(|value| !value.is_empty())
+}
+
+#[derive(Default)]
+struct VpcBuilder {
+    node: VpcNode,
+    subnets: BTreeMap<String, SubnetNode>,
+    /// Subnet ID to explicitly associated route table ID
+    subnet_route_tables: HashMap<String, String>,
+}
+
+impl VpcBuilder {
+    fn subnet(&mut self, subnet_id: &str) -> &mut SubnetNode {
+        self.subnets
+            .entry(subnet_id.to_string())
+            .or_insert_with(|| SubnetNode {
+                subnet_id: subnet_id.to_string(),
+                ..Default::default()
+            })
+    }
+
+    fn finish(mut self) -> VpcNode {
+        let mut subnets: Vec<SubnetNode> = self.subnets.into_values().collect();
+        for subnet in &mut subnets {
+            subnet.route_table_id = self
+                .subnet_route_tables
+                .get(&subnet.subnet_id)
+                .or(self.node.main_route_table_id.as_ref())
+                .cloned();
+            subnet
+                .members
+                .sort_by(|a, b| (&a.resource_type, &a.label).cmp(&(&b.resource_type, &b.label)));
+        }
+        subnets.sort_by(|a, b| {
+            (&a.availability_zone, &a.subnet_id).cmp(&(&b.availability_zone, &b.subnet_id))
+        });
+        self.node.subnets = subnets;
+        self.node.gateways.sort_by(|a, b| a.label.cmp(&b.label));
+        self.node.route_tables.sort_by(|a, b| a.label.cmp(&b.label));
+        self.node
+    }
+}
+
+fn vpc_builder<'a>(
+    vpcs: &'a mut BTreeMap<ScopedId, VpcBuilder>,
+    resource: &ResourceEntry,
+    vpc_id: &str,
+) -> &'a mut VpcBuilder {
+    vpcs.entry(scoped(resource, vpc_id))
+        .or_insert_with(|| VpcBuilder {
+            node: VpcNode {
+                vpc_id: vpc_id.to_string(),
+                account_id: resource.account_id.clone(),
+                region: resource.region.clone(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+}
+
+impl NetworkTopology {
+    pub fn build(resources: &[ResourceEntry]) -> Self {
+        let mut vpcs: BTreeMap<ScopedId, VpcBuilder> = BTreeMap::new();
+        let mut subnet_vpcs: HashMap<ScopedId, String> = HashMap::new();
+
+        // VPCs and subnets first so the resources placed in them find their box
+        for resource in resources {
+            let properties = &resource.properties;
+            match resource.resource_type.as_str() {
+                "AWS::EC2::VPC" => {
+                    let vpc = vpc_builder(&mut vpcs, resource, &resource.resource_id);
+                    vpc.node.item = Some(TopologyItem::from_resource(resource));
+                    vpc.node.cidr = str_prop(properties, "CidrBlock").map(String::from);
+                }
+                "AWS::EC2::Subnet" => {
+                    let Some(vpc_id) = str_prop(properties, "VpcId") else {
+                        continue;
+                    };
+                    subnet_vpcs.insert(scoped(resource, &resource.resource_id), vpc_id.to_string());
+                    let subnet =
+                        vpc_builder(&mut vpcs, resource, vpc_id).subnet(&resource.resource_id);
+                    subnet.item = Some(TopologyItem::from_resource(resource));
+                    subnet.cidr = str_prop(properties, "CidrBlock").map(String::from);
+                    subnet.availability_zone =
+                        str_prop(properties, "AvailabilityZone").map(String::from);
+                }
+                _ => {}
+            }
+        }
+
+        // An instance already shows its attached interfaces
+        let instances: HashSet<ScopedId> = resources
+            .iter()
+            .filter(|resource| resource.resource_type == "AWS::EC2::Instance")
+            .map(|resource| scoped(resource, &resource.resource_id))
+            .collect();
+
+        for resource in resources {
+            let properties = &resource.properties;
+            match resource.resource_type.as_str() {
+                "AWS::EC2::RouteTable" => {
+                    let Some(vpc_id) = str_prop(properties, "VpcId") else {
+                        continue;
+                    };
+                    let vpc = vpc_builder(&mut vpcs, resource, vpc_id);
+                    vpc.node
+                        .route_tables
+                        .push(TopologyItem::from_resource(resource));
+                    let associations = properties
+                        .get("Associations")
+                        .and_then(Value::as_array)
+                        .into_iter()
+                        .flatten();
+                    for association in associations {
+                        if association.get("Main").and_then(Value::as_bool) == Some(true) {
+                            vpc.node.main_route_table_id = Some(resource.resource_id.clone());
+                        }
+                        if let Some(subnet_id) = str_prop(association, "SubnetId") {
+                            vpc.subnet_route_tables
+                                .insert(subnet_id.to_string(), resource.resource_id.clone());
+                        }
+                    }
+                }
+                "AWS::EC2::InternetGateway" => {
+                    let attachments = properties
+                        .get("Attachments")
+                        .and_then(Value::as_array)
+                        .into_iter()
+                        .flatten();
+                    for vpc_id in attachments.filter_map(|a| str_prop(a, "VpcId")) {
+                        vpc_builder(&mut vpcs, resource, vpc_id)
+                            .node
+                            .gateways
+                            .push(TopologyItem::from_resource(resource));
+                    }
+                }
+                // Interfaces of loaded instances are drawn as their instance
+                "AWS::EC2::NetworkInterface"
+                    if properties
+                        .get("Attachment")
+                        .and_then(|attachment| str_prop(attachment, "InstanceId"))
+                        .is_some_and(|instance_id| {
+                            instances.contains(&scoped(resource, instance_id))
+                        }) => {}
+                "AWS::EC2::Instance" | "AWS::EC2::NetworkInterface" | "AWS::EC2::NatGateway" => {
+                    let Some(subnet_id) = str_prop(properties, "SubnetId") else {
+                        continue;
+                    };
+                    let vpc_id = subnet_vpcs
+                        .get(&scoped(resource, subnet_id))
+                        .map(String::as_str)
+                        .or_else(|| str_prop(properties, "VpcId"));
+                    let Some(vpc_id) = vpc_id else {
+                        continue;
+                    };
+                    vpc_builder(&mut vpcs, resource, vpc_id)
+                        .subnet(subnet_id)
+                        .members
+                        .push(TopologyItem::from_resource(resource));
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            vpcs: vpcs.into_values().map(VpcBuilder::finish).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BoxKind {
+    Vpc,
+    Subnet,
+    /// Resource listed in a VPC header or a subnet
+    Chip,
+}
+
+/// One box of the laid out diagram, positioned relative to the diagram origin
+#[derive(Debug, Clone)]
+struct DiagramBox {
+    rect: Rect,
+    kind: BoxKind,
+    title: String,
+    detail: Option<String>,
+    resource_type: &'static str,
+    /// Resource revealed on click; None for placeholders
+    selection_key: Option<String>,
+}
+
+impl DiagramBox {
+    fn color(&self) -> Color32 {
+        match self.resource_type {
+            "AWS::EC2::VPC" => Color32::from_rgb(100, 150, 255),
+            "AWS::EC2::Subnet" => Color32::from_rgb(80, 190, 120),
+            "AWS::EC2::InternetGateway" => Color32::from_rgb(170, 120, 230),
+            "AWS::EC2::NatGateway" => Color32::from_rgb(220, 180, 50),
+            "AWS::EC2::NetworkInterface" => Color32::from_rgb(60, 180, 200),
+            "AWS::EC2::Instance" => Color32::from_rgb(240, 130, 60),
+            _ => Color32::from_rgb(150, 150, 150),
+        }
+    }
+}
+
+/// Map a resource type to the `TOPOLOGY_TYPES` entry used for its color
+fn topology_type(resource_type: &str) -> &'static str {
+    TOPOLOGY_TYPES
+        .iter()
+        .copied()
+        .find(|known| *known == resource_type)
+        .unwrap_or("")
+}
+
+fn short_type(resource_type: &str) -> &str {
+    resource_type.rsplit("::").next().unwrap_or(resource_type)
+}
+
+fn chip(rect: Rect, item: &TopologyItem, title: String) -> DiagramBox {
+    DiagramBox {
+        rect,
+        kind: BoxKind::Chip,
+        title,
+        detail: None,
+        resource_type: topology_type(&item.resource_type),
+        selection_key: Some(item.selection_key.clone()),
+    }
+}
+
+fn container(
+    rect: Rect,
+    kind: BoxKind,
+    resource_type: &'static str,
+    id: &str,
+    item: Option<&TopologyItem>,
+    detail: Vec<String>,
+) -> DiagramBox {
+    DiagramBox {
+        rect,
+        kind,
+        title: match item {
+            Some(item) => item.label.clone(),
+            None => format!("{} (not loaded)", id),
+        },
+        detail: (!detail.is_empty()).then(|| detail.join("   ")),
+        resource_type,
+        selection_key: item.map(|item| item.selection_key.clone()),
+    }
+}
+
+/// Lay the topology out top to bottom in a diagram `width` wide
+///
+/// Boxes come out parents first, so painting them in order draws children on top.
+/// Returns the boxes and the diagram height.
+fn layout(topology: &NetworkTopology, width: f32) -> (Vec<DiagramBox>, f32) {
+    let mut boxes = Vec::new();
+    let mut y = 0.0;
+    let inner_width = width - 2.0 * PADDING;
+
+    for vpc in &topology.vpcs {
+        let vpc_index = boxes.len();
+        let detail = [
+            vpc.cidr.clone(),
+            Some(vpc.account_id.clone()),
+            Some(vpc.region.clone()),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        boxes.push(container(
+            Rect::NOTHING,
+            BoxKind::Vpc,
+            "AWS::EC2::VPC",
+            &vpc.vpc_id,
+            vpc.item.as_ref(),
+            detail,
+        ));
+        let mut cursor = y + HEADER_HEIGHT;
+
+        // Gateways and route tables wrap across the top of the VPC
+        let chip_width = CHIP_WIDTH.min(inner_width);
+        let columns = ((inner_width + GAP) / (chip_width + GAP)).floor().max(1.0) as usize;
+        let header_chips = vpc
+            .gateways
+            .iter()
+            .map(|gateway| (gateway, gateway.label.clone()))
+            .chain(vpc.route_tables.iter().map(|table| {
+                let main = vpc.main_route_table_id.as_deref() == Some(table.resource_id.as_str());
+                (
+                    table,
+                    if main {
+                        format!("{} (main)", table.label)
+                    } else {
+                        table.label.clone()
+                    },
+                )
+            }));
+        let mut chip_count = 0;
+        for (i, (item, title)) in header_chips.enumerate() {
+            let (row, column) = (i / columns, i % columns);
+            let min = Pos2::new(
+                PADDING + column as f32 * (chip_width + GAP),
+                cursor + row as f32 * (CHIP_HEIGHT + GAP),
+            );
+            boxes.push(chip(
+                Rect::from_min_size(min, Vec2::new(chip_width, CHIP_HEIGHT)),
+                item,
+                title,
+            ));
+            chip_count += 1;
+        }
+        cursor += chip_count.div_ceil(columns) as f32 * (CHIP_HEIGHT + GAP);
+
+        // Subnets in a grid, each listing its members one per line
+        let columns = ((inner_width + GAP) / (SUBNET_MIN_WIDTH + GAP))
+            .floor()
+            .max(1.0) as usize;
+        let subnet_width = (inner_width - GAP * (columns - 1) as f32) / columns as f32;
+        for row in vpc.subnets.chunks(columns) {
+            let row_start = boxes.len();
+            let mut row_height: f32 = 0.0;
+            for (column, subnet) in row.iter().enumerate() {
+                let height = HEADER_HEIGHT + subnet.members.len() as f32 * (CHIP_HEIGHT + GAP);
+                row_height = row_height.max(height);
+                let subnet_min = Pos2::new(PADDING + column as f32 * (subnet_width + GAP), cursor);
+                let detail = [
+                    subnet.cidr.clone(),
+                    subnet.availability_zone.clone(),
+                    subnet
+                        .route_table_id
+                        .as_ref()
+                        .map(|id| format!("routes: {}", id)),
+                ]
+                .into_iter()
+                .flatten()
+                .collect();
+                boxes.push(container(
+                    Rect::from_min_size(subnet_min, Vec2::new(subnet_width, height)),
+                    BoxKind::Subnet,
+                    "AWS::EC2::Subnet",
+                    &subnet.subnet_id,
+                    subnet.item.as_ref(),
+                    detail,
+                ));
+                for (i, member) in subnet.members.iter().enumerate() {
+                    let min = subnet_min
+                        + Vec2::new(PADDING, HEADER_HEIGHT + i as f32 * (CHIP_HEIGHT + GAP));
+                    let size = Vec2::new(subnet_width - 2.0 * PADDING, CHIP_HEIGHT);
+                    let title = format!("{}: {}", short_type(&member.resource_type), member.label);
+                    boxes.push(chip(Rect::from_min_size(min, size), member, title));
+                }
+            }
+            // Subnets in a row share the tallest one's height
+            for subnet_box in boxes[row_start..]
+                .iter_mut()
+                .filter(|b| b.kind == BoxKind::Subnet)
+            {
+                subnet_box.rect.max.y = cursor + row_height;
+            }
+            cursor += row_height + GAP;
+        }
+
+        let bottom = cursor + PADDING;
+        boxes[vpc_index].rect = Rect::from_min_max(Pos2::new(0.0, y), Pos2::new(width, bottom));
+        y = bottom + 2.0 * GAP;
+    }
+
+    (boxes, y)
+}
+
+/// The Network View of one pane; rebuilds its topology when the resources change
+#[derive(Default)]
+pub struct NetworkView {
+    topology: NetworkTopology,
+    /// Resources fingerprint and enrichment version the topology was built from
+    built_from: Option<(u64, u64)>,
+}
+
+impl NetworkView {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Draw the diagram; returns the selection key of a clicked resource
+    pub fn show(
+        &mut self,
+        ui: &mut Ui,
+        resources: &[ResourceEntry],
+        enrichment_version: u64,
+        pane_id: Uuid,
+    ) -> Option<String> {
+        let built_from = (
+            TreeRenderer::resources_fingerprint(resources),
+            enrichment_version,
+        );
+        if self.built_from != Some(built_from) {
+            self.topology = NetworkTopology::build(resources);
+            self.built_from = Some(built_from);
+        }
+
+        if self.topology.vpcs.is_empty() {
+            ui.centered_and_justified(|ui| {
+                ui.label(format!(
+                    "No VPC resources loaded. Add {} to the selection to draw the network",
+                    TOPOLOGY_TYPES.join(", ")
+                ));
+            });
+            return None;
+        }
+
+        let mut clicked = None;
+        egui::ScrollArea::vertical()
+            .id_salt(format!("network_view_scroll_{}", pane_id))
+            .auto_shrink([false, false])
+            .show(ui, |ui| {
+                let width = ui.available_width().max(SUBNET_MIN_WIDTH + 2.0 * PADDING);
+                let (boxes, height) = layout(&self.topology, width);
+                let (response, painter) =
+                    ui.allocate_painter(Vec2::new(width, height), Sense::hover());
+                let origin = response.rect.min.to_vec2();
+                let text_color = ui.visuals().text_color();
+                let weak_color = ui.visuals().weak_text_color();
+
+                for (i, diagram_box) in boxes.iter().enumerate() {
+                    let rect = diagram_box.rect.translate(origin);
+                    // Later boxes sit on top, so a chip takes the click before its subnet
+                    let sense = if diagram_box.selection_key.is_some() {
+                        Sense::click()
+                    } else {
+                        Sense::hover()
+                    };
+                    let box_response =
+                        ui.interact(rect, ui.id().with(("network_view_box", i)), sense);
+                    let hovered = box_response.hovered();
+                    if box_response.clicked() {
+                        clicked = diagram_box.selection_key.clone();
+                    }
+                    match &diagram_box.selection_key {
+                        Some(_) => {
+                            box_response.on_hover_text(format!(
+                                "{}\n{}\nClick to show in the tree",
+                                diagram_box.resource_type, diagram_box.title
+                            ));
+                        }
+                        None => {
+                            box_response.on_hover_text(format!(
+                                "Not loaded; add {} to the selection",
+                                diagram_box.resource_type
+                            ));
+                        }
+                    }
+
+                    let color = if diagram_box.selection_key.is_some() {
+                        diagram_box.color()
+                    } else {
+                        weak_color
+                    };
+                    let stroke = Stroke::new(if hovered { 2.0 } else { 1.0 }, color);
+                    let clipped = painter.with_clip_rect(rect.shrink(1.0));
+                    match diagram_box.kind {
+                        BoxKind::Vpc | BoxKind::Subnet => {
+                            painter.rect(
+                                rect,
+                                6.0,
+                                color.gamma_multiply(0.08),
+                                stroke,
+                                egui::StrokeKind::Inside,
+                            );
+                            clipped.text(
+                                rect.min + Vec2::new(PADDING, 6.0),
+                                Align2::LEFT_TOP,
+                                &diagram_box.title,
+                                FontId::proportional(13.0),
+                                text_color,
+                            );
+                            if let Some(detail) = &diagram_box.detail {
+                                clipped.text(
+                                    rect.min + Vec2::new(PADDING, 22.0),
+                                    Align2::LEFT_TOP,
+                                    detail,
+                                    FontId::proportional(11.0),
+                                    weak_color,
+                                );
+                            }
+                        }
+                        BoxKind::Chip => {
+                            painter.rect(
+                                rect,
+                                3.0,
+                                color.gamma_multiply(0.25),
+                                stroke,
+                                egui::StrokeKind::Inside,
+                            );
+                            clipped.text(
+                                rect.left_center() + Vec2::new(6.0, 0.0),
+                                Align2::LEFT_CENTER,
+                                &diagram_box.title,
+                                FontId::proportional(12.0),
+                                text_color,
+                            );
+                        }
+                    }
+                }
+            });
+        clicked
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn resource(resource_type: &str, id: &str, properties: Value) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: id.to_string(),
+            display_name: id.to_string(),
+            status: None,
+            properties,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: Color32::WHITE,
+            region_color: Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    fn sample() -> Vec<ResourceEntry> {
+        vec![
+            resource(
+                "AWS::EC2::VPC",
+                "vpc-1",
+                json!({ "CidrBlock": "10.0.0.0/16" }),
+            ),
+            resource(
+                "AWS::EC2::Subnet",
+                "subnet-a",
+                json!({ "VpcId": "vpc-1", "CidrBlock": "10.0.1.0/24", "AvailabilityZone": "us-east-1a" }),
+            ),
+            resource(
+                "AWS::EC2::Subnet",
+                "subnet-b",
+                json!({ "VpcId": "vpc-1", "CidrBlock": "10.0.2.0/24", "AvailabilityZone": "us-east-1b" }),
+            ),
+            resource(
+                "AWS::EC2::RouteTable",
+                "rtb-main",
+                json!({ "VpcId": "vpc-1", "Associations": [{ "Main": true }] }),
+            ),
+            resource(
+                "AWS::EC2::RouteTable",
+                "rtb-public",
+                json!({ "VpcId": "vpc-1", "Associations": [{ "Main": false, "SubnetId": "subnet-a" }] }),
+            ),
+            resource(
+                "AWS::EC2::InternetGateway",
+                "igw-1",
+                json!({ "Attachments": [{ "VpcId": "vpc-1", "State": "available" }] }),
+            ),
+            resource(
+                "AWS::EC2::Instance",
+                "i-1",
+                json!({ "SubnetId": "subnet-a", "VpcId": "vpc-1" }),
+            ),
+            resource(
+                "AWS::EC2::NetworkInterface",
+                "eni-attached",
+                json!({ "SubnetId": "subnet-a", "VpcId": "vpc-1", "Attachment": { "InstanceId": "i-1" } }),
+            ),
+            resource(
+                "AWS::EC2::NatGateway",
+                "nat-1",
+                json!({ "SubnetId": "subnet-a", "VpcId": "vpc-1" }),
+            ),
+            // Subnet and VPC not loaded
+            resource(
+                "AWS::EC2::Instance",
+                "i-2",
+                json!({ "SubnetId": "subnet-x", "VpcId": "vpc-2" }),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_build_places_resources_in_their_subnets() {
+        let topology = NetworkTopology::build(&sample());
+        assert_eq!(topology.vpcs.len(), 2);
+
+        let vpc = &topology.vpcs[0];
+        assert_eq!(vpc.vpc_id, "vpc-1");
+        assert_eq!(vpc.cidr.as_deref(), Some("10.0.0.0/16"));
+        assert_eq!(vpc.gateways[0].resource_id, "igw-1");
+        assert_eq!(vpc.route_tables.len(), 2);
+        assert_eq!(vpc.main_route_table_id.as_deref(), Some("rtb-main"));
+
+        let subnet_a = &vpc.subnets[0];
+        assert_eq!(subnet_a.subnet_id, "subnet-a");
+        assert_eq!(subnet_a.route_table_id.as_deref(), Some("rtb-public"));
+        // The attached interface is shown by its instance
+        let members: Vec<&str> = subnet_a
+            .members
+            .iter()
+            .map(|m| m.resource_id.as_str())
+            .collect();
+        assert_eq!(members, vec!["i-1", "nat-1"]);
+        assert_eq!(vpc.subnets[1].route_table_id.as_deref(), Some("rtb-main"));
+
+        let placeholder = &topology.vpcs[1];
+        assert!(placeholder.item.is_none());
+        assert!(placeholder.subnets[0].item.is_none());
+        assert_eq!(placeholder.subnets[0].members[0].resource_id, "i-2");
+    }
+
+    #[test]
+    fn test_layout_nests_boxes() {
+        let topology = NetworkTopology::build(&sample());
+        let (boxes, height) = layout(&topology, 600.0);
+
+        let vpc = boxes
+            .iter()
+            .find(|b| b.kind == BoxKind::Vpc && b.selection_key.is_some())
+            .unwrap();
+        let subnets: Vec<&DiagramBox> = boxes
+            .iter()
+            .filter(|b| b.kind == BoxKind::Subnet && b.selection_key.is_some())
+            .collect();
+        assert_eq!(subnets.len(), 2);
+        for subnet in &subnets {
+            assert!(vpc.rect.contains_rect(subnet.rect));
+        }
+        // Subnets of a row share a height
+        assert_eq!(subnets[0].rect.height(), subnets[1].rect.height());
+
+        let instance = boxes
+            .iter()
+            .find(|b| b.title.starts_with("Instance: i-1"))
+            .unwrap();
+        assert!(subnets[0].rect.contains_rect(instance.rect));
+        assert!(boxes.iter().all(|b| b.rect.max.y <= height));
+    }
+}
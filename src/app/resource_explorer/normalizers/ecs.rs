@@ -390,3 +390,83 @@ impl AsyncResourceNormalizer for ECSTaskDefinitionNormalizer {
         "AWS::ECS::TaskDefinition"
     }
 }
+
+/// Normalizer for container definitions of running ECS tasks (child resources of tasks)
+pub struct ECSContainerDefinitionNormalizer;
+
+#[async_trait]
+impl AsyncResourceNormalizer for ECSContainerDefinitionNormalizer {
+    async fn normalize(
+        &self,
+        raw_response: serde_json::Value,
+        account: &str,
+        region: &str,
+        query_timestamp: DateTime<Utc>,
+        _aws_client: &AWSResourceClient,
+    ) -> Result<ResourceEntry> {
+        let container_id = raw_response
+            .get("ContainerId")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown-container")
+            .to_string();
+
+        let display_name = raw_response
+            .get("Name")
+            .and_then(|v| v.as_str())
+            .unwrap_or(&container_id)
+            .to_string();
+
+        let status = raw_response
+            .get("LastStatus")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string());
+
+        // Container definitions are not taggable
+        Ok(ResourceEntry {
+            resource_type: "AWS::ECS::ContainerDefinition".to_string(),
+            account_id: account.to_string(),
+            region: region.to_string(),
+            resource_id: container_id,
+            display_name,
+            status,
+            properties: raw_response,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: assign_account_color(account),
+            region_color: assign_region_color(region),
+            query_timestamp,
+        })
+    }
+
+    fn extract_relationships(
+        &self,
+        entry: &ResourceEntry,
+        all_resources: &[ResourceEntry],
+    ) -> Vec<ResourceRelationship> {
+        let mut relationships = Vec::new();
+
+        // Map to the log group the container writes to
+        if let Some(log_group) = entry.properties.get("LogGroup").and_then(|v| v.as_str()) {
+            if all_resources
+                .iter()
+                .any(|r| r.resource_type == "AWS::Logs::LogGroup" && r.resource_id == log_group)
+            {
+                relationships.push(ResourceRelationship {
+                    relationship_type: RelationshipType::Uses,
+                    target_resource_id: log_group.to_string(),
+                    target_resource_type: "AWS::Logs::LogGroup".to_string(),
+                });
+            }
+        }
+
+        relationships
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::ECS::ContainerDefinition"
+    }
+}
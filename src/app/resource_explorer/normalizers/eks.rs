@@ -89,3 +89,97 @@ impl AsyncResourceNormalizer for EKSClusterNormalizer {
         "AWS::EKS::Cluster"
     }
 }
+
+/// Normalizer for EKS managed node groups (child resources of clusters)
+pub struct EKSNodegroupNormalizer;
+
+#[async_trait]
+impl AsyncResourceNormalizer for EKSNodegroupNormalizer {
+    async fn normalize(
+        &self,
+        raw_response: serde_json::Value,
+        account: &str,
+        region: &str,
+        query_timestamp: DateTime<Utc>,
+        aws_client: &AWSResourceClient,
+    ) -> Result<ResourceEntry> {
+        let nodegroup_name = raw_response
+            .get("NodegroupName")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown-nodegroup")
+            .to_string();
+        let cluster_name = raw_response
+            .get("ClusterName")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown-cluster");
+
+        // Node group names are only unique within a cluster
+        let resource_id = format!("{}:{}", cluster_name, nodegroup_name);
+        let status = extract_status(&raw_response);
+
+        let tags = match raw_response.get("NodegroupArn").and_then(|v| v.as_str()) {
+            Some(arn) => aws_client
+                .fetch_tags_for_resource("AWS::EKS::Nodegroup", arn, account, region)
+                .await
+                .unwrap_or_else(|e| {
+                    tracing::warn!(
+                        "Failed to fetch tags for AWS::EKS::Nodegroup {}: {}",
+                        resource_id,
+                        e
+                    );
+
+                    Vec::new()
+                }),
+            None => Vec::new(),
+        };
+
+        Ok(ResourceEntry {
+            resource_type: "AWS::EKS::Nodegroup".to_string(),
+            account_id: account.to_string(),
+            region: region.to_string(),
+            resource_id,
+            display_name: nodegroup_name,
+            status,
+            properties: raw_response,
+            detailed_timestamp: None,
+            tags,
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: assign_account_color(account),
+            region_color: assign_region_color(region),
+            query_timestamp,
+        })
+    }
+
+    fn extract_relationships(
+        &self,
+        entry: &ResourceEntry,
+        all_resources: &[ResourceEntry],
+    ) -> Vec<ResourceRelationship> {
+        let mut relationships = Vec::new();
+
+        // Find the subnets the nodes run in
+        if let Some(subnets) = entry.properties.get("Subnets").and_then(|v| v.as_array()) {
+            for subnet_id in subnets.iter().filter_map(|v| v.as_str()) {
+                if all_resources
+                    .iter()
+                    .any(|r| r.resource_type == "AWS::EC2::Subnet" && r.resource_id == subnet_id)
+                {
+                    relationships.push(ResourceRelationship {
+                        relationship_type: RelationshipType::Uses,
+                        target_resource_id: subnet_id.to_string(),
+                        target_resource_type: "AWS::EC2::Subnet".to_string(),
+                    });
+                }
+            }
+        }
+
+        relationships
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::EKS::Nodegroup"
+    }
+}
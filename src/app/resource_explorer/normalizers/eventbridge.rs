@@ -157,8 +157,38 @@ impl AsyncResourceNormalizer for EventBridgeRuleNormalizer {
             }
         }
 
-        // Could potentially analyze targets from rule configuration
-        // but that would require additional API calls or parsing rule target configurations
+        // Map targets (and their dead-letter queues) to loaded resources
+        let targets = entry
+            .properties
+            .get("Targets")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten();
+        for target in targets {
+            let links = [
+                (target.get("Arn"), RelationshipType::Triggers),
+                (
+                    target.pointer("/DeadLetterConfig/Arn"),
+                    RelationshipType::DeadLetterQueue,
+                ),
+            ];
+            for (arn, relationship_type) in links {
+                let Some((target_type, target_id)) =
+                    arn.and_then(|v| v.as_str()).and_then(target_resource)
+                else {
+                    continue;
+                };
+                if all_resources.iter().any(|resource| {
+                    resource.resource_type == target_type && resource.resource_id == target_id
+                }) {
+                    relationships.push(ResourceRelationship {
+                        relationship_type,
+                        target_resource_id: target_id,
+                        target_resource_type: target_type.to_string(),
+                    });
+                }
+            }
+        }
 
         relationships
     }
@@ -167,3 +197,75 @@ impl AsyncResourceNormalizer for EventBridgeRuleNormalizer {
         "AWS::Events::Rule"
     }
 }
+
+/// Resource type and ID of a rule target from its ARN
+///
+/// IDs follow the normalizers of the target types: names for Lambda functions,
+/// SQS queues and event buses, the full ARN for SNS topics and state machines.
+pub fn target_resource(arn: &str) -> Option<(&'static str, String)> {
+    // arn:partition:service:region:account:resource
+    let mut parts = arn.splitn(6, ':');
+    let (Some("arn"), Some(_), Some(service), Some(_), Some(_), Some(resource)) = (
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+        parts.next(),
+    ) else {
+        return None;
+    };
+
+    match service {
+        // function:name[:qualifier]
+        "lambda" => resource
+            .strip_prefix("function:")
+            .and_then(|rest| rest.split(':').next())
+            .map(|name| ("AWS::Lambda::Function", name.to_string())),
+        "sqs" => Some(("AWS::SQS::Queue", resource.to_string())),
+        "sns" => Some(("AWS::SNS::Topic", arn.to_string())),
+        "states" => resource
+            .starts_with("stateMachine:")
+            .then(|| ("AWS::StepFunctions::StateMachine", arn.to_string())),
+        "events" => resource
+            .strip_prefix("event-bus/")
+            .map(|name| ("AWS::Events::EventBus", name.to_string())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_resource_from_arn() {
+        assert_eq!(
+            target_resource("arn:aws:lambda:us-east-1:123456789012:function:process-order:live"),
+            Some(("AWS::Lambda::Function", "process-order".to_string()))
+        );
+        assert_eq!(
+            target_resource("arn:aws:sqs:us-east-1:123456789012:orders-dlq"),
+            Some(("AWS::SQS::Queue", "orders-dlq".to_string()))
+        );
+        assert_eq!(
+            target_resource("arn:aws:states:us-east-1:123456789012:stateMachine:fulfil"),
+            Some((
+                "AWS::StepFunctions::StateMachine",
+                "arn:aws:states:us-east-1:123456789012:stateMachine:fulfil".to_string()
+            ))
+        );
+        assert_eq!(
+            target_resource("arn:aws:sns:us-east-1:123456789012:alerts"),
+            Some((
+                "AWS::SNS::Topic",
+                "arn:aws:sns:us-east-1:123456789012:alerts".to_string()
+            ))
+        );
+        assert_eq!(
+            target_resource("arn:aws:kinesis:us-east-1:123456789012:stream/clicks"),
+            None
+        );
+        assert_eq!(target_resource("not-an-arn"), None);
+    }
+}
@@ -1,4 +1,7 @@
 use super::*;
+use crate::app::resource_explorer::threat_findings::{
+    findings_summary_json, global_findings, FindingSource,
+};
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -10,7 +13,7 @@ pub struct GuardDutyDetectorNormalizer;
 impl AsyncResourceNormalizer for GuardDutyDetectorNormalizer {
     async fn normalize(
         &self,
-        raw_response: serde_json::Value,
+        mut raw_response: serde_json::Value,
         account: &str,
         region: &str,
         query_timestamp: DateTime<Utc>,
@@ -48,6 +51,35 @@ impl AsyncResourceNormalizer for GuardDutyDetectorNormalizer {
                 Vec::new()
             });
 
+        // Active findings feed the tree severity badges and the findings window
+        if status
+            .as_deref()
+            .is_some_and(|s| s.eq_ignore_ascii_case("enabled"))
+        {
+            match aws_client
+                .get_guardduty_service()
+                .list_active_findings(account, region, &resource_id)
+                .await
+            {
+                Ok(findings) => {
+                    if let Some(properties) = raw_response.as_object_mut() {
+                        properties.insert(
+                            "ActiveFindings".to_string(),
+                            findings_summary_json(&findings),
+                        );
+                    }
+                    global_findings().replace(FindingSource::GuardDuty, account, region, findings);
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to fetch GuardDuty findings for detector {}: {:#}",
+                        resource_id,
+                        e
+                    );
+                }
+            }
+        }
+
         Ok(ResourceEntry {
             resource_type: "AWS::GuardDuty::Detector".to_string(),
             account_id: account.to_string(),
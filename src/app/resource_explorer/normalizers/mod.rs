@@ -554,7 +554,9 @@ impl NormalizerFactory {
             "AWS::ECS::Service" => Some(Box::new(ECSServiceNormalizer)),
             "AWS::ECS::Task" => Some(Box::new(ECSTaskNormalizer)),
             "AWS::ECS::TaskDefinition" => Some(Box::new(ECSTaskDefinitionNormalizer)),
+            "AWS::ECS::ContainerDefinition" => Some(Box::new(ECSContainerDefinitionNormalizer)),
             "AWS::EKS::Cluster" => Some(Box::new(EKSClusterNormalizer)),
+            "AWS::EKS::Nodegroup" => Some(Box::new(EKSNodegroupNormalizer)),
             "AWS::ElasticLoadBalancing::LoadBalancer" => Some(Box::new(ELBLoadBalancerNormalizer)),
             "AWS::ElasticLoadBalancingV2::LoadBalancer" => {
                 Some(Box::new(ELBv2LoadBalancerNormalizer))
@@ -577,6 +579,8 @@ impl NormalizerFactory {
             "AWS::Glue::Job" => Some(Box::new(GlueResourceNormalizer)),
             "AWS::Athena::WorkGroup" => Some(Box::new(AthenaResourceNormalizer)),
             "AWS::Route53::HostedZone" => Some(Box::new(Route53HostedZoneNormalizer)),
+            "AWS::Route53::RecordSet" => Some(Box::new(Route53RecordSetNormalizer)),
+            "AWS::Route53::HealthCheck" => Some(Box::new(Route53HealthCheckNormalizer)),
             "AWS::EFS::FileSystem" => Some(Box::new(EfsFileSystemNormalizer)),
             "AWS::CloudTrail::Trail" => Some(Box::new(CloudTrailNormalizer)),
             "AWS::CloudTrail::Event" => Some(Box::new(CloudTrailEventNormalizer)),
@@ -747,7 +751,9 @@ impl NormalizerFactory {
             "AWS::ECS::Service",
             "AWS::ECS::Task",
             "AWS::ECS::TaskDefinition",
+            "AWS::ECS::ContainerDefinition",
             "AWS::EKS::Cluster",
+            "AWS::EKS::Nodegroup",
             "AWS::ElasticLoadBalancing::LoadBalancer",
             "AWS::ElasticLoadBalancingV2::LoadBalancer",
             "AWS::ElasticLoadBalancingV2::TargetGroup",
@@ -766,6 +772,8 @@ impl NormalizerFactory {
             "AWS::Glue::Job",
             "AWS::Athena::WorkGroup",
             "AWS::Route53::HostedZone",
+            "AWS::Route53::RecordSet",
+            "AWS::Route53::HealthCheck",
             "AWS::EFS::FileSystem",
             "AWS::CloudTrail::Trail",
             "AWS::CloudTrail::Event",
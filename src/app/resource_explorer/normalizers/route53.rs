@@ -74,3 +74,299 @@ impl AsyncResourceNormalizer for Route53HostedZoneNormalizer {
         "AWS::Route53::HostedZone"
     }
 }
+
+/// Normalizer for Route53 record sets (child resources of hosted zones)
+pub struct Route53RecordSetNormalizer;
+
+#[async_trait]
+impl AsyncResourceNormalizer for Route53RecordSetNormalizer {
+    async fn normalize(
+        &self,
+        raw_response: serde_json::Value,
+        account: &str,
+        region: &str,
+        query_timestamp: DateTime<Utc>,
+        _aws_client: &AWSResourceClient,
+    ) -> Result<ResourceEntry> {
+        let resource_id = raw_response
+            .get("Id")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown-record-set")
+            .to_string();
+        let name = raw_response
+            .get("Name")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown-record")
+            .trim_end_matches('.');
+        let record_type = raw_response
+            .get("Type")
+            .and_then(|v| v.as_str())
+            .unwrap_or("?");
+        let display_name = match raw_response.get("SetIdentifier").and_then(|v| v.as_str()) {
+            Some(set_identifier) => format!("{} {} [{}]", name, record_type, set_identifier),
+            None => format!("{} {}", name, record_type),
+        };
+
+        Ok(ResourceEntry {
+            resource_type: "AWS::Route53::RecordSet".to_string(),
+            account_id: account.to_string(),
+            region: region.to_string(),
+            resource_id,
+            display_name,
+            status: None,
+            properties: raw_response,
+            detailed_timestamp: None,
+            // Record sets cannot be tagged
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: assign_account_color(account),
+            region_color: assign_region_color(region),
+            query_timestamp,
+        })
+    }
+
+    fn extract_relationships(
+        &self,
+        entry: &ResourceEntry,
+        all_resources: &[ResourceEntry],
+    ) -> Vec<ResourceRelationship> {
+        let mut relationships = Vec::new();
+
+        // Keep the link to the hosted zone the record was listed from
+        if let (Some(parent_id), Some(parent_type)) =
+            (&entry.parent_resource_id, &entry.parent_resource_type)
+        {
+            relationships.push(ResourceRelationship {
+                relationship_type: RelationshipType::ChildOf,
+                target_resource_id: parent_id.clone(),
+                target_resource_type: parent_type.clone(),
+            });
+        }
+
+        // Resolve alias targets to the load balancer or distribution they name
+        if let Some(alias_dns_name) = entry
+            .properties
+            .get("AliasTarget")
+            .and_then(|alias| alias.get("DNSName"))
+            .and_then(|v| v.as_str())
+        {
+            let alias_key = dns_name_key(alias_dns_name);
+            for resource in all_resources {
+                if resource.account_id != entry.account_id {
+                    continue;
+                }
+                let target_dns_name = match resource.resource_type.as_str() {
+                    "AWS::ElasticLoadBalancing::LoadBalancer"
+                    | "AWS::ElasticLoadBalancingV2::LoadBalancer" => {
+                        resource.properties.get("DNSName")
+                    }
+                    "AWS::CloudFront::Distribution" => resource.properties.get("DomainName"),
+                    _ => None,
+                };
+                if target_dns_name
+                    .and_then(|v| v.as_str())
+                    .is_some_and(|dns_name| dns_name_key(dns_name) == alias_key)
+                {
+                    relationships.push(ResourceRelationship {
+                        relationship_type: RelationshipType::Uses,
+                        target_resource_id: resource.resource_id.clone(),
+                        target_resource_type: resource.resource_type.clone(),
+                    });
+                }
+            }
+        }
+
+        // Find the health check that gates the record
+        if let Some(health_check_id) = entry
+            .properties
+            .get("HealthCheckId")
+            .and_then(|v| v.as_str())
+        {
+            if all_resources.iter().any(|r| {
+                r.resource_type == "AWS::Route53::HealthCheck" && r.resource_id == health_check_id
+            }) {
+                relationships.push(ResourceRelationship {
+                    relationship_type: RelationshipType::Uses,
+                    target_resource_id: health_check_id.to_string(),
+                    target_resource_type: "AWS::Route53::HealthCheck".to_string(),
+                });
+            }
+        }
+
+        relationships
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::Route53::RecordSet"
+    }
+}
+
+/// Comparable form of a DNS name: lowercase, without the trailing dot or the
+/// "dualstack." prefix Route53 adds to load balancer alias targets
+fn dns_name_key(dns_name: &str) -> String {
+    let dns_name = dns_name.trim_end_matches('.').to_lowercase();
+    match dns_name.strip_prefix("dualstack.") {
+        Some(stripped) => stripped.to_string(),
+        None => dns_name,
+    }
+}
+
+/// Normalizer for Route53 Health Checks
+pub struct Route53HealthCheckNormalizer;
+
+#[async_trait]
+impl AsyncResourceNormalizer for Route53HealthCheckNormalizer {
+    async fn normalize(
+        &self,
+        raw_response: serde_json::Value,
+        account: &str,
+        region: &str,
+        query_timestamp: DateTime<Utc>,
+        _aws_client: &AWSResourceClient,
+    ) -> Result<ResourceEntry> {
+        let resource_id = raw_response
+            .get("Id")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown-health-check")
+            .to_string();
+
+        let display_name = extract_display_name(&raw_response, &resource_id);
+        let status = extract_status(&raw_response);
+
+        Ok(ResourceEntry {
+            resource_type: "AWS::Route53::HealthCheck".to_string(),
+            account_id: account.to_string(),
+            region: region.to_string(),
+            resource_id,
+            display_name,
+            status,
+            properties: raw_response,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: assign_account_color(account),
+            region_color: assign_region_color(region),
+            query_timestamp,
+        })
+    }
+
+    fn extract_relationships(
+        &self,
+        entry: &ResourceEntry,
+        _all_resources: &[ResourceEntry],
+    ) -> Vec<ResourceRelationship> {
+        // Calculated health checks aggregate other health checks
+        entry
+            .properties
+            .get("ChildHealthChecks")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|v| v.as_str())
+            .map(|child_id| ResourceRelationship {
+                relationship_type: RelationshipType::Uses,
+                target_resource_id: child_id.to_string(),
+                target_resource_type: "AWS::Route53::HealthCheck".to_string(),
+            })
+            .collect()
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::Route53::HealthCheck"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(
+        resource_type: &str,
+        resource_id: &str,
+        properties: serde_json::Value,
+    ) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: resource_id.to_string(),
+            display_name: resource_id.to_string(),
+            status: None,
+            properties,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::GRAY,
+            region_color: egui::Color32::GRAY,
+            query_timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_alias_targets_resolve_to_load_balancers_and_distributions() {
+        let alb = entry(
+            "AWS::ElasticLoadBalancingV2::LoadBalancer",
+            "arn:aws:elasticloadbalancing:us-east-1:123456789012:loadbalancer/app/web/abc",
+            serde_json::json!({"DNSName": "web-123.us-east-1.elb.amazonaws.com"}),
+        );
+        let distribution = entry(
+            "AWS::CloudFront::Distribution",
+            "E2EXAMPLE",
+            serde_json::json!({"DomainName": "d111111abcdef8.cloudfront.net"}),
+        );
+        let health_check = entry("AWS::Route53::HealthCheck", "hc-1", serde_json::json!({}));
+        let mut www = entry(
+            "AWS::Route53::RecordSet",
+            "/hostedzone/Z1/www.example.com./A",
+            serde_json::json!({
+                "AliasTarget": {"DNSName": "dualstack.WEB-123.us-east-1.elb.amazonaws.com."},
+                "HealthCheckId": "hc-1"
+            }),
+        );
+        www.parent_resource_id = Some("/hostedzone/Z1".to_string());
+        www.parent_resource_type = Some("AWS::Route53::HostedZone".to_string());
+        let cdn = entry(
+            "AWS::Route53::RecordSet",
+            "/hostedzone/Z1/cdn.example.com./A",
+            serde_json::json!({"AliasTarget": {"DNSName": "d111111abcdef8.cloudfront.net."}}),
+        );
+        let all = vec![
+            alb.clone(),
+            distribution.clone(),
+            health_check,
+            www.clone(),
+            cdn.clone(),
+        ];
+
+        let relationships = Route53RecordSetNormalizer.extract_relationships(&www, &all);
+        let targets: Vec<&str> = relationships
+            .iter()
+            .map(|r| r.target_resource_type.as_str())
+            .collect();
+        assert_eq!(
+            targets,
+            [
+                "AWS::Route53::HostedZone",
+                "AWS::ElasticLoadBalancingV2::LoadBalancer",
+                "AWS::Route53::HealthCheck"
+            ]
+        );
+        assert_eq!(relationships[1].target_resource_id, alb.resource_id);
+
+        let relationships = Route53RecordSetNormalizer.extract_relationships(&cdn, &all);
+        assert_eq!(relationships.len(), 1);
+        assert_eq!(
+            relationships[0].target_resource_id,
+            distribution.resource_id
+        );
+    }
+}
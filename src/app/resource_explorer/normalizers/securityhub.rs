@@ -1,5 +1,8 @@
 use super::utils::*;
 use super::*;
+use crate::app::resource_explorer::threat_findings::{
+    findings_summary_json, global_findings, FindingSource,
+};
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
@@ -11,7 +14,7 @@ pub struct SecurityHubNormalizer;
 impl AsyncResourceNormalizer for SecurityHubNormalizer {
     async fn normalize(
         &self,
-        raw_response: serde_json::Value,
+        mut raw_response: serde_json::Value,
         account: &str,
         region: &str,
         query_timestamp: DateTime<Utc>,
@@ -41,6 +44,31 @@ impl AsyncResourceNormalizer for SecurityHubNormalizer {
                 Vec::new()
             });
 
+        // Active findings feed the tree severity badges and the findings window
+        match aws_client
+            .get_securityhub_service()
+            .list_active_findings(account, region)
+            .await
+        {
+            Ok(findings) => {
+                if let Some(properties) = raw_response.as_object_mut() {
+                    properties.insert(
+                        "ActiveFindings".to_string(),
+                        findings_summary_json(&findings),
+                    );
+                }
+                global_findings().replace(FindingSource::SecurityHub, account, region, findings);
+            }
+            Err(e) => {
+                tracing::warn!(
+                    "Failed to fetch Security Hub findings in {}/{}: {:#}",
+                    account,
+                    region,
+                    e
+                );
+            }
+        }
+
         Ok(ResourceEntry {
             resource_type: "AWS::SecurityHub::Hub".to_string(),
             account_id: account.to_string(),
@@ -0,0 +1,238 @@
+//! AWS Organizations structure for the Explorer
+//!
+//! Discovered once per login from the management account, when its role is
+//! permitted to list the organization. Maps every account to its OU path, which
+//! the account selector groups by and [`GroupingMode::ByOrganizationalUnit`]
+//! groups resources by. With cross-account roles enabled, organization accounts
+//! Identity Center does not list are added to the session so they can be queried.
+//!
+//! [`GroupingMode::ByOrganizationalUnit`]: super::state::GroupingMode::ByOrganizationalUnit
+
+use super::aws_services::OrganizationsService;
+use super::credentials::CredentialCoordinator;
+use super::global_services::get_global_query_region;
+use crate::app::aws_identity::{AwsAccount, AwsIdentityCenter, LoginState};
+use crate::app::credential_sources::role_name_from_arn;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+use tracing::{info, warn};
+
+/// Group label for accounts outside the discovered organization
+pub const NO_OU_LABEL: &str = "(no organizational unit)";
+
+/// An account of the organization and where it sits in the OU tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrganizationAccount {
+    pub account_id: String,
+    pub name: String,
+    pub email: Option<String>,
+    /// `ACTIVE`, `SUSPENDED` or `PENDING_CLOSURE`
+    pub status: Option<String>,
+    /// OU names from the root down; empty for accounts directly under the root
+    pub ou_path: Vec<String>,
+}
+
+impl OrganizationAccount {
+    pub fn is_active(&self) -> bool {
+        matches!(self.status.as_deref(), None | Some("ACTIVE"))
+    }
+}
+
+/// Format an OU path as `Root / Workloads / Prod`
+pub fn format_ou_path(ou_path: &[String]) -> String {
+    std::iter::once("Root")
+        .chain(ou_path.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join(" / ")
+}
+
+/// Progress of the organization discovery
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum DiscoveryStatus {
+    #[default]
+    NotStarted,
+    Running,
+    Done,
+    /// Not logged in to the management account, or not permitted to list the organization
+    Unavailable(String),
+}
+
+#[derive(Default)]
+struct OrganizationDirectory {
+    status: DiscoveryStatus,
+    accounts: HashMap<String, OrganizationAccount>,
+}
+
+static DIRECTORY: Lazy<RwLock<OrganizationDirectory>> = Lazy::new(Default::default);
+
+pub fn discovery_status() -> DiscoveryStatus {
+    DIRECTORY
+        .read()
+        .map(|directory| directory.status.clone())
+        .unwrap_or_default()
+}
+
+/// OU path of `account_id`, formatted with [`format_ou_path`]
+pub fn ou_path(account_id: &str) -> Option<String> {
+    let directory = DIRECTORY.read().ok()?;
+    directory
+        .accounts
+        .get(account_id)
+        .map(|account| format_ou_path(&account.ou_path))
+}
+
+/// Whether any accounts have been discovered
+pub fn has_accounts() -> bool {
+    DIRECTORY
+        .read()
+        .is_ok_and(|directory| !directory.accounts.is_empty())
+}
+
+/// Forget the discovered organization, e.g. after logout
+pub fn clear() {
+    if let Ok(mut directory) = DIRECTORY.write() {
+        *directory = OrganizationDirectory::default();
+    }
+}
+
+fn finish(status: DiscoveryStatus, accounts: Vec<OrganizationAccount>) {
+    if let Ok(mut directory) = DIRECTORY.write() {
+        directory.status = status;
+        directory.accounts = accounts
+            .into_iter()
+            .map(|account| (account.account_id.clone(), account))
+            .collect();
+    }
+}
+
+/// Discover the organization behind `identity` in the background, once per login
+///
+/// Waits until the session is logged in and knows the management account (or
+/// the account of the default role).
+pub fn ensure_discovery(
+    identity: &Arc<Mutex<AwsIdentityCenter>>,
+    credential_coordinator: Arc<CredentialCoordinator>,
+) {
+    if discovery_status() != DiscoveryStatus::NotStarted {
+        return;
+    }
+    let account_id = {
+        let Ok(identity) = identity.try_lock() else {
+            return;
+        };
+        if identity.login_state != LoginState::LoggedIn {
+            return;
+        }
+        match identity
+            .sso_management_account_id
+            .clone()
+            .or_else(|| identity.default_role_account_id.clone())
+        {
+            Some(account_id) => account_id,
+            None => return,
+        }
+    };
+    match DIRECTORY.write() {
+        Ok(mut directory) if directory.status == DiscoveryStatus::NotStarted => {
+            directory.status = DiscoveryStatus::Running;
+        }
+        _ => return,
+    }
+    if super::demo_data::is_demo_mode() {
+        finish(DiscoveryStatus::Done, super::demo_data::organization_accounts());
+        return;
+    }
+
+    let identity = Arc::clone(identity);
+    std::thread::spawn(move || {
+        let runtime = match tokio::runtime::Runtime::new() {
+            Ok(runtime) => runtime,
+            Err(e) => {
+                finish(
+                    DiscoveryStatus::Unavailable(format!("Failed to create Tokio runtime: {}", e)),
+                    Vec::new(),
+                );
+                return;
+            }
+        };
+
+        let service = OrganizationsService::new(credential_coordinator);
+        match runtime
+            .block_on(service.discover_account_tree(&account_id, get_global_query_region()))
+        {
+            Ok(accounts) => {
+                info!(
+                    "Discovered {} accounts in the organization of {}",
+                    accounts.len(),
+                    account_id
+                );
+                add_reachable_accounts(&identity, &accounts);
+                finish(DiscoveryStatus::Done, accounts);
+            }
+            Err(e) => {
+                warn!("AWS Organizations discovery unavailable: {:#}", e);
+                finish(DiscoveryStatus::Unavailable(format!("{:#}", e)), Vec::new());
+            }
+        }
+    });
+}
+
+/// Add active organization accounts missing from the session, when cross-account
+/// roles make them reachable
+fn add_reachable_accounts(
+    identity: &Arc<Mutex<AwsIdentityCenter>>,
+    accounts: &[OrganizationAccount],
+) {
+    let Ok(mut identity) = identity.lock() else {
+        return;
+    };
+    if !identity.cross_account_roles.is_enabled() {
+        return;
+    }
+    for account in accounts.iter().filter(|account| account.is_active()) {
+        if identity
+            .accounts
+            .iter()
+            .any(|known| known.account_id == account.account_id)
+        {
+            continue;
+        }
+        let role_arn = identity.cross_account_roles.role_arn(&account.account_id);
+        let role_name = role_name_from_arn(&role_arn)
+            .unwrap_or(&role_arn)
+            .to_string();
+        identity.update_account(AwsAccount {
+            account_id: account.account_id.clone(),
+            account_name: account.name.clone(),
+            account_email: account.email.clone(),
+            role_name,
+            credentials: None,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ou_path_format_and_status() {
+        assert_eq!(format_ou_path(&[]), "Root");
+        assert_eq!(
+            format_ou_path(&["Workloads".to_string(), "Prod".to_string()]),
+            "Root / Workloads / Prod"
+        );
+
+        let mut account = OrganizationAccount {
+            account_id: "111111111111".to_string(),
+            name: "prod".to_string(),
+            email: None,
+            status: Some("ACTIVE".to_string()),
+            ou_path: Vec::new(),
+        };
+        assert!(account.is_active());
+        account.status = Some("SUSPENDED".to_string());
+        assert!(!account.is_active());
+    }
+}
@@ -11,7 +11,7 @@
 use super::aws_client::AWSResourceClient;
 use super::cache::SharedResourceCache;
 use super::state::{QueryScope, ResourceEntry};
-use super::GlobalServiceRegistry;
+use super::global_services::{global_registry, GLOBAL_REGION};
 use std::sync::Arc;
 
 /// Progress events emitted during query execution
@@ -373,7 +373,9 @@ impl ResourceQueryEngine {
                 );
 
                 tracing::info!("Query Engine: tokio::join! completed, all branches finished");
-                let final_resources = all_resources.lock().await.clone();
+                // The same global resource can arrive from several queries
+                let final_resources =
+                    global_registry().dedupe_resources(all_resources.lock().await.clone());
                 tracing::info!("Query Engine: Locked and cloned final_resources ({} items)", final_resources.len());
 
                 Ok(final_resources)
@@ -530,8 +532,8 @@ impl ResourceQueryEngine {
         // Build (cache_key, resource) pairs to track which cache entry each resource belongs to
         let mut work_items: Vec<(String, ResourceEntry)> = Vec::with_capacity(total);
         for resource in resources {
-            let cache_region = if super::global_services::is_global_service(&resource.resource_type) {
-                "Global".to_string()
+            let cache_region = if global_registry().is_global(&resource.resource_type) {
+                GLOBAL_REGION.to_string()
             } else {
                 resource.region.clone()
             };
@@ -691,34 +693,13 @@ impl ResourceQueryEngine {
     /// - Global services: "account_id:Global:resource_type"
     /// - Regional services: "account_id:region_code:resource_type"
     fn build_query_keys(scope: &QueryScope) -> Vec<String> {
-        let global_registry = GlobalServiceRegistry::new();
-        let mut queries_to_track: Vec<String> = Vec::new();
-
-        for account in &scope.accounts {
-            for resource_type in &scope.resource_types {
-                if global_registry.is_global(&resource_type.resource_type) {
-                    // Global services: one query per account with region "Global"
-                    let query_key =
-                        make_query_key(&account.account_id, "Global", &resource_type.resource_type);
-
-                    if !queries_to_track.contains(&query_key) {
-                        queries_to_track.push(query_key);
-                    }
-                } else {
-                    // Regional services: one query per account × region
-                    for region in &scope.regions {
-                        let query_key = make_query_key(
-                            &account.account_id,
-                            &region.region_code,
-                            &resource_type.resource_type,
-                        );
-                        queries_to_track.push(query_key);
-                    }
-                }
-            }
-        }
-
-        queries_to_track
+        global_registry()
+            .query_plan(scope)
+            .iter()
+            .map(|(account_id, region, resource_type)| {
+                make_query_key(account_id, region, resource_type)
+            })
+            .collect()
     }
 }
 
@@ -0,0 +1,1266 @@
+//! Network reachability between two cached resources.
+//!
+//! Given a source and a destination (EC2 instances, network interfaces or RDS
+//! instances) and a port, the analyzer walks the path a packet takes using only
+//! cached data:
+//!
+//! 1. Security groups of the source (egress)
+//! 2. Network ACL of the source subnet (outbound)
+//! 3. Route table of the source subnet
+//! 4. Network ACL of the destination subnet (inbound)
+//! 5. Security groups of the destination (ingress)
+//! 6. Network ACLs again for the reply on ephemeral ports, since NACLs are stateless
+//!
+//! Each check becomes a [`PathStep`] naming the rule that decided it. A check
+//! whose data isn't cached is reported as unknown rather than guessed.
+//! Only IPv4 is evaluated.
+
+use super::cache::shared_cache;
+use super::state::ResourceEntry;
+use egui::{Color32, Context, RichText, Window};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::net::Ipv4Addr;
+
+/// Resource types that can be a source or destination
+pub const ENDPOINT_TYPES: &[&str] = &[
+    "AWS::EC2::Instance",
+    "AWS::EC2::NetworkInterface",
+    "AWS::RDS::DBInstance",
+];
+
+/// Resource types the analysis reads from the cache
+const NETWORK_TYPES: &[&str] = &[
+    "AWS::EC2::SecurityGroup",
+    "AWS::EC2::NetworkAcl",
+    "AWS::EC2::RouteTable",
+    "AWS::EC2::Subnet",
+];
+
+/// Port range a client picks the reply port from (covers Linux and Windows defaults)
+const EPHEMERAL_PORTS: PortRange = PortRange {
+    from: 1024,
+    to: 65535,
+};
+
+/// Check if a resource type can be analyzed
+pub fn is_endpoint_type(resource_type: &str) -> bool {
+    ENDPOINT_TYPES.contains(&resource_type)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Protocol {
+    #[default]
+    Tcp,
+    Udp,
+}
+
+impl Protocol {
+    pub fn label(self) -> &'static str {
+        match self {
+            Protocol::Tcp => "TCP",
+            Protocol::Udp => "UDP",
+        }
+    }
+
+    /// Check a rule protocol ("tcp", "6", "-1" for all)
+    fn matches(self, rule_protocol: &str) -> bool {
+        match (self, rule_protocol.to_ascii_lowercase().as_str()) {
+            (_, "-1") | (_, "all") => true,
+            (Protocol::Tcp, "tcp") | (Protocol::Tcp, "6") => true,
+            (Protocol::Udp, "udp") | (Protocol::Udp, "17") => true,
+            _ => false,
+        }
+    }
+}
+
+/// Outcome of one check, or of the whole path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Allowed,
+    Blocked,
+    /// The data needed for the check isn't cached
+    Unknown,
+}
+
+impl Verdict {
+    pub fn label(self) -> &'static str {
+        match self {
+            Verdict::Allowed => "ALLOW",
+            Verdict::Blocked => "BLOCK",
+            Verdict::Unknown => "UNKNOWN",
+        }
+    }
+
+    pub fn color(self) -> Color32 {
+        match self {
+            Verdict::Allowed => Color32::from_rgb(100, 200, 100),
+            Verdict::Blocked => Color32::from_rgb(255, 100, 100),
+            Verdict::Unknown => Color32::from_rgb(255, 200, 100),
+        }
+    }
+}
+
+/// One evaluated hop of the path
+#[derive(Debug, Clone)]
+pub struct PathStep {
+    pub stage: &'static str,
+    /// Security group, network ACL or route table IDs that were evaluated
+    pub subject: String,
+    pub verdict: Verdict,
+    /// Deciding rule, or what is missing
+    pub detail: String,
+}
+
+/// Evaluated path from source to destination
+#[derive(Debug, Clone)]
+pub struct ReachabilityResult {
+    pub protocol: Protocol,
+    pub port: u16,
+    pub steps: Vec<PathStep>,
+}
+
+impl ReachabilityResult {
+    /// Blocked if any step blocks, unknown if any step is unknown, allowed otherwise
+    pub fn verdict(&self) -> Verdict {
+        let verdicts: Vec<Verdict> = self.steps.iter().map(|step| step.verdict).collect();
+        if verdicts.contains(&Verdict::Blocked) {
+            Verdict::Blocked
+        } else if verdicts.contains(&Verdict::Unknown) {
+            Verdict::Unknown
+        } else {
+            Verdict::Allowed
+        }
+    }
+}
+
+/// An IPv4 CIDR block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cidr {
+    network: u32,
+    prefix: u8,
+}
+
+impl Cidr {
+    /// Parse "10.0.0.0/16", or a bare address as a /32
+    pub fn parse(text: &str) -> Option<Self> {
+        let (address, prefix) = match text.split_once('/') {
+            Some((address, prefix)) => (address, prefix.parse::<u8>().ok()?),
+            None => (text, 32),
+        };
+        if prefix > 32 {
+            return None;
+        }
+        let address: Ipv4Addr = address.trim().parse().ok()?;
+        Some(Self {
+            network: u32::from(address) & Self::mask(prefix),
+            prefix,
+        })
+    }
+
+    fn mask(prefix: u8) -> u32 {
+        if prefix == 0 {
+            0
+        } else {
+            u32::MAX << (32 - prefix)
+        }
+    }
+
+    /// Check if `other` lies entirely within this block
+    pub fn contains(&self, other: &Cidr) -> bool {
+        other.prefix >= self.prefix && other.network & Self::mask(self.prefix) == self.network
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", Ipv4Addr::from(self.network), self.prefix)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PortRange {
+    from: u16,
+    to: u16,
+}
+
+impl PortRange {
+    fn single(port: u16) -> Self {
+        Self {
+            from: port,
+            to: port,
+        }
+    }
+
+    /// Rule port range; missing or -1 bounds mean all ports
+    fn from_bounds(from: Option<i64>, to: Option<i64>) -> Self {
+        let bound = |value: Option<i64>, default: u16| match value {
+            Some(port) if (0..=65535).contains(&port) => port as u16,
+            _ => default,
+        };
+        Self {
+            from: bound(from, 0),
+            to: bound(to, 65535),
+        }
+    }
+
+    fn covers(&self, other: &PortRange) -> bool {
+        self.from <= other.from && other.to <= self.to
+    }
+}
+
+impl fmt::Display for PortRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.from == self.to {
+            write!(f, "{}", self.from)
+        } else {
+            write!(f, "{}-{}", self.from, self.to)
+        }
+    }
+}
+
+/// Where a resource sits in the network
+#[derive(Debug, Clone)]
+pub struct NetworkEndpoint {
+    pub label: String,
+    pub vpc_id: Option<String>,
+    pub subnet_id: Option<String>,
+    pub security_group_ids: Vec<String>,
+    /// Private address, or the subnet's block when the address isn't known
+    pub address: Option<Cidr>,
+    /// Port the resource listens on, if known (RDS endpoints)
+    pub port: Option<u16>,
+}
+
+impl NetworkEndpoint {
+    /// Read the network placement of an EC2 instance, network interface or RDS instance
+    pub fn from_resource(resource: &ResourceEntry, network: &NetworkSnapshot) -> Option<Self> {
+        let properties = &resource.properties;
+        let label = format!("{} ({})", resource.display_name, resource.resource_id);
+        let text =
+            |value: &Value, key: &str| value.get(key).and_then(Value::as_str).map(String::from);
+        let group_ids = |key: &str, id_key: &str| -> Vec<String> {
+            properties
+                .get(key)
+                .and_then(Value::as_array)
+                .map(|groups| {
+                    groups
+                        .iter()
+                        .filter_map(|group| text(group, id_key))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+
+        match resource.resource_type.as_str() {
+            "AWS::EC2::Instance" | "AWS::EC2::NetworkInterface" => {
+                let groups_key = if resource.resource_type == "AWS::EC2::Instance" {
+                    "SecurityGroups"
+                } else {
+                    "Groups"
+                };
+                Some(Self {
+                    label,
+                    vpc_id: text(properties, "VpcId"),
+                    subnet_id: text(properties, "SubnetId"),
+                    security_group_ids: group_ids(groups_key, "GroupId"),
+                    address: text(properties, "PrivateIpAddress")
+                        .and_then(|address| Cidr::parse(&address)),
+                    port: None,
+                })
+            }
+            "AWS::RDS::DBInstance" => {
+                let subnet_group = properties.get("DBSubnetGroup");
+                // The instance runs in the group's subnet in its availability zone
+                let zone = properties.get("AvailabilityZone").and_then(Value::as_str);
+                let subnet_id = subnet_group
+                    .and_then(|group| group.get("Subnets"))
+                    .and_then(Value::as_array)
+                    .and_then(|subnets| {
+                        subnets.iter().find(|subnet| {
+                            subnet
+                                .get("SubnetAvailabilityZone")
+                                .and_then(|zone| zone.get("Name"))
+                                .and_then(Value::as_str)
+                                == zone
+                        })
+                    })
+                    .and_then(|subnet| text(subnet, "SubnetIdentifier"));
+                let address = subnet_id
+                    .as_deref()
+                    .and_then(|subnet_id| network.subnet_cidr(subnet_id));
+                Some(Self {
+                    label,
+                    vpc_id: subnet_group.and_then(|group| text(group, "VpcId")),
+                    subnet_id,
+                    security_group_ids: group_ids("VpcSecurityGroups", "VpcSecurityGroupId"),
+                    address,
+                    port: properties
+                        .get("Endpoint")
+                        .and_then(|endpoint| endpoint.get("Port"))
+                        .and_then(Value::as_u64)
+                        .and_then(|port| u16::try_from(port).ok()),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    fn address_label(&self) -> String {
+        self.address
+            .map(|address| address.to_string())
+            .unwrap_or_else(|| "unknown address".to_string())
+    }
+}
+
+/// Security groups, network ACLs, route tables and subnets the analysis reads
+#[derive(Debug, Default)]
+pub struct NetworkSnapshot {
+    security_groups: HashMap<String, Value>,
+    network_acls: Vec<Value>,
+    route_tables: Vec<Value>,
+    subnets: HashMap<String, Value>,
+}
+
+impl NetworkSnapshot {
+    pub fn from_resources<'a>(resources: impl IntoIterator<Item = &'a ResourceEntry>) -> Self {
+        let mut snapshot = Self::default();
+        for resource in resources {
+            snapshot.add(resource);
+        }
+        snapshot
+    }
+
+    /// Network resources of the given accounts/regions from the shared cache,
+    /// plus any in `loaded` (the pane's own resources)
+    pub fn from_cache(locations: &[(String, String)], loaded: &[ResourceEntry]) -> Self {
+        let cache = shared_cache();
+        let mut snapshot = Self::default();
+        for (account_id, region) in locations {
+            for resource_type in NETWORK_TYPES {
+                let key = format!("{}:{}:{}", account_id, region, resource_type);
+                for resource in cache.get_resources_owned(&key).unwrap_or_default() {
+                    snapshot.add(&resource);
+                }
+            }
+        }
+        for resource in loaded {
+            snapshot.add(resource);
+        }
+        snapshot
+    }
+
+    fn add(&mut self, resource: &ResourceEntry) {
+        let properties = || resource.properties.clone();
+        match resource.resource_type.as_str() {
+            "AWS::EC2::SecurityGroup" => {
+                self.security_groups
+                    .insert(resource.resource_id.clone(), properties());
+            }
+            "AWS::EC2::NetworkAcl" => self.network_acls.push(properties()),
+            "AWS::EC2::RouteTable" => self.route_tables.push(properties()),
+            "AWS::EC2::Subnet" => {
+                self.subnets
+                    .insert(resource.resource_id.clone(), properties());
+            }
+            _ => {}
+        }
+    }
+
+    fn subnet_cidr(&self, subnet_id: &str) -> Option<Cidr> {
+        self.subnets
+            .get(subnet_id)
+            .and_then(|subnet| subnet.get("CidrBlock"))
+            .and_then(Value::as_str)
+            .and_then(Cidr::parse)
+    }
+
+    fn vpc_of_subnet(&self, subnet_id: &str) -> Option<&str> {
+        self.subnets
+            .get(subnet_id)
+            .and_then(|subnet| subnet.get("VpcId"))
+            .and_then(Value::as_str)
+    }
+
+    /// The ACL associated with the subnet, or the default ACL of its VPC
+    fn network_acl_for(&self, subnet_id: &str, vpc_id: Option<&str>) -> Option<&Value> {
+        let associated = self.network_acls.iter().find(|acl| {
+            acl.get("Associations")
+                .and_then(Value::as_array)
+                .is_some_and(|associations| {
+                    associations.iter().any(|association| {
+                        association.get("SubnetId").and_then(Value::as_str) == Some(subnet_id)
+                    })
+                })
+        });
+        let vpc_id = vpc_id.or_else(|| self.vpc_of_subnet(subnet_id));
+        associated.or_else(|| {
+            self.network_acls.iter().find(|acl| {
+                acl.get("IsDefault").and_then(Value::as_bool) == Some(true)
+                    && acl.get("VpcId").and_then(Value::as_str) == vpc_id
+            })
+        })
+    }
+
+    /// The table explicitly associated with the subnet, or the main table of its VPC
+    fn route_table_for(&self, subnet_id: &str, vpc_id: Option<&str>) -> Option<&Value> {
+        let has_association = |table: &&Value, matches: &dyn Fn(&Value) -> bool| {
+            table
+                .get("Associations")
+                .and_then(Value::as_array)
+                .is_some_and(|associations| associations.iter().any(matches))
+        };
+        let vpc_id = vpc_id.or_else(|| self.vpc_of_subnet(subnet_id));
+        self.route_tables
+            .iter()
+            .find(|table| {
+                has_association(table, &|association| {
+                    association.get("SubnetId").and_then(Value::as_str) == Some(subnet_id)
+                })
+            })
+            .or_else(|| {
+                self.route_tables.iter().find(|table| {
+                    table.get("VpcId").and_then(Value::as_str) == vpc_id
+                        && has_association(table, &|association| {
+                            association.get("Main").and_then(Value::as_bool) == Some(true)
+                        })
+                })
+            })
+    }
+}
+
+/// Which side of a security group or network ACL is evaluated
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Inbound,
+    Outbound,
+}
+
+/// The other end of a check: its address and security groups
+struct Peer<'a> {
+    address: Option<Cidr>,
+    security_group_ids: &'a [String],
+}
+
+/// Evaluate the path from `source` to `destination` on `protocol`/`port`
+pub fn analyze(
+    source: &NetworkEndpoint,
+    destination: &NetworkEndpoint,
+    protocol: Protocol,
+    port: u16,
+    network: &NetworkSnapshot,
+) -> ReachabilityResult {
+    let forward = PortRange::single(port);
+    let to_destination = Peer {
+        address: destination.address,
+        security_group_ids: &destination.security_group_ids,
+    };
+    let to_source = Peer {
+        address: source.address,
+        security_group_ids: &source.security_group_ids,
+    };
+    let same_subnet = source.subnet_id.is_some() && source.subnet_id == destination.subnet_id;
+
+    let mut steps = vec![security_group_step(
+        "Source security groups (egress)",
+        source,
+        Direction::Outbound,
+        &to_destination,
+        protocol,
+        forward,
+        network,
+    )];
+    if same_subnet {
+        steps.push(PathStep {
+            stage: "Network ACLs",
+            subject: source.subnet_id.clone().unwrap_or_default(),
+            verdict: Verdict::Allowed,
+            detail: "Same subnet; network ACLs don't apply".to_string(),
+        });
+    } else {
+        steps.push(network_acl_step(
+            "Source subnet ACL (outbound)",
+            source,
+            Direction::Outbound,
+            destination.address,
+            protocol,
+            forward,
+            network,
+        ));
+    }
+    steps.push(route_step(source, destination, network));
+    if !same_subnet {
+        steps.push(network_acl_step(
+            "Destination subnet ACL (inbound)",
+            destination,
+            Direction::Inbound,
+            source.address,
+            protocol,
+            forward,
+            network,
+        ));
+    }
+    steps.push(security_group_step(
+        "Destination security groups (ingress)",
+        destination,
+        Direction::Inbound,
+        &to_source,
+        protocol,
+        forward,
+        network,
+    ));
+    if !same_subnet {
+        steps.push(network_acl_step(
+            "Reply: destination subnet ACL (outbound)",
+            destination,
+            Direction::Outbound,
+            source.address,
+            protocol,
+            EPHEMERAL_PORTS,
+            network,
+        ));
+        steps.push(network_acl_step(
+            "Reply: source subnet ACL (inbound)",
+            source,
+            Direction::Inbound,
+            destination.address,
+            protocol,
+            EPHEMERAL_PORTS,
+            network,
+        ));
+    }
+
+    ReachabilityResult {
+        protocol,
+        port,
+        steps,
+    }
+}
+
+/// Security groups are stateful and allow-only: any matching rule allows
+fn security_group_step(
+    stage: &'static str,
+    endpoint: &NetworkEndpoint,
+    direction: Direction,
+    peer: &Peer<'_>,
+    protocol: Protocol,
+    ports: PortRange,
+    network: &NetworkSnapshot,
+) -> PathStep {
+    let subject = endpoint.security_group_ids.join(", ");
+    if endpoint.security_group_ids.is_empty() {
+        return PathStep {
+            stage,
+            subject: endpoint.label.clone(),
+            verdict: Verdict::Unknown,
+            detail: "No security groups recorded for this resource".to_string(),
+        };
+    }
+
+    let rules_key = match direction {
+        Direction::Inbound => "IpPermissions",
+        Direction::Outbound => "IpPermissionsEgress",
+    };
+    let mut missing = Vec::new();
+    for group_id in &endpoint.security_group_ids {
+        let Some(group) = network.security_groups.get(group_id) else {
+            missing.push(group_id.as_str());
+            continue;
+        };
+        let rules = group
+            .get(rules_key)
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        for rule in rules {
+            let rule_protocol = rule
+                .get("IpProtocol")
+                .and_then(Value::as_str)
+                .unwrap_or("-1");
+            let rule_ports = PortRange::from_bounds(
+                rule.get("FromPort").and_then(Value::as_i64),
+                rule.get("ToPort").and_then(Value::as_i64),
+            );
+            if !protocol.matches(rule_protocol) || !rule_ports.covers(&ports) {
+                continue;
+            }
+            if let Some(source) = security_group_rule_peer(rule, peer) {
+                return PathStep {
+                    stage,
+                    subject,
+                    verdict: Verdict::Allowed,
+                    detail: format!(
+                        "{} allows {} {} {} {}",
+                        group_id,
+                        protocol.label(),
+                        rule_ports,
+                        match direction {
+                            Direction::Inbound => "from",
+                            Direction::Outbound => "to",
+                        },
+                        source
+                    ),
+                };
+            }
+        }
+    }
+
+    if !missing.is_empty() {
+        return PathStep {
+            stage,
+            subject,
+            verdict: Verdict::Unknown,
+            detail: format!(
+                "{} not cached; load AWS::EC2::SecurityGroup for this account and region",
+                missing.join(", ")
+            ),
+        };
+    }
+    PathStep {
+        stage,
+        subject,
+        verdict: Verdict::Blocked,
+        detail: format!(
+            "No rule allows {} {} {} {}",
+            protocol.label(),
+            ports,
+            match direction {
+                Direction::Inbound => "from",
+                Direction::Outbound => "to",
+            },
+            peer.address
+                .map(|address| address.to_string())
+                .unwrap_or_else(|| "the peer".to_string())
+        ),
+    }
+}
+
+/// The CIDR or group of a security group rule that matches the peer
+fn security_group_rule_peer(rule: &Value, peer: &Peer<'_>) -> Option<String> {
+    let ranges = rule.get("IpRanges").and_then(Value::as_array);
+    for range in ranges.into_iter().flatten() {
+        let Some(cidr) = range
+            .get("CidrIp")
+            .and_then(Value::as_str)
+            .and_then(Cidr::parse)
+        else {
+            continue;
+        };
+        let matches = match peer.address {
+            Some(address) => cidr.contains(&address),
+            // Without the peer's address only an all-addresses rule is certain
+            None => cidr.prefix == 0,
+        };
+        if matches {
+            return Some(cidr.to_string());
+        }
+    }
+
+    let pairs = rule.get("UserIdGroupPairs").and_then(Value::as_array);
+    pairs
+        .into_iter()
+        .flatten()
+        .filter_map(|pair| pair.get("GroupId").and_then(Value::as_str))
+        .find(|group_id| peer.security_group_ids.iter().any(|id| id == group_id))
+        .map(String::from)
+}
+
+/// Network ACLs are stateless and ordered: the lowest-numbered matching entry decides
+fn network_acl_step(
+    stage: &'static str,
+    endpoint: &NetworkEndpoint,
+    direction: Direction,
+    peer_address: Option<Cidr>,
+    protocol: Protocol,
+    ports: PortRange,
+    network: &NetworkSnapshot,
+) -> PathStep {
+    let Some(subnet_id) = endpoint.subnet_id.as_deref() else {
+        return PathStep {
+            stage,
+            subject: endpoint.label.clone(),
+            verdict: Verdict::Unknown,
+            detail: "Subnet of this resource is not known".to_string(),
+        };
+    };
+    let Some(acl) = network.network_acl_for(subnet_id, endpoint.vpc_id.as_deref()) else {
+        return PathStep {
+            stage,
+            subject: subnet_id.to_string(),
+            verdict: Verdict::Unknown,
+            detail: "Network ACL not cached; load AWS::EC2::NetworkAcl for this account and region"
+                .to_string(),
+        };
+    };
+    let acl_id = acl
+        .get("NetworkAclId")
+        .and_then(Value::as_str)
+        .unwrap_or("network ACL")
+        .to_string();
+    let Some(peer_address) = peer_address else {
+        return PathStep {
+            stage,
+            subject: acl_id,
+            verdict: Verdict::Unknown,
+            detail: "Address of the other resource is not known".to_string(),
+        };
+    };
+
+    let egress = direction == Direction::Outbound;
+    let entries = acl
+        .get("Entries")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    if entries.iter().any(|entry| entry.get("Egress").is_none()) {
+        return PathStep {
+            stage,
+            subject: acl_id,
+            verdict: Verdict::Unknown,
+            detail: "Cached ACL entries predate rule directions; refresh AWS::EC2::NetworkAcl"
+                .to_string(),
+        };
+    }
+
+    let mut entries: Vec<&Value> = entries
+        .iter()
+        .filter(|entry| entry.get("Egress").and_then(Value::as_bool) == Some(egress))
+        .collect();
+    entries.sort_by_key(|entry| {
+        entry
+            .get("RuleNumber")
+            .and_then(Value::as_i64)
+            .unwrap_or(i64::MAX)
+    });
+
+    for entry in entries {
+        let entry_protocol = entry
+            .get("Protocol")
+            .and_then(Value::as_str)
+            .unwrap_or("-1");
+        let entry_ports = entry
+            .get("PortRange")
+            .map(|range| {
+                PortRange::from_bounds(
+                    range.get("From").and_then(Value::as_i64),
+                    range.get("To").and_then(Value::as_i64),
+                )
+            })
+            .unwrap_or(PortRange { from: 0, to: 65535 });
+        let entry_cidr = entry
+            .get("CidrBlock")
+            .and_then(Value::as_str)
+            .and_then(Cidr::parse);
+        let matches = protocol.matches(entry_protocol)
+            && entry_ports.covers(&ports)
+            && entry_cidr.is_some_and(|cidr| cidr.contains(&peer_address));
+        if !matches {
+            continue;
+        }
+
+        let allow = entry.get("RuleAction").and_then(Value::as_str) == Some("allow");
+        let rule_number = entry
+            .get("RuleNumber")
+            .and_then(Value::as_i64)
+            .map(|number| {
+                if number == 32767 {
+                    "*".to_string()
+                } else {
+                    number.to_string()
+                }
+            })
+            .unwrap_or_default();
+        return PathStep {
+            stage,
+            subject: acl_id,
+            verdict: if allow {
+                Verdict::Allowed
+            } else {
+                Verdict::Blocked
+            },
+            detail: format!(
+                "Rule {} {} {} {} {}",
+                rule_number,
+                if allow { "allows" } else { "denies" },
+                protocol.label(),
+                ports,
+                entry_cidr.map(|cidr| cidr.to_string()).unwrap_or_default()
+            ),
+        };
+    }
+
+    PathStep {
+        stage,
+        subject: acl_id,
+        verdict: Verdict::Blocked,
+        detail: format!(
+            "No entry matches {} {} for {}; denied by default",
+            protocol.label(),
+            ports,
+            peer_address
+        ),
+    }
+}
+
+/// Traffic within a VPC uses the local route; across VPCs the source subnet needs a route
+fn route_step(
+    source: &NetworkEndpoint,
+    destination: &NetworkEndpoint,
+    network: &NetworkSnapshot,
+) -> PathStep {
+    let stage = "Route table";
+    if source.vpc_id.is_some() && source.vpc_id == destination.vpc_id {
+        return PathStep {
+            stage,
+            subject: source.vpc_id.clone().unwrap_or_default(),
+            verdict: Verdict::Allowed,
+            detail: "Both resources are in the same VPC; the local route applies".to_string(),
+        };
+    }
+
+    let Some(subnet_id) = source.subnet_id.as_deref() else {
+        return PathStep {
+            stage,
+            subject: source.label.clone(),
+            verdict: Verdict::Unknown,
+            detail: "Subnet of the source is not known".to_string(),
+        };
+    };
+    let Some(table) = network.route_table_for(subnet_id, source.vpc_id.as_deref()) else {
+        return PathStep {
+            stage,
+            subject: subnet_id.to_string(),
+            verdict: Verdict::Unknown,
+            detail: "Route table not cached; load AWS::EC2::RouteTable for this account and region"
+                .to_string(),
+        };
+    };
+    let table_id = table
+        .get("RouteTableId")
+        .and_then(Value::as_str)
+        .unwrap_or("route table")
+        .to_string();
+    let Some(address) = destination.address else {
+        return PathStep {
+            stage,
+            subject: table_id,
+            verdict: Verdict::Unknown,
+            detail: "Address of the destination is not known".to_string(),
+        };
+    };
+
+    // Longest prefix wins
+    let best = table
+        .get("Routes")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|route| {
+            let cidr = route
+                .get("DestinationCidrBlock")
+                .and_then(Value::as_str)
+                .and_then(Cidr::parse)?;
+            cidr.contains(&address).then_some((cidr, route))
+        })
+        .max_by_key(|(cidr, _)| cidr.prefix);
+
+    let Some((cidr, route)) = best else {
+        return PathStep {
+            stage,
+            subject: table_id,
+            verdict: Verdict::Blocked,
+            detail: format!("No route to {}", address),
+        };
+    };
+    let target = [
+        "VpcPeeringConnectionId",
+        "TransitGatewayId",
+        "NatGatewayId",
+        "NetworkInterfaceId",
+        "InstanceId",
+        "GatewayId",
+    ]
+    .iter()
+    .find_map(|key| route.get(*key).and_then(Value::as_str))
+    .unwrap_or("unknown target");
+    let blackhole = route.get("State").and_then(Value::as_str) == Some("blackhole");
+    PathStep {
+        stage,
+        subject: table_id,
+        verdict: if blackhole {
+            Verdict::Blocked
+        } else {
+            Verdict::Allowed
+        },
+        detail: if blackhole {
+            format!("Route {} via {} is a blackhole", cidr, target)
+        } else {
+            format!("Route {} via {}", cidr, target)
+        },
+    }
+}
+
+/// Results window for a source/destination pair
+#[derive(Default)]
+pub struct ReachabilityWindow {
+    pub open: bool,
+    source: Option<ResourceEntry>,
+    destination: Option<ResourceEntry>,
+    /// Network resources of the pane, consulted besides the shared cache
+    loaded: Vec<ResourceEntry>,
+    protocol: Protocol,
+    port: u16,
+    result: Option<Result<(NetworkEndpoint, NetworkEndpoint, ReachabilityResult), String>>,
+}
+
+impl ReachabilityWindow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open on a pair of resources and run the analysis
+    pub fn open_for(
+        &mut self,
+        source: ResourceEntry,
+        destination: ResourceEntry,
+        pane_resources: &[ResourceEntry],
+    ) {
+        self.source = Some(source);
+        self.destination = Some(destination);
+        self.loaded = pane_resources
+            .iter()
+            .filter(|resource| NETWORK_TYPES.contains(&resource.resource_type.as_str()))
+            .cloned()
+            .collect();
+        self.port = 0;
+        self.open = true;
+        self.run();
+    }
+
+    fn run(&mut self) {
+        let (Some(source), Some(destination)) = (&self.source, &self.destination) else {
+            return;
+        };
+        let mut locations = vec![(source.account_id.clone(), source.region.clone())];
+        if (destination.account_id.as_str(), destination.region.as_str())
+            != (source.account_id.as_str(), source.region.as_str())
+        {
+            locations.push((destination.account_id.clone(), destination.region.clone()));
+        }
+        let network = NetworkSnapshot::from_cache(&locations, &self.loaded);
+
+        let endpoints = NetworkEndpoint::from_resource(source, &network)
+            .zip(NetworkEndpoint::from_resource(destination, &network));
+        self.result = Some(match endpoints {
+            Some((source, destination)) => {
+                if self.port == 0 {
+                    self.port = destination.port.unwrap_or(443);
+                }
+                let result = analyze(&source, &destination, self.protocol, self.port, &network);
+                Ok((source, destination, result))
+            }
+            None => Err(format!(
+                "Reachability works between {}",
+                ENDPOINT_TYPES.join(", ")
+            )),
+        });
+    }
+
+    pub fn show(&mut self, ctx: &Context, id_salt: impl std::hash::Hash) {
+        if !self.open {
+            return;
+        }
+
+        let mut open = self.open;
+        let mut rerun = false;
+        Window::new("Network Reachability")
+            .id(egui::Id::new(("reachability", id_salt)))
+            .open(&mut open)
+            .default_size([560.0, 460.0])
+            .resizable(true)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Protocol:");
+                    for protocol in [Protocol::Tcp, Protocol::Udp] {
+                        rerun |= ui
+                            .radio_value(&mut self.protocol, protocol, protocol.label())
+                            .changed();
+                    }
+                    ui.label("Port:");
+                    rerun |= ui
+                        .add(egui::DragValue::new(&mut self.port).range(1..=65535))
+                        .changed();
+                    if ui
+                        .button("Swap")
+                        .on_hover_text("Swap source and destination")
+                        .clicked()
+                    {
+                        std::mem::swap(&mut self.source, &mut self.destination);
+                        rerun = true;
+                    }
+                    if ui
+                        .button("Re-run")
+                        .on_hover_text("Re-read the cache and evaluate again")
+                        .clicked()
+                    {
+                        rerun = true;
+                    }
+                });
+                ui.separator();
+
+                match &self.result {
+                    None => {}
+                    Some(Err(message)) => {
+                        ui.label(RichText::new(message).color(Verdict::Unknown.color()));
+                    }
+                    Some(Ok((source, destination, result))) => {
+                        Self::show_result(ui, source, destination, result);
+                    }
+                }
+            });
+
+        if rerun {
+            self.run();
+        }
+        self.open = open;
+    }
+
+    fn show_result(
+        ui: &mut egui::Ui,
+        source: &NetworkEndpoint,
+        destination: &NetworkEndpoint,
+        result: &ReachabilityResult,
+    ) {
+        let verdict = result.verdict();
+        let headline = match verdict {
+            Verdict::Allowed => "Traffic is allowed",
+            Verdict::Blocked => "Traffic is blocked",
+            Verdict::Unknown => "Can't tell; some network data isn't cached",
+        };
+        ui.label(
+            RichText::new(format!(
+                "{} {} ({} {})",
+                verdict.label(),
+                headline,
+                result.protocol.label(),
+                result.port
+            ))
+            .strong()
+            .color(verdict.color()),
+        );
+        ui.add_space(4.0);
+
+        egui::Grid::new("reachability_endpoints")
+            .num_columns(2)
+            .spacing([12.0, 2.0])
+            .show(ui, |ui| {
+                for (role, endpoint) in [("From", source), ("To", destination)] {
+                    ui.label(RichText::new(role).weak());
+                    ui.label(format!(
+                        "{}  {}  {}",
+                        endpoint.label,
+                        endpoint.subnet_id.as_deref().unwrap_or("unknown subnet"),
+                        endpoint.address_label()
+                    ));
+                    ui.end_row();
+                }
+            });
+        ui.separator();
+
+        egui::ScrollArea::vertical()
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                for (index, step) in result.steps.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(
+                            RichText::new(step.verdict.label())
+                                .strong()
+                                .color(step.verdict.color()),
+                        );
+                        ui.label(RichText::new(format!("{}. {}", index + 1, step.stage)).strong());
+                        ui.label(RichText::new(&step.subject).monospace().small().weak());
+                    });
+                    ui.indent(("reachability_step", index), |ui| {
+                        ui.label(RichText::new(&step.detail).small());
+                    });
+                    ui.add_space(2.0);
+                }
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn resource(resource_type: &str, id: &str, properties: Value) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: id.to_string(),
+            display_name: id.to_string(),
+            status: None,
+            properties,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: Color32::WHITE,
+            region_color: Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    fn allow_all_acl(id: &str, subnets: &[&str]) -> ResourceEntry {
+        let entries: Vec<Value> = [true, false]
+            .into_iter()
+            .flat_map(|egress| {
+                [
+                    json!({ "RuleNumber": 100, "Protocol": "-1", "RuleAction": "allow",
+                            "Egress": egress, "CidrBlock": "0.0.0.0/0" }),
+                    json!({ "RuleNumber": 32767, "Protocol": "-1", "RuleAction": "deny",
+                            "Egress": egress, "CidrBlock": "0.0.0.0/0" }),
+                ]
+            })
+            .collect();
+        let associations: Vec<Value> = subnets
+            .iter()
+            .map(|subnet| json!({ "SubnetId": subnet }))
+            .collect();
+        resource(
+            "AWS::EC2::NetworkAcl",
+            id,
+            json!({ "NetworkAclId": id, "VpcId": "vpc-1", "Entries": entries,
+                    "Associations": associations }),
+        )
+    }
+
+    /// A web instance and a Postgres instance in two subnets of one VPC
+    fn web_and_database(
+        database_ingress: Value,
+    ) -> (Vec<ResourceEntry>, ResourceEntry, ResourceEntry) {
+        let network = vec![
+            resource(
+                "AWS::EC2::Subnet",
+                "subnet-web",
+                json!({ "SubnetId": "subnet-web", "VpcId": "vpc-1", "CidrBlock": "10.0.1.0/24" }),
+            ),
+            resource(
+                "AWS::EC2::Subnet",
+                "subnet-db",
+                json!({ "SubnetId": "subnet-db", "VpcId": "vpc-1", "CidrBlock": "10.0.2.0/24",
+                        "AvailabilityZone": "us-east-1a" }),
+            ),
+            resource(
+                "AWS::EC2::SecurityGroup",
+                "sg-web",
+                json!({ "GroupId": "sg-web", "IpPermissionsEgress": [{ "IpProtocol": "-1",
+                        "IpRanges": [{ "CidrIp": "0.0.0.0/0" }] }] }),
+            ),
+            resource(
+                "AWS::EC2::SecurityGroup",
+                "sg-db",
+                json!({ "GroupId": "sg-db", "IpPermissions": database_ingress }),
+            ),
+            allow_all_acl("acl-1", &["subnet-web", "subnet-db"]),
+        ];
+        let web = resource(
+            "AWS::EC2::Instance",
+            "i-web",
+            json!({ "VpcId": "vpc-1", "SubnetId": "subnet-web", "PrivateIpAddress": "10.0.1.10",
+                    "SecurityGroups": [{ "GroupId": "sg-web" }] }),
+        );
+        let database = resource(
+            "AWS::RDS::DBInstance",
+            "orders",
+            json!({ "AvailabilityZone": "us-east-1a",
+                    "DBSubnetGroup": { "VpcId": "vpc-1", "Subnets": [
+                        { "SubnetIdentifier": "subnet-db", "SubnetAvailabilityZone": { "Name": "us-east-1a" } }
+                    ] },
+                    "VpcSecurityGroups": [{ "VpcSecurityGroupId": "sg-db" }],
+                    "Endpoint": { "Port": 5432 } }),
+        );
+        (network, web, database)
+    }
+
+    #[test]
+    fn test_cidr_containment() {
+        let vpc = Cidr::parse("10.0.0.0/16").unwrap();
+        assert!(vpc.contains(&Cidr::parse("10.0.2.0/24").unwrap()));
+        assert!(vpc.contains(&Cidr::parse("10.0.9.9").unwrap()));
+        assert!(!vpc.contains(&Cidr::parse("10.1.0.1").unwrap()));
+        assert!(!Cidr::parse("10.0.2.0/24").unwrap().contains(&vpc));
+        assert!(Cidr::parse("0.0.0.0/0").unwrap().contains(&vpc));
+        assert_eq!(
+            Cidr::parse("10.0.2.7/24").unwrap().to_string(),
+            "10.0.2.0/24"
+        );
+        assert!(Cidr::parse("10.0.0.0/33").is_none());
+    }
+
+    #[test]
+    fn test_security_group_reference_allows_database_port() {
+        let (resources, web, database) = web_and_database(json!([{ "IpProtocol": "tcp",
+            "FromPort": 5432, "ToPort": 5432, "UserIdGroupPairs": [{ "GroupId": "sg-web" }] }]));
+        let network = NetworkSnapshot::from_resources(&resources);
+        let source = NetworkEndpoint::from_resource(&web, &network).unwrap();
+        let destination = NetworkEndpoint::from_resource(&database, &network).unwrap();
+        assert_eq!(destination.port, Some(5432));
+        assert_eq!(destination.subnet_id.as_deref(), Some("subnet-db"));
+
+        let result = analyze(&source, &destination, Protocol::Tcp, 5432, &network);
+        assert_eq!(result.verdict(), Verdict::Allowed, "{:#?}", result.steps);
+        assert_eq!(result.steps.len(), 7);
+
+        let blocked = analyze(&source, &destination, Protocol::Tcp, 3306, &network);
+        assert_eq!(blocked.verdict(), Verdict::Blocked);
+        assert_eq!(blocked.steps[4].verdict, Verdict::Blocked);
+    }
+
+    #[test]
+    fn test_network_acl_deny_wins_by_rule_number() {
+        let (mut resources, web, database) = web_and_database(json!([{ "IpProtocol": "tcp",
+            "FromPort": 5432, "ToPort": 5432, "IpRanges": [{ "CidrIp": "10.0.0.0/16" }] }]));
+        resources.retain(|resource| resource.resource_type != "AWS::EC2::NetworkAcl");
+        resources.push(allow_all_acl("acl-web", &["subnet-web"]));
+        let mut db_acl = allow_all_acl("acl-db", &["subnet-db"]);
+        db_acl.properties["Entries"].as_array_mut().unwrap().push(
+            json!({ "RuleNumber": 50, "Protocol": "6", "RuleAction": "deny", "Egress": false,
+                          "CidrBlock": "10.0.1.0/24", "PortRange": { "From": 5432, "To": 5432 } }),
+        );
+        resources.push(db_acl);
+
+        let network = NetworkSnapshot::from_resources(&resources);
+        let source = NetworkEndpoint::from_resource(&web, &network).unwrap();
+        let destination = NetworkEndpoint::from_resource(&database, &network).unwrap();
+        let result = analyze(&source, &destination, Protocol::Tcp, 5432, &network);
+        assert_eq!(result.verdict(), Verdict::Blocked);
+        let denied = &result.steps[3];
+        assert_eq!(denied.subject, "acl-db");
+        assert!(
+            denied.detail.starts_with("Rule 50 denies"),
+            "{}",
+            denied.detail
+        );
+    }
+
+    #[test]
+    fn test_missing_network_data_is_unknown() {
+        let (_, web, database) = web_and_database(json!([]));
+        let network = NetworkSnapshot::default();
+        let source = NetworkEndpoint::from_resource(&web, &network).unwrap();
+        let destination = NetworkEndpoint::from_resource(&database, &network).unwrap();
+        let result = analyze(&source, &destination, Protocol::Tcp, 5432, &network);
+        assert_eq!(result.verdict(), Verdict::Unknown);
+        assert!(result
+            .steps
+            .iter()
+            .all(|step| step.verdict != Verdict::Blocked));
+    }
+}
@@ -0,0 +1,154 @@
+//! Recommendation badges for Explorer resources
+//!
+//! Compute Optimizer reports resources by ARN, Trusted Advisor by ARN or ID.
+//! The index keys both by account and the resource's short name (the last
+//! part of an ARN, or the function name for Lambda) so tree nodes can look up
+//! their recommendations without parsing anything while rendering.
+
+use super::resource_finder::explorer_resource_id;
+use super::state::ResourceEntry;
+use crate::app::data_plane::recommendations::Recommendation;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+/// Recommendations by (account, resource ID)
+#[derive(Debug, Clone)]
+pub struct RecommendationIndex {
+    by_resource: HashMap<(String, String), Vec<Recommendation>>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl RecommendationIndex {
+    pub fn from_recommendations(recommendations: &[Recommendation]) -> Self {
+        let mut by_resource: HashMap<(String, String), Vec<Recommendation>> = HashMap::new();
+        for recommendation in recommendations {
+            by_resource
+                .entry((
+                    recommendation.account_id.clone(),
+                    explorer_resource_id(&recommendation.resource),
+                ))
+                .or_default()
+                .push(recommendation.clone());
+        }
+        for list in by_resource.values_mut() {
+            list.sort_by(|a, b| b.finding.severity().cmp(&a.finding.severity()));
+        }
+        Self {
+            by_resource,
+            fetched_at: Utc::now(),
+        }
+    }
+
+    /// Recommendations for a resource, most severe first
+    ///
+    /// Recommendations that name a resource type only match resources of that type.
+    pub fn for_resource(&self, resource: &ResourceEntry) -> Vec<&Recommendation> {
+        self.by_resource
+            .get(&(resource.account_id.clone(), resource.resource_id.clone()))
+            .map(|list| {
+                list.iter()
+                    .filter(|recommendation| {
+                        recommendation
+                            .resource_type
+                            .as_ref()
+                            .map_or(true, |resource_type| {
+                                *resource_type == resource.resource_type
+                            })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Number of resources with recommendations
+    pub fn resource_count(&self) -> usize {
+        self.by_resource.len()
+    }
+}
+
+/// Badge text for a resource's recommendations, e.g. "Over-provisioned" or "3 recommendations"
+pub fn badge_label(recommendations: &[&Recommendation]) -> Option<String> {
+    match recommendations {
+        [] => None,
+        [only] => Some(only.finding.label().to_string()),
+        all => Some(format!("{} recommendations", all.len())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::data_plane::recommendations::{RecommendationFinding, RecommendationSource};
+
+    fn recommendation(
+        resource: &str,
+        resource_type: Option<&str>,
+        finding: RecommendationFinding,
+    ) -> Recommendation {
+        Recommendation {
+            source: RecommendationSource::ComputeOptimizer,
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_type: resource_type.map(String::from),
+            resource: resource.to_string(),
+            finding,
+            title: "Check".to_string(),
+            current: None,
+            recommended: None,
+            estimated_monthly_savings: None,
+            currency: None,
+        }
+    }
+
+    fn entry(resource_type: &str, resource_id: &str) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: resource_id.to_string(),
+            display_name: resource_id.to_string(),
+            status: None,
+            properties: serde_json::json!({}),
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_matches_arns_and_ids() {
+        let index = RecommendationIndex::from_recommendations(&[
+            recommendation(
+                "arn:aws:ec2:us-east-1:123456789012:instance/i-0abc",
+                Some("AWS::EC2::Instance"),
+                RecommendationFinding::OverProvisioned,
+            ),
+            recommendation("i-0abc", None, RecommendationFinding::Error),
+            recommendation(
+                "arn:aws:lambda:us-east-1:123456789012:function:orders:$LATEST",
+                Some("AWS::Lambda::Function"),
+                RecommendationFinding::UnderProvisioned,
+            ),
+        ]);
+        assert_eq!(index.resource_count(), 2);
+
+        let instance = index.for_resource(&entry("AWS::EC2::Instance", "i-0abc"));
+        assert_eq!(instance.len(), 2);
+        assert_eq!(instance[0].finding, RecommendationFinding::Error);
+        assert_eq!(badge_label(&instance).as_deref(), Some("2 recommendations"));
+
+        let function = index.for_resource(&entry("AWS::Lambda::Function", "orders"));
+        assert_eq!(badge_label(&function).as_deref(), Some("Under-provisioned"));
+
+        // A type-specific recommendation does not match another type with the same ID
+        assert!(index
+            .for_resource(&entry("AWS::SQS::Queue", "orders"))
+            .is_empty());
+    }
+}
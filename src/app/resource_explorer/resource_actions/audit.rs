@@ -0,0 +1,134 @@
+//! Audit log of executed resource actions
+//!
+//! Every executed action (successful or not) is appended as one JSON line to
+//! `{data_local_dir}/awsdash/audit/resource_actions.jsonl` and mirrored to the
+//! application log. Dry-run previews are not recorded since they change nothing.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// One executed action on one resource
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionAuditEntry {
+    pub timestamp: DateTime<Utc>,
+    pub action_id: String,
+    pub account_id: String,
+    pub region: String,
+    pub resource_type: String,
+    pub resource_id: String,
+    pub success: bool,
+    /// What changed, or the error
+    pub message: String,
+}
+
+/// Append-only JSON Lines audit file
+#[derive(Debug, Clone)]
+pub struct ActionAuditLog {
+    path: PathBuf,
+}
+
+impl ActionAuditLog {
+    /// Audit log in the application data directory
+    pub fn new() -> Result<Self> {
+        let directory = dirs::data_local_dir()
+            .context("Failed to get local data directory")?
+            .join("awsdash/audit");
+        Ok(Self::with_path(directory.join("resource_actions.jsonl")))
+    }
+
+    pub fn with_path(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Append an entry; the file and its directory are created on first use
+    pub fn record(&self, entry: &ActionAuditEntry) -> Result<()> {
+        tracing::info!(
+            target: "resource_actions::audit",
+            "{} {} {}/{} {} -> {}: {}",
+            entry.action_id,
+            entry.resource_type,
+            entry.account_id,
+            entry.region,
+            entry.resource_id,
+            if entry.success { "ok" } else { "failed" },
+            entry.message
+        );
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).context("Failed to create audit log directory")?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("Failed to open audit log {}", self.path.display()))?;
+        let line = serde_json::to_string(entry).context("Failed to serialize audit entry")?;
+        writeln!(file, "{}", line).context("Failed to write audit entry")?;
+        Ok(())
+    }
+
+    /// Most recent entries, newest first; unreadable lines are skipped
+    pub fn recent(&self, limit: usize) -> Result<Vec<ActionAuditEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let file = fs::File::open(&self.path)
+            .with_context(|| format!("Failed to open audit log {}", self.path.display()))?;
+        let mut entries: Vec<ActionAuditEntry> = BufReader::new(file)
+            .lines()
+            .map_while(|line| line.ok())
+            .filter_map(|line| serde_json::from_str(&line).ok())
+            .collect();
+        entries.reverse();
+        entries.truncate(limit);
+        Ok(entries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(resource_id: &str, success: bool) -> ActionAuditEntry {
+        ActionAuditEntry {
+            timestamp: Utc::now(),
+            action_id: "ec2.stop_instance".to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_type: "AWS::EC2::Instance".to_string(),
+            resource_id: resource_id.to_string(),
+            success,
+            message: "Instance is stopping".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_record_and_read_recent() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = ActionAuditLog::with_path(dir.path().join("nested/audit.jsonl"));
+        assert!(log.recent(10).unwrap().is_empty());
+
+        log.record(&entry("i-1", true)).unwrap();
+        log.record(&entry("i-2", false)).unwrap();
+        log.record(&entry("i-3", true)).unwrap();
+
+        let recent = log.recent(2).unwrap();
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].resource_id, "i-3");
+        assert_eq!(recent[1].resource_id, "i-2");
+        assert!(!recent[1].success);
+
+        let contents = fs::read_to_string(log.path()).unwrap();
+        assert_eq!(contents.lines().count(), 3);
+        assert!(contents.contains("\"actionId\":\"ec2.stop_instance\""));
+    }
+}
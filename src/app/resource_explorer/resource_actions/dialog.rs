@@ -0,0 +1,538 @@
+//! Confirmation dialog for resource actions
+//!
+//! Opening the dialog starts a dry-run preview of every target in the
+//! background. The action can only be executed once the preview finished and
+//! at least one resource would change; only those resources are touched.
+//! Destructive actions skip resources with a protected tag; the list can be
+//! edited in the dialog.
+
+use super::audit::{ActionAuditEntry, ActionAuditLog};
+use super::protected_tags::ProtectedTags;
+use super::{ActionPreview, ActionRisk, ResourceAction, ResourceActionRegistry};
+use crate::app::resource_explorer::state::ResourceEntry;
+use crate::app::resource_explorer::status::{report_status, report_status_done};
+use crate::app::resource_explorer::AWSResourceClient;
+use egui::{Context, RichText, Window};
+use std::sync::{Arc, Mutex};
+
+/// Per-resource progress shared with the background thread
+#[derive(Debug, Default)]
+struct ActionJob {
+    previews: Vec<Option<ActionPreview>>,
+    outcomes: Vec<Option<Result<String, String>>>,
+    finished: bool,
+}
+
+/// Resources an action completed on, for updating the tree
+#[derive(Debug, Clone)]
+pub struct CompletedAction {
+    /// Selection keys of resources the action succeeded on
+    pub selection_keys: Vec<String>,
+    /// Status to show until the next refresh
+    pub status_after: Option<&'static str>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Phase {
+    #[default]
+    Previewing,
+    Ready,
+    Executing,
+    Done,
+}
+
+/// Dry-run preview and confirmation for running an action on resources
+#[derive(Default)]
+pub struct ResourceActionDialog {
+    pub open: bool,
+    action_id: String,
+    label: &'static str,
+    risk: Option<ActionRisk>,
+    confirm_by_resource_id: bool,
+    status_after: Option<&'static str>,
+    targets: Vec<ResourceEntry>,
+    previews: Vec<Option<ActionPreview>>,
+    outcomes: Vec<Option<Result<String, String>>>,
+    phase: Phase,
+    job: Option<Arc<Mutex<ActionJob>>>,
+    aws_client: Option<Arc<AWSResourceClient>>,
+    confirmation: String,
+    message: Option<String>,
+    /// Protected tags being edited, one per line
+    protected_tags_text: String,
+}
+
+impl ResourceActionDialog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Open the dialog and start the dry-run preview
+    ///
+    /// Resources of other types are left out; resources failing the cached
+    /// `check` are shown as skipped without calling AWS, and for destructive
+    /// actions resources with a protected tag are blocked.
+    pub fn open_for(
+        &mut self,
+        action_id: &str,
+        resources: Vec<ResourceEntry>,
+        aws_client: Arc<AWSResourceClient>,
+    ) {
+        let Some(action) = ResourceActionRegistry::action(action_id) else {
+            tracing::warn!("Unknown resource action: {}", action_id);
+            return;
+        };
+
+        self.targets = resources
+            .into_iter()
+            .filter(|r| r.resource_type == action.resource_type())
+            .collect();
+        let protected_tags = (action.risk() == ActionRisk::Destructive).then(ProtectedTags::load);
+        self.previews = self
+            .targets
+            .iter()
+            .map(|r| {
+                action.check(r).map(ActionPreview::Skip).or_else(|| {
+                    protected_tags
+                        .as_ref()?
+                        .matching(r.tags.iter().map(|t| (t.key.as_str(), t.value.as_str())))
+                        .map(|tag| ActionPreview::Blocked(format!("Protected by tag {}", tag)))
+                })
+            })
+            .collect();
+        self.protected_tags_text = protected_tags
+            .map(|tags| tags.to_text())
+            .unwrap_or_default();
+        self.outcomes = vec![None; self.targets.len()];
+        self.action_id = action_id.to_string();
+        self.label = action.label();
+        self.risk = Some(action.risk());
+        self.confirm_by_resource_id = action.confirm_by_resource_id();
+        self.status_after = action.status_after();
+        self.aws_client = Some(aws_client);
+        self.confirmation.clear();
+        self.message = None;
+        self.open = true;
+
+        self.start_job(action, false);
+    }
+
+    fn start_job(&mut self, action: Box<dyn ResourceAction>, execute: bool) {
+        let Some(aws_client) = self.aws_client.clone() else {
+            return;
+        };
+
+        // Preview everything not already skipped; execute only what would change
+        let pending: Vec<usize> = (0..self.targets.len())
+            .filter(|&i| match &self.previews[i] {
+                Some(preview) => execute && preview.will_change(),
+                None => !execute,
+            })
+            .collect();
+
+        let job = Arc::new(Mutex::new(ActionJob {
+            previews: self.previews.clone(),
+            outcomes: self.outcomes.clone(),
+            finished: false,
+        }));
+        self.job = Some(job.clone());
+        self.phase = if execute {
+            Phase::Executing
+        } else {
+            Phase::Previewing
+        };
+
+        let targets = self.targets.clone();
+        std::thread::spawn(move || {
+            let runtime = match tokio::runtime::Runtime::new() {
+                Ok(rt) => rt,
+                Err(e) => {
+                    tracing::error!("Failed to create Tokio runtime for resource action: {}", e);
+                    if let Ok(mut job) = job.lock() {
+                        for &index in &pending {
+                            if execute {
+                                job.outcomes[index] = Some(Err(e.to_string()));
+                            } else {
+                                job.previews[index] = Some(ActionPreview::Blocked(e.to_string()));
+                            }
+                        }
+                        job.finished = true;
+                    }
+                    return;
+                }
+            };
+
+            let audit_log = if execute {
+                ActionAuditLog::new()
+                    .inspect_err(|e| {
+                        tracing::warn!("Resource action audit log unavailable: {:#}", e)
+                    })
+                    .ok()
+            } else {
+                None
+            };
+            let operation = if execute { action.id() } else { "dry_run" };
+
+            for (n, &index) in pending.iter().enumerate() {
+                let resource = &targets[index];
+                report_status(
+                    "ResourceActions",
+                    operation,
+                    Some(&format!("{} of {}", n + 1, pending.len())),
+                );
+
+                if execute {
+                    let outcome = runtime
+                        .block_on(action.execute(resource, &aws_client))
+                        .map_err(|e| format!("{:#}", e));
+                    let entry = ActionAuditEntry {
+                        timestamp: chrono::Utc::now(),
+                        action_id: action.id().to_string(),
+                        account_id: resource.account_id.clone(),
+                        region: resource.region.clone(),
+                        resource_type: resource.resource_type.clone(),
+                        resource_id: resource.resource_id.clone(),
+                        success: outcome.is_ok(),
+                        message: match &outcome {
+                            Ok(message) | Err(message) => message.clone(),
+                        },
+                    };
+                    match &audit_log {
+                        Some(log) => {
+                            if let Err(e) = log.record(&entry) {
+                                tracing::warn!(
+                                    "Failed to write resource action audit entry: {:#}",
+                                    e
+                                );
+                            }
+                        }
+                        None => {
+                            tracing::warn!("Resource action not written to audit file: {:?}", entry)
+                        }
+                    }
+                    if let Ok(mut job) = job.lock() {
+                        job.outcomes[index] = Some(outcome);
+                    }
+                } else {
+                    let preview = runtime.block_on(action.preview(resource, &aws_client));
+                    if let Ok(mut job) = job.lock() {
+                        job.previews[index] = Some(preview);
+                    }
+                }
+            }
+
+            report_status_done("ResourceActions", operation, None);
+            if let Ok(mut job) = job.lock() {
+                job.finished = true;
+            }
+        });
+    }
+
+    fn poll_job(&mut self) {
+        let Some(job) = &self.job else {
+            return;
+        };
+        let Ok(job) = job.lock() else {
+            return;
+        };
+        self.previews = job.previews.clone();
+        self.outcomes = job.outcomes.clone();
+        let finished = job.finished;
+        drop(job);
+
+        if finished {
+            self.job = None;
+            self.phase = match self.phase {
+                Phase::Previewing => Phase::Ready,
+                _ => Phase::Done,
+            };
+        }
+    }
+
+    /// IDs of the resources that would change
+    fn changing_ids(&self) -> Vec<String> {
+        self.targets
+            .iter()
+            .zip(&self.previews)
+            .filter(|(_, preview)| preview.as_ref().is_some_and(ActionPreview::will_change))
+            .map(|(resource, _)| resource.resource_id.clone())
+            .collect()
+    }
+
+    fn change_count(&self) -> usize {
+        self.previews
+            .iter()
+            .filter(|p| p.as_ref().is_some_and(ActionPreview::will_change))
+            .count()
+    }
+
+    /// Render the dialog; returns the resources the action completed on once it finishes
+    ///
+    /// `id_salt` keeps dialogs from different panes apart.
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        id_salt: impl std::hash::Hash,
+    ) -> Option<CompletedAction> {
+        if !self.open {
+            return None;
+        }
+
+        let was_executing = self.phase == Phase::Executing;
+        self.poll_job();
+        if self.job.is_some() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        let mut open = self.open;
+        let mut execute = false;
+        let mut recheck = false;
+        let change_count = self.change_count();
+        let changing_ids = self.changing_ids();
+        let destructive = self.risk == Some(ActionRisk::Destructive);
+
+        Window::new(format!("{} - Preview", self.label))
+            .id(egui::Id::new(("resource_action_dialog", id_salt)))
+            .open(&mut open)
+            .default_size([620.0, 420.0])
+            .resizable(true)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                match self.phase {
+                    Phase::Previewing => {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label("Running dry run...");
+                        });
+                    }
+                    Phase::Ready => {
+                        ui.label(format!(
+                            "{} of {} resources would change",
+                            change_count,
+                            self.targets.len()
+                        ));
+                    }
+                    Phase::Executing => {
+                        ui.horizontal(|ui| {
+                            ui.spinner();
+                            ui.label(format!("Running {}...", self.label));
+                        });
+                    }
+                    Phase::Done => {
+                        let failed = self
+                            .outcomes
+                            .iter()
+                            .filter(|o| matches!(o, Some(Err(_))))
+                            .count();
+                        ui.label(format!(
+                            "Finished: {} succeeded, {} failed (recorded in the audit log)",
+                            self.outcomes
+                                .iter()
+                                .filter(|o| matches!(o, Some(Ok(_))))
+                                .count(),
+                            failed
+                        ));
+                    }
+                }
+                ui.separator();
+
+                egui::ScrollArea::vertical()
+                    .max_height(260.0)
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        egui::Grid::new("resource_action_grid")
+                            .num_columns(3)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.strong("Resource");
+                                ui.strong("Preview");
+                                ui.strong("Result");
+                                ui.end_row();
+
+                                for (index, resource) in self.targets.iter().enumerate() {
+                                    ui.label(format!(
+                                        "{} ({}, {})",
+                                        resource.display_name, resource.account_id, resource.region
+                                    ));
+                                    match &self.previews[index] {
+                                        Some(preview) => {
+                                            let color = match preview {
+                                                ActionPreview::WillChange(_) => {
+                                                    ui.visuals().text_color()
+                                                }
+                                                ActionPreview::Skip(_) => {
+                                                    ui.visuals().weak_text_color()
+                                                }
+                                                ActionPreview::Blocked(_) => {
+                                                    ui.visuals().warn_fg_color
+                                                }
+                                            };
+                                            ui.colored_label(
+                                                color,
+                                                format!(
+                                                    "{}: {}",
+                                                    preview.label(),
+                                                    preview.message()
+                                                ),
+                                            );
+                                        }
+                                        None => {
+                                            ui.spinner();
+                                        }
+                                    }
+                                    match &self.outcomes[index] {
+                                        Some(Ok(message)) => {
+                                            ui.label(message);
+                                        }
+                                        Some(Err(error)) => {
+                                            ui.colored_label(ui.visuals().error_fg_color, error);
+                                        }
+                                        None => {
+                                            ui.label("");
+                                        }
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+
+                if self.phase == Phase::Ready && change_count > 0 {
+                    ui.separator();
+                    let confirmed = if self.confirm_by_resource_id {
+                        ui.colored_label(ui.visuals().warn_fg_color, "This cannot be undone.");
+                        let ids: Vec<&str> = changing_ids.iter().map(String::as_str).collect();
+                        ui.label(if ids.len() == 1 {
+                            "Type the resource ID to confirm:".to_string()
+                        } else {
+                            format!("Type the {} resource IDs to confirm:", ids.len())
+                        });
+                        ui.label(RichText::new(ids.join(" ")).monospace().weak());
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.confirmation)
+                                .desired_width(f32::INFINITY),
+                        );
+                        ids_confirmed(&self.confirmation, &ids)
+                    } else if destructive {
+                        ui.colored_label(ui.visuals().warn_fg_color, "This cannot be undone.");
+                        ui.horizontal(|ui| {
+                            ui.label(format!("Type {} to confirm:", change_count));
+                            ui.add(
+                                egui::TextEdit::singleline(&mut self.confirmation)
+                                    .desired_width(60.0),
+                            );
+                        });
+                        self.confirmation.trim() == change_count.to_string()
+                    } else {
+                        true
+                    };
+                    if ui
+                        .add_enabled(
+                            confirmed,
+                            egui::Button::new(format!(
+                                "{} ({} resource{})",
+                                self.label,
+                                change_count,
+                                if change_count == 1 { "" } else { "s" }
+                            )),
+                        )
+                        .clicked()
+                    {
+                        execute = true;
+                    }
+                }
+
+                if destructive && self.phase != Phase::Executing {
+                    ui.separator();
+                    egui::CollapsingHeader::new("Protected tags")
+                        .id_salt("resource_action_protected_tags")
+                        .show(ui, |ui| {
+                            ui.label(
+                                RichText::new(
+                                    "Resources with one of these tags are blocked from destructive \
+                                     actions. One per line: Key, or Key=Value",
+                                )
+                                .small()
+                                .weak(),
+                            );
+                            ui.add(
+                                egui::TextEdit::multiline(&mut self.protected_tags_text)
+                                    .desired_rows(3)
+                                    .desired_width(f32::INFINITY),
+                            );
+                            if ui.button("Save and Re-check").clicked() {
+                                recheck = true;
+                            }
+                        });
+                }
+
+                if let Some(message) = &self.message {
+                    ui.label(message);
+                }
+            });
+
+        self.open = open;
+
+        if recheck {
+            match ProtectedTags::parse(&self.protected_tags_text).save() {
+                Ok(()) => {
+                    if let Some(aws_client) = self.aws_client.clone() {
+                        let action_id = self.action_id.clone();
+                        self.open_for(&action_id, self.targets.clone(), aws_client);
+                    }
+                }
+                Err(e) => self.message = Some(format!("{:#}", e)),
+            }
+        }
+
+        if execute {
+            match ResourceActionRegistry::action(&self.action_id) {
+                Some(action) => self.start_job(action, true),
+                None => self.message = Some(format!("Unknown action {}", self.action_id)),
+            }
+        }
+
+        if was_executing && self.phase == Phase::Done {
+            let selection_keys = self
+                .targets
+                .iter()
+                .zip(&self.outcomes)
+                .filter(|(_, outcome)| matches!(outcome, Some(Ok(_))))
+                .map(|(resource, _)| resource.selection_key())
+                .collect();
+            return Some(CompletedAction {
+                selection_keys,
+                status_after: self.status_after,
+            });
+        }
+        None
+    }
+}
+
+/// Whether the typed confirmation lists exactly the expected resource IDs,
+/// separated by spaces or commas in any order
+fn ids_confirmed(input: &str, expected: &[&str]) -> bool {
+    let mut typed: Vec<&str> = input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|id| !id.is_empty())
+        .collect();
+    typed.sort_unstable();
+    typed.dedup();
+    let mut expected = expected.to_vec();
+    expected.sort_unstable();
+    expected.dedup();
+    !expected.is_empty() && typed == expected
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ids_confirmed() {
+        assert!(ids_confirmed(" i-0abc ", &["i-0abc"]));
+        assert!(ids_confirmed("i-2, i-1", &["i-1", "i-2"]));
+        assert!(!ids_confirmed("i-1", &["i-1", "i-2"]));
+        assert!(!ids_confirmed("i-0ab", &["i-0abc"]));
+        assert!(!ids_confirmed("", &[]));
+    }
+}
@@ -0,0 +1,704 @@
+//! EC2 resource actions
+//!
+//! EC2 supports `DryRun` on every mutating call: AWS answers with
+//! `DryRunOperation` when the real call would succeed and with the usual error
+//! (`UnauthorizedOperation`, `IncorrectState`, ...) when it would not.
+//!
+//! Instance previews also list what is attached to the instance (Elastic IPs,
+//! EBS volumes, Auto Scaling group membership) and what happens to it.
+
+use super::protected_tags::ProtectedTags;
+use super::{ActionPreview, ActionRisk, ResourceAction};
+use crate::app::resource_explorer::state::ResourceEntry;
+use crate::app::resource_explorer::AWSResourceClient;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use aws_sdk_ec2 as ec2;
+use aws_sdk_ec2::error::ProvideErrorMetadata;
+
+async fn ec2_client(
+    resource: &ResourceEntry,
+    aws_client: &AWSResourceClient,
+) -> Result<ec2::Client> {
+    let aws_config = aws_client
+        .get_credential_coordinator()
+        .create_aws_config_for_account(&resource.account_id, &resource.region)
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to create AWS config for account {} in region {}",
+                resource.account_id, resource.region
+            )
+        })?;
+    Ok(ec2::Client::new(&aws_config))
+}
+
+/// Turn the outcome of a `DryRun` call into a preview
+///
+/// A dry run never succeeds: `DryRunOperation` is the "would succeed" answer.
+fn dry_run_preview<T, E>(
+    result: std::result::Result<T, ec2::error::SdkError<E>>,
+    will_change: String,
+) -> ActionPreview
+where
+    E: ProvideErrorMetadata + std::error::Error + 'static,
+{
+    match result {
+        Ok(_) => ActionPreview::WillChange(will_change),
+        Err(e) => match e.code() {
+            Some("DryRunOperation") => ActionPreview::WillChange(will_change),
+            Some(code) => ActionPreview::Blocked(format!(
+                "{}: {}",
+                code,
+                e.message().unwrap_or("request would fail")
+            )),
+            None => ActionPreview::Blocked(format!("Dry run failed: {}", e)),
+        },
+    }
+}
+
+/// Tag EC2 Auto Scaling puts on the instances of a group
+const AUTO_SCALING_GROUP_TAG: &str = "aws:autoscaling:groupName";
+
+/// Resources attached to an instance, read live before an action runs
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InstanceAttachments {
+    /// Public IPs of associated Elastic IPs
+    pub elastic_ips: Vec<String>,
+    /// EBS volume IDs with whether they are deleted on termination
+    pub volumes: Vec<(String, bool)>,
+    pub auto_scaling_group: Option<String>,
+    /// `DisableApiTermination` is set
+    pub termination_protected: bool,
+    /// Live tags of the instance
+    pub tags: Vec<(String, String)>,
+}
+
+impl InstanceAttachments {
+    async fn read(client: &ec2::Client, instance_id: &str) -> Result<Self> {
+        let response = client
+            .describe_instances()
+            .instance_ids(instance_id)
+            .send()
+            .await
+            .context("Failed to describe instance")?;
+        let instance = response
+            .reservations()
+            .iter()
+            .flat_map(|reservation| reservation.instances())
+            .next()
+            .context("Instance no longer exists")?;
+
+        let tags: Vec<(String, String)> = instance
+            .tags()
+            .iter()
+            .map(|tag| {
+                (
+                    tag.key().unwrap_or_default().to_string(),
+                    tag.value().unwrap_or_default().to_string(),
+                )
+            })
+            .collect();
+        let volumes = instance
+            .block_device_mappings()
+            .iter()
+            .filter_map(|mapping| mapping.ebs())
+            .filter_map(|ebs| {
+                ebs.volume_id()
+                    .map(|id| (id.to_string(), ebs.delete_on_termination().unwrap_or(false)))
+            })
+            .collect();
+
+        let elastic_ips = client
+            .describe_addresses()
+            .filters(
+                ec2::types::Filter::builder()
+                    .name("instance-id")
+                    .values(instance_id)
+                    .build(),
+            )
+            .send()
+            .await
+            .context("Failed to describe Elastic IPs")?
+            .addresses()
+            .iter()
+            .filter_map(|address| address.public_ip().map(String::from))
+            .collect();
+
+        let termination_protected = client
+            .describe_instance_attribute()
+            .instance_id(instance_id)
+            .attribute(ec2::types::InstanceAttributeName::DisableApiTermination)
+            .send()
+            .await
+            .context("Failed to read termination protection")?
+            .disable_api_termination()
+            .and_then(|value| value.value())
+            .unwrap_or(false);
+
+        Ok(Self {
+            elastic_ips,
+            volumes,
+            auto_scaling_group: tags
+                .iter()
+                .find(|(key, _)| key == AUTO_SCALING_GROUP_TAG)
+                .map(|(_, value)| value.clone()),
+            termination_protected,
+            tags,
+        })
+    }
+
+    /// What happens to the attached resources, one line each
+    pub fn describe(&self, terminating: bool) -> Vec<String> {
+        let mut lines = Vec::new();
+        for ip in &self.elastic_ips {
+            lines.push(if terminating {
+                format!(
+                    "Elastic IP {} is disassociated (still allocated and billed)",
+                    ip
+                )
+            } else {
+                format!("Elastic IP {} stays associated", ip)
+            });
+        }
+        for (volume_id, delete_on_termination) in &self.volumes {
+            lines.push(match (terminating, delete_on_termination) {
+                (true, true) => format!("Volume {} is deleted", volume_id),
+                (true, false) => format!("Volume {} is kept (detached)", volume_id),
+                (false, _) => format!("Volume {} stays attached", volume_id),
+            });
+        }
+        if let Some(group) = &self.auto_scaling_group {
+            lines.push(format!(
+                "Member of Auto Scaling group {}; the group may replace the instance",
+                group
+            ));
+        }
+        lines
+    }
+}
+
+/// Preview for an instance action: dry run plus attached resources
+async fn instance_preview<T, E>(
+    client: &ec2::Client,
+    resource: &ResourceEntry,
+    dry_run: std::result::Result<T, ec2::error::SdkError<E>>,
+    will_change: &str,
+    terminating: bool,
+) -> ActionPreview
+where
+    E: ProvideErrorMetadata + std::error::Error + 'static,
+{
+    let attachments = match InstanceAttachments::read(client, &resource.resource_id).await {
+        Ok(attachments) => attachments,
+        Err(e) => return ActionPreview::Blocked(format!("{:#}", e)),
+    };
+    if terminating {
+        if attachments.termination_protected {
+            return ActionPreview::Blocked(
+                "Termination protection is enabled (DisableApiTermination)".to_string(),
+            );
+        }
+        // Cached tags may be stale; check the live ones too
+        if let Some(tag) = ProtectedTags::load().matching(
+            attachments
+                .tags
+                .iter()
+                .map(|(key, value)| (key.as_str(), value.as_str())),
+        ) {
+            return ActionPreview::Blocked(format!("Protected by tag {}", tag));
+        }
+    }
+    let mut lines = vec![will_change.to_string()];
+    lines.extend(attachments.describe(terminating));
+    dry_run_preview(dry_run, lines.join("\n"))
+}
+
+/// Stop a running EC2 instance
+pub struct StopInstanceAction;
+
+#[async_trait]
+impl ResourceAction for StopInstanceAction {
+    fn id(&self) -> &'static str {
+        "ec2.stop_instance"
+    }
+
+    fn label(&self) -> &'static str {
+        "Stop Instance"
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::EC2::Instance"
+    }
+
+    fn risk(&self) -> ActionRisk {
+        ActionRisk::Reversible
+    }
+
+    fn status_after(&self) -> Option<&'static str> {
+        Some("stopping")
+    }
+
+    fn check(&self, resource: &ResourceEntry) -> Option<String> {
+        match resource.status.as_deref() {
+            Some("running") => None,
+            Some(state) => Some(format!("Instance is {}", state)),
+            None => Some("Instance state unknown".to_string()),
+        }
+    }
+
+    async fn preview(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> ActionPreview {
+        let client = match ec2_client(resource, aws_client).await {
+            Ok(client) => client,
+            Err(e) => return ActionPreview::Blocked(format!("{:#}", e)),
+        };
+        let result = client
+            .stop_instances()
+            .instance_ids(&resource.resource_id)
+            .dry_run(true)
+            .send()
+            .await;
+        instance_preview(&client, resource, result, "running -> stopped", false).await
+    }
+
+    async fn execute(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> Result<String> {
+        crate::app::capabilities::ensure_writable("Stop instance")?;
+        let client = ec2_client(resource, aws_client).await?;
+        let response = client
+            .stop_instances()
+            .instance_ids(&resource.resource_id)
+            .send()
+            .await
+            .context("Failed to stop instance")?;
+        let state = response
+            .stopping_instances()
+            .first()
+            .and_then(|change| change.current_state())
+            .and_then(|state| state.name())
+            .map(|name| name.as_str().to_string())
+            .unwrap_or_else(|| "stopping".to_string());
+        Ok(format!("Instance is {}", state))
+    }
+}
+
+/// Start a stopped EC2 instance
+pub struct StartInstanceAction;
+
+#[async_trait]
+impl ResourceAction for StartInstanceAction {
+    fn id(&self) -> &'static str {
+        "ec2.start_instance"
+    }
+
+    fn label(&self) -> &'static str {
+        "Start Instance"
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::EC2::Instance"
+    }
+
+    fn risk(&self) -> ActionRisk {
+        ActionRisk::Reversible
+    }
+
+    fn status_after(&self) -> Option<&'static str> {
+        Some("pending")
+    }
+
+    fn check(&self, resource: &ResourceEntry) -> Option<String> {
+        match resource.status.as_deref() {
+            Some("stopped") => None,
+            Some(state) => Some(format!("Instance is {}", state)),
+            None => Some("Instance state unknown".to_string()),
+        }
+    }
+
+    async fn preview(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> ActionPreview {
+        let client = match ec2_client(resource, aws_client).await {
+            Ok(client) => client,
+            Err(e) => return ActionPreview::Blocked(format!("{:#}", e)),
+        };
+        let result = client
+            .start_instances()
+            .instance_ids(&resource.resource_id)
+            .dry_run(true)
+            .send()
+            .await;
+        dry_run_preview(result, "stopped -> running".to_string())
+    }
+
+    async fn execute(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> Result<String> {
+        crate::app::capabilities::ensure_writable("Start instance")?;
+        let client = ec2_client(resource, aws_client).await?;
+        let response = client
+            .start_instances()
+            .instance_ids(&resource.resource_id)
+            .send()
+            .await
+            .context("Failed to start instance")?;
+        let state = response
+            .starting_instances()
+            .first()
+            .and_then(|change| change.current_state())
+            .and_then(|state| state.name())
+            .map(|name| name.as_str().to_string())
+            .unwrap_or_else(|| "pending".to_string());
+        Ok(format!("Instance is {}", state))
+    }
+}
+
+/// Reboot a running EC2 instance
+pub struct RebootInstanceAction;
+
+#[async_trait]
+impl ResourceAction for RebootInstanceAction {
+    fn id(&self) -> &'static str {
+        "ec2.reboot_instance"
+    }
+
+    fn label(&self) -> &'static str {
+        "Reboot Instance"
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::EC2::Instance"
+    }
+
+    fn risk(&self) -> ActionRisk {
+        ActionRisk::Reversible
+    }
+
+    fn check(&self, resource: &ResourceEntry) -> Option<String> {
+        match resource.status.as_deref() {
+            Some("running") => None,
+            Some(state) => Some(format!("Instance is {}", state)),
+            None => Some("Instance state unknown".to_string()),
+        }
+    }
+
+    async fn preview(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> ActionPreview {
+        let client = match ec2_client(resource, aws_client).await {
+            Ok(client) => client,
+            Err(e) => return ActionPreview::Blocked(format!("{:#}", e)),
+        };
+        let result = client
+            .reboot_instances()
+            .instance_ids(&resource.resource_id)
+            .dry_run(true)
+            .send()
+            .await;
+        instance_preview(
+            &client,
+            resource,
+            result,
+            "Reboot (operating system restart, same host)",
+            false,
+        )
+        .await
+    }
+
+    async fn execute(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> Result<String> {
+        crate::app::capabilities::ensure_writable("Reboot instance")?;
+        let client = ec2_client(resource, aws_client).await?;
+        client
+            .reboot_instances()
+            .instance_ids(&resource.resource_id)
+            .send()
+            .await
+            .context("Failed to reboot instance")?;
+        Ok("Reboot requested".to_string())
+    }
+}
+
+/// Terminate an EC2 instance
+///
+/// Blocked by termination protection and by protected tags; the confirmation
+/// asks for the instance IDs.
+pub struct TerminateInstanceAction;
+
+#[async_trait]
+impl ResourceAction for TerminateInstanceAction {
+    fn id(&self) -> &'static str {
+        "ec2.terminate_instance"
+    }
+
+    fn label(&self) -> &'static str {
+        "Terminate Instance"
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::EC2::Instance"
+    }
+
+    fn risk(&self) -> ActionRisk {
+        ActionRisk::Destructive
+    }
+
+    fn confirm_by_resource_id(&self) -> bool {
+        true
+    }
+
+    fn status_after(&self) -> Option<&'static str> {
+        Some("shutting-down")
+    }
+
+    fn check(&self, resource: &ResourceEntry) -> Option<String> {
+        match resource.status.as_deref() {
+            Some(state @ ("shutting-down" | "terminated")) => {
+                Some(format!("Instance is {}", state))
+            }
+            _ => None,
+        }
+    }
+
+    async fn preview(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> ActionPreview {
+        let client = match ec2_client(resource, aws_client).await {
+            Ok(client) => client,
+            Err(e) => return ActionPreview::Blocked(format!("{:#}", e)),
+        };
+        let result = client
+            .terminate_instances()
+            .instance_ids(&resource.resource_id)
+            .dry_run(true)
+            .send()
+            .await;
+        instance_preview(
+            &client,
+            resource,
+            result,
+            "Permanently terminate instance",
+            true,
+        )
+        .await
+    }
+
+    async fn execute(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> Result<String> {
+        crate::app::capabilities::ensure_writable("Terminate instance")?;
+        let client = ec2_client(resource, aws_client).await?;
+        let response = client
+            .terminate_instances()
+            .instance_ids(&resource.resource_id)
+            .send()
+            .await
+            .context("Failed to terminate instance")?;
+        let state = response
+            .terminating_instances()
+            .first()
+            .and_then(|change| change.current_state())
+            .and_then(|state| state.name())
+            .map(|name| name.as_str().to_string())
+            .unwrap_or_else(|| "shutting-down".to_string());
+        Ok(format!("Instance is {}", state))
+    }
+}
+
+/// Delete an EBS volume that is not attached to any instance
+pub struct DeleteUnattachedVolumeAction;
+
+#[async_trait]
+impl ResourceAction for DeleteUnattachedVolumeAction {
+    fn id(&self) -> &'static str {
+        "ec2.delete_unattached_volume"
+    }
+
+    fn label(&self) -> &'static str {
+        "Delete Unattached Volume"
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::EC2::Volume"
+    }
+
+    fn risk(&self) -> ActionRisk {
+        ActionRisk::Destructive
+    }
+
+    fn status_after(&self) -> Option<&'static str> {
+        Some("deleting")
+    }
+
+    fn check(&self, resource: &ResourceEntry) -> Option<String> {
+        let attached = resource
+            .properties
+            .get("Attachments")
+            .and_then(|a| a.as_array())
+            .is_some_and(|a| !a.is_empty());
+        match resource.status.as_deref() {
+            _ if attached => Some("Volume is attached".to_string()),
+            Some("available") => None,
+            Some(state) => Some(format!("Volume is {}", state)),
+            None => Some("Volume state unknown".to_string()),
+        }
+    }
+
+    async fn preview(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> ActionPreview {
+        let client = match ec2_client(resource, aws_client).await {
+            Ok(client) => client,
+            Err(e) => return ActionPreview::Blocked(format!("{:#}", e)),
+        };
+
+        // Cached data may be stale; re-check attachment before deleting
+        match client
+            .describe_volumes()
+            .volume_ids(&resource.resource_id)
+            .send()
+            .await
+        {
+            Ok(response) => {
+                let Some(volume) = response.volumes().first() else {
+                    return ActionPreview::Skip("Volume no longer exists".to_string());
+                };
+                if !volume.attachments().is_empty() {
+                    return ActionPreview::Skip("Volume is now attached".to_string());
+                }
+            }
+            Err(e) => {
+                return ActionPreview::Blocked(format!(
+                    "{}: {}",
+                    e.code().unwrap_or("DescribeVolumes failed"),
+                    e.message().unwrap_or("could not read volume")
+                ))
+            }
+        }
+
+        let size = resource
+            .properties
+            .get("Size")
+            .and_then(|s| s.as_i64())
+            .map(|size| format!(" ({} GiB)", size))
+            .unwrap_or_default();
+        let result = client
+            .delete_volume()
+            .volume_id(&resource.resource_id)
+            .dry_run(true)
+            .send()
+            .await;
+        dry_run_preview(result, format!("Permanently delete volume{}", size))
+    }
+
+    async fn execute(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> Result<String> {
+        crate::app::capabilities::ensure_writable("Delete volume")?;
+        let client = ec2_client(resource, aws_client).await?;
+        client
+            .delete_volume()
+            .volume_id(&resource.resource_id)
+            .send()
+            .await
+            .context("Failed to delete volume")?;
+        Ok("Volume deleted".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::resource;
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_instance_state_checks() {
+        let running = resource("AWS::EC2::Instance", Some("running"), json!({}));
+        let stopped = resource("AWS::EC2::Instance", Some("stopped"), json!({}));
+
+        assert!(StopInstanceAction.check(&running).is_none());
+        assert_eq!(
+            StopInstanceAction.check(&stopped).as_deref(),
+            Some("Instance is stopped")
+        );
+        assert!(StartInstanceAction.check(&stopped).is_none());
+        assert!(StartInstanceAction.check(&running).is_some());
+        assert!(RebootInstanceAction.check(&running).is_none());
+        assert!(RebootInstanceAction.check(&stopped).is_some());
+
+        let terminated = resource("AWS::EC2::Instance", Some("terminated"), json!({}));
+        assert!(TerminateInstanceAction.check(&stopped).is_none());
+        assert!(TerminateInstanceAction.check(&terminated).is_some());
+        assert!(TerminateInstanceAction.confirm_by_resource_id());
+    }
+
+    #[test]
+    fn test_attachment_descriptions() {
+        let attachments = InstanceAttachments {
+            elastic_ips: vec!["203.0.113.10".to_string()],
+            volumes: vec![
+                ("vol-root".to_string(), true),
+                ("vol-data".to_string(), false),
+            ],
+            auto_scaling_group: Some("web-asg".to_string()),
+            ..Default::default()
+        };
+        let terminate = attachments.describe(true);
+        assert_eq!(terminate.len(), 4);
+        assert!(terminate[0].contains("disassociated"));
+        assert_eq!(terminate[1], "Volume vol-root is deleted");
+        assert_eq!(terminate[2], "Volume vol-data is kept (detached)");
+        assert!(terminate[3].contains("web-asg"));
+
+        let stop = attachments.describe(false);
+        assert_eq!(stop[1], "Volume vol-root stays attached");
+    }
+
+    #[test]
+    fn test_volume_checks() {
+        let available = resource(
+            "AWS::EC2::Volume",
+            Some("available"),
+            json!({ "Attachments": [] }),
+        );
+        let in_use = resource("AWS::EC2::Volume", Some("in-use"), json!({}));
+        let attached = resource(
+            "AWS::EC2::Volume",
+            Some("available"),
+            json!({ "Attachments": [{ "InstanceId": "i-1" }] }),
+        );
+
+        assert!(DeleteUnattachedVolumeAction.check(&available).is_none());
+        assert!(DeleteUnattachedVolumeAction.check(&in_use).is_some());
+        assert_eq!(
+            DeleteUnattachedVolumeAction.check(&attached).as_deref(),
+            Some("Volume is attached")
+        );
+        assert_eq!(DeleteUnattachedVolumeAction.risk(), ActionRisk::Destructive);
+    }
+}
@@ -0,0 +1,149 @@
+//! IAM resource actions
+//!
+//! IAM has no `DryRun`, so previews read the live state and report what
+//! would change.
+
+use super::{ActionPreview, ActionRisk, ResourceAction};
+use crate::app::aws_regions::active_partition;
+use crate::app::credential_sources::mask_access_key;
+use crate::app::resource_explorer::state::ResourceEntry;
+use crate::app::resource_explorer::AWSResourceClient;
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use aws_sdk_iam as iam;
+
+async fn iam_client(
+    resource: &ResourceEntry,
+    aws_client: &AWSResourceClient,
+) -> Result<iam::Client> {
+    // IAM is global; its endpoint lives in the partition's global region
+    let aws_config = aws_client
+        .get_credential_coordinator()
+        .create_aws_config_for_account(&resource.account_id, active_partition().global_region())
+        .await
+        .with_context(|| {
+            format!(
+                "Failed to create AWS config for account {}",
+                resource.account_id
+            )
+        })?;
+    Ok(iam::Client::new(&aws_config))
+}
+
+/// Active access key IDs of a user, read live from IAM
+async fn active_access_keys(client: &iam::Client, user_name: &str) -> Result<Vec<String>> {
+    let response = client
+        .list_access_keys()
+        .user_name(user_name)
+        .send()
+        .await
+        .context("Failed to list access keys")?;
+    Ok(response
+        .access_key_metadata()
+        .iter()
+        .filter(|key| key.status() == Some(&iam::types::StatusType::Active))
+        .filter_map(|key| key.access_key_id().map(str::to_string))
+        .collect())
+}
+
+/// Deactivate every active access key of an IAM user
+///
+/// Keys are set to Inactive, not deleted, so they can be re-enabled.
+pub struct DisableAccessKeysAction;
+
+#[async_trait]
+impl ResourceAction for DisableAccessKeysAction {
+    fn id(&self) -> &'static str {
+        "iam.disable_access_keys"
+    }
+
+    fn label(&self) -> &'static str {
+        "Disable Access Keys"
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::IAM::User"
+    }
+
+    fn risk(&self) -> ActionRisk {
+        ActionRisk::Reversible
+    }
+
+    fn check(&self, resource: &ResourceEntry) -> Option<String> {
+        // Users loaded without access key details are checked live in the preview
+        let keys = resource.properties.get("AccessKeys")?.as_array()?;
+        let has_active = keys
+            .iter()
+            .any(|key| key.get("Status").and_then(|s| s.as_str()) == Some("Active"));
+        (!has_active).then(|| "No active access keys".to_string())
+    }
+
+    async fn preview(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> ActionPreview {
+        let keys = match iam_client(resource, aws_client).await {
+            Ok(client) => active_access_keys(&client, &resource.resource_id).await,
+            Err(e) => Err(e),
+        };
+        match keys {
+            Ok(keys) if keys.is_empty() => ActionPreview::Skip("No active access keys".to_string()),
+            Ok(keys) => ActionPreview::WillChange(format!(
+                "Deactivate {}",
+                keys.iter()
+                    .map(|key| mask_access_key(key))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+            Err(e) => ActionPreview::Blocked(format!("{:#}", e)),
+        }
+    }
+
+    async fn execute(
+        &self,
+        resource: &ResourceEntry,
+        aws_client: &AWSResourceClient,
+    ) -> Result<String> {
+        crate::app::capabilities::ensure_writable("Disable access keys")?;
+        let client = iam_client(resource, aws_client).await?;
+        let keys = active_access_keys(&client, &resource.resource_id).await?;
+        for key in &keys {
+            client
+                .update_access_key()
+                .user_name(&resource.resource_id)
+                .access_key_id(key)
+                .status(iam::types::StatusType::Inactive)
+                .send()
+                .await
+                .with_context(|| format!("Failed to deactivate {}", mask_access_key(key)))?;
+        }
+        Ok(format!("Deactivated {} access key(s)", keys.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::resource;
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_access_key_check() {
+        let active = resource(
+            "AWS::IAM::User",
+            None,
+            json!({ "AccessKeys": [{ "AccessKeyId": "AKIA1", "Status": "Inactive" }, { "AccessKeyId": "AKIA2", "Status": "Active" }] }),
+        );
+        let inactive = resource(
+            "AWS::IAM::User",
+            None,
+            json!({ "AccessKeys": [{ "AccessKeyId": "AKIA1", "Status": "Inactive" }] }),
+        );
+        let unknown = resource("AWS::IAM::User", None, json!({}));
+
+        assert!(DisableAccessKeysAction.check(&active).is_none());
+        assert!(DisableAccessKeysAction.check(&inactive).is_some());
+        assert!(DisableAccessKeysAction.check(&unknown).is_none());
+    }
+}
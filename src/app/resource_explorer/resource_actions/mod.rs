@@ -0,0 +1,251 @@
+//! Resource actions for the Explorer
+//!
+//! Operations that change AWS resources (stop an instance, delete an unattached
+//! volume, disable access keys) run through this module so every action gets
+//! the same safety rails:
+//!
+//! 1. **Eligibility** - `ResourceAction::check` skips resources that are already
+//!    in the target state using cached properties, without calling AWS
+//! 2. **Dry-run preview** - `ResourceAction::preview` asks AWS whether the call
+//!    would succeed (EC2 `DryRun`) or reads live state for APIs without one
+//! 3. **Confirmation** - `ResourceActionDialog` shows the preview per resource and
+//!    asks for explicit confirmation (typed for destructive actions)
+//! 4. **Protection** - destructive actions are blocked on resources carrying a
+//!    tag from `ProtectedTags`
+//! 5. **Audit** - every executed action is appended to `ActionAuditLog`
+//!
+//! # Module Organization
+//!
+//! Actions are organized by AWS service and registered per resource type in
+//! `ResourceActionRegistry`:
+//! - `ec2` - Stop/start/reboot/terminate instances, delete unattached volumes
+//! - `iam` - Disable active access keys of a user
+//!
+//! # Adding an action
+//!
+//! Implement `ResourceAction` in the service module and add it to
+//! `ResourceActionRegistry::actions_for_type`. Call
+//! `crate::app::capabilities::ensure_writable` at the start of `execute`.
+
+pub mod audit;
+pub mod dialog;
+pub mod ec2;
+pub mod iam;
+pub mod protected_tags;
+
+pub use audit::{ActionAuditEntry, ActionAuditLog};
+pub use dialog::ResourceActionDialog;
+pub use protected_tags::ProtectedTags;
+
+use super::state::ResourceEntry;
+use super::AWSResourceClient;
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// How much care an action needs before running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActionRisk {
+    /// Can be undone by another action (e.g., stop/start)
+    Reversible,
+    /// Cannot be undone (e.g., delete); confirmation must be typed
+    Destructive,
+}
+
+/// What an action would do to one resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ActionPreview {
+    /// The action would run; the message describes the change
+    WillChange(String),
+    /// Nothing to do (already in the target state, wrong kind of resource)
+    Skip(String),
+    /// AWS would reject the call (permissions, dependencies, limits)
+    Blocked(String),
+}
+
+impl ActionPreview {
+    pub fn will_change(&self) -> bool {
+        matches!(self, ActionPreview::WillChange(_))
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            ActionPreview::WillChange(message)
+            | ActionPreview::Skip(message)
+            | ActionPreview::Blocked(message) => message,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ActionPreview::WillChange(_) => "Will change",
+            ActionPreview::Skip(_) => "Skipped",
+            ActionPreview::Blocked(_) => "Blocked",
+        }
+    }
+}
+
+/// An operation that can be applied to resources of one type
+///
+/// Implementations are stateless; the registry hands out boxed instances.
+#[async_trait]
+pub trait ResourceAction: Send + Sync {
+    /// Stable identifier recorded in the audit log (e.g., "ec2.stop_instance")
+    fn id(&self) -> &'static str;
+
+    /// Menu label (e.g., "Stop Instance")
+    fn label(&self) -> &'static str;
+
+    /// CloudFormation resource type this action applies to
+    fn resource_type(&self) -> &'static str;
+
+    fn risk(&self) -> ActionRisk;
+
+    /// Whether confirming requires typing the resource IDs instead of the count
+    fn confirm_by_resource_id(&self) -> bool {
+        false
+    }
+
+    /// Status to show in the tree after a successful run, until the next refresh
+    fn status_after(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Check cached resource data; returns a skip reason when the action does not apply
+    fn check(&self, resource: &ResourceEntry) -> Option<String>;
+
+    /// Determine what the action would do without changing anything
+    async fn preview(&self, resource: &ResourceEntry, aws_client: &AWSResourceClient)
+        -> ActionPreview;
+
+    /// Run the action; returns a short description of what changed
+    async fn execute(&self, resource: &ResourceEntry, aws_client: &AWSResourceClient)
+        -> Result<String>;
+}
+
+/// Lookup of the actions available per resource type
+pub struct ResourceActionRegistry;
+
+impl ResourceActionRegistry {
+    /// Actions that can be applied to resources of the given type
+    pub fn actions_for_type(resource_type: &str) -> Vec<Box<dyn ResourceAction>> {
+        match resource_type {
+            "AWS::EC2::Instance" => vec![
+                Box::new(ec2::StopInstanceAction),
+                Box::new(ec2::StartInstanceAction),
+                Box::new(ec2::RebootInstanceAction),
+                Box::new(ec2::TerminateInstanceAction),
+            ],
+            "AWS::EC2::Volume" => vec![Box::new(ec2::DeleteUnattachedVolumeAction)],
+            "AWS::IAM::User" => vec![Box::new(iam::DisableAccessKeysAction)],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Find an action by its identifier
+    pub fn action(action_id: &str) -> Option<Box<dyn ResourceAction>> {
+        Self::supported_resource_types()
+            .iter()
+            .flat_map(|resource_type| Self::actions_for_type(resource_type))
+            .find(|action| action.id() == action_id)
+    }
+
+    /// Actions for every resource type present in `resources`, in first-seen order
+    pub fn actions_for_resources<'a>(
+        resources: impl IntoIterator<Item = &'a ResourceEntry>,
+    ) -> Vec<Box<dyn ResourceAction>> {
+        let mut seen_types = Vec::new();
+        for resource in resources {
+            if !seen_types.contains(&resource.resource_type.as_str()) {
+                seen_types.push(resource.resource_type.as_str());
+            }
+        }
+        seen_types
+            .into_iter()
+            .flat_map(Self::actions_for_type)
+            .collect()
+    }
+
+    /// Resource types that have at least one action
+    pub fn supported_resource_types() -> &'static [&'static str] {
+        &["AWS::EC2::Instance", "AWS::EC2::Volume", "AWS::IAM::User"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(super) fn resource(
+        resource_type: &str,
+        status: Option<&str>,
+        properties: serde_json::Value,
+    ) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: "id-1".to_string(),
+            display_name: "name-1".to_string(),
+            status: status.map(str::to_string),
+            properties,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_registry_lookup() {
+        let ids: Vec<_> = ResourceActionRegistry::actions_for_type("AWS::EC2::Instance")
+            .iter()
+            .map(|a| a.id())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                "ec2.stop_instance",
+                "ec2.start_instance",
+                "ec2.reboot_instance",
+                "ec2.terminate_instance"
+            ]
+        );
+        assert!(ResourceActionRegistry::actions_for_type("AWS::S3::Bucket").is_empty());
+
+        // Every registered action is findable and matches its registry type
+        for resource_type in ResourceActionRegistry::supported_resource_types() {
+            for action in ResourceActionRegistry::actions_for_type(resource_type) {
+                assert_eq!(action.resource_type(), *resource_type);
+                assert!(ResourceActionRegistry::action(action.id()).is_some());
+            }
+        }
+    }
+
+    #[test]
+    fn test_actions_for_mixed_selection() {
+        let resources = [
+            resource("AWS::EC2::Volume", Some("available"), serde_json::json!({})),
+            resource("AWS::EC2::Instance", Some("running"), serde_json::json!({})),
+            resource("AWS::EC2::Volume", Some("in-use"), serde_json::json!({})),
+        ];
+        let ids: Vec<_> = ResourceActionRegistry::actions_for_resources(resources.iter())
+            .iter()
+            .map(|a| a.id())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                "ec2.delete_unattached_volume",
+                "ec2.stop_instance",
+                "ec2.start_instance",
+                "ec2.reboot_instance",
+                "ec2.terminate_instance"
+            ]
+        );
+    }
+}
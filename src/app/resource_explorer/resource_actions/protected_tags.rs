@@ -0,0 +1,140 @@
+//! Tags that protect resources from destructive actions
+//!
+//! Resources carrying a protected tag are blocked from every
+//! `ActionRisk::Destructive` action (terminate, delete). Entries are either a
+//! tag key (`DoNotDelete`, any value) or `Key=Value` (`Environment=prod`, value
+//! compared ignoring case). The list is kept in
+//! `{config_dir}/awsdash/protected_tags.json` and edited from the action dialog.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// Protection used until the list is edited
+pub const DEFAULT_PROTECTED_TAGS: &[&str] = &["Protected=true", "DoNotDelete"];
+
+/// Tag keys or `Key=Value` pairs that block destructive actions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtectedTags {
+    pub entries: Vec<String>,
+}
+
+impl Default for ProtectedTags {
+    fn default() -> Self {
+        Self {
+            entries: DEFAULT_PROTECTED_TAGS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        }
+    }
+}
+
+impl ProtectedTags {
+    fn file_path() -> Result<PathBuf> {
+        Ok(dirs::config_dir()
+            .context("Failed to get config directory")?
+            .join("awsdash/protected_tags.json"))
+    }
+
+    /// Saved list, or the defaults when none was saved or it cannot be read
+    pub fn load() -> Self {
+        let Ok(path) = Self::file_path() else {
+            return Self::default();
+        };
+        if !path.exists() {
+            return Self::default();
+        }
+        match fs::read_to_string(&path)
+            .context("Failed to read protected tags")
+            .and_then(|contents| {
+                serde_json::from_str(&contents).context("Failed to parse protected tags")
+            }) {
+            Ok(tags) => tags,
+            Err(e) => {
+                tracing::warn!("{:#}; using default protected tags", e);
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let path = Self::file_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).context("Failed to create config directory")?;
+        }
+        let contents =
+            serde_json::to_string_pretty(self).context("Failed to serialize protected tags")?;
+        fs::write(&path, contents)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(())
+    }
+
+    /// One entry per line; blank lines are dropped
+    pub fn parse(text: &str) -> Self {
+        Self {
+            entries: text
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(String::from)
+                .collect(),
+        }
+    }
+
+    /// Entries one per line, for editing
+    pub fn to_text(&self) -> String {
+        self.entries.join("\n")
+    }
+
+    /// The first entry matching one of the tags, as "Key=Value" (or "Key"
+    /// for an empty value)
+    pub fn matching<'a>(
+        &self,
+        tags: impl IntoIterator<Item = (&'a str, &'a str)> + Clone,
+    ) -> Option<String> {
+        self.entries.iter().find_map(|entry| {
+            let (key, value) = match entry.split_once('=') {
+                Some((key, value)) => (key.trim(), Some(value.trim())),
+                None => (entry.trim(), None),
+            };
+            tags.clone()
+                .into_iter()
+                .find(|(tag_key, tag_value)| {
+                    *tag_key == key && value.map_or(true, |v| v.eq_ignore_ascii_case(tag_value))
+                })
+                .map(|(tag_key, tag_value)| {
+                    if tag_value.is_empty() {
+                        tag_key.to_string()
+                    } else {
+                        format!("{}={}", tag_key, tag_value)
+                    }
+                })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching() {
+        let protected = ProtectedTags::parse("Environment=prod\n\n DoNotDelete \n");
+        assert_eq!(protected.entries, ["Environment=prod", "DoNotDelete"]);
+
+        let prod = [("Name", "web"), ("Environment", "Prod")];
+        assert_eq!(
+            protected.matching(prod.iter().copied()).as_deref(),
+            Some("Environment=Prod")
+        );
+        let keep = [("DoNotDelete", "")];
+        assert_eq!(
+            protected.matching(keep.iter().copied()).as_deref(),
+            Some("DoNotDelete")
+        );
+        let dev = [("Environment", "dev"), ("environment", "prod")];
+        assert!(protected.matching(dev.iter().copied()).is_none());
+    }
+}
@@ -0,0 +1,911 @@
+//! Find a resource by ARN, ID, or IP address across accounts
+//!
+//! The search runs in two steps:
+//! 1. **Unified cache** - every cached query result (all panes, all windows) is
+//!    scanned for a matching ID, ARN, or IP address property
+//! 2. **Live APIs** - EC2 IDs and IP addresses are looked up with `Describe*`
+//!    calls in every selected account and region; ARNs of known resource types
+//!    resolve directly to their account, region, and type
+//!
+//! A match is shown in the Explorer with a `RevealRequest`: the pane already
+//! showing it focuses the tree node, otherwise the resource's account, region,
+//! and type are added to a pane's scope and the node is focused once loaded.
+
+use super::state::ResourceEntry;
+use super::status::{report_status, report_status_done};
+use super::{AWSResourceClient, SharedResourceCache};
+use aws_sdk_ec2 as ec2;
+use aws_sdk_ec2::error::ProvideErrorMetadata;
+use egui::{Context, Window};
+use std::collections::HashSet;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+
+/// A parsed Amazon Resource Name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedArn {
+    pub arn: String,
+    pub partition: String,
+    pub service: String,
+    pub region: String,
+    pub account_id: String,
+    /// Everything after the account (e.g., "instance/i-0abc", "function:my-fn")
+    pub resource: String,
+}
+
+impl ParsedArn {
+    pub fn parse(arn: &str) -> Option<Self> {
+        let mut parts = arn.splitn(6, ':');
+        if parts.next()? != "arn" {
+            return None;
+        }
+        let partition = parts.next()?.to_string();
+        let service = parts.next()?.to_string();
+        let region = parts.next()?.to_string();
+        let account_id = parts.next()?.to_string();
+        let resource = parts.next()?.to_string();
+        if partition.is_empty() || service.is_empty() || resource.is_empty() {
+            return None;
+        }
+        Some(Self {
+            arn: arn.to_string(),
+            partition,
+            service,
+            region,
+            account_id,
+            resource,
+        })
+    }
+
+    /// CloudFormation type of the resource, for the types the Explorer can load
+    pub fn resource_type(&self) -> Option<&'static str> {
+        let kind = self.resource.split(['/', ':']).next().unwrap_or_default();
+        let resource_type = match (self.service.as_str(), kind) {
+            ("ec2", "instance") => "AWS::EC2::Instance",
+            ("ec2", "volume") => "AWS::EC2::Volume",
+            ("ec2", "security-group") => "AWS::EC2::SecurityGroup",
+            ("ec2", "subnet") => "AWS::EC2::Subnet",
+            ("ec2", "vpc") => "AWS::EC2::VPC",
+            ("ec2", "network-interface") => "AWS::EC2::NetworkInterface",
+            ("lambda", "function") => "AWS::Lambda::Function",
+            ("s3", _) => "AWS::S3::Bucket",
+            ("iam", "role") => "AWS::IAM::Role",
+            ("iam", "user") => "AWS::IAM::User",
+            ("iam", "policy") => "AWS::IAM::Policy",
+            ("dynamodb", "table") => "AWS::DynamoDB::Table",
+            ("sns", _) => "AWS::SNS::Topic",
+            ("sqs", _) => "AWS::SQS::Queue",
+            ("rds", "db") => "AWS::RDS::DBInstance",
+            ("cloudformation", "stack") => "AWS::CloudFormation::Stack",
+            ("logs", "log-group") => "AWS::Logs::LogGroup",
+            ("kms", "key") => "AWS::KMS::Key",
+            ("ecs", "cluster") => "AWS::ECS::Cluster",
+            ("elasticloadbalancing", "loadbalancer") => "AWS::ElasticLoadBalancingV2::LoadBalancer",
+            _ => return None,
+        };
+        Some(resource_type)
+    }
+
+    /// Short name of the resource (last path segment, e.g., "i-0abc" or "my-fn")
+    ///
+    /// Resources with path-style names (IAM, log groups) keep only the last segment.
+    pub fn resource_name(&self) -> &str {
+        match self.service.as_str() {
+            // arn:aws:logs:region:account:log-group:/aws/lambda/fn:*
+            "logs" => self
+                .resource
+                .strip_prefix("log-group:")
+                .map(|name| name.trim_end_matches(":*"))
+                .unwrap_or(&self.resource),
+            // arn:aws:lambda:region:account:function:name[:qualifier]
+            "lambda" => self.resource.split(':').nth(1).unwrap_or(&self.resource),
+            _ => self
+                .resource
+                .rsplit(['/', ':'])
+                .next()
+                .unwrap_or(&self.resource),
+        }
+    }
+}
+
+/// Resource ID as the Explorer stores it for an ARN or ID reported by another service
+///
+/// ARNs give their short name (see `ParsedArn::resource_name`); anything else
+/// is returned as is.
+pub fn explorer_resource_id(reference: &str) -> String {
+    ParsedArn::parse(reference)
+        .map(|arn| arn.resource_name().to_string())
+        .unwrap_or_else(|| reference.to_string())
+}
+
+/// What the user is looking for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FindQuery {
+    Arn(ParsedArn),
+    IpAddress(String),
+    /// Resource ID or exact name (e.g., "i-0abc", "vol-0abc", "my-bucket")
+    ResourceId(String),
+}
+
+impl FindQuery {
+    /// Classify the input; returns None for empty or multi-word input
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if input.is_empty() || input.contains(char::is_whitespace) {
+            return None;
+        }
+        if input.starts_with("arn:") {
+            return ParsedArn::parse(input).map(FindQuery::Arn);
+        }
+        if let Ok(ip) = input.parse::<IpAddr>() {
+            return Some(FindQuery::IpAddress(ip.to_string()));
+        }
+        Some(FindQuery::ResourceId(input.to_string()))
+    }
+
+    /// Account the query is limited to (ARNs name their account)
+    pub fn account_id(&self) -> Option<&str> {
+        match self {
+            FindQuery::Arn(arn) if !arn.account_id.is_empty() => Some(&arn.account_id),
+            _ => None,
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            FindQuery::Arn(arn) => format!("ARN ({} {})", arn.service, arn.resource_name()),
+            FindQuery::IpAddress(ip) => format!("IP address {}", ip),
+            FindQuery::ResourceId(id) => format!("ID or name {}", id),
+        }
+    }
+
+    /// Check a loaded resource against the query
+    pub fn matches(&self, resource: &ResourceEntry) -> bool {
+        match self {
+            FindQuery::Arn(arn) => {
+                if resource.resource_id == arn.arn || arn_property(resource) == Some(&arn.arn) {
+                    return true;
+                }
+                // Resources loaded without an ARN property match by type and name
+                arn.resource_type() == Some(resource.resource_type.as_str())
+                    && (arn.account_id.is_empty() || arn.account_id == resource.account_id)
+                    && (resource.resource_id == arn.resource_name()
+                        || resource.display_name == arn.resource_name())
+            }
+            FindQuery::IpAddress(ip) => has_ip_property(&resource.properties, ip),
+            FindQuery::ResourceId(id) => {
+                resource.resource_id == *id || resource.display_name == *id
+            }
+        }
+    }
+}
+
+/// The resource's own ARN ("Arn", "ARN", or "<Type>Arn" like "FunctionArn")
+fn arn_property(resource: &ResourceEntry) -> Option<&String> {
+    let type_name = resource
+        .resource_type
+        .rsplit("::")
+        .next()
+        .unwrap_or_default();
+    let typed_key = format!("{}Arn", type_name);
+    let properties = resource.properties.as_object()?;
+    ["Arn", "ARN", typed_key.as_str()]
+        .iter()
+        .find_map(|key| match properties.get(*key) {
+            Some(serde_json::Value::String(value)) => Some(value),
+            _ => None,
+        })
+}
+
+/// Check for an IP address in properties whose key names an IP or address
+///
+/// Only address-like keys count, so a resource referencing an IP in a rule or
+/// description does not match.
+fn has_ip_property(value: &serde_json::Value, ip: &str) -> bool {
+    match value {
+        serde_json::Value::Object(map) => map.iter().any(|(key, value)| {
+            let key = key.to_lowercase();
+            let address_key = key.contains("ip") || key.contains("address");
+            (address_key && value.as_str() == Some(ip)) || has_ip_property(value, ip)
+        }),
+        serde_json::Value::Array(items) => items.iter().any(|item| has_ip_property(item, ip)),
+        _ => false,
+    }
+}
+
+/// Scan every cached query result for matches
+pub fn search_cache(cache: &SharedResourceCache, query: &FindQuery) -> Vec<ResourceEntry> {
+    let mut seen = HashSet::new();
+    let mut matches = Vec::new();
+    for key in cache.resource_keys() {
+        let Some(entries) = cache.get_resources(&key) else {
+            continue;
+        };
+        for entry in entries {
+            if query
+                .account_id()
+                .is_some_and(|account| account != entry.account_id)
+            {
+                continue;
+            }
+            if query.matches(&entry) && seen.insert(entry.selection_key()) {
+                matches.push((*entry).clone());
+            }
+        }
+    }
+    matches
+}
+
+/// A resource located through AWS APIs, not loaded in the Explorer yet
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LiveMatch {
+    pub account_id: String,
+    pub region: String,
+    pub resource_type: String,
+    pub resource_id: String,
+    /// How it was found (e.g., "ENI eni-0abc attached to i-0abc")
+    pub detail: String,
+}
+
+impl LiveMatch {
+    /// Same format as `ResourceEntry::selection_key`
+    fn key(&self) -> String {
+        format!(
+            "{}:{}:{}:{}",
+            self.account_id, self.region, self.resource_type, self.resource_id
+        )
+    }
+}
+
+/// EC2 resource type for an ID prefix the live search can describe
+fn ec2_type_for_id(id: &str) -> Option<&'static str> {
+    let prefix = id.split('-').next()?;
+    let resource_type = match prefix {
+        "i" => "AWS::EC2::Instance",
+        "vol" => "AWS::EC2::Volume",
+        "sg" => "AWS::EC2::SecurityGroup",
+        "subnet" => "AWS::EC2::Subnet",
+        "vpc" => "AWS::EC2::VPC",
+        "eni" => "AWS::EC2::NetworkInterface",
+        _ => return None,
+    };
+    Some(resource_type)
+}
+
+/// Look the query up with AWS APIs in the given accounts and regions
+///
+/// Returns the matches and per-account/region errors. "Not found" answers
+/// are not errors.
+pub async fn search_live(
+    aws_client: &AWSResourceClient,
+    query: &FindQuery,
+    accounts: &[String],
+    regions: &[String],
+) -> (Vec<LiveMatch>, Vec<String>) {
+    match query {
+        FindQuery::Arn(arn) => {
+            let Some(resource_type) = arn.resource_type() else {
+                return (
+                    Vec::new(),
+                    vec![format!("Live lookup does not support {} ARNs", arn.service)],
+                );
+            };
+            if arn.account_id.is_empty() {
+                return (
+                    Vec::new(),
+                    vec!["ARN has no account; load the resource type in the Explorer to find it".to_string()],
+                );
+            }
+            let region = if arn.region.is_empty() {
+                "Global".to_string()
+            } else {
+                arn.region.clone()
+            };
+            let live = LiveMatch {
+                account_id: arn.account_id.clone(),
+                region,
+                resource_type: resource_type.to_string(),
+                resource_id: arn.resource_name().to_string(),
+                detail: "Account, region, and type from the ARN".to_string(),
+            };
+            (vec![live], Vec::new())
+        }
+        FindQuery::ResourceId(id) if ec2_type_for_id(id).is_none() => (
+            Vec::new(),
+            vec!["Live lookup supports EC2 IDs (i-, vol-, sg-, subnet-, vpc-, eni-), ARNs, and IP addresses".to_string()],
+        ),
+        FindQuery::ResourceId(_) | FindQuery::IpAddress(_) => {
+            let lookups = accounts.iter().flat_map(move |account| {
+                regions
+                    .iter()
+                    .filter(|region| region.as_str() != "Global")
+                    .map(move |region| ec2_lookup(aws_client, query, account, region))
+            });
+
+            let mut matches = Vec::new();
+            let mut errors = Vec::new();
+            for result in futures::future::join_all(lookups).await {
+                match result {
+                    Ok(found) => matches.extend(found),
+                    Err(e) => errors.push(e),
+                }
+            }
+            (matches, errors)
+        }
+    }
+}
+
+/// Describe an EC2 ID or search ENIs for an IP address in one account/region
+async fn ec2_lookup(
+    aws_client: &AWSResourceClient,
+    query: &FindQuery,
+    account_id: &str,
+    region: &str,
+) -> Result<Vec<LiveMatch>, String> {
+    let aws_config = aws_client
+        .get_credential_coordinator()
+        .create_aws_config_for_account(account_id, region)
+        .await
+        .map_err(|e| format!("{}/{}: {:#}", account_id, region, e))?;
+    let client = ec2::Client::new(&aws_config);
+    let found = |resource_type: &str, resource_id: &str, detail: String| LiveMatch {
+        account_id: account_id.to_string(),
+        region: region.to_string(),
+        resource_type: resource_type.to_string(),
+        resource_id: resource_id.to_string(),
+        detail,
+    };
+    let failed = |code: Option<&str>, message: Option<&str>| -> Result<Vec<LiveMatch>, String> {
+        // Describe calls report unknown IDs as errors ("InvalidInstanceID.NotFound")
+        match code {
+            Some(code) if code.contains("NotFound") || code.contains("Malformed") => Ok(Vec::new()),
+            _ => Err(format!(
+                "{}/{}: {}",
+                account_id,
+                region,
+                message.or(code).unwrap_or("request failed")
+            )),
+        }
+    };
+
+    match query {
+        FindQuery::IpAddress(ip) => {
+            let mut matches = Vec::new();
+            for filter_name in ["addresses.private-ip-address", "association.public-ip"] {
+                let filter = ec2::types::Filter::builder()
+                    .name(filter_name)
+                    .values(ip)
+                    .build();
+                let response = match client
+                    .describe_network_interfaces()
+                    .filters(filter)
+                    .send()
+                    .await
+                {
+                    Ok(response) => response,
+                    Err(e) => return failed(e.code(), e.message()),
+                };
+                for eni in response.network_interfaces() {
+                    let eni_id = eni.network_interface_id().unwrap_or_default();
+                    match eni.attachment().and_then(|a| a.instance_id()) {
+                        Some(instance_id) => matches.push(found(
+                            "AWS::EC2::Instance",
+                            instance_id,
+                            format!("{} on {}", ip, eni_id),
+                        )),
+                        None => matches.push(found(
+                            "AWS::EC2::NetworkInterface",
+                            eni_id,
+                            format!("{} ({})", ip, eni.description().unwrap_or("unattached")),
+                        )),
+                    }
+                }
+            }
+            Ok(matches)
+        }
+        FindQuery::ResourceId(id) => {
+            let resource_type = ec2_type_for_id(id).unwrap_or_default();
+            let exists = match resource_type {
+                "AWS::EC2::Instance" => client
+                    .describe_instances()
+                    .instance_ids(id)
+                    .send()
+                    .await
+                    .map(|r| {
+                        r.reservations()
+                            .iter()
+                            .any(|res| !res.instances().is_empty())
+                    })
+                    .map_err(|e| {
+                        (
+                            e.code().map(str::to_string),
+                            e.message().map(str::to_string),
+                        )
+                    }),
+                "AWS::EC2::Volume" => client
+                    .describe_volumes()
+                    .volume_ids(id)
+                    .send()
+                    .await
+                    .map(|r| !r.volumes().is_empty())
+                    .map_err(|e| {
+                        (
+                            e.code().map(str::to_string),
+                            e.message().map(str::to_string),
+                        )
+                    }),
+                "AWS::EC2::SecurityGroup" => client
+                    .describe_security_groups()
+                    .group_ids(id)
+                    .send()
+                    .await
+                    .map(|r| !r.security_groups().is_empty())
+                    .map_err(|e| {
+                        (
+                            e.code().map(str::to_string),
+                            e.message().map(str::to_string),
+                        )
+                    }),
+                "AWS::EC2::Subnet" => client
+                    .describe_subnets()
+                    .subnet_ids(id)
+                    .send()
+                    .await
+                    .map(|r| !r.subnets().is_empty())
+                    .map_err(|e| {
+                        (
+                            e.code().map(str::to_string),
+                            e.message().map(str::to_string),
+                        )
+                    }),
+                "AWS::EC2::VPC" => client
+                    .describe_vpcs()
+                    .vpc_ids(id)
+                    .send()
+                    .await
+                    .map(|r| !r.vpcs().is_empty())
+                    .map_err(|e| {
+                        (
+                            e.code().map(str::to_string),
+                            e.message().map(str::to_string),
+                        )
+                    }),
+                _ => client
+                    .describe_network_interfaces()
+                    .network_interface_ids(id)
+                    .send()
+                    .await
+                    .map(|r| !r.network_interfaces().is_empty())
+                    .map_err(|e| {
+                        (
+                            e.code().map(str::to_string),
+                            e.message().map(str::to_string),
+                        )
+                    }),
+            };
+            match exists {
+                Ok(true) => Ok(vec![found(
+                    resource_type,
+                    id,
+                    "Found with EC2 Describe".to_string(),
+                )]),
+                Ok(false) => Ok(Vec::new()),
+                Err((code, message)) => failed(code.as_deref(), message.as_deref()),
+            }
+        }
+        FindQuery::Arn(_) => Ok(Vec::new()),
+    }
+}
+
+/// Where to show a found resource in the Explorer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RevealRequest {
+    pub query: FindQuery,
+    pub account_id: String,
+    pub region: String,
+    pub resource_type: String,
+    /// Known for cached matches; live matches are identified by `query` once loaded
+    pub selection_key: Option<String>,
+}
+
+impl RevealRequest {
+    /// Check a loaded resource against the request
+    pub fn matches(&self, resource: &ResourceEntry) -> bool {
+        match &self.selection_key {
+            Some(key) => resource.selection_key() == *key,
+            None => {
+                resource.account_id == self.account_id
+                    && resource.resource_type == self.resource_type
+                    && self.query.matches(resource)
+            }
+        }
+    }
+}
+
+/// Accounts and regions to search live, usually everything selected in any pane
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FindScope {
+    pub accounts: Vec<String>,
+    pub regions: Vec<String>,
+}
+
+/// Progress shared with the background search
+#[derive(Debug, Default)]
+struct FindJob {
+    cached: Vec<ResourceEntry>,
+    live: Vec<LiveMatch>,
+    errors: Vec<String>,
+    cache_done: bool,
+    finished: bool,
+}
+
+/// "Find Resource" dialog opened from the command palette
+#[derive(Default)]
+pub struct ResourceFinderDialog {
+    pub open: bool,
+    input: String,
+    query: Option<FindQuery>,
+    job: Option<Arc<Mutex<FindJob>>>,
+    cached: Vec<ResourceEntry>,
+    live: Vec<LiveMatch>,
+    errors: Vec<String>,
+    searching: bool,
+    request_focus: bool,
+}
+
+impl ResourceFinderDialog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn open_dialog(&mut self) {
+        self.open = true;
+        self.request_focus = true;
+    }
+
+    fn start_search(
+        &mut self,
+        query: FindQuery,
+        cache: Arc<SharedResourceCache>,
+        aws_client: Option<Arc<AWSResourceClient>>,
+        scope: FindScope,
+    ) {
+        let job = Arc::new(Mutex::new(FindJob::default()));
+        self.job = Some(job.clone());
+        self.query = Some(query.clone());
+        self.cached.clear();
+        self.live.clear();
+        self.errors.clear();
+        self.searching = true;
+
+        std::thread::spawn(move || {
+            report_status("Find", "search_cache", Some(&query.describe()));
+            let cached = search_cache(&cache, &query);
+            let cached_keys: HashSet<String> =
+                cached.iter().map(ResourceEntry::selection_key).collect();
+            if let Ok(mut job) = job.lock() {
+                job.cached = cached;
+                job.cache_done = true;
+            }
+            report_status_done("Find", "search_cache", None);
+
+            let Some(aws_client) = aws_client else {
+                if let Ok(mut job) = job.lock() {
+                    job.errors
+                        .push("Not logged in; searched the cache only".to_string());
+                    job.finished = true;
+                }
+                return;
+            };
+
+            report_status(
+                "Find",
+                "search_live",
+                Some(&format!(
+                    "{} accounts, {} regions",
+                    scope.accounts.len(),
+                    scope.regions.len()
+                )),
+            );
+            let (live, errors) = match tokio::runtime::Runtime::new() {
+                Ok(runtime) => runtime.block_on(search_live(
+                    &aws_client,
+                    &query,
+                    &scope.accounts,
+                    &scope.regions,
+                )),
+                Err(e) => (
+                    Vec::new(),
+                    vec![format!("Failed to create Tokio runtime: {}", e)],
+                ),
+            };
+            report_status_done("Find", "search_live", None);
+
+            if let Ok(mut job) = job.lock() {
+                // Resources already found in the cache are listed once
+                job.live = live
+                    .into_iter()
+                    .filter(|m| !cached_keys.contains(&m.key()))
+                    .collect();
+                job.errors = errors;
+                job.finished = true;
+            }
+        });
+    }
+
+    fn poll_job(&mut self) {
+        let Some(job) = &self.job else {
+            return;
+        };
+        let Ok(job) = job.lock() else {
+            return;
+        };
+        if job.cache_done {
+            self.cached = job.cached.clone();
+        }
+        if job.finished {
+            self.live = job.live.clone();
+            self.errors = job.errors.clone();
+            self.searching = false;
+            drop(job);
+            self.job = None;
+        }
+    }
+
+    /// Render the dialog; returns the resource to show in the Explorer
+    ///
+    /// `scope` lists the accounts and regions searched with live APIs.
+    pub fn show(
+        &mut self,
+        ctx: &Context,
+        cache: Arc<SharedResourceCache>,
+        aws_client: Option<Arc<AWSResourceClient>>,
+        scope: &FindScope,
+    ) -> Option<RevealRequest> {
+        if !self.open {
+            return None;
+        }
+
+        self.poll_job();
+        if self.job.is_some() {
+            ctx.request_repaint_after(std::time::Duration::from_millis(100));
+        }
+
+        let mut open = self.open;
+        let mut reveal = None;
+        let mut search = false;
+
+        Window::new("Find Resource")
+            .id(egui::Id::new("resource_finder_dialog"))
+            .open(&mut open)
+            .default_size([640.0, 380.0])
+            .resizable(true)
+            .collapsible(false)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    let response = ui.add(
+                        egui::TextEdit::singleline(&mut self.input)
+                            .hint_text("ARN, resource ID (i-0abc...), name, or IP address")
+                            .desired_width(460.0),
+                    );
+                    if self.request_focus {
+                        response.request_focus();
+                        self.request_focus = false;
+                    }
+                    let enter = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    let valid = FindQuery::parse(&self.input).is_some();
+                    if (ui.add_enabled(valid && !self.searching, egui::Button::new("Find")).clicked()
+                        || enter)
+                        && valid
+                        && !self.searching
+                    {
+                        search = true;
+                    }
+                });
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Searches the Explorer cache, then {} account(s) and {} region(s) selected in Explorer panes",
+                        scope.accounts.len(),
+                        scope.regions.len()
+                    ))
+                    .small()
+                    .weak(),
+                );
+                ui.separator();
+
+                if let Some(query) = &self.query {
+                    ui.horizontal(|ui| {
+                        ui.label(format!("Looking for {}", query.describe()));
+                        if self.searching {
+                            ui.spinner();
+                        }
+                    });
+                }
+
+                egui::ScrollArea::vertical()
+                    .auto_shrink([false, true])
+                    .show(ui, |ui| {
+                        egui::Grid::new("resource_finder_results")
+                            .num_columns(4)
+                            .striped(true)
+                            .show(ui, |ui| {
+                                let Some(query) = &self.query else {
+                                    return;
+                                };
+                                for resource in &self.cached {
+                                    ui.label(&resource.display_name);
+                                    ui.label(&resource.resource_type);
+                                    ui.label(format!("{} / {}", resource.account_id, resource.region));
+                                    if ui.button("Show").on_hover_text("Loaded in the cache").clicked() {
+                                        reveal = Some(RevealRequest {
+                                            query: query.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                            resource_type: resource.resource_type.clone(),
+                                            selection_key: Some(resource.selection_key()),
+                                        });
+                                    }
+                                    ui.end_row();
+                                }
+                                for found in &self.live {
+                                    ui.label(&found.resource_id).on_hover_text(&found.detail);
+                                    ui.label(&found.resource_type);
+                                    ui.label(format!("{} / {}", found.account_id, found.region));
+                                    if ui
+                                        .button("Load")
+                                        .on_hover_text("Add to an Explorer pane's scope and show it")
+                                        .clicked()
+                                    {
+                                        reveal = Some(RevealRequest {
+                                            query: query.clone(),
+                                            account_id: found.account_id.clone(),
+                                            region: found.region.clone(),
+                                            resource_type: found.resource_type.clone(),
+                                            selection_key: None,
+                                        });
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+
+                        if !self.searching
+                            && self.query.is_some()
+                            && self.cached.is_empty()
+                            && self.live.is_empty()
+                        {
+                            ui.label("No matching resources");
+                        }
+                        for error in &self.errors {
+                            ui.colored_label(ui.visuals().warn_fg_color, error);
+                        }
+                    });
+            });
+
+        self.open = open && reveal.is_none();
+
+        if search {
+            if let Some(query) = FindQuery::parse(&self.input) {
+                self.start_search(query, cache, aws_client, scope.clone());
+            }
+        }
+
+        reveal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn resource(
+        resource_type: &str,
+        resource_id: &str,
+        properties: serde_json::Value,
+    ) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: resource_id.to_string(),
+            display_name: resource_id.to_string(),
+            status: None,
+            properties,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_parse_query_kinds() {
+        assert!(matches!(
+            FindQuery::parse(" 10.0.1.25 "),
+            Some(FindQuery::IpAddress(ip)) if ip == "10.0.1.25"
+        ));
+        assert!(matches!(
+            FindQuery::parse("i-0abc123"),
+            Some(FindQuery::ResourceId(_))
+        ));
+        assert!(matches!(
+            FindQuery::parse("arn:aws:ec2:us-east-1:123456789012:instance/i-0abc123"),
+            Some(FindQuery::Arn(_))
+        ));
+        assert!(FindQuery::parse("").is_none());
+        assert!(FindQuery::parse("two words").is_none());
+        assert!(FindQuery::parse("arn:aws").is_none());
+    }
+
+    #[test]
+    fn test_arn_resource_type_and_name() {
+        let cases = [
+            (
+                "arn:aws:ec2:us-east-1:123456789012:instance/i-0abc",
+                "AWS::EC2::Instance",
+                "i-0abc",
+            ),
+            (
+                "arn:aws:lambda:us-east-1:123456789012:function:my-fn:PROD",
+                "AWS::Lambda::Function",
+                "my-fn",
+            ),
+            (
+                "arn:aws:iam::123456789012:role/service/my-role",
+                "AWS::IAM::Role",
+                "my-role",
+            ),
+            ("arn:aws:s3:::my-bucket", "AWS::S3::Bucket", "my-bucket"),
+            (
+                "arn:aws:logs:us-east-1:123456789012:log-group:/aws/lambda/my-fn:*",
+                "AWS::Logs::LogGroup",
+                "/aws/lambda/my-fn",
+            ),
+        ];
+        for (arn, resource_type, name) in cases {
+            let parsed = ParsedArn::parse(arn).unwrap();
+            assert_eq!(parsed.resource_type(), Some(resource_type), "{}", arn);
+            assert_eq!(parsed.resource_name(), name, "{}", arn);
+        }
+        let unknown = ParsedArn::parse("arn:aws:glue:us-east-1:123456789012:job/etl").unwrap();
+        assert_eq!(unknown.resource_type(), None);
+    }
+
+    #[test]
+    fn test_query_matches_resources() {
+        let function = resource(
+            "AWS::Lambda::Function",
+            "my-fn",
+            json!({ "FunctionArn": "arn:aws:lambda:us-east-1:123456789012:function:my-fn", "KMSKeyArn": "arn:aws:kms:us-east-1:123456789012:key/k1" }),
+        );
+        let instance = resource(
+            "AWS::EC2::Instance",
+            "i-0abc",
+            json!({ "NetworkInterfaces": [{ "PrivateIpAddress": "10.0.1.25" }], "Description": "10.0.9.9" }),
+        );
+
+        let by_arn =
+            FindQuery::parse("arn:aws:lambda:us-east-1:123456789012:function:my-fn").unwrap();
+        assert!(by_arn.matches(&function));
+        // Other ARNs referenced by a resource do not make it match
+        let kms = FindQuery::parse("arn:aws:kms:us-east-1:123456789012:key/k1").unwrap();
+        assert!(!kms.matches(&function));
+
+        assert!(FindQuery::parse("10.0.1.25").unwrap().matches(&instance));
+        assert!(!FindQuery::parse("10.0.9.9").unwrap().matches(&instance));
+        assert!(FindQuery::parse("i-0abc").unwrap().matches(&instance));
+        assert!(!FindQuery::parse("i-0abc").unwrap().matches(&function));
+    }
+
+    #[test]
+    fn test_ec2_type_for_id() {
+        assert_eq!(ec2_type_for_id("i-0abc"), Some("AWS::EC2::Instance"));
+        assert_eq!(ec2_type_for_id("sg-123"), Some("AWS::EC2::SecurityGroup"));
+        assert_eq!(ec2_type_for_id("my-bucket"), None);
+    }
+}
@@ -108,6 +108,9 @@ pub fn categorize_error_string(error_str: &str, service: &str, operation: &str)
         || error_str.contains("ProvisionedThroughputExceededException")
         || error_str.contains("LimitExceededException")
         || error_str.contains("RateExceeded")
+        || error_str.contains("Too Many Requests")
+        || error_str.contains("status: 429")
+        || error_str.contains("status code: 429")
     {
         let error_code = extract_error_code(error_str).unwrap_or("Throttling".to_string());
         return ErrorCategory::Throttled {
@@ -252,6 +255,13 @@ mod tests {
         assert!(matches!(cat, ErrorCategory::Throttled { .. }));
     }
 
+    #[test]
+    fn test_categorize_http_429() {
+        let error = "unhandled error (raw response: status: 429, body: Too Many Requests)";
+        let cat = categorize_error_string(error, "Bedrock", "ListAgents");
+        assert!(matches!(cat, ErrorCategory::Throttled { .. }));
+    }
+
     #[test]
     fn test_categorize_timeout() {
         let error = "TimeoutError: request timed out after 30s";
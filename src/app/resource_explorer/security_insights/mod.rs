@@ -0,0 +1,307 @@
+//! Security posture checks over cached resources
+//!
+//! Rules inspect the properties already loaded into the Explorer cache; no AWS
+//! calls are made. Some rules need Phase 2 details (S3 ACLs and policies, IAM
+//! MFA devices); resources loaded without them are counted as unchecked
+//! instead of passing silently.
+
+pub mod rules;
+
+use super::cache::SharedResourceCache;
+use super::state::ResourceEntry;
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// How urgent a finding is, most urgent first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Critical,
+    High,
+    Medium,
+    Low,
+}
+
+impl Severity {
+    pub fn all() -> [Severity; 4] {
+        [
+            Severity::Critical,
+            Severity::High,
+            Severity::Medium,
+            Severity::Low,
+        ]
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Severity::Critical => "Critical",
+            Severity::High => "High",
+            Severity::Medium => "Medium",
+            Severity::Low => "Low",
+        }
+    }
+}
+
+/// A rule violation on one resource
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub severity: Severity,
+    /// What is wrong with this resource (e.g., "Port 22 open to 0.0.0.0/0")
+    pub detail: String,
+}
+
+impl Violation {
+    pub fn new(severity: Severity, detail: impl Into<String>) -> Self {
+        Self {
+            severity,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// A check run against every cached resource of one type
+pub trait SecurityRule: Send + Sync {
+    /// Stable identifier (e.g., "s3-public-bucket")
+    fn id(&self) -> &'static str;
+
+    /// Short title shown in the findings window
+    fn title(&self) -> &'static str;
+
+    /// CloudFormation resource type the rule applies to
+    fn resource_type(&self) -> &'static str;
+
+    /// Check a resource; None when the cached properties are not enough to decide
+    fn check(&self, resource: &ResourceEntry) -> Option<Vec<Violation>>;
+}
+
+/// All built-in rules
+pub fn all_rules() -> Vec<Box<dyn SecurityRule>> {
+    vec![
+        Box::new(rules::PublicS3Bucket),
+        Box::new(rules::OpenSecurityGroupIngress),
+        Box::new(rules::UnencryptedEbsVolume),
+        Box::new(rules::IamUserWithoutMfa),
+    ]
+}
+
+/// A violation with the resource it was found on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub rule_id: &'static str,
+    pub title: &'static str,
+    pub severity: Severity,
+    pub detail: String,
+    pub resource_type: String,
+    pub resource_id: String,
+    pub display_name: String,
+    pub account_id: String,
+    pub region: String,
+    /// `ResourceEntry::selection_key` of the resource, for showing it in the Explorer
+    pub selection_key: String,
+}
+
+/// Findings of one scan
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScanReport {
+    /// Sorted by severity, then account, region, and resource
+    pub findings: Vec<Finding>,
+    /// Resources at least one rule applied to
+    pub resources_checked: usize,
+    /// Resources a rule could not decide on without Phase 2 details
+    pub resources_missing_details: usize,
+    pub scanned_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Run every rule over the resources; duplicates (same selection key) are checked once
+pub fn scan_resources<'a>(
+    resources: impl IntoIterator<Item = &'a ResourceEntry>,
+    rules: &[Box<dyn SecurityRule>],
+) -> ScanReport {
+    let mut report = ScanReport {
+        scanned_at: Some(chrono::Utc::now()),
+        ..Default::default()
+    };
+    let mut seen = HashSet::new();
+
+    for resource in resources {
+        let applicable: Vec<_> = rules
+            .iter()
+            .filter(|rule| rule.resource_type() == resource.resource_type)
+            .collect();
+        if applicable.is_empty() || !seen.insert(resource.selection_key()) {
+            continue;
+        }
+        report.resources_checked += 1;
+
+        let mut missing_details = false;
+        for rule in applicable {
+            match rule.check(resource) {
+                Some(violations) => {
+                    report
+                        .findings
+                        .extend(violations.into_iter().map(|violation| Finding {
+                            rule_id: rule.id(),
+                            title: rule.title(),
+                            severity: violation.severity,
+                            detail: violation.detail,
+                            resource_type: resource.resource_type.clone(),
+                            resource_id: resource.resource_id.clone(),
+                            display_name: resource.display_name.clone(),
+                            account_id: resource.account_id.clone(),
+                            region: resource.region.clone(),
+                            selection_key: resource.selection_key(),
+                        }))
+                }
+                None => missing_details = true,
+            }
+        }
+        if missing_details {
+            report.resources_missing_details += 1;
+        }
+    }
+
+    report.findings.sort_by(|a, b| {
+        (a.severity, &a.account_id, &a.region, &a.display_name).cmp(&(
+            b.severity,
+            &b.account_id,
+            &b.region,
+            &b.display_name,
+        ))
+    });
+    report
+}
+
+/// Run every rule over all cached query results
+pub fn scan_cache(cache: &SharedResourceCache) -> ScanReport {
+    let resources: Vec<_> = cache
+        .resource_keys()
+        .into_iter()
+        .filter_map(|key| cache.get_resources(&key))
+        .flatten()
+        .collect();
+    scan_resources(resources.iter().map(|r| r.as_ref()), &all_rules())
+}
+
+/// Default CSV path: Downloads (or home) directory with a timestamped name
+pub fn default_findings_path() -> PathBuf {
+    let directory = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .unwrap_or_else(|| PathBuf::from("."));
+    directory.join(format!(
+        "security-findings-{}.csv",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ))
+}
+
+/// Write findings as CSV with a header row
+pub fn export_findings_csv(findings: &[Finding], path: &Path) -> Result<()> {
+    let file = std::fs::File::create(path)
+        .with_context(|| format!("Failed to create {}", path.display()))?;
+    let mut writer = std::io::BufWriter::new(file);
+
+    writeln!(
+        writer,
+        "Severity,Rule,Finding,Detail,Resource Type,Resource ID,Name,Account,Region"
+    )?;
+    for finding in findings {
+        let fields = [
+            finding.severity.label(),
+            finding.rule_id,
+            finding.title,
+            finding.detail.as_str(),
+            finding.resource_type.as_str(),
+            finding.resource_id.as_str(),
+            finding.display_name.as_str(),
+            finding.account_id.as_str(),
+            finding.region.as_str(),
+        ];
+        let row: Vec<String> = fields
+            .iter()
+            .map(|field| super::export::csv_field(field))
+            .collect();
+        writeln!(writer, "{}", row.join(","))?;
+    }
+    writer
+        .flush()
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    pub(super) fn resource(
+        resource_type: &str,
+        resource_id: &str,
+        properties: serde_json::Value,
+    ) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: resource_id.to_string(),
+            display_name: resource_id.to_string(),
+            status: None,
+            properties,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_scan_sorts_dedupes_and_counts_missing_details() {
+        let volume = resource("AWS::EC2::Volume", "vol-1", json!({ "Encrypted": false }));
+        let resources = vec![
+            volume.clone(),
+            volume,
+            resource(
+                "AWS::EC2::SecurityGroup",
+                "sg-1",
+                json!({ "IpPermissions": [{ "IpProtocol": "-1", "IpRanges": [{ "CidrIp": "0.0.0.0/0" }] }] }),
+            ),
+            // No MFADevices property: loaded without details
+            resource("AWS::IAM::User", "alice", json!({})),
+            resource("AWS::Lambda::Function", "fn", json!({})),
+        ];
+
+        let report = scan_resources(&resources, &all_rules());
+        assert_eq!(report.resources_checked, 3);
+        assert_eq!(report.resources_missing_details, 1);
+        assert_eq!(report.findings.len(), 2);
+        assert_eq!(report.findings[0].severity, Severity::Critical);
+        assert_eq!(report.findings[1].rule_id, "ebs-unencrypted");
+    }
+
+    #[test]
+    fn test_export_findings_csv() {
+        let resources = vec![resource(
+            "AWS::EC2::Volume",
+            "vol-1",
+            json!({ "Encrypted": false }),
+        )];
+        let mut report = scan_resources(&resources, &all_rules());
+        report.findings[0].display_name = "data, \"primary\"".to_string();
+
+        let path =
+            std::env::temp_dir().join(format!("security-findings-test-{}.csv", std::process::id()));
+        export_findings_csv(&report.findings, &path).unwrap();
+        let csv = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        let mut lines = csv.lines();
+        assert!(lines.next().unwrap().starts_with("Severity,Rule,"));
+        let row = lines.next().unwrap();
+        assert!(row.starts_with("Medium,ebs-unencrypted,"));
+        assert!(row.contains("\"data, \"\"primary\"\"\""));
+    }
+}
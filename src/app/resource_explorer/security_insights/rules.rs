@@ -0,0 +1,376 @@
+//! Built-in security rules
+
+use super::{SecurityRule, Severity, Violation};
+use crate::app::resource_explorer::state::ResourceEntry;
+use serde_json::Value;
+
+/// Ports for remote administration; open to the world is critical
+const ADMIN_PORTS: [i64; 2] = [22, 3389];
+
+/// Common database and cache ports
+const DATABASE_PORTS: [i64; 7] = [1433, 1521, 3306, 5432, 6379, 9200, 27017];
+
+/// Ports expected to be public on web-facing resources
+const WEB_PORTS: [i64; 2] = [80, 443];
+
+fn bool_property(resource: &ResourceEntry, key: &str) -> Option<bool> {
+    resource.properties.get(key).and_then(Value::as_bool)
+}
+
+/// S3 buckets readable or writable by everyone through their ACL or policy
+pub struct PublicS3Bucket;
+
+impl PublicS3Bucket {
+    /// Check a bucket policy for Allow statements with a "*" principal and no conditions
+    fn policy_is_public(policy: &str) -> bool {
+        let Ok(policy) = serde_json::from_str::<Value>(policy) else {
+            return false;
+        };
+        let statements = match policy.get("Statement") {
+            Some(Value::Array(statements)) => statements.clone(),
+            Some(statement) => vec![statement.clone()],
+            None => return false,
+        };
+        statements.iter().any(|statement| {
+            let allows = statement.get("Effect").and_then(Value::as_str) == Some("Allow");
+            let everyone = match statement.get("Principal") {
+                Some(Value::String(principal)) => principal == "*",
+                Some(Value::Object(principal)) => match principal.get("AWS") {
+                    Some(Value::String(aws)) => aws == "*",
+                    Some(Value::Array(aws)) => aws.iter().any(|a| a.as_str() == Some("*")),
+                    _ => false,
+                },
+                _ => false,
+            };
+            allows && everyone && statement.get("Condition").is_none()
+        })
+    }
+}
+
+impl SecurityRule for PublicS3Bucket {
+    fn id(&self) -> &'static str {
+        "s3-public-bucket"
+    }
+
+    fn title(&self) -> &'static str {
+        "Public S3 bucket"
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::S3::Bucket"
+    }
+
+    fn check(&self, resource: &ResourceEntry) -> Option<Vec<Violation>> {
+        let grants = resource.properties.get("Grants");
+        let has_public_access_block = bool_property(resource, "HasPublicAccessBlock");
+        // ACL and public access block are Phase 2 details
+        if grants.is_none() && has_public_access_block.is_none() {
+            return None;
+        }
+
+        let ignore_public_acls = bool_property(resource, "IgnorePublicAcls").unwrap_or(false);
+        let restrict_public_buckets =
+            bool_property(resource, "RestrictPublicBuckets").unwrap_or(false);
+        let mut violations = Vec::new();
+
+        if !ignore_public_acls {
+            for grant in grants.and_then(Value::as_array).into_iter().flatten() {
+                let uri = grant
+                    .pointer("/Grantee/URI")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                let permission = grant
+                    .get("Permission")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default();
+                let grantee = if uri.ends_with("/global/AllUsers") {
+                    "everyone"
+                } else if uri.ends_with("/global/AuthenticatedUsers") {
+                    "any AWS account"
+                } else {
+                    continue;
+                };
+                let severity = match permission {
+                    "WRITE" | "WRITE_ACP" | "FULL_CONTROL" => Severity::Critical,
+                    _ => Severity::High,
+                };
+                violations.push(Violation::new(
+                    severity,
+                    format!("ACL grants {} to {}", permission, grantee),
+                ));
+            }
+        }
+
+        if !restrict_public_buckets {
+            if let Some(policy) = resource.properties.get("Policy").and_then(Value::as_str) {
+                if Self::policy_is_public(policy) {
+                    violations.push(Violation::new(
+                        Severity::High,
+                        "Bucket policy allows any principal without conditions",
+                    ));
+                }
+            }
+        }
+
+        if violations.is_empty() && has_public_access_block == Some(false) {
+            violations.push(Violation::new(
+                Severity::Low,
+                "No public access block; the bucket can be made public by an ACL or policy",
+            ));
+        }
+        Some(violations)
+    }
+}
+
+/// Security groups allowing inbound traffic from 0.0.0.0/0 or ::/0
+pub struct OpenSecurityGroupIngress;
+
+impl OpenSecurityGroupIngress {
+    /// Worst severity among the ports in an open range
+    fn range_severity(from_port: i64, to_port: i64) -> Severity {
+        let covers = |ports: &[i64]| ports.iter().any(|p| (from_port..=to_port).contains(p));
+        if covers(&ADMIN_PORTS) {
+            Severity::Critical
+        } else if covers(&DATABASE_PORTS) {
+            Severity::High
+        } else if WEB_PORTS.contains(&from_port) && from_port == to_port {
+            Severity::Low
+        } else {
+            Severity::Medium
+        }
+    }
+}
+
+impl SecurityRule for OpenSecurityGroupIngress {
+    fn id(&self) -> &'static str {
+        "sg-open-ingress"
+    }
+
+    fn title(&self) -> &'static str {
+        "Security group open to the internet"
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::EC2::SecurityGroup"
+    }
+
+    fn check(&self, resource: &ResourceEntry) -> Option<Vec<Violation>> {
+        // Groups without ingress rules have no IpPermissions property
+        let permissions = resource
+            .properties
+            .get("IpPermissions")
+            .and_then(Value::as_array);
+
+        let mut violations = Vec::new();
+        for permission in permissions.into_iter().flatten() {
+            let open_ipv4 = permission
+                .get("IpRanges")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|range| range.get("CidrIp").and_then(Value::as_str))
+                .filter(|cidr| *cidr == "0.0.0.0/0");
+            let open_ipv6 = permission
+                .get("Ipv6Ranges")
+                .and_then(Value::as_array)
+                .into_iter()
+                .flatten()
+                .filter_map(|range| range.get("CidrIpv6").and_then(Value::as_str))
+                .filter(|cidr| *cidr == "::/0");
+
+            let protocol = permission
+                .get("IpProtocol")
+                .and_then(Value::as_str)
+                .unwrap_or("-1");
+            let from_port = permission.get("FromPort").and_then(Value::as_i64);
+            let to_port = permission.get("ToPort").and_then(Value::as_i64);
+
+            for cidr in open_ipv4.chain(open_ipv6) {
+                let violation = match (protocol, from_port, to_port) {
+                    ("-1", _, _) | (_, None, _) | (_, _, None) => {
+                        Violation::new(Severity::Critical, format!("All traffic open to {}", cidr))
+                    }
+                    (_, Some(from), Some(to)) if from == to => Violation::new(
+                        Self::range_severity(from, to),
+                        format!("{} {} open to {}", protocol.to_uppercase(), from, cidr),
+                    ),
+                    (_, Some(from), Some(to)) => Violation::new(
+                        Self::range_severity(from, to),
+                        format!(
+                            "{} {}-{} open to {}",
+                            protocol.to_uppercase(),
+                            from,
+                            to,
+                            cidr
+                        ),
+                    ),
+                };
+                violations.push(violation);
+            }
+        }
+        Some(violations)
+    }
+}
+
+/// EBS volumes without encryption at rest
+pub struct UnencryptedEbsVolume;
+
+impl SecurityRule for UnencryptedEbsVolume {
+    fn id(&self) -> &'static str {
+        "ebs-unencrypted"
+    }
+
+    fn title(&self) -> &'static str {
+        "Unencrypted EBS volume"
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::EC2::Volume"
+    }
+
+    fn check(&self, resource: &ResourceEntry) -> Option<Vec<Violation>> {
+        let encrypted = bool_property(resource, "Encrypted")?;
+        Some(if encrypted {
+            Vec::new()
+        } else {
+            vec![Violation::new(
+                Severity::Medium,
+                "Volume is not encrypted at rest",
+            )]
+        })
+    }
+}
+
+/// IAM users without an MFA device
+pub struct IamUserWithoutMfa;
+
+impl SecurityRule for IamUserWithoutMfa {
+    fn id(&self) -> &'static str {
+        "iam-user-no-mfa"
+    }
+
+    fn title(&self) -> &'static str {
+        "IAM user without MFA"
+    }
+
+    fn resource_type(&self) -> &'static str {
+        "AWS::IAM::User"
+    }
+
+    fn check(&self, resource: &ResourceEntry) -> Option<Vec<Violation>> {
+        // MFA devices are a Phase 2 detail
+        let devices = resource
+            .properties
+            .get("MFADevices")
+            .and_then(Value::as_array)?;
+        if !devices.is_empty() {
+            return Some(Vec::new());
+        }
+
+        let console_access = bool_property(resource, "ConsoleAccess").unwrap_or(false)
+            || resource.properties.get("LoginProfile").is_some();
+        Some(vec![if console_access {
+            Violation::new(Severity::High, "Console password without MFA")
+        } else {
+            Violation::new(Severity::Low, "No MFA device (no console access)")
+        }])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::resource;
+    use super::*;
+    use serde_json::json;
+
+    fn severities(rule: &dyn SecurityRule, properties: Value) -> Option<Vec<Severity>> {
+        let resource = resource(rule.resource_type(), "r-1", properties);
+        rule.check(&resource)
+            .map(|violations| violations.iter().map(|v| v.severity).collect())
+    }
+
+    #[test]
+    fn test_public_s3_bucket() {
+        let rule = PublicS3Bucket;
+        assert_eq!(severities(&rule, json!({})), None);
+
+        let public_read = json!({
+            "HasPublicAccessBlock": true,
+            "Grants": [{ "Grantee": { "URI": "http://acs.amazonaws.com/groups/global/AllUsers" }, "Permission": "READ" }]
+        });
+        assert_eq!(
+            severities(&rule, public_read.clone()),
+            Some(vec![Severity::High])
+        );
+
+        let mut ignored = public_read;
+        ignored["IgnorePublicAcls"] = json!(true);
+        assert_eq!(severities(&rule, ignored), Some(vec![]));
+
+        assert_eq!(
+            severities(
+                &rule,
+                json!({ "Grants": [], "HasPublicAccessBlock": false })
+            ),
+            Some(vec![Severity::Low])
+        );
+
+        let policy =
+            r#"{"Statement":[{"Effect":"Allow","Principal":{"AWS":"*"},"Action":"s3:GetObject"}]}"#;
+        assert_eq!(
+            severities(
+                &rule,
+                json!({ "Grants": [], "HasPublicAccessBlock": true, "Policy": policy })
+            ),
+            Some(vec![Severity::High])
+        );
+    }
+
+    #[test]
+    fn test_open_security_group_ingress() {
+        let rule = OpenSecurityGroupIngress;
+        assert_eq!(severities(&rule, json!({})), Some(vec![]));
+
+        let group = json!({ "IpPermissions": [
+            { "IpProtocol": "tcp", "FromPort": 22, "ToPort": 22, "IpRanges": [{ "CidrIp": "0.0.0.0/0" }] },
+            { "IpProtocol": "tcp", "FromPort": 443, "ToPort": 443, "IpRanges": [{ "CidrIp": "0.0.0.0/0" }] },
+            { "IpProtocol": "tcp", "FromPort": 5000, "ToPort": 6000, "Ipv6Ranges": [{ "CidrIpv6": "::/0" }] },
+            { "IpProtocol": "tcp", "FromPort": 3306, "ToPort": 3306, "IpRanges": [{ "CidrIp": "10.0.0.0/8" }] }
+        ]});
+        assert_eq!(
+            severities(&rule, group),
+            Some(vec![Severity::Critical, Severity::Low, Severity::High])
+        );
+    }
+
+    #[test]
+    fn test_unencrypted_volume_and_user_mfa() {
+        assert_eq!(severities(&UnencryptedEbsVolume, json!({})), None);
+        assert_eq!(
+            severities(&UnencryptedEbsVolume, json!({ "Encrypted": false })),
+            Some(vec![Severity::Medium])
+        );
+        assert_eq!(
+            severities(&UnencryptedEbsVolume, json!({ "Encrypted": true })),
+            Some(vec![])
+        );
+
+        let rule = IamUserWithoutMfa;
+        assert_eq!(severities(&rule, json!({})), None);
+        assert_eq!(
+            severities(&rule, json!({ "MFADevices": [], "ConsoleAccess": true })),
+            Some(vec![Severity::High])
+        );
+        assert_eq!(
+            severities(&rule, json!({ "MFADevices": [] })),
+            Some(vec![Severity::Low])
+        );
+        assert_eq!(
+            severities(
+                &rule,
+                json!({ "MFADevices": [{ "SerialNumber": "arn:aws:iam::1:mfa/a" }] })
+            ),
+            Some(vec![])
+        );
+    }
+}
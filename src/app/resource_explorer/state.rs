@@ -23,6 +23,16 @@ pub struct ResourceEntry {
 }
 
 impl ResourceEntry {
+    /// Key identifying this resource across accounts, regions, and types
+    ///
+    /// Used for multi-selection in the tree and for targeting tag edits.
+    pub fn selection_key(&self) -> String {
+        format!(
+            "{}:{}:{}:{}",
+            self.account_id, self.region, self.resource_type, self.resource_id
+        )
+    }
+
     /// Estimate the memory footprint of this resource entry in bytes
     ///
     /// This provides an approximate calculation of heap-allocated memory
@@ -111,12 +121,38 @@ impl ResourceEntry {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ResourceTag {
     pub key: String,
     pub value: String,
 }
 
+/// Tags to add or update and tag keys to remove on one or more resources
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagChanges {
+    /// Tags to add, or whose value replaces the existing one
+    pub set: Vec<ResourceTag>,
+    /// Tag keys to remove
+    pub remove: Vec<String>,
+}
+
+impl TagChanges {
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.remove.is_empty()
+    }
+
+    /// Apply the changes to a tag list, keeping the order of existing tags
+    pub fn apply_to(&self, tags: &mut Vec<ResourceTag>) {
+        tags.retain(|tag| !self.remove.contains(&tag.key));
+        for new_tag in &self.set {
+            match tags.iter_mut().find(|tag| tag.key == new_tag.key) {
+                Some(tag) => tag.value = new_tag.value.clone(),
+                None => tags.push(new_tag.clone()),
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResourceRelationship {
     pub relationship_type: RelationshipType,
@@ -136,6 +172,7 @@ pub enum RelationshipType {
     ProtectedBy,     // MQ Broker protected by Security Group
     DeadLetterQueue, // SQS Queue uses another queue as DLQ
     ServesAsDlq,     // SQS Queue serves as DLQ for another queue
+    Triggers,        // EventBridge rule triggers its target
 }
 
 // ============================================================================
@@ -602,12 +639,19 @@ pub struct ResourceExplorerState {
     pub loading_tasks: HashSet<String>, // Track active queries
     pub cached_queries: HashMap<String, Vec<ResourceEntry>>, // Session cache
     pub show_refresh_dialog: bool,
+    pub delta_refresh_requested: bool, // "Refresh Changed" clicked; handled by the owning pane
+    pub show_export_dialog: bool,      // Export clicked; the owner snapshots the visible tree
+    pub show_network_view: bool,       // Network View replaces the tree with a VPC diagram
+    pub cost_fetch_requested: bool,    // "Costs" clicked; the owning pane fetches Cost Explorer data
+    pub cost_loading: bool,            // Cost Explorer fetch in flight
+    pub cost_index: Option<std::sync::Arc<crate::app::resource_explorer::cost_badges::CostIndex>>, // Cost badges for tree groups
     pub show_account_dialog: bool,
     pub show_region_dialog: bool,
     pub show_resource_type_dialog: bool,
     pub show_unified_selection_dialog: bool, // Unified selection dialog (3-panel)
     pub show_bookmark_dialog: bool, // Show add bookmark dialog
     pub show_bookmark_manager: bool, // Show bookmark manager
+    pub workspace_name_input: String, // Name typed in the Workspaces menu
     pub stale_data_threshold_minutes: i64, // Data older than this is considered stale
     // Tag filtering UI state
     pub show_only_tagged: bool,    // Filter to only resources with tags
@@ -626,6 +670,8 @@ pub struct ResourceExplorerState {
     // This matches the granularity of actual queries (not just resource_type)
     pub phase1_pending_queries: HashSet<String>, // Queries still loading (account:region:resource_type)
     pub phase1_failed_queries: HashSet<String>,  // Queries that failed to load
+    pub phase1_query_errors: HashMap<String, String>, // Raw SDK error of each failed query
+    pub show_failure_report: bool,               // Failed queries indicator clicked; the owner opens the report
     pub phase1_total_queries: usize,             // Total queries requested
     // Phase 1 tag fetching progress (during resource normalization)
     pub phase1_tag_fetching: bool,                // Tag fetching in progress
@@ -671,12 +717,19 @@ impl ResourceExplorerState {
             loading_tasks: HashSet::new(),
             cached_queries: HashMap::new(),
             show_refresh_dialog: false,
+            delta_refresh_requested: false,
+            show_export_dialog: false,
+            show_network_view: false,
+            cost_fetch_requested: false,
+            cost_loading: false,
+            cost_index: None,
             show_account_dialog: false,
             show_region_dialog: false,
             show_resource_type_dialog: false,
             show_unified_selection_dialog: false,
             show_bookmark_dialog: false,
             show_bookmark_manager: false,
+            workspace_name_input: String::new(),
             stale_data_threshold_minutes: 15, // Consider data stale after 15 minutes
             show_only_tagged: false,
             show_only_untagged: false,
@@ -688,6 +741,8 @@ impl ResourceExplorerState {
             show_property_hierarchy_builder: false,
             phase1_pending_queries: HashSet::new(),
             phase1_failed_queries: HashSet::new(),
+            phase1_query_errors: HashMap::new(),
+            show_failure_report: false,
             phase1_total_queries: 0,
             phase1_tag_fetching: false,
             phase1_tag_resource_type: None,
@@ -757,6 +812,7 @@ impl ResourceExplorerState {
         }
         self.phase1_pending_queries = query_keys.iter().cloned().collect();
         self.phase1_failed_queries.clear();
+        self.phase1_query_errors.clear();
         self.phase1_total_queries = query_keys.len();
     }
 
@@ -774,11 +830,13 @@ impl ResourceExplorerState {
         }
     }
 
-    /// Mark a query as failed
+    /// Mark a query as failed, keeping the raw error for the failure report
     /// Key should be in format "account_id:region:resource_type"
-    pub fn mark_phase1_query_failed(&mut self, query_key: &str) {
+    pub fn mark_phase1_query_failed(&mut self, query_key: &str, error: &str) {
         let was_present = self.phase1_pending_queries.remove(query_key);
         self.phase1_failed_queries.insert(query_key.to_string());
+        self.phase1_query_errors
+            .insert(query_key.to_string(), error.to_string());
         tracing::warn!(
             "✗ Phase 1: {} failed (was_tracked: {}, remaining: {}, total_failed: {})",
             query_key, was_present, self.phase1_pending_queries.len(), self.phase1_failed_queries.len()
@@ -807,6 +865,12 @@ impl ResourceExplorerState {
         self.phase1_failed_queries.len()
     }
 
+    /// Forget the failed queries of the last load (the report was dismissed)
+    pub fn clear_phase1_failures(&mut self) {
+        self.phase1_failed_queries.clear();
+        self.phase1_query_errors.clear();
+    }
+
     /// Reset Phase 1 state
     pub fn reset_phase1_state(&mut self) {
         if !self.phase1_pending_queries.is_empty() {
@@ -1153,6 +1217,79 @@ impl ResourceExplorerState {
         filtered
     }
 
+    /// Apply tag changes to the given resources before AWS confirms them
+    ///
+    /// Updates both the visible resources and the session cache so the tree
+    /// shows the new tags immediately. Returns the previous tags of every
+    /// changed resource (by selection key) for rolling back failed updates.
+    pub fn apply_tag_changes_optimistic(
+        &mut self,
+        selection_keys: &HashSet<String>,
+        changes: &TagChanges,
+    ) -> Vec<(String, Vec<ResourceTag>)> {
+        let mut previous = Vec::new();
+        for resource in self.resources.iter_mut() {
+            let key = resource.selection_key();
+            if selection_keys.contains(&key) {
+                previous.push((key, resource.tags.clone()));
+                changes.apply_to(&mut resource.tags);
+            }
+        }
+        for resource in self.cached_queries.values_mut().flatten() {
+            if selection_keys.contains(&resource.selection_key()) {
+                changes.apply_to(&mut resource.tags);
+            }
+        }
+        self.refresh_after_tag_edit();
+        previous
+    }
+
+    /// Put back tags saved by `apply_tag_changes_optimistic`
+    pub fn restore_resource_tags(&mut self, previous: &[(String, Vec<ResourceTag>)]) {
+        if previous.is_empty() {
+            return;
+        }
+        let previous: HashMap<&str, &Vec<ResourceTag>> = previous
+            .iter()
+            .map(|(key, tags)| (key.as_str(), tags))
+            .collect();
+        for resource in self
+            .resources
+            .iter_mut()
+            .chain(self.cached_queries.values_mut().flatten())
+        {
+            if let Some(tags) = previous.get(resource.selection_key().as_str()) {
+                resource.tags = (*tags).clone();
+            }
+        }
+        self.refresh_after_tag_edit();
+    }
+
+    /// Show a new status on the given resources until the next refresh
+    ///
+    /// Used after a resource action succeeded (e.g., "stopping").
+    pub fn set_resource_status(&mut self, selection_keys: &HashSet<String>, status: &str) {
+        if selection_keys.is_empty() {
+            return;
+        }
+        for resource in self
+            .resources
+            .iter_mut()
+            .chain(self.cached_queries.values_mut().flatten())
+        {
+            if selection_keys.contains(&resource.selection_key()) {
+                resource.status = Some(status.to_string());
+            }
+        }
+        self.increment_enrichment_version_force();
+    }
+
+    /// Rebuild tag metadata and the tree after tags changed in place
+    fn refresh_after_tag_edit(&mut self) {
+        self.update_tag_popularity();
+        self.increment_enrichment_version_force();
+    }
+
     /// Helper method to safely update loading state even if lock contention occurs
     pub fn loading_task_count(&self) -> usize {
         self.loading_tasks.len()
@@ -1249,6 +1386,9 @@ pub enum GroupingMode {
     ByRegion,
     /// Group resources by AWS resource type (e.g., AWS::EC2::Instance)
     ByResourceType,
+    /// Group resources by the AWS Organizations OU path of their account
+    /// Accounts outside the discovered organization are grouped under "(no organizational unit)"
+    ByOrganizationalUnit,
     /// Group resources by a single tag key (e.g., "Environment")
     /// Resources without this tag are grouped under "Untagged"
     ByTag(String),
@@ -1277,6 +1417,7 @@ impl GroupingMode {
             GroupingMode::ByAccount => "Account".to_string(),
             GroupingMode::ByRegion => "Region".to_string(),
             GroupingMode::ByResourceType => "Resource Type".to_string(),
+            GroupingMode::ByOrganizationalUnit => "Organizational Unit".to_string(),
             GroupingMode::ByTag(key) => format!("Tag: {}", key),
             GroupingMode::ByTagHierarchy(keys) => {
                 if keys.is_empty() {
@@ -1311,6 +1452,7 @@ impl GroupingMode {
             GroupingMode::ByAccount,
             GroupingMode::ByRegion,
             GroupingMode::ByResourceType,
+            GroupingMode::ByOrganizationalUnit,
         ]
     }
 
@@ -1340,7 +1482,10 @@ impl GroupingMode {
     /// Check if this grouping mode is valid
     pub fn is_valid(&self) -> bool {
         match self {
-            GroupingMode::ByAccount | GroupingMode::ByRegion | GroupingMode::ByResourceType => true,
+            GroupingMode::ByAccount
+            | GroupingMode::ByRegion
+            | GroupingMode::ByResourceType
+            | GroupingMode::ByOrganizationalUnit => true,
             GroupingMode::ByTag(key) => !key.is_empty(),
             GroupingMode::ByTagHierarchy(keys) => {
                 !keys.is_empty() && keys.iter().all(|k| !k.is_empty())
@@ -1358,6 +1503,7 @@ impl GroupingMode {
             GroupingMode::ByAccount => "Account".to_string(),
             GroupingMode::ByRegion => "Region".to_string(),
             GroupingMode::ByResourceType => "Type".to_string(),
+            GroupingMode::ByOrganizationalUnit => "OU".to_string(),
             GroupingMode::ByTag(key) => {
                 if key.len() > 15 {
                     format!("{}...", &key[..12])
@@ -13,6 +13,9 @@ const MAX_MESSAGES: usize = 50;
 /// Maximum age of messages to display (in seconds)
 const MESSAGE_DISPLAY_DURATION_SECS: f32 = 3.0;
 
+/// Operation name of throttle messages
+const THROTTLE_OPERATION: &str = "throttled";
+
 /// A status message from an async operation
 #[derive(Clone, Debug)]
 pub struct StatusMessage {
@@ -26,6 +29,8 @@ pub struct StatusMessage {
     pub detail: Option<String>,
     /// Whether this is a completion message
     pub is_complete: bool,
+    /// Whether this reports a throttled service (category is the service)
+    pub is_throttle: bool,
 }
 
 impl StatusMessage {
@@ -37,6 +42,7 @@ impl StatusMessage {
             operation: operation.to_string(),
             detail: detail.map(|s| s.to_string()),
             is_complete: false,
+            is_throttle: false,
         }
     }
 
@@ -48,11 +54,30 @@ impl StatusMessage {
             operation: operation.to_string(),
             detail: detail.map(|s| s.to_string()),
             is_complete: true,
+            is_throttle: false,
+        }
+    }
+
+    /// Create a message for a service that is throttling requests
+    pub fn throttled(service: &str, detail: &str) -> Self {
+        Self {
+            timestamp: Instant::now(),
+            category: service.to_string(),
+            operation: THROTTLE_OPERATION.to_string(),
+            detail: Some(detail.to_string()),
+            is_complete: false,
+            is_throttle: true,
         }
     }
 
     /// Format the message for display
     pub fn display_text(&self) -> String {
+        if self.is_throttle {
+            return match &self.detail {
+                Some(detail) => format!("{} throttled ({})", self.category, detail),
+                None => format!("{} throttled", self.category),
+            };
+        }
         let action = if self.is_complete { "Done" } else { "Getting" };
         match &self.detail {
             Some(detail) => format!(
@@ -110,6 +135,16 @@ impl StatusChannel {
         self.send(StatusMessage::completed(category, operation, detail));
     }
 
+    /// Report that a service is throttling requests
+    pub fn report_throttled(&self, service: &str, detail: &str) {
+        self.send(StatusMessage::throttled(service, detail));
+    }
+
+    /// Report that a service is back to full speed
+    pub fn report_throttle_cleared(&self, service: &str) {
+        self.send(StatusMessage::completed(service, THROTTLE_OPERATION, None));
+    }
+
     /// Get all fresh (recent) messages for display
     pub fn get_fresh_messages(&self) -> Vec<StatusMessage> {
         if let Ok(messages) = self.messages.read() {
@@ -215,6 +250,22 @@ mod tests {
 
         let msg = StatusMessage::completed("IAM", "list_roles", None);
         assert_eq!(msg.display_text(), "Done IAM list_roles");
+
+        let msg = StatusMessage::throttled("EC2", "backing off 1.0s, 5 parallel");
+        assert_eq!(
+            msg.display_text(),
+            "EC2 throttled (backing off 1.0s, 5 parallel)"
+        );
+    }
+
+    #[test]
+    fn test_throttle_cleared_ends_indicator() {
+        let channel = StatusChannel::new();
+        channel.report_throttled("EC2", "backing off 1.0s, 5 parallel");
+        assert!(channel.get_status_line().contains("EC2 throttled"));
+
+        channel.report_throttle_cleared("EC2");
+        assert_eq!(channel.get_status_line(), "Ready");
     }
 
     #[test]
@@ -0,0 +1,328 @@
+//! Active GuardDuty and Security Hub findings for Explorer resources
+//!
+//! The GuardDuty detector and Security Hub hub normalizers fetch the active
+//! findings of their account and region while the Explorer loads them, and
+//! store them here. Tree nodes look up the worst severity of the findings
+//! that name them, and the findings window lists, archives, and suppresses
+//! them.
+//!
+//! Findings reference resources by ARN or by ID (instance ID, bucket name,
+//! user name); both are keyed by account and the ID the Explorer stores.
+
+use super::resource_finder::explorer_resource_id;
+use super::security_insights::Severity;
+use super::state::ResourceEntry;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+
+/// Service a finding came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FindingSource {
+    GuardDuty,
+    SecurityHub,
+}
+
+impl FindingSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::GuardDuty => "GuardDuty",
+            Self::SecurityHub => "Security Hub",
+        }
+    }
+
+    /// Resource type of the Explorer node the findings are loaded with
+    pub fn resource_type(self) -> &'static str {
+        match self {
+            Self::GuardDuty => "AWS::GuardDuty::Detector",
+            Self::SecurityHub => "AWS::SecurityHub::Hub",
+        }
+    }
+}
+
+/// One active finding
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThreatFinding {
+    pub source: FindingSource,
+    /// GuardDuty finding ID or Security Hub finding ID (an ARN)
+    pub id: String,
+    /// Detector ID (GuardDuty) or product ARN (Security Hub), needed to archive or suppress
+    pub owner: String,
+    pub account_id: String,
+    pub region: String,
+    pub severity: Severity,
+    /// e.g. "Recon:EC2/PortProbeUnprotectedPort" or a Security Hub generator ID
+    pub finding_type: String,
+    pub title: String,
+    pub description: String,
+    /// ARNs or IDs of the affected resources
+    pub resources: Vec<String>,
+    pub updated_at: Option<String>,
+}
+
+impl ThreatFinding {
+    /// Whether the finding names the resource
+    pub fn affects(&self, resource: &ResourceEntry) -> bool {
+        self.account_id == resource.account_id
+            && self
+                .resources
+                .iter()
+                .any(|reference| explorer_resource_id(reference) == resource.resource_id)
+    }
+}
+
+/// GuardDuty severity score (0.0 to 10.0) as a severity level
+pub fn guardduty_severity(score: f64) -> Severity {
+    if score >= 9.0 {
+        Severity::Critical
+    } else if score >= 7.0 {
+        Severity::High
+    } else if score >= 4.0 {
+        Severity::Medium
+    } else {
+        Severity::Low
+    }
+}
+
+/// Security Hub severity label as a severity level; INFORMATIONAL counts as low
+pub fn securityhub_severity(label: &str) -> Severity {
+    match label {
+        "CRITICAL" => Severity::Critical,
+        "HIGH" => Severity::High,
+        "MEDIUM" => Severity::Medium,
+        _ => Severity::Low,
+    }
+}
+
+/// Findings per severity, in `Severity::all()` order
+pub fn severity_counts(findings: &[ThreatFinding]) -> [usize; 4] {
+    let mut counts = [0; 4];
+    for finding in findings {
+        if let Some(position) = Severity::all().iter().position(|s| *s == finding.severity) {
+            counts[position] += 1;
+        }
+    }
+    counts
+}
+
+/// Finding counts stored in a detector's or hub's properties, e.g.
+/// `{"Total": 3, "Critical": 0, "High": 1, "Medium": 2, "Low": 0}`
+pub fn findings_summary_json(findings: &[ThreatFinding]) -> serde_json::Value {
+    let mut summary = serde_json::Map::new();
+    summary.insert("Total".to_string(), findings.len().into());
+    for (severity, count) in Severity::all().iter().zip(severity_counts(findings)) {
+        summary.insert(severity.label().to_string(), count.into());
+    }
+    serde_json::Value::Object(summary)
+}
+
+/// Worst severity and number of findings per resource
+#[derive(Debug, Clone, Default)]
+pub struct ThreatFindingIndex {
+    by_resource: HashMap<(String, String), (Severity, usize)>,
+}
+
+impl ThreatFindingIndex {
+    pub fn from_findings<'a>(findings: impl IntoIterator<Item = &'a ThreatFinding>) -> Self {
+        let mut by_resource: HashMap<(String, String), (Severity, usize)> = HashMap::new();
+        for finding in findings {
+            let mut seen = Vec::new();
+            for reference in &finding.resources {
+                let key = (finding.account_id.clone(), explorer_resource_id(reference));
+                // A finding naming a resource twice (ARN and ID) counts once
+                if seen.contains(&key) {
+                    continue;
+                }
+                seen.push(key.clone());
+                let entry = by_resource.entry(key).or_insert((finding.severity, 0));
+                entry.0 = entry.0.min(finding.severity);
+                entry.1 += 1;
+            }
+        }
+        Self { by_resource }
+    }
+
+    /// Worst severity and finding count for a resource
+    pub fn badge_for(&self, resource: &ResourceEntry) -> Option<(Severity, usize)> {
+        self.by_resource
+            .get(&(resource.account_id.clone(), resource.resource_id.clone()))
+            .copied()
+    }
+}
+
+/// Active findings by source, account, and region
+pub struct ThreatFindingStore {
+    findings: RwLock<HashMap<(FindingSource, String, String), Vec<ThreatFinding>>>,
+    /// Bumped on every change so readers can rebuild cached views
+    version: AtomicU64,
+}
+
+impl Default for ThreatFindingStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ThreatFindingStore {
+    pub fn new() -> Self {
+        Self {
+            findings: RwLock::new(HashMap::new()),
+            version: AtomicU64::new(0),
+        }
+    }
+
+    /// Replace the findings of one source in an account and region
+    pub fn replace(
+        &self,
+        source: FindingSource,
+        account_id: &str,
+        region: &str,
+        findings: Vec<ThreatFinding>,
+    ) {
+        if let Ok(mut all) = self.findings.write() {
+            all.insert(
+                (source, account_id.to_string(), region.to_string()),
+                findings,
+            );
+            self.version.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Drop findings by ID (after archiving or suppressing them)
+    pub fn remove(&self, ids: &[String]) {
+        if let Ok(mut all) = self.findings.write() {
+            for findings in all.values_mut() {
+                findings.retain(|finding| !ids.contains(&finding.id));
+            }
+            self.version.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Every stored finding
+    pub fn all(&self) -> Vec<ThreatFinding> {
+        self.findings
+            .read()
+            .map(|all| all.values().flatten().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version.load(Ordering::Relaxed)
+    }
+
+    /// Index of the stored findings for tree badges
+    pub fn index(&self) -> Arc<ThreatFindingIndex> {
+        let index = self
+            .findings
+            .read()
+            .map(|all| ThreatFindingIndex::from_findings(all.values().flatten()))
+            .unwrap_or_default();
+        Arc::new(index)
+    }
+}
+
+/// Global findings store instance
+static GLOBAL_FINDINGS: std::sync::OnceLock<ThreatFindingStore> = std::sync::OnceLock::new();
+
+/// Get the global findings store
+pub fn global_findings() -> &'static ThreatFindingStore {
+    GLOBAL_FINDINGS.get_or_init(ThreatFindingStore::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(id: &str, severity: Severity, resources: &[&str]) -> ThreatFinding {
+        ThreatFinding {
+            source: FindingSource::SecurityHub,
+            id: id.to_string(),
+            owner: "arn:aws:securityhub:us-east-1::product/aws/guardduty".to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            severity,
+            finding_type: "Recon:EC2/PortProbeUnprotectedPort".to_string(),
+            title: "Unprotected port on EC2 instance is being probed".to_string(),
+            description: String::new(),
+            resources: resources.iter().map(|r| r.to_string()).collect(),
+            updated_at: None,
+        }
+    }
+
+    fn entry(resource_id: &str) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: "AWS::EC2::Instance".to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: resource_id.to_string(),
+            display_name: resource_id.to_string(),
+            status: None,
+            properties: serde_json::json!({}),
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: None,
+            parent_resource_type: None,
+            is_child_resource: false,
+            account_color: egui::Color32::WHITE,
+            region_color: egui::Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_severity_mapping() {
+        assert_eq!(guardduty_severity(9.5), Severity::Critical);
+        assert_eq!(guardduty_severity(8.0), Severity::High);
+        assert_eq!(guardduty_severity(5.0), Severity::Medium);
+        assert_eq!(guardduty_severity(2.0), Severity::Low);
+        assert_eq!(securityhub_severity("HIGH"), Severity::High);
+        assert_eq!(securityhub_severity("INFORMATIONAL"), Severity::Low);
+    }
+
+    #[test]
+    fn test_index_worst_severity() {
+        let findings = [
+            finding(
+                "a",
+                Severity::Medium,
+                &[
+                    "arn:aws:ec2:us-east-1:123456789012:instance/i-0abc",
+                    "i-0abc",
+                ],
+            ),
+            finding("b", Severity::High, &["i-0abc"]),
+            finding("c", Severity::Low, &["i-0def"]),
+        ];
+        let index = ThreatFindingIndex::from_findings(&findings);
+        assert_eq!(index.badge_for(&entry("i-0abc")), Some((Severity::High, 2)));
+        assert_eq!(index.badge_for(&entry("i-0def")), Some((Severity::Low, 1)));
+        assert_eq!(index.badge_for(&entry("i-0123")), None);
+
+        assert!(findings[0].affects(&entry("i-0abc")));
+        assert_eq!(severity_counts(&findings), [0, 1, 1, 1]);
+        assert_eq!(findings_summary_json(&findings)["Total"], 3);
+    }
+
+    #[test]
+    fn test_store_replace_and_remove() {
+        let store = ThreatFindingStore::new();
+        store.replace(
+            FindingSource::SecurityHub,
+            "123456789012",
+            "us-east-1",
+            vec![
+                finding("a", Severity::High, &["i-0abc"]),
+                finding("b", Severity::Low, &["i-0abc"]),
+            ],
+        );
+        let version = store.version();
+        store.remove(&["a".to_string()]);
+        assert!(store.version() > version);
+        assert_eq!(store.all().len(), 1);
+        assert_eq!(
+            store.index().badge_for(&entry("i-0abc")),
+            Some((Severity::Low, 1))
+        );
+    }
+}
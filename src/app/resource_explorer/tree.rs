@@ -1,6 +1,9 @@
-use super::{colors::*, state::*};
+use super::global_services::{global_registry, GLOBAL_REGION};
+use super::{colors::*, organization_directory, state::*};
 use crate::app::data_plane::cloudtrail_events::has_cloudtrail_support;
 use crate::app::data_plane::cloudwatch_logs::{get_log_group_name, has_cloudwatch_logs};
+use crate::app::data_plane::cloudwatch_metrics::has_cloudwatch_metrics;
+use crate::app::data_plane::config_history::has_config_history;
 use egui::{Color32, RichText, Ui};
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
@@ -112,6 +115,52 @@ impl TreeNode {
     }
 }
 
+/// Child resources of each parent resource, as indices into the resources slice
+///
+/// Built once per tree rebuild so attaching children doesn't rescan every
+/// resource for every parent.
+struct ChildIndex<'a> {
+    resources: &'a [ResourceEntry],
+    children: HashMap<(&'a str, &'a str), Vec<usize>>,
+}
+
+impl<'a> ChildIndex<'a> {
+    /// Index the child resources that passed the search filter, sorted by name
+    fn new(
+        resources: &'a [ResourceEntry],
+        filtered_resources: &[ResourceEntry],
+        resource_index_map: &HashMap<String, usize>,
+    ) -> Self {
+        let mut children: HashMap<(&'a str, &'a str), Vec<usize>> = HashMap::new();
+        for child in filtered_resources.iter().filter(|r| r.is_child_resource) {
+            let Some(&idx) = resource_index_map.get(&child.resource_id) else {
+                continue;
+            };
+            let child = &resources[idx];
+            if let (Some(parent_id), Some(parent_type)) =
+                (&child.parent_resource_id, &child.parent_resource_type)
+            {
+                children
+                    .entry((parent_id.as_str(), parent_type.as_str()))
+                    .or_default()
+                    .push(idx);
+            }
+        }
+        for indices in children.values_mut() {
+            indices.sort_by_cached_key(|&idx| resources[idx].display_name.to_lowercase());
+        }
+        Self {
+            resources,
+            children,
+        }
+    }
+
+    fn children_of(&self, parent: &ResourceEntry) -> Option<&Vec<usize>> {
+        self.children
+            .get(&(parent.resource_id.as_str(), parent.resource_type.as_str()))
+    }
+}
+
 /// Tree builder for creating hierarchical structure from flat resource list
 pub struct TreeBuilder;
 
@@ -137,6 +186,8 @@ impl TreeBuilder {
         } else {
             Self::filter_resources(resources, search_filter)
         };
+        // Global resources show once, under the "Global" pseudo-region
+        let filtered_resources = global_registry().dedupe_resources(filtered_resources);
 
         #[cfg(debug_assertions)]
         crate::perf_checkpoint!("tree.build_tree.after_filter", &format!("filtered: {}", filtered_resources.len()));
@@ -149,6 +200,9 @@ impl TreeBuilder {
             .cloned()
             .collect();
 
+        // Child resources are attached under their parents, looked up once here
+        let child_index = ChildIndex::new(resources, &filtered_resources, &resource_index_map);
+
         let mut root = TreeNode::new(
             "root".to_string(),
             "AWS Resources".to_string(),
@@ -162,7 +216,7 @@ impl TreeBuilder {
                     &mut root,
                     &parent_resources,
                     tag_keys,
-                    &filtered_resources,
+                    &child_index,
                     &resource_index_map,
                 );
 
@@ -181,7 +235,7 @@ impl TreeBuilder {
                     &mut root,
                     &parent_resources,
                     property_paths,
-                    &filtered_resources,
+                    &child_index,
                     &resource_index_map,
                 );
 
@@ -257,7 +311,7 @@ impl TreeBuilder {
                     }
 
                     // Attach child resources to their parent resources
-                    Self::attach_child_resources(&mut type_node, &filtered_resources, &resource_index_map);
+                    Self::attach_child_resources(&mut type_node, &child_index);
 
                     primary_node.add_child(type_node);
                 }
@@ -305,7 +359,7 @@ impl TreeBuilder {
                     }
 
                     // Attach child resources to their parent resources
-                    Self::attach_child_resources(&mut sub_node, &filtered_resources, &resource_index_map);
+                    Self::attach_child_resources(&mut sub_node, &child_index);
 
                     primary_node.add_child(sub_node);
                 }
@@ -326,77 +380,54 @@ impl TreeBuilder {
 
     /// Attach child resources as tree nodes under their parent resources
     /// This creates a hierarchical structure for resources with parent-child relationships
-    fn attach_child_resources(
-        parent_node: &mut TreeNode,
-        all_resources: &[ResourceEntry],
-        resource_index_map: &HashMap<String, usize>,
-    ) {
+    fn attach_child_resources(parent_node: &mut TreeNode, child_index: &ChildIndex<'_>) {
         // Iterate through all resource indices in this node
-        // Look up the actual resources from all_resources
         let parent_indices = parent_node.resource_indices.clone();
 
         for &parent_idx in &parent_indices {
-            let parent_resource = &all_resources[parent_idx];
-            // Find all child resources for this parent
-            let children: Vec<ResourceEntry> = all_resources
-                .iter()
-                .filter(|r| {
-                    r.is_child_resource
-                        && r.parent_resource_id.as_ref() == Some(&parent_resource.resource_id)
-                        && r.parent_resource_type.as_ref() == Some(&parent_resource.resource_type)
-                })
-                .cloned()
-                .collect();
-
-            if !children.is_empty() {
-                // Create a child node for this parent resource with its children
-                // Group children by resource type
-                let mut child_groups: HashMap<String, Vec<ResourceEntry>> = HashMap::new();
-                for child in &children {
-                    child_groups
-                        .entry(child.resource_type.clone())
-                        .or_default()
-                        .push(child.clone());
-                }
+            let parent_resource = &child_index.resources[parent_idx];
+            let Some(children) = child_index.children_of(parent_resource) else {
+                continue;
+            };
 
-                // Sort child group keys for consistent ordering
-                let mut child_type_keys: Vec<String> = child_groups.keys().cloned().collect();
-                child_type_keys.sort();
-
-                // For each child resource type, create a sub-node
-                for child_type in child_type_keys {
-                    let child_resources = child_groups.get(&child_type).unwrap();
-                    let child_type_display = child_type
-                        .strip_prefix("AWS::")
-                        .and_then(|s| s.split("::").last())
-                        .unwrap_or(&child_type);
-
-                    let child_node_id = format!(
-                        "child:{}:{}:{}",
-                        parent_resource.resource_id, parent_resource.resource_type, child_type
-                    );
+            // Group children by resource type (sorted for consistent ordering)
+            let mut child_groups: std::collections::BTreeMap<&str, Vec<usize>> =
+                std::collections::BTreeMap::new();
+            for &child_idx in children {
+                child_groups
+                    .entry(child_index.resources[child_idx].resource_type.as_str())
+                    .or_default()
+                    .push(child_idx);
+            }
 
-                    let mut child_node = TreeNode::new(
-                        child_node_id,
-                        format!("{} ({})", child_type_display, child_resources.len()),
-                        NodeType::ResourceType,
-                    );
+            // For each child resource type, create a sub-node
+            for (child_type, child_indices) in child_groups {
+                let child_type_display = child_type
+                    .strip_prefix("AWS::")
+                    .and_then(|s| s.split("::").last())
+                    .unwrap_or(child_type);
 
-                    // Add child resources sorted by name
-                    let mut sorted_children = child_resources.clone();
-                    Self::sort_resources_by_name(&mut sorted_children);
-                    for child_resource in &sorted_children {
-                        if let Some(&idx) = resource_index_map.get(&child_resource.resource_id) {
-                            child_node.add_resource_index(idx);
-                        }
-                    }
+                let child_node_id = format!(
+                    "child:{}:{}:{}",
+                    parent_resource.resource_id, parent_resource.resource_type, child_type
+                );
 
-                    // Recursively attach grandchildren
-                    Self::attach_child_resources(&mut child_node, all_resources, resource_index_map);
+                let mut child_node = TreeNode::new(
+                    child_node_id,
+                    format!("{} ({})", child_type_display, child_indices.len()),
+                    NodeType::ResourceType,
+                );
 
-                    // Add the child node to the parent node
-                    parent_node.add_child(child_node);
+                // Child indices are already sorted by name
+                for child_idx in child_indices {
+                    child_node.add_resource_index(child_idx);
                 }
+
+                // Recursively attach grandchildren
+                Self::attach_child_resources(&mut child_node, child_index);
+
+                // Add the child node to the parent node
+                parent_node.add_child(child_node);
             }
         }
     }
@@ -414,7 +445,7 @@ impl TreeBuilder {
         parent_node: &mut TreeNode,
         resources: &[ResourceEntry],
         tag_keys: &[String],
-        all_resources: &[ResourceEntry],
+        child_index: &ChildIndex<'_>,
         resource_index_map: &HashMap<String, usize>,
     ) {
         if tag_keys.is_empty() || resources.is_empty() {
@@ -483,7 +514,7 @@ impl TreeBuilder {
                     &mut tag_node,
                     group_resources,
                     remaining_tag_keys,
-                    all_resources,
+                    child_index,
                     resource_index_map,
                 );
             } else {
@@ -516,7 +547,7 @@ impl TreeBuilder {
                     }
 
                     // Attach child resources to their parent resources
-                    Self::attach_child_resources(&mut type_node, all_resources, resource_index_map);
+                    Self::attach_child_resources(&mut type_node, child_index);
 
                     tag_node.add_child(type_node);
                 }
@@ -538,7 +569,7 @@ impl TreeBuilder {
         parent_node: &mut TreeNode,
         resources: &[ResourceEntry],
         property_paths: &[String],
-        all_resources: &[ResourceEntry],
+        child_index: &ChildIndex<'_>,
         resource_index_map: &HashMap<String, usize>,
     ) {
         if property_paths.is_empty() || resources.is_empty() {
@@ -612,7 +643,7 @@ impl TreeBuilder {
                     &mut property_node,
                     group_resources,
                     remaining_property_paths,
-                    all_resources,
+                    child_index,
                     resource_index_map,
                 );
             } else {
@@ -645,7 +676,7 @@ impl TreeBuilder {
                     }
 
                     // Attach child resources to their parent resources
-                    Self::attach_child_resources(&mut type_node, all_resources, resource_index_map);
+                    Self::attach_child_resources(&mut type_node, child_index);
 
                     property_node.add_child(type_node);
                 }
@@ -671,6 +702,10 @@ impl TreeBuilder {
                 GroupingMode::ByAccount => resource.account_id.clone(),
                 GroupingMode::ByRegion => resource.region.clone(),
                 GroupingMode::ByResourceType => resource.resource_type.clone(),
+                GroupingMode::ByOrganizationalUnit => {
+                    organization_directory::ou_path(&resource.account_id)
+                        .unwrap_or_else(|| organization_directory::NO_OU_LABEL.to_string())
+                }
                 GroupingMode::ByTag(tag_key) => {
                     // Group by single tag value
                     resource
@@ -748,9 +783,12 @@ impl TreeBuilder {
             GroupingMode::ByRegion => {
                 // For regions, get the human-readable region name
                 // Format: "Region Name - region-id (count)"
-                let region_description = Self::get_region_description_static(key);
-                let display_format =
-                    format!("{} - {} ({})", region_description, key, resources.len());
+                let display_format = if key == GLOBAL_REGION {
+                    format!("{} ({})", GLOBAL_REGION, resources.len())
+                } else {
+                    let region_description = Self::get_region_description_static(key);
+                    format!("{} - {} ({})", region_description, key, resources.len())
+                };
                 if let Some(first_resource) = resources.first() {
                     (display_format, Some(first_resource.region_color))
                 } else {
@@ -762,6 +800,14 @@ impl TreeBuilder {
                 let color = Some(assign_resource_type_color(key));
                 (format!("{} ({})", display_name, resources.len()), color)
             }
+            GroupingMode::ByOrganizationalUnit => {
+                let color = if key == organization_directory::NO_OU_LABEL {
+                    Some(Color32::from_rgb(150, 150, 150)) // Gray outside the organization
+                } else {
+                    Some(Color32::from_rgb(200, 140, 60)) // Amber for OUs
+                };
+                (format!("{} ({})", key, resources.len()), color)
+            }
             GroupingMode::ByTag(tag_key) => {
                 // For tag grouping, display tag value
                 let no_tag_label = format!("No {}", tag_key);
@@ -817,6 +863,7 @@ impl TreeBuilder {
             GroupingMode::ByAccount => NodeType::Account,
             GroupingMode::ByRegion => NodeType::Region,
             GroupingMode::ByResourceType => NodeType::ResourceType,
+            GroupingMode::ByOrganizationalUnit => NodeType::Account,
             GroupingMode::ByTag(_) => NodeType::Account, // Temporary placeholder
             GroupingMode::ByTagHierarchy(_) => NodeType::Account, // Temporary placeholder
             GroupingMode::ByProperty(_) => NodeType::Account, // Temporary placeholder
@@ -850,7 +897,8 @@ impl TreeBuilder {
             "ca-central-1" => "Canada (Central)",
             "af-south-1" => "Africa (Cape Town)",
             "me-south-1" => "Middle East (Bahrain)",
-            _ => "Unknown Region",
+            _ => crate::app::aws_regions::partition_region_name(region_code)
+                .unwrap_or("Unknown Region"),
         }
     }
 
@@ -946,7 +994,7 @@ impl TreeBuilder {
         }
     }
 
-    fn filter_resources(resources: &[ResourceEntry], search_filter: &str) -> Vec<ResourceEntry> {
+    pub(crate) fn filter_resources(resources: &[ResourceEntry], search_filter: &str) -> Vec<ResourceEntry> {
         if search_filter.is_empty() {
             return resources.to_vec();
         }
@@ -1000,11 +1048,20 @@ impl TreeBuilder {
     }
 }
 
+/// Row height assumed before any resource row has been measured
+const ESTIMATED_ROW_HEIGHT: f32 = 24.0;
+
 /// Tree renderer for displaying the hierarchical structure in egui using CollapsingHeader
 pub struct TreeRenderer {
     // Cache tree structure to prevent unnecessary rebuilds
     cached_tree: Option<TreeNode>,
     cache_key: String, // Hash of resources, grouping, and search filter
+    // Filtered resources memoized by a fingerprint of the resources and active filters
+    filter_memo: Option<(u64, std::sync::Arc<Vec<ResourceEntry>>)>,
+    // Last measured height of each resource row by resource index (0.0 = not measured)
+    row_heights: Vec<f32>,
+    // Smallest measured row height (a collapsed row), used for rows not measured yet
+    default_row_height: f32,
     // Resource IDs that need detailed loading
     pub pending_detail_requests: Vec<String>,
     // Resource IDs that failed to load (to prevent infinite retries)
@@ -1013,6 +1070,20 @@ pub struct TreeRenderer {
     pub pending_tag_clicks: Vec<super::state::TagClickAction>,
     // Pending actions to communicate with main app (e.g., open CloudWatch Logs)
     pub pending_explorer_actions: Vec<super::ResourceExplorerAction>,
+    // Multi-selected resources (ResourceEntry::selection_key), toggled with Ctrl+click
+    pub selected_resources: std::collections::HashSet<String>,
+    // Resources to open in the tag editor (selection keys), taken by the pane
+    pub pending_tag_edit: Option<Vec<String>>,
+    // Resource action to open (action id, selection keys), taken by the pane
+    pub pending_resource_action: Option<(String, Vec<String>)>,
+    // Reachability check to open (source, destination selection keys), taken by the pane
+    pub pending_reachability: Option<(String, String)>,
+    // Resource to reveal (selection key) once it is in the rendered tree
+    focus_request: Option<String>,
+    // Group node IDs forced open this frame to reveal the focused resource
+    focus_path: std::collections::HashSet<String>,
+    // Revealed resource, highlighted until the Instant; scrolled to while the bool is set
+    focused_resource: Option<(String, std::time::Instant, bool)>,
     // Tag badge support
     badge_selector: Option<super::tag_badges::BadgeSelector>,
     tag_popularity: Option<super::tag_badges::TagPopularityTracker>,
@@ -1025,9 +1096,21 @@ pub struct TreeRenderer {
     expanded_names: std::collections::HashSet<String>,
     // Phase 2 enrichment status (set by parent before rendering)
     pub phase2_in_progress: bool,
+    // Cost Explorer data for group badges (set by parent before rendering)
+    cost_index: Option<std::sync::Arc<super::cost_badges::CostIndex>>,
+    // Badge text per group node ID, cleared when the tree or cost data changes
+    cost_badges: std::collections::HashMap<String, Option<String>>,
+    // Cost badges only make sense for account/region/resource type groupings
+    cost_badges_enabled: bool,
+    // Compute Optimizer and Trusted Advisor findings for resource badges (set by parent before rendering)
+    recommendation_index: Option<std::sync::Arc<super::recommendation_badges::RecommendationIndex>>,
+    // GuardDuty and Security Hub finding badges, rebuilt when the findings store version changes
+    finding_index: Option<(u64, std::sync::Arc<super::threat_findings::ThreatFindingIndex>)>,
     console_role_menu: ConsoleRoleMenuState,
     console_role_menu_next_request_id: u64,
     default_role_name: Option<String>,
+    // Group headers, resource rows, tag badges and action buttons offered to hint mode
+    pub hints: super::hint_targets::TreeHintTargets,
 }
 
 #[derive(Debug, Clone)]
@@ -1078,10 +1161,20 @@ impl TreeRenderer {
         Self {
             cached_tree: None,
             cache_key: String::new(),
+            filter_memo: None,
+            row_heights: Vec::new(),
+            default_row_height: 0.0,
             pending_detail_requests: Vec::new(),
             failed_detail_requests: std::collections::HashSet::new(),
             pending_tag_clicks: Vec::new(),
             pending_explorer_actions: Vec::new(),
+            selected_resources: std::collections::HashSet::new(),
+            pending_tag_edit: None,
+            pending_resource_action: None,
+            pending_reachability: None,
+            focus_request: None,
+            focus_path: std::collections::HashSet::new(),
+            focused_resource: None,
             badge_selector: None,
             tag_popularity: None,
             is_rebuilding: false,
@@ -1089,9 +1182,15 @@ impl TreeRenderer {
             json_search_terms: std::collections::HashMap::new(),
             expanded_names: std::collections::HashSet::new(),
             phase2_in_progress: false,
+            cost_index: None,
+            cost_badges: std::collections::HashMap::new(),
+            cost_badges_enabled: false,
+            recommendation_index: None,
+            finding_index: None,
             console_role_menu: ConsoleRoleMenuState::new(),
             console_role_menu_next_request_id: 1,
             default_role_name: None,
+            hints: super::hint_targets::TreeHintTargets::new(),
         }
     }
 
@@ -1252,6 +1351,133 @@ impl TreeRenderer {
         )
     }
 
+    /// Expand the groups containing a resource, scroll to it, and highlight it
+    ///
+    /// Applied on the next render where the resource is part of the tree.
+    pub fn focus_resource(&mut self, selection_key: String) {
+        self.focus_request = Some(selection_key);
+    }
+
+    /// Collect the IDs of group nodes leading to the resource with `selection_key`
+    fn find_focus_path(
+        node: &TreeNode,
+        resources: &[super::state::ResourceEntry],
+        selection_key: &str,
+        path: &mut Vec<String>,
+    ) -> bool {
+        path.push(node.id.clone());
+        let contains = node
+            .resource_indices
+            .iter()
+            .any(|&index| resources[index].selection_key() == selection_key)
+            || node
+                .children
+                .iter()
+                .any(|child| Self::find_focus_path(child, resources, selection_key, path));
+        if !contains {
+            path.pop();
+        }
+        contains
+    }
+
+    /// Set the cost data used for group badges (None hides the badges)
+    pub fn set_cost_index(&mut self, cost_index: Option<std::sync::Arc<super::cost_badges::CostIndex>>) {
+        let unchanged = match (&self.cost_index, &cost_index) {
+            (Some(current), Some(new)) => std::sync::Arc::ptr_eq(current, new),
+            (None, None) => true,
+            _ => false,
+        };
+        if !unchanged {
+            self.cost_index = cost_index;
+            self.cost_badges.clear();
+        }
+    }
+
+    /// Set the recommendations used for resource badges (None hides the badges)
+    pub fn set_recommendation_index(
+        &mut self,
+        recommendation_index: Option<std::sync::Arc<super::recommendation_badges::RecommendationIndex>>,
+    ) {
+        self.recommendation_index = recommendation_index;
+    }
+
+    /// Cost badge text for a group node (cached per node ID)
+    fn cost_badge_for(
+        &mut self,
+        node: &TreeNode,
+        resources: &[super::state::ResourceEntry],
+    ) -> Option<String> {
+        if !self.cost_badges_enabled || node.node_type == NodeType::Resource {
+            return None;
+        }
+        let cost_index = self.cost_index.as_ref()?;
+
+        self.cost_badges
+            .entry(node.id.clone())
+            .or_insert_with(|| {
+                let mut indices = Vec::new();
+                Self::collect_resource_indices(node, &mut indices);
+                cost_index
+                    .cost_for_resources(indices.iter().filter_map(|&i| resources.get(i)))
+                    .map(|amount| cost_index.badge_text(&amount))
+            })
+            .clone()
+    }
+
+    fn collect_resource_indices(node: &TreeNode, indices: &mut Vec<usize>) {
+        indices.extend_from_slice(&node.resource_indices);
+        for child in &node.children {
+            Self::collect_resource_indices(child, indices);
+        }
+    }
+
+    /// Fingerprint of a resource slice: the identity of every resource in order
+    ///
+    /// In-place edits that matter for rendering bump the enrichment version
+    /// instead, so only IDs, accounts, regions and types are hashed.
+    pub(crate) fn resources_fingerprint(resources: &[ResourceEntry]) -> u64 {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        resources.len().hash(&mut hasher);
+        for resource in resources {
+            resource.resource_id.hash(&mut hasher);
+            resource.account_id.hash(&mut hasher);
+            resource.region.hash(&mut hasher);
+            resource.resource_type.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Filter resources, reusing the previous result while neither the
+    /// resources nor the filters (summarized by `filter_signature`) change
+    pub fn memoized_filter(
+        &mut self,
+        resources: &[ResourceEntry],
+        filter_signature: u64,
+        filter: impl Fn(&ResourceEntry) -> bool,
+    ) -> std::sync::Arc<Vec<ResourceEntry>> {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+        Self::resources_fingerprint(resources).hash(&mut hasher);
+        filter_signature.hash(&mut hasher);
+        let key = hasher.finish();
+
+        match &self.filter_memo {
+            Some((memo_key, filtered)) if *memo_key == key => filtered.clone(),
+            _ => {
+                let filtered = std::sync::Arc::new(
+                    resources.iter().filter(|r| filter(r)).cloned().collect::<Vec<_>>(),
+                );
+                self.filter_memo = Some((key, filtered.clone()));
+                filtered
+            }
+        }
+    }
+
     /// Generate a cache key from resources, grouping, search filter, and enrichment version
     /// Only rebuild tree if this key changes
     fn generate_cache_key(
@@ -1266,13 +1492,7 @@ impl TreeRenderer {
         let mut hasher = DefaultHasher::new();
 
         // Hash all resource data for accurate change detection
-        resources.len().hash(&mut hasher);
-        for resource in resources.iter() {
-            resource.resource_id.hash(&mut hasher);
-            resource.account_id.hash(&mut hasher);
-            resource.region.hash(&mut hasher);
-            resource.resource_type.hash(&mut hasher);
-        }
+        Self::resources_fingerprint(resources).hash(&mut hasher);
 
         // Hash grouping mode and search filter
         format!("{:?}", primary_grouping).hash(&mut hasher);
@@ -1281,6 +1501,11 @@ impl TreeRenderer {
         // Hash enrichment version to invalidate cache when Phase 2 updates properties
         enrichment_version.hash(&mut hasher);
 
+        // OU groups change once organization discovery finishes
+        if *primary_grouping == super::state::GroupingMode::ByOrganizationalUnit {
+            format!("{:?}", organization_directory::discovery_status()).hash(&mut hasher);
+        }
+
         format!("{:x}", hasher.finish())
     }
 
@@ -1301,6 +1526,12 @@ impl TreeRenderer {
         // Update badge support (clone to store in renderer)
         self.badge_selector = Some(badge_selector.clone());
         self.tag_popularity = Some(tag_popularity.clone());
+        self.cost_badges_enabled = matches!(
+            primary_grouping,
+            super::state::GroupingMode::ByAccount
+                | super::state::GroupingMode::ByRegion
+                | super::state::GroupingMode::ByResourceType
+        );
 
         let new_cache_key = Self::generate_cache_key(
             resources,
@@ -1319,6 +1550,8 @@ impl TreeRenderer {
             let tree = TreeBuilder::build_tree(resources, primary_grouping, search_filter);
             self.cached_tree = Some(tree);
             self.cache_key = new_cache_key;
+            self.cost_badges.clear();
+            self.row_heights.clear();
 
             #[cfg(debug_assertions)]
             crate::perf_checkpoint!("tree.render_cached.rebuild_complete", "");
@@ -1337,12 +1570,31 @@ impl TreeRenderer {
         #[cfg(debug_assertions)]
         crate::perf_checkpoint!("tree.render_cached.before_render_node", "");
 
+        // Open the groups leading to a requested resource once it is in the tree
+        if let (Some(key), Some(tree)) = (&self.focus_request, &self.cached_tree) {
+            let mut path = Vec::new();
+            if Self::find_focus_path(tree, resources, key, &mut path) {
+                self.focus_path = path.into_iter().collect();
+                self.focused_resource = Some((
+                    key.clone(),
+                    std::time::Instant::now() + std::time::Duration::from_secs(3),
+                    true,
+                ));
+                self.focus_request = None;
+            }
+        }
+
+        // Row heights are indexed like the resources slice
+        self.row_heights.resize(resources.len(), 0.0);
+
         // Render the cached tree (borrow instead of clone)
         // Temporarily take ownership to avoid borrow checker issues, then put it back
         if let Some(tree) = self.cached_tree.take() {
             self.render_node(ui, &tree, resources, 0, search_filter);
             self.cached_tree = Some(tree);
         }
+        self.focus_path.clear();
+        self.hints.end_frame();
 
         #[cfg(debug_assertions)]
         crate::perf_checkpoint!("tree.render_cached.complete", "");
@@ -1365,6 +1617,12 @@ impl TreeRenderer {
                            node.node_type, node.id, node.display_name, depth, node.children.len(), node.resource_indices.len());
         }
 
+        let cost_badge = if node.is_leaf() {
+            None
+        } else {
+            self.cost_badge_for(node, resources)
+        };
+
         // Helper function to render the actual node content
         let mut render_content = |ui: &mut Ui| {
             if !node.is_leaf() {
@@ -1395,8 +1653,37 @@ impl TreeRenderer {
                                        node.id, node.display_name);
                     }
 
-                    egui::CollapsingHeader::new(final_header)
+                    // Append the cost badge (if any) in a smaller, muted style
+                    let header: egui::WidgetText = match &cost_badge {
+                        Some(badge) => {
+                            let mut job = egui::text::LayoutJob::default();
+                            final_header.append_to(
+                                &mut job,
+                                ui.style(),
+                                egui::FontSelection::Default,
+                                egui::Align::Center,
+                            );
+                            RichText::new(format!("   {}", badge))
+                                .small()
+                                .color(Color32::from_rgb(110, 170, 110))
+                                .append_to(
+                                    &mut job,
+                                    ui.style(),
+                                    egui::FontSelection::Default,
+                                    egui::Align::Center,
+                                );
+                            job.into()
+                        }
+                        None => final_header.into(),
+                    };
+
+                    let hint_id = format!("node:{}", node.id);
+                    let hint_open =
+                        self.hints.header_open_state(ui, &node.id, &hint_id, depth == 0);
+                    let force_open = self.focus_path.contains(&node.id).then_some(true);
+                    let response = egui::CollapsingHeader::new(header)
                         .default_open(depth == 0) // Auto-expand top-level node
+                        .open(force_open.or(hint_open)) // Reveal a focused resource or apply a hint
                         .id_salt(&node.id) // Unique ID for state management
                         .show(ui, |ui| {
                             // Render children
@@ -1405,18 +1692,23 @@ impl TreeRenderer {
                             }
 
                             // Render individual resources if this is a leaf node with resources
-                            for &resource_idx in &node.resource_indices {
-                                let resource = &resources[resource_idx];
-                                self.render_resource_node(ui, resource, search_filter);
-                            }
+                            self.render_resource_rows(
+                                ui,
+                                &node.resource_indices,
+                                resources,
+                                search_filter,
+                            );
                         });
+                    self.hints.register(
+                        &response.header_response,
+                        hint_id,
+                        crate::app::dashui::NavigableElementType::TreeNode,
+                        &node.display_name,
+                    );
                 });
             } else {
                 // For leaf nodes, render resources directly
-                for &resource_idx in &node.resource_indices {
-                    let resource = &resources[resource_idx];
-                    self.render_resource_node(ui, resource, search_filter);
-                }
+                self.render_resource_rows(ui, &node.resource_indices, resources, search_filter);
             }
         };
 
@@ -1432,6 +1724,84 @@ impl TreeRenderer {
         }
     }
 
+    /// Render resource rows, laying out only those inside the visible area
+    ///
+    /// Rows outside the clip rect are replaced by empty space of their last
+    /// measured height, so scrolling stays smooth with very large groups.
+    fn render_resource_rows(
+        &mut self,
+        ui: &mut Ui,
+        resource_indices: &[usize],
+        resources: &[super::state::ResourceEntry],
+        search_filter: &str,
+    ) {
+        let clip = ui.clip_rect();
+        let mut pending_space = 0.0;
+
+        for (position, &resource_idx) in resource_indices.iter().enumerate() {
+            let top = ui.cursor().top() + pending_space;
+            if top > clip.bottom() {
+                // Everything from here on is below the viewport
+                pending_space += resource_indices[position..]
+                    .iter()
+                    .map(|&idx| self.row_height(idx))
+                    .sum::<f32>();
+                break;
+            }
+
+            let resource = &resources[resource_idx];
+            let height = self.row_height(resource_idx);
+            if top + height < clip.top() && !self.is_scroll_target(resource) {
+                pending_space += height;
+                continue;
+            }
+
+            if pending_space > 0.0 {
+                ui.add_space(pending_space);
+                pending_space = 0.0;
+            }
+            let before = ui.cursor().top();
+            self.render_resource_node(ui, resource, search_filter);
+            let measured = ui.cursor().top() - before;
+            if let Some(slot) = self.row_heights.get_mut(resource_idx) {
+                *slot = measured;
+            }
+            // Remember the height of a collapsed row for rows not rendered yet
+            if measured > 0.0
+                && (self.default_row_height <= 0.0 || measured < self.default_row_height)
+            {
+                self.default_row_height = measured;
+            }
+        }
+
+        if pending_space > 0.0 {
+            ui.add_space(pending_space);
+        }
+    }
+
+    fn row_height(&self, resource_idx: usize) -> f32 {
+        match self.row_heights.get(resource_idx) {
+            Some(&height) if height > 0.0 => height,
+            _ if self.default_row_height > 0.0 => self.default_row_height,
+            _ => ESTIMATED_ROW_HEIGHT,
+        }
+    }
+
+    /// Whether a resource revealed by Find Resource still has to be scrolled to
+    fn is_scroll_target(&self, resource: &ResourceEntry) -> bool {
+        matches!(&self.focused_resource, Some((key, _, true)) if *key == resource.selection_key())
+    }
+
+    /// Whether an action button under an expanded resource was clicked or picked by a hint
+    fn hint_button(&mut self, response: egui::Response, resource_node_id: &str, label: &str) -> bool {
+        self.hints.clicked(
+            &response,
+            format!("button:{}:{}", label, resource_node_id),
+            crate::app::dashui::NavigableElementType::Button,
+            label,
+        )
+    }
+
     fn render_resource_node(
         &mut self,
         ui: &mut Ui,
@@ -1486,6 +1856,9 @@ impl TreeRenderer {
             additional_info.push(age_text);
         }
 
+        let row_hint_id = format!("row:{}", resource_node_id);
+        let mut hint_open = None;
+
         // Use vertical layout to separate header from JSON content
         ui.vertical(|ui| {
             // Header with proper order: arrow, account tag, region tag, then resource info
@@ -1502,9 +1875,13 @@ impl TreeRenderer {
                                       resource_node_id, resource.resource_type, resource.resource_id, resource.display_name);
                     }
 
+                    // A hint on the row opens (or closes) its details like the arrow does
+                    hint_open = self.hints.header_open_state(ui, &resource_node_id, &row_hint_id, false);
+
                     egui::CollapsingHeader::new("")
                         .id_salt(&resource_node_id)
                         .default_open(false)
+                        .open(hint_open)
                         .show_background(false)
                         .show(ui, |_ui| {
                             // Empty - content will be rendered below
@@ -1534,8 +1911,54 @@ impl TreeRenderer {
                     is_name_expanded,
                 );
 
-                // Handle left-click to toggle expanded/collapsed name
-                if tag_response.clicked() {
+                self.hints.register(
+                    &tag_response,
+                    row_hint_id.clone(),
+                    crate::app::dashui::NavigableElementType::TreeNode,
+                    &resource.display_name,
+                );
+
+                let selection_key = resource.selection_key();
+                let is_selected = self.selected_resources.contains(&selection_key);
+                if is_selected {
+                    ui.painter().rect_stroke(
+                        tag_response.rect.expand(1.0),
+                        3.0,
+                        ui.visuals().selection.stroke,
+                        egui::epaint::StrokeKind::Outside,
+                    );
+                }
+
+                // Highlight a resource revealed by Find Resource for a few seconds
+                if let Some((key, until, scroll)) = &mut self.focused_resource {
+                    if *key == selection_key {
+                        if *scroll {
+                            tag_response.scroll_to_me(Some(egui::Align::Center));
+                            *scroll = false;
+                        }
+                        if std::time::Instant::now() < *until {
+                            ui.painter().rect_stroke(
+                                tag_response.rect.expand(3.0),
+                                3.0,
+                                egui::Stroke::new(2.0, ui.visuals().warn_fg_color),
+                                egui::epaint::StrokeKind::Outside,
+                            );
+                            ui.ctx().request_repaint_after(std::time::Duration::from_millis(250));
+                        } else {
+                            self.focused_resource = None;
+                        }
+                    }
+                }
+
+                // Ctrl+click (Cmd+click on macOS) toggles multi-selection;
+                // a plain left-click toggles expanded/collapsed name
+                if tag_response.clicked() && ui.input(|i| i.modifiers.command) {
+                    if is_selected {
+                        self.selected_resources.remove(&selection_key);
+                    } else {
+                        self.selected_resources.insert(selection_key.clone());
+                    }
+                } else if tag_response.clicked() {
                     if is_name_expanded {
                         self.expanded_names.remove(&resource_node_id);
                     } else {
@@ -1576,6 +1999,80 @@ impl TreeRenderer {
                             ui.close();
                         }
                     }
+                    ui.separator();
+                    let selected_label = if is_selected { "Deselect" } else { "Select (Ctrl+Click)" };
+                    if ui.button(selected_label).clicked() {
+                        if is_selected {
+                            self.selected_resources.remove(&selection_key);
+                        } else {
+                            self.selected_resources.insert(selection_key.clone());
+                        }
+                        ui.close();
+                    }
+                    if !crate::app::capabilities::READ_ONLY {
+                        if ui.button("Edit Tags...").clicked() {
+                            self.pending_tag_edit = Some(vec![selection_key.clone()]);
+                            ui.close();
+                        }
+                        let selected_count = self.selected_resources.len();
+                        if is_selected
+                            && selected_count > 1
+                            && ui
+                                .button(format!("Edit Tags on {} Selected...", selected_count))
+                                .clicked()
+                        {
+                            self.pending_tag_edit =
+                                Some(self.selected_resources.iter().cloned().collect());
+                            ui.close();
+                        }
+                        let actions = super::resource_actions::ResourceActionRegistry::actions_for_type(
+                            &resource.resource_type,
+                        );
+                        if !actions.is_empty() {
+                            ui.menu_button("Actions", |ui| {
+                                // Apply to the whole selection when right-clicking a selected resource
+                                let targets: Vec<String> = if is_selected && selected_count > 1 {
+                                    self.selected_resources.iter().cloned().collect()
+                                } else {
+                                    vec![selection_key.clone()]
+                                };
+                                for action in &actions {
+                                    let label = if targets.len() > 1 {
+                                        format!("{} ({} selected)...", action.label(), targets.len())
+                                    } else {
+                                        format!("{}...", action.label())
+                                    };
+                                    if ui.button(label).clicked() {
+                                        self.pending_resource_action =
+                                            Some((action.id().to_string(), targets.clone()));
+                                        ui.close();
+                                    }
+                                }
+                            });
+                        }
+                    }
+                    // Reachability from the other selected resource to this one
+                    if is_selected
+                        && self.selected_resources.len() == 2
+                        && super::reachability::is_endpoint_type(&resource.resource_type)
+                    {
+                        let source = self
+                            .selected_resources
+                            .iter()
+                            .find(|key| **key != selection_key)
+                            .cloned();
+                        if let Some(source) = source {
+                            if ui
+                                .button("Analyze Reachability From Selected...")
+                                .on_hover_text("Check security groups, network ACLs and routes from the other selected resource to this one")
+                                .clicked()
+                            {
+                                self.pending_reachability = Some((source, selection_key.clone()));
+                                ui.close();
+                            }
+                        }
+                    }
+                    ui.separator();
                     ui.menu_button("AWS Console", |ui| {
                         ui.set_min_width(320.0);
                         let account_id = resource.account_id.clone();
@@ -1666,6 +2163,12 @@ impl TreeRenderer {
                     ui.label(egui::RichText::new(additional_info.join(" ")).small());
                 }
 
+                // Render recommendation badge (if any) before the tag badges
+                self.render_recommendation_badge(ui, resource);
+
+                // Render GuardDuty / Security Hub finding badge (if any)
+                self.render_finding_badge(ui, resource);
+
                 // Render tag badges
                 ui.add_space(8.0);
                 self.render_tag_badges(ui, resource);
@@ -1678,8 +2181,9 @@ impl TreeRenderer {
             let enrichable_types = super::state::ResourceExplorerState::enrichable_resource_types();
             let is_enrichable = enrichable_types.contains(&resource.resource_type.as_str());
 
-            if response.header_response.clicked() && response.openness > 0.0
-                && resource.detailed_timestamp.is_none() && is_enrichable {
+            let opened = (response.header_response.clicked() && response.openness > 0.0)
+                || hint_open == Some(true);
+            if opened && resource.detailed_timestamp.is_none() && is_enrichable {
                 let resource_key = format!("{}:{}:{}", resource.account_id, resource.region, resource.resource_id);
                 // Only request if not already pending and not previously failed
                 if !self.pending_detail_requests.contains(&resource_key)
@@ -1694,7 +2198,7 @@ impl TreeRenderer {
                 ui.indent("json_indent", |ui| {
                     // Add additional indentation to make it clearly a child
                     ui.indent("json_child_indent", |ui| {
-                        // Add View Logs and View Events buttons horizontally
+                        // Add View Logs, View Metrics, and View Events buttons horizontally
                         ui.horizontal(|ui| {
                             // Add "View Logs" button if resource has associated CloudWatch Logs
                             if has_cloudwatch_logs(&resource.resource_type) {
@@ -1703,7 +2207,7 @@ impl TreeRenderer {
                                     &resource.display_name,
                                     Some(&resource.resource_id),
                                 ) {
-                                    if ui.small_button("View Logs").clicked() {
+                                    if self.hint_button(ui.small_button("View Logs"), &resource_node_id, "View Logs") {
                                         // Queue action to open CloudWatch Logs window
                                         self.pending_explorer_actions.push(
                                             super::ResourceExplorerAction::OpenCloudWatchLogs {
@@ -1717,9 +2221,301 @@ impl TreeRenderer {
                                 }
                             }
 
+                            // Add "View Metrics" button for resources with default CloudWatch metrics
+                            if has_cloudwatch_metrics(&resource.resource_type)
+                                && self.hint_button(ui.small_button("View Metrics"), &resource_node_id, "View Metrics") {
+                                    // Queue action to open CloudWatch Metrics window
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenCloudWatchMetrics {
+                                            resource_type: resource.resource_type.clone(),
+                                            resource_id: resource.resource_id.clone(),
+                                            resource_name: resource.display_name.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "View Config" button for resources recorded by AWS Config
+                            if has_config_history(&resource.resource_type)
+                                && self.hint_button(ui.small_button("View Config"), &resource_node_id, "View Config") {
+                                    // Queue action to open Config History window
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenConfigHistory {
+                                            resource_type: resource.resource_type.clone(),
+                                            resource_id: resource.resource_id.clone(),
+                                            resource_name: resource.display_name.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Graph" button for resources with relationships in either direction
+                            if self.hint_button(ui.small_button("Graph").on_hover_text("Show related resources as a graph"), &resource_node_id, "Graph") {
+                                self.pending_explorer_actions.push(
+                                    super::ResourceExplorerAction::OpenRelationshipGraph {
+                                        resource_type: resource.resource_type.clone(),
+                                        resource_id: resource.resource_id.clone(),
+                                        resource_name: resource.display_name.clone(),
+                                        account_id: resource.account_id.clone(),
+                                        region: resource.region.clone(),
+                                    },
+                                );
+                            }
+
+                            // Add "Drift" button for CloudFormation stacks
+                            if resource.resource_type == "AWS::CloudFormation::Stack"
+                                && self.hint_button(ui.small_button("Drift").on_hover_text("Detect drift between the template and live resources"), &resource_node_id, "Drift") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenStackDrift {
+                                            stack_name: resource.resource_id.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Update" button for CloudFormation stacks (change set review first)
+                            if resource.resource_type == "AWS::CloudFormation::Stack"
+                                && !crate::app::capabilities::READ_ONLY
+                                && self.hint_button(ui.small_button("Update").on_hover_text("Update the stack through a reviewed change set"), &resource_node_id, "Update") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenStackUpdate {
+                                            stack_name: resource.resource_id.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Rules" button for EventBridge buses and rules
+                            if matches!(
+                                resource.resource_type.as_str(),
+                                "AWS::Events::EventBus" | "AWS::Events::Rule"
+                            ) && self.hint_button(
+                                ui.small_button("Rules")
+                                    .on_hover_text("Browse the bus's rules and targets, and send test events"),
+                                &resource_node_id,
+                                "Rules",
+                            ) {
+                                let is_rule = resource.resource_type == "AWS::Events::Rule";
+                                let event_bus_name = if is_rule {
+                                    resource
+                                        .properties
+                                        .get("EventBusName")
+                                        .and_then(|v| v.as_str())
+                                        .unwrap_or("default")
+                                        .to_string()
+                                } else {
+                                    resource.resource_id.clone()
+                                };
+                                self.pending_explorer_actions.push(
+                                    super::ResourceExplorerAction::OpenEventBridgeBrowser {
+                                        event_bus_name,
+                                        rule_name: is_rule.then(|| resource.resource_id.clone()),
+                                        account_id: resource.account_id.clone(),
+                                        region: resource.region.clone(),
+                                    },
+                                );
+                            }
+
+                            // Add "View Container Logs" for ECS containers (awslogs driver) and
+                            // EKS node groups (Container Insights application logs)
+                            let container_log_group = match resource.resource_type.as_str() {
+                                "AWS::ECS::ContainerDefinition" => resource
+                                    .properties
+                                    .get("LogGroup")
+                                    .and_then(|v| v.as_str())
+                                    .map(|s| s.to_string()),
+                                "AWS::EKS::Nodegroup" => resource
+                                    .properties
+                                    .get("ClusterName")
+                                    .and_then(|v| v.as_str())
+                                    .map(|cluster| format!("/aws/containerinsights/{}/application", cluster)),
+                                _ => None,
+                            };
+                            if let Some(log_group) = container_log_group {
+                                if self.hint_button(ui.small_button("View Container Logs").on_hover_text(&log_group), &resource_node_id, "View Container Logs") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenCloudWatchLogs {
+                                            log_group_name: log_group,
+                                            resource_name: resource.display_name.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+                            }
+
+                            // Add "Invoke" button for Lambda functions
+                            if resource.resource_type == "AWS::Lambda::Function"
+                                && self.hint_button(ui.small_button("Invoke").on_hover_text("Invoke with a test payload"), &resource_node_id, "Invoke") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenLambdaInvoke {
+                                            function_name: resource.resource_id.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Inspect" button for SQS queues
+                            if resource.resource_type == "AWS::SQS::Queue"
+                                && self.hint_button(ui.small_button("Inspect").on_hover_text("Queue depth, message peek, purge and redrive"), &resource_node_id, "Inspect") {
+                                    let queue_url = resource
+                                        .properties
+                                        .get("QueueUrl")
+                                        .and_then(|v| v.as_str())
+                                        .map(|s| s.to_string())
+                                        .unwrap_or_else(|| {
+                                            format!(
+                                                "https://sqs.{}.amazonaws.com/{}/{}",
+                                                resource.region, resource.account_id, resource.resource_id
+                                            )
+                                        });
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenSqsQueueInspector {
+                                            queue_name: resource.resource_id.clone(),
+                                            queue_url,
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Connect" button for EC2 instances (Session Manager shell)
+                            if resource.resource_type == "AWS::EC2::Instance"
+                                && !crate::app::capabilities::READ_ONLY
+                                && self.hint_button(ui.small_button("Connect").on_hover_text("Open a Session Manager shell on the instance"), &resource_node_id, "Connect") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenSsmSession {
+                                            instance_id: resource.resource_id.clone(),
+                                            instance_name: resource.display_name.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Key Usage" button for KMS keys
+                            if resource.resource_type == "AWS::KMS::Key"
+                                && self.hint_button(ui.small_button("Key Usage").on_hover_text("Key policy, grants, rotation, and recent callers"), &resource_node_id, "Key Usage") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenKmsKeyDetails {
+                                            key_id: resource.resource_id.clone(),
+                                            key_name: resource.display_name.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Storage" button for S3 buckets
+                            if resource.resource_type == "AWS::S3::Bucket"
+                                && self.hint_button(ui.small_button("Storage").on_hover_text("Size by storage class, lifecycle, replication, and public access"), &resource_node_id, "Storage") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenS3BucketDetails {
+                                            bucket_name: resource.resource_id.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Invalidations" button for CloudFront distributions
+                            if resource.resource_type == "AWS::CloudFront::Distribution"
+                                && self.hint_button(ui.small_button("Invalidations").on_hover_text("Invalidate cached paths and follow recent invalidations"), &resource_node_id, "Invalidations") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenCloudFrontInvalidations {
+                                            distribution_id: resource.resource_id.clone(),
+                                            distribution_name: resource.display_name.clone(),
+                                            account_id: resource.account_id.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Capacity" button for Auto Scaling groups
+                            if resource.resource_type == "AWS::AutoScaling::AutoScalingGroup"
+                                && self.hint_button(ui.small_button("Capacity").on_hover_text("Capacity, instance health, scaling policies, and activity history"), &resource_node_id, "Capacity") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenAutoScalingGroup {
+                                            group_name: resource.resource_id.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Model Catalog" button for Bedrock foundation models
+                            if resource.resource_type == "AWS::Bedrock::Model"
+                                && self.hint_button(ui.small_button("Model Catalog").on_hover_text("Models in this region with throughput options, quotas, and agent usability"), &resource_node_id, "Model Catalog") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenBedrockModelCatalog {
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Findings" button for GuardDuty detectors and Security Hub hubs
+                            if matches!(
+                                resource.resource_type.as_str(),
+                                "AWS::GuardDuty::Detector" | "AWS::SecurityHub::Hub"
+                            ) && self.hint_button(ui.small_button("Findings").on_hover_text("Show active findings in this account and region"), &resource_node_id, "Findings") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenThreatFindings {
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                            resource_id: None,
+                                        },
+                                    );
+                                }
+
+                            // Add "Records" button for Route53 hosted zones
+                            if resource.resource_type == "AWS::Route53::HostedZone"
+                                && self.hint_button(ui.small_button("Records").on_hover_text("Search the zone's record sets and health checks"), &resource_node_id, "Records") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenDnsRecords {
+                                            hosted_zone_id: resource.resource_id.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Executions" button for Step Functions state machines
+                            if resource.resource_type == "AWS::StepFunctions::StateMachine"
+                                && self.hint_button(ui.small_button("Executions"), &resource_node_id, "Executions") {
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenStepFunctionsExecutions {
+                                            state_machine_arn: resource.resource_id.clone(),
+                                            state_machine_name: resource.display_name.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                        },
+                                    );
+                                }
+
+                            // Add "Query" button for Athena workgroups
+                            if resource.resource_type == "AWS::Athena::WorkGroup"
+                                && self.hint_button(ui.small_button("Query"), &resource_node_id, "Query") {
+                                    // Queue action to open Athena query editor
+                                    self.pending_explorer_actions.push(
+                                        super::ResourceExplorerAction::OpenAthenaQuery {
+                                            workgroup: resource.resource_id.clone(),
+                                            account_id: resource.account_id.clone(),
+                                            region: resource.region.clone(),
+                                            output_location: resource
+                                                .properties
+                                                .pointer("/Configuration/ResultConfiguration/OutputLocation")
+                                                .and_then(|v| v.as_str())
+                                                .map(|s| s.to_string()),
+                                        },
+                                    );
+                                }
+
                             // Add "View Events" button for CloudTrail (all resources supported)
                             if has_cloudtrail_support(&resource.resource_type)
-                                && ui.small_button("View Events").clicked() {
+                                && self.hint_button(ui.small_button("View Events"), &resource_node_id, "View Events") {
                                     // Extract ARN from properties if available (Lambda, EC2, etc.)
                                     // Otherwise build it from resource metadata
                                     let resource_arn = extract_or_build_arn(
@@ -2163,10 +2959,12 @@ impl TreeRenderer {
             "AWS::ECS::Service" => "ECS-SERVICE",
             "AWS::ECS::Task" => "ECS-TASK",
             "AWS::ECS::TaskDefinition" => "TASK-DEF",
+            "AWS::ECS::ContainerDefinition" => "CONTAINER",
             "AWS::ECS::FargateService" => "FARGATE-SVC",
             "AWS::ECS::FargateTask" => "FARGATE-TASK",
             "AWS::EKS::Cluster" => "EKS-CLUSTER",
             "AWS::EKS::FargateProfile" => "FARGATE-PROF",
+            "AWS::EKS::Nodegroup" => "NODEGROUP",
             "AWS::ECR::Repository" => "ECR-REPO",
 
             // Serverless
@@ -2194,6 +2992,8 @@ impl TreeRenderer {
 
             // Networking
             "AWS::Route53::HostedZone" => "ROUTE53",
+            "AWS::Route53::RecordSet" => "DNS-RECORD",
+            "AWS::Route53::HealthCheck" => "HEALTH-CHECK",
             "AWS::ElasticLoadBalancing::LoadBalancer" => "ELB-CLASSIC",
             "AWS::ElasticLoadBalancingV2::LoadBalancer" => "ALB",
             "AWS::ElasticLoadBalancingV2::TargetGroup" => "TARGET-GROUP",
@@ -2420,7 +3220,101 @@ impl TreeRenderer {
             "ca-central-1" => "Canada (Central)",
             "af-south-1" => "Africa (Cape Town)",
             "me-south-1" => "Middle East (Bahrain)",
-            _ => "Unknown Region",
+            _ => crate::app::aws_regions::partition_region_name(region_code)
+                .unwrap_or("Unknown Region"),
+        }
+    }
+
+    /// Render a badge for a resource's Compute Optimizer and Trusted Advisor findings
+    fn render_recommendation_badge(&self, ui: &mut Ui, resource: &super::state::ResourceEntry) {
+        let Some(index) = &self.recommendation_index else {
+            return;
+        };
+        let recommendations = index.for_resource(resource);
+        let Some(label) = super::recommendation_badges::badge_label(&recommendations) else {
+            return;
+        };
+
+        let color = if recommendations[0].finding.severity() >= 2 {
+            ui.visuals().error_fg_color
+        } else {
+            ui.visuals().warn_fg_color
+        };
+        let details: Vec<String> = recommendations
+            .iter()
+            .map(|recommendation| {
+                let savings = recommendation
+                    .estimated_monthly_savings
+                    .map(|amount| {
+                        format!(
+                            " (~{:.2} {}/month)",
+                            amount,
+                            recommendation.currency.as_deref().unwrap_or("USD")
+                        )
+                    })
+                    .unwrap_or_default();
+                format!(
+                    "{}: {}{}",
+                    recommendation.source.label(),
+                    recommendation.badge_text(),
+                    savings
+                )
+            })
+            .collect();
+
+        ui.add_space(8.0);
+        ui.label(egui::RichText::new(label).small().color(color))
+            .on_hover_text(details.join("\n"));
+    }
+
+    /// Render a severity badge for active GuardDuty and Security Hub findings
+    /// naming the resource; clicking it opens the findings window
+    fn render_finding_badge(&mut self, ui: &mut Ui, resource: &super::state::ResourceEntry) {
+        let store = super::threat_findings::global_findings();
+        let version = store.version();
+        if self
+            .finding_index
+            .as_ref()
+            .map_or(true, |(indexed_version, _)| *indexed_version != version)
+        {
+            self.finding_index = Some((version, store.index()));
+        }
+        let Some((severity, count)) = self
+            .finding_index
+            .as_ref()
+            .and_then(|(_, index)| index.badge_for(resource))
+        else {
+            return;
+        };
+
+        use super::security_insights::Severity;
+        let color = match severity {
+            Severity::Critical => ui.visuals().error_fg_color,
+            Severity::High => Color32::from_rgb(230, 130, 60),
+            Severity::Medium => ui.visuals().warn_fg_color,
+            Severity::Low => ui.visuals().weak_text_color(),
+        };
+        let text = if count == 1 {
+            format!("{} finding", severity.label())
+        } else {
+            format!("{} ({} findings)", severity.label(), count)
+        };
+
+        ui.add_space(8.0);
+        let badge = ui
+            .add(
+                egui::Label::new(egui::RichText::new(text).small().color(color))
+                    .sense(egui::Sense::click()),
+            )
+            .on_hover_text("Active GuardDuty / Security Hub findings - click for details");
+        if badge.clicked() {
+            self.pending_explorer_actions.push(
+                super::ResourceExplorerAction::OpenThreatFindings {
+                    account_id: resource.account_id.clone(),
+                    region: resource.region.clone(),
+                    resource_id: Some(resource.resource_id.clone()),
+                },
+            );
         }
     }
 
@@ -2489,8 +3383,13 @@ impl TreeRenderer {
                     ui.painter().galley(text_pos, text_galley, text_color);
                 }
 
-                // Handle click (check before hover to avoid borrow issues)
-                let was_clicked = response.clicked();
+                // Handle click or hint (check before hover to avoid borrow issues)
+                let was_clicked = self.hints.clicked(
+                    &response,
+                    format!("tag:{}:{}={}", resource.selection_key(), badge.key, badge.value),
+                    crate::app::dashui::NavigableElementType::Link,
+                    &badge_text,
+                );
 
                 // Show enhanced tooltip with tag information and count
                 let tag_count = tag_popularity.get_count(&badge);
@@ -2552,6 +3451,102 @@ mod tests {
             ConsoleRoleMenuStatus::Loaded(_)
         ));
     }
+
+    fn resource(resource_type: &str, id: &str, parent: Option<(&str, &str)>) -> ResourceEntry {
+        ResourceEntry {
+            resource_type: resource_type.to_string(),
+            account_id: "123456789012".to_string(),
+            region: "us-east-1".to_string(),
+            resource_id: id.to_string(),
+            display_name: id.to_string(),
+            status: None,
+            properties: serde_json::Value::Null,
+            detailed_timestamp: None,
+            tags: Vec::new(),
+            relationships: Vec::new(),
+            parent_resource_id: parent.map(|(parent_id, _)| parent_id.to_string()),
+            parent_resource_type: parent.map(|(_, parent_type)| parent_type.to_string()),
+            is_child_resource: parent.is_some(),
+            account_color: Color32::WHITE,
+            region_color: Color32::WHITE,
+            query_timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_global_resources_group_once_under_global_region() {
+        let mut role_copy = resource("AWS::IAM::Role", "admin", None);
+        role_copy.region = "eu-west-1".to_string();
+        let resources = vec![
+            resource("AWS::IAM::Role", "admin", None),
+            role_copy,
+            resource("AWS::EC2::Instance", "i-1", None),
+        ];
+
+        let tree = TreeBuilder::build_tree(&resources, GroupingMode::ByRegion, "");
+        assert_eq!(tree.children.len(), 2);
+        assert_eq!(tree.children[0].display_name, "Global (1)");
+        assert_eq!(tree.children[0].children[0].resource_indices.len(), 1);
+        assert!(tree.children[1].display_name.contains("us-east-1"));
+    }
+
+    #[test]
+    fn test_child_resources_attach_under_their_parent() {
+        let kb = "AWS::Bedrock::KnowledgeBase";
+        let ds = "AWS::Bedrock::DataSource";
+        let resources = vec![
+            resource(ds, "ds-b", Some(("kb-1", kb))),
+            resource(kb, "kb-1", None),
+            resource(kb, "kb-2", None),
+            resource(ds, "ds-a", Some(("kb-1", kb))),
+        ];
+
+        let tree = TreeBuilder::build_tree(&resources, GroupingMode::ByAccount, "");
+        let type_node = &tree.children[0].children[0];
+        assert_eq!(type_node.resource_indices, vec![1, 2]);
+        assert_eq!(type_node.children.len(), 1);
+        // Children are sorted by name and only attached to their own parent
+        assert_eq!(type_node.children[0].resource_indices, vec![3, 0]);
+        assert!(type_node.children[0].id.contains("kb-1"));
+    }
+
+    #[test]
+    fn test_memoized_filter_reuses_result_until_inputs_change() {
+        let mut renderer = TreeRenderer::new();
+        let resources = vec![
+            resource("AWS::S3::Bucket", "a", None),
+            resource("AWS::S3::Bucket", "b", None),
+        ];
+        let calls = std::cell::Cell::new(0);
+        let filter = |r: &ResourceEntry| {
+            calls.set(calls.get() + 1);
+            r.resource_id == "a"
+        };
+
+        let first = renderer.memoized_filter(&resources, 1, filter);
+        let second = renderer.memoized_filter(&resources, 1, filter);
+        assert_eq!(first.len(), 1);
+        assert!(std::sync::Arc::ptr_eq(&first, &second));
+        assert_eq!(calls.get(), 2);
+
+        renderer.memoized_filter(&resources, 2, filter);
+        assert_eq!(calls.get(), 4);
+    }
+
+    #[test]
+    fn test_memoized_filter_sees_in_place_replacements() {
+        let mut renderer = TreeRenderer::new();
+        let mut resources: Vec<ResourceEntry> = (0..200)
+            .map(|i| resource("AWS::S3::Bucket", &format!("bucket-{}", i), None))
+            .collect();
+        let filter = |r: &ResourceEntry| r.resource_id.ends_with('7');
+
+        let before = renderer.memoized_filter(&resources, 1, filter);
+        // Same buffer and length, different resource in the middle
+        resources[101] = resource("AWS::S3::Bucket", "bucket-new-7", None);
+        let after = renderer.memoized_filter(&resources, 1, filter);
+        assert_eq!(after.len(), before.len() + 1);
+    }
 }
 
 /// Extract ARN from resource properties or build it from metadata
@@ -2601,39 +3596,41 @@ fn extract_or_build_arn(
         resource_type
     );
 
+    let partition = crate::app::aws_regions::arn_partition(region);
+
     match resource_type {
         // Lambda functions
         "AWS::Lambda::Function" => {
             format!(
-                "arn:aws:lambda:{}:{}:function:{}",
-                region, account_id, resource_id
+                "arn:{}:lambda:{}:{}:function:{}",
+                partition, region, account_id, resource_id
             )
         }
         // EC2 Instances
         "AWS::EC2::Instance" => {
             format!(
-                "arn:aws:ec2:{}:{}:instance/{}",
-                region, account_id, resource_id
+                "arn:{}:ec2:{}:{}:instance/{}",
+                partition, region, account_id, resource_id
             )
         }
         // S3 Buckets (buckets are global, no region in ARN)
         "AWS::S3::Bucket" => {
-            format!("arn:aws:s3:::{}", resource_id)
+            format!("arn:{}:s3:::{}", partition, resource_id)
         }
         // DynamoDB Tables
         "AWS::DynamoDB::Table" => {
             format!(
-                "arn:aws:dynamodb:{}:{}:table/{}",
-                region, account_id, resource_id
+                "arn:{}:dynamodb:{}:{}:table/{}",
+                partition, region, account_id, resource_id
             )
         }
         // IAM Roles (IAM is global, no region)
         "AWS::IAM::Role" => {
-            format!("arn:aws:iam::{}:role/{}", account_id, resource_id)
+            format!("arn:{}:iam::{}:role/{}", partition, account_id, resource_id)
         }
         // IAM Users (IAM is global, no region)
         "AWS::IAM::User" => {
-            format!("arn:aws:iam::{}:user/{}", account_id, resource_id)
+            format!("arn:{}:iam::{}:user/{}", partition, account_id, resource_id)
         }
         // Default fallback - just return the resource_id
         _ => resource_id.to_string(),
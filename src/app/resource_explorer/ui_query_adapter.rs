@@ -136,7 +136,7 @@ impl UIQueryAdapter {
                         &ctx_for_callback,
                         10,
                         move |state| {
-                            state.mark_phase1_query_failed(&query_key);
+                            state.mark_phase1_query_failed(&query_key, &error);
                         },
                     );
                 }
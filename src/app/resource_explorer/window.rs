@@ -1,5 +1,6 @@
 use super::{
-    aws_client::*, bookmarks::*, dialogs::*, instances::pane_renderer::PaneAction,
+    adaptive_concurrency::{adaptive_limiter, max_service_concurrency},
+    aws_client::*, bookmarks::*, dialogs::*, failure_report::QueryFailure, instances::pane_renderer::PaneAction,
     instances::pane_renderer::PaneRenderer, retry_tracker::retry_tracker,
     sdk_errors::ErrorCategory, state::*, status::global_status, tree::*, widgets::*,
 };
@@ -67,6 +68,7 @@ pub struct ResourceExplorerWindow {
     refresh_selection: HashMap<String, bool>, // Track which combinations to refresh (display name -> selected)
     refresh_display_to_cache: HashMap<String, String>, // Map display name to cache key
     show_refresh_dialog: bool,                // Local dialog state to avoid borrow conflicts
+    export_dialog: super::export::ExportDialog,
     show_filter_builder: bool,                // Local filter builder dialog state
     filter_builder_working_group: Option<TagFilterGroup>, // In-progress filter group (persists while dialog is open)
     show_hierarchy_builder: bool,                         // Local hierarchy builder dialog state
@@ -135,6 +137,7 @@ impl ResourceExplorerWindow {
             refresh_selection: HashMap::new(),
             refresh_display_to_cache: HashMap::new(),
             show_refresh_dialog: false,
+            export_dialog: super::export::ExportDialog::new(),
             show_filter_builder: false,
             filter_builder_working_group: None,
             show_hierarchy_builder: false,
@@ -252,7 +255,7 @@ impl ResourceExplorerWindow {
 
             // Reset loading state
             state.loading_tasks.clear();
-            state.phase1_failed_queries.clear();
+            state.clear_phase1_failures();
             state.phase1_tag_fetching = false;
             state.phase2_enrichment_in_progress = false;
             state.phase2_enrichment_completed = false;
@@ -516,8 +519,15 @@ impl ResourceExplorerWindow {
                                                         }
                                                     );
                                                 }
+                                            } else if let Some(failure) = state
+                                                .phase1_query_errors
+                                                .get(query_key)
+                                                .and_then(|error| QueryFailure::from_query_key(query_key, error))
+                                            {
+                                                // retry_tracker doesn't have this query; categorize the raw error
+                                                self.last_failed_queries.insert(query_key.clone(), failure.category);
                                             } else {
-                                                // Fallback: retry_tracker doesn't have this query
+                                                // Fallback: no error recorded for this query
                                                 self.last_failed_queries.insert(
                                                     query_key.clone(),
                                                     ErrorCategory::NonRetryable {
@@ -678,6 +688,30 @@ impl ResourceExplorerWindow {
                                     );
                                 }
 
+                                // Per-service throttle indicators from the adaptive limiter
+                                for throttle in adaptive_limiter().throttled_services() {
+                                    let indicator = match throttle.backoff_remaining {
+                                        Some(wait) => format!(
+                                            "[{} backoff {:.1}s]",
+                                            throttle.service,
+                                            wait.as_secs_f32()
+                                        ),
+                                        None => format!(
+                                            "[{} {}/{}]",
+                                            throttle.service, throttle.limit, max_service_concurrency()
+                                        ),
+                                    };
+                                    ui.label(
+                                        egui::RichText::new(indicator)
+                                            .color(Color32::from_rgb(255, 150, 50))
+                                            .small(),
+                                    )
+                                    .on_hover_text(format!(
+                                        "{} is throttling requests: running {} of {} parallel calls",
+                                        throttle.service, throttle.limit, max_service_concurrency()
+                                    ));
+                                }
+
                                 ui.ctx()
                                     .request_repaint_after(std::time::Duration::from_millis(100));
                             }
@@ -1308,6 +1342,14 @@ impl ResourceExplorerWindow {
             self.render_refresh_dialog_standalone(ctx);
         }
 
+        if let Ok(mut state) = self.state.try_write() {
+            if std::mem::take(&mut state.show_export_dialog) {
+                self.export_dialog
+                    .open_for(super::export::visible_resources(&state));
+            }
+        }
+        self.export_dialog.show(ctx, "resource_explorer_window");
+
         if self.show_filter_builder {
             self.render_filter_builder_dialog(ctx);
         }
@@ -1430,15 +1472,33 @@ impl ResourceExplorerWindow {
                         )
                         .clicked();
 
-                    (cancel_clicked, refresh_clicked)
+                    let delta_clicked = ui
+                        .add_enabled(
+                            !combinations.is_empty(),
+                            egui::Button::new("Refresh Changed Only"),
+                        )
+                        .on_hover_text(
+                            "Re-fetch only resource types with CloudTrail write events since they were cached",
+                        )
+                        .clicked();
+
+                    (cancel_clicked, refresh_clicked, delta_clicked)
                 });
 
                 buttons_response.inner
             });
 
         if let Some(inner_response) = response {
-            if let Some((cancel_clicked, refresh_clicked)) = inner_response.inner {
-                if cancel_clicked {
+            if let Some((cancel_clicked, refresh_clicked, delta_clicked)) = inner_response.inner {
+                if delta_clicked {
+                    self.trigger_delta_refresh(ctx);
+                    self.show_refresh_dialog = false;
+                    self.refresh_selection.clear();
+                    self.refresh_display_to_cache.clear();
+                    if let Ok(mut state) = self.state.try_write() {
+                        state.show_refresh_dialog = false;
+                    }
+                } else if cancel_clicked {
                     self.show_refresh_dialog = false;
                     self.refresh_selection.clear();
                     self.refresh_display_to_cache.clear();
@@ -1903,6 +1963,14 @@ impl ResourceExplorerWindow {
                     state.show_refresh_dialog = true;
                 }
 
+                if ui
+                    .add_enabled(!state.resources.is_empty(), egui::Button::new("Export"))
+                    .on_hover_text("Export the visible tree to CSV, JSON, or Excel")
+                    .clicked()
+                {
+                    state.show_export_dialog = true;
+                }
+
                 if ui
                     .button("Reset")
                     .on_hover_text("Reset all selections to default state")
@@ -2380,7 +2448,7 @@ impl ResourceExplorerWindow {
                                 self.last_failed_queries_snapshotted = false; // Reset snapshot flag
                                 // Also clear state's failed queries to prevent re-snapshot
                                 if let Ok(mut state) = self.state.try_write() {
-                                    state.phase1_failed_queries.clear();
+                                    state.clear_phase1_failures();
                                 }
                             }
                         });
@@ -3488,34 +3556,14 @@ impl ResourceExplorerWindow {
         // Build list of query keys to track for Phase 1 progress
         // Each key is "account_id:region:resource_type" to match actual query granularity
         // This prevents race conditions where one region completing marks the entire service as done
-        let global_registry = super::GlobalServiceRegistry::new();
-        let mut queries_to_track: Vec<String> = Vec::new();
-
-        for account in &scope.accounts {
-            for resource_type in &scope.resource_types {
-                if global_registry.is_global(&resource_type.resource_type) {
-                    // Global services: one query per account with region "Global"
-                    let query_key = ResourceExplorerState::make_query_key(
-                        &account.account_id,
-                        "Global",
-                        &resource_type.resource_type,
-                    );
-                    if !queries_to_track.contains(&query_key) {
-                        queries_to_track.push(query_key);
-                    }
-                } else {
-                    // Regional services: one query per account × region
-                    for region in &scope.regions {
-                        let query_key = ResourceExplorerState::make_query_key(
-                            &account.account_id,
-                            &region.region_code,
-                            &resource_type.resource_type,
-                        );
-                        queries_to_track.push(query_key);
-                    }
-                }
-            }
-        }
+        // Global services get one query per account with region "Global"
+        let queries_to_track: Vec<String> = super::global_services::global_registry()
+            .query_plan(&scope)
+            .iter()
+            .map(|(account_id, region, resource_type)| {
+                ResourceExplorerState::make_query_key(account_id, region, resource_type)
+            })
+            .collect();
 
         // Initialize Phase 1 tracking - this is critical for progress tracking
         // Try a few times on the main thread, but the thread will retry more aggressively
@@ -3659,7 +3707,7 @@ impl ResourceExplorerWindow {
                                     let mut success = false;
                                     for attempt in 0..max_retries {
                                         if let Ok(mut state) = state_arc_clone.try_write() {
-                                            state.mark_phase1_query_failed(&query_key);
+                                            state.mark_phase1_query_failed(&query_key, &e.to_string());
                                             success = true;
                                             if attempt > 0 {
                                                 tracing::debug!(
@@ -3820,6 +3868,79 @@ impl ResourceExplorerWindow {
     }
 
     /// Trigger selective refresh for selected combinations
+    /// Refresh only the cached queries whose resources changed since they were fetched
+    ///
+    /// Uses CloudTrail write events to decide which cache keys to drop, then
+    /// runs the normal parallel query, which re-fetches the dropped keys and
+    /// serves everything else from cache.
+    fn trigger_delta_refresh(&self, ctx: &Context) {
+        let scope = match self.state.try_read() {
+            Ok(state) if !state.query_scope.is_empty() => state.query_scope.clone(),
+            _ => return,
+        };
+
+        let aws_client = match &self.aws_client {
+            Some(client) => client.clone(),
+            None => {
+                warn!("AWS client not available for refresh - AWS Identity Center may not be configured");
+                return;
+            }
+        };
+
+        let state_arc = self.state.clone();
+        let cache = super::cache::shared_cache();
+
+        let cache_key = if let Ok(mut loading_state) = self.state.try_write() {
+            loading_state.start_loading_task("delta_refresh")
+        } else {
+            format!("delta_refresh_{}", chrono::Utc::now().timestamp_millis())
+        };
+
+        ctx.request_repaint_after(std::time::Duration::from_millis(50));
+
+        std::thread::spawn(move || {
+            let runtime = match tokio::runtime::Runtime::new() {
+                Ok(rt) => rt,
+                Err(e) => {
+                    tracing::error!("Failed to create Tokio runtime: {}", e);
+                    if let Ok(mut state) = state_arc.try_write() {
+                        state.loading_tasks.remove(&cache_key);
+                    }
+                    return;
+                }
+            };
+
+            super::status::report_status("Explorer", "delta_refresh", Some("Checking CloudTrail for changes"));
+            let plan = runtime.block_on(aws_client.invalidate_changed_resources(&scope, &cache));
+            super::status::report_status_done(
+                "Explorer",
+                "delta_refresh",
+                Some(&format!(
+                    "{} of {} cached queries changed",
+                    plan.stale_keys.len(),
+                    plan.total()
+                )),
+            );
+
+            // Drop stale keys from the state mirror too, with the same retry
+            // budget as the other background updates
+            for attempt in 0..30 {
+                if let Ok(mut state) = state_arc.try_write() {
+                    for key in &plan.stale_keys {
+                        state.cached_queries.remove(key);
+                    }
+                    break;
+                } else if attempt < 29 {
+                    std::thread::sleep(std::time::Duration::from_millis(100));
+                } else {
+                    tracing::warn!("Failed to update cached queries after delta refresh");
+                }
+            }
+
+            Self::spawn_parallel_query(state_arc, scope, cache, aws_client, cache_key);
+        });
+    }
+
     fn trigger_selective_refresh(&self, ctx: &Context) {
         let selected_combinations: Vec<String> = self
             .refresh_selection
@@ -23,23 +23,47 @@
 //! - Token passed to webview via environment variable
 //! - Each request validated with X-API-Token header
 //! - Stops random programs from calling API
+//! - Page windows get their own tokens, scoped to the capabilities the page
+//!   declares (see [`page_capabilities`](super::page_capabilities)); the main
+//!   token is only given to the Pages Manager
 //! - Future: Developer mode toggle for open API
+//!
+//! # Live Updates
+//!
+//! `GET /api/events?topics=...&token=...` streams events published with
+//! [`push::publish`](super::push::publish) as Server-Sent Events. The token is
+//! accepted as a query parameter because `EventSource` cannot set headers.
+//!
+//! # Window Heartbeats
+//!
+//! Webview processes post their window ID to `POST /api/webview/heartbeat` and
+//! get back the next queued focus/reload/close command (see
+//! [`window_registry`](super::window_registry)). The unguessable window ID
+//! authorizes the request, so AWS Console windows need no API token.
 
 #![warn(clippy::all, rust_2018_idioms)]
 
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
-    response::{IntoResponse, Response},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
     routing::{get, post},
     Json, Router,
 };
 use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
 use std::sync::Arc;
 use tokio::sync::RwLock;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::{info, warn};
 
+use super::page_capabilities::{self, PageCapability, PageGrant};
+use super::push::{self, PushMessage};
+use super::window_registry;
+
 /// API server configuration and state
 pub struct ApiServer {
     /// Random token for authentication
@@ -65,6 +89,15 @@ struct ApiRequest {
     payload: serde_json::Value,
 }
 
+/// Query parameters for the live event stream
+#[derive(Debug, Deserialize)]
+struct EventsQuery {
+    /// Comma-separated topic filters (default: all topics)
+    topics: Option<String>,
+    /// API token (EventSource cannot send the X-API-Token header)
+    token: Option<String>,
+}
+
 /// Generic API response
 #[derive(Debug, Serialize)]
 struct ApiResponse {
@@ -100,6 +133,10 @@ impl ApiServer {
         // Build router with all endpoints
         let app = Router::new()
             .route("/api/command", post(handle_api_request))
+            // Live updates pushed to pages as Server-Sent Events
+            .route("/api/events", get(handle_events))
+            // Liveness and control channel of webview windows
+            .route("/api/webview/heartbeat", post(handle_heartbeat))
             // VFS file serving endpoint for webview subprocess to fetch VFS files
             // Pattern: /vfs/{vfs_id}/pages/{page_id}/{file_path}
             .route("/vfs/:vfs_id/pages/:page_id/*file_path", get(handle_vfs_file))
@@ -158,20 +195,71 @@ impl ApiServer {
 }
 
 /// Generate a random API token (32 bytes = 64 hex characters)
-fn generate_api_token() -> String {
+pub(super) fn generate_api_token() -> String {
     use rand::Rng;
     let mut rng = rand::thread_rng();
     let bytes: Vec<u8> = (0..32).map(|_| rng.gen()).collect();
     hex::encode(bytes)
 }
 
-/// Validate API token from request headers
-fn validate_token(headers: &HeaderMap, expected_token: &str) -> bool {
-    headers
-        .get("X-API-Token")
-        .and_then(|v| v.to_str().ok())
-        .map(|token| token == expected_token)
-        .unwrap_or(false)
+/// Who a valid API token belongs to
+enum Caller {
+    /// The main token: every command is allowed
+    Manager,
+    /// A page window's scoped token
+    Page(PageGrant),
+}
+
+impl Caller {
+    /// Check whether the caller may run `cmd`
+    fn allows(&self, cmd: &str) -> bool {
+        match self {
+            Caller::Manager => true,
+            Caller::Page(grant) => grant.allows(cmd),
+        }
+    }
+}
+
+/// Read the API token from request headers
+fn header_token(headers: &HeaderMap) -> Option<&str> {
+    headers.get("X-API-Token").and_then(|v| v.to_str().ok())
+}
+
+/// Identify the caller of an API token (None if the token is invalid or revoked)
+fn authenticate(state: &AppState, token: Option<&str>) -> Option<Caller> {
+    let token = token?;
+    if token == state.api_token {
+        Some(Caller::Manager)
+    } else {
+        page_capabilities::grant_for_token(token).map(Caller::Page)
+    }
+}
+
+/// Response for a command the caller's token does not grant
+fn capability_denied(caller: &Caller, cmd: &str) -> Response {
+    let page_name = match caller {
+        Caller::Page(grant) => grant.page_name.as_str(),
+        Caller::Manager => "",
+    };
+    let error = match page_capabilities::command_scope(cmd) {
+        page_capabilities::CommandScope::Requires(capability) => format!(
+            "Page '{}' does not have the '{}' capability required by {}",
+            page_name,
+            capability.id(),
+            cmd
+        ),
+        _ => format!("{} is not available to pages", cmd),
+    };
+    warn!("⚠️ Capability denied: {}", error);
+
+    (
+        StatusCode::FORBIDDEN,
+        Json(ApiResponse {
+            success: false,
+            data: serde_json::json!({ "error": error }),
+        }),
+    )
+        .into_response()
 }
 
 /// Handle API request from webview
@@ -190,9 +278,26 @@ async fn handle_api_request(
     }
 
     // Validate API token
-    if !validate_token(&headers, &state.api_token) {
+    let Some(caller) = authenticate(&state, header_token(&headers)) else {
         warn!("⚠️ Unauthorized API request: invalid token");
         return (StatusCode::FORBIDDEN, "Invalid API token").into_response();
+    };
+
+    if !caller.allows(&request.cmd) {
+        return capability_denied(&caller, &request.cmd);
+    }
+    if let Caller::Page(grant) = &caller {
+        if let Some(error) = grant.payload_error(&request.cmd, &request.payload) {
+            warn!("⚠️ Capability denied: {}", error);
+            return (
+                StatusCode::FORBIDDEN,
+                Json(ApiResponse {
+                    success: false,
+                    data: serde_json::json!({ "error": error }),
+                }),
+            )
+                .into_response();
+        }
     }
 
     info!("📨 API command: {}", request.cmd);
@@ -220,6 +325,76 @@ async fn handle_api_request(
     }
 }
 
+/// Stream pushed events to a page
+///
+/// Each event is sent as an SSE message whose data is the JSON `PushEvent`.
+/// A `lagged` event tells a slow page how many events it missed.
+async fn handle_events(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<EventsQuery>,
+) -> Response {
+    let token = header_token(&headers).or(query.token.as_deref());
+    let Some(caller) = authenticate(&state, token) else {
+        warn!("⚠️ Unauthorized event subscription: invalid token");
+        return (StatusCode::FORBIDDEN, "Invalid API token").into_response();
+    };
+
+    if let Caller::Page(grant) = &caller {
+        if !grant.has(PageCapability::EventsSubscribe) {
+            warn!("⚠️ Event subscription denied for page: {}", grant.page_name);
+            return (
+                StatusCode::FORBIDDEN,
+                "Page lacks the events:subscribe capability",
+            )
+                .into_response();
+        }
+    }
+
+    let filters = query
+        .topics
+        .as_deref()
+        .map(push::parse_topics)
+        .filter(|topics| !topics.is_empty())
+        .unwrap_or_else(|| vec!["*".to_string()]);
+
+    let subscription = push::subscribe(filters);
+    info!(
+        "📡 Event subscription: {}",
+        subscription.filters().join(",")
+    );
+
+    let stream = futures::stream::unfold(subscription, |mut subscription| async move {
+        let event = match subscription.recv().await? {
+            PushMessage::Event(event) => Event::default()
+                .json_data(&event)
+                .unwrap_or_else(|_| Event::default().comment("unserializable event")),
+            PushMessage::Lagged(skipped) => {
+                Event::default().event("lagged").data(skipped.to_string())
+            }
+        };
+        Some((Ok::<_, Infallible>(event), subscription))
+    });
+
+    Sse::new(stream)
+        .keep_alive(KeepAlive::default())
+        .into_response()
+}
+
+/// Heartbeat request body
+#[derive(Debug, Deserialize)]
+struct HeartbeatRequest {
+    id: String,
+}
+
+/// Record a webview window heartbeat and reply with its next command
+async fn handle_heartbeat(Json(request): Json<HeartbeatRequest>) -> Response {
+    match window_registry::heartbeat(&request.id) {
+        Ok(command) => Json(serde_json::json!({ "command": command })).into_response(),
+        Err(e) => (StatusCode::NOT_FOUND, e.to_string()).into_response(),
+    }
+}
+
 /// Execute command using main process's AWS client and cache
 async fn execute_command(
     cmd: &str,
@@ -227,7 +402,8 @@ async fn execute_command(
 ) -> anyhow::Result<serde_json::Value> {
     use crate::app::webview::commands::*;
     use crate::app::agent_framework::v8_bindings::bindings::{
-        resources, cloudwatch_logs, cloudtrail_events,
+        resources, cloudwatch_logs, cloudwatch_metrics, cloudtrail_events, config_history, athena,
+        cost_explorer,
     };
 
     match cmd {
@@ -305,6 +481,21 @@ async fn execute_command(
             query_cached_resources(args).await
         }
 
+        "queryCachedResourcesPaged" => {
+            let args: QueryCachedResourcesPagedArgs = serde_json::from_value(payload)?;
+            query_cached_resources_paged(args).await
+        }
+
+        "fetchResourcePage" => {
+            let args: ResourceCursorArgs = serde_json::from_value(payload)?;
+            fetch_resource_page(args).await
+        }
+
+        "closeResourceCursor" => {
+            let args: ResourceCursorArgs = serde_json::from_value(payload)?;
+            close_resource_cursor(args).await
+        }
+
         "listBookmarks" => {
             list_bookmarks().await
         }
@@ -319,11 +510,36 @@ async fn execute_command(
             query_cloudwatch_log_events(args).await
         }
 
+        "tailLogEvents" => {
+            let args: TailLogEventsArgs = serde_json::from_value(payload)?;
+            tail_log_events(args).await
+        }
+
+        "getCloudWatchMetrics" => {
+            let args: cloudwatch_metrics::GetCloudWatchMetricsArgs = serde_json::from_value(payload)?;
+            get_cloudwatch_metrics(args).await
+        }
+
         "getCloudTrailEvents" => {
             let args: cloudtrail_events::GetCloudTrailEventsArgs = serde_json::from_value(payload)?;
             get_cloudtrail_events(args).await
         }
 
+        "getConfigHistory" => {
+            let args: config_history::GetConfigHistoryArgs = serde_json::from_value(payload)?;
+            get_config_history(args).await
+        }
+
+        "runAthenaQuery" => {
+            let args: athena::RunAthenaQueryArgs = serde_json::from_value(payload)?;
+            run_athena_query(args).await
+        }
+
+        "getCostData" => {
+            let args: cost_explorer::GetCostDataArgs = serde_json::from_value(payload)?;
+            get_cost_data(args).await
+        }
+
         // ========== Page Management Commands ==========
 
         "listPages" => {
@@ -350,6 +566,59 @@ async fn execute_command(
             rename_page(args).await
         }
 
+        "listPageTemplates" => {
+            list_page_templates().await
+        }
+
+        "createPageFromTemplate" => {
+            let args: CreatePageFromTemplateArgs = serde_json::from_value(payload)?;
+            create_page_from_template(args).await
+        }
+
+        "searchPages" => {
+            let args: crate::app::webview::page_search::PageSearchQuery = serde_json::from_value(payload)?;
+            search_pages(args).await
+        }
+
+        "previewReplaceInPages" => {
+            let args: PreviewReplaceArgs = serde_json::from_value(payload)?;
+            preview_replace_in_pages(args).await
+        }
+
+        "replaceInPages" => {
+            let args: ReplaceInPagesArgs = serde_json::from_value(payload)?;
+            replace_in_pages(args).await
+        }
+
+        "exportPage" => {
+            let args: crate::app::webview::page_export::PageExportRequest = serde_json::from_value(payload)?;
+            export_page(args).await
+        }
+
+        "getPageCapabilities" => {
+            let args: PageCapabilityArgs = serde_json::from_value(payload)?;
+            get_page_capabilities(args).await
+        }
+
+        "setPageCapabilityRevoked" => {
+            let args: SetPageCapabilityRevokedArgs = serde_json::from_value(payload)?;
+            set_page_capability_revoked(args).await
+        }
+
+        "revokePageTokens" => {
+            let args: PageCapabilityArgs = serde_json::from_value(payload)?;
+            revoke_page_tokens(args).await
+        }
+
+        "listVfsSnapshots" => {
+            list_vfs_snapshots().await
+        }
+
+        "reopenVfsSnapshot" => {
+            let args: ReopenVfsSnapshotArgs = serde_json::from_value(payload)?;
+            reopen_vfs_snapshot(args).await
+        }
+
         _ => Err(anyhow::anyhow!("Unknown command: {}", cmd)),
     }
 }
@@ -366,8 +635,8 @@ async fn handle_vfs_file(
     headers: HeaderMap,
     Path((vfs_id, page_id, file_path)): Path<(String, String, String)>,
 ) -> Response {
-    // Validate API token
-    if !validate_token(&headers, &state.api_token) {
+    // Validate API token (page windows fetch their own assets here)
+    if authenticate(&state, header_token(&headers)).is_none() {
         warn!("⚠️ Unauthorized VFS request: invalid token");
         return (StatusCode::FORBIDDEN, "Invalid API token").into_response();
     }
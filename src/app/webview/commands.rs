@@ -1,9 +1,11 @@
 //! HTTP API command implementations for web applications
 //!
 //! The webview API exposes direct access to V8-bound functions for AWS operations.
-//! Functions include: listAccounts, listRegions, loadCache, queryCachedResources,
-//! getResourceSchema, showInExplorer, listBookmarks, queryBookmarks,
-//! queryCloudWatchLogEvents, getCloudTrailEvents, and page management commands.
+//! Functions include: listAccounts, listRegions, loadCache, queryCachedResources
+//! (whole or cursor-paginated), getResourceSchema, showInExplorer, listBookmarks,
+//! queryBookmarks, queryCloudWatchLogEvents, getCloudTrailEvents, page management commands,
+//! page templates, workspace-wide find/replace across pages, standalone page
+//! export, page capability review, and reopening saved agent VFS workspaces.
 
 #![warn(clippy::all, rust_2018_idioms)]
 
@@ -11,8 +13,12 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use crate::app::agent_framework::v8_bindings::bindings::{
-    accounts, regions, resources, cloudwatch_logs, cloudtrail_events,
+    accounts, regions, resources, cloudwatch_logs, cloudwatch_metrics, cloudtrail_events,
+    config_history, athena, cost_explorer,
 };
+use crate::app::agent_framework::vfs::{reopen_snapshot, with_vfs, VfsSnapshotStore};
+use crate::app::webview::page_capabilities::{self, PageCapability};
+use crate::app::webview::{page_export, page_search, push, resource_cursor};
 
 /// Input arguments for openPage command
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -89,9 +95,31 @@ pub async fn list_regions() -> Result<serde_json::Value> {
 pub async fn load_cache(args: resources::LoadCacheArgs) -> Result<serde_json::Value> {
     tracing::info!("[WEBVIEW CMD] loadCache(resourceTypes: {:?})", args.resource_types);
 
-    let result = resources::execute_load_cache(args)?;
+    let resource_types = args.resource_types.clone();
+    push::publish(push::QUERY_PROGRESS_TOPIC, serde_json::json!({
+        "phase": "started",
+        "resourceTypes": resource_types
+    }));
+
+    let result = match resources::execute_load_cache(args) {
+        Ok(result) => result,
+        Err(e) => {
+            push::publish(push::QUERY_PROGRESS_TOPIC, serde_json::json!({
+                "phase": "failed",
+                "resourceTypes": resource_types,
+                "error": e.to_string()
+            }));
+            return Err(e);
+        }
+    };
     let json = serde_json::to_value(result)?;
 
+    push::publish(push::QUERY_PROGRESS_TOPIC, serde_json::json!({
+        "phase": "completed",
+        "resourceTypes": resource_types,
+        "totalCount": json.get("totalCount").cloned().unwrap_or_default()
+    }));
+
     tracing::info!("[WEBVIEW CMD] loadCache() -> success");
     Ok(json)
 }
@@ -145,6 +173,84 @@ pub async fn query_cached_resources(args: resources::QueryCachedResourcesArgs) -
     Ok(json)
 }
 
+/// Input arguments for queryCachedResourcesPaged command
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryCachedResourcesPagedArgs {
+    /// Same filters as queryCachedResources
+    #[serde(flatten)]
+    pub query: resources::QueryCachedResourcesArgs,
+    /// Resources per page (default 1000)
+    pub page_size: Option<usize>,
+}
+
+/// Input arguments for fetchResourcePage and closeResourceCursor commands
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceCursorArgs {
+    /// Cursor from a previous page's nextCursor
+    pub cursor: String,
+}
+
+/// Query cached resources and return them one page at a time
+///
+/// Returns the queryCachedResources result with only the first page of
+/// resources, plus offset, totalCount, and nextCursor for fetchResourcePage.
+pub async fn query_cached_resources_paged(
+    args: QueryCachedResourcesPagedArgs,
+) -> Result<serde_json::Value> {
+    tracing::info!(
+        "[WEBVIEW CMD] queryCachedResourcesPaged(resourceTypes: {:?}, pageSize: {:?})",
+        args.query.resource_types,
+        args.page_size
+    );
+
+    let mut result = resources::execute_query_cached_resources(args.query)?;
+    let page = resource_cursor::open_cursor(
+        result.resources.take().unwrap_or_default(),
+        args.page_size.unwrap_or(resource_cursor::DEFAULT_PAGE_SIZE),
+    );
+
+    let mut json = serde_json::to_value(result)?;
+    if let (Some(json), serde_json::Value::Object(page_fields)) =
+        (json.as_object_mut(), serde_json::to_value(&page)?)
+    {
+        json.extend(page_fields);
+    }
+
+    tracing::info!(
+        "[WEBVIEW CMD] queryCachedResourcesPaged() -> first {} of {} resources",
+        page.resources.len(),
+        page.total_count
+    );
+    Ok(json)
+}
+
+/// Return the next page of a paginated resource query
+pub async fn fetch_resource_page(args: ResourceCursorArgs) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] fetchResourcePage(cursor: {})", args.cursor);
+
+    let page = resource_cursor::next_page(&args.cursor)?;
+
+    tracing::info!(
+        "[WEBVIEW CMD] fetchResourcePage() -> {} resources at offset {}",
+        page.resources.len(),
+        page.offset
+    );
+    Ok(serde_json::to_value(page)?)
+}
+
+/// Release a paginated resource query that will not be read to the end
+pub async fn close_resource_cursor(args: ResourceCursorArgs) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] closeResourceCursor(cursor: {})", args.cursor);
+
+    let closed = resource_cursor::close_cursor(&args.cursor);
+    Ok(serde_json::json!({
+        "status": "success",
+        "closed": closed
+    }))
+}
+
 /// List all bookmarks
 ///
 /// Returns a flat list of all saved bookmarks
@@ -214,6 +320,53 @@ pub async fn query_cloudwatch_log_events(
     Ok(json)
 }
 
+/// Input arguments for tailLogEvents command
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TailLogEventsArgs {
+    /// AWS account ID
+    pub account_id: String,
+    /// AWS region
+    pub region: String,
+    /// Log group to follow
+    pub log_group_name: String,
+}
+
+/// Start pushing new log events to subscribers of `logs.{logGroupName}`
+///
+/// The tail stops by itself once no page is subscribed to the topic.
+pub async fn tail_log_events(args: TailLogEventsArgs) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] tailLogEvents(logGroup: {}, account: {}, region: {})",
+        args.log_group_name, args.account_id, args.region);
+
+    let topic = push::log_topic(&args.log_group_name);
+    let started = push::start_log_tail(args.account_id, args.region, args.log_group_name);
+
+    tracing::info!("[WEBVIEW CMD] tailLogEvents() -> {} (already running: {})", topic, !started);
+    Ok(serde_json::json!({
+        "status": "success",
+        "topic": topic,
+        "alreadyRunning": !started
+    }))
+}
+
+/// Get CloudWatch metric time series
+///
+/// Fetches default resource metrics or explicit metric queries
+pub async fn get_cloudwatch_metrics(
+    args: cloudwatch_metrics::GetCloudWatchMetricsArgs
+) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] getCloudWatchMetrics(account: {}, region: {})",
+        args.account_id, args.region);
+
+    let result = cloudwatch_metrics::get_cloudwatch_metrics_internal(args).await?;
+    let json = serde_json::to_value(result)?;
+
+    tracing::info!("[WEBVIEW CMD] getCloudWatchMetrics() -> {} datapoints",
+        json.get("totalDatapoints").and_then(|c| c.as_u64()).unwrap_or(0));
+    Ok(json)
+}
+
 /// Get CloudTrail events
 ///
 /// Queries CloudTrail events for governance and compliance analysis
@@ -231,6 +384,57 @@ pub async fn get_cloudtrail_events(
     Ok(json)
 }
 
+/// Get AWS Config history
+///
+/// Queries a resource's configuration timeline with snapshot diffs
+pub async fn get_config_history(
+    args: config_history::GetConfigHistoryArgs
+) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] getConfigHistory(type: {}, id: {}, account: {}, region: {})",
+        args.resource_type, args.resource_id, args.account_id, args.region);
+
+    let result = config_history::get_config_history_internal(args).await?;
+    let json = serde_json::to_value(result)?;
+
+    tracing::info!("[WEBVIEW CMD] getConfigHistory() -> {} items",
+        json.get("totalItems").and_then(|c| c.as_u64()).unwrap_or(0));
+    Ok(json)
+}
+
+/// Run an Athena query
+///
+/// Starts the query, waits for it to finish, and returns the fetched rows
+pub async fn run_athena_query(
+    args: athena::RunAthenaQueryArgs
+) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] runAthenaQuery(workgroup: {:?}, database: {:?}, account: {}, region: {})",
+        args.workgroup, args.database, args.account_id, args.region);
+
+    let result = athena::run_athena_query_internal(args).await?;
+    let json = serde_json::to_value(result)?;
+
+    tracing::info!("[WEBVIEW CMD] runAthenaQuery() -> {} rows",
+        json.get("rowCount").and_then(|c| c.as_u64()).unwrap_or(0));
+    Ok(json)
+}
+
+/// Get Cost Explorer data
+///
+/// Returns month-to-date costs grouped by service, account, and/or region
+pub async fn get_cost_data(
+    args: cost_explorer::GetCostDataArgs
+) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] getCostData(account: {}, groupBy: {:?})",
+        args.account_id, args.group_by);
+
+    let result = cost_explorer::get_cost_data_internal(args).await?;
+    let json = serde_json::to_value(result)?;
+
+    tracing::info!("[WEBVIEW CMD] getCostData() -> {} groups",
+        json.get("groupCount").and_then(|c| c.as_u64()).unwrap_or(0));
+    Ok(json)
+}
+
 // ============================================================================
 // Page Management Commands
 // ============================================================================
@@ -369,6 +573,7 @@ pub struct DeletePageArgs {
 /// Removes the page directory and all contents
 pub async fn delete_page(args: DeletePageArgs) -> Result<serde_json::Value> {
     tracing::info!("[WEBVIEW CMD] deletePage(page_name: {})", args.page_name);
+    crate::app::capabilities::ensure_writable("Delete page")?;
 
     let pages_dir = get_pages_dir()?;
     let page_path = pages_dir.join(&args.page_name);
@@ -452,6 +657,7 @@ pub struct EditPageArgs {
 /// The agent will have the page workspace set and prompt the user for changes.
 pub async fn edit_page(args: EditPageArgs) -> Result<serde_json::Value> {
     tracing::info!("[WEBVIEW CMD] editPage(page_name: {})", args.page_name);
+    crate::app::capabilities::ensure_writable("Edit page")?;
 
     let pages_dir = get_pages_dir()?;
     let page_path = pages_dir.join(&args.page_name);
@@ -496,6 +702,7 @@ pub struct RenamePageArgs {
 pub async fn rename_page(args: RenamePageArgs) -> Result<serde_json::Value> {
     tracing::info!("[WEBVIEW CMD] renamePage(old_name: {}, new_name: {})",
         args.old_name, args.new_name);
+    crate::app::capabilities::ensure_writable("Rename page")?;
 
     // Validate new name is not empty and doesn't contain invalid characters
     let new_name = args.new_name.trim();
@@ -606,3 +813,281 @@ fn update_wry_urls_in_directory(dir: &PathBuf, old_name: &str, new_name: &str) -
 
     Ok(files_updated)
 }
+
+// ============================================================================
+// Page Template Commands
+// ============================================================================
+
+/// Input arguments for createPageFromTemplate command
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePageFromTemplateArgs {
+    /// Template ID from listPageTemplates
+    pub template_id: String,
+    /// Name for the new page
+    pub name: String,
+    /// Optional description (defaults to the template description)
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// List the built-in page templates
+pub async fn list_page_templates() -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] listPageTemplates()");
+    Ok(serde_json::to_value(crate::app::webview::page_templates())?)
+}
+
+/// Create a new page workspace from a built-in template
+///
+/// Returns the page directory name to use with viewPage/openPage.
+pub async fn create_page_from_template(args: CreatePageFromTemplateArgs) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] createPageFromTemplate(template_id: {}, name: {})",
+        args.template_id, args.name);
+
+    let page = crate::app::webview::get_page_manager()
+        .write()
+        .map_err(|e| anyhow::anyhow!("Failed to lock page manager: {}", e))?
+        .create_page_from_template(&args.template_id, args.name, args.description, None)?;
+
+    let page_name = page
+        .page_path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    tracing::info!("[WEBVIEW CMD] createPageFromTemplate() -> {}", page_name);
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": format!("Created page '{}' from template '{}'", page_name, args.template_id),
+        "pageName": page_name,
+        "templateId": args.template_id
+    }))
+}
+
+// ============================================================================
+// Page Find & Replace Commands
+// ============================================================================
+
+/// Input arguments for previewReplaceInPages command
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewReplaceArgs {
+    /// Search parameters
+    #[serde(flatten)]
+    pub query: page_search::PageSearchQuery,
+    /// Replacement text (supports `$1` captures for regex queries)
+    pub replacement: String,
+}
+
+/// Input arguments for replaceInPages command
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceInPagesArgs {
+    /// Search parameters
+    #[serde(flatten)]
+    pub query: page_search::PageSearchQuery,
+    /// Replacement text (supports `$1` captures for regex queries)
+    pub replacement: String,
+    /// Files confirmed by the user from the preview
+    pub files: Vec<page_search::PageFileRef>,
+}
+
+/// Search all pages for a literal or regex pattern
+///
+/// Searches HTML/JS/CSS files in every disk page, and VFS pages if requested
+pub async fn search_pages(args: page_search::PageSearchQuery) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] searchPages(pattern: {}, regex: {}, vfs: {})",
+        args.pattern, args.is_regex, args.include_vfs);
+
+    let pages_dir = get_pages_dir()?;
+    let result = page_search::search_pages(&pages_dir, &args)?;
+
+    tracing::info!("[WEBVIEW CMD] searchPages() -> {} matches in {} files",
+        result.matches.len(), result.files_matched);
+    Ok(serde_json::to_value(result)?)
+}
+
+/// Preview a replacement across all pages without writing any file
+pub async fn preview_replace_in_pages(args: PreviewReplaceArgs) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] previewReplaceInPages(pattern: {})", args.query.pattern);
+
+    let pages_dir = get_pages_dir()?;
+    let previews = page_search::preview_replace(&pages_dir, &args.query, &args.replacement)?;
+
+    tracing::info!("[WEBVIEW CMD] previewReplaceInPages() -> {} files", previews.len());
+    Ok(serde_json::to_value(previews)?)
+}
+
+/// Apply a previewed replacement to the confirmed files
+pub async fn replace_in_pages(args: ReplaceInPagesArgs) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] replaceInPages(pattern: {}, files: {})",
+        args.query.pattern, args.files.len());
+    crate::app::capabilities::ensure_writable("Replace in pages")?;
+
+    let pages_dir = get_pages_dir()?;
+    let summary = page_search::apply_replace(&pages_dir, &args.query, &args.replacement, &args.files)?;
+
+    tracing::info!("[WEBVIEW CMD] replaceInPages() -> {} replacements", summary.replacements);
+    Ok(serde_json::to_value(summary)?)
+}
+
+// ============================================================================
+// Page Export
+// ============================================================================
+
+/// Export a page as a standalone zip or single-file HTML
+///
+/// Writes to the Downloads folder and returns the file path. The exported
+/// page uses mock `dashApp` data instead of the AWS Dash backend.
+pub async fn export_page(args: page_export::PageExportRequest) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] exportPage(page_name: {}, format: {:?})",
+        args.page_name, args.format);
+
+    let pages_dir = get_pages_dir()?;
+    let output_dir = page_export::default_export_dir()?;
+    let result = page_export::export_page(&pages_dir, &output_dir, &args)?;
+
+    tracing::info!("[WEBVIEW CMD] exportPage() -> {:?} ({} bytes)", result.path, result.size_bytes);
+    Ok(serde_json::to_value(result)?)
+}
+
+// ============================================================================
+// Page Capabilities
+// ============================================================================
+
+/// Input arguments for getPageCapabilities and revokePageTokens commands
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageCapabilityArgs {
+    /// Page/workspace name
+    pub page_name: String,
+}
+
+/// Input arguments for setPageCapabilityRevoked command
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetPageCapabilityRevokedArgs {
+    /// Page/workspace name
+    pub page_name: String,
+    /// Capability identifier (e.g., "pages:write")
+    pub capability: String,
+    /// True to revoke, false to restore
+    pub revoked: bool,
+}
+
+/// Show which capabilities a page asks for and which are revoked
+pub async fn get_page_capabilities(args: PageCapabilityArgs) -> Result<serde_json::Value> {
+    tracing::info!(
+        "[WEBVIEW CMD] getPageCapabilities(page_name: {})",
+        args.page_name
+    );
+
+    let pages_dir = get_pages_dir()?;
+    let index_path = pages_dir.join(&args.page_name).join("index.html");
+
+    // Validate the path is within pages directory
+    if !index_path.starts_with(&pages_dir) || args.page_name.contains("..") {
+        return Err(anyhow::anyhow!(
+            "Invalid page name: path traversal not allowed"
+        ));
+    }
+
+    let html = std::fs::read_to_string(&index_path).ok();
+    let report = page_capabilities::page_capability_report(&args.page_name, html.as_deref());
+
+    tracing::info!(
+        "[WEBVIEW CMD] getPageCapabilities() -> {} open windows",
+        report.active_tokens
+    );
+    Ok(serde_json::to_value(report)?)
+}
+
+/// Revoke or restore one capability of a page
+///
+/// Applies to open windows of the page right away and is remembered for
+/// windows opened later.
+pub async fn set_page_capability_revoked(
+    args: SetPageCapabilityRevokedArgs,
+) -> Result<serde_json::Value> {
+    tracing::info!(
+        "[WEBVIEW CMD] setPageCapabilityRevoked(page_name: {}, capability: {}, revoked: {})",
+        args.page_name,
+        args.capability,
+        args.revoked
+    );
+
+    let capability = PageCapability::parse(&args.capability)
+        .ok_or_else(|| anyhow::anyhow!("Unknown capability: {}", args.capability))?;
+    page_capabilities::set_capability_revoked(&args.page_name, capability, args.revoked)?;
+
+    let action = if args.revoked { "revoked" } else { "restored" };
+    tracing::info!("[WEBVIEW CMD] setPageCapabilityRevoked() -> {}", action);
+    Ok(serde_json::json!({
+        "status": "success",
+        "message": format!("'{}' {} for page '{}'", capability.id(), action, args.page_name)
+    }))
+}
+
+/// Invalidate the API tokens of every open window of a page
+pub async fn revoke_page_tokens(args: PageCapabilityArgs) -> Result<serde_json::Value> {
+    tracing::info!(
+        "[WEBVIEW CMD] revokePageTokens(page_name: {})",
+        args.page_name
+    );
+
+    let revoked = page_capabilities::revoke_page_tokens(&args.page_name);
+
+    tracing::info!("[WEBVIEW CMD] revokePageTokens() -> {} tokens", revoked);
+    Ok(serde_json::json!({
+        "status": "success",
+        "revoked": revoked,
+        "message": format!("Revoked {} token{} for page '{}'",
+            revoked, if revoked == 1 { "" } else { "s" }, args.page_name)
+    }))
+}
+
+// ============================================================================
+// Saved VFS Workspaces
+// ============================================================================
+
+/// Input arguments for reopenVfsSnapshot command
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReopenVfsSnapshotArgs {
+    /// Agent session the snapshot was saved for
+    pub session_id: String,
+}
+
+/// List VFS workspaces saved by agent sessions, newest first
+pub async fn list_vfs_snapshots() -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] listVfsSnapshots()");
+
+    let snapshots = VfsSnapshotStore::default_location()
+        .map(|store| store.list())
+        .unwrap_or_default();
+
+    tracing::info!("[WEBVIEW CMD] listVfsSnapshots() -> {} snapshots", snapshots.len());
+    Ok(serde_json::to_value(snapshots)?)
+}
+
+/// Reopen a saved VFS workspace so its pages can be opened
+///
+/// Returns the VFS ID and a `vfs:{vfs_id}:{page_id}` workspace name per page.
+pub async fn reopen_vfs_snapshot(args: ReopenVfsSnapshotArgs) -> Result<serde_json::Value> {
+    tracing::info!("[WEBVIEW CMD] reopenVfsSnapshot(session_id: {})", args.session_id);
+
+    let vfs_id = reopen_snapshot(&args.session_id)?;
+    let pages = with_vfs(&vfs_id, |vfs| vfs.list_dir("/pages"))
+        .ok_or_else(|| anyhow::anyhow!("VFS not found: {}", vfs_id))??;
+    let workspaces: Vec<String> = pages
+        .iter()
+        .filter(|entry| entry.is_directory)
+        .map(|entry| format!("vfs:{}:{}", vfs_id, entry.name))
+        .collect();
+
+    tracing::info!("[WEBVIEW CMD] reopenVfsSnapshot() -> {} pages", workspaces.len());
+    Ok(serde_json::json!({
+        "vfsId": vfs_id,
+        "workspaces": workspaces
+    }))
+}
@@ -156,7 +156,9 @@
       originalConsoleLog('[DASH] Response status:', response.status);
 
       if (!response.ok) {
-        throw new Error(`HTTP ${response.status}: ${response.statusText}`);
+        // Capability denials carry an error message in the body
+        const body = await response.json().catch(() => null);
+        throw new Error(body?.data?.error || `HTTP ${response.status}: ${response.statusText}`);
       }
 
       const result = await response.json();
@@ -331,6 +333,86 @@
       return invoke('queryCachedResources', options);
     },
 
+    /**
+     * Query cached resources one page at a time
+     *
+     * Runs the query once and returns the first page; the rest stays in the
+     * main process until fetched with fetchResourcePage(nextCursor). Use this
+     * (or streamCachedResources) when a query can return thousands of resources.
+     * Unread cursors expire after 5 minutes.
+     *
+     * @param {object} options - Same options as queryCachedResources
+     * @param {number} [pageSize=1000] - Resources per page (max 10000)
+     * @returns {Promise<object>} - queryCachedResources result holding the first
+     *   page in resources, plus { offset, totalCount, nextCursor }
+     *
+     * @example
+     * let page = await dashApp.queryCachedResourcesPaged({ resourceTypes: ['AWS::S3::Bucket'] }, 500);
+     * render(page.resources);
+     * while (page.nextCursor) {
+     *   page = await dashApp.fetchResourcePage(page.nextCursor);
+     *   render(page.resources);
+     * }
+     */
+    async queryCachedResourcesPaged(options, pageSize) {
+      return invoke('queryCachedResourcesPaged', { ...options, pageSize });
+    },
+
+    /**
+     * Fetch the next page of a paginated resource query
+     *
+     * @param {string} cursor - nextCursor from the previous page
+     * @returns {Promise<object>} - { resources, offset, totalCount, nextCursor }
+     *   (nextCursor is null on the last page)
+     */
+    async fetchResourcePage(cursor) {
+      return invoke('fetchResourcePage', { cursor });
+    },
+
+    /**
+     * Release a paginated resource query that will not be read to the end
+     *
+     * @param {string} cursor - nextCursor of the last page fetched
+     * @returns {Promise<object>} - { status, closed }
+     */
+    async closeResourceCursor(cursor) {
+      return invoke('closeResourceCursor', { cursor });
+    },
+
+    /**
+     * Stream cached resources page by page
+     *
+     * Async iterator over queryCachedResourcesPaged() pages, so large results
+     * can be rendered as they arrive. Breaking out of the loop releases the
+     * rest of the result.
+     *
+     * @param {object} options - Same options as queryCachedResources
+     * @param {object} [streamOptions] - { pageSize } (default 1000)
+     * @returns {AsyncGenerator<object>} - Yields { resources, offset, totalCount }
+     *
+     * @example
+     * for await (const page of dashApp.streamCachedResources({ resourceTypes: ['AWS::EC2::Instance'] })) {
+     *   appendRows(page.resources);
+     *   progress.textContent = `${page.offset + page.resources.length} / ${page.totalCount}`;
+     * }
+     */
+    async *streamCachedResources(options, { pageSize } = {}) {
+      let page = await this.queryCachedResourcesPaged(options, pageSize);
+      try {
+        while (true) {
+          yield { resources: page.resources || [], offset: page.offset, totalCount: page.totalCount };
+          if (!page.nextCursor) {
+            return;
+          }
+          page = await this.fetchResourcePage(page.nextCursor);
+        }
+      } finally {
+        if (page.nextCursor) {
+          this.closeResourceCursor(page.nextCursor).catch(() => {});
+        }
+      }
+    },
+
     // ========== BOOKMARK FUNCTIONS ==========
 
     /**
@@ -364,6 +446,56 @@
       return invoke('queryBookmarks', { bookmarkId, options });
     },
 
+    // ========== LIVE UPDATES ==========
+
+    /**
+     * Subscribe to events pushed by the main process
+     *
+     * Opens a Server-Sent Events stream to the API server. The browser
+     * reconnects automatically if the connection drops.
+     *
+     * Topics:
+     * - 'query.progress' - loadCache started/completed/failed ({ phase, resourceTypes, ... })
+     * - 'agent.status' - agent state changes ({ agentId, name, state, message, error })
+     * - 'logs.{logGroupName}' - new log events after tailLogEvents() ({ logGroupName, events })
+     * A topic ending in '*' matches by prefix; '*' alone matches everything.
+     *
+     * @param {string|string[]} topics - Topic filters
+     * @param {Function} callback - Called with (data, topic, timestamp)
+     * @returns {Function} - Call to unsubscribe
+     *
+     * @example
+     * const stop = dashApp.subscribe('agent.status', (s) => console.log(`${s.name}: ${s.state}`));
+     * // later
+     * stop();
+     */
+    subscribe(topics, callback) {
+      const apiUrl = window.__DASH_API_URL__;
+      const apiToken = window.__DASH_API_TOKEN__;
+      if (!apiUrl || !apiToken) {
+        throw new Error('API server not configured');
+      }
+
+      const topicList = Array.isArray(topics) ? topics.join(',') : topics;
+      const url = `${apiUrl}/api/events?topics=${encodeURIComponent(topicList)}` +
+        `&token=${encodeURIComponent(apiToken)}`;
+      const source = new EventSource(url);
+
+      source.onmessage = (message) => {
+        try {
+          const event = JSON.parse(message.data);
+          callback(event.data, event.topic, event.timestamp);
+        } catch (error) {
+          originalConsoleError('[DASH] Event handler failed:', error);
+        }
+      };
+      source.addEventListener('lagged', (message) => {
+        originalConsoleWarn(`[DASH] Missed ${message.data} events on ${topicList}`);
+      });
+
+      return () => source.close();
+    },
+
     // ========== CLOUDWATCH LOGS FUNCTIONS ==========
 
     /**
@@ -396,6 +528,57 @@
       return invoke('queryCloudWatchLogEvents', params);
     },
 
+    /**
+     * Push new events of a log group to subscribers
+     *
+     * Starts following the log group from now on; events arrive on the
+     * 'logs.{logGroupName}' topic. The tail stops once no page subscribes.
+     *
+     * @param {object} params - { accountId, region, logGroupName } (all required)
+     * @returns {Promise<object>} - { status, topic, alreadyRunning }
+     *
+     * @example
+     * const { topic } = await dashApp.tailLogEvents({
+     *   accountId: '123456789012',
+     *   region: 'us-east-1',
+     *   logGroupName: '/aws/lambda/my-function'
+     * });
+     * dashApp.subscribe(topic, ({ events }) => events.forEach(e => console.log(e.message)));
+     */
+    async tailLogEvents(params) {
+      return invoke('tailLogEvents', params);
+    },
+
+    /**
+     * Get CloudWatch metric time series
+     *
+     * Fetch the default metrics for a resource (EC2, Lambda, RDS, DynamoDB)
+     * or an explicit list of metrics.
+     *
+     * @param {object} params - Query parameters
+     * @param {string} params.accountId - AWS account ID (required)
+     * @param {string} params.region - AWS region (required)
+     * @param {string} [params.resourceType] - Resource type for default metrics
+     * @param {string} [params.resourceId] - Resource identifier for default metrics
+     * @param {Array<object>} [params.metrics] - Explicit metrics ({namespace, metricName, dimensions, statistic})
+     * @param {number} [params.startTime] - Start time in Unix milliseconds (default 3 hours ago)
+     * @param {number} [params.endTime] - End time in Unix milliseconds (default now)
+     * @param {number} [params.period] - Aggregation period in seconds (default 300)
+     * @returns {Promise<object>} - Result with series, summary, and totalDatapoints
+     *
+     * @example
+     * const metrics = await dashApp.getCloudWatchMetrics({
+     *   accountId: '123456789012',
+     *   region: 'us-east-1',
+     *   resourceType: 'AWS::EC2::Instance',
+     *   resourceId: 'i-0123456789abcdef0'
+     * });
+     * metrics.summary.forEach(s => console.log(`${s.label}: ${s.latest}`));
+     */
+    async getCloudWatchMetrics(params) {
+      return invoke('getCloudWatchMetrics', params);
+    },
+
     // ========== CLOUDTRAIL FUNCTIONS ==========
 
     /**
@@ -425,6 +608,93 @@
       return invoke('getCloudTrailEvents', params);
     },
 
+    // ========== AWS CONFIG FUNCTIONS ==========
+
+    /**
+     * Get AWS Config configuration history
+     *
+     * Returns a resource's recorded configuration snapshots and the
+     * field-level changes between consecutive snapshots.
+     *
+     * @param {object} params - Query parameters
+     * @param {string} params.accountId - AWS account ID (required)
+     * @param {string} params.region - AWS region (required)
+     * @param {string} params.resourceType - Resource type, e.g. 'AWS::EC2::SecurityGroup' (required)
+     * @param {string} params.resourceId - Resource ID (required)
+     * @param {number} [params.earlierTime] - Earliest capture time in Unix milliseconds
+     * @param {number} [params.laterTime] - Latest capture time in Unix milliseconds
+     * @param {number} [params.limit] - Max items (default 25, max 100)
+     * @returns {Promise<object>} - Result with timeline, items, and diffs
+     *
+     * @example
+     * const history = await dashApp.getConfigHistory({
+     *   accountId: '123456789012',
+     *   region: 'us-east-1',
+     *   resourceType: 'AWS::EC2::SecurityGroup',
+     *   resourceId: 'sg-0123456789abcdef0'
+     * });
+     * history.diffs.forEach(d => console.log(d.changes.map(c => c.path)));
+     */
+    async getConfigHistory(params) {
+      return invoke('getConfigHistory', params);
+    },
+
+    // ========== ATHENA FUNCTIONS ==========
+
+    /**
+     * Run an Athena SQL query
+     *
+     * Starts the query, waits for it to finish, and returns the rows as
+     * objects keyed by column name. All values are strings or null.
+     *
+     * @param {object} params - Query parameters
+     * @param {string} params.accountId - AWS account ID (required)
+     * @param {string} params.region - AWS region (required)
+     * @param {string} params.query - SQL statement (required)
+     * @param {string} [params.database] - Database for unqualified table names
+     * @param {string} [params.workgroup] - Workgroup (default 'primary')
+     * @param {string} [params.outputLocation] - S3 prefix for results if the workgroup has none
+     * @param {number} [params.maxRows] - Max rows (default 1000, max 10000)
+     * @param {number} [params.timeoutSeconds] - Cancel after this long (default 300, max 900)
+     * @returns {Promise<object>} - Result with columns, rows, and query statistics
+     *
+     * @example
+     * const result = await dashApp.runAthenaQuery({
+     *   accountId: '123456789012',
+     *   region: 'us-east-1',
+     *   database: 'default',
+     *   query: 'SELECT eventname, count(*) AS total FROM cloudtrail_logs GROUP BY 1'
+     * });
+     * result.rows.forEach(r => console.log(r.eventname, Number(r.total)));
+     */
+    async runAthenaQuery(params) {
+      return invoke('runAthenaQuery', params);
+    },
+
+    // ========== COST EXPLORER FUNCTIONS ==========
+
+    /**
+     * Get month-to-date costs and the month-end forecast
+     *
+     * @param {object} params - Query parameters
+     * @param {string} params.accountId - Account whose credentials are used (required)
+     * @param {string[]} [params.groupBy] - One or two of 'service', 'account', 'region' (default ['service'])
+     * @param {string} [params.metric] - 'unblended' (default), 'amortized', or 'blended'
+     * @param {boolean} [params.includeForecast] - Include the month-end forecast (default true)
+     * @param {string} [params.linkedAccount] - Only include costs of this linked account
+     * @returns {Promise<object>} - Totals, forecast, and groups sorted by amount
+     *
+     * @example
+     * const costs = await dashApp.getCostData({
+     *   accountId: '123456789012',
+     *   groupBy: ['service']
+     * });
+     * costs.groups.forEach(g => console.log(g.keys[0], g.amount.toFixed(2)));
+     */
+    async getCostData(params) {
+      return invoke('getCostData', params);
+    },
+
     // ========== PAGE MANAGEMENT FUNCTIONS ==========
 
     /**
@@ -510,6 +780,163 @@
      */
     async renamePage(oldName, newName) {
       return invoke('renamePage', { oldName, newName });
+    },
+
+    /**
+     * List the built-in page templates
+     *
+     * @returns {Promise<Array<object>>} - { id, name, description, apiMethods }
+     */
+    async listPageTemplates() {
+      return invoke('listPageTemplates', {});
+    },
+
+    /**
+     * Create a new page from a built-in template
+     *
+     * The page is written to the pages directory and is ready to open or
+     * hand to an agent for editing.
+     *
+     * @param {string} templateId - Template ID from listPageTemplates()
+     * @param {string} name - Name for the new page
+     * @param {string} [description] - Optional description
+     * @returns {Promise<object>} - { status, message, pageName, templateId }
+     *
+     * @example
+     * const { pageName } = await dashApp.createPageFromTemplate('cost-dashboard', 'Team Costs');
+     * await dashApp.viewPage(pageName);
+     */
+    async createPageFromTemplate(templateId, name, description) {
+      return invoke('createPageFromTemplate', { templateId, name, description });
+    },
+
+    /**
+     * Search all pages for text or a regex
+     *
+     * Searches HTML, JS and CSS files in every saved page.
+     *
+     * @param {object} query - Search parameters
+     * @param {string} query.pattern - Text or regex to find
+     * @param {boolean} [query.isRegex=false] - Treat pattern as a regex
+     * @param {boolean} [query.caseSensitive=false] - Match case exactly
+     * @param {boolean} [query.includeVfs=false] - Also search pages in live agent VFS
+     * @returns {Promise<object>} - { matches, filesSearched, filesMatched, truncated }
+     *
+     * @example
+     * const r = await dashApp.searchPages({ pattern: 'listAccounts(' });
+     * r.matches.forEach(m => console.log(`${m.file.pageName}/${m.file.filePath}:${m.lineNumber}`));
+     */
+    async searchPages(query) {
+      return invoke('searchPages', query);
+    },
+
+    /**
+     * Preview a replacement across all pages without changing any file
+     *
+     * @param {object} query - Same parameters as searchPages()
+     * @param {string} replacement - Replacement text ($1, ${name} captures allowed for regex)
+     * @returns {Promise<Array<object>>} - Per-file previews: { file, replacementCount, changes }
+     */
+    async previewReplaceInPages(query, replacement) {
+      return invoke('previewReplaceInPages', { ...query, replacement });
+    },
+
+    /**
+     * Apply a replacement to the files confirmed from a preview
+     *
+     * @param {object} query - Same parameters as searchPages()
+     * @param {string} replacement - Replacement text
+     * @param {Array<object>} files - File refs from previewReplaceInPages()
+     * @returns {Promise<object>} - { filesUpdated, replacements, failures }
+     */
+    async replaceInPages(query, replacement, files) {
+      return invoke('replaceInPages', { ...query, replacement, files });
+    },
+
+    /**
+     * Export a page as a standalone bundle that opens in any browser
+     *
+     * The export replaces dashApp with a shim that returns mock data, so it
+     * works without AWS Dash. The file is written to the Downloads folder.
+     *
+     * @param {string} pageName - Name of the page to export
+     * @param {string} [format='zip'] - 'zip' (folder with editable mock-data.js)
+     *   or 'html' (single file with assets inlined)
+     * @param {object} [options] - { source: { kind: 'vfs', vfsId }, mockData }
+     *   mockData maps method names to the values they return
+     * @returns {Promise<object>} - { path, format, fileCount, sizeBytes }
+     *
+     * @example
+     * const r = await dashApp.exportPage('my-dashboard', 'html');
+     * console.log(`Saved to ${r.path}`);
+     */
+    async exportPage(pageName, format = 'zip', options = {}) {
+      return invoke('exportPage', { pageName, format, ...options });
+    },
+
+    /**
+     * List VFS workspaces saved by agent sessions, newest first
+     *
+     * @returns {Promise<Array<object>>} - { sessionId, label, savedAt, fileCount, totalSize, pages }
+     */
+    async listVfsSnapshots() {
+      return invoke('listVfsSnapshots', {});
+    },
+
+    /**
+     * Reopen a saved VFS workspace so its pages can be opened again
+     *
+     * @param {string} sessionId - Session ID from listVfsSnapshots()
+     * @returns {Promise<object>} - { vfsId, workspaces } where each workspace is
+     *   a 'vfs:{vfsId}:{pageId}' name accepted by openPage()
+     *
+     * @example
+     * const { workspaces } = await dashApp.reopenVfsSnapshot(snapshots[0].sessionId);
+     * await dashApp.openPage(workspaces[0]);
+     */
+    async reopenVfsSnapshot(sessionId) {
+      return invoke('reopenVfsSnapshot', { sessionId });
+    },
+
+    // ========== PAGE CAPABILITIES (Pages Manager only) ==========
+
+    /**
+     * Show which API capabilities a page asks for and which are revoked
+     *
+     * Pages declare capabilities with
+     * <meta name="dash-capabilities" content="resources:read, telemetry:read">;
+     * pages without the tag get every read capability.
+     *
+     * @param {string} pageName - Name of the page
+     * @returns {Promise<object>} - { pageName, declared, activeTokens,
+     *   capabilities: [{ id, description, requested, revoked, write }] }
+     */
+    async getPageCapabilities(pageName) {
+      return invoke('getPageCapabilities', { pageName });
+    },
+
+    /**
+     * Revoke or restore one capability of a page
+     *
+     * Takes effect in open windows of the page immediately.
+     *
+     * @param {string} pageName - Name of the page
+     * @param {string} capability - Capability ID (e.g., 'pages:write')
+     * @param {boolean} revoked - true to revoke, false to restore
+     * @returns {Promise<object>} - { status, message }
+     */
+    async setPageCapabilityRevoked(pageName, capability, revoked) {
+      return invoke('setPageCapabilityRevoked', { pageName, capability, revoked });
+    },
+
+    /**
+     * Invalidate the API tokens of every open window of a page
+     *
+     * @param {string} pageName - Name of the page
+     * @returns {Promise<object>} - { status, revoked, message }
+     */
+    async revokePageTokens(pageName) {
+      return invoke('revokePageTokens', { pageName });
     }
   };
 
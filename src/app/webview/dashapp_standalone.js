@@ -0,0 +1,72 @@
+/**
+ * AWS Dash Standalone API Shim
+ *
+ * Replaces window.dashApp in exported Dash Pages so they run in any browser
+ * without the AWS Dash backend. Every call resolves with data from
+ * window.__DASH_MOCK_DATA__ (see mock-data.js), keyed by method name.
+ *
+ * Mock entries may be plain values or functions receiving the call arguments.
+ * Methods without mock data resolve to null and log a warning once.
+ */
+(function() {
+  'use strict';
+
+  const warned = new Set();
+
+  function mockData() {
+    return window.__DASH_MOCK_DATA__ || {};
+  }
+
+  function clone(value) {
+    return value === undefined ? null : JSON.parse(JSON.stringify(value));
+  }
+
+  async function call(name, args) {
+    const entry = mockData()[name];
+
+    if (typeof entry === 'function') {
+      return entry(...args);
+    }
+
+    if (entry === undefined && !warned.has(name)) {
+      warned.add(name);
+      console.warn(`[DashApp] ${name}() has no mock data in this exported page`);
+    }
+
+    return clone(entry);
+  }
+
+  // Paginated queries return the queryCachedResources mock as one page
+  function singlePage(result) {
+    if (Array.isArray(result)) {
+      result = { resources: result };
+    }
+    const resources = (result && result.resources) || [];
+    return { ...result, resources, offset: 0, totalCount: resources.length, nextCursor: null };
+  }
+
+  window.dashApp = new Proxy({}, {
+    get(target, name) {
+      if (typeof name !== 'string' || name === 'then') {
+        return undefined;
+      }
+      // No live updates without the backend; return the unsubscribe no-op
+      if (name === 'subscribe') {
+        return () => () => {};
+      }
+      if (name === 'queryCachedResourcesPaged') {
+        return async (options) => singlePage(await call('queryCachedResources', [options]));
+      }
+      if (name === 'streamCachedResources') {
+        return async function* (options) {
+          yield singlePage(await call('queryCachedResources', [options]));
+        };
+      }
+      return (...args) => call(name, args);
+    }
+  });
+
+  window.__DASH_STANDALONE__ = true;
+
+  console.log('[DashApp] Standalone export - using mock data');
+})();
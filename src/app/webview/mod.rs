@@ -12,7 +12,7 @@ use std::sync::{
 };
 use tao::{
     event::{Event, WindowEvent},
-    event_loop::{ControlFlow, EventLoop},
+    event_loop::{ControlFlow, EventLoopBuilder, EventLoopProxy},
     window::WindowBuilder,
 };
 use wry::WebViewBuilder;
@@ -20,11 +20,20 @@ use wry::WebViewBuilder;
 mod api_server;
 mod commands;
 mod page_manager;
+pub mod page_capabilities;
+pub mod page_export;
+pub mod page_search;
 mod pages_manager_window;
+pub mod push;
+pub mod resource_cursor;
+pub mod window_registry;
 
 pub use api_server::ApiServer;
-pub use page_manager::{DashPage, PageFolder, PageManager, get_page_manager};
+pub use page_manager::{
+    DashPage, PageFolder, PageManager, PageTemplate, get_page_manager, page_templates,
+};
 pub use pages_manager_window::spawn_pages_manager_window;
+pub use window_registry::{WebviewControl, WebviewInfo, WebviewStatus};
 
 /// Global API server info (set once at main process startup)
 static GLOBAL_API_SERVER_INFO: StdRwLock<Option<(String, String)>> = StdRwLock::new(None);
@@ -94,14 +103,24 @@ fn sanitize_page_path(page_name: &str, file_path: &str) -> Option<PathBuf> {
 
 pub fn spawn_webview_process(url: String, title: String) -> std::io::Result<()> {
     let current_exe = env::current_exe()?;
+    let window_id = window_registry::new_window_id();
 
-    Command::new(current_exe)
+    let mut command = Command::new(current_exe);
+    command
         .arg("--webview")
         .arg("--title")
-        .arg(title)
+        .arg(&title)
         .arg("--url")
         .arg(url)
-        .spawn()?;
+        .env("AWSDASH_WEBVIEW_ID", &window_id);
+
+    // External sites get no API token; the heartbeat is authorized by the window ID
+    if let Some((api_url, _)) = get_api_server_info() {
+        command.env("AWSDASH_HEARTBEAT_URL", heartbeat_url(&api_url));
+    }
+
+    let child = command.spawn()?;
+    window_registry::register_window(window_id, child, title, None, None);
 
     Ok(())
 }
@@ -183,42 +202,73 @@ pub async fn open_page_preview(page_name: &str, _page_url: &str) -> anyhow::Resu
 
     tracing::info!("Loaded page HTML ({} bytes)", html.len());
 
+    // The window gets a token scoped to the capabilities the page declares
+    let page_token = page_capabilities::issue_page_token(page_name, &html);
+
     // Spawn webview process with HTML
     // The subprocess custom protocol handler will proxy VFS asset requests
     // to the main process API server's /vfs endpoint
     let title = format!("Preview: {}", page_name.split(':').last().unwrap_or(page_name));
-    spawn_webview_process_with_html(html, title)?;
+    spawn_webview_process_with_token(html, title, Some(page_name), Some(page_token))?;
 
     tracing::info!("Page preview webview spawned for: {}", page_name);
 
     Ok(())
 }
 
+/// Spawn a webview process for HTML that uses the main API token
+///
+/// Only for trusted windows like the Pages Manager; pages are opened with
+/// [`open_page_preview`], which gives them a scoped token.
 pub fn spawn_webview_process_with_html(html: String, title: String) -> std::io::Result<()> {
+    spawn_webview_process_with_token(html, title, None, None)
+}
+
+/// Spawn a webview process for HTML, using `page_token` instead of the main API token if given
+///
+/// The process is added to the [`window_registry`] under `page_name`.
+fn spawn_webview_process_with_token(
+    html: String,
+    title: String,
+    page_name: Option<&str>,
+    page_token: Option<String>,
+) -> std::io::Result<()> {
     let current_exe = env::current_exe()?;
 
     // Get API server info (URL and token) to pass to webview
-    let (api_url, api_token) = get_api_server_info()
-        .ok_or_else(|| {
-            std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "API server not initialized - call set_api_server_info() first",
-            )
-        })?;
+    let (api_url, main_token) = get_api_server_info().ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::Other,
+            "API server not initialized - call set_api_server_info() first",
+        )
+    })?;
+
+    let api_token = page_token.clone().unwrap_or(main_token);
+    let window_id = window_registry::new_window_id();
 
     tracing::info!("Spawning webview with API URL: {}", api_url);
 
     // Pass HTML directly (not via HTTP) and API info via environment
-    Command::new(current_exe)
+    let child = Command::new(current_exe)
         .arg("--webview")
         .arg("--title")
-        .arg(title)
+        .arg(&title)
         .arg("--html")
         .arg(html)
+        .env("AWSDASH_HEARTBEAT_URL", heartbeat_url(&api_url))
         .env("AWSDASH_API_URL", api_url)
         .env("AWSDASH_API_TOKEN", api_token)
+        .env("AWSDASH_WEBVIEW_ID", &window_id)
         .spawn()?;
 
+    window_registry::register_window(
+        window_id,
+        child,
+        title,
+        page_name.map(String::from),
+        page_token,
+    );
+
     Ok(())
 }
 
@@ -264,7 +314,7 @@ pub fn run_webview(content: WebviewContent, title: String) -> wry::Result<()> {
         WebviewContent::Html(html) => tracing::info!("Content: HTML({} bytes)", html.len()),
     }
 
-    let event_loop = EventLoop::new();
+    let event_loop = EventLoopBuilder::<WebviewControl>::with_user_event().build();
     let window = WindowBuilder::new()
         .with_title(&title)
         .build(&event_loop)
@@ -495,7 +545,7 @@ pub fn run_webview(content: WebviewContent, title: String) -> wry::Result<()> {
         target_os = "ios",
         target_os = "android"
     ))]
-    let _webview = {
+    let webview = {
         tracing::info!("Building webview (non-Linux path)");
         builder.build(&window)?
     };
@@ -506,7 +556,7 @@ pub fn run_webview(content: WebviewContent, title: String) -> wry::Result<()> {
         target_os = "ios",
         target_os = "android"
     )))]
-    let _webview = {
+    let webview = {
         tracing::info!("Building webview (Linux/GTK path)");
         use tao::platform::unix::WindowExtUnix;
         use wry::WebViewBuilderExtUnix;
@@ -516,19 +566,85 @@ pub fn run_webview(content: WebviewContent, title: String) -> wry::Result<()> {
 
     tracing::info!("Webview built successfully");
 
+    // Report liveness to the main process and receive focus/reload/close commands
+    match (
+        env::var("AWSDASH_HEARTBEAT_URL"),
+        env::var("AWSDASH_WEBVIEW_ID"),
+    ) {
+        (Ok(url), Ok(window_id)) => spawn_heartbeat(url, window_id, event_loop.create_proxy()),
+        _ => tracing::warn!("No webview window ID - this window will not appear in Open Pages"),
+    }
+
     tracing::info!("Starting event loop");
     event_loop.run(move |event, _, control_flow| {
         *control_flow = ControlFlow::Wait;
 
-        if let Event::WindowEvent {
-            event: WindowEvent::CloseRequested,
-            ..
-        } = event
-        {
-            tracing::info!("Window close requested");
-            is_closing.store(true, Ordering::Relaxed);
-            *control_flow = ControlFlow::Exit;
+        match event {
+            Event::WindowEvent {
+                event: WindowEvent::CloseRequested,
+                ..
+            }
+            | Event::UserEvent(WebviewControl::Close) => {
+                tracing::info!("Window close requested");
+                is_closing.store(true, Ordering::Relaxed);
+                *control_flow = ControlFlow::Exit;
+            }
+            Event::UserEvent(WebviewControl::Focus) => {
+                window.set_minimized(false);
+                window.set_focus();
+            }
+            Event::UserEvent(WebviewControl::Reload) => {
+                if let Err(e) = webview.reload() {
+                    tracing::warn!("Failed to reload webview: {}", e);
+                }
+            }
+            _ => {}
+        }
+    });
+}
+
+/// Heartbeat endpoint of the API server at `api_url`
+fn heartbeat_url(api_url: &str) -> String {
+    format!("{}/api/webview/heartbeat", api_url)
+}
+
+/// Send heartbeats to the main process and forward the commands it returns
+///
+/// Stops when the event loop is gone. Failed heartbeats are retried; the main
+/// process shows the window as unresponsive meanwhile.
+fn spawn_heartbeat(url: String, window_id: String, proxy: EventLoopProxy<WebviewControl>) {
+    std::thread::spawn(move || {
+        let client = reqwest::blocking::Client::new();
+
+        loop {
+            let control = client
+                .post(&url)
+                .json(&serde_json::json!({ "id": window_id }))
+                .send()
+                .and_then(|response| response.error_for_status())
+                .and_then(|response| response.json::<HeartbeatReply>());
+
+            match control {
+                Ok(HeartbeatReply {
+                    command: Some(control),
+                }) => {
+                    tracing::info!("Webview command from main process: {:?}", control);
+                    if proxy.send_event(control).is_err() {
+                        return;
+                    }
+                }
+                Ok(_) => {}
+                Err(e) => tracing::debug!("Heartbeat failed: {}", e),
+            }
+
+            std::thread::sleep(window_registry::HEARTBEAT_INTERVAL);
         }
     });
 }
 
+/// Reply to `POST /api/webview/heartbeat`
+#[derive(serde::Deserialize)]
+struct HeartbeatReply {
+    command: Option<WebviewControl>,
+}
+
@@ -0,0 +1,602 @@
+//! Capability-scoped API tokens for Dash Pages
+//!
+//! Every page window gets its own API token instead of the main one, and that
+//! token only grants the commands of the capabilities the page declares in its
+//! `index.html`:
+//!
+//! ```html
+//! <meta name="dash-capabilities" content="resources:read, telemetry:read">
+//! ```
+//!
+//! Pages without the tag get [`DEFAULT_CAPABILITIES`], which are read-only, so
+//! a page has to ask for `pages:write` before it can delete or rewrite pages.
+//! No capability lets a page run an Athena statement that writes (see
+//! [`PageGrant::payload_error`]). The main token (used by the Pages Manager)
+//! is not scoped.
+//!
+//! Capabilities revoked in the Pages Manager are taken away from the page's
+//! live tokens immediately and remembered in `page_capabilities.json`, so
+//! windows opened later do not get them either.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+use crate::app::data_plane::athena::is_read_only_statement;
+
+/// A group of API commands a page token can be granted
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum PageCapability {
+    /// Accounts, regions, and cached resource queries
+    #[serde(rename = "resources:read")]
+    ResourcesRead,
+    /// Saved bookmarks and their queries
+    #[serde(rename = "bookmarks:read")]
+    BookmarksRead,
+    /// Logs, metrics, CloudTrail, Config history, Athena, and costs
+    #[serde(rename = "telemetry:read")]
+    TelemetryRead,
+    /// Live updates from `/api/events`
+    #[serde(rename = "events:subscribe")]
+    EventsSubscribe,
+    /// Listing, searching, and opening pages
+    #[serde(rename = "pages:read")]
+    PagesRead,
+    /// Creating, renaming, deleting, rewriting, and exporting pages
+    #[serde(rename = "pages:write")]
+    PagesWrite,
+}
+
+/// Capabilities of pages that do not declare any
+pub const DEFAULT_CAPABILITIES: &[PageCapability] = &[
+    PageCapability::ResourcesRead,
+    PageCapability::BookmarksRead,
+    PageCapability::TelemetryRead,
+    PageCapability::EventsSubscribe,
+    PageCapability::PagesRead,
+];
+
+impl PageCapability {
+    /// Every capability, in display order
+    pub const ALL: [PageCapability; 6] = [
+        PageCapability::ResourcesRead,
+        PageCapability::BookmarksRead,
+        PageCapability::TelemetryRead,
+        PageCapability::EventsSubscribe,
+        PageCapability::PagesRead,
+        PageCapability::PagesWrite,
+    ];
+
+    /// Identifier used in the meta tag and the API (e.g., "pages:write")
+    pub fn id(&self) -> &'static str {
+        match self {
+            PageCapability::ResourcesRead => "resources:read",
+            PageCapability::BookmarksRead => "bookmarks:read",
+            PageCapability::TelemetryRead => "telemetry:read",
+            PageCapability::EventsSubscribe => "events:subscribe",
+            PageCapability::PagesRead => "pages:read",
+            PageCapability::PagesWrite => "pages:write",
+        }
+    }
+
+    /// Parse a capability identifier
+    pub fn parse(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.id() == id)
+    }
+
+    /// Short description for the Pages Manager
+    pub fn description(&self) -> &'static str {
+        match self {
+            PageCapability::ResourcesRead => "List accounts and regions, query cached resources",
+            PageCapability::BookmarksRead => "List bookmarks and run their queries",
+            PageCapability::TelemetryRead => {
+                "Read logs, metrics, CloudTrail, Config history, Athena, and costs"
+            }
+            PageCapability::EventsSubscribe => "Receive live updates",
+            PageCapability::PagesRead => "List, search, and open pages",
+            PageCapability::PagesWrite => "Create, rename, delete, rewrite, and export pages",
+        }
+    }
+
+    /// Whether the capability allows changing persisted data
+    pub fn is_write(&self) -> bool {
+        matches!(self, PageCapability::PagesWrite)
+    }
+}
+
+/// What a token needs to run an API command
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandScope {
+    /// Any valid token
+    Any,
+    /// Page tokens need this capability
+    Requires(PageCapability),
+    /// Only the main token (the Pages Manager)
+    ManagerOnly,
+}
+
+/// Scope of an API command
+///
+/// Commands not listed here are manager-only, so a new command is never
+/// reachable from pages by accident.
+pub fn command_scope(cmd: &str) -> CommandScope {
+    use PageCapability::*;
+
+    match cmd {
+        "logToPageFile" => CommandScope::Any,
+
+        "listAccounts"
+        | "listRegions"
+        | "loadCache"
+        | "getResourceSchema"
+        | "showInExplorer"
+        | "queryCachedResources"
+        | "queryCachedResourcesPaged"
+        | "fetchResourcePage"
+        | "closeResourceCursor" => CommandScope::Requires(ResourcesRead),
+
+        "listBookmarks" | "queryBookmarks" => CommandScope::Requires(BookmarksRead),
+
+        "queryCloudWatchLogEvents"
+        | "tailLogEvents"
+        | "getCloudWatchMetrics"
+        | "getCloudTrailEvents"
+        | "getConfigHistory"
+        | "runAthenaQuery"
+        | "getCostData" => CommandScope::Requires(TelemetryRead),
+
+        "openPage"
+        | "listPages"
+        | "viewPage"
+        | "listPageTemplates"
+        | "searchPages"
+        | "previewReplaceInPages"
+        | "listVfsSnapshots" => CommandScope::Requires(PagesRead),
+
+        "deletePage"
+        | "renamePage"
+        | "editPage"
+        | "createPageFromTemplate"
+        | "replaceInPages"
+        | "exportPage"
+        | "reopenVfsSnapshot" => CommandScope::Requires(PagesWrite),
+
+        _ => CommandScope::ManagerOnly,
+    }
+}
+
+/// Read the capabilities a page declares with `<meta name="dash-capabilities">`
+///
+/// Returns None when the page has no such tag. Unknown identifiers are
+/// logged and ignored.
+pub fn declared_capabilities(html: &str) -> Option<Vec<PageCapability>> {
+    static META_TAG: OnceLock<Regex> = OnceLock::new();
+    static CONTENT_ATTR: OnceLock<Regex> = OnceLock::new();
+
+    let meta_tag = META_TAG.get_or_init(|| {
+        Regex::new(r#"(?i)<meta\s[^>]*name\s*=\s*["']dash-capabilities["'][^>]*>"#).unwrap()
+    });
+    let content_attr =
+        CONTENT_ATTR.get_or_init(|| Regex::new(r#"(?i)content\s*=\s*["']([^"']*)["']"#).unwrap());
+
+    let tag = meta_tag.find(html)?;
+    let content = content_attr.captures(tag.as_str())?.get(1)?.as_str();
+
+    let mut capabilities = BTreeSet::new();
+    for id in content
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|id| !id.is_empty())
+    {
+        match PageCapability::parse(id) {
+            Some(capability) => {
+                capabilities.insert(capability);
+            }
+            None => tracing::warn!("Ignoring unknown page capability: {}", id),
+        }
+    }
+
+    Some(capabilities.into_iter().collect())
+}
+
+/// Capabilities a page asks for: its declared ones, or the defaults
+fn requested_capabilities(html: &str) -> Vec<PageCapability> {
+    declared_capabilities(html).unwrap_or_else(|| DEFAULT_CAPABILITIES.to_vec())
+}
+
+/// A token issued to one page window
+#[derive(Debug, Clone)]
+pub struct PageGrant {
+    pub page_name: String,
+    /// Capabilities the page asked for
+    pub requested: Vec<PageCapability>,
+    /// Requested capabilities that are not revoked
+    pub capabilities: Vec<PageCapability>,
+    pub issued_at: DateTime<Utc>,
+}
+
+impl PageGrant {
+    /// Check whether this token may run `cmd`
+    pub fn allows(&self, cmd: &str) -> bool {
+        match command_scope(cmd) {
+            CommandScope::Any => true,
+            CommandScope::Requires(capability) => self.has(capability),
+            CommandScope::ManagerOnly => false,
+        }
+    }
+
+    /// Check whether this token holds `capability`
+    pub fn has(&self, capability: PageCapability) -> bool {
+        self.capabilities.contains(&capability)
+    }
+
+    /// Why this token may not run `cmd` with `payload`, for commands whose
+    /// arguments decide whether they read or write
+    ///
+    /// `runAthenaQuery` is a telemetry read, so pages may only run read-only
+    /// statements with it.
+    pub fn payload_error(&self, cmd: &str, payload: &serde_json::Value) -> Option<String> {
+        match cmd {
+            "runAthenaQuery" => {
+                let query = payload.get("query").and_then(|q| q.as_str()).unwrap_or("");
+                (!is_read_only_statement(query)).then(|| {
+                    format!(
+                        "Page '{}' can only run read-only Athena statements (SELECT, SHOW, ...)",
+                        self.page_name
+                    )
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Status of one capability for a page, shown in the Pages Manager
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CapabilityStatus {
+    pub id: &'static str,
+    pub description: &'static str,
+    /// The page asks for it (declared, or a default)
+    pub requested: bool,
+    pub revoked: bool,
+    pub write: bool,
+}
+
+/// Capability review of a page
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageCapabilityReport {
+    pub page_name: String,
+    /// False when the page has no meta tag and gets the defaults
+    pub declared: bool,
+    pub capabilities: Vec<CapabilityStatus>,
+    /// Tokens issued to open windows of this page
+    pub active_tokens: usize,
+}
+
+/// Revocations persisted between runs
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct RevocationFile {
+    /// Revoked capabilities by page name
+    revoked: HashMap<String, BTreeSet<PageCapability>>,
+}
+
+/// Live page tokens and persisted revocations
+struct CapabilityStore {
+    grants: HashMap<String, PageGrant>,
+    revocations: RevocationFile,
+    /// Where revocations are saved (None keeps them in memory)
+    path: Option<PathBuf>,
+}
+
+impl CapabilityStore {
+    fn load(path: Option<PathBuf>) -> Self {
+        let revocations = path
+            .as_ref()
+            .filter(|p| p.exists())
+            .and_then(|p| match std::fs::read_to_string(p) {
+                Ok(json) => serde_json::from_str(&json)
+                    .map_err(|e| tracing::warn!("Ignoring invalid {:?}: {}", p, e))
+                    .ok(),
+                Err(e) => {
+                    tracing::warn!("Failed to read {:?}: {}", p, e);
+                    None
+                }
+            })
+            .unwrap_or_default();
+
+        Self {
+            grants: HashMap::new(),
+            revocations,
+            path,
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let json = serde_json::to_string_pretty(&self.revocations)
+            .context("Failed to serialize page capabilities")?;
+        let temp_path = path.with_extension("json.tmp");
+        std::fs::write(&temp_path, json).context("Failed to write page capabilities")?;
+        std::fs::rename(&temp_path, path).context("Failed to save page capabilities")?;
+        Ok(())
+    }
+
+    fn revoked(&self, page_name: &str) -> Option<&BTreeSet<PageCapability>> {
+        self.revocations.revoked.get(page_name)
+    }
+
+    fn issue(&mut self, page_name: &str, requested: Vec<PageCapability>) -> String {
+        let capabilities = requested
+            .iter()
+            .copied()
+            .filter(|c| !self.revoked(page_name).is_some_and(|r| r.contains(c)))
+            .collect();
+
+        let token = super::api_server::generate_api_token();
+        self.grants.insert(
+            token.clone(),
+            PageGrant {
+                page_name: page_name.to_string(),
+                requested,
+                capabilities,
+                issued_at: Utc::now(),
+            },
+        );
+        token
+    }
+
+    fn set_revoked(
+        &mut self,
+        page_name: &str,
+        capability: PageCapability,
+        revoked: bool,
+    ) -> Result<()> {
+        let entry = self
+            .revocations
+            .revoked
+            .entry(page_name.to_string())
+            .or_default();
+        if revoked {
+            entry.insert(capability);
+        } else {
+            entry.remove(&capability);
+            if entry.is_empty() {
+                self.revocations.revoked.remove(page_name);
+            }
+        }
+        self.save()?;
+
+        // Apply to windows that are already open
+        for grant in self
+            .grants
+            .values_mut()
+            .filter(|g| g.page_name == page_name)
+        {
+            grant.capabilities.retain(|c| *c != capability);
+            if !revoked && grant.requested.contains(&capability) {
+                grant.capabilities.push(capability);
+                grant.capabilities.sort();
+            }
+        }
+        Ok(())
+    }
+
+    fn revoke_tokens(&mut self, page_name: &str) -> usize {
+        let before = self.grants.len();
+        self.grants.retain(|_, g| g.page_name != page_name);
+        before - self.grants.len()
+    }
+
+    fn report(&self, page_name: &str, html: Option<&str>) -> PageCapabilityReport {
+        let declared = html.and_then(declared_capabilities);
+        let requested = declared
+            .clone()
+            .unwrap_or_else(|| DEFAULT_CAPABILITIES.to_vec());
+
+        let capabilities = PageCapability::ALL
+            .iter()
+            .map(|c| CapabilityStatus {
+                id: c.id(),
+                description: c.description(),
+                requested: requested.contains(c),
+                revoked: self.revoked(page_name).is_some_and(|r| r.contains(c)),
+                write: c.is_write(),
+            })
+            .collect();
+
+        PageCapabilityReport {
+            page_name: page_name.to_string(),
+            declared: declared.is_some(),
+            capabilities,
+            active_tokens: self
+                .grants
+                .values()
+                .filter(|g| g.page_name == page_name)
+                .count(),
+        }
+    }
+}
+
+static CAPABILITY_STORE: OnceLock<Mutex<CapabilityStore>> = OnceLock::new();
+
+fn store() -> std::sync::MutexGuard<'static, CapabilityStore> {
+    CAPABILITY_STORE
+        .get_or_init(|| {
+            let path = dirs::data_local_dir().map(|d| d.join("awsdash/page_capabilities.json"));
+            Mutex::new(CapabilityStore::load(path))
+        })
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Issue an API token for a new window of `page_name`
+///
+/// The token grants the capabilities `html` declares (or the defaults),
+/// minus any the user revoked for this page.
+pub fn issue_page_token(page_name: &str, html: &str) -> String {
+    let requested = requested_capabilities(html);
+    let token = store().issue(page_name, requested);
+    tracing::info!("🔐 Issued scoped API token for page: {}", page_name);
+    token
+}
+
+/// Look up the grant of a page token
+pub fn grant_for_token(token: &str) -> Option<PageGrant> {
+    store().grants.get(token).cloned()
+}
+
+/// Revoke or restore a capability for a page and its open windows
+pub fn set_capability_revoked(
+    page_name: &str,
+    capability: PageCapability,
+    revoked: bool,
+) -> Result<()> {
+    store().set_revoked(page_name, capability, revoked)
+}
+
+/// Invalidate every token issued to `page_name`, returning how many there were
+///
+/// Open windows of the page lose API access until they are reopened.
+pub fn revoke_page_tokens(page_name: &str) -> usize {
+    store().revoke_tokens(page_name)
+}
+
+/// Invalidate one page token (its window was closed)
+pub fn revoke_token(token: &str) {
+    store().grants.remove(token);
+}
+
+/// Capability review of a page; `html` is its index.html if it could be read
+pub fn page_capability_report(page_name: &str, html: Option<&str>) -> PageCapabilityReport {
+    store().report(page_name, html)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const READ_ONLY_PAGE: &str = r#"<html><head>
+        <meta charset="UTF-8">
+        <meta content="resources:read, bogus:thing" name="dash-capabilities">
+        </head></html>"#;
+
+    #[test]
+    fn test_declared_capabilities() {
+        assert_eq!(
+            declared_capabilities(READ_ONLY_PAGE),
+            Some(vec![PageCapability::ResourcesRead])
+        );
+        assert_eq!(
+            declared_capabilities(
+                r#"<META NAME='dash-capabilities' CONTENT='pages:write events:subscribe'>"#
+            ),
+            Some(vec![
+                PageCapability::EventsSubscribe,
+                PageCapability::PagesWrite
+            ])
+        );
+        assert_eq!(declared_capabilities("<html></html>"), None);
+        assert!(!DEFAULT_CAPABILITIES.iter().any(|c| c.is_write()));
+    }
+
+    #[test]
+    fn test_grant_only_allows_declared_commands() {
+        let mut store = CapabilityStore::load(None);
+        let token = store.issue("inventory", requested_capabilities(READ_ONLY_PAGE));
+        let grant = &store.grants[&token];
+
+        assert!(grant.allows("queryCachedResources"));
+        assert!(grant.allows("logToPageFile"));
+        assert!(!grant.allows("listBookmarks"));
+        assert!(!grant.allows("deletePage"));
+        assert!(!grant.allows("setPageCapabilityRevoked"));
+        assert!(!grant.allows("someFutureCommand"));
+    }
+
+    #[test]
+    fn test_pages_only_run_read_only_athena_statements() {
+        let mut store = CapabilityStore::load(None);
+        let token = store.issue("costs", DEFAULT_CAPABILITIES.to_vec());
+        let grant = &store.grants[&token];
+        assert!(grant.allows("runAthenaQuery"));
+
+        let select = serde_json::json!({ "query": "SELECT * FROM cloudtrail_logs LIMIT 10" });
+        assert!(grant.payload_error("runAthenaQuery", &select).is_none());
+        for query in ["DROP TABLE cloudtrail_logs", "CREATE TABLE t AS SELECT 1", ""] {
+            let payload = serde_json::json!({ "query": query });
+            assert!(grant.payload_error("runAthenaQuery", &payload).is_some());
+        }
+        assert!(grant.payload_error("listAccounts", &select).is_none());
+    }
+
+    #[test]
+    fn test_revocation_applies_to_open_and_future_windows() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("page_capabilities.json");
+        let mut store = CapabilityStore::load(Some(path.clone()));
+
+        let open = store.issue(
+            "admin",
+            vec![PageCapability::PagesRead, PageCapability::PagesWrite],
+        );
+        store
+            .set_revoked("admin", PageCapability::PagesWrite, true)
+            .unwrap();
+        assert!(!store.grants[&open].allows("deletePage"));
+        assert!(store.grants[&open].allows("listPages"));
+
+        // Revocations survive a restart
+        let mut reloaded = CapabilityStore::load(Some(path));
+        let later = reloaded.issue("admin", vec![PageCapability::PagesWrite]);
+        assert!(reloaded.grants[&later].capabilities.is_empty());
+        assert!(reloaded.report("admin", None).capabilities[5].revoked);
+
+        // Restoring gives requested capabilities back to open windows
+        reloaded
+            .set_revoked("admin", PageCapability::PagesWrite, false)
+            .unwrap();
+        assert!(reloaded.grants[&later].allows("renamePage"));
+
+        assert_eq!(reloaded.report("admin", None).active_tokens, 1);
+        assert_eq!(reloaded.revoke_tokens("admin"), 1);
+        assert!(reloaded.grants.is_empty());
+    }
+
+    #[test]
+    fn test_templates_declare_what_they_call() {
+        for template in super::super::page_templates() {
+            let files = template.render_files("page", "Page");
+            let (_, index) = files.iter().find(|(p, _)| *p == "index.html").unwrap();
+            let declared = declared_capabilities(index)
+                .unwrap_or_else(|| panic!("{} declares no capabilities", template.id));
+
+            for method in template.api_methods {
+                // dashApp helpers built on other commands
+                let command = match *method {
+                    "streamCachedResources" => "queryCachedResourcesPaged",
+                    other => other,
+                };
+                match command_scope(command) {
+                    CommandScope::Requires(capability) => assert!(
+                        declared.contains(&capability),
+                        "{} calls {} without {}",
+                        template.id,
+                        method,
+                        capability.id()
+                    ),
+                    scope => panic!("{} is not callable from pages: {:?}", method, scope),
+                }
+            }
+        }
+    }
+}
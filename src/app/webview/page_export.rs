@@ -0,0 +1,633 @@
+//! Export Dash Pages as standalone bundles
+//!
+//! Packages a disk or VFS page so it can be shared and opened in any browser
+//! without AWS Dash running. Two formats are supported:
+//!
+//! - **Zip**: the page files in a folder, plus `dashapp-standalone.js` (a
+//!   `window.dashApp` replacement) and `mock-data.js` (the responses it
+//!   returns, editable by the recipient).
+//! - **Html**: a single file with the scripts and stylesheets inlined and
+//!   other assets embedded as `data:` URIs.
+//!
+//! `wry://localhost/pages/{name}/` URLs are rewritten to relative paths.
+//! Assets loaded at runtime from JavaScript (e.g. `fetch('data.json')`) are
+//! copied into the zip but cannot be inlined into the single-file export.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{Cursor, Write};
+use std::path::{Path, PathBuf};
+
+use super::get_mime_type;
+use super::page_search::PageSource;
+use crate::app::agent_framework::tools::file_operations::WorkspaceType;
+
+/// `window.dashApp` replacement used by exported pages
+const STANDALONE_SHIM_JS: &str = include_str!("dashapp_standalone.js");
+
+/// File name of the shim inside a zip export
+const SHIM_FILE: &str = "dashapp-standalone.js";
+
+/// File name of the mock data script inside a zip export
+const MOCK_DATA_FILE: &str = "mock-data.js";
+
+/// Files the app writes into page directories that are not part of the page
+const EXCLUDED_FILES: &[&str] = &["page.log"];
+
+/// Output format of an export
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Folder of page files in a zip archive
+    Zip,
+    /// Single self-contained HTML file
+    Html,
+}
+
+impl ExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Zip => "zip",
+            ExportFormat::Html => "html",
+        }
+    }
+}
+
+/// Page to export and how
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageExportRequest {
+    /// Where the page lives (default: disk)
+    #[serde(default = "disk_source")]
+    pub source: PageSource,
+    /// Page/workspace name (directory name for disk, page_id for VFS)
+    pub page_name: String,
+    pub format: ExportFormat,
+    /// Responses returned by the standalone `dashApp`, keyed by method name
+    /// (default: `default_mock_data()`)
+    #[serde(default)]
+    pub mock_data: Option<Value>,
+}
+
+fn disk_source() -> PageSource {
+    PageSource::Disk
+}
+
+/// Outcome of `export_page()`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageExportResult {
+    /// File that was written
+    pub path: PathBuf,
+    pub format: ExportFormat,
+    /// Page files included in the export
+    pub file_count: usize,
+    pub size_bytes: u64,
+}
+
+/// Mock responses used when the request does not provide any
+pub fn default_mock_data() -> Value {
+    serde_json::json!({
+        "listAccounts": [
+            { "id": "123456789012", "name": "Example Account", "alias": "example", "email": null }
+        ],
+        "listRegions": [
+            { "code": "us-east-1", "name": "US East (N. Virginia)" },
+            { "code": "us-west-2", "name": "US West (Oregon)" },
+            { "code": "eu-west-1", "name": "Europe (Ireland)" }
+        ],
+        "listBookmarks": [],
+        "queryCachedResources": [],
+        "listPages": []
+    })
+}
+
+/// Export a page to `output_dir`, returning the path of the written file
+///
+/// Existing files are never overwritten; a numeric suffix is added instead.
+pub fn export_page(
+    pages_dir: &Path,
+    output_dir: &Path,
+    request: &PageExportRequest,
+) -> Result<PageExportResult> {
+    let files = load_page_files(pages_dir, request)?;
+    let mock_data = request.mock_data.clone().unwrap_or_else(default_mock_data);
+
+    let bytes = match request.format {
+        ExportFormat::Zip => build_zip(&request.page_name, &files, &mock_data)?,
+        ExportFormat::Html => build_single_html(&files, &mock_data)?.into_bytes(),
+    };
+
+    std::fs::create_dir_all(output_dir)
+        .with_context(|| format!("Failed to create export directory: {:?}", output_dir))?;
+    let path = unique_output_path(output_dir, &request.page_name, request.format.extension());
+    std::fs::write(&path, &bytes).with_context(|| format!("Failed to write export: {:?}", path))?;
+
+    tracing::info!(
+        "Exported page '{}' ({} files) to {:?}",
+        request.page_name,
+        files.len(),
+        path
+    );
+
+    Ok(PageExportResult {
+        path,
+        format: request.format,
+        file_count: files.len(),
+        size_bytes: bytes.len() as u64,
+    })
+}
+
+/// Default export directory (`~/Downloads`, or `~/.local/share/awsdash/exports`)
+pub fn default_export_dir() -> Result<PathBuf> {
+    if let Some(dir) = dirs::download_dir() {
+        return Ok(dir);
+    }
+    Ok(dirs::data_local_dir()
+        .context("Failed to get local data directory")?
+        .join("awsdash/exports"))
+}
+
+/// Read every file of the page, with page URLs rewritten in text files
+fn load_page_files(
+    pages_dir: &Path,
+    request: &PageExportRequest,
+) -> Result<BTreeMap<String, Vec<u8>>> {
+    let name = &request.page_name;
+    if name.is_empty() || name.contains('/') || name.contains('\\') || name.contains("..") {
+        anyhow::bail!("Invalid page name: {}", name);
+    }
+
+    let workspace = match &request.source {
+        PageSource::Disk => {
+            let path = pages_dir.join(name);
+            if !path.is_dir() {
+                anyhow::bail!("Page not found: {}", name);
+            }
+            WorkspaceType::Disk { path }
+        }
+        PageSource::Vfs { vfs_id } => WorkspaceType::Vfs {
+            vfs_id: vfs_id.clone(),
+            page_id: name.clone(),
+        },
+    };
+
+    let mut paths = Vec::new();
+    collect_files(&workspace, None, &mut paths)?;
+
+    let url_prefixes = page_url_prefixes(request);
+    let mut files = BTreeMap::new();
+    for path in paths {
+        let mut content = workspace
+            .read_file(&path)
+            .with_context(|| format!("Failed to read page file: {}", path))?;
+        if is_text_file(&path) {
+            if let Ok(text) = std::str::from_utf8(&content) {
+                content = rewrite_page_urls(text, &url_prefixes).into_bytes();
+            }
+        }
+        files.insert(path, content);
+    }
+
+    if !files.contains_key("index.html") {
+        anyhow::bail!("Page '{}' has no index.html", name);
+    }
+
+    Ok(files)
+}
+
+/// Recursively collect page file paths, skipping hidden and runtime files
+fn collect_files(
+    workspace: &WorkspaceType,
+    dir: Option<&str>,
+    out: &mut Vec<String>,
+) -> Result<()> {
+    for entry in workspace.list_dir(dir)? {
+        if entry.name.starts_with('.') {
+            continue;
+        }
+        if entry.is_directory {
+            collect_files(workspace, Some(&entry.path), out)?;
+        } else if dir.is_some() || !EXCLUDED_FILES.contains(&entry.name.as_str()) {
+            out.push(entry.path);
+        }
+    }
+    Ok(())
+}
+
+/// Check whether a file may contain page URLs
+fn is_text_file(path: &str) -> bool {
+    matches!(
+        path.rsplit('.')
+            .next()
+            .map(|ext| ext.to_lowercase())
+            .as_deref(),
+        Some("html" | "htm" | "js" | "css" | "json" | "svg" | "txt")
+    )
+}
+
+/// URL prefixes that address the page root
+fn page_url_prefixes(request: &PageExportRequest) -> Vec<String> {
+    let mut prefixes = vec![format!("wry://localhost/pages/{}/", request.page_name)];
+    if let PageSource::Vfs { vfs_id } = &request.source {
+        prefixes.push(format!(
+            "wry://localhost/pages/vfs:{}:{}/",
+            vfs_id, request.page_name
+        ));
+    }
+    prefixes
+}
+
+/// Turn absolute page URLs into paths relative to the page root
+fn rewrite_page_urls(text: &str, prefixes: &[String]) -> String {
+    prefixes.iter().fold(text.to_string(), |text, prefix| {
+        text.replace(prefix.as_str(), "")
+    })
+}
+
+/// JavaScript that sets the mock responses for the standalone shim
+fn mock_data_script(mock_data: &Value) -> Result<String> {
+    Ok(format!(
+        "// Mock responses for window.dashApp, keyed by method name.\n\
+         // Values may be data or functions that receive the call arguments.\n\
+         window.__DASH_MOCK_DATA__ = {};\n",
+        serde_json::to_string_pretty(mock_data)?
+    ))
+}
+
+/// Insert markup at the start of `<head>` so it runs before page scripts
+fn inject_into_head(html: &str, markup: &str) -> String {
+    let head = Regex::new(r"(?i)<head[^>]*>|<html[^>]*>").expect("valid regex");
+    match head.find(html) {
+        Some(m) => format!("{}\n{}{}", &html[..m.end()], markup, &html[m.end()..]),
+        None => format!("{}{}", markup, html),
+    }
+}
+
+/// Build a zip archive with the page under a `{page_name}/` folder
+fn build_zip(
+    page_name: &str,
+    files: &BTreeMap<String, Vec<u8>>,
+    mock_data: &Value,
+) -> Result<Vec<u8>> {
+    let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+    let options =
+        zip::write::FileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+
+    let scripts = format!(
+        "<script src=\"{}\"></script>\n<script src=\"{}\"></script>\n",
+        MOCK_DATA_FILE, SHIM_FILE
+    );
+
+    for (path, content) in files {
+        zip.start_file(format!("{}/{}", page_name, path), options)?;
+        if path == "index.html" {
+            let html = String::from_utf8_lossy(content);
+            zip.write_all(inject_into_head(&html, &scripts).as_bytes())?;
+        } else {
+            zip.write_all(content)?;
+        }
+    }
+
+    zip.start_file(format!("{}/{}", page_name, MOCK_DATA_FILE), options)?;
+    zip.write_all(mock_data_script(mock_data)?.as_bytes())?;
+    zip.start_file(format!("{}/{}", page_name, SHIM_FILE), options)?;
+    zip.write_all(STANDALONE_SHIM_JS.as_bytes())?;
+
+    Ok(zip.finish()?.into_inner())
+}
+
+/// Build a single HTML file with every referenced page asset embedded
+fn build_single_html(files: &BTreeMap<String, Vec<u8>>, mock_data: &Value) -> Result<String> {
+    let html = String::from_utf8_lossy(&files["index.html"]).into_owned();
+
+    let script_tag =
+        Regex::new(r#"(?is)<script\b([^>]*?)\s+src\s*=\s*["']([^"']+)["']([^>]*)>\s*</script>"#)?;
+    let link_tag = Regex::new(r"(?i)<link\b[^>]*>")?;
+    let href_attr = Regex::new(r#"(?i)\bhref\s*=\s*["']([^"']+)["']"#)?;
+    let asset_attr = Regex::new(r#"(?i)\b(src|href)\s*=\s*(["'])([^"']+)["']"#)?;
+
+    // Inline local scripts
+    let html = script_tag.replace_all(&html, |caps: &Captures<'_>| {
+        match resolve_reference("", &caps[2]).and_then(|path| files.get(&path)) {
+            Some(content) => format!(
+                "<script{}{}>{}</script>",
+                &caps[1],
+                &caps[3],
+                escape_inline_script(&String::from_utf8_lossy(content))
+            ),
+            None => caps[0].to_string(),
+        }
+    });
+
+    // Inline local stylesheets
+    let html = link_tag.replace_all(&html, |caps: &Captures<'_>| {
+        let tag = &caps[0];
+        if !tag.to_lowercase().contains("stylesheet") {
+            return tag.to_string();
+        }
+        let Some(href) = href_attr.captures(tag) else {
+            return tag.to_string();
+        };
+        match resolve_reference("", &href[1]).and_then(|path| files.get(&path).map(|c| (path, c))) {
+            Some((path, content)) => format!(
+                "<style>\n{}\n</style>",
+                embed_css_urls(&String::from_utf8_lossy(content), parent_dir(&path), files)
+            ),
+            None => tag.to_string(),
+        }
+    });
+
+    // Embed remaining local assets (images, icons, fonts) as data URIs
+    let html = asset_attr.replace_all(&html, |caps: &Captures<'_>| {
+        match resolve_reference("", &caps[3]).and_then(|path| files.get(&path).map(|c| (path, c))) {
+            Some((path, content)) if !path.ends_with(".html") => {
+                format!(
+                    "{}={}{}{}",
+                    &caps[1],
+                    &caps[2],
+                    data_uri(&path, content),
+                    &caps[2]
+                )
+            }
+            _ => caps[0].to_string(),
+        }
+    });
+
+    // Inline <style> blocks may reference assets too
+    let html = embed_css_urls(&html, "", files);
+
+    let shim = format!(
+        "<script>\n{}</script>\n<script>\n{}</script>\n",
+        escape_inline_script(&mock_data_script(mock_data)?),
+        escape_inline_script(STANDALONE_SHIM_JS)
+    );
+    Ok(inject_into_head(&html, &shim))
+}
+
+/// Replace `url(...)` references in CSS with data URIs
+fn embed_css_urls(css: &str, base_dir: &str, files: &BTreeMap<String, Vec<u8>>) -> String {
+    let url = Regex::new(r#"url\(\s*["']?([^"')]+)["']?\s*\)"#).expect("valid regex");
+    url.replace_all(css, |caps: &Captures<'_>| {
+        match resolve_reference(base_dir, &caps[1])
+            .and_then(|path| files.get(&path).map(|c| (path, c)))
+        {
+            Some((path, content)) => format!("url(\"{}\")", data_uri(&path, content)),
+            None => caps[0].to_string(),
+        }
+    })
+    .into_owned()
+}
+
+/// Resolve a local reference to a page-relative path
+///
+/// Returns None for external URLs (`https:`, `data:`, `//host`) and fragments.
+fn resolve_reference(base_dir: &str, reference: &str) -> Option<String> {
+    let reference = reference.split(['?', '#']).next()?.trim();
+    if reference.is_empty() || reference.contains(':') || reference.starts_with("//") {
+        return None;
+    }
+
+    let mut parts: Vec<&str> = if reference.starts_with('/') {
+        Vec::new()
+    } else {
+        base_dir.split('/').filter(|p| !p.is_empty()).collect()
+    };
+
+    for part in reference.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            part => parts.push(part),
+        }
+    }
+
+    Some(parts.join("/"))
+}
+
+/// Directory of a page-relative path ("" for the page root)
+fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("")
+}
+
+/// Encode a file as a data URI
+fn data_uri(path: &str, content: &[u8]) -> String {
+    format!(
+        "data:{};base64,{}",
+        get_mime_type(&path.to_lowercase()),
+        aws_smithy_types::base64::encode(content)
+    )
+}
+
+/// Prevent inlined JavaScript from closing its `<script>` element early
+fn escape_inline_script(js: &str) -> String {
+    let close = Regex::new(r"(?i)</script").expect("valid regex");
+    close.replace_all(js, r"<\/script").into_owned()
+}
+
+/// First `{stem}.{ext}`, `{stem}-2.{ext}`, ... that does not exist yet
+fn unique_output_path(dir: &Path, stem: &str, ext: &str) -> PathBuf {
+    let stem = stem.replace(' ', "-");
+    let mut path = dir.join(format!("{}.{}", stem, ext));
+    let mut n = 2;
+    while path.exists() {
+        path = dir.join(format!("{}-{}.{}", stem, n, ext));
+        n += 1;
+    }
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const LOGO_PNG: &[u8] = &[0x89, b'P', b'N', b'G', 0x0d, 0x0a];
+
+    fn setup_page() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let page = dir.path().join("demo");
+        std::fs::create_dir_all(page.join("css")).unwrap();
+        std::fs::create_dir_all(page.join("js")).unwrap();
+        std::fs::create_dir_all(page.join("img")).unwrap();
+        std::fs::write(
+            page.join("index.html"),
+            r#"<!DOCTYPE html>
+<html>
+<head>
+<link rel="stylesheet" href="wry://localhost/pages/demo/css/style.css">
+</head>
+<body>
+<img src="img/logo.png" alt="logo">
+<script src="wry://localhost/pages/demo/js/app.js"></script>
+<script src="https://cdn.example.com/lib.js"></script>
+</body>
+</html>
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            page.join("css/style.css"),
+            "body { background: url('../img/logo.png'); }\n",
+        )
+        .unwrap();
+        std::fs::write(
+            page.join("js/app.js"),
+            "dashApp.listAccounts().then(a => document.title = a[0].name + '</script>');\n",
+        )
+        .unwrap();
+        std::fs::write(page.join("img/logo.png"), LOGO_PNG).unwrap();
+        std::fs::write(page.join("page.log"), "log output\n").unwrap();
+        dir
+    }
+
+    fn request(format: ExportFormat) -> PageExportRequest {
+        PageExportRequest {
+            source: PageSource::Disk,
+            page_name: "demo".to_string(),
+            format,
+            mock_data: None,
+        }
+    }
+
+    #[test]
+    fn test_zip_export_contains_page_and_shim() {
+        let pages = setup_page();
+        let out = TempDir::new().unwrap();
+
+        let result = export_page(pages.path(), out.path(), &request(ExportFormat::Zip)).unwrap();
+        assert_eq!(result.path, out.path().join("demo.zip"));
+        assert_eq!(result.file_count, 4);
+
+        let mut archive = zip::ZipArchive::new(std::fs::File::open(&result.path).unwrap()).unwrap();
+        let mut names: Vec<String> = archive.file_names().map(String::from).collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![
+                "demo/css/style.css",
+                "demo/dashapp-standalone.js",
+                "demo/img/logo.png",
+                "demo/index.html",
+                "demo/js/app.js",
+                "demo/mock-data.js",
+            ]
+        );
+
+        let mut index = String::new();
+        std::io::Read::read_to_string(&mut archive.by_name("demo/index.html").unwrap(), &mut index)
+            .unwrap();
+        assert!(!index.contains("wry://"));
+        assert!(index.contains(r#"href="css/style.css""#));
+        let shim = index.find("dashapp-standalone.js").unwrap();
+        assert!(shim < index.find("js/app.js").unwrap());
+    }
+
+    #[test]
+    fn test_single_html_export_inlines_assets() {
+        let pages = setup_page();
+        let out = TempDir::new().unwrap();
+
+        let result = export_page(pages.path(), out.path(), &request(ExportFormat::Html)).unwrap();
+        let html = std::fs::read_to_string(&result.path).unwrap();
+
+        assert!(!html.contains("wry://"));
+        assert!(!html.contains("css/style.css"));
+        assert!(!html.contains("js/app.js"));
+        assert!(html.contains("<style>"));
+        assert!(html.contains(r"'<\/script>'"));
+        assert!(html.contains("__DASH_MOCK_DATA__"));
+        assert!(html.contains("123456789012"));
+        // External scripts are left alone
+        assert!(html.contains(r#"src="https://cdn.example.com/lib.js""#));
+
+        let logo = data_uri("img/logo.png", LOGO_PNG);
+        assert!(logo.starts_with("data:image/png;base64,"));
+        // Once for the <img>, once for the stylesheet background
+        assert_eq!(html.matches(&logo).count(), 2);
+    }
+
+    #[test]
+    fn test_mock_data_override_and_unique_paths() {
+        let pages = setup_page();
+        let out = TempDir::new().unwrap();
+        let mut req = request(ExportFormat::Html);
+        req.mock_data = Some(serde_json::json!({ "listAccounts": [{ "id": "999999999999" }] }));
+
+        let first = export_page(pages.path(), out.path(), &req).unwrap();
+        let second = export_page(pages.path(), out.path(), &req).unwrap();
+        assert_eq!(second.path, out.path().join("demo-2.html"));
+
+        let html = std::fs::read_to_string(first.path).unwrap();
+        assert!(html.contains("999999999999"));
+        assert!(!html.contains("123456789012"));
+    }
+
+    #[test]
+    fn test_export_errors() {
+        let pages = setup_page();
+        let out = TempDir::new().unwrap();
+
+        let mut missing = request(ExportFormat::Zip);
+        missing.page_name = "nope".to_string();
+        assert!(export_page(pages.path(), out.path(), &missing).is_err());
+
+        let mut traversal = request(ExportFormat::Zip);
+        traversal.page_name = "../demo".to_string();
+        assert!(export_page(pages.path(), out.path(), &traversal).is_err());
+
+        std::fs::remove_file(pages.path().join("demo/index.html")).unwrap();
+        let error = export_page(pages.path(), out.path(), &request(ExportFormat::Zip))
+            .err()
+            .unwrap();
+        assert!(error.to_string().contains("no index.html"));
+    }
+
+    #[test]
+    fn test_resolve_reference() {
+        assert_eq!(
+            resolve_reference("", "js/app.js").as_deref(),
+            Some("js/app.js")
+        );
+        assert_eq!(
+            resolve_reference("", "./a.css?v=2").as_deref(),
+            Some("a.css")
+        );
+        assert_eq!(
+            resolve_reference("css", "../img/logo.png").as_deref(),
+            Some("img/logo.png")
+        );
+        assert_eq!(
+            resolve_reference("css", "/img/a.png").as_deref(),
+            Some("img/a.png")
+        );
+        assert_eq!(resolve_reference("", "../outside.js"), None);
+        assert_eq!(resolve_reference("", "https://example.com/a.js"), None);
+        assert_eq!(resolve_reference("", "data:image/png;base64,AA=="), None);
+        assert_eq!(resolve_reference("", "#top"), None);
+    }
+
+    #[test]
+    fn test_vfs_page_urls_are_rewritten() {
+        let req = PageExportRequest {
+            source: PageSource::Vfs {
+                vfs_id: "abc".to_string(),
+            },
+            page_name: "demo".to_string(),
+            format: ExportFormat::Zip,
+            mock_data: None,
+        };
+        let rewritten = rewrite_page_urls(
+            r#"<script src="wry://localhost/pages/vfs:abc:demo/app.js"></script>"#,
+            &page_url_prefixes(&req),
+        );
+        assert_eq!(rewritten, r#"<script src="app.js"></script>"#);
+    }
+}
@@ -5,6 +5,7 @@
 //! - Saving pages to persistent storage
 //! - Organizing pages in folder hierarchies
 //! - Managing concurrent access with file locking
+//! - Creating new pages from the built-in template gallery
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
@@ -83,6 +84,114 @@ impl DashPage {
     }
 }
 
+/// Placeholder in template files replaced with the page directory name
+const TEMPLATE_PAGE_NAME: &str = "{{PAGE_NAME}}";
+
+/// Placeholder in template files replaced with the page title
+const TEMPLATE_PAGE_TITLE: &str = "{{PAGE_TITLE}}";
+
+/// Stylesheet shared by all templates
+const TEMPLATE_STYLE_CSS: &str = include_str!("page_templates/style.css");
+
+/// A built-in starter page that can be copied into a new page workspace
+///
+/// Template files reference each other with `wry://localhost/pages/{{PAGE_NAME}}/`
+/// URLs, which are filled in when the page is created.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageTemplate {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub description: &'static str,
+    /// dashApp methods the template calls
+    pub api_methods: &'static [&'static str],
+    #[serde(skip)]
+    files: &'static [(&'static str, &'static str)],
+}
+
+impl PageTemplate {
+    /// Template files with placeholders filled in
+    pub fn render_files(&self, page_name: &str, title: &str) -> Vec<(&'static str, String)> {
+        self.files
+            .iter()
+            .map(|(path, content)| {
+                let content = content
+                    .replace(TEMPLATE_PAGE_NAME, page_name)
+                    .replace(TEMPLATE_PAGE_TITLE, title);
+                (*path, content)
+            })
+            .collect()
+    }
+}
+
+/// Built-in template gallery
+static PAGE_TEMPLATES: &[PageTemplate] = &[
+    PageTemplate {
+        id: "resource-table",
+        name: "Resource Table",
+        description: "Sortable, filterable table of one resource type across accounts and regions",
+        api_methods: &[
+            "listAccounts",
+            "listRegions",
+            "loadCache",
+            "streamCachedResources",
+        ],
+        files: &[
+            (
+                "index.html",
+                include_str!("page_templates/resource_table/index.html"),
+            ),
+            (
+                "app.js",
+                include_str!("page_templates/resource_table/app.js"),
+            ),
+            ("style.css", TEMPLATE_STYLE_CSS),
+        ],
+    },
+    PageTemplate {
+        id: "cost-dashboard",
+        name: "Cost Dashboard",
+        description: "Month-to-date spend and forecast grouped by service, account, or region",
+        api_methods: &["listAccounts", "getCostData"],
+        files: &[
+            (
+                "index.html",
+                include_str!("page_templates/cost_dashboard/index.html"),
+            ),
+            (
+                "app.js",
+                include_str!("page_templates/cost_dashboard/app.js"),
+            ),
+            ("style.css", TEMPLATE_STYLE_CSS),
+        ],
+    },
+    PageTemplate {
+        id: "log-viewer",
+        name: "Log Viewer",
+        description:
+            "Search a CloudWatch Logs group with a filter pattern over a recent time range",
+        api_methods: &["listAccounts", "listRegions", "queryCloudWatchLogEvents"],
+        files: &[
+            (
+                "index.html",
+                include_str!("page_templates/log_viewer/index.html"),
+            ),
+            ("app.js", include_str!("page_templates/log_viewer/app.js")),
+            ("style.css", TEMPLATE_STYLE_CSS),
+        ],
+    },
+];
+
+/// List the built-in page templates
+pub fn page_templates() -> &'static [PageTemplate] {
+    PAGE_TEMPLATES
+}
+
+/// Find a built-in page template by ID
+pub fn find_page_template(id: &str) -> Option<&'static PageTemplate> {
+    PAGE_TEMPLATES.iter().find(|t| t.id == id)
+}
+
 /// Collection of pages and folders with version tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PageCollection {
@@ -162,6 +271,11 @@ impl PageManager {
             .context("Failed to get local data directory")?
             .join("awsdash");
 
+        Self::with_data_dir(data_dir)
+    }
+
+    /// Create a page manager rooted at `data_dir` instead of ~/.local/share/awsdash
+    pub fn with_data_dir(data_dir: PathBuf) -> Result<Self> {
         let pages_dir = data_dir.join("pages");
         let temp_pages_dir = data_dir.join("temp_pages");
         let manifest_path = data_dir.join("dash_pages_manifest.json");
@@ -260,6 +374,8 @@ impl PageManager {
         description: Option<String>,
         folder_id: Option<String>,
     ) -> Result<DashPage> {
+        crate::app::capabilities::ensure_writable("Save page")?;
+
         let temp_file = self.temp_pages_dir.join(format!("{}.html", page_id));
 
         if !temp_file.exists() {
@@ -302,6 +418,54 @@ impl PageManager {
         Ok(page)
     }
 
+    /// Create a new page from a built-in template
+    ///
+    /// Writes the template files to pages/{name}/ and adds the page to the
+    /// manifest. The page name is sanitized the same way as in save_page().
+    pub fn create_page_from_template(
+        &mut self,
+        template_id: &str,
+        name: String,
+        description: Option<String>,
+        folder_id: Option<String>,
+    ) -> Result<DashPage> {
+        crate::app::capabilities::ensure_writable("Create page from template")?;
+
+        let template = find_page_template(template_id)
+            .with_context(|| format!("Unknown page template: {}", template_id))?;
+
+        let safe_name = Self::sanitize_name(name.trim());
+        if safe_name.trim_matches('-').is_empty() {
+            anyhow::bail!("Page name cannot be empty");
+        }
+
+        let page_dir = self.pages_dir.join(&safe_name);
+        if page_dir.exists() {
+            anyhow::bail!("A page with the name '{}' already exists", safe_name);
+        }
+
+        fs::create_dir_all(&page_dir).context("Failed to create page directory")?;
+
+        for (path, content) in template.render_files(&safe_name, name.trim()) {
+            fs::write(page_dir.join(path), content)
+                .with_context(|| format!("Failed to write template file: {}", path))?;
+        }
+
+        let mut page = DashPage::new(name.trim().to_string(), page_dir);
+        page.description = description.or_else(|| Some(template.description.to_string()));
+        page.folder_id = folder_id;
+
+        self.add_page_to_manifest(page.clone())?;
+
+        tracing::info!(
+            "Created page '{}' from template '{}'",
+            safe_name,
+            template.id
+        );
+
+        Ok(page)
+    }
+
     /// Add page to manifest with file locking for concurrent access
     ///
     /// This implements the critical file locking pattern to handle multiple
@@ -360,6 +524,8 @@ impl PageManager {
 
     /// Delete a page from persistent storage
     pub fn delete_page(&mut self, page_id: &str) -> Result<()> {
+        crate::app::capabilities::ensure_writable("Delete page")?;
+
         let page = self.collection.remove_page(page_id)
             .context("Page not found")?;
 
@@ -407,3 +573,66 @@ pub fn get_page_manager() -> &'static StdRwLock<PageManager> {
         StdRwLock::new(manager)
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_templates_are_wired_to_their_page() {
+        assert_eq!(page_templates().len(), 3);
+        for template in page_templates() {
+            let files = template.render_files("my-page", "My Page");
+            let (_, index) = files
+                .iter()
+                .find(|(path, _)| *path == "index.html")
+                .unwrap();
+
+            assert!(index.contains("wry://localhost/pages/my-page/app.js"));
+            assert!(index.contains("<title>My Page</title>"));
+            assert!(files.iter().all(|(_, content)| !content.contains("{{")));
+
+            let (_, app) = files.iter().find(|(path, _)| *path == "app.js").unwrap();
+            for method in template.api_methods {
+                assert!(
+                    app.contains(&format!("dashApp.{}(", method)),
+                    "{} missing {}",
+                    template.id,
+                    method
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_create_page_from_template() {
+        let dir = TempDir::new().unwrap();
+        let mut manager = PageManager::with_data_dir(dir.path().to_path_buf()).unwrap();
+
+        let page = manager
+            .create_page_from_template("cost-dashboard", "Team Costs".to_string(), None, None)
+            .unwrap();
+
+        let page_dir = dir.path().join("pages/team-costs");
+        assert_eq!(page.page_path, page_dir);
+        assert!(page_dir.join("index.html").exists());
+        assert!(page_dir.join("style.css").exists());
+        let index = fs::read_to_string(page_dir.join("index.html")).unwrap();
+        assert!(index.contains("wry://localhost/pages/team-costs/app.js"));
+
+        assert!(manager.collection().get_page(&page.id).is_some());
+        assert!(dir.path().join("dash_pages_manifest.json").exists());
+
+        // Same name again is refused rather than overwriting the page
+        assert!(manager
+            .create_page_from_template("log-viewer", "team costs".to_string(), None, None)
+            .is_err());
+        assert!(manager
+            .create_page_from_template("no-such-template", "Other".to_string(), None, None)
+            .is_err());
+        assert!(manager
+            .create_page_from_template("log-viewer", "  ".to_string(), None, None)
+            .is_err());
+    }
+}
@@ -0,0 +1,690 @@
+//! Workspace-wide find and replace across Dash Pages
+//!
+//! Searches every disk-based page under `~/.local/share/awsdash/pages/` (and
+//! optionally the `/pages/` tree of every live VFS instance) for a literal or
+//! regex pattern. Replacements are two-step: `preview_replace()` returns the
+//! before/after text of every affected line without touching any file, and
+//! `apply_replace()` writes only the files the user confirmed.
+//!
+//! Only text page sources are considered (HTML, JS, CSS).
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{Context, Result};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+use crate::app::agent_framework::tools::file_operations::WorkspaceType;
+use crate::app::agent_framework::vfs::registry::list_vfs_ids;
+
+/// File extensions included in page searches
+const SEARCHABLE_EXTENSIONS: &[&str] = &["html", "htm", "js", "css"];
+
+/// Maximum number of matches returned by a single search
+const MAX_MATCHES: usize = 5000;
+
+/// Search parameters shared by find, preview and apply
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageSearchQuery {
+    /// Text or regex pattern to find
+    pub pattern: String,
+    /// Treat `pattern` as a regular expression instead of literal text
+    #[serde(default)]
+    pub is_regex: bool,
+    /// Match case exactly
+    #[serde(default)]
+    pub case_sensitive: bool,
+    /// Also search pages stored in live agent VFS instances
+    #[serde(default)]
+    pub include_vfs: bool,
+}
+
+impl PageSearchQuery {
+    /// Compile the query into a regex
+    ///
+    /// Literal patterns are escaped so that `dashApp.list(` matches as typed.
+    pub fn to_regex(&self) -> Result<Regex> {
+        if self.pattern.is_empty() {
+            anyhow::bail!("Search pattern cannot be empty");
+        }
+
+        let source = if self.is_regex {
+            self.pattern.clone()
+        } else {
+            regex::escape(&self.pattern)
+        };
+
+        RegexBuilder::new(&source)
+            .case_insensitive(!self.case_sensitive)
+            .multi_line(true)
+            .build()
+            .with_context(|| format!("Invalid search pattern: {}", self.pattern))
+    }
+}
+
+/// Where a page file lives
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum PageSource {
+    /// Persistent page directory on disk
+    Disk,
+    /// Temporary page inside an agent VFS
+    Vfs {
+        #[serde(rename = "vfsId")]
+        vfs_id: String,
+    },
+}
+
+/// Identifies one file inside one page
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageFileRef {
+    pub source: PageSource,
+    /// Page/workspace name (directory name for disk, page_id for VFS)
+    pub page_name: String,
+    /// Path relative to the page root (e.g. `js/app.js`)
+    pub file_path: String,
+}
+
+/// A single match found by `search_pages()`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageSearchMatch {
+    pub file: PageFileRef,
+    /// 1-based line number
+    pub line_number: usize,
+    /// 1-based column (in characters) of the match start
+    pub column: usize,
+    /// Full text of the matching line
+    pub line_text: String,
+    /// The matched text
+    pub matched_text: String,
+}
+
+/// Result of a search across all pages
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageSearchResults {
+    pub matches: Vec<PageSearchMatch>,
+    pub files_searched: usize,
+    pub files_matched: usize,
+    /// True if the search stopped at `MAX_MATCHES`
+    pub truncated: bool,
+}
+
+/// One changed line in a replace preview
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineChange {
+    pub line_number: usize,
+    pub before: String,
+    pub after: String,
+}
+
+/// Preview of the replacements that would be made in one file
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileReplacePreview {
+    pub file: PageFileRef,
+    pub replacement_count: usize,
+    pub changes: Vec<LineChange>,
+}
+
+/// Outcome of `apply_replace()`
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceSummary {
+    pub files_updated: usize,
+    pub replacements: usize,
+    /// Files that could not be written, with the error message
+    pub failures: Vec<(PageFileRef, String)>,
+}
+
+/// A page file loaded into memory for searching
+struct LoadedPageFile {
+    file: PageFileRef,
+    content: String,
+}
+
+/// Check whether a file name has a searchable extension
+fn is_searchable(path: &str) -> bool {
+    Path::new(path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| SEARCHABLE_EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+/// Build the workspace used to read/write a page file
+///
+/// Page names come from the caller, so they must name a single page
+/// directory: separators, `..` and absolute paths are rejected, and a disk
+/// page must be an existing directory directly inside `pages_dir`.
+fn workspace_for(pages_dir: &Path, file: &PageFileRef) -> Result<WorkspaceType> {
+    let name = &file.page_name;
+    if name.is_empty()
+        || name.contains('/')
+        || name.contains('\\')
+        || name.contains("..")
+        || Path::new(name).is_absolute()
+    {
+        anyhow::bail!("Invalid page name: {}", name);
+    }
+
+    match &file.source {
+        PageSource::Disk => {
+            let path = pages_dir.join(name);
+            let inside_pages_dir = match (path.canonicalize(), pages_dir.canonicalize()) {
+                (Ok(page), Ok(root)) => page.is_dir() && page.parent() == Some(root.as_path()),
+                _ => false,
+            };
+            if !inside_pages_dir {
+                anyhow::bail!("Page not found: {}", name);
+            }
+            Ok(WorkspaceType::Disk { path })
+        }
+        PageSource::Vfs { vfs_id } => Ok(WorkspaceType::Vfs {
+            vfs_id: vfs_id.clone(),
+            page_id: name.clone(),
+        }),
+    }
+}
+
+/// Recursively collect searchable file paths inside a workspace
+fn collect_workspace_files(
+    workspace: &WorkspaceType,
+    dir: Option<&str>,
+    out: &mut Vec<String>,
+) -> Result<()> {
+    for entry in workspace.list_dir(dir)? {
+        if entry.is_directory {
+            collect_workspace_files(workspace, Some(&entry.path), out)?;
+        } else if is_searchable(&entry.name) {
+            out.push(entry.path);
+        }
+    }
+    Ok(())
+}
+
+/// Load every searchable file of every page
+fn load_page_files(pages_dir: &Path, include_vfs: bool) -> Result<Vec<LoadedPageFile>> {
+    let mut pages: Vec<(PageSource, String)> = Vec::new();
+
+    if pages_dir.exists() {
+        for entry in std::fs::read_dir(pages_dir)?.flatten() {
+            let name = entry.file_name().to_string_lossy().to_string();
+            if entry.path().is_dir() && !name.starts_with('.') {
+                pages.push((PageSource::Disk, name));
+            }
+        }
+    }
+
+    if include_vfs {
+        for vfs_id in list_vfs_ids() {
+            let workspace = WorkspaceType::Vfs {
+                vfs_id: vfs_id.clone(),
+                page_id: String::new(),
+            };
+            // An empty page_id lists /pages/ itself
+            if let Ok(entries) = workspace.list_dir(None) {
+                for entry in entries.into_iter().filter(|e| e.is_directory) {
+                    pages.push((
+                        PageSource::Vfs {
+                            vfs_id: vfs_id.clone(),
+                        },
+                        entry.name,
+                    ));
+                }
+            }
+        }
+    }
+
+    pages.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let mut files = Vec::new();
+    for (source, page_name) in pages {
+        let probe = PageFileRef {
+            source: source.clone(),
+            page_name: page_name.clone(),
+            file_path: String::new(),
+        };
+        let mut paths = Vec::new();
+        let collected = workspace_for(pages_dir, &probe).and_then(|workspace| {
+            collect_workspace_files(&workspace, None, &mut paths)?;
+            Ok(workspace)
+        });
+        let workspace = match collected {
+            Ok(workspace) => workspace,
+            Err(e) => {
+                tracing::warn!("Skipping page '{}' during search: {}", page_name, e);
+                continue;
+            }
+        };
+        paths.sort();
+
+        for file_path in paths {
+            match workspace.read_file_string(&file_path) {
+                Ok(content) => files.push(LoadedPageFile {
+                    file: PageFileRef {
+                        source: source.clone(),
+                        page_name: page_name.clone(),
+                        file_path,
+                    },
+                    content,
+                }),
+                Err(e) => {
+                    tracing::debug!("Skipping unreadable page file {}: {}", file_path, e);
+                }
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+/// Find all matches of `query` across pages in `pages_dir`
+pub fn search_pages(pages_dir: &Path, query: &PageSearchQuery) -> Result<PageSearchResults> {
+    let regex = query.to_regex()?;
+    let files = load_page_files(pages_dir, query.include_vfs)?;
+
+    let mut matches = Vec::new();
+    let mut files_matched = 0;
+    let mut truncated = false;
+
+    'files: for loaded in &files {
+        let mut file_has_match = false;
+
+        for (index, line) in loaded.content.lines().enumerate() {
+            for m in regex.find_iter(line) {
+                if matches.len() >= MAX_MATCHES {
+                    truncated = true;
+                    break 'files;
+                }
+                file_has_match = true;
+                matches.push(PageSearchMatch {
+                    file: loaded.file.clone(),
+                    line_number: index + 1,
+                    column: line[..m.start()].chars().count() + 1,
+                    line_text: line.to_string(),
+                    matched_text: m.as_str().to_string(),
+                });
+            }
+        }
+
+        if file_has_match {
+            files_matched += 1;
+        }
+    }
+
+    Ok(PageSearchResults {
+        matches,
+        files_searched: files.len(),
+        files_matched,
+        truncated,
+    })
+}
+
+/// Replace every match in `content`, returning the new content and the changed lines
+fn replace_in_content(
+    regex: &Regex,
+    content: &str,
+    replacement: &str,
+) -> (String, usize, Vec<LineChange>) {
+    let count = regex.find_iter(content).count();
+    if count == 0 {
+        return (content.to_string(), 0, Vec::new());
+    }
+
+    let replaced = regex.replace_all(content, replacement).into_owned();
+
+    // Line-level diff is only exact when line counts line up, which holds for
+    // any replacement that does not add or remove newlines. Fall back to a
+    // per-line replace for the preview otherwise.
+    let before_lines: Vec<&str> = content.lines().collect();
+    let after_lines: Vec<&str> = replaced.lines().collect();
+    let changes = if before_lines.len() == after_lines.len() {
+        before_lines
+            .iter()
+            .zip(after_lines.iter())
+            .enumerate()
+            .filter(|(_, (b, a))| b != a)
+            .map(|(i, (b, a))| LineChange {
+                line_number: i + 1,
+                before: b.to_string(),
+                after: a.to_string(),
+            })
+            .collect()
+    } else {
+        before_lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| regex.is_match(line))
+            .map(|(i, line)| LineChange {
+                line_number: i + 1,
+                before: line.to_string(),
+                after: regex.replace_all(line, replacement).into_owned(),
+            })
+            .collect()
+    };
+
+    (replaced, count, changes)
+}
+
+/// Compute the replacements that `apply_replace()` would make, without writing
+///
+/// `replacement` supports regex capture references (`$1`, `${name}`) when the
+/// query is a regex.
+pub fn preview_replace(
+    pages_dir: &Path,
+    query: &PageSearchQuery,
+    replacement: &str,
+) -> Result<Vec<FileReplacePreview>> {
+    let regex = query.to_regex()?;
+    let replacement = effective_replacement(query, replacement);
+    let files = load_page_files(pages_dir, query.include_vfs)?;
+
+    Ok(files
+        .into_iter()
+        .filter_map(|loaded| {
+            let (_, count, changes) = replace_in_content(&regex, &loaded.content, &replacement);
+            (count > 0).then_some(FileReplacePreview {
+                file: loaded.file,
+                replacement_count: count,
+                changes,
+            })
+        })
+        .collect())
+}
+
+/// Apply a replacement to the given files
+///
+/// Files are re-read before writing so edits made since the preview are not
+/// lost. Pass the file list from `preview_replace()` (optionally filtered by
+/// the user) as `files`.
+pub fn apply_replace(
+    pages_dir: &Path,
+    query: &PageSearchQuery,
+    replacement: &str,
+    files: &[PageFileRef],
+) -> Result<ReplaceSummary> {
+    let regex = query.to_regex()?;
+    let replacement = effective_replacement(query, replacement);
+
+    let mut summary = ReplaceSummary {
+        files_updated: 0,
+        replacements: 0,
+        failures: Vec::new(),
+    };
+
+    for file in files {
+        if !is_searchable(&file.file_path) {
+            summary
+                .failures
+                .push((file.clone(), "Not a searchable page file".to_string()));
+            continue;
+        }
+
+        let result = workspace_for(pages_dir, file).and_then(|workspace| {
+            let content = workspace.read_file_string(&file.file_path)?;
+            let (new_content, count, _) = replace_in_content(&regex, &content, &replacement);
+            if count > 0 {
+                workspace.write_file(&file.file_path, new_content.as_bytes())?;
+            }
+            Ok(count)
+        });
+
+        match result {
+            Ok(0) => {}
+            Ok(count) => {
+                summary.files_updated += 1;
+                summary.replacements += count;
+                tracing::debug!(
+                    "Replaced {} occurrence(s) in {}/{}",
+                    count,
+                    file.page_name,
+                    file.file_path
+                );
+            }
+            Err(e) => summary.failures.push((file.clone(), e.to_string())),
+        }
+    }
+
+    tracing::info!(
+        "Page replace: {} replacements in {} files ({} failures)",
+        summary.replacements,
+        summary.files_updated,
+        summary.failures.len()
+    );
+
+    Ok(summary)
+}
+
+/// Literal replacements must not expand `$` capture references
+fn effective_replacement(query: &PageSearchQuery, replacement: &str) -> String {
+    if query.is_regex {
+        replacement.to_string()
+    } else {
+        replacement.replace('$', "$$")
+    }
+}
+
+/// Default pages directory (`~/.local/share/awsdash/pages`)
+pub fn default_pages_dir() -> Result<PathBuf> {
+    Ok(dirs::data_local_dir()
+        .context("Failed to get local data directory")?
+        .join("awsdash/pages"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn literal(pattern: &str) -> PageSearchQuery {
+        PageSearchQuery {
+            pattern: pattern.to_string(),
+            is_regex: false,
+            case_sensitive: true,
+            include_vfs: false,
+        }
+    }
+
+    fn setup_pages() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let a = dir.path().join("dashboard-a");
+        std::fs::create_dir_all(a.join("js")).unwrap();
+        std::fs::write(
+            a.join("index.html"),
+            "<script>\nconst x = dashApp.listAccounts();\n</script>\n",
+        )
+        .unwrap();
+        std::fs::write(a.join("js/app.js"), "dashApp.listAccounts().then(render);\n").unwrap();
+        std::fs::write(a.join("page.log"), "dashApp.listAccounts()\n").unwrap();
+
+        let b = dir.path().join("dashboard-b");
+        std::fs::create_dir_all(&b).unwrap();
+        std::fs::write(b.join("style.css"), "body { color: red; }\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_search_finds_matches_in_html_js_only() {
+        let dir = setup_pages();
+        let results = search_pages(dir.path(), &literal("dashApp.listAccounts(")).unwrap();
+
+        assert_eq!(results.matches.len(), 2);
+        assert_eq!(results.files_matched, 2);
+        // page.log is not searchable
+        assert_eq!(results.files_searched, 3);
+        assert!(results
+            .matches
+            .iter()
+            .all(|m| m.file.page_name == "dashboard-a"));
+        let html = results
+            .matches
+            .iter()
+            .find(|m| m.file.file_path == "index.html")
+            .unwrap();
+        assert_eq!(html.line_number, 2);
+        assert_eq!(html.column, 11);
+    }
+
+    #[test]
+    fn test_literal_pattern_is_escaped() {
+        let query = literal("a.b(");
+        let regex = query.to_regex().unwrap();
+        assert!(regex.is_match("a.b("));
+        assert!(!regex.is_match("axb("));
+    }
+
+    #[test]
+    fn test_case_insensitive_search() {
+        let dir = setup_pages();
+        let mut query = literal("COLOR");
+        assert!(search_pages(dir.path(), &query).unwrap().matches.is_empty());
+        query.case_sensitive = false;
+        assert_eq!(search_pages(dir.path(), &query).unwrap().matches.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_regex_is_error() {
+        let query = PageSearchQuery {
+            pattern: "(unclosed".to_string(),
+            is_regex: true,
+            case_sensitive: true,
+            include_vfs: false,
+        };
+        assert!(query.to_regex().is_err());
+        assert!(literal("").to_regex().is_err());
+    }
+
+    #[test]
+    fn test_preview_does_not_modify_files() {
+        let dir = setup_pages();
+        let previews =
+            preview_replace(dir.path(), &literal("listAccounts"), "getAccounts").unwrap();
+
+        assert_eq!(previews.len(), 2);
+        let html = previews
+            .iter()
+            .find(|p| p.file.file_path == "index.html")
+            .unwrap();
+        assert_eq!(html.replacement_count, 1);
+        assert_eq!(html.changes[0].line_number, 2);
+        assert_eq!(html.changes[0].after, "const x = dashApp.getAccounts();");
+
+        let content =
+            std::fs::read_to_string(dir.path().join("dashboard-a/index.html")).unwrap();
+        assert!(content.contains("listAccounts"));
+    }
+
+    #[test]
+    fn test_apply_replace_only_selected_files() {
+        let dir = setup_pages();
+        let query = literal("listAccounts");
+        let previews = preview_replace(dir.path(), &query, "getAccounts").unwrap();
+        let selected: Vec<PageFileRef> = previews
+            .iter()
+            .filter(|p| p.file.file_path == "js/app.js")
+            .map(|p| p.file.clone())
+            .collect();
+
+        let summary = apply_replace(dir.path(), &query, "getAccounts", &selected).unwrap();
+        assert_eq!(summary.files_updated, 1);
+        assert_eq!(summary.replacements, 1);
+        assert!(summary.failures.is_empty());
+
+        let js = std::fs::read_to_string(dir.path().join("dashboard-a/js/app.js")).unwrap();
+        assert!(js.contains("getAccounts"));
+        let html =
+            std::fs::read_to_string(dir.path().join("dashboard-a/index.html")).unwrap();
+        assert!(html.contains("listAccounts"));
+    }
+
+    #[test]
+    fn test_apply_replace_rejects_pages_outside_pages_dir() {
+        let root = TempDir::new().unwrap();
+        let pages = root.path().join("pages");
+        std::fs::create_dir_all(pages.join("dashboard")).unwrap();
+        std::fs::write(root.path().join("outside.js"), "listAccounts();\n").unwrap();
+
+        let query = literal("listAccounts");
+        let escapes: Vec<PageFileRef> = ["..", "../pages", "dashboard/..", "/tmp", "missing"]
+            .iter()
+            .map(|name| PageFileRef {
+                source: PageSource::Disk,
+                page_name: name.to_string(),
+                file_path: "../outside.js".to_string(),
+            })
+            .collect();
+
+        let summary = apply_replace(&pages, &query, "getAccounts", &escapes).unwrap();
+        assert_eq!(summary.replacements, 0);
+        assert_eq!(summary.failures.len(), escapes.len());
+        let outside = std::fs::read_to_string(root.path().join("outside.js")).unwrap();
+        assert!(outside.contains("listAccounts"));
+    }
+
+    #[test]
+    fn test_regex_capture_replacement() {
+        let dir = setup_pages();
+        let query = PageSearchQuery {
+            pattern: r"color: (\w+)".to_string(),
+            is_regex: true,
+            case_sensitive: true,
+            include_vfs: false,
+        };
+        let previews = preview_replace(dir.path(), &query, "background: $1").unwrap();
+        assert_eq!(previews.len(), 1);
+        assert_eq!(previews[0].changes[0].after, "body { background: red; }");
+    }
+
+    #[test]
+    fn test_search_and_replace_in_vfs_pages() {
+        use crate::app::agent_framework::vfs::{deregister_vfs, register_vfs, with_vfs, VirtualFileSystem};
+
+        let dir = TempDir::new().unwrap();
+        let mut vfs = VirtualFileSystem::new(1024 * 1024);
+        vfs.write_file("/pages/vpc-explorer/index.html", b"<h1>Old Title</h1>")
+            .unwrap();
+        vfs.write_file("/results/data.json", b"Old Title").unwrap();
+        let vfs_id = register_vfs(vfs);
+
+        let mut query = literal("Old Title");
+        assert!(search_pages(dir.path(), &query).unwrap().matches.is_empty());
+
+        query.include_vfs = true;
+        let results = search_pages(dir.path(), &query).unwrap();
+        let ours: Vec<_> = results
+            .matches
+            .iter()
+            .filter(|m| m.file.source == PageSource::Vfs { vfs_id: vfs_id.clone() })
+            .collect();
+        assert_eq!(ours.len(), 1);
+        assert_eq!(ours[0].file.page_name, "vpc-explorer");
+
+        let file = ours[0].file.clone();
+        let summary = apply_replace(dir.path(), &query, "New Title", &[file]).unwrap();
+        assert_eq!(summary.replacements, 1);
+
+        let html = with_vfs(&vfs_id, |vfs| {
+            vfs.read_file("/pages/vpc-explorer/index.html").map(|c| c.to_vec())
+        })
+        .unwrap()
+        .unwrap();
+        assert_eq!(html, b"<h1>New Title</h1>");
+
+        deregister_vfs(&vfs_id);
+    }
+
+    #[test]
+    fn test_literal_replacement_keeps_dollar_signs() {
+        let regex = literal("price").to_regex().unwrap();
+        let replacement = effective_replacement(&literal("price"), "$1.00");
+        let (out, count, _) = replace_in_content(&regex, "price", &replacement);
+        assert_eq!(count, 1);
+        assert_eq!(out, "$1.00");
+    }
+}
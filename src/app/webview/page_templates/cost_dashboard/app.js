@@ -0,0 +1,59 @@
+// Cost dashboard: month-to-date spend and forecast from Cost Explorer.
+// Uses dashApp.getCostData().
+
+const $ = (id) => document.getElementById(id);
+
+function setStatus(message, isError = false) {
+  $('status').textContent = message;
+  $('status').className = isError ? 'status error' : 'status';
+}
+
+function escapeHtml(text) {
+  const div = document.createElement('div');
+  div.textContent = text == null ? '' : String(text);
+  return div.innerHTML;
+}
+
+function money(amount, currency) {
+  return amount == null ? '-' : `${amount.toFixed(2)} ${currency}`;
+}
+
+async function loadCosts() {
+  const accountId = $('account').value;
+  if (!accountId) {
+    setStatus('No accounts configured', true);
+    return;
+  }
+
+  setStatus('Loading costs...');
+  try {
+    const costs = await dashApp.getCostData({ accountId, groupBy: [$('group-by').value] });
+    $('total').textContent = money(costs.total, costs.currency);
+    $('forecast').textContent = money(costs.forecastTotal, costs.currency);
+    $('period').textContent = `${costs.periodStart} - ${costs.periodEnd}`;
+
+    const max = Math.max(...costs.groups.map(g => g.amount), 0.01);
+    $('rows').innerHTML = costs.groups.map(g => `
+      <tr>
+        <td>${escapeHtml(g.keys.join(' / '))}</td>
+        <td class="number">${money(g.amount, costs.currency)}</td>
+        <td class="number">${money(g.forecast, costs.currency)}</td>
+        <td><div class="bar" style="width: ${(100 * g.amount / max).toFixed(1)}%"></div></td>
+      </tr>
+    `).join('');
+    setStatus(`${costs.groups.length} groups`);
+  } catch (error) {
+    setStatus('Failed to load costs: ' + error.message, true);
+  }
+}
+
+async function init() {
+  const accounts = await dashApp.listAccounts();
+  accounts.forEach(a => $('account').add(new Option(`${a.name} (${a.id})`, a.id)));
+  $('load').addEventListener('click', loadCosts);
+  $('group-by').addEventListener('change', loadCosts);
+  $('account').addEventListener('change', loadCosts);
+  await loadCosts();
+}
+
+init().catch(error => setStatus('Failed to initialize: ' + error.message, true));
@@ -0,0 +1,56 @@
+// Log viewer: searches a CloudWatch Logs group over a recent time range.
+// Uses dashApp.queryCloudWatchLogEvents().
+
+const $ = (id) => document.getElementById(id);
+
+function setStatus(message, isError = false) {
+  $('status').textContent = message;
+  $('status').className = isError ? 'status error' : 'status';
+}
+
+function escapeHtml(text) {
+  const div = document.createElement('div');
+  div.textContent = text == null ? '' : String(text);
+  return div.innerHTML;
+}
+
+async function loadEvents() {
+  const logGroupName = $('log-group').value.trim();
+  if (!logGroupName) {
+    setStatus('Enter a log group name', true);
+    return;
+  }
+
+  setStatus('Searching ' + logGroupName + '...');
+  try {
+    const result = await dashApp.queryCloudWatchLogEvents({
+      logGroupName,
+      accountId: $('account').value,
+      region: $('region').value,
+      filterPattern: $('filter-pattern').value || undefined,
+      startTime: Date.now() - Number($('range').value) * 60 * 1000,
+      limit: 500
+    });
+
+    $('rows').innerHTML = result.events.map(e => `
+      <tr>
+        <td>${escapeHtml(new Date(e.timestamp).toLocaleString())}</td>
+        <td>${escapeHtml(e.logStreamName)}</td>
+        <td><pre class="log">${escapeHtml(e.message)}</pre></td>
+      </tr>
+    `).join('');
+    setStatus(`${result.events.length} events`);
+  } catch (error) {
+    setStatus('Failed to query logs: ' + error.message, true);
+  }
+}
+
+async function init() {
+  const [accounts, regions] = await Promise.all([dashApp.listAccounts(), dashApp.listRegions()]);
+  accounts.forEach(a => $('account').add(new Option(`${a.name} (${a.id})`, a.id)));
+  regions.forEach(r => $('region').add(new Option(r.code, r.code)));
+  $('region').value = 'us-east-1';
+  $('load').addEventListener('click', loadEvents);
+}
+
+init().catch(error => setStatus('Failed to initialize: ' + error.message, true));
@@ -0,0 +1,80 @@
+// Resource table: loads one resource type into the cache and lists it.
+// Uses dashApp.loadCache() and dashApp.streamCachedResources(), rendering
+// each page of results as it arrives.
+
+const state = { resources: [], sortKey: 'displayName', ascending: true };
+
+const $ = (id) => document.getElementById(id);
+
+function setStatus(message, isError = false) {
+  $('status').textContent = message;
+  $('status').className = isError ? 'status error' : 'status';
+}
+
+function escapeHtml(text) {
+  const div = document.createElement('div');
+  div.textContent = text == null ? '' : String(text);
+  return div.innerHTML;
+}
+
+async function loadFilters() {
+  const [accounts, regions] = await Promise.all([dashApp.listAccounts(), dashApp.listRegions()]);
+  accounts.forEach(a => $('account').add(new Option(`${a.name} (${a.id})`, a.id)));
+  regions.forEach(r => $('region').add(new Option(`${r.code} - ${r.name}`, r.code)));
+}
+
+async function loadResources() {
+  const resourceTypes = [$('resource-type').value.trim()];
+  const accounts = $('account').value ? [$('account').value] : null;
+  const regions = $('region').value ? [$('region').value] : null;
+
+  setStatus('Loading ' + resourceTypes[0] + '...');
+  try {
+    await dashApp.loadCache({ accounts, regions, resourceTypes });
+    state.resources = [];
+    for await (const page of dashApp.streamCachedResources({ accounts, regions, resourceTypes })) {
+      state.resources.push(...page.resources);
+      setStatus(`${state.resources.length} of ${page.totalCount} resources`);
+      render();
+    }
+    if (state.resources.length === 0) {
+      setStatus('No resources found');
+      render();
+    }
+  } catch (error) {
+    setStatus('Failed to load resources: ' + error.message, true);
+  }
+}
+
+function render() {
+  const filter = $('filter').value.toLowerCase();
+  const rows = state.resources
+    .filter(r => !filter || JSON.stringify([r.displayName, r.resourceId, r.status]).toLowerCase().includes(filter))
+    .sort((a, b) => {
+      const order = String(a[state.sortKey] ?? '').localeCompare(String(b[state.sortKey] ?? ''));
+      return state.ascending ? order : -order;
+    });
+
+  $('rows').innerHTML = rows.map(r => `
+    <tr>
+      <td>${escapeHtml(r.displayName)}</td>
+      <td>${escapeHtml(r.resourceId)}</td>
+      <td>${escapeHtml(r.accountId)}</td>
+      <td>${escapeHtml(r.region)}</td>
+      <td>${escapeHtml(r.status || '')}</td>
+    </tr>
+  `).join('');
+}
+
+document.querySelectorAll('th[data-key]').forEach(th => {
+  th.addEventListener('click', () => {
+    state.ascending = state.sortKey === th.dataset.key ? !state.ascending : true;
+    state.sortKey = th.dataset.key;
+    render();
+  });
+});
+
+$('load').addEventListener('click', loadResources);
+$('filter').addEventListener('input', render);
+
+loadFilters().catch(error => setStatus('Failed to list accounts: ' + error.message, true));
@@ -1,6 +1,8 @@
 //! Pages Manager Window - Manage Dash Pages
 //!
 //! A webview-based window for viewing, editing, and deleting Dash Pages.
+//! Includes a template gallery for new pages and a workspace-wide Find &
+//! Replace panel with preview-before-replace.
 //! Supports Catppuccin dark and light themes.
 
 #![warn(clippy::all, rust_2018_idioms)]
@@ -429,6 +431,106 @@ pub fn generate_pages_manager_html(is_dark_theme: bool) -> String {
             background: var(--warning-color);
             color: var(--background-color);
         }
+
+        .replace-panel {
+            display: none;
+            padding: 16px;
+            margin-bottom: 16px;
+        }
+
+        .replace-panel.visible {
+            display: block;
+        }
+
+        .template-grid {
+            display: grid;
+            grid-template-columns: repeat(auto-fill, minmax(220px, 1fr));
+            gap: 12px;
+        }
+
+        .template-card {
+            border: 1px solid var(--border-color);
+            border-radius: 6px;
+            padding: 12px;
+            display: flex;
+            flex-direction: column;
+            gap: 8px;
+        }
+
+        .template-card h4 {
+            margin: 0;
+        }
+
+        .workspace-row {
+            display: flex;
+            gap: 8px;
+            align-items: center;
+            padding: 6px 0;
+            border-bottom: 1px solid var(--border-color);
+            flex-wrap: wrap;
+        }
+
+        .workspace-row .workspace-label {
+            flex: 1;
+        }
+
+        .capability-revoked .workspace-label code {
+            text-decoration: line-through;
+        }
+
+        .replace-row {
+            display: flex;
+            gap: 8px;
+            align-items: center;
+            margin-bottom: 8px;
+            flex-wrap: wrap;
+        }
+
+        .replace-row .search-input {
+            max-width: none;
+            flex: 1;
+        }
+
+        .replace-options label {
+            font-size: 13px;
+            color: var(--text-secondary);
+            display: inline-flex;
+            align-items: center;
+            gap: 4px;
+        }
+
+        .replace-results {
+            max-height: 360px;
+            overflow-y: auto;
+            font-family: ui-monospace, SFMono-Regular, Menlo, monospace;
+            font-size: 12px;
+        }
+
+        .replace-file {
+            margin-top: 12px;
+        }
+
+        .replace-file-header {
+            display: flex;
+            gap: 8px;
+            align-items: center;
+            font-weight: 500;
+            color: var(--primary-color);
+        }
+
+        .replace-line {
+            padding: 2px 8px;
+            white-space: pre-wrap;
+            word-break: break-all;
+        }
+
+        .replace-line.before {
+            color: var(--error-color);
+        }
+
+        .replace-line.after {
+            color: var(--success-color);
+        }
     </style>
 </head>
 <body>
@@ -436,10 +538,54 @@ pub fn generate_pages_manager_html(is_dark_theme: bool) -> String {
         <div class="header">
             <h1><span class="icon">&#9889;</span> AWS Dash Pages</h1>
             <div class="header-actions">
+                <button class="btn btn-primary mutating" onclick="toggleTemplatesPanel()">New from Template</button>
+                <button class="btn" onclick="toggleWorkspacesPanel()">Saved Workspaces</button>
+                <button class="btn" onclick="toggleReplacePanel()">Find &amp; Replace</button>
                 <button class="btn" onclick="refreshPages()">Refresh</button>
             </div>
         </div>
 
+        <div id="replace-panel" class="card replace-panel">
+            <div class="replace-row">
+                <input type="text" id="find-input" class="search-input" placeholder="Find in all pages (HTML/JS/CSS)...">
+                <button class="btn" onclick="runFind()">Find</button>
+            </div>
+            <div class="replace-row">
+                <input type="text" id="replace-input" class="search-input mutating" placeholder="Replace with...">
+                <button class="btn btn-warning mutating" onclick="runPreviewReplace()">Preview Replace</button>
+                <button id="apply-replace-btn" class="btn btn-danger mutating" onclick="applyReplace()" disabled>Apply</button>
+            </div>
+            <div class="replace-row replace-options">
+                <label><input type="checkbox" id="opt-regex"> Regex</label>
+                <label><input type="checkbox" id="opt-case"> Match case</label>
+                <label><input type="checkbox" id="opt-vfs"> Include agent VFS pages</label>
+            </div>
+            <div id="replace-summary" class="search-results-info"></div>
+            <div id="replace-results" class="replace-results"></div>
+        </div>
+
+        <div id="templates-panel" class="card replace-panel">
+            <div class="replace-row">
+                <input type="text" id="template-page-name" class="search-input" placeholder="New page name...">
+            </div>
+            <div id="templates-list" class="template-grid"></div>
+        </div>
+
+        <div id="capabilities-panel" class="card replace-panel">
+            <div class="replace-row">
+                <span class="workspace-label">API capabilities of <strong id="capabilities-page-name"></strong></span>
+                <span id="capabilities-summary" class="text-secondary"></span>
+                <button class="btn btn-sm btn-danger" onclick="revokeTokens()">Revoke Tokens</button>
+                <button class="btn btn-sm" onclick="closeCapabilitiesPanel()">Close</button>
+            </div>
+            <div id="capabilities-list"></div>
+        </div>
+
+        <div id="workspaces-panel" class="card replace-panel">
+            <div class="search-results-info">Agent VFS workspaces saved with Save Snapshot in the VFS browser</div>
+            <div id="workspaces-list"></div>
+        </div>
+
         <div class="search-container">
             <span class="agent-hint">Use an Agent to create or edit pages</span>
             <input type="text" id="search-input" class="search-input" placeholder="Search pages..." oninput="onSearch(this.value)">
@@ -670,8 +816,11 @@ pub fn generate_pages_manager_html(is_dark_theme: bool) -> String {
                     <td><span class="size-badge">${formatSize(page.totalSize)}</span></td>
                     <td>
                         <div class="actions">
-                            <button class="btn btn-sm btn-warning" onclick="renamePage('${escapeHtml(page.name)}')">Rename</button>
-                            <button class="btn btn-sm btn-icon btn-danger" onclick="deletePage('${escapeHtml(page.name)}')" title="Delete">&#128465;</button>
+                            <button class="btn btn-sm" onclick="showCapabilities('${escapeHtml(page.name)}')" title="Review and revoke API access">Capabilities</button>
+                            <button class="btn btn-sm" onclick="exportPage('${escapeHtml(page.name)}', 'zip')" title="Export as zip with mock data">Export ZIP</button>
+                            <button class="btn btn-sm" onclick="exportPage('${escapeHtml(page.name)}', 'html')" title="Export as a single HTML file">Export HTML</button>
+                            <button class="btn btn-sm btn-warning mutating" onclick="renamePage('${escapeHtml(page.name)}')">Rename</button>
+                            <button class="btn btn-sm btn-icon btn-danger mutating" onclick="deletePage('${escapeHtml(page.name)}')" title="Delete">&#128465;</button>
                         </div>
                     </td>
                 </tr>
@@ -722,6 +871,17 @@ pub fn generate_pages_manager_html(is_dark_theme: bool) -> String {
             }
         }
 
+        // Export page as a standalone bundle
+        async function exportPage(pageName, format) {
+            try {
+                const result = await window.dashApp.exportPage(pageName, format);
+                showStatus('Exported ' + pageName + ' to ' + result.path);
+            } catch (error) {
+                console.error('Failed to export page:', error);
+                showStatus('Failed to export page: ' + error.message, true);
+            }
+        }
+
         // Delete page - show confirmation modal
         function deletePage(pageName) {
             pageToDelete = pageName;
@@ -820,6 +980,297 @@ pub fn generate_pages_manager_html(is_dark_theme: bool) -> String {
             }
         });
 
+        // ========== Find & Replace ==========
+
+        let replacePreview = [];
+
+        function toggleReplacePanel() {
+            const panel = document.getElementById('replace-panel');
+            panel.classList.toggle('visible');
+            if (panel.classList.contains('visible')) {
+                document.getElementById('find-input').focus();
+            }
+        }
+
+        function currentQuery() {
+            return {
+                pattern: document.getElementById('find-input').value,
+                isRegex: document.getElementById('opt-regex').checked,
+                caseSensitive: document.getElementById('opt-case').checked,
+                includeVfs: document.getElementById('opt-vfs').checked,
+            };
+        }
+
+        function fileLabel(file) {
+            const prefix = file.source.kind === 'vfs' ? '[VFS] ' : '';
+            return prefix + file.pageName + '/' + file.filePath;
+        }
+
+        function clearPreview() {
+            replacePreview = [];
+            document.getElementById('apply-replace-btn').disabled = true;
+        }
+
+        async function runFind() {
+            const query = currentQuery();
+            if (!query.pattern) return;
+            clearPreview();
+
+            try {
+                const result = await window.dashApp.searchPages(query);
+                document.getElementById('replace-summary').textContent =
+                    `${result.matches.length} matches in ${result.filesMatched} of ${result.filesSearched} files` +
+                    (result.truncated ? ' (truncated)' : '');
+
+                document.getElementById('replace-results').innerHTML = result.matches.map(m => `
+                    <div class="replace-line">
+                        <span class="text-secondary">${escapeHtml(fileLabel(m.file))}:${m.lineNumber}:${m.column}</span>
+                        ${escapeHtml(m.lineText.trim())}
+                    </div>
+                `).join('');
+            } catch (error) {
+                console.error('Search failed:', error);
+                showStatus('Search failed: ' + error.message, true);
+            }
+        }
+
+        async function runPreviewReplace() {
+            const query = currentQuery();
+            if (!query.pattern) return;
+            const replacement = document.getElementById('replace-input').value;
+
+            try {
+                replacePreview = await window.dashApp.previewReplaceInPages(query, replacement);
+                const total = replacePreview.reduce((n, p) => n + p.replacementCount, 0);
+                document.getElementById('replace-summary').textContent =
+                    `${total} replacements in ${replacePreview.length} files - uncheck files to skip, then Apply`;
+
+                document.getElementById('replace-results').innerHTML = replacePreview.map((p, i) => `
+                    <div class="replace-file">
+                        <div class="replace-file-header">
+                            <input type="checkbox" class="replace-select" data-index="${i}" checked>
+                            ${escapeHtml(fileLabel(p.file))} (${p.replacementCount})
+                        </div>
+                        ${p.changes.map(c => `
+                            <div class="replace-line before">${c.lineNumber} - ${escapeHtml(c.before.trim())}</div>
+                            <div class="replace-line after">${c.lineNumber} + ${escapeHtml(c.after.trim())}</div>
+                        `).join('')}
+                    </div>
+                `).join('');
+
+                document.getElementById('apply-replace-btn').disabled = replacePreview.length === 0;
+            } catch (error) {
+                console.error('Preview failed:', error);
+                showStatus('Preview failed: ' + error.message, true);
+            }
+        }
+
+        async function applyReplace() {
+            if (replacePreview.length === 0) return;
+
+            const selected = Array.from(document.querySelectorAll('.replace-select'))
+                .filter(cb => cb.checked)
+                .map(cb => replacePreview[Number(cb.dataset.index)].file);
+
+            if (selected.length === 0) {
+                showStatus('No files selected', true);
+                return;
+            }
+
+            const query = currentQuery();
+            const replacement = document.getElementById('replace-input').value;
+
+            try {
+                const summary = await window.dashApp.replaceInPages(query, replacement, selected);
+                clearPreview();
+                document.getElementById('replace-results').innerHTML = '';
+                document.getElementById('replace-summary').textContent = '';
+
+                if (summary.failures.length > 0) {
+                    showStatus(`Replaced ${summary.replacements} in ${summary.filesUpdated} files, ` +
+                        `${summary.failures.length} failed: ${summary.failures.map(f => f[1]).join('; ')}`, true);
+                } else {
+                    showStatus(`Replaced ${summary.replacements} occurrences in ${summary.filesUpdated} files`);
+                }
+
+                await loadPages();
+            } catch (error) {
+                console.error('Replace failed:', error);
+                showStatus('Replace failed: ' + error.message, true);
+            }
+        }
+
+        // ========== Template Gallery ==========
+
+        function toggleTemplatesPanel() {
+            const panel = document.getElementById('templates-panel');
+            panel.classList.toggle('visible');
+            if (panel.classList.contains('visible')) {
+                loadTemplates();
+            }
+        }
+
+        async function loadTemplates() {
+            const list = document.getElementById('templates-list');
+            try {
+                const templates = await window.dashApp.listPageTemplates();
+                list.innerHTML = templates.map(t => `
+                    <div class="template-card">
+                        <h4>${escapeHtml(t.name)}</h4>
+                        <span class="text-secondary">${escapeHtml(t.description)}</span>
+                        <span class="text-secondary">Uses ${t.apiMethods.map(escapeHtml).join(', ')}</span>
+                        <button class="btn btn-sm btn-primary mutating" onclick="createFromTemplate('${escapeHtml(t.id)}', '${escapeHtml(t.name)}')">Create Page</button>
+                    </div>
+                `).join('');
+            } catch (error) {
+                console.error('Failed to list templates:', error);
+                showStatus('Failed to list templates: ' + error.message, true);
+            }
+        }
+
+        // Create a page from a template and open it
+        async function createFromTemplate(templateId, templateName) {
+            const input = document.getElementById('template-page-name');
+            const name = input.value.trim() || templateName;
+            try {
+                const result = await window.dashApp.createPageFromTemplate(templateId, name);
+                input.value = '';
+                showStatus(result.message);
+                await loadPages();
+                await viewPage(result.pageName);
+            } catch (error) {
+                console.error('Failed to create page:', error);
+                showStatus('Failed to create page: ' + error.message, true);
+            }
+        }
+
+        // ========== Page Capabilities ==========
+
+        let capabilitiesPage = null;
+
+        async function showCapabilities(pageName) {
+            capabilitiesPage = pageName;
+            document.getElementById('capabilities-page-name').textContent = pageName;
+            document.getElementById('capabilities-panel').classList.add('visible');
+            await loadCapabilities();
+        }
+
+        function closeCapabilitiesPanel() {
+            capabilitiesPage = null;
+            document.getElementById('capabilities-panel').classList.remove('visible');
+        }
+
+        async function loadCapabilities() {
+            const list = document.getElementById('capabilities-list');
+            try {
+                const report = await window.dashApp.getPageCapabilities(capabilitiesPage);
+                document.getElementById('capabilities-summary').textContent =
+                    (report.declared ? 'Declared by page' : 'Not declared - read-only defaults') +
+                    ` \u00b7 ${report.activeTokens} open window${report.activeTokens !== 1 ? 's' : ''}`;
+                list.innerHTML = report.capabilities.filter(c => c.requested || c.revoked).map(c => `
+                    <div class="workspace-row ${c.revoked ? 'capability-revoked' : ''}">
+                        <span class="workspace-label">
+                            <code>${escapeHtml(c.id)}</code>
+                            <span class="text-secondary">${escapeHtml(c.description)}</span>
+                        </span>
+                        ${c.write ? '<span class="size-badge">write</span>' : ''}
+                        <button class="btn btn-sm ${c.revoked ? '' : 'btn-warning'}"
+                            onclick="setCapabilityRevoked('${escapeHtml(c.id)}', ${!c.revoked})">${c.revoked ? 'Restore' : 'Revoke'}</button>
+                    </div>
+                `).join('') || '<p class="text-secondary">This page requests no capabilities</p>';
+            } catch (error) {
+                console.error('Failed to load capabilities:', error);
+                showStatus('Failed to load capabilities: ' + error.message, true);
+            }
+        }
+
+        async function setCapabilityRevoked(capability, revoked) {
+            try {
+                const result = await window.dashApp.setPageCapabilityRevoked(capabilitiesPage, capability, revoked);
+                showStatus(result.message);
+                await loadCapabilities();
+            } catch (error) {
+                console.error('Failed to update capability:', error);
+                showStatus('Failed to update capability: ' + error.message, true);
+            }
+        }
+
+        // Cut off every open window of the page until it is reopened
+        async function revokeTokens() {
+            try {
+                const result = await window.dashApp.revokePageTokens(capabilitiesPage);
+                showStatus(result.message);
+                await loadCapabilities();
+            } catch (error) {
+                console.error('Failed to revoke tokens:', error);
+                showStatus('Failed to revoke tokens: ' + error.message, true);
+            }
+        }
+
+        // ========== Saved Workspaces ==========
+
+        function toggleWorkspacesPanel() {
+            const panel = document.getElementById('workspaces-panel');
+            panel.classList.toggle('visible');
+            if (panel.classList.contains('visible')) {
+                loadWorkspaces();
+            }
+        }
+
+        async function loadWorkspaces() {
+            const list = document.getElementById('workspaces-list');
+            try {
+                const snapshots = await window.dashApp.listVfsSnapshots();
+                if (snapshots.length === 0) {
+                    list.innerHTML = '<p class="text-secondary">No saved workspaces</p>';
+                    return;
+                }
+                list.innerHTML = snapshots.map(s => `
+                    <div class="workspace-row">
+                        <span class="workspace-label">
+                            ${escapeHtml(s.label)}
+                            <span class="text-secondary">
+                                ${formatDate(Date.parse(s.savedAt) / 1000)} &middot;
+                                ${s.fileCount} files &middot; ${formatSize(s.totalSize)} &middot;
+                                ${s.pages.length} page${s.pages.length !== 1 ? 's' : ''}
+                            </span>
+                        </span>
+                        <span id="workspace-pages-${escapeHtml(s.sessionId)}"></span>
+                        <button class="btn btn-sm" onclick="reopenWorkspace('${escapeHtml(s.sessionId)}')">Reopen</button>
+                    </div>
+                `).join('');
+            } catch (error) {
+                console.error('Failed to list workspaces:', error);
+                showStatus('Failed to list workspaces: ' + error.message, true);
+            }
+        }
+
+        // Reopen a workspace and list its pages as open buttons
+        async function reopenWorkspace(sessionId) {
+            try {
+                const result = await window.dashApp.reopenVfsSnapshot(sessionId);
+                const target = document.getElementById('workspace-pages-' + sessionId);
+                target.innerHTML = result.workspaces.map(w => `
+                    <button class="btn btn-sm" onclick="openWorkspacePage('${escapeHtml(w)}')">${escapeHtml(w.split(':')[2])}</button>
+                `).join('');
+                showStatus(result.workspaces.length > 0
+                    ? 'Workspace reopened - choose a page to open'
+                    : 'Workspace reopened - it has no pages');
+            } catch (error) {
+                console.error('Failed to reopen workspace:', error);
+                showStatus('Failed to reopen workspace: ' + error.message, true);
+            }
+        }
+
+        async function openWorkspacePage(workspace) {
+            try {
+                await window.dashApp.openPage(workspace);
+            } catch (error) {
+                console.error('Failed to open page:', error);
+                showStatus('Failed to open page: ' + error.message, true);
+            }
+        }
+
         // Close modal on Escape key
         document.addEventListener('keydown', function(e) {
             if (e.key === 'Escape') {
@@ -830,6 +1281,10 @@ pub fn generate_pages_manager_html(is_dark_theme: bool) -> String {
             if (e.key === 'Enter' && document.activeElement.id === 'rename-new-name') {
                 confirmRename();
             }
+            // Run search on Enter in the find box
+            if (e.key === 'Enter' && document.activeElement.id === 'find-input') {
+                runFind();
+            }
         });
 
         // Initialize on load
@@ -852,8 +1307,17 @@ pub fn generate_pages_manager_html(is_dark_theme: bool) -> String {
 </body>
 </html>"##;
 
-    // Concatenate all parts: html_start + theme_css + css_rest
-    html_start.to_string() + theme_css + css_rest
+    // Read-only builds hide every control that changes pages
+    let read_only_css = if crate::app::capabilities::READ_ONLY {
+        r#"
+        .mutating { display: none !important; }
+    "#
+    } else {
+        ""
+    };
+
+    // Concatenate all parts: html_start + theme_css + read_only_css + css_rest
+    html_start.to_string() + theme_css + read_only_css + css_rest
 }
 
 /// Spawn a Pages Manager webview window
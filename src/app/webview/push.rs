@@ -0,0 +1,370 @@
+//! Live update channel for Dash Pages
+//!
+//! The webview API is request/response; this module lets the main process
+//! push events to pages instead. Producers call `publish()` with a topic and
+//! a JSON payload, and the API server streams matching events to pages that
+//! subscribed with `GET /api/events?topics=...` (Server-Sent Events).
+//!
+//! # Topics
+//!
+//! - `query.progress`: `loadCache` started, completed, or failed
+//! - `agent.status`: an agent started or finished processing, failed, or was cancelled
+//! - `logs.{logGroupName}`: new events from a log tail started with `tailLogEvents`
+//!
+//! Subscriptions match a topic exactly, by prefix (`logs.*`), or everything (`*`).
+//! Publishing is cheap when nobody is subscribed, so producers need not check.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// Events buffered per subscriber before it starts missing events
+const CHANNEL_CAPACITY: usize = 256;
+
+/// Topic for resource query progress
+pub const QUERY_PROGRESS_TOPIC: &str = "query.progress";
+
+/// Topic for agent status changes
+pub const AGENT_STATUS_TOPIC: &str = "agent.status";
+
+/// How often a log tail polls CloudWatch Logs
+const LOG_TAIL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How long a log tail waits for its first subscriber before stopping
+const LOG_TAIL_CONNECT_GRACE: Duration = Duration::from_secs(30);
+
+/// Most events fetched per log tail poll
+const LOG_TAIL_MAX_EVENTS: usize = 500;
+
+/// Topic for new events of a log group
+pub fn log_topic(log_group_name: &str) -> String {
+    format!("logs.{}", log_group_name)
+}
+
+/// One event pushed to subscribed pages
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PushEvent {
+    pub topic: String,
+    pub data: serde_json::Value,
+    /// Unix milliseconds when the event was published
+    pub timestamp: i64,
+}
+
+/// Outcome of waiting for the next event
+#[derive(Debug)]
+pub enum PushMessage {
+    Event(PushEvent),
+    /// The subscriber fell behind and this many events were dropped
+    Lagged(u64),
+}
+
+/// Broadcast hub with a registry of active topic filters
+struct PushHub {
+    sender: broadcast::Sender<PushEvent>,
+    subscriptions: Mutex<HashMap<u64, Vec<String>>>,
+    next_id: AtomicU64,
+}
+
+impl PushHub {
+    fn new() -> Self {
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+        Self {
+            sender,
+            subscriptions: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    fn has_subscribers(&self, topic: &str) -> bool {
+        self.subscriptions
+            .lock()
+            .map(|subs| {
+                subs.values()
+                    .any(|filters| filters.iter().any(|f| topic_matches(f, topic)))
+            })
+            .unwrap_or(false)
+    }
+
+    fn publish(&self, topic: &str, data: serde_json::Value) -> bool {
+        if !self.has_subscribers(topic) {
+            return false;
+        }
+        let event = PushEvent {
+            topic: topic.to_string(),
+            data,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+        };
+        self.sender.send(event).is_ok()
+    }
+
+    fn subscribe(&'static self, filters: Vec<String>) -> Subscription {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let receiver = self.sender.subscribe();
+        if let Ok(mut subs) = self.subscriptions.lock() {
+            subs.insert(id, filters.clone());
+        }
+        Subscription {
+            hub: self,
+            id,
+            filters,
+            receiver,
+        }
+    }
+}
+
+static PUSH_HUB: OnceLock<PushHub> = OnceLock::new();
+
+fn hub() -> &'static PushHub {
+    PUSH_HUB.get_or_init(PushHub::new)
+}
+
+/// Check whether a subscription filter matches a topic
+///
+/// `*` matches everything, `prefix.*` matches topics under `prefix.`, and any
+/// other filter must equal the topic.
+pub fn topic_matches(filter: &str, topic: &str) -> bool {
+    if filter == "*" {
+        return true;
+    }
+    match filter.strip_suffix('*') {
+        Some(prefix) => topic.starts_with(prefix),
+        None => filter == topic,
+    }
+}
+
+/// Parse a comma-separated topic list (empty entries are ignored)
+pub fn parse_topics(topics: &str) -> Vec<String> {
+    topics
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Publish an event to every page subscribed to `topic`
+///
+/// Returns false when no page is subscribed (the event is dropped).
+pub fn publish(topic: &str, data: serde_json::Value) -> bool {
+    hub().publish(topic, data)
+}
+
+/// Check whether any page is subscribed to `topic`
+pub fn has_subscribers(topic: &str) -> bool {
+    hub().has_subscribers(topic)
+}
+
+/// Subscribe to the topics matching `filters`
+pub fn subscribe(filters: Vec<String>) -> Subscription {
+    hub().subscribe(filters)
+}
+
+/// A page's live subscription; unregisters itself when dropped
+pub struct Subscription {
+    hub: &'static PushHub,
+    id: u64,
+    filters: Vec<String>,
+    receiver: broadcast::Receiver<PushEvent>,
+}
+
+impl Subscription {
+    /// Wait for the next event matching this subscription
+    ///
+    /// Returns None once the hub is gone (never, in practice).
+    pub async fn recv(&mut self) -> Option<PushMessage> {
+        loop {
+            match self.receiver.recv().await {
+                Ok(event) => {
+                    if self.filters.iter().any(|f| topic_matches(f, &event.topic)) {
+                        return Some(PushMessage::Event(event));
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    return Some(PushMessage::Lagged(skipped));
+                }
+                Err(broadcast::error::RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Topic filters of this subscription
+    pub fn filters(&self) -> &[String] {
+        &self.filters
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Ok(mut subs) = self.hub.subscriptions.lock() {
+            subs.remove(&self.id);
+        }
+    }
+}
+
+/// Log groups with a running tail, by account, region, and log group
+static LOG_TAILS: OnceLock<Mutex<HashSet<(String, String, String)>>> = OnceLock::new();
+
+fn log_tails() -> &'static Mutex<HashSet<(String, String, String)>> {
+    LOG_TAILS.get_or_init(|| Mutex::new(HashSet::new()))
+}
+
+/// Start pushing new events of a log group to `logs.{logGroupName}`
+///
+/// Polls every few seconds from now onwards and stops by itself once no page
+/// is subscribed to the topic. Returns false if a tail for the same log group
+/// is already running.
+pub fn start_log_tail(account_id: String, region: String, log_group_name: String) -> bool {
+    let key = (account_id.clone(), region.clone(), log_group_name.clone());
+    match log_tails().lock() {
+        Ok(mut tails) if tails.insert(key.clone()) => {}
+        _ => return false,
+    }
+
+    tokio::spawn(async move {
+        if let Err(e) = run_log_tail(&account_id, &region, &log_group_name).await {
+            tracing::warn!("Log tail for {} stopped: {:#}", log_group_name, e);
+            publish(
+                &log_topic(&log_group_name),
+                serde_json::json!({ "error": format!("{:#}", e) }),
+            );
+        }
+        if let Ok(mut tails) = log_tails().lock() {
+            tails.remove(&key);
+        }
+    });
+
+    true
+}
+
+/// Poll a log group and publish new events until nobody is subscribed
+async fn run_log_tail(account_id: &str, region: &str, log_group_name: &str) -> anyhow::Result<()> {
+    use crate::app::data_plane::cloudwatch_logs::{CloudWatchLogsClient, TailCursor};
+
+    let aws_client = crate::app::agent_framework::utils::registry::get_global_aws_client()
+        .ok_or_else(|| anyhow::anyhow!("AWS client not initialized"))?;
+    let client = CloudWatchLogsClient::new(aws_client.get_credential_coordinator());
+
+    let topic = log_topic(log_group_name);
+    let started = tokio::time::Instant::now();
+    let mut cursor = TailCursor::starting_at(chrono::Utc::now().timestamp_millis());
+
+    tracing::info!(
+        "Started log tail for {} ({}, {})",
+        log_group_name,
+        account_id,
+        region
+    );
+
+    loop {
+        tokio::time::sleep(LOG_TAIL_INTERVAL).await;
+
+        if !has_subscribers(&topic) {
+            if started.elapsed() < LOG_TAIL_CONNECT_GRACE {
+                continue;
+            }
+            tracing::info!("Stopped log tail for {}: no subscribers", log_group_name);
+            return Ok(());
+        }
+
+        let events = client
+            .tail_log_events(
+                account_id,
+                region,
+                log_group_name,
+                cursor.start_time(),
+                LOG_TAIL_MAX_EVENTS,
+            )
+            .await?;
+
+        let events: Vec<serde_json::Value> = cursor
+            .accept(events)
+            .into_iter()
+            .map(|e| {
+                serde_json::json!({
+                    "timestamp": e.timestamp,
+                    "message": e.message,
+                    "logStreamName": e.log_stream_name,
+                })
+            })
+            .collect();
+
+        if !events.is_empty() {
+            publish(
+                &topic,
+                serde_json::json!({ "logGroupName": log_group_name, "events": events }),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_topic_matching() {
+        assert!(topic_matches("*", "agent.status"));
+        assert!(topic_matches("agent.status", "agent.status"));
+        assert!(!topic_matches("agent.status", "agent.statusx"));
+        assert!(topic_matches("logs.*", "logs./aws/lambda/fn"));
+        assert!(!topic_matches("logs.*", "query.progress"));
+
+        assert_eq!(
+            parse_topics(" query.progress, ,logs.*"),
+            vec!["query.progress", "logs.*"]
+        );
+    }
+
+    /// A hub of its own, so parallel tests do not see each other's events
+    fn test_hub() -> &'static PushHub {
+        Box::leak(Box::new(PushHub::new()))
+    }
+
+    #[tokio::test]
+    async fn test_subscribers_only_receive_matching_topics() {
+        let hub = test_hub();
+        let topic = "query.progress";
+        assert!(!hub.publish(topic, json!(1)), "no subscribers yet");
+
+        let mut subscription = hub.subscribe(vec!["query.*".to_string()]);
+        assert!(hub.has_subscribers(topic));
+        assert!(!hub.has_subscribers("agent.status"));
+
+        assert!(!hub.publish("agent.status", json!("ignored")));
+        assert!(hub.publish(topic, json!({ "n": 2 })));
+
+        match subscription.recv().await {
+            Some(PushMessage::Event(event)) => {
+                assert_eq!(event.topic, topic);
+                assert_eq!(event.data, json!({ "n": 2 }));
+            }
+            other => panic!("unexpected message: {:?}", other),
+        }
+
+        drop(subscription);
+        assert!(!hub.has_subscribers(topic));
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_is_told_it_lagged() {
+        let hub = test_hub();
+        let topic = "agent.status";
+        let mut subscription = hub.subscribe(vec![topic.to_string()]);
+
+        for n in 0..CHANNEL_CAPACITY + 10 {
+            hub.publish(topic, json!(n));
+        }
+
+        match subscription.recv().await {
+            Some(PushMessage::Lagged(skipped)) => assert_eq!(skipped, 10),
+            other => panic!("expected lag notice, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,228 @@
+//! Cursor pagination for large resource query results
+//!
+//! `queryCachedResources` returns every matching resource in one response,
+//! which can be tens of megabytes for large estates. `queryCachedResourcesPaged`
+//! instead runs the query once, keeps the results here, and returns the first
+//! page with a cursor; pages fetch the rest with `fetchResourcePage`.
+//!
+//! Each page is handed out once and dropped from memory. Cursors that are not
+//! read for [`CURSOR_TTL`] expire, and only the [`MAX_CURSORS`] most recently
+//! read are kept.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// Resources per page when the caller does not choose
+pub const DEFAULT_PAGE_SIZE: usize = 1000;
+
+/// Largest page size a caller can ask for
+pub const MAX_PAGE_SIZE: usize = 10_000;
+
+/// How long an unread cursor is kept
+const CURSOR_TTL: Duration = Duration::from_secs(300);
+
+/// Most cursors kept at once (oldest are dropped first)
+const MAX_CURSORS: usize = 16;
+
+/// One page of a paginated result
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourcePage {
+    pub resources: Vec<serde_json::Value>,
+    /// Index of the first resource of this page in the whole result
+    pub offset: usize,
+    /// Resources in the whole result
+    pub total_count: usize,
+    /// Cursor for the next page (None on the last page)
+    pub next_cursor: Option<String>,
+}
+
+/// Results not handed out yet
+struct Cursor {
+    remaining: VecDeque<serde_json::Value>,
+    offset: usize,
+    total_count: usize,
+    page_size: usize,
+    last_read: Instant,
+    /// Read order, for evicting the least recently read cursor
+    sequence: u64,
+}
+
+/// Open cursors by ID
+struct CursorStore {
+    cursors: HashMap<String, Cursor>,
+    next_sequence: u64,
+}
+
+impl CursorStore {
+    fn new() -> Self {
+        Self {
+            cursors: HashMap::new(),
+            next_sequence: 0,
+        }
+    }
+
+    fn open(&mut self, resources: Vec<serde_json::Value>, page_size: usize) -> ResourcePage {
+        self.expire();
+        while self.cursors.len() >= MAX_CURSORS {
+            let oldest = self
+                .cursors
+                .iter()
+                .min_by_key(|(_, c)| c.sequence)
+                .map(|(id, _)| id.clone());
+            match oldest {
+                Some(id) => self.cursors.remove(&id),
+                None => break,
+            };
+        }
+
+        let total_count = resources.len();
+        let cursor = Cursor {
+            remaining: resources.into(),
+            offset: 0,
+            total_count,
+            page_size: page_size.clamp(1, MAX_PAGE_SIZE),
+            last_read: Instant::now(),
+            sequence: 0,
+        };
+        self.take_page(uuid::Uuid::new_v4().to_string(), cursor)
+    }
+
+    fn next(&mut self, id: &str) -> Result<ResourcePage> {
+        self.expire();
+        let cursor = self
+            .cursors
+            .remove(id)
+            .ok_or_else(|| anyhow!("Cursor not found or expired: {}", id))?;
+        Ok(self.take_page(id.to_string(), cursor))
+    }
+
+    /// Hand out the next page, keeping the cursor only if more remain
+    fn take_page(&mut self, id: String, mut cursor: Cursor) -> ResourcePage {
+        let count = cursor.page_size.min(cursor.remaining.len());
+        let resources: Vec<_> = cursor.remaining.drain(..count).collect();
+        let offset = cursor.offset;
+        let total_count = cursor.total_count;
+
+        let next_cursor = if cursor.remaining.is_empty() {
+            None
+        } else {
+            cursor.offset += count;
+            cursor.last_read = Instant::now();
+            cursor.sequence = self.next_sequence;
+            self.next_sequence += 1;
+            self.cursors.insert(id.clone(), cursor);
+            Some(id)
+        };
+
+        ResourcePage {
+            resources,
+            offset,
+            total_count,
+            next_cursor,
+        }
+    }
+
+    fn close(&mut self, id: &str) -> bool {
+        self.cursors.remove(id).is_some()
+    }
+
+    fn expire(&mut self) {
+        self.cursors
+            .retain(|_, cursor| cursor.last_read.elapsed() < CURSOR_TTL);
+    }
+}
+
+static CURSOR_STORE: OnceLock<Mutex<CursorStore>> = OnceLock::new();
+
+fn store() -> std::sync::MutexGuard<'static, CursorStore> {
+    CURSOR_STORE
+        .get_or_init(|| Mutex::new(CursorStore::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Keep `resources` behind a cursor and return the first page
+///
+/// `page_size` is clamped to 1..=[`MAX_PAGE_SIZE`].
+pub fn open_cursor(resources: Vec<serde_json::Value>, page_size: usize) -> ResourcePage {
+    store().open(resources, page_size)
+}
+
+/// Return the next page of a cursor
+pub fn next_page(cursor: &str) -> Result<ResourcePage> {
+    store().next(cursor)
+}
+
+/// Drop a cursor before it is read to the end; returns false if it was unknown
+pub fn close_cursor(cursor: &str) -> bool {
+    store().close(cursor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn resources(n: usize) -> Vec<serde_json::Value> {
+        (0..n).map(|i| json!({ "resourceId": i })).collect()
+    }
+
+    #[test]
+    fn test_pages_cover_result_in_order() {
+        let mut store = CursorStore::new();
+
+        let mut page = store.open(resources(25), 10);
+        let mut seen = Vec::new();
+        let mut offsets = Vec::new();
+        loop {
+            assert_eq!(page.total_count, 25);
+            offsets.push(page.offset);
+            seen.extend(
+                page.resources
+                    .iter()
+                    .map(|r| r["resourceId"].as_u64().unwrap()),
+            );
+            match page.next_cursor {
+                Some(cursor) => page = store.next(&cursor).unwrap(),
+                None => break,
+            }
+        }
+
+        assert_eq!(offsets, vec![0, 10, 20]);
+        assert_eq!(seen, (0..25).collect::<Vec<u64>>());
+        assert!(store.cursors.is_empty(), "finished cursors are dropped");
+    }
+
+    #[test]
+    fn test_small_results_need_no_cursor() {
+        let mut store = CursorStore::new();
+        let page = store.open(resources(3), 10);
+        assert_eq!(page.resources.len(), 3);
+        assert!(page.next_cursor.is_none());
+
+        let empty = store.open(Vec::new(), 0);
+        assert!(empty.resources.is_empty());
+        assert!(empty.next_cursor.is_none());
+    }
+
+    #[test]
+    fn test_closed_and_evicted_cursors_are_gone() {
+        let mut store = CursorStore::new();
+        let first = store.open(resources(5), 1).next_cursor.unwrap();
+        assert!(store.close(&first));
+        assert!(store.next(&first).is_err());
+
+        let cursors: Vec<String> = (0..MAX_CURSORS + 1)
+            .map(|_| store.open(resources(2), 1).next_cursor.unwrap())
+            .collect();
+        assert_eq!(store.cursors.len(), MAX_CURSORS);
+        assert!(store.next(&cursors[0]).is_err(), "oldest cursor is evicted");
+        assert!(store.next(&cursors[MAX_CURSORS]).is_ok());
+    }
+}
@@ -0,0 +1,335 @@
+//! Registry of webview windows spawned by the main process
+//!
+//! Every webview runs in its own process. The registry keeps the child process
+//! of each one so the main app can list, focus, reload, and close page windows
+//! instead of losing track of them once spawned.
+//!
+//! # Heartbeat
+//!
+//! Each webview process gets a random window ID in `AWSDASH_WEBVIEW_ID` and posts
+//! it to `POST /api/webview/heartbeat` every [`HEARTBEAT_INTERVAL`]. The ID is the
+//! only credential, so windows showing external sites (the AWS Console) never
+//! receive an API token. The reply carries the next queued [`WebviewControl`],
+//! so commands reach the window within one heartbeat. Windows that stop sending
+//! heartbeats are shown as unresponsive and can still be terminated through
+//! their process.
+
+#![warn(clippy::all, rust_2018_idioms)]
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::process::Child;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// How often webview processes send a heartbeat
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Missed heartbeat time after which a window counts as unresponsive
+const UNRESPONSIVE_AFTER: Duration = Duration::from_secs(10);
+
+/// Command for a webview window, delivered with the heartbeat reply
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebviewControl {
+    /// Bring the window to the front
+    Focus,
+    /// Reload the page
+    Reload,
+    /// Close the window
+    Close,
+}
+
+/// Liveness of a webview window
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WebviewStatus {
+    /// Spawned, no heartbeat yet
+    Starting,
+    /// Heartbeats arriving
+    Running,
+    /// No heartbeat for a while (hung, or an old build without heartbeats)
+    Unresponsive,
+}
+
+impl WebviewStatus {
+    pub fn label(&self) -> &'static str {
+        match self {
+            WebviewStatus::Starting => "Starting",
+            WebviewStatus::Running => "Running",
+            WebviewStatus::Unresponsive => "Unresponsive",
+        }
+    }
+}
+
+/// A webview window as shown in the Open Pages window
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebviewInfo {
+    pub id: String,
+    pub pid: u32,
+    pub title: String,
+    /// Page shown in the window (None for the Pages Manager and consoles)
+    pub page_name: Option<String>,
+    pub spawned_at: DateTime<Utc>,
+    pub last_heartbeat: Option<DateTime<Utc>>,
+    pub status: WebviewStatus,
+}
+
+/// A registered window and its process
+struct WebviewEntry {
+    info: WebviewInfo,
+    child: Child,
+    /// Scoped API token of a page window, revoked when the window goes away
+    page_token: Option<String>,
+    spawned: Instant,
+    last_seen: Option<Instant>,
+    pending: VecDeque<WebviewControl>,
+}
+
+impl WebviewEntry {
+    fn status(&self) -> WebviewStatus {
+        match self.last_seen {
+            Some(seen) if seen.elapsed() < UNRESPONSIVE_AFTER => WebviewStatus::Running,
+            None if self.spawned.elapsed() < UNRESPONSIVE_AFTER => WebviewStatus::Starting,
+            _ => WebviewStatus::Unresponsive,
+        }
+    }
+
+    fn has_exited(&mut self) -> bool {
+        !matches!(self.child.try_wait(), Ok(None))
+    }
+}
+
+/// Registered windows by ID
+struct WindowRegistry {
+    windows: HashMap<String, WebviewEntry>,
+}
+
+impl WindowRegistry {
+    fn new() -> Self {
+        Self {
+            windows: HashMap::new(),
+        }
+    }
+
+    fn register(
+        &mut self,
+        id: String,
+        child: Child,
+        title: String,
+        page_name: Option<String>,
+        page_token: Option<String>,
+    ) {
+        let info = WebviewInfo {
+            id: id.clone(),
+            pid: child.id(),
+            title,
+            page_name,
+            spawned_at: Utc::now(),
+            last_heartbeat: None,
+            status: WebviewStatus::Starting,
+        };
+        self.windows.insert(
+            id,
+            WebviewEntry {
+                info,
+                child,
+                page_token,
+                spawned: Instant::now(),
+                last_seen: None,
+                pending: VecDeque::new(),
+            },
+        );
+    }
+
+    /// Record a heartbeat and return the next queued command
+    fn heartbeat(&mut self, id: &str) -> Result<Option<WebviewControl>> {
+        let entry = self
+            .windows
+            .get_mut(id)
+            .ok_or_else(|| anyhow!("Unknown webview window: {}", id))?;
+        entry.last_seen = Some(Instant::now());
+        entry.info.last_heartbeat = Some(Utc::now());
+        Ok(entry.pending.pop_front())
+    }
+
+    /// Drop windows whose process has exited
+    fn reap(&mut self) {
+        let exited: Vec<String> = self
+            .windows
+            .iter_mut()
+            .filter_map(|(id, entry)| entry.has_exited().then(|| id.clone()))
+            .collect();
+        for id in exited {
+            if let Some(entry) = self.windows.remove(&id) {
+                tracing::info!(
+                    "Webview window exited: {} (pid {})",
+                    entry.info.title,
+                    entry.info.pid
+                );
+                release_page_token(&entry);
+            }
+        }
+    }
+
+    fn list(&mut self) -> Vec<WebviewInfo> {
+        self.reap();
+        let mut windows: Vec<WebviewInfo> = self
+            .windows
+            .values()
+            .map(|entry| WebviewInfo {
+                status: entry.status(),
+                ..entry.info.clone()
+            })
+            .collect();
+        windows.sort_by_key(|w| w.spawned_at);
+        windows
+    }
+
+    fn send(&mut self, id: &str, control: WebviewControl) -> Result<()> {
+        let entry = self
+            .windows
+            .get_mut(id)
+            .ok_or_else(|| anyhow!("Unknown webview window: {}", id))?;
+        if !entry.pending.contains(&control) {
+            entry.pending.push_back(control);
+        }
+        Ok(())
+    }
+
+    fn terminate(&mut self, id: &str) -> Result<()> {
+        let mut entry = self
+            .windows
+            .remove(id)
+            .ok_or_else(|| anyhow!("Unknown webview window: {}", id))?;
+        if !entry.has_exited() {
+            entry.child.kill()?;
+            let _ = entry.child.wait();
+        }
+        tracing::info!(
+            "Terminated webview window: {} (pid {})",
+            entry.info.title,
+            entry.info.pid
+        );
+        release_page_token(&entry);
+        Ok(())
+    }
+}
+
+fn release_page_token(entry: &WebviewEntry) {
+    if let Some(token) = &entry.page_token {
+        super::page_capabilities::revoke_token(token);
+    }
+}
+
+static WINDOW_REGISTRY: OnceLock<Mutex<WindowRegistry>> = OnceLock::new();
+
+fn registry() -> std::sync::MutexGuard<'static, WindowRegistry> {
+    WINDOW_REGISTRY
+        .get_or_init(|| Mutex::new(WindowRegistry::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// New ID to pass to a webview process in `AWSDASH_WEBVIEW_ID`
+pub fn new_window_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Track a spawned webview process
+pub fn register_window(
+    id: String,
+    child: Child,
+    title: String,
+    page_name: Option<String>,
+    page_token: Option<String>,
+) {
+    tracing::info!("Registered webview window: {} (pid {})", title, child.id());
+    registry().register(id, child, title, page_name, page_token);
+}
+
+/// Record a heartbeat from window `id` and return its next command
+pub fn heartbeat(id: &str) -> Result<Option<WebviewControl>> {
+    registry().heartbeat(id)
+}
+
+/// Webview windows that are still running, oldest first
+pub fn list_windows() -> Vec<WebviewInfo> {
+    registry().list()
+}
+
+/// Queue a command for window `id`, delivered with its next heartbeat
+pub fn send_control(id: &str, control: WebviewControl) -> Result<()> {
+    registry().send(id, control)
+}
+
+/// Kill the process of window `id` without asking it to close
+pub fn terminate_window(id: &str) -> Result<()> {
+    registry().terminate(id)
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    fn spawn_sleeper() -> Child {
+        Command::new("sleep").arg("30").spawn().unwrap()
+    }
+
+    #[test]
+    fn test_heartbeat_delivers_queued_commands() {
+        let mut registry = WindowRegistry::new();
+        registry.register(
+            "w1".to_string(),
+            spawn_sleeper(),
+            "Preview: costs".to_string(),
+            Some("costs".to_string()),
+            None,
+        );
+        assert_eq!(registry.list()[0].status, WebviewStatus::Starting);
+
+        registry.send("w1", WebviewControl::Reload).unwrap();
+        registry.send("w1", WebviewControl::Reload).unwrap();
+        registry.send("w1", WebviewControl::Focus).unwrap();
+
+        assert_eq!(
+            registry.heartbeat("w1").unwrap(),
+            Some(WebviewControl::Reload)
+        );
+        assert_eq!(
+            registry.heartbeat("w1").unwrap(),
+            Some(WebviewControl::Focus)
+        );
+        assert_eq!(registry.heartbeat("w1").unwrap(), None);
+        assert_eq!(registry.list()[0].status, WebviewStatus::Running);
+        assert!(registry.heartbeat("unknown").is_err());
+
+        registry.terminate("w1").unwrap();
+        assert!(registry.list().is_empty());
+    }
+
+    #[test]
+    fn test_exited_processes_are_dropped() {
+        let mut registry = WindowRegistry::new();
+        let mut child = Command::new("true").spawn().unwrap();
+        child.wait().unwrap();
+        registry.register("done".to_string(), child, "Done".to_string(), None, None);
+        registry.register(
+            "alive".to_string(),
+            spawn_sleeper(),
+            "Alive".to_string(),
+            None,
+            None,
+        );
+
+        let windows = registry.list();
+        assert_eq!(windows.len(), 1);
+        assert_eq!(windows[0].id, "alive");
+
+        registry.terminate("alive").unwrap();
+    }
+}
@@ -88,31 +88,32 @@ pub fn set_tracing_reload_handle(handle: TracingReloadHandle) {
     *TRACING_RELOAD_HANDLE.lock().unwrap() = Some(handle);
 }
 
+/// Replace the global tracing filter, e.g. after the log levels change in Settings
+pub fn set_log_filter(directive: &str) {
+    if let Some(handle) = TRACING_RELOAD_HANDLE.lock().unwrap().as_ref() {
+        match tracing_subscriber::EnvFilter::builder().parse(directive) {
+            Ok(filter) => {
+                if let Err(e) = handle.reload(filter) {
+                    eprintln!("Failed to reload tracing filter: {}", e);
+                }
+            }
+            Err(e) => tracing::warn!("Invalid log filter '{}': {}", directive, e),
+        }
+    }
+}
+
 /// Set stood tracing level dynamically
 ///
 /// This updates the global tracing filter to capture stood library events
-/// at the specified level. Note that per-agent log capture is handled by
-/// the AgentTracingLayer which respects thread-local log level settings.
+/// at the specified level; the other targets keep the levels from Settings.
+/// Note that per-agent log capture is handled by the AgentTracingLayer which
+/// respects thread-local log level settings.
 ///
 /// # Arguments
 /// * `level` - The StoodLogLevel to set (Off, Info, Debug, Trace)
 pub fn set_stood_log_level(level: app::agent_framework::StoodLogLevel) {
-    if let Some(handle) = TRACING_RELOAD_HANDLE.lock().unwrap().as_ref() {
-        let stood_level = level.to_filter_str();
-
-        let new_filter = format!(
-            "awsdash=trace,stood={},aws_sdk_cloudformation=trace,aws_sdk_bedrockruntime=trace,aws_config=trace,aws_sigv4=trace,aws_smithy_runtime=trace,aws_smithy_runtime_api=trace,hyper=trace,aws_smithy_http=trace,aws_endpoint=trace",
-            stood_level
-        );
-
-        if let Ok(filter) = tracing_subscriber::EnvFilter::builder().parse(&new_filter) {
-            if let Err(e) = handle.reload(filter) {
-                eprintln!("Failed to reload tracing filter: {}", e);
-            } else {
-                tracing::info!("Stood log level set to: {}", level.display_name());
-            }
-        }
-    }
+    app::app_config::set_stood_filter_level(level);
+    tracing::info!("Stood log level set to: {}", level.display_name());
 }
 
 /// Legacy function for backward compatibility
@@ -11,7 +11,7 @@ use awsdash::app::agent_framework::logging::tracing::AgentTracingLayer;
 #[global_allocator]
 static ALLOC: dhat::Alloc = dhat::Alloc;
 
-fn init_logging() {
+fn init_logging(filter_directive: &str) {
     // Check if tokio-console profiling is requested
     // To enable: TOKIO_CONSOLE=1 RUSTFLAGS="--cfg tokio_unstable" cargo run
     let use_tokio_console = std::env::var("TOKIO_CONSOLE")
@@ -56,8 +56,9 @@ fn init_logging() {
         // Configure tracing with unified formatting for all logs
         // Stood agent library set to TRACE to capture all agent events in per-agent logs
         // GUI framework (eframe, egui, glow) logs are also captured via tracing-log bridge
+        // Levels come from Settings (see AppConfig::logging)
         let filter = tracing_subscriber::EnvFilter::builder()
-            .parse("awsdash=info,stood=trace,eframe=info,egui=warn,glow=warn,glutin=warn,winit=warn,aws_sdk_cloudformation=info,aws_sdk_bedrockruntime=info,aws_config=warn,aws_sigv4=warn,aws_smithy_runtime=warn,aws_smithy_runtime_api=warn,hyper=warn,aws_smithy_http=warn,aws_endpoint=warn")
+            .parse(filter_directive)
             .expect("Failed to parse env filter");
 
         let (filter, reload_handle) = tracing_subscriber::reload::Layer::new(filter);
@@ -87,7 +88,7 @@ fn init_logging() {
         awsdash::set_tracing_reload_handle(reload_handle);
 
         tracing::info!("Logging initialized to: {:?}", log_path);
-        tracing::info!("Log levels: awsdash=info, stood=trace (per-agent logs), GUI=info/warn, AWS SDKs=info/warn");
+        tracing::info!("Log levels: {}", filter_directive);
         tracing::info!("AgentTracingLayer enabled - stood:: traces routed to per-agent log files");
     }
 }
@@ -166,8 +167,20 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let args: Vec<String> = std::env::args().collect();
 
+    // Settings decide the log levels, so they are read before logging starts
+    let loaded_config = awsdash::app::app_config::load_app_config();
+    let app_config = match &loaded_config {
+        Ok(config) => config.clone().unwrap_or_default(),
+        Err(_) => Default::default(),
+    };
+
+    awsdash::app::app_config::set_app_config(app_config);
+
     // Early logging initialization for webview path debugging
-    init_logging();
+    init_logging(&awsdash::app::app_config::log_filter_directive());
+    if let Err(e) = &loaded_config {
+        tracing::warn!("Using default settings: {:#}", e);
+    }
 
     tracing::info!("awsdash starting, args: {:?}", args);
 
@@ -177,9 +190,21 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Ok(());
     }
 
+    // --cli runs a headless command and exits without starting the GUI
+    if let Some(exit_code) = awsdash::app::cli::run_cli(&args) {
+        tracing::info!("CLI command finished with exit code {}", exit_code);
+        std::process::exit(exit_code);
+    }
+
     tracing::info!("No webview args, starting normal GUI mode");
     init_perf_timing_path();
 
+    // --demo signs in with a synthetic dataset instead of AWS
+    if args.iter().any(|arg| arg == "--demo") {
+        tracing::info!("Demo mode enabled");
+        awsdash::app::resource_explorer::demo_data::set_demo_mode(true);
+    }
+
     // Initialize memory profiler (only when compiled with --features dhat-heap)
     #[cfg(feature = "dhat-heap")]
     let _profiler = dhat::Profiler::new_heap();
@@ -259,8 +284,10 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         ..Default::default()
     };
 
+    let app_title = format!("AWS Dash{}", awsdash::app::capabilities::build_label());
+
     eframe::run_native(
-        "AWS Dash",
+        &app_title,
         native_options,
         Box::new(|cc| {
             // Install image loaders to support SVG and other image formats